@@ -1,6 +1,4 @@
 extern crate earcutr;
-extern crate serde;
-extern crate serde_json;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::fs::File;
 use std::fs::OpenOptions;
@@ -31,33 +29,13 @@ fn mkoutput(filename_w_dashes: &str, triangles: &[usize]) {
 }
 
 fn parse_json(rawdata: &str) -> Option<Vec<Vec<Vec<f64>>>> {
-    let mut v: Vec<Vec<Vec<f64>>> = Vec::new();
-    match serde_json::from_str::<serde_json::Value>(rawdata) {
-        Err(e) => println!("error deserializing, {}", e),
-        Ok(jsondata) => {
-            if jsondata.is_array() {
-                let contours = jsondata.as_array().unwrap();
-                for contourval in contours {
-                    if contourval.is_array() {
-                        let contour = contourval.as_array().unwrap();
-                        let mut vc: Vec<Vec<f64>> = Vec::new();
-                        for j in contour {
-                            let points = j.as_array().unwrap();
-                            let mut vp: Vec<f64> = Vec::new();
-                            for k in points {
-                                let val = k.to_string();
-                                let pval = val.parse::<f64>().unwrap();
-                                vp.push(pval);
-                            }
-                            vc.push(vp);
-                        }
-                        v.push(vc);
-                    }
-                }
-            }
+    match earcutr::geojson::parse_polygons(rawdata) {
+        Err(e) => {
+            println!("error deserializing, {}", e);
+            None
         }
-    };
-    Some(v)
+        Ok(polygons) => polygons.into_iter().next().map(|polygon| polygon.rings),
+    }
 }
 
 fn load_json(testname: &str) -> (Vec<f64>, Vec<usize>, usize) {
@@ -89,11 +67,7 @@ fn load_json(testname: &str) -> (Vec<f64>, Vec<usize>, usize) {
 fn bench_quadrilateral(criterion: &mut Criterion) {
     criterion.bench_function("bench_quadrilateral", |bench| {
         bench.iter(|| {
-            black_box(earcutr::earcut(
-                &[10., 0., 0., 50., 60., 60., 70., 10.],
-                &[],
-                2,
-            ));
+            black_box(earcutr::earcut(&[10., 0., 0., 50., 60., 60., 70., 10.], &[], 2).unwrap());
         });
     });
 }
@@ -104,7 +78,7 @@ fn bench_hole(criterion: &mut Criterion) {
     v.extend(h);
     criterion.bench_function("bench_hole", |bench| {
         bench.iter(|| {
-            black_box(earcutr::earcut(&v, &[4], 2));
+            black_box(earcutr::earcut(&v, &[4], 2).unwrap());
         })
     });
 }
@@ -538,6 +512,113 @@ fn bench_issue83(criterion: &mut Criterion) {
     });
 }
 
+fn bench_batch(criterion: &mut Criterion) {
+    let polygons = ["water", "water2", "water3", "water3b", "water4"]
+        .iter()
+        .cycle()
+        .take(100)
+        .map(|nm| load_json(nm))
+        .collect::<Vec<_>>();
+    criterion.bench_function("bench_batch_sequential", |bench| {
+        bench.iter(|| {
+            for (data, holeidxs, dimensions) in &polygons {
+                black_box(earcutr::earcut(data, holeidxs, *dimensions).unwrap());
+            }
+        });
+    });
+    criterion.bench_function("bench_batch_parallel", |bench| {
+        bench.iter(|| {
+            black_box(earcutr::batch::earcut_merged(&polygons).unwrap());
+        });
+    });
+}
+
+fn bench_parallel_water_huge(criterion: &mut Criterion) {
+    let (data, holeidxs, dimensions) = load_json("water-huge");
+    criterion.bench_function("bench_parallel_water_huge", |bench| {
+        bench.iter(|| {
+            black_box(earcutr::batch::earcut_parallel(&data, &holeidxs, dimensions).unwrap());
+        });
+    });
+}
+
+// water-huge and water-huge2 hashed on the wide z-order grid, as they are
+// by default, and on the narrow one
+fn bench_zorder_grid(criterion: &mut Criterion) {
+    for nm in ["water-huge", "water-huge2"] {
+        let (data, holeidxs, dimensions) = load_json(nm);
+        let mut narrow = earcutr::EarcutOptions::default();
+        narrow.wide_zorder_threshold = usize::MAX;
+        let mut wide = earcutr::EarcutOptions::default();
+        wide.wide_zorder_threshold = 0;
+        let name = format!("bench_{}_zorder", str::replace(nm, "-", "_"));
+        let mut group = criterion.benchmark_group(name);
+        for (grid, options) in [("narrow", narrow), ("wide", wide)] {
+            group.bench_function(grid, |bench| {
+                bench.iter(|| {
+                    black_box(
+                        earcutr::earcut_with_options(&data, &holeidxs, dimensions, &options)
+                            .unwrap(),
+                    );
+                });
+            });
+        }
+        group.finish();
+    }
+}
+
+// a fine circle beside a long spike, which crowds into a few cells of a
+// 15 bit z-order grid
+fn bench_clustered(criterion: &mut Criterion) {
+    let n = 20_000;
+    let mut data = vec![1e5, 0.0, 1e5, 1e-3];
+    for i in 1..n {
+        let a = i as f64 / n as f64 * std::f64::consts::TAU;
+        data.extend([a.cos(), a.sin()]);
+    }
+    criterion.bench_function("bench_clustered", |bench| {
+        bench.iter(|| {
+            black_box(earcutr::earcut(&data, &[], 2).unwrap());
+        });
+    });
+}
+
+// a square with 2500 small holes, bridged with and without the hole grid
+fn bench_many_holes(criterion: &mut Criterion) {
+    let mut data = vec![0., 0., 200., 0., 200., 200., 0., 200.];
+    let mut holeidxs = Vec::new();
+    for i in 0..2500 {
+        let (x, y) = ((i % 50) as f64 * 4. + 1., (i / 50) as f64 * 4. + 1.);
+        let wobble = (i as f64).sin();
+        holeidxs.push(data.len() / 2);
+        data.extend([
+            x,
+            y,
+            x + 2.,
+            y + wobble,
+            x + 1.5,
+            y + 2.,
+            x + wobble,
+            y + 1.,
+        ]);
+    }
+    let mut group = criterion.benchmark_group("bench_many_holes");
+    group.sample_size(10);
+    group.bench_function("grid", |bench| {
+        bench.iter(|| {
+            black_box(earcutr::earcut(&data, &holeidxs, 2).unwrap());
+        });
+    });
+    let mut options = earcutr::EarcutOptions::default();
+    options.hole_grid_threshold = usize::MAX;
+    group.bench_function("walk", |bench| {
+        bench.iter(|| {
+            black_box(earcutr::earcut_with_options(&data, &holeidxs, 2, &options).unwrap());
+        });
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_indices_3d,
@@ -577,5 +658,10 @@ criterion_group!(
     bench_water3,
     bench_water3b,
     bench_water4,
+    bench_batch,
+    bench_parallel_water_huge,
+    bench_zorder_grid,
+    bench_clustered,
+    bench_many_holes,
 );
 criterion_main!(benches);
@@ -1,6 +1,4 @@
 extern crate earcutr;
-extern crate serde;
-extern crate serde_json;
 use std::fs::File;
 use std::fs::OpenOptions;
 use std::io::Read;
@@ -30,33 +28,13 @@ fn mkoutput(filename_w_dashes: &str, triangles: Vec<usize>) {
 }
 
 fn parse_json(rawdata: &str) -> Option<Vec<Vec<Vec<f64>>>> {
-    let mut v: Vec<Vec<Vec<f64>>> = Vec::new();
-    match serde_json::from_str::<serde_json::Value>(rawdata) {
-        Err(e) => println!("error deserializing, {}", e),
-        Ok(jsondata) => {
-            if jsondata.is_array() {
-                let contours = jsondata.as_array().unwrap();
-                for contourval in contours {
-                    if contourval.is_array() {
-                        let contour = contourval.as_array().unwrap();
-                        let mut vc: Vec<Vec<f64>> = Vec::new();
-                        for points in contour {
-                            let points = points.as_array().unwrap();
-                            let mut vp: Vec<f64> = Vec::new();
-                            for val in points {
-                                let val = val.to_string();
-                                let pval = val.parse::<f64>().unwrap();
-                                vp.push(pval);
-                            }
-                            vc.push(vp);
-                        }
-                        v.push(vc);
-                    }
-                }
-            }
+    match earcutr::geojson::parse_polygons(rawdata) {
+        Err(e) => {
+            println!("error deserializing, {}", e);
+            None
         }
-    };
-    Some(v)
+        Ok(polygons) => polygons.into_iter().next().map(|polygon| polygon.rings),
+    }
 }
 
 fn load_json(testname: &str) -> (Vec<f64>, Vec<usize>, usize) {
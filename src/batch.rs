@@ -0,0 +1,230 @@
+//! Triangulating on all cores: many independent polygons at once, or one
+//! huge polygon cut into pieces.
+//!
+//! Every thread keeps one set of linked lists, which it clears and reuses
+//! for each polygon it triangulates, so a worker only allocates for them
+//! again when a polygon outgrows them. Results always come back in input
+//! order, so the output never depends on the number of threads.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::cmp;
+
+use rayon::prelude::*;
+
+use crate::{
+    Error, FinalTriangleIndices, FlatPolygon, Float, LinkedListNode, LinkedListNodeIndex,
+    LinkedLists, NULL,
+};
+
+/// Pieces with more vertices than this are cut further by `earcut_parallel`.
+pub const DEFAULT_PIECE_SIZE: usize = 1024;
+
+// vertices tried as one end of a cutting diagonal per cut, and the
+// number of other ends tried for each
+const CUT_ATTEMPTS: usize = 64;
+const CUT_CANDIDATES: usize = 8;
+
+thread_local! {
+    // the linked lists of this thread, of the float type it last
+    // triangulated
+    static LINKED_LISTS: RefCell<Option<Box<dyn Any>>> = RefCell::new(None);
+}
+
+// run f with this thread's linked lists, made anew only if there are none
+// yet or they hold another float type
+fn with_linked_lists<T: Float + 'static, R>(f: impl FnOnce(&mut LinkedLists<T>) -> R) -> R {
+    let cached = LINKED_LISTS.with(|cell| cell.borrow_mut().take());
+    let mut ll = cached
+        .and_then(|ll| ll.downcast::<LinkedLists<T>>().ok())
+        .unwrap_or_else(|| Box::new(LinkedLists::new(0)));
+    let result = f(&mut ll);
+    LINKED_LISTS.with(|cell| *cell.borrow_mut() = Some(ll));
+    result
+}
+
+/// Triangulate every polygon, the same as calling `earcut` on each in turn;
+/// entry `i` holds the triangles of `polygons[i]`.
+pub fn earcut_each<T: Float + Send + Sync + 'static>(
+    polygons: &[FlatPolygon<T>],
+) -> Result<Vec<Vec<usize>>, Error> {
+    polygons
+        .par_iter()
+        .map(|(vertices, hole_indices, dims)| {
+            with_linked_lists(|ll| crate::triangulate_with(ll, vertices, hole_indices, *dims))
+        })
+        .collect()
+}
+
+/// Triangulate every polygon, e.g. the parts of a MultiPolygon, into a
+/// single index buffer. Indices refer to the vertices of all polygons laid
+/// end to end in input order, and the triangles of each polygon follow
+/// those of the polygon before it.
+pub fn earcut_merged<T: Float + Send + Sync + 'static>(
+    polygons: &[FlatPolygon<T>],
+) -> Result<Vec<usize>, Error> {
+    let each = earcut_each(polygons)?;
+    let mut triangles = Vec::with_capacity(each.iter().map(Vec::len).sum());
+    let mut offset = 0;
+    for ((vertices, _, dims), polygon_triangles) in polygons.iter().zip(each) {
+        triangles.extend(polygon_triangles.into_iter().map(|i| i + offset));
+        if !vertices.is_empty() {
+            offset += vertices.len() / dims;
+        }
+    }
+    Ok(triangles)
+}
+
+/// Triangulate one large polygon using all cores.
+///
+/// Once its holes are bridged, the polygon is cut along valid diagonals
+/// into pieces of at most `DEFAULT_PIECE_SIZE` vertices where such cuts can
+/// be found, and the pieces are triangulated in parallel. The triangles
+/// differ from those of `earcut` but cover the same area; polygons small
+/// enough to be a single piece come out exactly as `earcut` gives them.
+/// Of a broken polygon that `earcut` cannot cover exactly, cutting it can
+/// change which slivers are left out, though on the fixtures it leaves
+/// out the same area.
+pub fn earcut_parallel<T: Float + Send + Sync>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+) -> Result<Vec<usize>, Error> {
+    earcut_parallel_with_piece_size(vertices, hole_indices, dims, DEFAULT_PIECE_SIZE)
+}
+
+/// Same as `earcut_parallel`, cutting pieces down to `piece_size` vertices.
+pub fn earcut_parallel_with_piece_size<T: Float + Send + Sync>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+    piece_size: usize,
+) -> Result<Vec<usize>, Error> {
+    let mut ll = LinkedLists::new(0);
+    let outer_node = match crate::linked_polygon(&mut ll, vertices, hole_indices, dims)? {
+        Some(outer_node) => outer_node,
+        None => return Ok(Vec::new()),
+    };
+    let mut pieces = Vec::new();
+    partition(&mut ll, outer_node, piece_size.max(3), &mut pieces);
+
+    if pieces == [outer_node] {
+        let mut triangles = FinalTriangleIndices::default();
+        crate::earcut_linked(&mut ll, outer_node, &mut triangles)?;
+        return Ok(triangles.0);
+    }
+    let ll = &ll;
+    let triangles = pieces
+        .par_iter()
+        .map(|&start| {
+            let (mut piece, start) = extract_ring(ll, start);
+            let mut triangles = FinalTriangleIndices::default();
+            crate::earcut_linked(&mut piece, start, &mut triangles)?;
+            Ok(triangles.0)
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(triangles.concat())
+}
+
+// cut the ring at start in two along a valid diagonal, the same way
+// split_earcut does, and recurse into both halves until they are no larger
+// than piece_size or no diagonal is found. collects a node of every piece,
+// in an order that only depends on the input.
+fn partition<T: Float>(
+    ll: &mut LinkedLists<T>,
+    start: LinkedListNodeIndex,
+    piece_size: usize,
+    pieces: &mut Vec<LinkedListNodeIndex>,
+) {
+    if start == NULL {
+        return;
+    }
+    let ring = ll.iter(start..start).map(|n| n.idx).collect::<Vec<_>>();
+    if ring.len() <= piece_size {
+        pieces.push(start);
+        return;
+    }
+    match find_cut(ll, &ring) {
+        None => pieces.push(start),
+        Some((a, b)) => {
+            let c = crate::split_bridge_polygon(ll, a, b);
+
+            // filter colinear points around the cuts
+            let an = ll.nodes[a].next_linked_list_node_index;
+            let cn = ll.nodes[c].next_linked_list_node_index;
+            let a = crate::filter_points(ll, a, Some(an));
+            let c = crate::filter_points(ll, c, Some(cn));
+
+            partition(ll, a, piece_size, pieces);
+            partition(ll, c, piece_size, pieces);
+        }
+    }
+}
+
+// look for a short valid diagonal that leaves at least a quarter of the
+// ring on either side: from a few evenly spaced nodes, try the closest
+// nodes in the opposite half of the ring that the diagonal would leave
+// locally inside
+fn find_cut<T: Float>(
+    ll: &LinkedLists<T>,
+    ring: &[LinkedListNodeIndex],
+) -> Option<(LinkedListNodeIndex, LinkedListNodeIndex)> {
+    // cutting at a point the ring touches more than once is easily wrong,
+    // and there are better places to cut anyway
+    let touching =
+        |p: &LinkedListNode<T>| ring.iter().any(|&q| q != p.idx && ll.nodes[q].xy_eq(*p));
+    let n = ring.len();
+    (0..CUT_ATTEMPTS).find_map(|attempt| {
+        let i = attempt * n / CUT_ATTEMPTS;
+        let a = &ll.nodes[ring[i]];
+        if touching(a) {
+            return None;
+        }
+        let distance = |b: &LinkedListNode<T>| {
+            (b.coord.x - a.coord.x).powi(2) + (b.coord.y - a.coord.y).powi(2)
+        };
+        let mut candidates = (n / 4..=3 * n / 4)
+            .map(|offset| &ll.nodes[ring[(i + offset) % n]])
+            .filter(|b| {
+                b.vertices_index != a.vertices_index
+                    && crate::locally_inside(ll, a, b)
+                    && crate::locally_inside(ll, b, a)
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by(|p, q| {
+            distance(p)
+                .partial_cmp(&distance(q))
+                .unwrap_or(cmp::Ordering::Equal)
+        });
+        candidates
+            .into_iter()
+            .take(CUT_CANDIDATES)
+            .find(|b| {
+                !touching(b) && ll.is_valid_diagonal(a, b) && !crate::passes_vertex(ll, a.idx, a, b)
+            })
+            .map(|b| (a.idx, b.idx))
+    })
+}
+
+// copy the ring at start into linked lists of its own, so it can be
+// triangulated independently of the others
+fn extract_ring<T: Float>(
+    ll: &LinkedLists<T>,
+    start: LinkedListNodeIndex,
+) -> (LinkedLists<T>, LinkedListNodeIndex) {
+    let mut piece = LinkedLists::new(0);
+    piece.options = ll.options;
+    let mut last = None;
+    for node in ll.iter(start..start) {
+        let idx = piece.insert_node(node.vertices_index, node.coord, last);
+        piece.nodes[idx].is_steiner_point = node.is_steiner_point;
+        piece.min.x = piece.min.x.min(node.coord.x);
+        piece.min.y = piece.min.y.min(node.coord.y);
+        piece.max.x = piece.max.x.max(node.coord.x);
+        piece.max.y = piece.max.y.max(node.coord.y);
+        last = Some(idx);
+    }
+    // the first node inserted follows the last one around the ring
+    let start = piece.nodes[last.unwrap_or(NULL)].next_linked_list_node_index;
+    (piece, start)
+}
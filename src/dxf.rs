@@ -0,0 +1,443 @@
+//! Reading closed loops from DXF drawings, for filling floor plans and
+//! other CAD exports.
+//!
+//! Understands ASCII DXF, reading the `LWPOLYLINE` and `HATCH` entities of
+//! the `ENTITIES` section: closed polylines, and the boundary loops of
+//! hatches, whether polylines or made of line, arc and elliptic arc edges.
+//! Bulges and arcs are flattened to within a tolerance. Open polylines,
+//! other entities, blocks and loops with spline edges are left out.
+//! Entities seen from below, with an extrusion direction of -Z, are
+//! mirrored into place; other extrusion directions are not applied.
+
+use crate::geom::Point;
+use crate::nesting::nest_polygons;
+use crate::path::PathBuilder;
+use crate::{Error, FlatPolygon};
+
+/// A closed loop of a drawing, flattened.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Loop {
+    /// the layer of the entity it came from
+    pub layer: String,
+    /// for the boundary loops of a hatch, which hatch of the drawing,
+    /// counting from 0
+    pub hatch: Option<usize>,
+    /// x and y of every vertex
+    pub vertices: Vec<f64>,
+}
+
+/// Read every closed loop out of the `ENTITIES` section of a drawing, in
+/// drawing order, flattening bulges and arcs to within `tolerance`.
+pub fn parse_loops(dxf: &str, tolerance: f64) -> Result<Vec<Loop>, Error> {
+    let pairs = pairs(dxf)?;
+    let mut loops = Vec::new();
+    let mut hatches = 0;
+    let mut section = None;
+    let mut i = 0;
+    while i < pairs.len() {
+        // an entity, or a section marker, runs up to the next 0 code
+        let end = pairs[i + 1..]
+            .iter()
+            .position(|&(code, _)| code == 0)
+            .map_or(pairs.len(), |k| i + 1 + k);
+        let entity = &pairs[i..end];
+        i = end;
+        match (entity[0], section) {
+            ((0, "SECTION"), _) => section = entity.get(1).map(|&(_, name)| name),
+            ((0, "ENDSEC"), _) => section = None,
+            ((0, "LWPOLYLINE"), Some("ENTITIES")) => {
+                loops.extend(lwpolyline(entity, tolerance)?);
+            }
+            ((0, "HATCH"), Some("ENTITIES")) => {
+                loops.extend(hatch(entity, hatches, tolerance)?);
+                hatches += 1;
+            }
+            ((0, _), _) => {}
+            _ => return Err(Error::InvalidDxf),
+        }
+    }
+    Ok(loops)
+}
+
+/// `parse_loops`, then sort the loops into polygons with holes the way
+/// `nesting::nest_polygons` does: the boundary loops of each hatch among
+/// themselves, and closed polylines among those on the same layer.
+pub fn parse_polygons(dxf: &str, tolerance: f64) -> Result<Vec<FlatPolygon<f64>>, Error> {
+    let loops = parse_loops(dxf, tolerance)?;
+    // in the order each group first comes up
+    let mut groups: Vec<Vec<&Loop>> = Vec::new();
+    for l in &loops {
+        let group = groups
+            .iter_mut()
+            .find(|group| (&group[0].layer, group[0].hatch) == (&l.layer, l.hatch));
+        match group {
+            Some(group) => group.push(l),
+            None => groups.push(vec![l]),
+        }
+    }
+    let mut polygons = Vec::new();
+    for group in groups {
+        let mut vertices = Vec::new();
+        let mut ring_indices = Vec::new();
+        for l in group {
+            if !vertices.is_empty() {
+                ring_indices.push(vertices.len() / 2);
+            }
+            vertices.extend(&l.vertices);
+        }
+        polygons.extend(nest_polygons(&vertices, &ring_indices, 2)?);
+    }
+    Ok(polygons)
+}
+
+type Pair<'a> = (i32, &'a str);
+
+// the group code and value pairs, one line each
+fn pairs(dxf: &str) -> Result<Vec<Pair<'_>>, Error> {
+    let mut lines = dxf.lines();
+    let mut pairs = Vec::new();
+    while let Some(code) = lines.next() {
+        if code.trim().is_empty() && pairs.last() == Some(&(0, "EOF")) {
+            continue;
+        }
+        let code = code.trim().parse().map_err(|_| Error::InvalidDxf)?;
+        let value = lines.next().ok_or(Error::InvalidDxf)?;
+        // comments
+        if code != 999 {
+            pairs.push((code, value.trim()));
+        }
+    }
+    Ok(pairs)
+}
+
+// a polyline vertex: x, y and the bulge of the segment after it
+type Vertex = [f64; 3];
+
+fn lwpolyline(entity: &[Pair], tolerance: f64) -> Result<Option<Loop>, Error> {
+    let mut layer = "";
+    let mut closed = false;
+    let mut mirrored = false;
+    let mut vertices: Vec<Vertex> = Vec::new();
+    for &(code, value) in entity {
+        match code {
+            8 => layer = value,
+            70 => closed = int(value)? & 1 != 0,
+            230 => mirrored = float(value)? < 0.0,
+            10 => vertices.push([float(value)?, 0.0, 0.0]),
+            20 | 42 => {
+                let vertex = vertices.last_mut().ok_or(Error::InvalidDxf)?;
+                vertex[if code == 20 { 1 } else { 2 }] = float(value)?;
+            }
+            _ => {}
+        }
+    }
+    let first_again = vertices.len() > 1 && vertices[0][..2] == vertices[vertices.len() - 1][..2];
+    if first_again {
+        vertices.pop();
+    }
+    if !(closed || first_again) || vertices.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = PathBuilder::new(tolerance);
+    polyline(&mut builder, &vertices);
+    Ok(Some(Loop {
+        layer: layer.to_string(),
+        hatch: None,
+        vertices: flatten(builder, mirrored),
+    }))
+}
+
+fn hatch(entity: &[Pair], index: usize, tolerance: f64) -> Result<Vec<Loop>, Error> {
+    let mut reader = Reader { pairs: entity };
+    let mut layer = "";
+    let mut mirrored = false;
+    // the boundary paths follow their count
+    let path_count = loop {
+        match reader.next()? {
+            (8, value) => layer = value,
+            (230, value) => mirrored = float(value)? < 0.0,
+            (91, value) => break int(value)?,
+            _ => {}
+        }
+    };
+
+    let mut loops = Vec::new();
+    for _ in 0..path_count {
+        let mut builder = PathBuilder::new(tolerance);
+        let flags = reader.int(92)?;
+        let drawn = match flags & 2 != 0 {
+            true => polyline_path(&mut reader, &mut builder)?,
+            false => edge_path(&mut reader, &mut builder)?,
+        };
+        // the objects the boundary was picked from
+        for _ in 0..reader.int(97)? {
+            reader.value(330)?;
+        }
+        if drawn {
+            loops.push(Loop {
+                layer: layer.to_string(),
+                hatch: Some(index),
+                vertices: flatten(builder, mirrored),
+            });
+        }
+    }
+    Ok(loops)
+}
+
+// a polyline boundary path, always closed
+fn polyline_path(reader: &mut Reader, builder: &mut PathBuilder) -> Result<bool, Error> {
+    let has_bulge = reader.int(72)? != 0;
+    reader.int(73)?;
+    let mut vertices = Vec::new();
+    for _ in 0..reader.int(93)? {
+        let (x, y) = (reader.float(10)?, reader.float(20)?);
+        let bulge = match has_bulge {
+            true => reader.float(42)?,
+            false => 0.0,
+        };
+        vertices.push([x, y, bulge]);
+    }
+    polyline(builder, &vertices);
+    Ok(!vertices.is_empty())
+}
+
+// a boundary path of edges, each starting where the last ended; returns
+// whether it is drawn, which paths with spline edges are not
+fn edge_path(reader: &mut Reader, builder: &mut PathBuilder) -> Result<bool, Error> {
+    let mut drawn = true;
+    let mut first = true;
+    let mut start = |builder: &mut PathBuilder, p: Point| {
+        match std::mem::take(&mut first) {
+            true => builder.move_to(p[0], p[1]),
+            false => builder.line_to(p[0], p[1]),
+        };
+    };
+    for _ in 0..reader.int(93)? {
+        match reader.int(72)? {
+            // a line
+            1 => {
+                let from = [reader.float(10)?, reader.float(20)?];
+                let to = [reader.float(11)?, reader.float(21)?];
+                start(builder, from);
+                builder.line_to(to[0], to[1]);
+            }
+            // a circular arc, with angles in degrees
+            2 => {
+                let center = [reader.float(10)?, reader.float(20)?];
+                let radius = reader.float(40)?;
+                let angles = [reader.float(50)?, reader.float(51)?];
+                let ccw = reader.int(73)? != 0;
+                let arc = Arc::new(center, [radius, 0.0], 1.0, angles, ccw);
+                start(builder, arc.point(0.0));
+                arc.draw(builder);
+            }
+            // an elliptic arc, from the end of its major axis relative to
+            // the center and the ratio of the minor axis to it, with the
+            // angles its ends are at as seen from the center
+            3 => {
+                let center = [reader.float(10)?, reader.float(20)?];
+                let major = [reader.float(11)?, reader.float(21)?];
+                let ratio = reader.float(40)?;
+                let angles =
+                    [reader.float(50)?, reader.float(51)?].map(|angle| parametric(angle, ratio));
+                let ccw = reader.int(73)? != 0;
+                let arc = Arc::new(center, major, ratio, angles, ccw);
+                start(builder, arc.point(0.0));
+                arc.draw(builder);
+            }
+            4 => {
+                spline(reader)?;
+                drawn = false;
+            }
+            _ => return Err(Error::InvalidDxf),
+        }
+    }
+    Ok(drawn && !first)
+}
+
+// the angle t of the point of an ellipse at the given angle from its
+// center, both in degrees and measured from the major axis, in the same
+// turn: the point at t is at (cos t, ratio sin t) along the axes
+fn parametric(angle: f64, ratio: f64) -> f64 {
+    let turns = angle - angle.rem_euclid(360.0);
+    let (sin, cos) = angle.to_radians().sin_cos();
+    turns + (sin / ratio).atan2(cos).to_degrees().rem_euclid(360.0)
+}
+
+// read past a spline edge
+fn spline(reader: &mut Reader) -> Result<(), Error> {
+    reader.int(94)?;
+    let rational = reader.int(73)? != 0;
+    reader.int(74)?;
+    let knots = reader.int(95)?;
+    let control_points = reader.int(96)?;
+    for _ in 0..knots {
+        reader.float(40)?;
+    }
+    for _ in 0..control_points {
+        reader.float(10)?;
+        reader.float(20)?;
+        if rational {
+            reader.float(42)?;
+        }
+    }
+    // fit points and their end tangents, which older files leave out
+    if reader.peek() == Some(97) {
+        for _ in 0..reader.int(97)? {
+            reader.float(11)?;
+            reader.float(21)?;
+        }
+    }
+    for code in [12, 13] {
+        if reader.peek() == Some(code) {
+            reader.float(code)?;
+            reader.float(code + 10)?;
+        }
+    }
+    Ok(())
+}
+
+// the vertices of a closed polyline, bulged segments as arcs: a bulge is
+// the tangent of a quarter of the angle the arc turns through, positive
+// counterclockwise
+fn polyline(builder: &mut PathBuilder, vertices: &[Vertex]) {
+    let Some(first) = vertices.first() else {
+        return;
+    };
+    builder.move_to(first[0], first[1]);
+    for (k, from) in vertices.iter().enumerate() {
+        let to = vertices[(k + 1) % vertices.len()];
+        let bulge = from[2];
+        if bulge == 0.0 {
+            builder.line_to(to[0], to[1]);
+            continue;
+        }
+        let chord = (to[0] - from[0]).hypot(to[1] - from[1]);
+        let radius = chord * (1.0 + bulge * bulge) / (4.0 * bulge.abs());
+        builder.arc_to(
+            radius,
+            radius,
+            0.0,
+            bulge.abs() > 1.0,
+            bulge > 0.0,
+            to[0],
+            to[1],
+        );
+    }
+    builder.close();
+}
+
+// the vertices drawn, without the repeats left where edges meet end to
+// end, and turned over if seen from below
+fn flatten(builder: PathBuilder, mirrored: bool) -> Vec<f64> {
+    let same = |p: Point, q: Point| {
+        (p[0] - q[0]).hypot(p[1] - q[1]) <= 1e-9 * (1.0 + p[0].abs().max(p[1].abs()))
+    };
+    let mut points: Vec<Point> = Vec::new();
+    for p in builder.build().vertices.chunks(2) {
+        let p = [if mirrored { -p[0] } else { p[0] }, p[1]];
+        if points.last().is_none_or(|&q| !same(p, q)) {
+            points.push(p);
+        }
+    }
+    while points.len() > 1 && same(points[0], points[points.len() - 1]) {
+        points.pop();
+    }
+    points.concat()
+}
+
+// an arc of an ellipse, the point at angle t being
+// center + major cos(t) + minor sin(t)
+struct Arc {
+    center: Point,
+    major: Point,
+    minor: Point,
+    start: f64,
+    // radians, negative going clockwise
+    sweep: f64,
+}
+
+impl Arc {
+    // DXF gives the angles of a clockwise arc mirrored about the x axis,
+    // so such an arc runs clockwise from -start to -end
+    fn new(center: Point, major: Point, ratio: f64, angles: [f64; 2], ccw: bool) -> Arc {
+        let [start, end] = angles.map(f64::to_radians);
+        let mut sweep = (end - start).rem_euclid(std::f64::consts::TAU);
+        if sweep == 0.0 && end != start {
+            sweep = std::f64::consts::TAU;
+        }
+        let sign = if ccw { 1.0 } else { -1.0 };
+        Arc {
+            center,
+            major,
+            minor: [-major[1] * ratio, major[0] * ratio],
+            start: sign * start,
+            sweep: sign * sweep,
+        }
+    }
+
+    // the point a fraction of the way along
+    fn point(&self, fraction: f64) -> Point {
+        let t = self.start + fraction * self.sweep;
+        let (sin, cos) = t.sin_cos();
+        [
+            self.center[0] + self.major[0] * cos + self.minor[0] * sin,
+            self.center[1] + self.major[1] * cos + self.minor[1] * sin,
+        ]
+    }
+
+    // from the start point on, in quarter turns at most, so that no piece
+    // is ambiguous
+    fn draw(&self, builder: &mut PathBuilder) {
+        let rx = self.major[0].hypot(self.major[1]);
+        let ry = self.minor[0].hypot(self.minor[1]);
+        let rotation = self.major[1].atan2(self.major[0]);
+        let pieces = (self.sweep.abs() / std::f64::consts::FRAC_PI_2)
+            .ceil()
+            .max(1.0);
+        for k in 1..=pieces as usize {
+            let [x, y] = self.point(k as f64 / pieces);
+            builder.arc_to(rx, ry, rotation, false, self.sweep > 0.0, x, y);
+        }
+    }
+}
+
+fn int(value: &str) -> Result<i64, Error> {
+    value.parse().map_err(|_| Error::InvalidDxf)
+}
+
+fn float(value: &str) -> Result<f64, Error> {
+    value.parse().map_err(|_| Error::InvalidDxf)
+}
+
+// the pairs of an entity, read in the order they must come in
+struct Reader<'a, 'b> {
+    pairs: &'b [Pair<'a>],
+}
+
+impl<'a> Reader<'a, '_> {
+    fn next(&mut self) -> Result<Pair<'a>, Error> {
+        let (&pair, rest) = self.pairs.split_first().ok_or(Error::InvalidDxf)?;
+        self.pairs = rest;
+        Ok(pair)
+    }
+
+    fn peek(&self) -> Option<i32> {
+        self.pairs.first().map(|&(code, _)| code)
+    }
+
+    fn value(&mut self, code: i32) -> Result<&'a str, Error> {
+        match self.next()? {
+            (found, value) if found == code => Ok(value),
+            _ => Err(Error::InvalidDxf),
+        }
+    }
+
+    fn int(&mut self, code: i32) -> Result<i64, Error> {
+        int(self.value(code)?)
+    }
+
+    fn float(&mut self, code: i32) -> Result<f64, Error> {
+        float(self.value(code)?)
+    }
+}
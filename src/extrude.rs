@@ -0,0 +1,83 @@
+//! Extruding a polygon with holes into a closed prism, e.g. for buildings.
+//!
+//! The floor sits at the polygon's own z (0 for 2D input) and the roof is
+//! raised by the height. Floor and roof share their vertices with the walls,
+//! so the resulting mesh is watertight, and every triangle faces out of the
+//! solid: the roof up, the floor down, outer walls outwards and hole walls
+//! into the hole. Duplicate and collinear points that earcut leaves out of
+//! the caps are left out of the walls too, and stay unused in the mesh.
+
+use std::collections::HashSet;
+
+use crate::mesh::Mesh;
+use crate::{Error, Float};
+
+/// Extrude the polygon by the same height everywhere.
+pub fn extrude<T: Float>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+    height: T,
+) -> Result<Mesh, Error> {
+    if dims < 2 || vertices.len() % dims != 0 {
+        return Err(Error::Unknown);
+    }
+    let heights = vec![height; vertices.len() / dims];
+    extrude_with_heights(vertices, hole_indices, dims, &heights)
+}
+
+/// Extrude the polygon with one height per vertex. Heights are expected
+/// to be positive.
+pub fn extrude_with_heights<T: Float>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+    heights: &[T],
+) -> Result<Mesh, Error> {
+    let triangles = crate::earcut(vertices, hole_indices, dims)?;
+    let floor = Mesh::from_triangulation(vertices, dims, &triangles)?;
+    let n = floor.positions.len();
+    if heights.len() != n {
+        return Err(Error::Unknown);
+    }
+
+    // floor vertices are 0..n, roof vertices n..2n
+    let mut positions = floor.positions.clone();
+    for (&[x, y, z], height) in floor.positions.iter().zip(heights) {
+        positions.push([x, y, z + height.to_f64().ok_or(Error::Unknown)?]);
+    }
+
+    // roof faces up as triangulated, floor faces down
+    let mut mesh_triangles = Vec::with_capacity(2 * triangles.len() + 6 * n);
+    mesh_triangles.extend(floor.triangles.iter().map(|i| i + n));
+    for t in floor.triangles.chunks(3) {
+        mesh_triangles.extend([t[0], t[2], t[1]]);
+    }
+
+    // walls stand on the edges of the caps that only one cap triangle has,
+    // rather than on the input rings: earcut leaves out duplicate and
+    // collinear points, which the walls would otherwise reach the caps at
+    // without meeting a cap edge. as triangulated the polygon is to the
+    // left of these edges, so walls built along them face out. sorted by
+    // their start, the walls go round ring after ring, in input order.
+    let edges = floor
+        .triangles
+        .chunks(3)
+        .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+        .collect::<HashSet<_>>();
+    let mut walls = edges
+        .iter()
+        .filter(|&&(a, b)| !edges.contains(&(b, a)))
+        .copied()
+        .collect::<Vec<_>>();
+    walls.sort_unstable();
+    for (a, b) in walls {
+        mesh_triangles.extend([a, b, b + n, a, b + n, a + n]);
+    }
+
+    Ok(Mesh {
+        positions,
+        triangles: mesh_triangles,
+        normals: Vec::new(),
+    })
+}
@@ -0,0 +1,569 @@
+//! Tessellating contours that overlap or cross themselves and each other,
+//! like SVG paths and font glyphs, under a fill rule.
+//!
+//! The contours are cut wherever they cross or touch, which makes a planar
+//! graph. Every face of the graph gets the winding number of the contours
+//! around it, the edges between faces the rule fills and faces it does not
+//! are traced into rings, and the rings are nested into polygons with holes
+//! for `earcut` to triangulate.
+//!
+//! The same goes for repairing invalid polygons: `make_valid` fills a
+//! polygon's rings by the even-odd rule.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+
+use crate::geom::{bounds, contains, orient, signed_area, Edge, Grid, Point};
+use crate::{Error, FlatPolygon, Float, Triangulated};
+
+/// Which points the contours fill, going by their winding number: how many
+/// times the contours go around the point, counterclockwise (with y up)
+/// counting as positive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// odd winding numbers, like SVG's `evenodd`
+    EvenOdd,
+    /// any winding number but zero, like SVG's `nonzero`
+    NonZero,
+    /// winding numbers above zero
+    Positive,
+    /// winding numbers below zero
+    Negative,
+}
+
+impl FillRule {
+    fn fills(self, winding: i64) -> bool {
+        match self {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+            FillRule::Positive => winding > 0,
+            FillRule::Negative => winding < 0,
+        }
+    }
+}
+
+/// The area the contours fill under the rule, as polygons in the
+/// `(vertices, hole_indices, dims)` form `earcut` takes, in 2D.
+///
+/// The contours are laid out like the rings of an `earcut` polygon, with
+/// `contour_indices` holding the index of the first vertex of every contour
+/// but the first, and only x and y are used. Outer rings come out
+/// counterclockwise and holes clockwise (with y up); rings may touch each
+/// other at vertices but never cross, unless rounding a crossing point off
+/// two segments moves them across a third one right next to it.
+pub fn fill_polygons<T: Float>(
+    vertices: &[T],
+    contour_indices: &[usize],
+    dims: usize,
+    rule: FillRule,
+) -> Result<Vec<FlatPolygon<T>>, Error> {
+    let outline = Outline::new(vertices, contour_indices, dims, rule)?;
+    outline
+        .polygons
+        .iter()
+        .map(|rings| outline.flat_polygon(rings))
+        .collect()
+}
+
+/// Triangulate the area the contours fill under the rule, laid out as for
+/// `fill_polygons`.
+///
+/// Returns the x and y of the vertices the triangles use, which include
+/// the points where contours cross, and three indices into those per
+/// triangle.
+pub fn tessellate<T: Float>(
+    vertices: &[T],
+    contour_indices: &[usize],
+    dims: usize,
+    rule: FillRule,
+) -> Result<(Vec<T>, Vec<usize>), Error> {
+    let outline = Outline::new(vertices, contour_indices, dims, rule)?;
+    // points in the order the triangles first use them
+    let mut index = vec![usize::MAX; outline.points.len()];
+    let mut used = Vec::new();
+    let mut triangles = Vec::new();
+    for rings in &outline.polygons {
+        let (vertices, hole_indices, dims) = outline.flat_polygon::<T>(rings)?;
+        let ring_points: Vec<usize> = rings.iter().flatten().copied().collect();
+        for i in crate::earcut(&vertices, &hole_indices, dims)? {
+            let p = ring_points[i];
+            if index[p] == usize::MAX {
+                index[p] = used.len();
+                used.push(p);
+            }
+            triangles.push(index[p]);
+        }
+    }
+    let vertices = used
+        .iter()
+        .flat_map(|&p| outline.points[p])
+        .map(num_traits::cast::<f64, T>)
+        .collect::<Option<Vec<T>>>()
+        .ok_or(Error::Unknown)?;
+    Ok((vertices, triangles))
+}
+
+/// Repair a polygon whose rings cross or touch themselves or each other,
+/// like bowties and figure eights, the way OGC make-valid does: the result
+/// covers what lies inside an odd number of the rings, split into valid
+/// polygons in the form `fill_polygons` returns.
+///
+/// Parts of rings that enclose nothing, like spikes and rings folded flat,
+/// are dropped, and so are z and any other coordinates past y.
+pub fn make_valid<T: Float>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+) -> Result<Vec<FlatPolygon<T>>, Error> {
+    fill_polygons(vertices, hole_indices, dims, FillRule::EvenOdd)
+}
+
+/// `make_valid`, then `earcut` each polygon it makes. Returns the polygons
+/// along with their triangles, which index into that polygon's vertices.
+pub fn earcut_valid<T: Float>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+) -> Result<Vec<Triangulated<T>>, Error> {
+    make_valid(vertices, hole_indices, dims)?
+        .into_iter()
+        .map(|polygon| {
+            let triangles = crate::earcut(&polygon.0, &polygon.1, polygon.2)?;
+            Ok((polygon, triangles))
+        })
+        .collect()
+}
+
+// the outline of the filled area: rings of indices into points, with the
+// filled area on their left, grouped into polygons outer ring first
+struct Outline {
+    points: Vec<Point>,
+    polygons: Vec<Vec<Vec<usize>>>,
+}
+
+impl Outline {
+    fn new<T: Float>(
+        vertices: &[T],
+        contour_indices: &[usize],
+        dims: usize,
+        rule: FillRule,
+    ) -> Result<Outline, Error> {
+        if dims < 2 || vertices.len() % dims != 0 {
+            return Err(Error::Unknown);
+        }
+        let points = vertices
+            .chunks(dims)
+            .map(|v| Some([v[0].to_f64()?, v[1].to_f64()?]))
+            .collect::<Option<Vec<Point>>>()
+            .ok_or(Error::Unknown)?;
+        if points.iter().flatten().any(|c| !c.is_finite()) {
+            return Err(Error::Unknown);
+        }
+
+        let mut starts = vec![0];
+        starts.extend(contour_indices);
+        starts.push(points.len());
+        let mut segments = Vec::new();
+        for bounds in starts.windows(2) {
+            let contour = points.get(bounds[0]..bounds[1]).ok_or(Error::Unknown)?;
+            segments.extend(
+                contour
+                    .iter()
+                    .zip(contour.iter().cycle().skip(1))
+                    .map(|(&a, &b)| [a, b])
+                    .filter(|&[a, b]| a != b),
+            );
+        }
+
+        let graph = Graph::new(&node(segments));
+        let winding = graph.winding();
+        let filled = |h: usize| rule.fills(winding[graph.face[h]]);
+        let rings = graph.trace(|h| filled(h) && !filled(h ^ 1));
+        let polygons = nest(&graph.points, rings);
+        Ok(Outline {
+            points: graph.points,
+            polygons,
+        })
+    }
+
+    fn flat_polygon<T: Float>(&self, rings: &[Vec<usize>]) -> Result<FlatPolygon<T>, Error> {
+        let mut vertices = Vec::new();
+        let mut hole_indices = Vec::new();
+        for (i, ring) in rings.iter().enumerate() {
+            if i > 0 {
+                hole_indices.push(vertices.len() / 2);
+            }
+            for &p in ring {
+                for c in self.points[p] {
+                    vertices.push(num_traits::cast::<f64, T>(c).ok_or(Error::Unknown)?);
+                }
+            }
+        }
+        Ok((vertices, hole_indices, 2))
+    }
+}
+
+// cut the segments wherever another crosses or touches them, so that
+// segments only meet at their ends, or lie on top of each other end to end.
+// all the crossings are found in one pass over the grid and every segment
+// is split once; both segments are cut at the same point where they cross,
+// so they still meet there after it is rounded off them
+fn node(segments: Vec<Edge>) -> Vec<Edge> {
+    if segments.is_empty() {
+        return segments;
+    }
+    let (min, max) = bounds(&segments.concat());
+    let mut grid = Grid::new(min, max, segments.len());
+    for (i, s) in segments.iter().enumerate() {
+        grid.insert(bounds(s), i);
+    }
+    let mut cuts = vec![Vec::new(); segments.len()];
+    let mut seen = vec![usize::MAX; segments.len()];
+    for (i, &s) in segments.iter().enumerate() {
+        for j in grid.query(bounds(&s)) {
+            if j <= i || seen[j] == i {
+                continue;
+            }
+            seen[j] = i;
+            let (s_cuts, t_cuts) = crossing(s, segments[j]);
+            cuts[i].extend(s_cuts);
+            cuts[j].extend(t_cuts);
+        }
+    }
+    segments
+        .into_iter()
+        .zip(cuts)
+        .flat_map(|(s, cuts)| split(s, cuts))
+        .collect()
+}
+
+// the points to cut s and t at: the ends of each lying inside the other,
+// and the point they cross at if they cross inside both
+fn crossing(s: Edge, t: Edge) -> (Vec<Point>, Vec<Point>) {
+    let [a, b] = s;
+    let [c, d] = t;
+    let (oc, od) = (orient(a, b, c), orient(a, b, d));
+    let (oa, ob) = (orient(c, d, a), orient(c, d, b));
+    let opposite = |x: f64, y: f64| (x < 0.0 && y > 0.0) || (x > 0.0 && y < 0.0);
+
+    let mut s_cuts: Vec<Point> = [(c, oc), (d, od)]
+        .into_iter()
+        .filter(|&(p, o)| o == 0.0 && between(s, p))
+        .map(|(p, _)| p)
+        .collect();
+    let mut t_cuts: Vec<Point> = [(a, oa), (b, ob)]
+        .into_iter()
+        .filter(|&(p, o)| o == 0.0 && between(t, p))
+        .map(|(p, _)| p)
+        .collect();
+    if opposite(oc, od) && opposite(oa, ob) {
+        let k = oa / (oa - ob);
+        let p = [a[0] + k * (b[0] - a[0]), a[1] + k * (b[1] - a[1])];
+        s_cuts.push(p);
+        t_cuts.push(p);
+    }
+    (s_cuts, t_cuts)
+}
+
+// whether p, on the line through e, lies between its ends
+fn between(e: Edge, p: Point) -> bool {
+    let [a, b] = e;
+    p != a
+        && p != b
+        && (a[0].min(b[0])..=a[0].max(b[0])).contains(&p[0])
+        && (a[1].min(b[1])..=a[1].max(b[1])).contains(&p[1])
+}
+
+// the pieces of s between the cuts, in order along it
+fn split(s: Edge, mut cuts: Vec<Point>) -> Vec<Edge> {
+    let [a, b] = s;
+    let along = |p: &Point| (p[0] - a[0]) * (b[0] - a[0]) + (p[1] - a[1]) * (b[1] - a[1]);
+    cuts.sort_by(|p, q| along(p).partial_cmp(&along(q)).unwrap_or(Ordering::Equal));
+    let mut pieces = Vec::with_capacity(cuts.len() + 1);
+    let mut from = a;
+    for to in cuts.into_iter().chain([b]) {
+        if to != from {
+            pieces.push([from, to]);
+            from = to;
+        }
+    }
+    pieces
+}
+
+// the noded segments as a planar graph. edge k runs both ways between two
+// points, as half-edge 2k from the lower numbered point to the higher and
+// 2k + 1 back, and each half-edge is weighted by how many more segments
+// run its way than the other
+struct Graph {
+    points: Vec<Point>,
+    // point each half-edge starts at
+    origin: Vec<usize>,
+    weight: Vec<i64>,
+    // half-edges leaving each point, counterclockwise
+    around: Vec<Vec<usize>>,
+    // where each half-edge is in the list around its origin
+    position: Vec<usize>,
+    // face on the left of each half-edge
+    face: Vec<usize>,
+    faces: usize,
+}
+
+impl Graph {
+    fn new(segments: &[Edge]) -> Graph {
+        let mut points = Vec::new();
+        let mut ids = HashMap::new();
+        // -0.0 and 0.0 are one point
+        let mut id = |p: Point| {
+            *ids.entry(p.map(|c| (c + 0.0).to_bits()))
+                .or_insert_with(|| {
+                    points.push(p);
+                    points.len() - 1
+                })
+        };
+        let mut edges: Vec<(usize, usize, i64)> = segments
+            .iter()
+            .map(|&[a, b]| match (id(a), id(b)) {
+                (a, b) if a < b => (a, b, 1),
+                (a, b) => (b, a, -1),
+            })
+            .collect();
+        edges.sort_unstable();
+        let mut merged: Vec<(usize, usize, i64)> = Vec::with_capacity(edges.len());
+        for (a, b, w) in edges {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (a, b) => last.2 += w,
+                _ => merged.push((a, b, w)),
+            }
+        }
+        merged.retain(|&(_, _, w)| w != 0);
+
+        let mut origin = Vec::with_capacity(2 * merged.len());
+        let mut weight = Vec::with_capacity(2 * merged.len());
+        for (a, b, w) in merged {
+            origin.extend([a, b]);
+            weight.extend([w, -w]);
+        }
+        let mut around = vec![Vec::new(); points.len()];
+        for (h, &p) in origin.iter().enumerate() {
+            around[p].push(h);
+        }
+        for list in &mut around {
+            let direction = |h: usize| {
+                let (p, q) = (points[origin[h]], points[origin[h ^ 1]]);
+                [q[0] - p[0], q[1] - p[1]]
+            };
+            list.sort_by(|&g, &h| by_angle(direction(g), direction(h)));
+        }
+        let mut position = vec![0; origin.len()];
+        for list in &around {
+            for (i, &h) in list.iter().enumerate() {
+                position[h] = i;
+            }
+        }
+
+        let mut graph = Graph {
+            points,
+            origin,
+            weight,
+            around,
+            position,
+            face: Vec::new(),
+            faces: 0,
+        };
+        let mut face = vec![usize::MAX; graph.origin.len()];
+        for start in 0..face.len() {
+            if face[start] != usize::MAX {
+                continue;
+            }
+            let mut h = start;
+            while face[h] == usize::MAX {
+                face[h] = graph.faces;
+                h = graph.next(h);
+            }
+            graph.faces += 1;
+        }
+        graph.face = face;
+        graph
+    }
+
+    // the half-edge after h around the face on its left: the one leaving
+    // its end just clockwise of the way back
+    fn next(&self, h: usize) -> usize {
+        let back = h ^ 1;
+        let around = &self.around[self.origin[back]];
+        around[(self.position[back] + around.len() - 1) % around.len()]
+    }
+
+    // the winding number of every face
+    fn winding(&self) -> Vec<i64> {
+        let mut edges = vec![Vec::new(); self.faces];
+        let mut areas = vec![0.0; self.faces];
+        for h in 0..self.origin.len() {
+            let (p, q) = (self.points[self.origin[h]], self.points[self.origin[h ^ 1]]);
+            edges[self.face[h]].push(h);
+            areas[self.face[h]] += (p[0] * q[1] - q[0] * p[1]) / 2.0;
+        }
+
+        // the connected parts of the graph, each with the outside face
+        // around it being the one of least area
+        let mut parent: Vec<usize> = (0..self.points.len()).collect();
+        fn root(parent: &mut [usize], mut p: usize) -> usize {
+            while parent[p] != p {
+                parent[p] = parent[parent[p]];
+                p = parent[p];
+            }
+            p
+        }
+        for h in (0..self.origin.len()).step_by(2) {
+            let (a, b) = (
+                root(&mut parent, self.origin[h]),
+                root(&mut parent, self.origin[h + 1]),
+            );
+            parent[a] = b;
+        }
+        let part: Vec<usize> = (0..self.points.len())
+            .map(|p| root(&mut parent, p))
+            .collect();
+        let mut outside: HashMap<usize, usize> = HashMap::new();
+        for f in 0..self.faces {
+            let p = part[self.origin[edges[f][0]]];
+            let o = outside.entry(p).or_insert(f);
+            if areas[f] < areas[*o] {
+                *o = f;
+            }
+        }
+
+        let (min, max) = bounds(&self.points);
+        let mut grid = Grid::new(min, max, self.origin.len() / 2);
+        for h in (0..self.origin.len()).step_by(2) {
+            grid.insert(
+                bounds(&[self.points[self.origin[h]], self.points[self.origin[h + 1]]]),
+                h,
+            );
+        }
+
+        let mut winding = vec![None; self.faces];
+        let mut seen = vec![usize::MAX; self.origin.len()];
+        let mut queue = VecDeque::new();
+        let mut outsides: Vec<(usize, usize)> = outside.into_iter().collect();
+        outsides.sort_unstable();
+        for (p, f) in outsides {
+            // the other parts wind around every point of this one the same,
+            // so around its outside face
+            let point = self.points[self.origin[edges[f][0]]];
+            let mut number = 0;
+            let row = ([min[0], point[1]], [max[0], point[1]]);
+            for h in grid.query(row) {
+                if seen[h] == p || part[self.origin[h]] == p {
+                    continue;
+                }
+                seen[h] = p;
+                let (a, b) = (self.points[self.origin[h]], self.points[self.origin[h + 1]]);
+                if a[1] <= point[1] {
+                    if b[1] > point[1] && orient(a, b, point) > 0.0 {
+                        number += self.weight[h];
+                    }
+                } else if b[1] <= point[1] && orient(a, b, point) < 0.0 {
+                    number -= self.weight[h];
+                }
+            }
+            winding[f] = Some(number);
+            queue.push_back(f);
+            while let Some(f) = queue.pop_front() {
+                let number = winding[f].unwrap_or(0);
+                for &h in &edges[f] {
+                    let g = self.face[h ^ 1];
+                    if winding[g].is_none() {
+                        winding[g] = Some(number - self.weight[h]);
+                        queue.push_back(g);
+                    }
+                }
+            }
+        }
+        winding.into_iter().map(|w| w.unwrap_or(0)).collect()
+    }
+
+    // the rings of half-edges for which boundary holds, as the points they
+    // start at. where several meet, a ring turns as sharply left as it
+    // can, so that rings touch there rather than cross
+    fn trace(&self, boundary: impl Fn(usize) -> bool) -> Vec<Vec<usize>> {
+        let boundary: Vec<bool> = (0..self.origin.len()).map(boundary).collect();
+        let mut used = vec![false; self.origin.len()];
+        let mut rings = Vec::new();
+        for start in 0..self.origin.len() {
+            if !boundary[start] || used[start] {
+                continue;
+            }
+            let mut ring = Vec::new();
+            let mut h = start;
+            while !used[h] {
+                used[h] = true;
+                ring.push(self.origin[h]);
+                let back = h ^ 1;
+                let around = &self.around[self.origin[back]];
+                let turn = (1..=around.len())
+                    .map(|k| around[(self.position[back] + around.len() - k) % around.len()])
+                    .find(|&g| boundary[g]);
+                match turn {
+                    Some(g) => h = g,
+                    None => break,
+                }
+            }
+            rings.push(ring);
+        }
+        rings
+    }
+}
+
+// counterclockwise from the positive x axis
+fn by_angle(u: Point, v: Point) -> Ordering {
+    let half = |d: Point| d[1] < 0.0 || (d[1] == 0.0 && d[0] < 0.0);
+    half(u).cmp(&half(v)).then_with(|| {
+        0.0.partial_cmp(&orient([0.0; 2], u, v))
+            .unwrap_or(Ordering::Equal)
+    })
+}
+
+// group the rings into polygons: the counterclockwise ones are outer
+// rings, and each clockwise one is a hole in the smallest outer ring
+// around it
+fn nest(points: &[Point], rings: Vec<Vec<usize>>) -> Vec<Vec<Vec<usize>>> {
+    let coordinates = |ring: &[usize]| ring.iter().map(|&p| points[p]).collect::<Vec<Point>>();
+    let mut outers = Vec::new();
+    let mut holes = Vec::new();
+    for ring in rings {
+        let a = signed_area(coordinates(&ring));
+        if a > 0.0 {
+            outers.push((a, ring));
+        } else if a < 0.0 {
+            holes.push(ring);
+        }
+    }
+    outers.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    // each outer ring's points and their box, made once for all the holes
+    let outlines: Vec<(Vec<Point>, (Point, Point))> = outers
+        .iter()
+        .map(|(_, ring)| {
+            let outline = coordinates(ring);
+            let bounds = bounds(&outline);
+            (outline, bounds)
+        })
+        .collect();
+    let mut polygons: Vec<Vec<Vec<usize>>> =
+        outers.into_iter().map(|(_, ring)| vec![ring]).collect();
+    for hole in holes {
+        // the middle of an edge is only on the outer ring it lies in if
+        // the two share that edge, which rings never do
+        let (a, b) = (points[hole[0]], points[hole[1]]);
+        let p = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+        let around = outlines.iter().position(|(outline, (min, max))| {
+            (min[0]..=max[0]).contains(&p[0])
+                && (min[1]..=max[1]).contains(&p[1])
+                && contains(outline, p)
+        });
+        if let Some(i) = around {
+            polygons[i].push(hole);
+        }
+    }
+    polygons
+}
@@ -0,0 +1,145 @@
+//! Triangulating GeoArrow polygon arrays.
+//!
+//! Takes a polygon column in GeoArrow's native encoding: a list of polygons,
+//! each a list of rings, each a list of coordinates. Coordinates may be
+//! interleaved, a fixed size list of 2 to 4 `Float64` values (xy, xyz, xym
+//! or xyzm), or separated, a struct of 2 to 4 `Float64` arrays with x and y
+//! first. Interleaved coordinates go to `earcut` straight from the
+//! coordinate buffer; separated ones are interleaved one polygon at a time
+//! into a single buffer reused for the whole column.
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{Array, ArrayRef, GenericListArray, OffsetSizeTrait, UInt32Array};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{DataType, Field};
+
+use crate::Error;
+
+/// Triangulate every polygon of a `List` or `LargeList` polygon column.
+/// Returns a list array of the same kind, as `earcut_polygons` does.
+pub fn earcut_column(polygons: &dyn Array) -> Result<ArrayRef, Error> {
+    match polygons.data_type() {
+        DataType::List(_) => Ok(Arc::new(earcut_polygons(polygons.as_list::<i32>())?)),
+        DataType::LargeList(_) => Ok(Arc::new(earcut_polygons(polygons.as_list::<i64>())?)),
+        _ => Err(Error::InvalidGeoArrow),
+    }
+}
+
+/// Triangulate every polygon of a polygon array. Returns a list of `UInt32`
+/// triangle indices per polygon, counting from the polygon's first
+/// coordinate, with null polygons left null.
+pub fn earcut_polygons<O: OffsetSizeTrait>(
+    polygons: &GenericListArray<O>,
+) -> Result<GenericListArray<O>, Error> {
+    let rings = polygons
+        .values()
+        .as_list_opt::<O>()
+        .ok_or(Error::InvalidGeoArrow)?;
+    let coordinates = Coordinates::new(rings.values().as_ref())?;
+    let ring_offsets = rings.offsets();
+
+    let mut scratch = Vec::new();
+    let mut hole_indices = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut offsets = vec![O::usize_as(0)];
+    for (i, w) in polygons.offsets().windows(2).enumerate() {
+        if polygons.is_valid(i) {
+            let starts = ring_offsets
+                .get(w[0].as_usize()..=w[1].as_usize())
+                .ok_or(Error::InvalidGeoArrow)?;
+            let start = starts[0].as_usize();
+            let end = starts[starts.len() - 1].as_usize();
+            hole_indices.clear();
+            hole_indices.extend(
+                starts[1..]
+                    .iter()
+                    .take(starts.len().saturating_sub(2))
+                    .map(|offset| offset.as_usize() - start),
+            );
+            let vertices = coordinates.vertices(start..end, &mut scratch)?;
+            let triangles = crate::earcut(vertices, &hole_indices, coordinates.dims)?;
+            for index in triangles {
+                indices.push(u32::try_from(index).map_err(|_| Error::InvalidGeoArrow)?);
+            }
+        }
+        offsets.push(O::from_usize(indices.len()).ok_or(Error::InvalidGeoArrow)?);
+    }
+    Ok(GenericListArray::new(
+        Arc::new(Field::new_list_field(DataType::UInt32, false)),
+        OffsetBuffer::new(offsets.into()),
+        Arc::new(UInt32Array::from(indices)),
+        polygons.nulls().cloned(),
+    ))
+}
+
+struct Coordinates<'a> {
+    dims: usize,
+    values: Values<'a>,
+}
+
+enum Values<'a> {
+    Interleaved(&'a [f64]),
+    // one array per dimension
+    Separated(Vec<&'a [f64]>),
+}
+
+impl<'a> Coordinates<'a> {
+    fn new(coordinates: &'a dyn Array) -> Result<Coordinates<'a>, Error> {
+        let values = |array: &'a ArrayRef| {
+            let array = array.as_primitive_opt::<Float64Type>();
+            array
+                .map(|array| &array.values()[..])
+                .ok_or(Error::InvalidGeoArrow)
+        };
+        let coordinates = if let Some(list) = coordinates.as_fixed_size_list_opt() {
+            Coordinates {
+                dims: list.value_length() as usize,
+                values: Values::Interleaved(values(list.values())?),
+            }
+        } else if let Some(columns) = coordinates.as_struct_opt() {
+            Coordinates {
+                dims: columns.num_columns(),
+                values: Values::Separated(
+                    columns
+                        .columns()
+                        .iter()
+                        .map(values)
+                        .collect::<Result<_, _>>()?,
+                ),
+            }
+        } else {
+            return Err(Error::InvalidGeoArrow);
+        };
+        match (2..=4).contains(&coordinates.dims) {
+            true => Ok(coordinates),
+            false => Err(Error::InvalidGeoArrow),
+        }
+    }
+
+    // the flat vertices of a range of coordinates
+    fn vertices<'b>(
+        &'b self,
+        range: Range<usize>,
+        scratch: &'b mut Vec<f64>,
+    ) -> Result<&'b [f64], Error> {
+        match &self.values {
+            Values::Interleaved(values) => {
+                let range = range.start * self.dims..range.end * self.dims;
+                values.get(range).ok_or(Error::InvalidGeoArrow)
+            }
+            Values::Separated(columns) => {
+                scratch.clear();
+                for i in range {
+                    for column in columns {
+                        scratch.push(*column.get(i).ok_or(Error::InvalidGeoArrow)?);
+                    }
+                }
+                Ok(scratch)
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Reading polygons from GeoJSON and writing triangulations back out.
+//!
+//! Accepts `Polygon`, `MultiPolygon`, `GeometryCollection`, `Feature` and
+//! `FeatureCollection` objects, as well as bare nested coordinate arrays
+//! like the ones under tests/fixtures.
+
+use serde_json::{Map, Value};
+
+use crate::{Error, FlatPolygon};
+
+/// A polygon read from GeoJSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Polygon {
+    /// outer ring first, followed by the holes
+    pub rings: Vec<Vec<Vec<f64>>>,
+    /// properties of the Feature the polygon came from, if any
+    pub properties: Option<Map<String, Value>>,
+}
+
+impl Polygon {
+    /// Turn the rings into the flat vertices, hole indices and dimensions
+    /// that `earcut` takes.
+    pub fn flatten(&self) -> FlatPolygon<f64> {
+        match self.rings.first().and_then(|ring| ring.first()) {
+            None => (vec![], vec![], 2),
+            Some(_) => crate::flatten(&self.rings),
+        }
+    }
+
+    /// Triangulate the polygon; the indices refer to its positions in ring
+    /// order, as `flatten` lays them out.
+    pub fn earcut(&self) -> Result<Vec<usize>, Error> {
+        let (vertices, hole_indices, dims) = self.flatten();
+        crate::earcut(&vertices, &hole_indices, dims)
+    }
+}
+
+/// Parse every polygon out of a GeoJSON document, in document order.
+///
+/// Point and line geometries are skipped, features without geometry too.
+pub fn parse_polygons(json: &str) -> Result<Vec<Polygon>, Error> {
+    let value: Value = serde_json::from_str(json).map_err(|_| Error::InvalidGeoJson)?;
+    let mut polygons = Vec::new();
+    read_value(&value, None, &mut polygons)?;
+    Ok(polygons)
+}
+
+fn read_value(
+    value: &Value,
+    properties: Option<&Map<String, Value>>,
+    polygons: &mut Vec<Polygon>,
+) -> Result<(), Error> {
+    let object = match value {
+        Value::Array(_) => return read_coordinates(value, properties, polygons),
+        Value::Object(object) => object,
+        _ => return Err(Error::InvalidGeoJson),
+    };
+    let member = |name: &str| object.get(name).ok_or(Error::InvalidGeoJson);
+    let array = |name: &str| member(name)?.as_array().ok_or(Error::InvalidGeoJson);
+
+    match object.get("type").and_then(Value::as_str) {
+        Some("FeatureCollection") => {
+            for feature in array("features")? {
+                read_value(feature, None, polygons)?;
+            }
+        }
+        Some("Feature") => {
+            let properties = match object.get("properties") {
+                None | Some(Value::Null) => None,
+                Some(Value::Object(properties)) => Some(properties),
+                Some(_) => return Err(Error::InvalidGeoJson),
+            };
+            match member("geometry")? {
+                Value::Null => {}
+                geometry => read_value(geometry, properties, polygons)?,
+            }
+        }
+        Some("GeometryCollection") => {
+            for geometry in array("geometries")? {
+                read_value(geometry, properties, polygons)?;
+            }
+        }
+        Some("Polygon") => {
+            polygons.push(Polygon {
+                rings: read_rings(member("coordinates")?)?,
+                properties: properties.cloned(),
+            });
+        }
+        Some("MultiPolygon") => {
+            for coordinates in array("coordinates")? {
+                polygons.push(Polygon {
+                    rings: read_rings(coordinates)?,
+                    properties: properties.cloned(),
+                });
+            }
+        }
+        Some("Point") | Some("MultiPoint") | Some("LineString") | Some("MultiLineString") => {}
+        _ => return Err(Error::InvalidGeoJson),
+    }
+    Ok(())
+}
+
+// bare coordinates, either of a polygon (three levels of nesting) or of a
+// multipolygon (four levels)
+fn read_coordinates(
+    value: &Value,
+    properties: Option<&Map<String, Value>>,
+    polygons: &mut Vec<Polygon>,
+) -> Result<(), Error> {
+    let is_multi = value
+        .get(0)
+        .and_then(|polygon| polygon.get(0))
+        .and_then(|ring| ring.get(0))
+        .is_some_and(Value::is_array);
+    let coordinates = match is_multi {
+        true => value
+            .as_array()
+            .ok_or(Error::InvalidGeoJson)?
+            .iter()
+            .collect(),
+        false => vec![value],
+    };
+    for coordinates in coordinates {
+        polygons.push(Polygon {
+            rings: read_rings(coordinates)?,
+            properties: properties.cloned(),
+        });
+    }
+    Ok(())
+}
+
+fn read_rings(value: &Value) -> Result<Vec<Vec<Vec<f64>>>, Error> {
+    let rings = as_vec(value, |ring| {
+        as_vec(ring, |position| as_vec(position, Value::as_f64))
+    })
+    .ok_or(Error::InvalidGeoJson)?;
+    // every position needs the same number of coordinates, since earcut
+    // reads them with a fixed stride
+    let dims = rings.iter().flatten().map(Vec::len).next().unwrap_or(2);
+    match dims >= 2
+        && rings
+            .iter()
+            .flatten()
+            .all(|position| position.len() == dims)
+    {
+        true => Ok(rings),
+        false => Err(Error::InvalidGeoJson),
+    }
+}
+
+fn as_vec<U>(value: &Value, f: impl Fn(&Value) -> Option<U>) -> Option<Vec<U>> {
+    value.as_array()?.iter().map(f).collect()
+}
+
+/// Build a FeatureCollection with one triangle Polygon per output triangle.
+///
+/// `triangles[i]` is the `earcut` output for `polygons[i]`; the polygon's
+/// properties are copied onto each of its triangles. Fails with
+/// `InvalidGeoJson` if there is not one list of triangles per polygon, or a
+/// triangle is cut short or refers to a vertex the polygon does not have.
+pub fn triangles_to_feature_collection(
+    polygons: &[Polygon],
+    triangles: &[Vec<usize>],
+) -> Result<Value, Error> {
+    if polygons.len() != triangles.len() {
+        return Err(Error::InvalidGeoJson);
+    }
+    let mut features = Vec::new();
+    for (polygon, triangles) in polygons.iter().zip(triangles) {
+        let positions = polygon.rings.iter().flatten().collect::<Vec<_>>();
+        let properties = match &polygon.properties {
+            Some(properties) => Value::Object(properties.clone()),
+            None => Value::Null,
+        };
+        for triangle in triangles.chunks(3) {
+            let ring = triangle
+                .iter()
+                .chain(triangle.first())
+                .map(|&i| {
+                    positions
+                        .get(i)
+                        .map(|position| Value::from(position.as_slice()))
+                })
+                .collect::<Option<Vec<_>>>()
+                .ok_or(Error::InvalidGeoJson)?;
+            if ring.len() != 4 {
+                return Err(Error::InvalidGeoJson);
+            }
+            features.push(serde_json::json!({
+                "type": "Feature",
+                "properties": properties,
+                "geometry": { "type": "Polygon", "coordinates": [ring] },
+            }));
+        }
+    }
+    Ok(serde_json::json!({ "type": "FeatureCollection", "features": features }))
+}
@@ -0,0 +1,154 @@
+//! Plane geometry shared by the modules: points, rings and a grid to find
+//! them in.
+
+use crate::Float;
+
+pub(crate) type Point = [f64; 2];
+pub(crate) type Edge = [Point; 2];
+
+/// Signed area of a ring of points, positive when it runs counterclockwise
+/// (with y up). Points are given by their coordinates, of which any past x
+/// and y are ignored.
+///
+/// ```
+/// let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+/// assert_eq!(earcutr::signed_area(&square), 1.0);
+/// ```
+pub fn signed_area<T, P, I>(ring: I) -> T
+where
+    T: Float,
+    P: AsRef<[T]>,
+    I: IntoIterator<Item = P>,
+    I::IntoIter: Clone,
+{
+    let ring = ring.into_iter();
+    let last = match ring.clone().last() {
+        Some(last) => last,
+        None => return T::zero(),
+    };
+    // summed the way earcut.js does, over each point and the one before it
+    let (sum, _) = ring.fold((T::zero(), last), |(sum, prev), p| {
+        let (q, r) = (prev.as_ref(), p.as_ref());
+        (sum + (q[0] - r[0]) * (r[1] + q[1]), p)
+    });
+    sum / (T::one() + T::one())
+}
+
+// positive when p is left of a-b
+pub(crate) fn orient(a: Point, b: Point, p: Point) -> f64 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+pub(crate) fn bounds(points: &[Point]) -> (Point, Point) {
+    points.iter().fold(
+        ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]),
+        |(min, max), p| {
+            (
+                [min[0].min(p[0]), min[1].min(p[1])],
+                [max[0].max(p[0]), max[1].max(p[1])],
+            )
+        },
+    )
+}
+
+// even-odd test of p against the ring
+#[cfg(any(feature = "fill", feature = "nesting", feature = "shapefile"))]
+pub(crate) fn contains(ring: &[Point], p: Point) -> bool {
+    let mut inside = false;
+    for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+        if (a[1] > p[1]) != (b[1] > p[1])
+            && p[0] < a[0] + (p[1] - a[1]) * (b[0] - a[0]) / (b[1] - a[1])
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// a point to test whether the ring lies inside another: the middle of its
+// first edge, which is only on another ring if the two share that edge
+#[cfg(any(feature = "nesting", feature = "shapefile"))]
+pub(crate) fn probe(ring: &[Point]) -> Option<Point> {
+    let a = *ring.first()?;
+    let b = ring.iter().copied().find(|&b| b != a).unwrap_or(a);
+    Some([(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0])
+}
+
+// a uniform grid of items listed in the cells their bounds overlap
+pub(crate) struct Grid {
+    min: Point,
+    inv_cell_size: f64,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+}
+
+impl Grid {
+    // about one cell per item
+    pub(crate) fn new(min: Point, max: Point, count: usize) -> Grid {
+        let (width, height) = (max[0] - min[0], max[1] - min[1]);
+        let count = count.max(1) as f64;
+        let cell_size = (width * height / count)
+            .sqrt()
+            .max(width.max(height) / count);
+        let inv_cell_size = match cell_size > 0.0 {
+            true => 1.0 / cell_size,
+            false => 0.0,
+        };
+        let cols = (width * inv_cell_size) as usize + 1;
+        let rows = (height * inv_cell_size) as usize + 1;
+        Grid {
+            min,
+            inv_cell_size,
+            cols,
+            rows,
+            cells: vec![Vec::new(); cols * rows],
+        }
+    }
+
+    fn col(&self, x: f64) -> usize {
+        (((x - self.min[0]) * self.inv_cell_size).max(0.0) as usize).min(self.cols - 1)
+    }
+
+    fn row(&self, y: f64) -> usize {
+        (((y - self.min[1]) * self.inv_cell_size).max(0.0) as usize).min(self.rows - 1)
+    }
+
+    pub(crate) fn insert(&mut self, (min, max): (Point, Point), item: usize) {
+        for row in self.row(min[1])..=self.row(max[1]) {
+            for col in self.col(min[0])..=self.col(max[0]) {
+                self.cells[row * self.cols + col].push(item);
+            }
+        }
+    }
+
+    // the items in the cells overlapping the bounds, some more than once
+    pub(crate) fn query(&self, (min, max): (Point, Point)) -> impl Iterator<Item = usize> + '_ {
+        let cols = self.col(min[0])..=self.col(max[0]);
+        (self.row(min[1])..=self.row(max[1])).flat_map(move |row| {
+            cols.clone()
+                .flat_map(move |col| self.cells[row * self.cols + col].iter().copied())
+        })
+    }
+
+    // even-odd test of p against the edges, counting each edge crossed by
+    // the ray to the left of p in the one cell the crossing falls in
+    pub(crate) fn contains(&self, edges: &[Edge], p: Point) -> bool {
+        let row = self.row(p[1]);
+        let mut inside = false;
+        for col in 0..=self.col(p[0]) {
+            for &e in &self.cells[row * self.cols + col] {
+                let [a, b] = edges[e];
+                if (a[1] > p[1]) == (b[1] > p[1]) {
+                    continue;
+                }
+                let x = a[0] + (p[1] - a[1]) * (b[0] - a[0]) / (b[1] - a[1]);
+                let x = x.clamp(a[0].min(b[0]), a[0].max(b[0]));
+                if x < p[0] && self.col(x) == col {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
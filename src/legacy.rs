@@ -74,7 +74,7 @@ pub fn pb(a: bool) -> String {
 
 // turn a polygon in a multi-dimensional array form (e.g. as in GeoJSON)
 // into a form Earcut accepts
-pub fn flatten<T: Float + Display>(data: &Vec<Vec<Vec<T>>>) -> (Vec<T>, Vec<usize>, usize) {
+pub fn flatten<T: Float + Display>(data: &[Vec<Vec<T>>]) -> (Vec<T>, Vec<usize>, usize) {
     (
         data.iter().flatten().flatten().cloned().collect::<Vec<T>>(), // flat data
         data.iter()
@@ -103,19 +103,22 @@ pub fn deviation<T: Float + Display>(
     let mut indices = hole_indices.to_vec();
     indices.push(vertices.len() / DIM);
     let (ix, iy) = (indices.iter(), indices.iter().skip(1));
-    let body_area = vertices.signed_area(0, indices[0] * DIM).abs();
-    let polygon_area = ix.zip(iy).fold(body_area, |a, (ix, iy)| {
-        a - vertices.signed_area(ix * DIM, iy * DIM).abs()
-    });
+    let ring_area = |start: usize, end: usize| {
+        crate::signed_area(vertices.0[start * DIM..end * DIM].chunks(DIM)).abs()
+    };
+    let body_area = ring_area(0, indices[0]);
+    let polygon_area = ix
+        .zip(iy)
+        .fold(body_area, |a, (&ix, &iy)| a - ring_area(ix, iy));
 
-    let i = triangles.iter().skip(0).step_by(3).map(|x| x * DIM);
+    let i = triangles.iter().step_by(3).map(|x| x * DIM);
     let j = triangles.iter().skip(1).step_by(3).map(|x| x * DIM);
     let k = triangles.iter().skip(2).step_by(3).map(|x| x * DIM);
     let triangles_area = i.zip(j).zip(k).fold(T::zero(), |ta, ((a, b), c)| {
         ta + ((vertices.0[a] - vertices.0[c]) * (vertices.0[b + 1] - vertices.0[a + 1])
             - (vertices.0[a] - vertices.0[b]) * (vertices.0[c + 1] - vertices.0[a + 1]))
             .abs()
-    });
+    }) / (T::one() + T::one());
 
     match polygon_area.is_zero() && triangles_area.is_zero() {
         true => T::zero(),
@@ -10,8 +10,69 @@ mod tests;
 #[doc(hidden)]
 pub mod legacy;
 
+#[cfg(feature = "rayon")]
+pub mod batch;
+
+#[cfg(feature = "dxf")]
+pub mod dxf;
+
+#[cfg(feature = "fill")]
+pub mod fill;
+
+#[cfg(feature = "geoarrow")]
+pub mod geoarrow;
+
+#[cfg(feature = "geojson")]
+pub mod geojson;
+
+#[cfg(feature = "mesh")]
+pub mod extrude;
+
+#[cfg(feature = "mesh")]
+pub mod mesh;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[cfg(feature = "mvt")]
+pub mod mvt;
+
+#[cfg(feature = "nesting")]
+pub mod nesting;
+
+#[cfg(feature = "path")]
+pub mod path;
+
+#[cfg(feature = "shapefile")]
+pub mod shapefile;
+
+#[cfg(any(feature = "svg", feature = "svg-import"))]
+pub mod svg;
+
+#[cfg(feature = "wkb")]
+pub mod wkb;
+
+#[cfg(feature = "wkt")]
+pub mod wkt;
+
+mod geom;
+mod verify;
+
+pub use geom::signed_area;
 pub use legacy::deviation;
 pub use legacy::flatten;
+pub use verify::{verify, Verification};
+
+/// Flat vertices, hole indices and dimensions of one polygon, the way
+/// `earcut` takes them and `flatten` returns them.
+pub type FlatPolygon<T> = (Vec<T>, Vec<usize>, usize);
+
+/// A polygon, and the triangles `earcut` makes of it.
+pub type Triangulated<T> = (FlatPolygon<T>, Vec<usize>);
+
+/// The indices of the hole vertex and the outer vertex a bridge links, as
+/// `earcut_with_bridges` returns them.
+pub type Bridge = (usize, usize);
 
 type LinkedListNodeIndex = usize;
 type VerticesIndex = usize;
@@ -20,16 +81,151 @@ pub trait Float: num_traits::float::Float {}
 
 impl<T> Float for T where T: num_traits::float::Float {}
 
+// pairs of vertices tried as bridges by find_nearest_bridge before giving
+// up on a hole
+const NEAREST_BRIDGE_CANDIDATES: usize = 32;
+
+/// Tuning for `earcut_with_options`. The default is what `earcut` does.
+///
+/// More options may be added, so start from `default()` or `upstream()`
+/// and set the fields to change:
+///
+/// ```
+/// let mut options = earcutr::EarcutOptions::default();
+/// options.hash_threshold = 0;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct EarcutOptions {
+    /// polygons with at least this many vertices, holes included, use
+    /// z-order hashing to find points inside ears; 0 hashes every polygon
+    /// and `usize::MAX` none
+    pub hash_threshold: usize,
+    /// hashed polygons with more than this many vertices use a 2^32 by 2^32
+    /// z-order grid rather than 2^15 by 2^15
+    pub wide_zorder_threshold: usize,
+    /// polygons with at least this many holes look up the bridge for each
+    /// in a grid over the outer ring rather than walking all of it; the
+    /// bridges are the same either way
+    pub hole_grid_threshold: usize,
+    /// the order holes are bridged into the outer ring in
+    pub hole_order: HoleOrder,
+    /// where each hole is bridged to
+    pub bridging: Bridging,
+    /// what to try when no more ears can be found
+    pub repair: Repair,
+    /// move hashed polygons so their bounding box starts at 0,0 before
+    /// slicing ears, which saves a subtraction in every z-order lookup.
+    /// earcut.js leaves the points where they are; moved, they round
+    /// differently, and some polygons come out as other triangles.
+    pub translate: bool,
+    /// treat points that touch the way earcut.js 3.0 does: curing local
+    /// intersections also cuts segments that only touch, an ear may hold
+    /// a point in the same place as its first vertex, and the leftmost
+    /// point of a ring is the lowest of those as far left. Without, the
+    /// triangles of water-huge and water-huge2 are fewer and closer to
+    /// their outlines.
+    pub earcut3_touching: bool,
+}
+
+impl Default for EarcutOptions {
+    fn default() -> Self {
+        EarcutOptions {
+            hash_threshold: 40,
+            wide_zorder_threshold: 1024,
+            hole_grid_threshold: 16,
+            hole_order: HoleOrder::LeftToRight,
+            bridging: Bridging::Eberly,
+            repair: Repair::SplitPolygon,
+            translate: true,
+            earcut3_touching: false,
+        }
+    }
+}
+
+impl EarcutOptions {
+    /// The settings of the earcut.js copied into viz/earcut.js, currently
+    /// 3.0: hashing from 81 vertices on, on the narrow grid only and with
+    /// the points left in place, holes bridged left to right after Eberly,
+    /// every repair, and touching points treated as earcut.js 3.0 does. The triangles are index for index the ones that
+    /// earcut.js makes. This follows the copy in viz, so it changes when
+    /// the copy is brought up to a newer earcut.js.
+    pub fn upstream() -> Self {
+        EarcutOptions {
+            hash_threshold: 81,
+            wide_zorder_threshold: usize::MAX,
+            hole_grid_threshold: usize::MAX,
+            hole_order: HoleOrder::LeftToRight,
+            bridging: Bridging::Eberly,
+            repair: Repair::SplitPolygon,
+            translate: false,
+            earcut3_touching: true,
+        }
+    }
+}
+
+/// Order of bridging holes into the outer ring.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HoleOrder {
+    /// by each hole's leftmost vertex, and holes meeting there
+    /// counterclockwise
+    LeftToRight,
+    /// as given
+    Input,
+}
+
+/// Where to cut the outer ring open to link in a hole.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bridging {
+    /// to the left of the hole's leftmost vertex, after David Eberly. fast,
+    /// but bridges can be long and leave fans of thin triangles around holes
+    Eberly,
+    /// the shortest bridge between any vertex of the hole and a vertex of
+    /// the outer ring that can see it, falling back to `Eberly` if there is
+    /// none. slower, with better shaped triangles around the holes.
+    Nearest,
+}
+
+/// How far to go when ear slicing gets stuck. Each step also takes the
+/// ones before it; whatever is left after the last one goes untriangulated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Repair {
+    /// give up straight away
+    None,
+    /// drop duplicate and collinear points, then retry
+    FilterPoints,
+    /// cut off small self-intersections, then retry
+    CureIntersections,
+    /// split the rest in two along a diagonal and triangulate both halves
+    SplitPolygon,
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 #[non_exhaustive]
 pub enum Error {
     Unknown,
+    InvalidGeoJson,
+    InvalidWkt,
+    InvalidWkb,
+    InvalidSvg,
+    InvalidShapefile,
+    InvalidMvt,
+    InvalidGeoArrow,
+    InvalidDxf,
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, mut f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Error::Unknown => write!(&mut f, "Unknown error"),
+            Error::InvalidGeoJson => write!(&mut f, "Invalid GeoJSON"),
+            Error::InvalidWkt => write!(&mut f, "Invalid WKT"),
+            Error::InvalidWkb => write!(&mut f, "Invalid WKB"),
+            Error::InvalidSvg => write!(&mut f, "Invalid SVG"),
+            Error::InvalidShapefile => write!(&mut f, "Invalid shapefile"),
+            Error::InvalidMvt => write!(&mut f, "Invalid MVT geometry"),
+            Error::InvalidGeoArrow => write!(&mut f, "Invalid GeoArrow array"),
+            Error::InvalidDxf => write!(&mut f, "Invalid DXF"),
         }
     }
 }
@@ -46,23 +242,29 @@ impl<T: Float> Coord<T> {
     // z-order of a point given coords and inverse of the longer side of
     // data bbox
     #[inline(always)]
-    fn zorder(&self, invsize: T) -> Result<i32, Error> {
-        // coords are transformed into non-negative 15-bit integer range
-        // stored in two 32bit ints, which are combined into a single 64 bit int.
+    fn zorder(&self, invsize: T) -> Result<u64, Error> {
+        // coords are transformed into non-negative integers of up to 32
+        // bits (15 unless the grid is wide, see calc_invsize), whose bits
+        // are interleaved into a single 64 bit morton code.
         let x: i64 = num_traits::cast::<T, i64>(self.x * invsize).ok_or(Error::Unknown)?;
         let y: i64 = num_traits::cast::<T, i64>(self.y * invsize).ok_or(Error::Unknown)?;
-        let mut xy: i64 = x << 32 | y;
-
-        // todo ... big endian?
-        xy = (xy | (xy << 8)) & 0x00FF00FF00FF00FF;
-        xy = (xy | (xy << 4)) & 0x0F0F0F0F0F0F0F0F;
-        xy = (xy | (xy << 2)) & 0x3333333333333333;
-        xy = (xy | (xy << 1)) & 0x5555555555555555;
-
-        Ok(((xy >> 32) | (xy << 1)) as i32)
+        // rounding may overshoot the grid a little; keep it in 32 bits
+        let (x, y) = (x.clamp(0, u32::MAX.into()), y.clamp(0, u32::MAX.into()));
+        Ok(spread_bits(x as u64) | (spread_bits(y as u64) << 1))
     }
 }
 
+// spread the low 32 bits of v out to the even bits of the result
+#[inline(always)]
+fn spread_bits(v: u64) -> u64 {
+    let mut v = v & 0xFFFF_FFFF;
+    v = (v | (v << 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v | (v << 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v | (v << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    v = (v | (v << 2)) & 0x3333_3333_3333_3333;
+    (v | (v << 1)) & 0x5555_5555_5555_5555
+}
+
 #[derive(Clone, Copy, Debug)]
 struct LinkedListNode<T: Float> {
     /// vertex index in flat one-d array of 64bit float coords
@@ -74,7 +276,7 @@ struct LinkedListNode<T: Float> {
     /// next vertex node in a polygon ring
     next_linked_list_node_index: LinkedListNodeIndex,
     /// z-order curve value
-    z: i32,
+    z: u64,
     /// previous node in z-order
     prevz_idx: LinkedListNodeIndex,
     /// next node in z-order
@@ -120,6 +322,8 @@ pub struct LinkedLists<T: Float> {
     min: Coord<T>,
     max: Coord<T>,
     usehash: bool,
+    bridges: Vec<Bridge>,
+    options: EarcutOptions,
 }
 
 struct Vertices<'a, T: Float>(&'a [T]);
@@ -132,15 +336,6 @@ impl<'a, T: Float> Vertices<'a, T> {
     fn len(&'a self) -> usize {
         self.0.len()
     }
-
-    fn signed_area(&self, start: VerticesIndex, end: VerticesIndex) -> T {
-        let i = (start..end).step_by(DIM);
-        let j = (start..end).cycle().skip((end - DIM) - start).step_by(DIM);
-        let zero = T::zero();
-        i.zip(j).fold(zero, |s, (i, j)| {
-            s + (self.0[j] - self.0[i]) * (self.0[i + 1] + self.0[j + 1])
-        })
-    }
 }
 
 // Note: none of the following macros work for Left-Hand-Side of assignment.
@@ -166,11 +361,11 @@ macro_rules! prevref {
 }
 
 impl<T: Float> LinkedLists<T> {
-    fn iter(&self, r: ops::Range<LinkedListNodeIndex>) -> NodeIterator<T> {
+    fn iter(&self, r: ops::Range<LinkedListNodeIndex>) -> NodeIterator<'_, T> {
         NodeIterator::new(self, r.start, r.end)
     }
 
-    fn iter_pairs(&self, r: ops::Range<LinkedListNodeIndex>) -> NodePairIterator<T> {
+    fn iter_pairs(&self, r: ops::Range<LinkedListNodeIndex>) -> NodePairIterator<'_, T> {
         NodePairIterator::new(self, r.start, r.end)
     }
 
@@ -209,8 +404,24 @@ impl<T: Float> LinkedLists<T> {
         self.nodes[nz].prevz_idx = pz;
     }
     fn new(size_hint: usize) -> LinkedLists<T> {
+        LinkedLists::with_buffers(Vec::with_capacity(size_hint), Vec::new())
+    }
+
+    // empty the lists for another polygon, keeping their allocations
+    fn reset(&mut self, size_hint: usize) {
+        let mut nodes = std::mem::take(&mut self.nodes);
+        let mut bridges = std::mem::take(&mut self.bridges);
+        nodes.clear();
+        nodes.reserve(size_hint);
+        bridges.clear();
+        let options = self.options;
+        *self = LinkedLists::with_buffers(nodes, bridges);
+        self.options = options;
+    }
+
+    fn with_buffers(nodes: Vec<LinkedListNode<T>>, bridges: Vec<Bridge>) -> LinkedLists<T> {
         let mut ll = LinkedLists {
-            nodes: Vec::with_capacity(size_hint),
+            nodes,
             invsize: T::zero(),
             min: Coord {
                 x: T::max_value(),
@@ -221,6 +432,8 @@ impl<T: Float> LinkedLists<T> {
                 y: T::min_value(),
             },
             usehash: true,
+            bridges,
+            options: EarcutOptions::default(),
         };
         // ll.nodes[0] is the NULL node. For example usage, see remove_node()
         ll.nodes.push(LinkedListNode {
@@ -242,11 +455,10 @@ impl<T: Float> LinkedLists<T> {
 
     // interlink polygon nodes in z-order
     fn index_curve(&mut self, start: LinkedListNodeIndex) -> Result<(), Error> {
-        let invsize = self.invsize;
         let mut p = start;
         loop {
             if self.nodes[p].z == 0 {
-                self.nodes[p].z = self.nodes[p].coord.zorder(invsize)?;
+                self.nodes[p].z = self.zorder(self.nodes[p].coord)?;
             }
             self.nodes[p].prevz_idx = self.nodes[p].prev_linked_list_node_index;
             self.nodes[p].nextz_idx = self.nodes[p].next_linked_list_node_index;
@@ -263,17 +475,61 @@ impl<T: Float> LinkedLists<T> {
         Ok(())
     }
 
+    // z-order of a point, on the grid over the bbox of the polygon
+    #[inline(always)]
+    fn zorder(&self, p: Coord<T>) -> Result<u64, Error> {
+        Coord {
+            x: p.x - self.min.x,
+            y: p.y - self.min.y,
+        }
+        .zorder(self.invsize)
+    }
+
     // find a bridge between vertices that connects hole with an outer ring
-    // and and link it
+    // and and link it. pending are the holes still to be linked after this
+    // one. returns a node of the outer ring, now with the hole in it, or
+    // outer_node_idx as it was if there is no bridge.
     fn eliminate_hole(
         &mut self,
         hole_idx: LinkedListNodeIndex,
         outer_node_idx: LinkedListNodeIndex,
-    ) {
-        let test_idx = find_hole_bridge(self, hole_idx, outer_node_idx);
+        grid: Option<&mut HoleGrid<T>>,
+        pending: &[LinkedListNodeIndex],
+    ) -> LinkedListNodeIndex {
+        let nearest = match self.options.bridging {
+            Bridging::Eberly => None,
+            Bridging::Nearest => find_nearest_bridge(self, hole_idx, outer_node_idx, pending),
+        };
+        let (test_idx, hole_idx) = nearest.unwrap_or_else(|| match &grid {
+            Some(grid) => (
+                grid.find_hole_bridge(self, hole_idx, outer_node_idx),
+                hole_idx,
+            ),
+            None => (find_hole_bridge(self, hole_idx, outer_node_idx), hole_idx),
+        });
+        if test_idx == NULL {
+            return outer_node_idx;
+        }
+        self.bridges.push((
+            self.nodes[hole_idx].vertices_index,
+            self.nodes[test_idx].vertices_index,
+        ));
         let b = split_bridge_polygon(self, test_idx, hole_idx);
+        if let Some(grid) = grid {
+            // the bridge, the hole and the copies of both ends are now
+            // part of the outer ring
+            grid.insert(self, test_idx);
+            for p in self.iter(hole_idx..b - 1) {
+                grid.insert(self, p.idx);
+            }
+            grid.insert(self, b - 1);
+        }
+
+        // filter collinear points around the cuts
         let ni = self.nodes[b].next_linked_list_node_index;
         filter_points(self, b, Some(ni));
+        let ni = self.nodes[test_idx].next_linked_list_node_index;
+        filter_points(self, test_idx, Some(ni))
     }
 
     // Simon Tatham's linked list merge sort algorithm
@@ -358,17 +614,13 @@ impl<T: Float> LinkedLists<T> {
         }
 
         let mut lastidx = None;
-        let mut leftmost_idx = None;
         let mut contour_minx = T::max_value();
 
         let clockwise_iter = vertices.0[start..end].iter().copied().enumerate();
 
         let mut iter_body = |x_index: usize, x: T, y_index: usize, y: T| {
             lastidx = Some(self.insert_node((start + x_index) / DIM, Coord { x, y }, lastidx));
-            if contour_minx > x {
-                contour_minx = x;
-                leftmost_idx = lastidx
-            };
+            contour_minx = contour_minx.min(x);
             if self.usehash {
                 self.min.y = vertices.0[y_index].min(self.min.y);
                 self.max.x = vertices.0[x_index].max(self.max.x);
@@ -376,7 +628,7 @@ impl<T: Float> LinkedLists<T> {
             }
         };
 
-        if clockwise == (vertices.signed_area(start, end) > T::zero()) {
+        if clockwise == (signed_area(vertices.0[start..end].chunks(DIM)) > T::zero()) {
             for ((x_index, x), (y_index, y)) in clockwise_iter.tuples() {
                 iter_body(x_index, x, y_index, y);
             }
@@ -392,18 +644,49 @@ impl<T: Float> LinkedLists<T> {
             self.remove_node(lastidx.unwrap());
             lastidx = Some(self.nodes[lastidx.unwrap()].next_linked_list_node_index);
         }
-        Ok((lastidx.unwrap(), leftmost_idx.unwrap()))
+
+        // the leftmost node, walking the ring from the last node as
+        // earcut.js does: the first of several as far left, or the lowest
+        // of them after earcut.js 3.0
+        let lastidx = lastidx.unwrap();
+        let lowest = self.options.earcut3_touching;
+        let leftmost_idx = self.iter(lastidx..lastidx).fold(lastidx, |l, p| {
+            let l = &self.nodes[l];
+            match p.coord.x < l.coord.x
+                || (lowest && p.coord.x == l.coord.x && p.coord.y < l.coord.y)
+            {
+                true => p.idx,
+                false => l.idx,
+            }
+        });
+        Ok((lastidx, leftmost_idx))
     }
 
     // check if a diagonal between two polygon nodes is valid (lies in
     // polygon interior)
     fn is_valid_diagonal(&self, a: &LinkedListNode<T>, b: &LinkedListNode<T>) -> bool {
-        next!(self, a.idx).vertices_index != b.vertices_index
-            && prev!(self, a.idx).vertices_index != b.vertices_index
+        let zero = T::zero();
+        let (ap, an) = (*prevref!(self, a.idx), *nextref!(self, a.idx));
+        let (bp, bn) = (*prevref!(self, b.idx), *nextref!(self, b.idx));
+        // locally visible, and not making sectors facing opposite ways
+        let visible = || {
+            locally_inside(self, a, b)
+                && locally_inside(self, b, a)
+                && middle_inside(self, a, b)
+                && (NodeTriangle(ap, *a, bp).area() != zero
+                    || NodeTriangle(*a, bp, *b).area() != zero)
+        };
+        // the special case of a zero length diagonal between two reflex
+        // vertices in the same place
+        let zero_length = || {
+            a.xy_eq(*b)
+                && NodeTriangle(ap, *a, an).area() > zero
+                && NodeTriangle(bp, *b, bn).area() > zero
+        };
+        an.vertices_index != b.vertices_index
+            && ap.vertices_index != b.vertices_index
             && !intersects_polygon(self, *a, *b)
-            && locally_inside(self, a, b)
-            && locally_inside(self, b, a)
-            && middle_inside(self, a, b)
+            && (visible() || zero_length())
     }
 }
 
@@ -419,7 +702,7 @@ impl<'a, T: Float> NodeIterator<'a, T> {
         ll: &LinkedLists<T>,
         start: LinkedListNodeIndex,
         end: LinkedListNodeIndex,
-    ) -> NodeIterator<T> {
+    ) -> NodeIterator<'_, T> {
         NodeIterator {
             pending_result: Some(&ll.nodes[start]),
             cur: start,
@@ -456,7 +739,7 @@ impl<'a, T: Float> NodePairIterator<'a, T> {
         ll: &LinkedLists<T>,
         start: LinkedListNodeIndex,
         end: LinkedListNodeIndex,
-    ) -> NodePairIterator<T> {
+    ) -> NodePairIterator<'_, T> {
         NodePairIterator {
             pending_result: Some((&ll.nodes[start], nextref!(ll, start))),
             cur: start,
@@ -511,29 +794,52 @@ fn eliminate_holes<T: Float>(
         queue.push(ll.nodes[leftmost_idx]);
     }
 
-    queue.sort_by(|a, b| {
-        a.coord
-            .x
-            .partial_cmp(&b.coord.x)
-            .unwrap_or(cmp::Ordering::Equal)
+    if ll.options.hole_order == HoleOrder::LeftToRight {
+        // when the leftmost points of two holes meet at a vertex, sort the
+        // holes counterclockwise so that the bridge to the outer ring is
+        // always found at the point where they meet
+        let slope = |p: &LinkedListNode<T>| {
+            let n = nextref!(ll, p.idx);
+            (n.coord.y - p.coord.y) / (n.coord.x - p.coord.x)
+        };
+        queue.sort_by(|a, b| {
+            (a.coord.x, a.coord.y)
+                .partial_cmp(&(b.coord.x, b.coord.y))
+                .filter(|order| order.is_ne())
+                .or_else(|| slope(a).partial_cmp(&slope(b)))
+                .unwrap_or(cmp::Ordering::Equal)
+        });
+    }
+
+    let mut grid = (queue.len() >= ll.options.hole_grid_threshold).then(|| {
+        let mut grid = HoleGrid::new(ll);
+        for p in ll.iter(outer_node..outer_node) {
+            grid.insert(ll, p.idx);
+        }
+        grid
     });
 
-    // process holes from left to right
-    for node in queue {
-        ll.eliminate_hole(node.idx, outer_node);
-        let nextidx = next!(ll, outer_node).idx;
-        outer_node = filter_points(ll, outer_node, Some(nextidx));
+    // process holes from left to right, or as ordered
+    let queue = queue.iter().map(|node| node.idx).collect::<Vec<_>>();
+    for (i, &hole) in queue.iter().enumerate() {
+        outer_node = ll.eliminate_hole(hole, outer_node, grid.as_mut(), &queue[i + 1..]);
     }
     Ok(outer_node)
 } // elim holes
 
 // minx, miny and invsize are later used to transform coords
-// into integers for z-order calculation
-fn calc_invsize<T: Float>(min: Coord<T>, max: Coord<T>) -> T {
+// into integers for z-order calculation. a wide grid has 2^32 cells a
+// side instead of 2^15, for polygons with so many vertices that they
+// would crowd into the same cells.
+fn calc_invsize<T: Float>(min: Coord<T>, max: Coord<T>, wide: bool) -> T {
     let invsize = (max.x - min.x).max(max.y - min.y);
+    let cells: f64 = match wide {
+        true => u32::MAX.into(),
+        false => 32767.0,
+    };
     match invsize.is_zero() {
         true => T::zero(),
-        false => num_traits::cast::<f64, T>(32767.0).unwrap() / invsize,
+        false => num_traits::cast::<f64, T>(cells).unwrap() / invsize,
     }
 }
 
@@ -576,13 +882,14 @@ fn earcut_linked_hashed<const PASS: usize, T: Float>(
     };
     // if we looped through the whole remaining polygon and can't
     // find any more ears
-    if PASS == 0 {
+    if PASS == 0 && ll.options.repair >= Repair::FilterPoints {
         let tmp = filter_points(ll, next_idx, None);
         earcut_linked_hashed::<1, T>(ll, tmp, triangle_indices)?;
-    } else if PASS == 1 {
-        ear_idx = cure_local_intersections(ll, next_idx, triangle_indices);
+    } else if PASS == 1 && ll.options.repair >= Repair::CureIntersections {
+        let tmp = filter_points(ll, next_idx, None);
+        ear_idx = cure_local_intersections(ll, tmp, triangle_indices);
         earcut_linked_hashed::<2, T>(ll, ear_idx, triangle_indices)?;
-    } else if PASS == 2 {
+    } else if PASS == 2 && ll.options.repair >= Repair::SplitPolygon {
         split_earcut(ll, next_idx, triangle_indices)?;
     }
     Ok(())
@@ -622,13 +929,14 @@ fn earcut_linked_unhashed<const PASS: usize, T: Float>(
     };
     // if we looped through the whole remaining polygon and can't
     // find any more ears
-    if PASS == 0 {
+    if PASS == 0 && ll.options.repair >= Repair::FilterPoints {
         let tmp = filter_points(ll, next_idx, None);
         earcut_linked_unhashed::<1, T>(ll, tmp, triangles)?;
-    } else if PASS == 1 {
-        ear_idx = cure_local_intersections(ll, next_idx, triangles);
+    } else if PASS == 1 && ll.options.repair >= Repair::CureIntersections {
+        let tmp = filter_points(ll, next_idx, None);
+        ear_idx = cure_local_intersections(ll, tmp, triangles);
         earcut_linked_unhashed::<2, T>(ll, ear_idx, triangles)?;
-    } else if PASS == 2 {
+    } else if PASS == 2 && ll.options.repair >= Repair::SplitPolygon {
         split_earcut(ll, next_idx, triangles)?;
     }
     Ok(())
@@ -665,12 +973,15 @@ impl NodeIndexTriangle {
     // check whether a polygon node forms a valid ear with adjacent nodes
     fn is_ear<T: Float>(self, ll: &LinkedLists<T>) -> bool {
         let zero = T::zero();
+        let triangle = self.node_triangle(ll);
+        let bbox = triangle.bbox();
         match self.area(ll) >= zero {
             true => false, // reflex, cant be ear
             false => !ll
                 .iter(self.next_node(ll).next_linked_list_node_index..self.prev_node(ll).idx)
                 .any(|p| {
-                    self.node_triangle(ll).contains_point(*p)
+                    in_bbox(&bbox, p.coord)
+                        && triangle.blocks_ear(*p, ll.options.earcut3_touching)
                         && (NodeTriangle(*prevref!(ll, p.idx), *p, *nextref!(ll, p.idx)).area()
                             >= zero)
                 }),
@@ -714,38 +1025,49 @@ impl<T: Float> NodeTriangle<T> {
                 >= zero)
     }
 
+    // whether p keeps the triangle from being an ear: it lies within,
+    // and, where touching points are treated as in earcut.js 3.0, is not
+    // where the first point is
+    fn blocks_ear(&self, p: LinkedListNode<T>, earcut3_touching: bool) -> bool {
+        !(earcut3_touching && p.xy_eq(self.0)) && self.contains_point(p)
+    }
+
+    // the lower left and upper right corners of the bounding box
+    fn bbox(&self) -> (Coord<T>, Coord<T>) {
+        let NodeTriangle(a, b, c) = self;
+        let min = Coord {
+            x: a.coord.x.min(b.coord.x.min(c.coord.x)),
+            y: a.coord.y.min(b.coord.y.min(c.coord.y)),
+        };
+        let max = Coord {
+            x: a.coord.x.max(b.coord.x.max(c.coord.x)),
+            y: a.coord.y.max(b.coord.y.max(c.coord.y)),
+        };
+        (min, max)
+    }
+
     #[inline(always)]
-    fn is_ear_hashed(&self, ll: &mut LinkedLists<T>) -> Result<bool, Error> {
+    fn is_ear_hashed(&self, ll: &LinkedLists<T>) -> Result<bool, Error> {
         let zero = T::zero();
 
         if self.area() >= zero {
             return Ok(false);
         };
-        let NodeTriangle(prev, ear, next) = self;
+        let ear = &self.1;
 
-        let bbox_maxx = prev.coord.x.max(ear.coord.x.max(next.coord.x));
-        let bbox_maxy = prev.coord.y.max(ear.coord.y.max(next.coord.y));
-        let bbox_minx = prev.coord.x.min(ear.coord.x.min(next.coord.x));
-        let bbox_miny = prev.coord.y.min(ear.coord.y.min(next.coord.y));
+        let bbox = self.bbox();
+        let touching = ll.options.earcut3_touching;
         // z-order range for the current triangle bbox;
-        let min_z = Coord {
-            x: bbox_minx,
-            y: bbox_miny,
-        }
-        .zorder(ll.invsize)?;
-        let max_z = Coord {
-            x: bbox_maxx,
-            y: bbox_maxy,
-        }
-        .zorder(ll.invsize)?;
+        let min_z = ll.zorder(bbox.0)?;
+        let max_z = ll.zorder(bbox.1)?;
 
         let mut p = ear.prevz_idx;
         let mut n = ear.nextz_idx;
         while (p != NULL) && (ll.nodes[p].z >= min_z) && (n != NULL) && (ll.nodes[n].z <= max_z) {
             if earcheck(
-                prev,
-                ear,
-                next,
+                self,
+                &bbox,
+                touching,
                 prevref!(ll, p),
                 &ll.nodes[p],
                 nextref!(ll, p),
@@ -755,9 +1077,9 @@ impl<T: Float> NodeTriangle<T> {
             p = ll.nodes[p].prevz_idx;
 
             if earcheck(
-                prev,
-                ear,
-                next,
+                self,
+                &bbox,
+                touching,
                 prevref!(ll, n),
                 &ll.nodes[n],
                 nextref!(ll, n),
@@ -767,12 +1089,11 @@ impl<T: Float> NodeTriangle<T> {
             n = ll.nodes[n].nextz_idx;
         }
 
-        ll.nodes[NULL].z = min_z - 1;
-        while ll.nodes[p].z >= min_z {
+        while p != NULL && ll.nodes[p].z >= min_z {
             if earcheck(
-                prev,
-                ear,
-                next,
+                self,
+                &bbox,
+                touching,
                 prevref!(ll, p),
                 &ll.nodes[p],
                 nextref!(ll, p),
@@ -782,12 +1103,11 @@ impl<T: Float> NodeTriangle<T> {
             p = ll.nodes[p].prevz_idx;
         }
 
-        ll.nodes[NULL].z = max_z + 1;
-        while ll.nodes[n].z <= max_z {
+        while n != NULL && ll.nodes[n].z <= max_z {
             if earcheck(
-                prev,
-                ear,
-                next,
+                self,
+                &bbox,
+                touching,
                 prevref!(ll, n),
                 &ll.nodes[n],
                 nextref!(ll, n),
@@ -804,21 +1124,28 @@ impl<T: Float> NodeTriangle<T> {
 // helper for is_ear_hashed. needs manual inline (rust 2018)
 #[inline(always)]
 fn earcheck<T: Float>(
-    a: &LinkedListNode<T>,
-    b: &LinkedListNode<T>,
-    c: &LinkedListNode<T>,
+    ear: &NodeTriangle<T>,
+    bbox: &(Coord<T>, Coord<T>),
+    earcut3_touching: bool,
     prev: &LinkedListNode<T>,
     p: &LinkedListNode<T>,
     next: &LinkedListNode<T>,
 ) -> bool {
     let zero = T::zero();
 
-    (p.idx != a.idx)
-        && (p.idx != c.idx)
-        && NodeTriangle(*a, *b, *c).contains_point(*p)
+    (p.idx != ear.0.idx)
+        && (p.idx != ear.2.idx)
+        && in_bbox(bbox, p.coord)
+        && ear.blocks_ear(*p, earcut3_touching)
         && NodeTriangle(*prev, *p, *next).area() >= zero
 }
 
+// whether p lies in the bounding box, edges included
+#[inline(always)]
+fn in_bbox<T: Float>((min, max): &(Coord<T>, Coord<T>), p: Coord<T>) -> bool {
+    p.x >= min.x && p.x <= max.x && p.y >= min.y && p.y <= max.y
+}
+
 fn filter_points<T: Float>(
     ll: &mut LinkedLists<T>,
     start: LinkedListNodeIndex,
@@ -864,6 +1191,7 @@ fn filter_points<T: Float>(
 
 // create a circular doubly linked list from polygon points in the
 // specified winding order
+#[cfg(test)]
 fn linked_list<T: Float>(
     vertices: &Vertices<T>,
     start: usize,
@@ -871,11 +1199,23 @@ fn linked_list<T: Float>(
     clockwise: bool,
 ) -> Result<(LinkedLists<T>, LinkedListNodeIndex), Error> {
     let mut ll: LinkedLists<T> = LinkedLists::new(vertices.len() / DIM);
-    if vertices.len() < 80 {
+    let last_idx = linked_list_into(&mut ll, vertices, start, end, clockwise)?;
+    Ok((ll, last_idx))
+}
+
+// same as linked_list, building into existing (empty) linked lists
+fn linked_list_into<T: Float>(
+    ll: &mut LinkedLists<T>,
+    vertices: &Vertices<T>,
+    start: usize,
+    end: usize,
+    clockwise: bool,
+) -> Result<LinkedListNodeIndex, Error> {
+    if vertices.len() / DIM < ll.options.hash_threshold {
         ll.usehash = false
     };
     let (last_idx, _) = ll.add_contour(vertices, start, end, clockwise)?;
-    Ok((ll, last_idx))
+    Ok(last_idx)
 }
 
 struct VerticesIndexTriangle(usize, usize, usize);
@@ -896,8 +1236,75 @@ pub fn earcut<T: Float>(
     hole_indices: &[VerticesIndex],
     dims: usize,
 ) -> Result<Vec<usize>, Error> {
+    earcut_with_options(vertices, hole_indices, dims, &EarcutOptions::default())
+}
+
+/// Same as `earcut`, tuned by `options`.
+pub fn earcut_with_options<T: Float>(
+    vertices: &[T],
+    hole_indices: &[VerticesIndex],
+    dims: usize,
+    options: &EarcutOptions,
+) -> Result<Vec<usize>, Error> {
+    let (triangles, _) = earcut_with_bridges(vertices, hole_indices, dims, options)?;
+    Ok(triangles)
+}
+
+/// Same as `earcut_with_options`, also returning the bridges that were cut
+/// to link each hole into the outer ring; e.g. for `svg::to_svg`.
+pub fn earcut_with_bridges<T: Float>(
+    vertices: &[T],
+    hole_indices: &[VerticesIndex],
+    dims: usize,
+    options: &EarcutOptions,
+) -> Result<(Vec<usize>, Vec<Bridge>), Error> {
+    let mut ll = LinkedLists::new(0);
+    ll.options = *options;
+    let triangles = triangulate_with(&mut ll, vertices, hole_indices, dims)?;
+    Ok((triangles, ll.bridges))
+}
+
+// earcut using the given linked lists, so that their allocations can be
+// reused from one polygon to the next. the bridges are left in ll.bridges.
+pub(crate) fn triangulate_with<T: Float>(
+    ll: &mut LinkedLists<T>,
+    vertices: &[T],
+    hole_indices: &[VerticesIndex],
+    dims: usize,
+) -> Result<Vec<usize>, Error> {
+    let mut triangles = FinalTriangleIndices(Vec::with_capacity(vertices.len() / DIM));
+    if let Some(outer_node) = linked_polygon(ll, vertices, hole_indices, dims)? {
+        earcut_linked(ll, outer_node, &mut triangles)?;
+    }
+    Ok(triangles.0)
+}
+
+// reset ll to hold the polygon as one ring, with the holes bridged into
+// it. returns a node of that ring, or None if there is nothing to
+// triangulate.
+fn linked_polygon<T: Float>(
+    ll: &mut LinkedLists<T>,
+    vertices: &[T],
+    hole_indices: &[VerticesIndex],
+    dims: usize,
+) -> Result<Option<LinkedListNodeIndex>, Error> {
+    ll.reset(vertices.len() / DIM);
     if vertices.is_empty() && hole_indices.is_empty() {
-        return Ok(vec![]);
+        return Ok(None);
+    }
+
+    // extra coordinates (z, m, ...) play no part in the triangulation; drop
+    // them and keep x and y. triangle indices refer to vertices, so they
+    // are the same either way.
+    if dims != DIM {
+        if dims < DIM || vertices.len() % dims != 0 {
+            return Err(Error::Unknown);
+        }
+        let xy = vertices
+            .chunks(dims)
+            .flat_map(|vertex| [vertex[0], vertex[1]])
+            .collect::<Vec<T>>();
+        return linked_polygon(ll, &xy, hole_indices, DIM);
     }
 
     if vertices.len() % 2 == 1 || dims > vertices.len() {
@@ -919,33 +1326,48 @@ pub fn earcut<T: Float>(
     };
 
     let vertices = Vertices(vertices);
-    let (mut ll, outer_node) = linked_list(&vertices, 0, outer_len, true)?;
-    let mut triangles = FinalTriangleIndices(Vec::with_capacity(vertices.len() / DIM));
-    if ll.nodes.len() == 1 || DIM != dims {
-        return Ok(triangles.0);
+    let outer_node = linked_list_into(ll, &vertices, 0, outer_len, true)?;
+    if ll.nodes.len() == 1 || next!(ll, outer_node).idx == prev!(ll, outer_node).idx {
+        return Ok(None);
     }
 
-    let outer_node = eliminate_holes(&mut ll, &vertices, hole_indices, outer_node)?;
+    eliminate_holes(ll, &vertices, hole_indices, outer_node).map(Some)
+}
 
+// triangulate the ring starting at outer_node, z-order hashed if ll uses
+// hashing
+fn earcut_linked<T: Float>(
+    ll: &mut LinkedLists<T>,
+    outer_node: LinkedListNodeIndex,
+    triangles: &mut FinalTriangleIndices,
+) -> Result<(), Error> {
     if ll.usehash {
-        ll.invsize = calc_invsize(ll.min, ll.max);
-
-        // translate all points so min is 0,0. prevents subtraction inside
-        // zorder. also note invsize does not depend on translation in space
-        // if one were translating in a space with an even spaced grid of points.
-        // floating point space is not evenly spaced, but it is close enough for
-        // this hash algorithm
-        let (mx, my) = (ll.min.x, ll.min.y);
-        ll.nodes.iter_mut().for_each(|n| {
-            n.coord.x = n.coord.x - mx;
-            n.coord.y = n.coord.y - my;
-        });
-        earcut_linked_hashed::<0, T>(&mut ll, outer_node, &mut triangles)?;
+        let wide = ll.nodes.len() > ll.options.wide_zorder_threshold;
+        ll.invsize = calc_invsize(ll.min, ll.max, wide);
+        if ll.options.translate {
+            // translate all points so min is 0,0. invsize does not depend
+            // on translation in space if one were translating in a space
+            // with an even spaced grid of points. floating point space is
+            // not evenly spaced, but it is close enough for this hash
+            // algorithm
+            let (mx, my) = (ll.min.x, ll.min.y);
+            ll.nodes.iter_mut().for_each(|n| {
+                n.coord.x = n.coord.x - mx;
+                n.coord.y = n.coord.y - my;
+            });
+            ll.max = Coord {
+                x: ll.max.x - mx,
+                y: ll.max.y - my,
+            };
+            ll.min = Coord {
+                x: T::zero(),
+                y: T::zero(),
+            };
+        }
+        earcut_linked_hashed::<0, T>(ll, outer_node, triangles)
     } else {
-        earcut_linked_unhashed::<0, T>(&mut ll, outer_node, &mut triangles)?;
+        earcut_linked_unhashed::<0, T>(ll, outer_node, triangles)
     }
-
-    Ok(triangles.0)
 }
 
 /* go through all polygon nodes and cure small local self-intersections
@@ -980,12 +1402,16 @@ fn cure_local_intersections<T: Float>(
     //
     //                            a p  pn b
 
+    let crosses = match ll.options.earcut3_touching {
+        true => intersects,
+        false => pseudo_intersects,
+    };
     loop {
         let a = ll.nodes[p].prev_linked_list_node_index;
         let b = next!(ll, p).next_linked_list_node_index;
 
         if !ll.nodes[a].xy_eq(ll.nodes[b])
-            && pseudo_intersects(
+            && crosses(
                 ll.nodes[a],
                 ll.nodes[p],
                 *nextref!(ll, p),
@@ -1015,7 +1441,7 @@ fn cure_local_intersections<T: Float>(
         }
     }
 
-    p
+    filter_points(ll, p, None)
 }
 
 // try splitting polygon into two and triangulate them independently
@@ -1062,7 +1488,6 @@ fn find_hole_bridge<T: Float>(
     hole: LinkedListNodeIndex,
     outer_node: LinkedListNodeIndex,
 ) -> LinkedListNodeIndex {
-    let mut p = outer_node;
     let hx = ll.nodes[hole].coord.x;
     let hy = ll.nodes[hole].coord.y;
     let mut qx = T::neg_infinity();
@@ -1070,26 +1495,31 @@ fn find_hole_bridge<T: Float>(
 
     // find a segment intersected by a ray from the hole's leftmost
     // point to the left; segment's endpoint with lesser x will be
-    // potential connection point
+    // potential connection point, unless they intersect at a vertex,
+    // then choose the vertex
     let calcx = |p: &LinkedListNode<T>| {
         p.coord.x
             + (hy - p.coord.y) * (next!(ll, p.idx).coord.x - p.coord.x)
                 / (next!(ll, p.idx).coord.y - p.coord.y)
     };
-    for (p, n) in ll
-        .iter_pairs(p..outer_node)
-        .filter(|(p, n)| hy <= p.coord.y && hy >= n.coord.y)
-        .filter(|(p, n)| n.coord.y != p.coord.y)
-        .filter(|(p, _)| calcx(p) <= hx)
-    {
-        if qx < calcx(p) {
-            qx = calcx(p);
-            if qx == hx && hy == p.coord.y {
-                return p.idx;
-            } else if qx == hx && hy == n.coord.y {
-                return p.next_linked_list_node_index;
+    if ll.nodes[hole].xy_eq(ll.nodes[outer_node]) {
+        return outer_node;
+    }
+    for (p, n) in ll.iter_pairs(outer_node..outer_node) {
+        if ll.nodes[hole].xy_eq(*n) {
+            return n.idx;
+        }
+        if hy <= p.coord.y && hy >= n.coord.y && n.coord.y != p.coord.y {
+            let x = calcx(p);
+            if x <= hx && x > qx {
+                qx = x;
+                let leftmost = if p.coord.x < n.coord.x { p.idx } else { n.idx };
+                if x == hx {
+                    // hole touches outer segment; pick leftmost endpoint
+                    return leftmost;
+                }
+                m = Some(leftmost);
             }
-            m = Some(if p.coord.x < n.coord.x { p.idx } else { n.idx });
         }
     }
 
@@ -1098,34 +1528,25 @@ fn find_hole_bridge<T: Float>(
         None => return NULL,
     };
 
-    // hole touches outer segment; pick lower endpoint
-    if hx == qx {
-        return prev!(ll, m).idx;
-    }
-
     // look for points inside the triangle of hole point, segment
     // intersection and endpoint; if there are no points found, we have
     // a valid connection; otherwise choose the point of the minimum
     // angle with the ray as connection point
 
-    let mp = LinkedListNode::new(0, ll.nodes[m].coord, 0);
-    p = next!(ll, m).idx;
+    let mp = ll.nodes[m];
     let x1 = if hy < mp.coord.y { hx } else { qx };
     let x2 = if hy < mp.coord.y { qx } else { hx };
     let n1 = LinkedListNode::new(0, Coord { x: x1, y: hy }, 0);
     let n2 = LinkedListNode::new(0, Coord { x: x2, y: hy }, 0);
-    let two = num_traits::cast::<f64, T>(2.).unwrap();
 
     let calctan = |p: &LinkedListNode<T>| (hy - p.coord.y).abs() / (hx - p.coord.x); // tangential
-    ll.iter(p..m)
+    ll.iter(m..m)
         .filter(|p| hx > p.coord.x && p.coord.x >= mp.coord.x)
         .filter(|p| NodeTriangle(n1, mp, n2).contains_point(**p))
-        .fold((m, T::max_value() / two), |(m, tan_min), p| {
-            if ((calctan(p) < tan_min)
-                || (calctan(p) == tan_min && p.coord.x > ll.nodes[m].coord.x))
-                && locally_inside(ll, p, &ll.nodes[hole])
-            {
-                (p.idx, calctan(p))
+        .fold((m, T::infinity()), |(m, tan_min), p| {
+            let tan = calctan(p);
+            if locally_inside(ll, p, &ll.nodes[hole]) && better_bridge(ll, m, tan_min, p, tan) {
+                (p.idx, tan)
             } else {
                 (m, tan_min)
             }
@@ -1133,6 +1554,345 @@ fn find_hole_bridge<T: Float>(
         .0
 }
 
+// whether p, at tangent tan to the ray, makes a better bridge than m at
+// tan_min: the lowest tangent wins, then the greatest x, then, of points
+// in the same place, the one whose sector lies inside that of the other
+fn better_bridge<T: Float>(
+    ll: &LinkedLists<T>,
+    m: LinkedListNodeIndex,
+    tan_min: T,
+    p: &LinkedListNode<T>,
+    tan: T,
+) -> bool {
+    let m = &ll.nodes[m];
+    tan < tan_min
+        || (tan == tan_min
+            && (p.coord.x > m.coord.x
+                || (p.coord.x == m.coord.x && sector_contains_sector(ll, m, p))))
+}
+
+// whether the sector at vertex m contains the sector at vertex p, which is
+// in the same place
+fn sector_contains_sector<T: Float>(
+    ll: &LinkedLists<T>,
+    m: &LinkedListNode<T>,
+    p: &LinkedListNode<T>,
+) -> bool {
+    let zero = T::zero();
+    NodeTriangle(*prevref!(ll, m.idx), *m, *prevref!(ll, p.idx)).area() < zero
+        && NodeTriangle(*nextref!(ll, p.idx), *m, *nextref!(ll, m.idx)).area() < zero
+}
+
+// the shortest bridge from a vertex of the hole to one of the outer ring,
+// as (outer ring node, hole node), that crosses neither ring nor any of
+// the pending holes and does not run through a vertex of any of them.
+// only the closest pairs are tried, and vertices the hole shares with the
+// outer ring are no use as bridges.
+fn find_nearest_bridge<T: Float>(
+    ll: &LinkedLists<T>,
+    hole: LinkedListNodeIndex,
+    outer_node: LinkedListNodeIndex,
+    pending: &[LinkedListNodeIndex],
+) -> Option<(LinkedListNodeIndex, LinkedListNodeIndex)> {
+    let distance = |p: &LinkedListNode<T>, h: &LinkedListNode<T>| {
+        (p.coord.x - h.coord.x).powi(2) + (p.coord.y - h.coord.y).powi(2)
+    };
+    let mut pairs = ll
+        .iter(hole..hole)
+        .flat_map(|h| {
+            ll.iter(outer_node..outer_node)
+                .map(move |p| (distance(p, h), p, h))
+        })
+        .filter(|(d, _, _)| !d.is_zero())
+        .enumerate()
+        .collect::<Vec<_>>();
+    // by distance, then in ring order
+    let order = |a: &(usize, (T, _, _)), b: &(usize, (T, _, _))| {
+        (a.1 .0, a.0)
+            .partial_cmp(&(b.1 .0, b.0))
+            .unwrap_or(cmp::Ordering::Equal)
+    };
+
+    let rings = || {
+        [outer_node, hole]
+            .into_iter()
+            .chain(pending.iter().copied())
+    };
+    let is_bridge = |p: &LinkedListNode<T>, h: &LinkedListNode<T>| {
+        locally_inside(ll, p, h)
+            && locally_inside(ll, h, p)
+            && rings()
+                .all(|ring| !intersects_ring(ll, ring, *p, *h) && !passes_vertex(ll, ring, p, h))
+    };
+
+    let nearest = pairs.len().min(NEAREST_BRIDGE_CANDIDATES);
+    if nearest < pairs.len() {
+        pairs.select_nth_unstable_by(nearest, order);
+    }
+    pairs.truncate(nearest);
+    pairs.sort_by(order);
+    pairs
+        .into_iter()
+        .map(|(_, pair)| pair)
+        .find(|(_, p, h)| is_bridge(p, h))
+        .map(|(_, p, h)| (p.idx, h.idx))
+}
+
+// a uniform grid over the polygon, so that find_hole_bridge only needs to
+// look at the part of the outer ring near the hole. every node of the
+// outer ring is listed in the cells overlapped by its segment to the next
+// node at the time it joined the ring, and never taken out again: a node
+// since dropped by filter_points is told apart by its old previous node
+// no longer linking to it, and stands in for the segment that swallowed
+// its own.
+struct HoleGrid<T: Float> {
+    min: Coord<T>,
+    inv_cell_size: T,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<LinkedListNodeIndex>>,
+}
+
+impl<T: Float> HoleGrid<T> {
+    // about one cell per node, covering all rings
+    fn new(ll: &LinkedLists<T>) -> HoleGrid<T> {
+        let (mut min, mut max) = (ll.nodes[1].coord, ll.nodes[1].coord);
+        for n in &ll.nodes[1..] {
+            min.x = min.x.min(n.coord.x);
+            min.y = min.y.min(n.coord.y);
+            max.x = max.x.max(n.coord.x);
+            max.y = max.y.max(n.coord.y);
+        }
+        let (width, height) = (max.x - min.x, max.y - min.y);
+        let count = num_traits::cast::<usize, T>(ll.nodes.len()).unwrap();
+        let cell_size = (width * height / count)
+            .sqrt()
+            .max(width.max(height) / count);
+        let inv_cell_size = match cell_size > T::zero() {
+            true => T::one() / cell_size,
+            false => T::zero(),
+        };
+        let cells_along =
+            |length: T| num_traits::cast::<T, usize>(length * inv_cell_size).unwrap_or(0) + 1;
+        let (cols, rows) = (cells_along(width), cells_along(height));
+        HoleGrid {
+            min,
+            inv_cell_size,
+            cols,
+            rows,
+            cells: vec![Vec::new(); cols * rows],
+        }
+    }
+
+    fn col(&self, x: T) -> usize {
+        let col = num_traits::cast::<T, usize>((x - self.min.x) * self.inv_cell_size);
+        col.unwrap_or(0).min(self.cols - 1)
+    }
+
+    fn row(&self, y: T) -> usize {
+        let row = num_traits::cast::<T, usize>((y - self.min.y) * self.inv_cell_size);
+        row.unwrap_or(0).min(self.rows - 1)
+    }
+
+    fn cell(&self, col: usize, row: usize) -> &[LinkedListNodeIndex] {
+        &self.cells[row * self.cols + col]
+    }
+
+    // list node p in the cells overlapped by its segment to the next node
+    fn insert(&mut self, ll: &LinkedLists<T>, p: LinkedListNodeIndex) {
+        let (a, b) = (ll.nodes[p].coord, next!(ll, p).coord);
+        for row in self.row(a.y.min(b.y))..=self.row(a.y.max(b.y)) {
+            for col in self.col(a.x.min(b.x))..=self.col(a.x.max(b.x)) {
+                self.cells[row * self.cols + col].push(p);
+            }
+        }
+    }
+
+    // whether p is still part of its ring
+    fn is_linked(ll: &LinkedLists<T>, p: LinkedListNodeIndex) -> bool {
+        prev!(ll, p).next_linked_list_node_index == p
+    }
+
+    // the node whose segment covers that of p, which is p itself unless p
+    // was dropped from the ring
+    fn linked(ll: &LinkedLists<T>, mut p: LinkedListNodeIndex) -> LinkedListNodeIndex {
+        while !HoleGrid::is_linked(ll, p) {
+            p = ll.nodes[p].prev_linked_list_node_index;
+        }
+        p
+    }
+
+    // the first of nodes met walking the ring from start
+    fn first_from(
+        ll: &LinkedLists<T>,
+        start: LinkedListNodeIndex,
+        nodes: &[LinkedListNodeIndex],
+    ) -> LinkedListNodeIndex {
+        match nodes {
+            [p] => *p,
+            _ => ll
+                .iter(start..start)
+                .find(|p| nodes.contains(&p.idx))
+                .map_or(nodes[0], |p| p.idx),
+        }
+    }
+
+    // nodes in the order they are met walking the ring from start
+    fn ring_order(
+        ll: &LinkedLists<T>,
+        start: LinkedListNodeIndex,
+        nodes: &[LinkedListNodeIndex],
+    ) -> Vec<LinkedListNodeIndex> {
+        match nodes {
+            [_] => nodes.to_vec(),
+            _ => ll
+                .iter(start..start)
+                .map(|p| p.idx)
+                .filter(|p| nodes.contains(p))
+                .collect(),
+        }
+    }
+
+    // same as find_hole_bridge, looking only at the cells along the ray
+    // and those of the triangle searched for a better connection. where
+    // segments or points tie, the one find_hole_bridge would come across
+    // first wins.
+    fn find_hole_bridge(
+        &self,
+        ll: &LinkedLists<T>,
+        hole: LinkedListNodeIndex,
+        outer_node: LinkedListNodeIndex,
+    ) -> LinkedListNodeIndex {
+        let hx = ll.nodes[hole].coord.x;
+        let hy = ll.nodes[hole].coord.y;
+        let calcx = |p: &LinkedListNode<T>| {
+            p.coord.x
+                + (hy - p.coord.y) * (next!(ll, p.idx).coord.x - p.coord.x)
+                    / (next!(ll, p.idx).coord.y - p.coord.y)
+        };
+
+        // walk the cells along the ray to the left, one cell either side
+        // to allow for rounding, until past the nearest segment it hits
+        let row = self.row(hy);
+        let rows = row.saturating_sub(1)..=(row + 1).min(self.rows - 1);
+        let mut qx = T::neg_infinity();
+        let mut hits: Vec<LinkedListNodeIndex> = Vec::new();
+        for col in (0..=(self.col(hx) + 1).min(self.cols - 1)).rev() {
+            if !hits.is_empty() && col + 1 < self.col(qx) {
+                break;
+            }
+            for row in rows.clone() {
+                for &p in self.cell(col, row) {
+                    let p = &ll.nodes[HoleGrid::linked(ll, p)];
+                    let n = nextref!(ll, p.idx);
+                    if hy <= p.coord.y
+                        && hy >= n.coord.y
+                        && n.coord.y != p.coord.y
+                        && calcx(p) <= hx
+                    {
+                        let x = calcx(p);
+                        if qx < x {
+                            qx = x;
+                            hits.clear();
+                        }
+                        if qx == x && !hits.contains(&p.idx) {
+                            hits.push(p.idx);
+                        }
+                    }
+                }
+            }
+        }
+        let leftmost = |p: LinkedListNodeIndex| {
+            let n = next!(ll, p);
+            if ll.nodes[p].coord.x < n.coord.x {
+                p
+            } else {
+                n.idx
+            }
+        };
+
+        // the vertices in the same place as the hole, and the segments
+        // running through it: find_hole_bridge takes the first of those it
+        // comes across, checking each vertex before the segment leading
+        // to it
+        let mut touching: Vec<LinkedListNodeIndex> = Vec::new();
+        for &p in self.cell(self.col(hx), self.row(hy)) {
+            if HoleGrid::is_linked(ll, p)
+                && ll.nodes[p].xy_eq(ll.nodes[hole])
+                && !touching.contains(&p)
+            {
+                touching.push(p);
+            }
+        }
+        if touching.contains(&outer_node) {
+            return outer_node;
+        }
+        if let ([p], false) = (&touching[..], qx == hx) {
+            return *p;
+        }
+        if !touching.is_empty() || qx == hx {
+            for p in ll.iter(outer_node..outer_node) {
+                if touching.contains(&p.next_linked_list_node_index) {
+                    return p.next_linked_list_node_index;
+                }
+                if qx == hx && hits.contains(&p.idx) {
+                    return leftmost(p.idx);
+                }
+            }
+        }
+        if hits.is_empty() {
+            return NULL;
+        }
+        let m = leftmost(HoleGrid::first_from(ll, outer_node, &hits));
+
+        let mp = ll.nodes[m];
+        let x1 = if hy < mp.coord.y { hx } else { qx };
+        let x2 = if hy < mp.coord.y { qx } else { hx };
+        let n1 = LinkedListNode::new(0, Coord { x: x1, y: hy }, 0);
+        let n2 = LinkedListNode::new(0, Coord { x: x2, y: hy }, 0);
+
+        // the lowest tangent wins, then the greatest x. of the points tied
+        // on both, find_hole_bridge takes the first it comes across walking
+        // from m, then any after it whose sector lies inside that of the
+        // one it has
+        let calctan = |p: &LinkedListNode<T>| (hy - p.coord.y).abs() / (hx - p.coord.x);
+        let (mut tan_min, mut x_max) = (T::infinity(), T::neg_infinity());
+        let mut best: Vec<LinkedListNodeIndex> = Vec::new();
+        let (row_a, row_b) = (self.row(hy), self.row(mp.coord.y));
+        let cols = self.col(mp.coord.x)..=self.col(hx);
+        let candidates = (row_a.min(row_b)..=row_a.max(row_b))
+            .flat_map(|row| cols.clone().flat_map(move |col| self.cell(col, row)))
+            .map(|&p| &ll.nodes[p])
+            .filter(|p| HoleGrid::is_linked(ll, p.idx))
+            .filter(|p| hx > p.coord.x && p.coord.x >= mp.coord.x)
+            .filter(|p| NodeTriangle(n1, mp, n2).contains_point(**p))
+            .filter(|p| locally_inside(ll, p, &ll.nodes[hole]));
+        for p in candidates {
+            let tan = calctan(p);
+            if tan < tan_min || (tan == tan_min && p.coord.x > x_max) {
+                (tan_min, x_max) = (tan, p.coord.x);
+                best.clear();
+                best.push(p.idx);
+            } else if !best.is_empty()
+                && tan == tan_min
+                && p.coord.x == x_max
+                && !best.contains(&p.idx)
+            {
+                best.push(p.idx);
+            }
+        }
+        HoleGrid::ring_order(ll, m, &best)
+            .into_iter()
+            .reduce(
+                |m, p| match sector_contains_sector(ll, &ll.nodes[m], &ll.nodes[p]) {
+                    true => p,
+                    false => m,
+                },
+            )
+            .unwrap_or(m)
+    }
+}
+
 /* check if two segments cross over each other. note this is different
 from pure intersction. only two segments crossing over at some interior
 point is considered intersection.
@@ -1142,16 +1902,11 @@ line segment p1-q1 vs line segment p2-q2.
 note that if they are collinear, or if the end points touch, or if
 one touches the other at one point, it is not considered an intersection.
 
-please note that the other algorithms in this earcut code depend on this
-interpretation of the concept of intersection - if this is modified
-so that endpoint touching qualifies as intersection, then it will have
-a problem with certain inputs.
+this is what earcut.js before 3.0 used for curing local intersections;
+3.0 uses intersects, which counts touching too.
 
 bsed on https://www.geeksforgeeks.org/check-if-two-given-line-segments-intersect/
 
-this has been modified from the version in earcut.js to remove the
-detection for endpoint detection.
-
     a1=area(p1,q1,p2);a2=area(p1,q1,q2);a3=area(p2,q2,p1);a4=area(p2,q2,q1);
     p1 q1    a1 cw   a2 cw   a3 ccw   a4  ccw  a1==a2  a3==a4  fl
     p2 q2
@@ -1178,18 +1933,77 @@ fn pseudo_intersects<T: Float>(
         && (NodeTriangle(p2, q2, p1).area() > zero) != (NodeTriangle(p2, q2, q1).area() > zero)
 }
 
+// check if two segments intersect, which they also do where one ends on
+// the other, or where they overlap
+fn intersects<T: Float>(
+    p1: LinkedListNode<T>,
+    q1: LinkedListNode<T>,
+    p2: LinkedListNode<T>,
+    q2: LinkedListNode<T>,
+) -> bool {
+    let sign = |area: T| area.partial_cmp(&T::zero()).unwrap_or(cmp::Ordering::Equal);
+    let o1 = sign(NodeTriangle(p1, q1, p2).area());
+    let o2 = sign(NodeTriangle(p1, q1, q2).area());
+    let o3 = sign(NodeTriangle(p2, q2, p1).area());
+    let o4 = sign(NodeTriangle(p2, q2, q1).area());
+    let collinear = cmp::Ordering::Equal;
+
+    (o1 != o2 && o3 != o4) // general case
+        || (o1 == collinear && on_segment(p1, p2, q1)) // p2 lies on p1q1
+        || (o2 == collinear && on_segment(p1, q2, q1)) // q2 lies on p1q1
+        || (o3 == collinear && on_segment(p2, p1, q2)) // p1 lies on p2q2
+        || (o4 == collinear && on_segment(p2, q1, q2)) // q1 lies on p2q2
+}
+
+// for collinear points p, q, r, check if point q lies on segment pr
+fn on_segment<T: Float>(p: LinkedListNode<T>, q: LinkedListNode<T>, r: LinkedListNode<T>) -> bool {
+    q.coord.x <= p.coord.x.max(r.coord.x)
+        && q.coord.x >= p.coord.x.min(r.coord.x)
+        && q.coord.y <= p.coord.y.max(r.coord.y)
+        && q.coord.y >= p.coord.y.min(r.coord.y)
+}
+
 // check if a polygon diagonal intersects any polygon segments
 fn intersects_polygon<T: Float>(
     ll: &LinkedLists<T>,
     a: LinkedListNode<T>,
     b: LinkedListNode<T>,
 ) -> bool {
-    ll.iter_pairs(a.idx..a.idx).any(|(p, n)| {
+    intersects_ring(ll, a.idx, a, b)
+}
+
+// check if a-b intersects any segment of the ring at start
+fn intersects_ring<T: Float>(
+    ll: &LinkedLists<T>,
+    start: LinkedListNodeIndex,
+    a: LinkedListNode<T>,
+    b: LinkedListNode<T>,
+) -> bool {
+    ll.iter_pairs(start..start).any(|(p, n)| {
         p.vertices_index != a.vertices_index
             && n.vertices_index != a.vertices_index
             && p.vertices_index != b.vertices_index
             && n.vertices_index != b.vertices_index
-            && pseudo_intersects(*p, *n, a, b)
+            && intersects(*p, *n, a, b)
+    })
+}
+
+// check if a-b runs through a vertex of the ring at start, including those
+// on the segments next to a and b that intersects_ring leaves out
+fn passes_vertex<T: Float>(
+    ll: &LinkedLists<T>,
+    start: LinkedListNodeIndex,
+    a: &LinkedListNode<T>,
+    b: &LinkedListNode<T>,
+) -> bool {
+    ll.iter(start..start).any(|q| {
+        !q.xy_eq(*a)
+            && !q.xy_eq(*b)
+            && NodeTriangle(*a, *q, *b).area().is_zero()
+            && q.coord.x >= a.coord.x.min(b.coord.x)
+            && q.coord.x <= a.coord.x.max(b.coord.x)
+            && q.coord.y >= a.coord.y.min(b.coord.y)
+            && q.coord.y <= a.coord.y.max(b.coord.y)
     })
 }
 
@@ -0,0 +1,190 @@
+//! Triangle meshes in 3D, and writing them out as Wavefront OBJ, PLY and
+//! STL for inspection in other tools.
+
+use std::io::{self, Write};
+
+use crate::{Error, Float};
+
+/// An indexed triangle mesh.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mesh {
+    /// x, y and z of every vertex
+    pub positions: Vec<[f64; 3]>,
+    /// three vertex indices per triangle, counterclockwise seen from the
+    /// side the triangle faces
+    pub triangles: Vec<usize>,
+    /// one normal per triangle once `compute_normals` has run, else empty
+    pub normals: Vec<[f64; 3]>,
+}
+
+impl Mesh {
+    /// Lift an `earcut` triangulation into 3D.
+    ///
+    /// The z coordinate is the third value of each vertex when `dims >= 3`,
+    /// and 0 otherwise. Triangles are wound so that they face +Z.
+    pub fn from_triangulation<T: Float>(
+        vertices: &[T],
+        dims: usize,
+        triangles: &[usize],
+    ) -> Result<Mesh, Error> {
+        if dims < 2 || vertices.len() % dims != 0 || triangles.len() % 3 != 0 {
+            return Err(Error::Unknown);
+        }
+        let positions = vertices
+            .chunks(dims)
+            .map(|v| {
+                let z = match dims {
+                    2 => Some(0.0),
+                    _ => v[2].to_f64(),
+                };
+                Some([v[0].to_f64()?, v[1].to_f64()?, z?])
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(Error::Unknown)?;
+        if triangles.iter().any(|&i| i >= positions.len()) {
+            return Err(Error::Unknown);
+        }
+
+        let mut mesh = Mesh {
+            positions,
+            triangles: triangles.to_vec(),
+            normals: Vec::new(),
+        };
+        for i in (0..mesh.triangles.len()).step_by(3) {
+            let [a, b, c] = mesh.triangle(i / 3).map(|i| mesh.positions[i]);
+            // clockwise in the xy plane means facing -Z
+            if (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]) < 0.0 {
+                mesh.triangles.swap(i + 1, i + 2);
+            }
+        }
+        Ok(mesh)
+    }
+
+    fn triangle(&self, i: usize) -> [usize; 3] {
+        [
+            self.triangles[3 * i],
+            self.triangles[3 * i + 1],
+            self.triangles[3 * i + 2],
+        ]
+    }
+
+    /// Unit normal of triangle `i`, following its winding; zero for
+    /// degenerate triangles.
+    pub fn face_normal(&self, i: usize) -> [f64; 3] {
+        let [a, b, c] = self.triangle(i).map(|i| self.positions[i]);
+        let (u, v) = (sub(b, a), sub(c, a));
+        let n = [
+            u[1] * v[2] - u[2] * v[1],
+            u[2] * v[0] - u[0] * v[2],
+            u[0] * v[1] - u[1] * v[0],
+        ];
+        let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+        match len > 0.0 {
+            true => n.map(|x| x / len),
+            false => [0.0; 3],
+        }
+    }
+
+    /// Fill in `normals` with the normal of every triangle.
+    pub fn compute_normals(&mut self) {
+        self.normals = (0..self.triangles.len() / 3)
+            .map(|i| self.face_normal(i))
+            .collect();
+    }
+
+    /// Write a Wavefront OBJ file, with `vn` lines if normals were computed.
+    pub fn write_obj<W: Write>(&self, mut w: W) -> io::Result<()> {
+        writeln!(w, "# earcutr")?;
+        for [x, y, z] in &self.positions {
+            writeln!(w, "v {} {} {}", x, y, z)?;
+        }
+        for [x, y, z] in &self.normals {
+            writeln!(w, "vn {} {} {}", x, y, z)?;
+        }
+        // obj indices start at 1
+        for (i, t) in self.triangles.chunks(3).enumerate() {
+            match self.normals.is_empty() {
+                true => writeln!(w, "f {} {} {}", t[0] + 1, t[1] + 1, t[2] + 1)?,
+                false => writeln!(
+                    w,
+                    "f {}//{n} {}//{n} {}//{n}",
+                    t[0] + 1,
+                    t[1] + 1,
+                    t[2] + 1,
+                    n = i + 1
+                )?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Write an ASCII PLY file.
+    pub fn write_ply_ascii<W: Write>(&self, mut w: W) -> io::Result<()> {
+        self.write_ply_header(&mut w, "ascii")?;
+        for [x, y, z] in &self.positions {
+            writeln!(w, "{} {} {}", x, y, z)?;
+        }
+        for t in self.triangles.chunks(3) {
+            writeln!(w, "3 {} {} {}", t[0], t[1], t[2])?;
+        }
+        Ok(())
+    }
+
+    /// Write a binary (little endian) PLY file.
+    pub fn write_ply_binary<W: Write>(&self, mut w: W) -> io::Result<()> {
+        self.write_ply_header(&mut w, "binary_little_endian")?;
+        for position in &self.positions {
+            for x in position {
+                w.write_all(&x.to_le_bytes())?;
+            }
+        }
+        for t in self.triangles.chunks(3) {
+            w.write_all(&[3])?;
+            for &i in t {
+                w.write_all(&index_u32(i)?.to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_ply_header<W: Write>(&self, w: &mut W, format: &str) -> io::Result<()> {
+        writeln!(w, "ply")?;
+        writeln!(w, "format {} 1.0", format)?;
+        writeln!(w, "comment earcutr")?;
+        writeln!(w, "element vertex {}", self.positions.len())?;
+        writeln!(w, "property double x")?;
+        writeln!(w, "property double y")?;
+        writeln!(w, "property double z")?;
+        writeln!(w, "element face {}", self.triangles.len() / 3)?;
+        writeln!(w, "property list uchar uint vertex_indices")?;
+        writeln!(w, "end_header")
+    }
+
+    /// Write a binary STL file.
+    pub fn write_stl<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let mut header = [0u8; 80];
+        header[..7].copy_from_slice(b"earcutr");
+        w.write_all(&header)?;
+        w.write_all(&index_u32(self.triangles.len() / 3)?.to_le_bytes())?;
+        for i in 0..self.triangles.len() / 3 {
+            let normal = self.face_normal(i);
+            let corners = self.triangle(i).map(|i| self.positions[i]);
+            for xyz in std::iter::once(&normal).chain(&corners) {
+                for &x in xyz {
+                    w.write_all(&(x as f32).to_le_bytes())?;
+                }
+            }
+            // attribute byte count
+            w.write_all(&[0, 0])?;
+        }
+        Ok(())
+    }
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn index_u32(i: usize) -> io::Result<u32> {
+    u32::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
@@ -0,0 +1,140 @@
+//! Measuring the shape of triangles, to compare triangulations of the same
+//! polygon made with different settings.
+
+use crate::geom::signed_area;
+use crate::{Error, Float};
+
+/// Triangles whose smallest angle is below this many degrees count as
+/// slivers.
+pub const SLIVER_ANGLE: f64 = 5.0;
+
+/// Upper bounds of the buckets of `Metrics::aspect_ratios`; the last
+/// bucket takes everything from 32 up.
+pub const ASPECT_RATIO_BUCKETS: [f64; 6] = [1.5, 2.0, 4.0, 8.0, 16.0, 32.0];
+
+/// Shape and area figures of a triangulation, from `measure`.
+///
+/// Angles are in degrees. Degenerate triangles have no angles or aspect
+/// ratio worth speaking of, so only their number is kept; all other
+/// figures are over the rest.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Metrics {
+    /// number of triangles
+    pub triangles: usize,
+    /// triangles of zero area
+    pub degenerate: usize,
+    /// triangles with an angle under `SLIVER_ANGLE`
+    pub slivers: usize,
+    /// smallest angle of any triangle
+    pub min_angle: f64,
+    /// largest angle of any triangle
+    pub max_angle: f64,
+    /// the smallest angle of each triangle, averaged
+    pub mean_min_angle: f64,
+    /// number of triangles by aspect ratio, bucketed by
+    /// `ASPECT_RATIO_BUCKETS`. the aspect ratio is the longest side over
+    /// the shortest height, scaled so that it is 1 for an equilateral
+    /// triangle.
+    pub aspect_ratios: [usize; ASPECT_RATIO_BUCKETS.len() + 1],
+    /// area of the polygon, holes taken out
+    pub polygon_area: f64,
+    /// sum of the areas of the triangles
+    pub triangles_area: f64,
+}
+
+impl Metrics {
+    /// Relative difference between the area of the triangles and of the
+    /// polygon, the same as `deviation` gives.
+    pub fn deviation(&self) -> f64 {
+        match self.polygon_area == 0.0 && self.triangles_area == 0.0 {
+            true => 0.0,
+            false => ((self.triangles_area - self.polygon_area) / self.polygon_area).abs(),
+        }
+    }
+}
+
+/// Measure the triangles `earcut` made of a polygon. Unlike `deviation`
+/// this takes any number of dimensions, looking at x and y only.
+pub fn measure<T: Float>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+    triangles: &[usize],
+) -> Result<Metrics, Error> {
+    if dims < 2 || vertices.len() % dims != 0 || triangles.len() % 3 != 0 {
+        return Err(Error::Unknown);
+    }
+    let points = vertices
+        .chunks(dims)
+        .map(|v| Some([v[0].to_f64()?, v[1].to_f64()?]))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::Unknown)?;
+
+    let mut starts = vec![0];
+    starts.extend(hole_indices);
+    starts.push(points.len());
+    let mut polygon_area = 0.0;
+    for (ring, bounds) in starts.windows(2).enumerate() {
+        let ring_points = points.get(bounds[0]..bounds[1]).ok_or(Error::Unknown)?;
+        let area = signed_area(ring_points).abs();
+        polygon_area += if ring == 0 { area } else { -area };
+    }
+
+    let mut metrics = Metrics {
+        triangles: triangles.len() / 3,
+        min_angle: f64::INFINITY,
+        max_angle: 0.0,
+        polygon_area,
+        ..Default::default()
+    };
+    let mut min_angles_sum = 0.0;
+    for t in triangles.chunks(3) {
+        let corner = |i: usize| points.get(t[i]).copied().ok_or(Error::Unknown);
+        let triangle = [corner(0)?, corner(1)?, corner(2)?];
+        let area = signed_area(triangle).abs();
+        metrics.triangles_area += area;
+        if area == 0.0 {
+            metrics.degenerate += 1;
+            continue;
+        }
+
+        let sides = [0, 1, 2].map(|i| distance(triangle[(i + 1) % 3], triangle[(i + 2) % 3]));
+        let angles = [0, 1, 2].map(|i| {
+            let (a, b) = (sides[(i + 1) % 3], sides[(i + 2) % 3]);
+            // law of cosines, clamped against rounding
+            ((a * a + b * b - sides[i] * sides[i]) / (2.0 * a * b))
+                .clamp(-1.0, 1.0)
+                .acos()
+                .to_degrees()
+        });
+        let min_angle = angles.iter().copied().fold(f64::INFINITY, f64::min);
+        let max_angle = angles.iter().copied().fold(0.0, f64::max);
+        metrics.min_angle = metrics.min_angle.min(min_angle);
+        metrics.max_angle = metrics.max_angle.max(max_angle);
+        min_angles_sum += min_angle;
+        if min_angle < SLIVER_ANGLE {
+            metrics.slivers += 1;
+        }
+
+        let longest = sides.iter().copied().fold(0.0, f64::max);
+        let shortest_height = 2.0 * area / longest;
+        let aspect_ratio = longest / shortest_height * 3.0_f64.sqrt() / 2.0;
+        let bucket = ASPECT_RATIO_BUCKETS
+            .iter()
+            .position(|&bound| aspect_ratio < bound)
+            .unwrap_or(ASPECT_RATIO_BUCKETS.len());
+        metrics.aspect_ratios[bucket] += 1;
+    }
+
+    let measured = metrics.triangles - metrics.degenerate;
+    if measured == 0 {
+        metrics.min_angle = 0.0;
+    } else {
+        metrics.mean_min_angle = min_angles_sum / measured as f64;
+    }
+    Ok(metrics)
+}
+
+fn distance(p: [f64; 2], q: [f64; 2]) -> f64 {
+    (p[0] - q[0]).hypot(p[1] - q[1])
+}
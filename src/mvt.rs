@@ -0,0 +1,88 @@
+//! Decoding the polygons of Mapbox Vector Tile features.
+//!
+//! Takes the `geometry` field of a `POLYGON` feature, the command integers
+//! as they come out of the protocol buffer, and leaves reading the tile
+//! itself to whatever protobuf library is at hand. Following version 2 of
+//! the specification, a ring with a positive area by the surveyor's formula
+//! in tile coordinates (clockwise on screen, since y grows downwards) starts
+//! a new polygon and a ring with a negative area is a hole in the polygon
+//! before it. Rings with no area are dropped.
+
+use crate::geom::{signed_area, Point};
+use crate::{Error, FlatPolygon};
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+/// Decode the geometry of a polygon feature into earcut input, one entry
+/// per polygon, in tile coordinates with y growing downwards.
+pub fn decode_polygons(geometry: &[u32]) -> Result<Vec<FlatPolygon<f64>>, Error> {
+    let mut polygons: Vec<FlatPolygon<f64>> = Vec::new();
+    for ring in rings(geometry)? {
+        let area = signed_area(&ring);
+        let flat = ring.iter().flatten();
+        if area > 0.0 {
+            polygons.push((flat.copied().collect(), Vec::new(), 2));
+        } else if area < 0.0 {
+            // a hole needs a polygon to go in
+            let (vertices, hole_indices, _) = polygons.last_mut().ok_or(Error::InvalidMvt)?;
+            hole_indices.push(vertices.len() / 2);
+            vertices.extend(flat);
+        }
+    }
+    Ok(polygons)
+}
+
+/// `decode_polygons`, with y turned to grow upwards across a tile of the
+/// given extent (4096 in most tiles), so the tile fills the same square
+/// the right way up. The rings are wound as they look on screen: outer
+/// rings clockwise and holes counterclockwise.
+pub fn decode_polygons_y_up(geometry: &[u32], extent: u32) -> Result<Vec<FlatPolygon<f64>>, Error> {
+    let mut polygons = decode_polygons(geometry)?;
+    for (vertices, _, _) in &mut polygons {
+        for y in vertices.iter_mut().skip(1).step_by(2) {
+            *y = f64::from(extent) - *y;
+        }
+    }
+    Ok(polygons)
+}
+
+// each ring a MoveTo, a LineTo and a ClosePath, the last of which adds no
+// point of its own
+fn rings(geometry: &[u32]) -> Result<Vec<Vec<Point>>, Error> {
+    let mut rings = Vec::new();
+    let mut integers = geometry.iter().copied();
+    let mut cursor = [0_i64; 2];
+    let mut next_point = |integers: &mut dyn Iterator<Item = u32>| {
+        for value in &mut cursor {
+            *value += zigzag(integers.next().ok_or(Error::InvalidMvt)?);
+        }
+        Ok::<Point, Error>([cursor[0] as f64, cursor[1] as f64])
+    };
+    while let Some(command) = integers.next() {
+        let (id, count) = (command & 0x7, command >> 3);
+        if (id, count) != (MOVE_TO, 1) {
+            return Err(Error::InvalidMvt);
+        }
+        let mut ring = vec![next_point(&mut integers)?];
+        match integers.next().map(|command| (command & 0x7, command >> 3)) {
+            Some((LINE_TO, count)) => {
+                for _ in 0..count {
+                    ring.push(next_point(&mut integers)?);
+                }
+            }
+            _ => return Err(Error::InvalidMvt),
+        }
+        match integers.next() {
+            Some(command) if (command & 0x7, command >> 3) == (CLOSE_PATH, 1) => {}
+            _ => return Err(Error::InvalidMvt),
+        }
+        rings.push(ring);
+    }
+    Ok(rings)
+}
+
+fn zigzag(value: u32) -> i64 {
+    i64::from(value >> 1) ^ -i64::from(value & 1)
+}
@@ -0,0 +1,192 @@
+//! Working out which rings are outer rings and which are holes, for
+//! sources that only give a bag of rings: shapefiles wound the wrong way,
+//! traced contours, CAD exports.
+//!
+//! Every ring is nested in the smallest ring around it. Rings inside an
+//! even number of others are outer rings and the rest are holes in the
+//! ring they lie in, so islands in lakes in islands come out as polygons
+//! of their own. Rings may touch at vertices, but must not cross or share
+//! edges; the way they are wound does not matter.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::geom::{bounds, contains, probe, signed_area, Grid, Point};
+use crate::{Error, FlatPolygon, Float, Triangulated};
+
+/// How a set of rings lie inside each other.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Nesting {
+    /// the smallest ring around each ring, if any
+    pub parents: Vec<Option<usize>>,
+    /// how many rings are around each ring: even for outer rings and odd
+    /// for holes
+    pub depths: Vec<usize>,
+}
+
+impl Nesting {
+    /// Nest rings laid out like the rings of an `earcut` polygon, with
+    /// `ring_indices` holding the index of the first vertex of every ring
+    /// but the first. Takes any number of dimensions, looking at x and y
+    /// only.
+    pub fn new<T: Float>(
+        vertices: &[T],
+        ring_indices: &[usize],
+        dims: usize,
+    ) -> Result<Nesting, Error> {
+        let points = points(vertices, dims)?;
+        let rings = ranges(points.len(), ring_indices)?
+            .into_iter()
+            .map(|range| &points[range])
+            .collect::<Vec<_>>();
+        Ok(Nesting::of(&rings))
+    }
+
+    fn of(rings: &[&[Point]]) -> Nesting {
+        let mut nesting = Nesting {
+            parents: vec![None; rings.len()],
+            depths: vec![0; rings.len()],
+        };
+        // largest first, so that every ring comes after those around it
+        let areas: Vec<f64> = rings.iter().map(|ring| signed_area(*ring).abs()).collect();
+        let mut order: Vec<usize> = (0..rings.len()).collect();
+        order.sort_by(|&i, &j| {
+            areas[j]
+                .partial_cmp(&areas[i])
+                .unwrap_or(Ordering::Equal)
+                .then(i.cmp(&j))
+        });
+        let mut rank = vec![0; rings.len()];
+        for (k, &i) in order.iter().enumerate() {
+            rank[i] = k;
+        }
+
+        let boxes: Vec<(Point, Point)> = rings.iter().map(|ring| bounds(ring)).collect();
+        let (min, max) = bounds(&rings.concat());
+        let mut grid = Grid::new(min, max, rings.len());
+        for (i, ring) in rings.iter().enumerate() {
+            if !ring.is_empty() {
+                grid.insert(boxes[i], i);
+            }
+        }
+
+        for &i in &order {
+            let Some(p) = probe(rings[i]) else {
+                continue;
+            };
+            let parent = grid
+                .query((p, p))
+                .filter(|&j| {
+                    let (min, max) = boxes[j];
+                    rank[j] < rank[i]
+                        && (min[0]..=max[0]).contains(&p[0])
+                        && (min[1]..=max[1]).contains(&p[1])
+                        && contains(rings[j], p)
+                })
+                .max_by_key(|&j| rank[j]);
+            if let Some(j) = parent {
+                nesting.parents[i] = Some(j);
+                nesting.depths[i] = nesting.depths[j] + 1;
+            }
+        }
+        nesting
+    }
+
+    /// Whether the ring is a hole, lying inside an odd number of others.
+    pub fn is_hole(&self, ring: usize) -> bool {
+        self.depths[ring] % 2 == 1
+    }
+
+    /// The rings grouped into polygons, each an outer ring followed by the
+    /// holes in it, in the order the outer rings come in.
+    pub fn polygons(&self) -> Vec<Vec<usize>> {
+        let mut polygon = vec![usize::MAX; self.depths.len()];
+        let mut polygons = Vec::new();
+        for ring in (0..self.depths.len()).filter(|&ring| !self.is_hole(ring)) {
+            polygon[ring] = polygons.len();
+            polygons.push(vec![ring]);
+        }
+        for ring in (0..self.depths.len()).filter(|&ring| self.is_hole(ring)) {
+            if let Some(outer) = self.parents[ring] {
+                polygons[polygon[outer]].push(ring);
+            }
+        }
+        polygons
+    }
+}
+
+/// Sort the rings, laid out as for `Nesting::new`, into polygons in the
+/// `(vertices, hole_indices, dims)` form `earcut` takes, with outer rings
+/// turned counterclockwise and holes clockwise (with y up). Every vertex
+/// keeps all its dimensions.
+pub fn nest_polygons<T: Float>(
+    vertices: &[T],
+    ring_indices: &[usize],
+    dims: usize,
+) -> Result<Vec<FlatPolygon<T>>, Error> {
+    let points = points(vertices, dims)?;
+    let ranges = ranges(points.len(), ring_indices)?;
+    let rings = ranges
+        .iter()
+        .map(|range| &points[range.clone()])
+        .collect::<Vec<_>>();
+    let nesting = Nesting::of(&rings);
+
+    let polygons = nesting.polygons().into_iter().filter(|polygon| {
+        // no vertices at all make one empty ring
+        !ranges[polygon[0]].is_empty()
+    });
+    let polygons = polygons.map(|polygon| {
+        let mut flat = Vec::new();
+        let mut hole_indices = Vec::new();
+        for (k, &ring) in polygon.iter().enumerate() {
+            if k > 0 {
+                hole_indices.push(flat.len() / dims);
+            }
+            let chunks = vertices[ranges[ring].start * dims..ranges[ring].end * dims].chunks(dims);
+            match (signed_area(rings[ring]) < 0.0) == (k == 0) {
+                true => chunks.rev().for_each(|v| flat.extend_from_slice(v)),
+                false => chunks.for_each(|v| flat.extend_from_slice(v)),
+            }
+        }
+        (flat, hole_indices, dims)
+    });
+    Ok(polygons.collect())
+}
+
+/// `nest_polygons`, then `earcut` each polygon. Returns the polygons along
+/// with their triangles, which index into that polygon's vertices.
+pub fn earcut_nested<T: Float>(
+    vertices: &[T],
+    ring_indices: &[usize],
+    dims: usize,
+) -> Result<Vec<Triangulated<T>>, Error> {
+    nest_polygons(vertices, ring_indices, dims)?
+        .into_iter()
+        .map(|polygon| {
+            let triangles = crate::earcut(&polygon.0, &polygon.1, polygon.2)?;
+            Ok((polygon, triangles))
+        })
+        .collect()
+}
+
+fn points<T: Float>(vertices: &[T], dims: usize) -> Result<Vec<Point>, Error> {
+    if dims < 2 || vertices.len() % dims != 0 {
+        return Err(Error::Unknown);
+    }
+    vertices
+        .chunks(dims)
+        .map(|v| Some([v[0].to_f64()?, v[1].to_f64()?]))
+        .collect::<Option<Vec<Point>>>()
+        .ok_or(Error::Unknown)
+}
+
+fn ranges(count: usize, ring_indices: &[usize]) -> Result<Vec<Range<usize>>, Error> {
+    let mut starts = vec![0];
+    starts.extend(ring_indices);
+    starts.push(count);
+    match starts.windows(2).all(|w| w[0] <= w[1]) {
+        true => Ok(starts.windows(2).map(|w| w[0]..w[1]).collect()),
+        false => Err(Error::Unknown),
+    }
+}
@@ -0,0 +1,366 @@
+//! Building polygons out of lines, Bezier curves and arcs, the way icons
+//! and glyphs are drawn, flattened into the rings `earcut` takes.
+//!
+//! Curves are cut in half until every piece is within the tolerance of a
+//! straight line, so the flatter a curve the fewer vertices it gets. Arcs
+//! are stepped around evenly, as finely as their radius needs.
+//!
+//! With the `kurbo` and `lyon` features, kurbo's `BezPath` and lyon's
+//! `Path` can be drawn onto a `PathBuilder` too, and with `lyon` the
+//! triangles come out as lyon's `VertexBuffers`.
+
+use std::f64::consts::TAU;
+
+use crate::Error;
+
+type Point = [f64; 2];
+
+// how many times a curve is cut in half at most, for 2^10 pieces
+const MAX_DEPTH: usize = 10;
+
+// most pieces an arc is cut into
+const MAX_ARC_PIECES: usize = 1024;
+
+/// A path flattened by `PathBuilder`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    /// x and y of every vertex
+    pub vertices: Vec<f64>,
+    /// index of the first vertex of every contour but the first, which
+    /// `earcut` takes as holes
+    pub hole_indices: Vec<usize>,
+    /// for every vertex, the number of the call to the builder that drew
+    /// it, counting from 0 over all of them (`move_to` and `close`
+    /// included), if `record_segments` was on; else empty
+    pub segments: Vec<usize>,
+}
+
+impl Path {
+    /// Triangulate the path, taking its first contour as the outer ring and
+    /// the rest as holes.
+    pub fn earcut(&self) -> Result<Vec<usize>, Error> {
+        crate::earcut(&self.vertices, &self.hole_indices, 2)
+    }
+
+    /// Triangulate the path into a vertex buffer and an index buffer, the
+    /// way lyon's `VertexBuffers` hold them, making every vertex out of its
+    /// x and y with `vertex`. Fails if `I` cannot count all the vertices.
+    pub fn fill<V, I: TryFrom<usize>>(
+        &self,
+        vertex: impl Fn(f64, f64) -> V,
+    ) -> Result<(Vec<V>, Vec<I>), Error> {
+        let indices = self
+            .earcut()?
+            .into_iter()
+            .map(|i| I::try_from(i).map_err(|_| Error::Unknown))
+            .collect::<Result<Vec<I>, Error>>()?;
+        let vertices = self.vertices.chunks(2).map(|p| vertex(p[0], p[1]));
+        Ok((vertices.collect(), indices))
+    }
+}
+
+#[cfg(feature = "kurbo")]
+impl Path {
+    /// Flatten a kurbo `BezPath` to within `tolerance`.
+    pub fn from_bez_path(path: &kurbo::BezPath, tolerance: f64) -> Path {
+        let mut builder = PathBuilder::new(tolerance);
+        builder.add_bez_path(path);
+        builder.build()
+    }
+}
+
+#[cfg(feature = "lyon")]
+impl Path {
+    /// Flatten a lyon `Path`, or anything else giving `PathEvent`s, to
+    /// within `tolerance`.
+    pub fn from_lyon_path(
+        path: impl IntoIterator<Item = lyon_tessellation::path::PathEvent>,
+        tolerance: f64,
+    ) -> Path {
+        let mut builder = PathBuilder::new(tolerance);
+        builder.add_lyon_path(path);
+        builder.build()
+    }
+
+    /// Triangulate the path into lyon's `VertexBuffers`, as
+    /// `lyon_tessellation::FillTessellator` would. Fails if `I` cannot
+    /// count all the vertices.
+    pub fn vertex_buffers<I: TryFrom<usize>>(
+        &self,
+    ) -> Result<lyon_tessellation::VertexBuffers<lyon_tessellation::math::Point, I>, Error> {
+        let point = |x: f64, y: f64| lyon_tessellation::math::point(x as f32, y as f32);
+        let (vertices, indices) = self.fill(point)?;
+        Ok(lyon_tessellation::VertexBuffers { vertices, indices })
+    }
+}
+
+/// Draws a path one segment at a time, like a 2D canvas does.
+///
+/// `move_to` starts a new contour; drawing without one starts at the
+/// current point, which is the origin at first and the start of the last
+/// contour after `close`. Contours are closed either way, `close` only
+/// moves the current point back to the start.
+#[derive(Clone, Debug)]
+pub struct PathBuilder {
+    tolerance: f64,
+    record_segments: bool,
+    path: Path,
+    // where the open contour starts in path.vertices, if one is open
+    contour: Option<usize>,
+    start: Point,
+    // the segment that moved to start, if any
+    start_segment: Option<usize>,
+    current: Point,
+    segment: usize,
+}
+
+impl PathBuilder {
+    /// A builder keeping the flattened path within `tolerance` of the
+    /// curves.
+    pub fn new(tolerance: f64) -> PathBuilder {
+        PathBuilder {
+            tolerance,
+            record_segments: false,
+            path: Path::default(),
+            contour: None,
+            start: [0.0; 2],
+            start_segment: None,
+            current: [0.0; 2],
+            segment: 0,
+        }
+    }
+
+    /// Record which segment every vertex came from in `Path::segments`, so
+    /// that per-curve attributes can follow the vertices through `earcut`.
+    pub fn record_segments(&mut self) {
+        self.record_segments = true;
+    }
+
+    /// Start a new contour at `(x, y)`, ending the one before.
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.end_contour();
+        self.start = [x, y];
+        self.start_segment = Some(self.segment);
+        self.current = self.start;
+        self.segment += 1;
+    }
+
+    /// A straight line to `(x, y)`.
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        self.begin_contour();
+        self.push([x, y], self.segment);
+        self.segment += 1;
+    }
+
+    /// A quadratic Bezier curve through control point `(cx, cy)`.
+    pub fn quad_to(&mut self, cx: f64, cy: f64, x: f64, y: f64) {
+        self.begin_contour();
+        // the same curve as a cubic
+        let [p, c] = [self.current, [cx, cy]];
+        let c1 = [
+            p[0] + 2.0 / 3.0 * (c[0] - p[0]),
+            p[1] + 2.0 / 3.0 * (c[1] - p[1]),
+        ];
+        let c2 = [x + 2.0 / 3.0 * (c[0] - x), y + 2.0 / 3.0 * (c[1] - y)];
+        self.flatten_cubic([p, c1, c2, [x, y]], 0);
+        self.segment += 1;
+    }
+
+    /// A cubic Bezier curve through control points `(c1x, c1y)` and
+    /// `(c2x, c2y)`.
+    pub fn cubic_to(&mut self, c1x: f64, c1y: f64, c2x: f64, c2y: f64, x: f64, y: f64) {
+        self.begin_contour();
+        self.flatten_cubic([self.current, [c1x, c1y], [c2x, c2y], [x, y]], 0);
+        self.segment += 1;
+    }
+
+    /// An elliptical arc to `(x, y)`, the way SVG's `A` command draws it:
+    /// radii `rx` and `ry`, with the x axis turned by `rotation` radians,
+    /// taking the longer way around if `large_arc` and going
+    /// counterclockwise (with y up) if `sweep`. Radii too small to reach
+    /// are scaled up, and a zero radius draws a line.
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc_to(
+        &mut self,
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    ) {
+        self.begin_contour();
+        let [from, to] = [self.current, [x, y]];
+        let (mut rx, mut ry) = (rx.abs(), ry.abs());
+        if from != to && (rx == 0.0 || ry == 0.0) {
+            self.push(to, self.segment);
+        } else if from != to {
+            // after SVG 1.1, appendix F.6.5
+            let (sin, cos) = rotation.sin_cos();
+            let (dx, dy) = ((from[0] - to[0]) / 2.0, (from[1] - to[1]) / 2.0);
+            let (x1, y1) = (cos * dx + sin * dy, cos * dy - sin * dx);
+            let scale = (x1 * x1 / (rx * rx) + y1 * y1 / (ry * ry)).sqrt();
+            if scale > 1.0 {
+                rx *= scale;
+                ry *= scale;
+            }
+            let (a, b) = ((rx * y1).powi(2), (ry * x1).powi(2));
+            let k = (((rx * ry).powi(2) - a - b) / (a + b)).max(0.0).sqrt();
+            let k = if large_arc == sweep { -k } else { k };
+            let (cx1, cy1) = (k * rx * y1 / ry, -k * ry * x1 / rx);
+            let center = [
+                cos * cx1 - sin * cy1 + (from[0] + to[0]) / 2.0,
+                sin * cx1 + cos * cy1 + (from[1] + to[1]) / 2.0,
+            ];
+            let start = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+            let end = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+            let mut angle = (end - start).rem_euclid(TAU);
+            if !sweep && angle > 0.0 {
+                angle -= TAU;
+            }
+
+            // the most an arc of the larger radius can turn per piece while
+            // staying within the tolerance of its chord
+            let step = 2.0 * (1.0 - self.tolerance / rx.max(ry)).clamp(-1.0, 1.0).acos();
+            let pieces = match step > 0.0 {
+                true => ((angle.abs() / step).ceil() as usize).clamp(1, MAX_ARC_PIECES),
+                false => MAX_ARC_PIECES,
+            };
+            for i in 1..pieces {
+                let (s, c) = (start + angle * i as f64 / pieces as f64).sin_cos();
+                let p = [
+                    center[0] + rx * c * cos - ry * s * sin,
+                    center[1] + rx * c * sin + ry * s * cos,
+                ];
+                self.push(p, self.segment);
+            }
+            self.push(to, self.segment);
+        }
+        self.segment += 1;
+    }
+
+    /// End the contour, going back to where it started.
+    pub fn close(&mut self) {
+        self.end_contour();
+        self.current = self.start;
+        self.segment += 1;
+    }
+
+    /// Draw a kurbo `BezPath`, each of its elements a call to the builder.
+    #[cfg(feature = "kurbo")]
+    pub fn add_bez_path(&mut self, path: &kurbo::BezPath) {
+        for element in path.elements() {
+            match *element {
+                kurbo::PathEl::MoveTo(p) => self.move_to(p.x, p.y),
+                kurbo::PathEl::LineTo(p) => self.line_to(p.x, p.y),
+                kurbo::PathEl::QuadTo(c, p) => self.quad_to(c.x, c.y, p.x, p.y),
+                kurbo::PathEl::CurveTo(c1, c2, p) => {
+                    self.cubic_to(c1.x, c1.y, c2.x, c2.y, p.x, p.y)
+                }
+                kurbo::PathEl::ClosePath => self.close(),
+            }
+        }
+    }
+
+    /// Draw lyon `PathEvent`s, as iterating over a lyon `Path` gives them,
+    /// each a call to the builder. Open subpaths are closed, since earcut
+    /// fills them that way anyway.
+    #[cfg(feature = "lyon")]
+    pub fn add_lyon_path(
+        &mut self,
+        path: impl IntoIterator<Item = lyon_tessellation::path::PathEvent>,
+    ) {
+        use lyon_tessellation::path::PathEvent;
+        let xy = |p: lyon_tessellation::math::Point| (f64::from(p.x), f64::from(p.y));
+        for event in path {
+            match event {
+                PathEvent::Begin { at } => {
+                    let (x, y) = xy(at);
+                    self.move_to(x, y);
+                }
+                PathEvent::Line { to, .. } => {
+                    let (x, y) = xy(to);
+                    self.line_to(x, y);
+                }
+                PathEvent::Quadratic { ctrl, to, .. } => {
+                    let ((cx, cy), (x, y)) = (xy(ctrl), xy(to));
+                    self.quad_to(cx, cy, x, y);
+                }
+                PathEvent::Cubic {
+                    ctrl1, ctrl2, to, ..
+                } => {
+                    let ((c1x, c1y), (c2x, c2y), (x, y)) = (xy(ctrl1), xy(ctrl2), xy(to));
+                    self.cubic_to(c1x, c1y, c2x, c2y, x, y);
+                }
+                PathEvent::End { .. } => self.close(),
+            }
+        }
+    }
+
+    /// The path drawn so far. A last contour left open is closed like the
+    /// others, by the edge from its last vertex back to its first.
+    pub fn build(mut self) -> Path {
+        self.end_contour();
+        self.path
+    }
+
+    fn begin_contour(&mut self) {
+        if self.contour.is_none() {
+            let start = self.path.vertices.len() / 2;
+            if start > 0 {
+                self.path.hole_indices.push(start);
+            }
+            self.contour = Some(start);
+            self.push(self.start, self.start_segment.unwrap_or(self.segment));
+        }
+    }
+
+    // drop a last vertex back on the first, which earcut would only filter
+    fn end_contour(&mut self) {
+        if let Some(start) = self.contour.take() {
+            let count = self.path.vertices.len() / 2;
+            if count - start > 1 && self.current == self.start {
+                self.path.vertices.truncate(2 * (count - 1));
+                if self.record_segments {
+                    self.path.segments.pop();
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, p: Point, segment: usize) {
+        self.path.vertices.extend(p);
+        if self.record_segments {
+            self.path.segments.push(segment);
+        }
+        self.current = p;
+    }
+
+    fn flatten_cubic(&mut self, [p0, p1, p2, p3]: [Point; 4], depth: usize) {
+        // the curve lies within its control points, so it is within the
+        // tolerance of the chord if they are
+        let flat = distance(p0, p3, p1).max(distance(p0, p3, p2)) <= self.tolerance;
+        if flat || depth == MAX_DEPTH {
+            self.push(p3, self.segment);
+            return;
+        }
+        let mid = |a: Point, b: Point| [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+        let (a, b, c) = (mid(p0, p1), mid(p1, p2), mid(p2, p3));
+        let (d, e) = (mid(a, b), mid(b, c));
+        let f = mid(d, e);
+        self.flatten_cubic([p0, a, d, f], depth + 1);
+        self.flatten_cubic([f, e, c, p3], depth + 1);
+    }
+}
+
+// how far p is from the segment a-b
+fn distance(a: Point, b: Point, p: Point) -> f64 {
+    let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+    let length = dx * dx + dy * dy;
+    let t = match length > 0.0 {
+        true => (((p[0] - a[0]) * dx + (p[1] - a[1]) * dy) / length).clamp(0.0, 1.0),
+        false => 0.0,
+    };
+    (p[0] - a[0] - t * dx).hypot(p[1] - a[1] - t * dy)
+}
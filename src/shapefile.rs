@@ -0,0 +1,215 @@
+//! Reading polygons from ESRI shapefiles.
+//!
+//! Understands the `Polygon`, `PolygonZ` and `PolygonM` records of a `.shp`
+//! file, read in order or one at a time through the `.shx` index that comes
+//! with it. A record is a bag of rings: by the shapefile convention, rings
+//! wound clockwise (with y up) are outer rings and the others holes, each
+//! in the smallest outer ring around it. A hole outside every outer ring is
+//! taken for an outer ring wound the wrong way.
+//!
+//! Every polygon comes out in the same form as `flatten` gives, with any z
+//! and m values kept in the vertex stride: x, y, z, m for `PolygonZ` and
+//! x, y, m for `PolygonM`. Measures are optional, and records without them
+//! leave them out. They are read as written, including the values below
+//! -1e38 that stand for no data.
+
+use std::ops::Range;
+
+use crate::geom::{bounds, contains, probe, signed_area, Point};
+use crate::{Error, FlatPolygon};
+
+const FILE_CODE: i32 = 9994;
+const VERSION: i32 = 1000;
+
+const NULL_SHAPE: i32 = 0;
+const POLYGON: i32 = 5;
+const POLYGON_Z: i32 = 15;
+const POLYGON_M: i32 = 25;
+
+/// Parse every record of a `.shp` file into earcut input: the polygons in
+/// each record, in record order. Null records have none.
+pub fn parse_records(shp: &[u8]) -> Result<Vec<Vec<FlatPolygon<f64>>>, Error> {
+    let mut reader = Reader::file(shp)?;
+    let mut records = Vec::new();
+    while !reader.bytes.is_empty() {
+        // the record number, counting from 1
+        reader.be_i32()?;
+        let length = words(reader.be_i32()?)?;
+        records.push(parse_record(reader.slice(length)?)?);
+    }
+    Ok(records)
+}
+
+/// Read a `.shx` index: where the content of every record lies in the
+/// `.shp` file, for handing `&shp[range]` to `parse_record`.
+pub fn parse_index(shx: &[u8]) -> Result<Vec<Range<usize>>, Error> {
+    let mut reader = Reader::file(shx)?;
+    let mut ranges = Vec::new();
+    while !reader.bytes.is_empty() {
+        // past the record header
+        let start = words(reader.be_i32()?)? + 8;
+        let length = words(reader.be_i32()?)?;
+        ranges.push(start..start + length);
+    }
+    Ok(ranges)
+}
+
+/// Parse the content of one record, without its record header, into the
+/// polygons in it.
+pub fn parse_record(content: &[u8]) -> Result<Vec<FlatPolygon<f64>>, Error> {
+    let mut reader = Reader { bytes: content };
+    let shape_type = reader.le_i32()?;
+    match shape_type {
+        NULL_SHAPE => return Ok(Vec::new()),
+        POLYGON | POLYGON_Z | POLYGON_M => {}
+        _ => return Err(Error::InvalidShapefile),
+    }
+    // the bounding box
+    reader.slice(32)?;
+    let part_count = count(reader.le_i32()?)?;
+    let point_count = count(reader.le_i32()?)?;
+    let mut starts = (0..part_count)
+        .map(|_| count(reader.le_i32()?))
+        .collect::<Result<Vec<usize>, Error>>()?;
+    let points = (0..point_count)
+        .map(|_| Ok([reader.le_f64()?, reader.le_f64()?]))
+        .collect::<Result<Vec<Point>, Error>>()?;
+
+    // z values, then measures, each after their range
+    let mut values = Vec::new();
+    let mut read_values = |reader: &mut Reader| -> Result<(), Error> {
+        reader.slice(16)?;
+        values.push(
+            (0..point_count)
+                .map(|_| reader.le_f64())
+                .collect::<Result<Vec<f64>, Error>>()?,
+        );
+        Ok(())
+    };
+    if shape_type == POLYGON_Z {
+        read_values(&mut reader)?;
+    }
+    if shape_type != POLYGON && !reader.bytes.is_empty() {
+        read_values(&mut reader)?;
+    }
+    if !reader.bytes.is_empty() {
+        return Err(Error::InvalidShapefile);
+    }
+
+    if starts.first().is_some_and(|&start| start != 0) {
+        return Err(Error::InvalidShapefile);
+    }
+    starts.push(point_count);
+    if !starts.windows(2).all(|w| w[0] <= w[1]) {
+        return Err(Error::InvalidShapefile);
+    }
+    let ranges: Vec<Range<usize>> = starts
+        .windows(2)
+        .map(|w| w[0]..w[1])
+        .filter(|range| !range.is_empty())
+        .collect();
+
+    let dims = 2 + values.len();
+    let polygons = classify(&points, &ranges).into_iter().map(|polygon| {
+        let mut vertices = Vec::new();
+        let mut hole_indices = Vec::new();
+        for (k, &ring) in polygon.iter().enumerate() {
+            if k > 0 {
+                hole_indices.push(vertices.len() / dims);
+            }
+            for i in ranges[ring].clone() {
+                vertices.extend(points[i]);
+                vertices.extend(values.iter().map(|values| values[i]));
+            }
+        }
+        (vertices, hole_indices, dims)
+    });
+    Ok(polygons.collect())
+}
+
+// the rings grouped into polygons, each an outer ring followed by its holes
+fn classify(points: &[Point], ranges: &[Range<usize>]) -> Vec<Vec<usize>> {
+    let rings: Vec<&[Point]> = ranges.iter().map(|range| &points[range.clone()]).collect();
+    let areas: Vec<f64> = rings.iter().map(|ring| signed_area(*ring)).collect();
+    let boxes: Vec<(Point, Point)> = rings.iter().map(|ring| bounds(ring)).collect();
+
+    let mut polygons: Vec<Vec<usize>> = Vec::new();
+    let outers: Vec<usize> = (0..rings.len()).filter(|&i| areas[i] < 0.0).collect();
+    for &i in &outers {
+        polygons.push(vec![i]);
+    }
+    for hole in (0..rings.len()).filter(|&i| areas[i] >= 0.0) {
+        let p = probe(rings[hole]).unwrap();
+        let outer = (0..outers.len())
+            .filter(|&k| {
+                let (min, max) = boxes[outers[k]];
+                (min[0]..=max[0]).contains(&p[0])
+                    && (min[1]..=max[1]).contains(&p[1])
+                    && contains(rings[outers[k]], p)
+            })
+            .min_by(|&k, &l| areas[outers[k]].abs().total_cmp(&areas[outers[l]].abs()));
+        match outer {
+            Some(k) => polygons[k].push(hole),
+            None => polygons.push(vec![hole]),
+        }
+    }
+    polygons
+}
+
+// a length or offset in 16-bit words, in bytes
+fn words(value: i32) -> Result<usize, Error> {
+    Ok(count(value)? * 2)
+}
+
+fn count(value: i32) -> Result<usize, Error> {
+    usize::try_from(value).map_err(|_| Error::InvalidShapefile)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    // past the 100 byte header of a `.shp` or `.shx` file, up to the file
+    // length it gives
+    fn file(bytes: &'a [u8]) -> Result<Reader<'a>, Error> {
+        let mut reader = Reader { bytes };
+        if reader.be_i32()? != FILE_CODE {
+            return Err(Error::InvalidShapefile);
+        }
+        reader.slice(20)?;
+        let length = words(reader.be_i32()?)?;
+        if reader.le_i32()? != VERSION || length < 100 || length > bytes.len() {
+            return Err(Error::InvalidShapefile);
+        }
+        // the shape type and the bounding box
+        reader.slice(68)?;
+        reader.bytes = &bytes[100..length];
+        Ok(reader)
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        Ok(self.slice(N)?.try_into().unwrap())
+    }
+
+    fn slice(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if self.bytes.len() < n {
+            return Err(Error::InvalidShapefile);
+        }
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn be_i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_be_bytes(self.take()?))
+    }
+
+    fn le_i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_le_bytes(self.take()?))
+    }
+
+    fn le_f64(&mut self) -> Result<f64, Error> {
+        Ok(f64::from_le_bytes(self.take()?))
+    }
+}
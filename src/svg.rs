@@ -0,0 +1,465 @@
+//! Rendering a triangulation as a standalone SVG image, for looking at
+//! failing inputs without going through viz/ (the `svg` feature), and
+//! reading the shapes of an SVG image back in to fill them (the
+//! `svg-import` feature).
+//!
+//! Coordinates are written as given (y grows downwards, like viz.js draws
+//! them), with the `viewBox` fitted around the input. They are read as
+//! given too: transforms, styles inherited from groups and CSS are not
+//! applied.
+
+#[cfg(feature = "svg")]
+use std::fmt::Write;
+
+#[cfg(feature = "svg-import")]
+use crate::fill::FillRule;
+#[cfg(feature = "svg-import")]
+use crate::path::{Path, PathBuilder};
+use crate::Error;
+#[cfg(feature = "svg")]
+use crate::{Bridge, Float};
+
+/// What to draw, and how large.
+#[cfg(feature = "svg")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+    /// width of the image in pixels; the height follows the aspect ratio
+    pub width: f64,
+    /// outline the outer ring and holes
+    pub rings: bool,
+    /// fill the output triangles
+    pub triangles: bool,
+    /// triangles to mark as problems, numbered by their position in the
+    /// earcut output (triangle `i` is `triangles[3 * i..3 * i + 3]`)
+    pub problems: Vec<usize>,
+}
+
+#[cfg(feature = "svg")]
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            width: 800.0,
+            rings: true,
+            triangles: true,
+            problems: Vec::new(),
+        }
+    }
+}
+
+/// Render the input polygon and its triangulation into an SVG document,
+/// with the bridges earcut cut between the holes and the outer ring if
+/// given, as `earcut_with_bridges` returns them.
+#[cfg(feature = "svg")]
+pub fn to_svg<T: Float>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+    triangles: &[usize],
+    bridges: Option<&[Bridge]>,
+    options: &SvgOptions,
+) -> Result<String, Error> {
+    if dims < 2 || vertices.len() % dims != 0 || triangles.len() % 3 != 0 {
+        return Err(Error::Unknown);
+    }
+    let points = vertices
+        .chunks(dims)
+        .map(|v| Some((v[0].to_f64()?, v[1].to_f64()?)))
+        .collect::<Option<Vec<(f64, f64)>>>()
+        .ok_or(Error::Unknown)?;
+    let point = |i: usize| points.get(i).copied().ok_or(Error::Unknown);
+    let polygon = |indices: &[usize]| -> Result<String, Error> {
+        let mut s = String::new();
+        for &i in indices {
+            let (x, y) = point(i)?;
+            write!(s, "{},{} ", x, y).unwrap();
+        }
+        Ok(s.trim_end().to_string())
+    };
+
+    let (minx, miny, maxx, maxy) = points.iter().fold(
+        (
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ),
+        |(minx, miny, maxx, maxy), &(x, y)| (minx.min(x), miny.min(y), maxx.max(x), maxy.max(y)),
+    );
+    let (minx, miny, maxx, maxy) = match points.is_empty() {
+        true => (0.0, 0.0, 1.0, 1.0),
+        false => (minx, miny, maxx, maxy),
+    };
+    let size = (maxx - minx).max(maxy - miny);
+    let pad = if size > 0.0 { size * 0.02 } else { 1.0 };
+    let (w, h) = (maxx - minx + 2.0 * pad, maxy - miny + 2.0 * pad);
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        options.width,
+        (options.width * h / w).ceil(),
+        minx - pad,
+        miny - pad,
+        w,
+        h
+    )
+    .unwrap();
+    svg.push_str("<style>* { vector-effect: non-scaling-stroke; }</style>\n");
+
+    if options.triangles {
+        let count = triangles.len() / 3;
+        svg.push_str("<g stroke=\"black\" stroke-width=\"0.5\">\n");
+        for (i, triangle) in triangles.chunks(3).enumerate() {
+            // same coloring as viz.js
+            let hue = 360.0 * (i * 3) as f64 / (count * 3 - 1) as f64;
+            writeln!(
+                svg,
+                r#"<polygon points="{}" fill="hsla({:.0},100%,50%,0.32)"/>"#,
+                polygon(triangle)?,
+                hue
+            )
+            .unwrap();
+        }
+        svg.push_str("</g>\n");
+    }
+
+    if options.rings {
+        svg.push_str("<g fill=\"none\" stroke=\"black\" stroke-width=\"1.5\">\n");
+        let mut starts = vec![0];
+        starts.extend(hole_indices);
+        starts.push(points.len());
+        for ring in starts.windows(2) {
+            let (start, end) = (ring[0], ring[1]);
+            if start > end || end > points.len() {
+                return Err(Error::Unknown);
+            }
+            let indices = (start..end).collect::<Vec<_>>();
+            match indices.len() {
+                0 => {}
+                // a steiner point
+                1 => {
+                    let (x, y) = point(start)?;
+                    writeln!(svg, r#"<circle cx="{}" cy="{}" r="{}"/>"#, x, y, pad / 4.0).unwrap();
+                }
+                _ => writeln!(svg, r#"<polygon points="{}"/>"#, polygon(&indices)?).unwrap(),
+            }
+        }
+        svg.push_str("</g>\n");
+    }
+
+    if let Some(bridges) = bridges {
+        svg.push_str("<g stroke=\"blue\" stroke-width=\"1.5\" stroke-dasharray=\"4 2\">\n");
+        for &(hole, outer) in bridges {
+            let ((x1, y1), (x2, y2)) = (point(hole)?, point(outer)?);
+            writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+                x1, y1, x2, y2
+            )
+            .unwrap();
+        }
+        svg.push_str("</g>\n");
+    }
+
+    if !options.problems.is_empty() {
+        svg.push_str("<g fill=\"red\" fill-opacity=\"0.6\" stroke=\"red\" stroke-width=\"2\">\n");
+        for &i in &options.problems {
+            let triangle = triangles.get(3 * i..3 * i + 3).ok_or(Error::Unknown)?;
+            writeln!(svg, r#"<polygon points="{}"/>"#, polygon(triangle)?).unwrap();
+        }
+        svg.push_str("</g>\n");
+    }
+
+    svg.push_str("</svg>\n");
+    Ok(svg)
+}
+
+#[cfg(feature = "svg-import")]
+/// A filled `<path>`, `<polygon>` or `<polyline>` read from an SVG image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shape {
+    /// the contours, flattened
+    pub path: Path,
+    /// from the `fill-rule` attribute or style, `NonZero` if neither
+    pub fill_rule: FillRule,
+}
+
+#[cfg(feature = "svg-import")]
+impl Shape {
+    /// Triangulate the shape under its fill rule, as `fill::tessellate`
+    /// does.
+    pub fn tessellate(&self) -> Result<(Vec<f64>, Vec<usize>), Error> {
+        crate::fill::tessellate(
+            &self.path.vertices,
+            &self.path.hole_indices,
+            2,
+            self.fill_rule,
+        )
+    }
+}
+
+#[cfg(feature = "svg-import")]
+/// Read every filled `<path>`, `<polygon>` and `<polyline>` out of an SVG
+/// image, in document order, flattening curves to within `tolerance`.
+/// Shapes with `fill="none"` are skipped, and polylines fill as if closed,
+/// like SVG fills them.
+pub fn parse_shapes(svg: &str, tolerance: f64) -> Result<Vec<Shape>, Error> {
+    let mut shapes = Vec::new();
+    let mut rest = svg;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            let end = comment.find("-->").ok_or(Error::InvalidSvg)?;
+            rest = &comment[end + 3..];
+            continue;
+        }
+        // closing tags, declarations, doctypes and processing instructions
+        if rest.starts_with(['/', '?', '!']) {
+            rest = &rest[rest.find('>').ok_or(Error::InvalidSvg)? + 1..];
+            continue;
+        }
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .unwrap_or(rest.len());
+        let name = &rest[..name_end];
+        let (attributes, after) = attributes(&rest[name_end..])?;
+        rest = after;
+
+        let attribute = |key: &str| {
+            attributes
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|&(_, value)| value)
+        };
+        // a style declaration wins over the attribute of the same name
+        let property = |key: &str| {
+            let style = attribute("style").unwrap_or("");
+            let mut declarations = style.split(';').filter_map(|d| d.split_once(':'));
+            let styled = declarations.rfind(|(k, _)| k.trim() == key);
+            styled.map(|(_, value)| value.trim()).or(attribute(key))
+        };
+        let path = match name {
+            "path" => parse_path_data(attribute("d").unwrap_or(""), tolerance)?,
+            "polygon" | "polyline" => {
+                let vertices = parse_points(attribute("points").unwrap_or(""))?;
+                Path {
+                    vertices,
+                    ..Default::default()
+                }
+            }
+            _ => continue,
+        };
+        if property("fill") == Some("none") {
+            continue;
+        }
+        let fill_rule = match property("fill-rule") {
+            None | Some("nonzero") | Some("inherit") => FillRule::NonZero,
+            Some("evenodd") => FillRule::EvenOdd,
+            Some(_) => return Err(Error::InvalidSvg),
+        };
+        shapes.push(Shape { path, fill_rule });
+    }
+    Ok(shapes)
+}
+
+#[cfg(feature = "svg-import")]
+// name="value" pairs
+type Attributes<'a> = Vec<(&'a str, &'a str)>;
+
+#[cfg(feature = "svg-import")]
+// the attributes of a tag, and what follows its closing '>'
+fn attributes(tag: &str) -> Result<(Attributes<'_>, &str), Error> {
+    let mut attributes = Vec::new();
+    let mut rest = tag;
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("/>").or(rest.strip_prefix('>')) {
+            return Ok((attributes, after));
+        }
+        let (name, after) = rest.split_once('=').ok_or(Error::InvalidSvg)?;
+        let after = after.trim_start();
+        let quote = after.chars().next().filter(|&c| c == '"' || c == '\'');
+        let quote = quote.ok_or(Error::InvalidSvg)?;
+        let (value, after) = after[1..].split_once(quote).ok_or(Error::InvalidSvg)?;
+        attributes.push((name.trim(), value));
+        rest = after;
+    }
+}
+
+#[cfg(feature = "svg-import")]
+/// Read the `points` attribute of a `<polygon>` or `<polyline>` into flat
+/// x and y pairs.
+pub fn parse_points(points: &str) -> Result<Vec<f64>, Error> {
+    let mut tokens = Tokens::new(points);
+    let mut vertices = Vec::new();
+    while !tokens.done() {
+        vertices.push(tokens.number()?);
+    }
+    match vertices.len() % 2 {
+        0 => Ok(vertices),
+        _ => Err(Error::InvalidSvg),
+    }
+}
+
+#[cfg(feature = "svg-import")]
+/// Read SVG path data, the `d` attribute of a `<path>`, flattening curves
+/// and arcs to within `tolerance`. Takes every command, absolute and
+/// relative, with the same shorthands SVG allows: repeated commands left
+/// out, and numbers and arc flags run together where that is unambiguous.
+pub fn parse_path_data(d: &str, tolerance: f64) -> Result<Path, Error> {
+    let mut builder = PathBuilder::new(tolerance);
+    let mut tokens = Tokens::new(d);
+    let (mut current, mut start) = ([0.0; 2], [0.0; 2]);
+    // the last control point of a cubic or quadratic curve, for the next
+    // smooth curve to mirror
+    let (mut cubic, mut quad): (Option<[f64; 2]>, Option<[f64; 2]>) = (None, None);
+    let mut previous: Option<char> = None;
+    while !tokens.done() {
+        let command = match (tokens.command(), previous) {
+            (Some(command), _) => command,
+            // a repeated command, except that more pairs after a move are
+            // lines
+            (None, Some('M')) => 'L',
+            (None, Some('m')) => 'l',
+            (None, Some(command)) if !matches!(command, 'Z' | 'z') => command,
+            _ => return Err(Error::InvalidSvg),
+        };
+        if previous.is_none() && !matches!(command, 'M' | 'm') {
+            return Err(Error::InvalidSvg);
+        }
+        let base = match command.is_ascii_lowercase() {
+            true => current,
+            false => [0.0; 2],
+        };
+        let point = |tokens: &mut Tokens| -> Result<[f64; 2], Error> {
+            Ok([base[0] + tokens.number()?, base[1] + tokens.number()?])
+        };
+        let mirror = |control: Option<[f64; 2]>| match control {
+            Some(c) => [2.0 * current[0] - c[0], 2.0 * current[1] - c[1]],
+            None => current,
+        };
+        let (mut next_cubic, mut next_quad) = (None, None);
+        match command.to_ascii_uppercase() {
+            'M' => {
+                current = point(&mut tokens)?;
+                start = current;
+                builder.move_to(current[0], current[1]);
+            }
+            'L' => {
+                current = point(&mut tokens)?;
+                builder.line_to(current[0], current[1]);
+            }
+            'H' => {
+                current[0] = base[0] + tokens.number()?;
+                builder.line_to(current[0], current[1]);
+            }
+            'V' => {
+                current[1] = base[1] + tokens.number()?;
+                builder.line_to(current[0], current[1]);
+            }
+            'C' | 'S' => {
+                let c1 = match command.to_ascii_uppercase() {
+                    'C' => point(&mut tokens)?,
+                    _ => mirror(cubic),
+                };
+                let (c2, p) = (point(&mut tokens)?, point(&mut tokens)?);
+                builder.cubic_to(c1[0], c1[1], c2[0], c2[1], p[0], p[1]);
+                (current, next_cubic) = (p, Some(c2));
+            }
+            'Q' | 'T' => {
+                let c = match command.to_ascii_uppercase() {
+                    'Q' => point(&mut tokens)?,
+                    _ => mirror(quad),
+                };
+                let p = point(&mut tokens)?;
+                builder.quad_to(c[0], c[1], p[0], p[1]);
+                (current, next_quad) = (p, Some(c));
+            }
+            'A' => {
+                let (rx, ry, rotation) = (tokens.number()?, tokens.number()?, tokens.number()?);
+                let (large_arc, sweep) = (tokens.flag()?, tokens.flag()?);
+                let p = point(&mut tokens)?;
+                builder.arc_to(rx, ry, rotation.to_radians(), large_arc, sweep, p[0], p[1]);
+                current = p;
+            }
+            'Z' => {
+                builder.close();
+                current = start;
+            }
+            _ => return Err(Error::InvalidSvg),
+        }
+        (cubic, quad) = (next_cubic, next_quad);
+        previous = Some(command);
+    }
+    Ok(builder.build())
+}
+
+#[cfg(feature = "svg-import")]
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+#[cfg(feature = "svg-import")]
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str) -> Self {
+        Tokens { rest: text }
+    }
+
+    // skip whitespace and commas
+    fn done(&mut self) -> bool {
+        self.rest = self
+            .rest
+            .trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        self.rest.is_empty()
+    }
+
+    fn command(&mut self) -> Option<char> {
+        self.done();
+        let c = self.rest.chars().next().filter(char::is_ascii_alphabetic)?;
+        self.rest = &self.rest[1..];
+        Some(c)
+    }
+
+    // a number ends where a character can no longer continue it, so that
+    // "1-2" and ".5.5" are two numbers each
+    fn number(&mut self) -> Result<f64, Error> {
+        self.done();
+        let bytes = self.rest.as_bytes();
+        let mut end = 0;
+        if matches!(bytes.first(), Some(b'+' | b'-')) {
+            end += 1;
+        }
+        let digits = |from: usize| {
+            from + bytes[from..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+        };
+        end = digits(end);
+        if bytes.get(end) == Some(&b'.') {
+            end = digits(end + 1);
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let sign = matches!(bytes.get(end + 1), Some(b'+' | b'-')) as usize;
+            if bytes.get(end + 1 + sign).is_some_and(u8::is_ascii_digit) {
+                end = digits(end + 1 + sign);
+            }
+        }
+        let (number, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        number.parse().map_err(|_| Error::InvalidSvg)
+    }
+
+    // an arc flag, which needs nothing after it
+    fn flag(&mut self) -> Result<bool, Error> {
+        self.done();
+        let flag = match self.rest.chars().next() {
+            Some('0') => false,
+            Some('1') => true,
+            _ => return Err(Error::InvalidSvg),
+        };
+        self.rest = &self.rest[1..];
+        Ok(flag)
+    }
+}
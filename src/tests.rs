@@ -219,11 +219,47 @@ fn test_point_in_triangle() {
 
 #[test]
 fn test_signed_area() {
-    let vertices1 = Vertices(&[0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0]);
-    let vertices2 = Vertices(&[1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0]);
-    let a1 = vertices1.signed_area(0, 4);
-    let a2 = vertices2.signed_area(0, 4);
+    let vertices1 = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+    let vertices2 = [1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0];
+    let a1 = signed_area(vertices1.chunks(2));
+    let a2 = signed_area(vertices2.chunks(2));
     assert!(a1 == -a2);
+    assert_eq!(a1, -1.0);
+    assert_eq!(signed_area([[0.0, 0.0], [2.0, 0.0], [0.0, 2.0]]), 2.0);
+    assert_eq!(signed_area(Vec::<[f64; 2]>::new()), 0.0);
+}
+
+#[test]
+fn test_zorder() {
+    let zorder = |x: f64, y: f64, invsize: f64| Coord { x, y }.zorder(invsize).unwrap();
+    assert_eq!(zorder(0.0, 0.0, 1.0), 0);
+    assert_eq!(zorder(1.0, 0.0, 1.0), 0b01);
+    assert_eq!(zorder(0.0, 1.0, 1.0), 0b10);
+    assert_eq!(zorder(3.0, 5.0, 1.0), 0b100111);
+
+    // the narrow grid keeps the keys of the original 15 bit hash
+    let narrow = calc_invsize(Coord { x: 0.0, y: 0.0 }, Coord { x: 1.0, y: 1.0 }, false);
+    assert_eq!(zorder(1.0, 1.0, narrow), (1 << 30) - 1);
+    let wide = calc_invsize(Coord { x: 0.0, y: 0.0 }, Coord { x: 1.0, y: 1.0 }, true);
+    assert_eq!(zorder(1.0, 1.0, wide), u64::MAX);
+    assert!(zorder(0.5, 0.5, wide) < zorder(0.5, 0.5 + 1e-9, wide));
+    assert_eq!(zorder(0.5, 0.5, narrow), zorder(0.5, 0.5 + 1e-9, narrow));
+}
+
+#[test]
+fn test_wide_zorder() {
+    // a fine circle next to a long spike crowds into a few cells of the
+    // narrow grid
+    let n = 5000;
+    let mut vertices = vec![1e5, 0.0, 1e5, 1e-3];
+    for i in 1..n {
+        let a = i as f64 / n as f64 * std::f64::consts::TAU;
+        vertices.extend([a.cos(), a.sin()]);
+    }
+    assert!(n > EarcutOptions::default().wide_zorder_threshold);
+    let triangles = earcut(&vertices, &[], DIM).unwrap();
+    assert_eq!(triangles.len(), 3 * (n - 1));
+    assert!(deviation(&vertices, &[], DIM, &triangles) < 1e-12);
 }
 
 #[test]
@@ -338,19 +374,19 @@ fn test_earcut_linked() {
     let m = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
     let (mut ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
     let mut tris = FinalTriangleIndices::default();
-    earcut_linked_hashed::<0, f32>(&mut ll, 1, &mut tris);
+    earcut_linked_hashed::<0, f32>(&mut ll, 1, &mut tris).unwrap();
     assert!(tris.0.len() == 6);
 
     let m = vec![0.0, 0.0, 0.5, 0.5, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
     let (mut ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
     let mut tris = FinalTriangleIndices::default();
-    earcut_linked_unhashed::<0, f32>(&mut ll, 1, &mut tris);
+    earcut_linked_unhashed::<0, f32>(&mut ll, 1, &mut tris).unwrap();
     assert!(tris.0.len() == 9);
 
     let m = vec![0.0, 0.0, 0.5, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
     let (mut ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
     let mut tris = FinalTriangleIndices::default();
-    earcut_linked_hashed::<0, f32>(&mut ll, 1, &mut tris);
+    earcut_linked_hashed::<0, f32>(&mut ll, 1, &mut tris).unwrap();
     assert!(tris.0.len() == 9);
 }
 
@@ -403,6 +439,58 @@ fn test_intersects_polygon() {
 
 #[test]
 fn test_intersects_itself() {
+    let m = vec![0.0, 0.0, 1.0, 0.0, 0.9, 0.9, 0.0, 1.0];
+    let (ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
+    macro_rules! ti {
+        ($ok:expr,$a:expr,$b:expr,$c:expr,$d:expr) => {
+            assert!($ok == intersects(ll.nodes[$a], ll.nodes[$b], ll.nodes[$c], ll.nodes[$d]));
+        };
+    }
+    // touching at an end counts
+    ti!(true, 1, 2 + 1, 1, 1 + 1);
+    ti!(true, 1, 2 + 1, 1 + 1, 2 + 1);
+    ti!(true, 1, 2 + 1, 2 + 1, 3 + 1);
+    ti!(true, 1, 2 + 1, 3 + 1, 1);
+    ti!(true, 1, 2 + 1, 3 + 1, 1 + 1);
+    ti!(true, 1, 2 + 1, 1 + 1, 3 + 1);
+    ti!(true, 2 + 1, 1, 3 + 1, 1 + 1);
+    ti!(true, 2 + 1, 1, 1 + 1, 3 + 1);
+    ti!(false, 1, 1 + 1, 2 + 1, 3 + 1);
+    ti!(false, 1 + 1, 1, 2 + 1, 3 + 1);
+    ti!(false, 1, 1, 2 + 1, 3 + 1);
+    ti!(false, 1, 1 + 1, 3 + 1, 2 + 1);
+    ti!(false, 1 + 1, 1, 3 + 1, 2 + 1);
+
+    ti!(true, 1, 2 + 1, 2 + 1, 1); // special cases
+    ti!(true, 1, 2 + 1, 1, 2 + 1);
+
+    let m = vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.1, 0.1, 0.9, 1.0, 0.0, 1.0];
+    let (ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
+    // 4 lies on 1-3
+    assert!(intersects(
+        ll.nodes[4],
+        ll.nodes[5],
+        ll.nodes[1],
+        ll.nodes[3]
+    ));
+    assert!(!intersects(
+        ll.nodes[4],
+        ll.nodes[5],
+        ll.nodes[2],
+        ll.nodes[3]
+    ));
+
+    // special case
+    assert!(intersects(
+        ll.nodes[4],
+        ll.nodes[5],
+        ll.nodes[3],
+        ll.nodes[1]
+    ));
+}
+
+#[test]
+fn test_pseudo_intersects() {
     let m = vec![0.0, 0.0, 1.0, 0.0, 0.9, 0.9, 0.0, 1.0];
     let (ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
     macro_rules! ti {
@@ -506,6 +594,66 @@ fn test_find_hole_bridge() {
     assert!(5 == find_hole_bridge(&ll, hole_idx, 1));
     let hole_idx = ll.insert_node(0, Coord { x: 0.2, y: 0.95 }, None);
     assert!(5 == find_hole_bridge(&ll, hole_idx, 1));
+
+    // a hole touching the outer ring at a vertex is bridged there
+    let hole_idx = ll.insert_node(0, Coord { x: 0.1, y: 0.8 }, None);
+    let bridge = find_hole_bridge(&ll, hole_idx, 1);
+    assert!(ll.nodes[bridge].xy_eq(ll.nodes[hole_idx]));
+    let hole_idx = ll.insert_node(0, Coord { x: 0.0, y: 0.0 }, None);
+    assert!(1 == find_hole_bridge(&ll, hole_idx, 1));
+}
+
+#[test]
+fn test_hole_grid() {
+    let m = vec![
+        0.0, 0.0, 0.5, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, -0.1, 0.9, 0.1, 0.8, -0.1, 0.7, 0.1, 0.6,
+        -0.1, 0.5,
+    ];
+    let (mut ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
+    let holes = [
+        (0.5, 0.9),
+        (0.2, 0.1),
+        (0.2, 0.5),
+        (0.2, 0.6),
+        (0.2, 0.65),
+        (0.9, 0.3),
+        // touching a vertex, or a segment
+        (0.1, 0.8),
+        (1.0, 1.0),
+        (1.0, 0.5),
+    ]
+    .map(|(x, y)| ll.insert_node(0, Coord { x, y }, None));
+    let mut grid = HoleGrid::new(&ll);
+    for p in 1..=m.len() / DIM {
+        grid.insert(&ll, p);
+    }
+    for &hole_idx in &holes {
+        assert_eq!(
+            grid.find_hole_bridge(&ll, hole_idx, 1),
+            find_hole_bridge(&ll, hole_idx, 1)
+        );
+    }
+
+    // a tall box with a collinear point halfway up its left edge
+    let m = vec![0.0, 0.0, 1.0, 0.0, 1.0, 10.0, 0.0, 10.0, 0.0, 5.0];
+    let (mut ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
+    let holes = [1.0, 5.0, 7.0].map(|y| ll.insert_node(0, Coord { x: 0.5, y }, None));
+    let mut grid = HoleGrid::new(&ll);
+    for p in 1..=m.len() / DIM {
+        grid.insert(&ll, p);
+    }
+    // once it is dropped, the left edge runs all the way from 0,10 to 0,0
+    // but is listed only in the cells of its upper half under the node
+    // that remains
+    let mid = (1..=5).find(|&p| ll.nodes[p].coord.y == 5.0).unwrap();
+    ll.remove_node(mid);
+    for &hole_idx in &holes {
+        let outer_node = ll.nodes[mid].next_linked_list_node_index;
+        assert_eq!(
+            grid.find_hole_bridge(&ll, hole_idx, outer_node),
+            find_hole_bridge(&ll, hole_idx, outer_node)
+        );
+    }
 }
 
 #[test]
@@ -518,10 +666,11 @@ fn test_eliminate_hole() {
     let holestart = bodyend;
     let holeend = body.len();
     let (mut ll, _) = linked_list(&Vertices(&body), 0, bodyend, true).unwrap();
-    ll.add_contour(&Vertices(&body), holestart, holeend, false);
+    ll.add_contour(&Vertices(&body), holestart, holeend, false)
+        .unwrap();
     assert!(cycle_len(&ll, 1) == 4);
     assert!(cycle_len(&ll, 5) == 4);
-    ll.eliminate_hole(holestart / DIM + 1, 1);
+    ll.eliminate_hole(holestart / DIM + 1, 1, None, &[]);
     println!("{}", crate::legacy::dump(&ll));
     println!("{}", cycle_len(&ll, 1));
     println!("{}", cycle_len(&ll, 7));
@@ -532,11 +681,12 @@ fn test_eliminate_hole() {
     body.extend(hole);
     let holestart = bodyend;
     let holeend = body.len();
-    ll.add_contour(&Vertices(&body), holestart, holeend, false);
+    ll.add_contour(&Vertices(&body), holestart, holeend, false)
+        .unwrap();
     assert!(cycle_len(&ll, 1) == 10);
     assert!(cycle_len(&ll, 5) == 10);
     assert!(cycle_len(&ll, 11) == 4);
-    ll.eliminate_hole(11, 2);
+    ll.eliminate_hole(11, 2, None, &[]);
     assert!(!cycle_len(&ll, 1) != 10);
     assert!(!cycle_len(&ll, 1) != 10);
     assert!(!cycle_len(&ll, 5) != 10);
@@ -557,14 +707,16 @@ fn test_cycle_len() {
     let holestart = bodyend;
     let holeend = body.len();
     let (mut ll, _) = linked_list(&Vertices(&body), 0, bodyend, true).unwrap();
-    ll.add_contour(&Vertices(&body), holestart, holeend, false);
+    ll.add_contour(&Vertices(&body), holestart, holeend, false)
+        .unwrap();
 
     let hole = vec![0.2, 0.2, 0.8, 0.2, 0.8, 0.8];
     let bodyend = body.len();
     body.extend(hole);
     let holestart = bodyend;
     let holeend = body.len();
-    ll.add_contour(&Vertices(&body), holestart, holeend, false);
+    ll.add_contour(&Vertices(&body), holestart, holeend, false)
+        .unwrap();
 
     dlog!(5, "{}", crate::legacy::dump(&ll));
     dlog!(5, "{}", cycles_report(&ll));
@@ -580,14 +732,16 @@ fn test_cycles_report() {
     let holestart = bodyend;
     let holeend = body.len();
     let (mut ll, _) = linked_list(&Vertices(&body), 0, bodyend, true).unwrap();
-    ll.add_contour(&Vertices(&body), holestart, holeend, false);
+    ll.add_contour(&Vertices(&body), holestart, holeend, false)
+        .unwrap();
 
     let hole = vec![0.2, 0.2, 0.8, 0.2, 0.8, 0.8];
     let bodyend = body.len();
     body.extend(hole);
     let holestart = bodyend;
     let holeend = body.len();
-    ll.add_contour(&Vertices(&body), holestart, holeend, false);
+    ll.add_contour(&Vertices(&body), holestart, holeend, false)
+        .unwrap();
 
     dlog!(5, "{}", crate::legacy::dump(&ll));
     dlog!(5, "{}", cycles_report(&ll));
@@ -605,7 +759,7 @@ fn test_eliminate_holes() {
     body.extend(hole1);
     body.extend(hole2);
 
-    eliminate_holes(&mut ll, &Vertices(&body), &hole_indices, 0);
+    eliminate_holes(&mut ll, &Vertices(&body), &hole_indices, 0).unwrap();
 }
 
 #[test]
@@ -638,7 +792,7 @@ fn test_split_earcut() {
     let (mut ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
     let start = 1;
     let mut triangles = FinalTriangleIndices::default();
-    split_earcut(&mut ll, start, &mut triangles);
+    split_earcut(&mut ll, start, &mut triangles).unwrap();
     assert!(triangles.0.len() == 6);
     assert!(ll.nodes.len() == 7);
 
@@ -649,7 +803,7 @@ fn test_split_earcut() {
     let (mut ll, _) = linked_list(&Vertices(&m), 0, m.len(), true).unwrap();
     let start = 1;
     let mut triangles = FinalTriangleIndices::default();
-    split_earcut(&mut ll, start, &mut triangles);
+    split_earcut(&mut ll, start, &mut triangles).unwrap();
     assert!(ll.nodes.len() == 13);
 }
 
@@ -702,6 +856,25 @@ fn test_iss45() {
     assert!(triangles.len() > 4);
 }
 
+#[test]
+fn test_holes_touching_at_leftmost() {
+    // both holes have their leftmost point at 2,5
+    let data = vec![
+        vec![
+            vec![0.0, 0.0],
+            vec![10.0, 0.0],
+            vec![10.0, 10.0],
+            vec![0.0, 10.0],
+        ],
+        vec![vec![2.0, 5.0], vec![4.0, 8.0], vec![4.0, 6.0]],
+        vec![vec![2.0, 5.0], vec![4.0, 4.0], vec![4.0, 2.0]],
+    ];
+    let (coords, hole_indices, dims) = crate::legacy::flatten(&data);
+    let triangles = earcut(&coords, &hole_indices, dims).unwrap();
+    assert_eq!(triangles.len(), 10 * 3);
+    assert_eq!(deviation(&coords, &hole_indices, dims, &triangles), 0.0);
+}
+
 #[test]
 fn test_infinite_loop_bug() {
     let coords = [
@@ -719,5 +892,5 @@ fn test_infinite_loop_bug() {
         -1358303.0608723268,
     ];
     let hole_indices = [2, 4];
-    earcut(&coords, &hole_indices, DIM);
+    earcut(&coords, &hole_indices, DIM).unwrap();
 }
@@ -0,0 +1,201 @@
+//! Checking a triangulation against the polygon it was made from.
+
+use crate::geom::{bounds, orient, signed_area, Edge, Grid, Point};
+use crate::{Error, Float};
+
+/// What `verify` found. Triangles are numbered by their position in the
+/// earcut output (triangle `i` is `triangles[3 * i..3 * i + 3]`).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Verification {
+    /// area of the polygon, holes taken out
+    pub polygon_area: f64,
+    /// sum of the areas of the triangles
+    pub triangles_area: f64,
+    /// relative difference between the two areas, as `deviation` gives it
+    pub deviation: f64,
+    /// triangles wound clockwise (with y up), where earcut winds them all
+    /// counterclockwise
+    pub reversed: Vec<usize>,
+    /// triangles covering some of the outside of the polygon, holes
+    /// included
+    pub outside: Vec<usize>,
+    /// triangles covering some of another triangle
+    pub overlapping: Vec<usize>,
+}
+
+impl Verification {
+    /// Whether the triangles cover the polygon once over: none of them is
+    /// reversed, outside or overlapping, and the areas differ by no more
+    /// than `max_deviation`.
+    pub fn is_valid(&self, max_deviation: f64) -> bool {
+        self.deviation <= max_deviation
+            && self.reversed.is_empty()
+            && self.outside.is_empty()
+            && self.overlapping.is_empty()
+    }
+
+    /// Every triangle found wanting, in order; e.g. for
+    /// `SvgOptions::problems`.
+    pub fn problems(&self) -> Vec<usize> {
+        let mut problems = [&self.reversed[..], &self.outside, &self.overlapping].concat();
+        problems.sort_unstable();
+        problems.dedup();
+        problems
+    }
+}
+
+/// Check the triangles `earcut` made of a polygon. Takes any number of
+/// dimensions, looking at x and y only.
+///
+/// Triangles of zero area, give or take rounding, cover nothing, so they
+/// are never reversed, outside or overlapping.
+pub fn verify<T: Float>(
+    vertices: &[T],
+    hole_indices: &[usize],
+    dims: usize,
+    triangles: &[usize],
+) -> Result<Verification, Error> {
+    if dims < 2 || vertices.len() % dims != 0 || triangles.len() % 3 != 0 {
+        return Err(Error::Unknown);
+    }
+    let points = vertices
+        .chunks(dims)
+        .map(|v| Some([v[0].to_f64()?, v[1].to_f64()?]))
+        .collect::<Option<Vec<Point>>>()
+        .ok_or(Error::Unknown)?;
+
+    let mut starts = vec![0];
+    starts.extend(hole_indices);
+    starts.push(points.len());
+    let mut rings = Vec::with_capacity(starts.len() - 1);
+    for bounds in starts.windows(2) {
+        rings.push(points.get(bounds[0]..bounds[1]).ok_or(Error::Unknown)?);
+    }
+    let triangles = triangles
+        .chunks(3)
+        .map(|t| Some([*points.get(t[0])?, *points.get(t[1])?, *points.get(t[2])?]))
+        .collect::<Option<Vec<Triangle>>>()
+        .ok_or(Error::Unknown)?;
+
+    let ring_areas = rings.iter().map(|ring| signed_area(*ring).abs());
+    let polygon_area = ring_areas
+        .enumerate()
+        .map(|(i, a)| if i == 0 { a } else { -a })
+        .sum::<f64>();
+    let triangles_area = triangles.iter().map(|t| signed_area(t).abs()).sum::<f64>();
+    let deviation = match polygon_area == 0.0 && triangles_area == 0.0 {
+        true => 0.0,
+        false => ((triangles_area - polygon_area) / polygon_area).abs(),
+    };
+
+    let reversed = (0..triangles.len())
+        .filter(|&i| !degenerate(&triangles[i]) && signed_area(triangles[i]) < 0.0)
+        .collect();
+
+    let edges = rings
+        .iter()
+        .flat_map(|ring| ring.iter().zip(ring.iter().cycle().skip(1)))
+        .map(|(&a, &b)| [a, b])
+        .collect::<Vec<Edge>>();
+    let (min, max) = bounds(&points);
+    let mut edge_grid = Grid::new(min, max, edges.len());
+    for (i, edge) in edges.iter().enumerate() {
+        edge_grid.insert(bounds(edge), i);
+    }
+    let mut triangle_grid = Grid::new(min, max, triangles.len());
+    for (i, triangle) in triangles.iter().enumerate() {
+        triangle_grid.insert(bounds(triangle), i);
+    }
+
+    // a triangle is outside if its centroid is, or if the boundary of the
+    // polygon passes through it: crossing an edge, or with a vertex inside,
+    // or else running from one side to another, through its middle
+    let mut outside = Vec::new();
+    for (i, t) in triangles.iter().enumerate() {
+        if degenerate(t) {
+            continue;
+        }
+        let centroid = [
+            (t[0][0] + t[1][0] + t[2][0]) / 3.0,
+            (t[0][1] + t[1][1] + t[2][1]) / 3.0,
+        ];
+        let crossed = edge_grid.query(bounds(t)).any(|e| {
+            let [a, b] = edges[e];
+            let middle = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0];
+            let along_side = (0..3).any(|k| {
+                let side = [t[k], t[(k + 1) % 3]];
+                on_line(side, a) && on_line(side, b)
+            });
+            strictly_inside(t, a)
+                || (!along_side && strictly_inside(t, middle))
+                || (0..3).any(|k| crosses([t[k], t[(k + 1) % 3]], [a, b]))
+        });
+        if crossed || !edge_grid.contains(&edges, centroid) {
+            outside.push(i);
+        }
+    }
+
+    let mut overlapping = vec![false; triangles.len()];
+    let mut seen = vec![usize::MAX; triangles.len()];
+    for (i, t) in triangles.iter().enumerate() {
+        if degenerate(t) {
+            continue;
+        }
+        for j in triangle_grid.query(bounds(t)) {
+            if j <= i || seen[j] == i || degenerate(&triangles[j]) {
+                continue;
+            }
+            seen[j] = i;
+            if overlap(t, &triangles[j]) {
+                overlapping[i] = true;
+                overlapping[j] = true;
+            }
+        }
+    }
+    let overlapping = (0..triangles.len()).filter(|&i| overlapping[i]).collect();
+
+    Ok(Verification {
+        polygon_area,
+        triangles_area,
+        deviation,
+        reversed,
+        outside,
+        overlapping,
+    })
+}
+
+type Triangle = [Point; 3];
+
+// whether the segments cross at a point inside both
+fn crosses(s: Edge, t: Edge) -> bool {
+    orient(s[0], s[1], t[0]) * orient(s[0], s[1], t[1]) < 0.0
+        && orient(t[0], t[1], s[0]) * orient(t[0], t[1], s[1]) < 0.0
+}
+
+// whether p is on the line through the edge, give or take rounding
+fn on_line(e: Edge, p: Point) -> bool {
+    let length = |a: Point, b: Point| (b[0] - a[0]).hypot(b[1] - a[1]);
+    orient(e[0], e[1], p).abs() <= 1e-9 * length(e[0], e[1]) * length(e[0], p)
+}
+
+fn degenerate(t: &Triangle) -> bool {
+    on_line([t[0], t[1]], t[2])
+}
+
+fn strictly_inside(t: &Triangle, p: Point) -> bool {
+    let sign = signed_area(t).signum();
+    (0..3).all(|k| sign * orient(t[k], t[(k + 1) % 3], p) > 0.0)
+}
+
+// whether the insides of two triangles meet: no edge of either has the
+// whole of the other on its outer side
+fn overlap(t: &Triangle, u: &Triangle) -> bool {
+    let separates = |t: &Triangle, u: &Triangle| {
+        let sign = signed_area(t).signum();
+        (0..3).any(|k| {
+            u.iter()
+                .all(|&p| sign * orient(t[k], t[(k + 1) % 3], p) <= 0.0)
+        })
+    };
+    !separates(t, u) && !separates(u, t)
+}
@@ -0,0 +1,132 @@
+//! Reading polygons from Well-Known Binary.
+//!
+//! Understands ISO WKB and PostGIS EWKB `Polygon` and `MultiPolygon`
+//! geometries in either byte order, including their Z, M and ZM variants.
+//! Every polygon comes out in the same form as `flatten` gives: flat
+//! vertices, hole indices and dimensions, with any z or m values kept in
+//! the vertex stride.
+
+use crate::{Error, FlatPolygon};
+
+const POLYGON: u32 = 3;
+const MULTIPOLYGON: u32 = 6;
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Parse a WKB or EWKB `Polygon` or `MultiPolygon` into earcut input, one
+/// entry per polygon.
+pub fn parse_polygons(wkb: &[u8]) -> Result<Vec<FlatPolygon<f64>>, Error> {
+    let mut reader = Reader {
+        bytes: wkb,
+        little_endian: true,
+    };
+    let mut polygons = Vec::new();
+    match reader.header()? {
+        (POLYGON, dims) => polygons.push(reader.polygon(dims)?),
+        (MULTIPOLYGON, _) => {
+            for _ in 0..reader.u32()? {
+                match reader.header()? {
+                    (POLYGON, dims) => polygons.push(reader.polygon(dims)?),
+                    _ => return Err(Error::InvalidWkb),
+                }
+            }
+        }
+        _ => return Err(Error::InvalidWkb),
+    }
+    polygons.retain(|(vertices, _, _)| !vertices.is_empty());
+    match reader.bytes.is_empty() {
+        true => Ok(polygons),
+        false => Err(Error::InvalidWkb),
+    }
+}
+
+/// Same as `parse_polygons`, for hex encoded (E)WKB as PostGIS prints it.
+pub fn parse_hex_polygons(hex: &str) -> Result<Vec<FlatPolygon<f64>>, Error> {
+    let hex = hex.trim().as_bytes();
+    if hex.len() % 2 == 1 {
+        return Err(Error::InvalidWkb);
+    }
+    let wkb = hex
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).map_err(|_| Error::InvalidWkb)?;
+            u8::from_str_radix(pair, 16).map_err(|_| Error::InvalidWkb)
+        })
+        .collect::<Result<Vec<u8>, Error>>()?;
+    parse_polygons(&wkb)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        if self.bytes.len() < N {
+            return Err(Error::InvalidWkb);
+        }
+        let (taken, rest) = self.bytes.split_at(N);
+        self.bytes = rest;
+        Ok(taken.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take()?;
+        Ok(match self.little_endian {
+            true => u32::from_le_bytes(bytes),
+            false => u32::from_be_bytes(bytes),
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, Error> {
+        let bytes = self.take()?;
+        Ok(match self.little_endian {
+            true => f64::from_le_bytes(bytes),
+            false => f64::from_be_bytes(bytes),
+        })
+    }
+
+    // byte order and geometry type; returns the base type and dimensions
+    fn header(&mut self) -> Result<(u32, usize), Error> {
+        self.little_endian = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return Err(Error::InvalidWkb),
+        };
+        let code = self.u32()?;
+        if code & EWKB_SRID != 0 {
+            self.u32()?;
+        }
+        let ewkb_dims = 2 + (code & EWKB_Z != 0) as usize + (code & EWKB_M != 0) as usize;
+        let iso_code = code & 0x0fff_ffff;
+        let iso_dims = match iso_code / 1000 {
+            0 => 2,
+            1 | 2 => 3,
+            3 => 4,
+            _ => return Err(Error::InvalidWkb),
+        };
+        match (ewkb_dims, iso_dims) {
+            (2, dims) | (dims, 2) => Ok((iso_code % 1000, dims)),
+            _ => Err(Error::InvalidWkb),
+        }
+    }
+
+    fn polygon(&mut self, dims: usize) -> Result<FlatPolygon<f64>, Error> {
+        let mut vertices = Vec::new();
+        let mut hole_indices = Vec::new();
+        for ring in 0..self.u32()? {
+            if ring > 0 {
+                hole_indices.push(vertices.len() / dims);
+            }
+            for _ in 0..self.u32()? {
+                for _ in 0..dims {
+                    vertices.push(self.f64()?);
+                }
+            }
+        }
+        Ok((vertices, hole_indices, dims))
+    }
+}
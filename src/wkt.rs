@@ -0,0 +1,180 @@
+//! Reading polygons from Well-Known Text.
+//!
+//! Understands `POLYGON` and `MULTIPOLYGON`, with or without `Z`, `M` or
+//! `ZM`, and an optional EWKT `SRID=...;` prefix. Every polygon comes out
+//! in the same form as `flatten` gives: flat vertices, hole indices and
+//! dimensions, with any z or m values kept in the vertex stride.
+
+use crate::{Error, FlatPolygon};
+
+/// Parse a `POLYGON` or `MULTIPOLYGON` into earcut input, one entry per
+/// polygon.
+pub fn parse_polygons(wkt: &str) -> Result<Vec<FlatPolygon<f64>>, Error> {
+    let mut tokens = Tokens::new(wkt);
+    let mut tag = tokens.word()?;
+    if tag.eq_ignore_ascii_case("SRID") {
+        tokens.expect('=')?;
+        tokens.number()?;
+        tokens.expect(';')?;
+        tag = tokens.word()?;
+    }
+
+    // the dimension may be glued to the tag, as in POLYGONZ
+    let upper = tag.to_ascii_uppercase();
+    let (multi, suffix) = match upper.strip_prefix("MULTIPOLYGON") {
+        Some(suffix) => (true, suffix.to_string()),
+        None => match upper.strip_prefix("POLYGON") {
+            Some(suffix) => (false, suffix.to_string()),
+            None => return Err(Error::InvalidWkt),
+        },
+    };
+    let suffix = match suffix.is_empty() {
+        true => tokens.dimension_word(),
+        false => suffix,
+    };
+    let mut dims = match suffix.as_str() {
+        "" => None,
+        "Z" | "M" => Some(3),
+        "ZM" => Some(4),
+        _ => return Err(Error::InvalidWkt),
+    };
+
+    let mut polygons = Vec::new();
+    match multi {
+        false => {
+            if let Some(polygon) = tokens.polygon(&mut dims)? {
+                polygons.push(polygon);
+            }
+        }
+        true => {
+            if !tokens.empty()? {
+                tokens.expect('(')?;
+                loop {
+                    if let Some(polygon) = tokens.polygon(&mut dims)? {
+                        polygons.push(polygon);
+                    }
+                    if !tokens.separator()? {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    match tokens.peek() {
+        None => Ok(polygons),
+        Some(_) => Err(Error::InvalidWkt),
+    }
+}
+
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(wkt: &'a str) -> Self {
+        Tokens { rest: wkt }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.rest = self.rest.trim_start();
+        self.rest.chars().next()
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        self.rest = self.rest.trim_start();
+        let end = self.rest.find(|c| !f(c)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), Error> {
+        match self.peek() == Some(c) {
+            true => {
+                self.rest = &self.rest[1..];
+                Ok(())
+            }
+            false => Err(Error::InvalidWkt),
+        }
+    }
+
+    fn word(&mut self) -> Result<&'a str, Error> {
+        match self.take_while(|c| c.is_ascii_alphabetic()) {
+            "" => Err(Error::InvalidWkt),
+            word => Ok(word),
+        }
+    }
+
+    // an optional Z, M or ZM after the geometry tag
+    fn dimension_word(&mut self) -> String {
+        let rest = self.rest;
+        let word = self.take_while(|c| c.is_ascii_alphabetic());
+        match word.to_ascii_uppercase().as_str() {
+            upper @ ("Z" | "M" | "ZM") => upper.to_string(),
+            _ => {
+                self.rest = rest;
+                String::new()
+            }
+        }
+    }
+
+    fn empty(&mut self) -> Result<bool, Error> {
+        match self.peek() {
+            Some('(') => Ok(false),
+            _ => match self.word()?.eq_ignore_ascii_case("EMPTY") {
+                true => Ok(true),
+                false => Err(Error::InvalidWkt),
+            },
+        }
+    }
+
+    // a ',' before another element, or the ')' closing the list
+    fn separator(&mut self) -> Result<bool, Error> {
+        match self.peek() {
+            Some(',') => self.expect(',').map(|_| true),
+            _ => self.expect(')').map(|_| false),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, Error> {
+        self.take_while(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-' || c == '+')
+            .parse()
+            .map_err(|_| Error::InvalidWkt)
+    }
+
+    fn polygon(&mut self, dims: &mut Option<usize>) -> Result<Option<FlatPolygon<f64>>, Error> {
+        if self.empty()? {
+            return Ok(None);
+        }
+        let mut vertices = Vec::new();
+        let mut hole_indices = Vec::new();
+        self.expect('(')?;
+        loop {
+            self.ring(dims, &mut vertices)?;
+            if !self.separator()? {
+                break;
+            }
+            hole_indices.push(vertices.len() / dims.unwrap_or(2));
+        }
+        Ok(Some((vertices, hole_indices, dims.unwrap_or(2))))
+    }
+
+    fn ring(&mut self, dims: &mut Option<usize>, vertices: &mut Vec<f64>) -> Result<(), Error> {
+        self.expect('(')?;
+        loop {
+            let start = vertices.len();
+            while !matches!(self.peek(), Some(',') | Some(')') | None) {
+                vertices.push(self.number()?);
+            }
+            // without Z or M, the first position decides the dimensions
+            if *dims.get_or_insert(vertices.len() - start) != vertices.len() - start
+                || vertices.len() - start < 2
+            {
+                return Err(Error::InvalidWkt);
+            }
+            if !self.separator()? {
+                return Ok(());
+            }
+        }
+    }
+}
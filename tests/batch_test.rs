@@ -0,0 +1,119 @@
+extern crate earcutr;
+
+use earcutr::batch::{
+    earcut_each, earcut_merged, earcut_parallel, earcut_parallel_with_piece_size,
+    DEFAULT_PIECE_SIZE,
+};
+use earcutr::FlatPolygon;
+
+mod common;
+
+fn load_fixtures() -> Vec<FlatPolygon<f64>> {
+    common::fixture_names()
+        .iter()
+        .map(|name| earcutr::flatten(&common::load_fixture(name)))
+        .collect()
+}
+
+#[test]
+fn test_earcut_each() {
+    let polygons = load_fixtures();
+    let each = earcut_each(&polygons).unwrap();
+    assert_eq!(each.len(), polygons.len());
+    for ((vertices, hole_indices, dims), triangles) in polygons.iter().zip(&each) {
+        assert_eq!(
+            triangles,
+            &earcutr::earcut(vertices, hole_indices, *dims).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_thread_count_does_not_matter() {
+    let polygons = load_fixtures();
+    let run = |threads| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(|| earcut_merged(&polygons).unwrap())
+    };
+    let single = run(1);
+    assert_eq!(run(3), single);
+    assert_eq!(run(8), single);
+}
+
+#[test]
+fn test_earcut_merged() {
+    let square = |x: f64| (vec![x, 0., x + 1., 0., x + 1., 1., x, 1.], vec![], 2);
+    let polygons = vec![square(0.), (vec![], vec![], 2), square(5.)];
+    let triangles = earcut_merged(&polygons).unwrap();
+    let first = earcutr::earcut(&polygons[0].0, &[], 2).unwrap();
+    let mut expected = first.clone();
+    expected.extend(first.iter().map(|i| i + 4));
+    assert_eq!(triangles, expected);
+
+    // 3d input keeps counting vertices, not coordinates
+    let polygons = vec![
+        (vec![0., 0., 9., 1., 0., 9., 1., 1., 9.], vec![], 3),
+        (vec![0., 0., 9., 1., 0., 9., 1., 1., 9.], vec![], 3),
+    ];
+    assert_eq!(earcut_merged(&polygons).unwrap().len(), 6);
+    assert!(earcut_merged(&polygons).unwrap().iter().any(|&i| i >= 3));
+
+    let bad = vec![square(0.), (vec![0., 0., 1.], vec![], 2)];
+    assert!(earcut_each(&bad).is_err());
+    assert!(earcut_merged(&bad).is_err());
+}
+
+#[test]
+fn test_earcut_parallel_deviation() {
+    for (vertices, hole_indices, dims) in load_fixtures() {
+        let sequential = earcutr::earcut(&vertices, &hole_indices, dims).unwrap();
+        let expected = earcutr::deviation(&vertices, &hole_indices, dims, &sequential);
+
+        let triangles = earcut_parallel(&vertices, &hole_indices, dims).unwrap();
+        if vertices.len() / dims <= DEFAULT_PIECE_SIZE {
+            assert_eq!(triangles, sequential);
+        }
+        let deviation = earcutr::deviation(&vertices, &hole_indices, dims, &triangles);
+        assert!((deviation - expected).abs() < 1e-12);
+
+        // cutting valid polygons into tiny pieces keeps them exact
+        if expected == 0.0 {
+            let triangles =
+                earcut_parallel_with_piece_size(&vertices, &hole_indices, dims, 16).unwrap();
+            assert!(earcutr::deviation(&vertices, &hole_indices, dims, &triangles) < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_earcut_parallel_cuts() {
+    let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture("water-huge"));
+    let sequential = earcutr::earcut(&vertices, &hole_indices, dims).unwrap();
+    let triangles = earcut_parallel(&vertices, &hole_indices, dims).unwrap();
+    // the polygon was cut, yet covers the same area
+    assert_ne!(triangles, sequential);
+    assert_eq!(
+        earcutr::deviation(&vertices, &hole_indices, dims, &triangles),
+        earcutr::deviation(&vertices, &hole_indices, dims, &sequential)
+    );
+
+    let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture("hilbert"));
+    let triangles = earcut_parallel_with_piece_size(&vertices, &hole_indices, dims, 8).unwrap();
+    assert_eq!(
+        earcutr::deviation(&vertices, &hole_indices, dims, &triangles),
+        0.0
+    );
+
+    let run = |threads| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap()
+            .install(|| earcut_parallel_with_piece_size(&vertices, &hole_indices, dims, 8).unwrap())
+    };
+    assert_eq!(run(1), triangles);
+    assert_eq!(run(4), triangles);
+}
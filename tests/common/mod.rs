@@ -0,0 +1,50 @@
+// helpers shared by the integration tests; not every test uses all of them
+#![allow(dead_code)]
+
+use std::fs;
+
+use serde_json::value::RawValue;
+
+/// The rings of the polygon in tests/fixtures/{name}.json.
+pub fn load_fixture(name: &str) -> Vec<Vec<Vec<f64>>> {
+    let json = fs::read_to_string(format!("tests/fixtures/{}.json", name)).unwrap();
+    let polygons = earcutr::geojson::parse_polygons(&json).unwrap();
+    polygons.into_iter().next().unwrap().rings
+}
+
+/// The same rings as `load_fixture`, with every coordinate rounded to the
+/// nearest f64 the way JSON.parse does it. serde_json's own float parsing
+/// is sometimes off by the last bit, which earcut.js never is.
+pub fn load_fixture_exact(name: &str) -> Vec<Vec<Vec<f64>>> {
+    let json = fs::read_to_string(format!("tests/fixtures/{}.json", name)).unwrap();
+    let rings: Vec<Vec<Vec<&RawValue>>> = serde_json::from_str(&json).unwrap();
+    rings
+        .iter()
+        .map(|ring| {
+            ring.iter()
+                .map(|point| point.iter().map(|c| c.get().parse().unwrap()).collect())
+                .collect()
+        })
+        .collect()
+}
+
+/// The names of all the JSON fixtures, sorted, for `load_fixture`. Other
+/// files and directories in tests/fixtures are left out.
+pub fn fixture_names() -> Vec<String> {
+    let mut names = fs::read_dir("tests/fixtures")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_file() && path.extension().is_some_and(|e| e == "json"))
+        .map(|path| path.file_stem().unwrap().to_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Area of the polygon, after checking that earcut covers it exactly.
+pub fn triangulated_area(vertices: &[f64], hole_indices: &[usize], dims: usize) -> f64 {
+    let triangles = earcutr::earcut(vertices, hole_indices, dims).unwrap();
+    let report = earcutr::verify(vertices, hole_indices, dims, &triangles).unwrap();
+    assert!(report.is_valid(1e-12));
+    report.polygon_area
+}
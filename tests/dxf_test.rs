@@ -0,0 +1,168 @@
+extern crate earcutr;
+
+use std::f64::consts::PI;
+use std::fmt::Write;
+use std::fs;
+
+use earcutr::dxf::{parse_loops, parse_polygons};
+use earcutr::{Error, FlatPolygon};
+
+mod common;
+
+// every ring of every polygon as a closed LWPOLYLINE, on a layer per
+// polygon
+fn to_dxf(polygons: &[FlatPolygon<f64>]) -> String {
+    let mut dxf = String::from("  0\nSECTION\n  2\nENTITIES\n");
+    for (i, (vertices, hole_indices, dims)) in polygons.iter().enumerate() {
+        let mut starts = vec![0];
+        starts.extend(hole_indices);
+        starts.push(vertices.len() / dims);
+        for w in starts.windows(2) {
+            write!(
+                dxf,
+                "  0\nLWPOLYLINE\n  8\nP{i}\n 90\n{}\n 70\n1\n",
+                w[1] - w[0]
+            )
+            .unwrap();
+            for p in vertices[w[0] * dims..w[1] * dims].chunks(*dims) {
+                write!(dxf, " 10\n{}\n 20\n{}\n", p[0], p[1]).unwrap();
+            }
+        }
+    }
+    dxf.push_str("  0\nENDSEC\n  0\nEOF\n");
+    dxf
+}
+
+#[test]
+fn test_plan() {
+    let dxf = fs::read_to_string("tests/fixtures/dxf/plan.dxf").unwrap();
+    let loops = parse_loops(&dxf, 0.01).unwrap();
+    // the walls and the courtyard, then the floor and the column in it;
+    // the open polyline and the polyline of the block are left out
+    let kinds: Vec<(&str, Option<usize>)> =
+        loops.iter().map(|l| (l.layer.as_str(), l.hatch)).collect();
+    assert_eq!(
+        kinds,
+        [
+            ("WALLS", None),
+            ("WALLS", None),
+            ("FLOOR", Some(0)),
+            ("FLOOR", Some(0))
+        ]
+    );
+    // the courtyard's last vertex is its first again
+    assert_eq!(loops[1].vertices.len(), 8);
+    // the bay window reaches a radius above the top wall
+    let top = loops[0].vertices.chunks(2).map(|p| p[1]).fold(0., f64::max);
+    assert_eq!(top, 700.);
+
+    let polygons = parse_polygons(&dxf, 0.01).unwrap();
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[0].1.len(), 1);
+    assert_eq!(polygons[1].1.len(), 1);
+    let walls = 1000. * 600. + PI * 100. * 100. / 2. - 100. * 100.;
+    let floor = 200. * 200. - PI * 50. * 50.;
+    for ((vertices, hole_indices, dims), expected) in polygons.iter().zip([walls, floor]) {
+        let area = common::triangulated_area(vertices, hole_indices, *dims);
+        assert!((area - expected).abs() < 5.);
+    }
+}
+
+#[test]
+fn test_hatch_edges() {
+    let dxf = fs::read_to_string("tests/fixtures/dxf/hatch.dxf").unwrap();
+    let loops = parse_loops(&dxf, 0.01).unwrap();
+    // the spline loop is left out
+    assert_eq!(loops.len(), 4);
+    assert_eq!(loops[0].hatch, Some(0));
+    assert_eq!(loops[1].hatch, Some(1));
+
+    // the arc of the D bulges to the right, clockwise from the top
+    let d = &loops[0].vertices;
+    assert_eq!(d[..4], [500., 100., 500., 300.]);
+    assert!(d.chunks(2).all(|p| p[0] >= 500. - 1e-9));
+    assert!(d.chunks(2).any(|p| (p[0] - 600.).abs() < 1e-6));
+
+    // the ellipse, 100 across its major axis and 50 across its minor
+    let ellipse = &loops[1].vertices;
+    assert!(ellipse.chunks(2).all(|p| {
+        let (x, y) = ((p[0] - 800.) / 100., (p[1] - 450.) / 50.);
+        (x * x + y * y - 1.).abs() < 1e-9
+    }));
+
+    // the square seen from below
+    assert_eq!(loops[2].layer, "MIRRORED");
+    assert_eq!(
+        loops[2].vertices,
+        [-100., 0., -200., 0., -200., 100., -100., 100.]
+    );
+
+    // a wedge of an ellipse 200 by 100, its arc ending 45 degrees round
+    // from the center rather than 45 degrees round the ellipse
+    assert_eq!(loops[3].hatch, Some(2));
+    let wedge = &loops[3].vertices;
+    assert_eq!(wedge[..4], [1000., 0., 1200., 0.]);
+    let end = 200. / 5f64.sqrt();
+    let last = &wedge[wedge.len() - 2..];
+    assert!((last[0] - (1000. + end)).abs() < 1e-9 && (last[1] - end).abs() < 1e-9);
+    assert!(wedge[2..].chunks(2).all(|p| {
+        let (x, y) = ((p[0] - 1000.) / 200., p[1] / 100.);
+        (x * x + y * y - 1.).abs() < 1e-9
+    }));
+
+    let areas: Vec<f64> = parse_polygons(&dxf, 0.01)
+        .unwrap()
+        .iter()
+        .map(|(vertices, hole_indices, dims)| {
+            common::triangulated_area(vertices, hole_indices, *dims)
+        })
+        .collect();
+    assert_eq!(areas.len(), 4);
+    assert!((areas[0] - PI * 100. * 100. / 2.).abs() < 5.);
+    assert!((areas[1] - PI * 100. * 50.).abs() < 5.);
+    assert_eq!(areas[2], 100. * 100.);
+    assert!((areas[3] - 200. * 100. / 2. * 2f64.atan()).abs() < 5.);
+}
+
+#[test]
+fn test_round_trip() {
+    for name in ["plan", "hatch"] {
+        let dxf = fs::read_to_string(format!("tests/fixtures/dxf/{name}.dxf")).unwrap();
+        let polygons = parse_polygons(&dxf, 0.01).unwrap();
+        assert_eq!(parse_polygons(&to_dxf(&polygons), 0.01).unwrap(), polygons);
+    }
+
+    for name in ["building", "dude", "water2"] {
+        let expected = earcutr::flatten(&common::load_fixture(name));
+        let polygons = parse_polygons(&to_dxf(std::slice::from_ref(&expected)), 0.01).unwrap();
+        assert_eq!(polygons.len(), 1, "{name}");
+        // the rings may come back wound the other way
+        let (vertices, hole_indices, dims) = &polygons[0];
+        let read = earcutr::verify(vertices, hole_indices, *dims, &[]).unwrap();
+        let original = earcutr::verify(&expected.0, &expected.1, 2, &[]).unwrap();
+        assert_eq!(hole_indices.len(), expected.1.len(), "{name}");
+        let error = (read.polygon_area - original.polygon_area).abs();
+        assert!(error <= 1e-12 * original.polygon_area, "{name}");
+    }
+}
+
+#[test]
+fn test_errors() {
+    let entities =
+        |body: &str| format!("  0\nSECTION\n  2\nENTITIES\n{body}  0\nENDSEC\n  0\nEOF\n");
+    for dxf in [
+        // a code with no value, and a code that is not a number
+        "  0\nSECTION\n  2".to_string(),
+        "  x\nSECTION\n".to_string(),
+        // a coordinate that is not a number
+        entities("  0\nLWPOLYLINE\n 70\n1\n 10\nwest\n 20\n0\n"),
+        // a y before any x
+        entities("  0\nLWPOLYLINE\n 20\n0\n"),
+        // a hatch cut short, or with an unknown edge type
+        entities("  0\nHATCH\n 91\n1\n 92\n0\n 93\n1\n"),
+        entities("  0\nHATCH\n 91\n1\n 92\n0\n 93\n1\n 72\n9\n 97\n0\n"),
+    ] {
+        assert_eq!(parse_loops(&dxf, 0.01), Err(Error::InvalidDxf), "{dxf}");
+    }
+    assert!(parse_loops("", 0.01).unwrap().is_empty());
+}
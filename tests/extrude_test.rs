@@ -0,0 +1,105 @@
+extern crate earcutr;
+
+use std::collections::HashMap;
+
+use earcutr::extrude::{extrude, extrude_with_heights};
+use earcutr::mesh::Mesh;
+
+fn square_with_hole() -> (Vec<f64>, Vec<usize>) {
+    let vertices = vec![
+        0., 0., 100., 0., 100., 100., 0., 100., 20., 20., 80., 20., 80., 80., 20., 80.,
+    ];
+    (vertices, vec![4])
+}
+
+// every edge is shared by exactly two triangles, walking it in opposite
+// directions
+fn assert_watertight(mesh: &Mesh) {
+    let mut edges = HashMap::new();
+    for t in mesh.triangles.chunks(3) {
+        for k in 0..3 {
+            *edges.entry((t[k], t[(k + 1) % 3])).or_insert(0) += 1;
+        }
+    }
+    for (&(a, b), &count) in &edges {
+        assert_eq!(count, 1, "edge {}-{} used {} times", a, b, count);
+        assert_eq!(edges.get(&(b, a)), Some(&1), "edge {}-{} is open", a, b);
+    }
+}
+
+// divergence theorem, positive when the triangles face outwards
+fn volume(mesh: &Mesh) -> f64 {
+    mesh.triangles
+        .chunks(3)
+        .map(|t| {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| mesh.positions[i]);
+            (a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+                + a[2] * (b[0] * c[1] - b[1] * c[0]))
+                / 6.0
+        })
+        .sum()
+}
+
+#[test]
+fn test_extrude() {
+    let (vertices, holes) = square_with_hole();
+    let mut mesh = extrude(&vertices, &holes, 2, 10.0).unwrap();
+    assert_eq!(mesh.positions.len(), 16);
+    assert_eq!(mesh.positions[8 + 2], [100.0, 100.0, 10.0]);
+    // 8 cap triangles each for roof and floor, 2 per wall
+    assert_eq!(mesh.triangles.len(), 3 * (8 + 8 + 16));
+    assert_watertight(&mesh);
+    assert!((volume(&mesh) - (100.0 * 100.0 - 60.0 * 60.0) * 10.0).abs() < 1e-6);
+
+    mesh.compute_normals();
+    assert_eq!(mesh.normals[0], [0.0, 0.0, 1.0]);
+    assert_eq!(mesh.normals[8], [0.0, 0.0, -1.0]);
+    // the first wall runs along y = 0 and faces -Y
+    assert_eq!(mesh.normals[16], [0.0, -1.0, 0.0]);
+
+    let mut obj = Vec::new();
+    mesh.write_obj(&mut obj).unwrap();
+    let obj = String::from_utf8(obj).unwrap();
+    assert_eq!(obj.lines().filter(|l| l.starts_with("vn ")).count(), 32);
+    assert!(obj
+        .lines()
+        .any(|l| l.starts_with("f ") && l.ends_with("//32")));
+}
+
+#[test]
+fn test_extrude_winding() {
+    // clockwise outer ring, counterclockwise hole, stacked on z = 5
+    let vertices = vec![
+        0., 0., 5., 0., 100., 5., 100., 100., 5., 100., 0., 5., 20., 20., 5., 80., 20., 5., 80.,
+        80., 5., 20., 80., 5.,
+    ];
+    let mesh = extrude(&vertices, &[4], 3, 10.0).unwrap();
+    assert_eq!(mesh.positions[8], [0.0, 0.0, 15.0]);
+    assert_watertight(&mesh);
+    assert!((volume(&mesh) - (100.0 * 100.0 - 60.0 * 60.0) * 10.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_extrude_filtered_points() {
+    // a point halfway along the bottom edge and a corner given twice, in
+    // both the outer ring and the hole, which earcut leaves out
+    let vertices = vec![
+        0., 0., 50., 0., 100., 0., 100., 100., 100., 100., 0., 100., 20., 20., 20., 80., 80., 80.,
+        80., 50., 80., 20., 80., 20.,
+    ];
+    let mesh = extrude(&vertices, &[6], 2, 10.0).unwrap();
+    assert_watertight(&mesh);
+    assert!((volume(&mesh) - (100.0 * 100.0 - 60.0 * 60.0) * 10.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_extrude_with_heights() {
+    let vertices = vec![0., 0., 10., 0., 10., 10., 0., 10.];
+    let mesh = extrude_with_heights(&vertices, &[], 2, &[1., 1., 3., 3.]).unwrap();
+    assert_watertight(&mesh);
+    assert_eq!(mesh.positions[6], [10.0, 10.0, 3.0]);
+    assert!((volume(&mesh) - 200.0).abs() < 1e-6);
+
+    assert!(extrude_with_heights(&vertices, &[], 2, &[1., 1., 3.]).is_err());
+    assert!(extrude(&vertices, &[], 3, 1.0).is_err());
+}
@@ -0,0 +1,276 @@
+extern crate earcutr;
+
+use earcutr::fill::{earcut_valid, fill_polygons, make_valid, tessellate, FillRule};
+
+mod common;
+
+// total area of the triangles, checking that none of them is clockwise
+fn tessellated_area(vertices: &[f64], contour_indices: &[usize], rule: FillRule) -> f64 {
+    let (points, triangles) = tessellate(vertices, contour_indices, 2, rule).unwrap();
+    triangles
+        .chunks(3)
+        .map(|t| {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| [points[2 * i], points[2 * i + 1]]);
+            let area = ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])) / 2.0;
+            assert!(area >= 0.0);
+            area
+        })
+        .sum()
+}
+
+// total area of the polygons, checking that earcut covers each exactly
+fn filled_area(vertices: &[f64], contour_indices: &[usize], rule: FillRule) -> f64 {
+    fill_polygons(vertices, contour_indices, 2, rule)
+        .unwrap()
+        .iter()
+        .map(|(vertices, hole_indices, dims)| {
+            let triangles = earcutr::earcut(vertices, hole_indices, *dims).unwrap();
+            let report = earcutr::verify(vertices, hole_indices, *dims, &triangles).unwrap();
+            assert!(report.deviation < 1e-12);
+            report.polygon_area
+        })
+        .sum()
+}
+
+fn assert_areas(vertices: &[f64], contour_indices: &[usize], expected: [f64; 4]) {
+    let rules = [
+        FillRule::EvenOdd,
+        FillRule::NonZero,
+        FillRule::Positive,
+        FillRule::Negative,
+    ];
+    for (rule, expected) in rules.into_iter().zip(expected) {
+        let area = tessellated_area(vertices, contour_indices, rule);
+        assert!(
+            (area - expected).abs() < 1e-9,
+            "{rule:?}: {area} != {expected}"
+        );
+        let area = filled_area(vertices, contour_indices, rule);
+        assert!(
+            (area - expected).abs() < 1e-9,
+            "{rule:?}: {area} != {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_overlapping_squares() {
+    // both counterclockwise, overlapping in a unit square
+    let vertices = [
+        0., 0., 2., 0., 2., 2., 0., 2., 1., 1., 3., 1., 3., 3., 1., 3.,
+    ];
+    assert_areas(&vertices, &[4], [6.0, 7.0, 7.0, 0.0]);
+
+    // the second one clockwise, so the overlap winds zero times
+    let vertices = [
+        0., 0., 2., 0., 2., 2., 0., 2., 1., 1., 1., 3., 3., 3., 3., 1.,
+    ];
+    assert_areas(&vertices, &[4], [6.0, 6.0, 3.0, 3.0]);
+
+    let polygons = fill_polygons(&vertices, &[4], 2, FillRule::Positive).unwrap();
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].0.len(), 2 * 6);
+}
+
+#[test]
+fn test_star() {
+    // a pentagram, going around its middle twice
+    let points: Vec<[f64; 2]> = (0..5)
+        .map(|k| {
+            let angle = std::f64::consts::PI * (0.5 + 0.8 * k as f64);
+            [angle.cos(), angle.sin()]
+        })
+        .collect();
+    let vertices: Vec<f64> = points.iter().flatten().copied().collect();
+
+    let inner = (0.4 * std::f64::consts::PI).cos() / (0.2 * std::f64::consts::PI).cos();
+    let star = 5.0 * inner * (0.2 * std::f64::consts::PI).sin();
+    let pentagon = 2.5 * inner * inner * (0.4 * std::f64::consts::PI).sin();
+    assert_areas(&vertices, &[], [star - pentagon, star, star, 0.0]);
+
+    // even-odd leaves the middle out, so the points come apart
+    let polygons = fill_polygons(&vertices, &[], 2, FillRule::EvenOdd).unwrap();
+    assert_eq!(polygons.len(), 5);
+    assert!(polygons
+        .iter()
+        .all(|p| p.0.len() == 2 * 3 && p.1.is_empty()));
+    let polygons = fill_polygons(&vertices, &[], 2, FillRule::NonZero).unwrap();
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].0.len(), 2 * 10);
+}
+
+#[test]
+fn test_bowtie() {
+    // one counterclockwise and one clockwise triangle, crossing at (1, 1)
+    let vertices = [0., 0., 2., 2., 2., 0., 0., 2.];
+    assert_areas(&vertices, &[], [2.0, 2.0, 1.0, 1.0]);
+
+    let (points, triangles) = tessellate(&vertices, &[], 2, FillRule::NonZero).unwrap();
+    assert_eq!(triangles.len(), 6);
+    assert_eq!(points.len(), 2 * 5);
+    assert!(points.chunks(2).any(|p| p == [1.0, 1.0]));
+}
+
+#[test]
+fn test_holes() {
+    let outer = [0., 0., 4., 0., 4., 4., 0., 4.];
+
+    // a clockwise inner square is a hole under every rule that fills
+    // the outer one
+    let vertices = [&outer[..], &[1., 1., 1., 3., 3., 3., 3., 1.]].concat();
+    assert_areas(&vertices, &[4], [12.0, 12.0, 12.0, 0.0]);
+    let polygons = fill_polygons(&vertices, &[4], 2, FillRule::NonZero).unwrap();
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].1, vec![4]);
+
+    // a counterclockwise one only under even-odd
+    let vertices = [&outer[..], &[1., 1., 3., 1., 3., 3., 1., 3.]].concat();
+    assert_areas(&vertices, &[4], [12.0, 16.0, 16.0, 0.0]);
+
+    // an island in the hole, touching it at a vertex
+    let vertices = [
+        &outer[..],
+        &[1., 1., 1., 3., 3., 3., 3., 1.],
+        &[2., 2., 2.5, 2., 3., 3.],
+    ]
+    .concat();
+    assert_areas(&vertices, &[4, 8], [12.25, 12.25, 12.25, 0.0]);
+    let polygons = fill_polygons(&vertices, &[4, 8], 2, FillRule::NonZero).unwrap();
+    assert_eq!(polygons.len(), 2);
+}
+
+#[test]
+fn test_shared_edges() {
+    // side by side, meeting along x = 1 and at a vertex in the middle of
+    // the bottom edge of a wider square below
+    let vertices = [
+        0., 0., 1., 0., 1., 1., 0., 1., 1., 0., 2., 0., 2., 1., 1., 1., 0., -1., 2., -1., 2., 0.,
+        0., 0.,
+    ];
+    assert_areas(&vertices, &[4, 8], [4.0, 4.0, 4.0, 0.0]);
+    let polygons = fill_polygons(&vertices, &[4, 8], 2, FillRule::NonZero).unwrap();
+    assert_eq!(polygons.len(), 1);
+
+    // the same contour twice winds twice
+    let square = [0., 0., 1., 0., 1., 1., 0., 1.];
+    let vertices = [square, square].concat();
+    assert_areas(&vertices, &[4], [0.0, 1.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_degenerate() {
+    assert_eq!(
+        fill_polygons::<f64>(&[], &[], 2, FillRule::NonZero).unwrap(),
+        vec![]
+    );
+    let (points, triangles) = tessellate::<f64>(&[], &[], 2, FillRule::NonZero).unwrap();
+    assert!(points.is_empty() && triangles.is_empty());
+
+    // points, lines and contours doubling back on themselves fill nothing
+    let vertices = [0., 0., 1., 1., 1., 1., 2., 2., 3., 3., 2., 2.];
+    assert!(fill_polygons(&vertices, &[1, 3], 2, FillRule::NonZero)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_many_crossings() {
+    // a long bar across a hundred thin posts, crossing each of them twice
+    let mut vertices = vec![-1., 4., 200., 4., 200., 6., -1., 6.];
+    let mut contour_indices = Vec::new();
+    for i in 0..100 {
+        let x = 2. * i as f64;
+        contour_indices.push(vertices.len() / 2);
+        vertices.extend([x, 0., x + 1., 0., x + 1., 10., x, 10.]);
+    }
+    let bar = 201. * 2.;
+    let posts = 100. * 10.;
+    let overlap = 100. * 2.;
+    assert_areas(
+        &vertices,
+        &contour_indices,
+        [
+            bar + posts - 2. * overlap,
+            bar + posts - overlap,
+            bar + posts - overlap,
+            0.0,
+        ],
+    );
+}
+
+#[test]
+fn test_errors() {
+    let square = [0., 0., 1., 0., 1., 1., 0., 1.];
+    assert!(fill_polygons(&square, &[], 1, FillRule::NonZero).is_err());
+    assert!(fill_polygons(&square, &[], 3, FillRule::NonZero).is_err());
+    assert!(fill_polygons(&square, &[5], 2, FillRule::NonZero).is_err());
+    assert!(fill_polygons(&square, &[3, 2], 2, FillRule::NonZero).is_err());
+    assert!(tessellate(&[0., 0., 1., f64::NAN, 0., 1.], &[], 2, FillRule::NonZero).is_err());
+}
+
+#[test]
+fn test_fixtures() {
+    // rings that do not cross fill just the polygon under even-odd
+    for name in ["building", "dude", "hilbert", "water2", "water3", "water4"] {
+        let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture(name));
+        let triangles = earcutr::earcut(&vertices, &hole_indices, dims).unwrap();
+        let report = earcutr::verify(&vertices, &hole_indices, dims, &triangles).unwrap();
+        let area = tessellated_area(&vertices, &hole_indices, FillRule::EvenOdd);
+        assert!(
+            (area - report.polygon_area).abs() < 1e-9 * report.polygon_area,
+            "{name}: {area} != {}",
+            report.polygon_area
+        );
+    }
+}
+
+// the repaired polygons and their total area, checking that each is
+// triangulated without problems
+fn repaired(vertices: &[f64], hole_indices: &[usize]) -> (usize, f64) {
+    let repaired = earcut_valid(vertices, hole_indices, 2).unwrap();
+    let mut area = 0.0;
+    for ((vertices, hole_indices, dims), triangles) in &repaired {
+        let report = earcutr::verify(vertices, hole_indices, *dims, triangles).unwrap();
+        assert!(report.is_valid(1e-12), "{report:?}");
+        area += report.polygon_area;
+    }
+    (repaired.len(), area)
+}
+
+#[test]
+fn test_make_valid() {
+    // a bowtie comes apart into its two triangles
+    let bowtie = [0., 0., 2., 2., 2., 0., 0., 2.];
+    assert_eq!(repaired(&bowtie, &[]), (2, 2.0));
+    let polygons = make_valid(&bowtie, &[], 2).unwrap();
+    assert!(polygons.iter().all(|p| p.0.contains(&1.0)));
+
+    // and a figure eight into its loops, touching where it does
+    let eight = [0., 0., 1., 1., 2., 0., 2., 2., 1., 1., 0., 2.];
+    assert_eq!(repaired(&eight, &[]), (2, 2.0));
+
+    // a hole sticking out of the outer ring cuts a notch in it and the
+    // part outside fills, a hole outside it altogether fills, and one
+    // drawn twice cancels out
+    let outer = [0., 0., 4., 0., 4., 4., 0., 4.];
+    let across = [2., 1., 6., 1., 6., 3., 2., 3.];
+    let away = [8., 0., 9., 0., 9., 1., 8., 1.];
+    let vertices = [&outer[..], &across, &away].concat();
+    assert_eq!(repaired(&vertices, &[4, 8]), (3, 17.0));
+    let hole = [1., 1., 1., 2., 2., 2., 2., 1.];
+    let vertices = [&outer[..], &hole, &hole].concat();
+    assert_eq!(repaired(&vertices, &[4, 8]), (1, 16.0));
+
+    // valid polygons keep their shape, with z dropped
+    let vertices = [
+        0., 0., 7., 4., 0., 7., 4., 4., 7., 0., 4., 7., 1., 1., 7., 1., 3., 7., 3., 3., 7., 3., 1.,
+        7.,
+    ];
+    let polygons = make_valid(&vertices, &[4], 3).unwrap();
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].1, vec![4]);
+    assert_eq!(polygons[0].2, 2);
+    assert_eq!(polygons[0].0.len(), 2 * 8);
+
+    assert!(make_valid(&bowtie, &[9], 2).is_err());
+}
@@ -0,0 +1,176 @@
+extern crate earcutr;
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt32Type;
+use arrow_array::{
+    Array, ArrayRef, FixedSizeListArray, Float64Array, GenericListArray, Int32Array,
+    LargeListArray, ListArray, OffsetSizeTrait, StructArray,
+};
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::{DataType, Field};
+use earcutr::geoarrow::{earcut_column, earcut_polygons};
+use earcutr::Error;
+
+mod common;
+
+type Rings = Vec<Vec<Vec<f64>>>;
+
+fn square(min: f64, max: f64) -> Vec<Vec<f64>> {
+    [[min, min], [max, min], [max, max], [min, max]]
+        .iter()
+        .map(|p| p.to_vec())
+        .collect()
+}
+
+fn list<O: OffsetSizeTrait>(
+    name: &str,
+    values: ArrayRef,
+    lengths: impl IntoIterator<Item = usize>,
+    nulls: Option<NullBuffer>,
+) -> GenericListArray<O> {
+    let field = Field::new(name, values.data_type().clone(), false);
+    GenericListArray::new(
+        Arc::new(field),
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        nulls,
+    )
+}
+
+// a GeoArrow polygon column, with None for null polygons
+fn column<O: OffsetSizeTrait>(
+    polygons: &[Option<Rings>],
+    interleaved: bool,
+) -> GenericListArray<O> {
+    let rings: Vec<&Vec<Vec<f64>>> = polygons.iter().flatten().flatten().collect();
+    let points: Vec<&Vec<f64>> = rings.iter().copied().flatten().collect();
+    let dims = points.first().map_or(2, |p| p.len());
+    let coordinates: ArrayRef = match interleaved {
+        true => {
+            let values = Float64Array::from_iter_values(points.iter().copied().flatten().copied());
+            let field = Field::new("xy", DataType::Float64, false);
+            Arc::new(FixedSizeListArray::new(
+                Arc::new(field),
+                dims as i32,
+                Arc::new(values),
+                None,
+            ))
+        }
+        false => {
+            let names = ["x", "y", "z", "m"];
+            let fields: Vec<Field> = names[..dims]
+                .iter()
+                .map(|name| Field::new(*name, DataType::Float64, false))
+                .collect();
+            let columns: Vec<ArrayRef> = (0..dims)
+                .map(|d| Arc::new(Float64Array::from_iter_values(points.iter().map(|p| p[d]))) as _)
+                .collect();
+            Arc::new(StructArray::new(fields.into(), columns, None))
+        }
+    };
+    let rings = list::<O>("vertices", coordinates, rings.iter().map(|r| r.len()), None);
+    let nulls = NullBuffer::from_iter(polygons.iter().map(Option::is_some));
+    list(
+        "rings",
+        Arc::new(rings),
+        polygons.iter().map(|p| p.as_ref().map_or(0, Vec::len)),
+        Some(nulls),
+    )
+}
+
+fn triangles<O: OffsetSizeTrait>(indices: &GenericListArray<O>, i: usize) -> Vec<usize> {
+    let value = indices.value(i);
+    let value = value.as_primitive::<UInt32Type>();
+    value.values().iter().map(|&i| i as usize).collect()
+}
+
+#[test]
+fn test_fixtures() {
+    let names = common::fixture_names();
+    let polygons = names
+        .iter()
+        .map(|name| Some(common::load_fixture(name)))
+        .collect::<Vec<_>>();
+    for interleaved in [true, false] {
+        let indices = earcut_polygons(&column::<i32>(&polygons, interleaved)).unwrap();
+        assert_eq!(indices.len(), polygons.len());
+        for (i, rings) in polygons.iter().enumerate() {
+            let (vertices, hole_indices, dims) = earcutr::flatten(rings.as_ref().unwrap());
+            let expected = earcutr::earcut(&vertices, &hole_indices, dims).unwrap();
+            assert_eq!(triangles(&indices, i), expected, "{}", names[i]);
+        }
+    }
+}
+
+#[test]
+fn test_column() {
+    let mut with_hole = vec![square(0., 10.), square(2., 8.)];
+    with_hole[1].reverse();
+    let polygons = [
+        Some(vec![square(0., 1.)]),
+        None,
+        Some(with_hole.clone()),
+        Some(vec![]),
+    ];
+    for interleaved in [true, false] {
+        let array = column::<i64>(&polygons, interleaved);
+        let indices = earcut_column(&array).unwrap();
+        let indices: &LargeListArray = indices.as_list();
+        assert_eq!(indices.len(), 4);
+        assert!(indices.is_null(1) && indices.is_valid(3));
+        assert_eq!(triangles(indices, 0).len(), 6);
+        assert_eq!(triangles(indices, 2).len(), 3 * 8);
+        // counting from each polygon's own first coordinate
+        assert!(triangles(indices, 2).iter().all(|&i| i < 8));
+        assert!(triangles(indices, 3).is_empty());
+
+        // a slice of the column, starting past its first coordinates
+        let sliced = array.slice(2, 1);
+        let indices = earcut_polygons(&sliced).unwrap();
+        let (vertices, hole_indices, dims) = earcutr::flatten(&with_hole);
+        assert_eq!(
+            triangles(&indices, 0),
+            earcutr::earcut(&vertices, &hole_indices, dims).unwrap()
+        );
+    }
+
+    // z values ride along
+    let ring = square(0., 1.).into_iter().map(|p| vec![p[0], p[1], 5.]);
+    let polygons = [Some(vec![ring.collect()])];
+    for interleaved in [true, false] {
+        let indices = earcut_polygons(&column::<i32>(&polygons, interleaved)).unwrap();
+        assert_eq!(triangles(&indices, 0).len(), 6);
+    }
+}
+
+#[test]
+fn test_errors() {
+    let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    assert_eq!(earcut_column(&ints).unwrap_err(), Error::InvalidGeoArrow);
+
+    // rings of plain numbers rather than coordinates
+    let rings = list::<i32>("vertices", ints.clone(), [3], None);
+    let polygons: ListArray = list("rings", Arc::new(rings), [1], None);
+    assert_eq!(
+        earcut_polygons(&polygons).unwrap_err(),
+        Error::InvalidGeoArrow
+    );
+
+    // coordinates of one or five values, or not Float64
+    for (size, values) in [
+        (1, Arc::new(Float64Array::from(vec![0.; 3])) as ArrayRef),
+        (5, Arc::new(Float64Array::from(vec![0.; 15])) as ArrayRef),
+        (2, Arc::new(Int32Array::from(vec![0; 6])) as ArrayRef),
+    ] {
+        let field = Field::new("xy", values.data_type().clone(), false);
+        let coordinates = FixedSizeListArray::new(Arc::new(field), size, values, None);
+        let rings = list::<i32>("vertices", Arc::new(coordinates), [3], None);
+        let polygons: ListArray = list("rings", Arc::new(rings), [1], None);
+        assert_eq!(
+            earcut_polygons(&polygons).unwrap_err(),
+            Error::InvalidGeoArrow
+        );
+    }
+}
@@ -0,0 +1,126 @@
+extern crate earcutr;
+
+use earcutr::geojson::{parse_polygons, triangles_to_feature_collection};
+use earcutr::Error;
+
+static SQUARE_WITH_HOLE: &str = r#"{
+    "type": "Feature",
+    "properties": { "name": "square", "height": 3 },
+    "geometry": {
+        "type": "Polygon",
+        "coordinates": [
+            [[0, 0], [10, 0], [10, 10], [0, 10], [0, 0]],
+            [[2, 2], [2, 8], [8, 8], [8, 2], [2, 2]]
+        ]
+    }
+}"#;
+
+#[test]
+fn test_parse_feature() {
+    let polygons = parse_polygons(SQUARE_WITH_HOLE).unwrap();
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].rings.len(), 2);
+    assert_eq!(polygons[0].properties.as_ref().unwrap()["name"], "square");
+
+    let (vertices, hole_indices, dims) = polygons[0].flatten();
+    assert_eq!(vertices.len(), 20);
+    assert_eq!(hole_indices, vec![5]);
+    assert_eq!(dims, 2);
+
+    let triangles = polygons[0].earcut().unwrap();
+    let deviation = earcutr::deviation(&vertices, &hole_indices, dims, &triangles);
+    assert_eq!(triangles.len() / 3, 8);
+    assert_eq!(deviation, 0.0);
+}
+
+#[test]
+fn test_parse_feature_collection() {
+    let json = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            { "type": "Feature", "properties": { "id": 1 }, "geometry": {
+                "type": "MultiPolygon",
+                "coordinates": [
+                    [[[0, 0], [1, 0], [1, 1]]],
+                    [[[2, 0], [3, 0], [3, 1], [2, 1]]]
+                ]
+            }},
+            { "type": "Feature", "properties": null, "geometry": null },
+            { "type": "Feature", "properties": {}, "geometry": {
+                "type": "LineString", "coordinates": [[0, 0], [1, 1]]
+            }},
+            { "type": "Feature", "properties": { "id": 2 }, "geometry": {
+                "type": "GeometryCollection",
+                "geometries": [
+                    { "type": "Point", "coordinates": [0, 0] },
+                    { "type": "Polygon", "coordinates": [[[0, 0, 5], [1, 0, 5], [1, 1, 5]]] }
+                ]
+            }}
+        ]
+    }"#;
+    let polygons = parse_polygons(json).unwrap();
+    assert_eq!(polygons.len(), 3);
+    assert_eq!(polygons[0].properties.as_ref().unwrap()["id"], 1);
+    assert_eq!(polygons[1].properties.as_ref().unwrap()["id"], 1);
+    assert_eq!(polygons[1].rings[0].len(), 4);
+    assert_eq!(polygons[2].properties.as_ref().unwrap()["id"], 2);
+    assert_eq!(polygons[2].flatten().2, 3);
+}
+
+#[test]
+fn test_parse_bare_coordinates() {
+    let polygons = parse_polygons("[[[0, 0], [1, 0], [1, 1]], [[0.5, 0.2]]]").unwrap();
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].rings.len(), 2);
+    assert!(polygons[0].properties.is_none());
+
+    let polygons = parse_polygons("[[[[0, 0], [1, 0], [1, 1]]], [[[5, 5], [6, 5], [6, 6]]]]");
+    assert_eq!(polygons.unwrap().len(), 2);
+}
+
+#[test]
+fn test_parse_invalid() {
+    let invalid = [
+        "",
+        "{",
+        "42",
+        r#"{ "type": "Polygon" }"#,
+        r#"{ "type": "Polygon", "coordinates": [[[0, 0], [1, "a"], [1, 1]]] }"#,
+        r#"{ "type": "Polygon", "coordinates": [[[0, 0], [1, 0, 1], [1, 1]]] }"#,
+        r#"{ "type": "Polygon", "coordinates": [[[0], [1], [1]]] }"#,
+        r#"{ "type": "Feature", "properties": 3, "geometry": null }"#,
+        r#"{ "type": "Circle", "coordinates": [0, 0] }"#,
+    ];
+    for json in invalid {
+        assert_eq!(parse_polygons(json), Err(Error::InvalidGeoJson), "{}", json);
+    }
+}
+
+#[test]
+fn test_triangles_to_feature_collection() {
+    let polygons = parse_polygons(SQUARE_WITH_HOLE).unwrap();
+    let triangles = vec![polygons[0].earcut().unwrap()];
+    let collection = triangles_to_feature_collection(&polygons, &triangles).unwrap();
+    assert_eq!(collection["type"], "FeatureCollection");
+
+    let features = collection["features"].as_array().unwrap();
+    assert_eq!(features.len(), 8);
+    for feature in features {
+        assert_eq!(feature["properties"]["height"], 3);
+        let ring = feature["geometry"]["coordinates"][0].as_array().unwrap();
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring[0], ring[3]);
+    }
+
+    // the output is GeoJSON again, one polygon per triangle
+    let reparsed = parse_polygons(&collection.to_string()).unwrap();
+    assert_eq!(reparsed.len(), 8);
+    assert_eq!(reparsed[0].properties, polygons[0].properties);
+
+    for triangles in [vec![], vec![vec![0, 1, 99]], vec![vec![0, 1]]] {
+        assert_eq!(
+            triangles_to_feature_collection(&polygons, &triangles),
+            Err(Error::InvalidGeoJson)
+        );
+    }
+}
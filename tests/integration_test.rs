@@ -1,13 +1,10 @@
 extern crate earcutr;
 
-extern crate serde;
-extern crate serde_json;
-
-use std::fs::File;
 use std::fs::OpenOptions;
-use std::io::Read;
 use std::io::Write;
 
+mod common;
+
 static DEBUG: usize = 1;
 macro_rules! dlog {
     ($loglevel:expr, $($s:expr),*) => (
@@ -21,38 +18,6 @@ macro_rules! dlog {
 //    return ((1e8 * num).round() / 1e6).to_string();// + "%";
 //}
 
-fn parse_json(rawdata: &str) -> Option<Vec<Vec<Vec<f64>>>> {
-    let mut v: Vec<Vec<Vec<f64>>> = Vec::new();
-    match serde_json::from_str::<serde_json::Value>(rawdata) {
-        Err(e) => println!("error deserializing, {}", e),
-        Ok(jsondata) => {
-            if jsondata.is_array() {
-                let contours = jsondata.as_array().unwrap();
-                dlog!(4, "deserialize ok, {} contours", contours.len());
-                for (i, contourval) in contours.iter().enumerate() {
-                    if contourval.is_array() {
-                        let contour = contourval.as_array().unwrap();
-                        dlog!(9, "countour {} numpoints {}", i, contour.len());
-                        let mut vc: Vec<Vec<f64>> = Vec::new();
-                        for points in contour {
-                            let points = points.as_array().unwrap();
-                            let mut vp: Vec<f64> = Vec::new();
-                            for val in points {
-                                let val = val.to_string();
-                                let pval = val.parse::<f64>().unwrap();
-                                vp.push(pval);
-                            }
-                            vc.push(vp);
-                        }
-                        v.push(vc);
-                    }
-                }
-            }
-        }
-    };
-    Some(v)
-}
-
 fn mkoutput(
     filename_w_dashes: &str,
     tris: Vec<usize>,
@@ -93,40 +58,16 @@ fn area_test(filename: &str, expected_num_tris: usize, expected_deviation: f64)
     //    let visualize = std::env::args().any(|x| x == "--test-threads=1");
     let visualize = true;
     dlog!(4, "visualization: {}", visualize);
-    let mut actual_num_tris = 0;
-    let mut actual_deviation = 0.0;
     let mut edeviation = expected_deviation;
-    let mut triangles: Vec<usize> = Vec::new();
-    let mut xdata: Vec<Vec<Vec<f64>>> = Vec::new();
     if edeviation == 0.0 {
         edeviation = 1e-14;
     }
-    let fullname = format!("tests/fixtures/{}.json", filename);
-    match File::open(&fullname) {
-        Err(why) => panic!("failed to open file '{}': {}", fullname, why),
-        Ok(mut f) => {
-            dlog!(4, "testing {},", fullname);
-            let mut strdata = String::new();
-            match f.read_to_string(&mut strdata) {
-                Err(why) => dlog!(4, "failed to read {}, {}", fullname, why),
-                Ok(numb) => {
-                    dlog!(4, "read {} bytes", numb);
-                    let rawstring = strdata.trim();
-                    match parse_json(rawstring) {
-                        None => dlog!(4, "failed to parse {}", fullname),
-                        Some(parsed_data) => {
-                            xdata = parsed_data;
-                            let (data, holeidxs, dimensions) = earcutr::legacy::flatten(&xdata);
-                            triangles = earcutr::earcut(&data, &holeidxs, dimensions).unwrap();
-                            actual_num_tris = triangles.len() / 3;
-                            actual_deviation =
-                                earcutr::deviation(&data, &holeidxs, dimensions, &triangles);
-                        }
-                    };
-                }
-            };
-        }
-    };
+    dlog!(4, "testing {},", filename);
+    let xdata = common::load_fixture(filename);
+    let (data, holeidxs, dimensions) = earcutr::legacy::flatten(&xdata);
+    let triangles = earcutr::earcut(&data, &holeidxs, dimensions).unwrap();
+    let actual_num_tris = triangles.len() / 3;
+    let actual_deviation = earcutr::deviation(&data, &holeidxs, dimensions, &triangles);
     let mut pass = true;
     if expected_num_tris > 0 && (expected_num_tris < actual_num_tris) {
         pass = false;
@@ -139,6 +80,16 @@ fn area_test(filename: &str, expected_num_tris: usize, expected_deviation: f64)
         expected_num_tris, edeviation, actual_num_tris, actual_deviation
     );
     if visualize {
+        if !pass {
+            // leave a picture of the failure next to the viz output
+            let options = earcutr::svg::SvgOptions::default();
+            let outfile = format!("viz/testoutput/{}.svg", str::replace(filename, "-", "_"));
+            match earcutr::svg::to_svg(&data, &holeidxs, dimensions, &triangles, None, &options) {
+                Err(e) => println!("error rendering {} {}", outfile, e),
+                Ok(svg) => std::fs::write(&outfile, svg)
+                    .unwrap_or_else(|e| println!("error writing {} {}", outfile, e)),
+            }
+        }
         if let Err(e) = mkoutput(filename, triangles, &xdata, pass, &rpt) {
             println!("error writing output {}", e);
         }
@@ -154,7 +105,6 @@ fn test_indices_2d() {
     assert!(indices == vec![1, 0, 3, 3, 2, 1]);
 }
 
-/*
 #[test]
 fn test_indices_3d() {
     let indices = earcutr::earcut(
@@ -163,10 +113,10 @@ fn test_indices_3d() {
         ],
         &[],
         3,
-    );
+    )
+    .unwrap();
     assert!(indices == vec![1, 0, 3, 3, 2, 1]);
 }
-*/
 
 #[test]
 fn test_empty() {
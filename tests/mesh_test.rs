@@ -0,0 +1,95 @@
+extern crate earcutr;
+
+use earcutr::mesh::Mesh;
+
+fn square_with_hole() -> (Vec<f64>, Vec<usize>) {
+    let vertices = vec![
+        0., 0., 100., 0., 100., 100., 0., 100., 20., 20., 80., 20., 80., 80., 20., 80.,
+    ];
+    (vertices, vec![4])
+}
+
+#[test]
+fn test_from_triangulation() {
+    let (vertices, holes) = square_with_hole();
+    let triangles = earcutr::earcut(&vertices, &holes, 2).unwrap();
+    let mesh = Mesh::from_triangulation(&vertices, 2, &triangles).unwrap();
+    assert_eq!(mesh.positions.len(), 8);
+    assert_eq!(mesh.positions[2], [100.0, 100.0, 0.0]);
+    assert_eq!(mesh.triangles.len(), triangles.len());
+    for i in 0..mesh.triangles.len() / 3 {
+        assert_eq!(mesh.face_normal(i), [0.0, 0.0, 1.0]);
+    }
+
+    // clockwise triangles get flipped to face +Z
+    let mesh = Mesh::from_triangulation(&[0., 0., 0., 1., 1., 0.], 2, &[0, 1, 2]).unwrap();
+    assert_eq!(mesh.triangles, vec![0, 2, 1]);
+    assert_eq!(mesh.face_normal(0), [0.0, 0.0, 1.0]);
+
+    // z comes from the third coordinate
+    let vertices = [0., 0., 5., 1., 0., 5., 0., 1., 5., 9., 9., 9.];
+    let mesh = Mesh::from_triangulation(&vertices, 3, &[0, 1, 2]).unwrap();
+    assert_eq!(mesh.positions[1], [1.0, 0.0, 5.0]);
+
+    assert!(Mesh::from_triangulation(&vertices, 3, &[0, 1, 4]).is_err());
+    assert!(Mesh::from_triangulation(&vertices, 3, &[0, 1]).is_err());
+    assert!(Mesh::from_triangulation(&vertices, 5, &[]).is_err());
+}
+
+#[test]
+fn test_write_obj() {
+    let mesh = Mesh::from_triangulation(&[0., 0., 1., 0., 0., 1.], 2, &[0, 1, 2]).unwrap();
+    let mut obj = Vec::new();
+    mesh.write_obj(&mut obj).unwrap();
+    assert_eq!(
+        String::from_utf8(obj).unwrap(),
+        "# earcutr\nv 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n"
+    );
+}
+
+#[test]
+fn test_write_ply() {
+    let (vertices, holes) = square_with_hole();
+    let triangles = earcutr::earcut(&vertices, &holes, 2).unwrap();
+    let mesh = Mesh::from_triangulation(&vertices, 2, &triangles).unwrap();
+
+    let mut ply = Vec::new();
+    mesh.write_ply_ascii(&mut ply).unwrap();
+    let ply = String::from_utf8(ply).unwrap();
+    assert!(ply.starts_with("ply\nformat ascii 1.0\n"));
+    assert!(ply.contains("element vertex 8\n"));
+    assert!(ply.contains("element face 8\n"));
+    let body = ply.split("end_header\n").nth(1).unwrap();
+    assert_eq!(body.lines().count(), 16);
+    assert_eq!(body.lines().next(), Some("0 0 0"));
+    assert!(body.lines().nth(8).unwrap().starts_with("3 "));
+
+    let mut binary = Vec::new();
+    mesh.write_ply_binary(&mut binary).unwrap();
+    let header_len = ply.len() - body.len() + "binary_little_endian".len() - "ascii".len();
+    assert!(binary.starts_with(b"ply\nformat binary_little_endian 1.0\n"));
+    assert_eq!(binary.len(), header_len + 8 * 3 * 8 + 8 * (1 + 3 * 4));
+    assert_eq!(
+        binary[header_len + 24..header_len + 32],
+        100f64.to_le_bytes()
+    );
+}
+
+#[test]
+fn test_write_stl() {
+    let (vertices, holes) = square_with_hole();
+    let triangles = earcutr::earcut(&vertices, &holes, 2).unwrap();
+    let mesh = Mesh::from_triangulation(&vertices, 2, &triangles).unwrap();
+
+    let mut stl = Vec::new();
+    mesh.write_stl(&mut stl).unwrap();
+    assert_eq!(stl.len(), 80 + 4 + 8 * 50);
+    assert_eq!(stl[80..84], 8u32.to_le_bytes());
+    for facet in stl[84..].chunks(50) {
+        let normal = facet[..12]
+            .chunks(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(normal, vec![0.0, 0.0, 1.0]);
+    }
+}
@@ -0,0 +1,66 @@
+extern crate earcutr;
+
+use earcutr::metrics::{measure, ASPECT_RATIO_BUCKETS};
+
+mod common;
+
+#[test]
+fn test_square() {
+    let vertices = vec![0., 0., 1., 0., 1., 1., 0., 1.];
+    let triangles = earcutr::earcut(&vertices, &[], 2).unwrap();
+    let metrics = measure(&vertices, &[], 2, &triangles).unwrap();
+    assert_eq!(metrics.triangles, 2);
+    assert_eq!(metrics.degenerate, 0);
+    assert_eq!(metrics.slivers, 0);
+    assert!((metrics.min_angle - 45.).abs() < 1e-9);
+    assert!((metrics.max_angle - 90.).abs() < 1e-9);
+    assert!((metrics.mean_min_angle - 45.).abs() < 1e-9);
+    // longest side sqrt(2) over height sqrt(0.5), times sqrt(3) / 2
+    assert_eq!(metrics.aspect_ratios, [0, 2, 0, 0, 0, 0, 0]);
+    assert_eq!(metrics.polygon_area, 1.);
+    assert_eq!(metrics.triangles_area, 1.);
+    assert_eq!(metrics.deviation(), 0.);
+}
+
+#[test]
+fn test_shapes() {
+    let h = 3.0_f64.sqrt() / 2.;
+    // equilateral, sliver and degenerate, with a z that plays no part
+    let vertices = vec![
+        0., 0., 7., 1., 0., 7., 0.5, h, 7., 10., 0., 7., 20., 0., 7., 15., 0.25, 7., 30., 0., 7.,
+    ];
+    let metrics = measure(&vertices, &[], 3, &[0, 1, 2, 3, 4, 5, 3, 4, 6]).unwrap();
+    assert_eq!(metrics.triangles, 3);
+    assert_eq!(metrics.degenerate, 1);
+    assert_eq!(metrics.slivers, 1);
+    assert!((metrics.max_angle - 180. + 2. * (0.05_f64).atan().to_degrees()).abs() < 1e-9);
+    assert!((metrics.min_angle - (0.05_f64).atan().to_degrees()).abs() < 1e-9);
+    assert_eq!(metrics.aspect_ratios[0], 1);
+    assert_eq!(metrics.aspect_ratios[ASPECT_RATIO_BUCKETS.len()], 1);
+    assert!((metrics.triangles_area - h / 2. - 1.25).abs() < 1e-9);
+
+    assert!(measure(&vertices, &[], 3, &[0, 1, 9]).is_err());
+    assert!(measure(&vertices, &[], 3, &[0, 1]).is_err());
+    assert!(measure(&vertices, &[], 1, &[]).is_err());
+    assert!(measure(&vertices, &[9], 3, &[]).is_err());
+}
+
+#[test]
+fn test_deviation() {
+    for name in common::fixture_names() {
+        let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture(&name));
+        let triangles = earcutr::earcut(&vertices, &hole_indices, dims).unwrap();
+        let metrics = measure(&vertices, &hole_indices, dims, &triangles).unwrap();
+        let deviation = earcutr::deviation(&vertices, &hole_indices, dims, &triangles);
+        assert!(
+            (metrics.deviation() - deviation).abs() < 1e-9 || metrics.deviation() == deviation,
+            "{}",
+            name
+        );
+        assert_eq!(metrics.triangles, triangles.len() / 3);
+        assert_eq!(
+            metrics.aspect_ratios.iter().sum::<usize>(),
+            metrics.triangles - metrics.degenerate
+        );
+    }
+}
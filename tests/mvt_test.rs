@@ -0,0 +1,169 @@
+extern crate earcutr;
+
+use earcutr::mvt::{decode_polygons, decode_polygons_y_up};
+use earcutr::Error;
+
+mod common;
+
+fn command(id: u32, count: usize) -> u32 {
+    id | (count as u32) << 3
+}
+
+fn zigzag(value: i64) -> u32 {
+    ((value << 1) ^ (value >> 63)) as u32
+}
+
+// the command integers for the rings, taken as they are wound
+fn encode(rings: &[Vec<Vec<f64>>]) -> Vec<u32> {
+    let mut geometry = Vec::new();
+    let mut cursor = [0, 0];
+    for ring in rings {
+        for (i, p) in ring.iter().enumerate() {
+            match i {
+                0 => geometry.push(command(1, 1)),
+                1 => geometry.push(command(2, ring.len() - 1)),
+                _ => {}
+            }
+            for (value, &coordinate) in cursor.iter_mut().zip(p) {
+                geometry.push(zigzag(coordinate as i64 - *value));
+                *value = coordinate as i64;
+            }
+        }
+        geometry.push(command(7, 1));
+    }
+    geometry
+}
+
+#[test]
+fn test_specification_examples() {
+    // a polygon, and a multipolygon whose second polygon has a hole
+    let polygon = decode_polygons(&[9, 6, 12, 18, 10, 12, 24, 44, 15]).unwrap();
+    assert_eq!(polygon, vec![(vec![3., 6., 8., 12., 20., 34.], vec![], 2)]);
+    let geometry = [
+        9, 0, 0, 26, 20, 0, 0, 20, 19, 0, 15, 9, 22, 2, 26, 18, 0, 0, 18, 17, 0, 15, 9, 4, 13, 26,
+        0, 8, 8, 0, 0, 7, 15,
+    ];
+    let polygons = decode_polygons(&geometry).unwrap();
+    assert_eq!(
+        polygons,
+        vec![
+            (vec![0., 0., 10., 0., 10., 10., 0., 10.], vec![], 2),
+            (
+                vec![
+                    11., 11., 20., 11., 20., 20., 11., 20., 13., 13., 13., 17., 17., 17., 17., 13.
+                ],
+                vec![4],
+                2
+            ),
+        ]
+    );
+
+    // the same the right way up in a tile of 20 by 20
+    let flipped = decode_polygons_y_up(&geometry, 20).unwrap();
+    assert_eq!(flipped[0].0, vec![0., 20., 10., 20., 10., 10., 0., 10.]);
+    let outer: Vec<Vec<f64>> = flipped[0].0.chunks(2).map(|p| p.to_vec()).collect();
+    assert!(earcutr::signed_area(&outer) < 0.0);
+    let mut area = 0.0;
+    for (vertices, hole_indices, dims) in &flipped {
+        let triangles = earcutr::earcut(vertices, hole_indices, *dims).unwrap();
+        let report = earcutr::verify(vertices, hole_indices, *dims, &triangles).unwrap();
+        assert!(report.is_valid(1e-12));
+        area += report.polygon_area;
+    }
+    assert_eq!(area, 100. + 81. - 16.);
+}
+
+#[test]
+fn test_fixtures() {
+    for name in common::fixture_names() {
+        let rings = common::load_fixture(&name);
+        // tiles hold integer coordinates only
+        if rings.iter().flatten().flatten().any(|v| v.fract() != 0.0)
+            || rings.iter().any(|ring| earcutr::signed_area(ring) == 0.0)
+        {
+            continue;
+        }
+        let expected = earcutr::flatten(&rings);
+        // wound the tile way: outer ring positive, holes negative
+        let wound: Vec<Vec<Vec<f64>>> = rings
+            .iter()
+            .enumerate()
+            .map(|(i, ring)| {
+                let mut ring = ring.clone();
+                if (earcutr::signed_area(&ring) > 0.0) != (i == 0) {
+                    ring.reverse();
+                }
+                ring
+            })
+            .collect();
+        let polygons = decode_polygons(&encode(&wound)).unwrap();
+        assert_eq!(polygons.len(), 1, "{}", name);
+        if wound == rings {
+            assert_eq!(polygons[0], expected, "{}", name);
+        }
+        let (vertices, hole_indices, dims) = &polygons[0];
+        let triangles = earcutr::earcut(vertices, hole_indices, *dims).unwrap();
+        let report = earcutr::verify(vertices, hole_indices, *dims, &triangles).unwrap();
+        let original = earcutr::verify(&expected.0, &expected.1, 2, &[]).unwrap();
+        assert_eq!(report.polygon_area, original.polygon_area, "{}", name);
+    }
+}
+
+#[test]
+fn test_winding() {
+    let square = |min: f64, max: f64| {
+        // clockwise on screen
+        vec![
+            vec![min, min],
+            vec![max, min],
+            vec![max, max],
+            vec![min, max],
+        ]
+    };
+    let hole = |min: f64, max: f64| {
+        let mut ring = square(min, max);
+        ring.reverse();
+        ring
+    };
+    // two polygons, the first with two holes, and a ring with no area
+    let rings = [
+        square(0., 10.),
+        hole(1., 2.),
+        vec![vec![5., 5.], vec![6., 6.], vec![7., 7.]],
+        hole(3., 4.),
+        square(20., 30.),
+    ];
+    let polygons = decode_polygons(&encode(&rings)).unwrap();
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[0].1, vec![4, 8]);
+    assert_eq!(polygons[0].0[16..], [3., 4., 4., 4., 4., 3., 3., 3.]);
+    assert_eq!(polygons[1].0[..2], [20., 20.]);
+
+    // a hole first has nothing to be a hole in
+    assert_eq!(
+        decode_polygons(&encode(&[hole(0., 1.)])),
+        Err(Error::InvalidMvt)
+    );
+    assert!(decode_polygons(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn test_errors() {
+    for geometry in [
+        // truncated parameters
+        &[9, 6, 12, 18, 10, 12, 24][..],
+        &[9, 6][..],
+        // no ClosePath, or LineTo
+        &[9, 6, 12, 18, 10, 12, 24, 44][..],
+        &[9, 6, 12, 15][..],
+        // two points to one MoveTo, and an unknown command
+        &[17, 6, 12, 2, 2, 18, 10, 12, 24, 44, 15][..],
+        &[9, 6, 12, 18, 10, 12, 24, 44, 15, 11][..],
+    ] {
+        assert_eq!(
+            decode_polygons(geometry),
+            Err(Error::InvalidMvt),
+            "{geometry:?}"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+extern crate earcutr;
+
+use earcutr::nesting::{earcut_nested, nest_polygons, Nesting};
+
+mod common;
+
+fn square(min: f64, max: f64) -> Vec<f64> {
+    vec![min, min, max, min, max, max, min, max]
+}
+
+#[test]
+fn test_depths() {
+    // an island in a lake in an island in a lake, out of order and wound
+    // every which way
+    let mut reversed = square(1., 9.);
+    reversed.reverse();
+    let rings = [square(3., 7.), square(0., 10.), square(2., 8.), reversed];
+    let vertices = rings.concat();
+    let nesting = Nesting::new(&vertices, &[4, 8, 12], 2).unwrap();
+    assert_eq!(nesting.depths, vec![3, 0, 2, 1]);
+    assert_eq!(nesting.parents, vec![Some(2), None, Some(3), Some(1)]);
+    assert!(nesting.is_hole(0) && !nesting.is_hole(2));
+    assert_eq!(nesting.polygons(), vec![vec![1, 3], vec![2, 0]]);
+
+    let polygons = nest_polygons(&vertices, &[4, 8, 12], 2).unwrap();
+    assert_eq!(polygons.len(), 2);
+    for (vertices, hole_indices, dims) in &polygons {
+        assert_eq!((hole_indices.as_slice(), *dims), (&[4][..], 2));
+        assert!(earcutr::signed_area(vertices[..8].chunks(2)) > 0.0);
+        assert!(earcutr::signed_area(vertices[8..].chunks(2)) < 0.0);
+    }
+    assert_eq!(polygons[0].0[..8], square(0., 10.));
+
+    let mut area = 0.0;
+    for ((vertices, hole_indices, dims), triangles) in
+        earcut_nested(&vertices, &[4, 8, 12], 2).unwrap()
+    {
+        let report = earcutr::verify(&vertices, &hole_indices, dims, &triangles).unwrap();
+        assert!(report.is_valid(1e-12));
+        area += report.polygon_area;
+    }
+    assert_eq!(area, 100. - 64. + 36. - 16.);
+}
+
+#[test]
+fn test_siblings() {
+    // two islands side by side, one with two lakes, and a hole touching
+    // the outer ring at a vertex
+    let vertices = [
+        square(4., 6.),
+        square(0., 10.),
+        square(11., 12.),
+        square(1., 3.),
+        vec![0., 0., 1., 0.5, 0.5, 1.],
+    ]
+    .concat();
+    let nesting = Nesting::new(&vertices, &[4, 8, 12, 16], 2).unwrap();
+    assert_eq!(nesting.parents, vec![Some(1), None, None, Some(1), Some(1)]);
+    assert_eq!(nesting.polygons(), vec![vec![1, 0, 3, 4], vec![2]]);
+
+    // other dimensions come along
+    let vertices: Vec<f64> = vertices.chunks(2).flat_map(|p| [p[0], p[1], 5.]).collect();
+    let polygons = nest_polygons(&vertices, &[4, 8, 12, 16], 3).unwrap();
+    assert_eq!(
+        polygons[1],
+        (
+            vec![11., 11., 5., 12., 11., 5., 12., 12., 5., 11., 12., 5.],
+            vec![],
+            3
+        )
+    );
+    assert_eq!(polygons[0].1, vec![4, 8, 12]);
+}
+
+#[test]
+fn test_fixture() {
+    // the rings of a polygon, shuffled and all wound the same way, sort
+    // back into it
+    let (original, original_holes, dims) = earcutr::flatten(&common::load_fixture("water2"));
+    let mut starts = vec![0];
+    starts.extend(&original_holes);
+    starts.push(original.len() / dims);
+    let mut rings: Vec<Vec<f64>> = starts
+        .windows(2)
+        .map(|w| original[w[0] * dims..w[1] * dims].to_vec())
+        .collect();
+    for ring in &mut rings {
+        if earcutr::signed_area(ring.chunks(2)) < 0.0 {
+            let points: Vec<&[f64]> = ring.chunks(2).rev().collect();
+            *ring = points.concat();
+        }
+    }
+    let half = rings.len() / 2;
+    rings.rotate_left(half);
+    let mut ring_indices = Vec::new();
+    let mut shuffled: Vec<f64> = Vec::new();
+    for ring in &rings {
+        if !shuffled.is_empty() {
+            ring_indices.push(shuffled.len() / 2);
+        }
+        shuffled.extend(ring);
+    }
+
+    let nested = earcut_nested(&shuffled, &ring_indices, 2).unwrap();
+    assert_eq!(nested.len(), 1);
+    let ((vertices, hole_indices, dims), triangles) = &nested[0];
+    assert_eq!(hole_indices.len(), original_holes.len());
+    let report = earcutr::verify(vertices, hole_indices, *dims, triangles).unwrap();
+    let expected = earcutr::verify(&original, &original_holes, 2, &[]).unwrap();
+    assert_eq!(report.polygon_area, expected.polygon_area);
+    assert_eq!(report.deviation, 0.0);
+}
+
+#[test]
+fn test_errors() {
+    let vertices = square(0., 1.);
+    // no vertices make one empty ring, and no polygons
+    let nesting = Nesting::new::<f64>(&[], &[], 2).unwrap();
+    assert_eq!(nesting.parents, vec![None]);
+    assert!(nest_polygons::<f64>(&[], &[], 2).unwrap().is_empty());
+    assert!(Nesting::new(&vertices, &[], 1).is_err());
+    assert!(Nesting::new(&vertices, &[], 3).is_err());
+    assert!(Nesting::new(&vertices, &[5], 2).is_err());
+    assert!(nest_polygons(&vertices, &[3, 2], 2).is_err());
+}
@@ -0,0 +1,155 @@
+extern crate earcutr;
+
+use earcutr::metrics::measure;
+use earcutr::{
+    earcut, earcut_with_options, Bridging, EarcutOptions, FlatPolygon, HoleOrder, Repair,
+};
+
+mod common;
+
+#[test]
+fn test_hashing_does_not_change_output() {
+    let options = [(0, 1024), (usize::MAX, 1024), (0, 0)].map(|(hash, wide)| {
+        let mut options = EarcutOptions::default();
+        options.hash_threshold = hash;
+        options.wide_zorder_threshold = wide;
+        options
+    });
+    for name in common::fixture_names() {
+        let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture(&name));
+        let expected = earcut(&vertices, &hole_indices, dims).unwrap();
+        for options in &options {
+            let triangles = earcut_with_options(&vertices, &hole_indices, dims, options).unwrap();
+            assert_eq!(triangles, expected, "{} {:?}", name, options);
+        }
+    }
+}
+
+// a square with a grid of small square holes, every other row shifted
+// so the rays from the holes run into corners and edges alike. the sides
+// of the square are made of unit steps, which get merged while bridging.
+fn square_with_holes(holes_per_side: usize) -> FlatPolygon<f64> {
+    let size = holes_per_side as f64 * 4.;
+    let steps = (0..holes_per_side * 4).map(|i| i as f64);
+    let mut vertices = Vec::new();
+    vertices.extend(steps.clone().flat_map(|i| [i, 0.]));
+    vertices.extend(steps.clone().flat_map(|i| [size, i]));
+    vertices.extend(steps.clone().flat_map(|i| [size - i, size]));
+    vertices.extend(steps.flat_map(|i| [0., size - i]));
+    let mut hole_indices = Vec::new();
+    for row in 0..holes_per_side {
+        for col in 0..holes_per_side {
+            let x = col as f64 * 4. + 1. + (row % 2) as f64;
+            let y = row as f64 * 4. + 1.;
+            hole_indices.push(vertices.len() / 2);
+            vertices.extend([x, y, x + 1., y, x + 1., y + 1., x, y + 1.]);
+        }
+    }
+    (vertices, hole_indices, 2)
+}
+
+#[test]
+fn test_hole_grid_does_not_change_output() {
+    let mut polygons = common::fixture_names()
+        .iter()
+        .map(|name| (name.clone(), earcutr::flatten(&common::load_fixture(name))))
+        .collect::<Vec<_>>();
+    polygons.push(("square_with_holes".to_string(), square_with_holes(30)));
+    for (name, (vertices, hole_indices, dims)) in polygons {
+        for hole_order in [HoleOrder::LeftToRight, HoleOrder::Input] {
+            let run = |hole_grid_threshold| {
+                let mut options = EarcutOptions::default();
+                options.hole_grid_threshold = hole_grid_threshold;
+                options.hole_order = hole_order;
+                earcut_with_options(&vertices, &hole_indices, dims, &options).unwrap()
+            };
+            assert_eq!(run(0), run(usize::MAX), "{} {:?}", name, hole_order);
+        }
+    }
+}
+
+#[test]
+fn test_hole_order() {
+    // the right hole comes first
+    let vertices = vec![
+        0., 0., 30., 0., 30., 10., 0., 10., 20., 2., 20., 8., 28., 8., 28., 2., 2., 2., 2., 8., 8.,
+        8., 8., 2.,
+    ];
+    let hole_indices = vec![4, 8];
+    let left_to_right = earcut(&vertices, &hole_indices, 2).unwrap();
+    let mut options = EarcutOptions::default();
+    options.hole_order = HoleOrder::Input;
+    let input = earcut_with_options(&vertices, &hole_indices, 2, &options).unwrap();
+    assert_ne!(input, left_to_right);
+    for triangles in [&left_to_right, &input] {
+        assert_eq!(
+            earcutr::deviation(&vertices, &hole_indices, 2, triangles),
+            0.0
+        );
+    }
+}
+
+#[test]
+fn test_nearest_bridging() {
+    let mut nearest = EarcutOptions::default();
+    nearest.bridging = Bridging::Nearest;
+    // mean smallest angle and slivers, over the fixtures with holes that
+    // come out exact either way
+    let (mut eberly_stats, mut nearest_stats) = ((0.0, 0), (0.0, 0));
+    for name in common::fixture_names() {
+        let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture(&name));
+        if hole_indices.is_empty() {
+            continue;
+        }
+        let eberly = earcut(&vertices, &hole_indices, dims).unwrap();
+        if eberly.is_empty() || earcutr::deviation(&vertices, &hole_indices, dims, &eberly) != 0.0 {
+            continue;
+        }
+        let triangles = earcut_with_options(&vertices, &hole_indices, dims, &nearest).unwrap();
+        assert_eq!(
+            earcutr::deviation(&vertices, &hole_indices, dims, &triangles),
+            0.0,
+            "{}",
+            name
+        );
+        for (triangles, stats) in [
+            (&eberly, &mut eberly_stats),
+            (&triangles, &mut nearest_stats),
+        ] {
+            let metrics = measure(&vertices, &hole_indices, dims, triangles).unwrap();
+            stats.0 += metrics.mean_min_angle;
+            stats.1 += metrics.slivers;
+        }
+    }
+    assert!(nearest_stats.0 > eberly_stats.0);
+    assert!(nearest_stats.1 < eberly_stats.1);
+}
+
+#[test]
+fn test_repair() {
+    let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture("water"));
+    let mut previous = 0;
+    for repair in [
+        Repair::None,
+        Repair::FilterPoints,
+        Repair::CureIntersections,
+        Repair::SplitPolygon,
+    ] {
+        let mut options = EarcutOptions::default();
+        options.repair = repair;
+        let triangles = earcut_with_options(&vertices, &hole_indices, dims, &options).unwrap();
+        assert!(triangles.len() >= previous, "{:?}", repair);
+        previous = triangles.len();
+    }
+    assert_eq!(
+        previous,
+        earcut(&vertices, &hole_indices, dims).unwrap().len()
+    );
+
+    // the diagonals here need splitting to be found
+    let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture("bad-diagonals"));
+    let mut options = EarcutOptions::default();
+    options.repair = Repair::None;
+    let triangles = earcut_with_options(&vertices, &hole_indices, dims, &options).unwrap();
+    assert!(triangles.is_empty());
+}
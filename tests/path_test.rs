@@ -0,0 +1,287 @@
+extern crate earcutr;
+
+use std::f64::consts::PI;
+
+use earcutr::path::{Path, PathBuilder};
+
+mod common;
+
+fn points(path: &Path) -> Vec<[f64; 2]> {
+    path.vertices.chunks(2).map(|p| [p[0], p[1]]).collect()
+}
+
+// how far p is from the polyline through the points
+fn distance(points: &[[f64; 2]], p: [f64; 2]) -> f64 {
+    points
+        .windows(2)
+        .map(|w| {
+            let [a, b] = [w[0], w[1]];
+            let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+            let t = ((p[0] - a[0]) * dx + (p[1] - a[1]) * dy) / (dx * dx + dy * dy);
+            let t = t.clamp(0.0, 1.0);
+            (p[0] - a[0] - t * dx).hypot(p[1] - a[1] - t * dy)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+#[test]
+fn test_lines() {
+    let mut builder = PathBuilder::new(0.1);
+    builder.move_to(0., 0.);
+    builder.line_to(4., 0.);
+    builder.line_to(4., 4.);
+    builder.line_to(0., 4.);
+    // back to the start, which is dropped
+    builder.line_to(0., 0.);
+    builder.close();
+    // a hole drawn from where the outer ring started
+    builder.move_to(5., 5.);
+    builder.move_to(1., 1.);
+    builder.line_to(1., 3.);
+    builder.line_to(3., 3.);
+    builder.line_to(3., 1.);
+    let path = builder.build();
+    assert_eq!(path.vertices.len(), 2 * 8);
+    assert_eq!(path.hole_indices, vec![4]);
+    assert!(path.segments.is_empty());
+    let area = common::triangulated_area(&path.vertices, &path.hole_indices, 2);
+    assert_eq!(area, 12.);
+
+    // drawing after close starts another contour where the last began
+    let mut builder = PathBuilder::new(0.1);
+    builder.line_to(1., 0.);
+    builder.line_to(0., 1.);
+    builder.close();
+    builder.line_to(-1., 0.);
+    builder.line_to(0., -1.);
+    let path = builder.build();
+    assert_eq!(path.hole_indices, vec![3]);
+    assert_eq!(points(&path)[3], [0., 0.]);
+
+    assert_eq!(PathBuilder::new(0.1).build(), Path::default());
+}
+
+#[test]
+fn test_curves() {
+    let quad = |t: f64| {
+        let s = 1. - t;
+        [2. * s * t * 5. + t * t * 10., 2. * s * t * 10.]
+    };
+    let cubic = |t: f64| {
+        let s = 1. - t;
+        [
+            3. * s * s * t * 0. + 3. * s * t * t * 10. + t * t * t * 10.,
+            3. * s * s * t * 10. + 3. * s * t * t * 10.,
+        ]
+    };
+    let mut counts = Vec::new();
+    for tolerance in [1., 0.1, 0.01, 0.001] {
+        let mut builder = PathBuilder::new(tolerance);
+        builder.quad_to(5., 10., 10., 0.);
+        let path = builder.build();
+        let flat = points(&path);
+        assert_eq!(flat[0], [0., 0.]);
+        assert_eq!(*flat.last().unwrap(), [10., 0.]);
+        for i in 0..=100 {
+            assert!(distance(&flat, quad(i as f64 / 100.)) <= tolerance);
+        }
+
+        let mut builder = PathBuilder::new(tolerance);
+        builder.cubic_to(0., 10., 10., 10., 10., 0.);
+        let flat = points(&builder.build());
+        for i in 0..=100 {
+            assert!(distance(&flat, cubic(i as f64 / 100.)) <= tolerance);
+        }
+        counts.push(flat.len());
+    }
+    assert!(counts.windows(2).all(|w| w[0] < w[1]));
+
+    // a straight curve stays one line
+    let mut builder = PathBuilder::new(0.01);
+    builder.cubic_to(1., 0., 2., 0., 3., 0.);
+    assert_eq!(builder.build().vertices, vec![0., 0., 3., 0.]);
+}
+
+#[test]
+fn test_arcs() {
+    // a circle of two halves, over the top and back under
+    let tolerance = 0.001;
+    let mut builder = PathBuilder::new(tolerance);
+    builder.move_to(1., 0.);
+    builder.arc_to(1., 1., 0., false, true, -1., 0.);
+    builder.arc_to(1., 1., 0., false, true, 1., 0.);
+    let path = builder.build();
+    let flat = points(&path);
+    assert!(flat[1][1] > 0.);
+    for p in &flat {
+        assert!((p[0].hypot(p[1]) - 1.).abs() < 1e-12);
+    }
+    for i in 0..360 {
+        let angle = (i as f64).to_radians();
+        let p = [angle.cos(), angle.sin()];
+        assert!(distance(&[&flat[..], &flat[..1]].concat(), p) <= tolerance);
+    }
+    let area = common::triangulated_area(&path.vertices, &path.hole_indices, 2);
+    assert!((area - PI).abs() < PI * 2. * tolerance);
+
+    // the large arc the other way round, on an ellipse turned a quarter:
+    // round the far side of its center at (-sqrt(3) / 2, 1)
+    let mut builder = PathBuilder::new(0.01);
+    builder.move_to(0., 0.);
+    builder.arc_to(2., 1., PI / 2., true, false, 0., 2.);
+    let flat = points(&builder.build());
+    for p in &flat {
+        let [x, y] = [p[0] + 3_f64.sqrt() / 2., (p[1] - 1.) / 2.];
+        assert!((x * x + y * y - 1.).abs() < 1e-9);
+    }
+    assert!(flat.iter().any(|p| p[0] < -1.8));
+
+    // radii too small to reach are scaled up to a half circle, clockwise
+    // over the top
+    let mut builder = PathBuilder::new(0.01);
+    builder.move_to(0., 0.);
+    builder.arc_to(0.5, 0.5, 0., false, false, 2., 0.);
+    let flat = points(&builder.build());
+    assert!(flat
+        .iter()
+        .all(|p| ((p[0] - 1.).hypot(p[1]) - 1.).abs() < 1e-9));
+    assert!(flat.iter().any(|p| p[1] > 0.99));
+
+    // no radius draws a line, and no distance nothing
+    let mut builder = PathBuilder::new(0.01);
+    builder.arc_to(0., 1., 0., false, false, 2., 0.);
+    builder.arc_to(1., 1., 0., false, false, 2., 0.);
+    assert_eq!(builder.build().vertices, vec![0., 0., 2., 0.]);
+}
+
+#[test]
+fn test_segments() {
+    let mut builder = PathBuilder::new(0.1);
+    builder.record_segments();
+    builder.move_to(0., 0.); // 0
+    builder.line_to(10., 0.); // 1
+    builder.quad_to(15., 5., 10., 10.); // 2
+    builder.line_to(0., 10.); // 3
+    builder.close(); // 4
+    builder.move_to(2., 2.); // 5
+    builder.arc_to(1., 1., 0., false, false, 4., 2.); // 6
+    let path = builder.build();
+    assert_eq!(path.segments.len(), path.vertices.len() / 2);
+    let first = path.hole_indices[0];
+    assert_eq!(path.segments[..2], [0, 1]);
+    assert!(path.segments[2..first - 1].iter().all(|&s| s == 2));
+    assert_eq!(path.segments[first - 1], 3);
+    assert_eq!(path.segments[first], 5);
+    assert!(path.segments[first + 1..].iter().all(|&s| s == 6));
+
+    // every triangle corner can look its segment up
+    let triangles = path.earcut().unwrap();
+    assert!(triangles.iter().all(|&i| path.segments[i] <= 6));
+}
+
+// the square with a bulging top and a square hole that the kurbo and lyon
+// tests draw
+fn draw(builder: &mut PathBuilder) {
+    builder.move_to(0., 0.);
+    builder.line_to(10., 0.);
+    builder.line_to(10., 10.);
+    builder.quad_to(5., 15., 0., 10.);
+    builder.close();
+    builder.move_to(2., 2.);
+    builder.cubic_to(2., 4., 2., 6., 2., 8.);
+    builder.line_to(8., 8.);
+    builder.line_to(8., 2.);
+    builder.close();
+}
+
+#[test]
+fn test_fill() {
+    let mut builder = PathBuilder::new(0.01);
+    draw(&mut builder);
+    let path = builder.build();
+    let (vertices, indices) = path.fill::<_, u16>(|x, y| (x, y)).unwrap();
+    assert_eq!(vertices.len(), path.vertices.len() / 2);
+    assert_eq!(vertices[1], (10., 0.));
+    let triangles = path.earcut().unwrap();
+    assert_eq!(
+        indices,
+        triangles.iter().map(|&i| i as u16).collect::<Vec<_>>()
+    );
+
+    // more vertices than the index type counts
+    let mut builder = PathBuilder::new(1e-6);
+    builder.move_to(1., 0.);
+    builder.arc_to(1., 1., 0., false, true, -1., 0.);
+    builder.arc_to(1., 1., 0., false, true, 1., 0.);
+    assert!(builder.build().fill::<_, u8>(|x, y| [x, y]).is_err());
+}
+
+#[test]
+fn test_kurbo() {
+    let mut bez = kurbo::BezPath::new();
+    bez.move_to((0., 0.));
+    bez.line_to((10., 0.));
+    bez.line_to((10., 10.));
+    bez.quad_to((5., 15.), (0., 10.));
+    bez.close_path();
+    bez.move_to((2., 2.));
+    bez.curve_to((2., 4.), (2., 6.), (2., 8.));
+    bez.line_to((8., 8.));
+    bez.line_to((8., 2.));
+    bez.close_path();
+
+    let mut builder = PathBuilder::new(0.01);
+    builder.record_segments();
+    draw(&mut builder);
+    let mut from_kurbo = PathBuilder::new(0.01);
+    from_kurbo.record_segments();
+    from_kurbo.add_bez_path(&bez);
+    assert_eq!(from_kurbo.build(), builder.build());
+
+    let circle = kurbo::Shape::to_path(&kurbo::Circle::new((0., 0.), 1.), 1e-3);
+    let path = Path::from_bez_path(&circle, 1e-3);
+    let area = common::triangulated_area(&path.vertices, &path.hole_indices, 2);
+    assert!((area - PI).abs() < 1e-2);
+    let (points, indices) = path.fill::<_, u32>(kurbo::Point::new).unwrap();
+    assert!(points
+        .iter()
+        .all(|p| (p.to_vec2().hypot() - 1.).abs() < 1e-2));
+    assert_eq!(indices.len(), 3 * (points.len() - 2));
+}
+
+#[test]
+fn test_lyon() {
+    use lyon_tessellation::math::point;
+
+    let mut lyon = lyon_tessellation::path::Path::builder();
+    lyon.begin(point(0., 0.));
+    lyon.line_to(point(10., 0.));
+    lyon.line_to(point(10., 10.));
+    lyon.quadratic_bezier_to(point(5., 15.), point(0., 10.));
+    lyon.end(true);
+    lyon.begin(point(2., 2.));
+    lyon.cubic_bezier_to(point(2., 4.), point(2., 6.), point(2., 8.));
+    lyon.line_to(point(8., 8.));
+    lyon.line_to(point(8., 2.));
+    lyon.end(false);
+    let lyon = lyon.build();
+
+    let mut builder = PathBuilder::new(0.01);
+    draw(&mut builder);
+    let path = builder.build();
+    assert_eq!(Path::from_lyon_path(&lyon, 0.01), path);
+
+    let buffers = path.vertex_buffers::<u16>().unwrap();
+    assert_eq!(buffers.vertices.len(), path.vertices.len() / 2);
+    assert_eq!(buffers.vertices[2], point(10., 10.));
+    let covered: f32 = buffers
+        .indices
+        .chunks(3)
+        .map(|t| {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| buffers.vertices[i as usize]);
+            (b - a).cross(c - a) / 2.
+        })
+        .sum();
+    let area = common::triangulated_area(&path.vertices, &path.hole_indices, 2);
+    assert!((f64::from(covered) - area).abs() < 1e-3);
+}
@@ -0,0 +1,241 @@
+extern crate earcutr;
+
+use earcutr::shapefile::{parse_index, parse_record, parse_records};
+use earcutr::Error;
+
+mod common;
+
+fn square(min: f64, max: f64, z: f64) -> Vec<Vec<f64>> {
+    // clockwise, as shapefile outer rings go
+    [[min, min], [min, max], [max, max], [max, min], [min, min]]
+        .iter()
+        .map(|p| vec![p[0], p[1], z])
+        .collect()
+}
+
+fn reversed(mut ring: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    ring.reverse();
+    ring
+}
+
+// the content of a record of the shape type, with rings of x, y and then
+// as many z and m values as the type takes
+fn record(shape_type: i32, rings: &[Vec<Vec<f64>>], values: usize) -> Vec<u8> {
+    let mut content = Vec::new();
+    content.extend(shape_type.to_le_bytes());
+    content.extend([0; 32]);
+    content.extend((rings.len() as i32).to_le_bytes());
+    let points: Vec<&Vec<f64>> = rings.iter().flatten().collect();
+    content.extend((points.len() as i32).to_le_bytes());
+    let mut start = 0;
+    for ring in rings {
+        content.extend((start as i32).to_le_bytes());
+        start += ring.len();
+    }
+    for p in &points {
+        content.extend(p[0].to_le_bytes());
+        content.extend(p[1].to_le_bytes());
+    }
+    for value in 2..2 + values {
+        content.extend([0; 16]);
+        for p in &points {
+            content.extend(p[value].to_le_bytes());
+        }
+    }
+    content
+}
+
+fn header(length: usize) -> Vec<u8> {
+    let mut header = Vec::new();
+    header.extend(9994_i32.to_be_bytes());
+    header.extend([0; 20]);
+    header.extend((length as i32 / 2).to_be_bytes());
+    header.extend(1000_i32.to_le_bytes());
+    header.extend(5_i32.to_le_bytes());
+    header.extend([0; 64]);
+    header
+}
+
+// a .shp file and its .shx index
+fn shapefile(records: &[Vec<u8>]) -> (Vec<u8>, Vec<u8>) {
+    let mut shp = Vec::new();
+    let mut shx = Vec::new();
+    for (i, content) in records.iter().enumerate() {
+        shx.extend((50 + shp.len() as i32 / 2).to_be_bytes());
+        shx.extend((content.len() as i32 / 2).to_be_bytes());
+        shp.extend((i as i32 + 1).to_be_bytes());
+        shp.extend((content.len() as i32 / 2).to_be_bytes());
+        shp.extend(content);
+    }
+    (
+        [header(100 + shp.len()), shp].concat(),
+        [header(100 + shx.len()), shx].concat(),
+    )
+}
+
+#[test]
+fn test_fixtures() {
+    for name in common::fixture_names() {
+        let rings = common::load_fixture(&name);
+        let expected = earcutr::flatten(&rings);
+        // wound the shapefile way, and left as they are where that already
+        // holds
+        let wound: Vec<Vec<Vec<f64>>> = rings
+            .iter()
+            .enumerate()
+            .map(
+                |(i, ring)| match (earcutr::signed_area(ring) > 0.0) == (i == 0) {
+                    true => reversed(ring.clone()),
+                    false => ring.clone(),
+                },
+            )
+            .collect();
+        let (shp, _) = shapefile(&[record(5, &wound, 0)]);
+        let records = parse_records(&shp).unwrap();
+        assert_eq!(records.len(), 1, "{}", name);
+        let ring_count: usize = records[0].iter().map(|p| p.1.len() + 1).sum();
+        assert_eq!(ring_count, rings.len(), "{}", name);
+        if records[0].len() != 1 {
+            // holes sharing the edge their probe is on with the outer ring,
+            // or lying outside it, come out as polygons of their own
+            let split = [
+                "issue149",
+                "issue83",
+                "water3",
+                "water4",
+                "water-huge",
+                "water-huge2",
+            ];
+            assert!(split.contains(&name.as_str()), "{}", name);
+            continue;
+        }
+        let (vertices, hole_indices, dims) = &records[0][0];
+        assert_eq!(*dims, 2);
+        assert_eq!(hole_indices.len(), expected.1.len(), "{}", name);
+        // the rings come back exactly as written, so with the same area
+        assert_eq!(records[0][0], earcutr::flatten(&wound), "{}", name);
+        if wound == rings {
+            assert_eq!(records[0][0], expected, "{}", name);
+        }
+        let triangles = earcutr::earcut(vertices, hole_indices, *dims).unwrap();
+        let report = earcutr::verify(vertices, hole_indices, *dims, &triangles).unwrap();
+        let written = earcutr::flatten(&wound);
+        let written = earcutr::verify(&written.0, &written.1, 2, &[]).unwrap();
+        assert_eq!(report.polygon_area, written.polygon_area, "{}", name);
+    }
+}
+
+#[test]
+fn test_classification() {
+    // two islands, the larger with a lake holding an island of its own,
+    // and every hole listed before the ring it lies in
+    let rings = [
+        reversed(square(2., 8., 1.)),
+        square(3., 4., 2.),
+        reversed(square(21., 22., 3.)),
+        square(0., 10., 4.),
+        square(20., 30., 5.),
+    ];
+    let (shp, _) = shapefile(&[record(15, &rings, 1)]);
+    let polygons = &parse_records(&shp).unwrap()[0];
+    assert_eq!(polygons.len(), 3);
+    let firsts: Vec<&[f64]> = polygons.iter().map(|p| &p.0[..3]).collect();
+    assert_eq!(firsts, [[3., 3., 2.], [0., 0., 4.], [20., 20., 5.]]);
+    assert!(polygons[0].1.is_empty());
+    assert_eq!(polygons[1].1, vec![5]);
+    assert_eq!(polygons[1].0[15..18], [2., 2., 1.]);
+    assert_eq!(polygons[2].1, vec![5]);
+    assert_eq!(polygons[2].2, 3);
+
+    let mut area = 0.0;
+    for (vertices, hole_indices, dims) in polygons {
+        let triangles = earcutr::earcut(vertices, hole_indices, *dims).unwrap();
+        let report = earcutr::verify(vertices, hole_indices, *dims, &triangles).unwrap();
+        assert!(report.is_valid(1e-12));
+        area += report.polygon_area;
+    }
+    assert_eq!(area, 1. + 100. - 36. + 100. - 1.);
+
+    // a hole with nothing around it is an outer ring wound the wrong way
+    let (shp, _) = shapefile(&[record(5, &[reversed(square(0., 1., 0.))], 0)]);
+    let polygons = &parse_records(&shp).unwrap()[0];
+    assert_eq!(polygons.len(), 1);
+    assert_eq!(polygons[0].0[..4], [0., 0., 1., 0.]);
+}
+
+#[test]
+fn test_measures() {
+    let ring: Vec<Vec<f64>> = square(0., 1., 2.)
+        .into_iter()
+        .map(|p| vec![p[0], p[1], p[2], -p[2]])
+        .collect();
+    let rings = [ring];
+
+    // PolygonZ with and without measures
+    let z = parse_record(&record(15, &rings, 2)).unwrap();
+    assert_eq!((z[0].0[..4].to_vec(), z[0].2), (vec![0., 0., 2., -2.], 4));
+    let z = parse_record(&record(15, &rings, 1)).unwrap();
+    assert_eq!((z[0].0[..3].to_vec(), z[0].2), (vec![0., 0., 2.], 3));
+
+    // PolygonM keeps the values it finds first as measures
+    let m = parse_record(&record(25, &rings, 1)).unwrap();
+    assert_eq!((m[0].0[..3].to_vec(), m[0].2), (vec![0., 0., 2.], 3));
+    let m = parse_record(&record(25, &rings, 0)).unwrap();
+    assert_eq!(m[0].2, 2);
+}
+
+#[test]
+fn test_index() {
+    let records = [
+        record(5, &[square(0., 1., 0.)], 0),
+        0_i32.to_le_bytes().to_vec(),
+        record(5, &[square(0., 2., 0.), reversed(square(0.5, 1.5, 0.))], 0),
+    ];
+    let (shp, shx) = shapefile(&records);
+    let index = parse_index(&shx).unwrap();
+    assert_eq!(index.len(), 3);
+    let all = parse_records(&shp).unwrap();
+    assert!(all[1].is_empty());
+    for (range, polygons) in index.into_iter().zip(&all) {
+        assert_eq!(&parse_record(&shp[range]).unwrap(), polygons);
+    }
+    assert_eq!(all[2][0].1, vec![5]);
+}
+
+#[test]
+fn test_errors() {
+    let content = record(5, &[square(0., 1., 0.)], 0);
+    let (shp, shx) = shapefile(std::slice::from_ref(&content));
+    assert_eq!(
+        parse_records(&shp[..shp.len() - 1]),
+        Err(Error::InvalidShapefile)
+    );
+    assert_eq!(parse_records(&shp[..99]), Err(Error::InvalidShapefile));
+    let mut wrong_code = shp.clone();
+    wrong_code[3] = 0;
+    assert_eq!(parse_records(&wrong_code), Err(Error::InvalidShapefile));
+    let mut wrong_version = shx.clone();
+    wrong_version[28] = 0;
+    assert_eq!(parse_index(&wrong_version), Err(Error::InvalidShapefile));
+
+    // points and polylines are not polygons
+    let mut point = content.clone();
+    point[0] = 1;
+    assert_eq!(parse_record(&point), Err(Error::InvalidShapefile));
+    // a first part starting past the first point, parts out of order
+    let mut offset = content.clone();
+    offset[44] = 1;
+    assert_eq!(parse_record(&offset), Err(Error::InvalidShapefile));
+    let parts = record(5, &[square(0., 1., 0.), square(2., 3., 0.)], 0);
+    let mut backwards = parts.clone();
+    backwards[48] = 200;
+    assert_eq!(parse_record(&backwards), Err(Error::InvalidShapefile));
+    // bytes left over
+    let trailing = [content, vec![0; 8]].concat();
+    assert_eq!(parse_record(&trailing), Err(Error::InvalidShapefile));
+    // a Polygon record with measures it does not take
+    assert_eq!(
+        parse_record(&record(5, &[square(0., 1., 0.)], 1)),
+        Err(Error::InvalidShapefile)
+    );
+}
@@ -0,0 +1,258 @@
+extern crate earcutr;
+
+use std::f64::consts::PI;
+use std::fs;
+
+use earcutr::fill::FillRule;
+use earcutr::svg::{parse_path_data, parse_points, parse_shapes, to_svg, SvgOptions};
+use earcutr::{Bridging, EarcutOptions, Error};
+
+fn square_with_hole() -> (Vec<f64>, Vec<usize>) {
+    let vertices = vec![
+        0., 0., 100., 0., 100., 100., 0., 100., 20., 20., 80., 20., 80., 80., 20., 80.,
+    ];
+    (vertices, vec![4])
+}
+
+#[test]
+fn test_to_svg() {
+    let (vertices, holes) = square_with_hole();
+    let (triangles, bridges) =
+        earcutr::earcut_with_bridges(&vertices, &holes, 2, &EarcutOptions::default()).unwrap();
+    let svg = to_svg(
+        &vertices,
+        &holes,
+        2,
+        &triangles,
+        Some(&bridges),
+        &SvgOptions::default(),
+    )
+    .unwrap();
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"800\""));
+    assert!(svg.contains("viewBox=\"-2 -2 104 104\""));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    // 8 triangles and 2 rings
+    assert_eq!(svg.matches("<polygon").count(), 10);
+    assert!(svg.contains(r#"<polygon points="0,0 100,0 100,100 0,100"/>"#));
+    // one bridge for the one hole
+    assert_eq!(svg.matches("<line").count(), 1);
+}
+
+#[test]
+fn test_to_svg_bridges() {
+    // a hole near the top right corner, bridged to the far corner by
+    // default and to the near one when the shortest bridge is asked for
+    let vertices = vec![
+        0., 0., 100., 0., 100., 100., 0., 100., 70., 70., 90., 70., 90., 90., 70., 90.,
+    ];
+    let mut earcut_options = EarcutOptions::default();
+    let svg = |earcut_options: &EarcutOptions| {
+        let (triangles, bridges) =
+            earcutr::earcut_with_bridges(&vertices, &[4], 2, earcut_options).unwrap();
+        let options = SvgOptions::default();
+        to_svg(&vertices, &[4], 2, &triangles, Some(&bridges), &options).unwrap()
+    };
+    assert!(svg(&earcut_options).contains(r#"<line x1="70" y1="70" x2="0" y2="0"/>"#));
+    earcut_options.bridging = Bridging::Nearest;
+    assert!(svg(&earcut_options).contains(r#"<line x1="90" y1="90" x2="100" y2="100"/>"#));
+}
+
+#[test]
+fn test_to_svg_options() {
+    let (vertices, holes) = square_with_hole();
+    let triangles = earcutr::earcut(&vertices, &holes, 2).unwrap();
+    let options = SvgOptions {
+        width: 200.0,
+        rings: false,
+        triangles: false,
+        problems: vec![0, 7],
+    };
+    let svg = to_svg(&vertices, &holes, 2, &triangles, None, &options).unwrap();
+    assert!(svg.contains("width=\"200\" height=\"200\""));
+    assert_eq!(svg.matches("<polygon").count(), 2);
+    assert!(svg.contains("fill=\"red\""));
+    assert!(!svg.contains("<line"));
+
+    let options = SvgOptions {
+        problems: vec![8],
+        ..SvgOptions::default()
+    };
+    assert_eq!(
+        to_svg(&vertices, &holes, 2, &triangles, None, &options),
+        Err(Error::Unknown)
+    );
+    // bridges to vertices that are not there
+    assert_eq!(
+        to_svg(
+            &vertices,
+            &holes,
+            2,
+            &triangles,
+            Some(&[(4, 99)]),
+            &SvgOptions::default()
+        ),
+        Err(Error::Unknown)
+    );
+}
+
+#[test]
+fn test_to_svg_steiner_and_dims() {
+    // a 3d square with a steiner point in the middle
+    let vertices = vec![
+        0., 0., 1., 10., 0., 1., 10., 10., 1., 0., 10., 1., 5., 5., 1.,
+    ];
+    let triangles = earcutr::earcut(&vertices, &[4], 3).unwrap();
+    assert_eq!(triangles.len(), 12);
+    let svg = to_svg(&vertices, &[4], 3, &triangles, None, &SvgOptions::default()).unwrap();
+    assert_eq!(svg.matches("<circle cx=\"5\" cy=\"5\"").count(), 1);
+
+    assert!(to_svg(&vertices, &[4], 2, &triangles, None, &SvgOptions::default()).is_err());
+    assert!(to_svg(
+        &vertices,
+        &[4],
+        3,
+        &[0, 1, 99],
+        None,
+        &SvgOptions::default()
+    )
+    .is_err());
+    assert!(to_svg::<f64>(&[], &[], 2, &[], None, &SvgOptions::default()).is_ok());
+}
+
+fn path_points(d: &str) -> Vec<f64> {
+    parse_path_data(d, 0.01).unwrap().vertices
+}
+
+// the area the triangles cover
+fn covered((vertices, triangles): (Vec<f64>, Vec<usize>)) -> f64 {
+    triangles
+        .chunks(3)
+        .map(|t| {
+            let [a, b, c] = [t[0], t[1], t[2]].map(|i| [vertices[2 * i], vertices[2 * i + 1]]);
+            ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])).abs() / 2.
+        })
+        .sum()
+}
+
+#[test]
+fn test_parse_path_data() {
+    let square = vec![0., 0., 10., 0., 10., 10., 0., 10.];
+    assert_eq!(path_points("M 0 0 H 10 V 10 H 0 Z"), square);
+    assert_eq!(path_points("m0 0h10v10h-10z"), square);
+    // pairs after a move are lines, and numbers run together
+    assert_eq!(path_points("M0,0 10,0 10,10 0,10z"), square);
+    assert_eq!(path_points("M0-0L10-0 10 10-0 10"), square);
+    assert_eq!(
+        path_points("M.5.5l1e1 0,-.5e1 1E+1"),
+        vec![0.5, 0.5, 10.5, 0.5, 5.5, 10.5]
+    );
+
+    // drawing on after a close starts from where the contour began
+    let path = parse_path_data("M 5 5 l 10 0 l 0 10 z l -5 0 l 0 -5 z", 0.01).unwrap();
+    assert_eq!(path.hole_indices, vec![3]);
+    assert_eq!(path.vertices[6..], [5., 5., 0., 5., 0., 0.]);
+
+    // smooth curves mirror the last control point
+    assert_eq!(
+        path_points("M0 0C0 10 10 10 10 0S20-10 20 0"),
+        path_points("M0 0C0 10 10 10 10 0C10-10 20-10 20 0")
+    );
+    assert_eq!(
+        path_points("M0 0Q5 10 10 0T20 0"),
+        path_points("M0 0Q5 10 10 0Q15-10 20 0")
+    );
+    // but only those of curves of their own kind
+    assert_eq!(
+        path_points("M0 0L10 0S20 10 20 0"),
+        path_points("M0 0L10 0C10 0 20 10 20 0")
+    );
+
+    // arc flags need no separators
+    assert_eq!(
+        path_points("M0 0a5 5 0 1010 0"),
+        path_points("M0 0 A 5,5 0 1,0 10,0")
+    );
+    let arc = path_points("M0 0a5 5 0 1010 0");
+    assert!(arc
+        .chunks(2)
+        .all(|p| ((p[0] - 5.).hypot(p[1]) - 5.).abs() < 1e-9));
+
+    for d in [
+        "L 1 1",
+        "M 0",
+        "M 0 0 X 1",
+        "M 0 0 Z 1 1",
+        "M 0 0 A 1 1 0 2 0 1 1",
+        "M 0 0 L 1 1e",
+    ] {
+        assert_eq!(parse_path_data(d, 0.01), Err(Error::InvalidSvg), "{d}");
+    }
+    assert!(parse_path_data("", 0.01).unwrap().vertices.is_empty());
+}
+
+#[test]
+fn test_parse_points() {
+    assert_eq!(
+        parse_points(" 0,0 10,0\n10 , 10 ").unwrap(),
+        vec![0., 0., 10., 0., 10., 10.]
+    );
+    assert_eq!(parse_points("0,0 10"), Err(Error::InvalidSvg));
+    assert_eq!(parse_points("0,0 x"), Err(Error::InvalidSvg));
+}
+
+#[test]
+fn test_parse_shapes() {
+    let star = fs::read_to_string("tests/svg/star.svg").unwrap();
+    let shapes = parse_shapes(&star, 0.01).unwrap();
+    assert_eq!(shapes.len(), 2);
+    assert_eq!(shapes[0].fill_rule, FillRule::EvenOdd);
+    assert_eq!(shapes[1].fill_rule, FillRule::NonZero);
+    let (tips, whole) = (
+        covered(shapes[0].tessellate().unwrap()),
+        covered(shapes[1].tessellate().unwrap()),
+    );
+    // the pentagon in the middle is only filled under nonzero, and winds
+    // twice, so the signed area of the outline counts it twice
+    assert!(tips > 1800. && whole - tips > 800.);
+    assert!((2. * whole - tips - 3655.).abs() < 1e-6);
+
+    let donut = fs::read_to_string("tests/svg/donut.svg").unwrap();
+    let shapes = parse_shapes(&donut, 0.01).unwrap();
+    assert_eq!(shapes.len(), 1);
+    assert_eq!(shapes[0].path.hole_indices.len(), 1);
+    let area = covered(shapes[0].tessellate().unwrap());
+    assert!((area - PI * (40. * 40. - 20. * 20.)).abs() < 5.);
+
+    let icon = fs::read_to_string("tests/svg/icon.svg").unwrap();
+    let shapes = parse_shapes(&icon, 0.01).unwrap();
+    assert_eq!(shapes.len(), 4);
+    let fill_rules: Vec<_> = shapes.iter().map(|s| s.fill_rule).collect();
+    assert_eq!(
+        fill_rules,
+        [
+            FillRule::NonZero,
+            FillRule::EvenOdd,
+            FillRule::NonZero,
+            FillRule::NonZero
+        ]
+    );
+    let areas: Vec<f64> = shapes
+        .iter()
+        .map(|s| covered(s.tessellate().unwrap()))
+        .collect();
+    // the house, the walls less the door, and the polyline closed
+    assert_eq!(areas[..3], [240., 152., 2.]);
+    assert!(areas[3] > 0.);
+
+    assert_eq!(
+        parse_shapes("<path d='M 0 0 L'/>", 0.01),
+        Err(Error::InvalidSvg)
+    );
+    assert_eq!(parse_shapes("<path d=0/>", 0.01), Err(Error::InvalidSvg));
+    assert_eq!(parse_shapes("<!-- open", 0.01), Err(Error::InvalidSvg));
+    assert_eq!(
+        parse_shapes(r#"<path d="M0 0" fill-rule="sometimes"/>"#, 0.01),
+        Err(Error::InvalidSvg)
+    );
+}
@@ -0,0 +1,37 @@
+extern crate earcutr;
+
+use std::fs;
+
+use earcutr::EarcutOptions;
+
+mod common;
+
+// every fixture, with the triangles earcut.js makes of it, from
+// tests/upstream (see viz/upstream.js).
+//
+// still to be added from earcut.js 3.0's test/fixtures, each to
+// tests/fixtures, tests/upstream and integration_test: issue107, issue111,
+// issue119, issue131, boxy, collinear-diagonal, hourglass, touching2,
+// touching3, touching4, touching-dups, rain
+fn fixtures() -> Vec<(String, earcutr::FlatPolygon<f64>, Vec<usize>)> {
+    common::fixture_names()
+        .into_iter()
+        .map(|name| {
+            let polygon = earcutr::flatten(&common::load_fixture_exact(&name));
+            let json = fs::read_to_string(format!("tests/upstream/{}.json", name))
+                .unwrap_or_else(|_| panic!("no upstream output for {}", name));
+            let triangles = serde_json::from_str(&json).unwrap();
+            (name, polygon, triangles)
+        })
+        .collect()
+}
+
+#[test]
+fn test_upstream() {
+    for (name, (vertices, hole_indices, dims), expected) in fixtures() {
+        let options = EarcutOptions::upstream();
+        let triangles =
+            earcutr::earcut_with_options(&vertices, &hole_indices, dims, &options).unwrap();
+        assert_eq!(triangles, expected, "{}", name);
+    }
+}
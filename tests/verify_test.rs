@@ -0,0 +1,113 @@
+extern crate earcutr;
+
+use earcutr::svg::{to_svg, SvgOptions};
+use earcutr::verify;
+
+mod common;
+
+fn square_with_hole() -> (Vec<f64>, Vec<usize>) {
+    let vertices = vec![
+        0., 0., 10., 0., 10., 10., 0., 10., 2., 2., 8., 2., 8., 8., 2., 8.,
+    ];
+    (vertices, vec![4])
+}
+
+#[test]
+fn test_verify() {
+    let (vertices, holes) = square_with_hole();
+    let triangles = earcutr::earcut(&vertices, &holes, 2).unwrap();
+    let verification = verify(&vertices, &holes, 2, &triangles).unwrap();
+    assert_eq!(verification.polygon_area, 64.);
+    assert_eq!(verification.triangles_area, 64.);
+    assert_eq!(verification.deviation, 0.);
+    assert!(verification.is_valid(0.));
+    assert!(verification.problems().is_empty());
+}
+
+#[test]
+fn test_verify_problems() {
+    let (vertices, holes) = square_with_hole();
+    let triangles = [
+        0, 1, 5, // fine
+        1, 0, 4, // reversed, and over the one before
+        4, 5, 6, // over the hole
+        1, 2, 3, // over the hole and across its edges
+        0, 0, 2, // degenerate
+    ];
+    let verification = verify(&vertices, &holes, 2, &triangles).unwrap();
+    assert_eq!(verification.reversed, [1]);
+    assert_eq!(verification.outside, [2, 3]);
+    assert_eq!(verification.overlapping, [0, 1, 2, 3]);
+    assert_eq!(verification.problems(), [0, 1, 2, 3]);
+    assert!(!verification.is_valid(f64::INFINITY));
+
+    // a triangle sticking out of the polygon, with its corners on it
+    let vertices = [0., 0., 2., 0., 2., 2., 1., 1., 0., 2.];
+    let verification = verify(&vertices, &[], 2, &[0, 1, 2, 0, 2, 4]).unwrap();
+    assert_eq!(verification.outside, [1]);
+    assert!(verification.reversed.is_empty());
+    assert!(verification.overlapping.is_empty());
+
+    // two copies of one triangle
+    let verification = verify(&vertices, &[], 2, &[0, 1, 3, 0, 1, 3]).unwrap();
+    assert_eq!(verification.overlapping, [0, 1]);
+    assert!(verification.outside.is_empty());
+
+    // triangles that only share an edge or a corner do not overlap
+    let verification = verify(&vertices, &[], 2, &[0, 1, 3, 1, 2, 3, 0, 3, 4]).unwrap();
+    assert!(verification.is_valid(1e-9), "{:?}", verification);
+
+    // problems can be drawn
+    let svg_options = SvgOptions {
+        problems: verify(&vertices, &[], 2, &[0, 1, 2, 0, 2, 4])
+            .unwrap()
+            .problems(),
+        ..Default::default()
+    };
+    let svg = to_svg(&vertices, &[], 2, &[0, 1, 2, 0, 2, 4], None, &svg_options).unwrap();
+    let plain = to_svg(
+        &vertices,
+        &[],
+        2,
+        &[0, 1, 2, 0, 2, 4],
+        None,
+        &SvgOptions::default(),
+    )
+    .unwrap();
+    assert_ne!(svg, plain);
+}
+
+#[test]
+fn test_verify_errors() {
+    let (vertices, holes) = square_with_hole();
+    assert!(verify(&vertices, &holes, 2, &[0, 1, 8]).is_err());
+    assert!(verify(&vertices, &holes, 2, &[0, 1]).is_err());
+    assert!(verify(&vertices, &holes, 1, &[]).is_err());
+    assert!(verify(&vertices, &[9], 2, &[]).is_err());
+    assert!(verify(&vertices[..15], &holes, 2, &[]).is_err());
+}
+
+#[test]
+fn test_verify_fixtures() {
+    for name in common::fixture_names() {
+        let (vertices, hole_indices, dims) = earcutr::flatten(&common::load_fixture(&name));
+        let triangles = earcutr::earcut(&vertices, &hole_indices, dims).unwrap();
+        let verification = verify(&vertices, &hole_indices, dims, &triangles).unwrap();
+        let deviation = earcutr::deviation(&vertices, &hole_indices, dims, &triangles);
+        assert!(
+            (verification.deviation - deviation).abs() < 1e-9
+                || verification.deviation == deviation,
+            "{}",
+            name
+        );
+        // the triangles earcut makes of this one overlap without changing
+        // the area
+        let known = name == "simplified-us-border";
+        if deviation < 1e-9 && !known {
+            assert!(verification.is_valid(1e-9), "{}", name);
+        } else {
+            assert!(!verification.outside.is_empty(), "{}", name);
+            assert!(!verification.overlapping.is_empty(), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+extern crate earcutr;
+
+use earcutr::wkb::{parse_hex_polygons, parse_polygons};
+use earcutr::Error;
+
+mod common;
+
+fn push_u32(wkb: &mut Vec<u8>, little_endian: bool, value: u32) {
+    match little_endian {
+        true => wkb.extend(value.to_le_bytes()),
+        false => wkb.extend(value.to_be_bytes()),
+    }
+}
+
+fn push_polygon(wkb: &mut Vec<u8>, little_endian: bool, code: u32, rings: &[Vec<Vec<f64>>]) {
+    wkb.push(little_endian as u8);
+    push_u32(wkb, little_endian, code);
+    if code & 0x2000_0000 != 0 {
+        push_u32(wkb, little_endian, 4326);
+    }
+    push_u32(wkb, little_endian, rings.len() as u32);
+    for ring in rings {
+        push_u32(wkb, little_endian, ring.len() as u32);
+        for value in ring.iter().flatten() {
+            match little_endian {
+                true => wkb.extend(value.to_le_bytes()),
+                false => wkb.extend(value.to_be_bytes()),
+            }
+        }
+    }
+}
+
+fn to_wkb(little_endian: bool, code: u32, rings: &[Vec<Vec<f64>>]) -> Vec<u8> {
+    let mut wkb = Vec::new();
+    push_polygon(&mut wkb, little_endian, code, rings);
+    wkb
+}
+
+#[test]
+fn test_fixtures_round_trip() {
+    for name in common::fixture_names() {
+        let rings = common::load_fixture(&name);
+        let expected = earcutr::flatten(&rings);
+        let triangles = earcutr::earcut(&expected.0, &expected.1, expected.2);
+
+        for little_endian in [true, false] {
+            let polygons = parse_polygons(&to_wkb(little_endian, 3, &rings)).unwrap();
+            assert_eq!(polygons, vec![expected.clone()], "{}", name);
+
+            let (vertices, hole_indices, dims) = &polygons[0];
+            let actual = earcutr::earcut(vertices, hole_indices, *dims);
+            assert_eq!(actual, triangles, "{}", name);
+        }
+    }
+}
+
+#[test]
+fn test_dimensions() {
+    let rings = vec![vec![
+        vec![0.0, 0.0, 1.0],
+        vec![1.0, 0.0, 2.0],
+        vec![1.0, 1.0, 3.0],
+    ]];
+    // ISO Polygon Z and Polygon M, EWKB with Z flag and SRID
+    for code in [1003, 2003, 0x8000_0003, 0x4000_0003, 0xa000_0003] {
+        let polygons = parse_polygons(&to_wkb(true, code, &rings)).unwrap();
+        assert_eq!(polygons[0].0, vec![0., 0., 1., 1., 0., 2., 1., 1., 3.]);
+        assert_eq!(polygons[0].2, 3);
+        let (vertices, hole_indices, dims) = &polygons[0];
+        assert_eq!(
+            earcutr::earcut(vertices, hole_indices, *dims)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    let rings = vec![vec![vec![0.0, 0.0, 1.0, 5.0]; 3]];
+    for code in [3003, 0xc000_0003] {
+        let polygons = parse_polygons(&to_wkb(false, code, &rings)).unwrap();
+        assert_eq!(polygons[0].2, 4);
+    }
+
+    // both EWKB flags and an ISO dimension code
+    assert_eq!(
+        parse_polygons(&to_wkb(true, 0x8000_03eb, &rings)),
+        Err(Error::InvalidWkb)
+    );
+}
+
+#[test]
+fn test_multipolygon() {
+    let outer = vec![vec![0.0, 0.0], vec![10.0, 0.0], vec![10.0, 10.0]];
+    let hole = vec![vec![6.0, 2.0], vec![8.0, 2.0], vec![8.0, 4.0]];
+    let mut wkb = Vec::new();
+    wkb.push(0);
+    push_u32(&mut wkb, false, 6);
+    push_u32(&mut wkb, false, 3);
+    push_polygon(&mut wkb, true, 3, &[outer.clone(), hole]);
+    push_polygon(&mut wkb, false, 3, &[]);
+    push_polygon(&mut wkb, true, 3, &[outer]);
+
+    let polygons = parse_polygons(&wkb).unwrap();
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[0].1, vec![3]);
+    assert!(polygons[1].1.is_empty());
+
+    let hex = wkb.iter().map(|b| format!("{:02X}", b)).collect::<String>();
+    assert_eq!(parse_hex_polygons(&hex), Ok(polygons));
+}
+
+#[test]
+fn test_invalid() {
+    let rings = vec![vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0]]];
+    let wkb = to_wkb(true, 3, &rings);
+    let mut trailing = wkb.clone();
+    trailing.push(0);
+    let mut byte_order = wkb.clone();
+    byte_order[0] = 2;
+
+    let invalid = [
+        vec![],
+        wkb[..wkb.len() - 1].to_vec(),
+        trailing,
+        byte_order,
+        to_wkb(true, 1, &rings),
+        to_wkb(true, 4003, &rings),
+    ];
+    for wkb in invalid {
+        assert_eq!(parse_polygons(&wkb), Err(Error::InvalidWkb), "{:?}", wkb);
+    }
+    assert_eq!(parse_hex_polygons("0"), Err(Error::InvalidWkb));
+    assert_eq!(parse_hex_polygons("zz"), Err(Error::InvalidWkb));
+}
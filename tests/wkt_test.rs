@@ -0,0 +1,109 @@
+extern crate earcutr;
+
+use earcutr::wkt::parse_polygons;
+use earcutr::Error;
+
+mod common;
+
+fn to_wkt(rings: &[Vec<Vec<f64>>]) -> String {
+    let rings = rings
+        .iter()
+        .map(|ring| {
+            let positions = ring
+                .iter()
+                .map(|position| {
+                    let position = position.iter().map(f64::to_string).collect::<Vec<_>>();
+                    position.join(" ")
+                })
+                .collect::<Vec<_>>();
+            format!("({})", positions.join(", "))
+        })
+        .collect::<Vec<_>>();
+    format!("POLYGON ({})", rings.join(", "))
+}
+
+#[test]
+fn test_fixtures_round_trip() {
+    for name in common::fixture_names() {
+        let rings = common::load_fixture(&name);
+        let expected = earcutr::flatten(&rings);
+
+        let polygons = parse_polygons(&to_wkt(&rings)).unwrap();
+        assert_eq!(polygons, vec![expected.clone()], "{}", name);
+
+        let (vertices, hole_indices, dims) = &polygons[0];
+        assert_eq!(
+            earcutr::earcut(vertices, hole_indices, *dims),
+            earcutr::earcut(&expected.0, &expected.1, expected.2),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_polygon_with_hole() {
+    let wkt = "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 8, 8 8, 8 2, 2 2))";
+    let polygons = parse_polygons(wkt).unwrap();
+    assert_eq!(polygons.len(), 1);
+    let (vertices, hole_indices, dims) = &polygons[0];
+    assert_eq!(vertices.len(), 20);
+    assert_eq!(hole_indices, &vec![5]);
+    assert_eq!(*dims, 2);
+    let triangles = earcutr::earcut(vertices, hole_indices, *dims).unwrap();
+    assert_eq!(triangles.len() / 3, 8);
+}
+
+#[test]
+fn test_dimensions() {
+    let z = parse_polygons("POLYGON Z ((0 0 1, 1 0 2, 1 1 3, 0 0 1))").unwrap();
+    assert_eq!(z[0].2, 3);
+    assert_eq!(z[0].0[..3], [0.0, 0.0, 1.0]);
+    assert_eq!(earcutr::earcut(&z[0].0, &z[0].1, z[0].2).unwrap().len(), 3);
+
+    let m = parse_polygons("polygonm((0 0 7, 1 0 7, 1 1 7))").unwrap();
+    assert_eq!(m[0].2, 3);
+
+    let zm = parse_polygons("POLYGON ZM ((0 0 1 2, 1 0 1 2, 1 1 1 2))").unwrap();
+    assert_eq!(zm[0].2, 4);
+    assert_eq!(zm[0].0.len(), 12);
+
+    // no tag, dimensions from the coordinates
+    let implicit = parse_polygons("POLYGON ((0 0 1, 1 0 2, 1 1 3))").unwrap();
+    assert_eq!(implicit[0].2, 3);
+}
+
+#[test]
+fn test_multipolygon() {
+    let wkt = "SRID=4326;MULTIPOLYGON (((0 0, 1 0, 1 1)), EMPTY, \
+               ((5 5, 9 5, 9 9, 5 9), (6 6, 6 7, 7 7)))";
+    let polygons = parse_polygons(wkt).unwrap();
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons[0].0.len(), 6);
+    assert!(polygons[0].1.is_empty());
+    assert_eq!(polygons[1].1, vec![4]);
+
+    assert!(parse_polygons("POLYGON EMPTY").unwrap().is_empty());
+    assert!(parse_polygons("MULTIPOLYGON Z EMPTY").unwrap().is_empty());
+}
+
+#[test]
+fn test_invalid() {
+    let invalid = [
+        "",
+        "POINT (1 2)",
+        "POLYGON",
+        "POLYGON ((0 0, 1 0, 1 1)",
+        "POLYGON ((0 0, 1 0, 1 1))) ",
+        "POLYGON ((0 0, 1 a, 1 1))",
+        "POLYGON ((0 0, 1 0 0, 1 1))",
+        "POLYGON Z ((0 0, 1 0, 1 1))",
+        "POLYGON ((0, 1, 1))",
+        "POLYGON Q ((0 0, 1 0, 1 1))",
+        "MULTIPOLYGON ((0 0, 1 0, 1 1))",
+        "SRID=4326 POLYGON ((0 0, 1 0, 1 1))",
+    ];
+    for wkt in invalid {
+        assert_eq!(parse_polygons(wkt), Err(Error::InvalidWkt), "{}", wkt);
+    }
+}
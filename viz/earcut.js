@@ -1,11 +1,12 @@
 "use strict";
 
+// earcut 3.0, https://github.com/mapbox/earcut, as a CommonJS module for the
+// viz page and for upstream.js. earcutr follows it triangle for triangle.
+
 module.exports = earcut;
 module.exports.default = earcut;
 
-
 function earcut(data, holeIndices, dim) {
-//    console.log("earcut " + data.length + "," + holeIndices + "," + dim);
     dim = dim || 2;
 
     var hasHoles = holeIndices && holeIndices.length,
@@ -13,20 +14,22 @@ function earcut(data, holeIndices, dim) {
         outerNode = linkedList(data, 0, outerLen, dim, true),
         triangles = [];
 
-    if (!outerNode) return triangles;
+    if (!outerNode || outerNode.next === outerNode.prev) return triangles;
 
-    var minX, minY, maxX, maxY, x, y, invSize;
+    var minX, minY, invSize;
 
     if (hasHoles) outerNode = eliminateHoles(data, holeIndices, outerNode, dim);
 
-    // if the shape is not too simple, we"ll use z-order curve hash later; calculate polygon bbox
+    // if the shape is not too simple, we'll use z-order curve hash later; calculate polygon bbox
     if (data.length > 80 * dim) {
-        minX = maxX = data[0];
-        minY = maxY = data[1];
+        minX = data[0];
+        minY = data[1];
+        var maxX = minX;
+        var maxY = minY;
 
         for (var i = dim; i < outerLen; i += dim) {
-            x = data[i];
-            y = data[i + 1];
+            var x = data[i];
+            var y = data[i + 1];
             if (x < minX) minX = x;
             if (y < minY) minY = y;
             if (x > maxX) maxX = x;
@@ -35,25 +38,22 @@ function earcut(data, holeIndices, dim) {
 
         // minX, minY and invSize are later used to transform coords into integers for z-order calculation
         invSize = Math.max(maxX - minX, maxY - minY);
-        invSize = invSize !== 0 ? 1 / invSize : 0;
+        invSize = invSize !== 0 ? 32767 / invSize : 0;
     }
 
-    earcutLinked(outerNode, triangles, dim, minX, minY, invSize);
+    earcutLinked(outerNode, triangles, dim, minX, minY, invSize, 0);
 
     return triangles;
 }
 
 // create a circular doubly linked list from polygon points in the specified winding order
 function linkedList(data, start, end, dim, clockwise) {
-//    console.log("llist" + data.length + "," + start.i + "," + end.i + "," + dim + "," + clockwise);
     var i, last;
 
     if (clockwise === (signedArea(data, start, end, dim) > 0)) {
-        for (i = start; i < end; i += dim)
-			last = insertNode(i, data[i], data[i + 1], last);
+        for (i = start; i < end; i += dim) last = insertNode(i / dim | 0, data[i], data[i + 1], last);
     } else {
-        for (i = end - dim; i >= start; i -= dim)
-			last = insertNode(i, data[i], data[i + 1], last);
+        for (i = end - dim; i >= start; i -= dim) last = insertNode(i / dim | 0, data[i], data[i + 1], last);
     }
 
     if (last && equals(last, last.next)) {
@@ -68,7 +68,6 @@ function linkedList(data, start, end, dim, clockwise) {
 function filterPoints(start, end) {
     if (!start) return start;
     if (!end) end = start;
-//    console.log("filterpoints points " + start.i + "," + end.i);
 
     var p = start,
         again;
@@ -86,83 +85,49 @@ function filterPoints(start, end) {
         }
     } while (again || p !== end);
 
-//    console.log("filter points end", end.i);
     return end;
 }
 
-/* main ear slicing loop which triangulates a polygon (given as a linked list).
-nodes are removed from the linked list as the triangles are added.
-*/
+// main ear slicing loop which triangulates a polygon (given as a linked list)
 function earcutLinked(ear, triangles, dim, minX, minY, invSize, pass) {
-//    console.log("earcutlinked: ear.i:", ear.i, "pass:", pass);
-    //",",dim,minX,minY,invSize,pass);
     if (!ear) return;
 
     // interlink polygon nodes in z-order
     if (!pass && invSize) indexCurve(ear, minX, minY, invSize);
 
-    var stop = ear,
-        prev, next;
+    var stop = ear;
 
     // iterate through ears, slicing them one by one
     while (ear.prev !== ear.next) {
-        prev = ear.prev;
-        next = ear.next;
+        var prev = ear.prev;
+        var next = ear.next;
 
         if (invSize ? isEarHashed(ear, minX, minY, invSize) : isEar(ear)) {
-            // cut off the triangle
-            triangles.push(prev.i / dim);
-            triangles.push(ear.i / dim);
-            triangles.push(next.i / dim);
+            triangles.push(prev.i, ear.i, next.i); // cut off the triangle
 
-            //			console.log("earcutlinked beg removeNode ear.i:",ear.i," cyclen:",cycle_len(ear));
             removeNode(ear);
 
             // skipping the next vertex leads to less sliver triangles
             ear = next.next;
             stop = next.next;
 
-            //			console.log("earcutlinked end removeNode ear.i:",ear.i," cyclen:",cycle_len(ear));
             continue;
         }
 
-        /*
-4:earcutlinked beg remove_node ear.i 1454 cyclen 1339
-4:earcutlinked end remove_node ear.i 1450 cyclen 1338
-4:earcutlinked beg remove_node ear.i 1448 cyclen 1338 << vi 327,n328,nn1323
-4:earcutlinked end remove_node ear.i 2674 cyclen 1337 <<<<< vi i p n vi:1323
-4:earcutlinked beg remove_node ear.i 2674 cyclen 1337
-4:earcutlinked end remove_node ear.i 2670 cyclen 1336
-4:earcutlinked beg remove_node ear.i 2660 cyclen 1336
-4:earcutlinked end remove_node ear.i 2656 cyclen 1335
-4:earcutlinked beg remove_node ear.i 1446 cyclen 1335
-4:earcutlinked end remove_node ear.i 1442 cyclen 1334
-b 1454 1339 --
-e 1450 1338 --
-b 1448 1338 --
-e 1444 1337 xx <<<<<<<<< s/b vi 329
-b 2660 1337 xx
-e 2656 1336 xx
-b 1440 1336
-e 1436 1335
-b 1434 1335
-e 1430 1334
-		*/
-
         ear = next;
 
-        // if we looped through the whole remaining polygon and can"t find any more ears
+        // if we looped through the whole remaining polygon and can't find any more ears
         if (ear === stop) {
             // try filtering points and slicing again
             if (!pass) {
                 earcutLinked(filterPoints(ear), triangles, dim, minX, minY, invSize, 1);
 
-                // if this didn"t work, try curing all small self-intersections locally
+            // if this didn't work, try curing all small self-intersections locally
             } else if (pass === 1) {
-                ear = cureLocalIntersections(ear, triangles, dim);
+                ear = cureLocalIntersections(filterPoints(ear), triangles);
                 earcutLinked(ear, triangles, dim, minX, minY, invSize, 2);
 
-                // as a last resort, try splitting the remaining polygon into two
+            // as a last resort, try splitting the remaining polygon into two
             } else if (pass === 2) {
                 splitEarcut(ear, triangles, dim, minX, minY, invSize);
             }
@@ -174,18 +139,25 @@ e 1430 1334
 
 // check whether a polygon node forms a valid ear with adjacent nodes
 function isEar(ear) {
-    //	console.log("isear"+ear);
     var a = ear.prev,
         b = ear,
         c = ear.next;
 
-    if (area(a, b, c) >= 0) return false; // reflex, can"t be an ear
+    if (area(a, b, c) >= 0) return false; // reflex, can't be an ear
+
+    // now make sure we don't have other points inside the potential ear
+    var ax = a.x, bx = b.x, cx = c.x, ay = a.y, by = b.y, cy = c.y;
 
-    // now make sure we don"t have other points inside the potential ear
-    var p = ear.next.next;
+    // triangle bbox
+    var x0 = Math.min(ax, bx, cx),
+        y0 = Math.min(ay, by, cy),
+        x1 = Math.max(ax, bx, cx),
+        y1 = Math.max(ay, by, cy);
 
-    while (p !== ear.prev) {
-        if (pointInTriangle(a.x, a.y, b.x, b.y, c.x, c.y, p.x, p.y) &&
+    var p = c.next;
+    while (p !== a) {
+        if (p.x >= x0 && p.x <= x1 && p.y >= y0 && p.y <= y1 &&
+            pointInTriangleExceptFirst(ax, ay, bx, by, cx, cy, p.x, p.y) &&
             area(p.prev, p, p.next) >= 0) return false;
         p = p.next;
     }
@@ -194,52 +166,49 @@ function isEar(ear) {
 }
 
 function isEarHashed(ear, minX, minY, invSize) {
-    //console.log("isearhashed",ear.i,minX,minY,invSize);
     var a = ear.prev,
         b = ear,
         c = ear.next;
 
-    if (area(a, b, c) >= 0) return false; // reflex, can"t be an ear
+    if (area(a, b, c) >= 0) return false; // reflex, can't be an ear
 
-    // triangle bbox; min & max are calculated like this for speed
-    var minTX = a.x < b.x ? (a.x < c.x ? a.x : c.x) : (b.x < c.x ? b.x : c.x),
-        minTY = a.y < b.y ? (a.y < c.y ? a.y : c.y) : (b.y < c.y ? b.y : c.y),
-        maxTX = a.x > b.x ? (a.x > c.x ? a.x : c.x) : (b.x > c.x ? b.x : c.x),
-        maxTY = a.y > b.y ? (a.y > c.y ? a.y : c.y) : (b.y > c.y ? b.y : c.y);
+    var ax = a.x, bx = b.x, cx = c.x, ay = a.y, by = b.y, cy = c.y;
+
+    // triangle bbox
+    var x0 = Math.min(ax, bx, cx),
+        y0 = Math.min(ay, by, cy),
+        x1 = Math.max(ax, bx, cx),
+        y1 = Math.max(ay, by, cy);
 
     // z-order range for the current triangle bbox;
-    var minZ = zOrder(minTX, minTY, minX, minY, invSize),
-        maxZ = zOrder(maxTX, maxTY, minX, minY, invSize);
+    var minZ = zOrder(x0, y0, minX, minY, invSize),
+        maxZ = zOrder(x1, y1, minX, minY, invSize);
 
     var p = ear.prevZ,
         n = ear.nextZ;
 
     // look for points inside the triangle in both directions
     while (p && p.z >= minZ && n && n.z <= maxZ) {
-        if (p !== ear.prev && p !== ear.next &&
-            pointInTriangle(a.x, a.y, b.x, b.y, c.x, c.y, p.x, p.y) &&
-            area(p.prev, p, p.next) >= 0) return false;
+        if (p.x >= x0 && p.x <= x1 && p.y >= y0 && p.y <= y1 && p !== a && p !== c &&
+            pointInTriangleExceptFirst(ax, ay, bx, by, cx, cy, p.x, p.y) && area(p.prev, p, p.next) >= 0) return false;
         p = p.prevZ;
 
-        if (n !== ear.prev && n !== ear.next &&
-            pointInTriangle(a.x, a.y, b.x, b.y, c.x, c.y, n.x, n.y) &&
-            area(n.prev, n, n.next) >= 0) return false;
+        if (n.x >= x0 && n.x <= x1 && n.y >= y0 && n.y <= y1 && n !== a && n !== c &&
+            pointInTriangleExceptFirst(ax, ay, bx, by, cx, cy, n.x, n.y) && area(n.prev, n, n.next) >= 0) return false;
         n = n.nextZ;
     }
 
     // look for remaining points in decreasing z-order
     while (p && p.z >= minZ) {
-        if (p !== ear.prev && p !== ear.next &&
-            pointInTriangle(a.x, a.y, b.x, b.y, c.x, c.y, p.x, p.y) &&
-            area(p.prev, p, p.next) >= 0) return false;
+        if (p.x >= x0 && p.x <= x1 && p.y >= y0 && p.y <= y1 && p !== a && p !== c &&
+            pointInTriangleExceptFirst(ax, ay, bx, by, cx, cy, p.x, p.y) && area(p.prev, p, p.next) >= 0) return false;
         p = p.prevZ;
     }
 
     // look for remaining points in increasing z-order
     while (n && n.z <= maxZ) {
-        if (n !== ear.prev && n !== ear.next &&
-            pointInTriangle(a.x, a.y, b.x, b.y, c.x, c.y, n.x, n.y) &&
-            area(n.prev, n, n.next) >= 0) return false;
+        if (n.x >= x0 && n.x <= x1 && n.y >= y0 && n.y <= y1 && n !== a && n !== c &&
+            pointInTriangleExceptFirst(ax, ay, bx, by, cx, cy, n.x, n.y) && area(n.prev, n, n.next) >= 0) return false;
         n = n.nextZ;
     }
 
@@ -247,8 +216,7 @@ function isEarHashed(ear, minX, minY, invSize) {
 }
 
 // go through all polygon nodes and cure small local self-intersections
-function cureLocalIntersections(start, triangles, dim) {
-    console.log("cure local intersections i" + start.i + "," + triangles + "," + dim);
+function cureLocalIntersections(start, triangles) {
     var p = start;
     do {
         var a = p.prev,
@@ -256,9 +224,7 @@ function cureLocalIntersections(start, triangles, dim) {
 
         if (!equals(a, b) && intersects(a, p, p.next, b) && locallyInside(a, b) && locallyInside(b, a)) {
 
-            triangles.push(a.i / dim);
-            triangles.push(p.i / dim);
-            triangles.push(b.i / dim);
+            triangles.push(a.i, p.i, b.i);
 
             // remove two nodes involved
             removeNode(p);
@@ -269,21 +235,17 @@ function cureLocalIntersections(start, triangles, dim) {
         p = p.next;
     } while (p !== start);
 
-    return p;
+    return filterPoints(p);
 }
 
 // try splitting polygon into two and triangulate them independently
 function splitEarcut(start, triangles, dim, minX, minY, invSize) {
-//    console.log("split ear cut i:" + start.i);
     // look for a valid diagonal that divides the polygon into two
     var a = start;
     do {
         var b = a.next.next;
-//        console.log("splitearcut loop is:", a.i, ",", b.i);
         while (b !== a.prev) {
-  //          console.log("splitearcut mloop", a.i, b.i, isValidDiagonal(a, b));
             if (a.i !== b.i && isValidDiagonal(a, b)) {
-//                console.log("splitear inner loop ai bi", a.i, b.i);
                 // split the polygon in two by the diagonal
                 var c = splitPolygon(a, b);
 
@@ -292,8 +254,8 @@ function splitEarcut(start, triangles, dim, minX, minY, invSize) {
                 c = filterPoints(c, c.next);
 
                 // run earcut on each half
-                earcutLinked(a, triangles, dim, minX, minY, invSize);
-                earcutLinked(c, triangles, dim, minX, minY, invSize);
+                earcutLinked(a, triangles, dim, minX, minY, invSize, 0);
+                earcutLinked(c, triangles, dim, minX, minY, invSize, 0);
                 return;
             }
             b = b.next;
@@ -302,86 +264,8 @@ function splitEarcut(start, triangles, dim, minX, minY, invSize) {
     } while (a !== start);
 }
 
-function cycle_len(node) {
-    var start = node;
-    var a = start;
-    var count = 0;
-    var s1 = a.i;
-    var s2 = -1;
-    do {
-        count += 1;
-        s2 = a.i;
-        a = a.next;
-    } while (a != start);
-    return count;
-}
-
-// https://www.cs.hmc.edu/~geoff/classes/hmc.cs070.200101/homework10/hashfuncs.html
-// https://stackoverflow.com/questions/1908492/unsigned-integer-in-javascript
-function horsh(h,n) {
-     var highorder = h>>>0 & 0xf8000000 >>>0  ;  // extract high-order 5 bits from h
-//	console.log("horsh a",h>>>0," ",n>>>0);
-                                   // 0xf8000000 is the hexadecimal representation
-                                   //   for the 32-bit number with the first five 
-                                   //   bits = 1 and the other bits = 0   
-     h = h << 5               ; // shift h left by 5 bits
-//	console.log("horsh b",h>>>0," ",n>>>0);
-     h = h ^ ( highorder >>> 27)  ;  // move the highorder 5 bits to the low-order
-                                   //   end and XOR into h
-//	console.log("horsh c",h>>>0," ",n>>>0);
-     h = h ^ n                ;  // XOR h and ki
-//	console.log("horsh d",h>>>0," ",n>>>0);
-    return h >>>0;
-}
-
-function llhorsh(node) {
-    var start = node;
-    var a = start;
-    var count = 0;
-    var s1 = a.i;
-    var s2 = -1;
-	var state = 0;
-    do {
-		state = horsh(state,a.i);
-        count += 1;
-        a = a.next;
-    } while (a != start);
-    return "horshcount:"+count+" horsh:"+state;
-}
-
-function dumpnodes(node) {
-    var start = node;
-    var a = start;
-    var count = 0;
-    var s1 = a.i;
-    var s2 = -1;
-	var state = 0;
-    do {
-		state = horsh(state,a.i);
-//        console.log(count, a.i, a.prev.i, a.next.i, a.x, a.y, a.steiner);
-        count += 1;
-        a = a.next;
-    } while (a != start);
-//    console.log("ll dump end. horsh: ",state);
-}
-
-function dumplist(node) {
-    var start = node;
-    var a = start;
-    var count = 0;
-    var s1 = a.i;
-    var s2 = -1;
-    do {
-        count += 1;
-        s2 = a.i;
-        a = a.next;
-    } while (a != start);
-//    console.log("ll count:", count, " 1sti:", s1, " lasti:", s2);
-}
-
 // link every hole into the outer loop, producing a single-ring polygon without holes
 function eliminateHoles(data, holeIndices, outerNode, dim) {
-//    console.log("eliminateHoles", data, holeIndices, outerNode, dim);
     var queue = [],
         i, len, start, end, list;
 
@@ -393,68 +277,72 @@ function eliminateHoles(data, holeIndices, outerNode, dim) {
         queue.push(getLeftmost(list));
     }
 
-    queue.sort(compareX);
+    queue.sort(compareXYSlope);
 
     // process holes from left to right
     for (i = 0; i < queue.length; i++) {
-//        console.log("eliminating hole begin, " + i + " of " + queue.length, "outerNode.i", outerNode.i, " cyclelen", cycle_len(outerNode),llhorsh( outerNode) );
-        eliminateHole(queue[i], outerNode);
-//        console.log("eliminating hole end,   " + i + " of " + queue.length, "outerNode.i", outerNode.i, " cyclelen", cycle_len(outerNode), llhorsh( outerNode) );
-        outerNode = filterPoints(outerNode, outerNode.next);
+        outerNode = eliminateHole(queue[i], outerNode);
     }
 
     return outerNode;
 }
 
-function compareX(a, b) {
-    return a.x - b.x;
+function compareXYSlope(a, b) {
+    var result = a.x - b.x;
+    // when the left-most point of 2 holes meet at a vertex, sort the holes counterclockwise so that when we find
+    // the bridge to the outer shell is always the point that they meet at.
+    if (result === 0) {
+        result = a.y - b.y;
+        if (result === 0) {
+            var aSlope = (a.next.y - a.y) / (a.next.x - a.x);
+            var bSlope = (b.next.y - b.y) / (b.next.x - b.x);
+            result = aSlope - bSlope;
+        }
+    }
+    return result;
 }
 
 // find a bridge between vertices that connects hole with an outer ring and and link it
 function eliminateHole(hole, outerNode) {
-//    console.log("eliminateHole", hole.i, outerNode.i);
-    outerNode = findHoleBridge(hole, outerNode);
-    if (outerNode) {
-        var b = splitPolygon(outerNode, hole);
-        filterPoints(b, b.next);
+    var bridge = findHoleBridge(hole, outerNode);
+    if (!bridge) {
+        return outerNode;
     }
+
+    var bridgeReverse = splitPolygon(bridge, hole);
+
+    // filter collinear points around the cuts
+    filterPoints(bridgeReverse, bridgeReverse.next);
+    return filterPoints(bridge, bridge.next);
 }
 
-// David Eberly"s algorithm for finding a bridge between hole and outer
-// polygon
+// David Eberly's algorithm for finding a bridge between hole and outer polygon
 function findHoleBridge(hole, outerNode) {
-//    console.log("findholebridge", hole.i, outerNode.i);
     var p = outerNode,
         hx = hole.x,
         hy = hole.y,
         qx = -Infinity,
         m;
 
-    // find a segment intersected by a ray from the hole"s leftmost point to the left;
-    // segment"s endpoint with lesser x will be potential connection point
+    // find a segment intersected by a ray from the hole's leftmost point to the left;
+    // segment's endpoint with lesser x will be potential connection point
+    // unless they intersect at a vertex, then choose the vertex
+    if (equals(hole, p)) return p;
     do {
-        if (hy <= p.y &&
-            hy >= p.next.y &&
-            p.next.y !== p.y) {
+        if (equals(hole, p.next)) return p.next;
+        else if (hy <= p.y && hy >= p.next.y && p.next.y !== p.y) {
             var x = p.x + (hy - p.y) * (p.next.x - p.x) / (p.next.y - p.y);
             if (x <= hx && x > qx) {
                 qx = x;
-                if (x === hx) {
-                    if (hy === p.y) return p;
-                    if (hy === p.next.y) return p.next;
-                }
                 m = p.x < p.next.x ? p : p.next;
+                if (x === hx) return m; // hole touches outer segment; pick leftmost endpoint
             }
         }
         p = p.next;
     } while (p !== outerNode);
 
-//	console.log("fhb p",p.i,"hx",hx,"hy",hy,"qx",qx,"m",m.i);
-
     if (!m) return null;
 
-    if (hx === qx) return m.prev; // hole touches outer segment; pick lower endpoint
-
     // look for points inside the triangle of hole point, segment intersection and endpoint;
     // if there are no points found, we have a valid connection;
     // otherwise choose the point of the minimum angle with the ray as connection point
@@ -465,49 +353,37 @@ function findHoleBridge(hole, outerNode) {
         tanMin = Infinity,
         tan;
 
-    p = m.next;
-
+    p = m;
 
-    while (p !== stop) {
-
-        var x1 = hy < my ? hx : qx;
-        var x2 = hy < my ? qx : hx;
-
-        if (
-            (hx >= p.x) &&
-            (p.x >= mx) &&
-            (hx !== p.x) &&
-            pointInTriangle(
-                x1,
-                hy,
-                mx,
-                my,
-                x2,
-                hy,
-                p.x,
-                p.y)) {
+    do {
+        if (hx >= p.x && p.x >= mx && hx !== p.x &&
+                pointInTriangle(hy < my ? hx : qx, hy, mx, my, hy < my ? qx : hx, hy, p.x, p.y)) {
 
             tan = Math.abs(hy - p.y) / (hx - p.x); // tangential
-            if ((tan < tanMin || (tan === tanMin && p.x > m.x)) &&
-                locallyInside(p, hole)) {
+
+            if (locallyInside(p, hole) &&
+                (tan < tanMin || (tan === tanMin && (p.x > m.x || (p.x === m.x && sectorContainsSector(m, p)))))) {
                 m = p;
                 tanMin = tan;
             }
         }
 
         p = p.next;
-    }
-
+    } while (p !== stop);
 
     return m;
 }
 
+// whether sector in vertex m contains sector in vertex p in the same coordinates
+function sectorContainsSector(m, p) {
+    return area(m.prev, m, p.prev) < 0 && area(p.next, m, m.next) < 0;
+}
+
 // interlink polygon nodes in z-order
 function indexCurve(start, minX, minY, invSize) {
-//    console.log("indexcurve", start, minX, minY, invSize);
     var p = start;
     do {
-        if (p.z === null) p.z = zOrder(p.x, p.y, minX, minY, invSize);
+        if (p.z === 0) p.z = zOrder(p.x, p.y, minX, minY, invSize);
         p.prevZ = p.prev;
         p.nextZ = p.next;
         p = p.next;
@@ -519,10 +395,9 @@ function indexCurve(start, minX, minY, invSize) {
     sortLinked(p);
 }
 
-// Simon Tatham"s linked list merge sort algorithm
+// Simon Tatham's linked list merge sort algorithm
 // http://www.chiark.greenend.org.uk/~sgtatham/algorithms/listsort.html
 function sortLinked(list) {
-//    console.log("sortlinked", list);
     var i, p, q, e, tail, numMerges, pSize, qSize,
         inSize = 1;
 
@@ -575,10 +450,9 @@ function sortLinked(list) {
 
 // z-order of a point given coords and inverse of the longer side of data bbox
 function zOrder(x, y, minX, minY, invSize) {
-    //console.log("zorder",x,y);
     // coords are transformed into non-negative 15-bit integer range
-    x = 32767 * (x - minX) * invSize;
-    y = 32767 * (y - minY) * invSize;
+    x = (x - minX) * invSize | 0;
+    y = (y - minY) * invSize | 0;
 
     x = (x | (x << 8)) & 0x00FF00FF;
     x = (x | (x << 4)) & 0x0F0F0F0F;
@@ -595,11 +469,10 @@ function zOrder(x, y, minX, minY, invSize) {
 
 // find the leftmost node of a polygon ring
 function getLeftmost(start) {
-//    console.log("getleftmost ", start.i);
     var p = start,
         leftmost = start;
     do {
-        if (p.x < leftmost.x) leftmost = p;
+        if (p.x < leftmost.x || (p.x === leftmost.x && p.y < leftmost.y)) leftmost = p;
         p = p.next;
     } while (p !== start);
 
@@ -608,28 +481,26 @@ function getLeftmost(start) {
 
 // check if a point lies within a convex triangle
 function pointInTriangle(ax, ay, bx, by, cx, cy, px, py) {
-    //console.log("point in tri",ax,ay,bx,by,cx,cy,px,py);
-    return (cx - px) * (ay - py) - (ax - px) * (cy - py) >= 0 &&
-        (ax - px) * (by - py) - (bx - px) * (ay - py) >= 0 &&
-        (bx - px) * (cy - py) - (cx - px) * (by - py) >= 0;
+    return (cx - px) * (ay - py) >= (ax - px) * (cy - py) &&
+           (ax - px) * (by - py) >= (bx - px) * (ay - py) &&
+           (bx - px) * (cy - py) >= (cx - px) * (by - py);
+}
+
+// check if a point lies within a convex triangle but false if its equal to the first point of the triangle
+function pointInTriangleExceptFirst(ax, ay, bx, by, cx, cy, px, py) {
+    return !(ax === px && ay === py) && pointInTriangle(ax, ay, bx, by, cx, cy, px, py);
 }
 
 // check if a diagonal between two polygon nodes is valid (lies in polygon interior)
 function isValidDiagonal(a, b) {
-
-//    console.log("is-valid-diagonal a.i b.i !ip li li mi ", a.i, b.i, !intersectsPolygon(a, b), locallyInside(a, b), locallyInside(b, a), middleInside(a, b));
-
-    return a.next.i !== b.i &&
-        a.prev.i !== b.i &&
-        !intersectsPolygon(a, b) &&
-        locallyInside(a, b) &&
-        locallyInside(b, a) &&
-        middleInside(a, b);
+    return a.next.i !== b.i && a.prev.i !== b.i && !intersectsPolygon(a, b) && // dones't intersect other edges
+           (locallyInside(a, b) && locallyInside(b, a) && middleInside(a, b) && // locally visible
+            (area(a.prev, a, b.prev) || area(a, b.prev, b)) || // does not create opposite-facing sectors
+            equals(a, b) && area(a.prev, a, a.next) > 0 && area(b.prev, b, b.next) > 0); // special zero-length case
 }
 
 // signed area of a triangle
 function area(p, q, r) {
-    //	console.log("area",p,q,r);
     return (q.y - p.y) * (r.x - q.x) - (q.x - p.x) * (r.y - q.y);
 }
 
@@ -638,28 +509,38 @@ function equals(p1, p2) {
     return p1.x === p2.x && p1.y === p2.y;
 }
 
-// check if two segments intersect p1-q1  p2-q2
+// check if two segments intersect
 function intersects(p1, q1, p2, q2) {
-    //	console.log("intersects",p1,q1,p2,q1);
-    if ((equals(p1, p2) && equals(q1, q2)) ||
-        (equals(p1, q2) && equals(q1, p2))) return true;
-    return area(p1, q1, p2) > 0 !== area(p1, q1, q2) > 0 &&
-        area(p2, q2, p1) > 0 !== area(p2, q2, q1) > 0;
+    var o1 = sign(area(p1, q1, p2));
+    var o2 = sign(area(p1, q1, q2));
+    var o3 = sign(area(p2, q2, p1));
+    var o4 = sign(area(p2, q2, q1));
+
+    if (o1 !== o2 && o3 !== o4) return true; // general case
+
+    if (o1 === 0 && onSegment(p1, p2, q1)) return true; // p1, q1 and p2 are collinear and p2 lies on p1q1
+    if (o2 === 0 && onSegment(p1, q2, q1)) return true; // p1, q1 and q2 are collinear and q2 lies on p1q1
+    if (o3 === 0 && onSegment(p2, p1, q2)) return true; // p2, q2 and p1 are collinear and p1 lies on p2q2
+    if (o4 === 0 && onSegment(p2, q1, q2)) return true; // p2, q2 and q1 are collinear and q1 lies on p2q2
+
+    return false;
+}
+
+// for collinear points p, q, r, check if point q lies on segment pr
+function onSegment(p, q, r) {
+    return q.x <= Math.max(p.x, r.x) && q.x >= Math.min(p.x, r.x) && q.y <= Math.max(p.y, r.y) && q.y >= Math.min(p.y, r.y);
+}
+
+function sign(num) {
+    return num > 0 ? 1 : num < 0 ? -1 : 0;
 }
 
 // check if a polygon diagonal intersects any polygon segments
 function intersectsPolygon(a, b) {
-    //	console.log("intersectsPolygon",a,b);
     var p = a;
     do {
-//        console.log("intersectsPolygon isect?", p.i, p.next.i, a.i, b.i, intersects(p, p.next, a, b));
-        if (p.i !== a.i &&
-            p.next.i !== a.i &&
-            p.i !== b.i &&
-            p.next.i !== b.i &&
-            intersects(p, p.next, a, b)) {
-            return true;
-        }
+        if (p.i !== a.i && p.next.i !== a.i && p.i !== b.i && p.next.i !== b.i &&
+                intersects(p, p.next, a, b)) return true;
         p = p.next;
     } while (p !== a);
 
@@ -668,7 +549,6 @@ function intersectsPolygon(a, b) {
 
 // check if a polygon diagonal is locally inside the polygon
 function locallyInside(a, b) {
-    //	console.log("locallyInside",a,b);
     return area(a.prev, a, a.next) < 0 ?
         area(a, b, a.next) >= 0 && area(a, a.prev, b) >= 0 :
         area(a, b, a.prev) < 0 || area(a, a.next, b) < 0;
@@ -676,14 +556,13 @@ function locallyInside(a, b) {
 
 // check if the middle point of a polygon diagonal is inside the polygon
 function middleInside(a, b) {
-    //	console.log("middleInside",a,b);
     var p = a,
         inside = false,
         px = (a.x + b.x) / 2,
         py = (a.y + b.y) / 2;
     do {
         if (((p.y > py) !== (p.next.y > py)) && p.next.y !== p.y &&
-            (px < (p.next.x - p.x) * (py - p.y) / (p.next.y - p.y) + p.x))
+                (px < (p.next.x - p.x) * (py - p.y) / (p.next.y - p.y) + p.x))
             inside = !inside;
         p = p.next;
     } while (p !== a);
@@ -694,9 +573,8 @@ function middleInside(a, b) {
 // link two polygon vertices with a bridge; if the vertices belong to the same ring, it splits polygon into two;
 // if one belongs to the outer ring and another to a hole, it merges it into a single ring
 function splitPolygon(a, b) {
-//    console.log("splitPolygon", a.i, b.i);
-    var a2 = new Node(a.i, a.x, a.y),
-        b2 = new Node(b.i, b.x, b.y),
+    var a2 = createNode(a.i, a.x, a.y),
+        b2 = createNode(b.i, b.x, b.y),
         an = a.next,
         bp = b.prev;
 
@@ -717,7 +595,7 @@ function splitPolygon(a, b) {
 
 // create a node and optionally link it with previous one (in a circular doubly linked list)
 function insertNode(i, x, y, last) {
-    var p = new Node(i, x, y);
+    var p = createNode(i, x, y);
 
     if (!last) {
         p.prev = p;
@@ -733,7 +611,6 @@ function insertNode(i, x, y, last) {
 }
 
 function removeNode(p) {
-    //	console.log("removeNode",p.i);
     p.next.prev = p.prev;
     p.prev.next = p.next;
 
@@ -741,33 +618,22 @@ function removeNode(p) {
     if (p.nextZ) p.nextZ.prevZ = p.prevZ;
 }
 
-function Node(i, x, y) {
-    //	console.log("Node",i,x,y);
-    // vertex index in coordinates array
-    this.i = i;
-
-    // vertex coordinates
-    this.x = x;
-    this.y = y;
-
-    // previous and next vertex nodes in a polygon ring
-    this.prev = null;
-    this.next = null;
-
-    // z-order curve value
-    this.z = null;
-
-    // previous and next nodes in z-order
-    this.prevZ = null;
-    this.nextZ = null;
-
-    // indicates whether this is a steiner point
-    this.steiner = false;
+function createNode(i, x, y) {
+    return {
+        i: i, // vertex index in coordinates array
+        x: x, y: y, // vertex coordinates
+        prev: null, // previous and next vertex nodes in a polygon ring
+        next: null,
+        z: 0, // z-order curve value
+        prevZ: null, // previous and next nodes in z-order
+        nextZ: null,
+        steiner: false // indicates whether this is a steiner point
+    };
 }
 
 // return a percentage difference between the polygon area and its triangulation area;
 // used to verify correctness of triangulation
-earcut.deviation = function(data, holeIndices, dim, triangles) {
+earcut.deviation = function (data, holeIndices, dim, triangles) {
     var hasHoles = holeIndices && holeIndices.length;
     var outerLen = hasHoles ? holeIndices[0] * dim : data.length;
 
@@ -795,7 +661,6 @@ earcut.deviation = function(data, holeIndices, dim, triangles) {
 };
 
 function signedArea(data, start, end, dim) {
-    //console.log("signedarea",data,start,end,dim);
     var sum = 0;
     for (var i = start, j = end - dim; i < end; i += dim) {
         sum += (data[j] - data[i]) * (data[i + 1] + data[j + 1]);
@@ -805,24 +670,23 @@ function signedArea(data, start, end, dim) {
 }
 
 // turn a polygon in a multi-dimensional array form (e.g. as in GeoJSON) into a form Earcut accepts
-earcut.flatten = function(data) {
-    console.log("flatten", data);
-    var dim = data[0][0].length,
-        result = {
-            vertices: [],
-            holes: [],
-            dimensions: dim
-        },
-        holeIndex = 0;
+earcut.flatten = function (data) {
+    var vertices = [],
+        holes = [],
+        dimensions = data[0][0].length,
+        holeIndex = 0,
+        prevLen = 0;
 
     for (var i = 0; i < data.length; i++) {
-        for (var j = 0; j < data[i].length; j++) {
-            for (var d = 0; d < dim; d++) result.vertices.push(data[i][j][d]);
+        var ring = data[i];
+        for (var j = 0; j < ring.length; j++) {
+            for (var d = 0; d < dimensions; d++) vertices.push(ring[j][d]);
         }
         if (i > 0) {
-            holeIndex += data[i - 1].length;
-            result.holes.push(holeIndex);
+            holeIndex += prevLen;
+            holes.push(holeIndex);
         }
+        prevLen = ring.length;
     }
-    return result;
+    return {vertices: vertices, holes: holes, dimensions: dimensions};
 };
@@ -1,5 +1,5 @@
 testOutput["bad_hole"]=[];
 testOutput["bad_hole"]["json"]=[[[810.0, 2828.0], [818.0, 2828.0], [832.0, 2818.0], [844.0, 2806.0], [855.0, 2808.0], [866.0, 2816.0], [867.0, 2824.0], [876.0, 2827.0], [883.0, 2834.0], [875.0, 2834.0], [867.0, 2840.0], [878.0, 2838.0], [889.0, 2844.0], [880.0, 2847.0], [870.0, 2847.0], [860.0, 2864.0], [852.0, 2879.0], [847.0, 2867.0], [810.0, 2828.0], [810.0, 2828.0]], [[818.0, 2834.0], [823.0, 2833.0], [831.0, 2828.0], [839.0, 2829.0], [839.0, 2837.0], [851.0, 2845.0], [847.0, 2835.0], [846.0, 2827.0], [847.0, 2827.0], [837.0, 2827.0], [840.0, 2815.0], [835.0, 2823.0], [818.0, 2834.0], [818.0, 2834.0]], [[857.0, 2846.0], [864.0, 2850.0], [866.0, 2839.0], [857.0, 2846.0], [857.0, 2846.0]], [[848.0, 2863.0], [848.0, 2866.0], [854.0, 2852.0], [846.0, 2854.0], [847.0, 2862.0], [838.0, 2851.0], [838.0, 2859.0], [848.0, 2863.0], [848.0, 2863.0]]];
-testOutput["bad_hole"]["triangles"]=[21, 22, 23, 25, 34, 35, 27, 29, 30, 2, 3, 4, 4, 5, 6, 6, 7, 8, 10, 11, 12, 12, 13, 14, 15, 16, 17, 45, 39, 40, 42, 43, 44, 44, 0, 20, 21, 23, 24, 30, 31, 32, 32, 0, 1, 6, 8, 9, 10, 12, 14, 14, 15, 17, 17, 0, 44, 20, 21, 24, 30, 32, 1, 6, 9, 10, 20, 24, 25, 36, 37, 25, 25, 26, 27, 30, 1, 2, 4, 6, 10, 20, 25, 35, 36, 25, 27, 30, 2, 4, 36, 27, 30, 36, 30, 4, 36, 4, 10, 35, 36, 10, 35, 10, 14, 17, 44, 40, 41, 42, 44, 44, 20, 35, 14, 17, 40, 41, 44, 35, 35, 14, 40, 40, 41, 35];
+testOutput["bad_hole"]["triangles"]=[25, 34, 35, 27, 29, 30, 2, 3, 4, 4, 5, 6, 6, 7, 8, 10, 11, 12, 12, 13, 14, 15, 16, 17, 45, 39, 40, 42, 43, 44, 44, 18, 20, 21, 22, 23, 30, 31, 32, 32, 0, 1, 6, 8, 9, 10, 12, 14, 14, 15, 17, 17, 18, 44, 21, 23, 24, 30, 32, 1, 6, 9, 10, 20, 21, 24, 30, 1, 2, 4, 6, 10, 20, 24, 25, 36, 37, 25, 25, 26, 27, 30, 2, 4, 20, 25, 35, 36, 25, 27, 27, 30, 4, 36, 27, 4, 36, 4, 10, 35, 36, 10, 35, 10, 14, 17, 44, 40, 41, 42, 44, 44, 20, 35, 14, 17, 40, 41, 44, 35, 35, 14, 40, 40, 41, 35];
 testOutput["bad_hole"]["pass"]=true;
 testOutput["bad_hole"]["report"]="exp numtri:42\nexp dev:0.019\nact numtri:42\nact dev:0.018674136321195144";
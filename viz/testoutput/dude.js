@@ -1,5 +1,5 @@
 testOutput["dude"]=[];
 testOutput["dude"]["json"]=[[[280.35714, 648.79075], [286.78571, 662.8979], [263.28607, 661.17871], [262.31092, 671.41548], [250.53571, 677.00504], [250.53571, 683.43361], [256.42857, 685.21933], [297.14286, 669.50504], [289.28571, 649.50504], [285.0, 631.6479], [285.0, 608.79075], [292.85714, 585.21932], [306.42857, 563.79075], [323.57143, 548.79075], [339.28571, 545.21932], [357.85714, 547.36218], [375.0, 550.21932], [391.42857, 568.07647], [404.28571, 588.79075], [413.57143, 612.36218], [417.14286, 628.07647], [438.57143, 619.1479], [438.03572, 618.96932], [437.5, 609.50504], [426.96429, 609.86218], [424.64286, 615.57647], [419.82143, 615.04075], [420.35714, 605.04075], [428.39286, 598.43361], [437.85714, 599.68361], [443.57143, 613.79075], [450.71429, 610.21933], [431.42857, 575.21932], [405.71429, 550.21932], [372.85714, 534.50504], [349.28571, 531.6479], [346.42857, 521.6479], [346.42857, 511.6479], [350.71429, 496.6479], [367.85714, 476.6479], [377.14286, 460.93361], [385.71429, 445.21932], [388.57143, 404.50504], [360.0, 352.36218], [337.14286, 325.93361], [330.71429, 334.50504], [347.14286, 354.50504], [337.85714, 370.21932], [333.57143, 359.50504], [319.28571, 353.07647], [312.85714, 366.6479], [350.71429, 387.36218], [368.57143, 408.07647], [375.71429, 431.6479], [372.14286, 454.50504], [366.42857, 462.36218], [352.85714, 462.36218], [336.42857, 456.6479], [332.85714, 438.79075], [338.57143, 423.79075], [338.57143, 411.6479], [327.85714, 405.93361], [320.71429, 407.36218], [315.71429, 423.07647], [314.28571, 440.21932], [325.0, 447.71932], [324.82143, 460.93361], [317.85714, 470.57647], [304.28571, 483.79075], [287.14286, 491.29075], [263.03571, 498.61218], [251.60714, 503.07647], [251.25, 533.61218], [260.71429, 533.61218], [272.85714, 528.43361], [286.07143, 518.61218], [297.32143, 508.25504], [297.85714, 507.36218], [298.39286, 506.46932], [307.14286, 496.6479], [312.67857, 491.6479], [317.32143, 503.07647], [322.5, 514.1479], [325.53571, 521.11218], [327.14286, 525.75504], [326.96429, 535.04075], [311.78571, 540.04075], [291.07143, 552.71932], [274.82143, 568.43361], [259.10714, 592.8979], [254.28571, 604.50504], [251.07143, 621.11218], [250.53571, 649.1479], [268.1955, 654.36208]], [[325.0, 437.0], [320.0, 423.0], [329.0, 413.0], [332.0, 423.0]], [[320.72342, 480.0], [338.90617, 465.96863], [347.99754, 480.61584], [329.8148, 510.41534], [339.91632, 480.11077], [334.86556, 478.09046]]];
-testOutput["dude"]["triangles"]=[93, 92, 91, 91, 90, 89, 89, 88, 87, 87, 86, 85, 82, 81, 80, 77, 76, 75, 75, 74, 73, 73, 72, 71, 71, 70, 69, 69, 68, 98, 98, 103, 102, 99, 98, 68, 65, 64, 63, 63, 95, 94, 96, 95, 63, 63, 62, 61, 61, 60, 59, 51, 50, 49, 49, 48, 47, 46, 45, 44, 44, 43, 42, 42, 41, 40, 40, 39, 38, 35, 34, 33, 33, 32, 31, 31, 30, 29, 25, 24, 23, 22, 21, 20, 7, 6, 5, 5, 4, 3, 3, 2, 1, 0, 93, 91, 91, 89, 87, 78, 77, 75, 75, 73, 71, 69, 98, 102, 99, 68, 67, 65, 63, 94, 96, 63, 61, 51, 49, 47, 46, 44, 42, 33, 31, 29, 25, 23, 22, 7, 5, 3, 0, 91, 87, 79, 78, 75, 75, 71, 69, 99, 67, 66, 65, 94, 97, 97, 96, 61, 52, 51, 47, 47, 46, 42, 33, 29, 28, 26, 25, 22, 7, 3, 1, 79, 75, 69, 99, 66, 65, 97, 61, 59, 52, 47, 42, 33, 28, 27, 26, 22, 20, 8, 7, 1, 80, 79, 69, 65, 97, 59, 53, 52, 42, 33, 27, 26, 26, 20, 19, 9, 8, 1, 80, 69, 102, 65, 59, 58, 54, 53, 42, 33, 26, 19, 9, 1, 0, 82, 80, 102, 65, 58, 57, 54, 42, 40, 33, 19, 18, 10, 9, 0, 83, 82, 102, 99, 65, 57, 55, 54, 40, 33, 18, 17, 10, 0, 87, 83, 102, 101, 100, 99, 57, 55, 40, 38, 33, 17, 16, 11, 10, 87, 84, 83, 101, 100, 57, 56, 56, 55, 38, 35, 33, 16, 12, 11, 87, 85, 84, 101, 100, 56, 38, 35, 16, 15, 13, 12, 87, 85, 101, 100, 100, 38, 37, 35, 15, 14, 14, 13, 87, 85, 100, 37, 36, 35, 14, 14, 87, 85, 85, 37, 36, 36, 14, 85];
+testOutput["dude"]["triangles"]=[68, 98, 103, 103, 102, 101, 99, 98, 68, 65, 64, 63, 63, 95, 94, 96, 95, 63, 63, 62, 61, 61, 60, 59, 51, 50, 49, 49, 48, 47, 46, 45, 44, 44, 43, 42, 42, 41, 40, 40, 39, 38, 35, 34, 33, 33, 32, 31, 31, 30, 29, 25, 24, 23, 22, 21, 20, 7, 6, 5, 5, 4, 3, 3, 2, 1, 0, 93, 92, 92, 91, 90, 90, 89, 88, 88, 87, 86, 82, 81, 80, 77, 76, 75, 75, 74, 73, 73, 72, 71, 71, 70, 69, 69, 68, 103, 99, 68, 67, 65, 63, 94, 96, 63, 61, 51, 49, 47, 46, 44, 42, 33, 31, 29, 25, 23, 22, 7, 5, 3, 0, 92, 90, 90, 88, 86, 78, 77, 75, 75, 73, 71, 71, 69, 103, 99, 67, 66, 65, 94, 97, 97, 96, 61, 52, 51, 47, 47, 46, 42, 33, 29, 28, 26, 25, 22, 7, 3, 1, 90, 86, 85, 79, 78, 75, 99, 66, 65, 97, 61, 59, 52, 47, 42, 33, 28, 27, 26, 22, 20, 8, 7, 1, 79, 75, 71, 65, 97, 59, 53, 52, 42, 33, 27, 26, 26, 20, 19, 9, 8, 1, 80, 79, 71, 65, 59, 58, 54, 53, 42, 33, 26, 19, 9, 1, 0, 80, 71, 103, 65, 58, 57, 54, 42, 40, 33, 19, 18, 10, 9, 0, 82, 80, 103, 99, 65, 57, 55, 54, 40, 33, 18, 17, 10, 0, 90, 83, 82, 103, 100, 99, 57, 55, 40, 38, 33, 17, 16, 11, 10, 90, 84, 83, 103, 100, 57, 56, 56, 55, 38, 35, 33, 16, 12, 11, 90, 84, 103, 101, 100, 56, 38, 35, 16, 15, 13, 12, 90, 85, 84, 101, 101, 100, 38, 35, 15, 14, 13, 90, 85, 85, 101, 38, 36, 35, 14, 14, 13, 85, 85, 38, 37, 37, 36, 14, 14, 85, 37];
 testOutput["dude"]["pass"]=true;
 testOutput["dude"]["report"]="exp numtri:106\nexp dev:0.00000000000001\nact numtri:106\nact dev:0.0000000000000012205647034940505";
@@ -1,5 +1,5 @@
 testOutput["eberly_3"]=[];
 testOutput["eberly_3"]["json"]=[[[2328.0, 2408.0], [2328.0, 2472.0], [2344.0, 2472.0], [2344.0, 2432.0], [2384.0, 2448.0], [2384.0, 2536.0], [2408.0, 2552.0], [2448.0, 2544.0], [2456.0, 2560.0], [2496.0, 2544.0], [2480.0, 2624.0], [2456.0, 2664.0], [2424.0, 2680.0], [2400.0, 2768.0], [2376.0, 2768.0], [2368.0, 2704.0], [2336.0, 2704.0], [2264.0, 2784.0], [2216.0, 2784.0], [2200.0, 2760.0], [2168.0, 2760.0], [2152.0, 2744.0], [2128.0, 2744.0], [2128.0, 2784.0], [2072.0, 2768.0], [2032.0, 2720.0], [2000.0, 2720.0], [2000.0, 2688.0], [1936.0, 2696.0], [1920.0, 2736.0], [1888.0, 2728.0], [1896.0, 2696.0], [1928.0, 2688.0], [1928.0, 2664.0], [1896.0, 2664.0], [1896.0, 2640.0], [1912.0, 2632.0], [1872.0, 2608.0], [1888.0, 2576.0], [2056.0, 2576.0], [2088.0, 2600.0], [2184.0, 2608.0], [2216.0, 2632.0], [2256.0, 2624.0], [2248.0, 2600.0], [2216.0, 2592.0], [2192.0, 2560.0], [2120.0, 2576.0], [2072.0, 2544.0], [2096.0, 2544.0], [2080.0, 2520.0], [2080.0, 2488.0], [2096.0, 2480.0], [2080.0, 2448.0], [2096.0, 2432.0], [2176.0, 2496.0], [2200.0, 2488.0], [2224.0, 2528.0], [2248.0, 2528.0], [2240.0, 2488.0], [2256.0, 2472.0], [2280.0, 2480.0], [2264.0, 2416.0], [2272.0, 2392.0], [2328.0, 2408.0]], [[2320.0, 2608.0], [2304.0, 2640.0], [2312.0, 2664.0], [2360.0, 2632.0], [2352.0, 2608.0], [2320.0, 2608.0]], [[1912.0, 2632.0], [1936.0, 2632.0], [1936.0, 2616.0], [1912.0, 2608.0], [1912.0, 2632.0]]];
-testOutput["eberly_3"]["triangles"]=[2, 3, 4, 6, 7, 8, 8, 9, 10, 10, 11, 12, 12, 13, 14, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 30, 31, 32, 33, 34, 35, 35, 66, 67, 66, 35, 71, 74, 36, 37, 37, 38, 39, 40, 41, 42, 44, 45, 46, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 63, 0, 1, 2, 4, 5, 6, 8, 10, 12, 14, 15, 16, 18, 19, 22, 24, 25, 28, 30, 32, 33, 35, 67, 66, 71, 72, 74, 37, 39, 46, 48, 49, 49, 51, 52, 52, 54, 55, 58, 60, 61, 61, 63, 1, 1, 2, 5, 5, 6, 10, 10, 12, 15, 16, 19, 21, 21, 22, 25, 27, 28, 32, 32, 33, 67, 66, 72, 73, 73, 74, 39, 46, 49, 52, 52, 55, 57, 58, 61, 1, 5, 10, 15, 16, 21, 25, 27, 32, 67, 73, 39, 40, 44, 46, 52, 58, 1, 5, 16, 25, 27, 73, 40, 42, 44, 52, 57, 57, 58, 5, 15, 16, 27, 66, 73, 42, 44, 57, 5, 15, 27, 67, 66, 42, 43, 43, 44, 5, 15, 67, 68, 65, 66, 43, 5, 15, 68, 65, 43, 5, 5, 68, 69, 69, 65, 5];
+testOutput["eberly_3"]["triangles"]=[35, 66, 67, 66, 35, 71, 74, 36, 37, 37, 38, 39, 40, 41, 42, 44, 45, 46, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 63, 0, 1, 2, 3, 4, 6, 7, 8, 8, 9, 10, 10, 11, 12, 12, 13, 14, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 30, 31, 32, 33, 34, 35, 66, 71, 72, 74, 37, 39, 46, 48, 49, 49, 51, 52, 52, 54, 55, 58, 60, 61, 61, 63, 1, 2, 4, 5, 6, 8, 10, 12, 14, 15, 16, 18, 19, 22, 24, 25, 28, 30, 32, 33, 35, 67, 66, 72, 73, 73, 74, 39, 46, 49, 52, 52, 55, 57, 58, 61, 1, 1, 2, 5, 5, 6, 10, 10, 12, 15, 16, 19, 21, 21, 22, 25, 27, 28, 32, 32, 33, 67, 73, 39, 40, 44, 46, 52, 58, 1, 5, 5, 10, 15, 16, 21, 25, 27, 32, 67, 73, 40, 42, 44, 52, 57, 57, 58, 5, 16, 25, 27, 66, 73, 42, 44, 57, 5, 15, 16, 27, 66, 42, 43, 43, 44, 5, 15, 27, 67, 65, 66, 43, 15, 67, 68, 65, 43, 5, 5, 15, 68, 69, 65, 5, 5, 68, 69];
 testOutput["eberly_3"]["pass"]=true;
 testOutput["eberly_3"]["report"]="exp numtri:73\nexp dev:0.00000000000001\nact numtri:73\nact dev:0";
@@ -1,5 +1,5 @@
 testOutput["eberly_6"]=[];
 testOutput["eberly_6"]["json"]=[[[142.807, -11.178], [142.81, -11.171], [142.807, -11.168], [142.797, -11.143], [142.795, -11.113], [142.79, -11.085], [142.778, -11.054], [142.77, -11.039], [142.759, -11.027], [142.752, -11.015], [142.746, -10.986], [142.742, -10.975], [142.725, -10.968], [142.701, -10.97], [142.678, -10.982], [142.666, -11.003], [142.66, -10.983], [142.668, -10.951], [142.659, -10.934], [142.649, -10.929], [142.64, -10.932], [142.634, -10.931], [142.625, -10.882], [142.615, -10.868], [142.591, -10.865], [142.567, -10.87], [142.556, -10.882], [142.552, -10.904], [142.54, -10.925], [142.523, -10.942], [142.502, -10.948], [142.515, -10.924], [142.514, -10.9], [142.51, -10.877], [142.515, -10.852], [142.521, -10.847], [142.542, -10.842], [142.55, -10.838], [142.555, -10.829], [142.564, -10.806], [142.57, -10.797], [142.577, -10.792], [142.593, -10.786], [142.601, -10.78], [142.606, -10.772], [142.612, -10.762], [142.616, -10.752], [142.615, -10.745], [142.601, -10.735], [142.564, -10.717], [142.55, -10.708], [142.548, -10.703], [142.55, -10.696], [142.55, -10.69], [142.546, -10.688], [142.54, -10.689], [142.537, -10.691], [142.535, -10.693], [142.532, -10.695], [142.527, -10.698], [142.511, -10.712], [142.505, -10.715], [142.463, -10.711], [142.449, -10.715], [142.434, -10.728], [142.425, -10.745], [142.409, -10.792], [142.406, -10.807], [142.401, -10.82], [142.365, -10.858], [142.34, -10.89], [142.323, -10.902], [142.3, -10.906], [142.28, -10.907], [142.258, -10.911], [142.239, -10.918], [142.228, -10.927], [142.221, -10.92], [142.175, -10.93], [142.151, -10.951], [142.145, -10.983], [142.158, -11.067], [142.159, -11.151], [142.154, -11.178], [136.72, -11.178], [136.72, -11.174], [136.734, -11.138], [136.747, -11.093], [136.764, -11.04], [136.762, -11.019], [136.756, -11.027], [136.752, -11.037], [136.749, -11.035], [136.746, -11.034], [136.742, -11.033], [136.739, -11.03], [136.719, -11.051], [136.735, -11.064], [136.731, -11.079], [136.723, -11.086], [136.711, -11.097], [136.71, -11.121], [136.7, -11.14], [136.688, -11.178], [136.685, -11.178], [135.0, -11.178], [135.0, -4.342], [135.186, -4.449], [135.228, -4.461], [135.272, -4.458], [135.331, -4.44], [135.359, -4.444], [135.413, -4.436], [135.43, -4.43], [135.444, -4.441], [135.467, -4.446], [135.587, -4.46], [135.605, -4.468], [135.614, -4.475], [135.656, -4.486], [135.7, -4.487], [135.708, -4.489], [135.725, -4.495], [135.75, -4.497], [135.776, -4.495], [135.793, -4.486], [135.829, -4.499], [135.912, -4.498], [135.95, -4.506], [135.97, -4.52], [136.016, -4.571], [136.036, -4.586], [136.05, -4.59], [136.063, -4.591], [136.081, -4.595], [136.095, -4.603], [136.107, -4.612], [136.122, -4.619], [136.146, -4.622], [136.147, -4.626], [136.177, -4.65], [136.267, -4.676], [136.355, -4.677], [136.376, -4.684], [136.403, -4.708], [136.413, -4.712], [136.433, -4.712], [136.44, -4.715], [136.464, -4.736], [136.538, -4.774], [136.626, -4.823], [136.696, -4.847], [136.743, -4.882], [136.753, -4.88], [136.763, -4.874], [136.786, -4.876], [136.799, -4.88], [136.803, -4.883], [136.801, -4.9], [136.804, -4.91], [136.812, -4.92], [136.822, -4.928], [136.832, -4.931], [136.854, -4.928], [136.875, -4.92], [136.947, -4.876], [136.969, -4.87], [136.979, -4.88], [136.974, -4.885], [136.962, -4.891], [136.95, -4.899], [136.944, -4.91], [136.947, -4.918], [136.954, -4.923], [137.007, -4.946], [137.042, -4.943], [137.073, -4.924], [137.095, -4.889], [137.102, -4.889], [137.096, -4.922], [137.088, -4.943], [137.09, -4.96], [137.115, -4.979], [137.15, -4.945], [137.152, -4.974], [137.169, -4.991], [137.219, -5.013], [137.232, -4.999], [137.224, -4.982], [137.232, -4.969], [137.279, -4.937], [137.283, -4.94], [137.267, -4.999], [137.268, -5.014], [137.277, -5.02], [137.302, -5.027], [137.309, -5.022], [137.308, -4.999], [137.335, -5.031], [137.35, -5.04], [137.363, -5.026], [137.369, -5.026], [137.37, -5.037], [137.368, -5.046], [137.363, -5.055], [137.356, -5.062], [137.378, -5.082], [137.404, -5.098], [137.433, -5.103], [137.465, -5.096], [137.468, -5.11], [137.474, -5.115], [137.482, -5.117], [137.492, -5.123], [137.509, -5.142], [137.519, -5.149], [137.533, -5.15], [137.54, -5.144], [137.556, -5.121], [137.561, -5.116], [137.573, -5.121], [137.581, -5.143], [137.595, -5.15], [137.595, -5.157], [137.574, -5.167], [137.58, -5.182], [137.598, -5.196], [137.616, -5.205], [137.647, -5.212], [137.663, -5.22], [137.673, -5.218], [137.682, -5.213], [137.691, -5.211], [137.706, -5.222], [137.716, -5.257], [137.732, -5.273], [137.752, -5.278], [137.771, -5.272], [137.808, -5.253], [137.802, -5.27], [137.783, -5.298], [137.78, -5.315], [137.783, -5.318], [137.809, -5.353], [137.814, -5.358], [137.822, -5.363], [137.853, -5.362], [137.904, -5.324], [137.925, -5.329], [137.908, -5.363], [137.915, -5.387], [137.959, -5.431], [137.987, -5.47], [137.999, -5.479], [138.026, -5.485], [138.036, -5.475], [138.035, -5.455], [138.027, -5.431], [138.041, -5.436], [138.051, -5.432], [138.058, -5.42], [138.061, -5.404], [138.071, -5.41], [138.075, -5.42], [138.074, -5.445], [138.064, -5.489], [138.064, -5.511], [138.078, -5.52], [138.085, -5.522], [138.105, -5.531], [138.11, -5.534], [138.108, -5.545], [138.101, -5.549], [138.092, -5.55], [138.082, -5.555], [138.069, -5.565], [138.06, -5.575], [138.055, -5.59], [138.053, -5.613], [138.057, -5.658], [138.068, -5.698], [138.069, -5.708], [138.069, -5.72], [138.071, -5.729], [138.082, -5.734], [138.083, -5.738], [138.086, -5.74], [138.106, -5.726], [138.152, -5.723], [138.172, -5.717], [138.214, -5.692], [138.233, -5.684], [138.253, -5.68], [138.277, -5.679], [138.32, -5.671], [138.343, -5.67], [138.363, -5.679], [138.307, -5.696], [138.252, -5.719], [138.158, -5.774], [138.17, -5.787], [138.176, -5.796], [138.178, -5.804], [138.182, -5.81], [138.209, -5.825], [138.322, -5.851], [138.343, -5.849], [138.386, -5.837], [138.404, -5.836], [138.377, -5.856], [138.304, -5.868], [138.271, -5.887], [138.262, -5.915], [138.276, -5.946], [138.339, -6.043], [138.343, -6.058], [138.347, -6.071], [138.365, -6.092], [138.37, -6.106], [138.372, -6.124], [138.39, -6.172], [138.392, -6.186], [138.39, -6.234], [138.395, -6.253], [138.414, -6.291], [138.421, -6.319], [138.466, -6.402], [138.604, -6.528], [138.619, -6.535], [138.632, -6.544], [138.678, -6.603], [138.709, -6.63], [138.829, -6.706], [138.763, -6.67], [138.743, -6.665], [138.703, -6.645], [138.682, -6.645], [138.671, -6.672], [138.673, -6.695], [138.683, -6.715], [138.7, -6.728], [138.746, -6.74], [138.768, -6.757], [138.822, -6.806], [138.838, -6.815], [138.857, -6.82], [138.924, -6.826], [138.966, -6.836], [139.0, -6.853], [139.069, -6.918], [139.115, -6.941], [139.123, -6.949], [139.133, -6.952], [139.157, -6.954], [139.18, -6.959], [139.192, -6.973], [139.182, -6.971], [139.14, -6.973], [139.126, -6.972], [139.118, -6.968], [139.104, -6.959], [139.083, -6.953], [139.073, -6.949], [139.069, -6.942], [139.038, -6.918], [139.018, -6.909], [138.989, -6.866], [138.97, -6.856], [138.96, -6.854], [138.94, -6.845], [138.931, -6.843], [138.917, -6.842], [138.884, -6.849], [138.798, -6.856], [138.783, -6.862], [138.756, -6.888], [138.74, -6.897], [138.716, -6.898], [138.675, -6.881], [138.654, -6.877], [138.613, -6.877], [138.597, -6.881], [138.581, -6.891], [138.562, -6.919], [138.572, -6.942], [138.646, -7.0], [138.748, -7.098], [138.753, -7.107], [138.762, -7.114], [138.815, -7.131], [138.843, -7.146], [138.858, -7.15], [138.925, -7.153], [138.946, -7.157], [138.966, -7.165], [139.019, -7.194], [139.038, -7.212], [139.061, -7.212], [139.104, -7.206], [139.147, -7.206], [139.159, -7.203], [139.167, -7.195], [139.181, -7.175], [139.206, -7.15], [139.222, -7.144], [139.24, -7.151], [139.24, -7.158], [139.215, -7.167], [139.199, -7.189], [139.188, -7.215], [139.175, -7.237], [139.157, -7.243], [139.004, -7.24], [138.991, -7.236], [138.962, -7.215], [138.945, -7.206], [138.905, -7.199], [138.688, -7.193], [138.667, -7.198], [138.666, -7.21], [138.677, -7.223], [138.691, -7.234], [138.7, -7.237], [138.709, -7.239], [138.729, -7.24], [138.739, -7.244], [138.746, -7.251], [138.751, -7.258], [138.767, -7.265], [138.841, -7.315], [138.865, -7.336], [138.886, -7.361], [138.926, -7.427], [138.935, -7.449], [138.944, -7.497], [138.958, -7.507], [139.0, -7.508], [139.036, -7.515], [139.071, -7.533], [139.093, -7.56], [139.089, -7.597], [139.081, -7.606], [139.069, -7.614], [139.059, -7.623], [139.055, -7.634], [139.048, -7.668], [139.045, -7.675], [139.035, -7.69], [139.007, -7.754], [139.002, -7.836], [138.994, -7.865], [138.966, -7.885], [138.927, -7.894], [138.915, -7.903], [138.911, -7.923], [138.911, -8.008], [138.906, -8.031], [138.906, -8.044], [138.92, -8.052], [138.924, -8.061], [138.925, -8.07], [138.921, -8.08], [138.901, -8.092], [138.859, -8.101], [138.843, -8.117], [138.839, -8.138], [138.843, -8.161], [138.853, -8.184], [138.876, -8.219], [138.914, -8.292], [138.921, -8.295], [138.935, -8.296], [138.943, -8.291], [138.951, -8.281], [138.957, -8.271], [138.959, -8.265], [138.963, -8.246], [138.973, -8.23], [139.09, -8.134], [139.123, -8.117], [139.201, -8.096], [139.224, -8.074], [139.227, -8.028], [139.23, -8.023], [139.232, -8.018], [139.23, -8.011], [139.225, -8.009], [139.214, -8.007], [139.212, -8.004], [139.211, -7.994], [139.207, -7.974], [139.206, -7.963], [139.217, -7.951], [139.243, -7.957], [139.288, -7.973], [139.269, -7.986], [139.252, -7.982], [139.235, -7.975], [139.219, -7.98], [139.24, -8.003], [139.245, -8.015], [139.248, -8.049], [139.252, -8.067], [139.25, -8.073], [139.246, -8.08], [139.243, -8.091], [139.24, -8.102], [139.24, -8.111], [139.255, -8.146], [139.283, -8.173], [139.32, -8.19], [139.357, -8.199], [139.395, -8.201], [139.435, -8.197], [139.515, -8.179], [139.547, -8.166], [139.599, -8.135], [139.631, -8.125], [139.769, -8.11], [139.932, -8.109], [139.974, -8.097], [140.015, -8.074], [140.034, -8.059], [140.042, -8.046], [140.046, -7.98], [140.054, -7.936], [140.061, -7.924], [140.075, -7.92], [140.1, -7.92], [140.119, -7.915], [140.135, -7.893], [140.152, -7.885], [140.129, -7.936], [140.112, -7.947], [140.083, -7.933], [140.061, -7.948], [140.054, -7.972], [140.057, -7.996], [140.066, -8.029], [140.062, -8.066], [140.058, -8.082], [140.048, -8.087], [140.035, -8.087], [140.021, -8.09], [139.998, -8.104], [139.98, -8.122], [139.971, -8.142], [139.974, -8.165], [139.988, -8.192], [140.032, -8.246], [140.252, -8.409], [140.267, -8.431], [140.301, -8.467], [140.354, -8.489], [140.363, -8.491], [140.368, -8.508], [140.378, -8.522], [140.446, -8.578], [140.453, -8.587], [140.507, -8.638], [140.622, -8.807], [140.857, -9.049], [140.921, -9.079], [140.933, -9.08], [140.977, -9.106], [140.977, -9.106], [141.008, -9.124], [141.115, -9.218], [141.153, -9.232], [141.2, -9.228], [141.232, -9.213], [141.31, -9.152], [141.346, -9.142], [141.385, -9.142], [141.422, -9.15], [141.453, -9.166], [141.495, -9.195], [141.51, -9.215], [141.53, -9.218], [141.551, -9.218], [141.563, -9.22], [141.579, -9.213], [141.593, -9.219], [141.606, -9.229], [141.621, -9.234], [141.635, -9.232], [141.651, -9.227], [141.665, -9.22], [141.673, -9.214], [141.698, -9.215], [141.783, -9.2], [141.806, -9.203], [141.847, -9.217], [141.868, -9.22], [141.893, -9.219], [141.913, -9.215], [141.974, -9.193], [141.994, -9.189], [142.136, -9.178], [142.169, -9.179], [142.181, -9.176], [142.2, -9.162], [142.211, -9.159], [142.223, -9.161], [142.276, -9.179], [142.319, -9.204], [142.334, -9.207], [142.358, -9.209], [142.373, -9.215], [142.399, -9.228], [142.429, -9.225], [142.465, -9.24], [142.499, -9.263], [142.547, -9.304], [142.577, -9.324], [142.612, -9.335], [142.653, -9.33], [142.708, -9.299], [142.715, -9.288], [142.731, -9.278], [142.838, -9.237], [142.874, -9.209], [142.92, -9.19], [142.941, -9.179], [142.949, -9.169], [142.958, -9.156], [142.967, -9.144], [142.978, -9.138], [142.988, -9.136], [143.011, -9.122], [143.036, -9.102], [143.053, -9.093], [143.142, -9.066], [143.156, -9.063], [143.163, -9.06], [143.168, -9.053], [143.171, -9.046], [143.173, -9.043], [143.178, -9.042], [143.189, -9.044], [143.204, -9.041], [143.225, -9.039], [143.235, -9.036], [143.229, -9.029], [143.258, -9.024], [143.315, -9.024], [143.345, -9.016], [143.361, -9.007], [143.373, -9.0], [143.396, -8.978], [143.406, -8.962], [143.406, -8.945], [143.4, -8.912], [143.4, -8.782], [143.395, -8.769], [143.386, -8.753], [143.348, -8.708], [143.318, -8.659], [143.262, -8.592], [143.132, -8.486], [143.102, -8.467], [143.063, -8.453], [142.938, -8.428], [142.9, -8.413], [142.835, -8.37], [142.814, -8.365], [142.804, -8.36], [142.769, -8.33], [142.728, -8.317], [142.712, -8.323], [142.632, -8.309], [142.596, -8.321], [142.53, -8.365], [142.494, -8.378], [142.452, -8.373], [142.42, -8.355], [142.397, -8.328], [142.367, -8.232], [142.365, -8.21], [142.354, -8.189], [142.33, -8.184], [142.302, -8.188], [142.26, -8.199], [142.247, -8.2], [142.231, -8.194], [142.225, -8.198], [142.219, -8.204], [142.211, -8.207], [142.196, -8.211], [142.182, -8.22], [142.16, -8.241], [142.144, -8.234], [142.14, -8.226], [142.146, -8.217], [142.16, -8.207], [142.171, -8.203], [142.182, -8.201], [142.193, -8.198], [142.204, -8.189], [142.211, -8.181], [142.217, -8.175], [142.225, -8.173], [142.26, -8.171], [142.317, -8.159], [142.36, -8.167], [142.381, -8.193], [142.405, -8.293], [142.414, -8.31], [142.429, -8.324], [142.451, -8.33], [142.494, -8.333], [142.514, -8.332], [142.536, -8.324], [142.593, -8.287], [142.615, -8.282], [142.634, -8.28], [142.684, -8.269], [142.7, -8.261], [142.71, -8.267], [142.715, -8.268], [142.841, -8.288], [142.94, -8.333], [142.958, -8.337], [142.98, -8.336], [143.215, -8.275], [143.3, -8.269], [143.328, -8.252], [143.338, -8.248], [143.592, -8.242], [143.612, -8.239], [143.629, -8.23], [143.635, -8.211], [143.628, -8.186], [143.57, -8.085], [143.56, -8.045], [143.548, -8.032], [143.467, -7.996], [143.448, -7.982], [143.441, -7.963], [143.437, -7.938], [143.426, -7.93], [143.386, -7.926], [143.375, -7.922], [143.367, -7.918], [143.361, -7.911], [143.359, -7.902], [143.364, -7.896], [143.375, -7.899], [143.386, -7.903], [143.389, -7.906], [143.416, -7.906], [143.434, -7.909], [143.448, -7.919], [143.461, -7.939], [143.478, -7.976], [143.489, -7.989], [143.506, -7.994], [143.563, -7.998], [143.628, -7.982], [143.651, -7.981], [143.67, -7.99], [143.68, -7.997], [143.715, -8.008], [143.723, -8.014], [143.741, -8.029], [143.749, -8.035], [143.767, -8.04], [143.861, -8.041], [143.881, -8.038], [143.898, -8.028], [143.914, -8.008], [143.876, -7.97], [143.859, -7.96], [143.85, -7.957], [143.833, -7.956], [143.824, -7.953], [143.818, -7.948], [143.81, -7.937], [143.804, -7.933], [143.862, -7.935], [143.879, -7.939], [143.894, -7.953], [143.908, -7.972], [143.923, -7.989], [143.941, -7.994], [143.943, -7.992], [143.957, -7.972], [143.941, -7.93], [143.893, -7.857], [143.867, -7.826], [143.856, -7.808], [143.852, -7.785], [143.851, -7.761], [143.847, -7.74], [143.841, -7.72], [143.76, -7.583], [143.742, -7.542], [143.744, -7.536], [143.747, -7.529], [143.746, -7.524], [143.723, -7.519], [143.7, -7.51], [143.691, -7.508], [143.681, -7.502], [143.649, -7.468], [143.639, -7.452], [143.646, -7.438], [143.65, -7.449], [143.653, -7.452], [143.662, -7.462], [143.696, -7.488], [143.711, -7.494], [143.723, -7.494], [143.746, -7.497], [143.756, -7.5], [143.766, -7.506], [143.769, -7.51], [143.777, -7.528], [143.813, -7.594], [143.818, -7.614], [143.828, -7.632], [143.851, -7.652], [143.924, -7.694], [144.112, -7.758], [144.129, -7.772], [144.139, -7.777], [144.151, -7.774], [144.163, -7.77], [144.174, -7.768], [144.187, -7.772], [144.196, -7.778], [144.215, -7.796], [144.229, -7.789], [144.266, -7.776], [144.276, -7.768], [144.275, -7.756], [144.265, -7.742], [144.252, -7.731], [144.242, -7.726], [144.255, -7.706], [144.261, -7.684], [144.254, -7.667], [144.229, -7.666], [144.229, -7.658], [144.241, -7.655], [144.253, -7.65], [144.263, -7.643], [144.27, -7.631], [144.285, -7.647], [144.307, -7.693], [144.324, -7.7], [144.334, -7.689], [144.327, -7.665], [144.316, -7.637], [144.311, -7.61], [144.344, -7.659], [144.355, -7.687], [144.36, -7.717], [144.367, -7.744], [144.385, -7.752], [144.403, -7.743], [144.413, -7.72], [144.424, -7.727], [144.438, -7.74], [144.45, -7.754], [144.455, -7.765], [144.46, -7.769], [144.48, -7.796], [144.482, -7.802], [144.508, -7.798], [144.5, -7.774], [144.48, -7.748], [144.469, -7.737], [144.465, -7.721], [144.457, -7.708], [144.447, -7.696], [144.441, -7.686], [144.436, -7.66], [144.434, -7.627], [144.431, -7.612], [144.418, -7.585], [144.413, -7.569], [144.412, -7.531], [144.407, -7.514], [144.413, -7.514], [144.422, -7.527], [144.433, -7.559], [144.441, -7.576], [144.446, -7.58], [144.453, -7.584], [144.459, -7.588], [144.462, -7.593], [144.462, -7.599], [144.466, -7.617], [144.469, -7.624], [144.478, -7.635], [144.504, -7.656], [144.51, -7.669], [144.514, -7.672], [144.523, -7.669], [144.533, -7.662], [144.538, -7.652], [144.515, -7.633], [144.51, -7.624], [144.509, -7.611], [144.517, -7.579], [144.517, -7.508], [144.522, -7.496], [144.538, -7.514], [144.539, -7.525], [144.537, -7.549], [144.541, -7.559], [144.547, -7.566], [144.55, -7.573], [144.551, -7.582], [144.551, -7.593], [144.555, -7.607], [144.564, -7.625], [144.575, -7.642], [144.585, -7.652], [144.604, -7.657], [144.629, -7.656], [144.652, -7.649], [144.661, -7.634], [144.658, -7.616], [144.651, -7.598], [144.648, -7.58], [144.654, -7.561], [144.679, -7.582], [144.69, -7.594], [144.699, -7.617], [144.736, -7.644], [144.757, -7.669], [144.771, -7.681], [144.787, -7.686], [144.805, -7.686], [144.825, -7.683], [144.837, -7.674], [144.831, -7.658], [144.84, -7.637], [144.844, -7.589], [144.852, -7.569], [144.864, -7.586], [144.867, -7.608], [144.866, -7.706], [144.858, -7.724], [144.842, -7.744], [144.843, -7.75], [144.851, -7.758], [144.861, -7.765], [144.87, -7.768], [144.872, -7.77], [144.878, -7.779], [144.88, -7.782], [144.885, -7.782], [144.897, -7.777], [144.904, -7.775], [144.92, -7.781], [144.932, -7.79], [144.943, -7.792], [144.955, -7.775], [144.985, -7.81], [144.996, -7.817], [145.058, -7.817], [145.068, -7.812], [145.071, -7.806], [145.071, -7.785], [145.075, -7.776], [145.082, -7.779], [145.089, -7.788], [145.093, -7.799], [145.102, -7.817], [145.124, -7.831], [145.15, -7.838], [145.168, -7.836], [145.167, -7.831], [145.175, -7.816], [145.184, -7.806], [145.192, -7.818], [145.206, -7.828], [145.213, -7.84], [145.234, -7.859], [145.243, -7.864], [145.255, -7.865], [145.265, -7.863], [145.275, -7.859], [145.284, -7.857], [145.322, -7.862], [145.35, -7.877], [145.408, -7.932], [145.423, -7.94], [145.442, -7.944], [145.466, -7.945], [145.533, -7.933], [145.552, -7.939], [145.552, -7.933], [145.568, -7.939], [145.583, -7.937], [145.598, -7.933], [145.613, -7.933], [145.627, -7.938], [145.645, -7.956], [145.658, -7.96], [145.719, -7.96], [145.737, -7.953], [145.723, -7.933], [145.741, -7.924], [145.758, -7.927], [145.772, -7.927], [145.785, -7.912], [145.791, -7.912], [145.779, -7.936], [145.778, -7.942], [145.78, -7.947], [145.785, -7.95], [145.79, -7.954], [145.791, -7.96], [145.787, -7.965], [145.774, -7.967], [145.771, -7.97], [145.773, -7.978], [145.778, -7.983], [145.782, -7.987], [145.785, -7.99], [145.798, -8.007], [145.861, -8.028], [145.881, -8.042], [145.907, -8.033], [145.939, -8.035], [145.997, -8.049], [146.05, -8.076], [146.079, -8.087], [146.1, -8.083], [146.105, -8.093], [146.103, -8.1], [146.096, -8.106], [146.086, -8.111], [146.098, -8.122], [146.11, -8.138], [146.113, -8.154], [146.1, -8.165], [146.117, -8.2], [146.127, -8.21], [146.151, -8.214], [146.16, -8.22], [146.189, -8.248], [146.222, -8.26], [146.235, -8.274], [146.245, -8.292], [146.25, -8.307], [146.25, -11.178], [142.807, -11.178]], [[138.623, -6.767], [138.639, -6.787], [138.65, -6.797], [138.661, -6.802], [138.673, -6.81], [138.682, -6.848], [138.695, -6.856], [138.707, -6.859], [138.716, -6.865], [138.728, -6.868], [138.747, -6.863], [138.769, -6.849], [138.782, -6.839], [138.787, -6.83], [138.783, -6.814], [138.759, -6.792], [138.753, -6.778], [138.747, -6.768], [138.731, -6.763], [138.695, -6.761], [138.679, -6.758], [138.666, -6.75], [138.641, -6.731], [138.627, -6.72], [138.619, -6.729], [138.617, -6.748], [138.623, -6.767]], [[138.395, -7.405], [138.244, -7.456], [138.089, -7.56], [138.028, -7.61], [138.007, -7.638], [137.985, -7.679], [137.976, -7.703], [137.973, -7.723], [137.966, -7.739], [137.918, -7.782], [137.895, -7.816], [137.824, -7.964], [137.818, -7.973], [137.81, -7.984], [137.801, -8.042], [137.789, -8.052], [137.759, -8.073], [137.753, -8.087], [137.75, -8.101], [137.726, -8.145], [137.637, -8.389], [137.638, -8.426], [137.677, -8.426], [137.76, -8.394], [137.802, -8.383], [137.849, -8.378], [138.034, -8.382], [138.178, -8.385], [138.223, -8.392], [138.293, -8.419], [138.336, -8.419], [138.376, -8.41], [138.412, -8.396], [138.444, -8.379], [138.472, -8.358], [138.508, -8.316], [138.518, -8.309], [138.536, -8.302], [138.603, -8.255], [138.621, -8.234], [138.646, -8.189], [138.661, -8.169], [138.68, -8.157], [138.701, -8.154], [138.747, -8.152], [138.772, -8.143], [138.788, -8.13], [138.818, -8.093], [138.837, -8.08], [138.858, -8.075], [138.904, -8.076], [138.893, -8.041], [138.886, -8.007], [138.884, -7.933], [138.891, -7.898], [138.911, -7.879], [138.966, -7.851], [138.977, -7.841], [138.983, -7.83], [138.989, -7.738], [138.994, -7.72], [139.02, -7.672], [139.035, -7.63], [139.044, -7.611], [139.057, -7.595], [139.076, -7.576], [139.046, -7.562], [138.974, -7.553], [138.939, -7.542], [138.923, -7.532], [138.909, -7.52], [138.896, -7.505], [138.848, -7.435], [138.843, -7.422], [138.805, -7.374], [138.717, -7.36], [138.621, -7.365], [138.556, -7.377], [138.529, -7.386], [138.395, -7.405]], [[138.558, -8.367], [138.582, -8.373], [138.63, -8.371], [138.654, -8.376], [138.7, -8.395], [138.894, -8.405], [138.91, -8.396], [138.911, -8.375], [138.904, -8.352], [138.897, -8.337], [138.858, -8.297], [138.842, -8.277], [138.835, -8.251], [138.835, -8.219], [138.831, -8.186], [138.817, -8.162], [138.785, -8.159], [138.729, -8.177], [138.689, -8.181], [138.675, -8.189], [138.667, -8.203], [138.665, -8.224], [138.657, -8.241], [138.64, -8.257], [138.564, -8.307], [138.552, -8.323], [138.548, -8.347], [138.558, -8.367]], [[142.181, -9.285], [142.222, -9.291], [142.261, -9.29], [142.279, -9.287], [142.284, -9.278], [142.28, -9.262], [142.207, -9.24], [142.164, -9.251], [142.151, -9.271], [142.181, -9.285]], [[142.755, -9.37], [142.719, -9.378], [142.694, -9.38], [142.665, -9.374], [142.637, -9.374], [142.612, -9.383], [142.601, -9.4], [142.612, -9.416], [142.629, -9.425], [142.642, -9.428], [142.714, -9.429], [142.732, -9.425], [142.742, -9.419], [142.752, -9.408], [142.768, -9.398], [142.783, -9.386], [142.771, -9.374], [142.755, -9.37]], [[142.125, -10.158], [142.139, -10.173], [142.143, -10.186], [142.155, -10.188], [142.167, -10.182], [142.176, -10.161], [142.183, -10.153], [142.19, -10.144], [142.198, -10.102], [142.198, -10.092], [142.19, -10.081], [142.17, -10.06], [142.157, -10.053], [142.142, -10.05], [142.097, -10.119], [142.099, -10.136], [142.11, -10.148], [142.125, -10.158]], [[142.211, -10.183], [142.204, -10.188], [142.193, -10.194], [142.195, -10.207], [142.201, -10.22], [142.211, -10.232], [142.221, -10.242], [142.234, -10.238], [142.249, -10.24], [142.264, -10.247], [142.276, -10.256], [142.29, -10.26], [142.317, -10.213], [142.334, -10.2], [142.341, -10.191], [142.332, -10.17], [142.314, -10.149], [142.293, -10.139], [142.254, -10.138], [142.232, -10.142], [142.214, -10.153], [142.213, -10.158], [142.214, -10.177], [142.211, -10.183]], [[143.492, -8.553], [143.482, -8.534], [143.475, -8.525], [143.465, -8.522], [143.443, -8.518], [143.359, -8.489], [143.271, -8.469], [143.256, -8.457], [143.219, -8.415], [143.207, -8.414], [143.194, -8.417], [143.179, -8.419], [143.188, -8.43], [143.209, -8.444], [143.221, -8.453], [143.244, -8.485], [143.255, -8.495], [143.282, -8.506], [143.338, -8.522], [143.401, -8.573], [143.407, -8.587], [143.412, -8.596], [143.425, -8.6], [143.44, -8.602], [143.451, -8.608], [143.474, -8.625], [143.591, -8.679], [143.606, -8.689], [143.612, -8.703], [143.617, -8.722], [143.629, -8.73], [143.642, -8.726], [143.653, -8.706], [143.653, -8.683], [143.644, -8.661], [143.629, -8.645], [143.608, -8.638], [143.588, -8.631], [143.492, -8.553]], [[143.53, -8.486], [143.582, -8.487], [143.601, -8.484], [143.611, -8.475], [143.612, -8.462], [143.605, -8.447], [143.589, -8.421], [143.586, -8.406], [143.585, -8.381], [143.574, -8.374], [143.484, -8.364], [143.417, -8.365], [143.385, -8.371], [143.373, -8.371], [143.365, -8.368], [143.359, -8.362], [143.351, -8.357], [143.338, -8.358], [143.317, -8.372], [143.33, -8.394], [143.358, -8.415], [143.379, -8.426], [143.424, -8.433], [143.445, -8.439], [143.462, -8.458], [143.51, -8.481], [143.53, -8.486]], [[143.541, -8.35], [143.59, -8.372], [143.626, -8.412], [143.648, -8.426], [143.668, -8.428], [143.679, -8.421], [143.682, -8.413], [143.676, -8.4], [143.674, -8.391], [143.669, -8.373], [143.657, -8.355], [143.638, -8.341], [143.6, -8.333], [143.551, -8.336], [143.541, -8.35]], [[143.783, -8.528], [143.796, -8.538], [143.813, -8.531], [143.823, -8.51], [143.822, -8.5], [143.816, -8.495], [143.81, -8.497], [143.803, -8.501], [143.792, -8.504], [143.78, -8.513], [143.783, -8.528]], [[143.654, -8.104], [143.641, -8.099], [143.613, -8.095], [143.599, -8.111], [143.614, -8.139], [143.634, -8.165], [143.667, -8.167], [143.688, -8.154], [143.69, -8.137], [143.68, -8.121], [143.663, -8.109], [143.654, -8.104]], [[143.676, -8.093], [143.69, -8.1], [143.698, -8.091], [143.686, -8.066], [143.676, -8.054], [143.656, -8.04], [143.613, -8.02], [143.578, -8.018], [143.564, -8.03], [143.574, -8.051], [143.582, -8.069], [143.607, -8.082], [143.648, -8.085], [143.676, -8.093]], [[142.255, -10.667], [142.229, -10.62], [142.212, -10.598], [142.193, -10.591], [142.182, -10.61], [142.18, -10.615], [142.177, -10.616], [142.16, -10.626], [142.152, -10.632], [142.15, -10.636], [142.146, -10.639], [142.128, -10.641], [142.124, -10.644], [142.121, -10.648], [142.118, -10.653], [142.112, -10.684], [142.122, -10.71], [142.137, -10.731], [142.146, -10.749], [142.153, -10.756], [142.187, -10.77], [142.19, -10.758], [142.197, -10.745], [142.206, -10.733], [142.218, -10.728], [142.23, -10.726], [142.253, -10.717], [142.263, -10.716], [142.273, -10.718], [142.28, -10.719], [142.283, -10.715], [142.283, -10.701], [142.279, -10.691], [142.255, -10.667]], [[142.254, -10.625], [142.274, -10.641], [142.303, -10.633], [142.325, -10.612], [142.319, -10.589], [142.294, -10.573], [142.262, -10.578], [142.247, -10.6], [142.254, -10.625]]];
-testOutput["eberly_6"]["triangles"]=[1036, 1035, 1034, 1034, 1033, 1032, 1030, 1029, 1028, 1028, 1027, 1026, 1026, 1025, 1024, 1024, 1023, 1022, 1018, 1017, 1016, 1016, 1015, 1014, 1011, 1010, 1009, 1007, 1006, 1005, 1001, 1000, 999, 999, 998, 997, 996, 995, 994, 994, 993, 992, 989, 988, 987, 987, 986, 985, 980, 979, 978, 978, 977, 976, 976, 975, 974, 970, 969, 968, 968, 967, 966, 961, 960, 959, 958, 957, 956, 956, 955, 954, 952, 951, 950, 946, 945, 944, 944, 943, 942, 942, 941, 940, 939, 938, 937, 932, 931, 930, 930, 929, 928, 928, 927, 926, 926, 925, 924, 915, 914, 913, 913, 912, 911, 910, 909, 908, 908, 907, 906, 905, 904, 903, 903, 902, 901, 897, 896, 895, 892, 891, 890, 890, 889, 888, 886, 885, 884, 884, 883, 882, 882, 881, 880, 878, 877, 876, 876, 875, 874, 873, 872, 871, 868, 867, 866, 865, 864, 863, 863, 862, 861, 857, 856, 855, 855, 854, 853, 849, 848, 847, 844, 843, 842, 839, 838, 837, 833, 832, 831, 831, 830, 829, 829, 828, 827, 824, 823, 822, 819, 818, 817, 817, 816, 815, 815, 814, 813, 813, 812, 811, 809, 808, 807, 807, 806, 805, 805, 804, 803, 803, 802, 801, 801, 800, 799, 798, 797, 796, 796, 795, 794, 792, 791, 790, 790, 789, 788, 782, 781, 780, 780, 779, 778, 777, 776, 775, 775, 774, 773, 764, 763, 762, 761, 760, 759, 759, 758, 757, 754, 753, 752, 752, 751, 750, 749, 748, 747, 747, 746, 745, 745, 744, 743, 743, 742, 741, 739, 738, 737, 737, 736, 1369, 1362, 1374, 1373, 1371, 1370, 1369, 1369, 736, 735, 734, 733, 1352, 1353, 1352, 733, 728, 727, 726, 725, 724, 723, 1331, 1330, 1329, 721, 720, 719, 718, 717, 716, 715, 714, 713, 706, 705, 704, 704, 703, 702, 702, 701, 700, 700, 699, 698, 696, 695, 694, 694, 693, 692, 692, 691, 690, 688, 687, 686, 684, 683, 682, 678, 677, 676, 676, 675, 674, 674, 673, 672, 670, 669, 668, 667, 666, 665, 665, 664, 1314, 1311, 1310, 1309, 1309, 1308, 1307, 1304, 1303, 1302, 1320, 1319, 1318, 1315, 1314, 664, 664, 663, 1268, 1268, 1267, 1266, 1265, 1264, 1263, 1263, 1262, 1347, 1346, 1345, 1344, 1348, 1347, 1262, 1262, 1261, 1260, 1258, 1295, 1294, 1294, 1293, 1292, 1286, 1285, 1284, 1284, 1283, 1282, 1282, 1281, 1280, 1277, 1276, 1275, 1272, 1271, 1270, 1269, 1268, 663, 663, 662, 661, 657, 656, 655, 653, 652, 651, 645, 644, 643, 641, 640, 639, 639, 638, 637, 637, 636, 635, 633, 632, 631, 631, 630, 629, 628, 627, 626, 626, 625, 624, 622, 621, 620, 620, 619, 618, 613, 612, 611, 611, 610, 609, 609, 608, 607, 605, 604, 603, 603, 602, 601, 599, 598, 597, 597, 596, 595, 592, 591, 590, 589, 588, 587, 583, 582, 581, 580, 579, 578, 577, 576, 575, 575, 574, 573, 573, 572, 571, 560, 559, 558, 558, 557, 556, 555, 554, 553, 551, 550, 549, 542, 541, 540, 540, 539, 538, 534, 533, 532, 532, 531, 530, 528, 527, 526, 526, 525, 524, 519, 518, 517, 517, 516, 515, 504, 503, 502, 501, 500, 499, 497, 496, 495, 495, 494, 493, 493, 492, 491, 491, 490, 489, 489, 488, 487, 483, 482, 481, 480, 479, 478, 478, 477, 476, 469, 468, 467, 463, 462, 461, 461, 460, 459, 459, 458, 457, 456, 455, 454, 452, 451, 450, 450, 449, 448, 447, 446, 445, 442, 441, 440, 440, 439, 438, 438, 437, 436, 434, 433, 432, 432, 431, 430, 430, 429, 428, 427, 426, 425, 425, 424, 423, 414, 413, 412, 412, 411, 410, 410, 409, 408, 407, 406, 405, 405, 404, 403, 403, 402, 401, 399, 398, 397, 396, 395, 394, 394, 393, 392, 390, 389, 388, 387, 386, 385, 378, 377, 376, 376, 375, 374, 373, 372, 371, 369, 368, 367, 366, 365, 364, 363, 362, 361, 360, 359, 358, 358, 357, 356, 356, 355, 354, 354, 353, 352, 351, 350, 349, 349, 348, 347, 347, 346, 345, 343, 342, 341, 335, 334, 333, 333, 332, 331, 330, 329, 328, 325, 324, 323, 322, 321, 320, 319, 318, 317, 315, 314, 313, 310, 309, 308, 308, 307, 306, 302, 301, 300, 297, 296, 295, 295, 294, 293, 293, 292, 291, 291, 290, 289, 288, 287, 286, 285, 284, 283, 282, 281, 280, 273, 272, 271, 271, 270, 269, 269, 268, 267, 265, 264, 263, 263, 262, 261, 258, 257, 256, 252, 251, 250, 249, 248, 247, 243, 242, 241, 240, 239, 238, 234, 233, 232, 232, 231, 230, 229, 228, 227, 224, 223, 222, 221, 220, 219, 219, 218, 217, 214, 213, 212, 210, 209, 208, 205, 204, 203, 203, 202, 201, 201, 200, 199, 198, 197, 196, 192, 191, 190, 190, 189, 188, 184, 183, 182, 180, 179, 178, 178, 177, 176, 169, 168, 167, 167, 166, 165, 158, 157, 156, 156, 155, 154, 152, 151, 150, 150, 149, 148, 148, 147, 146, 144, 143, 142, 139, 138, 137, 136, 135, 134, 134, 133, 132, 130, 129, 128, 128, 127, 126, 126, 125, 124, 122, 121, 120, 118, 117, 116, 114, 113, 112, 111, 110, 109, 107, 106, 105, 105, 1077, 1076, 1075, 1074, 1073, 1073, 1072, 1071, 1068, 1067, 1066, 1060, 1059, 1058, 1057, 1056, 1055, 1078, 1077, 105, 105, 1099, 1098, 1098, 1097, 1096, 1095, 1094, 1093, 1092, 1091, 1090, 1088, 1087, 1086, 1158, 1157, 1156, 1152, 1151, 1150, 1150, 1149, 1148, 1148, 1147, 1146, 1144, 1143, 1142, 1142, 1141, 1140, 1140, 1139, 1138, 1135, 1134, 1133, 1133, 1132, 1131, 1131, 1130, 1129, 1129, 1128, 1127, 1127, 1126, 1125, 1123, 1122, 1121, 1121, 1120, 1119, 1116, 1115, 1114, 1114, 1113, 1185, 1183, 1182, 1181, 1181, 1180, 1179, 1177, 1176, 1175, 1172, 1171, 1170, 1170, 1169, 1168, 1163, 1162, 1161, 1186, 1185, 1113, 1108, 1107, 1106, 1105, 1104, 1103, 1103, 1102, 1101, 1100, 1099, 105, 105, 1195, 1194, 1196, 1195, 105, 105, 1203, 1202, 1200, 1199, 1198, 1211, 1210, 1209, 1204, 1203, 105, 105, 1229, 1228, 1221, 1220, 1219, 1217, 1216, 1232, 1230, 1229, 105, 105, 1235, 1234, 1234, 1256, 1255, 1246, 1245, 1244, 1243, 1242, 1241, 1241, 1240, 1239, 1236, 1235, 105, 104, 103, 102, 102, 101, 100, 99, 98, 97, 95, 94, 93, 92, 91, 90, 89, 1390, 1389, 1386, 1385, 1384, 1381, 1380, 1379, 1378, 1377, 1416, 1417, 1416, 1377, 1376, 1408, 1407, 1403, 1402, 1401, 1400, 1399, 1398, 1398, 1397, 1396, 1393, 1392, 1391, 88, 87, 86, 86, 85, 84, 84, 83, 82, 82, 81, 80, 77, 76, 75, 73, 72, 71, 71, 70, 69, 69, 68, 67, 62, 61, 60, 59, 58, 57, 52, 51, 50, 50, 49, 48, 42, 41, 40, 40, 39, 38, 36, 35, 34, 34, 33, 32, 31, 30, 29, 29, 28, 27, 22, 21, 20, 17, 16, 15, 10, 9, 8, 4, 3, 2, 1, 1051, 1050, 1050, 1049, 1048, 1048, 1047, 1046, 1045, 1044, 1043, 1040, 1039, 1038, 1038, 1037, 1036, 1036, 1034, 1032, 1031, 1030, 1028, 1024, 1022, 1021, 1018, 1016, 1014, 1011, 1009, 1008, 1008, 1007, 1005, 1001, 999, 997, 996, 994, 992, 989, 987, 985, 980, 978, 976, 971, 970, 968, 968, 966, 965, 962, 961, 959, 958, 956, 954, 953, 952, 950, 946, 944, 942, 942, 940, 939, 930, 928, 926, 915, 913, 911, 910, 908, 906, 905, 903, 901, 893, 892, 890, 886, 884, 882, 882, 880, 879, 878, 876, 874, 873, 871, 870, 868, 866, 865, 865, 863, 861, 858, 857, 855, 855, 853, 852, 849, 847, 846, 845, 844, 842, 840, 839, 837, 831, 829, 827, 825, 824, 822, 819, 817, 815, 815, 813, 811, 809, 807, 805, 805, 803, 801, 798, 796, 794, 792, 790, 788, 782, 780, 778, 777, 775, 773, 764, 762, 761, 761, 759, 757, 754, 752, 750, 749, 747, 745, 745, 743, 741, 737, 1369, 1368, 1362, 1373, 1372, 1369, 735, 734, 1354, 1353, 733, 728, 726, 725, 1332, 1331, 1329, 721, 719, 718, 718, 716, 715, 715, 713, 712, 704, 702, 700, 697, 696, 694, 694, 692, 690, 688, 686, 685, 684, 682, 681, 676, 674, 672, 667, 665, 1314, 1311, 1309, 1307, 1304, 1302, 1301, 1320, 1318, 1317, 1316, 1315, 664, 664, 1268, 1266, 1263, 1347, 1346, 1339, 1348, 1262, 1262, 1260, 1259, 1259, 1258, 1294, 1286, 1284, 1282, 1282, 1280, 1279, 1278, 1277, 1275, 1272, 1270, 1269, 1269, 663, 661, 645, 643, 642, 639, 637, 635, 633, 631, 629, 628, 626, 624, 623, 622, 620, 620, 618, 617, 613, 611, 609, 609, 607, 606, 605, 603, 601, 599, 597, 595, 592, 590, 589, 589, 587, 586, 583, 581, 580, 577, 575, 573, 573, 571, 570, 560, 558, 556, 556, 555, 553, 552, 551, 549, 543, 542, 540, 540, 538, 537, 532, 530, 529, 526, 524, 523, 519, 517, 515, 504, 502, 501, 501, 499, 498, 497, 495, 493, 491, 489, 487, 484, 483, 481, 480, 478, 476, 461, 459, 457, 447, 445, 444, 438, 436, 435, 434, 432, 430, 430, 428, 427, 427, 425, 423, 414, 412, 410, 407, 405, 403, 396, 394, 392, 387, 385, 384, 378, 376, 374, 366, 364, 363, 363, 361, 360, 360, 358, 356, 356, 354, 352, 343, 341, 340, 335, 333, 331, 330, 328, 327, 322, 320, 319, 319, 317, 316, 316, 315, 313, 310, 308, 306, 302, 300, 299, 298, 297, 295, 293, 291, 289, 282, 280, 279, 273, 271, 269, 265, 263, 261, 259, 258, 256, 249, 247, 246, 240, 238, 237, 234, 232, 230, 224, 222, 221, 221, 219, 217, 214, 212, 211, 211, 210, 208, 205, 203, 201, 199, 198, 196, 192, 190, 188, 185, 184, 182, 180, 178, 176, 169, 167, 165, 158, 156, 154, 148, 146, 145, 144, 142, 141, 140, 139, 137, 136, 134, 132, 130, 128, 126, 126, 124, 123, 122, 120, 119, 118, 116, 115, 115, 114, 112, 112, 111, 109, 108, 107, 105, 1075, 1073, 1071, 1069, 1068, 1066, 1057, 1055, 1054, 1053, 1078, 105, 105, 1098, 1096, 1095, 1093, 1092, 1092, 1090, 1089, 1088, 1086, 1085, 1158, 1156, 1155, 1152, 1150, 1148, 1148, 1146, 1145, 1144, 1142, 1140, 1140, 1138, 1137, 1133, 1131, 1129, 1129, 1127, 1125, 1123, 1121, 1119, 1116, 1114, 1185, 1183, 1181, 1179, 1172, 1170, 1168, 1163, 1161, 1160, 1160, 1186, 1113, 1108, 1106, 1105, 1105, 1103, 1101, 1101, 1100, 105, 1188, 1196, 105, 105, 1202, 1201, 1200, 1198, 1214, 1212, 1211, 1209, 1205, 1204, 105, 1222, 1221, 1219, 1217, 1232, 1231, 1231, 1230, 105, 1234, 1255, 1254, 1247, 1246, 1244, 1243, 1241, 1239, 1237, 1236, 105, 105, 104, 102, 100, 99, 97, 95, 93, 92, 92, 90, 89, 89, 1389, 1388, 1386, 1384, 1383, 1382, 1381, 1379, 1378, 1416, 1415, 1410, 1417, 1377, 1404, 1403, 1401, 1400, 1398, 1396, 86, 84, 82, 77, 75, 74, 73, 71, 69, 69, 67, 66, 62, 60, 59, 59, 57, 56, 52, 50, 48, 42, 40, 38, 36, 34, 32, 32, 31, 29, 29, 27, 26, 22, 20, 19, 17, 15, 14, 10, 8, 7, 5, 4, 2, 2, 1, 1050, 1050, 1048, 1046, 1045, 1043, 1042, 1040, 1038, 1036, 1036, 1032, 1031, 1031, 1028, 1026, 1024, 1021, 1020, 1019, 1018, 1014, 1012, 1011, 1008, 1008, 1005, 1004, 1002, 1001, 997, 989, 985, 984, 981, 980, 976, 971, 968, 965, 962, 959, 958, 958, 954, 953, 953, 950, 949, 946, 942, 939, 932, 930, 926, 916, 915, 911, 911, 910, 906, 906, 905, 901, 893, 890, 888, 887, 886, 882, 879, 878, 874, 874, 873, 870, 868, 865, 861, 858, 855, 852, 849, 846, 845, 845, 842, 841, 840, 837, 836, 831, 827, 826, 810, 809, 805, 805, 801, 799, 799, 798, 794, 793, 792, 788, 783, 782, 778, 778, 777, 773, 765, 764, 761, 755, 754, 750, 749, 745, 741, 737, 1368, 1367, 1371, 1369, 734, 1354, 733, 732, 728, 725, 723, 721, 718, 715, 704, 700, 698, 697, 694, 690, 688, 685, 684, 684, 681, 680, 668, 667, 1314, 1312, 1311, 1307, 1304, 1301, 1300, 1321, 1320, 1317, 1317, 1316, 664, 664, 1266, 1265, 1339, 1262, 1259, 1259, 1294, 1292, 1286, 1282, 1279, 1278, 1275, 1274, 1272, 1269, 661, 645, 642, 641, 641, 639, 635, 633, 629, 628, 628, 624, 623, 620, 617, 616, 614, 613, 609, 609, 606, 605, 605, 601, 600, 599, 595, 594, 593, 592, 589, 589, 586, 585, 584, 583, 580, 577, 573, 570, 556, 553, 552, 540, 537, 536, 534, 532, 529, 519, 515, 514, 505, 504, 501, 498, 497, 493, 491, 487, 486, 480, 476, 475, 448, 447, 444, 434, 430, 427, 427, 423, 422, 415, 414, 410, 408, 407, 403, 396, 392, 391, 379, 378, 374, 366, 363, 360, 360, 356, 352, 336, 335, 331, 330, 327, 326, 322, 319, 316, 310, 306, 305, 303, 302, 299, 298, 295, 293, 274, 273, 269, 266, 265, 261, 259, 256, 255, 250, 249, 246, 241, 240, 237, 235, 234, 230, 224, 221, 217, 214, 211, 208, 205, 201, 199, 199, 196, 195, 193, 192, 188, 186, 185, 182, 181, 180, 176, 170, 169, 165, 159, 158, 154, 150, 148, 145, 145, 144, 141, 140, 137, 136, 136, 132, 131, 131, 130, 126, 119, 118, 115, 115, 112, 109, 109, 108, 105, 1075, 1071, 1070, 1057, 1054, 1053, 105, 1096, 1095, 1095, 1092, 1089, 1088, 1085, 1084, 1129, 1125, 1124, 1124, 1123, 1119, 1116, 1185, 1184, 1183, 1179, 1178, 1173, 1172, 1168, 1163, 1160, 1113, 1108, 1105, 1101, 1189, 1188, 105, 1201, 1200, 1214, 1206, 1205, 105, 1217, 1231, 105, 1234, 1254, 1253, 1244, 1243, 1239, 1238, 1237, 105, 105, 102, 100, 100, 97, 96, 95, 92, 89, 89, 1388, 1387, 1386, 1383, 1382, 1382, 1379, 1378, 1410, 1377, 1376, 1404, 1401, 1400, 1400, 1396, 1395, 88, 86, 82, 78, 77, 74, 74, 73, 69, 69, 66, 65, 62, 59, 56, 53, 52, 48, 43, 42, 38, 37, 36, 32, 32, 29, 26, 22, 19, 18, 17, 14, 13, 11, 10, 7, 5, 2, 1050, 1050, 1046, 1045, 1040, 1036, 1031, 1019, 1014, 1013, 1012, 1008, 1004, 1002, 997, 996, 990, 989, 984, 981, 976, 974, 972, 971, 965, 962, 958, 953, 953, 949, 948, 946, 939, 937, 933, 932, 926, 916, 911, 906, 894, 893, 888, 887, 882, 879, 879, 874, 870, 868, 861, 860, 858, 852, 851, 849, 845, 841, 840, 836, 835, 831, 826, 825, 810, 805, 799, 794, 793, 788, 778, 773, 772, 765, 761, 757, 750, 749, 741, 1371, 734, 1352, 1355, 1354, 732, 729, 728, 723, 722, 721, 715, 704, 698, 697, 697, 690, 689, 1312, 1307, 1306, 1317, 664, 1265, 1339, 1259, 1292, 1287, 1286, 1279, 1278, 1274, 1273, 1273, 1272, 661, 645, 641, 635, 634, 633, 628, 614, 609, 605, 605, 600, 599, 593, 589, 585, 584, 580, 578, 578, 577, 570, 560, 556, 552, 534, 529, 528, 519, 514, 513, 506, 505, 501, 498, 493, 491, 491, 486, 485, 480, 475, 474, 448, 444, 443, 434, 427, 422, 416, 415, 410, 410, 408, 403, 396, 391, 390, 360, 352, 351, 336, 331, 330, 323, 322, 316, 311, 310, 305, 299, 298, 293, 275, 274, 269, 266, 261, 260, 260, 259, 255, 252, 250, 246, 241, 237, 236, 235, 230, 229, 225, 224, 217, 215, 214, 208, 205, 199, 195, 193, 188, 187, 181, 176, 175, 171, 170, 165, 160, 159, 154, 150, 145, 141, 140, 136, 131, 131, 126, 123, 119, 115, 109, 109, 105, 1076, 1075, 1070, 1069, 105, 1095, 1089, 1089, 1088, 1084, 1116, 1184, 1183, 1173, 1168, 1167, 1163, 1113, 1112, 1109, 1108, 1101, 1190, 1189, 105, 1207, 1206, 105, 1218, 1217, 105, 1239, 1238, 105, 105, 100, 96, 89, 1387, 1386, 1386, 1382, 1378, 1410, 1376, 1407, 1404, 1400, 1395, 88, 82, 80, 78, 74, 69, 69, 65, 64, 62, 56, 55, 53, 48, 47, 43, 38, 37, 37, 32, 26, 18, 17, 13, 11, 7, 6, 6, 5, 1050, 1050, 1045, 1042, 1040, 1031, 1026, 1019, 1013, 1012, 1012, 1004, 1003, 1002, 996, 992, 990, 984, 983, 982, 981, 974, 972, 965, 964, 946, 937, 936, 933, 926, 924, 916, 906, 901, 894, 888, 887, 887, 879, 870, 868, 860, 859, 858, 851, 850, 849, 841, 840, 811, 810, 799, 794, 788, 787, 783, 778, 772, 766, 765, 757, 750, 741, 740, 1372, 1371, 1352, 1355, 732, 731, 697, 689, 688, 1321, 1317, 1265, 1340, 1339, 1292, 1287, 1279, 1278, 1273, 661, 660, 645, 635, 634, 634, 628, 623, 614, 605, 599, 594, 593, 585, 584, 578, 570, 560, 552, 549, 535, 534, 528, 498, 491, 485, 480, 474, 473, 434, 422, 421, 410, 403, 401, 360, 351, 349, 337, 336, 330, 323, 316, 313, 311, 305, 304, 299, 293, 289, 275, 269, 267, 266, 260, 255, 252, 246, 245, 241, 236, 235, 235, 229, 227, 225, 217, 216, 215, 208, 207, 206, 205, 195, 194, 193, 187, 182, 181, 175, 171, 165, 164, 160, 154, 153, 152, 150, 141, 140, 131, 123, 122, 119, 109, 109, 1076, 1075, 105, 1089, 1084, 1117, 1116, 1183, 1163, 1112, 1111, 1109, 1101, 105, 1190, 105, 1201, 1208, 1207, 105, 1218, 105, 1234, 1244, 1239, 105, 105, 96, 95, 89, 1386, 1378, 1411, 1410, 1407, 88, 80, 79, 78, 69, 64, 43, 37, 26, 22, 18, 13, 11, 6, 1050, 1050, 1042, 1041, 1041, 1040, 1026, 1019, 1012, 1003, 1002, 992, 991, 990, 983, 982, 982, 974, 973, 972, 964, 963, 947, 946, 936, 933, 924, 923, 917, 916, 901, 894, 887, 870, 868, 859, 858, 858, 850, 849, 849, 840, 835, 815, 811, 799, 783, 772, 771, 766, 757, 756, 750, 740, 739, 1372, 1352, 1351, 1356, 1355, 731, 697, 688, 684, 1322, 1321, 1265, 1340, 1292, 1291, 1278, 1273, 660, 645, 634, 623, 594, 585, 584, 584, 570, 569, 561, 560, 549, 535, 528, 526, 501, 498, 485, 480, 473, 472, 434, 421, 420, 410, 401, 400, 366, 360, 349, 337, 330, 326, 323, 313, 312, 312, 311, 304, 299, 289, 288, 276, 275, 267, 266, 255, 254, 253, 252, 245, 243, 241, 235, 235, 227, 226, 226, 225, 216, 206, 195, 194, 194, 187, 186, 182, 175, 174, 172, 171, 164, 161, 160, 153, 152, 141, 140, 140, 123, 122, 122, 109, 1075, 105, 1084, 1083, 1118, 1117, 1183, 1164, 1163, 1111, 1110, 1109, 105, 1191, 1190, 1201, 1208, 105, 1228, 1219, 1218, 1234, 105, 95, 89, 89, 1378, 1415, 1412, 1411, 1407, 88, 79, 78, 78, 64, 63, 44, 43, 26, 23, 22, 13, 12, 11, 1050, 1050, 1041, 1026, 1020, 1019, 1003, 990, 982, 973, 973, 972, 963, 947, 936, 935, 933, 923, 922, 918, 917, 901, 895, 894, 870, 858, 849, 835, 819, 815, 799, 783, 771, 770, 755, 750, 739, 1362, 1372, 1351, 1356, 731, 730, 704, 697, 684, 1322, 1265, 1263, 1340, 1291, 1290, 1287, 1278, 660, 646, 645, 623, 584, 569, 568, 536, 535, 526, 501, 485, 484, 410, 400, 399, 366, 349, 347, 338, 337, 326, 323, 312, 304, 299, 288, 286, 277, 276, 267, 266, 254, 253, 253, 245, 244, 243, 235, 226, 226, 216, 215, 207, 206, 194, 186, 182, 174, 173, 172, 164, 161, 153, 152, 152, 140, 122, 122, 1075, 1069, 1053, 105, 1083, 1118, 1183, 1178, 1164, 1111, 1110, 1192, 1191, 1201, 1209, 1208, 1228, 1222, 1219, 1234, 1244, 105, 89, 89, 1415, 1414, 1412, 1407, 1406, 1391, 1390, 89, 89, 88, 78, 45, 44, 26, 23, 13, 12, 12, 1050, 1026, 1024, 1020, 1003, 990, 973, 963, 947, 935, 934, 934, 933, 922, 919, 918, 901, 897, 895, 870, 868, 858, 835, 820, 819, 799, 784, 783, 770, 755, 739, 737, 1362, 1351, 1350, 704, 684, 680, 1322, 1263, 1346, 1340, 1290, 1289, 1287, 660, 659, 646, 623, 620, 536, 526, 523, 506, 501, 484, 410, 399, 397, 367, 366, 347, 339, 338, 326, 325, 323, 304, 303, 299, 286, 277, 267, 266, 244, 243, 226, 226, 215, 207, 207, 194, 186, 174, 173, 164, 161, 152, 122, 1053, 1083, 1082, 1119, 1118, 1178, 1164, 1110, 105, 1192, 1201, 1214, 1212, 1209, 1228, 1223, 1222, 1234, 1244, 89, 1414, 1412, 1406, 1405, 1393, 1391, 89, 45, 26, 25, 23, 12, 1026, 1024, 1003, 1002, 990, 963, 962, 947, 934, 922, 920, 919, 901, 898, 897, 870, 868, 835, 834, 820, 799, 794, 784, 770, 769, 756, 755, 737, 1362, 1350, 1360, 704, 680, 679, 1297, 1322, 1346, 1340, 1289, 1288, 1287, 659, 658, 646, 620, 616, 540, 536, 523, 507, 506, 484, 410, 397, 396, 369, 367, 347, 325, 304, 303, 303, 286, 285, 277, 266, 253, 244, 226, 207, 207, 186, 174, 174, 164, 163, 1053, 1082, 1081, 1119, 1178, 1177, 1165, 1164, 105, 1193, 1192, 1214, 1212, 1228, 1227, 1224, 1223, 1234, 1247, 1244, 1414, 1394, 1393, 89, 45, 25, 24, 24, 23, 1026, 1026, 1024, 1002, 948, 947, 922, 920, 901, 900, 899, 898, 870, 868, 834, 833, 821, 820, 794, 756, 737, 1367, 1362, 1360, 1359, 704, 679, 678, 1298, 1297, 1346, 1287, 658, 657, 543, 540, 523, 507, 484, 481, 416, 410, 396, 370, 369, 347, 325, 303, 285, 278, 277, 253, 253, 244, 207, 207, 174, 163, 1053, 1081, 1080, 1124, 1119, 1177, 1212, 1227, 1226, 1224, 1234, 1253, 1247, 1414, 1413, 1395, 1394, 89, 46, 45, 24, 24, 1026, 1002, 953, 948, 922, 921, 920, 900, 900, 899, 870, 868, 833, 831, 822, 821, 794, 766, 756, 1367, 1362, 1359, 1358, 706, 704, 678, 1299, 1298, 1346, 1287, 657, 655, 543, 523, 522, 508, 507, 481, 416, 396, 390, 371, 370, 347, 326, 325, 285, 279, 278, 253, 253, 207, 163, 1124, 1177, 1175, 1212, 1226, 1225, 1224, 1253, 1252, 1247, 1413, 1412, 1395, 89, 78, 46, 24, 1002, 953, 922, 921, 921, 900, 870, 825, 822, 794, 766, 1367, 1366, 1363, 1362, 1358, 706, 678, 676, 1300, 1299, 1346, 1287, 655, 654, 544, 543, 522, 508, 481, 480, 417, 416, 390, 373, 371, 347, 326, 285, 283, 282, 279, 253, 253, 163, 162, 1124, 1175, 1174, 1212, 1225, 1224, 1224, 1252, 1251, 1404, 1395, 78, 47, 46, 1002, 953, 921, 870, 831, 825, 794, 767, 766, 1366, 1363, 1358, 1357, 707, 706, 676, 1300, 1346, 1344, 1287, 654, 653, 545, 544, 522, 509, 508, 480, 418, 417, 390, 373, 347, 345, 283, 282, 253, 253, 162, 161, 1212, 1224, 1251, 1405, 1404, 78, 53, 47, 1002, 962, 953, 870, 831, 794, 787, 767, 1366, 1365, 1363, 1357, 1356, 708, 707, 676, 1300, 1344, 1343, 1287, 653, 651, 545, 522, 521, 509, 480, 472, 418, 390, 388, 374, 373, 345, 283, 253, 161, 1212, 1251, 1250, 1405, 78, 63, 54, 53, 1002, 990, 962, 870, 868, 831, 787, 767, 1365, 1364, 709, 708, 676, 1288, 1287, 651, 545, 521, 520, 510, 509, 472, 418, 388, 387, 374, 345, 344, 326, 283, 161, 1212, 1250, 1249, 1412, 1405, 63, 55, 54, 1002, 991, 990, 870, 869, 868, 787, 767, 1364, 1363, 710, 709, 676, 1288, 651, 650, 546, 545, 520, 511, 510, 472, 418, 387, 384, 374, 344, 343, 339, 326, 161, 1212, 1249, 1248, 1247, 1412, 63, 62, 55, 1002, 991, 870, 869, 869, 787, 786, 768, 767, 1363, 710, 676, 672, 1288, 650, 649, 546, 520, 519, 512, 511, 472, 419, 418, 384, 339, 161, 122, 1212, 1248, 1247, 1247, 63, 62, 62, 1002, 991, 991, 869, 786, 768, 1363, 1356, 711, 710, 672, 547, 546, 519, 513, 512, 472, 419, 384, 383, 340, 339, 122, 1212, 1247, 62, 991, 786, 785, 769, 768, 1356, 712, 711, 672, 548, 547, 519, 519, 513, 472, 340, 122, 1069, 1212, 62, 991, 991, 785, 784, 769, 1356, 730, 715, 712, 672, 548, 519, 472, 343, 340, 1069, 1213, 1212, 991, 991, 784, 769, 715, 672, 671, 549, 548, 472, 343, 1069, 1066, 1214, 1213, 991, 991, 769, 730, 715, 671, 670, 561, 549, 472, 343, 1066, 1065, 991, 730, 729, 715, 670, 668, 562, 561, 472, 374, 343, 1065, 722, 715, 668, 562, 472, 471, 374, 1065, 1064, 722, 668, 1314, 562, 471, 470, 374, 1064, 1063, 723, 722, 1337, 722, 1314, 1313, 374, 1063, 1062, 729, 723, 1337, 722, 1313, 1312, 374, 1062, 1061, 729, 1337, 1336, 1337, 722, 1312, 379, 374, 1061, 991, 729, 1336, 1337, 1312, 1306, 380, 379, 1061, 991, 1336, 1335, 1324, 1337, 1306, 380, 1061, 1060, 991, 1335, 1334, 1324, 1306, 1305, 380, 1060, 1058, 991, 1334, 1333, 1324, 1305, 1304, 381, 380, 1058, 991, 1333, 1332, 1325, 1324, 1304, 381, 1058, 1057, 991, 1332, 1329, 1325, 1304, 1300, 381, 1057, 1053, 991, 1329, 1328, 1326, 1325, 1300, 382, 381, 1053, 991, 1328, 1327, 1327, 1326, 1300, 383, 382, 1053, 991, 1327, 1300, 383, 1053, 1080, 991, 1300, 1343, 419, 383, 1080, 991, 1343, 1342, 420, 419, 1080, 991, 1342, 1341, 420, 1080, 1158, 991, 1341, 1340, 420, 1158, 1155, 991, 1340, 1288, 420, 1155, 1154, 991, 1288, 649, 420, 1154, 1153, 991, 649, 648, 434, 420, 1153, 1214, 991, 648, 434, 1153, 1152, 1214, 648, 647, 434, 1152, 1148, 1214, 647, 646, 435, 434, 1148, 1214, 646, 616, 438, 435, 1148, 1214, 616, 615, 438, 1148, 1145, 1193, 1214, 615, 438, 1145, 1144, 1193, 615, 614, 440, 438, 1144, 1193, 614, 599, 442, 440, 1144, 1194, 1193, 599, 443, 442, 1144, 105, 1194, 599, 448, 443, 1144, 105, 599, 594, 448, 1144, 1140, 105, 594, 584, 448, 1140, 1137, 105, 584, 568, 450, 448, 1137, 105, 568, 567, 450, 1137, 1136, 1165, 105, 567, 452, 450, 1136, 1166, 1165, 567, 453, 452, 1136, 1167, 1166, 567, 453, 1136, 1135, 1167, 567, 566, 453, 1135, 1133, 454, 453, 1133, 456, 454, 1133, 457, 456, 1133, 457, 1133, 1129, 461, 457, 1129, 463, 461, 1129, 463, 1129, 1124, 464, 463, 1124, 465, 464, 1124, 466, 465, 1124, 466, 1124, 1174, 467, 466, 1174, 469, 467, 1174, 469, 1174, 1173, 469, 1173, 1167, 470, 469, 1167, 562, 470, 1167, 562, 1167, 566, 562, 566, 564, 564, 563, 562];
+testOutput["eberly_6"]["triangles"]=[1262, 1347, 1346, 1346, 1345, 1344, 1348, 1347, 1262, 1262, 1261, 1260, 1258, 1295, 1294, 1294, 1293, 1292, 1286, 1285, 1284, 1284, 1283, 1282, 1282, 1281, 1280, 1277, 1276, 1275, 1272, 1271, 1270, 1269, 1268, 663, 663, 662, 661, 657, 656, 655, 653, 652, 651, 645, 644, 643, 641, 640, 639, 639, 638, 637, 637, 636, 635, 633, 632, 631, 631, 630, 629, 628, 627, 626, 626, 625, 624, 622, 621, 620, 620, 619, 618, 613, 612, 611, 611, 610, 609, 609, 608, 607, 605, 604, 603, 603, 602, 601, 599, 598, 597, 597, 596, 595, 592, 591, 590, 589, 588, 587, 583, 582, 581, 580, 579, 578, 577, 576, 575, 575, 574, 573, 573, 572, 571, 560, 559, 558, 558, 557, 556, 555, 554, 553, 551, 550, 549, 542, 541, 540, 540, 539, 538, 534, 533, 532, 532, 531, 530, 528, 527, 526, 526, 525, 524, 519, 518, 517, 517, 516, 515, 504, 503, 502, 501, 500, 499, 497, 496, 495, 495, 494, 493, 493, 492, 491, 491, 490, 489, 489, 488, 487, 483, 482, 481, 480, 479, 478, 478, 477, 476, 469, 468, 467, 463, 462, 461, 461, 460, 459, 459, 458, 457, 456, 455, 454, 452, 451, 450, 450, 449, 448, 447, 446, 445, 442, 441, 440, 440, 439, 438, 438, 437, 436, 434, 433, 432, 432, 431, 430, 430, 429, 428, 427, 426, 425, 425, 424, 423, 414, 413, 412, 412, 411, 410, 410, 409, 408, 407, 406, 405, 405, 404, 403, 403, 402, 401, 399, 398, 397, 396, 395, 394, 394, 393, 392, 390, 389, 388, 387, 386, 385, 378, 377, 376, 376, 375, 374, 373, 372, 371, 369, 368, 367, 366, 365, 364, 363, 362, 361, 360, 359, 358, 358, 357, 356, 356, 355, 354, 354, 353, 352, 351, 350, 349, 349, 348, 347, 347, 346, 345, 343, 342, 341, 335, 334, 333, 333, 332, 331, 330, 329, 328, 325, 324, 323, 322, 321, 320, 319, 318, 317, 315, 314, 313, 310, 309, 308, 308, 307, 306, 302, 301, 300, 297, 296, 295, 295, 294, 293, 293, 292, 291, 291, 290, 289, 288, 287, 286, 285, 284, 283, 282, 281, 280, 273, 272, 271, 271, 270, 269, 269, 268, 267, 265, 264, 263, 263, 262, 261, 258, 257, 256, 252, 251, 250, 249, 248, 247, 243, 242, 241, 240, 239, 238, 234, 233, 232, 232, 231, 230, 229, 228, 227, 224, 223, 222, 221, 220, 219, 219, 218, 217, 214, 213, 212, 210, 209, 208, 205, 204, 203, 203, 202, 201, 201, 200, 199, 198, 197, 196, 192, 191, 190, 190, 189, 188, 184, 183, 182, 180, 179, 178, 178, 177, 176, 169, 168, 167, 167, 166, 165, 158, 157, 156, 156, 155, 154, 152, 151, 150, 150, 149, 148, 148, 147, 146, 144, 143, 142, 139, 138, 137, 136, 135, 134, 134, 133, 132, 130, 129, 128, 128, 127, 126, 126, 125, 124, 122, 121, 120, 118, 117, 116, 114, 113, 112, 111, 110, 109, 107, 106, 105, 105, 1077, 1076, 1075, 1074, 1073, 1073, 1072, 1071, 1068, 1067, 1066, 1060, 1059, 1058, 1057, 1056, 1055, 1078, 1077, 105, 105, 1099, 1098, 1098, 1097, 1096, 1095, 1094, 1093, 1092, 1091, 1090, 1088, 1087, 1086, 1158, 1157, 1156, 1152, 1151, 1150, 1150, 1149, 1148, 1148, 1147, 1146, 1144, 1143, 1142, 1142, 1141, 1140, 1140, 1139, 1138, 1135, 1134, 1133, 1133, 1132, 1131, 1131, 1130, 1129, 1129, 1128, 1127, 1127, 1126, 1125, 1123, 1122, 1121, 1121, 1120, 1119, 1116, 1115, 1114, 1114, 1113, 1185, 1183, 1182, 1181, 1181, 1180, 1179, 1177, 1176, 1175, 1172, 1171, 1170, 1170, 1169, 1168, 1163, 1162, 1161, 1186, 1185, 1113, 1108, 1107, 1106, 1105, 1104, 1103, 1103, 1102, 1101, 1100, 1099, 105, 105, 1195, 1194, 1196, 1195, 105, 105, 1203, 1202, 1200, 1199, 1198, 1211, 1210, 1209, 1204, 1203, 105, 105, 1229, 1228, 1221, 1220, 1219, 1217, 1216, 1232, 1230, 1229, 105, 105, 1235, 1234, 1234, 1256, 1255, 1246, 1245, 1244, 1243, 1242, 1241, 1241, 1240, 1239, 1236, 1235, 105, 104, 103, 102, 102, 101, 100, 99, 98, 97, 95, 94, 93, 92, 91, 90, 89, 1390, 1389, 1386, 1385, 1384, 1381, 1380, 1379, 1378, 1377, 1416, 1417, 1416, 1377, 1376, 1408, 1407, 1403, 1402, 1401, 1400, 1399, 1398, 1398, 1397, 1396, 1393, 1392, 1391, 88, 87, 86, 86, 85, 84, 84, 83, 82, 82, 81, 80, 77, 76, 75, 73, 72, 71, 71, 70, 69, 69, 68, 67, 62, 61, 60, 59, 58, 57, 52, 51, 50, 50, 49, 48, 42, 41, 40, 40, 39, 38, 36, 35, 34, 34, 33, 32, 31, 30, 29, 29, 28, 27, 22, 21, 20, 17, 16, 15, 10, 9, 8, 4, 3, 2, 1, 1051, 1050, 1050, 1049, 1048, 1048, 1047, 1046, 1045, 1044, 1043, 1040, 1039, 1038, 1038, 1037, 1036, 1036, 1035, 1034, 1034, 1033, 1032, 1030, 1029, 1028, 1028, 1027, 1026, 1026, 1025, 1024, 1024, 1023, 1022, 1018, 1017, 1016, 1016, 1015, 1014, 1011, 1010, 1009, 1007, 1006, 1005, 1001, 1000, 999, 999, 998, 997, 996, 995, 994, 994, 993, 992, 989, 988, 987, 987, 986, 985, 980, 979, 978, 978, 977, 976, 976, 975, 974, 970, 969, 968, 968, 967, 966, 961, 960, 959, 958, 957, 956, 956, 955, 954, 952, 951, 950, 946, 945, 944, 944, 943, 942, 942, 941, 940, 939, 938, 937, 932, 931, 930, 930, 929, 928, 928, 927, 926, 926, 925, 924, 915, 914, 913, 913, 912, 911, 910, 909, 908, 908, 907, 906, 905, 904, 903, 903, 902, 901, 897, 896, 895, 892, 891, 890, 890, 889, 888, 886, 885, 884, 884, 883, 882, 882, 881, 880, 878, 877, 876, 876, 875, 874, 873, 872, 871, 868, 867, 866, 865, 864, 863, 863, 862, 861, 857, 856, 855, 855, 854, 853, 849, 848, 847, 844, 843, 842, 839, 838, 837, 833, 832, 831, 831, 830, 829, 829, 828, 827, 824, 823, 822, 819, 818, 817, 817, 816, 815, 815, 814, 813, 813, 812, 811, 809, 808, 807, 807, 806, 805, 805, 804, 803, 803, 802, 801, 801, 800, 799, 798, 797, 796, 796, 795, 794, 792, 791, 790, 790, 789, 788, 782, 781, 780, 780, 779, 778, 777, 776, 775, 775, 774, 773, 764, 763, 762, 761, 760, 759, 759, 758, 757, 754, 753, 752, 752, 751, 750, 749, 748, 747, 747, 746, 745, 745, 744, 743, 743, 742, 741, 739, 738, 737, 737, 736, 1369, 1362, 1374, 1373, 1371, 1370, 1369, 1369, 736, 735, 734, 733, 1352, 1353, 1352, 733, 728, 727, 726, 725, 724, 723, 1331, 1330, 1329, 721, 720, 719, 718, 717, 716, 715, 714, 713, 706, 705, 704, 704, 703, 702, 702, 701, 700, 700, 699, 698, 696, 695, 694, 694, 693, 692, 692, 691, 690, 688, 687, 686, 684, 683, 682, 678, 677, 676, 676, 675, 674, 674, 673, 672, 670, 669, 668, 667, 666, 665, 665, 664, 1314, 1311, 1310, 1309, 1309, 1308, 1307, 1304, 1303, 1302, 1320, 1319, 1318, 1315, 1314, 664, 664, 663, 1268, 1268, 1267, 1266, 1265, 1264, 1263, 1263, 1262, 1346, 1339, 1348, 1262, 1262, 1260, 1259, 1259, 1258, 1294, 1286, 1284, 1282, 1282, 1280, 1279, 1278, 1277, 1275, 1272, 1270, 1269, 1269, 663, 661, 645, 643, 642, 639, 637, 635, 633, 631, 629, 628, 626, 624, 623, 622, 620, 620, 618, 617, 613, 611, 609, 609, 607, 606, 605, 603, 601, 599, 597, 595, 592, 590, 589, 589, 587, 586, 583, 581, 580, 577, 575, 573, 573, 571, 570, 560, 558, 556, 556, 555, 553, 552, 551, 549, 543, 542, 540, 540, 538, 537, 532, 530, 529, 526, 524, 523, 519, 517, 515, 504, 502, 501, 501, 499, 498, 497, 495, 493, 491, 489, 487, 484, 483, 481, 480, 478, 476, 461, 459, 457, 447, 445, 444, 438, 436, 435, 434, 432, 430, 430, 428, 427, 427, 425, 423, 414, 412, 410, 407, 405, 403, 396, 394, 392, 387, 385, 384, 378, 376, 374, 366, 364, 363, 363, 361, 360, 360, 358, 356, 356, 354, 352, 343, 341, 340, 335, 333, 331, 330, 328, 327, 322, 320, 319, 319, 317, 316, 316, 315, 313, 310, 308, 306, 302, 300, 299, 298, 297, 295, 293, 291, 289, 282, 280, 279, 273, 271, 269, 265, 263, 261, 259, 258, 256, 249, 247, 246, 240, 238, 237, 234, 232, 230, 224, 222, 221, 221, 219, 217, 214, 212, 211, 211, 210, 208, 205, 203, 201, 199, 198, 196, 192, 190, 188, 185, 184, 182, 180, 178, 176, 169, 167, 165, 158, 156, 154, 148, 146, 145, 144, 142, 141, 140, 139, 137, 136, 134, 132, 130, 128, 126, 126, 124, 123, 122, 120, 119, 118, 116, 115, 115, 114, 112, 112, 111, 109, 108, 107, 105, 1075, 1073, 1071, 1069, 1068, 1066, 1057, 1055, 1054, 1053, 1078, 105, 105, 1098, 1096, 1095, 1093, 1092, 1092, 1090, 1089, 1088, 1086, 1085, 1158, 1156, 1155, 1152, 1150, 1148, 1148, 1146, 1145, 1144, 1142, 1140, 1140, 1138, 1137, 1133, 1131, 1129, 1129, 1127, 1125, 1123, 1121, 1119, 1116, 1114, 1185, 1183, 1181, 1179, 1172, 1170, 1168, 1163, 1161, 1160, 1160, 1186, 1113, 1108, 1106, 1105, 1105, 1103, 1101, 1101, 1100, 105, 1188, 1196, 105, 105, 1202, 1201, 1200, 1198, 1214, 1212, 1211, 1209, 1205, 1204, 105, 1222, 1221, 1219, 1217, 1232, 1231, 1231, 1230, 105, 1234, 1255, 1254, 1247, 1246, 1244, 1243, 1241, 1239, 1237, 1236, 105, 105, 104, 102, 100, 99, 97, 95, 93, 92, 92, 90, 89, 89, 1389, 1388, 1386, 1384, 1383, 1382, 1381, 1379, 1378, 1416, 1415, 1410, 1417, 1377, 1404, 1403, 1401, 1400, 1398, 1396, 86, 84, 82, 77, 75, 74, 73, 71, 69, 69, 67, 66, 62, 60, 59, 59, 57, 56, 52, 50, 48, 42, 40, 38, 36, 34, 32, 32, 31, 29, 29, 27, 26, 22, 20, 19, 17, 15, 14, 10, 8, 7, 5, 4, 2, 2, 1, 1050, 1050, 1048, 1046, 1045, 1043, 1042, 1040, 1038, 1036, 1036, 1034, 1032, 1031, 1030, 1028, 1024, 1022, 1021, 1018, 1016, 1014, 1011, 1009, 1008, 1008, 1007, 1005, 1001, 999, 997, 996, 994, 992, 989, 987, 985, 980, 978, 976, 971, 970, 968, 968, 966, 965, 962, 961, 959, 958, 956, 954, 953, 952, 950, 946, 944, 942, 942, 940, 939, 930, 928, 926, 915, 913, 911, 910, 908, 906, 905, 903, 901, 893, 892, 890, 886, 884, 882, 882, 880, 879, 878, 876, 874, 873, 871, 870, 868, 866, 865, 865, 863, 861, 858, 857, 855, 855, 853, 852, 849, 847, 846, 845, 844, 842, 840, 839, 837, 831, 829, 827, 825, 824, 822, 819, 817, 815, 815, 813, 811, 809, 807, 805, 805, 803, 801, 798, 796, 794, 792, 790, 788, 782, 780, 778, 777, 775, 773, 764, 762, 761, 761, 759, 757, 754, 752, 750, 749, 747, 745, 745, 743, 741, 737, 1369, 1368, 1362, 1373, 1372, 1369, 735, 734, 1354, 1353, 733, 728, 726, 725, 1332, 1331, 1329, 721, 719, 718, 718, 716, 715, 715, 713, 712, 704, 702, 700, 697, 696, 694, 694, 692, 690, 688, 686, 685, 684, 682, 681, 676, 674, 672, 667, 665, 1314, 1311, 1309, 1307, 1304, 1302, 1301, 1320, 1318, 1317, 1316, 1315, 664, 664, 1268, 1266, 1339, 1262, 1259, 1259, 1294, 1292, 1286, 1282, 1279, 1278, 1275, 1274, 1272, 1269, 661, 645, 642, 641, 641, 639, 635, 633, 629, 628, 628, 624, 623, 620, 617, 616, 614, 613, 609, 609, 606, 605, 605, 601, 600, 599, 595, 594, 593, 592, 589, 589, 586, 585, 584, 583, 580, 577, 573, 570, 556, 553, 552, 540, 537, 536, 534, 532, 529, 519, 515, 514, 505, 504, 501, 498, 497, 493, 491, 487, 486, 480, 476, 475, 448, 447, 444, 434, 430, 427, 427, 423, 422, 415, 414, 410, 408, 407, 403, 396, 392, 391, 379, 378, 374, 366, 363, 360, 360, 356, 352, 336, 335, 331, 330, 327, 326, 322, 319, 316, 310, 306, 305, 303, 302, 299, 298, 295, 293, 274, 273, 269, 266, 265, 261, 259, 256, 255, 250, 249, 246, 241, 240, 237, 235, 234, 230, 224, 221, 217, 214, 211, 208, 205, 201, 199, 199, 196, 195, 193, 192, 188, 186, 185, 182, 181, 180, 176, 170, 169, 165, 159, 158, 154, 150, 148, 145, 145, 144, 141, 140, 137, 136, 136, 132, 131, 131, 130, 126, 119, 118, 115, 115, 112, 109, 109, 108, 105, 1075, 1071, 1070, 1057, 1054, 1053, 105, 1096, 1095, 1095, 1092, 1089, 1088, 1085, 1084, 1129, 1125, 1124, 1124, 1123, 1119, 1116, 1185, 1184, 1183, 1179, 1178, 1173, 1172, 1168, 1163, 1160, 1113, 1108, 1105, 1101, 1189, 1188, 105, 1201, 1200, 1214, 1206, 1205, 105, 1217, 1231, 105, 1234, 1254, 1253, 1244, 1243, 1239, 1238, 1237, 105, 105, 102, 100, 100, 97, 96, 95, 92, 89, 89, 1388, 1387, 1386, 1383, 1382, 1382, 1379, 1378, 1410, 1377, 1376, 1404, 1401, 1400, 1400, 1396, 1395, 88, 86, 82, 78, 77, 74, 74, 73, 69, 69, 66, 65, 62, 59, 56, 53, 52, 48, 43, 42, 38, 37, 36, 32, 32, 29, 26, 22, 19, 18, 17, 14, 13, 11, 10, 7, 5, 2, 1050, 1050, 1046, 1045, 1036, 1032, 1031, 1031, 1028, 1026, 1024, 1021, 1020, 1019, 1018, 1014, 1012, 1011, 1008, 1008, 1005, 1004, 1002, 1001, 997, 989, 985, 984, 981, 980, 976, 971, 968, 965, 962, 959, 958, 958, 954, 953, 953, 950, 949, 946, 942, 939, 932, 930, 926, 916, 915, 911, 911, 910, 906, 906, 905, 901, 893, 890, 888, 887, 886, 882, 879, 878, 874, 874, 873, 870, 868, 865, 861, 858, 855, 852, 849, 846, 845, 845, 842, 841, 840, 837, 836, 831, 827, 826, 810, 809, 805, 805, 801, 799, 799, 798, 794, 793, 792, 788, 783, 782, 778, 778, 777, 773, 765, 764, 761, 755, 754, 750, 749, 745, 741, 737, 1368, 1367, 1371, 1369, 734, 1354, 733, 732, 728, 725, 723, 721, 718, 715, 704, 700, 698, 697, 694, 690, 688, 685, 684, 684, 681, 680, 668, 667, 1314, 1312, 1311, 1307, 1304, 1301, 1300, 1321, 1320, 1317, 1317, 1316, 664, 664, 1266, 1265, 1339, 1259, 1292, 1287, 1286, 1279, 1278, 1274, 1273, 1273, 1272, 661, 645, 641, 635, 634, 633, 628, 614, 609, 605, 605, 600, 599, 593, 589, 585, 584, 580, 578, 578, 577, 570, 560, 556, 552, 534, 529, 528, 519, 514, 513, 506, 505, 501, 498, 493, 491, 491, 486, 485, 480, 475, 474, 448, 444, 443, 434, 427, 422, 416, 415, 410, 410, 408, 403, 396, 391, 390, 360, 352, 351, 336, 331, 330, 323, 322, 316, 311, 310, 305, 299, 298, 293, 275, 274, 269, 266, 261, 260, 260, 259, 255, 252, 250, 246, 241, 237, 236, 235, 230, 229, 225, 224, 217, 215, 214, 208, 205, 199, 195, 193, 188, 187, 181, 176, 175, 171, 170, 165, 160, 159, 154, 150, 145, 141, 140, 136, 131, 131, 126, 123, 119, 115, 109, 109, 105, 1076, 1075, 1070, 1069, 105, 1095, 1089, 1089, 1088, 1084, 1116, 1184, 1183, 1173, 1168, 1167, 1163, 1113, 1112, 1109, 1108, 1101, 1190, 1189, 105, 1207, 1206, 105, 1218, 1217, 105, 1239, 1238, 105, 105, 100, 96, 89, 1387, 1386, 1386, 1382, 1378, 1410, 1376, 1407, 1404, 1400, 1395, 88, 82, 80, 78, 74, 69, 69, 65, 64, 62, 56, 55, 53, 48, 47, 43, 38, 37, 37, 32, 26, 18, 17, 13, 11, 7, 6, 6, 5, 1050, 1050, 1045, 1042, 1040, 1036, 1031, 1019, 1014, 1013, 1012, 1008, 1004, 1002, 997, 996, 990, 989, 984, 981, 976, 974, 972, 971, 965, 962, 958, 953, 953, 949, 948, 946, 939, 937, 933, 932, 926, 916, 911, 906, 894, 893, 888, 887, 882, 879, 879, 874, 870, 868, 861, 860, 858, 852, 851, 849, 845, 841, 840, 836, 835, 831, 826, 825, 810, 805, 799, 794, 793, 788, 778, 773, 772, 765, 761, 757, 750, 749, 741, 1371, 734, 1352, 1355, 1354, 732, 729, 728, 723, 722, 721, 715, 704, 698, 697, 697, 690, 689, 1312, 1307, 1306, 1317, 664, 1265, 1340, 1339, 1292, 1287, 1279, 1278, 1273, 661, 660, 645, 635, 634, 634, 628, 623, 614, 605, 599, 594, 593, 585, 584, 578, 570, 560, 552, 549, 535, 534, 528, 498, 491, 485, 480, 474, 473, 434, 422, 421, 410, 403, 401, 360, 351, 349, 337, 336, 330, 323, 316, 313, 311, 305, 304, 299, 293, 289, 275, 269, 267, 266, 260, 255, 252, 246, 245, 241, 236, 235, 235, 229, 227, 225, 217, 216, 215, 208, 207, 206, 205, 195, 194, 193, 187, 182, 181, 175, 171, 165, 164, 160, 154, 153, 152, 150, 141, 140, 131, 123, 122, 119, 109, 109, 1076, 1075, 105, 1089, 1084, 1117, 1116, 1183, 1163, 1112, 1111, 1109, 1101, 105, 1190, 105, 1201, 1208, 1207, 105, 1218, 105, 1234, 1244, 1239, 105, 105, 96, 95, 89, 1386, 1378, 1411, 1410, 1407, 88, 80, 79, 78, 69, 64, 43, 37, 26, 22, 18, 13, 11, 6, 1050, 1050, 1042, 1041, 1040, 1031, 1026, 1019, 1013, 1012, 1012, 1004, 1003, 1002, 996, 992, 990, 984, 983, 982, 981, 974, 972, 965, 964, 946, 937, 936, 933, 926, 924, 916, 906, 901, 894, 888, 887, 887, 879, 870, 868, 860, 859, 858, 851, 850, 849, 841, 840, 811, 810, 799, 794, 788, 787, 783, 778, 772, 766, 765, 757, 750, 741, 740, 1372, 1371, 1352, 1355, 732, 731, 697, 689, 688, 1321, 1317, 1265, 1340, 1292, 1291, 1278, 1273, 660, 645, 634, 623, 594, 585, 584, 584, 570, 569, 561, 560, 549, 535, 528, 526, 501, 498, 485, 480, 473, 472, 434, 421, 420, 410, 401, 400, 366, 360, 349, 337, 330, 326, 323, 313, 312, 312, 311, 304, 299, 289, 288, 276, 275, 267, 266, 255, 254, 253, 252, 245, 243, 241, 235, 235, 227, 226, 226, 225, 216, 206, 195, 194, 194, 187, 186, 182, 175, 174, 172, 171, 164, 161, 160, 153, 152, 141, 140, 140, 123, 122, 122, 109, 1075, 105, 1084, 1083, 1118, 1117, 1183, 1164, 1163, 1111, 1110, 1109, 105, 1191, 1190, 1201, 1208, 105, 1228, 1219, 1218, 1234, 105, 95, 89, 89, 1378, 1415, 1412, 1411, 1407, 88, 79, 78, 78, 64, 63, 44, 43, 26, 23, 22, 13, 12, 11, 1050, 1050, 1041, 1040, 1040, 1026, 1024, 1019, 1012, 1003, 1002, 992, 991, 990, 983, 982, 982, 974, 973, 972, 964, 963, 947, 946, 936, 933, 924, 923, 917, 916, 901, 894, 887, 870, 868, 859, 858, 858, 850, 849, 849, 840, 835, 815, 811, 799, 783, 772, 771, 766, 757, 756, 750, 740, 739, 1372, 1352, 1351, 1356, 1355, 731, 697, 688, 684, 1322, 1321, 1265, 1340, 1291, 1290, 1287, 1278, 660, 646, 645, 623, 584, 569, 568, 536, 535, 526, 501, 485, 484, 410, 400, 399, 366, 349, 347, 338, 337, 326, 323, 312, 304, 299, 288, 286, 277, 276, 267, 266, 254, 253, 253, 245, 244, 243, 235, 226, 226, 216, 215, 207, 206, 194, 186, 182, 174, 173, 172, 164, 161, 153, 152, 152, 140, 122, 122, 1075, 1069, 1053, 105, 1083, 1118, 1183, 1178, 1164, 1111, 1110, 1192, 1191, 1201, 1209, 1208, 1228, 1222, 1219, 1234, 1244, 105, 89, 89, 1415, 1414, 1412, 1407, 1406, 1391, 1390, 89, 89, 88, 78, 45, 44, 26, 23, 13, 12, 12, 1050, 1040, 1020, 1019, 1003, 990, 982, 973, 973, 972, 963, 947, 936, 935, 933, 923, 922, 918, 917, 901, 895, 894, 870, 858, 849, 835, 819, 815, 799, 783, 771, 770, 755, 750, 739, 1362, 1372, 1351, 1356, 731, 730, 704, 697, 684, 1322, 1265, 1263, 1340, 1290, 1289, 1287, 660, 659, 646, 623, 620, 536, 526, 523, 506, 501, 484, 410, 399, 397, 367, 366, 347, 339, 338, 326, 325, 323, 304, 303, 299, 286, 277, 267, 266, 244, 243, 226, 226, 215, 207, 207, 194, 186, 174, 173, 164, 161, 152, 122, 1053, 1083, 1082, 1119, 1118, 1178, 1164, 1110, 105, 1192, 1201, 1214, 1212, 1209, 1228, 1223, 1222, 1234, 1244, 89, 1414, 1412, 1406, 1405, 1393, 1391, 89, 45, 26, 25, 23, 12, 1040, 1024, 1020, 1003, 990, 973, 963, 947, 935, 934, 934, 933, 922, 919, 918, 901, 897, 895, 870, 868, 858, 835, 820, 819, 799, 784, 783, 770, 755, 739, 737, 1362, 1351, 1350, 704, 684, 680, 1322, 1263, 1346, 1340, 1289, 1288, 1287, 659, 658, 646, 620, 616, 540, 536, 523, 507, 506, 484, 410, 397, 396, 369, 367, 347, 325, 304, 303, 303, 286, 285, 277, 266, 253, 244, 226, 207, 207, 186, 174, 174, 164, 163, 1053, 1082, 1081, 1119, 1178, 1177, 1165, 1164, 105, 1193, 1192, 1214, 1212, 1228, 1227, 1224, 1223, 1234, 1247, 1244, 1414, 1394, 1393, 89, 45, 25, 24, 24, 23, 1040, 1024, 1003, 1002, 990, 963, 962, 947, 934, 922, 920, 919, 901, 898, 897, 870, 868, 835, 834, 820, 799, 794, 784, 770, 769, 756, 755, 737, 1362, 1350, 1360, 704, 680, 679, 1297, 1322, 1346, 1287, 658, 657, 543, 540, 523, 507, 484, 481, 416, 410, 396, 370, 369, 347, 325, 303, 285, 278, 277, 253, 253, 244, 207, 207, 174, 163, 1053, 1081, 1080, 1124, 1119, 1177, 1212, 1227, 1226, 1224, 1234, 1253, 1247, 1414, 1413, 1395, 1394, 89, 46, 45, 24, 24, 1040, 1024, 1024, 1002, 991, 948, 947, 922, 920, 901, 900, 899, 898, 870, 868, 834, 833, 821, 820, 794, 756, 737, 1367, 1362, 1360, 1359, 704, 679, 678, 1298, 1297, 1346, 1287, 657, 655, 543, 523, 522, 508, 507, 481, 416, 396, 390, 371, 370, 347, 326, 325, 285, 279, 278, 253, 253, 207, 163, 1124, 1177, 1175, 1212, 1226, 1225, 1224, 1253, 1252, 1247, 1413, 1412, 1395, 89, 78, 46, 24, 1024, 953, 948, 922, 921, 920, 900, 900, 899, 870, 868, 833, 831, 822, 821, 794, 766, 756, 1367, 1362, 1359, 1358, 706, 704, 678, 1299, 1298, 1346, 1287, 655, 654, 544, 543, 522, 508, 481, 480, 417, 416, 390, 373, 371, 347, 326, 285, 283, 282, 279, 253, 253, 163, 162, 1124, 1175, 1174, 1212, 1225, 1224, 1224, 1252, 1251, 1404, 1395, 78, 47, 46, 1024, 953, 922, 921, 921, 900, 870, 825, 822, 794, 766, 1367, 1366, 1363, 1362, 1358, 706, 678, 676, 1300, 1299, 1346, 1287, 654, 653, 545, 544, 522, 509, 508, 480, 418, 417, 390, 373, 347, 345, 283, 282, 253, 253, 162, 161, 1212, 1224, 1251, 1405, 1404, 78, 53, 47, 1024, 953, 921, 870, 831, 825, 794, 767, 766, 1366, 1363, 1358, 1357, 707, 706, 676, 1300, 1346, 1344, 1287, 653, 651, 545, 522, 521, 509, 480, 472, 418, 390, 388, 374, 373, 345, 283, 253, 161, 1212, 1251, 1250, 1405, 78, 63, 54, 53, 1024, 962, 953, 870, 831, 794, 787, 767, 1366, 1365, 1363, 1357, 1356, 708, 707, 676, 1300, 1344, 1343, 1288, 1287, 651, 545, 521, 520, 510, 509, 472, 418, 388, 387, 374, 345, 344, 326, 283, 161, 1212, 1250, 1249, 1412, 1405, 63, 55, 54, 1024, 990, 962, 870, 868, 831, 787, 767, 1365, 1364, 709, 708, 676, 1288, 651, 650, 546, 545, 520, 511, 510, 472, 418, 387, 384, 374, 344, 343, 339, 326, 161, 1212, 1249, 1248, 1247, 1412, 63, 62, 55, 1024, 991, 990, 870, 869, 868, 787, 767, 1364, 1363, 710, 709, 676, 1288, 650, 649, 546, 520, 519, 512, 511, 472, 419, 418, 384, 339, 161, 122, 1212, 1248, 1247, 1247, 63, 62, 62, 1024, 991, 991, 870, 869, 869, 787, 786, 768, 767, 1363, 710, 676, 672, 547, 546, 519, 513, 512, 472, 419, 384, 383, 340, 339, 122, 1212, 1247, 62, 62, 991, 869, 869, 786, 785, 768, 1363, 1356, 711, 710, 672, 548, 547, 519, 519, 513, 472, 340, 122, 1069, 869, 785, 784, 769, 768, 1356, 712, 711, 672, 548, 519, 472, 343, 340, 1069, 869, 784, 769, 769, 1356, 730, 715, 712, 672, 549, 548, 472, 343, 1069, 1066, 869, 769, 730, 715, 672, 671, 561, 549, 472, 343, 1066, 1065, 869, 730, 729, 715, 671, 670, 562, 561, 472, 374, 343, 1065, 715, 670, 668, 562, 472, 471, 374, 1065, 1064, 722, 715, 668, 562, 471, 470, 374, 1064, 1063, 722, 668, 1314, 374, 1063, 1062, 723, 722, 1337, 722, 1314, 1313, 374, 1062, 1061, 729, 723, 1337, 722, 1313, 1312, 379, 374, 1061, 729, 1337, 1336, 1337, 722, 1312, 380, 379, 1061, 869, 729, 1336, 1337, 1312, 1306, 380, 1061, 1060, 869, 1336, 1335, 1324, 1337, 1306, 380, 1060, 1058, 869, 1335, 1334, 1324, 1306, 1305, 381, 380, 1058, 869, 1334, 1333, 1324, 1305, 1304, 381, 1058, 1057, 869, 1333, 1332, 1325, 1324, 1304, 381, 1057, 1053, 869, 1332, 1329, 1325, 1304, 1300, 382, 381, 1053, 869, 1329, 1328, 1326, 1325, 1300, 383, 382, 1053, 1327, 1326, 1300, 383, 1053, 1080, 1327, 1300, 1343, 419, 383, 1080, 1328, 1327, 1343, 420, 419, 1080, 869, 1328, 1343, 420, 1080, 1158, 869, 1343, 1342, 420, 1158, 1155, 869, 1342, 1341, 420, 1155, 1154, 869, 1341, 1340, 420, 1154, 1153, 62, 869, 1340, 434, 420, 1153, 62, 1340, 1288, 434, 1153, 1152, 62, 1288, 649, 434, 1152, 1148, 62, 649, 648, 435, 434, 1148, 1212, 62, 648, 438, 435, 1148, 1213, 1212, 648, 438, 1148, 1145, 1214, 1213, 648, 438, 1145, 1144, 1214, 648, 647, 440, 438, 1144, 1214, 647, 646, 442, 440, 1144, 1214, 646, 616, 443, 442, 1144, 1214, 616, 615, 448, 443, 1144, 1193, 1214, 615, 448, 1144, 1140, 1193, 615, 614, 448, 1140, 1137, 1193, 614, 599, 450, 448, 1137, 1194, 1193, 599, 450, 1137, 1136, 105, 1194, 599, 452, 450, 1136, 105, 599, 594, 453, 452, 1136, 105, 594, 584, 453, 1136, 1135, 105, 584, 568, 453, 1135, 1133, 105, 568, 567, 454, 453, 1133, 1165, 105, 567, 456, 454, 1133, 1166, 1165, 567, 457, 456, 1133, 1167, 1166, 567, 457, 1133, 1129, 1167, 567, 566, 461, 457, 1129, 463, 461, 1129, 463, 1129, 1124, 464, 463, 1124, 465, 464, 1124, 466, 465, 1124, 466, 1124, 1174, 467, 466, 1174, 469, 467, 1174, 469, 1174, 1173, 469, 1173, 1167, 470, 469, 1167, 562, 470, 1167, 562, 1167, 566, 562, 566, 564, 564, 563, 562];
 testOutput["eberly_6"]["pass"]=true;
-testOutput["eberly_6"]["report"]="exp numtri:1429\nexp dev:0.00000000000001\nact numtri:1429\nact dev:0.0000000000000021764342840981093";
+testOutput["eberly_6"]["report"]="exp numtri:1429\nexp dev:0.00000000000001\nact numtri:1429\nact dev:0.000000000000002846106371512912";
@@ -1,5 +1,5 @@
 testOutput["filtered_bridge_jhl"]=[];
 testOutput["filtered_bridge_jhl"]["json"]=[[[22.0, 14.0], [17.0, 12.0], [5.0, 12.0], [0.0, 12.0], [0.0, 0.0], [22.0, 0.0]], [[9.0, 4.0], [10.0, 4.0], [10.0, 3.0], [9.0, 3.0]], [[6.0, 9.0], [7.0, 9.0], [7.0, 8.0], [6.0, 8.0]], [[7.0, 10.0], [17.0, 10.0], [17.0, 5.0], [8.0, 5.0]], [[13.0, 4.0], [14.0, 4.0], [14.0, 3.0], [13.0, 3.0]]];
-testOutput["filtered_bridge_jhl"]["triangles"]=[21, 18, 7, 5, 0, 1, 3, 4, 10, 13, 10, 4, 21, 7, 8, 3, 10, 14, 14, 10, 11, 12, 13, 4, 1, 3, 14, 17, 14, 11, 1, 14, 15, 17, 11, 12, 5, 1, 15, 17, 12, 4, 5, 15, 16, 17, 4, 6, 9, 6, 4, 16, 17, 6, 8, 9, 4, 16, 6, 19, 21, 8, 4, 5, 16, 19, 20, 21, 4, 5, 19, 20, 20, 4, 5];
+testOutput["filtered_bridge_jhl"]["triangles"]=[8, 21, 18, 20, 9, 4, 5, 0, 1, 3, 4, 13, 12, 13, 4, 4, 9, 6, 7, 8, 18, 20, 4, 5, 3, 13, 10, 19, 20, 5, 3, 10, 14, 14, 10, 11, 1, 3, 14, 17, 14, 11, 1, 14, 15, 17, 11, 12, 5, 1, 15, 17, 12, 4, 5, 15, 16, 17, 4, 6, 19, 5, 16, 16, 17, 6, 18, 19, 16, 16, 6, 7, 7, 18, 16];
 testOutput["filtered_bridge_jhl"]["pass"]=true;
 testOutput["filtered_bridge_jhl"]["report"]="exp numtri:0\nexp dev:0.00000000000001\nact numtri:25\nact dev:0";
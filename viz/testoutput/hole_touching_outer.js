@@ -1,5 +1,5 @@
 testOutput["hole_touching_outer"]=[];
 testOutput["hole_touching_outer"]["json"]=[[[-64.0, -64.0], [253.0, -64.0], [491.0, 358.0], [697.0, 298.0], [928.0, 197.0], [929.0, 505.0], [1346.0, 507.0], [1347.0, 303.0], [1771.0, 306.0], [1770.0, 512.0], [2191.0, 509.0], [2198.0, 933.0], [2621.0, 932.0], [2623.0, 1115.0], [2577.0, 1120.0], [2494.0, 1183.0], [2390.0, 1329.0], [2326.0, 1590.0], [2287.0, 1678.0], [2286.0, 1407.0], [2229.0, 1407.0], [2182.0, 1493.0], [2106.0, 1494.0], [2068.0, 1460.0], [2019.0, 1460.0], [2016.0, 1775.0], [1889.0, 1923.0], [1953.0, 1989.0], [2097.0, 1866.0], [2198.0, 1925.0], [2203.0, 1973.0], [2311.0, 1976.0], [2320.0, 1831.0], [2352.0, 1824.0], [2358.0, 1797.0], [2378.0, 1780.0], [3350.0, 1782.0], [3307.0, 2086.0], [3139.0, 2088.0], [3143.0, 2203.0], [3493.0, 2205.0], [3543.0, 2187.0], [3540.0, 2260.0], [3661.0, 2264.0], [3665.0, 1906.0], [3630.0, 1902.0], [3626.0, 1784.0], [4160.0, 1786.0], [4160.0, 2631.0], [4076.0, 2631.0], [4021.0, 2683.0], [3930.0, 2701.0], [3915.0, 2693.0], [3898.0, 2639.0], [2630.0, 2630.0], [2635.0, 3476.0], [2287.0, 3478.0], [2118.0, 3203.0], [2180.0, 3145.0], [2327.0, 3087.0], [2610.0, 2643.0], [2613.0, 2536.0], [2658.0, 2495.0], [2650.0, 2203.0], [1829.0, 2189.0], [1732.0, 2241.0], [1551.0, 2245.0], [933.0, 1183.0], [890.0, 1152.0], [455.0, 401.0], [398.0, 412.0], [89.0, 547.0], [-64.0, 606.0], [-64.0, -64.0]], [[1762.0, 928.0], [1770.0, 512.0], [1343.0, 513.0], [1345.0, 715.0], [931.0, 719.0], [932.0, 930.0], [1762.0, 928.0]]];
-testOutput["hole_touching_outer"]["triangles"]=[0, 1, 2, 3, 4, 5, 6, 7, 8, 11, 12, 13, 16, 17, 18, 20, 21, 22, 24, 25, 26, 27, 28, 29, 31, 32, 33, 33, 34, 35, 35, 36, 37, 40, 41, 42, 44, 45, 46, 46, 47, 48, 49, 50, 51, 51, 52, 53, 54, 55, 56, 56, 57, 58, 60, 61, 62, 64, 65, 66, 76, 77, 78, 70, 71, 72, 72, 0, 2, 2, 3, 5, 11, 13, 14, 16, 18, 19, 20, 22, 23, 27, 29, 30, 31, 33, 35, 35, 37, 38, 39, 40, 42, 44, 46, 48, 49, 51, 53, 56, 58, 59, 64, 66, 67, 70, 72, 2, 11, 14, 15, 15, 16, 19, 20, 23, 24, 27, 30, 31, 31, 35, 38, 43, 44, 48, 49, 53, 54, 54, 56, 59, 69, 70, 2, 11, 15, 19, 27, 31, 38, 42, 43, 48, 48, 49, 54, 54, 59, 60, 67, 68, 69, 69, 78, 79, 78, 69, 2, 11, 19, 20, 27, 38, 39, 39, 42, 48, 54, 60, 62, 64, 67, 69, 78, 2, 5, 11, 20, 24, 27, 39, 48, 48, 54, 62, 64, 69, 79, 76, 78, 5, 10, 11, 24, 48, 62, 63, 64, 79, 74, 76, 5, 6, 10, 24, 26, 27, 48, 63, 76, 6, 8, 27, 63, 64, 26, 27, 64, 10, 26, 64, 10, 64, 74, 75, 76, 8, 74, 9, 10];
+testOutput["hole_touching_outer"]["triangles"]=[76, 77, 78, 70, 71, 72, 72, 0, 1, 3, 4, 5, 6, 7, 8, 11, 12, 13, 16, 17, 18, 20, 21, 22, 24, 25, 26, 27, 28, 29, 31, 32, 33, 33, 34, 35, 35, 36, 37, 40, 41, 42, 44, 45, 46, 46, 47, 48, 49, 50, 51, 51, 52, 53, 54, 55, 56, 56, 57, 58, 60, 61, 62, 64, 65, 66, 70, 72, 1, 2, 3, 5, 11, 13, 14, 16, 18, 19, 20, 22, 23, 27, 29, 30, 31, 33, 35, 35, 37, 38, 39, 40, 42, 44, 46, 48, 49, 51, 53, 56, 58, 59, 64, 66, 67, 69, 70, 1, 11, 14, 15, 15, 16, 19, 20, 23, 24, 27, 30, 31, 31, 35, 38, 43, 44, 48, 49, 53, 54, 54, 56, 59, 67, 68, 69, 69, 78, 79, 69, 1, 2, 11, 15, 19, 27, 31, 38, 42, 43, 48, 48, 49, 54, 54, 59, 60, 64, 67, 69, 78, 69, 2, 11, 19, 20, 27, 38, 39, 39, 42, 48, 54, 60, 62, 64, 69, 79, 78, 2, 5, 11, 20, 24, 27, 39, 48, 48, 54, 62, 64, 79, 74, 76, 78, 5, 10, 11, 24, 48, 62, 63, 76, 5, 6, 10, 24, 26, 27, 48, 63, 76, 6, 8, 27, 63, 64, 26, 27, 64, 10, 26, 64, 10, 64, 74, 10, 74, 9, 76, 8, 75];
 testOutput["hole_touching_outer"]["pass"]=true;
 testOutput["hole_touching_outer"]["report"]="exp numtri:77\nexp dev:0.00000000000001\nact numtri:77\nact dev:0";
@@ -1,5 +1,5 @@
 testOutput["issue17"]=[];
 testOutput["issue17"]["json"]=[[[-20037508.34, 19971868.877628453], [-20037508.34, -19971868.877628453], [20037508.34, -19971868.877628453], [20037508.34, 19971868.877628453]], [[537637.6007702783, 5907542.234420554], [539500.1483225027, 5905165.501947839], [538610.3146341922, 5905217.430281373], [538040.6306361248, 5906132.0755739985], [538068.958329954, 5906571.138846622], [537711.0379352621, 5906645.06648362], [537629.886026485, 5907533.69114742]]];
-testOutput["issue17"]["triangles"]=[0, 1, 10, 7, 8, 9, 9, 10, 1, 1, 2, 3, 0, 10, 4, 6, 7, 9, 3, 0, 4, 6, 9, 1, 3, 4, 5, 5, 6, 1, 1, 3, 5];
+testOutput["issue17"]["triangles"]=[7, 8, 9, 1, 2, 3, 0, 1, 10, 6, 7, 9, 9, 10, 1, 0, 10, 4, 6, 9, 1, 3, 0, 4, 5, 6, 1, 3, 4, 5, 5, 1, 3];
 testOutput["issue17"]["pass"]=true;
 testOutput["issue17"]["report"]="exp numtri:11\nexp dev:0.00000000000001\nact numtri:11\nact dev:0.0000000000000001561771867280377";
@@ -1,5 +1,5 @@
 testOutput["issue34"]=[];
 testOutput["issue34"]["json"]=[[[1500.0, 0.0], [0.0, 0.0], [0.0, 1000.0], [1500.0, 1000.0], [1500.0, 0.0]], [[804.0, 642.0], [814.0, 644.0], [818.0, 676.0], [850.0, 690.0], [838.0, 728.0], [806.0, 728.0], [772.0, 752.0], [748.0, 746.0], [764.0, 724.0], [728.0, 726.0], [710.0, 708.0], [738.0, 656.0], [764.0, 668.0], [784.0, 700.0], [806.0, 702.0], [792.0, 666.0], [804.0, 642.0]], [[1176.0, 214.0], [1254.0, 216.0], [1292.0, 242.0], [1324.0, 242.0], [1332.0, 268.0], [1352.0, 278.0], [1352.0, 298.0], [1290.0, 348.0], [1290.0, 358.0], [1312.0, 350.0], [1314.0, 362.0], [1266.0, 416.0], [1240.0, 474.0], [1182.0, 500.0], [1200.0, 510.0], [1200.0, 520.0], [1186.0, 520.0], [1200.0, 544.0], [1186.0, 580.0], [1160.0, 584.0], [1162.0, 606.0], [1146.0, 620.0], [1162.0, 650.0], [1136.0, 672.0], [1124.0, 658.0], [1076.0, 668.0], [1022.0, 658.0], [1036.0, 698.0], [1066.0, 706.0], [1118.0, 688.0], [1144.0, 708.0], [1132.0, 746.0], [1064.0, 748.0], [1004.0, 740.0], [990.0, 668.0], [966.0, 670.0], [946.0, 648.0], [948.0, 632.0], [962.0, 628.0], [992.0, 650.0], [1016.0, 648.0], [1054.0, 622.0], [1044.0, 592.0], [1054.0, 584.0], [1078.0, 606.0], [1076.0, 576.0], [1052.0, 570.0], [1056.0, 540.0], [1038.0, 568.0], [1004.0, 570.0], [976.0, 526.0], [996.0, 502.0], [958.0, 496.0], [948.0, 454.0], [962.0, 454.0], [952.0, 436.0], [964.0, 390.0], [986.0, 382.0], [974.0, 368.0], [1004.0, 376.0], [1018.0, 420.0], [1052.0, 434.0], [1060.0, 482.0], [1078.0, 490.0], [1062.0, 472.0], [1062.0, 442.0], [1104.0, 450.0], [1104.0, 436.0], [1142.0, 422.0], [1154.0, 402.0], [1110.0, 424.0], [1046.0, 416.0], [1022.0, 388.0], [1022.0, 344.0], [1002.0, 344.0], [1018.0, 318.0], [1060.0, 308.0], [1076.0, 272.0], [1104.0, 288.0], [1122.0, 246.0], [1140.0, 230.0], [1168.0, 234.0], [1176.0, 214.0]], [[974.0, 698.0], [986.0, 738.0], [964.0, 740.0], [952.0, 714.0], [974.0, 698.0]], [[842.0, 596.0], [860.0, 626.0], [848.0, 622.0], [842.0, 596.0]], [[798.0, 572.0], [792.0, 606.0], [768.0, 614.0], [740.0, 580.0], [758.0, 586.0], [798.0, 572.0]], [[892.0, 584.0], [894.0, 594.0], [882.0, 588.0], [892.0, 584.0]], [[870.0, 500.0], [912.0, 538.0], [922.0, 586.0], [908.0, 590.0], [894.0, 568.0], [864.0, 564.0], [854.0, 550.0], [868.0, 538.0], [846.0, 520.0], [854.0, 500.0], [870.0, 500.0]]];
-testOutput["issue34"]["triangles"]=[11, 10, 9, 9, 108, 107, 109, 108, 9, 8, 7, 6, 6, 58, 57, 57, 56, 55, 51, 50, 49, 49, 48, 47, 47, 46, 45, 44, 43, 42, 42, 41, 40, 39, 38, 37, 36, 35, 34, 34, 33, 32, 31, 30, 29, 27, 26, 25, 25, 24, 23, 104, 103, 102, 101, 100, 99, 99, 98, 97, 96, 95, 94, 92, 91, 90, 89, 88, 87, 86, 85, 84, 83, 82, 81, 80, 79, 78, 77, 76, 75, 74, 73, 72, 70, 69, 68, 68, 67, 66, 64, 63, 62, 62, 61, 60, 59, 58, 6, 20, 19, 18, 16, 15, 1, 1, 117, 116, 123, 122, 113, 113, 115, 119, 119, 118, 117, 117, 1, 132, 132, 131, 130, 129, 128, 127, 133, 132, 1, 2, 1, 15, 14, 13, 12, 11, 9, 107, 109, 9, 8, 8, 6, 57, 51, 49, 47, 44, 42, 40, 37, 36, 34, 31, 29, 28, 102, 101, 99, 92, 90, 89, 87, 86, 84, 71, 70, 68, 68, 66, 65, 64, 62, 60, 60, 59, 6, 20, 18, 17, 17, 16, 1, 116, 115, 113, 111, 113, 122, 123, 113, 119, 119, 117, 132, 134, 133, 1, 2, 15, 14, 109, 8, 57, 51, 47, 45, 44, 40, 39, 39, 37, 34, 32, 31, 28, 93, 92, 89, 87, 84, 83, 71, 68, 65, 65, 64, 60, 60, 6, 21, 21, 20, 17, 17, 1, 116, 116, 113, 112, 111, 122, 121, 119, 132, 130, 2, 14, 12, 106, 109, 57, 52, 51, 45, 44, 39, 34, 93, 89, 87, 87, 83, 81, 71, 65, 60, 21, 17, 116, 116, 112, 111, 119, 130, 129, 3, 2, 12, 106, 57, 55, 52, 45, 44, 44, 34, 32, 94, 93, 87, 72, 71, 60, 21, 116, 111, 123, 119, 129, 3, 12, 11, 107, 106, 55, 52, 44, 32, 94, 87, 81, 74, 72, 60, 60, 21, 111, 123, 129, 127, 3, 11, 107, 107, 55, 54, 53, 52, 32, 96, 94, 81, 75, 74, 60, 60, 111, 121, 121, 123, 127, 3, 107, 54, 53, 32, 28, 96, 81, 80, 60, 121, 127, 3, 54, 53, 97, 96, 80, 60, 127, 126, 4, 3, 53, 75, 60, 126, 4, 53, 28, 75, 126, 125, 4, 28, 27, 77, 75, 125, 4, 27, 25, 78, 77, 125, 4, 25, 23, 78, 125, 134, 1, 4, 23, 80, 78, 134, 1, 23, 104, 80, 134, 1, 1, 104, 102, 97, 80, 1, 1, 102, 99, 99, 97, 1];
+testOutput["issue34"]["triangles"]=[9, 108, 107, 109, 108, 9, 8, 7, 6, 6, 58, 57, 57, 56, 55, 51, 50, 49, 49, 48, 47, 47, 46, 45, 44, 43, 42, 42, 41, 40, 39, 38, 37, 36, 35, 34, 34, 33, 32, 31, 30, 29, 27, 26, 25, 25, 24, 23, 104, 103, 102, 101, 100, 99, 99, 98, 97, 96, 95, 94, 92, 91, 90, 89, 88, 87, 86, 85, 84, 83, 82, 81, 80, 79, 78, 77, 76, 75, 74, 73, 72, 70, 69, 68, 68, 67, 66, 64, 63, 62, 62, 61, 60, 59, 58, 6, 20, 19, 18, 16, 15, 1, 1, 117, 116, 123, 122, 113, 113, 115, 119, 119, 118, 117, 117, 1, 132, 132, 131, 130, 129, 128, 127, 133, 132, 1, 2, 1, 15, 14, 13, 12, 11, 10, 9, 109, 9, 8, 8, 6, 57, 51, 49, 47, 44, 42, 40, 37, 36, 34, 31, 29, 28, 102, 101, 99, 92, 90, 89, 87, 86, 84, 71, 70, 68, 68, 66, 65, 64, 62, 60, 60, 59, 6, 20, 18, 17, 17, 16, 1, 116, 115, 113, 111, 113, 122, 123, 113, 119, 119, 117, 132, 134, 133, 1, 2, 15, 14, 11, 9, 107, 109, 8, 57, 51, 47, 45, 44, 40, 39, 39, 37, 34, 32, 31, 28, 93, 92, 89, 87, 84, 83, 71, 68, 65, 65, 64, 60, 60, 6, 21, 21, 20, 17, 17, 1, 116, 116, 113, 112, 111, 122, 121, 119, 132, 130, 2, 14, 12, 106, 109, 57, 52, 51, 45, 44, 39, 34, 93, 89, 87, 87, 83, 81, 71, 65, 60, 21, 17, 116, 116, 112, 111, 119, 130, 129, 3, 2, 12, 106, 57, 55, 52, 45, 44, 44, 34, 32, 94, 93, 87, 72, 71, 60, 21, 116, 111, 123, 119, 129, 3, 12, 11, 107, 106, 55, 52, 44, 32, 94, 87, 81, 74, 72, 60, 60, 21, 111, 123, 129, 127, 3, 11, 107, 107, 55, 54, 53, 52, 32, 96, 94, 81, 75, 74, 60, 60, 111, 121, 121, 123, 127, 3, 107, 54, 53, 32, 28, 96, 81, 80, 60, 121, 127, 3, 54, 53, 97, 96, 80, 60, 127, 126, 4, 3, 53, 75, 60, 126, 4, 53, 28, 75, 126, 125, 4, 28, 27, 77, 75, 125, 4, 27, 25, 78, 77, 125, 4, 25, 23, 78, 125, 134, 1, 4, 23, 80, 78, 134, 1, 23, 104, 80, 134, 1, 1, 104, 102, 97, 80, 1, 1, 102, 99, 99, 97, 1];
 testOutput["issue34"]["pass"]=true;
 testOutput["issue34"]["report"]="exp numtri:139\nexp dev:0.00000000000001\nact numtri:139\nact dev:0";
@@ -1,5 +1,5 @@
 testOutput["issue35"]=[];
 testOutput["issue35"]["json"]=[[[216.0, -128.0], [218.0, -98.0], [232.0, -104.0], [238.0, -98.0], [234.0, -58.0], [242.0, -32.0], [232.0, -16.0], [254.0, -2.0], [256.0, 20.0], [268.0, 6.0], [274.0, 6.0], [278.0, 20.0], [284.0, 16.0], [284.0, 6.0], [266.0, -4.0], [276.0, -28.0], [262.0, -64.0], [296.0, -68.0], [312.0, -60.0], [308.0, -50.0], [322.0, -46.0], [338.0, -46.0], [344.0, -52.0], [378.0, -50.0], [396.0, -16.0], [434.0, 22.0], [446.0, 26.0], [452.0, 38.0], [470.0, 42.0], [466.0, 58.0], [450.0, 56.0], [436.0, 68.0], [428.0, 102.0], [406.0, 96.0], [408.0, 116.0], [370.0, 114.0], [340.0, 96.0], [356.0, 124.0], [358.0, 164.0], [352.0, 174.0], [328.0, 186.0], [326.0, 208.0], [342.0, 208.0], [346.0, 232.0], [328.0, 232.0], [318.0, 248.0], [306.0, 246.0], [302.0, 236.0], [284.0, 238.0], [240.0, 190.0], [218.0, 194.0], [194.0, 176.0], [184.0, 124.0], [142.0, 102.0], [134.0, 102.0], [118.0, 118.0], [72.0, 118.0], [70.0, 90.0], [52.0, 68.0], [68.0, 42.0], [68.0, 24.0], [54.0, 40.0], [36.0, 28.0], [30.0, 46.0], [12.0, 48.0], [14.0, 82.0], [30.0, 98.0], [18.0, 150.0], [-8.0, 170.0], [-22.0, 190.0], [-40.0, 194.0], [-50.0, 204.0], [-70.0, 208.0], [-112.0, 198.0], [-116.0, 212.0], [-114.0, 230.0], [-96.0, 250.0], [-72.0, 244.0], [-58.0, 276.0], [-50.0, 332.0], [-24.0, 364.0], [-30.0, 384.0], [-20.0, 390.0], [-16.0, 410.0], [-26.0, 426.0], [-36.0, 428.0], [-38.0, 444.0], [-92.0, 422.0], [-128.0, 422.0], [-128.0, 4224.0], [4224.0, 4224.0], [4224.0, 3520.0], [4204.0, 3506.0], [4204.0, 3498.0], [4212.0, 3498.0], [4210.0, 3486.0], [4164.0, 3492.0], [4132.0, 3478.0], [4104.0, 3474.0], [4074.0, 3458.0], [4034.0, 3456.0], [3992.0, 3428.0], [3928.0, 3362.0], [3880.0, 3342.0], [3870.0, 3342.0], [3850.0, 3360.0], [3814.0, 3374.0], [3790.0, 3374.0], [3768.0, 3362.0], [3742.0, 3360.0], [3706.0, 3344.0], [3698.0, 3334.0], [3656.0, 3326.0], [3640.0, 3314.0], [3592.0, 3298.0], [3554.0, 3266.0], [3534.0, 3266.0], [3488.0, 3252.0], [3464.0, 3224.0], [3416.0, 3202.0], [3384.0, 3154.0], [3386.0, 3142.0], [3398.0, 3140.0], [3392.0, 3124.0], [3400.0, 3116.0], [3402.0, 3094.0], [3392.0, 3088.0], [3388.0, 3070.0], [3394.0, 3062.0], [3382.0, 3052.0], [3378.0, 3038.0], [3346.0, 3010.0], [3316.0, 2966.0], [3278.0, 2938.0], [3278.0, 2926.0], [3264.0, 2910.0], [3212.0, 2880.0], [3212.0, 2866.0], [3220.0, 2858.0], [3218.0, 2844.0], [3208.0, 2834.0], [3198.0, 2836.0], [3184.0, 2814.0], [3162.0, 2802.0], [3158.0, 2776.0], [3130.0, 2768.0], [3090.0, 2720.0], [3080.0, 2728.0], [3070.0, 2724.0], [3080.0, 2698.0], [3044.0, 2628.0], [3044.0, 2602.0], [3024.0, 2596.0], [3010.0, 2580.0], [2992.0, 2584.0], [2980.0, 2574.0], [2966.0, 2580.0], [2966.0, 2608.0], [2972.0, 2614.0], [2980.0, 2664.0], [3022.0, 2700.0], [3026.0, 2718.0], [3056.0, 2746.0], [3062.0, 2778.0], [3080.0, 2792.0], [3098.0, 2824.0], [3112.0, 2832.0], [3122.0, 2848.0], [3130.0, 2884.0], [3156.0, 2922.0], [3156.0, 2954.0], [3162.0, 2960.0], [3176.0, 2954.0], [3186.0, 2960.0], [3202.0, 2988.0], [3212.0, 2994.0], [3212.0, 3010.0], [3186.0, 3026.0], [3170.0, 2992.0], [3112.0, 2946.0], [3102.0, 2944.0], [3086.0, 2926.0], [3090.0, 2882.0], [3078.0, 2860.0], [3062.0, 2854.0], [3040.0, 2832.0], [3018.0, 2832.0], [3012.0, 2822.0], [2986.0, 2812.0], [2958.0, 2782.0], [2960.0, 2776.0], [2994.0, 2776.0], [3000.0, 2742.0], [2958.0, 2696.0], [2928.0, 2680.0], [2920.0, 2648.0], [2910.0, 2638.0], [2910.0, 2624.0], [2900.0, 2616.0], [2896.0, 2598.0], [2882.0, 2582.0], [2882.0, 2566.0], [2862.0, 2538.0], [2852.0, 2496.0], [2828.0, 2472.0], [2804.0, 2464.0], [2796.0, 2448.0], [2772.0, 2446.0], [2754.0, 2428.0], [2708.0, 2424.0], [2702.0, 2418.0], [2700.0, 2388.0], [2644.0, 2322.0], [2642.0, 2304.0], [2648.0, 2292.0], [2624.0, 2276.0], [2616.0, 2234.0], [2598.0, 2224.0], [2590.0, 2202.0], [2564.0, 2176.0], [2556.0, 2120.0], [2530.0, 2086.0], [2542.0, 2054.0], [2544.0, 2012.0], [2530.0, 1976.0], [2524.0, 1928.0], [2542.0, 1870.0], [2554.0, 1718.0], [2546.0, 1710.0], [2546.0, 1668.0], [2540.0, 1664.0], [2528.0, 1616.0], [2518.0, 1606.0], [2516.0, 1576.0], [2524.0, 1574.0], [2552.0, 1588.0], [2612.0, 1594.0], [2614.0, 1588.0], [2606.0, 1580.0], [2616.0, 1564.0], [2616.0, 1552.0], [2600.0, 1528.0], [2586.0, 1528.0], [2580.0, 1506.0], [2552.0, 1498.0], [2542.0, 1480.0], [2534.0, 1480.0], [2534.0, 1494.0], [2512.0, 1456.0], [2498.0, 1452.0], [2498.0, 1470.0], [2524.0, 1504.0], [2552.0, 1514.0], [2560.0, 1520.0], [2564.0, 1536.0], [2578.0, 1540.0], [2578.0, 1572.0], [2556.0, 1576.0], [2498.0, 1550.0], [2498.0, 1534.0], [2474.0, 1532.0], [2460.0, 1514.0], [2434.0, 1502.0], [2430.0, 1490.0], [2418.0, 1486.0], [2414.0, 1472.0], [2402.0, 1468.0], [2400.0, 1460.0], [2374.0, 1452.0], [2368.0, 1428.0], [2350.0, 1414.0], [2352.0, 1402.0], [2380.0, 1396.0], [2396.0, 1412.0], [2418.0, 1420.0], [2426.0, 1420.0], [2430.0, 1410.0], [2394.0, 1396.0], [2378.0, 1380.0], [2380.0, 1352.0], [2364.0, 1356.0], [2360.0, 1350.0], [2360.0, 1340.0], [2370.0, 1336.0], [2370.0, 1328.0], [2358.0, 1328.0], [2356.0, 1312.0], [2348.0, 1306.0], [2350.0, 1290.0], [2344.0, 1284.0], [2332.0, 1288.0], [2330.0, 1270.0], [2318.0, 1278.0], [2308.0, 1264.0], [2314.0, 1246.0], [2294.0, 1236.0], [2306.0, 1220.0], [2288.0, 1220.0], [2278.0, 1228.0], [2252.0, 1202.0], [2258.0, 1180.0], [2246.0, 1174.0], [2246.0, 1164.0], [2264.0, 1158.0], [2254.0, 1140.0], [2258.0, 1112.0], [2232.0, 1102.0], [2230.0, 1082.0], [2222.0, 1070.0], [2216.0, 1070.0], [2220.0, 1096.0], [2208.0, 1092.0], [2202.0, 1072.0], [2190.0, 1068.0], [2196.0, 1032.0], [2188.0, 1044.0], [2172.0, 1048.0], [2186.0, 1068.0], [2182.0, 1110.0], [2170.0, 1108.0], [2168.0, 1096.0], [2154.0, 1084.0], [2144.0, 1090.0], [2154.0, 1092.0], [2154.0, 1106.0], [2144.0, 1108.0], [2130.0, 1086.0], [2130.0, 1074.0], [2106.0, 1048.0], [2108.0, 1042.0], [2122.0, 1040.0], [2110.0, 1022.0], [2120.0, 1022.0], [2122.0, 1014.0], [2102.0, 1012.0], [2112.0, 996.0], [2110.0, 980.0], [2136.0, 980.0], [2140.0, 966.0], [2110.0, 970.0], [2102.0, 964.0], [2096.0, 992.0], [2082.0, 992.0], [2080.0, 976.0], [2088.0, 966.0], [2076.0, 950.0], [2076.0, 934.0], [2090.0, 930.0], [2100.0, 938.0], [2094.0, 916.0], [2134.0, 922.0], [2114.0, 906.0], [2120.0, 892.0], [2108.0, 872.0], [2112.0, 858.0], [2100.0, 842.0], [2094.0, 840.0], [2098.0, 896.0], [2090.0, 898.0], [2074.0, 920.0], [2066.0, 920.0], [2068.0, 880.0], [2060.0, 868.0], [2050.0, 814.0], [2038.0, 820.0], [2028.0, 808.0], [2002.0, 802.0], [1996.0, 812.0], [1970.0, 818.0], [1960.0, 806.0], [1948.0, 804.0], [1918.0, 776.0], [1900.0, 748.0], [1832.0, 708.0], [1840.0, 692.0], [1836.0, 674.0], [1810.0, 690.0], [1792.0, 690.0], [1762.0, 678.0], [1758.0, 662.0], [1738.0, 666.0], [1690.0, 654.0], [1638.0, 662.0], [1630.0, 652.0], [1602.0, 640.0], [1596.0, 624.0], [1578.0, 632.0], [1548.0, 616.0], [1538.0, 630.0], [1520.0, 638.0], [1516.0, 622.0], [1546.0, 612.0], [1548.0, 604.0], [1534.0, 596.0], [1520.0, 598.0], [1514.0, 570.0], [1492.0, 580.0], [1476.0, 574.0], [1472.0, 582.0], [1452.0, 590.0], [1454.0, 570.0], [1448.0, 570.0], [1440.0, 592.0], [1450.0, 594.0], [1450.0, 608.0], [1458.0, 614.0], [1456.0, 632.0], [1448.0, 642.0], [1460.0, 654.0], [1460.0, 664.0], [1440.0, 658.0], [1432.0, 668.0], [1392.0, 664.0], [1354.0, 706.0], [1340.0, 710.0], [1334.0, 722.0], [1286.0, 738.0], [1276.0, 730.0], [1276.0, 720.0], [1310.0, 696.0], [1310.0, 690.0], [1288.0, 694.0], [1280.0, 686.0], [1286.0, 660.0], [1298.0, 646.0], [1306.0, 622.0], [1302.0, 596.0], [1340.0, 568.0], [1350.0, 568.0], [1358.0, 578.0], [1376.0, 572.0], [1364.0, 548.0], [1332.0, 546.0], [1312.0, 566.0], [1296.0, 570.0], [1286.0, 580.0], [1286.0, 594.0], [1272.0, 602.0], [1262.0, 614.0], [1258.0, 634.0], [1244.0, 644.0], [1248.0, 660.0], [1238.0, 674.0], [1228.0, 676.0], [1228.0, 688.0], [1222.0, 694.0], [1206.0, 694.0], [1196.0, 712.0], [1180.0, 722.0], [1176.0, 744.0], [1212.0, 754.0], [1212.0, 774.0], [1182.0, 798.0], [1172.0, 828.0], [1140.0, 838.0], [1124.0, 860.0], [1112.0, 862.0], [1104.0, 876.0], [1076.0, 892.0], [1076.0, 912.0], [1068.0, 924.0], [1056.0, 926.0], [1040.0, 940.0], [1028.0, 940.0], [1022.0, 956.0], [1006.0, 956.0], [1006.0, 966.0], [984.0, 970.0], [982.0, 978.0], [990.0, 988.0], [980.0, 1002.0], [940.0, 1018.0], [930.0, 1034.0], [918.0, 1020.0], [890.0, 1044.0], [868.0, 1048.0], [856.0, 1058.0], [842.0, 1056.0], [846.0, 1040.0], [838.0, 1038.0], [820.0, 1078.0], [806.0, 1086.0], [792.0, 1082.0], [788.0, 1090.0], [776.0, 1090.0], [768.0, 1080.0], [772.0, 1092.0], [762.0, 1102.0], [766.0, 1108.0], [752.0, 1116.0], [720.0, 1118.0], [704.0, 1134.0], [688.0, 1132.0], [686.0, 1118.0], [706.0, 1092.0], [718.0, 1092.0], [734.0, 1082.0], [758.0, 1088.0], [762.0, 1076.0], [794.0, 1056.0], [806.0, 1030.0], [836.0, 1010.0], [864.0, 1008.0], [870.0, 1020.0], [888.0, 1016.0], [886.0, 1000.0], [900.0, 974.0], [952.0, 938.0], [970.0, 936.0], [976.0, 910.0], [998.0, 894.0], [1004.0, 882.0], [1016.0, 878.0], [1022.0, 800.0], [1044.0, 774.0], [1044.0, 766.0], [1036.0, 766.0], [990.0, 786.0], [976.0, 762.0], [970.0, 762.0], [964.0, 776.0], [968.0, 800.0], [956.0, 804.0], [928.0, 764.0], [912.0, 770.0], [898.0, 760.0], [896.0, 750.0], [888.0, 750.0], [842.0, 786.0], [826.0, 786.0], [830.0, 744.0], [820.0, 740.0], [818.0, 730.0], [830.0, 704.0], [802.0, 646.0], [794.0, 664.0], [766.0, 678.0], [724.0, 678.0], [718.0, 660.0], [702.0, 642.0], [680.0, 630.0], [676.0, 616.0], [666.0, 614.0], [682.0, 596.0], [684.0, 580.0], [674.0, 574.0], [680.0, 564.0], [674.0, 558.0], [658.0, 562.0], [652.0, 544.0], [640.0, 534.0], [644.0, 522.0], [630.0, 518.0], [630.0, 510.0], [638.0, 506.0], [634.0, 492.0], [650.0, 488.0], [650.0, 460.0], [674.0, 424.0], [688.0, 418.0], [690.0, 382.0], [706.0, 354.0], [732.0, 350.0], [756.0, 370.0], [768.0, 370.0], [794.0, 346.0], [806.0, 322.0], [818.0, 328.0], [848.0, 328.0], [868.0, 310.0], [874.0, 286.0], [866.0, 254.0], [844.0, 230.0], [844.0, 220.0], [866.0, 216.0], [872.0, 210.0], [872.0, 194.0], [854.0, 182.0], [846.0, 194.0], [814.0, 202.0], [794.0, 220.0], [788.0, 236.0], [782.0, 236.0], [778.0, 220.0], [768.0, 210.0], [768.0, 230.0], [750.0, 216.0], [710.0, 216.0], [676.0, 228.0], [634.0, 216.0], [616.0, 204.0], [618.0, 182.0], [600.0, 160.0], [614.0, 150.0], [620.0, 136.0], [570.0, 124.0], [542.0, 104.0], [542.0, 96.0], [566.0, 86.0], [570.0, 74.0], [588.0, 72.0], [620.0, 38.0], [652.0, 44.0], [644.0, 24.0], [652.0, 16.0], [684.0, 8.0], [696.0, -4.0], [716.0, -10.0], [740.0, -4.0], [732.0, 34.0], [738.0, 48.0], [828.0, 54.0], [840.0, 30.0], [860.0, 32.0], [854.0, 18.0], [824.0, 16.0], [826.0, 2.0], [846.0, 4.0], [826.0, -20.0], [826.0, -36.0], [836.0, -54.0], [820.0, -60.0], [798.0, -52.0], [748.0, -66.0], [724.0, -128.0], [216.0, -128.0]], [[4124.0, 4134.0], [4118.0, 4136.0], [4120.0, 4130.0], [4126.0, 4130.0], [4124.0, 4134.0]], [[4086.0, 4128.0], [4074.0, 4130.0], [4072.0, 4122.0], [4086.0, 4118.0], [4086.0, 4128.0]], [[4068.0, 4106.0], [4068.0, 4112.0], [4060.0, 4112.0], [4060.0, 4104.0], [4068.0, 4106.0]], [[1032.0, 4014.0], [1026.0, 4014.0], [1026.0, 4008.0], [1032.0, 4014.0]], [[1122.0, 3162.0], [1144.0, 3186.0], [1142.0, 3198.0], [1124.0, 3204.0], [1108.0, 3218.0], [1096.0, 3212.0], [1090.0, 3176.0], [1098.0, 3168.0], [1098.0, 3156.0], [1122.0, 3162.0]], [[1088.0, 3124.0], [1092.0, 3134.0], [1074.0, 3138.0], [1062.0, 3126.0], [1064.0, 3118.0], [1088.0, 3124.0]], [[1054.0, 3114.0], [1038.0, 3114.0], [1038.0, 3108.0], [1050.0, 3108.0], [1054.0, 3114.0]], [[1014.0, 3104.0], [994.0, 3102.0], [994.0, 3088.0], [1006.0, 3086.0], [1014.0, 3104.0]], [[940.0, 3068.0], [934.0, 3076.0], [926.0, 3074.0], [922.0, 3062.0], [940.0, 3058.0], [940.0, 3068.0]], [[3042.0, 2702.0], [3042.0, 2710.0], [3034.0, 2710.0], [3022.0, 2694.0], [3030.0, 2690.0], [3042.0, 2702.0]], [[2598.0, 1566.0], [2590.0, 1566.0], [2588.0, 1558.0], [2594.0, 1554.0], [2600.0, 1554.0], [2598.0, 1566.0]], [[158.0, 1326.0], [170.0, 1326.0], [168.0, 1334.0], [142.0, 1342.0], [140.0, 1336.0], [150.0, 1318.0], [158.0, 1326.0]], [[100.0, 1326.0], [98.0, 1340.0], [90.0, 1340.0], [84.0, 1322.0], [100.0, 1326.0]], [[130.0, 1336.0], [120.0, 1338.0], [128.0, 1326.0], [130.0, 1336.0]], [[-12.0, 1318.0], [-14.0, 1324.0], [-22.0, 1324.0], [-18.0, 1314.0], [-12.0, 1318.0]], [[-112.0, 1318.0], [-116.0, 1320.0], [-110.0, 1312.0], [-112.0, 1318.0]], [[182.0, 1314.0], [174.0, 1316.0], [174.0, 1310.0], [182.0, 1314.0]], [[272.0, 1288.0], [266.0, 1308.0], [234.0, 1312.0], [248.0, 1304.0], [260.0, 1286.0], [272.0, 1288.0]], [[300.0, 1312.0], [278.0, 1308.0], [296.0, 1306.0], [300.0, 1312.0]], [[2172.0, 1156.0], [2180.0, 1162.0], [2200.0, 1160.0], [2188.0, 1196.0], [2188.0, 1222.0], [2200.0, 1232.0], [2198.0, 1260.0], [2208.0, 1264.0], [2206.0, 1278.0], [2226.0, 1294.0], [2228.0, 1310.0], [2208.0, 1298.0], [2202.0, 1282.0], [2160.0, 1236.0], [2156.0, 1210.0], [2142.0, 1202.0], [2132.0, 1178.0], [2136.0, 1152.0], [2172.0, 1156.0]], [[348.0, 1290.0], [346.0, 1296.0], [338.0, 1294.0], [342.0, 1288.0], [348.0, 1290.0]], [[428.0, 1270.0], [420.0, 1272.0], [420.0, 1266.0], [428.0, 1270.0]], [[610.0, 1170.0], [626.0, 1168.0], [626.0, 1186.0], [616.0, 1192.0], [604.0, 1206.0], [588.0, 1208.0], [568.0, 1222.0], [552.0, 1214.0], [536.0, 1222.0], [518.0, 1244.0], [506.0, 1246.0], [512.0, 1224.0], [526.0, 1220.0], [532.0, 1202.0], [546.0, 1198.0], [554.0, 1212.0], [580.0, 1206.0], [590.0, 1188.0], [586.0, 1172.0], [596.0, 1164.0], [608.0, 1164.0], [610.0, 1170.0]], [[648.0, 1152.0], [642.0, 1160.0], [632.0, 1158.0], [636.0, 1148.0], [648.0, 1152.0]], [[2240.0, 1118.0], [2238.0, 1126.0], [2232.0, 1124.0], [2234.0, 1116.0], [2240.0, 1118.0]], [[806.0, 1096.0], [802.0, 1090.0], [808.0, 1090.0], [806.0, 1096.0]], [[908.0, 1080.0], [906.0, 1076.0], [914.0, 1072.0], [908.0, 1080.0]], [[894.0, 1060.0], [884.0, 1072.0], [872.0, 1070.0], [876.0, 1054.0], [894.0, 1060.0]], [[2024.0, 826.0], [2052.0, 836.0], [2048.0, 856.0], [2056.0, 876.0], [2054.0, 904.0], [2066.0, 948.0], [2062.0, 986.0], [2052.0, 980.0], [2044.0, 954.0], [2030.0, 938.0], [2028.0, 912.0], [2020.0, 922.0], [2010.0, 922.0], [2012.0, 894.0], [1976.0, 846.0], [1976.0, 834.0], [1988.0, 820.0], [2016.0, 816.0], [2024.0, 826.0]]];
-testOutput["issue35"]["triangles"]=[595, 594, 593, 592, 591, 590, 588, 587, 586, 585, 584, 583, 581, 580, 579, 578, 577, 576, 576, 575, 574, 572, 571, 570, 570, 569, 568, 568, 567, 566, 566, 565, 564, 564, 563, 562, 560, 559, 558, 557, 556, 555, 555, 554, 553, 552, 551, 550, 550, 549, 548, 548, 547, 546, 545, 544, 543, 542, 541, 540, 540, 539, 538, 535, 534, 533, 531, 530, 529, 528, 527, 526, 524, 523, 522, 521, 520, 519, 519, 518, 517, 517, 516, 515, 515, 514, 513, 513, 512, 511, 511, 510, 509, 509, 508, 507, 505, 504, 503, 500, 499, 498, 498, 497, 496, 496, 495, 494, 491, 785, 784, 488, 487, 486, 486, 485, 484, 484, 789, 791, 790, 483, 482, 481, 480, 793, 794, 793, 480, 480, 798, 797, 799, 798, 480, 480, 479, 478, 476, 475, 474, 474, 473, 472, 472, 471, 470, 469, 468, 467, 466, 465, 464, 464, 463, 462, 462, 461, 460, 459, 458, 457, 457, 456, 455, 455, 454, 816, 815, 814, 813, 812, 811, 810, 810, 809, 808, 806, 805, 804, 804, 803, 802, 802, 819, 818, 817, 816, 454, 453, 452, 451, 450, 449, 448, 447, 446, 445, 444, 443, 442, 441, 440, 439, 439, 438, 437, 436, 435, 434, 433, 432, 431, 431, 430, 429, 426, 425, 424, 421, 420, 419, 415, 414, 413, 413, 412, 411, 411, 410, 409, 408, 407, 406, 405, 404, 403, 403, 402, 401, 401, 400, 399, 398, 397, 396, 396, 395, 394, 394, 393, 392, 392, 391, 390, 388, 387, 386, 386, 385, 384, 384, 383, 382, 382, 381, 380, 380, 379, 378, 376, 375, 374, 373, 372, 371, 370, 369, 368, 367, 366, 365, 362, 361, 360, 359, 358, 357, 357, 356, 355, 355, 354, 353, 353, 352, 351, 351, 350, 349, 349, 348, 347, 345, 344, 343, 341, 340, 339, 339, 338, 337, 336, 335, 334, 334, 333, 332, 331, 330, 329, 328, 327, 326, 323, 322, 321, 321, 320, 319, 317, 316, 315, 315, 314, 313, 313, 312, 311, 310, 309, 308, 308, 307, 306, 306, 305, 304, 304, 303, 302, 301, 300, 299, 298, 297, 296, 295, 294, 293, 292, 291, 290, 290, 289, 288, 287, 286, 285, 285, 284, 283, 280, 279, 278, 277, 276, 275, 275, 274, 273, 270, 269, 268, 268, 267, 266, 266, 265, 264, 264, 263, 262, 262, 261, 260, 260, 259, 258, 686, 685, 255, 255, 254, 253, 252, 251, 250, 250, 249, 248, 247, 246, 245, 244, 243, 242, 242, 241, 240, 240, 239, 238, 238, 237, 236, 236, 235, 234, 232, 231, 230, 230, 229, 228, 228, 227, 226, 224, 223, 222, 221, 220, 219, 219, 218, 217, 217, 216, 215, 215, 214, 213, 212, 211, 210, 209, 208, 207, 207, 206, 205, 205, 204, 203, 202, 201, 200, 200, 199, 198, 198, 197, 196, 196, 195, 194, 194, 193, 192, 192, 191, 190, 188, 187, 186, 186, 185, 184, 184, 183, 182, 180, 179, 178, 175, 174, 173, 172, 171, 170, 169, 168, 167, 166, 165, 164, 164, 163, 162, 162, 161, 160, 160, 159, 680, 681, 680, 159, 158, 157, 156, 156, 155, 154, 154, 153, 152, 152, 151, 150, 150, 149, 148, 147, 146, 145, 145, 144, 143, 143, 142, 141, 141, 140, 139, 139, 138, 137, 136, 135, 134, 133, 132, 131, 131, 130, 129, 129, 128, 127, 126, 125, 124, 123, 122, 121, 119, 118, 117, 116, 115, 114, 114, 113, 112, 112, 111, 110, 109, 108, 107, 105, 104, 103, 103, 102, 101, 100, 99, 98, 98, 97, 96, 96, 95, 94, 92, 91, 90, 89, 88, 711, 711, 643, 642, 644, 643, 711, 711, 674, 673, 673, 651, 650, 649, 648, 647, 653, 652, 651, 672, 668, 667, 667, 658, 657, 667, 663, 662, 664, 663, 667, 669, 668, 672, 675, 674, 711, 702, 704, 693, 690, 695, 694, 694, 693, 704, 712, 711, 88, 88, 716, 715, 717, 716, 720, 720, 719, 725, 726, 725, 719, 719, 749, 748, 750, 749, 719, 88, 754, 753, 755, 754, 88, 765, 764, 763, 762, 761, 760, 760, 759, 758, 743, 742, 741, 741, 740, 739, 737, 736, 735, 735, 734, 733, 733, 732, 731, 730, 729, 746, 745, 744, 757, 757, 777, 776, 774, 773, 772, 772, 771, 770, 769, 768, 767, 88, 87, 86, 86, 85, 84, 82, 81, 80, 80, 79, 78, 77, 76, 75, 75, 74, 73, 71, 70, 69, 69, 68, 67, 66, 65, 64, 63, 62, 61, 61, 60, 59, 58, 57, 56, 55, 54, 53, 53, 52, 51, 50, 49, 48, 48, 47, 46, 45, 44, 43, 42, 41, 40, 37, 36, 35, 34, 33, 32, 32, 31, 30, 28, 27, 26, 26, 25, 24, 22, 21, 20, 20, 19, 18, 16, 15, 14, 14, 13, 12, 12, 11, 10, 9, 8, 7, 7, 6, 5, 5, 4, 3, 2, 1, 625, 625, 624, 623, 622, 621, 620, 618, 617, 616, 615, 614, 613, 612, 611, 610, 610, 609, 608, 606, 605, 604, 603, 602, 601, 601, 600, 599, 599, 598, 597, 595, 593, 592, 588, 586, 585, 585, 583, 582, 581, 579, 578, 578, 576, 574, 572, 570, 568, 566, 564, 562, 560, 558, 557, 548, 546, 545, 545, 543, 542, 540, 538, 537, 536, 535, 533, 532, 531, 529, 528, 526, 525, 525, 524, 522, 521, 519, 517, 517, 515, 513, 513, 511, 509, 509, 507, 506, 506, 505, 503, 500, 498, 496, 488, 486, 484, 790, 482, 481, 481, 793, 795, 794, 480, 797, 480, 478, 477, 476, 474, 472, 469, 467, 466, 464, 462, 460, 460, 459, 457, 457, 455, 816, 813, 812, 810, 806, 804, 802, 817, 454, 453, 450, 448, 447, 441, 439, 437, 436, 434, 433, 431, 429, 428, 426, 424, 423, 421, 419, 418, 415, 413, 411, 408, 406, 405, 403, 401, 399, 399, 398, 396, 394, 392, 390, 389, 388, 386, 386, 384, 382, 382, 380, 378, 377, 376, 374, 373, 371, 370, 368, 367, 365, 363, 362, 360, 357, 355, 353, 353, 351, 349, 349, 347, 346, 345, 343, 342, 339, 337, 336, 334, 332, 331, 331, 329, 328, 323, 321, 319, 317, 315, 313, 310, 308, 306, 298, 296, 295, 290, 288, 287, 285, 283, 282, 281, 280, 278, 277, 275, 273, 268, 266, 264, 256, 255, 685, 687, 686, 255, 253, 252, 250, 250, 248, 247, 247, 245, 244, 238, 236, 234, 232, 230, 228, 228, 226, 225, 224, 222, 221, 219, 217, 215, 215, 213, 212, 209, 207, 205, 205, 203, 202, 200, 198, 196, 194, 192, 190, 186, 184, 182, 180, 178, 177, 172, 170, 169, 166, 164, 162, 162, 160, 680, 681, 159, 158, 158, 156, 154, 154, 152, 150, 148, 147, 145, 141, 139, 137, 136, 134, 133, 133, 131, 129, 129, 127, 126, 126, 124, 123, 117, 116, 114, 112, 110, 109, 105, 103, 101, 100, 98, 96, 96, 94, 93, 89, 711, 642, 642, 633, 632, 634, 633, 642, 639, 642, 644, 644, 711, 673, 653, 651, 673, 673, 672, 667, 658, 667, 662, 664, 667, 670, 669, 672, 676, 675, 711, 707, 702, 693, 692, 694, 704, 703, 708, 707, 711, 712, 88, 715, 715, 717, 720, 720, 725, 727, 726, 719, 748, 750, 719, 723, 722, 721, 720, 720, 716, 88, 755, 88, 766, 765, 763, 762, 758, 757, 744, 737, 735, 733, 733, 731, 730, 745, 757, 776, 774, 772, 770, 767, 766, 88, 83, 82, 80, 77, 75, 73, 66, 64, 63, 63, 61, 59, 55, 53, 51, 42, 40, 39, 38, 37, 35, 32, 30, 29, 28, 26, 24, 22, 20, 18, 14, 12, 10, 10, 9, 7, 7, 5, 3, 3, 2, 625, 625, 623, 622, 622, 620, 619, 618, 616, 615, 615, 613, 612, 612, 610, 608, 604, 603, 601, 596, 595, 592, 588, 585, 582, 578, 574, 573, 572, 568, 566, 566, 562, 561, 548, 545, 542, 537, 536, 533, 528, 525, 522, 521, 517, 513, 513, 509, 506, 506, 503, 502, 500, 496, 494, 488, 484, 791, 790, 481, 795, 794, 797, 800, 480, 477, 476, 470, 469, 466, 460, 457, 816, 813, 810, 808, 817, 453, 451, 450, 447, 445, 441, 437, 436, 436, 433, 431, 431, 428, 427, 422, 421, 418, 415, 411, 409, 405, 403, 399, 399, 396, 394, 389, 386, 382, 382, 378, 377, 377, 374, 373, 357, 353, 349, 341, 339, 336, 334, 331, 328, 324, 323, 319, 318, 317, 313, 311, 310, 306, 298, 295, 293, 292, 290, 287, 287, 285, 282, 278, 277, 273, 268, 264, 262, 256, 685, 684, 687, 255, 253, 253, 250, 247, 247, 244, 242, 232, 228, 225, 225, 224, 221, 219, 215, 212, 209, 205, 202, 200, 196, 194, 194, 190, 189, 188, 186, 182, 173, 172, 169, 162, 680, 679, 682, 681, 158, 158, 154, 150, 148, 145, 143, 143, 141, 137, 136, 133, 129, 129, 126, 123, 106, 105, 101, 96, 93, 92, 90, 89, 642, 644, 673, 650, 654, 653, 673, 673, 667, 657, 659, 658, 662, 664, 670, 669, 669, 676, 675, 675, 707, 706, 697, 702, 692, 703, 702, 697, 699, 706, 709, 708, 711, 713, 715, 720, 727, 727, 726, 748, 750, 723, 722, 722, 720, 88, 755, 766, 765, 760, 758, 744, 737, 733, 730, 745, 776, 775, 775, 774, 770, 769, 767, 88, 77, 73, 72, 66, 63, 59, 56, 55, 51, 43, 42, 39, 38, 35, 34, 28, 24, 23, 23, 22, 18, 14, 10, 7, 3, 625, 622, 622, 619, 618, 615, 612, 608, 596, 592, 590, 581, 578, 573, 573, 572, 566, 550, 548, 542, 537, 533, 532, 529, 528, 522, 522, 521, 513, 513, 506, 502, 501, 500, 494, 489, 488, 791, 791, 790, 795, 799, 480, 476, 470, 466, 464, 464, 460, 816, 817, 451, 450, 450, 445, 444, 436, 431, 427, 405, 399, 394, 382, 377, 373, 359, 357, 349, 341, 336, 334, 325, 324, 319, 318, 313, 311, 299, 298, 293, 292, 287, 282, 281, 278, 273, 256, 684, 688, 687, 253, 247, 247, 242, 240, 233, 232, 225, 209, 202, 200, 200, 194, 189, 188, 182, 181, 173, 169, 167, 166, 162, 679, 682, 158, 150, 148, 143, 137, 136, 129, 123, 107, 106, 101, 100, 96, 92, 90, 642, 627, 627, 642, 632, 644, 650, 649, 654, 673, 657, 659, 662, 665, 669, 675, 706, 698, 697, 692, 703, 697, 700, 699, 709, 708, 708, 713, 712, 750, 722, 88, 762, 760, 744, 775, 770, 769, 78, 77, 72, 66, 59, 58, 56, 51, 50, 43, 39, 38, 38, 34, 32, 23, 18, 17, 16, 14, 7, 3, 622, 618, 618, 615, 608, 581, 573, 566, 552, 550, 542, 537, 532, 529, 522, 513, 502, 489, 791, 795, 799, 476, 472, 470, 464, 816, 450, 444, 442, 436, 427, 426, 408, 405, 394, 389, 382, 373, 359, 349, 346, 342, 341, 334, 325, 319, 318, 318, 311, 306, 299, 293, 292, 292, 282, 281, 281, 273, 272, 687, 247, 240, 209, 200, 189, 189, 188, 181, 175, 173, 167, 166, 679, 678, 678, 682, 150, 148, 137, 136, 136, 123, 121, 107, 101, 100, 100, 92, 90, 90, 627, 630, 628, 627, 632, 639, 644, 649, 654, 657, 656, 660, 659, 665, 664, 669, 706, 694, 703, 700, 708, 712, 715, 751, 750, 88, 765, 762, 744, 775, 769, 88, 80, 78, 72, 67, 66, 58, 56, 50, 48, 43, 38, 32, 16, 7, 3, 618, 608, 607, 582, 581, 566, 529, 522, 502, 800, 799, 472, 472, 470, 816, 450, 442, 441, 441, 436, 426, 409, 408, 394, 389, 373, 370, 360, 359, 346, 342, 334, 328, 299, 292, 281, 281, 272, 271, 688, 687, 240, 210, 209, 189, 167, 166, 678, 678, 150, 148, 148, 136, 121, 109, 107, 100, 90, 630, 629, 628, 632, 635, 642, 639, 638, 640, 639, 649, 654, 656, 660, 660, 665, 664, 694, 700, 699, 699, 708, 715, 751, 88, 753, 755, 765, 744, 775, 88, 780, 781, 780, 88, 80, 72, 71, 67, 58, 56, 43, 32, 29, 17, 16, 3, 3, 618, 607, 588, 582, 566, 537, 529, 502, 794, 800, 472, 472, 816, 815, 450, 441, 426, 409, 394, 390, 389, 370, 368, 360, 346, 345, 299, 281, 271, 688, 240, 238, 175, 167, 678, 678, 148, 121, 109, 100, 90, 629, 628, 635, 634, 642, 638, 640, 649, 647, 646, 654, 660, 660, 664, 706, 706, 699, 698, 694, 699, 715, 748, 751, 753, 753, 755, 744, 775, 780, 779, 782, 781, 88, 83, 80, 71, 69, 67, 56, 23, 17, 3, 3, 607, 606, 589, 588, 566, 540, 537, 502, 794, 472, 815, 450, 426, 423, 409, 390, 389, 360, 345, 342, 299, 271, 270, 256, 688, 238, 175, 678, 121, 112, 109, 90, 629, 635, 634, 634, 638, 637, 640, 647, 646, 646, 660, 706, 690, 694, 715, 727, 748, 753, 753, 744, 743, 745, 775, 779, 782, 88, 86, 83, 71, 69, 69, 56, 48, 23, 3, 606, 589, 566, 561, 542, 540, 502, 795, 794, 815, 450, 423, 422, 415, 409, 389, 257, 256, 238, 176, 175, 121, 114, 112, 90, 629, 634, 637, 640, 646, 706, 691, 690, 715, 727, 753, 743, 745, 779, 782, 782, 86, 84, 83, 69, 48, 28, 23, 606, 590, 589, 561, 542, 502, 501, 489, 795, 815, 450, 422, 418, 415, 389, 368, 257, 238, 234, 177, 176, 121, 117, 114, 90, 629, 637, 640, 640, 706, 698, 691, 715, 727, 727, 743, 741, 782, 84, 83, 83, 48, 46, 29, 28, 606, 596, 590, 561, 542, 501, 494, 489, 815, 813, 450, 418, 417, 416, 415, 368, 258, 257, 234, 177, 121, 120, 119, 117, 90, 629, 640, 698, 692, 691, 727, 727, 741, 739, 782, 83, 46, 29, 606, 604, 596, 561, 560, 552, 542, 494, 489, 813, 808, 450, 417, 416, 260, 258, 234, 177, 120, 119, 119, 90, 629, 629, 698, 692, 692, 727, 739, 782, 46, 45, 29, 604, 601, 596, 560, 557, 552, 494, 493, 489, 808, 807, 450, 416, 368, 262, 260, 234, 177, 119, 629, 692, 739, 738, 782, 45, 43, 43, 29, 601, 596, 557, 555, 817, 450, 368, 262, 234, 233, 180, 177, 629, 43, 601, 599, 596, 555, 553, 818, 817, 368, 268, 262, 233, 181, 180, 629, 43, 599, 597, 596, 553, 552, 818, 368, 365, 268, 233, 225, 189, 181, 629, 43, 597, 596, 596, 552, 493, 818, 365, 364, 270, 268, 225, 189, 629, 692, 782, 43, 596, 802, 818, 364, 299, 270, 225, 210, 189, 692, 782, 596, 493, 802, 364, 363, 299, 225, 221, 212, 210, 692, 782, 493, 492, 806, 802, 363, 219, 212, 692, 782, 492, 491, 786, 785, 491, 491, 490, 489, 806, 363, 360, 221, 219, 692, 745, 782, 491, 786, 491, 489, 806, 360, 342, 221, 692, 738, 745, 491, 784, 807, 806, 342, 299, 221, 738, 746, 745, 784, 489, 807, 342, 301, 299, 738, 730, 746, 784, 489, 342, 328, 302, 301, 738, 737, 730, 784, 489, 328, 326, 302, 738, 737, 737, 784, 787, 489, 326, 325, 302, 737, 787, 786, 489, 325, 304, 302, 787, 786, 325, 318, 306, 304, 787, 786, 318, 306, 306, 787, 786];
+testOutput["issue35"]["triangles"]=[673, 651, 650, 649, 648, 647, 653, 652, 651, 672, 668, 667, 667, 658, 657, 667, 663, 662, 664, 663, 667, 669, 668, 672, 702, 704, 693, 690, 695, 694, 694, 693, 704, 88, 716, 715, 717, 716, 720, 720, 719, 725, 726, 725, 719, 719, 749, 748, 750, 749, 719, 88, 754, 753, 755, 754, 88, 765, 764, 763, 762, 761, 760, 760, 759, 758, 743, 742, 741, 741, 740, 739, 737, 736, 735, 735, 734, 733, 733, 732, 731, 730, 729, 746, 745, 744, 757, 757, 777, 776, 774, 773, 772, 772, 771, 770, 769, 768, 767, 88, 87, 86, 86, 85, 84, 82, 81, 80, 80, 79, 78, 77, 76, 75, 75, 74, 73, 71, 70, 69, 69, 68, 67, 66, 65, 64, 63, 62, 61, 61, 60, 59, 58, 57, 56, 55, 54, 53, 53, 52, 51, 50, 49, 48, 48, 47, 46, 45, 44, 43, 42, 41, 40, 37, 36, 35, 34, 33, 32, 32, 31, 30, 28, 27, 26, 26, 25, 24, 22, 21, 20, 20, 19, 18, 16, 15, 14, 14, 13, 12, 12, 11, 10, 9, 8, 7, 7, 6, 5, 5, 4, 3, 2, 1, 625, 625, 624, 623, 622, 621, 620, 618, 617, 616, 615, 614, 613, 612, 611, 610, 610, 609, 608, 606, 605, 604, 603, 602, 601, 601, 600, 599, 599, 598, 597, 595, 594, 593, 592, 591, 590, 588, 587, 586, 585, 584, 583, 581, 580, 579, 578, 577, 576, 576, 575, 574, 572, 571, 570, 570, 569, 568, 568, 567, 566, 566, 565, 564, 564, 563, 562, 560, 559, 558, 557, 556, 555, 555, 554, 553, 552, 551, 550, 550, 549, 548, 548, 547, 546, 545, 544, 543, 542, 541, 540, 540, 539, 538, 535, 534, 533, 531, 530, 529, 528, 527, 526, 524, 523, 522, 521, 520, 519, 519, 518, 517, 517, 516, 515, 515, 514, 513, 513, 512, 511, 511, 510, 509, 509, 508, 507, 505, 504, 503, 500, 499, 498, 498, 497, 496, 496, 495, 494, 491, 785, 784, 488, 487, 486, 486, 485, 484, 484, 789, 791, 790, 483, 482, 481, 480, 793, 794, 793, 480, 480, 798, 797, 799, 798, 480, 480, 479, 478, 476, 475, 474, 474, 473, 472, 472, 471, 470, 469, 468, 467, 466, 465, 464, 464, 463, 462, 462, 461, 460, 459, 458, 457, 457, 456, 455, 455, 454, 816, 815, 814, 813, 812, 811, 810, 810, 809, 808, 806, 805, 804, 804, 803, 802, 802, 819, 818, 817, 816, 454, 453, 452, 451, 450, 449, 448, 447, 446, 445, 444, 443, 442, 441, 440, 439, 439, 438, 437, 436, 435, 434, 433, 432, 431, 431, 430, 429, 426, 425, 424, 421, 420, 419, 415, 414, 413, 413, 412, 411, 411, 410, 409, 408, 407, 406, 405, 404, 403, 403, 402, 401, 401, 400, 399, 398, 397, 396, 396, 395, 394, 394, 393, 392, 392, 391, 390, 388, 387, 386, 386, 385, 384, 384, 383, 382, 382, 381, 380, 380, 379, 378, 376, 375, 374, 373, 372, 371, 370, 369, 368, 367, 366, 365, 362, 361, 360, 359, 358, 357, 357, 356, 355, 355, 354, 353, 353, 352, 351, 351, 350, 349, 349, 348, 347, 345, 344, 343, 341, 340, 339, 339, 338, 337, 336, 335, 334, 334, 333, 332, 331, 330, 329, 328, 327, 326, 323, 322, 321, 321, 320, 319, 317, 316, 315, 315, 314, 313, 313, 312, 311, 310, 309, 308, 308, 307, 306, 306, 305, 304, 304, 303, 302, 301, 300, 299, 298, 297, 296, 295, 294, 293, 292, 291, 290, 290, 289, 288, 287, 286, 285, 285, 284, 283, 280, 279, 278, 277, 276, 275, 275, 274, 273, 270, 269, 268, 268, 267, 266, 266, 265, 264, 264, 263, 262, 262, 261, 260, 260, 259, 258, 686, 685, 255, 255, 254, 253, 252, 251, 250, 250, 249, 248, 247, 246, 245, 244, 243, 242, 242, 241, 240, 240, 239, 238, 238, 237, 236, 236, 235, 234, 232, 231, 230, 230, 229, 228, 228, 227, 226, 224, 223, 222, 221, 220, 219, 219, 218, 217, 217, 216, 215, 215, 214, 213, 212, 211, 210, 209, 208, 207, 207, 206, 205, 205, 204, 203, 202, 201, 200, 200, 199, 198, 198, 197, 196, 196, 195, 194, 194, 193, 192, 192, 191, 190, 188, 187, 186, 186, 185, 184, 184, 183, 182, 180, 179, 178, 175, 174, 173, 172, 171, 170, 169, 168, 167, 166, 165, 164, 164, 163, 162, 162, 161, 160, 160, 159, 680, 681, 680, 159, 158, 157, 156, 156, 155, 154, 154, 153, 152, 152, 151, 150, 150, 149, 148, 147, 146, 145, 145, 144, 143, 143, 142, 141, 141, 140, 139, 139, 138, 137, 136, 135, 134, 133, 132, 131, 131, 130, 129, 129, 128, 127, 126, 125, 124, 123, 122, 121, 119, 118, 117, 116, 115, 114, 114, 113, 112, 112, 111, 110, 109, 108, 107, 105, 104, 103, 103, 102, 101, 100, 99, 98, 98, 97, 96, 96, 95, 94, 92, 91, 90, 89, 88, 711, 711, 643, 642, 644, 643, 711, 711, 674, 673, 672, 667, 657, 658, 667, 662, 664, 667, 670, 669, 672, 676, 675, 674, 711, 702, 693, 692, 694, 704, 703, 712, 711, 88, 715, 717, 720, 720, 725, 727, 726, 719, 748, 750, 719, 723, 722, 721, 720, 720, 716, 88, 755, 88, 766, 765, 763, 762, 758, 757, 744, 737, 735, 733, 733, 731, 730, 745, 757, 776, 774, 772, 770, 767, 766, 88, 83, 82, 80, 77, 75, 73, 66, 64, 63, 63, 61, 59, 55, 53, 51, 42, 40, 39, 38, 37, 35, 32, 30, 29, 28, 26, 24, 22, 20, 18, 14, 12, 10, 10, 9, 7, 7, 5, 3, 3, 2, 625, 625, 623, 622, 622, 620, 619, 618, 616, 615, 615, 613, 612, 612, 610, 608, 604, 603, 601, 595, 593, 592, 588, 586, 585, 585, 583, 582, 581, 579, 578, 578, 576, 574, 572, 570, 568, 566, 564, 562, 560, 558, 557, 548, 546, 545, 545, 543, 542, 540, 538, 537, 536, 535, 533, 532, 531, 529, 528, 526, 525, 525, 524, 522, 521, 519, 517, 517, 515, 513, 513, 511, 509, 509, 507, 506, 506, 505, 503, 500, 498, 496, 488, 486, 484, 790, 482, 481, 481, 793, 795, 794, 480, 797, 480, 478, 477, 476, 474, 472, 469, 467, 466, 464, 462, 460, 460, 459, 457, 457, 455, 816, 813, 812, 810, 806, 804, 802, 817, 454, 453, 450, 448, 447, 441, 439, 437, 436, 434, 433, 431, 429, 428, 426, 424, 423, 421, 419, 418, 415, 413, 411, 408, 406, 405, 403, 401, 399, 399, 398, 396, 394, 392, 390, 389, 388, 386, 386, 384, 382, 382, 380, 378, 377, 376, 374, 373, 371, 370, 368, 367, 365, 363, 362, 360, 357, 355, 353, 353, 351, 349, 349, 347, 346, 345, 343, 342, 339, 337, 336, 334, 332, 331, 331, 329, 328, 323, 321, 319, 317, 315, 313, 310, 308, 306, 298, 296, 295, 290, 288, 287, 285, 283, 282, 281, 280, 278, 277, 275, 273, 268, 266, 264, 256, 255, 685, 687, 686, 255, 253, 252, 250, 250, 248, 247, 247, 245, 244, 238, 236, 234, 232, 230, 228, 228, 226, 225, 224, 222, 221, 219, 217, 215, 215, 213, 212, 209, 207, 205, 205, 203, 202, 200, 198, 196, 194, 192, 190, 186, 184, 182, 180, 178, 177, 172, 170, 169, 166, 164, 162, 162, 160, 680, 681, 159, 158, 158, 156, 154, 154, 152, 150, 148, 147, 145, 141, 139, 137, 136, 134, 133, 133, 131, 129, 129, 127, 126, 126, 124, 123, 117, 116, 114, 112, 110, 109, 105, 103, 101, 100, 98, 96, 96, 94, 93, 89, 711, 642, 642, 633, 632, 634, 633, 642, 639, 642, 644, 644, 711, 673, 653, 651, 673, 673, 672, 657, 659, 658, 662, 664, 670, 669, 669, 676, 675, 675, 711, 707, 697, 702, 692, 703, 702, 697, 708, 707, 711, 712, 88, 715, 715, 720, 727, 727, 726, 748, 750, 723, 722, 722, 720, 88, 755, 766, 765, 760, 758, 744, 737, 733, 730, 745, 776, 775, 775, 774, 770, 769, 767, 88, 77, 73, 72, 66, 63, 59, 56, 55, 51, 43, 42, 39, 38, 35, 34, 28, 24, 23, 23, 22, 18, 14, 10, 7, 3, 625, 622, 622, 619, 618, 615, 612, 608, 596, 595, 592, 588, 585, 582, 578, 574, 573, 572, 568, 566, 566, 562, 561, 548, 545, 542, 537, 536, 533, 528, 525, 522, 521, 517, 513, 513, 509, 506, 506, 503, 502, 500, 496, 494, 488, 484, 791, 790, 481, 795, 794, 797, 800, 480, 477, 476, 470, 469, 466, 460, 457, 816, 813, 810, 808, 817, 453, 451, 450, 447, 445, 441, 437, 436, 436, 433, 431, 431, 428, 427, 422, 421, 418, 415, 411, 409, 405, 403, 399, 399, 396, 394, 389, 386, 382, 382, 378, 377, 377, 374, 373, 357, 353, 349, 341, 339, 336, 334, 331, 328, 324, 323, 319, 318, 317, 313, 311, 310, 306, 298, 295, 293, 292, 290, 287, 287, 285, 282, 278, 277, 273, 268, 264, 262, 256, 685, 684, 687, 255, 253, 253, 250, 247, 247, 244, 242, 232, 228, 225, 225, 224, 221, 219, 215, 212, 209, 205, 202, 200, 196, 194, 194, 190, 189, 188, 186, 182, 173, 172, 169, 162, 680, 679, 682, 681, 158, 158, 154, 150, 148, 145, 143, 143, 141, 137, 136, 133, 129, 129, 126, 123, 106, 105, 101, 96, 93, 92, 90, 89, 642, 644, 673, 650, 654, 653, 673, 659, 662, 665, 669, 675, 707, 698, 697, 692, 703, 697, 700, 708, 711, 713, 750, 722, 88, 762, 760, 744, 775, 770, 769, 78, 77, 72, 66, 59, 58, 56, 51, 50, 43, 39, 38, 38, 34, 32, 23, 18, 17, 16, 14, 7, 3, 622, 618, 618, 615, 608, 596, 592, 590, 581, 578, 573, 573, 572, 566, 550, 548, 542, 537, 533, 532, 529, 528, 522, 522, 521, 513, 513, 506, 502, 501, 500, 494, 489, 488, 791, 791, 790, 795, 799, 480, 476, 470, 466, 464, 464, 460, 816, 817, 451, 450, 450, 445, 444, 436, 431, 427, 405, 399, 394, 382, 377, 373, 359, 357, 349, 341, 336, 334, 325, 324, 319, 318, 313, 311, 299, 298, 293, 292, 287, 282, 281, 278, 273, 256, 684, 688, 687, 253, 247, 247, 242, 240, 233, 232, 225, 209, 202, 200, 200, 194, 189, 188, 182, 181, 173, 169, 167, 166, 162, 679, 682, 158, 150, 148, 143, 137, 136, 129, 123, 107, 106, 101, 100, 96, 92, 90, 642, 627, 627, 642, 632, 644, 650, 649, 654, 673, 657, 660, 659, 665, 664, 669, 707, 694, 703, 700, 708, 713, 712, 751, 750, 88, 765, 762, 744, 775, 769, 88, 80, 78, 72, 67, 66, 58, 56, 50, 48, 43, 38, 32, 16, 7, 3, 618, 608, 607, 581, 573, 566, 552, 550, 542, 537, 532, 529, 522, 513, 502, 489, 791, 795, 799, 476, 472, 470, 464, 816, 450, 444, 442, 436, 427, 426, 408, 405, 394, 389, 382, 373, 359, 349, 346, 342, 341, 334, 325, 319, 318, 318, 311, 306, 299, 293, 292, 292, 282, 281, 281, 273, 272, 687, 247, 240, 209, 200, 189, 189, 188, 181, 175, 173, 167, 166, 679, 678, 678, 682, 150, 148, 137, 136, 136, 123, 121, 107, 101, 100, 100, 92, 90, 90, 627, 630, 628, 627, 632, 639, 644, 649, 654, 657, 656, 660, 665, 664, 664, 707, 706, 694, 700, 699, 699, 706, 709, 708, 712, 715, 751, 88, 753, 755, 765, 744, 775, 88, 780, 781, 780, 88, 80, 72, 71, 67, 58, 56, 43, 32, 29, 17, 16, 3, 3, 618, 607, 582, 581, 566, 529, 522, 502, 800, 799, 472, 472, 470, 816, 450, 442, 441, 441, 436, 426, 409, 408, 394, 389, 373, 370, 360, 359, 346, 342, 334, 328, 299, 292, 281, 281, 272, 271, 688, 687, 240, 210, 209, 189, 167, 166, 678, 678, 150, 148, 148, 136, 121, 109, 107, 100, 90, 630, 629, 628, 632, 635, 642, 639, 638, 640, 639, 649, 654, 656, 660, 660, 664, 706, 706, 699, 698, 694, 699, 709, 709, 708, 715, 748, 751, 753, 753, 755, 744, 775, 780, 779, 782, 781, 88, 83, 80, 71, 69, 67, 56, 23, 17, 3, 3, 607, 606, 588, 582, 566, 537, 529, 502, 794, 800, 472, 472, 816, 815, 450, 441, 426, 409, 394, 390, 389, 370, 368, 360, 346, 345, 299, 281, 271, 688, 240, 238, 175, 167, 678, 678, 148, 121, 109, 100, 90, 629, 628, 635, 634, 642, 638, 640, 649, 647, 646, 654, 660, 660, 706, 698, 694, 709, 715, 727, 748, 753, 753, 744, 743, 745, 775, 779, 782, 88, 86, 83, 71, 69, 69, 56, 48, 23, 3, 606, 589, 588, 566, 540, 537, 502, 794, 472, 815, 450, 426, 423, 409, 390, 389, 360, 345, 342, 299, 271, 270, 256, 688, 238, 175, 678, 121, 112, 109, 90, 629, 635, 634, 634, 638, 637, 640, 647, 646, 646, 660, 698, 690, 694, 715, 727, 753, 743, 745, 779, 782, 782, 86, 84, 83, 69, 48, 28, 23, 606, 589, 566, 561, 542, 540, 502, 795, 794, 815, 450, 423, 422, 415, 409, 389, 257, 256, 238, 176, 175, 121, 114, 112, 90, 629, 634, 637, 640, 646, 698, 691, 690, 715, 727, 743, 741, 782, 84, 83, 83, 48, 46, 29, 28, 606, 590, 589, 561, 542, 502, 501, 489, 795, 815, 450, 422, 418, 415, 389, 368, 257, 238, 234, 177, 176, 121, 117, 114, 90, 629, 637, 640, 640, 698, 692, 691, 715, 727, 727, 741, 739, 782, 83, 46, 29, 606, 604, 596, 590, 561, 542, 501, 494, 489, 815, 813, 450, 418, 417, 416, 415, 368, 258, 257, 234, 177, 121, 120, 119, 117, 90, 629, 640, 692, 692, 691, 727, 727, 739, 738, 782, 46, 45, 29, 604, 601, 596, 561, 560, 552, 542, 494, 489, 813, 808, 450, 417, 416, 260, 258, 234, 177, 120, 119, 119, 90, 629, 629, 692, 727, 782, 45, 43, 43, 29, 601, 596, 560, 557, 552, 494, 493, 489, 808, 807, 450, 416, 368, 262, 260, 234, 177, 119, 629, 43, 601, 599, 596, 557, 555, 817, 450, 368, 262, 234, 233, 180, 177, 629, 43, 599, 597, 596, 555, 553, 818, 817, 368, 268, 262, 233, 181, 180, 629, 43, 597, 596, 596, 553, 552, 818, 368, 365, 268, 233, 225, 189, 181, 629, 782, 43, 596, 596, 552, 493, 818, 365, 364, 270, 268, 225, 189, 629, 727, 782, 596, 493, 802, 818, 364, 299, 270, 225, 210, 189, 727, 782, 493, 492, 802, 364, 363, 299, 225, 221, 212, 210, 727, 782, 492, 491, 786, 785, 491, 491, 490, 489, 806, 802, 363, 219, 212, 727, 745, 782, 491, 786, 491, 489, 806, 363, 360, 221, 219, 727, 745, 491, 784, 806, 360, 342, 221, 727, 738, 746, 745, 784, 807, 806, 342, 299, 221, 738, 730, 746, 784, 489, 807, 342, 301, 299, 738, 737, 730, 784, 489, 342, 328, 302, 301, 738, 738, 737, 784, 489, 328, 326, 302, 738, 784, 489, 326, 325, 304, 302, 784, 786, 489, 325, 304, 784, 787, 786, 325, 318, 306, 304, 787, 786, 318, 306, 306, 787, 786];
 testOutput["issue35"]["pass"]=true;
 testOutput["issue35"]["report"]="exp numtri:844\nexp dev:0.00000000000001\nact numtri:844\nact dev:0";
@@ -1,5 +1,5 @@
 testOutput["issue52"]=[];
 testOutput["issue52"]["json"]=[[[1920.0, 552.0], [1904.0, 616.0], [1912.0, 664.0], [1984.0, 672.0], [2008.0, 712.0], [1944.0, 720.0], [1904.0, 760.0], [1896.0, 800.0], [1856.0, 760.0], [1824.0, 768.0], [1824.0, 832.0], [1864.0, 864.0], [1888.0, 864.0], [1904.0, 936.0], [1936.0, 944.0], [1936.0, 1064.0], [1936.0, 1112.0], [1872.0, 1136.0], [1856.0, 1160.0], [1840.0, 1144.0], [1792.0, 1152.0], [1784.0, 1112.0], [1752.0, 1096.0], [1608.0, 1096.0], [1600.0, 1064.0], [1640.0, 1040.0], [1664.0, 992.0], [1640.0, 968.0], [1568.0, 1024.0], [1560.0, 1056.0], [1480.0, 1048.0], [1440.0, 1072.0], [1440.0, 1032.0], [1400.0, 1032.0], [1400.0, 1088.0], [1336.0, 1136.0], [1320.0, 1136.0], [1264.0, 1072.0], [1232.0, 1080.0], [1240.0, 1104.0], [1200.0, 1096.0], [1232.0, 1048.0], [1272.0, 1032.0], [1272.0, 1000.0], [1232.0, 1024.0], [1176.0, 1024.0], [1176.0, 1000.0], [1248.0, 952.0], [1344.0, 944.0], [1352.0, 904.0], [1424.0, 880.0], [1448.0, 848.0], [1496.0, 840.0], [1512.0, 800.0], [1568.0, 760.0], [1616.0, 752.0], [1640.0, 640.0], [1680.0, 600.0], [1736.0, 592.0], [1776.0, 560.0], [1776.0, 536.0], [1840.0, 464.0], [1848.0, 400.0], [1888.0, 328.0], [1952.0, 264.0], [2000.0, 240.0], [2040.0, 240.0], [2040.0, 264.0], [1968.0, 376.0], [1912.0, 424.0], [1936.0, 512.0], [1920.0, 528.0], [1880.0, 528.0], [1872.0, 552.0], [1920.0, 552.0]], [[1608.0, 800.0], [1576.0, 848.0], [1520.0, 840.0], [1512.0, 872.0], [1456.0, 904.0], [1440.0, 952.0], [1528.0, 936.0], [1552.0, 912.0], [1584.0, 912.0], [1608.0, 880.0], [1664.0, 864.0], [1680.0, 816.0], [1656.0, 776.0], [1608.0, 800.0]], [[1720.0, 792.0], [1736.0, 792.0], [1720.0, 780.0], [1720.0, 792.0]], [[1656.0, 728.0], [1670.0, 752.0], [1672.0, 728.0], [1656.0, 728.0]], [[1712.0, 680.0], [1696.0, 720.0], [1720.0, 728.0], [1736.0, 704.0], [1736.0, 680.0], [1712.0, 680.0]], [[1968.0, 712.0], [2000.0, 712.0], [1968.0, 688.0], [1968.0, 712.0]]];
-testOutput["issue52"]["triangles"]=[6, 7, 8, 11, 12, 13, 13, 14, 15, 15, 16, 17, 17, 18, 19, 19, 20, 21, 22, 23, 24, 28, 29, 30, 30, 31, 32, 33, 34, 35, 35, 36, 37, 38, 39, 40, 40, 41, 42, 43, 44, 45, 45, 46, 47, 81, 82, 83, 83, 84, 85, 75, 76, 77, 77, 78, 79, 48, 49, 50, 50, 51, 52, 52, 53, 54, 99, 103, 104, 97, 98, 93, 56, 57, 58, 59, 60, 61, 61, 62, 63, 63, 64, 65, 65, 66, 67, 67, 68, 69, 69, 70, 71, 73, 0, 1, 5, 6, 8, 10, 11, 13, 13, 15, 17, 17, 19, 21, 22, 24, 25, 27, 28, 30, 33, 35, 37, 38, 40, 42, 43, 45, 47, 87, 75, 77, 48, 50, 52, 52, 54, 55, 55, 93, 94, 95, 93, 98, 97, 93, 55, 55, 56, 58, 61, 63, 65, 65, 67, 69, 69, 71, 72, 73, 1, 2, 5, 8, 9, 10, 13, 17, 17, 21, 22, 22, 25, 26, 27, 30, 32, 33, 37, 38, 43, 47, 80, 80, 47, 48, 52, 55, 94, 94, 95, 98, 97, 55, 58, 59, 61, 65, 69, 72, 73, 10, 17, 22, 27, 32, 33, 33, 38, 42, 42, 43, 80, 79, 80, 48, 94, 98, 99, 105, 103, 99, 101, 97, 58, 59, 65, 69, 9, 10, 22, 27, 33, 42, 42, 80, 81, 77, 79, 48, 52, 94, 99, 105, 99, 100, 101, 58, 59, 59, 69, 73, 9, 22, 26, 27, 42, 81, 77, 48, 52, 105, 100, 101, 101, 59, 73, 9, 26, 27, 27, 81, 83, 87, 77, 52, 101, 73, 2, 27, 83, 85, 87, 52, 99, 105, 101, 2, 9, 27, 85, 86, 87, 89, 91, 89, 87, 105, 2, 3, 9, 85, 86, 86, 89, 90, 91, 87, 99, 104, 105, 3, 5, 9, 86, 90, 91, 99, 104, 3, 4, 5, 86, 90, 99, 104, 4, 5, 90, 99, 99, 4, 5];
+testOutput["issue52"]["triangles"]=[99, 103, 104, 97, 98, 93, 56, 57, 58, 59, 60, 61, 61, 62, 63, 63, 64, 65, 65, 66, 67, 67, 68, 69, 69, 70, 71, 73, 0, 1, 6, 7, 8, 11, 12, 13, 13, 14, 15, 15, 16, 17, 17, 18, 19, 19, 20, 21, 22, 23, 24, 28, 29, 30, 30, 31, 32, 33, 34, 35, 35, 36, 37, 38, 39, 40, 40, 41, 42, 43, 44, 45, 45, 46, 47, 81, 82, 83, 83, 84, 85, 75, 76, 77, 77, 78, 79, 48, 49, 50, 50, 51, 52, 52, 53, 54, 55, 93, 94, 95, 93, 98, 97, 93, 55, 55, 56, 58, 61, 63, 65, 65, 67, 69, 69, 71, 72, 73, 1, 2, 5, 6, 8, 10, 11, 13, 13, 15, 17, 17, 19, 21, 22, 24, 25, 27, 28, 30, 33, 35, 37, 38, 40, 42, 43, 45, 47, 87, 75, 77, 48, 50, 52, 52, 54, 55, 94, 95, 98, 97, 55, 58, 59, 61, 65, 69, 72, 73, 5, 8, 9, 10, 13, 17, 17, 21, 22, 22, 25, 26, 27, 30, 32, 33, 37, 38, 43, 47, 80, 80, 47, 48, 52, 55, 94, 94, 98, 99, 105, 103, 99, 101, 97, 58, 59, 65, 69, 10, 17, 22, 27, 32, 33, 33, 38, 42, 42, 43, 80, 79, 80, 48, 52, 94, 99, 105, 99, 100, 101, 58, 59, 59, 69, 73, 9, 10, 22, 27, 33, 42, 42, 80, 81, 77, 79, 48, 105, 100, 101, 101, 59, 73, 9, 22, 26, 27, 42, 81, 77, 48, 52, 101, 73, 2, 9, 26, 27, 27, 81, 83, 87, 77, 52, 105, 101, 2, 27, 83, 85, 87, 52, 99, 105, 2, 3, 9, 27, 85, 86, 87, 89, 91, 89, 87, 104, 105, 3, 9, 85, 86, 86, 89, 90, 91, 87, 99, 104, 3, 4, 5, 9, 86, 90, 91, 99, 99, 104, 4, 5, 86, 90, 99, 4, 5, 5, 90, 99];
 testOutput["issue52"]["pass"]=true;
 testOutput["issue52"]["report"]="exp numtri:109\nexp dev:0.00000000000001\nact numtri:109\nact dev:0";
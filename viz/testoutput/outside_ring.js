@@ -1,5 +1,5 @@
 testOutput["outside_ring"]=[];
 testOutput["outside_ring"]["json"]=[[[2181.0, 1228.0], [2182.0, 1231.0], [2178.0, 1231.0], [2180.0, 1228.0], [2175.0, 1225.0], [2174.0, 1212.0], [2182.0, 1210.0], [2182.0, 1193.0], [2190.0, 1187.0], [2187.0, 1166.0], [2194.0, 1158.0], [2186.0, 1149.0], [2186.0, 1103.0], [2195.0, 1091.0], [2207.0, 1092.0], [2209.0, 1080.0], [2203.0, 1077.0], [2213.0, 1057.0], [2213.0, 1035.0], [2224.0, 1031.0], [2238.0, 983.0], [2251.0, 982.0], [2254.0, 965.0], [2275.0, 970.0], [2277.0, 948.0], [2317.0, 982.0], [2317.0, 1030.0], [2323.0, 1044.0], [2306.0, 1041.0], [2303.0, 1051.0], [2290.0, 1057.0], [2294.0, 1062.0], [2287.0, 1071.0], [2294.0, 1081.0], [2255.0, 1123.0], [2249.0, 1118.0], [2253.0, 1128.0], [2245.0, 1131.0], [2249.0, 1137.0], [2243.0, 1168.0], [2265.0, 1195.0], [2253.0, 1203.0], [2260.0, 1204.0], [2252.0, 1215.0], [2249.0, 1208.0], [2245.0, 1217.0], [2232.0, 1220.0], [2241.0, 1223.0], [2235.0, 1223.0], [2238.0, 1245.0], [2229.0, 1274.0], [2215.0, 1272.0], [2209.0, 1288.0], [2196.0, 1288.0], [2190.0, 1269.0], [2194.0, 1271.0], [2195.0, 1262.0], [2181.0, 1240.0], [2182.0, 1233.0], [2183.0, 1229.0], [2181.0, 1228.0]], [[2181.0, 1228.0], [2181.0, 1227.0], [2180.0, 1228.0], [2181.0, 1228.0]], [[2246.0, 1197.0], [2230.0, 1201.0], [2251.0, 1203.0], [2246.0, 1197.0]]];
-testOutput["outside_ring"]["triangles"]=[1, 2, 63, 62, 3, 4, 4, 5, 6, 6, 7, 66, 65, 66, 7, 8, 9, 10, 10, 11, 12, 12, 13, 14, 15, 16, 17, 17, 18, 19, 19, 20, 21, 21, 22, 23, 23, 24, 25, 26, 27, 28, 28, 29, 30, 30, 31, 32, 32, 33, 34, 35, 36, 37, 37, 38, 39, 39, 40, 41, 41, 42, 43, 44, 45, 46, 46, 47, 48, 48, 49, 50, 51, 52, 53, 53, 54, 55, 56, 57, 58, 62, 4, 6, 6, 66, 67, 65, 7, 8, 10, 12, 14, 14, 15, 17, 17, 19, 21, 21, 23, 25, 25, 26, 28, 30, 32, 34, 41, 43, 44, 46, 48, 50, 51, 53, 55, 56, 58, 59, 62, 6, 67, 65, 8, 10, 14, 17, 21, 21, 25, 28, 30, 34, 35, 41, 44, 46, 46, 50, 51, 51, 55, 56, 65, 10, 14, 14, 21, 28, 46, 51, 56, 67, 65, 14, 14, 28, 30, 46, 56, 59, 14, 30, 35, 41, 46, 59, 14, 35, 37, 14, 37, 39, 67, 14, 39, 67, 39, 41, 62, 67, 41, 62, 41, 59, 62, 59, 61, 63, 0, 1];
+testOutput["outside_ring"]["triangles"]=[8, 9, 10, 10, 11, 12, 12, 13, 14, 15, 16, 17, 17, 18, 19, 19, 20, 21, 21, 22, 23, 23, 24, 25, 26, 27, 28, 28, 29, 30, 30, 31, 32, 32, 33, 34, 35, 36, 37, 37, 38, 39, 39, 40, 41, 41, 42, 43, 44, 45, 46, 46, 47, 48, 48, 49, 50, 51, 52, 53, 53, 54, 55, 56, 57, 58, 1, 2, 63, 62, 3, 4, 4, 5, 6, 6, 7, 66, 65, 66, 7, 10, 12, 14, 14, 15, 17, 17, 19, 21, 21, 23, 25, 25, 26, 28, 30, 32, 34, 41, 43, 44, 46, 48, 50, 51, 53, 55, 56, 58, 59, 62, 4, 6, 6, 66, 67, 65, 7, 8, 8, 10, 14, 14, 17, 21, 21, 25, 28, 30, 34, 35, 41, 44, 46, 46, 50, 51, 51, 55, 56, 62, 6, 67, 65, 8, 14, 14, 21, 28, 46, 51, 56, 67, 65, 14, 14, 28, 30, 46, 56, 59, 14, 30, 35, 41, 46, 59, 14, 35, 37, 14, 37, 39, 67, 14, 39, 67, 39, 41, 62, 67, 41, 62, 41, 59, 62, 59, 61, 1, 63, 0];
 testOutput["outside_ring"]["pass"]=true;
 testOutput["outside_ring"]["report"]="exp numtri:64\nexp dev:0.00000000000001\nact numtri:64\nact dev:0";
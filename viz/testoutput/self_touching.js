@@ -1,5 +1,5 @@
 testOutput["self_touching"]=[];
 testOutput["self_touching"]["json"]=[[[160.40671875, 11.3976701817587], [160.396875, 11.3935345987524], [160.39828125, 11.4018057045896], [160.39265625, 11.4004272036667], [160.38984375, 11.3811274888866], [160.3940625, 11.3838846711709], [160.3771875, 11.3521754635814], [160.33921875, 11.3590690696413], [160.35046875, 11.3645838345287], [160.3575, 11.3645838345287], [160.3575, 11.3756130442004], [160.29421875, 11.3507967223837], [160.2928125, 11.3480392200086], [160.28859375, 11.3480392200086], [160.295625, 11.3287359579628], [160.26328125, 11.3080524456288], [160.295625, 11.1866791818427], [160.31671875, 11.1811610026871], [160.318125, 11.1770222993774], [160.31390625, 11.1687447155658], [160.3125, 11.1494294353899], [160.2703125, 11.1107950268865], [160.2421875, 11.1149346728405], [160.23796875, 11.0997556838987], [160.25625, 11.095615822671], [160.21828125, 11.0735355725517], [160.21546875, 11.0652550492086], [160.2084375, 11.0762956949617], [160.20140625, 11.0638749392263], [160.19015625, 11.0528338254202], [160.18453125, 11.0528338254202], [160.183125, 11.0486933005675], [160.24640625, 11.0583544343014], [160.26890625, 11.0555941428523], [160.250625, 11.0804358297701], [160.28015625, 11.0942358558913], [160.295625, 11.0845759059922], [160.2928125, 11.0721555015877], [160.318125, 11.0790557913426], [160.31953125, 11.0942358558913], [160.33359375, 11.1038954864431], [160.34484375, 11.0900959164515], [160.35609375, 11.1038954864431], [160.363125, 11.0969957829326], [160.36453125, 11.1052754075802], [160.36171875, 11.1121749153987], [160.37578125, 11.1149346728405], [160.39828125, 11.1080352302834], [160.36734375, 11.1756427184796], [160.48125, 11.1852996469051], [160.48546875, 11.1825405573266], [160.5121875, 11.1852996469051], [160.5459375, 11.1342522433585], [160.56421875, 11.1301128717933], [160.55578125, 11.1204541093718], [160.56140625, 11.1135547973836], [160.588125, 11.1314926688534], [160.62328125, 11.1121749153987], [160.633125, 11.1135547973836], [160.6471875, 11.1025155587833], [160.64296875, 11.1176944041669], [160.63734375, 11.1190742600349], [160.62328125, 11.1342522433585], [160.62046875, 11.128733068196], [160.6078125, 11.1480497233847], [160.61203125, 11.1480497233847], [160.6134375, 11.1563278971795], [160.5909375, 11.1425308098987], [160.576875, 11.1480497233847], [160.57125, 11.1549482179223], [160.57125, 11.1494294353899], [160.57828125, 11.1452902797332], [160.57265625, 11.1425308098987], [160.57125, 11.1494294353899], [160.54875, 11.1577075698847], [160.554375, 11.179781441482], [160.54875, 11.1770222993774], [160.5628125, 11.2087508469621], [160.5234375, 11.2059919808933], [160.52203125, 11.2032330885061], [160.50515625, 11.2184066708578], [160.49390625, 11.2032330885061], [160.46296875, 11.2046125379891], [160.46296875, 11.201853632445], [160.4165625, 11.2115096867066], [160.41796875, 11.2211654184183], [160.39546875, 11.2266828344767], [160.35609375, 11.2225447823168], [160.35328125, 11.2363380587922], [160.3659375, 11.2473722050633], [160.351875, 11.2915045605453], [160.32375, 11.2721974885629], [160.32234375, 11.2846093266964], [160.35328125, 11.3080524456288], [160.351875, 11.3149471157772], [160.3659375, 11.3204627323768], [160.36171875, 11.2997786224589], [160.3828125, 11.3011576095711], [160.37859375, 11.3080524456288], [160.38140625, 11.3094313929343], [160.3828125, 11.3011576095711], [160.408125, 11.3039155638972], [160.408125, 11.2997786224589], [160.425, 11.3094313929343], [160.41234375, 11.3411453475587], [160.3996875, 11.3301148056307], [160.40953125, 11.3700984927314], [160.39265625, 11.3618264654176], [160.396875, 11.3797488877286], [160.4053125, 11.3893989555911], [160.40953125, 11.3866418267411], [160.419375, 11.4004272036667], [160.41515625, 11.4059411672242], [160.419375, 11.4114550237293], [160.425, 11.412833471123], [160.42359375, 11.422482415387], [160.40671875, 11.3976701817587]], [[160.363125, 11.1425308098987], [160.3603125, 11.1383915560672], [160.3603125, 11.1439105480884], [160.363125, 11.1425308098987]], [[160.35046875, 11.1397713138873], [160.34625, 11.1383915560672], [160.34203125, 11.1480497233847], [160.35046875, 11.1397713138873]]];
-testOutput["self_touching"]["triangles"]=[1, 2, 3, 3, 4, 5, 8, 9, 10, 10, 11, 12, 12, 13, 14, 14, 15, 16, 18, 19, 20, 21, 22, 23, 24, 25, 26, 26, 27, 28, 29, 30, 31, 32, 33, 34, 36, 37, 38, 40, 41, 42, 42, 43, 44, 46, 47, 48, 49, 50, 51, 51, 52, 53, 53, 54, 55, 56, 57, 58, 58, 59, 60, 61, 62, 63, 64, 65, 66, 68, 69, 70, 72, 73, 74, 74, 75, 76, 76, 77, 78, 79, 80, 81, 81, 82, 83, 84, 85, 86, 88, 89, 90, 92, 93, 94, 95, 96, 97, 99, 100, 101, 101, 102, 103, 103, 104, 105, 105, 106, 107, 109, 110, 111, 113, 114, 115, 1, 3, 5, 7, 8, 10, 10, 12, 14, 14, 16, 17, 21, 23, 24, 26, 28, 29, 29, 31, 32, 35, 36, 38, 40, 42, 44, 45, 46, 48, 53, 55, 56, 58, 60, 61, 64, 66, 67, 67, 68, 70, 74, 76, 78, 79, 81, 83, 84, 86, 87, 88, 90, 91, 95, 97, 98, 99, 101, 103, 109, 111, 112, 112, 113, 115, 0, 1, 5, 7, 10, 14, 14, 17, 18, 20, 21, 123, 26, 29, 32, 35, 38, 39, 40, 44, 45, 56, 58, 61, 63, 64, 67, 67, 70, 71, 74, 78, 79, 83, 84, 87, 87, 88, 91, 95, 98, 99, 99, 103, 105, 108, 109, 112, 112, 115, 0, 0, 5, 6, 6, 7, 14, 18, 20, 123, 122, 123, 21, 26, 32, 34, 35, 39, 40, 56, 61, 63, 63, 67, 71, 95, 99, 105, 108, 112, 0, 14, 18, 123, 118, 122, 21, 24, 26, 34, 35, 40, 45, 56, 63, 71, 95, 105, 107, 108, 0, 6, 14, 123, 121, 118, 21, 24, 24, 34, 35, 53, 56, 71, 94, 95, 107, 107, 108, 6, 118, 24, 35, 53, 71, 72, 92, 94, 107, 118, 35, 45, 53, 72, 74, 92, 107, 6, 121, 122, 118, 117, 118, 45, 51, 53, 74, 92, 6, 14, 14, 121, 118, 117, 45, 48, 51, 74, 79, 91, 92, 14, 14, 118, 119, 119, 117, 48, 49, 51, 79, 91, 14, 119, 48, 49, 79, 87, 91, 119, 48, 79, 83, 87, 119, 48, 48, 83, 87];
+testOutput["self_touching"]["triangles"]=[21, 22, 23, 24, 25, 26, 26, 27, 28, 29, 30, 31, 32, 33, 34, 36, 37, 38, 40, 41, 42, 42, 43, 44, 46, 47, 48, 49, 50, 51, 51, 52, 53, 53, 54, 55, 56, 57, 58, 58, 59, 60, 61, 62, 63, 64, 65, 66, 68, 69, 70, 72, 73, 74, 74, 75, 76, 76, 77, 78, 79, 80, 81, 81, 82, 83, 84, 85, 86, 88, 89, 90, 92, 93, 94, 95, 96, 97, 99, 100, 101, 101, 102, 103, 103, 104, 105, 105, 106, 107, 109, 110, 111, 113, 114, 115, 1, 2, 3, 3, 4, 5, 8, 9, 10, 10, 11, 12, 12, 13, 14, 14, 15, 16, 18, 19, 20, 21, 23, 24, 26, 28, 29, 29, 31, 32, 35, 36, 38, 40, 42, 44, 45, 46, 48, 53, 55, 56, 58, 60, 61, 64, 66, 67, 67, 68, 70, 74, 76, 78, 79, 81, 83, 84, 86, 87, 88, 90, 91, 95, 97, 98, 99, 101, 103, 109, 111, 112, 112, 113, 115, 1, 3, 5, 7, 8, 10, 10, 12, 14, 14, 16, 17, 20, 21, 123, 26, 29, 32, 35, 38, 39, 40, 44, 45, 56, 58, 61, 63, 64, 67, 67, 70, 71, 74, 78, 79, 83, 84, 87, 87, 88, 91, 95, 98, 99, 99, 103, 105, 108, 109, 112, 112, 115, 0, 0, 1, 5, 7, 10, 14, 14, 17, 18, 18, 20, 123, 122, 123, 21, 26, 32, 34, 35, 39, 40, 56, 61, 63, 63, 67, 71, 95, 99, 105, 108, 112, 0, 0, 5, 6, 6, 7, 14, 14, 18, 123, 118, 122, 21, 24, 26, 34, 35, 40, 45, 56, 63, 71, 95, 105, 107, 108, 0, 6, 14, 123, 121, 118, 21, 24, 24, 34, 35, 53, 56, 71, 94, 95, 107, 107, 108, 6, 118, 24, 35, 53, 71, 72, 92, 94, 107, 118, 35, 45, 53, 72, 74, 92, 107, 6, 121, 122, 118, 117, 118, 45, 51, 53, 74, 92, 6, 14, 14, 121, 118, 117, 45, 48, 51, 74, 79, 91, 92, 14, 14, 118, 119, 119, 117, 48, 49, 51, 79, 91, 14, 119, 48, 49, 79, 87, 91, 119, 48, 79, 83, 87, 119, 48, 48, 83, 87];
 testOutput["self_touching"]["pass"]=true;
 testOutput["self_touching"]["report"]="exp numtri:124\nexp dev:0.000000000000034\nact numtri:124\nact dev:0.00000000000003355590533513436";
@@ -1,5 +1,5 @@
 testOutput["simplified_us_border"]=[];
 testOutput["simplified_us_border"]["json"]=[[[1130.0, 1713.0], [1131.0, 1710.0], [1137.0, 1731.0], [1133.0, 1752.0], [1125.0, 1753.0], [1118.0, 1742.0], [1110.0, 1717.0], [1105.0, 1718.0], [1108.0, 1704.0], [1096.0, 1691.0], [1077.0, 1694.0], [1067.0, 1683.0], [1019.0, 1687.0], [1031.0, 1689.0], [1031.0, 1704.0], [1022.0, 1696.0], [1022.0, 1702.0], [1010.0, 1700.0], [1003.0, 1692.0], [998.0, 1696.0], [980.0, 1690.0], [970.0, 1698.0], [966.0, 1694.0], [966.0, 1702.0], [938.0, 1718.0], [943.0, 1742.0], [920.0, 1736.0], [916.0, 1721.0], [894.0, 1693.0], [884.0, 1691.0], [872.0, 1703.0], [837.0, 1667.0], [785.0, 1672.0], [743.0, 1654.0], [715.0, 1656.0], [699.0, 1636.0], [676.0, 1628.0], [654.0, 1587.0], [656.0, 1583.0], [660.0, 1588.0], [657.0, 1579.0], [649.0, 1580.0], [633.0, 1547.0], [637.0, 1529.0], [631.0, 1507.0], [638.0, 1454.0], [647.0, 1454.0], [637.0, 1452.0], [639.0, 1441.0], [635.0, 1442.0], [629.0, 1417.0], [651.0, 1421.0], [647.0, 1434.0], [655.0, 1428.0], [650.0, 1440.0], [656.0, 1434.0], [654.0, 1423.0], [651.0, 1420.0], [653.0, 1419.0], [651.0, 1407.0], [965.0, 1407.0], [966.0, 1400.0], [972.0, 1411.0], [1008.0, 1423.0], [1043.0, 1419.0], [1083.0, 1442.0], [1086.0, 1450.0], [1091.0, 1448.0], [1109.0, 1468.0], [1114.0, 1496.0], [1102.0, 1520.0], [1107.0, 1525.0], [1149.0, 1508.0], [1147.0, 1498.0], [1152.0, 1495.0], [1174.0, 1495.0], [1195.0, 1474.0], [1242.0, 1470.0], [1260.0, 1433.0], [1277.0, 1440.0], [1277.0, 1462.0], [1286.0, 1476.0], [1274.0, 1484.0], [1265.0, 1480.0], [1243.0, 1503.0], [1240.0, 1516.0], [1252.0, 1526.0], [1238.0, 1529.0], [1236.0, 1523.0], [1234.0, 1530.0], [1218.0, 1531.0], [1206.0, 1540.0], [1205.0, 1554.0], [1195.0, 1567.0], [1188.0, 1556.0], [1194.0, 1574.0], [1185.0, 1590.0], [1187.0, 1581.0], [1179.0, 1567.0], [1185.0, 1557.0], [1176.0, 1562.0], [1180.0, 1579.0], [1179.0, 1585.0], [1170.0, 1577.0], [1180.0, 1593.0], [1169.0, 1590.0], [1183.0, 1596.0], [1186.0, 1607.0], [1175.0, 1605.0], [1183.0, 1613.0], [1182.0, 1618.0], [1171.0, 1615.0], [1179.0, 1624.0], [1167.0, 1626.0], [1145.0, 1650.0], [1132.0, 1659.0], [1128.0, 1656.0], [1121.0, 1675.0], [1131.0, 1708.0], [1129.0, 1710.0], [1130.0, 1713.0]], [[654.0, 1419.0], [653.0, 1419.0], [654.0, 1423.0], [656.0, 1425.0], [654.0, 1419.0]]];
-testOutput["simplified_us_border"]["triangles"]=[0, 1, 2, 2, 3, 4, 4, 5, 6, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 20, 21, 22, 22, 23, 24, 24, 25, 26, 29, 30, 31, 31, 32, 33, 33, 34, 35, 35, 36, 37, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 48, 49, 50, 50, 51, 52, 52, 53, 54, 121, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 68, 69, 70, 72, 73, 74, 75, 76, 77, 77, 78, 79, 80, 81, 82, 85, 86, 87, 88, 89, 90, 91, 92, 93, 95, 96, 97, 97, 98, 99, 100, 101, 102, 103, 104, 105, 105, 106, 107, 108, 109, 110, 111, 112, 113, 113, 114, 115, 117, 118, 119, 0, 2, 4, 4, 6, 8, 12, 14, 15, 15, 17, 18, 24, 26, 27, 28, 29, 31, 31, 33, 35, 35, 37, 39, 40, 42, 43, 43, 45, 46, 46, 48, 50, 50, 52, 54, 124, 121, 59, 59, 60, 62, 63, 65, 66, 66, 68, 70, 72, 74, 75, 77, 79, 80, 80, 82, 83, 85, 87, 88, 91, 93, 94, 94, 95, 97, 100, 102, 103, 105, 107, 108, 108, 110, 111, 111, 113, 115, 119, 0, 4, 12, 15, 18, 22, 24, 27, 31, 35, 39, 40, 43, 46, 46, 50, 54, 124, 59, 62, 62, 63, 66, 72, 75, 77, 77, 80, 83, 84, 85, 88, 94, 97, 99, 105, 108, 111, 111, 115, 116, 117, 119, 4, 12, 18, 20, 22, 27, 28, 31, 39, 40, 40, 46, 54, 124, 62, 66, 72, 77, 83, 84, 88, 90, 91, 94, 99, 105, 111, 116, 117, 4, 8, 11, 12, 20, 20, 22, 28, 28, 31, 40, 40, 54, 55, 124, 66, 70, 71, 72, 83, 84, 90, 91, 91, 99, 100, 103, 105, 116, 116, 117, 8, 11, 20, 28, 28, 40, 55, 124, 70, 71, 71, 83, 84, 84, 91, 100, 100, 103, 116, 116, 8, 9, 11, 28, 55, 71, 84, 100, 100, 116, 9, 9, 11, 55, 124, 71, 100, 100, 9, 55, 124, 100, 55, 124, 55, 123, 123, 57, 122];
+testOutput["simplified_us_border"]["triangles"]=[121, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 68, 69, 70, 72, 73, 74, 75, 76, 77, 77, 78, 79, 80, 81, 82, 85, 86, 87, 88, 89, 90, 91, 92, 93, 95, 96, 97, 97, 98, 99, 100, 101, 102, 103, 104, 105, 105, 106, 107, 108, 109, 110, 111, 112, 113, 113, 114, 115, 117, 118, 119, 0, 1, 2, 2, 3, 4, 4, 5, 6, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 20, 21, 22, 22, 23, 24, 24, 25, 26, 29, 30, 31, 31, 32, 33, 33, 34, 35, 35, 36, 37, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 48, 49, 50, 50, 51, 52, 52, 53, 54, 124, 121, 59, 59, 60, 62, 63, 65, 66, 66, 68, 70, 72, 74, 75, 77, 79, 80, 80, 82, 83, 85, 87, 88, 91, 93, 94, 94, 95, 97, 100, 102, 103, 105, 107, 108, 108, 110, 111, 111, 113, 115, 0, 2, 4, 4, 6, 8, 12, 14, 15, 15, 17, 18, 24, 26, 27, 28, 29, 31, 31, 33, 35, 35, 37, 39, 40, 42, 43, 43, 45, 46, 46, 48, 50, 50, 52, 54, 124, 59, 62, 62, 63, 66, 72, 75, 77, 77, 80, 83, 84, 85, 88, 94, 97, 99, 105, 108, 111, 111, 115, 116, 119, 0, 4, 12, 15, 18, 22, 24, 27, 31, 35, 39, 40, 43, 46, 46, 50, 54, 124, 62, 66, 72, 77, 83, 84, 88, 90, 91, 94, 99, 105, 111, 116, 117, 119, 4, 12, 18, 20, 22, 27, 28, 31, 39, 40, 40, 46, 54, 124, 66, 70, 71, 72, 83, 84, 90, 91, 91, 99, 100, 103, 105, 116, 117, 4, 8, 11, 12, 20, 20, 22, 28, 28, 31, 40, 40, 54, 55, 124, 70, 71, 71, 83, 84, 84, 91, 100, 100, 103, 116, 116, 117, 8, 11, 20, 28, 28, 40, 55, 71, 84, 100, 116, 8, 9, 11, 28, 55, 124, 71, 100, 100, 116, 9, 9, 11, 55, 124, 100, 9, 124, 9, 55, 124, 55, 123, 57, 122, 56];
 testOutput["simplified_us_border"]["pass"]=true;
 testOutput["simplified_us_border"]["report"]="exp numtri:120\nexp dev:0.00000000000001\nact numtri:120\nact dev:0";
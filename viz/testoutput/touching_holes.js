@@ -1,5 +1,5 @@
 testOutput["touching_holes"]=[];
 testOutput["touching_holes"]["json"]=[[[3694.0, 2061.0], [3794.0, 2035.0], [3812.0, 2123.0], [3784.0, 2123.0], [3708.0, 2139.0], [3694.0, 2061.0]], [[3752.0, 2109.0], [3740.0, 2102.0], [3712.0, 2109.0], [3715.0, 2125.0], [3723.0, 2128.0], [3740.0, 2124.0], [3742.0, 2112.0], [3752.0, 2109.0]], [[3797.0, 2101.0], [3787.0, 2096.0], [3780.0, 2106.0], [3788.0, 2114.0], [3797.0, 2101.0]], [[3734.0, 2099.0], [3732.0, 2091.0], [3719.0, 2094.0], [3721.0, 2102.0], [3734.0, 2099.0]], [[3777.0, 2082.0], [3774.0, 2071.0], [3772.0, 2086.0], [3765.0, 2091.0], [3748.0, 2088.0], [3749.0, 2062.0], [3738.0, 2081.0], [3745.0, 2095.0], [3761.0, 2099.0], [3777.0, 2082.0]], [[3719.0, 2079.0], [3712.0, 2079.0], [3706.0, 2091.0], [3712.0, 2097.0], [3721.0, 2080.0], [3719.0, 2079.0]], [[3773.0, 2067.0], [3761.0, 2053.0], [3753.0, 2061.0], [3753.0, 2071.0], [3756.0, 2075.0], [3773.0, 2067.0]], [[3708.0, 2079.0], [3712.0, 2079.0], [3714.0, 2076.0], [3719.0, 2079.0], [3722.0, 2079.0], [3718.0, 2088.0], [3723.0, 2089.0], [3734.0, 2075.0], [3730.0, 2068.0], [3717.0, 2065.0], [3708.0, 2079.0]]];
-testOutput["touching_holes"]["triangles"]=[25, 26, 27, 41, 42, 0, 4, 0, 8, 11, 12, 6, 15, 16, 7, 8, 0, 36, 36, 0, 46, 25, 27, 28, 55, 46, 0, 41, 0, 1, 4, 8, 9, 8, 36, 37, 55, 0, 42, 40, 41, 1, 4, 9, 10, 3, 4, 10, 3, 10, 11, 3, 11, 6, 2, 3, 6, 17, 2, 6, 6, 7, 17, 2, 17, 14, 1, 2, 14, 40, 1, 14, 40, 14, 15, 7, 22, 19, 7, 19, 20, 21, 51, 52, 29, 30, 52, 20, 21, 52, 29, 52, 53, 20, 52, 30, 29, 53, 54, 7, 20, 30, 29, 54, 55, 7, 30, 31, 29, 55, 42, 7, 31, 32, 28, 29, 42, 15, 7, 32, 28, 42, 43, 15, 32, 24, 28, 43, 44, 40, 15, 24, 25, 28, 44, 40, 24, 25, 25, 44, 40, 51, 21, 37, 50, 51, 37, 50, 37, 38, 47, 48, 34, 38, 49, 50, 46, 35, 36, 22, 7, 8, 8, 37, 21, 21, 22, 8, 17, 7, 16];
+testOutput["touching_holes"]["triangles"]=[15, 16, 7, 8, 0, 36, 36, 0, 46, 25, 26, 27, 55, 46, 0, 41, 42, 0, 4, 0, 8, 11, 12, 6, 8, 36, 37, 25, 27, 28, 55, 0, 42, 41, 0, 1, 4, 8, 9, 40, 41, 1, 4, 9, 10, 3, 4, 10, 3, 10, 11, 3, 11, 6, 2, 3, 6, 17, 2, 6, 6, 7, 17, 2, 17, 14, 1, 2, 14, 40, 1, 14, 40, 14, 15, 7, 22, 19, 7, 19, 20, 21, 51, 52, 29, 30, 52, 20, 21, 52, 29, 52, 53, 20, 52, 30, 29, 53, 54, 7, 20, 30, 29, 54, 55, 7, 30, 31, 29, 55, 42, 7, 31, 32, 28, 29, 42, 15, 7, 32, 28, 42, 43, 15, 32, 24, 28, 43, 44, 40, 15, 24, 25, 28, 44, 40, 24, 25, 25, 44, 40, 51, 21, 37, 50, 51, 37, 50, 37, 38, 50, 38, 49, 48, 34, 47, 36, 46, 35, 22, 7, 8, 8, 37, 21, 21, 22, 8, 17, 7, 16];
 testOutput["touching_holes"]["pass"]=true;
 testOutput["touching_holes"]["report"]="exp numtri:57\nexp dev:0.00000000000001\nact numtri:57\nact dev:0";
@@ -1,5 +1,5 @@
 testOutput["water"]=[];
 testOutput["water"]["json"]=[[[2293.0, 4224.0], [2294.0, 4219.0], [2280.0, 4180.0], [2288.0, 4172.0], [2283.0, 4189.0], [2301.0, 4218.0], [2299.0, 4224.0], [2347.0, 4224.0], [2344.0, 4215.0], [2351.0, 4224.0], [2371.0, 4224.0], [2379.0, 4224.0], [2375.0, 4206.0], [2363.0, 4202.0], [2346.0, 4194.0], [2371.0, 4195.0], [2376.0, 4180.0], [2383.0, 4202.0], [2429.0, 4176.0], [2431.0, 4163.0], [2439.0, 4161.0], [2438.0, 4144.0], [2464.0, 4124.0], [2457.0, 4111.0], [2467.0, 4102.0], [2473.0, 4060.0], [2482.0, 4053.0], [2488.0, 4031.0], [2467.0, 3982.0], [2456.0, 3991.0], [2456.0, 4005.0], [2451.0, 3998.0], [2451.0, 4014.0], [2432.0, 4011.0], [2433.0, 4025.0], [2427.0, 4012.0], [2415.0, 4009.0], [2438.0, 4003.0], [2419.0, 3990.0], [2411.0, 3987.0], [2391.0, 4000.0], [2391.0, 3989.0], [2374.0, 3991.0], [2382.0, 3995.0], [2363.0, 4028.0], [2364.0, 4042.0], [2380.0, 4041.0], [2389.0, 4029.0], [2387.0, 4053.0], [2412.0, 4055.0], [2376.0, 4059.0], [2389.0, 4085.0], [2381.0, 4093.0], [2371.0, 4076.0], [2363.0, 4082.0], [2372.0, 4067.0], [2362.0, 4059.0], [2347.0, 4054.0], [2349.0, 4040.0], [2344.0, 4057.0], [2325.0, 4063.0], [2322.0, 4073.0], [2332.0, 4078.0], [2325.0, 4086.0], [2317.0, 4082.0], [2308.0, 4065.0], [2321.0, 4063.0], [2299.0, 4051.0], [2312.0, 4056.0], [2326.0, 4055.0], [2325.0, 4044.0], [2302.0, 4021.0], [2315.0, 4029.0], [2324.0, 4023.0], [2333.0, 4040.0], [2335.0, 4026.0], [2349.0, 4016.0], [2333.0, 3999.0], [2318.0, 4002.0], [2333.0, 3991.0], [2321.0, 3985.0], [2350.0, 4000.0], [2350.0, 3962.0], [2331.0, 3964.0], [2340.0, 3949.0], [2332.0, 3946.0], [2341.0, 3943.0], [2345.0, 3952.0], [2358.0, 3947.0], [2350.0, 3922.0], [2362.0, 3931.0], [2363.0, 3922.0], [2368.0, 3946.0], [2377.0, 3946.0], [2388.0, 3933.0], [2378.0, 3908.0], [2389.0, 3920.0], [2393.0, 3948.0], [2401.0, 3924.0], [2400.0, 3911.0], [2382.0, 3899.0], [2388.0, 3875.0], [2366.0, 3885.0], [2350.0, 3878.0], [2340.0, 3896.0], [2327.0, 3880.0], [2323.0, 3900.0], [2313.0, 3894.0], [2300.0, 3871.0], [2307.0, 3890.0], [2331.0, 3872.0], [2317.0, 3867.0], [2345.0, 3852.0], [2343.0, 3840.0], [2351.0, 3843.0], [2348.0, 3823.0], [2339.0, 3821.0], [2341.0, 3805.0], [2350.0, 3828.0], [2379.0, 3853.0], [2385.0, 3844.0], [2368.0, 3823.0], [2370.0, 3809.0], [2362.0, 3809.0], [2361.0, 3793.0], [2372.0, 3804.0], [2379.0, 3795.0], [2376.0, 3823.0], [2388.0, 3815.0], [2402.0, 3822.0], [2393.0, 3832.0], [2384.0, 3824.0], [2404.0, 3860.0], [2407.0, 3876.0], [2416.0, 3872.0], [2418.0, 3852.0], [2427.0, 3876.0], [2409.0, 3893.0], [2429.0, 3921.0], [2484.0, 3863.0], [2478.0, 3792.0], [2440.0, 3820.0], [2439.0, 3834.0], [2438.0, 3820.0], [2426.0, 3809.0], [2435.0, 3801.0], [2421.0, 3794.0], [2429.0, 3786.0], [2406.0, 3752.0], [2424.0, 3748.0], [2440.0, 3762.0], [2431.0, 3732.0], [2415.0, 3735.0], [2412.0, 3721.0], [2391.0, 3707.0], [2381.0, 3720.0], [2382.0, 3732.0], [2370.0, 3724.0], [2382.0, 3716.0], [2372.0, 3705.0], [2380.0, 3708.0], [2378.0, 3687.0], [2368.0, 3685.0], [2349.0, 3710.0], [2338.0, 3694.0], [2337.0, 3708.0], [2324.0, 3706.0], [2327.0, 3717.0], [2316.0, 3729.0], [2323.0, 3715.0], [2309.0, 3714.0], [2318.0, 3713.0], [2311.0, 3699.0], [2332.0, 3698.0], [2334.0, 3678.0], [2356.0, 3670.0], [2343.0, 3651.0], [2332.0, 3649.0], [2335.0, 3658.0], [2324.0, 3655.0], [2323.0, 3670.0], [2324.0, 3661.0], [2311.0, 3661.0], [2332.0, 3640.0], [2316.0, 3633.0], [2286.0, 3655.0], [2302.0, 3631.0], [2277.0, 3610.0], [2269.0, 3619.0], [2250.0, 3619.0], [2250.0, 3630.0], [2239.0, 3635.0], [2244.0, 3650.0], [2228.0, 3660.0], [2236.0, 3679.0], [2226.0, 3666.0], [2207.0, 3677.0], [2199.0, 3672.0], [2235.0, 3648.0], [2232.0, 3621.0], [2240.0, 3624.0], [2249.0, 3605.0], [2229.0, 3607.0], [2219.0, 3593.0], [2209.0, 3597.0], [2218.0, 3587.0], [2202.0, 3586.0], [2201.0, 3576.0], [2180.0, 3583.0], [2177.0, 3598.0], [2178.0, 3573.0], [2165.0, 3548.0], [2153.0, 3544.0], [2148.0, 3519.0], [2154.0, 3506.0], [2139.0, 3492.0], [2141.0, 3484.0], [2149.0, 3492.0], [2186.0, 3568.0], [2207.0, 3567.0], [2233.0, 3585.0], [2231.0, 3533.0], [2248.0, 3519.0], [2248.0, 3532.0], [2238.0, 3536.0], [2247.0, 3547.0], [2238.0, 3549.0], [2237.0, 3574.0], [2260.0, 3602.0], [2261.0, 3586.0], [2272.0, 3598.0], [2294.0, 3599.0], [2288.0, 3559.0], [2300.0, 3578.0], [2303.0, 3610.0], [2324.0, 3615.0], [2341.0, 3612.0], [2342.0, 3596.0], [2330.0, 3591.0], [2342.0, 3588.0], [2346.0, 3561.0], [2348.0, 3612.0], [2363.0, 3604.0], [2365.0, 3582.0], [2374.0, 3590.0], [2376.0, 3576.0], [2386.0, 3591.0], [2403.0, 3596.0], [2387.0, 3598.0], [2370.0, 3598.0], [2364.0, 3636.0], [2379.0, 3621.0], [2387.0, 3653.0], [2400.0, 3620.0], [2414.0, 3623.0], [2402.0, 3632.0], [2397.0, 3650.0], [2411.0, 3667.0], [2410.0, 3682.0], [2427.0, 3678.0], [2420.0, 3667.0], [2425.0, 3649.0], [2443.0, 3653.0], [2426.0, 3651.0], [2433.0, 3664.0], [2429.0, 3672.0], [2440.0, 3671.0], [2440.0, 3681.0], [2426.0, 3688.0], [2454.0, 3700.0], [2457.0, 3689.0], [2462.0, 3698.0], [2456.0, 3717.0], [2499.0, 3696.0], [2488.0, 3686.0], [2498.0, 3690.0], [2499.0, 3682.0], [2520.0, 3700.0], [2503.0, 3702.0], [2499.0, 3713.0], [2510.0, 3706.0], [2518.0, 3708.0], [2513.0, 3730.0], [2530.0, 3746.0], [2521.0, 3746.0], [2546.0, 3760.0], [2549.0, 3777.0], [2574.0, 3779.0], [2550.0, 3768.0], [2536.0, 3721.0], [2551.0, 3725.0], [2555.0, 3750.0], [2578.0, 3746.0], [2564.0, 3704.0], [2554.0, 3709.0], [2542.0, 3694.0], [2563.0, 3704.0], [2571.0, 3682.0], [2602.0, 3652.0], [2577.0, 3623.0], [2568.0, 3629.0], [2581.0, 3639.0], [2576.0, 3655.0], [2561.0, 3636.0], [2560.0, 3648.0], [2549.0, 3634.0], [2524.0, 3637.0], [2531.0, 3625.0], [2564.0, 3627.0], [2537.0, 3582.0], [2530.0, 3591.0], [2534.0, 3554.0], [2522.0, 3558.0], [2519.0, 3574.0], [2506.0, 3576.0], [2504.0, 3595.0], [2493.0, 3598.0], [2502.0, 3592.0], [2505.0, 3572.0], [2513.0, 3563.0], [2502.0, 3559.0], [2517.0, 3548.0], [2527.0, 3551.0], [2533.0, 3539.0], [2518.0, 3523.0], [2503.0, 3538.0], [2485.0, 3535.0], [2479.0, 3545.0], [2478.0, 3535.0], [2467.0, 3540.0], [2466.0, 3555.0], [2454.0, 3547.0], [2443.0, 3562.0], [2450.0, 3547.0], [2437.0, 3543.0], [2462.0, 3542.0], [2459.0, 3532.0], [2470.0, 3535.0], [2477.0, 3525.0], [2500.0, 3530.0], [2496.0, 3515.0], [2515.0, 3515.0], [2527.0, 3502.0], [2503.0, 3482.0], [2481.0, 3486.0], [2453.0, 3506.0], [2462.0, 3496.0], [2461.0, 3485.0], [2496.0, 3481.0], [2490.0, 3464.0], [2467.0, 3463.0], [2479.0, 3445.0], [2458.0, 3437.0], [2430.0, 3471.0], [2391.0, 3471.0], [2403.0, 3470.0], [2410.0, 3460.0], [2428.0, 3464.0], [2450.0, 3428.0], [2435.0, 3418.0], [2426.0, 3429.0], [2437.0, 3412.0], [2418.0, 3406.0], [2415.0, 3415.0], [2388.0, 3419.0], [2411.0, 3403.0], [2395.0, 3399.0], [2404.0, 3392.0], [2385.0, 3384.0], [2366.0, 3406.0], [2347.0, 3410.0], [2384.0, 3376.0], [2370.0, 3365.0], [2347.0, 3376.0], [2327.0, 3371.0], [2376.0, 3358.0], [2372.0, 3347.0], [2357.0, 3342.0], [2331.0, 3350.0], [2334.0, 3342.0], [2361.0, 3335.0], [2363.0, 3326.0], [2321.0, 3317.0], [2303.0, 3328.0], [2312.0, 3311.0], [2320.0, 3316.0], [2270.0, 3302.0], [2257.0, 3308.0], [2263.0, 3321.0], [2248.0, 3330.0], [2257.0, 3316.0], [2246.0, 3300.0], [2238.0, 3323.0], [2227.0, 3318.0], [2227.0, 3304.0], [2243.0, 3294.0], [2253.0, 3297.0], [2249.0, 3282.0], [2232.0, 3278.0], [2242.0, 3274.0], [2242.0, 3266.0], [2258.0, 3264.0], [2257.0, 3255.0], [2269.0, 3248.0], [2292.0, 3263.0], [2278.0, 3245.0], [2259.0, 3239.0], [2278.0, 3244.0], [2281.0, 3231.0], [2266.0, 3217.0], [2252.0, 3217.0], [2250.0, 3196.0], [2228.0, 3218.0], [2220.0, 3219.0], [2237.0, 3199.0], [2215.0, 3194.0], [2243.0, 3196.0], [2237.0, 3177.0], [2209.0, 3171.0], [2189.0, 3154.0], [2157.0, 3144.0], [2144.0, 3152.0], [2151.0, 3141.0], [2139.0, 3130.0], [2122.0, 3148.0], [2113.0, 3127.0], [2125.0, 3142.0], [2130.0, 3117.0], [2147.0, 3119.0], [2149.0, 3128.0], [2167.0, 3120.0], [2169.0, 3146.0], [2187.0, 3143.0], [2212.0, 3166.0], [2224.0, 3161.0], [2225.0, 3173.0], [2243.0, 3145.0], [2244.0, 3156.0], [2235.0, 3162.0], [2254.0, 3175.0], [2272.0, 3176.0], [2289.0, 3165.0], [2286.0, 3182.0], [2274.0, 3178.0], [2268.0, 3195.0], [2304.0, 3196.0], [2310.0, 3180.0], [2323.0, 3209.0], [2339.0, 3214.0], [2349.0, 3249.0], [2359.0, 3249.0], [2357.0, 3295.0], [2359.0, 3287.0], [2385.0, 3284.0], [2368.0, 3300.0], [2393.0, 3315.0], [2388.0, 3339.0], [2401.0, 3357.0], [2414.0, 3352.0], [2410.0, 3342.0], [2422.0, 3334.0], [2417.0, 3348.0], [2435.0, 3354.0], [2416.0, 3357.0], [2420.0, 3376.0], [2436.0, 3385.0], [2452.0, 3381.0], [2444.0, 3384.0], [2447.0, 3396.0], [2466.0, 3394.0], [2479.0, 3417.0], [2490.0, 3400.0], [2499.0, 3452.0], [2529.0, 3469.0], [2531.0, 3481.0], [2547.0, 3497.0], [2554.0, 3488.0], [2565.0, 3503.0], [2579.0, 3490.0], [2569.0, 3465.0], [2591.0, 3499.0], [2589.0, 3527.0], [2600.0, 3518.0], [2606.0, 3498.0], [2604.0, 3456.0], [2589.0, 3465.0], [2591.0, 3456.0], [2572.0, 3442.0], [2583.0, 3440.0], [2582.0, 3430.0], [2571.0, 3419.0], [2557.0, 3420.0], [2570.0, 3415.0], [2580.0, 3423.0], [2580.0, 3409.0], [2530.0, 3373.0], [2587.0, 3402.0], [2595.0, 3447.0], [2604.0, 3413.0], [2598.0, 3436.0], [2609.0, 3432.0], [2601.0, 3446.0], [2614.0, 3451.0], [2635.0, 3442.0], [2644.0, 3447.0], [2655.0, 3434.0], [2646.0, 3429.0], [2646.0, 3411.0], [2632.0, 3413.0], [2650.0, 3405.0], [2646.0, 3393.0], [2610.0, 3389.0], [2619.0, 3383.0], [2603.0, 3373.0], [2616.0, 3372.0], [2623.0, 3384.0], [2646.0, 3388.0], [2650.0, 3359.0], [2612.0, 3347.0], [2641.0, 3355.0], [2643.0, 3345.0], [2654.0, 3359.0], [2648.0, 3382.0], [2662.0, 3381.0], [2654.0, 3413.0], [2659.0, 3426.0], [2673.0, 3429.0], [2673.0, 3417.0], [2685.0, 3418.0], [2679.0, 3396.0], [2695.0, 3397.0], [2684.0, 3405.0], [2693.0, 3416.0], [2686.0, 3425.0], [2676.0, 3422.0], [2684.0, 3439.0], [2669.0, 3471.0], [2676.0, 3482.0], [2688.0, 3472.0], [2684.0, 3453.0], [2711.0, 3444.0], [2698.0, 3444.0], [2710.0, 3426.0], [2702.0, 3424.0], [2716.0, 3422.0], [2737.0, 3403.0], [2731.0, 3374.0], [2746.0, 3381.0], [2744.0, 3399.0], [2765.0, 3387.0], [2745.0, 3326.0], [2735.0, 3313.0], [2715.0, 3318.0], [2724.0, 3314.0], [2739.0, 3307.0], [2666.0, 3259.0], [2658.0, 3286.0], [2668.0, 3292.0], [2656.0, 3294.0], [2655.0, 3312.0], [2642.0, 3304.0], [2656.0, 3286.0], [2645.0, 3286.0], [2653.0, 3278.0], [2644.0, 3273.0], [2662.0, 3259.0], [2637.0, 3234.0], [2591.0, 3240.0], [2555.0, 3270.0], [2578.0, 3246.0], [2574.0, 3238.0], [2585.0, 3239.0], [2596.0, 3228.0], [2583.0, 3210.0], [2576.0, 3218.0], [2580.0, 3208.0], [2545.0, 3209.0], [2529.0, 3198.0], [2517.0, 3209.0], [2513.0, 3182.0], [2491.0, 3203.0], [2495.0, 3222.0], [2488.0, 3230.0], [2483.0, 3213.0], [2491.0, 3208.0], [2476.0, 3207.0], [2486.0, 3201.0], [2483.0, 3187.0], [2471.0, 3185.0], [2460.0, 3193.0], [2466.0, 3183.0], [2452.0, 3158.0], [2426.0, 3163.0], [2418.0, 3175.0], [2434.0, 3195.0], [2435.0, 3211.0], [2424.0, 3200.0], [2426.0, 3185.0], [2413.0, 3177.0], [2403.0, 3190.0], [2405.0, 3205.0], [2395.0, 3210.0], [2398.0, 3198.0], [2383.0, 3205.0], [2384.0, 3194.0], [2400.0, 3195.0], [2414.0, 3172.0], [2393.0, 3157.0], [2362.0, 3192.0], [2364.0, 3183.0], [2356.0, 3180.0], [2371.0, 3170.0], [2364.0, 3166.0], [2379.0, 3166.0], [2360.0, 3150.0], [2394.0, 3153.0], [2398.0, 3135.0], [2401.0, 3147.0], [2408.0, 3128.0], [2395.0, 3126.0], [2386.0, 3114.0], [2377.0, 3117.0], [2371.0, 3106.0], [2351.0, 3105.0], [2343.0, 3109.0], [2360.0, 3098.0], [2380.0, 3105.0], [2376.0, 3090.0], [2355.0, 3081.0], [2362.0, 3073.0], [2358.0, 3041.0], [2339.0, 3025.0], [2334.0, 3010.0], [2314.0, 3000.0], [2335.0, 3006.0], [2349.0, 3032.0], [2359.0, 3025.0], [2370.0, 3073.0], [2384.0, 3089.0], [2388.0, 3072.0], [2411.0, 3063.0], [2422.0, 3047.0], [2417.0, 3063.0], [2394.0, 3072.0], [2384.0, 3101.0], [2400.0, 3113.0], [2418.0, 3094.0], [2412.0, 3108.0], [2423.0, 3106.0], [2426.0, 3117.0], [2446.0, 3119.0], [2446.0, 3141.0], [2466.0, 3128.0], [2456.0, 3108.0], [2460.0, 3097.0], [2471.0, 3117.0], [2487.0, 3107.0], [2470.0, 3122.0], [2479.0, 3137.0], [2485.0, 3127.0], [2499.0, 3130.0], [2506.0, 3106.0], [2528.0, 3101.0], [2529.0, 3109.0], [2515.0, 3113.0], [2529.0, 3135.0], [2526.0, 3114.0], [2541.0, 3130.0], [2558.0, 3114.0], [2561.0, 3101.0], [2548.0, 3102.0], [2535.0, 3087.0], [2555.0, 3094.0], [2544.0, 3073.0], [2562.0, 3099.0], [2576.0, 3101.0], [2585.0, 3096.0], [2580.0, 3079.0], [2588.0, 3073.0], [2605.0, 3065.0], [2615.0, 3073.0], [2632.0, 3084.0], [2628.0, 3071.0], [2620.0, 3067.0], [2600.0, 3039.0], [2612.0, 3046.0], [2634.0, 3071.0], [2642.0, 3082.0], [2646.0, 3073.0], [2646.0, 3083.0], [2672.0, 3093.0], [2677.0, 3106.0], [2670.0, 3071.0], [2652.0, 3011.0], [2659.0, 2936.0], [2636.0, 2937.0], [2629.0, 2961.0], [2636.0, 2973.0], [2617.0, 2976.0], [2629.0, 2992.0], [2618.0, 2988.0], [2599.0, 2996.0], [2613.0, 2989.0], [2613.0, 2967.0], [2604.0, 2973.0], [2609.0, 2958.0], [2598.0, 2962.0], [2586.0, 2962.0], [2583.0, 2975.0], [2581.0, 2965.0], [2565.0, 2975.0], [2574.0, 2963.0], [2537.0, 2964.0], [2536.0, 2984.0], [2535.0, 2961.0], [2512.0, 2973.0], [2501.0, 2962.0], [2531.0, 2959.0], [2530.0, 2951.0], [2538.0, 2957.0], [2553.0, 2954.0], [2578.0, 2954.0], [2604.0, 2944.0], [2618.0, 2953.0], [2610.0, 2939.0], [2574.0, 2946.0], [2541.0, 2926.0], [2576.0, 2938.0], [2618.0, 2927.0], [2634.0, 2921.0], [2552.0, 2863.0], [2521.0, 2805.0], [2498.0, 2823.0], [2494.0, 2838.0], [2503.0, 2847.0], [2493.0, 2842.0], [2455.0, 2853.0], [2485.0, 2877.0], [2459.0, 2862.0], [2443.0, 2871.0], [2449.0, 2888.0], [2429.0, 2873.0], [2430.0, 2861.0], [2416.0, 2880.0], [2421.0, 2900.0], [2408.0, 2887.0], [2388.0, 2915.0], [2420.0, 2868.0], [2412.0, 2855.0], [2441.0, 2851.0], [2455.0, 2834.0], [2447.0, 2809.0], [2421.0, 2784.0], [2423.0, 2796.0], [2395.0, 2818.0], [2400.0, 2827.0], [2387.0, 2823.0], [2384.0, 2844.0], [2376.0, 2841.0], [2367.0, 2847.0], [2371.0, 2858.0], [2348.0, 2865.0], [2347.0, 2879.0], [2325.0, 2871.0], [2326.0, 2891.0], [2308.0, 2884.0], [2298.0, 2898.0], [2297.0, 2888.0], [2283.0, 2893.0], [2283.0, 2884.0], [2312.0, 2880.0], [2294.0, 2859.0], [2316.0, 2872.0], [2315.0, 2862.0], [2349.0, 2857.0], [2333.0, 2848.0], [2360.0, 2850.0], [2358.0, 2840.0], [2369.0, 2834.0], [2339.0, 2821.0], [2309.0, 2824.0], [2310.0, 2815.0], [2328.0, 2816.0], [2342.0, 2814.0], [2340.0, 2804.0], [2357.0, 2819.0], [2360.0, 2798.0], [2365.0, 2818.0], [2379.0, 2820.0], [2391.0, 2793.0], [2409.0, 2789.0], [2392.0, 2771.0], [2392.0, 2782.0], [2384.0, 2779.0], [2370.0, 2792.0], [2368.0, 2783.0], [2356.0, 2785.0], [2366.0, 2775.0], [2375.0, 2783.0], [2373.0, 2774.0], [2389.0, 2773.0], [2360.0, 2717.0], [2360.0, 2707.0], [2377.0, 2698.0], [2356.0, 2669.0], [2364.0, 2645.0], [2355.0, 2642.0], [2360.0, 2633.0], [2339.0, 2644.0], [2342.0, 2629.0], [2324.0, 2645.0], [2312.0, 2639.0], [2310.0, 2616.0], [2275.0, 2627.0], [2229.0, 2610.0], [2219.0, 2563.0], [2234.0, 2585.0], [2252.0, 2578.0], [2270.0, 2589.0], [2286.0, 2553.0], [2266.0, 2527.0], [2255.0, 2528.0], [2235.0, 2496.0], [2221.0, 2505.0], [2230.0, 2489.0], [2220.0, 2497.0], [2225.0, 2486.0], [2210.0, 2479.0], [2185.0, 2482.0], [2190.0, 2517.0], [2171.0, 2513.0], [2171.0, 2500.0], [2162.0, 2517.0], [2144.0, 2518.0], [2121.0, 2472.0], [2096.0, 2474.0], [2076.0, 2458.0], [2094.0, 2427.0], [2087.0, 2410.0], [2095.0, 2408.0], [2104.0, 2408.0], [2105.0, 2444.0], [2126.0, 2453.0], [2136.0, 2471.0], [2152.0, 2458.0], [2142.0, 2448.0], [2159.0, 2433.0], [2196.0, 2452.0], [2216.0, 2442.0], [2225.0, 2451.0], [2232.0, 2441.0], [2223.0, 2420.0], [2217.0, 2428.0], [2223.0, 2416.0], [2215.0, 2425.0], [2219.0, 2412.0], [2210.0, 2420.0], [2213.0, 2409.0], [2207.0, 2417.0], [2197.0, 2397.0], [2170.0, 2407.0], [2154.0, 2398.0], [2150.0, 2376.0], [2132.0, 2372.0], [2132.0, 2360.0], [2143.0, 2364.0], [2143.0, 2356.0], [2158.0, 2373.0], [2176.0, 2372.0], [2166.0, 2375.0], [2167.0, 2385.0], [2192.0, 2374.0], [2202.0, 2384.0], [2199.0, 2373.0], [2208.0, 2368.0], [2225.0, 2380.0], [2228.0, 2392.0], [2250.0, 2393.0], [2253.0, 2411.0], [2257.0, 2423.0], [2246.0, 2426.0], [2257.0, 2428.0], [2246.0, 2431.0], [2257.0, 2438.0], [2248.0, 2447.0], [2257.0, 2459.0], [2268.0, 2456.0], [2269.0, 2469.0], [2276.0, 2452.0], [2286.0, 2468.0], [2285.0, 2451.0], [2294.0, 2450.0], [2295.0, 2465.0], [2304.0, 2453.0], [2304.0, 2464.0], [2340.0, 2491.0], [2352.0, 2462.0], [2339.0, 2446.0], [2342.0, 2433.0], [2357.0, 2470.0], [2345.0, 2510.0], [2362.0, 2534.0], [2389.0, 2522.0], [2408.0, 2524.0], [2409.0, 2559.0], [2418.0, 2574.0], [2398.0, 2596.0], [2450.0, 2560.0], [2456.0, 2541.0], [2445.0, 2541.0], [2461.0, 2531.0], [2440.0, 2529.0], [2437.0, 2519.0], [2467.0, 2522.0], [2474.0, 2513.0], [2460.0, 2501.0], [2437.0, 2505.0], [2447.0, 2498.0], [2433.0, 2476.0], [2447.0, 2493.0], [2439.0, 2469.0], [2449.0, 2475.0], [2456.0, 2497.0], [2462.0, 2489.0], [2477.0, 2501.0], [2489.0, 2493.0], [2488.0, 2485.0], [2468.0, 2477.0], [2473.0, 2460.0], [2461.0, 2455.0], [2462.0, 2446.0], [2486.0, 2477.0], [2507.0, 2485.0], [2509.0, 2472.0], [2518.0, 2469.0], [2505.0, 2458.0], [2514.0, 2462.0], [2522.0, 2451.0], [2512.0, 2414.0], [2493.0, 2409.0], [2505.0, 2409.0], [2516.0, 2401.0], [2517.0, 2412.0], [2533.0, 2405.0], [2519.0, 2414.0], [2522.0, 2438.0], [2554.0, 2449.0], [2525.0, 2453.0], [2527.0, 2462.0], [2541.0, 2463.0], [2529.0, 2465.0], [2524.0, 2475.0], [2542.0, 2492.0], [2521.0, 2481.0], [2517.0, 2490.0], [2527.0, 2501.0], [2492.0, 2499.0], [2494.0, 2516.0], [2475.0, 2543.0], [2487.0, 2578.0], [2466.0, 2581.0], [2461.0, 2594.0], [2470.0, 2601.0], [2458.0, 2602.0], [2471.0, 2608.0], [2458.0, 2609.0], [2470.0, 2612.0], [2468.0, 2625.0], [2455.0, 2633.0], [2475.0, 2634.0], [2454.0, 2640.0], [2456.0, 2652.0], [2468.0, 2652.0], [2462.0, 2661.0], [2481.0, 2677.0], [2481.0, 2671.0], [2499.0, 2671.0], [2501.0, 2639.0], [2527.0, 2635.0], [2513.0, 2645.0], [2511.0, 2676.0], [2515.0, 2664.0], [2565.0, 2659.0], [2565.0, 2614.0], [2563.0, 2589.0], [2571.0, 2569.0], [2587.0, 2575.0], [2604.0, 2570.0], [2572.0, 2584.0], [2572.0, 2592.0], [2587.0, 2588.0], [2584.0, 2594.0], [2594.0, 2594.0], [2578.0, 2606.0], [2594.0, 2613.0], [2626.0, 2608.0], [2630.0, 2590.0], [2619.0, 2589.0], [2633.0, 2586.0], [2634.0, 2572.0], [2642.0, 2572.0], [2638.0, 2598.0], [2635.0, 2613.0], [2642.0, 2605.0], [2618.0, 2624.0], [2622.0, 2642.0], [2604.0, 2675.0], [2611.0, 2691.0], [2626.0, 2699.0], [2643.0, 2671.0], [2620.0, 2670.0], [2642.0, 2663.0], [2624.0, 2659.0], [2628.0, 2645.0], [2640.0, 2649.0], [2635.0, 2637.0], [2645.0, 2638.0], [2653.0, 2625.0], [2660.0, 2639.0], [2647.0, 2655.0], [2655.0, 2662.0], [2676.0, 2660.0], [2684.0, 2667.0], [2676.0, 2657.0], [2698.0, 2605.0], [2754.0, 2571.0], [2743.0, 2564.0], [2753.0, 2537.0], [2723.0, 2564.0], [2687.0, 2563.0], [2673.0, 2544.0], [2627.0, 2520.0], [2633.0, 2509.0], [2617.0, 2501.0], [2613.0, 2511.0], [2594.0, 2512.0], [2615.0, 2487.0], [2591.0, 2457.0], [2597.0, 2444.0], [2587.0, 2421.0], [2599.0, 2423.0], [2602.0, 2413.0], [2570.0, 2381.0], [2538.0, 2365.0], [2525.0, 2340.0], [2498.0, 2347.0], [2511.0, 2334.0], [2508.0, 2322.0], [2495.0, 2321.0], [2485.0, 2302.0], [2459.0, 2293.0], [2458.0, 2281.0], [2468.0, 2271.0], [2483.0, 2280.0], [2486.0, 2262.0], [2498.0, 2265.0], [2487.0, 2288.0], [2503.0, 2296.0], [2517.0, 2291.0], [2528.0, 2304.0], [2551.0, 2311.0], [2558.0, 2335.0], [2562.0, 2313.0], [2552.0, 2307.0], [2548.0, 2290.0], [2562.0, 2302.0], [2567.0, 2322.0], [2591.0, 2296.0], [2574.0, 2321.0], [2565.0, 2332.0], [2589.0, 2352.0], [2601.0, 2338.0], [2593.0, 2353.0], [2600.0, 2361.0], [2636.0, 2366.0], [2638.0, 2355.0], [2646.0, 2352.0], [2643.0, 2336.0], [2651.0, 2352.0], [2658.0, 2343.0], [2653.0, 2354.0], [2661.0, 2363.0], [2644.0, 2361.0], [2642.0, 2371.0], [2651.0, 2375.0], [2635.0, 2374.0], [2639.0, 2413.0], [2650.0, 2416.0], [2631.0, 2451.0], [2641.0, 2454.0], [2642.0, 2479.0], [2671.0, 2475.0], [2702.0, 2501.0], [2717.0, 2497.0], [2726.0, 2504.0], [2731.0, 2491.0], [2721.0, 2477.0], [2735.0, 2496.0], [2746.0, 2498.0], [2734.0, 2473.0], [2764.0, 2425.0], [2742.0, 2435.0], [2741.0, 2419.0], [2752.0, 2418.0], [2756.0, 2407.0], [2743.0, 2403.0], [2756.0, 2396.0], [2745.0, 2389.0], [2756.0, 2389.0], [2761.0, 2380.0], [2763.0, 2397.0], [2787.0, 2379.0], [2794.0, 2397.0], [2800.0, 2387.0], [2792.0, 2370.0], [2803.0, 2363.0], [2795.0, 2356.0], [2764.0, 2357.0], [2762.0, 2344.0], [2761.0, 2363.0], [2753.0, 2361.0], [2742.0, 2372.0], [2750.0, 2359.0], [2741.0, 2357.0], [2715.0, 2378.0], [2719.0, 2360.0], [2702.0, 2371.0], [2709.0, 2361.0], [2695.0, 2343.0], [2713.0, 2354.0], [2722.0, 2350.0], [2714.0, 2327.0], [2722.0, 2329.0], [2731.0, 2352.0], [2740.0, 2346.0], [2732.0, 2334.0], [2750.0, 2346.0], [2752.0, 2318.0], [2744.0, 2320.0], [2736.0, 2307.0], [2723.0, 2306.0], [2725.0, 2316.0], [2723.0, 2301.0], [2714.0, 2290.0], [2710.0, 2304.0], [2702.0, 2304.0], [2702.0, 2313.0], [2693.0, 2318.0], [2685.0, 2320.0], [2686.0, 2310.0], [2704.0, 2299.0], [2703.0, 2289.0], [2683.0, 2300.0], [2675.0, 2294.0], [2673.0, 2303.0], [2665.0, 2298.0], [2653.0, 2304.0], [2662.0, 2298.0], [2657.0, 2289.0], [2669.0, 2293.0], [2668.0, 2283.0], [2678.0, 2289.0], [2677.0, 2277.0], [2683.0, 2285.0], [2694.0, 2282.0], [2693.0, 2266.0], [2677.0, 2264.0], [2675.0, 2254.0], [2653.0, 2260.0], [2654.0, 2270.0], [2642.0, 2269.0], [2647.0, 2259.0], [2635.0, 2266.0], [2620.0, 2242.0], [2636.0, 2256.0], [2652.0, 2253.0], [2629.0, 2228.0], [2623.0, 2204.0], [2645.0, 2235.0], [2669.0, 2245.0], [2658.0, 2225.0], [2646.0, 2205.0], [2660.0, 2213.0], [2677.0, 2198.0], [2668.0, 2223.0], [2680.0, 2227.0], [2677.0, 2236.0], [2717.0, 2275.0], [2695.0, 2228.0], [2713.0, 2238.0], [2722.0, 2259.0], [2737.0, 2242.0], [2740.0, 2218.0], [2722.0, 2199.0], [2719.0, 2182.0], [2727.0, 2191.0], [2730.0, 2177.0], [2742.0, 2169.0], [2733.0, 2198.0], [2738.0, 2208.0], [2744.0, 2188.0], [2756.0, 2187.0], [2748.0, 2188.0], [2743.0, 2208.0], [2755.0, 2209.0], [2747.0, 2236.0], [2750.0, 2247.0], [2760.0, 2245.0], [2743.0, 2262.0], [2742.0, 2274.0], [2755.0, 2270.0], [2766.0, 2291.0], [2770.0, 2261.0], [2783.0, 2253.0], [2783.0, 2274.0], [2797.0, 2272.0], [2797.0, 2262.0], [2806.0, 2267.0], [2808.0, 2290.0], [2796.0, 2294.0], [2795.0, 2305.0], [2809.0, 2309.0], [2833.0, 2291.0], [2851.0, 2258.0], [2836.0, 2266.0], [2826.0, 2257.0], [2845.0, 2248.0], [2833.0, 2244.0], [2833.0, 2235.0], [2813.0, 2245.0], [2805.0, 2227.0], [2787.0, 2242.0], [2773.0, 2224.0], [2789.0, 2229.0], [2797.0, 2219.0], [2781.0, 2202.0], [2798.0, 2214.0], [2808.0, 2209.0], [2809.0, 2220.0], [2825.0, 2230.0], [2834.0, 2227.0], [2830.0, 2215.0], [2839.0, 2207.0], [2840.0, 2218.0], [2849.0, 2223.0], [2860.0, 2203.0], [2848.0, 2185.0], [2836.0, 2195.0], [2819.0, 2192.0], [2836.0, 2189.0], [2837.0, 2179.0], [2825.0, 2164.0], [2824.0, 2156.0], [2794.0, 2148.0], [2799.0, 2140.0], [2790.0, 2130.0], [2813.0, 2149.0], [2822.0, 2147.0], [2821.0, 2133.0], [2835.0, 2159.0], [2877.0, 2174.0], [2870.0, 2160.0], [2875.0, 2149.0], [2856.0, 2150.0], [2846.0, 2131.0], [2838.0, 2134.0], [2829.0, 2109.0], [2841.0, 2122.0], [2848.0, 2110.0], [2858.0, 2117.0], [2865.0, 2103.0], [2851.0, 2093.0], [2860.0, 2092.0], [2858.0, 2083.0], [2869.0, 2100.0], [2867.0, 2110.0], [2876.0, 2110.0], [2868.0, 2121.0], [2874.0, 2135.0], [2886.0, 2133.0], [2895.0, 2116.0], [2890.0, 2138.0], [2896.0, 2157.0], [2909.0, 2148.0], [2932.0, 2155.0], [2940.0, 2177.0], [2952.0, 2172.0], [2908.0, 2089.0], [2923.0, 2075.0], [2923.0, 2049.0], [2929.0, 2028.0], [2895.0, 2023.0], [2885.0, 2002.0], [2871.0, 1999.0], [2861.0, 2014.0], [2837.0, 2011.0], [2832.0, 2021.0], [2851.0, 2042.0], [2828.0, 2033.0], [2831.0, 2049.0], [2828.0, 2049.0], [2814.0, 2049.0], [2826.0, 2029.0], [2811.0, 2019.0], [2795.0, 2032.0], [2801.0, 2018.0], [2755.0, 2011.0], [2757.0, 1995.0], [2749.0, 1996.0], [2750.0, 2007.0], [2732.0, 2008.0], [2733.0, 1998.0], [2704.0, 1994.0], [2715.0, 1985.0], [2695.0, 1987.0], [2713.0, 1981.0], [2753.0, 1987.0], [2772.0, 2002.0], [2788.0, 1999.0], [2788.0, 1988.0], [2792.0, 2001.0], [2828.0, 1999.0], [2856.0, 1952.0], [2837.0, 1954.0], [2828.0, 1933.0], [2815.0, 1928.0], [2860.0, 1933.0], [2854.0, 1971.0], [2863.0, 1959.0], [2872.0, 1966.0], [2874.0, 1936.0], [2882.0, 1933.0], [2892.0, 1956.0], [2896.0, 1943.0], [2905.0, 1942.0], [2911.0, 1961.0], [2954.0, 1975.0], [2966.0, 1967.0], [2992.0, 1968.0], [3021.0, 1984.0], [3036.0, 2021.0], [3030.0, 2047.0], [3022.0, 2078.0], [2973.0, 2093.0], [3007.0, 2095.0], [3051.0, 2113.0], [3055.0, 2128.0], [3044.0, 2153.0], [3043.0, 2189.0], [3066.0, 2238.0], [3069.0, 2269.0], [3049.0, 2299.0], [3073.0, 2303.0], [3094.0, 2306.0], [3103.0, 2225.0], [3136.0, 2196.0], [3135.0, 2188.0], [3126.0, 2194.0], [3168.0, 2163.0], [3157.0, 2149.0], [3163.0, 2130.0], [3141.0, 2120.0], [3137.0, 2109.0], [3141.0, 2083.0], [3120.0, 2077.0], [3119.0, 2109.0], [3115.0, 2078.0], [3104.0, 2078.0], [3115.0, 2074.0], [3112.0, 2064.0], [3088.0, 2062.0], [3078.0, 2047.0], [3083.0, 2035.0], [3092.0, 2042.0], [3094.0, 2033.0], [3106.0, 2038.0], [3122.0, 2017.0], [3115.0, 2006.0], [3103.0, 2008.0], [3105.0, 1998.0], [3132.0, 1971.0], [3125.0, 1979.0], [3107.0, 1972.0], [3107.0, 1956.0], [3093.0, 1963.0], [3081.0, 1966.0], [3101.0, 1952.0], [3089.0, 1939.0], [3101.0, 1935.0], [3121.0, 1953.0], [3124.0, 1964.0], [3142.0, 1956.0], [3131.0, 1919.0], [3111.0, 1905.0], [3105.0, 1918.0], [3103.0, 1908.0], [3086.0, 1912.0], [3099.0, 1897.0], [3092.0, 1887.0], [3077.0, 1887.0], [3093.0, 1881.0], [3103.0, 1894.0], [3102.0, 1874.0], [3093.0, 1869.0], [3105.0, 1870.0], [3111.0, 1858.0], [3110.0, 1895.0], [3124.0, 1899.0], [3131.0, 1881.0], [3144.0, 1877.0], [3133.0, 1886.0], [3146.0, 1889.0], [3133.0, 1891.0], [3168.0, 1888.0], [3172.0, 1866.0], [3183.0, 1858.0], [3187.0, 1826.0], [3176.0, 1824.0], [3194.0, 1797.0], [3178.0, 1774.0], [3162.0, 1766.0], [3156.0, 1779.0], [3117.0, 1761.0], [3119.0, 1775.0], [3097.0, 1784.0], [3092.0, 1795.0], [3097.0, 1783.0], [3096.0, 1772.0], [3082.0, 1774.0], [3110.0, 1736.0], [3127.0, 1740.0], [3138.0, 1756.0], [3159.0, 1740.0], [3187.0, 1737.0], [3203.0, 1725.0], [3210.0, 1699.0], [3190.0, 1698.0], [3192.0, 1690.0], [3205.0, 1682.0], [3235.0, 1684.0], [3241.0, 1675.0], [3262.0, 1675.0], [3272.0, 1637.0], [3286.0, 1633.0], [3260.0, 1692.0], [3238.0, 1703.0], [3239.0, 1732.0], [3231.0, 1767.0], [3217.0, 1783.0], [3258.0, 1823.0], [3270.0, 1815.0], [3249.0, 1859.0], [3209.0, 1900.0], [3211.0, 1916.0], [3183.0, 1980.0], [3196.0, 1975.0], [3194.0, 1987.0], [3203.0, 1997.0], [3195.0, 1995.0], [3175.0, 2017.0], [3194.0, 2030.0], [3207.0, 2029.0], [3194.0, 2046.0], [3179.0, 2049.0], [3186.0, 2075.0], [3215.0, 2074.0], [3232.0, 2105.0], [3250.0, 2117.0], [3273.0, 2089.0], [3330.0, 2061.0], [3341.0, 2049.0], [3370.0, 2001.0], [3344.0, 1990.0], [3321.0, 2016.0], [3321.0, 1997.0], [3311.0, 1995.0], [3307.0, 2006.0], [3305.0, 1984.0], [3291.0, 1993.0], [3294.0, 1972.0], [3287.0, 1985.0], [3266.0, 1991.0], [3272.0, 1999.0], [3262.0, 1999.0], [3268.0, 2009.0], [3258.0, 2004.0], [3247.0, 2014.0], [3239.0, 1990.0], [3232.0, 2003.0], [3235.0, 1987.0], [3248.0, 1976.0], [3232.0, 1931.0], [3249.0, 1880.0], [3283.0, 1873.0], [3280.0, 1883.0], [3254.0, 1882.0], [3246.0, 1919.0], [3237.0, 1925.0], [3256.0, 1964.0], [3266.0, 1955.0], [3266.0, 1968.0], [3288.0, 1957.0], [3277.0, 1953.0], [3281.0, 1942.0], [3293.0, 1948.0], [3316.0, 1916.0], [3298.0, 1954.0], [3313.0, 1970.0], [3339.0, 1952.0], [3324.0, 1969.0], [3332.0, 1984.0], [3347.0, 1968.0], [3364.0, 1980.0], [3375.0, 1976.0], [3387.0, 1964.0], [3378.0, 1953.0], [3389.0, 1962.0], [3399.0, 1946.0], [3408.0, 1946.0], [3396.0, 1937.0], [3412.0, 1943.0], [3429.0, 1933.0], [3390.0, 1971.0], [3390.0, 2008.0], [3402.0, 1974.0], [3451.0, 1903.0], [3477.0, 1909.0], [3508.0, 1895.0], [3556.0, 1852.0], [3573.0, 1824.0], [3616.0, 1797.0], [3610.0, 1781.0], [3599.0, 1780.0], [3601.0, 1795.0], [3581.0, 1797.0], [3584.0, 1806.0], [3575.0, 1800.0], [3573.0, 1783.0], [3573.0, 1772.0], [3579.0, 1792.0], [3577.0, 1762.0], [3585.0, 1760.0], [3584.0, 1783.0], [3601.0, 1792.0], [3595.0, 1778.0], [3608.0, 1779.0], [3609.0, 1771.0], [3602.0, 1756.0], [3619.0, 1769.0], [3630.0, 1746.0], [3609.0, 1734.0], [3622.0, 1726.0], [3633.0, 1673.0], [3625.0, 1656.0], [3616.0, 1662.0], [3603.0, 1637.0], [3627.0, 1651.0], [3604.0, 1630.0], [3588.0, 1604.0], [3564.0, 1640.0], [3570.0, 1625.0], [3553.0, 1620.0], [3541.0, 1601.0], [3527.0, 1597.0], [3525.0, 1569.0], [3523.0, 1539.0], [3534.0, 1502.0], [3533.0, 1531.0], [3544.0, 1544.0], [3542.0, 1553.0], [3556.0, 1554.0], [3546.0, 1571.0], [3558.0, 1578.0], [3555.0, 1596.0], [3587.0, 1546.0], [3603.0, 1473.0], [3627.0, 1443.0], [3625.0, 1422.0], [3667.0, 1400.0], [3667.0, 1370.0], [3651.0, 1351.0], [3682.0, 1339.0], [3722.0, 1374.0], [3778.0, 1376.0], [3760.0, 1362.0], [3748.0, 1337.0], [3784.0, 1353.0], [3807.0, 1317.0], [3809.0, 1292.0], [3822.0, 1285.0], [3817.0, 1297.0], [3825.0, 1325.0], [3807.0, 1354.0], [3832.0, 1359.0], [3824.0, 1361.0], [3827.0, 1369.0], [3864.0, 1376.0], [3877.0, 1401.0], [3897.0, 1405.0], [3907.0, 1393.0], [3894.0, 1372.0], [3903.0, 1353.0], [3897.0, 1343.0], [3906.0, 1344.0], [3916.0, 1320.0], [3943.0, 1312.0], [3974.0, 1284.0], [3993.0, 1285.0], [4015.0, 1251.0], [4016.0, 1224.0], [4038.0, 1198.0], [4057.0, 1198.0], [4055.0, 1187.0], [4073.0, 1191.0], [4062.0, 1199.0], [4074.0, 1200.0], [4069.0, 1208.0], [4070.0, 1220.0], [4065.0, 1247.0], [4057.0, 1255.0], [4067.0, 1262.0], [4057.0, 1256.0], [4042.0, 1296.0], [4022.0, 1317.0], [4034.0, 1330.0], [4024.0, 1334.0], [4005.0, 1320.0], [4005.0, 1344.0], [4009.0, 1369.0], [4025.0, 1379.0], [4012.0, 1378.0], [4004.0, 1394.0], [4028.0, 1418.0], [4017.0, 1429.0], [3987.0, 1431.0], [3971.0, 1442.0], [3972.0, 1476.0], [3957.0, 1504.0], [3952.0, 1553.0], [3942.0, 1580.0], [3928.0, 1593.0], [3932.0, 1638.0], [3923.0, 1673.0], [3881.0, 1719.0], [3880.0, 1735.0], [3894.0, 1743.0], [3926.0, 1726.0], [3923.0, 1713.0], [3952.0, 1698.0], [3956.0, 1664.0], [3976.0, 1664.0], [3979.0, 1651.0], [4000.0, 1650.0], [4003.0, 1622.0], [4022.0, 1623.0], [4023.0, 1611.0], [4040.0, 1604.0], [4036.0, 1592.0], [4062.0, 1588.0], [4062.0, 1573.0], [4087.0, 1555.0], [4095.0, 1555.0], [4111.0, 1553.0], [4115.0, 1537.0], [4127.0, 1533.0], [4121.0, 1502.0], [4104.0, 1505.0], [4096.0, 1492.0], [4112.0, 1499.0], [4112.0, 1478.0], [4140.0, 1496.0], [4160.0, 1481.0], [4200.0, 1507.0], [4224.0, 1474.0], [4224.0, 1532.0], [4190.0, 1541.0], [4159.0, 1541.0], [4133.0, 1596.0], [4137.0, 1606.0], [4178.0, 1607.0], [4218.0, 1604.0], [4224.0, 1600.0], [4224.0, 1660.0], [4208.0, 1675.0], [4211.0, 1663.0], [4203.0, 1664.0], [4222.0, 1658.0], [4194.0, 1643.0], [4175.0, 1662.0], [4175.0, 1679.0], [4156.0, 1678.0], [4161.0, 1687.0], [4140.0, 1688.0], [4138.0, 1680.0], [4148.0, 1682.0], [4128.0, 1651.0], [4097.0, 1650.0], [4070.0, 1649.0], [4043.0, 1687.0], [4056.0, 1707.0], [4095.0, 1718.0], [4082.0, 1720.0], [4076.0, 1739.0], [4078.0, 1727.0], [4057.0, 1724.0], [4052.0, 1713.0], [4030.0, 1710.0], [4030.0, 1698.0], [4011.0, 1694.0], [3982.0, 1748.0], [3976.0, 1783.0], [3959.0, 1802.0], [3925.0, 1812.0], [3906.0, 1845.0], [3899.0, 1878.0], [3908.0, 1864.0], [3920.0, 1880.0], [3920.0, 1871.0], [3945.0, 1880.0], [3949.0, 1887.0], [3935.0, 1887.0], [3939.0, 1900.0], [3911.0, 1900.0], [3906.0, 1910.0], [3908.0, 1885.0], [3893.0, 1900.0], [3894.0, 1887.0], [3884.0, 1881.0], [3807.0, 1927.0], [3795.0, 1949.0], [3802.0, 1978.0], [3593.0, 2033.0], [3585.0, 2049.0], [3571.0, 2080.0], [3524.0, 2110.0], [3517.0, 2123.0], [3516.0, 2140.0], [3532.0, 2163.0], [3529.0, 2177.0], [3536.0, 2167.0], [3561.0, 2172.0], [3575.0, 2164.0], [3577.0, 2174.0], [3549.0, 2180.0], [3565.0, 2191.0], [3571.0, 2211.0], [3624.0, 2208.0], [3642.0, 2181.0], [3633.0, 2209.0], [3658.0, 2215.0], [3666.0, 2233.0], [3650.0, 2240.0], [3649.0, 2226.0], [3620.0, 2230.0], [3610.0, 2225.0], [3611.0, 2215.0], [3582.0, 2225.0], [3572.0, 2218.0], [3556.0, 2222.0], [3544.0, 2213.0], [3550.0, 2197.0], [3538.0, 2180.0], [3515.0, 2183.0], [3492.0, 2211.0], [3514.0, 2208.0], [3529.0, 2215.0], [3538.0, 2235.0], [3573.0, 2263.0], [3551.0, 2256.0], [3551.0, 2247.0], [3508.0, 2218.0], [3497.0, 2225.0], [3499.0, 2240.0], [3489.0, 2242.0], [3495.0, 2251.0], [3478.0, 2222.0], [3482.0, 2204.0], [3496.0, 2201.0], [3492.0, 2177.0], [3473.0, 2183.0], [3469.0, 2173.0], [3449.0, 2171.0], [3416.0, 2188.0], [3408.0, 2213.0], [3373.0, 2236.0], [3372.0, 2260.0], [3392.0, 2266.0], [3397.0, 2277.0], [3395.0, 2310.0], [3412.0, 2312.0], [3424.0, 2326.0], [3422.0, 2345.0], [3461.0, 2323.0], [3458.0, 2334.0], [3435.0, 2347.0], [3420.0, 2377.0], [3423.0, 2387.0], [3439.0, 2383.0], [3453.0, 2390.0], [3454.0, 2406.0], [3446.0, 2397.0], [3429.0, 2391.0], [3416.0, 2421.0], [3429.0, 2428.0], [3404.0, 2425.0], [3421.0, 2404.0], [3412.0, 2398.0], [3409.0, 2362.0], [3424.0, 2364.0], [3386.0, 2337.0], [3358.0, 2377.0], [3330.0, 2383.0], [3327.0, 2413.0], [3317.0, 2430.0], [3319.0, 2451.0], [3328.0, 2475.0], [3341.0, 2479.0], [3353.0, 2469.0], [3345.0, 2477.0], [3350.0, 2495.0], [3358.0, 2493.0], [3369.0, 2510.0], [3390.0, 2502.0], [3389.0, 2510.0], [3411.0, 2510.0], [3370.0, 2515.0], [3382.0, 2540.0], [3391.0, 2541.0], [3380.0, 2550.0], [3356.0, 2506.0], [3334.0, 2492.0], [3318.0, 2495.0], [3324.0, 2515.0], [3318.0, 2503.0], [3310.0, 2498.0], [3315.0, 2488.0], [3299.0, 2467.0], [3305.0, 2435.0], [3296.0, 2430.0], [3299.0, 2420.0], [3289.0, 2427.0], [3269.0, 2463.0], [3277.0, 2460.0], [3282.0, 2481.0], [3268.0, 2466.0], [3259.0, 2491.0], [3248.0, 2537.0], [3260.0, 2547.0], [3251.0, 2543.0], [3247.0, 2555.0], [3245.0, 2543.0], [3231.0, 2579.0], [3214.0, 2595.0], [3197.0, 2630.0], [3206.0, 2627.0], [3203.0, 2639.0], [3177.0, 2701.0], [3135.0, 2759.0], [3116.0, 2805.0], [3104.0, 2876.0], [3111.0, 2884.0], [3138.0, 2885.0], [3148.0, 2820.0], [3144.0, 2798.0], [3154.0, 2834.0], [3141.0, 2892.0], [3154.0, 2875.0], [3169.0, 2870.0], [3157.0, 2851.0], [3165.0, 2826.0], [3177.0, 2824.0], [3170.0, 2813.0], [3178.0, 2799.0], [3202.0, 2789.0], [3213.0, 2794.0], [3189.0, 2797.0], [3178.0, 2816.0], [3193.0, 2826.0], [3175.0, 2830.0], [3171.0, 2848.0], [3186.0, 2880.0], [3193.0, 2859.0], [3205.0, 2853.0], [3193.0, 2869.0], [3203.0, 2878.0], [3201.0, 2904.0], [3188.0, 2906.0], [3175.0, 2883.0], [3165.0, 2900.0], [3154.0, 2884.0], [3150.0, 2905.0], [3183.0, 2933.0], [3193.0, 2924.0], [3216.0, 2933.0], [3202.0, 2943.0], [3200.0, 2962.0], [3207.0, 2972.0], [3205.0, 2988.0], [3223.0, 3007.0], [3194.0, 2987.0], [3195.0, 2998.0], [3229.0, 3061.0], [3243.0, 3129.0], [3240.0, 3182.0], [3258.0, 3195.0], [3250.0, 3204.0], [3241.0, 3199.0], [3247.0, 3212.0], [3215.0, 3281.0], [3073.0, 3283.0], [3071.0, 3283.0], [3032.0, 3283.0], [3004.0, 3303.0], [2976.0, 3371.0], [2979.0, 3405.0], [2970.0, 3445.0], [2943.0, 3481.0], [2943.0, 3494.0], [2922.0, 3493.0], [2951.0, 3507.0], [2939.0, 3508.0], [2932.0, 3528.0], [2947.0, 3520.0], [2931.0, 3530.0], [2940.0, 3549.0], [2920.0, 3528.0], [2880.0, 3594.0], [2860.0, 3665.0], [2858.0, 3698.0], [2867.0, 3707.0], [2847.0, 3750.0], [2857.0, 3750.0], [2848.0, 3751.0], [2853.0, 3863.0], [2876.0, 3855.0], [2854.0, 3866.0], [2849.0, 3917.0], [2820.0, 3998.0], [2821.0, 4035.0], [2842.0, 4044.0], [2856.0, 3991.0], [2890.0, 3967.0], [2918.0, 3973.0], [2908.0, 3941.0], [2912.0, 3906.0], [2919.0, 3946.0], [2924.0, 3926.0], [2930.0, 3937.0], [2923.0, 3962.0], [2932.0, 3957.0], [2923.0, 3972.0], [2951.0, 3940.0], [2928.0, 3836.0], [2933.0, 3820.0], [2922.0, 3857.0], [2923.0, 3835.0], [2907.0, 3828.0], [2904.0, 3812.0], [2922.0, 3826.0], [2936.0, 3790.0], [2926.0, 3778.0], [2918.0, 3784.0], [2914.0, 3758.0], [2900.0, 3761.0], [2915.0, 3749.0], [2907.0, 3715.0], [2914.0, 3700.0], [2922.0, 3762.0], [2935.0, 3749.0], [2929.0, 3727.0], [2956.0, 3736.0], [2955.0, 3712.0], [2969.0, 3763.0], [2988.0, 3763.0], [2992.0, 3722.0], [2979.0, 3716.0], [2979.0, 3701.0], [2998.0, 3681.0], [2975.0, 3653.0], [2959.0, 3649.0], [2946.0, 3662.0], [2958.0, 3641.0], [2953.0, 3631.0], [2933.0, 3642.0], [2929.0, 3631.0], [2939.0, 3625.0], [2934.0, 3609.0], [2945.0, 3617.0], [2918.0, 3606.0], [2932.0, 3599.0], [2923.0, 3593.0], [2938.0, 3593.0], [2941.0, 3601.0], [2952.0, 3594.0], [2955.0, 3613.0], [2963.0, 3616.0], [2954.0, 3620.0], [2969.0, 3621.0], [2980.0, 3627.0], [2979.0, 3638.0], [2985.0, 3630.0], [3000.0, 3642.0], [3000.0, 3622.0], [2983.0, 3621.0], [2994.0, 3612.0], [2975.0, 3584.0], [2985.0, 3592.0], [2984.0, 3572.0], [2995.0, 3591.0], [3002.0, 3551.0], [2993.0, 3527.0], [3007.0, 3553.0], [3006.0, 3601.0], [3016.0, 3595.0], [3040.0, 3577.0], [3017.0, 3529.0], [3032.0, 3527.0], [3036.0, 3554.0], [3058.0, 3554.0], [3047.0, 3564.0], [3045.0, 3587.0], [3036.0, 3598.0], [3019.0, 3614.0], [3026.0, 3645.0], [3036.0, 3653.0], [3034.0, 3663.0], [3025.0, 3658.0], [3027.0, 3687.0], [3019.0, 3682.0], [3007.0, 3691.0], [3014.0, 3715.0], [3033.0, 3724.0], [3043.0, 3800.0], [3059.0, 3796.0], [3041.0, 3737.0], [3057.0, 3689.0], [3051.0, 3680.0], [3059.0, 3680.0], [3067.0, 3675.0], [3061.0, 3654.0], [3073.0, 3642.0], [3073.0, 3663.0], [3077.0, 3637.0], [3073.0, 3628.0], [3065.0, 3625.0], [3064.0, 3605.0], [3073.0, 3622.0], [3081.0, 3621.0], [3085.0, 3612.0], [3090.0, 3571.0], [3093.0, 3607.0], [3075.0, 3651.0], [3079.0, 3671.0], [3102.0, 3651.0], [3086.0, 3674.0], [3097.0, 3669.0], [3123.0, 3640.0], [3124.0, 3651.0], [3138.0, 3647.0], [3125.0, 3661.0], [3152.0, 3732.0], [3161.0, 3721.0], [3169.0, 3736.0], [3171.0, 3723.0], [3188.0, 3727.0], [3186.0, 3706.0], [3159.0, 3700.0], [3148.0, 3665.0], [3161.0, 3677.0], [3165.0, 3664.0], [3176.0, 3660.0], [3173.0, 3649.0], [3181.0, 3644.0], [3170.0, 3634.0], [3181.0, 3632.0], [3181.0, 3622.0], [3172.0, 3625.0], [3176.0, 3598.0], [3161.0, 3609.0], [3148.0, 3604.0], [3144.0, 3623.0], [3132.0, 3611.0], [3109.0, 3614.0], [3120.0, 3605.0], [3113.0, 3574.0], [3121.0, 3580.0], [3125.0, 3606.0], [3141.0, 3601.0], [3139.0, 3592.0], [3158.0, 3598.0], [3153.0, 3573.0], [3143.0, 3572.0], [3139.0, 3563.0], [3158.0, 3566.0], [3161.0, 3576.0], [3175.0, 3567.0], [3183.0, 3546.0], [3194.0, 3557.0], [3190.0, 3545.0], [3179.0, 3542.0], [3190.0, 3543.0], [3191.0, 3533.0], [3177.0, 3532.0], [3191.0, 3531.0], [3191.0, 3513.0], [3204.0, 3506.0], [3211.0, 3538.0], [3206.0, 3526.0], [3197.0, 3546.0], [3205.0, 3561.0], [3217.0, 3558.0], [3224.0, 3568.0], [3212.0, 3573.0], [3222.0, 3576.0], [3230.0, 3566.0], [3250.0, 3562.0], [3251.0, 3576.0], [3260.0, 3569.0], [3265.0, 3578.0], [3264.0, 3589.0], [3255.0, 3590.0], [3259.0, 3600.0], [3270.0, 3601.0], [3274.0, 3592.0], [3293.0, 3596.0], [3278.0, 3596.0], [3269.0, 3609.0], [3273.0, 3620.0], [3261.0, 3620.0], [3264.0, 3611.0], [3248.0, 3614.0], [3230.0, 3590.0], [3230.0, 3648.0], [3253.0, 3651.0], [3258.0, 3631.0], [3268.0, 3627.0], [3277.0, 3626.0], [3294.0, 3644.0], [3302.0, 3633.0], [3308.0, 3658.0], [3296.0, 3662.0], [3295.0, 3683.0], [3318.0, 3690.0], [3318.0, 3675.0], [3318.0, 3685.0], [3331.0, 3685.0], [3318.0, 3691.0], [3326.0, 3713.0], [3318.0, 3749.0], [3322.0, 3774.0], [3320.0, 3830.0], [3332.0, 3821.0], [3331.0, 3794.0], [3341.0, 3772.0], [3340.0, 3814.0], [3348.0, 3806.0], [3367.0, 3807.0], [3374.0, 3794.0], [3368.0, 3767.0], [3378.0, 3753.0], [3370.0, 3729.0], [3382.0, 3741.0], [3388.0, 3718.0], [3385.0, 3692.0], [3392.0, 3701.0], [3394.0, 3688.0], [3396.0, 3736.0], [3378.0, 3764.0], [3377.0, 3804.0], [3370.0, 3816.0], [3341.0, 3823.0], [3313.0, 3975.0], [3340.0, 4009.0], [3347.0, 4053.0], [3375.0, 4064.0], [3358.0, 4069.0], [3352.0, 4090.0], [3363.0, 4085.0], [3363.0, 4095.0], [3363.0, 4097.0], [3351.0, 4097.0], [3336.0, 4132.0], [3339.0, 4140.0], [3347.0, 4136.0], [3338.0, 4143.0], [3333.0, 4172.0], [3343.0, 4187.0], [3350.0, 4178.0], [3342.0, 4153.0], [3358.0, 4157.0], [3364.0, 4144.0], [3376.0, 4143.0], [3370.0, 4133.0], [3382.0, 4134.0], [3382.0, 4126.0], [3397.0, 4133.0], [3412.0, 4121.0], [3411.0, 4107.0], [3426.0, 4095.0], [3447.0, 4079.0], [3434.0, 4095.0], [3422.0, 4108.0], [3430.0, 4116.0], [3415.0, 4123.0], [3432.0, 4132.0], [3381.0, 4147.0], [3388.0, 4166.0], [3375.0, 4160.0], [3354.0, 4196.0], [3357.0, 4209.0], [3374.0, 4204.0], [3370.0, 4224.0], [3394.0, 4224.0], [3407.0, 4205.0], [3415.0, 4210.0], [3423.0, 4197.0], [3440.0, 4196.0], [3436.0, 4184.0], [3446.0, 4195.0], [3462.0, 4189.0], [3471.0, 4195.0], [3431.0, 4202.0], [3426.0, 4213.0], [3407.0, 4224.0], [3492.0, 4224.0], [3492.0, 4214.0], [3504.0, 4219.0], [3519.0, 4197.0], [3519.0, 4205.0], [3534.0, 4208.0], [3540.0, 4194.0], [3554.0, 4190.0], [3555.0, 4177.0], [3543.0, 4181.0], [3542.0, 4146.0], [3532.0, 4145.0], [3547.0, 4138.0], [3541.0, 4097.0], [3542.0, 4085.0], [3529.0, 4075.0], [3539.0, 4068.0], [3538.0, 4079.0], [3546.0, 4082.0], [3571.0, 4062.0], [3595.0, 4025.0], [3604.0, 4025.0], [3622.0, 3995.0], [3611.0, 4014.0], [3613.0, 4022.0], [3593.0, 4031.0], [3578.0, 4058.0], [3585.0, 4067.0], [3577.0, 4064.0], [3550.0, 4085.0], [3555.0, 4097.0], [3563.0, 4098.0], [3556.0, 4120.0], [3574.0, 4111.0], [3556.0, 4126.0], [3558.0, 4139.0], [3550.0, 4148.0], [3563.0, 4173.0], [3586.0, 4157.0], [3586.0, 4140.0], [3593.0, 4151.0], [3603.0, 4135.0], [3609.0, 4145.0], [3635.0, 4134.0], [3588.0, 4160.0], [3565.0, 4189.0], [3570.0, 4199.0], [3645.0, 4193.0], [3605.0, 4203.0], [3608.0, 4214.0], [3591.0, 4201.0], [3586.0, 4215.0], [3573.0, 4206.0], [3563.0, 4209.0], [3563.0, 4201.0], [3553.0, 4204.0], [3540.0, 4221.0], [3546.0, 4224.0], [3275.0, 4224.0], [3266.0, 4212.0], [3279.0, 4218.0], [3289.0, 4199.0], [3274.0, 4194.0], [3279.0, 4180.0], [3261.0, 4189.0], [3271.0, 4177.0], [3266.0, 4168.0], [3263.0, 4158.0], [3287.0, 4177.0], [3299.0, 4162.0], [3252.0, 4108.0], [3248.0, 4128.0], [3238.0, 4106.0], [3246.0, 4101.0], [3220.0, 4105.0], [3228.0, 4123.0], [3206.0, 4134.0], [3212.0, 4120.0], [3194.0, 4119.0], [3201.0, 4110.0], [3191.0, 4095.0], [3179.0, 4089.0], [3186.0, 4069.0], [3171.0, 4059.0], [3165.0, 4072.0], [3161.0, 4085.0], [3159.0, 4075.0], [3149.0, 4081.0], [3145.0, 4068.0], [3157.0, 4065.0], [3148.0, 4061.0], [3167.0, 4049.0], [3164.0, 4029.0], [3156.0, 4017.0], [3151.0, 4027.0], [3152.0, 4035.0], [3137.0, 4038.0], [3137.0, 4057.0], [3132.0, 4040.0], [3124.0, 4037.0], [3136.0, 4030.0], [3128.0, 4014.0], [3140.0, 4016.0], [3140.0, 3987.0], [3180.0, 3967.0], [3175.0, 3949.0], [3100.0, 4026.0], [3096.0, 4037.0], [3108.0, 4063.0], [3091.0, 4065.0], [3079.0, 4085.0], [3071.0, 4081.0], [3056.0, 4084.0], [3047.0, 4074.0], [3033.0, 4097.0], [3018.0, 4123.0], [3013.0, 4192.0], [2988.0, 4224.0], [3027.0, 4224.0], [3044.0, 4223.0], [3040.0, 4206.0], [3046.0, 4219.0], [3051.0, 4224.0], [3074.0, 4224.0], [3074.0, 4216.0], [3076.0, 4224.0], [3116.0, 4224.0], [3111.0, 4198.0], [3098.0, 4192.0], [3094.0, 4178.0], [3075.0, 4180.0], [3069.0, 4127.0], [3080.0, 4145.0], [3084.0, 4173.0], [3089.0, 4165.0], [3100.0, 4169.0], [3124.0, 4202.0], [3132.0, 4190.0], [3125.0, 4181.0], [3134.0, 4185.0], [3139.0, 4173.0], [3127.0, 4143.0], [3138.0, 4148.0], [3157.0, 4136.0], [3154.0, 4122.0], [3144.0, 4124.0], [3155.0, 4117.0], [3153.0, 4109.0], [3166.0, 4134.0], [3140.0, 4155.0], [3146.0, 4172.0], [3131.0, 4214.0], [3133.0, 4224.0], [3161.0, 4224.0], [3164.0, 4180.0], [3176.0, 4203.0], [3203.0, 4177.0], [3199.0, 4162.0], [3213.0, 4155.0], [3207.0, 4166.0], [3216.0, 4186.0], [3208.0, 4187.0], [3205.0, 4199.0], [3189.0, 4197.0], [3188.0, 4215.0], [3170.0, 4221.0], [3169.0, 4224.0], [4224.0, 4224.0], [4224.0, 4224.0], [4224.0, -128.0], [4224.0, -128.0], [4224.0, 4224.0], [2293.0, 4224.0]], [[2681.0, 3125.0], [2665.0, 3123.0], [2657.0, 3113.0], [2655.0, 3124.0], [2640.0, 3119.0], [2663.0, 3099.0], [2642.0, 3105.0], [2640.0, 3089.0], [2618.0, 3108.0], [2622.0, 3138.0], [2622.0, 3153.0], [2653.0, 3192.0], [2652.0, 3210.0], [2685.0, 3144.0], [2681.0, 3125.0], [2681.0, 3125.0]], [[3189.0, 3788.0], [3205.0, 3783.0], [3206.0, 3763.0], [3193.0, 3756.0], [3189.0, 3788.0], [3189.0, 3788.0]], [[2264.0, 3287.0], [2274.0, 3283.0], [2269.0, 3270.0], [2264.0, 3287.0], [2264.0, 3287.0]], [[2436.0, 3801.0], [2448.0, 3809.0], [2446.0, 3798.0], [2462.0, 3796.0], [2473.0, 3772.0], [2449.0, 3781.0], [2436.0, 3801.0], [2436.0, 3801.0]], [[3142.0, 2365.0], [3155.0, 2362.0], [3162.0, 2350.0], [3157.0, 2333.0], [3137.0, 2341.0], [3122.0, 2401.0], [3142.0, 2382.0], [3142.0, 2365.0], [3142.0, 2365.0]], [[2761.0, 2561.0], [2769.0, 2563.0], [2780.0, 2549.0], [2761.0, 2561.0], [2761.0, 2561.0]], [[2923.0, 2209.0], [2910.0, 2200.0], [2893.0, 2208.0], [2898.0, 2222.0], [2888.0, 2211.0], [2896.0, 2176.0], [2880.0, 2194.0], [2862.0, 2195.0], [2864.0, 2218.0], [2876.0, 2232.0], [2865.0, 2227.0], [2886.0, 2247.0], [2879.0, 2258.0], [2884.0, 2266.0], [2892.0, 2260.0], [2894.0, 2239.0], [2912.0, 2246.0], [2917.0, 2236.0], [2916.0, 2251.0], [2907.0, 2248.0], [2924.0, 2290.0], [2934.0, 2286.0], [2922.0, 2272.0], [2928.0, 2254.0], [2955.0, 2248.0], [2946.0, 2241.0], [2942.0, 2214.0], [2923.0, 2209.0], [2923.0, 2209.0]], [[2823.0, 2539.0], [2896.0, 2498.0], [2911.0, 2465.0], [2876.0, 2449.0], [2805.0, 2496.0], [2788.0, 2496.0], [2792.0, 2523.0], [2823.0, 2539.0], [2823.0, 2539.0]], [[3675.0, 1790.0], [3740.0, 1742.0], [3708.0, 1651.0], [3683.0, 1646.0], [3674.0, 1634.0], [3638.0, 1646.0], [3652.0, 1647.0], [3661.0, 1664.0], [3670.0, 1660.0], [3649.0, 1717.0], [3641.0, 1722.0], [3647.0, 1736.0], [3635.0, 1756.0], [3653.0, 1759.0], [3655.0, 1741.0], [3666.0, 1743.0], [3674.0, 1731.0], [3662.0, 1730.0], [3661.0, 1711.0], [3670.0, 1711.0], [3674.0, 1691.0], [3678.0, 1695.0], [3678.0, 1649.0], [3688.0, 1650.0], [3692.0, 1665.0], [3684.0, 1671.0], [3686.0, 1698.0], [3696.0, 1689.0], [3696.0, 1708.0], [3707.0, 1709.0], [3696.0, 1719.0], [3707.0, 1731.0], [3719.0, 1729.0], [3702.0, 1729.0], [3696.0, 1741.0], [3688.0, 1759.0], [3676.0, 1769.0], [3675.0, 1790.0], [3675.0, 1790.0]]];
-testOutput["water"]["triangles"]=[12, 13, 14, 15, 16, 17, 18, 19, 20, 20, 21, 22, 22, 23, 24, 24, 25, 26, 29, 30, 31, 31, 32, 33, 33, 34, 35, 35, 36, 37, 39, 40, 41, 42, 43, 44, 46, 47, 48, 48, 49, 50, 50, 51, 52, 53, 54, 55, 56, 57, 58, 58, 59, 60, 61, 62, 63, 63, 64, 65, 66, 67, 68, 70, 71, 72, 72, 73, 74, 74, 75, 76, 77, 78, 79, 82, 83, 84, 84, 85, 86, 88, 89, 90, 90, 91, 92, 94, 95, 96, 99, 100, 101, 101, 102, 103, 103, 104, 105, 105, 106, 107, 110, 111, 112, 112, 113, 114, 115, 116, 117, 122, 123, 124, 125, 126, 127, 127, 128, 129, 129, 130, 131, 131, 132, 133, 134, 135, 136, 141, 142, 143, 143, 144, 145, 145, 2424, 2425, 2425, 2426, 2427, 2429, 2430, 145, 145, 146, 147, 147, 148, 149, 151, 152, 153, 155, 156, 157, 158, 159, 160, 162, 163, 164, 164, 165, 166, 167, 168, 169, 169, 170, 171, 173, 174, 175, 177, 178, 179, 184, 185, 186, 187, 188, 189, 189, 190, 191, 193, 194, 195, 195, 196, 197, 198, 199, 200, 201, 202, 203, 203, 204, 205, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 218, 219, 220, 221, 222, 223, 224, 225, 226, 228, 229, 230, 231, 232, 233, 237, 238, 239, 239, 240, 241, 242, 243, 244, 244, 245, 246, 246, 247, 248, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260, 261, 261, 262, 263, 263, 264, 265, 265, 266, 267, 269, 270, 271, 273, 274, 275, 275, 276, 277, 279, 280, 281, 282, 283, 284, 284, 285, 286, 288, 289, 290, 294, 295, 296, 296, 297, 298, 300, 301, 302, 303, 304, 305, 305, 306, 307, 309, 310, 311, 312, 313, 314, 315, 316, 317, 319, 320, 321, 326, 327, 328, 329, 330, 331, 331, 332, 333, 333, 334, 335, 335, 336, 337, 337, 338, 339, 339, 340, 341, 344, 345, 346, 349, 350, 351, 353, 354, 355, 355, 356, 357, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 372, 373, 374, 377, 378, 379, 382, 383, 384, 384, 385, 386, 388, 389, 390, 391, 392, 393, 393, 394, 395, 397, 398, 399, 399, 400, 401, 401, 402, 403, 405, 406, 407, 409, 410, 411, 411, 412, 413, 414, 415, 416, 420, 421, 422, 424, 425, 426, 426, 427, 428, 429, 430, 431, 433, 434, 435, 436, 437, 438, 440, 441, 442, 445, 446, 447, 447, 448, 449, 449, 450, 451, 451, 452, 453, 454, 455, 456, 458, 459, 460, 461, 462, 463, 465, 466, 467, 468, 469, 470, 470, 471, 472, 472, 473, 474, 475, 476, 477, 478, 479, 480, 484, 485, 486, 486, 487, 488, 490, 491, 492, 494, 495, 496, 497, 498, 499, 499, 500, 501, 502, 503, 504, 505, 506, 507, 507, 508, 509, 510, 511, 512, 512, 513, 514, 517, 518, 519, 519, 520, 521, 522, 523, 524, 529, 530, 531, 531, 532, 533, 534, 535, 536, 538, 539, 540, 540, 541, 542, 542, 543, 544, 545, 546, 547, 551, 552, 553, 556, 557, 558, 558, 559, 560, 561, 562, 563, 563, 564, 565, 567, 568, 569, 569, 570, 571, 573, 574, 575, 575, 576, 577, 577, 578, 579, 581, 582, 583, 584, 585, 586, 588, 589, 590, 594, 595, 596, 599, 600, 601, 602, 603, 604, 607, 608, 609, 609, 610, 611, 611, 612, 613, 613, 614, 615, 615, 616, 617, 618, 619, 620, 620, 621, 622, 623, 624, 625, 627, 628, 629, 632, 633, 634, 640, 641, 642, 645, 646, 647, 647, 648, 649, 649, 650, 651, 652, 653, 654, 655, 656, 657, 658, 659, 660, 661, 662, 663, 665, 666, 667, 667, 668, 2405, 2405, 2406, 2407, 2407, 2408, 2409, 2397, 2398, 2399, 2399, 2400, 2401, 2402, 2403, 2404, 2404, 2405, 668, 669, 670, 671, 672, 673, 674, 676, 677, 678, 678, 679, 680, 682, 683, 684, 684, 685, 686, 687, 688, 689, 689, 690, 691, 692, 693, 694, 696, 697, 698, 698, 699, 700, 700, 701, 702, 703, 704, 705, 705, 706, 707, 707, 708, 709, 709, 710, 711, 712, 713, 714, 714, 715, 716, 717, 718, 719, 719, 720, 721, 721, 722, 723, 725, 726, 727, 729, 730, 731, 733, 734, 735, 736, 737, 738, 739, 740, 741, 743, 744, 745, 745, 746, 747, 747, 748, 749, 752, 753, 754, 754, 755, 756, 756, 757, 758, 759, 760, 761, 761, 762, 763, 763, 764, 765, 765, 766, 767, 767, 768, 769, 770, 771, 772, 772, 773, 774, 774, 775, 776, 776, 777, 778, 779, 780, 781, 783, 784, 785, 785, 786, 787, 788, 789, 790, 791, 792, 793, 795, 796, 797, 798, 799, 800, 800, 801, 802, 803, 804, 805, 805, 806, 807, 807, 808, 809, 809, 810, 811, 812, 813, 814, 814, 815, 816, 816, 817, 818, 820, 821, 822, 822, 823, 824, 824, 825, 826, 828, 829, 830, 831, 832, 833, 834, 835, 836, 837, 838, 839, 839, 840, 841, 841, 842, 843, 844, 845, 846, 847, 848, 849, 851, 852, 853, 853, 854, 855, 855, 856, 857, 857, 858, 859, 859, 860, 861, 862, 863, 864, 865, 866, 867, 867, 868, 869, 870, 871, 872, 872, 873, 874, 874, 875, 876, 876, 877, 878, 878, 879, 880, 880, 881, 882, 882, 883, 884, 885, 886, 887, 887, 888, 889, 889, 890, 891, 892, 893, 894, 896, 897, 898, 901, 902, 903, 904, 905, 906, 908, 909, 910, 910, 911, 912, 915, 916, 917, 917, 918, 919, 919, 920, 921, 922, 923, 924, 926, 927, 928, 928, 929, 930, 932, 933, 934, 934, 935, 936, 938, 939, 940, 940, 941, 942, 942, 943, 944, 945, 946, 947, 948, 949, 950, 951, 952, 953, 954, 955, 956, 956, 957, 958, 958, 959, 960, 961, 962, 963, 963, 964, 965, 965, 966, 967, 968, 969, 970, 971, 972, 973, 974, 975, 976, 977, 978, 979, 980, 981, 982, 983, 984, 985, 986, 987, 988, 989, 990, 991, 991, 992, 993, 996, 997, 998, 998, 999, 1000, 1000, 1001, 1002, 1004, 1005, 1006, 1009, 1010, 1011, 1011, 1012, 1013, 1014, 1015, 1016, 1016, 1017, 1018, 1020, 1021, 1022, 1022, 1023, 1024, 1025, 1026, 2441, 2443, 2444, 1026, 1027, 1028, 1029, 1030, 1031, 1032, 1033, 1034, 1035, 1036, 1037, 1038, 1038, 1039, 1040, 1042, 1043, 1044, 1044, 1045, 1046, 1047, 1048, 1049, 1049, 1050, 1051, 1051, 1052, 1053, 1053, 1054, 1055, 1057, 1058, 1059, 1059, 1060, 1061, 1062, 1063, 1064, 1066, 1067, 1068, 1070, 1071, 1072, 1074, 1075, 1076, 1076, 1077, 1078, 1078, 1079, 1080, 1080, 1081, 1082, 1083, 1084, 1085, 1086, 1087, 1088, 1088, 1089, 1090, 1090, 1091, 1092, 1092, 1093, 1094, 1095, 1096, 1097, 1098, 1099, 2480, 2478, 2479, 2480, 2480, 1099, 1100, 1100, 1101, 1102, 1104, 1105, 2437, 2438, 2432, 2433, 1106, 1107, 1108, 1108, 1109, 1110, 1110, 1111, 1112, 1113, 1114, 1115, 1116, 1117, 1118, 1118, 1119, 1120, 1120, 1121, 1122, 1123, 1124, 1125, 1125, 1126, 1127, 1127, 1128, 1129, 1130, 1131, 1132, 1134, 1135, 1136, 1137, 1138, 1139, 1143, 1144, 1145, 1145, 1146, 1147, 1147, 1148, 1149, 1151, 1152, 1153, 1153, 1154, 1155, 1155, 1156, 1157, 1157, 1158, 1159, 1159, 1160, 1161, 1161, 1162, 1163, 1165, 1166, 1167, 1168, 1169, 1170, 1172, 1173, 1174, 1175, 1176, 1177, 1180, 1181, 1182, 1182, 1183, 1184, 1184, 1185, 1186, 1187, 1188, 1189, 1192, 1193, 1194, 1195, 1196, 1197, 1200, 1201, 1202, 1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1211, 1211, 1212, 1213, 1215, 1216, 1217, 1217, 1218, 1219, 1223, 1224, 1225, 1226, 1227, 1228, 1228, 1229, 1230, 1231, 1232, 1233, 1234, 1235, 1236, 1236, 1237, 1238, 1240, 1241, 1242, 2454, 2455, 2456, 2456, 2457, 2458, 2462, 2463, 2464, 2467, 2468, 2469, 2470, 2471, 2472, 2472, 2446, 2447, 2448, 2449, 2450, 2451, 2452, 2453, 1247, 1248, 1249, 1250, 1251, 1252, 1254, 1255, 1256, 1257, 1258, 1259, 1260, 1261, 1262, 1262, 1263, 1264, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1272, 1273, 1274, 1275, 1276, 1277, 1279, 1280, 1281, 1282, 1283, 1284, 1286, 1287, 1288, 1288, 1289, 1290, 1290, 1291, 1292, 1293, 1294, 1295, 1296, 1297, 1298, 1298, 1299, 1301, 1303, 1304, 1305, 1305, 1306, 1307, 1308, 1309, 1310, 1311, 1312, 1313, 1313, 1314, 1315, 1318, 1319, 1320, 1322, 1323, 1324, 1324, 1325, 1326, 1327, 1328, 1329, 1329, 1330, 1331, 1332, 1333, 1334, 1336, 1337, 1338, 1338, 1339, 1340, 1340, 1341, 1342, 1343, 1344, 1345, 1345, 1346, 1347, 1348, 1349, 1350, 1353, 1354, 1355, 1356, 1357, 1358, 1358, 1359, 1360, 1360, 1361, 1362, 1364, 1365, 1366, 1366, 1367, 1368, 1369, 1370, 1371, 1371, 1372, 1373, 1373, 1374, 1375, 1377, 1378, 1379, 1379, 1380, 1381, 1381, 1382, 1383, 1383, 1384, 1385, 1386, 1387, 1388, 1388, 1389, 1390, 1393, 1394, 1395, 1395, 1396, 1397, 1398, 1399, 1400, 1402, 1403, 1404, 1404, 1405, 1406, 1408, 1409, 1410, 1410, 1411, 1412, 1413, 1414, 1415, 1416, 1417, 1418, 1420, 1421, 1422, 1422, 1423, 1424, 1424, 1425, 1426, 1427, 1428, 1429, 1429, 1430, 1431, 1431, 1432, 1433, 1435, 1436, 1437, 1437, 1438, 1439, 1439, 1440, 1441, 1441, 1442, 1443, 1445, 1446, 1447, 1449, 1450, 1451, 1452, 1453, 1454, 1454, 1455, 1456, 1456, 1457, 1458, 1460, 1461, 1462, 1464, 1465, 1466, 1467, 1468, 1469, 1472, 1473, 1474, 1475, 1476, 1477, 1477, 1478, 1479, 1479, 1480, 1481, 1481, 1482, 1483, 1483, 1484, 1485, 1485, 1486, 1487, 1487, 1488, 1489, 1492, 1493, 1494, 1495, 1496, 1497, 1498, 1499, 1500, 1501, 1502, 1503, 1504, 1505, 1506, 1507, 1508, 1509, 1510, 1511, 1512, 1514, 1515, 1516, 1516, 1517, 1518, 1518, 1519, 1520, 1520, 1521, 1522, 1523, 1524, 1525, 1528, 1529, 1530, 1532, 1533, 1534, 1534, 1535, 1536, 1536, 1537, 1538, 1539, 1540, 1541, 1546, 1547, 1548, 1548, 2496, 2497, 2497, 2498, 2499, 2500, 2501, 2502, 2503, 2504, 2505, 2505, 2506, 2507, 2507, 2508, 2509, 2510, 2511, 2512, 2512, 2513, 2514, 2518, 2519, 2520, 2486, 2487, 2488, 2489, 2490, 2491, 2491, 2492, 2493, 2494, 2495, 2496, 1549, 1550, 1551, 1553, 1554, 1555, 1556, 1557, 1558, 1558, 1559, 1560, 1560, 1561, 1562, 1562, 1563, 1564, 1564, 1565, 1566, 1567, 1568, 1569, 1569, 1570, 1571, 1571, 1572, 1573, 1574, 1575, 1576, 1576, 1577, 1578, 1579, 1580, 1581, 1583, 1584, 1585, 1587, 1588, 1589, 1590, 1591, 1592, 1592, 1593, 1594, 1595, 1596, 1597, 1599, 1600, 1601, 1601, 1602, 1603, 1603, 1604, 1605, 1605, 1606, 1607, 1608, 1609, 1610, 1611, 1612, 1613, 1614, 1615, 1616, 1616, 1617, 1618, 1619, 1620, 1621, 1621, 1622, 1623, 1623, 1624, 1625, 1628, 1629, 1630, 1631, 1632, 1633, 1635, 1636, 1637, 1637, 1638, 1639, 1640, 1641, 1642, 1646, 1647, 1648, 1648, 1649, 1650, 1650, 1651, 1652, 1652, 1653, 1654, 1654, 1655, 1656, 1656, 1657, 1658, 1658, 1659, 1660, 1662, 1663, 1664, 1666, 1667, 1668, 1668, 1669, 1670, 1670, 1671, 1672, 1672, 1673, 1674, 1674, 1675, 1676, 1680, 1681, 1682, 1682, 1683, 1684, 1684, 1685, 1686, 1688, 1689, 1690, 1690, 1691, 1692, 1692, 1693, 1694, 1695, 1696, 1697, 1699, 1700, 1701, 1701, 1702, 1703, 1703, 1704, 1705, 1705, 1706, 1707, 1709, 1710, 1711, 1714, 1715, 1716, 1716, 1717, 1718, 1718, 1719, 1720, 1720, 1721, 1722, 1722, 1723, 1724, 1724, 1725, 1726, 1729, 1730, 1731, 1732, 1733, 1734, 1736, 1737, 1738, 1738, 1739, 1740, 1740, 1741, 1742, 1743, 1744, 1745, 1746, 1747, 1748, 1748, 1749, 1750, 1750, 1751, 1752, 1752, 1753, 1754, 1755, 1756, 1757, 1757, 1758, 1759, 1763, 1764, 1765, 1767, 1768, 1769, 1771, 1772, 1773, 1774, 1775, 1776, 1778, 1779, 1780, 1782, 1783, 1784, 1785, 1786, 1787, 1788, 1789, 1790, 1791, 1792, 1793, 1796, 1797, 1798, 1798, 1799, 1800, 1802, 1803, 1804, 1805, 1806, 1807, 1809, 1810, 1811, 1811, 1812, 1813, 1816, 1817, 1818, 1819, 1820, 1821, 1821, 1822, 1823, 1823, 1824, 1825, 1826, 1827, 1828, 1831, 1832, 1833, 1833, 1834, 1835, 1835, 1836, 1837, 1837, 1838, 1839, 1841, 1842, 1843, 1846, 1847, 1848, 1848, 1849, 1850, 1850, 1851, 1852, 1853, 1854, 1855, 1855, 1856, 1857, 1862, 1863, 1864, 1865, 1866, 1867, 1867, 1868, 1869, 1870, 1871, 1872, 1873, 1874, 1875, 1881, 1882, 1883, 1883, 1884, 1885, 1885, 1886, 1887, 1887, 1888, 1889, 1891, 1892, 1893, 1895, 1896, 1897, 1897, 1898, 1899, 1900, 1901, 1902, 1903, 1904, 1905, 1906, 1907, 1908, 1908, 1909, 1911, 1913, 1914, 1915, 1916, 1917, 1918, 1918, 1919, 1920, 1921, 1922, 1923, 1923, 1924, 1925, 1928, 1929, 1930, 1930, 1931, 1932, 1933, 1934, 1935, 1935, 1936, 1937, 1939, 1940, 1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1949, 1950, 1951, 1952, 1953, 1953, 1954, 1955, 1955, 1956, 1957, 1960, 1961, 1962, 1962, 1963, 1964, 1964, 1965, 1966, 1968, 1969, 1970, 1970, 1971, 1972, 1974, 1975, 1976, 1979, 1980, 1981, 1982, 1983, 1984, 1985, 1986, 1987, 1987, 1988, 1989, 1989, 1990, 1991, 1992, 1993, 1994, 1994, 1995, 1996, 1996, 1997, 1998, 1999, 2000, 2001, 2002, 2003, 2004, 2004, 2005, 2006, 2006, 2007, 2008, 2009, 2010, 2011, 2015, 2016, 2017, 2017, 2018, 2019, 2019, 2020, 2021, 2023, 2024, 2025, 2026, 2027, 2028, 2030, 2031, 2032, 2033, 2034, 2413, 2035, 2036, 2037, 2038, 2039, 2040, 2043, 2044, 2045, 2048, 2049, 2050, 2052, 2053, 2054, 2055, 2056, 2057, 2057, 2058, 2059, 2060, 2061, 2062, 2062, 2063, 2064, 2065, 2066, 2067, 2068, 2069, 2070, 2070, 2071, 2072, 2072, 2073, 2074, 2075, 2076, 2077, 2077, 2078, 2079, 2079, 2080, 2081, 2081, 2082, 2083, 2083, 2084, 2085, 2087, 2088, 2089, 2090, 2091, 2092, 2092, 2093, 2094, 2095, 2096, 2097, 2098, 2099, 2100, 2101, 2102, 2103, 2103, 2104, 2105, 2105, 2106, 2107, 2109, 2110, 2111, 2113, 2114, 2115, 2116, 2117, 2118, 2118, 2119, 2120, 2123, 2124, 2125, 2126, 2127, 2128, 2129, 2130, 2131, 2133, 2134, 2135, 2135, 2136, 2137, 2137, 2138, 2139, 2142, 2144, 2145, 2146, 2147, 2148, 2148, 2149, 2150, 2151, 2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2159, 2160, 2161, 2162, 2163, 2164, 2164, 2165, 2166, 2168, 2169, 2170, 2171, 2172, 2173, 2173, 2174, 2175, 2176, 2177, 2179, 2182, 2183, 2184, 2187, 2188, 2189, 2189, 2190, 2191, 2191, 2192, 2193, 2193, 2194, 2195, 2196, 2197, 2198, 2198, 2199, 2200, 2201, 2202, 2203, 2203, 2204, 2205, 2205, 2206, 2207, 2209, 2210, 2211, 2212, 2213, 2214, 2214, 2215, 2216, 2216, 2217, 2218, 2218, 2219, 2220, 2221, 2222, 2223, 2224, 2225, 2226, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2234, 2235, 2236, 2236, 2237, 2238, 2239, 2240, 2241, 2245, 2246, 2247, 2250, 2251, 2252, 2254, 2255, 2256, 2256, 2257, 2258, 2258, 2259, 2260, 2262, 2263, 2264, 2264, 2265, 2266, 2266, 2267, 2268, 2272, 2273, 2274, 2274, 2275, 2276, 2276, 2277, 2278, 2282, 2283, 2284, 2285, 2286, 2287, 2287, 2288, 2289, 2289, 2290, 2291, 2294, 2295, 2296, 2298, 2299, 2300, 2301, 2302, 2303, 2304, 2305, 2306, 2308, 2309, 2310, 2310, 2311, 2312, 2313, 2314, 2315, 2318, 2319, 2320, 2320, 2321, 2322, 2322, 2323, 2324, 2324, 2325, 2326, 2326, 2327, 2328, 2331, 2332, 2333, 2333, 2334, 2335, 2335, 2336, 2337, 2339, 2340, 2341, 2343, 2344, 2345, 2347, 2348, 2349, 2351, 2352, 2353, 2354, 2355, 2356, 2357, 2358, 2359, 2361, 2362, 2363, 2364, 2365, 2366, 2368, 2369, 2370, 2370, 2371, 2372, 2377, 2378, 2379, 2381, 2382, 2383, 2383, 2384, 2385, 2385, 2386, 2387, 2387, 2388, 2389, 1, 2, 3, 4, 5, 6, 7, 8, 9, 12, 14, 15, 18, 20, 22, 22, 24, 26, 29, 31, 33, 33, 35, 37, 45, 46, 48, 50, 52, 53, 55, 56, 58, 61, 63, 65, 66, 68, 69, 70, 72, 74, 76, 77, 79, 84, 86, 87, 88, 90, 92, 94, 96, 97, 105, 107, 108, 115, 117, 118, 122, 124, 125, 127, 129, 131, 133, 134, 136, 140, 141, 143, 143, 145, 2425, 2429, 145, 147, 147, 149, 150, 151, 153, 154, 155, 157, 158, 158, 160, 161, 166, 167, 169, 169, 171, 172, 184, 186, 187, 193, 195, 197, 201, 203, 205, 207, 208, 210, 211, 213, 214, 214, 216, 217, 221, 223, 224, 224, 226, 227, 228, 230, 231, 231, 233, 234, 237, 239, 241, 244, 246, 248, 252, 253, 255, 259, 261, 263, 265, 267, 268, 269, 271, 272, 273, 275, 277, 279, 281, 282, 288, 290, 291, 293, 294, 296, 300, 302, 303, 303, 305, 307, 308, 309, 311, 314, 315, 317, 319, 321, 322, 329, 331, 333, 344, 346, 347, 353, 355, 357, 358, 359, 361, 362, 364, 365, 368, 370, 371, 372, 374, 375, 377, 379, 380, 381, 382, 384, 387, 388, 390, 391, 393, 395, 396, 397, 2419, 2421, 2422, 397, 397, 399, 401, 401, 403, 404, 404, 405, 407, 408, 409, 411, 411, 413, 414, 419, 420, 422, 423, 424, 426, 435, 436, 438, 440, 442, 443, 444, 445, 447, 447, 449, 451, 451, 453, 454, 458, 460, 461, 465, 467, 468, 468, 470, 472, 472, 474, 475, 477, 478, 480, 484, 486, 488, 489, 490, 492, 493, 494, 496, 497, 499, 501, 505, 507, 509, 512, 514, 515, 517, 519, 521, 528, 529, 531, 540, 542, 544, 545, 547, 548, 551, 553, 554, 556, 558, 560, 561, 563, 565, 567, 569, 571, 575, 577, 579, 581, 583, 584, 584, 586, 587, 587, 588, 590, 594, 596, 597, 602, 604, 605, 609, 611, 613, 613, 615, 617, 622, 623, 625, 627, 629, 630, 631, 632, 634, 639, 640, 642, 645, 647, 649, 652, 654, 655, 661, 663, 664, 665, 667, 2405, 2399, 2401, 2402, 669, 671, 672, 676, 678, 680, 682, 684, 686, 687, 689, 691, 691, 692, 694, 695, 696, 698, 698, 700, 702, 707, 709, 711, 711, 712, 714, 714, 716, 717, 717, 719, 721, 724, 725, 727, 733, 735, 736, 736, 738, 739, 739, 741, 742, 742, 743, 745, 752, 754, 756, 756, 758, 759, 759, 761, 763, 765, 767, 769, 779, 781, 782, 782, 783, 785, 785, 787, 788, 791, 793, 794, 795, 797, 798, 800, 802, 803, 809, 811, 812, 812, 814, 816, 820, 822, 824, 828, 830, 831, 831, 833, 834, 834, 836, 837, 837, 839, 841, 844, 846, 847, 850, 851, 853, 855, 857, 859, 862, 864, 865, 867, 869, 870, 872, 874, 876, 876, 878, 880, 889, 891, 892, 892, 894, 895, 896, 898, 899, 901, 903, 904, 907, 908, 910, 910, 912, 913, 915, 917, 919, 919, 921, 922, 934, 936, 937, 938, 940, 942, 942, 944, 945, 948, 950, 951, 953, 954, 956, 958, 960, 961, 965, 967, 968, 976, 977, 979, 982, 983, 985, 985, 986, 988, 989, 991, 993, 995, 996, 998, 998, 1000, 1002, 1011, 1013, 1014, 1014, 1016, 1018, 1022, 1024, 1025, 1025, 2441, 2442, 2443, 1026, 1027, 1027, 1029, 1030, 1033, 1035, 1036, 1036, 1038, 1040, 1044, 1046, 1047, 1049, 1051, 1053, 1053, 1055, 1056, 1057, 1059, 1061, 1062, 1064, 1065, 1066, 1068, 1069, 1070, 1072, 1073, 1074, 1076, 1078, 1078, 1080, 1082, 1082, 1083, 1085, 1085, 1086, 1088, 1094, 1095, 1097, 1098, 2480, 2481, 2478, 2480, 1100, 1100, 1102, 1103, 1103, 1104, 2437, 1106, 1108, 1110, 1118, 1120, 1122, 1123, 1125, 1127, 1127, 1129, 1130, 1130, 1132, 1133, 1133, 1134, 1136, 1145, 1147, 1149, 1153, 1155, 1157, 1157, 1159, 1161, 1161, 1163, 1164, 1168, 1170, 1171, 1171, 1172, 1174, 1175, 1177, 1178, 1179, 1180, 1182, 1182, 1184, 1186, 1187, 1189, 1190, 1192, 1194, 1195, 1195, 1197, 1198, 1199, 1200, 1202, 1205, 1206, 1208, 1211, 1213, 1214, 1215, 1217, 1219, 1223, 1225, 1226, 1230, 1231, 1233, 1234, 1236, 1238, 1240, 1242, 1243, 2461, 2462, 2464, 2467, 2469, 2470, 2448, 2450, 2451, 2451, 2453, 1246, 1246, 1247, 1249, 1253, 1254, 1256, 1256, 1257, 1259, 1264, 1266, 1267, 1270, 1272, 1274, 1278, 1279, 1281, 1282, 1284, 1285, 1286, 1288, 1290, 1296, 1298, 1301, 1308, 1310, 1311, 1311, 1313, 1315, 1317, 1318, 1320, 1322, 1324, 1326, 1329, 1331, 1332, 1332, 1334, 1335, 1336, 1338, 1340, 1340, 1342, 1343, 1343, 1345, 1347, 1348, 1350, 1351, 1355, 1356, 1358, 1360, 1362, 1363, 1364, 1366, 1368, 1369, 1371, 1373, 1377, 1379, 1381, 1383, 1385, 1386, 1386, 1388, 1390, 1393, 1395, 1397, 1397, 1398, 1400, 1401, 1402, 1404, 1407, 1408, 1410, 1415, 1416, 1418, 1422, 1424, 1426, 1426, 1427, 1429, 1435, 1437, 1439, 1441, 1443, 1444, 1445, 1447, 1448, 1448, 1449, 1451, 1454, 1456, 1458, 1459, 1460, 1462, 1464, 1466, 1467, 1472, 1474, 1475, 1481, 1483, 1485, 1485, 1487, 1489, 1492, 1494, 1495, 1501, 1503, 1504, 1507, 1509, 1510, 1514, 1516, 1518, 1518, 1520, 1522, 1523, 1525, 1526, 1532, 1534, 1536, 1536, 1538, 1539, 1539, 1541, 1542, 1545, 1546, 1548, 2500, 2502, 2503, 2505, 2507, 2509, 2510, 2512, 2514, 2517, 2518, 2520, 2489, 2491, 2493, 2496, 1548, 1549, 1549, 1551, 1552, 1553, 1555, 1556, 1558, 1560, 1562, 1564, 1566, 1567, 1567, 1569, 1571, 1574, 1576, 1578, 1578, 1579, 1581, 1583, 1585, 1586, 1587, 1589, 1590, 1592, 1594, 1595, 1599, 1601, 1603, 1603, 1605, 1607, 1608, 1610, 1611, 1611, 1613, 1614, 1614, 1616, 1618, 1619, 1621, 1623, 1623, 1625, 1626, 1628, 1630, 1631, 1631, 1633, 1634, 1637, 1639, 1640, 1640, 1642, 1643, 1648, 1650, 1652, 1652, 1654, 1656, 1658, 1660, 1661, 1665, 1666, 1668, 1668, 1670, 1672, 1672, 1674, 1676, 1679, 1680, 1682, 1682, 1684, 1686, 1687, 1688, 1690, 1690, 1692, 1694, 1699, 1701, 1703, 1703, 1705, 1707, 1709, 1711, 1712, 1716, 1718, 1720, 1720, 1722, 1724, 1724, 1726, 1727, 1728, 1729, 1731, 1731, 1732, 1734, 1738, 1740, 1742, 1745, 1746, 1748, 1748, 1750, 1752, 1752, 1754, 1755, 1755, 1757, 1759, 1766, 1767, 1769, 1785, 1787, 1788, 1788, 1790, 1791, 1791, 1793, 1794, 1796, 1798, 1800, 1802, 1804, 1805, 1816, 1818, 1819, 1821, 1823, 1825, 1825, 1826, 1828, 1831, 1833, 1835, 1841, 1843, 1844, 1846, 1848, 1850, 1853, 1855, 1857, 1861, 1862, 1864, 1872, 1873, 1875, 1880, 1881, 1883, 1891, 1893, 1894, 1895, 1897, 1899, 1900, 1902, 1903, 1903, 1905, 1906, 1906, 1908, 1911, 1913, 1915, 1916, 1918, 1920, 1921, 1921, 1923, 1925, 1927, 1928, 1930, 1930, 1932, 1933, 1933, 1935, 1937, 1939, 1941, 1942, 1942, 1944, 1945, 1945, 1947, 1948, 1955, 1957, 1958, 1959, 1960, 1962, 1964, 1966, 1967, 1967, 1968, 1970, 1974, 1976, 1977, 1978, 1979, 1981, 1982, 1984, 1985, 1987, 1989, 1991, 1992, 1994, 1996, 1996, 1998, 1999, 2002, 2004, 2006, 2008, 2009, 2011, 2014, 2015, 2017, 2023, 2025, 2026, 2026, 2028, 2029, 2032, 2033, 2413, 2035, 2037, 2038, 2038, 2040, 2041, 2042, 2043, 2045, 2047, 2048, 2050, 2055, 2057, 2059, 2065, 2067, 2068, 2083, 2085, 2086, 2086, 2087, 2089, 2090, 2092, 2094, 2094, 2095, 2097, 2097, 2098, 2100, 2101, 2103, 2105, 2105, 2107, 2108, 2109, 2111, 2112, 2113, 2115, 2116, 2116, 2118, 2120, 2122, 2123, 2125, 2125, 2126, 2128, 2133, 2135, 2137, 2137, 2139, 2140, 2142, 2145, 2146, 2151, 2153, 2154, 2162, 2164, 2166, 2173, 2175, 2176, 2176, 2179, 2180, 2181, 2182, 2184, 2187, 2189, 2191, 2191, 2193, 2195, 2198, 2200, 2201, 2205, 2207, 2208, 2212, 2214, 2216, 2216, 2218, 2220, 2226, 2228, 2229, 2232, 2234, 2236, 2238, 2239, 2241, 2245, 2247, 2248, 2250, 2252, 2253, 2253, 2254, 2256, 2256, 2258, 2260, 2262, 2264, 2266, 2271, 2272, 2274, 2274, 2276, 2278, 2287, 2289, 2291, 2294, 2296, 2297, 2298, 2300, 2301, 2301, 2303, 2304, 2307, 2308, 2310, 2310, 2312, 2313, 2317, 2318, 2320, 2320, 2322, 2324, 2324, 2326, 2328, 2331, 2333, 2335, 2343, 2345, 2346, 2354, 2356, 2357, 2361, 2363, 2364, 2367, 2368, 2370, 2377, 2379, 2380, 2380, 2381, 2383, 12, 15, 17, 22, 26, 27, 29, 33, 37, 45, 48, 50, 50, 53, 55, 60, 61, 65, 69, 70, 74, 82, 84, 87, 87, 88, 92, 93, 94, 97, 103, 105, 108, 114, 115, 118, 121, 122, 125, 127, 131, 133, 133, 136, 137, 140, 143, 2425, 2428, 2429, 147, 154, 155, 158, 166, 169, 172, 183, 184, 187, 192, 193, 197, 201, 205, 207, 210, 211, 214, 214, 217, 218, 220, 221, 224, 228, 231, 234, 236, 237, 241, 244, 248, 249, 252, 255, 256, 259, 263, 265, 268, 269, 272, 273, 277, 278, 279, 282, 284, 287, 288, 291, 293, 296, 298, 300, 303, 307, 314, 317, 318, 318, 319, 322, 329, 333, 335, 343, 344, 347, 352, 353, 357, 361, 362, 365, 367, 368, 371, 371, 372, 375, 376, 377, 380, 387, 390, 391, 391, 395, 396, 396, 2419, 2420, 2421, 397, 401, 404, 407, 408, 411, 414, 416, 423, 426, 428, 435, 438, 439, 439, 440, 443, 444, 447, 451, 451, 454, 456, 458, 461, 463, 464, 465, 468, 477, 480, 481, 489, 492, 493, 493, 496, 497, 497, 501, 502, 510, 512, 515, 516, 517, 521, 528, 531, 533, 540, 544, 545, 550, 551, 554, 556, 560, 561, 566, 567, 571, 580, 581, 584, 587, 590, 591, 601, 602, 605, 607, 609, 613, 620, 622, 625, 631, 634, 635, 639, 642, 643, 645, 649, 651, 652, 655, 657, 660, 661, 664, 665, 2405, 2407, 2397, 2399, 2402, 669, 672, 674, 676, 680, 681, 681, 682, 686, 698, 702, 703, 705, 707, 711, 711, 714, 717, 717, 721, 723, 724, 727, 728, 732, 733, 736, 736, 739, 742, 742, 745, 747, 765, 769, 770, 778, 779, 782, 794, 795, 798, 807, 809, 812, 812, 816, 818, 828, 831, 834, 834, 837, 841, 843, 844, 847, 862, 865, 867, 870, 872, 876, 876, 880, 882, 889, 892, 895, 895, 896, 899, 901, 904, 906, 907, 910, 913, 915, 919, 922, 937, 938, 942, 947, 948, 951, 951, 953, 956, 958, 961, 963, 963, 965, 968, 976, 979, 980, 982, 985, 988, 995, 998, 1002, 1014, 1018, 1019, 1022, 1025, 2442, 1027, 1030, 1032, 1032, 1033, 1036, 1036, 1040, 1041, 1042, 1044, 1047, 1049, 1053, 1056, 1056, 1057, 1061, 1062, 1065, 1066, 1074, 1078, 1082, 1094, 1097, 1098, 2478, 1100, 1103, 1105, 1106, 1110, 1118, 1122, 1123, 1123, 1127, 1130, 1145, 1149, 1150, 1153, 1157, 1161, 1167, 1168, 1171, 1171, 1174, 1175, 1175, 1178, 1179, 1179, 1182, 1186, 1187, 1190, 1191, 1192, 1195, 1198, 1199, 1202, 1203, 1203, 1205, 1208, 1211, 1214, 1215, 1215, 1219, 1220, 1230, 1233, 1234, 1234, 1238, 1239, 1240, 1243, 1244, 2461, 2464, 2465, 2447, 2448, 2451, 1246, 1249, 1250, 1252, 1253, 1256, 1262, 1264, 1267, 1269, 1270, 1274, 1278, 1281, 1282, 1295, 1296, 1301, 1308, 1311, 1315, 1322, 1326, 1327, 1329, 1332, 1335, 1335, 1336, 1340, 1343, 1347, 1348, 1353, 1355, 1358, 1363, 1364, 1368, 1369, 1373, 1375, 1376, 1377, 1381, 1383, 1386, 1390, 1393, 1397, 1400, 1401, 1404, 1406, 1407, 1410, 1412, 1422, 1426, 1429, 1434, 1435, 1439, 1439, 1441, 1444, 1448, 1451, 1452, 1454, 1458, 1459, 1459, 1462, 1463, 1472, 1475, 1477, 1481, 1485, 1489, 1491, 1492, 1495, 1501, 1504, 1506, 1513, 1514, 1518, 1523, 1526, 1527, 1532, 1536, 1539, 1539, 1542, 1543, 1545, 1548, 2497, 2500, 2503, 2505, 2505, 2509, 2510, 2489, 2493, 2494, 2494, 2496, 1549, 1552, 1553, 1556, 1562, 1564, 1567, 1578, 1581, 1582, 1583, 1586, 1587, 1587, 1590, 1592, 1599, 1603, 1607, 1607, 1608, 1611, 1611, 1614, 1618, 1619, 1623, 1626, 1627, 1628, 1631, 1631, 1634, 1635, 1635, 1637, 1640, 1646, 1648, 1652, 1652, 1656, 1658, 1658, 1661, 1662, 1665, 1668, 1672, 1679, 1682, 1686, 1687, 1690, 1694, 1699, 1703, 1707, 1708, 1709, 1712, 1714, 1716, 1720, 1720, 1724, 1727, 1727, 1728, 1731, 1738, 1742, 1743, 1748, 1752, 1755, 1766, 1769, 1770, 1796, 1800, 1801, 1801, 1802, 1805, 1815, 1816, 1819, 1819, 1821, 1825, 1825, 1828, 1829, 1830, 1831, 1835, 1841, 1844, 1845, 1845, 1846, 1850, 1861, 1864, 1865, 1872, 1875, 1876, 1880, 1883, 1885, 1891, 1894, 1895, 1900, 1903, 1906, 1913, 1916, 1918, 1918, 1921, 1925, 1930, 1933, 1937, 1942, 1945, 1948, 1953, 1955, 1958, 1959, 1962, 1964, 1967, 1970, 1972, 1973, 1974, 1977, 1978, 1981, 1982, 1982, 1985, 1987, 1987, 1991, 1992, 2002, 2006, 2008, 2008, 2011, 2012, 2014, 2017, 2019, 2022, 2023, 2026, 2034, 2035, 2038, 2042, 2045, 2046, 2047, 2050, 2051, 2054, 2055, 2059, 2065, 2068, 2070, 2081, 2083, 2086, 2089, 2090, 2094, 2097, 2100, 2101, 2101, 2105, 2108, 2113, 2116, 2120, 2122, 2125, 2128, 2133, 2137, 2140, 2141, 2142, 2146, 2151, 2154, 2156, 2161, 2162, 2166, 2171, 2173, 2176, 2176, 2180, 2181, 2181, 2184, 2185, 2196, 2198, 2201, 2216, 2220, 2221, 2224, 2226, 2229, 2238, 2241, 2242, 2245, 2248, 2249, 2253, 2256, 2260, 2261, 2262, 2266, 2270, 2271, 2274, 2287, 2291, 2292, 2293, 2294, 2297, 2298, 2301, 2304, 2307, 2310, 2313, 2317, 2320, 2324, 2330, 2331, 2335, 2342, 2343, 2346, 2353, 2354, 2357, 2367, 2370, 2372, 2380, 2383, 2385, 11, 12, 17, 28, 29, 37, 45, 50, 55, 60, 65, 66, 69, 74, 76, 82, 87, 92, 92, 93, 97, 101, 103, 108, 114, 118, 119, 121, 125, 127, 127, 133, 137, 140, 2425, 2427, 2428, 147, 150, 154, 158, 161, 166, 172, 173, 192, 197, 198, 210, 214, 218, 220, 224, 227, 228, 234, 235, 235, 236, 241, 242, 244, 249, 252, 256, 258, 259, 265, 268, 272, 273, 278, 287, 291, 292, 292, 293, 298, 300, 307, 308, 312, 314, 318, 328, 329, 335, 343, 347, 348, 352, 357, 358, 361, 365, 367, 367, 371, 375, 376, 380, 381, 386, 387, 391, 2421, 401, 404, 411, 416, 417, 422, 423, 428, 435, 439, 443, 444, 451, 456, 457, 458, 463, 464, 468, 472, 475, 477, 481, 489, 493, 497, 510, 515, 516, 516, 521, 522, 527, 528, 533, 540, 545, 548, 549, 550, 554, 555, 556, 561, 566, 571, 572, 580, 584, 587, 599, 601, 605, 607, 613, 617, 620, 625, 626, 630, 631, 635, 638, 639, 643, 652, 657, 658, 660, 664, 665, 665, 2407, 2409, 668, 669, 674, 675, 676, 681, 681, 686, 687, 695, 698, 703, 705, 711, 717, 723, 724, 728, 731, 732, 736, 742, 747, 749, 763, 765, 770, 778, 782, 785, 791, 794, 798, 807, 812, 818, 827, 828, 834, 834, 841, 843, 843, 847, 849, 861, 862, 867, 876, 882, 884, 887, 889, 895, 895, 899, 900, 900, 901, 906, 907, 913, 914, 914, 915, 922, 937, 942, 945, 945, 947, 951, 963, 968, 970, 974, 976, 980, 982, 988, 989, 994, 995, 1002, 1011, 1014, 1019, 1027, 1032, 1036, 1047, 1049, 1056, 1061, 1062, 1066, 1074, 1082, 1085, 1094, 1098, 2481, 2478, 1103, 2437, 1105, 1110, 1112, 1123, 1130, 1133, 1143, 1145, 1150, 1151, 1153, 1161, 1167, 1171, 1175, 1179, 1186, 1187, 1192, 1198, 1199, 1203, 1208, 1209, 1211, 1215, 1220, 1228, 1230, 1234, 1239, 1240, 1244, 1245, 1246, 2453, 2460, 2461, 2465, 2472, 2447, 2451, 2451, 1246, 1250, 1252, 1256, 1259, 1262, 1267, 1269, 1269, 1274, 1275, 1295, 1301, 1302, 1307, 1308, 1315, 1321, 1322, 1327, 1327, 1329, 1335, 1335, 1340, 1343, 1343, 1348, 1351, 1363, 1368, 1369, 1369, 1375, 1376, 1381, 1383, 1390, 1393, 1400, 1401, 1401, 1406, 1407, 1407, 1412, 1413, 1422, 1429, 1431, 1439, 1444, 1445, 1448, 1452, 1454, 1472, 1477, 1479, 1479, 1481, 1489, 1491, 1495, 1497, 1500, 1501, 1506, 1513, 1518, 1522, 1523, 1527, 1528, 2500, 2505, 2510, 2489, 2494, 1549, 1562, 1567, 1571, 1574, 1578, 1582, 1583, 1587, 1592, 1607, 1611, 1618, 1626, 1627, 1631, 1635, 1640, 1643, 1646, 1652, 1658, 1664, 1665, 1672, 1678, 1679, 1686, 1687, 1694, 1695, 1698, 1699, 1707, 1714, 1720, 1727, 1745, 1748, 1755, 1765, 1766, 1770, 1796, 1801, 1805, 1815, 1819, 1825, 1830, 1835, 1837, 1845, 1850, 1852, 1860, 1861, 1865, 1870, 1872, 1876, 1880, 1885, 1887, 1890, 1891, 1895, 1900, 1906, 1911, 1912, 1913, 1918, 1918, 1925, 1926, 1942, 1948, 1950, 1953, 1958, 1959, 1959, 1964, 1967, 1978, 1982, 1987, 1987, 1992, 1996, 2002, 2008, 2012, 2014, 2019, 2021, 2022, 2026, 2029, 2034, 2038, 2041, 2042, 2046, 2047, 2054, 2059, 2060, 2065, 2070, 2072, 2079, 2081, 2086, 2089, 2094, 2097, 2097, 2101, 2108, 2112, 2113, 2120, 2122, 2128, 2129, 2132, 2133, 2140, 2141, 2146, 2148, 2150, 2151, 2156, 2159, 2161, 2166, 2171, 2176, 2181, 2196, 2201, 2203, 2212, 2216, 2221, 2236, 2238, 2242, 2244, 2245, 2249, 2261, 2266, 2268, 2270, 2274, 2278, 2285, 2287, 2292, 2297, 2298, 2304, 2307, 2313, 2315, 2317, 2324, 2328, 2330, 2335, 2337, 2353, 2357, 2359, 2366, 2367, 2372, 2380, 2385, 2387, 11, 17, 18, 28, 37, 38, 45, 55, 58, 58, 60, 66, 81, 82, 92, 112, 114, 119, 120, 121, 127, 140, 2427, 2428, 2428, 150, 151, 151, 154, 161, 164, 166, 173, 191, 192, 198, 207, 210, 218, 220, 227, 228, 242, 249, 250, 250, 252, 258, 258, 259, 268, 272, 278, 279, 287, 292, 298, 299, 300, 308, 312, 318, 322, 328, 335, 337, 343, 348, 349, 351, 352, 358, 361, 367, 375, 386, 391, 396, 2420, 2421, 404, 408, 411, 417, 422, 428, 429, 444, 456, 457, 457, 463, 464, 488, 489, 497, 509, 510, 516, 516, 522, 524, 527, 533, 534, 540, 548, 549, 555, 561, 565, 566, 572, 573, 579, 580, 587, 606, 607, 617, 618, 620, 626, 630, 635, 636, 638, 643, 644, 651, 652, 658, 658, 660, 665, 668, 674, 675, 675, 681, 687, 705, 717, 723, 723, 728, 729, 731, 736, 742, 763, 770, 772, 778, 785, 788, 791, 798, 800, 807, 818, 819, 827, 834, 843, 861, 867, 870, 887, 895, 900, 914, 922, 924, 937, 945, 951, 982, 989, 993, 1009, 1011, 1019, 1042, 1047, 1056, 1061, 1066, 1069, 1073, 1074, 1085, 1092, 1094, 2481, 2477, 2478, 2437, 1118, 1123, 1133, 1143, 1150, 1151, 1151, 1161, 1164, 1167, 1175, 1179, 1192, 1199, 1203, 1228, 1234, 1239, 1245, 2453, 2454, 2460, 2465, 2466, 1250, 1252, 1259, 1269, 1275, 1277, 1295, 1302, 1303, 1307, 1315, 1316, 1321, 1327, 1335, 1335, 1343, 1351, 1363, 1369, 1376, 1381, 1390, 1391, 1392, 1393, 1401, 1420, 1422, 1431, 1434, 1439, 1445, 1448, 1454, 1459, 1479, 1489, 1490, 1490, 1491, 1497, 1500, 1506, 1507, 1512, 1513, 1522, 1523, 1528, 1530, 2500, 2510, 2514, 2489, 1549, 1552, 1562, 1571, 1573, 1573, 1574, 1582, 1583, 1592, 1595, 1599, 1607, 1618, 1626, 1631, 1635, 1646, 1658, 1662, 1662, 1664, 1672, 1678, 1686, 1687, 1687, 1695, 1697, 1697, 1698, 1707, 1713, 1714, 1727, 1745, 1755, 1759, 1763, 1765, 1770, 1795, 1796, 1805, 1815, 1825, 1829, 1830, 1837, 1839, 1841, 1845, 1852, 1870, 1876, 1877, 1879, 1880, 1887, 1890, 1895, 1899, 1899, 1900, 1911, 1939, 1942, 1950, 1951, 1953, 1959, 1959, 1967, 1972, 1978, 1987, 1996, 2001, 2002, 2012, 2013, 2014, 2021, 2042, 2047, 2051, 2052, 2054, 2060, 2064, 2065, 2072, 2077, 2079, 2086, 2097, 2108, 2109, 2112, 2120, 2121, 2121, 2122, 2129, 2132, 2140, 2141, 2141, 2148, 2150, 2159, 2166, 2167, 2171, 2181, 2185, 2195, 2196, 2203, 2212, 2221, 2223, 2243, 2244, 2249, 2261, 2268, 2269, 2269, 2270, 2278, 2285, 2292, 2293, 2297, 2304, 2306, 2306, 2307, 2315, 2316, 2317, 2328, 2329, 2330, 2337, 2351, 2353, 2359, 2366, 2372, 2373, 2377, 2380, 2387, 28, 38, 39, 44, 45, 58, 58, 66, 69, 81, 92, 97, 110, 112, 119, 120, 127, 137, 164, 173, 175, 191, 198, 200, 207, 218, 220, 241, 242, 250, 258, 268, 272, 272, 279, 284, 299, 308, 311, 311, 312, 322, 328, 337, 339, 342, 343, 349, 351, 358, 361, 361, 375, 376, 384, 386, 396, 422, 429, 431, 484, 488, 497, 509, 516, 524, 526, 527, 534, 538, 540, 549, 555, 565, 566, 566, 573, 575, 579, 587, 591, 606, 617, 618, 630, 636, 637, 637, 638, 644, 651, 658, 665, 2404, 668, 675, 703, 705, 723, 731, 742, 749, 763, 772, 774, 776, 778, 788, 791, 800, 803, 807, 819, 820, 827, 843, 849, 859, 861, 870, 887, 900, 906, 914, 924, 925, 934, 937, 951, 982, 993, 994, 1009, 1019, 1020, 1041, 1042, 1056, 1061, 1069, 1070, 1073, 1085, 1088, 1092, 2481, 2475, 2476, 2477, 2437, 1118, 1133, 1136, 1143, 1151, 1164, 1165, 1167, 1179, 1191, 1192, 1203, 1228, 1239, 1244, 1244, 1245, 2454, 2459, 2460, 2466, 1250, 1259, 1260, 1262, 1269, 1277, 1293, 1295, 1303, 1307, 1316, 1317, 1360, 1363, 1376, 1376, 1381, 1391, 1392, 1401, 1407, 1420, 1431, 1433, 1433, 1434, 1445, 1490, 1497, 1498, 1512, 1522, 1523, 2499, 2500, 2514, 2489, 1552, 1556, 1558, 1562, 1573, 1573, 1582, 1583, 1583, 1595, 1597, 1598, 1599, 1618, 1626, 1635, 1643, 1646, 1662, 1672, 1678, 1687, 1697, 1697, 1707, 1708, 1712, 1713, 1727, 1745, 1759, 1760, 1763, 1770, 1771, 1795, 1805, 1807, 1815, 1829, 1830, 1841, 1852, 1853, 1869, 1870, 1877, 1890, 1899, 1911, 1951, 1959, 1972, 1977, 1978, 1996, 2001, 2012, 2013, 2013, 2021, 2022, 2041, 2042, 2051, 2064, 2072, 2074, 2077, 2086, 2089, 2089, 2097, 2109, 2112, 2121, 2129, 2132, 2141, 2150, 2157, 2159, 2167, 2195, 2203, 2205, 2243, 2249, 2250, 2260, 2261, 2269, 2269, 2278, 2279, 2297, 2306, 2315, 2315, 2316, 2328, 2351, 2359, 2360, 2364, 2366, 2373, 2377, 2387, 2389, 42, 44, 58, 58, 69, 76, 109, 110, 119, 119, 120, 137, 162, 164, 175, 189, 191, 200, 201, 207, 220, 235, 241, 250, 272, 284, 286, 298, 299, 311, 311, 322, 323, 326, 328, 339, 342, 349, 351, 351, 361, 376, 384, 396, 2420, 419, 422, 431, 484, 497, 502, 505, 509, 524, 526, 534, 536, 554, 555, 566, 575, 579, 591, 627, 630, 637, 651, 665, 2409, 2404, 675, 687, 695, 703, 723, 731, 749, 750, 759, 763, 774, 776, 788, 790, 790, 791, 803, 826, 827, 849, 859, 870, 876, 885, 887, 906, 907, 914, 925, 932, 934, 951, 1008, 1009, 1020, 1041, 1056, 1061, 1061, 1070, 1073, 2475, 2476, 2437, 1118, 1136, 1137, 1143, 1164, 1165, 1165, 1179, 1187, 1191, 1203, 1209, 1226, 1228, 1244, 1244, 2454, 2456, 2451, 1250, 1260, 1262, 1277, 1278, 1293, 1303, 1305, 1305, 1307, 1317, 1360, 1376, 1391, 1392, 1407, 1413, 1419, 1420, 1433, 1433, 1445, 1448, 1490, 1498, 1500, 1510, 1512, 1523, 2497, 2499, 2514, 2489, 1556, 1558, 1558, 1573, 1583, 1598, 1618, 1619, 1646, 1672, 1676, 1677, 1678, 1697, 1712, 1727, 1731, 1743, 1745, 1760, 1763, 1771, 1773, 1795, 1807, 1808, 1814, 1815, 1830, 1841, 1853, 1857, 1869, 1877, 1878, 1889, 1890, 1911, 1951, 1972, 1973, 1977, 1996, 1999, 2001, 2013, 2022, 2041, 2051, 2052, 2064, 2074, 2075, 2077, 2089, 2109, 2112, 2129, 2131, 2157, 2167, 2168, 2191, 2195, 2205, 2242, 2243, 2250, 2297, 2315, 2328, 2350, 2351, 2360, 2364, 2373, 2374, 2377, 2389, 2390, 42, 58, 76, 119, 137, 138, 161, 162, 175, 189, 200, 201, 201, 220, 228, 228, 235, 250, 298, 311, 323, 325, 326, 339, 351, 376, 381, 381, 384, 2420, 419, 431, 432, 483, 484, 502, 505, 524, 525, 525, 526, 536, 627, 637, 644, 2402, 2404, 687, 695, 723, 729, 731, 750, 751, 759, 774, 776, 790, 803, 805, 824, 826, 849, 855, 859, 876, 932, 951, 956, 1008, 1020, 1022, 1041, 1061, 1073, 1116, 1118, 1137, 1143, 1165, 1187, 1187, 1191, 1209, 1223, 1226, 1244, 1244, 2456, 2458, 2451, 1260, 1262, 1262, 1278, 1282, 1293, 1305, 1317, 1358, 1360, 1391, 1391, 1392, 1413, 1418, 1419, 1433, 1479, 1490, 1500, 2488, 2489, 1558, 1558, 1583, 1597, 1598, 1619, 1626, 1646, 1676, 1677, 1677, 1697, 1708, 1708, 1712, 1731, 1743, 1760, 1761, 1794, 1795, 1808, 1814, 1830, 1839, 1869, 1878, 1879, 1889, 1911, 1912, 1977, 1999, 2001, 2001, 2022, 2029, 2034, 2041, 2052, 2075, 2077, 2109, 2112, 2131, 2132, 2156, 2157, 2168, 2187, 2191, 2205, 2242, 2250, 2253, 2293, 2297, 2328, 2350, 2360, 2361, 2361, 2364, 2374, 161, 175, 176, 187, 189, 201, 324, 325, 339, 381, 2420, 404, 418, 419, 432, 483, 502, 504, 505, 525, 536, 626, 627, 644, 2402, 687, 691, 694, 695, 729, 731, 751, 752, 756, 759, 776, 820, 824, 849, 853, 855, 876, 931, 932, 956, 1036, 1041, 1073, 1115, 1116, 1137, 1142, 1143, 1187, 1187, 1209, 1211, 1223, 1244, 2458, 2472, 2451, 1262, 1262, 1282, 1285, 1293, 1317, 1320, 1358, 1391, 1413, 1418, 1433, 1448, 1479, 1500, 1507, 2488, 1558, 1597, 1598, 1626, 1643, 1646, 1677, 1708, 1738, 1743, 1761, 1791, 1794, 1808, 1813, 1814, 1839, 1867, 1869, 1879, 1889, 1912, 1918, 1977, 2001, 2029, 2034, 2052, 2060, 2064, 2075, 2109, 2109, 2112, 2132, 2150, 2156, 2168, 2186, 2187, 2205, 2236, 2242, 2253, 2293, 2328, 2329, 2350, 2361, 2374, 151, 161, 176, 187, 201, 228, 324, 339, 341, 381, 404, 408, 418, 432, 433, 482, 483, 504, 504, 505, 536, 618, 626, 644, 2397, 2402, 691, 694, 729, 731, 752, 756, 776, 807, 820, 849, 850, 853, 876, 1036, 1073, 1088, 1142, 1187, 1211, 1222, 1223, 2458, 2472, 1262, 1285, 1293, 1320, 1321, 1415, 1418, 1448, 1479, 1507, 1510, 2486, 2488, 1597, 1597, 1598, 1643, 1645, 1646, 1708, 1738, 1761, 1762, 1788, 1791, 1808, 1811, 1813, 1839, 1867, 1879, 1887, 1889, 1918, 1926, 1973, 1977, 2029, 2034, 2060, 2062, 2064, 2109, 2132, 2150, 2168, 2170, 2186, 2205, 2208, 2236, 2253, 2260, 2350, 2374, 2375, 187, 228, 250, 323, 324, 341, 351, 381, 408, 418, 433, 435, 482, 504, 536, 618, 644, 645, 2397, 691, 694, 752, 776, 790, 807, 849, 850, 850, 876, 884, 1036, 1088, 1090, 1142, 1211, 1220, 1221, 1222, 2458, 2470, 2472, 1285, 1293, 1321, 1335, 1415, 1448, 1459, 1472, 1479, 1510, 2485, 2486, 1597, 1597, 1643, 1644, 1645, 1708, 1731, 1736, 1738, 1762, 1788, 1808, 1809, 1811, 1839, 1840, 1867, 1887, 1889, 1951, 1973, 2029, 2064, 2132, 2150, 2150, 2170, 2171, 2186, 2208, 2209, 2232, 2236, 2260, 2350, 2375, 2376, 183, 187, 250, 323, 341, 342, 351, 408, 417, 417, 418, 435, 481, 482, 536, 618, 645, 651, 2410, 2397, 694, 752, 790, 805, 807, 850, 884, 1027, 1036, 1090, 1142, 1220, 1221, 1221, 2458, 2459, 2470, 1285, 1286, 1292, 1293, 1335, 1413, 1415, 1459, 1471, 1472, 1510, 2485, 1597, 1644, 1644, 1645, 1731, 1785, 1788, 1809, 1865, 1867, 1889, 1951, 2029, 2030, 2062, 2064, 2150, 2185, 2186, 2209, 2232, 2260, 2269, 183, 250, 258, 298, 323, 342, 417, 435, 443, 481, 536, 537, 606, 618, 651, 731, 752, 805, 807, 884, 885, 2443, 1027, 1090, 1221, 2459, 2466, 1290, 1292, 1335, 1358, 1413, 1459, 1471, 1510, 1523, 2485, 1644, 1731, 1785, 1809, 1811, 1865, 1889, 1926, 1951, 2030, 2032, 2231, 2232, 2269, 183, 258, 272, 417, 443, 444, 694, 731, 805, 807, 885, 906, 2443, 1090, 1092, 1290, 1335, 1351, 1358, 1459, 1463, 1470, 1471, 1523, 2485, 1731, 1734, 1784, 1785, 1811, 1860, 1865, 1926, 1951, 2032, 2413, 2416, 2417, 2034, 2229, 2231, 2269, 183, 272, 286, 417, 444, 457, 694, 805, 807, 807, 906, 907, 2443, 1092, 2475, 1286, 1290, 1351, 1358, 1463, 1464, 1469, 1470, 1523, 1784, 1811, 1840, 1859, 1860, 1926, 1950, 1951, 2413, 2416, 2034, 2062, 2229, 2269, 2279, 351, 417, 457, 2442, 2443, 2475, 2470, 1286, 1351, 1358, 1464, 1467, 1784, 1840, 1841, 1858, 1859, 1926, 1939, 1950, 2413, 2415, 2416, 2062, 2224, 2229, 2279, 351, 457, 464, 1022, 2442, 2475, 2467, 2470, 1351, 1353, 1358, 1467, 1939, 2413, 2414, 2415, 2062, 2150, 2224, 2279, 2280, 351, 464, 472, 1022, 2475, 2437, 2466, 2467, 1351, 1353, 1467, 1469, 2414, 2415, 2150, 2224, 2280, 2281, 342, 351, 472, 1221, 2466, 1351, 1353, 1469, 1523, 1939, 2414, 2150, 342, 472, 475, 1221, 1351, 1352, 1353, 1523, 1530, 298, 342, 475, 1221, 1352, 1353, 298, 475, 481, 298, 481, 537, 287, 298, 537, 287, 537, 538, 287, 538, 549, 593, 597, 598, 2484, 2485, 1734, 2209, 2211, 2282, 592, 593, 598, 2520, 2484, 1734, 2185, 2209, 2282, 2520, 1734, 1735, 2185, 2282, 2284, 2185, 2284, 2285, 2185, 2285, 2293, 2171, 2185, 2293, 2150, 2171, 2293, 2150, 2293, 2329, 1939, 2150, 2329, 1939, 2329, 2337, 1939, 2337, 2338, 1939, 2338, 2339, 1938, 1939, 2339, 1938, 2339, 2341, 1, 3, 6, 76, 79, 81, 101, 108, 119, 179, 180, 182, 598, 599, 606, 926, 928, 931, 994, 1002, 1004, 1139, 1140, 1142, 1532, 1539, 1544, 2514, 2515, 2517, 1763, 1773, 1776, 1763, 1776, 1777, 0, 1, 6, 42, 76, 81, 101, 119, 138, 177, 179, 182, 592, 598, 606, 926, 931, 956, 982, 994, 1004, 1139, 1142, 1221, 1531, 1532, 1544, 2497, 2514, 2517, 1762, 1763, 1777, 42, 81, 97, 99, 101, 138, 177, 182, 183, 592, 606, 651, 925, 926, 956, 982, 1004, 1006, 1137, 1139, 1221, 1531, 1544, 1545, 2497, 2517, 2520, 1736, 1762, 1777, 41, 42, 97, 98, 99, 138, 176, 177, 183, 591, 592, 651, 907, 925, 956, 980, 982, 1006, 1115, 1137, 1221, 1531, 1545, 2497, 2497, 2520, 1735, 1735, 1736, 1777, 39, 41, 97, 97, 98, 138, 151, 176, 183, 575, 591, 651, 907, 956, 958, 974, 980, 1006, 1115, 1221, 1353, 1530, 1531, 2497, 1735, 1777, 1778, 28, 39, 97, 151, 183, 286, 566, 575, 651, 807, 907, 958, 974, 1006, 1007, 1113, 1115, 1353, 1530, 2497, 1735, 1735, 1778, 1780, 28, 97, 138, 2428, 151, 286, 566, 651, 2409, 807, 958, 963, 974, 1007, 1008, 1112, 1113, 1353, 1353, 1530, 1735, 1735, 1780, 1781, 28, 138, 139, 140, 2428, 286, 554, 566, 2409, 807, 963, 970, 1353, 1735, 1781, 27, 28, 139, 139, 140, 286, 554, 2409, 2410, 807, 970, 971, 1353, 1781, 1782, 27, 139, 286, 554, 2410, 694, 807, 971, 973, 1353, 1782, 1784, 27, 286, 287, 549, 554, 694, 694, 807, 973, 1112, 1353, 1784, 22, 27, 287, 694, 973, 974, 22, 287, 549, 694, 974, 1008, 549, 694, 1008, 549, 1008, 1022, 549, 1022, 2437, 2437, 1105, 1112, 549, 2437, 2438, 2436, 2437, 1112, 549, 2438, 2433, 2435, 2436, 1112, 549, 2433, 2434, 2435, 1112, 1784, 2434, 2435, 1784, 549, 2434, 1784, 22, 549, 1784, 22, 1784, 1841, 22, 1841, 1857, 22, 1857, 1858, 22, 1858, 1926, 18, 22, 1926, 18, 1926, 1927, 18, 1927, 1930, 18, 1930, 1937, 11, 18, 1937, 11, 1937, 1938, 11, 1938, 2341];
+testOutput["water"]["triangles"]=[1548, 2496, 2497, 2497, 2498, 2499, 2500, 2501, 2502, 2503, 2504, 2505, 2505, 2506, 2507, 2507, 2508, 2509, 2510, 2511, 2512, 2512, 2513, 2514, 2518, 2519, 2520, 2486, 2487, 2488, 2489, 2490, 2491, 2491, 2492, 2493, 2494, 2495, 2496, 1549, 1550, 1551, 1553, 1554, 1555, 1556, 1557, 1558, 1558, 1559, 1560, 1560, 1561, 1562, 1562, 1563, 1564, 1564, 1565, 1566, 1567, 1568, 1569, 1569, 1570, 1571, 1571, 1572, 1573, 1574, 1575, 1576, 1576, 1577, 1578, 1579, 1580, 1581, 1583, 1584, 1585, 1587, 1588, 1589, 1590, 1591, 1592, 1592, 1593, 1594, 1595, 1596, 1597, 1599, 1600, 1601, 1601, 1602, 1603, 1603, 1604, 1605, 1605, 1606, 1607, 1608, 1609, 1610, 1611, 1612, 1613, 1614, 1615, 1616, 1616, 1617, 1618, 1619, 1620, 1621, 1621, 1622, 1623, 1623, 1624, 1625, 1628, 1629, 1630, 1631, 1632, 1633, 1635, 1636, 1637, 1637, 1638, 1639, 1640, 1641, 1642, 1646, 1647, 1648, 1648, 1649, 1650, 1650, 1651, 1652, 1652, 1653, 1654, 1654, 1655, 1656, 1656, 1657, 1658, 1658, 1659, 1660, 1662, 1663, 1664, 1666, 1667, 1668, 1668, 1669, 1670, 1670, 1671, 1672, 1672, 1673, 1674, 1674, 1675, 1676, 1680, 1681, 1682, 1682, 1683, 1684, 1684, 1685, 1686, 1688, 1689, 1690, 1690, 1691, 1692, 1692, 1693, 1694, 1695, 1696, 1697, 1699, 1700, 1701, 1701, 1702, 1703, 1703, 1704, 1705, 1705, 1706, 1707, 1709, 1710, 1711, 1714, 1715, 1716, 1716, 1717, 1718, 1718, 1719, 1720, 1720, 1721, 1722, 1722, 1723, 1724, 1724, 1725, 1726, 1729, 1730, 1731, 1732, 1733, 1734, 1736, 1737, 1738, 1738, 1739, 1740, 1740, 1741, 1742, 1743, 1744, 1745, 1746, 1747, 1748, 1748, 1749, 1750, 1750, 1751, 1752, 1752, 1753, 1754, 1755, 1756, 1757, 1757, 1758, 1759, 1763, 1764, 1765, 1767, 1768, 1769, 1771, 1772, 1773, 1774, 1775, 1776, 1778, 1779, 1780, 1782, 1783, 1784, 1785, 1786, 1787, 1788, 1789, 1790, 1791, 1792, 1793, 1796, 1797, 1798, 1798, 1799, 1800, 1802, 1803, 1804, 1805, 1806, 1807, 1809, 1810, 1811, 1811, 1812, 1813, 1816, 1817, 1818, 1819, 1820, 1821, 1821, 1822, 1823, 1823, 1824, 1825, 1826, 1827, 1828, 1831, 1832, 1833, 1833, 1834, 1835, 1835, 1836, 1837, 1837, 1838, 1839, 1841, 1842, 1843, 1846, 1847, 1848, 1848, 1849, 1850, 1850, 1851, 1852, 1853, 1854, 1855, 1855, 1856, 1857, 1862, 1863, 1864, 1865, 1866, 1867, 1867, 1868, 1869, 1870, 1871, 1872, 1873, 1874, 1875, 1881, 1882, 1883, 1883, 1884, 1885, 1885, 1886, 1887, 1887, 1888, 1889, 1891, 1892, 1893, 1895, 1896, 1897, 1897, 1898, 1899, 1900, 1901, 1902, 1903, 1904, 1905, 1906, 1907, 1908, 1908, 1909, 1911, 1913, 1914, 1915, 1916, 1917, 1918, 1918, 1919, 1920, 1921, 1922, 1923, 1923, 1924, 1925, 1928, 1929, 1930, 1930, 1931, 1932, 1933, 1934, 1935, 1935, 1936, 1937, 1939, 1940, 1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1949, 1950, 1951, 1952, 1953, 1953, 1954, 1955, 1955, 1956, 1957, 1960, 1961, 1962, 1962, 1963, 1964, 1964, 1965, 1966, 1968, 1969, 1970, 1970, 1971, 1972, 1974, 1975, 1976, 1979, 1980, 1981, 1982, 1983, 1984, 1985, 1986, 1987, 1987, 1988, 1989, 1989, 1990, 1991, 1992, 1993, 1994, 1994, 1995, 1996, 1996, 1997, 1998, 1999, 2000, 2001, 2002, 2003, 2004, 2004, 2005, 2006, 2006, 2007, 2008, 2009, 2010, 2011, 2015, 2016, 2017, 2017, 2018, 2019, 2019, 2020, 2021, 2023, 2024, 2025, 2026, 2027, 2028, 2030, 2031, 2032, 2033, 2034, 2413, 2035, 2036, 2037, 2038, 2039, 2040, 2043, 2044, 2045, 2048, 2049, 2050, 2052, 2053, 2054, 2055, 2056, 2057, 2057, 2058, 2059, 2060, 2061, 2062, 2062, 2063, 2064, 2065, 2066, 2067, 2068, 2069, 2070, 2070, 2071, 2072, 2072, 2073, 2074, 2075, 2076, 2077, 2077, 2078, 2079, 2079, 2080, 2081, 2081, 2082, 2083, 2083, 2084, 2085, 2087, 2088, 2089, 2090, 2091, 2092, 2092, 2093, 2094, 2095, 2096, 2097, 2098, 2099, 2100, 2101, 2102, 2103, 2103, 2104, 2105, 2105, 2106, 2107, 2109, 2110, 2111, 2113, 2114, 2115, 2116, 2117, 2118, 2118, 2119, 2120, 2123, 2124, 2125, 2126, 2127, 2128, 2129, 2130, 2131, 2133, 2134, 2135, 2135, 2136, 2137, 2137, 2138, 2139, 2142, 2144, 2145, 2146, 2147, 2148, 2148, 2149, 2150, 2151, 2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2159, 2160, 2161, 2162, 2163, 2164, 2164, 2165, 2166, 2168, 2169, 2170, 2171, 2172, 2173, 2173, 2174, 2175, 2176, 2177, 2179, 2182, 2183, 2184, 2187, 2188, 2189, 2189, 2190, 2191, 2191, 2192, 2193, 2193, 2194, 2195, 2196, 2197, 2198, 2198, 2199, 2200, 2201, 2202, 2203, 2203, 2204, 2205, 2205, 2206, 2207, 2209, 2210, 2211, 2212, 2213, 2214, 2214, 2215, 2216, 2216, 2217, 2218, 2218, 2219, 2220, 2221, 2222, 2223, 2224, 2225, 2226, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2234, 2235, 2236, 2236, 2237, 2238, 2239, 2240, 2241, 2245, 2246, 2247, 2250, 2251, 2252, 2254, 2255, 2256, 2256, 2257, 2258, 2258, 2259, 2260, 2262, 2263, 2264, 2264, 2265, 2266, 2266, 2267, 2268, 2272, 2273, 2274, 2274, 2275, 2276, 2276, 2277, 2278, 2282, 2283, 2284, 2285, 2286, 2287, 2287, 2288, 2289, 2289, 2290, 2291, 2294, 2295, 2296, 2298, 2299, 2300, 2301, 2302, 2303, 2304, 2305, 2306, 2308, 2309, 2310, 2310, 2311, 2312, 2313, 2314, 2315, 2318, 2319, 2320, 2320, 2321, 2322, 2322, 2323, 2324, 2324, 2325, 2326, 2326, 2327, 2328, 2331, 2332, 2333, 2333, 2334, 2335, 2335, 2336, 2337, 2339, 2340, 2341, 2343, 2344, 2345, 2347, 2348, 2349, 2351, 2352, 2353, 2354, 2355, 2356, 2357, 2358, 2359, 2361, 2362, 2363, 2364, 2365, 2366, 2368, 2369, 2370, 2370, 2371, 2372, 2377, 2378, 2379, 2381, 2382, 2383, 2383, 2384, 2385, 2385, 2386, 2387, 2387, 2388, 2389, 1, 2, 3, 4, 5, 6, 7, 8, 9, 12, 13, 14, 15, 16, 17, 18, 19, 20, 20, 21, 22, 22, 23, 24, 24, 25, 26, 29, 30, 31, 31, 32, 33, 33, 34, 35, 35, 36, 37, 39, 40, 41, 42, 43, 44, 46, 47, 48, 48, 49, 50, 50, 51, 52, 53, 54, 55, 56, 57, 58, 58, 59, 60, 61, 62, 63, 63, 64, 65, 66, 67, 68, 70, 71, 72, 72, 73, 74, 74, 75, 76, 77, 78, 79, 82, 83, 84, 84, 85, 86, 88, 89, 90, 90, 91, 92, 94, 95, 96, 99, 100, 101, 101, 102, 103, 103, 104, 105, 105, 106, 107, 110, 111, 112, 112, 113, 114, 115, 116, 117, 122, 123, 124, 125, 126, 127, 127, 128, 129, 129, 130, 131, 131, 132, 133, 134, 135, 136, 141, 142, 143, 143, 144, 145, 145, 2424, 2425, 2425, 2426, 2427, 2429, 2430, 145, 145, 146, 147, 147, 148, 149, 151, 152, 153, 155, 156, 157, 158, 159, 160, 162, 163, 164, 164, 165, 166, 167, 168, 169, 169, 170, 171, 173, 174, 175, 177, 178, 179, 184, 185, 186, 187, 188, 189, 189, 190, 191, 193, 194, 195, 195, 196, 197, 198, 199, 200, 201, 202, 203, 203, 204, 205, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 218, 219, 220, 221, 222, 223, 224, 225, 226, 228, 229, 230, 231, 232, 233, 237, 238, 239, 239, 240, 241, 242, 243, 244, 244, 245, 246, 246, 247, 248, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260, 261, 261, 262, 263, 263, 264, 265, 265, 266, 267, 269, 270, 271, 273, 274, 275, 275, 276, 277, 279, 280, 281, 282, 283, 284, 284, 285, 286, 288, 289, 290, 294, 295, 296, 296, 297, 298, 300, 301, 302, 303, 304, 305, 305, 306, 307, 309, 310, 311, 312, 313, 314, 315, 316, 317, 319, 320, 321, 326, 327, 328, 329, 330, 331, 331, 332, 333, 333, 334, 335, 335, 336, 337, 337, 338, 339, 339, 340, 341, 344, 345, 346, 349, 350, 351, 353, 354, 355, 355, 356, 357, 359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 372, 373, 374, 377, 378, 379, 382, 383, 384, 384, 385, 386, 388, 389, 390, 391, 392, 393, 393, 394, 395, 397, 398, 399, 399, 400, 401, 401, 402, 403, 405, 406, 407, 409, 410, 411, 411, 412, 413, 414, 415, 416, 420, 421, 422, 424, 425, 426, 426, 427, 428, 429, 430, 431, 433, 434, 435, 436, 437, 438, 440, 441, 442, 445, 446, 447, 447, 448, 449, 449, 450, 451, 451, 452, 453, 454, 455, 456, 458, 459, 460, 461, 462, 463, 465, 466, 467, 468, 469, 470, 470, 471, 472, 472, 473, 474, 475, 476, 477, 478, 479, 480, 484, 485, 486, 486, 487, 488, 490, 491, 492, 494, 495, 496, 497, 498, 499, 499, 500, 501, 502, 503, 504, 505, 506, 507, 507, 508, 509, 510, 511, 512, 512, 513, 514, 517, 518, 519, 519, 520, 521, 522, 523, 524, 529, 530, 531, 531, 532, 533, 534, 535, 536, 538, 539, 540, 540, 541, 542, 542, 543, 544, 545, 546, 547, 551, 552, 553, 556, 557, 558, 558, 559, 560, 561, 562, 563, 563, 564, 565, 567, 568, 569, 569, 570, 571, 573, 574, 575, 575, 576, 577, 577, 578, 579, 581, 582, 583, 584, 585, 586, 588, 589, 590, 594, 595, 596, 599, 600, 601, 602, 603, 604, 607, 608, 609, 609, 610, 611, 611, 612, 613, 613, 614, 615, 615, 616, 617, 618, 619, 620, 620, 621, 622, 623, 624, 625, 627, 628, 629, 632, 633, 634, 640, 641, 642, 645, 646, 647, 647, 648, 649, 649, 650, 651, 652, 653, 654, 655, 656, 657, 658, 659, 660, 661, 662, 663, 665, 666, 667, 667, 668, 2405, 2405, 2406, 2407, 2407, 2408, 2409, 2397, 2398, 2399, 2399, 2400, 2401, 2402, 2403, 2404, 2404, 2405, 668, 669, 670, 671, 672, 673, 674, 676, 677, 678, 678, 679, 680, 682, 683, 684, 684, 685, 686, 687, 688, 689, 689, 690, 691, 692, 693, 694, 696, 697, 698, 698, 699, 700, 700, 701, 702, 703, 704, 705, 705, 706, 707, 707, 708, 709, 709, 710, 711, 712, 713, 714, 714, 715, 716, 717, 718, 719, 719, 720, 721, 721, 722, 723, 725, 726, 727, 729, 730, 731, 733, 734, 735, 736, 737, 738, 739, 740, 741, 743, 744, 745, 745, 746, 747, 747, 748, 749, 752, 753, 754, 754, 755, 756, 756, 757, 758, 759, 760, 761, 761, 762, 763, 763, 764, 765, 765, 766, 767, 767, 768, 769, 770, 771, 772, 772, 773, 774, 774, 775, 776, 776, 777, 778, 779, 780, 781, 783, 784, 785, 785, 786, 787, 788, 789, 790, 791, 792, 793, 795, 796, 797, 798, 799, 800, 800, 801, 802, 803, 804, 805, 805, 806, 807, 807, 808, 809, 809, 810, 811, 812, 813, 814, 814, 815, 816, 816, 817, 818, 820, 821, 822, 822, 823, 824, 824, 825, 826, 828, 829, 830, 831, 832, 833, 834, 835, 836, 837, 838, 839, 839, 840, 841, 841, 842, 843, 844, 845, 846, 847, 848, 849, 851, 852, 853, 853, 854, 855, 855, 856, 857, 857, 858, 859, 859, 860, 861, 862, 863, 864, 865, 866, 867, 867, 868, 869, 870, 871, 872, 872, 873, 874, 874, 875, 876, 876, 877, 878, 878, 879, 880, 880, 881, 882, 882, 883, 884, 885, 886, 887, 887, 888, 889, 889, 890, 891, 892, 893, 894, 896, 897, 898, 901, 902, 903, 904, 905, 906, 908, 909, 910, 910, 911, 912, 915, 916, 917, 917, 918, 919, 919, 920, 921, 922, 923, 924, 926, 927, 928, 928, 929, 930, 932, 933, 934, 934, 935, 936, 938, 939, 940, 940, 941, 942, 942, 943, 944, 945, 946, 947, 948, 949, 950, 951, 952, 953, 954, 955, 956, 956, 957, 958, 958, 959, 960, 961, 962, 963, 963, 964, 965, 965, 966, 967, 968, 969, 970, 971, 972, 973, 974, 975, 976, 977, 978, 979, 980, 981, 982, 983, 984, 985, 986, 987, 988, 989, 990, 991, 991, 992, 993, 996, 997, 998, 998, 999, 1000, 1000, 1001, 1002, 1004, 1005, 1006, 1009, 1010, 1011, 1011, 1012, 1013, 1014, 1015, 1016, 1016, 1017, 1018, 1020, 1021, 1022, 1022, 1023, 1024, 1025, 1026, 2441, 2443, 2444, 1026, 1027, 1028, 1029, 1030, 1031, 1032, 1033, 1034, 1035, 1036, 1037, 1038, 1038, 1039, 1040, 1042, 1043, 1044, 1044, 1045, 1046, 1047, 1048, 1049, 1049, 1050, 1051, 1051, 1052, 1053, 1053, 1054, 1055, 1057, 1058, 1059, 1059, 1060, 1061, 1062, 1063, 1064, 1066, 1067, 1068, 1070, 1071, 1072, 1074, 1075, 1076, 1076, 1077, 1078, 1078, 1079, 1080, 1080, 1081, 1082, 1083, 1084, 1085, 1086, 1087, 1088, 1088, 1089, 1090, 1090, 1091, 1092, 1092, 1093, 1094, 1095, 1096, 1097, 1098, 1099, 2480, 2478, 2479, 2480, 2480, 1099, 1100, 1100, 1101, 1102, 1104, 1105, 2437, 2438, 2432, 2433, 1106, 1107, 1108, 1108, 1109, 1110, 1110, 1111, 1112, 1113, 1114, 1115, 1116, 1117, 1118, 1118, 1119, 1120, 1120, 1121, 1122, 1123, 1124, 1125, 1125, 1126, 1127, 1127, 1128, 1129, 1130, 1131, 1132, 1134, 1135, 1136, 1137, 1138, 1139, 1143, 1144, 1145, 1145, 1146, 1147, 1147, 1148, 1149, 1151, 1152, 1153, 1153, 1154, 1155, 1155, 1156, 1157, 1157, 1158, 1159, 1159, 1160, 1161, 1161, 1162, 1163, 1165, 1166, 1167, 1168, 1169, 1170, 1172, 1173, 1174, 1175, 1176, 1177, 1180, 1181, 1182, 1182, 1183, 1184, 1184, 1185, 1186, 1187, 1188, 1189, 1192, 1193, 1194, 1195, 1196, 1197, 1200, 1201, 1202, 1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1211, 1211, 1212, 1213, 1215, 1216, 1217, 1217, 1218, 1219, 1223, 1224, 1225, 1226, 1227, 1228, 1228, 1229, 1230, 1231, 1232, 1233, 1234, 1235, 1236, 1236, 1237, 1238, 1240, 1241, 1242, 2454, 2455, 2456, 2456, 2457, 2458, 2462, 2463, 2464, 2467, 2468, 2469, 2470, 2471, 2472, 2472, 2446, 2447, 2448, 2449, 2450, 2451, 2452, 2453, 1247, 1248, 1249, 1250, 1251, 1252, 1254, 1255, 1256, 1257, 1258, 1259, 1260, 1261, 1262, 1262, 1263, 1264, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1272, 1273, 1274, 1275, 1276, 1277, 1279, 1280, 1281, 1282, 1283, 1284, 1286, 1287, 1288, 1288, 1289, 1290, 1290, 1291, 1292, 1293, 1294, 1295, 1296, 1297, 1298, 1298, 1299, 1301, 1303, 1304, 1305, 1305, 1306, 1307, 1308, 1309, 1310, 1311, 1312, 1313, 1313, 1314, 1315, 1318, 1319, 1320, 1322, 1323, 1324, 1324, 1325, 1326, 1327, 1328, 1329, 1329, 1330, 1331, 1332, 1333, 1334, 1336, 1337, 1338, 1338, 1339, 1340, 1340, 1341, 1342, 1343, 1344, 1345, 1345, 1346, 1347, 1348, 1349, 1350, 1353, 1354, 1355, 1356, 1357, 1358, 1358, 1359, 1360, 1360, 1361, 1362, 1364, 1365, 1366, 1366, 1367, 1368, 1369, 1370, 1371, 1371, 1372, 1373, 1373, 1374, 1375, 1377, 1378, 1379, 1379, 1380, 1381, 1381, 1382, 1383, 1383, 1384, 1385, 1386, 1387, 1388, 1388, 1389, 1390, 1393, 1394, 1395, 1395, 1396, 1397, 1398, 1399, 1400, 1402, 1403, 1404, 1404, 1405, 1406, 1408, 1409, 1410, 1410, 1411, 1412, 1413, 1414, 1415, 1416, 1417, 1418, 1420, 1421, 1422, 1422, 1423, 1424, 1424, 1425, 1426, 1427, 1428, 1429, 1429, 1430, 1431, 1431, 1432, 1433, 1435, 1436, 1437, 1437, 1438, 1439, 1439, 1440, 1441, 1441, 1442, 1443, 1445, 1446, 1447, 1449, 1450, 1451, 1452, 1453, 1454, 1454, 1455, 1456, 1456, 1457, 1458, 1460, 1461, 1462, 1464, 1465, 1466, 1467, 1468, 1469, 1472, 1473, 1474, 1475, 1476, 1477, 1477, 1478, 1479, 1479, 1480, 1481, 1481, 1482, 1483, 1483, 1484, 1485, 1485, 1486, 1487, 1487, 1488, 1489, 1492, 1493, 1494, 1495, 1496, 1497, 1498, 1499, 1500, 1501, 1502, 1503, 1504, 1505, 1506, 1507, 1508, 1509, 1510, 1511, 1512, 1514, 1515, 1516, 1516, 1517, 1518, 1518, 1519, 1520, 1520, 1521, 1522, 1523, 1524, 1525, 1528, 1529, 1530, 1532, 1533, 1534, 1534, 1535, 1536, 1536, 1537, 1538, 1539, 1540, 1541, 1546, 1547, 1548, 2500, 2502, 2503, 2505, 2507, 2509, 2510, 2512, 2514, 2517, 2518, 2520, 2489, 2491, 2493, 1549, 1551, 1552, 1553, 1555, 1556, 1558, 1560, 1562, 1564, 1566, 1567, 1567, 1569, 1571, 1574, 1576, 1578, 1578, 1579, 1581, 1583, 1585, 1586, 1587, 1589, 1590, 1592, 1594, 1595, 1599, 1601, 1603, 1603, 1605, 1607, 1608, 1610, 1611, 1611, 1613, 1614, 1614, 1616, 1618, 1619, 1621, 1623, 1623, 1625, 1626, 1628, 1630, 1631, 1631, 1633, 1634, 1637, 1639, 1640, 1640, 1642, 1643, 1648, 1650, 1652, 1652, 1654, 1656, 1658, 1660, 1661, 1665, 1666, 1668, 1668, 1670, 1672, 1672, 1674, 1676, 1679, 1680, 1682, 1682, 1684, 1686, 1687, 1688, 1690, 1690, 1692, 1694, 1699, 1701, 1703, 1703, 1705, 1707, 1709, 1711, 1712, 1716, 1718, 1720, 1720, 1722, 1724, 1724, 1726, 1727, 1728, 1729, 1731, 1731, 1732, 1734, 1738, 1740, 1742, 1745, 1746, 1748, 1748, 1750, 1752, 1752, 1754, 1755, 1755, 1757, 1759, 1766, 1767, 1769, 1785, 1787, 1788, 1788, 1790, 1791, 1791, 1793, 1794, 1796, 1798, 1800, 1802, 1804, 1805, 1816, 1818, 1819, 1821, 1823, 1825, 1825, 1826, 1828, 1831, 1833, 1835, 1841, 1843, 1844, 1846, 1848, 1850, 1853, 1855, 1857, 1861, 1862, 1864, 1872, 1873, 1875, 1880, 1881, 1883, 1891, 1893, 1894, 1895, 1897, 1899, 1900, 1902, 1903, 1903, 1905, 1906, 1906, 1908, 1911, 1913, 1915, 1916, 1918, 1920, 1921, 1921, 1923, 1925, 1927, 1928, 1930, 1930, 1932, 1933, 1933, 1935, 1937, 1939, 1941, 1942, 1942, 1944, 1945, 1945, 1947, 1948, 1955, 1957, 1958, 1959, 1960, 1962, 1964, 1966, 1967, 1967, 1968, 1970, 1974, 1976, 1977, 1978, 1979, 1981, 1982, 1984, 1985, 1987, 1989, 1991, 1992, 1994, 1996, 1996, 1998, 1999, 2002, 2004, 2006, 2008, 2009, 2011, 2014, 2015, 2017, 2023, 2025, 2026, 2026, 2028, 2029, 2032, 2033, 2413, 2035, 2037, 2038, 2038, 2040, 2041, 2042, 2043, 2045, 2047, 2048, 2050, 2055, 2057, 2059, 2065, 2067, 2068, 2083, 2085, 2086, 2086, 2087, 2089, 2090, 2092, 2094, 2094, 2095, 2097, 2097, 2098, 2100, 2101, 2103, 2105, 2105, 2107, 2108, 2109, 2111, 2112, 2113, 2115, 2116, 2116, 2118, 2120, 2122, 2123, 2125, 2125, 2126, 2128, 2133, 2135, 2137, 2137, 2139, 2140, 2142, 2145, 2146, 2151, 2153, 2154, 2162, 2164, 2166, 2173, 2175, 2176, 2176, 2179, 2180, 2181, 2182, 2184, 2187, 2189, 2191, 2191, 2193, 2195, 2198, 2200, 2201, 2205, 2207, 2208, 2212, 2214, 2216, 2216, 2218, 2220, 2226, 2228, 2229, 2232, 2234, 2236, 2238, 2239, 2241, 2245, 2247, 2248, 2250, 2252, 2253, 2253, 2254, 2256, 2256, 2258, 2260, 2262, 2264, 2266, 2271, 2272, 2274, 2274, 2276, 2278, 2287, 2289, 2291, 2294, 2296, 2297, 2298, 2300, 2301, 2301, 2303, 2304, 2307, 2308, 2310, 2310, 2312, 2313, 2317, 2318, 2320, 2320, 2322, 2324, 2324, 2326, 2328, 2331, 2333, 2335, 2343, 2345, 2346, 2354, 2356, 2357, 2361, 2363, 2364, 2367, 2368, 2370, 2377, 2379, 2380, 2380, 2381, 2383, 12, 14, 15, 18, 20, 22, 22, 24, 26, 29, 31, 33, 33, 35, 37, 45, 46, 48, 50, 52, 53, 55, 56, 58, 61, 63, 65, 66, 68, 69, 70, 72, 74, 76, 77, 79, 84, 86, 87, 88, 90, 92, 94, 96, 97, 105, 107, 108, 115, 117, 118, 122, 124, 125, 127, 129, 131, 133, 134, 136, 140, 141, 143, 143, 145, 2425, 2429, 145, 147, 147, 149, 150, 151, 153, 154, 155, 157, 158, 158, 160, 161, 166, 167, 169, 169, 171, 172, 184, 186, 187, 193, 195, 197, 201, 203, 205, 207, 208, 210, 211, 213, 214, 214, 216, 217, 221, 223, 224, 224, 226, 227, 228, 230, 231, 231, 233, 234, 237, 239, 241, 244, 246, 248, 252, 253, 255, 259, 261, 263, 265, 267, 268, 269, 271, 272, 273, 275, 277, 279, 281, 282, 288, 290, 291, 293, 294, 296, 300, 302, 303, 303, 305, 307, 308, 309, 311, 314, 315, 317, 319, 321, 322, 329, 331, 333, 344, 346, 347, 353, 355, 357, 358, 359, 361, 362, 364, 365, 368, 370, 371, 372, 374, 375, 377, 379, 380, 381, 382, 384, 387, 388, 390, 391, 393, 395, 396, 397, 2419, 2421, 2422, 397, 397, 399, 401, 401, 403, 404, 404, 405, 407, 408, 409, 411, 411, 413, 414, 419, 420, 422, 423, 424, 426, 435, 436, 438, 440, 442, 443, 444, 445, 447, 447, 449, 451, 451, 453, 454, 458, 460, 461, 465, 467, 468, 468, 470, 472, 472, 474, 475, 477, 478, 480, 484, 486, 488, 489, 490, 492, 493, 494, 496, 497, 499, 501, 505, 507, 509, 512, 514, 515, 517, 519, 521, 528, 529, 531, 540, 542, 544, 545, 547, 548, 551, 553, 554, 556, 558, 560, 561, 563, 565, 567, 569, 571, 575, 577, 579, 581, 583, 584, 584, 586, 587, 587, 588, 590, 594, 596, 597, 602, 604, 605, 609, 611, 613, 613, 615, 617, 622, 623, 625, 627, 629, 630, 631, 632, 634, 639, 640, 642, 645, 647, 649, 652, 654, 655, 661, 663, 664, 665, 667, 2405, 2399, 2401, 2402, 669, 671, 672, 676, 678, 680, 682, 684, 686, 687, 689, 691, 691, 692, 694, 695, 696, 698, 698, 700, 702, 707, 709, 711, 711, 712, 714, 714, 716, 717, 717, 719, 721, 724, 725, 727, 733, 735, 736, 736, 738, 739, 739, 741, 742, 742, 743, 745, 752, 754, 756, 756, 758, 759, 759, 761, 763, 765, 767, 769, 779, 781, 782, 782, 783, 785, 785, 787, 788, 791, 793, 794, 795, 797, 798, 800, 802, 803, 809, 811, 812, 812, 814, 816, 820, 822, 824, 828, 830, 831, 831, 833, 834, 834, 836, 837, 837, 839, 841, 844, 846, 847, 850, 851, 853, 855, 857, 859, 862, 864, 865, 867, 869, 870, 872, 874, 876, 876, 878, 880, 889, 891, 892, 892, 894, 895, 896, 898, 899, 901, 903, 904, 907, 908, 910, 910, 912, 913, 915, 917, 919, 919, 921, 922, 934, 936, 937, 938, 940, 942, 942, 944, 945, 948, 950, 951, 953, 954, 956, 958, 960, 961, 965, 967, 968, 976, 977, 979, 982, 983, 985, 985, 986, 988, 989, 991, 993, 995, 996, 998, 998, 1000, 1002, 1011, 1013, 1014, 1014, 1016, 1018, 1022, 1024, 1025, 1025, 2441, 2442, 2443, 1026, 1027, 1027, 1029, 1030, 1033, 1035, 1036, 1036, 1038, 1040, 1044, 1046, 1047, 1049, 1051, 1053, 1053, 1055, 1056, 1057, 1059, 1061, 1062, 1064, 1065, 1066, 1068, 1069, 1070, 1072, 1073, 1074, 1076, 1078, 1078, 1080, 1082, 1082, 1083, 1085, 1085, 1086, 1088, 1094, 1095, 1097, 1098, 2480, 2481, 2478, 2480, 1100, 1100, 1102, 1103, 1103, 1104, 2437, 1106, 1108, 1110, 1118, 1120, 1122, 1123, 1125, 1127, 1127, 1129, 1130, 1130, 1132, 1133, 1133, 1134, 1136, 1145, 1147, 1149, 1153, 1155, 1157, 1157, 1159, 1161, 1161, 1163, 1164, 1168, 1170, 1171, 1171, 1172, 1174, 1175, 1177, 1178, 1179, 1180, 1182, 1182, 1184, 1186, 1187, 1189, 1190, 1192, 1194, 1195, 1195, 1197, 1198, 1199, 1200, 1202, 1205, 1206, 1208, 1211, 1213, 1214, 1215, 1217, 1219, 1223, 1225, 1226, 1230, 1231, 1233, 1234, 1236, 1238, 1240, 1242, 1243, 2461, 2462, 2464, 2467, 2469, 2470, 2448, 2450, 2451, 2451, 2453, 1246, 1246, 1247, 1249, 1253, 1254, 1256, 1256, 1257, 1259, 1264, 1266, 1267, 1270, 1272, 1274, 1278, 1279, 1281, 1282, 1284, 1285, 1286, 1288, 1290, 1296, 1298, 1301, 1308, 1310, 1311, 1311, 1313, 1315, 1317, 1318, 1320, 1322, 1324, 1326, 1329, 1331, 1332, 1332, 1334, 1335, 1336, 1338, 1340, 1340, 1342, 1343, 1343, 1345, 1347, 1348, 1350, 1351, 1355, 1356, 1358, 1360, 1362, 1363, 1364, 1366, 1368, 1369, 1371, 1373, 1377, 1379, 1381, 1383, 1385, 1386, 1386, 1388, 1390, 1393, 1395, 1397, 1397, 1398, 1400, 1401, 1402, 1404, 1407, 1408, 1410, 1415, 1416, 1418, 1422, 1424, 1426, 1426, 1427, 1429, 1435, 1437, 1439, 1441, 1443, 1444, 1445, 1447, 1448, 1448, 1449, 1451, 1454, 1456, 1458, 1459, 1460, 1462, 1464, 1466, 1467, 1472, 1474, 1475, 1481, 1483, 1485, 1485, 1487, 1489, 1492, 1494, 1495, 1501, 1503, 1504, 1507, 1509, 1510, 1514, 1516, 1518, 1518, 1520, 1522, 1523, 1525, 1526, 1532, 1534, 1536, 1536, 1538, 1539, 1539, 1541, 1542, 1545, 1546, 1548, 2500, 2503, 2505, 2505, 2509, 2510, 2489, 2493, 2494, 2496, 1548, 1549, 1552, 1553, 1556, 1562, 1564, 1567, 1578, 1581, 1582, 1583, 1586, 1587, 1587, 1590, 1592, 1599, 1603, 1607, 1607, 1608, 1611, 1611, 1614, 1618, 1619, 1623, 1626, 1627, 1628, 1631, 1631, 1634, 1635, 1635, 1637, 1640, 1646, 1648, 1652, 1652, 1656, 1658, 1658, 1661, 1662, 1665, 1668, 1672, 1679, 1682, 1686, 1687, 1690, 1694, 1699, 1703, 1707, 1708, 1709, 1712, 1714, 1716, 1720, 1720, 1724, 1727, 1727, 1728, 1731, 1738, 1742, 1743, 1748, 1752, 1755, 1766, 1769, 1770, 1796, 1800, 1801, 1801, 1802, 1805, 1815, 1816, 1819, 1819, 1821, 1825, 1825, 1828, 1829, 1830, 1831, 1835, 1841, 1844, 1845, 1845, 1846, 1850, 1861, 1864, 1865, 1872, 1875, 1876, 1880, 1883, 1885, 1891, 1894, 1895, 1900, 1903, 1906, 1913, 1916, 1918, 1918, 1921, 1925, 1930, 1933, 1937, 1942, 1945, 1948, 1953, 1955, 1958, 1959, 1962, 1964, 1967, 1970, 1972, 1973, 1974, 1977, 1978, 1981, 1982, 1982, 1985, 1987, 1987, 1991, 1992, 2002, 2006, 2008, 2008, 2011, 2012, 2014, 2017, 2019, 2022, 2023, 2026, 2034, 2035, 2038, 2042, 2045, 2046, 2047, 2050, 2051, 2054, 2055, 2059, 2065, 2068, 2070, 2081, 2083, 2086, 2089, 2090, 2094, 2097, 2100, 2101, 2101, 2105, 2108, 2113, 2116, 2120, 2122, 2125, 2128, 2133, 2137, 2140, 2141, 2142, 2146, 2151, 2154, 2156, 2161, 2162, 2166, 2171, 2173, 2176, 2176, 2180, 2181, 2181, 2184, 2185, 2196, 2198, 2201, 2216, 2220, 2221, 2224, 2226, 2229, 2238, 2241, 2242, 2245, 2248, 2249, 2253, 2256, 2260, 2261, 2262, 2266, 2270, 2271, 2274, 2287, 2291, 2292, 2293, 2294, 2297, 2298, 2301, 2304, 2307, 2310, 2313, 2317, 2320, 2324, 2330, 2331, 2335, 2342, 2343, 2346, 2353, 2354, 2357, 2367, 2370, 2372, 2380, 2383, 2385, 12, 15, 17, 22, 26, 27, 29, 33, 37, 45, 48, 50, 50, 53, 55, 60, 61, 65, 69, 70, 74, 82, 84, 87, 87, 88, 92, 93, 94, 97, 103, 105, 108, 114, 115, 118, 121, 122, 125, 127, 131, 133, 133, 136, 137, 140, 143, 2425, 2428, 2429, 147, 154, 155, 158, 166, 169, 172, 183, 184, 187, 192, 193, 197, 201, 205, 207, 210, 211, 214, 214, 217, 218, 220, 221, 224, 228, 231, 234, 236, 237, 241, 244, 248, 249, 252, 255, 256, 259, 263, 265, 268, 269, 272, 273, 277, 278, 279, 282, 284, 287, 288, 291, 293, 296, 298, 300, 303, 307, 314, 317, 318, 318, 319, 322, 329, 333, 335, 343, 344, 347, 352, 353, 357, 361, 362, 365, 367, 368, 371, 371, 372, 375, 376, 377, 380, 387, 390, 391, 391, 395, 396, 396, 2419, 2420, 2421, 397, 401, 404, 407, 408, 411, 414, 416, 423, 426, 428, 435, 438, 439, 439, 440, 443, 444, 447, 451, 451, 454, 456, 458, 461, 463, 464, 465, 468, 477, 480, 481, 489, 492, 493, 493, 496, 497, 497, 501, 502, 510, 512, 515, 516, 517, 521, 528, 531, 533, 540, 544, 545, 550, 551, 554, 556, 560, 561, 566, 567, 571, 580, 581, 584, 587, 590, 591, 601, 602, 605, 607, 609, 613, 620, 622, 625, 631, 634, 635, 639, 642, 643, 645, 649, 651, 652, 655, 657, 660, 661, 664, 665, 2405, 2407, 2397, 2399, 2402, 669, 672, 674, 676, 680, 681, 681, 682, 686, 698, 702, 703, 705, 707, 711, 711, 714, 717, 717, 721, 723, 724, 727, 728, 732, 733, 736, 736, 739, 742, 742, 745, 747, 765, 769, 770, 778, 779, 782, 794, 795, 798, 807, 809, 812, 812, 816, 818, 828, 831, 834, 834, 837, 841, 843, 844, 847, 862, 865, 867, 870, 872, 876, 876, 880, 882, 889, 892, 895, 895, 896, 899, 901, 904, 906, 907, 910, 913, 915, 919, 922, 937, 938, 942, 947, 948, 951, 951, 953, 956, 958, 961, 963, 963, 965, 968, 976, 979, 980, 982, 985, 988, 995, 998, 1002, 1014, 1018, 1019, 1022, 1025, 2442, 1027, 1030, 1032, 1032, 1033, 1036, 1036, 1040, 1041, 1042, 1044, 1047, 1049, 1053, 1056, 1056, 1057, 1061, 1062, 1065, 1066, 1074, 1078, 1082, 1094, 1097, 1098, 2478, 1100, 1103, 1105, 1106, 1110, 1118, 1122, 1123, 1123, 1127, 1130, 1145, 1149, 1150, 1153, 1157, 1161, 1167, 1168, 1171, 1171, 1174, 1175, 1175, 1178, 1179, 1179, 1182, 1186, 1187, 1190, 1191, 1192, 1195, 1198, 1199, 1202, 1203, 1203, 1205, 1208, 1211, 1214, 1215, 1215, 1219, 1220, 1230, 1233, 1234, 1234, 1238, 1239, 1240, 1243, 1244, 2461, 2464, 2465, 2447, 2448, 2451, 1246, 1249, 1250, 1252, 1253, 1256, 1262, 1264, 1267, 1269, 1270, 1274, 1278, 1281, 1282, 1295, 1296, 1301, 1308, 1311, 1315, 1322, 1326, 1327, 1329, 1332, 1335, 1335, 1336, 1340, 1343, 1347, 1348, 1353, 1355, 1358, 1363, 1364, 1368, 1369, 1373, 1375, 1376, 1377, 1381, 1383, 1386, 1390, 1393, 1397, 1400, 1401, 1404, 1406, 1407, 1410, 1412, 1422, 1426, 1429, 1434, 1435, 1439, 1439, 1441, 1444, 1448, 1451, 1452, 1454, 1458, 1459, 1459, 1462, 1463, 1472, 1475, 1477, 1481, 1485, 1489, 1491, 1492, 1495, 1501, 1504, 1506, 1513, 1514, 1518, 1523, 1526, 1527, 1532, 1536, 1539, 1539, 1542, 1543, 1545, 1548, 2497, 2500, 2505, 2510, 2489, 2494, 2496, 2496, 1549, 1552, 1562, 1567, 1571, 1574, 1578, 1582, 1583, 1587, 1592, 1607, 1611, 1618, 1626, 1627, 1631, 1635, 1640, 1643, 1646, 1652, 1658, 1664, 1665, 1672, 1678, 1679, 1686, 1687, 1694, 1695, 1698, 1699, 1707, 1714, 1720, 1727, 1745, 1748, 1755, 1765, 1766, 1770, 1796, 1801, 1805, 1815, 1819, 1825, 1830, 1835, 1837, 1845, 1850, 1852, 1860, 1861, 1865, 1870, 1872, 1876, 1880, 1885, 1887, 1890, 1891, 1895, 1900, 1906, 1911, 1912, 1913, 1918, 1918, 1925, 1926, 1942, 1948, 1950, 1953, 1958, 1959, 1959, 1964, 1967, 1978, 1982, 1987, 1987, 1992, 1996, 2002, 2008, 2012, 2014, 2019, 2021, 2022, 2026, 2029, 2034, 2038, 2041, 2042, 2046, 2047, 2054, 2059, 2060, 2065, 2070, 2072, 2079, 2081, 2086, 2089, 2094, 2097, 2097, 2101, 2108, 2112, 2113, 2120, 2122, 2128, 2129, 2132, 2133, 2140, 2141, 2146, 2148, 2150, 2151, 2156, 2159, 2161, 2166, 2171, 2176, 2181, 2196, 2201, 2203, 2212, 2216, 2221, 2236, 2238, 2242, 2244, 2245, 2249, 2261, 2266, 2268, 2270, 2274, 2278, 2285, 2287, 2292, 2297, 2298, 2304, 2307, 2313, 2315, 2317, 2324, 2328, 2330, 2335, 2337, 2353, 2357, 2359, 2366, 2367, 2372, 2380, 2385, 2387, 11, 12, 17, 28, 29, 37, 45, 50, 55, 60, 65, 66, 69, 74, 76, 82, 87, 92, 92, 93, 97, 101, 103, 108, 114, 118, 119, 121, 125, 127, 127, 133, 137, 140, 2425, 2427, 2428, 147, 150, 154, 158, 161, 166, 172, 173, 192, 197, 198, 210, 214, 218, 220, 224, 227, 228, 234, 235, 235, 236, 241, 242, 244, 249, 252, 256, 258, 259, 265, 268, 272, 273, 278, 287, 291, 292, 292, 293, 298, 300, 307, 308, 312, 314, 318, 328, 329, 335, 343, 347, 348, 352, 357, 358, 361, 365, 367, 367, 371, 375, 376, 380, 381, 386, 387, 391, 2421, 401, 404, 411, 416, 417, 422, 423, 428, 435, 439, 443, 444, 451, 456, 457, 458, 463, 464, 468, 472, 475, 477, 481, 489, 493, 497, 510, 515, 516, 516, 521, 522, 527, 528, 533, 540, 545, 548, 549, 550, 554, 555, 556, 561, 566, 571, 572, 580, 584, 587, 599, 601, 605, 607, 613, 617, 620, 625, 626, 630, 631, 635, 638, 639, 643, 652, 657, 658, 660, 664, 665, 665, 2407, 2409, 668, 669, 674, 675, 676, 681, 681, 686, 687, 695, 698, 703, 705, 711, 717, 723, 724, 728, 731, 732, 736, 742, 747, 749, 763, 765, 770, 778, 782, 785, 791, 794, 798, 807, 812, 818, 827, 828, 834, 834, 841, 843, 843, 847, 849, 861, 862, 867, 876, 882, 884, 887, 889, 895, 895, 899, 900, 900, 901, 906, 907, 913, 914, 914, 915, 922, 937, 942, 945, 945, 947, 951, 963, 968, 970, 974, 976, 980, 982, 988, 989, 994, 995, 1002, 1011, 1014, 1019, 1027, 1032, 1036, 1047, 1049, 1056, 1061, 1062, 1066, 1074, 1082, 1085, 1094, 1098, 2481, 2478, 1103, 2437, 1105, 1110, 1112, 1123, 1130, 1133, 1143, 1145, 1150, 1151, 1153, 1161, 1167, 1171, 1175, 1179, 1186, 1187, 1192, 1198, 1199, 1203, 1208, 1209, 1211, 1215, 1220, 1228, 1230, 1234, 1239, 1240, 1244, 1245, 1246, 2453, 2460, 2461, 2465, 2472, 2447, 2451, 2451, 1246, 1250, 1252, 1256, 1259, 1262, 1267, 1269, 1269, 1274, 1275, 1295, 1301, 1302, 1307, 1308, 1315, 1321, 1322, 1327, 1327, 1329, 1335, 1335, 1340, 1343, 1343, 1348, 1351, 1363, 1368, 1369, 1369, 1375, 1376, 1381, 1383, 1390, 1393, 1400, 1401, 1401, 1406, 1407, 1407, 1412, 1413, 1422, 1429, 1431, 1439, 1444, 1445, 1448, 1452, 1454, 1472, 1477, 1479, 1479, 1481, 1489, 1491, 1495, 1497, 1500, 1501, 1506, 1513, 1518, 1522, 1523, 1527, 1528, 2500, 2510, 2514, 2489, 2496, 1552, 1562, 1571, 1573, 1573, 1574, 1582, 1583, 1592, 1595, 1599, 1607, 1618, 1626, 1631, 1635, 1646, 1658, 1662, 1662, 1664, 1672, 1678, 1686, 1687, 1687, 1695, 1697, 1697, 1698, 1707, 1713, 1714, 1727, 1745, 1755, 1759, 1763, 1765, 1770, 1795, 1796, 1805, 1815, 1825, 1829, 1830, 1837, 1839, 1841, 1845, 1852, 1870, 1876, 1877, 1879, 1880, 1887, 1890, 1895, 1899, 1899, 1900, 1911, 1939, 1942, 1950, 1951, 1953, 1959, 1959, 1967, 1972, 1978, 1987, 1996, 2001, 2002, 2012, 2013, 2014, 2021, 2042, 2047, 2051, 2052, 2054, 2060, 2064, 2065, 2072, 2077, 2079, 2086, 2097, 2108, 2109, 2112, 2120, 2121, 2121, 2122, 2129, 2132, 2140, 2141, 2141, 2148, 2150, 2159, 2166, 2167, 2171, 2181, 2185, 2195, 2196, 2203, 2212, 2221, 2223, 2243, 2244, 2249, 2261, 2268, 2269, 2269, 2270, 2278, 2285, 2292, 2293, 2297, 2304, 2306, 2306, 2307, 2315, 2316, 2317, 2328, 2329, 2330, 2337, 2351, 2353, 2359, 2366, 2372, 2373, 2377, 2380, 2387, 11, 17, 18, 28, 37, 38, 45, 55, 58, 58, 60, 66, 81, 82, 92, 112, 114, 119, 120, 121, 127, 140, 2427, 2428, 2428, 150, 151, 151, 154, 161, 164, 166, 173, 191, 192, 198, 207, 210, 218, 220, 227, 228, 242, 249, 250, 250, 252, 258, 258, 259, 268, 272, 278, 279, 287, 292, 298, 299, 300, 308, 312, 318, 322, 328, 335, 337, 343, 348, 349, 351, 352, 358, 361, 367, 375, 386, 391, 396, 2420, 2421, 404, 408, 411, 417, 422, 428, 429, 444, 456, 457, 457, 463, 464, 488, 489, 497, 509, 510, 516, 516, 522, 524, 527, 533, 534, 540, 548, 549, 555, 561, 565, 566, 572, 573, 579, 580, 587, 606, 607, 617, 618, 620, 626, 630, 635, 636, 638, 643, 644, 651, 652, 658, 658, 660, 665, 668, 674, 675, 675, 681, 687, 705, 717, 723, 723, 728, 729, 731, 736, 742, 763, 770, 772, 778, 785, 788, 791, 798, 800, 807, 818, 819, 827, 834, 843, 861, 867, 870, 887, 895, 900, 914, 922, 924, 937, 945, 951, 982, 989, 993, 1009, 1011, 1019, 1042, 1047, 1056, 1061, 1066, 1069, 1073, 1074, 1085, 1092, 1094, 2481, 2477, 2478, 2437, 1118, 1123, 1133, 1143, 1150, 1151, 1151, 1161, 1164, 1167, 1175, 1179, 1192, 1199, 1203, 1228, 1234, 1239, 1245, 2453, 2454, 2460, 2465, 2466, 1250, 1252, 1259, 1269, 1275, 1277, 1295, 1302, 1303, 1307, 1315, 1316, 1321, 1327, 1335, 1335, 1343, 1351, 1363, 1369, 1376, 1381, 1390, 1391, 1392, 1393, 1401, 1420, 1422, 1431, 1434, 1439, 1445, 1448, 1454, 1459, 1479, 1489, 1490, 1490, 1491, 1497, 1500, 1506, 1507, 1512, 1513, 1522, 1523, 1528, 1530, 2499, 2500, 2514, 2489, 1552, 1556, 1558, 1562, 1573, 1573, 1582, 1583, 1583, 1595, 1597, 1598, 1599, 1618, 1626, 1635, 1643, 1646, 1662, 1672, 1678, 1687, 1697, 1697, 1707, 1708, 1712, 1713, 1727, 1745, 1759, 1760, 1763, 1770, 1771, 1795, 1805, 1807, 1815, 1829, 1830, 1841, 1852, 1853, 1869, 1870, 1877, 1890, 1899, 1911, 1951, 1959, 1972, 1977, 1978, 1996, 2001, 2012, 2013, 2013, 2021, 2022, 2041, 2042, 2051, 2064, 2072, 2074, 2077, 2086, 2089, 2089, 2097, 2109, 2112, 2121, 2129, 2132, 2141, 2150, 2157, 2159, 2167, 2195, 2203, 2205, 2243, 2249, 2250, 2260, 2261, 2269, 2269, 2278, 2279, 2297, 2306, 2315, 2315, 2316, 2328, 2351, 2359, 2360, 2364, 2366, 2373, 2377, 2387, 2389, 28, 38, 39, 44, 45, 58, 58, 66, 69, 81, 92, 97, 110, 112, 119, 120, 127, 137, 164, 173, 175, 191, 198, 200, 207, 218, 220, 241, 242, 250, 258, 268, 272, 272, 279, 284, 299, 308, 311, 311, 312, 322, 328, 337, 339, 342, 343, 349, 351, 358, 361, 361, 375, 376, 384, 386, 396, 422, 429, 431, 484, 488, 497, 509, 516, 524, 526, 527, 534, 538, 540, 549, 555, 565, 566, 566, 573, 575, 579, 587, 591, 606, 617, 618, 630, 636, 637, 637, 638, 644, 651, 658, 665, 2404, 668, 675, 703, 705, 723, 731, 742, 749, 763, 772, 774, 776, 778, 788, 791, 800, 803, 807, 819, 820, 827, 843, 849, 859, 861, 870, 887, 900, 906, 914, 924, 925, 934, 937, 951, 982, 993, 994, 1009, 1019, 1020, 1041, 1042, 1056, 1061, 1069, 1070, 1073, 1085, 1088, 1092, 2481, 2475, 2476, 2477, 2437, 1118, 1133, 1136, 1143, 1151, 1164, 1165, 1167, 1179, 1191, 1192, 1203, 1228, 1239, 1244, 1244, 1245, 2454, 2459, 2460, 2466, 1250, 1259, 1260, 1262, 1269, 1277, 1293, 1295, 1303, 1307, 1316, 1317, 1360, 1363, 1376, 1376, 1381, 1391, 1392, 1401, 1407, 1420, 1431, 1433, 1433, 1434, 1445, 1490, 1497, 1498, 1512, 1522, 1523, 2497, 2499, 2514, 2489, 1556, 1558, 1558, 1573, 1583, 1598, 1618, 1619, 1646, 1672, 1676, 1677, 1678, 1697, 1712, 1727, 1731, 1743, 1745, 1760, 1763, 1771, 1773, 1795, 1807, 1808, 1814, 1815, 1830, 1841, 1853, 1857, 1869, 1877, 1878, 1889, 1890, 1911, 1951, 1972, 1973, 1977, 1996, 1999, 2001, 2013, 2022, 2041, 2051, 2052, 2064, 2074, 2075, 2077, 2089, 2109, 2112, 2129, 2131, 2157, 2167, 2168, 2191, 2195, 2205, 2242, 2243, 2250, 2297, 2315, 2328, 2350, 2351, 2360, 2364, 2373, 2374, 2377, 2389, 2390, 42, 44, 58, 58, 69, 76, 109, 110, 119, 119, 120, 137, 162, 164, 175, 189, 191, 200, 201, 207, 220, 235, 241, 250, 272, 284, 286, 298, 299, 311, 311, 322, 323, 326, 328, 339, 342, 349, 351, 351, 361, 376, 384, 396, 2420, 419, 422, 431, 484, 497, 502, 505, 509, 524, 526, 534, 536, 554, 555, 566, 575, 579, 591, 627, 630, 637, 651, 665, 2409, 2404, 675, 687, 695, 703, 723, 731, 749, 750, 759, 763, 774, 776, 788, 790, 790, 791, 803, 826, 827, 849, 859, 870, 876, 885, 887, 906, 907, 914, 925, 932, 934, 951, 1008, 1009, 1020, 1041, 1056, 1061, 1061, 1070, 1073, 2475, 2476, 2437, 1118, 1136, 1137, 1143, 1164, 1165, 1165, 1179, 1187, 1191, 1203, 1209, 1226, 1228, 1244, 1244, 2454, 2456, 2451, 1250, 1260, 1262, 1277, 1278, 1293, 1303, 1305, 1305, 1307, 1317, 1360, 1376, 1391, 1392, 1407, 1413, 1419, 1420, 1433, 1433, 1445, 1448, 1490, 1498, 1500, 1510, 1512, 1523, 2488, 2489, 1558, 1558, 1583, 1597, 1598, 1619, 1626, 1646, 1676, 1677, 1677, 1697, 1708, 1708, 1712, 1731, 1743, 1760, 1761, 1794, 1795, 1808, 1814, 1830, 1839, 1869, 1878, 1879, 1889, 1911, 1912, 1977, 1999, 2001, 2001, 2022, 2029, 2034, 2041, 2052, 2075, 2077, 2109, 2112, 2131, 2132, 2156, 2157, 2168, 2187, 2191, 2205, 2242, 2250, 2253, 2293, 2297, 2328, 2350, 2360, 2361, 2361, 2364, 2374, 42, 58, 76, 119, 137, 138, 161, 162, 175, 189, 200, 201, 201, 220, 228, 228, 235, 250, 298, 311, 323, 325, 326, 339, 351, 376, 381, 381, 384, 2420, 419, 431, 432, 483, 484, 502, 505, 524, 525, 525, 526, 536, 627, 637, 644, 2402, 2404, 687, 695, 723, 729, 731, 750, 751, 759, 774, 776, 790, 803, 805, 824, 826, 849, 855, 859, 876, 932, 951, 956, 1008, 1020, 1022, 1041, 1061, 1073, 1116, 1118, 1137, 1143, 1165, 1187, 1187, 1191, 1209, 1223, 1226, 1244, 1244, 2456, 2458, 2451, 1260, 1262, 1262, 1278, 1282, 1293, 1305, 1317, 1358, 1360, 1391, 1391, 1392, 1413, 1418, 1419, 1433, 1479, 1490, 1500, 2488, 1558, 1597, 1598, 1626, 1643, 1646, 1677, 1708, 1738, 1743, 1761, 1791, 1794, 1808, 1813, 1814, 1839, 1867, 1869, 1879, 1889, 1912, 1918, 1977, 2001, 2029, 2034, 2052, 2060, 2064, 2075, 2109, 2109, 2112, 2132, 2150, 2156, 2168, 2186, 2187, 2205, 2236, 2242, 2253, 2293, 2328, 2329, 2350, 2361, 2374, 161, 175, 176, 187, 189, 201, 324, 325, 339, 381, 2420, 404, 418, 419, 432, 483, 502, 504, 505, 525, 536, 626, 627, 644, 2402, 687, 691, 694, 695, 729, 731, 751, 752, 756, 759, 776, 820, 824, 849, 853, 855, 876, 931, 932, 956, 1036, 1041, 1073, 1115, 1116, 1137, 1142, 1143, 1187, 1187, 1209, 1211, 1223, 1244, 2458, 2472, 2451, 1262, 1262, 1282, 1285, 1293, 1317, 1320, 1358, 1391, 1413, 1418, 1433, 1448, 1479, 1500, 1507, 2486, 2488, 1597, 1597, 1598, 1643, 1645, 1646, 1708, 1738, 1761, 1762, 1788, 1791, 1808, 1811, 1813, 1839, 1867, 1879, 1887, 1889, 1918, 1926, 1973, 1977, 2029, 2034, 2060, 2062, 2064, 2109, 2132, 2150, 2168, 2170, 2186, 2205, 2208, 2236, 2253, 2260, 2350, 2374, 2375, 151, 161, 176, 187, 201, 228, 324, 339, 341, 381, 404, 408, 418, 432, 433, 482, 483, 504, 504, 505, 536, 618, 626, 644, 2397, 2402, 691, 694, 729, 731, 752, 756, 776, 807, 820, 849, 850, 853, 876, 1036, 1073, 1088, 1142, 1187, 1211, 1222, 1223, 2458, 2472, 1262, 1285, 1293, 1320, 1321, 1415, 1418, 1448, 1479, 1507, 1510, 2485, 2486, 1597, 1597, 1643, 1644, 1645, 1708, 1731, 1736, 1738, 1762, 1788, 1808, 1809, 1811, 1839, 1840, 1867, 1887, 1889, 1951, 1973, 2029, 2064, 2132, 2150, 2150, 2170, 2171, 2186, 2208, 2209, 2232, 2236, 2260, 2350, 2375, 2376, 187, 228, 250, 323, 324, 341, 351, 381, 408, 418, 433, 435, 482, 504, 536, 618, 644, 645, 2397, 691, 694, 752, 776, 790, 807, 849, 850, 850, 876, 884, 1036, 1088, 1090, 1142, 1211, 1220, 1221, 1222, 2458, 2470, 2472, 1285, 1293, 1321, 1335, 1415, 1448, 1459, 1472, 1479, 1510, 2485, 1597, 1644, 1644, 1645, 1731, 1785, 1788, 1809, 1865, 1867, 1889, 1951, 2029, 2030, 2062, 2064, 2150, 2185, 2186, 2209, 2232, 2260, 2269, 183, 187, 250, 323, 341, 342, 351, 408, 417, 417, 418, 435, 481, 482, 536, 618, 645, 651, 2410, 2397, 694, 752, 790, 805, 807, 850, 884, 1027, 1036, 1090, 1142, 1220, 1221, 1221, 2458, 2459, 2470, 1285, 1286, 1292, 1293, 1335, 1413, 1415, 1459, 1471, 1472, 1510, 2485, 1644, 1731, 1785, 1809, 1811, 1865, 1889, 1926, 1951, 2030, 2032, 2231, 2232, 2269, 183, 250, 258, 298, 323, 342, 417, 435, 443, 481, 536, 537, 606, 618, 651, 731, 752, 805, 807, 884, 885, 2443, 1027, 1090, 1221, 2459, 2466, 1290, 1292, 1335, 1358, 1413, 1459, 1471, 1510, 1523, 2485, 1731, 1734, 1784, 1785, 1811, 1860, 1865, 1926, 1951, 2032, 2413, 2416, 2417, 2034, 2229, 2231, 2269, 183, 258, 272, 417, 443, 444, 694, 731, 805, 807, 885, 906, 2443, 1090, 1092, 1290, 1335, 1351, 1358, 1459, 1463, 1470, 1471, 1523, 1784, 1811, 1840, 1859, 1860, 1926, 1950, 1951, 2413, 2416, 2034, 2062, 2229, 2269, 2279, 183, 272, 286, 417, 444, 457, 694, 805, 807, 807, 906, 907, 2443, 1092, 2475, 1286, 1290, 1351, 1358, 1463, 1464, 1469, 1470, 1523, 1784, 1840, 1841, 1858, 1859, 1926, 1939, 1950, 2413, 2415, 2416, 2062, 2224, 2229, 2279, 351, 417, 457, 2442, 2443, 2475, 2470, 1286, 1351, 1358, 1464, 1467, 1939, 2413, 2414, 2415, 2062, 2150, 2224, 2279, 2280, 351, 457, 464, 1022, 2442, 2475, 2467, 2470, 1351, 1353, 1358, 1467, 2414, 2415, 2150, 2224, 2280, 2281, 351, 464, 472, 1022, 2475, 2437, 2466, 2467, 1351, 1353, 1467, 1469, 1939, 2414, 2150, 342, 351, 472, 1221, 2466, 1351, 1353, 1469, 1523, 342, 472, 475, 1221, 1351, 1352, 1353, 1523, 1530, 298, 342, 475, 1221, 1352, 1353, 298, 475, 481, 298, 481, 537, 287, 298, 537, 287, 537, 538, 287, 538, 549, 593, 597, 598, 2484, 2485, 1734, 2209, 2211, 2282, 592, 593, 598, 2520, 2484, 1734, 2185, 2209, 2282, 2520, 1734, 1735, 2185, 2282, 2284, 2185, 2284, 2285, 2185, 2285, 2293, 2171, 2185, 2293, 2150, 2171, 2293, 2150, 2293, 2329, 1939, 2150, 2329, 1939, 2329, 2337, 1939, 2337, 2338, 1939, 2338, 2339, 1938, 1939, 2339, 1938, 2339, 2341, 1, 3, 6, 76, 79, 81, 101, 108, 119, 179, 180, 182, 598, 599, 606, 926, 928, 931, 994, 1002, 1004, 1139, 1140, 1142, 1532, 1539, 1544, 2514, 2515, 2517, 1763, 1773, 1776, 1763, 1776, 1777, 0, 1, 6, 42, 76, 81, 101, 119, 138, 177, 179, 182, 592, 598, 606, 926, 931, 956, 982, 994, 1004, 1139, 1142, 1221, 1531, 1532, 1544, 2497, 2514, 2517, 1762, 1763, 1777, 42, 81, 97, 99, 101, 138, 177, 182, 183, 592, 606, 651, 925, 926, 956, 982, 1004, 1006, 1137, 1139, 1221, 1531, 1544, 1545, 2497, 2517, 2520, 1736, 1762, 1777, 41, 42, 97, 98, 99, 138, 176, 177, 183, 591, 592, 651, 907, 925, 956, 980, 982, 1006, 1115, 1137, 1221, 1531, 1545, 2497, 2497, 2520, 1735, 1735, 1736, 1777, 39, 41, 97, 97, 98, 138, 151, 176, 183, 575, 591, 651, 907, 956, 958, 974, 980, 1006, 1115, 1221, 1353, 1530, 1531, 2497, 1735, 1777, 1778, 28, 39, 97, 151, 183, 286, 566, 575, 651, 807, 907, 958, 974, 1006, 1007, 1113, 1115, 1353, 1530, 2497, 1735, 1735, 1778, 1780, 28, 97, 138, 2428, 151, 286, 566, 651, 2409, 807, 958, 963, 974, 1007, 1008, 1112, 1113, 1353, 1353, 1530, 1735, 1735, 1780, 1781, 28, 138, 139, 140, 2428, 286, 554, 566, 2409, 807, 963, 970, 1353, 1735, 1781, 27, 28, 139, 139, 140, 286, 554, 2409, 2410, 807, 970, 971, 1353, 1781, 1782, 27, 139, 286, 554, 2410, 694, 807, 971, 973, 1353, 1782, 1784, 27, 286, 287, 549, 554, 694, 694, 807, 973, 1112, 1353, 1784, 22, 27, 287, 694, 973, 974, 22, 287, 549, 694, 974, 1008, 549, 694, 1008, 549, 1008, 1022, 549, 1022, 2437, 2437, 1105, 1112, 549, 2437, 2438, 2436, 2437, 1112, 549, 2438, 2433, 2435, 2436, 1112, 549, 2433, 2434, 2435, 1112, 1784, 2434, 2435, 1784, 549, 2434, 1784, 22, 549, 1784, 22, 1784, 1841, 22, 1841, 1857, 22, 1857, 1858, 22, 1858, 1926, 18, 22, 1926, 18, 1926, 1927, 18, 1927, 1930, 18, 1930, 1937, 11, 18, 1937, 11, 1937, 1938, 11, 1938, 2341];
 testOutput["water"]["pass"]=true;
 testOutput["water"]["report"]="exp numtri:2482\nexp dev:0.0008\nact numtri:2482\nact dev:0.0007892413258004665";
@@ -1,5 +1,5 @@
 testOutput["water2"]=[];
 testOutput["water2"]["json"]=[[[-128.0, -128.0], [-128.0, -128.0], [-128.0, -128.0], [-128.0, 1998.0], [-119.0, 1978.0], [-127.0, 2036.0], [-128.0, 2037.0], [-128.0, 2050.0], [-107.0, 2044.0], [-77.0, 2056.0], [-61.0, 2076.0], [-57.0, 2112.0], [-59.0, 2198.0], [-49.0, 2208.0], [-29.0, 2196.0], [-21.0, 2178.0], [33.0, 2132.0], [23.0, 2018.0], [69.0, 1948.0], [75.0, 1932.0], [65.0, 1912.0], [13.0, 1888.0], [-13.0, 1862.0], [-29.0, 1834.0], [-67.0, 1832.0], [-107.0, 1816.0], [-128.0, 1817.0], [-128.0, 962.0], [-128.0, 962.0], [-128.0, 1583.0], [-121.0, 1578.0], [-93.0, 1578.0], [-55.0, 1586.0], [-23.0, 1598.0], [37.0, 1598.0], [169.0, 1466.0], [45.0, 1606.0], [65.0, 1672.0], [91.0, 1692.0], [83.0, 1864.0], [101.0, 1886.0], [139.0, 1872.0], [175.0, 1810.0], [177.0, 1786.0], [185.0, 1808.0], [163.0, 1846.0], [153.0, 1876.0], [185.0, 1882.0], [219.0, 1874.0], [269.0, 1846.0], [293.0, 1842.0], [317.0, 1846.0], [329.0, 1866.0], [361.0, 1888.0], [395.0, 1908.0], [415.0, 1910.0], [431.0, 1886.0], [441.0, 1864.0], [465.0, 1842.0], [491.0, 1828.0], [519.0, 1800.0], [537.0, 1758.0], [547.0, 1698.0], [549.0, 1626.0], [579.0, 1580.0], [595.0, 1528.0], [593.0, 1470.0], [585.0, 1428.0], [555.0, 1356.0], [509.0, 1302.0], [481.0, 1262.0], [441.0, 1236.0], [419.0, 1232.0], [393.0, 1248.0], [375.0, 1228.0], [397.0, 1238.0], [417.0, 1222.0], [441.0, 1224.0], [491.0, 1264.0], [501.0, 1230.0], [501.0, 1206.0], [511.0, 1236.0], [497.0, 1272.0], [531.0, 1312.0], [571.0, 1350.0], [583.0, 1382.0], [603.0, 1422.0], [651.0, 1386.0], [661.0, 1352.0], [655.0, 1390.0], [633.0, 1414.0], [607.0, 1430.0], [615.0, 1476.0], [615.0, 1590.0], [657.0, 1568.0], [703.0, 1522.0], [667.0, 1570.0], [637.0, 1596.0], [667.0, 1590.0], [693.0, 1612.0], [663.0, 1600.0], [641.0, 1604.0], [621.0, 1616.0], [607.0, 1736.0], [629.0, 1718.0], [653.0, 1710.0], [671.0, 1710.0], [703.0, 1726.0], [737.0, 1726.0], [699.0, 1734.0], [677.0, 1722.0], [657.0, 1718.0], [637.0, 1722.0], [619.0, 1738.0], [603.0, 1766.0], [585.0, 1828.0], [571.0, 1854.0], [555.0, 1874.0], [513.0, 1880.0], [483.0, 1876.0], [465.0, 1888.0], [443.0, 1888.0], [433.0, 1914.0], [431.0, 1926.0], [437.0, 1946.0], [461.0, 1942.0], [483.0, 1918.0], [481.0, 1934.0], [459.0, 1954.0], [485.0, 1960.0], [509.0, 1960.0], [539.0, 1968.0], [569.0, 1970.0], [597.0, 1980.0], [617.0, 1980.0], [643.0, 1976.0], [667.0, 1958.0], [719.0, 1900.0], [699.0, 1934.0], [671.0, 1972.0], [743.0, 1978.0], [765.0, 1984.0], [735.0, 1988.0], [697.0, 1982.0], [661.0, 1990.0], [645.0, 1998.0], [665.0, 2042.0], [695.0, 2098.0], [705.0, 2140.0], [719.0, 2162.0], [757.0, 2190.0], [853.0, 2186.0], [865.0, 2214.0], [881.0, 2242.0], [915.0, 2252.0], [925.0, 2264.0], [925.0, 2286.0], [917.0, 2316.0], [907.0, 2376.0], [941.0, 2382.0], [903.0, 2392.0], [895.0, 2444.0], [883.0, 2470.0], [877.0, 2566.0], [871.0, 2596.0], [947.0, 2648.0], [1011.0, 2686.0], [1017.0, 2706.0], [1029.0, 2708.0], [1041.0, 2700.0], [1099.0, 2724.0], [1167.0, 2768.0], [1427.0, 2832.0], [1447.0, 2826.0], [1467.0, 2810.0], [1481.0, 2792.0], [1487.0, 2766.0], [1489.0, 2742.0], [1480.0, 2705.0], [1466.0, 2683.0], [1429.0, 2654.0], [1270.0, 2561.0], [1243.0, 2565.0], [1260.0, 2552.0], [1209.0, 2524.0], [1190.0, 2506.0], [1187.0, 2482.0], [1195.0, 2431.0], [1215.0, 2397.0], [1222.0, 2368.0], [1210.0, 2357.0], [1174.0, 2343.0], [1126.0, 2305.0], [1114.0, 2281.0], [1099.0, 2136.0], [1135.0, 2096.0], [1174.0, 2060.0], [1179.0, 2040.0], [1173.0, 2020.0], [1175.0, 2008.0], [1170.0, 1976.0], [1136.0, 1924.0], [1121.0, 1897.0], [1129.0, 1863.0], [1157.0, 1815.0], [1175.0, 1800.0], [1205.0, 1791.0], [1227.0, 1771.0], [1243.0, 1737.0], [1236.0, 1776.0], [1218.0, 1800.0], [1198.0, 1805.0], [1178.0, 1818.0], [1160.0, 1839.0], [1140.0, 1887.0], [1147.0, 1915.0], [1170.0, 1942.0], [1191.0, 1948.0], [1211.0, 1962.0], [1259.0, 1977.0], [1301.0, 2020.0], [1369.0, 2046.0], [1385.0, 2048.0], [1379.0, 2063.0], [1409.0, 2125.0], [1425.0, 2141.0], [1444.0, 2188.0], [1459.0, 2197.0], [1549.0, 2188.0], [1579.0, 2180.0], [1592.0, 2165.0], [1601.0, 2136.0], [1595.0, 2110.0], [1579.0, 2086.0], [1556.0, 2073.0], [1529.0, 2070.0], [1485.0, 2071.0], [1444.0, 2053.0], [1423.0, 2036.0], [1453.0, 2042.0], [1483.0, 2056.0], [1511.0, 2058.0], [1529.0, 2055.0], [1560.0, 2061.0], [1597.0, 2085.0], [1636.0, 2120.0], [1653.0, 2115.0], [1681.0, 2098.0], [1693.0, 2072.0], [1696.0, 2048.0], [1696.0, 2016.0], [1713.0, 2003.0], [1702.0, 1986.0], [1705.0, 1966.0], [1675.0, 1931.0], [1659.0, 1920.0], [1626.0, 1915.0], [1571.0, 1885.0], [1554.0, 1857.0], [1573.0, 1877.0], [1627.0, 1907.0], [1665.0, 1912.0], [1686.0, 1925.0], [1711.0, 1953.0], [1739.0, 1963.0], [1731.0, 1945.0], [1707.0, 1909.0], [1661.0, 1846.0], [1631.0, 1839.0], [1575.0, 1842.0], [1602.0, 1839.0], [1618.0, 1827.0], [1642.0, 1824.0], [1671.0, 1834.0], [1703.0, 1862.0], [1755.0, 1893.0], [1774.0, 1896.0], [1798.0, 1891.0], [1823.0, 1878.0], [1843.0, 1862.0], [1857.0, 1814.0], [1835.0, 1738.0], [1871.0, 1804.0], [1890.0, 1811.0], [1903.0, 1824.0], [1911.0, 1841.0], [1937.0, 1864.0], [1974.0, 1888.0], [2001.0, 1894.0], [2026.0, 1906.0], [2047.0, 1921.0], [2062.0, 1952.0], [2076.0, 2007.0], [2092.0, 1995.0], [2091.0, 1910.0], [2081.0, 1879.0], [2085.0, 1853.0], [2127.0, 1818.0], [2134.0, 1801.0], [2127.0, 1787.0], [2102.0, 1764.0], [2099.0, 1746.0], [2103.0, 1728.0], [1649.0, 1232.0], [1587.0, 1151.0], [1616.0, 1180.0], [1651.0, 1226.0], [2110.0, 1719.0], [2122.0, 1720.0], [2153.0, 1755.0], [2175.0, 1772.0], [2202.0, 1775.0], [2214.0, 1750.0], [2218.0, 1728.0], [2205.0, 1715.0], [2186.0, 1704.0], [2179.0, 1689.0], [2186.0, 1674.0], [2203.0, 1672.0], [2236.0, 1679.0], [2247.0, 1666.0], [2251.0, 1648.0], [2231.0, 1615.0], [2226.0, 1573.0], [2218.0, 1565.0], [2200.0, 1574.0], [2178.0, 1572.0], [2166.0, 1561.0], [2165.0, 1549.0], [2179.0, 1538.0], [2198.0, 1513.0], [2192.0, 1477.0], [2166.0, 1483.0], [2141.0, 1480.0], [2121.0, 1442.0], [2116.0, 1427.0], [2100.0, 1420.0], [2077.0, 1420.0], [2059.0, 1409.0], [2061.0, 1378.0], [2079.0, 1319.0], [2095.0, 1305.0], [2117.0, 1303.0], [2135.0, 1314.0], [2147.0, 1330.0], [2171.0, 1325.0], [2183.0, 1312.0], [2183.0, 1290.0], [2163.0, 1260.0], [2143.0, 1238.0], [2107.0, 1221.0], [2101.0, 1206.0], [2104.0, 1191.0], [2122.0, 1176.0], [2143.0, 1160.0], [2151.0, 1143.0], [2147.0, 1126.0], [2135.0, 1114.0], [2115.0, 1111.0], [2095.0, 1101.0], [2084.0, 1086.0], [2087.0, 1067.0], [2111.0, 1023.0], [2109.0, 1010.0], [2095.0, 1002.0], [2081.0, 984.0], [2081.0, 962.0], [2095.0, 945.0], [2115.0, 937.0], [2137.0, 939.0], [2157.0, 929.0], [2161.0, 889.0], [2153.0, 869.0], [2127.0, 869.0], [2101.0, 859.0], [2091.0, 829.0], [2093.0, 801.0], [2121.0, 783.0], [2151.0, 783.0], [2167.0, 799.0], [2189.0, 791.0], [2197.0, 771.0], [2191.0, 741.0], [2199.0, 729.0], [2231.0, 713.0], [2237.0, 699.0], [2209.0, 681.0], [2191.0, 659.0], [2189.0, 635.0], [2207.0, 591.0], [2233.0, 553.0], [2231.0, 519.0], [2239.0, 495.0], [2255.0, 483.0], [2271.0, 443.0], [2299.0, 437.0], [2341.0, 439.0], [2371.0, 421.0], [2419.0, 399.0], [2443.0, 397.0], [2457.0, 405.0], [2483.0, 393.0], [2515.0, 371.0], [2545.0, 361.0], [2597.0, 359.0], [2631.0, 321.0], [2665.0, 317.0], [2705.0, 319.0], [2679.0, 327.0], [2678.0, 327.0], [2645.0, 327.0], [2636.0, 332.0], [2615.0, 361.0], [2591.0, 371.0], [2541.0, 373.0], [2507.0, 389.0], [2469.0, 415.0], [2453.0, 415.0], [2429.0, 405.0], [2401.0, 415.0], [2363.0, 445.0], [2337.0, 457.0], [2303.0, 453.0], [2283.0, 457.0], [2271.0, 477.0], [2247.0, 507.0], [2239.0, 531.0], [2245.0, 555.0], [2213.0, 605.0], [2201.0, 641.0], [2201.0, 655.0], [2247.0, 687.0], [2257.0, 707.0], [2217.0, 733.0], [2203.0, 747.0], [2209.0, 775.0], [2197.0, 801.0], [2171.0, 811.0], [2153.0, 805.0], [2141.0, 797.0], [2117.0, 801.0], [2103.0, 819.0], [2107.0, 839.0], [2129.0, 851.0], [2165.0, 857.0], [2177.0, 873.0], [2175.0, 897.0], [2167.0, 923.0], [2153.0, 949.0], [2107.0, 957.0], [2095.0, 969.0], [2097.0, 985.0], [2121.0, 1003.0], [2135.0, 1021.0], [2127.0, 1041.0], [2105.0, 1065.0], [2107.0, 1075.0], [2121.0, 1089.0], [2145.0, 1103.0], [2163.0, 1125.0], [2167.0, 1143.0], [2157.0, 1163.0], [2125.0, 1191.0], [2119.0, 1203.0], [2135.0, 1221.0], [2161.0, 1233.0], [2199.0, 1287.0], [2201.0, 1299.0], [2195.0, 1323.0], [2175.0, 1339.0], [2147.0, 1341.0], [2129.0, 1337.0], [2125.0, 1319.0], [2113.0, 1317.0], [2095.0, 1335.0], [2087.0, 1353.0], [2077.0, 1397.0], [2079.0, 1411.0], [2117.0, 1407.0], [2135.0, 1415.0], [2149.0, 1457.0], [2165.0, 1467.0], [2415.0, 1383.0], [2205.0, 1467.0], [2213.0, 1487.0], [2211.0, 1523.0], [2201.0, 1545.0], [2183.0, 1557.0], [2223.0, 1553.0], [2233.0, 1563.0], [2243.0, 1579.0], [2241.0, 1597.0], [2255.0, 1617.0], [2273.0, 1625.0], [2477.0, 1599.0], [2263.0, 1639.0], [2261.0, 1671.0], [2251.0, 1687.0], [2237.0, 1697.0], [2223.0, 1701.0], [2199.0, 1695.0], [2227.0, 1713.0], [2237.0, 1733.0], [2233.0, 1761.0], [2219.0, 1781.0], [2207.0, 1791.0], [2225.0, 1811.0], [2245.0, 1827.0], [2281.0, 1839.0], [2315.0, 1839.0], [2377.0, 1855.0], [2313.0, 1849.0], [2277.0, 1853.0], [2251.0, 1843.0], [2223.0, 1825.0], [2207.0, 1807.0], [2193.0, 1799.0], [2173.0, 1797.0], [2159.0, 1799.0], [2153.0, 1821.0], [2141.0, 1839.0], [2115.0, 1853.0], [2105.0, 1871.0], [2113.0, 2025.0], [2117.0, 2057.0], [2113.0, 2077.0], [2101.0, 2097.0], [2095.0, 2119.0], [2101.0, 2135.0], [2121.0, 2151.0], [2133.0, 2169.0], [2127.0, 2193.0], [2129.0, 2211.0], [2145.0, 2221.0], [2167.0, 2215.0], [2195.0, 2199.0], [2219.0, 2191.0], [2265.0, 2189.0], [2293.0, 2169.0], [2317.0, 2143.0], [2355.0, 2137.0], [2377.0, 2141.0], [2427.0, 1971.0], [2387.0, 2147.0], [2401.0, 2165.0], [2535.0, 2167.0], [2405.0, 2179.0], [2421.0, 2205.0], [2441.0, 2227.0], [2445.0, 2255.0], [2427.0, 2287.0], [2391.0, 2325.0], [2365.0, 2357.0], [2361.0, 2381.0], [2349.0, 2397.0], [2327.0, 2419.0], [2291.0, 2413.0], [2263.0, 2453.0], [2229.0, 2473.0], [2251.0, 2491.0], [2265.0, 2555.0], [2263.0, 2583.0], [2253.0, 2613.0], [2285.0, 2623.0], [2331.0, 2645.0], [2319.0, 2617.0], [2319.0, 2583.0], [2335.0, 2565.0], [2359.0, 2565.0], [2389.0, 2603.0], [2401.0, 2627.0], [2447.0, 2639.0], [2475.0, 2631.0], [2493.0, 2619.0], [2515.0, 2615.0], [2561.0, 2633.0], [2603.0, 2635.0], [2636.0, 2627.0], [2661.0, 2571.0], [2687.0, 2569.0], [2678.0, 2561.0], [2655.0, 2537.0], [2636.0, 2546.0], [2621.0, 2553.0], [2581.0, 2555.0], [2549.0, 2539.0], [2523.0, 2509.0], [2571.0, 2537.0], [2611.0, 2545.0], [2636.0, 2533.0], [2653.0, 2525.0], [2637.0, 2503.0], [2701.0, 2565.0], [2737.0, 2561.0], [2679.0, 2583.0], [2669.0, 2599.0], [2667.0, 2619.0], [2659.0, 2637.0], [2673.0, 2651.0], [2677.0, 2679.0], [2665.0, 2697.0], [2641.0, 2719.0], [2609.0, 2733.0], [2601.0, 2759.0], [2599.0, 2787.0], [2633.0, 2785.0], [2671.0, 2787.0], [2701.0, 2725.0], [2725.0, 2715.0], [2779.0, 2717.0], [2725.0, 2723.0], [2705.0, 2739.0], [2689.0, 2771.0], [2683.0, 2791.0], [2693.0, 2793.0], [2737.0, 2831.0], [2687.0, 2797.0], [2678.0, 2795.0], [2667.0, 2793.0], [2599.0, 2797.0], [2589.0, 2819.0], [2575.0, 2839.0], [2581.0, 2881.0], [2607.0, 2901.0], [2611.0, 2927.0], [2623.0, 2951.0], [2636.0, 2968.0], [2647.0, 2997.0], [2649.0, 3019.0], [2671.0, 3031.0], [2687.0, 3029.0], [2733.0, 3053.0], [2747.0, 3067.0], [2753.0, 3089.0], [2741.0, 3117.0], [2709.0, 3139.0], [2757.0, 3151.0], [2791.0, 3129.0], [2837.0, 3093.0], [2865.0, 3063.0], [2877.0, 3041.0], [2871.0, 3019.0], [2823.0, 2963.0], [2885.0, 3017.0], [2909.0, 2993.0], [2951.0, 2975.0], [2909.0, 3007.0], [2887.0, 3027.0], [2885.0, 3053.0], [2867.0, 3081.0], [2833.0, 3109.0], [2805.0, 3139.0], [2817.0, 3153.0], [2973.0, 3125.0], [2983.0, 3135.0], [2995.0, 3157.0], [2971.0, 3135.0], [2939.0, 3135.0], [2809.0, 3165.0], [2795.0, 3177.0], [2801.0, 3191.0], [2795.0, 3207.0], [2783.0, 3221.0], [2785.0, 3239.0], [2801.0, 3251.0], [2823.0, 3253.0], [2847.0, 3239.0], [2879.0, 3245.0], [2901.0, 3257.0], [2921.0, 3273.0], [2931.0, 3303.0], [2947.0, 3277.0], [2967.0, 3273.0], [3013.0, 3297.0], [3027.0, 3315.0], [3179.0, 3419.0], [3227.0, 3457.0], [3257.0, 3455.0], [3275.0, 3441.0], [3265.0, 3417.0], [3283.0, 3351.0], [3277.0, 3327.0], [3293.0, 3293.0], [3119.0, 3119.0], [3297.0, 3283.0], [3305.0, 3303.0], [3295.0, 3323.0], [3309.0, 3333.0], [3327.0, 3321.0], [3331.0, 3293.0], [3383.0, 3241.0], [3387.0, 3211.0], [3351.0, 3187.0], [3321.0, 3153.0], [3289.0, 3091.0], [3279.0, 3051.0], [3263.0, 3047.0], [3217.0, 3077.0], [3179.0, 3083.0], [3147.0, 3067.0], [3199.0, 3071.0], [3235.0, 3055.0], [3261.0, 3039.0], [3217.0, 2997.0], [3115.0, 2953.0], [3169.0, 2955.0], [3219.0, 2977.0], [3261.0, 3019.0], [3289.0, 3039.0], [3303.0, 3081.0], [3313.0, 3121.0], [3351.0, 3171.0], [3395.0, 3195.0], [3415.0, 3153.0], [3433.0, 3131.0], [3435.0, 3055.0], [3403.0, 3049.0], [3373.0, 3033.0], [3303.0, 2931.0], [3281.0, 2915.0], [3213.0, 2909.0], [3287.0, 2907.0], [3311.0, 2925.0], [3381.0, 3027.0], [3417.0, 3043.0], [3443.0, 3045.0], [3449.0, 3061.0], [3443.0, 3123.0], [3475.0, 3115.0], [3493.0, 3091.0], [3485.0, 3113.0], [3521.0, 3107.0], [3545.0, 3085.0], [3577.0, 2981.0], [3567.0, 2961.0], [3519.0, 2943.0], [3515.0, 2933.0], [3491.0, 2937.0], [3447.0, 2913.0], [3363.0, 2899.0], [3449.0, 2901.0], [3497.0, 2923.0], [3527.0, 2921.0], [3533.0, 2895.0], [3557.0, 2881.0], [3585.0, 2875.0], [3635.0, 2839.0], [3615.0, 2817.0], [3589.0, 2797.0], [3603.0, 2777.0], [3609.0, 2743.0], [3591.0, 2723.0], [3587.0, 2709.0], [3453.0, 2701.0], [3435.0, 2695.0], [3411.0, 2719.0], [3179.0, 2655.0], [3409.0, 2707.0], [3439.0, 2685.0], [3467.0, 2691.0], [3585.0, 2695.0], [3607.0, 2639.0], [3597.0, 2605.0], [3609.0, 2567.0], [3601.0, 2557.0], [3539.0, 2551.0], [3531.0, 2531.0], [3551.0, 2515.0], [3593.0, 2505.0], [3629.0, 2463.0], [3617.0, 2419.0], [3605.0, 2389.0], [3583.0, 2365.0], [3581.0, 2341.0], [3553.0, 2347.0], [3477.0, 2343.0], [3465.0, 2315.0], [3479.0, 2289.0], [3473.0, 2265.0], [3453.0, 2283.0], [3431.0, 2289.0], [3407.0, 2285.0], [3381.0, 2267.0], [3377.0, 2245.0], [3405.0, 2213.0], [3397.0, 2195.0], [3361.0, 2197.0], [3337.0, 2209.0], [3313.0, 2213.0], [3305.0, 2235.0], [3319.0, 2261.0], [3321.0, 2287.0], [3305.0, 2335.0], [3311.0, 2287.0], [3305.0, 2259.0], [3289.0, 2235.0], [3301.0, 2215.0], [3321.0, 2199.0], [3347.0, 2195.0], [3371.0, 2181.0], [3395.0, 2187.0], [3407.0, 2139.0], [3391.0, 2121.0], [3379.0, 2083.0], [3375.0, 2019.0], [3367.0, 1959.0], [3373.0, 1913.0], [3371.0, 1893.0], [3313.0, 1855.0], [3299.0, 1829.0], [3305.0, 1797.0], [3305.0, 1781.0], [3289.0, 1769.0], [3243.0, 1759.0], [3209.0, 1737.0], [3183.0, 1699.0], [3155.0, 1687.0], [3141.0, 1673.0], [3151.0, 1627.0], [3151.0, 1603.0], [3189.0, 1555.0], [3201.0, 1533.0], [3181.0, 1483.0], [3189.0, 1465.0], [3225.0, 1463.0], [3239.0, 1441.0], [3269.0, 1439.0], [3287.0, 1421.0], [3307.0, 1411.0], [3329.0, 1417.0], [3349.0, 1403.0], [3355.0, 1373.0], [3385.0, 1341.0], [3389.0, 1313.0], [3377.0, 1269.0], [3387.0, 1253.0], [3399.0, 1211.0], [3409.0, 1197.0], [3403.0, 1183.0], [3407.0, 1169.0], [3439.0, 1145.0], [3445.0, 1101.0], [3439.0, 1063.0], [3455.0, 1047.0], [3459.0, 1025.0], [3471.0, 1007.0], [3483.0, 981.0], [3505.0, 961.0], [3569.0, 935.0], [3639.0, 895.0], [3569.0, 945.0], [3509.0, 971.0], [3489.0, 985.0], [3479.0, 1007.0], [3463.0, 1031.0], [3465.0, 1049.0], [3449.0, 1065.0], [3453.0, 1101.0], [3449.0, 1143.0], [3431.0, 1165.0], [3411.0, 1179.0], [3423.0, 1197.0], [3411.0, 1211.0], [3389.0, 1273.0], [3395.0, 1317.0], [3393.0, 1339.0], [3373.0, 1371.0], [3363.0, 1379.0], [3359.0, 1405.0], [3337.0, 1427.0], [3305.0, 1425.0], [3277.0, 1441.0], [3259.0, 1459.0], [3243.0, 1455.0], [3227.0, 1475.0], [3197.0, 1481.0], [3209.0, 1511.0], [3213.0, 1541.0], [3173.0, 1595.0], [3161.0, 1623.0], [3157.0, 1657.0], [3165.0, 1671.0], [3197.0, 1689.0], [3221.0, 1729.0], [3263.0, 1751.0], [3291.0, 1751.0], [3319.0, 1767.0], [3325.0, 1795.0], [3319.0, 1817.0], [3331.0, 1847.0], [3363.0, 1865.0], [3383.0, 1883.0], [3395.0, 1909.0], [3387.0, 1985.0], [3397.0, 2027.0], [3399.0, 2071.0], [3415.0, 2097.0], [3423.0, 2117.0], [3423.0, 2141.0], [3415.0, 2185.0], [3429.0, 2211.0], [3473.0, 2235.0], [3497.0, 2257.0], [3501.0, 2287.0], [3489.0, 2309.0], [3495.0, 2325.0], [3531.0, 2331.0], [3565.0, 2321.0], [3593.0, 2327.0], [3605.0, 2353.0], [3603.0, 2367.0], [3631.0, 2387.0], [3729.0, 2143.0], [3771.0, 2119.0], [3735.0, 2149.0], [3635.0, 2407.0], [3649.0, 2459.0], [3737.0, 2519.0], [3733.0, 2477.0], [3747.0, 2447.0], [3811.0, 2361.0], [3749.0, 2461.0], [3739.0, 2487.0], [3745.0, 2531.0], [3741.0, 2557.0], [3729.0, 2583.0], [3699.0, 2625.0], [3695.0, 2649.0], [3707.0, 2671.0], [3753.0, 2731.0], [3791.0, 2773.0], [3805.0, 2811.0], [3823.0, 2847.0], [3855.0, 2803.0], [3853.0, 2771.0], [3837.0, 2759.0], [3829.0, 2737.0], [3797.0, 2695.0], [3785.0, 2689.0], [3801.0, 2653.0], [3831.0, 2651.0], [3835.0, 2623.0], [3851.0, 2621.0], [3857.0, 2593.0], [3867.0, 2569.0], [3865.0, 2547.0], [3879.0, 2529.0], [3873.0, 2553.0], [3875.0, 2577.0], [3861.0, 2625.0], [3845.0, 2633.0], [3841.0, 2659.0], [3807.0, 2661.0], [3799.0, 2681.0], [3843.0, 2737.0], [3847.0, 2753.0], [3865.0, 2765.0], [3865.0, 2807.0], [3837.0, 2847.0], [3845.0, 2865.0], [3869.0, 2899.0], [3889.0, 2945.0], [3911.0, 3021.0], [3951.0, 3069.0], [3959.0, 3097.0], [3955.0, 3129.0], [3975.0, 3143.0], [4017.0, 3147.0], [4051.0, 3169.0], [4075.0, 3188.0], [4087.0, 3197.0], [4117.0, 3198.0], [4145.0, 3199.0], [4177.0, 3223.0], [4215.0, 3245.0], [4213.0, 3279.0], [4199.0, 3331.0], [4224.0, 3350.0], [4224.0, 3661.0], [4117.0, 3663.0], [4081.0, 3664.0], [3963.0, 3670.0], [3937.0, 3690.0], [3941.0, 3736.0], [3949.0, 3798.0], [3965.0, 3816.0], [3987.0, 3852.0], [4045.0, 3858.0], [4075.0, 3832.0], [4089.0, 3820.0], [4155.0, 3788.0], [4215.0, 3782.0], [4224.0, 3785.0], [4224.0, 4062.0], [4195.0, 4066.0], [4179.0, 4088.0], [4189.0, 4112.0], [4224.0, 4129.0], [4224.0, 4145.0], [4177.0, 4122.0], [4101.0, 4200.0], [4082.0, 4224.0], [4224.0, 4224.0], [4224.0, 4224.0], [-128.0, 4224.0], [-128.0, -128.0]], [[4117.0, 4162.0], [4161.0, 4115.0], [4165.0, 4091.0], [4177.0, 4067.0], [4203.0, 4053.0], [4224.0, 4047.0], [4224.0, 3801.0], [4181.0, 3801.0], [4157.0, 3801.0], [4127.0, 3819.0], [4081.0, 3857.0], [4041.0, 3877.0], [3997.0, 3875.0], [3969.0, 3851.0], [3941.0, 3831.0], [3933.0, 3769.0], [3911.0, 3717.0], [3925.0, 3665.0], [3929.0, 3639.0], [3879.0, 3619.0], [3849.0, 3581.0], [3823.0, 3601.0], [3815.0, 3629.0], [3829.0, 3669.0], [3861.0, 3699.0], [3883.0, 3737.0], [3883.0, 3775.0], [3881.0, 3827.0], [3867.0, 3869.0], [3839.0, 3907.0], [3787.0, 3959.0], [3783.0, 3989.0], [3801.0, 4005.0], [3797.0, 4027.0], [3799.0, 4061.0], [3813.0, 4093.0], [3805.0, 4141.0], [3795.0, 4191.0], [3789.0, 4221.0], [3801.0, 4224.0], [3907.0, 4224.0], [4033.0, 4217.0], [4049.0, 4224.0], [4059.0, 4224.0], [4117.0, 4162.0], [4117.0, 4162.0]], [[3857.0, 2915.0], [3833.0, 2925.0], [3817.0, 2921.0], [3807.0, 2913.0], [3773.0, 2913.0], [3761.0, 2927.0], [3765.0, 2957.0], [3757.0, 2979.0], [3773.0, 2991.0], [3791.0, 2995.0], [3789.0, 3013.0], [3817.0, 3017.0], [3835.0, 3027.0], [3839.0, 3039.0], [3827.0, 3047.0], [3817.0, 3055.0], [3811.0, 3077.0], [3789.0, 3085.0], [3765.0, 3079.0], [3739.0, 3093.0], [3711.0, 3107.0], [3705.0, 3115.0], [3707.0, 3135.0], [3689.0, 3161.0], [3705.0, 3167.0], [3695.0, 3197.0], [3705.0, 3205.0], [3723.0, 3211.0], [3723.0, 3231.0], [3751.0, 3229.0], [3749.0, 3217.0], [3767.0, 3203.0], [3799.0, 3167.0], [3793.0, 3131.0], [3809.0, 3117.0], [3869.0, 3085.0], [3911.0, 3057.0], [3891.0, 3035.0], [3887.0, 3001.0], [3871.0, 2959.0], [3857.0, 2915.0], [3857.0, 2915.0]], [[3653.0, 2641.0], [3665.0, 2621.0], [3681.0, 2615.0], [3693.0, 2613.0], [3731.0, 2567.0], [3737.0, 2541.0], [3727.0, 2521.0], [3649.0, 2473.0], [3625.0, 2503.0], [3599.0, 2519.0], [3573.0, 2525.0], [3555.0, 2531.0], [3555.0, 2543.0], [3603.0, 2541.0], [3631.0, 2553.0], [3625.0, 2579.0], [3615.0, 2607.0], [3625.0, 2643.0], [3653.0, 2641.0], [3653.0, 2641.0]], [[3413.0, 2227.0], [3395.0, 2243.0], [3393.0, 2261.0], [3413.0, 2273.0], [3433.0, 2277.0], [3473.0, 2259.0], [3413.0, 2227.0], [3413.0, 2227.0]], [[1991.0, 2570.0], [2223.0, 2614.0], [2239.0, 2600.0], [2247.0, 2570.0], [2237.0, 2542.0], [2235.0, 2516.0], [2223.0, 2504.0], [2207.0, 2490.0], [2213.0, 2464.0], [2225.0, 2446.0], [2249.0, 2440.0], [2255.0, 2424.0], [2263.0, 2406.0], [2283.0, 2394.0], [2307.0, 2388.0], [2333.0, 2394.0], [2343.0, 2386.0], [2343.0, 2364.0], [2351.0, 2340.0], [2373.0, 2318.0], [2403.0, 2298.0], [2417.0, 2272.0], [2421.0, 2256.0], [2411.0, 2234.0], [2397.0, 2200.0], [2379.0, 2172.0], [2349.0, 2158.0], [2333.0, 2156.0], [2315.0, 2166.0], [2303.0, 2192.0], [2279.0, 2208.0], [2241.0, 2208.0], [2221.0, 2212.0], [2197.0, 2218.0], [2181.0, 2228.0], [2159.0, 2246.0], [2139.0, 2256.0], [2127.0, 2260.0], [2111.0, 2266.0], [2127.0, 2332.0], [2251.0, 2288.0], [2121.0, 2348.0], [2099.0, 2358.0], [2081.0, 2378.0], [2055.0, 2388.0], [2015.0, 2410.0], [1973.0, 2430.0], [1951.0, 2442.0], [1945.0, 2456.0], [1947.0, 2476.0], [1963.0, 2484.0], [2095.0, 2432.0], [1969.0, 2494.0], [1965.0, 2510.0], [1971.0, 2524.0], [1983.0, 2538.0], [1989.0, 2552.0], [1991.0, 2570.0], [1991.0, 2570.0]], [[850.0, 2552.0], [858.0, 2452.0], [790.0, 2436.0], [778.0, 2380.0], [806.0, 2416.0], [870.0, 2428.0], [910.0, 2276.0], [870.0, 2256.0], [838.0, 2204.0], [762.0, 2212.0], [706.0, 2164.0], [686.0, 2128.0], [634.0, 2016.0], [582.0, 1996.0], [510.0, 1976.0], [422.0, 1964.0], [370.0, 1928.0], [314.0, 1884.0], [270.0, 1884.0], [218.0, 1908.0], [130.0, 1924.0], [134.0, 1988.0], [106.0, 2028.0], [86.0, 2064.0], [114.0, 2108.0], [114.0, 2152.0], [-22.0, 2292.0], [-102.0, 2272.0], [-128.0, 2252.0], [-128.0, 2319.0], [-106.0, 2328.0], [-102.0, 2364.0], [-10.0, 2400.0], [98.0, 2400.0], [234.0, 2408.0], [338.0, 2432.0], [450.0, 2448.0], [606.0, 2488.0], [734.0, 2536.0], [850.0, 2552.0], [850.0, 2552.0]], [[-128.0, 1781.0], [-118.0, 1780.0], [-54.0, 1808.0], [-2.0, 1820.0], [30.0, 1860.0], [58.0, 1860.0], [74.0, 1708.0], [46.0, 1692.0], [18.0, 1620.0], [-54.0, 1608.0], [-114.0, 1608.0], [-128.0, 1615.0], [-128.0, 1781.0]]];
-testOutput["water2"]["triangles"]=[1187, 1188, 1189, 1190, 1191, 1192, 1195, 1196, 1197, 1199, 1200, 1201, 1201, 1202, 1203, 1204, 1205, 1206, 1206, 1207, 1208, 3, 4, 5, 7, 8, 9, 9, 10, 11, 14, 15, 16, 16, 17, 18, 20, 21, 22, 23, 24, 25, 25, 26, 1222, 1222, 1223, 1224, 1224, 1225, 1226, 1228, 1229, 1230, 1232, 1233, 29, 29, 30, 31, 31, 32, 33, 34, 35, 36, 42, 43, 44, 48, 49, 50, 50, 51, 52, 56, 57, 58, 62, 63, 64, 68, 69, 70, 73, 74, 75, 75, 76, 77, 79, 80, 81, 83, 84, 85, 87, 88, 89, 89, 90, 91, 91, 92, 93, 94, 95, 96, 98, 99, 100, 103, 104, 105, 105, 106, 107, 107, 108, 109, 114, 115, 116, 116, 117, 118, 119, 120, 121, 125, 126, 127, 129, 130, 131, 131, 132, 133, 136, 137, 138, 139, 140, 141, 141, 142, 143, 146, 147, 148, 153, 154, 155, 155, 156, 157, 158, 159, 160, 160, 161, 162, 162, 163, 164, 165, 166, 167, 168, 169, 170, 181, 182, 183, 183, 184, 185, 185, 186, 1170, 1172, 1173, 1174, 1176, 1177, 1178, 1125, 1126, 1127, 1128, 1129, 1130, 1130, 1131, 1132, 1132, 1133, 1134, 1134, 1135, 1136, 1138, 1139, 1140, 1140, 1141, 1142, 1144, 1145, 1146, 1150, 1151, 1152, 1156, 1157, 1158, 1161, 1162, 1163, 1164, 1165, 1166, 1166, 1167, 1168, 1169, 1170, 186, 186, 187, 188, 190, 191, 192, 192, 193, 194, 194, 195, 196, 197, 198, 199, 200, 201, 202, 202, 203, 204, 204, 205, 206, 207, 208, 209, 209, 210, 211, 216, 217, 218, 218, 219, 220, 221, 222, 223, 224, 225, 226, 235, 236, 237, 237, 238, 239, 241, 242, 243, 243, 244, 245, 249, 250, 251, 251, 252, 253, 255, 256, 257, 257, 258, 259, 260, 261, 262, 268, 269, 270, 270, 271, 272, 272, 273, 274, 280, 281, 282, 282, 283, 284, 287, 288, 289, 289, 290, 291, 294, 295, 296, 299, 300, 301, 303, 304, 305, 307, 308, 309, 314, 315, 316, 316, 317, 318, 321, 322, 323, 324, 325, 326, 326, 327, 328, 331, 332, 333, 333, 334, 335, 336, 337, 338, 338, 339, 340, 340, 341, 342, 342, 343, 344, 349, 350, 351, 351, 352, 353, 357, 358, 359, 359, 360, 361, 363, 364, 365, 365, 366, 367, 367, 368, 369, 372, 373, 374, 374, 375, 376, 376, 377, 378, 381, 382, 383, 385, 386, 387, 387, 388, 389, 390, 391, 392, 393, 394, 395, 396, 397, 398, 398, 399, 400, 401, 402, 403, 405, 406, 407, 407, 408, 410, 411, 412, 413, 415, 416, 417, 419, 420, 421, 423, 424, 425, 430, 431, 432, 434, 435, 436, 436, 437, 438, 443, 444, 445, 445, 446, 447, 451, 452, 453, 453, 454, 455, 457, 458, 459, 459, 460, 461, 464, 465, 466, 466, 467, 468, 468, 469, 470, 470, 471, 472, 477, 478, 479, 481, 482, 483, 483, 484, 485, 485, 486, 487, 487, 488, 489, 489, 490, 491, 493, 494, 495, 495, 496, 497, 497, 498, 499, 500, 501, 502, 502, 503, 504, 508, 509, 510, 511, 512, 513, 513, 514, 515, 518, 519, 520, 523, 524, 525, 528, 529, 530, 534, 535, 536, 538, 539, 540, 541, 542, 543, 544, 545, 546, 547, 548, 549, 549, 550, 551, 552, 553, 554, 554, 555, 556, 556, 557, 558, 558, 559, 560, 560, 561, 562, 562, 563, 564, 564, 565, 566, 566, 567, 568, 568, 569, 570, 572, 573, 574, 577, 578, 579, 579, 580, 581, 581, 582, 583, 583, 584, 585, 585, 586, 587, 588, 589, 590, 590, 591, 592, 592, 593, 594, 595, 596, 597, 597, 598, 599, 599, 600, 601, 604, 605, 606, 607, 608, 609, 613, 614, 615, 619, 620, 621, 622, 623, 624, 625, 626, 627, 629, 630, 631, 631, 632, 633, 633, 634, 635, 641, 642, 643, 643, 644, 645, 647, 648, 649, 653, 654, 655, 659, 660, 661, 665, 666, 667, 667, 668, 669, 670, 671, 672, 672, 673, 674, 674, 675, 676, 678, 679, 680, 680, 681, 682, 682, 683, 684, 684, 685, 686, 688, 689, 690, 691, 692, 693, 693, 694, 695, 696, 697, 698, 698, 699, 700, 703, 704, 705, 705, 706, 707, 707, 708, 709, 712, 1075, 1076, 1078, 1079, 1080, 1081, 1082, 1083, 1084, 1085, 1086, 1088, 1089, 1090, 1053, 1054, 1055, 1057, 1058, 1059, 1060, 1061, 1062, 1062, 1063, 1064, 1064, 1065, 1066, 1067, 1068, 1069, 1070, 1071, 1072, 1073, 1074, 1075, 715, 716, 717, 719, 720, 721, 721, 722, 723, 724, 725, 726, 728, 729, 730, 734, 735, 736, 736, 737, 738, 738, 739, 740, 742, 743, 744, 747, 748, 749, 750, 751, 752, 752, 753, 754, 755, 756, 757, 761, 762, 763, 765, 766, 1105, 1106, 1107, 1108, 1108, 1109, 1110, 1095, 1096, 1097, 1101, 1102, 1103, 1104, 1105, 766, 766, 767, 768, 771, 772, 773, 773, 774, 775, 775, 776, 777, 778, 779, 780, 780, 781, 782, 782, 783, 1116, 1115, 1116, 783, 786, 787, 788, 789, 790, 791, 791, 792, 793, 794, 795, 796, 796, 797, 798, 798, 799, 800, 801, 802, 803, 804, 805, 806, 807, 808, 809, 812, 813, 814, 815, 816, 817, 818, 819, 820, 821, 822, 823, 824, 825, 826, 826, 827, 828, 830, 831, 832, 833, 834, 835, 835, 836, 837, 837, 838, 839, 843, 844, 845, 845, 846, 847, 848, 849, 850, 852, 853, 854, 854, 855, 856, 856, 857, 858, 858, 859, 860, 860, 861, 862, 863, 864, 865, 865, 866, 867, 867, 868, 869, 871, 872, 873, 873, 874, 875, 875, 876, 877, 881, 882, 883, 884, 885, 886, 886, 887, 888, 889, 890, 891, 891, 892, 893, 893, 894, 895, 895, 896, 897, 897, 898, 899, 900, 901, 902, 902, 903, 904, 906, 907, 908, 908, 909, 910, 912, 913, 914, 918, 919, 920, 922, 923, 924, 924, 925, 926, 929, 930, 931, 934, 935, 936, 937, 938, 939, 940, 941, 942, 942, 943, 944, 944, 945, 946, 947, 948, 949, 953, 954, 955, 959, 960, 961, 962, 963, 964, 966, 967, 968, 970, 971, 972, 973, 974, 975, 976, 977, 978, 984, 985, 986, 987, 988, 989, 989, 990, 991, 996, 997, 998, 1004, 1210, 1211, 1212, 1037, 1038, 1040, 1041, 1042, 1046, 1047, 1048, 1007, 1008, 1009, 1009, 1010, 1011, 1015, 1016, 1017, 1017, 1018, 1019, 1019, 1020, 1021, 1021, 1022, 1023, 1024, 1025, 1026, 1029, 1030, 1031, 1031, 1032, 1033, 1033, 1034, 1035, 1213, 1214, 1215, 1216, 1217, 1218, 1183, 1184, 1185, 1194, 1195, 1197, 1201, 1203, 1204, 1206, 1208, 1209, 3, 5, 6, 7, 9, 11, 16, 18, 19, 20, 22, 23, 23, 25, 1222, 1222, 1224, 1226, 1231, 1232, 29, 29, 31, 33, 33, 34, 36, 41, 42, 44, 48, 50, 52, 55, 56, 58, 61, 62, 64, 72, 73, 75, 78, 79, 81, 82, 83, 85, 86, 87, 89, 93, 94, 96, 97, 98, 100, 107, 109, 110, 114, 116, 118, 125, 127, 128, 129, 131, 133, 136, 138, 139, 139, 141, 143, 145, 146, 148, 155, 157, 158, 158, 160, 162, 164, 165, 167, 167, 168, 170, 180, 181, 183, 183, 185, 1170, 1171, 1172, 1174, 1127, 1128, 1130, 1132, 1134, 1136, 1138, 1140, 1142, 1150, 1152, 1153, 1156, 1158, 1159, 1160, 1161, 1163, 1164, 1166, 1168, 1168, 1169, 186, 190, 192, 194, 206, 207, 209, 209, 211, 212, 216, 218, 220, 220, 221, 223, 234, 235, 237, 237, 239, 240, 248, 249, 251, 257, 259, 260, 268, 270, 272, 279, 280, 282, 282, 284, 285, 287, 289, 291, 299, 301, 302, 303, 305, 306, 316, 318, 319, 326, 328, 329, 351, 353, 354, 357, 359, 361, 387, 389, 390, 405, 407, 410, 445, 447, 448, 492, 493, 495, 497, 499, 500, 502, 504, 505, 508, 510, 511, 518, 520, 521, 522, 523, 525, 528, 530, 531, 534, 536, 537, 537, 538, 540, 540, 541, 543, 543, 544, 546, 546, 547, 549, 549, 551, 552, 564, 566, 568, 572, 574, 575, 583, 585, 587, 597, 599, 601, 607, 609, 610, 613, 615, 616, 622, 624, 625, 625, 627, 628, 629, 631, 633, 640, 641, 643, 643, 645, 646, 653, 655, 656, 659, 661, 662, 665, 667, 669, 670, 672, 674, 678, 680, 682, 682, 684, 686, 696, 698, 700, 703, 705, 707, 712, 1076, 1077, 1077, 1078, 1080, 1081, 1083, 1084, 1084, 1086, 1087, 1088, 1090, 1091, 1059, 1060, 1062, 1062, 1064, 1066, 1067, 1069, 1070, 718, 719, 721, 723, 724, 726, 727, 728, 730, 736, 738, 740, 742, 744, 745, 746, 747, 749, 754, 755, 757, 765, 1105, 1106, 1101, 1103, 1104, 1104, 766, 768, 780, 782, 1116, 1115, 783, 784, 789, 791, 793, 793, 794, 796, 801, 803, 804, 818, 820, 821, 847, 848, 850, 870, 871, 873, 895, 897, 899, 911, 912, 914, 918, 920, 921, 928, 929, 931, 944, 946, 947, 959, 961, 962, 962, 964, 965, 966, 968, 969, 970, 972, 973, 973, 975, 976, 976, 978, 979, 996, 998, 999, 1004, 1211, 1212, 1212, 1038, 1039, 1040, 1042, 1043, 1021, 1023, 1024, 1029, 1031, 1033, 1033, 1035, 1036, 1036, 1037, 1212, 1213, 1215, 1216, 1216, 1218, 1219, 1182, 1183, 1185, 7, 11, 12, 23, 1222, 1226, 1230, 1231, 29, 41, 44, 45, 47, 48, 52, 55, 58, 59, 61, 64, 65, 72, 75, 77, 78, 81, 82, 82, 85, 86, 86, 89, 91, 93, 96, 97, 97, 100, 101, 105, 107, 110, 113, 114, 118, 124, 125, 128, 135, 136, 139, 139, 143, 144, 155, 158, 162, 167, 170, 171, 180, 183, 1170, 1171, 1174, 1175, 1150, 1153, 1154, 1155, 1156, 1159, 1160, 1163, 1164, 1164, 1168, 186, 189, 190, 194, 204, 206, 209, 220, 223, 224, 234, 237, 240, 255, 257, 260, 267, 268, 272, 279, 282, 285, 286, 287, 291, 298, 299, 302, 302, 303, 306, 405, 410, 411, 491, 492, 495, 508, 511, 513, 522, 525, 526, 528, 531, 532, 533, 534, 537, 540, 543, 546, 564, 568, 570, 571, 572, 575, 583, 587, 588, 597, 601, 602, 607, 610, 611, 613, 616, 617, 622, 625, 628, 628, 629, 633, 643, 646, 647, 653, 656, 657, 664, 665, 669, 670, 674, 676, 678, 682, 686, 696, 700, 701, 702, 703, 707, 712, 1077, 1080, 1081, 1084, 1087, 1062, 1066, 1067, 717, 718, 721, 727, 730, 731, 736, 740, 741, 742, 745, 746, 746, 749, 750, 754, 757, 758, 764, 765, 1106, 1101, 1104, 768, 780, 1116, 1117, 789, 793, 796, 801, 804, 806, 847, 850, 851, 911, 914, 915, 918, 921, 922, 928, 931, 932, 942, 944, 947, 958, 959, 962, 966, 969, 970, 970, 973, 976, 976, 979, 980, 995, 996, 999, 1004, 1212, 1039, 1039, 1040, 1043, 1028, 1029, 1033, 1036, 1212, 1213, 1213, 1216, 1219, 1182, 1185, 1186, 20, 23, 1226, 1230, 29, 33, 41, 45, 46, 55, 59, 60, 71, 72, 77, 77, 78, 82, 82, 86, 91, 93, 97, 101, 105, 110, 111, 113, 118, 119, 134, 135, 139, 139, 144, 145, 179, 180, 1170, 1171, 1175, 1176, 1149, 1150, 1154, 1155, 1159, 1160, 1160, 1164, 186, 189, 194, 196, 204, 209, 212, 216, 220, 224, 234, 240, 241, 255, 260, 262, 267, 272, 274, 278, 279, 285, 286, 291, 292, 302, 306, 307, 404, 405, 411, 507, 508, 513, 522, 526, 527, 527, 528, 532, 540, 546, 549, 564, 570, 571, 571, 575, 576, 581, 583, 588, 595, 597, 602, 606, 607, 611, 612, 613, 617, 628, 633, 635, 640, 643, 647, 652, 653, 657, 664, 669, 670, 678, 686, 687, 696, 701, 702, 1081, 1087, 1088, 1059, 1062, 1067, 717, 721, 723, 734, 736, 741, 754, 758, 759, 764, 1106, 1108, 1101, 768, 769, 780, 1117, 1118, 788, 789, 796, 801, 806, 807, 847, 851, 852, 910, 911, 915, 917, 918, 922, 942, 947, 949, 958, 962, 965, 966, 970, 976, 976, 980, 981, 1004, 1039, 1043, 1028, 1033, 1036, 1181, 1182, 1186, 20, 1226, 1227, 1230, 33, 36, 40, 41, 46, 70, 71, 77, 93, 101, 102, 105, 111, 112, 113, 119, 121, 134, 139, 145, 178, 179, 1170, 1155, 1160, 186, 189, 196, 197, 204, 212, 213, 216, 224, 226, 234, 241, 243, 254, 255, 262, 266, 267, 274, 277, 278, 285, 285, 286, 292, 298, 302, 307, 404, 411, 413, 506, 507, 513, 564, 571, 576, 581, 588, 590, 606, 611, 612, 612, 617, 618, 639, 640, 647, 652, 657, 658, 664, 670, 676, 678, 687, 688, 695, 696, 702, 1059, 1067, 1070, 715, 717, 723, 734, 741, 742, 752, 754, 759, 763, 764, 1108, 1101, 769, 770, 778, 780, 1118, 786, 788, 796, 845, 847, 852, 910, 915, 916, 917, 922, 924, 942, 949, 950, 965, 966, 976, 976, 981, 982, 1004, 1043, 1044, 1181, 1186, 1187, 19, 20, 1227, 1228, 1230, 36, 40, 46, 47, 70, 77, 82, 93, 102, 103, 103, 105, 112, 112, 113, 121, 133, 134, 145, 177, 178, 1170, 1155, 186, 188, 189, 197, 199, 202, 204, 213, 233, 234, 243, 253, 254, 262, 265, 266, 274, 277, 285, 292, 298, 307, 309, 403, 404, 413, 505, 506, 513, 579, 581, 590, 606, 612, 618, 639, 647, 649, 651, 652, 658, 678, 688, 690, 695, 702, 707, 1059, 1070, 1072, 715, 723, 726, 734, 742, 746, 752, 759, 760, 763, 1108, 1110, 1101, 770, 771, 778, 1118, 1119, 786, 796, 798, 845, 852, 854, 940, 942, 950, 965, 976, 982, 1004, 1044, 1045, 19, 1227, 1228, 1228, 36, 37, 68, 70, 82, 91, 93, 103, 103, 112, 121, 129, 133, 145, 176, 177, 1170, 1154, 1155, 188, 202, 213, 214, 233, 243, 245, 253, 262, 263, 264, 265, 274, 276, 277, 292, 297, 298, 309, 403, 413, 414, 505, 513, 515, 579, 590, 592, 604, 606, 618, 638, 639, 649, 651, 658, 659, 695, 707, 709, 1057, 1059, 1072, 714, 715, 726, 733, 734, 746, 750, 752, 760, 761, 763, 1110, 1101, 771, 773, 777, 778, 1119, 785, 786, 798, 843, 845, 854, 940, 950, 951, 1228, 37, 38, 68, 82, 91, 128, 129, 145, 175, 176, 1170, 1154, 188, 189, 202, 214, 215, 232, 233, 245, 251, 253, 263, 264, 274, 275, 275, 276, 292, 297, 309, 310, 401, 403, 414, 505, 515, 516, 579, 592, 594, 604, 618, 619, 637, 638, 649, 651, 659, 662, 695, 709, 710, 1057, 1072, 1073, 714, 726, 727, 732, 733, 746, 750, 760, 761, 761, 1110, 1111, 785, 798, 800, 843, 854, 856, 939, 940, 951, 19, 1228, 38, 67, 68, 91, 174, 175, 1170, 200, 202, 215, 231, 232, 245, 251, 263, 264, 264, 275, 292, 400, 401, 414, 502, 505, 516, 577, 579, 594, 604, 619, 621, 636, 637, 649, 693, 695, 710, 1057, 1073, 1075, 713, 714, 727, 746, 750, 761, 784, 785, 800, 842, 843, 856, 939, 951, 952, 19, 38, 39, 66, 67, 91, 173, 174, 1170, 200, 215, 216, 230, 231, 245, 248, 251, 264, 400, 414, 415, 577, 594, 595, 603, 604, 621, 636, 649, 650, 693, 710, 711, 713, 727, 731, 746, 761, 1111, 784, 800, 801, 841, 842, 856, 939, 952, 953, 19, 39, 40, 65, 66, 91, 173, 1170, 1171, 199, 200, 216, 229, 230, 245, 248, 264, 292, 400, 415, 417, 577, 595, 602, 602, 603, 621, 636, 650, 651, 691, 693, 711, 841, 856, 858, 937, 939, 953, 16, 19, 40, 65, 91, 103, 173, 1171, 1176, 189, 199, 216, 229, 245, 246, 247, 248, 292, 398, 400, 417, 576, 577, 602, 636, 651, 662, 691, 711, 712, 1075, 712, 713, 840, 841, 858, 936, 937, 953, 61, 65, 103, 173, 1176, 1178, 189, 216, 226, 229, 246, 247, 398, 417, 418, 564, 576, 602, 635, 636, 662, 690, 691, 712, 1075, 713, 731, 839, 840, 858, 934, 936, 953, 60, 61, 103, 189, 226, 227, 229, 247, 292, 398, 418, 419, 564, 602, 621, 635, 662, 663, 690, 712, 1080, 1057, 1075, 731, 839, 858, 860, 934, 953, 955, 55, 60, 103, 189, 227, 228, 396, 398, 419, 562, 564, 621, 678, 690, 1080, 1056, 1057, 731, 837, 839, 860, 934, 955, 956, 55, 103, 121, 189, 228, 229, 396, 419, 421, 562, 621, 622, 677, 678, 1080, 1056, 731, 732, 837, 860, 862, 933, 934, 956, 55, 121, 122, 189, 229, 292, 395, 396, 421, 562, 622, 628, 677, 1080, 1081, 1056, 732, 746, 835, 837, 862, 933, 956, 957, 54, 55, 122, 395, 421, 422, 562, 628, 635, 1055, 1056, 746, 835, 862, 863, 932, 933, 957, 54, 122, 123, 395, 422, 423, 562, 635, 663, 1055, 746, 1111, 833, 835, 863, 932, 957, 958, 54, 123, 124, 393, 395, 423, 1053, 1055, 1111, 833, 863, 865, 53, 54, 124, 393, 423, 425, 832, 833, 865, 52, 53, 124, 392, 393, 425, 832, 865, 867, 392, 425, 426, 830, 832, 867, 390, 392, 426, 830, 867, 869, 390, 426, 427, 829, 830, 869, 390, 427, 428, 828, 829, 869, 387, 390, 428, 828, 869, 870, 387, 428, 429, 826, 828, 870, 387, 429, 430, 824, 826, 870, 385, 387, 430, 824, 870, 873, 385, 430, 432, 824, 873, 875, 384, 385, 432, 823, 824, 875, 383, 384, 432, 821, 823, 875, 383, 432, 433, 821, 875, 877, 383, 433, 434, 821, 877, 878, 381, 383, 434, 818, 821, 878, 381, 434, 436, 818, 878, 879, 380, 381, 436, 817, 818, 879, 379, 380, 436, 817, 879, 880, 379, 436, 438, 817, 880, 881, 378, 379, 438, 815, 817, 881, 378, 438, 439, 814, 815, 881, 376, 378, 439, 814, 881, 883, 376, 439, 440, 812, 814, 883, 376, 440, 441, 812, 883, 884, 374, 376, 441, 812, 884, 886, 374, 441, 442, 811, 812, 886, 372, 374, 442, 810, 811, 886, 372, 442, 443, 809, 810, 886, 372, 443, 445, 809, 886, 888, 371, 372, 445, 809, 888, 889, 370, 371, 445, 807, 809, 889, 370, 445, 448, 807, 889, 891, 369, 370, 448, 807, 891, 893, 367, 369, 448, 801, 807, 893, 367, 448, 449, 801, 893, 895, 367, 449, 450, 801, 895, 899, 365, 367, 450, 784, 801, 899, 365, 450, 451, 1115, 784, 899, 363, 365, 451, 1115, 899, 900, 363, 451, 453, 362, 363, 453, 361, 362, 453, 361, 453, 455, 361, 455, 456, 357, 361, 456, 356, 357, 456, 356, 456, 457, 356, 457, 459, 355, 356, 459, 354, 355, 459, 354, 459, 461, 351, 354, 461, 351, 461, 462, 351, 462, 463, 349, 351, 463, 348, 349, 463, 348, 463, 464, 348, 464, 466, 347, 348, 466, 346, 347, 466, 346, 466, 468, 345, 346, 468, 344, 345, 468, 344, 468, 470, 342, 344, 470, 342, 470, 472, 342, 472, 473, 340, 342, 473, 340, 473, 474, 338, 340, 474, 338, 474, 475, 338, 475, 476, 338, 476, 477, 336, 338, 477, 336, 477, 479, 335, 336, 479, 333, 335, 479, 333, 479, 480, 333, 480, 481, 331, 333, 481, 331, 481, 483, 330, 331, 483, 330, 483, 485, 329, 330, 485, 329, 485, 487, 326, 329, 487, 324, 326, 487, 324, 487, 489, 323, 324, 489, 321, 323, 489, 321, 489, 491, 321, 491, 495, 320, 321, 495, 320, 495, 497, 319, 320, 497, 316, 319, 497, 316, 497, 500, 314, 316, 500, 313, 314, 500, 313, 500, 502, 312, 313, 502, 311, 312, 502, 311, 502, 516, 310, 311, 516, 310, 516, 517, 310, 517, 518, 297, 310, 518, 296, 297, 518, 296, 518, 521, 296, 521, 522, 294, 296, 522, 293, 294, 522, 293, 522, 527, 292, 293, 527, 189, 292, 527, 189, 527, 532, 189, 532, 533, 1154, 189, 533, 1154, 533, 537, 1149, 1154, 537, 1149, 537, 540, 1148, 1149, 540, 1147, 1148, 540, 1147, 540, 549, 1146, 1147, 549, 1144, 1146, 549, 1143, 1144, 549, 1142, 1143, 549, 1142, 549, 552, 1138, 1142, 552, 1138, 552, 554, 1137, 1138, 554, 1137, 554, 556, 1136, 1137, 556, 1132, 1136, 556, 1130, 1132, 556, 1130, 556, 558, 1127, 1130, 558, 1125, 1127, 558, 1125, 558, 560, 1124, 1125, 560, 1124, 560, 562, 1123, 1124, 562, 1123, 562, 663, 173, 1178, 1122, 1122, 1123, 663, 1088, 1091, 1052, 1052, 1053, 1111, 1094, 1095, 1097, 1114, 1115, 900, 1000, 1001, 1049, 1049, 1006, 1007, 1209, 7, 12, 173, 1122, 663, 1052, 1111, 1094, 1119, 1114, 900, 999, 1000, 1049, 1209, 12, 13, 172, 173, 663, 1119, 900, 902, 999, 1049, 1007, 1206, 1209, 13, 171, 172, 663, 777, 1119, 902, 995, 999, 1007, 1206, 13, 14, 171, 663, 664, 775, 777, 902, 995, 1007, 1009, 1206, 14, 16, 171, 664, 676, 775, 902, 904, 994, 995, 1009, 1204, 1206, 16, 775, 904, 905, 993, 994, 1009, 1201, 1204, 16, 773, 775, 905, 993, 1009, 1011, 1201, 16, 40, 773, 905, 906, 1199, 1201, 40, 773, 906, 908, 1199, 40, 47, 773, 908, 910, 1198, 1199, 47, 1101, 773, 910, 1198, 47, 52, 1101, 910, 916, 1197, 1198, 52, 1100, 1101, 916, 1197, 52, 124, 1100, 916, 917, 1197, 124, 128, 1099, 1100, 917, 1194, 1197, 128, 1099, 917, 924, 1194, 128, 145, 1098, 1099, 924, 1193, 1194, 145, 1097, 1098, 924, 1192, 1193, 145, 1097, 924, 926, 1192, 145, 148, 1094, 1097, 926, 1192, 148, 149, 1094, 926, 927, 1190, 1192, 149, 1094, 927, 928, 1190, 149, 150, 1052, 1094, 928, 1189, 1190, 150, 1052, 928, 932, 1189, 150, 151, 1052, 932, 958, 1189, 151, 152, 1088, 1052, 958, 1189, 152, 153, 1088, 958, 965, 1187, 1189, 153, 1081, 1088, 965, 1187, 153, 155, 1181, 1187, 155, 1181, 155, 162, 1181, 162, 164, 1219, 1181, 164, 1213, 1219, 164, 1213, 164, 167, 1036, 1213, 167, 1036, 167, 171, 1036, 171, 676, 1028, 1036, 676, 1027, 1028, 676, 1026, 1027, 676, 1026, 676, 677, 1026, 677, 1081, 1024, 1026, 1081, 1024, 1081, 965, 1021, 1024, 965, 1021, 965, 982, 1021, 982, 983, 1021, 983, 984, 1019, 1021, 984, 1019, 984, 986, 1017, 1019, 986, 1017, 986, 987, 1015, 1017, 987, 1014, 1015, 987, 1014, 987, 989, 1014, 989, 991, 1012, 1014, 991, 1012, 991, 992];
+testOutput["water2"]["triangles"]=[1212, 1037, 1038, 1040, 1041, 1042, 1046, 1047, 1048, 1007, 1008, 1009, 1009, 1010, 1011, 1015, 1016, 1017, 1017, 1018, 1019, 1019, 1020, 1021, 1021, 1022, 1023, 1024, 1025, 1026, 1029, 1030, 1031, 1031, 1032, 1033, 1033, 1034, 1035, 1213, 1214, 1215, 1216, 1217, 1218, 1183, 1184, 1185, 1187, 1188, 1189, 1190, 1191, 1192, 1195, 1196, 1197, 1199, 1200, 1201, 1201, 1202, 1203, 1204, 1205, 1206, 1206, 1207, 1208, 3, 4, 5, 7, 8, 9, 9, 10, 11, 14, 15, 16, 16, 17, 18, 20, 21, 22, 23, 24, 25, 25, 26, 1222, 1222, 1223, 1224, 1224, 1225, 1226, 1228, 1229, 1230, 1232, 1233, 29, 29, 30, 31, 31, 32, 33, 34, 35, 36, 42, 43, 44, 48, 49, 50, 50, 51, 52, 56, 57, 58, 62, 63, 64, 68, 69, 70, 73, 74, 75, 75, 76, 77, 79, 80, 81, 83, 84, 85, 87, 88, 89, 89, 90, 91, 91, 92, 93, 94, 95, 96, 98, 99, 100, 103, 104, 105, 105, 106, 107, 107, 108, 109, 114, 115, 116, 116, 117, 118, 119, 120, 121, 125, 126, 127, 129, 130, 131, 131, 132, 133, 136, 137, 138, 139, 140, 141, 141, 142, 143, 146, 147, 148, 153, 154, 155, 155, 156, 157, 158, 159, 160, 160, 161, 162, 162, 163, 164, 165, 166, 167, 168, 169, 170, 181, 182, 183, 183, 184, 185, 185, 186, 1170, 1172, 1173, 1174, 1176, 1177, 1178, 1125, 1126, 1127, 1128, 1129, 1130, 1130, 1131, 1132, 1132, 1133, 1134, 1134, 1135, 1136, 1138, 1139, 1140, 1140, 1141, 1142, 1144, 1145, 1146, 1150, 1151, 1152, 1156, 1157, 1158, 1161, 1162, 1163, 1164, 1165, 1166, 1166, 1167, 1168, 1169, 1170, 186, 186, 187, 188, 190, 191, 192, 192, 193, 194, 194, 195, 196, 197, 198, 199, 200, 201, 202, 202, 203, 204, 204, 205, 206, 207, 208, 209, 209, 210, 211, 216, 217, 218, 218, 219, 220, 221, 222, 223, 224, 225, 226, 235, 236, 237, 237, 238, 239, 241, 242, 243, 243, 244, 245, 249, 250, 251, 251, 252, 253, 255, 256, 257, 257, 258, 259, 260, 261, 262, 268, 269, 270, 270, 271, 272, 272, 273, 274, 280, 281, 282, 282, 283, 284, 287, 288, 289, 289, 290, 291, 294, 295, 296, 299, 300, 301, 303, 304, 305, 307, 308, 309, 314, 315, 316, 316, 317, 318, 321, 322, 323, 324, 325, 326, 326, 327, 328, 331, 332, 333, 333, 334, 335, 336, 337, 338, 338, 339, 340, 340, 341, 342, 342, 343, 344, 349, 350, 351, 351, 352, 353, 357, 358, 359, 359, 360, 361, 363, 364, 365, 365, 366, 367, 367, 368, 369, 372, 373, 374, 374, 375, 376, 376, 377, 378, 381, 382, 383, 385, 386, 387, 387, 388, 389, 390, 391, 392, 393, 394, 395, 396, 397, 398, 398, 399, 400, 401, 402, 403, 405, 406, 407, 407, 408, 410, 411, 412, 413, 415, 416, 417, 419, 420, 421, 423, 424, 425, 430, 431, 432, 434, 435, 436, 436, 437, 438, 443, 444, 445, 445, 446, 447, 451, 452, 453, 453, 454, 455, 457, 458, 459, 459, 460, 461, 464, 465, 466, 466, 467, 468, 468, 469, 470, 470, 471, 472, 477, 478, 479, 481, 482, 483, 483, 484, 485, 485, 486, 487, 487, 488, 489, 489, 490, 491, 493, 494, 495, 495, 496, 497, 497, 498, 499, 500, 501, 502, 502, 503, 504, 508, 509, 510, 511, 512, 513, 513, 514, 515, 518, 519, 520, 523, 524, 525, 528, 529, 530, 534, 535, 536, 538, 539, 540, 541, 542, 543, 544, 545, 546, 547, 548, 549, 549, 550, 551, 552, 553, 554, 554, 555, 556, 556, 557, 558, 558, 559, 560, 560, 561, 562, 562, 563, 564, 564, 565, 566, 566, 567, 568, 568, 569, 570, 572, 573, 574, 577, 578, 579, 579, 580, 581, 581, 582, 583, 583, 584, 585, 585, 586, 587, 588, 589, 590, 590, 591, 592, 592, 593, 594, 595, 596, 597, 597, 598, 599, 599, 600, 601, 604, 605, 606, 607, 608, 609, 613, 614, 615, 619, 620, 621, 622, 623, 624, 625, 626, 627, 629, 630, 631, 631, 632, 633, 633, 634, 635, 641, 642, 643, 643, 644, 645, 647, 648, 649, 653, 654, 655, 659, 660, 661, 665, 666, 667, 667, 668, 669, 670, 671, 672, 672, 673, 674, 674, 675, 676, 678, 679, 680, 680, 681, 682, 682, 683, 684, 684, 685, 686, 688, 689, 690, 691, 692, 693, 693, 694, 695, 696, 697, 698, 698, 699, 700, 703, 704, 705, 705, 706, 707, 707, 708, 709, 712, 1075, 1076, 1078, 1079, 1080, 1081, 1082, 1083, 1084, 1085, 1086, 1088, 1089, 1090, 1053, 1054, 1055, 1057, 1058, 1059, 1060, 1061, 1062, 1062, 1063, 1064, 1064, 1065, 1066, 1067, 1068, 1069, 1070, 1071, 1072, 1073, 1074, 1075, 715, 716, 717, 719, 720, 721, 721, 722, 723, 724, 725, 726, 728, 729, 730, 734, 735, 736, 736, 737, 738, 738, 739, 740, 742, 743, 744, 747, 748, 749, 750, 751, 752, 752, 753, 754, 755, 756, 757, 761, 762, 763, 765, 766, 1105, 1106, 1107, 1108, 1108, 1109, 1110, 1095, 1096, 1097, 1101, 1102, 1103, 1104, 1105, 766, 766, 767, 768, 771, 772, 773, 773, 774, 775, 775, 776, 777, 778, 779, 780, 780, 781, 782, 782, 783, 1116, 1115, 1116, 783, 786, 787, 788, 789, 790, 791, 791, 792, 793, 794, 795, 796, 796, 797, 798, 798, 799, 800, 801, 802, 803, 804, 805, 806, 807, 808, 809, 812, 813, 814, 815, 816, 817, 818, 819, 820, 821, 822, 823, 824, 825, 826, 826, 827, 828, 830, 831, 832, 833, 834, 835, 835, 836, 837, 837, 838, 839, 843, 844, 845, 845, 846, 847, 848, 849, 850, 852, 853, 854, 854, 855, 856, 856, 857, 858, 858, 859, 860, 860, 861, 862, 863, 864, 865, 865, 866, 867, 867, 868, 869, 871, 872, 873, 873, 874, 875, 875, 876, 877, 881, 882, 883, 884, 885, 886, 886, 887, 888, 889, 890, 891, 891, 892, 893, 893, 894, 895, 895, 896, 897, 897, 898, 899, 900, 901, 902, 902, 903, 904, 906, 907, 908, 908, 909, 910, 912, 913, 914, 918, 919, 920, 922, 923, 924, 924, 925, 926, 929, 930, 931, 934, 935, 936, 937, 938, 939, 940, 941, 942, 942, 943, 944, 944, 945, 946, 947, 948, 949, 953, 954, 955, 959, 960, 961, 962, 963, 964, 966, 967, 968, 970, 971, 972, 973, 974, 975, 976, 977, 978, 984, 985, 986, 987, 988, 989, 989, 990, 991, 996, 997, 998, 1004, 1210, 1211, 1212, 1038, 1039, 1040, 1042, 1043, 1021, 1023, 1024, 1029, 1031, 1033, 1033, 1035, 1036, 1213, 1215, 1216, 1216, 1218, 1219, 1182, 1183, 1185, 1194, 1195, 1197, 1201, 1203, 1204, 1206, 1208, 1209, 3, 5, 6, 7, 9, 11, 16, 18, 19, 20, 22, 23, 23, 25, 1222, 1222, 1224, 1226, 1231, 1232, 29, 29, 31, 33, 33, 34, 36, 41, 42, 44, 48, 50, 52, 55, 56, 58, 61, 62, 64, 72, 73, 75, 78, 79, 81, 82, 83, 85, 86, 87, 89, 93, 94, 96, 97, 98, 100, 107, 109, 110, 114, 116, 118, 125, 127, 128, 129, 131, 133, 136, 138, 139, 139, 141, 143, 145, 146, 148, 155, 157, 158, 158, 160, 162, 164, 165, 167, 167, 168, 170, 180, 181, 183, 183, 185, 1170, 1171, 1172, 1174, 1127, 1128, 1130, 1132, 1134, 1136, 1138, 1140, 1142, 1150, 1152, 1153, 1156, 1158, 1159, 1160, 1161, 1163, 1164, 1166, 1168, 1168, 1169, 186, 190, 192, 194, 206, 207, 209, 209, 211, 212, 216, 218, 220, 220, 221, 223, 234, 235, 237, 237, 239, 240, 248, 249, 251, 257, 259, 260, 268, 270, 272, 279, 280, 282, 282, 284, 285, 287, 289, 291, 299, 301, 302, 303, 305, 306, 316, 318, 319, 326, 328, 329, 351, 353, 354, 357, 359, 361, 387, 389, 390, 405, 407, 410, 445, 447, 448, 492, 493, 495, 497, 499, 500, 502, 504, 505, 508, 510, 511, 518, 520, 521, 522, 523, 525, 528, 530, 531, 534, 536, 537, 537, 538, 540, 540, 541, 543, 543, 544, 546, 546, 547, 549, 549, 551, 552, 564, 566, 568, 572, 574, 575, 583, 585, 587, 597, 599, 601, 607, 609, 610, 613, 615, 616, 622, 624, 625, 625, 627, 628, 629, 631, 633, 640, 641, 643, 643, 645, 646, 653, 655, 656, 659, 661, 662, 665, 667, 669, 670, 672, 674, 678, 680, 682, 682, 684, 686, 696, 698, 700, 703, 705, 707, 712, 1076, 1077, 1077, 1078, 1080, 1081, 1083, 1084, 1084, 1086, 1087, 1088, 1090, 1091, 1059, 1060, 1062, 1062, 1064, 1066, 1067, 1069, 1070, 718, 719, 721, 723, 724, 726, 727, 728, 730, 736, 738, 740, 742, 744, 745, 746, 747, 749, 754, 755, 757, 765, 1105, 1106, 1101, 1103, 1104, 1104, 766, 768, 780, 782, 1116, 1115, 783, 784, 789, 791, 793, 793, 794, 796, 801, 803, 804, 818, 820, 821, 847, 848, 850, 870, 871, 873, 895, 897, 899, 911, 912, 914, 918, 920, 921, 928, 929, 931, 944, 946, 947, 959, 961, 962, 962, 964, 965, 966, 968, 969, 970, 972, 973, 973, 975, 976, 976, 978, 979, 996, 998, 999, 1004, 1211, 1212, 1212, 1039, 1040, 1040, 1043, 1044, 1028, 1029, 1033, 1036, 1037, 1212, 1213, 1216, 1219, 1182, 1185, 1186, 7, 11, 12, 23, 1222, 1226, 1230, 1231, 29, 41, 44, 45, 47, 48, 52, 55, 58, 59, 61, 64, 65, 72, 75, 77, 78, 81, 82, 82, 85, 86, 86, 89, 91, 93, 96, 97, 97, 100, 101, 105, 107, 110, 113, 114, 118, 124, 125, 128, 135, 136, 139, 139, 143, 144, 155, 158, 162, 167, 170, 171, 180, 183, 1170, 1171, 1174, 1175, 1150, 1153, 1154, 1155, 1156, 1159, 1160, 1163, 1164, 1164, 1168, 186, 189, 190, 194, 204, 206, 209, 220, 223, 224, 234, 237, 240, 255, 257, 260, 267, 268, 272, 279, 282, 285, 286, 287, 291, 298, 299, 302, 302, 303, 306, 405, 410, 411, 491, 492, 495, 508, 511, 513, 522, 525, 526, 528, 531, 532, 533, 534, 537, 540, 543, 546, 564, 568, 570, 571, 572, 575, 583, 587, 588, 597, 601, 602, 607, 610, 611, 613, 616, 617, 622, 625, 628, 628, 629, 633, 643, 646, 647, 653, 656, 657, 664, 665, 669, 670, 674, 676, 678, 682, 686, 696, 700, 701, 702, 703, 707, 712, 1077, 1080, 1081, 1084, 1087, 1062, 1066, 1067, 717, 718, 721, 727, 730, 731, 736, 740, 741, 742, 745, 746, 746, 749, 750, 754, 757, 758, 764, 765, 1106, 1101, 1104, 768, 780, 1116, 1117, 789, 793, 796, 801, 804, 806, 847, 850, 851, 911, 914, 915, 918, 921, 922, 928, 931, 932, 942, 944, 947, 958, 959, 962, 966, 969, 970, 970, 973, 976, 976, 979, 980, 995, 996, 999, 1004, 1212, 1040, 1028, 1033, 1036, 1036, 1212, 1213, 1181, 1182, 1186, 20, 23, 1226, 1230, 29, 33, 41, 45, 46, 55, 59, 60, 71, 72, 77, 77, 78, 82, 82, 86, 91, 93, 97, 101, 105, 110, 111, 113, 118, 119, 134, 135, 139, 139, 144, 145, 179, 180, 1170, 1171, 1175, 1176, 1149, 1150, 1154, 1155, 1159, 1160, 1160, 1164, 186, 189, 194, 196, 204, 209, 212, 216, 220, 224, 234, 240, 241, 255, 260, 262, 267, 272, 274, 278, 279, 285, 286, 291, 292, 302, 306, 307, 404, 405, 411, 507, 508, 513, 522, 526, 527, 527, 528, 532, 540, 546, 549, 564, 570, 571, 571, 575, 576, 581, 583, 588, 595, 597, 602, 606, 607, 611, 612, 613, 617, 628, 633, 635, 640, 643, 647, 652, 653, 657, 664, 669, 670, 678, 686, 687, 696, 701, 702, 1081, 1087, 1088, 1059, 1062, 1067, 717, 721, 723, 734, 736, 741, 754, 758, 759, 764, 1106, 1108, 1101, 768, 769, 780, 1117, 1118, 788, 789, 796, 801, 806, 807, 847, 851, 852, 910, 911, 915, 917, 918, 922, 942, 947, 949, 958, 962, 965, 966, 970, 976, 976, 980, 981, 1004, 1040, 1044, 1181, 1186, 1187, 20, 1226, 1227, 1230, 33, 36, 40, 41, 46, 70, 71, 77, 93, 101, 102, 105, 111, 112, 113, 119, 121, 134, 139, 145, 178, 179, 1170, 1155, 1160, 186, 189, 196, 197, 204, 212, 213, 216, 224, 226, 234, 241, 243, 254, 255, 262, 266, 267, 274, 277, 278, 285, 285, 286, 292, 298, 302, 307, 404, 411, 413, 506, 507, 513, 564, 571, 576, 581, 588, 590, 606, 611, 612, 612, 617, 618, 639, 640, 647, 652, 657, 658, 664, 670, 676, 678, 687, 688, 695, 696, 702, 1059, 1067, 1070, 715, 717, 723, 734, 741, 742, 752, 754, 759, 763, 764, 1108, 1101, 769, 770, 778, 780, 1118, 786, 788, 796, 845, 847, 852, 910, 915, 916, 917, 922, 924, 942, 949, 950, 965, 966, 976, 976, 981, 982, 1004, 1044, 1045, 19, 20, 1227, 1228, 1230, 36, 40, 46, 47, 70, 77, 82, 93, 102, 103, 103, 105, 112, 112, 113, 121, 133, 134, 145, 177, 178, 1170, 1155, 186, 188, 189, 197, 199, 202, 204, 213, 233, 234, 243, 253, 254, 262, 265, 266, 274, 277, 285, 292, 298, 307, 309, 403, 404, 413, 505, 506, 513, 579, 581, 590, 606, 612, 618, 639, 647, 649, 651, 652, 658, 678, 688, 690, 695, 702, 707, 1059, 1070, 1072, 715, 723, 726, 734, 742, 746, 752, 759, 760, 763, 1108, 1110, 1101, 770, 771, 778, 1118, 1119, 786, 796, 798, 845, 852, 854, 940, 942, 950, 965, 976, 982, 19, 1227, 1228, 1228, 36, 37, 68, 70, 82, 91, 93, 103, 103, 112, 121, 129, 133, 145, 176, 177, 1170, 1154, 1155, 188, 202, 213, 214, 233, 243, 245, 253, 262, 263, 264, 265, 274, 276, 277, 292, 297, 298, 309, 403, 413, 414, 505, 513, 515, 579, 590, 592, 604, 606, 618, 638, 639, 649, 651, 658, 659, 695, 707, 709, 1057, 1059, 1072, 714, 715, 726, 733, 734, 746, 750, 752, 760, 761, 763, 1110, 1101, 771, 773, 777, 778, 1119, 785, 786, 798, 843, 845, 854, 940, 950, 951, 1228, 37, 38, 68, 82, 91, 128, 129, 145, 175, 176, 1170, 1154, 188, 189, 202, 214, 215, 232, 233, 245, 251, 253, 263, 264, 274, 275, 275, 276, 292, 297, 309, 310, 401, 403, 414, 505, 515, 516, 579, 592, 594, 604, 618, 619, 637, 638, 649, 651, 659, 662, 695, 709, 710, 1057, 1072, 1073, 714, 726, 727, 732, 733, 746, 750, 760, 761, 761, 1110, 1111, 785, 798, 800, 843, 854, 856, 939, 940, 951, 19, 1228, 38, 67, 68, 91, 174, 175, 1170, 200, 202, 215, 231, 232, 245, 251, 263, 264, 264, 275, 292, 400, 401, 414, 502, 505, 516, 577, 579, 594, 604, 619, 621, 636, 637, 649, 693, 695, 710, 1057, 1073, 1075, 713, 714, 727, 746, 750, 761, 784, 785, 800, 842, 843, 856, 939, 951, 952, 19, 38, 39, 66, 67, 91, 173, 174, 1170, 200, 215, 216, 230, 231, 245, 248, 251, 264, 400, 414, 415, 577, 594, 595, 603, 604, 621, 636, 649, 650, 693, 710, 711, 713, 727, 731, 746, 761, 1111, 784, 800, 801, 841, 842, 856, 939, 952, 953, 19, 39, 40, 65, 66, 91, 173, 1170, 1171, 199, 200, 216, 229, 230, 245, 248, 264, 292, 400, 415, 417, 577, 595, 602, 602, 603, 621, 636, 650, 651, 691, 693, 711, 841, 856, 858, 937, 939, 953, 16, 19, 40, 65, 91, 103, 173, 1171, 1176, 189, 199, 216, 229, 245, 246, 247, 248, 292, 398, 400, 417, 576, 577, 602, 636, 651, 662, 691, 711, 712, 1075, 712, 713, 840, 841, 858, 936, 937, 953, 61, 65, 103, 173, 1176, 1178, 189, 216, 226, 229, 246, 247, 398, 417, 418, 564, 576, 602, 635, 636, 662, 690, 691, 712, 1075, 713, 731, 839, 840, 858, 934, 936, 953, 60, 61, 103, 189, 226, 227, 229, 247, 292, 398, 418, 419, 564, 602, 621, 635, 662, 663, 690, 712, 1080, 1057, 1075, 731, 839, 858, 860, 934, 953, 955, 55, 60, 103, 189, 227, 228, 396, 398, 419, 562, 564, 621, 678, 690, 1080, 1056, 1057, 731, 837, 839, 860, 934, 955, 956, 55, 103, 121, 189, 228, 229, 396, 419, 421, 562, 621, 622, 677, 678, 1080, 1056, 731, 732, 837, 860, 862, 933, 934, 956, 55, 121, 122, 189, 229, 292, 395, 396, 421, 562, 622, 628, 677, 1080, 1081, 1056, 732, 746, 835, 837, 862, 933, 956, 957, 54, 55, 122, 395, 421, 422, 562, 628, 635, 1055, 1056, 746, 835, 862, 863, 932, 933, 957, 54, 122, 123, 395, 422, 423, 562, 635, 663, 1055, 746, 1111, 833, 835, 863, 932, 957, 958, 54, 123, 124, 393, 395, 423, 1053, 1055, 1111, 833, 863, 865, 53, 54, 124, 393, 423, 425, 832, 833, 865, 52, 53, 124, 392, 393, 425, 832, 865, 867, 392, 425, 426, 830, 832, 867, 390, 392, 426, 830, 867, 869, 390, 426, 427, 829, 830, 869, 390, 427, 428, 828, 829, 869, 387, 390, 428, 828, 869, 870, 387, 428, 429, 826, 828, 870, 387, 429, 430, 824, 826, 870, 385, 387, 430, 824, 870, 873, 385, 430, 432, 824, 873, 875, 384, 385, 432, 823, 824, 875, 383, 384, 432, 821, 823, 875, 383, 432, 433, 821, 875, 877, 383, 433, 434, 821, 877, 878, 381, 383, 434, 818, 821, 878, 381, 434, 436, 818, 878, 879, 380, 381, 436, 817, 818, 879, 379, 380, 436, 817, 879, 880, 379, 436, 438, 817, 880, 881, 378, 379, 438, 815, 817, 881, 378, 438, 439, 814, 815, 881, 376, 378, 439, 814, 881, 883, 376, 439, 440, 812, 814, 883, 376, 440, 441, 812, 883, 884, 374, 376, 441, 812, 884, 886, 374, 441, 442, 811, 812, 886, 372, 374, 442, 810, 811, 886, 372, 442, 443, 809, 810, 886, 372, 443, 445, 809, 886, 888, 371, 372, 445, 809, 888, 889, 370, 371, 445, 807, 809, 889, 370, 445, 448, 807, 889, 891, 369, 370, 448, 807, 891, 893, 367, 369, 448, 801, 807, 893, 367, 448, 449, 801, 893, 895, 367, 449, 450, 801, 895, 899, 365, 367, 450, 784, 801, 899, 365, 450, 451, 1115, 784, 899, 363, 365, 451, 1115, 899, 900, 363, 451, 453, 362, 363, 453, 361, 362, 453, 361, 453, 455, 361, 455, 456, 357, 361, 456, 356, 357, 456, 356, 456, 457, 356, 457, 459, 355, 356, 459, 354, 355, 459, 354, 459, 461, 351, 354, 461, 351, 461, 462, 351, 462, 463, 349, 351, 463, 348, 349, 463, 348, 463, 464, 348, 464, 466, 347, 348, 466, 346, 347, 466, 346, 466, 468, 345, 346, 468, 344, 345, 468, 344, 468, 470, 342, 344, 470, 342, 470, 472, 342, 472, 473, 340, 342, 473, 340, 473, 474, 338, 340, 474, 338, 474, 475, 338, 475, 476, 338, 476, 477, 336, 338, 477, 336, 477, 479, 335, 336, 479, 333, 335, 479, 333, 479, 480, 333, 480, 481, 331, 333, 481, 331, 481, 483, 330, 331, 483, 330, 483, 485, 329, 330, 485, 329, 485, 487, 326, 329, 487, 324, 326, 487, 324, 487, 489, 323, 324, 489, 321, 323, 489, 321, 489, 491, 321, 491, 495, 320, 321, 495, 320, 495, 497, 319, 320, 497, 316, 319, 497, 316, 497, 500, 314, 316, 500, 313, 314, 500, 313, 500, 502, 312, 313, 502, 311, 312, 502, 311, 502, 516, 310, 311, 516, 310, 516, 517, 310, 517, 518, 297, 310, 518, 296, 297, 518, 296, 518, 521, 296, 521, 522, 294, 296, 522, 293, 294, 522, 293, 522, 527, 292, 293, 527, 189, 292, 527, 189, 527, 532, 189, 532, 533, 1154, 189, 533, 1154, 533, 537, 1149, 1154, 537, 1149, 537, 540, 1148, 1149, 540, 1147, 1148, 540, 1147, 540, 549, 1146, 1147, 549, 1144, 1146, 549, 1143, 1144, 549, 1142, 1143, 549, 1142, 549, 552, 1138, 1142, 552, 1138, 552, 554, 1137, 1138, 554, 1137, 554, 556, 1136, 1137, 556, 1132, 1136, 556, 1130, 1132, 556, 1130, 556, 558, 1127, 1130, 558, 1125, 1127, 558, 1125, 558, 560, 1124, 1125, 560, 1124, 560, 562, 1123, 1124, 562, 1123, 562, 663, 173, 1178, 1122, 1122, 1123, 663, 1088, 1091, 1052, 1052, 1053, 1111, 1094, 1095, 1097, 1114, 1115, 900, 1000, 1001, 1049, 1049, 1006, 1007, 1209, 7, 12, 173, 1122, 663, 1052, 1111, 1094, 1119, 1114, 900, 999, 1000, 1049, 1209, 12, 13, 172, 173, 663, 1119, 900, 902, 999, 1049, 1007, 1206, 1209, 13, 171, 172, 663, 777, 1119, 902, 995, 999, 1007, 1206, 13, 14, 171, 663, 664, 775, 777, 902, 995, 1007, 1009, 1206, 14, 16, 171, 664, 676, 775, 902, 904, 994, 995, 1009, 1204, 1206, 16, 775, 904, 905, 993, 994, 1009, 1201, 1204, 16, 773, 775, 905, 993, 1009, 1011, 1201, 16, 40, 773, 905, 906, 1199, 1201, 40, 773, 906, 908, 1199, 40, 47, 773, 908, 910, 1198, 1199, 47, 1101, 773, 910, 1198, 47, 52, 1101, 910, 916, 1197, 1198, 52, 1100, 1101, 916, 1197, 52, 124, 1100, 916, 917, 1197, 124, 128, 1099, 1100, 917, 1194, 1197, 128, 1099, 917, 924, 1194, 128, 145, 1098, 1099, 924, 1193, 1194, 145, 1097, 1098, 924, 1192, 1193, 145, 1097, 924, 926, 1192, 145, 148, 1094, 1097, 926, 1192, 148, 149, 1094, 926, 927, 1190, 1192, 149, 1094, 927, 928, 1190, 149, 150, 1052, 1094, 928, 1189, 1190, 150, 1052, 928, 932, 1189, 150, 151, 1052, 932, 958, 1189, 151, 152, 1088, 1052, 958, 1189, 152, 153, 1088, 958, 965, 1187, 1189, 153, 1081, 1088, 965, 1187, 153, 155, 1181, 1187, 155, 1181, 155, 162, 1181, 162, 164, 1219, 1181, 164, 1213, 1219, 164, 1213, 164, 167, 1036, 1213, 167, 1036, 167, 171, 1036, 171, 676, 1028, 1036, 676, 1027, 1028, 676, 1026, 1027, 676, 1026, 676, 677, 1026, 677, 1081, 1024, 1026, 1081, 1024, 1081, 965, 1021, 1024, 965, 1021, 965, 982, 1021, 982, 983, 1021, 983, 984, 1019, 1021, 984, 1019, 984, 986, 1017, 1019, 986, 1017, 986, 987, 1015, 1017, 987, 1014, 1015, 987, 1014, 987, 989, 1014, 989, 991, 1012, 1014, 991, 1012, 991, 992];
 testOutput["water2"]["pass"]=true;
 testOutput["water2"]["report"]="exp numtri:1212\nexp dev:0.00000000000001\nact numtri:1212\nact dev:0";
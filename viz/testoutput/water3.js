@@ -1,5 +1,5 @@
 testOutput["water3"]=[];
 testOutput["water3"]["json"]=[[[-128.0, 4224.0], [-128.0, -128.0], [4224.0, -128.0], [4224.0, 4224.0], [-128.0, 4224.0]], [[3030.0, -21.0], [3019.0, 7.0], [3025.0, 21.0], [3045.0, 65.0], [3054.0, 114.0], [3041.0, 189.0], [3000.0, 219.0], [3017.0, 257.0], [2966.0, 338.0], [2938.0, 340.0], [2934.0, 541.0], [2973.0, 618.0], [2979.0, 752.0], [3026.0, 803.0], [3052.0, 938.0], [3083.0, 1030.0], [3034.0, 1175.0], [3041.0, 1264.0], [3083.0, 1311.0], [3088.0, 1348.0], [3067.0, 1399.0], [3139.0, 1435.0], [3209.0, 1412.0], [3220.0, 1378.0], [3242.0, 1316.0], [3276.0, 1335.0], [3314.0, 1367.0], [3369.0, 1529.0], [3436.0, 1563.0], [3464.0, 1681.0], [3512.0, 1732.0], [3521.0, 1811.0], [3508.0, 1883.0], [3591.0, 1939.0], [3724.0, 2088.0], [3828.0, 2171.0], [3867.0, 2238.0], [3905.0, 2344.0], [3939.0, 2443.0], [3892.0, 2498.0], [3889.0, 2521.0], [3884.0, 2560.0], [3942.0, 2624.0], [3986.0, 2681.0], [3999.0, 2831.0], [4089.0, 3008.0], [4117.0, 3130.0], [4104.0, 3172.0], [4023.0, 3205.0], [3969.0, 3283.0], [3991.0, 3347.0], [4091.0, 3365.0], [4146.0, 3411.0], [4136.0, 3456.0], [4068.0, 3467.0], [3999.0, 3412.0], [3978.0, 3373.0], [3935.0, 3350.0], [3937.0, 3401.0], [4001.0, 3465.0], [4036.0, 3503.0], [3941.0, 3459.0], [3907.0, 3533.0], [3939.0, 3655.0], [3867.0, 3574.0], [3867.0, 3663.0], [3796.0, 3614.0], [3773.0, 3730.0], [3880.0, 4118.0], [3854.0, 4159.0], [3891.0, 4224.0], [4084.0, 4224.0], [4106.0, 4103.0], [4213.0, 4062.0], [4224.0, 4060.0], [4224.0, 2439.0], [4155.0, 2244.0], [4020.0, 2282.0], [3978.0, 2233.0], [3976.0, 2146.0], [3927.0, 2103.0], [3937.0, 2060.0], [3910.0, 1930.0], [3933.0, 1862.0], [3893.0, 1781.0], [3850.0, 1774.0], [3803.0, 1712.0], [3824.0, 1651.0], [3794.0, 1572.0], [3820.0, 1535.0], [3835.0, 1493.0], [3920.0, 1550.0], [3957.0, 1520.0], [3948.0, 1444.0], [3973.0, 1459.0], [3982.0, 1508.0], [4016.0, 1520.0], [4012.0, 1471.0], [4042.0, 1426.0], [4068.0, 1422.0], [4198.0, 1390.0], [4224.0, 1368.0], [4224.0, 969.0], [4214.0, 1019.0], [4177.0, 1095.0], [4142.0, 1070.0], [4176.0, 978.0], [4202.0, 843.0], [4213.0, 653.0], [4181.0, 656.0], [4181.0, 804.0], [4155.0, 796.0], [4138.0, 673.0], [4114.0, 600.0], [4050.0, 622.0], [4050.0, 573.0], [4100.0, 551.0], [4050.0, 379.0], [4015.0, 274.0], [3986.0, 208.0], [3972.0, 175.0], [3965.0, 158.0], [3953.0, 131.0], [3921.0, 99.0], [3885.0, 60.0], [3853.0, 29.0], [3807.0, -4.0], [3755.0, -27.0], [3692.0, -53.0], [3639.0, -58.0], [3595.0, -34.0], [3573.0, -11.0], [3519.0, -4.0], [3488.0, 17.0], [3467.0, 6.0], [3426.0, 4.0], [3384.0, 25.0], [3308.0, 28.0], [3263.0, -93.0], [3126.0, -83.0], [3041.0, -46.0], [3030.0, -21.0], [3030.0, -21.0]], [[3832.0, -21.0], [3840.0, -17.0], [3877.0, 21.0], [3895.0, 39.0], [3961.0, -21.0], [3893.0, -98.0], [3855.0, -128.0], [3688.0, -128.0], [3742.0, -81.0], [3793.0, -41.0], [3832.0, -21.0], [3832.0, -21.0]], [[4205.0, 596.0], [4224.0, 572.0], [4224.0, 248.0], [4166.0, 163.0], [4119.0, 50.0], [4020.0, 36.0], [4004.0, 21.0], [3969.0, 21.0], [3936.0, 62.0], [3982.0, 117.0], [4088.0, 293.0], [4152.0, 419.0], [4185.0, 544.0], [4205.0, 596.0], [4205.0, 596.0]], [[3228.0, 2459.0], [3243.0, 2459.0], [3248.0, 2434.0], [3273.0, 2429.0], [3218.0, 2186.0], [3255.0, 2102.0], [3285.0, 2094.0], [3314.0, 1972.0], [3198.0, 1969.0], [3192.0, 1943.0], [3223.0, 1943.0], [3222.0, 1913.0], [3177.0, 1928.0], [3187.0, 1979.0], [3180.0, 2171.0], [3134.0, 2187.0], [3212.0, 2399.0], [3243.0, 2398.0], [3228.0, 2459.0], [3228.0, 2459.0]], [[4224.0, 1574.0], [4212.0, 1572.0], [4175.0, 1600.0], [4152.0, 1647.0], [4131.0, 1689.0], [4106.0, 1736.0], [4101.0, 1785.0], [4115.0, 1851.0], [4149.0, 1885.0], [4169.0, 1920.0], [4204.0, 1908.0], [4224.0, 1875.0], [4214.0, 1844.0], [4199.0, 1798.0], [4215.0, 1763.0], [4224.0, 1767.0], [4224.0, 1574.0]]];
-testOutput["water3"]["triangles"]=[176, 177, 178, 178, 179, 180, 182, 183, 184, 187, 188, 189, 189, 190, 1, 1, 15, 16, 17, 18, 19, 19, 20, 21, 22, 23, 24, 27, 28, 29, 29, 30, 31, 32, 33, 34, 34, 35, 36, 37, 38, 39, 39, 40, 41, 41, 42, 43, 46, 47, 48, 49, 50, 51, 51, 52, 53, 55, 56, 57, 57, 58, 59, 59, 60, 61, 63, 64, 65, 67, 68, 69, 69, 70, 71, 72, 73, 74, 76, 77, 78, 81, 82, 83, 84, 85, 86, 86, 87, 88, 88, 89, 201, 202, 203, 204, 207, 208, 209, 197, 198, 199, 200, 201, 89, 89, 90, 91, 92, 93, 94, 94, 95, 96, 97, 98, 99, 101, 102, 103, 107, 108, 109, 109, 110, 111, 114, 115, 116, 118, 119, 120, 123, 124, 125, 127, 128, 129, 129, 168, 169, 169, 170, 171, 162, 163, 164, 164, 165, 166, 167, 168, 129, 135, 136, 137, 137, 138, 139, 140, 141, 142, 7, 8, 9, 9, 10, 11, 11, 12, 13, 14, 15, 1, 148, 149, 150, 153, 154, 2, 0, 1, 190, 191, 192, 175, 178, 180, 181, 182, 184, 185, 187, 189, 1, 1, 16, 17, 17, 19, 21, 22, 24, 25, 27, 29, 31, 32, 34, 36, 37, 39, 41, 41, 43, 44, 46, 48, 49, 49, 51, 53, 55, 57, 59, 63, 65, 66, 66, 67, 69, 76, 78, 79, 81, 83, 84, 84, 86, 88, 88, 201, 202, 206, 207, 209, 197, 199, 200, 200, 89, 91, 92, 94, 96, 97, 99, 100, 101, 103, 104, 107, 109, 111, 114, 116, 117, 118, 120, 121, 123, 125, 126, 127, 129, 169, 169, 171, 172, 134, 135, 137, 137, 139, 140, 140, 142, 143, 6, 7, 9, 11, 13, 14, 157, 148, 150, 152, 153, 2, 0, 190, 191, 178, 181, 182, 182, 185, 186, 186, 187, 1, 1, 17, 21, 27, 31, 32, 32, 36, 37, 37, 41, 44, 46, 49, 53, 55, 59, 61, 63, 66, 69, 81, 84, 88, 88, 202, 204, 206, 209, 210, 197, 200, 91, 91, 92, 96, 96, 97, 100, 101, 104, 105, 107, 111, 112, 114, 117, 118, 126, 127, 169, 134, 137, 140, 6, 9, 11, 11, 14, 1, 157, 150, 151, 151, 152, 2, 0, 191, 175, 1, 21, 22, 26, 27, 32, 37, 44, 45, 46, 53, 54, 54, 55, 61, 62, 63, 69, 81, 88, 204, 196, 197, 91, 91, 96, 100, 107, 112, 113, 113, 114, 118, 123, 126, 169, 134, 140, 143, 6, 11, 1, 0, 175, 176, 1, 22, 25, 26, 32, 37, 37, 45, 46, 46, 54, 61, 62, 69, 71, 80, 81, 204, 91, 100, 101, 113, 118, 121, 122, 123, 169, 5, 6, 1, 186, 1, 25, 25, 26, 37, 37, 46, 61, 80, 204, 205, 196, 91, 101, 113, 121, 122, 122, 169, 172, 145, 5, 1, 182, 186, 25, 25, 37, 61, 80, 205, 206, 195, 196, 101, 113, 122, 172, 144, 145, 1, 182, 25, 61, 195, 101, 105, 143, 144, 1, 178, 182, 61, 195, 105, 106, 143, 1, 155, 176, 178, 61, 134, 143, 155, 176, 61, 62, 133, 134, 155, 0, 176, 62, 132, 133, 155, 0, 62, 71, 131, 132, 155, 0, 71, 72, 130, 131, 155, 0, 72, 74, 130, 155, 156, 0, 74, 75, 129, 130, 156, 129, 156, 157, 129, 157, 151, 167, 129, 151, 167, 151, 2, 166, 167, 2, 164, 166, 2, 162, 164, 2, 3, 76, 79, 113, 172, 160, 113, 160, 161, 107, 113, 161];
+testOutput["water3"]["triangles"]=[89, 201, 202, 202, 203, 204, 207, 208, 209, 197, 198, 199, 200, 201, 89, 89, 90, 91, 92, 93, 94, 94, 95, 96, 97, 98, 99, 101, 102, 103, 107, 108, 109, 109, 110, 111, 114, 115, 116, 118, 119, 120, 123, 124, 125, 127, 128, 129, 129, 168, 169, 169, 170, 171, 162, 163, 164, 164, 165, 166, 167, 168, 129, 135, 136, 137, 137, 138, 139, 140, 141, 142, 7, 8, 9, 9, 10, 11, 11, 12, 13, 14, 15, 1, 148, 149, 150, 153, 154, 2, 0, 1, 190, 191, 192, 175, 176, 177, 178, 178, 179, 180, 182, 183, 184, 187, 188, 189, 189, 190, 1, 1, 15, 16, 17, 18, 19, 19, 20, 21, 22, 23, 24, 27, 28, 29, 29, 30, 31, 32, 33, 34, 34, 35, 36, 37, 38, 39, 39, 40, 41, 41, 42, 43, 46, 47, 48, 49, 50, 51, 51, 52, 53, 55, 56, 57, 57, 58, 59, 59, 60, 61, 63, 64, 65, 67, 68, 69, 69, 70, 71, 72, 73, 74, 76, 77, 78, 81, 82, 83, 84, 85, 86, 86, 87, 88, 88, 89, 202, 206, 207, 209, 197, 199, 200, 200, 89, 91, 92, 94, 96, 97, 99, 100, 101, 103, 104, 107, 109, 111, 114, 116, 117, 118, 120, 121, 123, 125, 126, 127, 129, 169, 169, 171, 172, 134, 135, 137, 137, 139, 140, 140, 142, 143, 6, 7, 9, 11, 13, 14, 157, 148, 150, 152, 153, 2, 0, 190, 191, 178, 180, 181, 182, 184, 185, 187, 189, 1, 1, 16, 17, 17, 19, 21, 22, 24, 25, 27, 29, 31, 32, 34, 36, 37, 39, 41, 41, 43, 44, 46, 48, 49, 49, 51, 53, 55, 57, 59, 63, 65, 66, 66, 67, 69, 76, 78, 79, 81, 83, 84, 84, 86, 88, 88, 202, 204, 206, 209, 210, 197, 200, 91, 91, 92, 96, 96, 97, 100, 101, 104, 105, 107, 111, 112, 114, 117, 118, 126, 127, 169, 134, 137, 140, 6, 9, 11, 11, 14, 1, 157, 150, 151, 151, 152, 2, 0, 191, 175, 178, 181, 182, 182, 185, 186, 186, 187, 1, 1, 17, 21, 27, 31, 32, 32, 36, 37, 37, 41, 44, 46, 49, 53, 55, 59, 61, 63, 66, 69, 81, 84, 88, 196, 197, 91, 91, 96, 100, 107, 112, 113, 113, 114, 118, 123, 126, 169, 134, 140, 143, 6, 11, 1, 0, 175, 176, 1, 21, 22, 26, 27, 32, 37, 44, 45, 46, 53, 54, 54, 55, 61, 62, 63, 69, 81, 88, 204, 91, 100, 101, 113, 118, 121, 122, 123, 169, 5, 6, 1, 1, 22, 25, 26, 32, 37, 37, 45, 46, 46, 54, 61, 62, 69, 71, 80, 81, 204, 196, 91, 101, 113, 121, 122, 122, 169, 172, 145, 5, 1, 186, 1, 25, 25, 26, 37, 37, 46, 61, 80, 204, 205, 195, 196, 101, 113, 122, 172, 144, 145, 1, 182, 186, 25, 25, 37, 61, 80, 205, 206, 195, 101, 105, 143, 144, 1, 182, 25, 61, 195, 105, 106, 143, 1, 155, 178, 182, 61, 134, 143, 155, 176, 178, 61, 133, 134, 155, 176, 61, 62, 132, 133, 155, 0, 176, 62, 131, 132, 155, 0, 62, 71, 130, 131, 155, 0, 71, 72, 130, 155, 156, 0, 72, 74, 129, 130, 156, 0, 74, 75, 129, 156, 157, 129, 157, 151, 167, 129, 151, 167, 151, 2, 166, 167, 2, 164, 166, 2, 162, 164, 2, 3, 76, 79, 113, 172, 160, 113, 160, 161, 107, 113, 161];
 testOutput["water3"]["pass"]=true;
 testOutput["water3"]["report"]="exp numtri:197\nexp dev:0.00000000000001\nact numtri:197\nact dev:0";
@@ -1,5 +1,5 @@
 testOutput["water4"]=[];
 testOutput["water4"]["json"]=[[[3911.0, 4075.0], [3910.0, 4075.0], [3926.0, 4114.0], [3950.0, 4132.0], [3965.0, 4126.0], [3978.0, 4139.0], [3986.0, 4131.0], [3979.0, 4119.0], [3986.0, 4084.0], [3991.0, 4075.0], [4020.0, 4020.0], [4027.0, 4012.0], [4030.0, 4022.0], [4047.0, 4033.0], [4058.0, 4030.0], [4067.0, 4022.0], [4120.0, 4003.0], [4118.0, 3993.0], [4135.0, 3995.0], [4130.0, 4032.0], [4121.0, 4038.0], [4118.0, 4056.0], [4107.0, 4050.0], [4095.0, 4060.0], [4086.0, 4062.0], [4070.0, 4075.0], [4027.0, 4111.0], [4042.0, 4140.0], [4058.0, 4139.0], [4067.0, 4135.0], [4078.0, 4138.0], [4067.0, 4151.0], [4067.0, 4166.0], [4077.0, 4180.0], [4088.0, 4191.0], [4047.0, 4172.0], [4031.0, 4161.0], [4020.0, 4165.0], [4000.0, 4147.0], [4013.0, 4171.0], [4000.0, 4224.0], [3836.0, 4224.0], [3828.0, 4224.0], [3071.0, 4224.0], [3070.0, 4212.0], [3158.0, 4140.0], [3202.0, 4158.0], [3171.0, 4117.0], [3161.0, 4104.0], [3136.0, 4131.0], [3122.0, 4117.0], [3105.0, 4099.0], [3122.0, 4075.0], [3136.0, 4055.0], [3156.0, 4075.0], [3178.0, 4097.0], [3205.0, 4082.0], [3176.0, 4036.0], [3187.0, 3979.0], [3105.0, 3947.0], [3084.0, 3920.0], [3036.0, 3976.0], [2980.0, 3982.0], [2993.0, 4043.0], [2956.0, 3964.0], [3020.0, 3960.0], [3050.0, 3921.0], [3032.0, 3880.0], [3069.0, 3902.0], [3093.0, 3894.0], [3188.0, 3961.0], [3236.0, 3917.0], [3222.0, 3893.0], [3191.0, 3891.0], [3173.0, 3863.0], [3187.0, 3818.0], [3225.0, 3775.0], [3292.0, 3774.0], [3239.0, 3798.0], [3197.0, 3845.0], [3211.0, 3873.0], [3249.0, 3877.0], [3442.0, 3801.0], [3567.0, 3769.0], [3587.0, 3798.0], [3632.0, 3777.0], [3607.0, 3820.0], [3644.0, 3880.0], [3707.0, 3851.0], [3726.0, 3899.0], [3766.0, 3951.0], [3770.0, 3977.0], [3778.0, 3985.0], [3772.0, 4012.0], [3763.0, 3998.0], [3745.0, 3990.0], [3747.0, 3977.0], [3738.0, 3974.0], [3735.0, 3985.0], [3725.0, 3995.0], [3745.0, 4010.0], [3758.0, 4024.0], [3763.0, 4048.0], [3772.0, 4067.0], [3762.0, 4074.0], [3754.0, 4093.0], [3763.0, 4094.0], [3779.0, 4083.0], [3779.0, 4074.0], [3784.0, 4066.0], [3792.0, 4060.0], [3794.0, 4043.0], [3795.0, 4029.0], [3798.0, 4016.0], [3807.0, 4006.0], [3799.0, 3933.0], [3849.0, 3871.0], [3838.0, 3840.0], [3885.0, 3818.0], [3913.0, 3778.0], [3958.0, 3687.0], [4056.0, 3501.0], [4109.0, 3301.0], [3956.0, 3259.0], [3876.0, 3277.0], [3775.0, 3251.0], [3705.0, 3225.0], [3659.0, 3243.0], [3613.0, 3232.0], [3614.0, 3310.0], [3606.0, 3266.0], [3552.0, 3297.0], [3494.0, 3235.0], [3440.0, 3221.0], [3402.0, 3190.0], [3344.0, 3177.0], [3264.0, 3142.0], [3279.0, 3201.0], [3248.0, 3256.0], [3229.0, 3197.0], [3200.0, 3194.0], [3191.0, 3246.0], [3159.0, 3244.0], [3125.0, 3282.0], [3097.0, 3296.0], [3068.0, 3279.0], [3013.0, 3393.0], [3002.0, 3486.0], [2994.0, 3383.0], [2917.0, 3406.0], [2985.0, 3359.0], [3073.0, 3239.0], [3106.0, 3270.0], [3139.0, 3238.0], [3156.0, 3166.0], [3156.0, 3101.0], [3106.0, 3069.0], [3095.0, 3021.0], [3060.0, 3025.0], [3100.0, 2967.0], [3058.0, 2920.0], [3115.0, 2933.0], [3138.0, 2891.0], [3168.0, 2877.0], [3217.0, 2892.0], [3207.0, 2847.0], [3143.0, 2821.0], [3192.0, 2691.0], [3239.0, 2686.0], [3176.0, 2615.0], [3191.0, 2615.0], [3191.0, 2600.0], [3240.0, 2590.0], [3189.0, 2544.0], [3161.0, 2570.0], [3112.0, 2769.0], [3087.0, 2798.0], [3045.0, 2878.0], [3028.0, 2890.0], [2992.0, 3118.0], [2905.0, 3266.0], [2880.0, 3271.0], [2860.0, 3342.0], [2820.0, 3348.0], [2800.0, 3419.0], [2775.0, 3424.0], [2756.0, 3495.0], [2731.0, 3501.0], [2711.0, 3572.0], [2686.0, 3577.0], [2642.0, 3729.0], [2612.0, 3730.0], [2628.0, 3805.0], [2628.0, 3821.0], [2579.0, 3968.0], [2554.0, 3973.0], [2554.0, 4004.0], [2524.0, 4004.0], [2534.0, 4029.0], [2479.0, 4050.0], [2470.0, 4075.0], [2457.0, 4111.0], [2442.0, 4117.0], [2419.0, 4126.0], [2414.0, 4197.0], [2390.0, 4218.0], [2388.0, 4224.0], [-128.0, 4224.0], [-128.0, -128.0], [-128.0, -128.0], [4224.0, -128.0], [4224.0, -128.0], [4224.0, 3063.0], [4156.0, 3260.0], [4172.0, 3284.0], [4147.0, 3288.0], [4125.0, 3375.0], [4092.0, 3462.0], [4080.0, 3524.0], [3983.0, 3692.0], [3965.0, 3786.0], [3984.0, 3816.0], [3943.0, 3891.0], [3973.0, 3902.0], [3978.0, 3917.0], [3976.0, 3929.0], [3964.0, 3953.0], [3957.0, 3968.0], [3941.0, 3983.0], [3925.0, 4009.0], [3923.0, 4024.0], [3924.0, 4033.0], [3922.0, 4046.0], [3920.0, 4055.0], [3911.0, 4075.0], [3911.0, 4075.0]], [[3364.0, 4097.0], [3383.0, 4108.0], [3398.0, 4124.0], [3406.0, 4144.0], [3413.0, 4155.0], [3426.0, 4156.0], [3442.0, 4162.0], [3446.0, 4176.0], [3465.0, 4185.0], [3488.0, 4189.0], [3502.0, 4192.0], [3517.0, 4205.0], [3520.0, 4224.0], [3549.0, 4224.0], [3557.0, 4208.0], [3540.0, 4198.0], [3528.0, 4191.0], [3523.0, 4183.0], [3510.0, 4184.0], [3498.0, 4178.0], [3485.0, 4184.0], [3479.0, 4173.0], [3480.0, 4136.0], [3489.0, 4120.0], [3475.0, 4116.0], [3460.0, 4105.0], [3471.0, 4103.0], [3469.0, 4083.0], [3444.0, 4074.0], [3444.0, 4056.0], [3455.0, 4070.0], [3470.0, 4074.0], [3478.0, 4083.0], [3488.0, 4079.0], [3477.0, 4089.0], [3483.0, 4100.0], [3475.0, 4107.0], [3483.0, 4113.0], [3506.0, 4102.0], [3502.0, 4092.0], [3527.0, 4107.0], [3513.0, 4106.0], [3512.0, 4117.0], [3504.0, 4125.0], [3490.0, 4136.0], [3487.0, 4146.0], [3486.0, 4172.0], [3501.0, 4173.0], [3510.0, 4178.0], [3523.0, 4177.0], [3532.0, 4185.0], [3568.0, 4199.0], [3582.0, 4198.0], [3600.0, 4183.0], [3579.0, 4144.0], [3580.0, 4123.0], [3586.0, 4114.0], [3586.0, 4104.0], [3594.0, 4099.0], [3573.0, 4090.0], [3581.0, 4103.0], [3578.0, 4114.0], [3565.0, 4110.0], [3565.0, 4101.0], [3562.0, 4092.0], [3542.0, 4081.0], [3540.0, 4067.0], [3553.0, 4052.0], [3538.0, 4041.0], [3529.0, 4040.0], [3532.0, 4027.0], [3538.0, 4019.0], [3551.0, 4018.0], [3565.0, 4011.0], [3567.0, 3999.0], [3576.0, 3996.0], [3595.0, 3992.0], [3588.0, 3958.0], [3573.0, 3922.0], [3578.0, 3885.0], [3559.0, 3878.0], [3553.0, 3866.0], [3558.0, 3790.0], [3529.0, 3795.0], [3468.0, 3809.0], [3395.0, 3836.0], [3247.0, 3891.0], [3246.0, 3922.0], [3246.0, 3948.0], [3274.0, 3976.0], [3335.0, 3983.0], [3387.0, 3980.0], [3383.0, 4036.0], [3387.0, 4052.0], [3364.0, 4097.0], [3364.0, 4097.0]], [[2976.0, 2176.0], [2953.0, 2237.0], [2969.0, 2285.0], [3011.0, 2301.0], [3046.0, 2297.0], [3091.0, 2255.0], [3133.0, 2276.0], [3155.0, 2245.0], [3145.0, 2194.0], [3171.0, 2157.0], [3148.0, 2085.0], [3109.0, 2097.0], [3096.0, 2079.0], [3073.0, 2080.0], [3056.0, 2136.0], [3008.0, 2171.0], [3032.0, 2134.0], [3042.0, 2085.0], [3064.0, 2068.0], [3067.0, 2028.0], [3099.0, 1984.0], [3126.0, 1980.0], [3146.0, 1951.0], [3158.0, 1893.0], [3207.0, 1857.0], [3240.0, 1826.0], [3321.0, 1842.0], [3238.0, 1848.0], [3213.0, 1885.0], [3177.0, 1917.0], [3163.0, 1968.0], [3138.0, 2017.0], [3180.0, 2004.0], [3206.0, 1958.0], [3272.0, 1951.0], [3346.0, 1944.0], [3360.0, 1988.0], [3402.0, 2001.0], [3437.0, 2053.0], [3460.0, 2054.0], [3468.0, 2089.0], [3455.0, 2140.0], [3425.0, 2187.0], [3486.0, 2135.0], [3505.0, 2080.0], [3506.0, 2038.0], [3516.0, 1985.0], [3559.0, 1955.0], [3556.0, 1887.0], [3548.0, 1857.0], [3520.0, 1881.0], [3482.0, 1895.0], [3473.0, 1860.0], [3448.0, 1842.0], [3449.0, 1791.0], [3480.0, 1773.0], [3532.0, 1750.0], [3510.0, 1789.0], [3463.0, 1808.0], [3475.0, 1835.0], [3509.0, 1874.0], [3542.0, 1836.0], [3579.0, 1847.0], [3579.0, 1891.0], [3580.0, 1936.0], [3601.0, 1954.0], [3630.0, 1924.0], [3699.0, 1930.0], [3720.0, 1966.0], [3708.0, 1994.0], [3695.0, 1943.0], [3629.0, 1945.0], [3611.0, 1975.0], [3581.0, 1966.0], [3556.0, 1993.0], [3548.0, 2073.0], [3517.0, 2148.0], [3475.0, 2199.0], [3486.0, 2254.0], [3456.0, 2300.0], [3410.0, 2293.0], [3384.0, 2219.0], [3346.0, 2189.0], [3270.0, 2185.0], [3214.0, 2148.0], [3183.0, 2217.0], [3231.0, 2271.0], [3301.0, 2317.0], [3296.0, 2369.0], [3282.0, 2441.0], [3230.0, 2488.0], [3242.0, 2507.0], [3233.0, 2539.0], [3275.0, 2568.0], [3272.0, 2646.0], [3301.0, 2638.0], [3312.0, 2583.0], [3359.0, 2662.0], [3350.0, 2602.0], [3374.0, 2555.0], [3413.0, 2549.0], [3444.0, 2550.0], [3459.0, 2526.0], [3481.0, 2534.0], [3464.0, 2556.0], [3506.0, 2588.0], [3536.0, 2535.0], [3555.0, 2549.0], [3518.0, 2625.0], [3491.0, 2600.0], [3463.0, 2623.0], [3451.0, 2583.0], [3398.0, 2637.0], [3437.0, 2690.0], [3378.0, 2678.0], [3354.0, 2693.0], [3301.0, 2701.0], [3301.0, 2767.0], [3266.0, 2712.0], [3235.0, 2713.0], [3195.0, 2731.0], [3203.0, 2761.0], [3224.0, 2792.0], [3291.0, 2804.0], [3341.0, 2802.0], [3357.0, 2780.0], [3363.0, 2753.0], [3388.0, 2724.0], [3415.0, 2726.0], [3445.0, 2736.0], [3460.0, 2779.0], [3516.0, 2736.0], [3537.0, 2741.0], [3579.0, 2749.0], [3608.0, 2715.0], [3645.0, 2709.0], [3659.0, 2727.0], [3691.0, 2712.0], [3734.0, 2691.0], [3745.0, 2648.0], [3731.0, 2635.0], [3687.0, 2679.0], [3654.0, 2685.0], [3633.0, 2662.0], [3598.0, 2658.0], [3584.0, 2630.0], [3596.0, 2597.0], [3594.0, 2557.0], [3613.0, 2518.0], [3640.0, 2517.0], [3636.0, 2486.0], [3658.0, 2444.0], [3647.0, 2409.0], [3686.0, 2446.0], [3652.0, 2488.0], [3651.0, 2525.0], [3626.0, 2560.0], [3655.0, 2597.0], [3630.0, 2629.0], [3667.0, 2669.0], [3727.0, 2616.0], [3758.0, 2623.0], [3817.0, 2628.0], [3823.0, 2593.0], [3791.0, 2551.0], [3753.0, 2515.0], [3754.0, 2484.0], [3775.0, 2484.0], [3795.0, 2454.0], [3831.0, 2471.0], [3893.0, 2485.0], [3927.0, 2485.0], [3953.0, 2458.0], [3998.0, 2422.0], [4046.0, 2383.0], [4022.0, 2355.0], [4020.0, 2296.0], [3946.0, 2285.0], [3902.0, 2357.0], [3920.0, 2295.0], [3894.0, 2249.0], [3839.0, 2250.0], [3898.0, 2235.0], [3927.0, 2187.0], [3916.0, 2149.0], [3887.0, 2161.0], [3822.0, 2135.0], [3883.0, 2132.0], [3922.0, 2040.0], [3909.0, 2109.0], [3937.0, 2104.0], [3949.0, 2152.0], [3998.0, 2135.0], [4080.0, 2138.0], [4119.0, 2083.0], [4101.0, 2020.0], [4106.0, 1978.0], [4164.0, 1986.0], [4163.0, 1952.0], [4203.0, 1992.0], [4210.0, 2013.0], [4200.0, 2031.0], [4224.0, 2021.0], [4224.0, 1949.0], [4216.0, 1915.0], [4199.0, 1891.0], [4199.0, 1876.0], [4183.0, 1816.0], [4097.0, 1796.0], [4059.0, 1748.0], [4030.0, 1713.0], [3989.0, 1696.0], [3955.0, 1681.0], [3926.0, 1657.0], [3869.0, 1646.0], [3824.0, 1612.0], [3788.0, 1591.0], [3561.0, 1609.0], [3546.0, 1624.0], [3515.0, 1624.0], [3510.0, 1649.0], [3350.0, 1732.0], [3335.0, 1732.0], [3274.0, 1748.0], [3259.0, 1763.0], [3108.0, 1795.0], [3049.0, 1887.0], [3015.0, 2080.0], [2976.0, 2176.0], [2976.0, 2176.0]], [[3183.0, -128.0], [3192.0, -118.0], [3253.0, -36.0], [3313.0, 26.0], [3357.0, 31.0], [3406.0, 103.0], [3514.0, 134.0], [3541.0, 271.0], [3547.0, 344.0], [3664.0, 311.0], [3669.0, 216.0], [3565.0, 168.0], [3662.0, 150.0], [3655.0, 88.0], [3528.0, 39.0], [3581.0, 26.0], [3608.0, -21.0], [3675.0, -87.0], [3637.0, -128.0], [3183.0, -128.0]], [[3205.0, -15.0], [3114.0, -30.0], [3065.0, -59.0], [3017.0, -29.0], [3047.0, 7.0], [3013.0, 118.0], [2971.0, 183.0], [2978.0, 281.0], [3026.0, 305.0], [3081.0, 298.0], [3107.0, 369.0], [3157.0, 360.0], [3191.0, 397.0], [3312.0, 393.0], [3350.0, 371.0], [3363.0, 294.0], [3419.0, 236.0], [3334.0, 298.0], [3306.0, 338.0], [3305.0, 290.0], [3261.0, 235.0], [3253.0, 140.0], [3254.0, 86.0], [3335.0, 51.0], [3273.0, 34.0], [3227.0, 26.0], [3199.0, 49.0], [3232.0, 96.0], [3223.0, 166.0], [3224.0, 247.0], [3242.0, 268.0], [3224.0, 281.0], [3202.0, 244.0], [3173.0, 266.0], [3187.0, 328.0], [3158.0, 328.0], [3136.0, 258.0], [3184.0, 231.0], [3177.0, 169.0], [3149.0, 144.0], [3133.0, 188.0], [3078.0, 155.0], [3058.0, 183.0], [3102.0, 221.0], [3074.0, 236.0], [3048.0, 198.0], [3011.0, 180.0], [3043.0, 144.0], [3111.0, 74.0], [3120.0, 37.0], [3098.0, 19.0], [3100.0, -1.0], [3201.0, 8.0], [3205.0, -15.0], [3205.0, -15.0]], [[4224.0, 2296.0], [4180.0, 2330.0], [4198.0, 2362.0], [4203.0, 2411.0], [4224.0, 2415.0], [4224.0, 2482.0], [4212.0, 2474.0], [4198.0, 2509.0], [4197.0, 2541.0], [4177.0, 2531.0], [4124.0, 2533.0], [4101.0, 2514.0], [4023.0, 2555.0], [4029.0, 2506.0], [4055.0, 2468.0], [4029.0, 2422.0], [3955.0, 2489.0], [3928.0, 2532.0], [3913.0, 2515.0], [3857.0, 2512.0], [3804.0, 2481.0], [3789.0, 2511.0], [3844.0, 2590.0], [3830.0, 2643.0], [3767.0, 2641.0], [3761.0, 2730.0], [3704.0, 2728.0], [3675.0, 2737.0], [3670.0, 2764.0], [3651.0, 2765.0], [3651.0, 2741.0], [3626.0, 2729.0], [3607.0, 2761.0], [3552.0, 2763.0], [3551.0, 2781.0], [3513.0, 2781.0], [3505.0, 2817.0], [3477.0, 2800.0], [3459.0, 2831.0], [3422.0, 2852.0], [3420.0, 2901.0], [3399.0, 2855.0], [3428.0, 2807.0], [3428.0, 2770.0], [3402.0, 2751.0], [3378.0, 2764.0], [3400.0, 2817.0], [3363.0, 2833.0], [3319.0, 2849.0], [3299.0, 2895.0], [3313.0, 2925.0], [3283.0, 2942.0], [3286.0, 2853.0], [3237.0, 2904.0], [3230.0, 2941.0], [3254.0, 2952.0], [3267.0, 2985.0], [3302.0, 3016.0], [3346.0, 2995.0], [3379.0, 3004.0], [3384.0, 3070.0], [3416.0, 3072.0], [3456.0, 3069.0], [3459.0, 3042.0], [3478.0, 2986.0], [3481.0, 3022.0], [3496.0, 3023.0], [3512.0, 3072.0], [3486.0, 3080.0], [3488.0, 3150.0], [3508.0, 3174.0], [3535.0, 3195.0], [3553.0, 3174.0], [3571.0, 3206.0], [3605.0, 3179.0], [3639.0, 3213.0], [3701.0, 3204.0], [3881.0, 3256.0], [3964.0, 3239.0], [4113.0, 3273.0], [4168.0, 3133.0], [4224.0, 2979.0], [4224.0, 2296.0]]];
-testOutput["water4"]["triangles"]=[590, 591, 592, 592, 593, 594, 596, 597, 598, 599, 600, 601, 601, 602, 603, 603, 604, 605, 608, 609, 610, 611, 612, 613, 615, 616, 617, 621, 622, 623, 624, 625, 626, 627, 628, 629, 631, 632, 633, 582, 583, 584, 585, 586, 587, 587, 588, 209, 209, 562, 563, 565, 566, 567, 567, 568, 569, 572, 573, 574, 575, 576, 577, 577, 578, 579, 579, 580, 211, 213, 214, 215, 215, 216, 217, 217, 218, 219, 220, 221, 222, 222, 223, 224, 224, 225, 226, 226, 227, 228, 230, 231, 232, 232, 233, 234, 3, 4, 5, 6, 7, 8, 9, 10, 11, 14, 15, 16, 16, 17, 18, 18, 19, 20, 20, 21, 22, 22, 23, 24, 28, 29, 30, 33, 34, 35, 36, 37, 38, 38, 39, 40, 43, 44, 45, 48, 49, 50, 50, 51, 52, 52, 53, 55, 56, 57, 58, 58, 59, 60, 62, 63, 64, 66, 67, 68, 68, 69, 70, 70, 323, 324, 326, 327, 328, 237, 238, 239, 240, 241, 242, 244, 245, 246, 246, 247, 248, 251, 252, 253, 253, 254, 255, 255, 256, 257, 257, 258, 259, 259, 260, 261, 264, 265, 266, 266, 267, 268, 268, 269, 270, 270, 271, 272, 274, 275, 276, 277, 278, 279, 282, 283, 284, 284, 285, 286, 289, 290, 291, 292, 293, 294, 295, 296, 297, 297, 298, 299, 300, 301, 302, 304, 305, 306, 306, 307, 308, 309, 310, 311, 313, 314, 315, 315, 316, 317, 72, 73, 74, 74, 75, 76, 76, 77, 78, 81, 82, 83, 84, 85, 86, 87, 88, 89, 89, 90, 91, 91, 92, 93, 94, 95, 96, 97, 98, 99, 99, 100, 101, 102, 103, 104, 107, 108, 109, 111, 112, 113, 114, 115, 116, 116, 117, 118, 119, 120, 121, 124, 125, 126, 126, 127, 128, 128, 129, 130, 130, 131, 132, 132, 133, 134, 134, 135, 136, 136, 137, 138, 140, 141, 142, 142, 143, 144, 146, 147, 148, 148, 149, 150, 155, 156, 157, 157, 158, 159, 159, 160, 161, 161, 691, 692, 694, 695, 696, 699, 700, 701, 702, 703, 704, 708, 709, 710, 710, 711, 712, 712, 713, 714, 638, 639, 640, 640, 641, 642, 644, 645, 646, 646, 647, 648, 648, 649, 650, 653, 654, 655, 655, 656, 657, 664, 665, 666, 668, 669, 670, 670, 671, 672, 672, 673, 674, 674, 675, 676, 676, 677, 678, 682, 683, 684, 686, 687, 688, 690, 691, 161, 161, 162, 163, 168, 169, 170, 170, 171, 172, 174, 175, 176, 176, 177, 178, 178, 179, 180, 181, 182, 183, 183, 184, 185, 185, 186, 187, 187, 188, 189, 189, 190, 191, 191, 192, 193, 193, 194, 195, 195, 196, 197, 197, 198, 199, 199, 200, 201, 201, 202, 203, 203, 204, 205, 205, 206, 207, 207, 209, 333, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 348, 349, 350, 350, 351, 352, 354, 355, 356, 357, 358, 359, 359, 360, 361, 361, 362, 363, 364, 365, 366, 366, 367, 368, 368, 369, 370, 370, 371, 372, 372, 373, 374, 376, 377, 378, 381, 382, 383, 384, 385, 386, 386, 387, 388, 388, 389, 390, 393, 394, 395, 399, 400, 401, 403, 404, 405, 406, 407, 408, 409, 410, 411, 411, 412, 413, 414, 415, 416, 418, 419, 420, 420, 421, 422, 422, 423, 424, 424, 425, 426, 427, 428, 429, 429, 430, 431, 431, 432, 433, 433, 434, 435, 437, 438, 439, 439, 440, 441, 441, 442, 443, 444, 445, 446, 446, 447, 448, 448, 449, 450, 457, 458, 459, 459, 460, 461, 462, 463, 464, 465, 466, 467, 472, 473, 474, 475, 476, 477, 478, 479, 480, 481, 482, 483, 483, 484, 485, 486, 487, 488, 488, 489, 490, 496, 497, 498, 499, 500, 501, 503, 504, 505, 506, 507, 508, 509, 510, 511, 512, 513, 514, 516, 517, 518, 519, 520, 521, 521, 522, 523, 523, 524, 525, 526, 527, 528, 529, 530, 531, 531, 532, 533, 536, 537, 538, 539, 540, 541, 542, 543, 544, 545, 546, 547, 549, 550, 551, 551, 552, 553, 555, 556, 557, 558, 559, 332, 332, 333, 209, 209, 588, 589, 589, 590, 592, 598, 599, 601, 601, 603, 605, 608, 610, 611, 611, 613, 614, 615, 617, 618, 624, 626, 627, 627, 629, 630, 631, 633, 634, 585, 587, 209, 565, 567, 569, 575, 577, 579, 215, 217, 219, 219, 220, 222, 222, 224, 226, 230, 232, 234, 6, 8, 9, 9, 11, 12, 16, 18, 20, 20, 22, 24, 27, 28, 30, 32, 33, 35, 43, 45, 46, 48, 50, 52, 61, 62, 64, 65, 66, 68, 70, 324, 325, 325, 326, 328, 237, 239, 240, 240, 242, 243, 244, 246, 248, 250, 251, 253, 253, 255, 257, 263, 264, 266, 266, 268, 270, 274, 276, 277, 277, 279, 280, 282, 284, 286, 289, 291, 292, 295, 297, 299, 300, 302, 303, 303, 304, 306, 309, 311, 312, 312, 313, 315, 315, 317, 318, 74, 76, 78, 84, 86, 87, 87, 89, 91, 91, 93, 94, 99, 101, 102, 102, 104, 105, 107, 109, 110, 110, 111, 113, 114, 116, 118, 128, 130, 132, 136, 138, 139, 140, 142, 144, 145, 146, 148, 148, 150, 151, 155, 157, 159, 159, 161, 692, 694, 696, 697, 699, 701, 702, 702, 704, 705, 640, 642, 643, 643, 644, 646, 648, 650, 651, 652, 653, 655, 664, 666, 667, 674, 676, 678, 682, 684, 685, 686, 688, 689, 690, 161, 163, 168, 170, 172, 174, 176, 178, 178, 180, 181, 185, 187, 189, 191, 193, 195, 195, 197, 199, 199, 201, 203, 203, 205, 207, 207, 333, 334, 345, 347, 348, 350, 352, 353, 354, 356, 357, 357, 359, 361, 364, 366, 368, 368, 370, 372, 376, 378, 379, 386, 388, 390, 393, 395, 396, 399, 401, 402, 406, 408, 409, 409, 411, 413, 417, 418, 420, 426, 427, 429, 429, 431, 433, 433, 435, 436, 437, 439, 441, 444, 446, 448, 448, 450, 451, 459, 461, 462, 462, 464, 465, 475, 477, 478, 478, 480, 481, 483, 485, 486, 495, 496, 498, 503, 505, 506, 509, 511, 512, 512, 514, 515, 516, 518, 519, 519, 521, 523, 526, 528, 529, 529, 531, 533, 536, 538, 539, 539, 541, 542, 542, 544, 545, 545, 547, 548, 551, 553, 554, 558, 332, 209, 209, 589, 592, 598, 601, 605, 608, 611, 614, 614, 615, 618, 624, 627, 630, 630, 631, 634, 584, 585, 209, 574, 575, 579, 213, 215, 219, 222, 226, 228, 229, 230, 234, 6, 9, 12, 14, 16, 20, 27, 30, 31, 31, 32, 35, 48, 52, 55, 61, 64, 65, 70, 325, 328, 243, 244, 248, 263, 266, 270, 273, 274, 277, 289, 292, 294, 295, 299, 300, 303, 306, 308, 74, 78, 79, 91, 94, 96, 99, 102, 105, 110, 113, 114, 114, 118, 119, 126, 128, 132, 136, 139, 140, 145, 148, 151, 155, 159, 692, 699, 702, 705, 638, 640, 643, 643, 646, 648, 648, 651, 652, 663, 664, 667, 674, 678, 679, 685, 686, 689, 690, 163, 164, 178, 181, 183, 191, 195, 199, 199, 203, 207, 207, 334, 335, 345, 348, 350, 353, 354, 357, 363, 364, 368, 368, 372, 374, 375, 376, 379, 384, 386, 390, 398, 399, 402, 409, 413, 414, 417, 420, 422, 429, 433, 436, 436, 437, 441, 474, 475, 478, 481, 483, 486, 495, 498, 499, 508, 509, 512, 516, 519, 523, 526, 529, 533, 551, 554, 555, 557, 558, 209, 209, 592, 594, 614, 618, 619, 623, 624, 630, 584, 209, 563, 572, 574, 579, 212, 213, 219, 222, 228, 229, 229, 234, 1, 6, 12, 13, 13, 14, 20, 27, 31, 35, 47, 48, 55, 60, 61, 65, 70, 328, 329, 240, 243, 248, 262, 263, 270, 295, 300, 303, 303, 308, 309, 74, 79, 80, 87, 91, 96, 110, 114, 119, 126, 132, 134, 144, 145, 151, 154, 155, 692, 698, 699, 705, 638, 643, 648, 663, 667, 668, 672, 674, 679, 689, 690, 164, 174, 178, 183, 191, 199, 207, 207, 335, 336, 344, 345, 350, 350, 353, 357, 363, 368, 374, 383, 384, 390, 398, 402, 403, 409, 414, 416, 417, 422, 424, 436, 441, 443, 481, 486, 488, 508, 512, 515, 515, 516, 523, 525, 526, 533, 551, 555, 557, 557, 209, 594, 608, 614, 619, 621, 623, 630, 582, 584, 563, 572, 579, 211, 222, 229, 1, 13, 20, 24, 26, 27, 35, 46, 47, 55, 60, 65, 68, 262, 270, 272, 294, 295, 303, 303, 309, 312, 72, 74, 80, 87, 96, 97, 110, 119, 121, 144, 151, 152, 153, 154, 692, 697, 698, 705, 638, 648, 652, 670, 672, 679, 689, 164, 165, 174, 183, 185, 189, 191, 207, 207, 336, 338, 342, 344, 350, 350, 357, 361, 383, 390, 391, 397, 398, 403, 429, 436, 443, 478, 481, 488, 508, 515, 523, 525, 533, 534, 551, 557, 594, 608, 619, 620, 620, 621, 630, 582, 563, 564, 571, 572, 211, 6, 13, 24, 26, 35, 36, 46, 55, 56, 58, 60, 68, 261, 262, 272, 294, 303, 312, 71, 72, 80, 87, 97, 99, 140, 144, 152, 153, 692, 693, 697, 705, 706, 637, 638, 652, 685, 689, 165, 174, 185, 189, 189, 207, 338, 350, 361, 363, 383, 391, 392, 397, 403, 405, 429, 443, 444, 478, 488, 490, 508, 523, 525, 549, 551, 594, 608, 620, 630, 634, 582, 564, 5, 6, 24, 26, 36, 38, 58, 68, 70, 259, 261, 272, 289, 294, 312, 323, 70, 71, 71, 80, 81, 87, 99, 105, 136, 140, 152, 153, 693, 694, 694, 697, 706, 685, 165, 166, 174, 189, 338, 342, 350, 363, 381, 383, 392, 396, 397, 405, 429, 444, 448, 474, 478, 490, 506, 508, 525, 548, 549, 594, 607, 608, 630, 634, 564, 565, 5, 24, 25, 56, 58, 70, 259, 272, 273, 322, 323, 71, 84, 87, 105, 136, 152, 153, 694, 706, 707, 173, 174, 338, 341, 342, 363, 381, 392, 393, 393, 396, 405, 426, 429, 448, 474, 490, 491, 506, 525, 534, 545, 548, 594, 607, 630, 634, 5, 25, 26, 56, 70, 329, 259, 273, 277, 322, 71, 81, 83, 84, 105, 136, 153, 694, 694, 707, 708, 172, 173, 338, 381, 393, 405, 426, 448, 451, 472, 474, 491, 542, 545, 594, 606, 607, 634, 5, 26, 38, 46, 56, 329, 259, 277, 280, 321, 322, 81, 134, 136, 694, 172, 338, 339, 380, 381, 405, 472, 491, 492, 539, 542, 594, 605, 606, 634, 3, 5, 38, 259, 280, 281, 320, 321, 81, 134, 694, 708, 172, 339, 341, 379, 380, 405, 471, 472, 492, 539, 594, 595, 605, 634, 565, 3, 38, 40, 257, 259, 281, 320, 81, 83, 134, 708, 710, 379, 405, 406, 471, 492, 493, 539, 595, 596, 605, 565, 569, 2, 3, 40, 257, 281, 282, 319, 320, 83, 126, 134, 710, 375, 379, 406, 470, 471, 493, 539, 596, 598, 598, 605, 569, 253, 257, 282, 318, 319, 83, 126, 710, 712, 375, 406, 409, 598, 569, 570, 253, 282, 286, 315, 318, 83, 126, 712, 714, 374, 375, 409, 539, 598, 570, 250, 253, 286, 312, 315, 83, 124, 126, 714, 374, 409, 416, 539, 570, 571, 250, 286, 287, 312, 83, 105, 123, 124, 714, 363, 374, 416, 539, 571, 211, 249, 250, 287, 289, 312, 105, 123, 714, 715, 341, 363, 416, 536, 539, 211, 249, 287, 288, 288, 289, 105, 122, 123, 715, 341, 416, 417, 535, 536, 211, 249, 288, 105, 122, 715, 716, 172, 341, 417, 249, 105, 106, 122, 716, 717, 172, 417, 424, 121, 122, 717, 172, 424, 426, 121, 717, 718, 168, 172, 426, 168, 426, 451, 167, 168, 451, 167, 451, 452, 166, 167, 452, 166, 452, 453, 166, 453, 454, 685, 166, 454, 685, 454, 455, 685, 455, 456, 682, 685, 456, 682, 456, 457, 682, 457, 459, 681, 682, 459, 680, 681, 459, 680, 459, 462, 679, 680, 462, 670, 679, 462, 670, 462, 465, 668, 670, 465, 668, 465, 467, 668, 467, 468, 663, 668, 468, 663, 468, 469, 662, 663, 469, 662, 469, 470, 661, 662, 470, 661, 470, 493, 660, 661, 493, 660, 493, 494, 659, 660, 494, 659, 494, 495, 658, 659, 495, 658, 495, 499, 657, 658, 499, 657, 499, 501, 655, 657, 501, 655, 501, 502, 655, 502, 503, 652, 655, 503, 637, 652, 503, 637, 503, 506, 637, 506, 534, 46, 329, 236, 236, 237, 240, 121, 718, 212, 43, 46, 236, 121, 212, 219, 43, 236, 240, 110, 121, 219, 43, 240, 248, 110, 219, 222, 107, 110, 222, 107, 222, 1, 106, 107, 1, 249, 106, 1, 249, 1, 2, 249, 2, 40];
+testOutput["water4"]["triangles"]=[70, 323, 324, 326, 327, 328, 237, 238, 239, 240, 241, 242, 244, 245, 246, 246, 247, 248, 251, 252, 253, 253, 254, 255, 255, 256, 257, 257, 258, 259, 259, 260, 261, 264, 265, 266, 266, 267, 268, 268, 269, 270, 270, 271, 272, 274, 275, 276, 277, 278, 279, 282, 283, 284, 284, 285, 286, 289, 290, 291, 292, 293, 294, 295, 296, 297, 297, 298, 299, 300, 301, 302, 304, 305, 306, 306, 307, 308, 309, 310, 311, 313, 314, 315, 315, 316, 317, 72, 73, 74, 74, 75, 76, 76, 77, 78, 81, 82, 83, 84, 85, 86, 87, 88, 89, 89, 90, 91, 91, 92, 93, 94, 95, 96, 97, 98, 99, 99, 100, 101, 102, 103, 104, 107, 108, 109, 111, 112, 113, 114, 115, 116, 116, 117, 118, 119, 120, 121, 124, 125, 126, 126, 127, 128, 128, 129, 130, 130, 131, 132, 132, 133, 134, 134, 135, 136, 136, 137, 138, 140, 141, 142, 142, 143, 144, 146, 147, 148, 148, 149, 150, 155, 156, 157, 157, 158, 159, 159, 160, 161, 161, 691, 692, 694, 695, 696, 699, 700, 701, 702, 703, 704, 708, 709, 710, 710, 711, 712, 712, 713, 714, 638, 639, 640, 640, 641, 642, 644, 645, 646, 646, 647, 648, 648, 649, 650, 653, 654, 655, 655, 656, 657, 664, 665, 666, 668, 669, 670, 670, 671, 672, 672, 673, 674, 674, 675, 676, 676, 677, 678, 682, 683, 684, 686, 687, 688, 690, 691, 161, 161, 162, 163, 168, 169, 170, 170, 171, 172, 174, 175, 176, 176, 177, 178, 178, 179, 180, 181, 182, 183, 183, 184, 185, 185, 186, 187, 187, 188, 189, 189, 190, 191, 191, 192, 193, 193, 194, 195, 195, 196, 197, 197, 198, 199, 199, 200, 201, 201, 202, 203, 203, 204, 205, 205, 206, 207, 207, 208, 333, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347, 348, 349, 350, 350, 351, 352, 354, 355, 356, 357, 358, 359, 359, 360, 361, 361, 362, 363, 364, 365, 366, 366, 367, 368, 368, 369, 370, 370, 371, 372, 372, 373, 374, 376, 377, 378, 381, 382, 383, 384, 385, 386, 386, 387, 388, 388, 389, 390, 393, 394, 395, 399, 400, 401, 403, 404, 405, 406, 407, 408, 409, 410, 411, 411, 412, 413, 414, 415, 416, 418, 419, 420, 420, 421, 422, 422, 423, 424, 424, 425, 426, 427, 428, 429, 429, 430, 431, 431, 432, 433, 433, 434, 435, 437, 438, 439, 439, 440, 441, 441, 442, 443, 444, 445, 446, 446, 447, 448, 448, 449, 450, 457, 458, 459, 459, 460, 461, 462, 463, 464, 465, 466, 467, 472, 473, 474, 475, 476, 477, 478, 479, 480, 481, 482, 483, 483, 484, 485, 486, 487, 488, 488, 489, 490, 496, 497, 498, 499, 500, 501, 503, 504, 505, 506, 507, 508, 509, 510, 511, 512, 513, 514, 516, 517, 518, 519, 520, 521, 521, 522, 523, 523, 524, 525, 526, 527, 528, 529, 530, 531, 531, 532, 533, 536, 537, 538, 539, 540, 541, 542, 543, 544, 545, 546, 547, 549, 550, 551, 551, 552, 553, 555, 556, 557, 558, 559, 332, 332, 333, 208, 208, 588, 589, 590, 591, 592, 592, 593, 594, 596, 597, 598, 599, 600, 601, 601, 602, 603, 603, 604, 605, 608, 609, 610, 611, 612, 613, 615, 616, 617, 621, 622, 623, 624, 625, 626, 627, 628, 629, 631, 632, 633, 582, 583, 584, 585, 586, 587, 587, 588, 208, 208, 562, 563, 565, 566, 567, 567, 568, 569, 572, 573, 574, 575, 576, 577, 577, 578, 579, 579, 580, 211, 213, 214, 215, 215, 216, 217, 217, 218, 219, 220, 221, 222, 222, 223, 224, 224, 225, 226, 226, 227, 228, 230, 231, 232, 232, 233, 0, 3, 4, 5, 6, 7, 8, 9, 10, 11, 14, 15, 16, 16, 17, 18, 18, 19, 20, 20, 21, 22, 22, 23, 24, 28, 29, 30, 33, 34, 35, 36, 37, 38, 38, 39, 40, 43, 44, 45, 48, 49, 50, 50, 51, 52, 52, 53, 55, 56, 57, 58, 58, 59, 60, 62, 63, 64, 66, 67, 68, 68, 69, 70, 70, 324, 325, 325, 326, 328, 237, 239, 240, 240, 242, 243, 244, 246, 248, 250, 251, 253, 253, 255, 257, 263, 264, 266, 266, 268, 270, 274, 276, 277, 277, 279, 280, 282, 284, 286, 289, 291, 292, 295, 297, 299, 300, 302, 303, 303, 304, 306, 309, 311, 312, 312, 313, 315, 315, 317, 318, 74, 76, 78, 84, 86, 87, 87, 89, 91, 91, 93, 94, 99, 101, 102, 102, 104, 105, 107, 109, 110, 110, 111, 113, 114, 116, 118, 128, 130, 132, 136, 138, 139, 140, 142, 144, 145, 146, 148, 148, 150, 151, 155, 157, 159, 159, 161, 692, 694, 696, 697, 699, 701, 702, 702, 704, 705, 640, 642, 643, 643, 644, 646, 648, 650, 651, 652, 653, 655, 664, 666, 667, 674, 676, 678, 682, 684, 685, 686, 688, 689, 690, 161, 163, 168, 170, 172, 174, 176, 178, 178, 180, 181, 185, 187, 189, 191, 193, 195, 195, 197, 199, 199, 201, 203, 203, 205, 207, 207, 333, 334, 345, 347, 348, 350, 352, 353, 354, 356, 357, 357, 359, 361, 364, 366, 368, 368, 370, 372, 376, 378, 379, 386, 388, 390, 393, 395, 396, 399, 401, 402, 406, 408, 409, 409, 411, 413, 417, 418, 420, 426, 427, 429, 429, 431, 433, 433, 435, 436, 437, 439, 441, 444, 446, 448, 448, 450, 451, 459, 461, 462, 462, 464, 465, 475, 477, 478, 478, 480, 481, 483, 485, 486, 495, 496, 498, 503, 505, 506, 509, 511, 512, 512, 514, 515, 516, 518, 519, 519, 521, 523, 526, 528, 529, 529, 531, 533, 536, 538, 539, 539, 541, 542, 542, 544, 545, 545, 547, 548, 551, 553, 554, 558, 332, 208, 208, 589, 590, 598, 599, 601, 601, 603, 605, 608, 610, 611, 611, 613, 614, 615, 617, 618, 624, 626, 627, 627, 629, 630, 631, 633, 634, 585, 587, 208, 565, 567, 569, 575, 577, 579, 215, 217, 219, 219, 220, 222, 222, 224, 226, 230, 232, 0, 6, 8, 9, 9, 11, 12, 16, 18, 20, 20, 22, 24, 27, 28, 30, 32, 33, 35, 43, 45, 46, 48, 50, 52, 61, 62, 64, 65, 66, 68, 70, 325, 328, 243, 244, 248, 263, 266, 270, 273, 274, 277, 289, 292, 294, 295, 299, 300, 303, 306, 308, 74, 78, 79, 91, 94, 96, 99, 102, 105, 110, 113, 114, 114, 118, 119, 126, 128, 132, 136, 139, 140, 145, 148, 151, 155, 159, 692, 699, 702, 705, 638, 640, 643, 643, 646, 648, 648, 651, 652, 663, 664, 667, 674, 678, 679, 685, 686, 689, 690, 163, 164, 178, 181, 183, 191, 195, 199, 199, 203, 207, 207, 334, 335, 345, 348, 350, 353, 354, 357, 363, 364, 368, 368, 372, 374, 375, 376, 379, 384, 386, 390, 398, 399, 402, 409, 413, 414, 417, 420, 422, 429, 433, 436, 436, 437, 441, 474, 475, 478, 481, 483, 486, 495, 498, 499, 508, 509, 512, 516, 519, 523, 526, 529, 533, 551, 554, 555, 557, 558, 208, 208, 590, 592, 598, 601, 605, 608, 611, 614, 614, 615, 618, 624, 627, 630, 630, 631, 634, 584, 585, 208, 574, 575, 579, 213, 215, 219, 222, 226, 228, 229, 230, 0, 6, 9, 12, 14, 16, 20, 27, 30, 31, 31, 32, 35, 48, 52, 55, 61, 64, 65, 70, 328, 329, 240, 243, 248, 262, 263, 270, 295, 300, 303, 303, 308, 309, 74, 79, 80, 87, 91, 96, 110, 114, 119, 126, 132, 134, 144, 145, 151, 154, 155, 692, 698, 699, 705, 638, 643, 648, 663, 667, 668, 672, 674, 679, 689, 690, 164, 174, 178, 183, 191, 199, 207, 207, 335, 336, 344, 345, 350, 350, 353, 357, 363, 368, 374, 383, 384, 390, 398, 402, 403, 409, 414, 416, 417, 422, 424, 436, 441, 443, 481, 486, 488, 508, 512, 515, 515, 516, 523, 525, 526, 533, 551, 555, 557, 557, 208, 592, 614, 618, 619, 623, 624, 630, 584, 208, 563, 572, 574, 579, 212, 213, 219, 222, 228, 229, 229, 0, 1, 6, 12, 13, 13, 14, 20, 27, 31, 35, 47, 48, 55, 60, 61, 65, 262, 270, 272, 294, 295, 303, 303, 309, 312, 72, 74, 80, 87, 96, 97, 110, 119, 121, 144, 151, 152, 153, 154, 692, 697, 698, 705, 638, 648, 652, 670, 672, 679, 689, 164, 165, 174, 183, 185, 189, 191, 207, 207, 336, 338, 342, 344, 350, 350, 357, 361, 383, 390, 391, 397, 398, 403, 429, 436, 443, 478, 481, 488, 508, 515, 523, 525, 533, 534, 551, 557, 592, 608, 614, 619, 621, 623, 630, 582, 584, 563, 572, 579, 211, 222, 229, 1, 13, 20, 24, 26, 27, 35, 46, 47, 55, 60, 65, 68, 261, 262, 272, 294, 303, 312, 71, 72, 80, 87, 97, 99, 140, 144, 152, 153, 692, 693, 697, 705, 706, 637, 638, 652, 685, 689, 165, 174, 185, 189, 189, 207, 338, 350, 361, 363, 383, 391, 392, 397, 403, 405, 429, 443, 444, 478, 488, 490, 508, 523, 525, 549, 551, 592, 608, 619, 620, 620, 621, 630, 582, 563, 564, 571, 572, 211, 6, 13, 24, 26, 35, 36, 46, 55, 56, 58, 60, 68, 259, 261, 272, 289, 294, 312, 71, 80, 81, 87, 99, 105, 136, 140, 152, 153, 693, 694, 694, 697, 706, 685, 165, 166, 174, 189, 338, 342, 350, 363, 381, 383, 392, 396, 397, 405, 429, 444, 448, 474, 478, 490, 506, 508, 525, 548, 549, 592, 608, 620, 630, 634, 582, 564, 5, 6, 24, 26, 36, 38, 58, 68, 70, 259, 272, 273, 323, 70, 71, 84, 87, 105, 136, 152, 153, 694, 706, 707, 173, 174, 338, 341, 342, 363, 381, 392, 393, 393, 396, 405, 426, 429, 448, 474, 490, 491, 506, 525, 534, 545, 548, 592, 607, 608, 630, 634, 564, 565, 5, 24, 25, 56, 58, 70, 259, 273, 277, 322, 323, 71, 83, 84, 105, 136, 153, 694, 694, 707, 708, 172, 173, 338, 381, 393, 405, 426, 448, 451, 472, 474, 491, 542, 545, 592, 607, 630, 634, 5, 25, 26, 56, 70, 329, 259, 277, 280, 322, 71, 81, 134, 136, 694, 172, 338, 339, 380, 381, 405, 472, 491, 492, 539, 542, 592, 606, 607, 634, 5, 26, 38, 46, 56, 329, 259, 280, 281, 321, 322, 81, 134, 694, 708, 172, 339, 341, 379, 380, 405, 471, 472, 492, 539, 592, 594, 605, 606, 634, 3, 5, 38, 257, 259, 281, 320, 321, 81, 134, 708, 710, 379, 405, 406, 471, 492, 493, 539, 594, 595, 605, 634, 565, 3, 38, 40, 257, 281, 282, 320, 81, 83, 126, 134, 710, 375, 379, 406, 470, 471, 493, 539, 595, 596, 605, 565, 569, 2, 3, 40, 253, 257, 282, 319, 320, 83, 126, 710, 712, 375, 406, 409, 539, 596, 598, 598, 605, 569, 253, 282, 286, 318, 319, 83, 126, 712, 714, 374, 375, 409, 598, 569, 570, 250, 253, 286, 315, 318, 83, 124, 126, 714, 374, 409, 416, 539, 598, 570, 250, 286, 287, 312, 315, 83, 123, 124, 714, 363, 374, 416, 539, 570, 571, 249, 250, 287, 312, 83, 105, 123, 714, 715, 341, 363, 416, 539, 571, 211, 249, 287, 288, 289, 312, 105, 122, 123, 715, 341, 416, 417, 536, 539, 211, 288, 289, 105, 122, 715, 716, 172, 341, 417, 535, 536, 211, 249, 288, 105, 122, 716, 717, 172, 417, 424, 249, 105, 106, 121, 122, 717, 172, 424, 426, 121, 717, 718, 168, 172, 426, 168, 426, 451, 167, 168, 451, 167, 451, 452, 166, 167, 452, 166, 452, 453, 166, 453, 454, 685, 166, 454, 685, 454, 455, 685, 455, 456, 682, 685, 456, 682, 456, 457, 682, 457, 459, 681, 682, 459, 680, 681, 459, 680, 459, 462, 679, 680, 462, 670, 679, 462, 670, 462, 465, 668, 670, 465, 668, 465, 467, 668, 467, 468, 663, 668, 468, 663, 468, 469, 662, 663, 469, 662, 469, 470, 661, 662, 470, 661, 470, 493, 660, 661, 493, 660, 493, 494, 659, 660, 494, 659, 494, 495, 658, 659, 495, 658, 495, 499, 657, 658, 499, 657, 499, 501, 655, 657, 501, 655, 501, 502, 655, 502, 503, 652, 655, 503, 637, 652, 503, 637, 503, 506, 637, 506, 534, 46, 329, 236, 236, 237, 240, 121, 718, 212, 43, 46, 236, 121, 212, 219, 43, 236, 240, 110, 121, 219, 43, 240, 248, 110, 219, 222, 107, 110, 222, 107, 222, 1, 106, 107, 1, 249, 106, 1, 249, 1, 2, 249, 2, 40];
 testOutput["water4"]["pass"]=true;
 testOutput["water4"]["report"]="exp numtri:705\nexp dev:0.00000000000001\nact numtri:705\nact dev:0";
@@ -1,5 +1,5 @@
 testOutput["water_huge"]=[];
 testOutput["water_huge"]["json"]=[[[3116.0, 3071.0], [3118.0, 3068.0], [3108.0, 3102.0], [3100.0, 3105.0], [3096.0, 3113.0], [3099.0, 3121.0], [3091.0, 3135.0], [3099.0, 3133.0], [3105.0, 3144.0], [3113.0, 3144.0], [3105.0, 3143.0], [3117.0, 3157.0], [3129.0, 3155.0], [3137.0, 3167.0], [3152.0, 3177.0], [3160.0, 3187.0], [3172.0, 3204.0], [3174.0, 3195.0], [3179.0, 3217.0], [3197.0, 3225.0], [3189.0, 3217.0], [3203.0, 3217.0], [3199.0, 3202.0], [3186.0, 3188.0], [3186.0, 3174.0], [3174.0, 3166.0], [3165.0, 3145.0], [3168.0, 3143.0], [3159.0, 3143.0], [3151.0, 3118.0], [3154.0, 3107.0], [3165.0, 3110.0], [3174.0, 3105.0], [3175.0, 3082.0], [3186.0, 3076.0], [3178.0, 3089.0], [3183.0, 3103.0], [3196.0, 3116.0], [3181.0, 3105.0], [3180.0, 3111.0], [3155.0, 3111.0], [3173.0, 3130.0], [3179.0, 3150.0], [3197.0, 3170.0], [3199.0, 3178.0], [3216.0, 3190.0], [3214.0, 3203.0], [3235.0, 3219.0], [3243.0, 3212.0], [3244.0, 3198.0], [3246.0, 3208.0], [3244.0, 3219.0], [3236.0, 3240.0], [3237.0, 3249.0], [3248.0, 3262.0], [3263.0, 3267.0], [3327.0, 3313.0], [3338.0, 3327.0], [3340.0, 3340.0], [3351.0, 3349.0], [3353.0, 3361.0], [3345.0, 3365.0], [3355.0, 3387.0], [3363.0, 3392.0], [3364.0, 3401.0], [3375.0, 3413.0], [3382.0, 3421.0], [3394.0, 3431.0], [3404.0, 3433.0], [3398.0, 3416.0], [3406.0, 3433.0], [3409.0, 3422.0], [3428.0, 3400.0], [3423.0, 3392.0], [3446.0, 3377.0], [3461.0, 3366.0], [3495.0, 3354.0], [3506.0, 3343.0], [3506.0, 3334.0], [3495.0, 3338.0], [3505.0, 3332.0], [3503.0, 3323.0], [3511.0, 3316.0], [3512.0, 3303.0], [3502.0, 3302.0], [3513.0, 3296.0], [3509.0, 3286.0], [3517.0, 3283.0], [3525.0, 3277.0], [3528.0, 3269.0], [3526.0, 3277.0], [3526.0, 3287.0], [3517.0, 3288.0], [3518.0, 3301.0], [3515.0, 3313.0], [3508.0, 3329.0], [3517.0, 3333.0], [3522.0, 3341.0], [3534.0, 3344.0], [3547.0, 3333.0], [3549.0, 3323.0], [3561.0, 3314.0], [3565.0, 3302.0], [3576.0, 3301.0], [3573.0, 3314.0], [3568.0, 3329.0], [3559.0, 3348.0], [3543.0, 3341.0], [3547.0, 3362.0], [3563.0, 3362.0], [3573.0, 3327.0], [3576.0, 3309.0], [3583.0, 3292.0], [3594.0, 3256.0], [3611.0, 3205.0], [3599.0, 3181.0], [3585.0, 3172.0], [3574.0, 3167.0], [3583.0, 3176.0], [3597.0, 3193.0], [3583.0, 3184.0], [3583.0, 3192.0], [3583.0, 3200.0], [3576.0, 3188.0], [3575.0, 3198.0], [3573.0, 3190.0], [3557.0, 3197.0], [3565.0, 3205.0], [3564.0, 3211.0], [3564.0, 3224.0], [3563.0, 3233.0], [3565.0, 3245.0], [3555.0, 3240.0], [3564.0, 3226.0], [3558.0, 3218.0], [3558.0, 3210.0], [3549.0, 3208.0], [3557.0, 3202.0], [3540.0, 3186.0], [3539.0, 3195.0], [3540.0, 3204.0], [3532.0, 3198.0], [3530.0, 3209.0], [3528.0, 3222.0], [3516.0, 3220.0], [3515.0, 3235.0], [3503.0, 3239.0], [3503.0, 3241.0], [3495.0, 3241.0], [3497.0, 3249.0], [3489.0, 3260.0], [3478.0, 3271.0], [3477.0, 3281.0], [3478.0, 3272.0], [3482.0, 3253.0], [3470.0, 3248.0], [3481.0, 3245.0], [3488.0, 3230.0], [3496.0, 3232.0], [3509.0, 3221.0], [3515.0, 3199.0], [3502.0, 3197.0], [3493.0, 3189.0], [3488.0, 3181.0], [3478.0, 3174.0], [3470.0, 3185.0], [3474.0, 3172.0], [3470.0, 3162.0], [3461.0, 3170.0], [3452.0, 3169.0], [3449.0, 3160.0], [3464.0, 3158.0], [3456.0, 3146.0], [3464.0, 3154.0], [3476.0, 3153.0], [3470.0, 3145.0], [3488.0, 3161.0], [3504.0, 3182.0], [3511.0, 3171.0], [3522.0, 3166.0], [3533.0, 3168.0], [3541.0, 3169.0], [3537.0, 3158.0], [3545.0, 3152.0], [3544.0, 3163.0], [3553.0, 3159.0], [3578.0, 3138.0], [3570.0, 3127.0], [3561.0, 3128.0], [3561.0, 3120.0], [3552.0, 3119.0], [3518.0, 3101.0], [3509.0, 3090.0], [3508.0, 3085.0], [3517.0, 3085.0], [3508.0, 3079.0], [3506.0, 3071.0], [3512.0, 3056.0], [3495.0, 3053.0], [3481.0, 3056.0], [3478.0, 3066.0], [3491.0, 3073.0], [3497.0, 3099.0], [3493.0, 3091.0], [3467.0, 3086.0], [3470.0, 3096.0], [3476.0, 3104.0], [3484.0, 3106.0], [3474.0, 3108.0], [3484.0, 3124.0], [3472.0, 3116.0], [3464.0, 3116.0], [3468.0, 3107.0], [3464.0, 3099.0], [3452.0, 3108.0], [3448.0, 3120.0], [3448.0, 3112.0], [3440.0, 3114.0], [3449.0, 3108.0], [3440.0, 3108.0], [3457.0, 3103.0], [3461.0, 3090.0], [3453.0, 3089.0], [3452.0, 3099.0], [3437.0, 3103.0], [3453.0, 3083.0], [3435.0, 3077.0], [3432.0, 3087.0], [3424.0, 3087.0], [3425.0, 3077.0], [3413.0, 3082.0], [3407.0, 3090.0], [3413.0, 3102.0], [3405.0, 3102.0], [3406.0, 3116.0], [3403.0, 3094.0], [3392.0, 3092.0], [3386.0, 3101.0], [3382.0, 3118.0], [3385.0, 3130.0], [3380.0, 3119.0], [3354.0, 3116.0], [3378.0, 3115.0], [3377.0, 3107.0], [3367.0, 3098.0], [3376.0, 3100.0], [3378.0, 3092.0], [3386.0, 3092.0], [3375.0, 3084.0], [3374.0, 3071.0], [3381.0, 3082.0], [3397.0, 3081.0], [3398.0, 3072.0], [3407.0, 3061.0], [3398.0, 3055.0], [3406.0, 3057.0], [3411.0, 3045.0], [3402.0, 3042.0], [3397.0, 3033.0], [3383.0, 3037.0], [3375.0, 3035.0], [3367.0, 3038.0], [3358.0, 3047.0], [3348.0, 3040.0], [3356.0, 3041.0], [3359.0, 3033.0], [3372.0, 3034.0], [3368.0, 3024.0], [3375.0, 3032.0], [3385.0, 3029.0], [3383.0, 3018.0], [3379.0, 3010.0], [3389.0, 3025.0], [3397.0, 3024.0], [3419.0, 3040.0], [3419.0, 3023.0], [3427.0, 3034.0], [3431.0, 3043.0], [3436.0, 3029.0], [3422.0, 3010.0], [3435.0, 3021.0], [3436.0, 3008.0], [3414.0, 2983.0], [3417.0, 2995.0], [3413.0, 3008.0], [3406.0, 3000.0], [3407.0, 2992.0], [3384.0, 2994.0], [3378.0, 3003.0], [3378.0, 2994.0], [3367.0, 2995.0], [3348.0, 2988.0], [3373.0, 2990.0], [3383.0, 2990.0], [3387.0, 2985.0], [3397.0, 2985.0], [3395.0, 2975.0], [3390.0, 2964.0], [3368.0, 2965.0], [3328.0, 2969.0], [3326.0, 2977.0], [3324.0, 2985.0], [3326.0, 3001.0], [3319.0, 2988.0], [3314.0, 2997.0], [3319.0, 2986.0], [3311.0, 2984.0], [3321.0, 2974.0], [3313.0, 2978.0], [3305.0, 2979.0], [3295.0, 2982.0], [3289.0, 2970.0], [3297.0, 2977.0], [3321.0, 2973.0], [3285.0, 2952.0], [3264.0, 2928.0], [3239.0, 2921.0], [3203.0, 2882.0], [3194.0, 2873.0], [3190.0, 2882.0], [3184.0, 2892.0], [3184.0, 2883.0], [3189.0, 2880.0], [3179.0, 2880.0], [3191.0, 2877.0], [3152.0, 2864.0], [3160.0, 2878.0], [3164.0, 2890.0], [3166.0, 2902.0], [3158.0, 2907.0], [3163.0, 2891.0], [3151.0, 2882.0], [3151.0, 2873.0], [3151.0, 2855.0], [3137.0, 2864.0], [3129.0, 2860.0], [3119.0, 2869.0], [3128.0, 2859.0], [3142.0, 2850.0], [3153.0, 2849.0], [3158.0, 2858.0], [3155.0, 2848.0], [3133.0, 2837.0], [3114.0, 2818.0], [3094.0, 2819.0], [3071.0, 2818.0], [3079.0, 2816.0], [3108.0, 2809.0], [3081.0, 2774.0], [3072.0, 2753.0], [3050.0, 2728.0], [3044.0, 2711.0], [3043.0, 2694.0], [3028.0, 2690.0], [3024.0, 2679.0], [2993.0, 2629.0], [2965.0, 2604.0], [2950.0, 2584.0], [2919.0, 2559.0], [2909.0, 2528.0], [2896.0, 2507.0], [2894.0, 2482.0], [2883.0, 2474.0], [2837.0, 2458.0], [2831.0, 2438.0], [2835.0, 2413.0], [2846.0, 2402.0], [2867.0, 2396.0], [2871.0, 2429.0], [2879.0, 2431.0], [2893.0, 2446.0], [2908.0, 2456.0], [2916.0, 2454.0], [2915.0, 2462.0], [2921.0, 2458.0], [2921.0, 2466.0], [2933.0, 2479.0], [2948.0, 2508.0], [2953.0, 2528.0], [2969.0, 2552.0], [2977.0, 2545.0], [2983.0, 2537.0], [3002.0, 2542.0], [3006.0, 2550.0], [2997.0, 2553.0], [2989.0, 2549.0], [2985.0, 2541.0], [2987.0, 2551.0], [2985.0, 2566.0], [2992.0, 2579.0], [3001.0, 2575.0], [3009.0, 2575.0], [3018.0, 2574.0], [3010.0, 2576.0], [3000.0, 2577.0], [3000.0, 2592.0], [3016.0, 2602.0], [3028.0, 2613.0], [3038.0, 2609.0], [3040.0, 2599.0], [3040.0, 2608.0], [3050.0, 2609.0], [3048.0, 2621.0], [3061.0, 2620.0], [3053.0, 2618.0], [3047.0, 2631.0], [3058.0, 2650.0], [3073.0, 2662.0], [3098.0, 2699.0], [3105.0, 2704.0], [3113.0, 2704.0], [3121.0, 2730.0], [3129.0, 2733.0], [3141.0, 2747.0], [3142.0, 2730.0], [3151.0, 2735.0], [3143.0, 2740.0], [3145.0, 2752.0], [3143.0, 2763.0], [3167.0, 2789.0], [3162.0, 2777.0], [3185.0, 2754.0], [3174.0, 2766.0], [3182.0, 2791.0], [3188.0, 2781.0], [3189.0, 2796.0], [3199.0, 2796.0], [3213.0, 2777.0], [3205.0, 2791.0], [3193.0, 2803.0], [3191.0, 2815.0], [3196.0, 2826.0], [3197.0, 2807.0], [3208.0, 2815.0], [3205.0, 2824.0], [3213.0, 2823.0], [3203.0, 2827.0], [3212.0, 2834.0], [3224.0, 2832.0], [3218.0, 2821.0], [3226.0, 2827.0], [3226.0, 2819.0], [3225.0, 2790.0], [3230.0, 2801.0], [3233.0, 2799.0], [3233.0, 2821.0], [3227.0, 2837.0], [3226.0, 2849.0], [3239.0, 2846.0], [3247.0, 2848.0], [3259.0, 2865.0], [3269.0, 2916.0], [3305.0, 2899.0], [3302.0, 2889.0], [3293.0, 2891.0], [3295.0, 2883.0], [3296.0, 2875.0], [3297.0, 2884.0], [3307.0, 2882.0], [3306.0, 2890.0], [3316.0, 2890.0], [3321.0, 2882.0], [3329.0, 2881.0], [3328.0, 2888.0], [3343.0, 2888.0], [3329.0, 2890.0], [3317.0, 2894.0], [3305.0, 2902.0], [3318.0, 2906.0], [3327.0, 2908.0], [3367.0, 2899.0], [3388.0, 2886.0], [3386.0, 2878.0], [3378.0, 2880.0], [3367.0, 2878.0], [3368.0, 2870.0], [3379.0, 2864.0], [3383.0, 2876.0], [3391.0, 2882.0], [3401.0, 2872.0], [3401.0, 2881.0], [3430.0, 2880.0], [3438.0, 2885.0], [3439.0, 2873.0], [3427.0, 2875.0], [3417.0, 2868.0], [3441.0, 2869.0], [3442.0, 2853.0], [3437.0, 2832.0], [3424.0, 2828.0], [3438.0, 2827.0], [3427.0, 2825.0], [3431.0, 2815.0], [3420.0, 2824.0], [3415.0, 2836.0], [3415.0, 2825.0], [3425.0, 2816.0], [3414.0, 2817.0], [3422.0, 2811.0], [3433.0, 2808.0], [3426.0, 2794.0], [3408.0, 2799.0], [3425.0, 2803.0], [3395.0, 2803.0], [3389.0, 2816.0], [3394.0, 2802.0], [3380.0, 2796.0], [3381.0, 2807.0], [3370.0, 2802.0], [3365.0, 2812.0], [3370.0, 2816.0], [3362.0, 2816.0], [3368.0, 2825.0], [3359.0, 2823.0], [3350.0, 2818.0], [3348.0, 2835.0], [3356.0, 2846.0], [3345.0, 2833.0], [3336.0, 2843.0], [3328.0, 2844.0], [3318.0, 2847.0], [3329.0, 2841.0], [3338.0, 2831.0], [3336.0, 2816.0], [3327.0, 2813.0], [3319.0, 2815.0], [3328.0, 2808.0], [3337.0, 2808.0], [3355.0, 2808.0], [3359.0, 2799.0], [3343.0, 2801.0], [3352.0, 2795.0], [3346.0, 2790.0], [3356.0, 2790.0], [3367.0, 2791.0], [3353.0, 2779.0], [3349.0, 2783.0], [3340.0, 2783.0], [3331.0, 2793.0], [3319.0, 2783.0], [3295.0, 2799.0], [3299.0, 2791.0], [3319.0, 2780.0], [3315.0, 2769.0], [3306.0, 2770.0], [3310.0, 2751.0], [3300.0, 2746.0], [3301.0, 2738.0], [3313.0, 2746.0], [3317.0, 2758.0], [3317.0, 2767.0], [3323.0, 2777.0], [3331.0, 2776.0], [3345.0, 2775.0], [3336.0, 2774.0], [3343.0, 2760.0], [3351.0, 2762.0], [3356.0, 2762.0], [3356.0, 2748.0], [3363.0, 2757.0], [3363.0, 2768.0], [3367.0, 2776.0], [3375.0, 2776.0], [3370.0, 2758.0], [3381.0, 2774.0], [3391.0, 2778.0], [3399.0, 2779.0], [3407.0, 2772.0], [3400.0, 2764.0], [3398.0, 2753.0], [3402.0, 2744.0], [3385.0, 2729.0], [3373.0, 2717.0], [3358.0, 2695.0], [3357.0, 2704.0], [3360.0, 2719.0], [3350.0, 2715.0], [3352.0, 2705.0], [3338.0, 2709.0], [3335.0, 2701.0], [3327.0, 2696.0], [3317.0, 2706.0], [3309.0, 2704.0], [3294.0, 2708.0], [3303.0, 2699.0], [3295.0, 2693.0], [3297.0, 2680.0], [3301.0, 2683.0], [3301.0, 2691.0], [3306.0, 2699.0], [3319.0, 2697.0], [3322.0, 2681.0], [3324.0, 2671.0], [3328.0, 2667.0], [3328.0, 2659.0], [3327.0, 2648.0], [3315.0, 2651.0], [3312.0, 2643.0], [3300.0, 2645.0], [3292.0, 2633.0], [3278.0, 2629.0], [3289.0, 2630.0], [3285.0, 2621.0], [3284.0, 2608.0], [3294.0, 2620.0], [3307.0, 2636.0], [3316.0, 2629.0], [3320.0, 2641.0], [3329.0, 2640.0], [3337.0, 2639.0], [3341.0, 2653.0], [3350.0, 2655.0], [3349.0, 2660.0], [3349.0, 2669.0], [3381.0, 2667.0], [3390.0, 2680.0], [3401.0, 2691.0], [3411.0, 2689.0], [3408.0, 2679.0], [3397.0, 2670.0], [3399.0, 2644.0], [3391.0, 2648.0], [3394.0, 2640.0], [3385.0, 2640.0], [3389.0, 2631.0], [3397.0, 2636.0], [3401.0, 2628.0], [3399.0, 2616.0], [3375.0, 2612.0], [3364.0, 2627.0], [3371.0, 2610.0], [3353.0, 2606.0], [3329.0, 2611.0], [3346.0, 2599.0], [3334.0, 2590.0], [3347.0, 2592.0], [3346.0, 2578.0], [3338.0, 2569.0], [3350.0, 2570.0], [3354.0, 2559.0], [3344.0, 2544.0], [3359.0, 2538.0], [3360.0, 2531.0], [3370.0, 2531.0], [3367.0, 2539.0], [3356.0, 2542.0], [3361.0, 2560.0], [3364.0, 2573.0], [3354.0, 2580.0], [3360.0, 2593.0], [3377.0, 2597.0], [3384.0, 2589.0], [3394.0, 2599.0], [3405.0, 2601.0], [3418.0, 2599.0], [3399.0, 2582.0], [3407.0, 2577.0], [3400.0, 2559.0], [3409.0, 2572.0], [3410.0, 2581.0], [3421.0, 2585.0], [3426.0, 2574.0], [3420.0, 2588.0], [3432.0, 2583.0], [3429.0, 2592.0], [3425.0, 2602.0], [3428.0, 2615.0], [3436.0, 2615.0], [3428.0, 2619.0], [3427.0, 2630.0], [3437.0, 2630.0], [3434.0, 2639.0], [3434.0, 2650.0], [3442.0, 2658.0], [3452.0, 2649.0], [3444.0, 2661.0], [3453.0, 2665.0], [3457.0, 2656.0], [3454.0, 2664.0], [3465.0, 2667.0], [3468.0, 2659.0], [3466.0, 2670.0], [3470.0, 2679.0], [3482.0, 2674.0], [3480.0, 2684.0], [3489.0, 2685.0], [3498.0, 2687.0], [3491.0, 2678.0], [3501.0, 2679.0], [3505.0, 2668.0], [3507.0, 2653.0], [3500.0, 2643.0], [3493.0, 2633.0], [3485.0, 2636.0], [3494.0, 2632.0], [3485.0, 2629.0], [3488.0, 2619.0], [3496.0, 2614.0], [3493.0, 2603.0], [3478.0, 2605.0], [3479.0, 2595.0], [3470.0, 2593.0], [3472.0, 2582.0], [3470.0, 2565.0], [3479.0, 2559.0], [3482.0, 2550.0], [3474.0, 2548.0], [3482.0, 2547.0], [3490.0, 2542.0], [3487.0, 2557.0], [3478.0, 2571.0], [3477.0, 2584.0], [3484.0, 2594.0], [3492.0, 2596.0], [3500.0, 2582.0], [3497.0, 2593.0], [3501.0, 2602.0], [3512.0, 2604.0], [3516.0, 2596.0], [3520.0, 2587.0], [3530.0, 2577.0], [3523.0, 2591.0], [3523.0, 2600.0], [3514.0, 2604.0], [3516.0, 2612.0], [3500.0, 2627.0], [3512.0, 2629.0], [3511.0, 2637.0], [3519.0, 2635.0], [3518.0, 2644.0], [3533.0, 2642.0], [3519.0, 2654.0], [3532.0, 2657.0], [3524.0, 2660.0], [3526.0, 2670.0], [3518.0, 2669.0], [3518.0, 2677.0], [3516.0, 2689.0], [3514.0, 2701.0], [3525.0, 2717.0], [3533.0, 2718.0], [3538.0, 2726.0], [3540.0, 2737.0], [3549.0, 2734.0], [3560.0, 2720.0], [3567.0, 2728.0], [3556.0, 2732.0], [3559.0, 2742.0], [3551.0, 2739.0], [3558.0, 2764.0], [3570.0, 2767.0], [3570.0, 2759.0], [3579.0, 2759.0], [3571.0, 2766.0], [3583.0, 2774.0], [3594.0, 2769.0], [3594.0, 2783.0], [3603.0, 2800.0], [3609.0, 2787.0], [3610.0, 2762.0], [3601.0, 2769.0], [3598.0, 2765.0], [3598.0, 2754.0], [3596.0, 2738.0], [3597.0, 2748.0], [3609.0, 2748.0], [3606.0, 2732.0], [3598.0, 2725.0], [3609.0, 2718.0], [3600.0, 2719.0], [3599.0, 2709.0], [3589.0, 2706.0], [3579.0, 2715.0], [3581.0, 2704.0], [3577.0, 2704.0], [3569.0, 2704.0], [3563.0, 2690.0], [3553.0, 2693.0], [3544.0, 2679.0], [3554.0, 2690.0], [3562.0, 2689.0], [3570.0, 2697.0], [3580.0, 2698.0], [3592.0, 2702.0], [3604.0, 2698.0], [3607.0, 2688.0], [3608.0, 2700.0], [3621.0, 2697.0], [3619.0, 2711.0], [3632.0, 2698.0], [3629.0, 2685.0], [3631.0, 2677.0], [3619.0, 2676.0], [3610.0, 2682.0], [3605.0, 2673.0], [3593.0, 2678.0], [3585.0, 2668.0], [3601.0, 2672.0], [3599.0, 2660.0], [3608.0, 2672.0], [3609.0, 2657.0], [3600.0, 2652.0], [3613.0, 2660.0], [3622.0, 2669.0], [3630.0, 2671.0], [3642.0, 2673.0], [3633.0, 2684.0], [3644.0, 2679.0], [3645.0, 2653.0], [3637.0, 2654.0], [3620.0, 2651.0], [3615.0, 2641.0], [3627.0, 2650.0], [3635.0, 2650.0], [3630.0, 2638.0], [3632.0, 2623.0], [3642.0, 2619.0], [3635.0, 2561.0], [3624.0, 2537.0], [3622.0, 2551.0], [3620.0, 2541.0], [3615.0, 2550.0], [3608.0, 2558.0], [3607.0, 2528.0], [3617.0, 2529.0], [3600.0, 2513.0], [3595.0, 2527.0], [3601.0, 2539.0], [3589.0, 2554.0], [3590.0, 2542.0], [3589.0, 2534.0], [3586.0, 2522.0], [3563.0, 2523.0], [3562.0, 2531.0], [3554.0, 2543.0], [3554.0, 2531.0], [3561.0, 2522.0], [3550.0, 2519.0], [3544.0, 2527.0], [3548.0, 2517.0], [3560.0, 2518.0], [3568.0, 2519.0], [3581.0, 2516.0], [3585.0, 2503.0], [3598.0, 2501.0], [3573.0, 2481.0], [3565.0, 2490.0], [3554.0, 2492.0], [3563.0, 2484.0], [3568.0, 2474.0], [3547.0, 2476.0], [3526.0, 2474.0], [3517.0, 2477.0], [3507.0, 2479.0], [3493.0, 2475.0], [3484.0, 2471.0], [3495.0, 2474.0], [3508.0, 2477.0], [3508.0, 2468.0], [3517.0, 2474.0], [3523.0, 2466.0], [3518.0, 2443.0], [3497.0, 2443.0], [3489.0, 2439.0], [3480.0, 2421.0], [3481.0, 2410.0], [3467.0, 2413.0], [3459.0, 2408.0], [3447.0, 2407.0], [3437.0, 2399.0], [3424.0, 2400.0], [3428.0, 2409.0], [3430.0, 2421.0], [3427.0, 2413.0], [3419.0, 2411.0], [3418.0, 2396.0], [3411.0, 2409.0], [3414.0, 2422.0], [3402.0, 2436.0], [3387.0, 2439.0], [3376.0, 2431.0], [3361.0, 2432.0], [3347.0, 2423.0], [3317.0, 2429.0], [3334.0, 2417.0], [3329.0, 2401.0], [3311.0, 2404.0], [3301.0, 2397.0], [3301.0, 2387.0], [3293.0, 2389.0], [3283.0, 2383.0], [3271.0, 2387.0], [3268.0, 2395.0], [3272.0, 2377.0], [3260.0, 2365.0], [3249.0, 2364.0], [3240.0, 2366.0], [3231.0, 2347.0], [3222.0, 2347.0], [3209.0, 2346.0], [3212.0, 2334.0], [3204.0, 2330.0], [3200.0, 2350.0], [3190.0, 2349.0], [3184.0, 2339.0], [3170.0, 2340.0], [3166.0, 2332.0], [3155.0, 2322.0], [3161.0, 2311.0], [3147.0, 2306.0], [3142.0, 2291.0], [3149.0, 2303.0], [3166.0, 2307.0], [3174.0, 2317.0], [3169.0, 2327.0], [3182.0, 2330.0], [3196.0, 2339.0], [3195.0, 2331.0], [3201.0, 2325.0], [3211.0, 2325.0], [3218.0, 2331.0], [3218.0, 2339.0], [3233.0, 2339.0], [3243.0, 2357.0], [3256.0, 2355.0], [3254.0, 2340.0], [3262.0, 2343.0], [3268.0, 2359.0], [3277.0, 2353.0], [3278.0, 2366.0], [3280.0, 2378.0], [3291.0, 2381.0], [3308.0, 2374.0], [3319.0, 2387.0], [3335.0, 2392.0], [3342.0, 2407.0], [3350.0, 2407.0], [3359.0, 2413.0], [3359.0, 2404.0], [3369.0, 2396.0], [3380.0, 2397.0], [3385.0, 2406.0], [3390.0, 2428.0], [3402.0, 2421.0], [3397.0, 2410.0], [3400.0, 2382.0], [3401.0, 2384.0], [3401.0, 2407.0], [3402.0, 2412.0], [3402.0, 2398.0], [3411.0, 2376.0], [3422.0, 2375.0], [3434.0, 2386.0], [3430.0, 2378.0], [3432.0, 2369.0], [3444.0, 2362.0], [3432.0, 2371.0], [3435.0, 2379.0], [3464.0, 2378.0], [3479.0, 2387.0], [3491.0, 2382.0], [3502.0, 2383.0], [3510.0, 2380.0], [3513.0, 2371.0], [3522.0, 2373.0], [3524.0, 2403.0], [3528.0, 2369.0], [3517.0, 2371.0], [3509.0, 2367.0], [3509.0, 2357.0], [3515.0, 2366.0], [3524.0, 2364.0], [3518.0, 2353.0], [3531.0, 2364.0], [3533.0, 2352.0], [3537.0, 2363.0], [3546.0, 2366.0], [3554.0, 2363.0], [3554.0, 2355.0], [3566.0, 2354.0], [3571.0, 2344.0], [3598.0, 2233.0], [3579.0, 2200.0], [3566.0, 2194.0], [3546.0, 2192.0], [3554.0, 2193.0], [3554.0, 2207.0], [3543.0, 2208.0], [3541.0, 2217.0], [3520.0, 2210.0], [3488.0, 2216.0], [3485.0, 2205.0], [3477.0, 2200.0], [3467.0, 2190.0], [3481.0, 2203.0], [3496.0, 2201.0], [3499.0, 2209.0], [3511.0, 2200.0], [3522.0, 2207.0], [3520.0, 2190.0], [3520.0, 2178.0], [3511.0, 2168.0], [3505.0, 2180.0], [3505.0, 2172.0], [3492.0, 2171.0], [3473.0, 2167.0], [3486.0, 2168.0], [3498.0, 2168.0], [3510.0, 2164.0], [3517.0, 2152.0], [3514.0, 2147.0], [3506.0, 2147.0], [3500.0, 2156.0], [3506.0, 2143.0], [3495.0, 2141.0], [3488.0, 2153.0], [3487.0, 2145.0], [3494.0, 2137.0], [3485.0, 2140.0], [3485.0, 2133.0], [3476.0, 2133.0], [3486.0, 2128.0], [3475.0, 2121.0], [3471.0, 2117.0], [3460.0, 2117.0], [3472.0, 2115.0], [3472.0, 2106.0], [3473.0, 2119.0], [3482.0, 2117.0], [3484.0, 2125.0], [3496.0, 2124.0], [3497.0, 2132.0], [3505.0, 2134.0], [3503.0, 2123.0], [3509.0, 2134.0], [3513.0, 2126.0], [3518.0, 2128.0], [3518.0, 2137.0], [3526.0, 2132.0], [3519.0, 2115.0], [3509.0, 2110.0], [3498.0, 2108.0], [3498.0, 2096.0], [3492.0, 2101.0], [3481.0, 2101.0], [3491.0, 2098.0], [3496.0, 2087.0], [3487.0, 2088.0], [3495.0, 2081.0], [3482.0, 2070.0], [3496.0, 2080.0], [3500.0, 2065.0], [3490.0, 2056.0], [3500.0, 2056.0], [3508.0, 2059.0], [3506.0, 2067.0], [3498.0, 2076.0], [3498.0, 2091.0], [3507.0, 2088.0], [3505.0, 2097.0], [3517.0, 2100.0], [3519.0, 2092.0], [3518.0, 2103.0], [3527.0, 2108.0], [3526.0, 2093.0], [3538.0, 2092.0], [3534.0, 2100.0], [3542.0, 2105.0], [3550.0, 2110.0], [3544.0, 2102.0], [3549.0, 2090.0], [3540.0, 2077.0], [3549.0, 2082.0], [3552.0, 2090.0], [3557.0, 2103.0], [3566.0, 2086.0], [3561.0, 2073.0], [3556.0, 2064.0], [3552.0, 2066.0], [3552.0, 2051.0], [3542.0, 2053.0], [3533.0, 2049.0], [3543.0, 2051.0], [3537.0, 2042.0], [3540.0, 2034.0], [3531.0, 2029.0], [3522.0, 2028.0], [3513.0, 2035.0], [3525.0, 2023.0], [3536.0, 2025.0], [3526.0, 2013.0], [3512.0, 2006.0], [3508.0, 2017.0], [3500.0, 2020.0], [3508.0, 2012.0], [3516.0, 2003.0], [3502.0, 1994.0], [3514.0, 1989.0], [3517.0, 1983.0], [3517.0, 1975.0], [3516.0, 1967.0], [3508.0, 1962.0], [3517.0, 1962.0], [3519.0, 1973.0], [3516.0, 1997.0], [3527.0, 2002.0], [3536.0, 2003.0], [3534.0, 2011.0], [3549.0, 2017.0], [3558.0, 2026.0], [3556.0, 2007.0], [3548.0, 2004.0], [3545.0, 1994.0], [3553.0, 1978.0], [3544.0, 1966.0], [3535.0, 1967.0], [3531.0, 1959.0], [3539.0, 1959.0], [3555.0, 1966.0], [3555.0, 1944.0], [3542.0, 1942.0], [3560.0, 1943.0], [3571.0, 1931.0], [3581.0, 1927.0], [3586.0, 1940.0], [3590.0, 1931.0], [3574.0, 1929.0], [3560.0, 1921.0], [3555.0, 1932.0], [3539.0, 1935.0], [3546.0, 1927.0], [3538.0, 1924.0], [3544.0, 1916.0], [3530.0, 1914.0], [3520.0, 1913.0], [3512.0, 1913.0], [3508.0, 1903.0], [3496.0, 1897.0], [3485.0, 1904.0], [3490.0, 1895.0], [3503.0, 1892.0], [3512.0, 1906.0], [3527.0, 1911.0], [3541.0, 1909.0], [3551.0, 1914.0], [3552.0, 1905.0], [3538.0, 1895.0], [3556.0, 1904.0], [3558.0, 1878.0], [3548.0, 1878.0], [3539.0, 1878.0], [3528.0, 1875.0], [3518.0, 1870.0], [3529.0, 1872.0], [3525.0, 1861.0], [3541.0, 1869.0], [3551.0, 1872.0], [3559.0, 1857.0], [3543.0, 1861.0], [3539.0, 1851.0], [3534.0, 1838.0], [3521.0, 1847.0], [3531.0, 1841.0], [3522.0, 1837.0], [3532.0, 1837.0], [3525.0, 1826.0], [3528.0, 1818.0], [3518.0, 1812.0], [3505.0, 1820.0], [3497.0, 1818.0], [3510.0, 1825.0], [3502.0, 1840.0], [3504.0, 1829.0], [3491.0, 1826.0], [3490.0, 1814.0], [3483.0, 1826.0], [3471.0, 1841.0], [3473.0, 1833.0], [3473.0, 1823.0], [3481.0, 1813.0], [3472.0, 1818.0], [3459.0, 1816.0], [3453.0, 1810.0], [3453.0, 1818.0], [3445.0, 1815.0], [3436.0, 1822.0], [3432.0, 1812.0], [3454.0, 1807.0], [3465.0, 1806.0], [3460.0, 1795.0], [3454.0, 1784.0], [3445.0, 1790.0], [3443.0, 1781.0], [3434.0, 1784.0], [3423.0, 1781.0], [3431.0, 1775.0], [3418.0, 1777.0], [3400.0, 1769.0], [3402.0, 1777.0], [3390.0, 1773.0], [3379.0, 1765.0], [3370.0, 1766.0], [3364.0, 1753.0], [3375.0, 1755.0], [3386.0, 1766.0], [3395.0, 1767.0], [3408.0, 1765.0], [3418.0, 1770.0], [3434.0, 1766.0], [3437.0, 1758.0], [3443.0, 1767.0], [3439.0, 1776.0], [3448.0, 1779.0], [3458.0, 1777.0], [3469.0, 1784.0], [3472.0, 1779.0], [3472.0, 1764.0], [3473.0, 1774.0], [3472.0, 1782.0], [3473.0, 1800.0], [3472.0, 1809.0], [3480.0, 1797.0], [3503.0, 1806.0], [3499.0, 1791.0], [3509.0, 1789.0], [3506.0, 1802.0], [3527.0, 1796.0], [3532.0, 1808.0], [3543.0, 1798.0], [3551.0, 1795.0], [3540.0, 1804.0], [3548.0, 1807.0], [3541.0, 1825.0], [3547.0, 1827.0], [3547.0, 1842.0], [3547.0, 1823.0], [3559.0, 1807.0], [3555.0, 1819.0], [3553.0, 1829.0], [3554.0, 1837.0], [3564.0, 1837.0], [3568.0, 1825.0], [3572.0, 1834.0], [3580.0, 1837.0], [3575.0, 1839.0], [3575.0, 1849.0], [3583.0, 1859.0], [3593.0, 1858.0], [3593.0, 1842.0], [3597.0, 1834.0], [3589.0, 1831.0], [3582.0, 1821.0], [3590.0, 1826.0], [3593.0, 1810.0], [3585.0, 1807.0], [3584.0, 1796.0], [3593.0, 1793.0], [3591.0, 1801.0], [3605.0, 1804.0], [3595.0, 1812.0], [3597.0, 1821.0], [3598.0, 1829.0], [3606.0, 1819.0], [3615.0, 1815.0], [3611.0, 1825.0], [3619.0, 1822.0], [3614.0, 1831.0], [3605.0, 1826.0], [3612.0, 1836.0], [3604.0, 1831.0], [3602.0, 1839.0], [3607.0, 1854.0], [3603.0, 1864.0], [3595.0, 1865.0], [3597.0, 1875.0], [3606.0, 1874.0], [3619.0, 1866.0], [3616.0, 1855.0], [3624.0, 1858.0], [3621.0, 1847.0], [3623.0, 1839.0], [3628.0, 1848.0], [3639.0, 1835.0], [3641.0, 1811.0], [3632.0, 1816.0], [3642.0, 1807.0], [3626.0, 1806.0], [3634.0, 1802.0], [3644.0, 1801.0], [3660.0, 1733.0], [3651.0, 1755.0], [3640.0, 1770.0], [3643.0, 1762.0], [3646.0, 1752.0], [3644.0, 1740.0], [3638.0, 1751.0], [3630.0, 1748.0], [3637.0, 1744.0], [3637.0, 1734.0], [3626.0, 1738.0], [3605.0, 1744.0], [3608.0, 1756.0], [3619.0, 1757.0], [3618.0, 1767.0], [3608.0, 1758.0], [3609.0, 1770.0], [3605.0, 1754.0], [3600.0, 1771.0], [3592.0, 1766.0], [3596.0, 1758.0], [3600.0, 1737.0], [3588.0, 1746.0], [3591.0, 1736.0], [3579.0, 1738.0], [3565.0, 1734.0], [3573.0, 1729.0], [3561.0, 1717.0], [3552.0, 1723.0], [3559.0, 1714.0], [3555.0, 1704.0], [3547.0, 1696.0], [3538.0, 1694.0], [3548.0, 1694.0], [3553.0, 1685.0], [3550.0, 1694.0], [3556.0, 1702.0], [3567.0, 1698.0], [3559.0, 1708.0], [3563.0, 1717.0], [3576.0, 1713.0], [3577.0, 1722.0], [3583.0, 1732.0], [3600.0, 1729.0], [3592.0, 1713.0], [3597.0, 1722.0], [3605.0, 1734.0], [3615.0, 1732.0], [3618.0, 1724.0], [3604.0, 1710.0], [3599.0, 1702.0], [3613.0, 1714.0], [3621.0, 1712.0], [3620.0, 1721.0], [3631.0, 1723.0], [3640.0, 1723.0], [3648.0, 1728.0], [3661.0, 1732.0], [3640.0, 1721.0], [3619.0, 1694.0], [3516.0, 1632.0], [3453.0, 1610.0], [3456.0, 1619.0], [3458.0, 1628.0], [3449.0, 1613.0], [3446.0, 1622.0], [3441.0, 1634.0], [3455.0, 1642.0], [3446.0, 1647.0], [3449.0, 1661.0], [3438.0, 1671.0], [3449.0, 1673.0], [3456.0, 1682.0], [3444.0, 1688.0], [3453.0, 1680.0], [3440.0, 1676.0], [3430.0, 1676.0], [3446.0, 1660.0], [3440.0, 1652.0], [3438.0, 1642.0], [3430.0, 1645.0], [3438.0, 1641.0], [3422.0, 1639.0], [3422.0, 1631.0], [3442.0, 1621.0], [3426.0, 1621.0], [3428.0, 1616.0], [3436.0, 1616.0], [3445.0, 1610.0], [3421.0, 1602.0], [3420.0, 1611.0], [3420.0, 1601.0], [3403.0, 1594.0], [3406.0, 1602.0], [3404.0, 1611.0], [3396.0, 1622.0], [3403.0, 1632.0], [3398.0, 1624.0], [3387.0, 1616.0], [3395.0, 1616.0], [3397.0, 1608.0], [3389.0, 1608.0], [3389.0, 1599.0], [3403.0, 1593.0], [3385.0, 1573.0], [3377.0, 1568.0], [3373.0, 1581.0], [3377.0, 1589.0], [3374.0, 1593.0], [3374.0, 1585.0], [3372.0, 1576.0], [3363.0, 1572.0], [3356.0, 1582.0], [3358.0, 1573.0], [3366.0, 1567.0], [3359.0, 1554.0], [3350.0, 1562.0], [3334.0, 1554.0], [3341.0, 1563.0], [3341.0, 1588.0], [3323.0, 1598.0], [3317.0, 1588.0], [3307.0, 1601.0], [3315.0, 1612.0], [3305.0, 1605.0], [3305.0, 1615.0], [3300.0, 1624.0], [3305.0, 1626.0], [3305.0, 1644.0], [3296.0, 1645.0], [3286.0, 1656.0], [3295.0, 1648.0], [3301.0, 1638.0], [3297.0, 1627.0], [3299.0, 1612.0], [3287.0, 1608.0], [3275.0, 1617.0], [3290.0, 1604.0], [3303.0, 1608.0], [3304.0, 1598.0], [3315.0, 1583.0], [3303.0, 1587.0], [3313.0, 1579.0], [3325.0, 1582.0], [3331.0, 1566.0], [3315.0, 1569.0], [3318.0, 1558.0], [3310.0, 1561.0], [3312.0, 1552.0], [3300.0, 1550.0], [3296.0, 1562.0], [3292.0, 1552.0], [3281.0, 1551.0], [3282.0, 1562.0], [3274.0, 1565.0], [3269.0, 1575.0], [3272.0, 1565.0], [3263.0, 1568.0], [3264.0, 1565.0], [3255.0, 1565.0], [3269.0, 1563.0], [3277.0, 1562.0], [3275.0, 1546.0], [3265.0, 1547.0], [3264.0, 1537.0], [3274.0, 1542.0], [3284.0, 1546.0], [3288.0, 1536.0], [3293.0, 1547.0], [3297.0, 1537.0], [3308.0, 1543.0], [3318.0, 1541.0], [3298.0, 1531.0], [3306.0, 1526.0], [3301.0, 1514.0], [3313.0, 1516.0], [3311.0, 1527.0], [3322.0, 1526.0], [3327.0, 1535.0], [3331.0, 1535.0], [3331.0, 1520.0], [3323.0, 1520.0], [3314.0, 1511.0], [3306.0, 1510.0], [3320.0, 1504.0], [3276.0, 1492.0], [3255.0, 1480.0], [3224.0, 1481.0], [3240.0, 1492.0], [3224.0, 1490.0], [3224.0, 1499.0], [3222.0, 1507.0], [3231.0, 1504.0], [3231.0, 1516.0], [3223.0, 1522.0], [3239.0, 1524.0], [3230.0, 1526.0], [3222.0, 1526.0], [3218.0, 1537.0], [3220.0, 1545.0], [3224.0, 1555.0], [3225.0, 1569.0], [3217.0, 1558.0], [3215.0, 1549.0], [3219.0, 1541.0], [3206.0, 1544.0], [3212.0, 1535.0], [3211.0, 1527.0], [3218.0, 1517.0], [3205.0, 1514.0], [3198.0, 1522.0], [3186.0, 1524.0], [3176.0, 1531.0], [3182.0, 1522.0], [3185.0, 1516.0], [3193.0, 1516.0], [3204.0, 1509.0], [3202.0, 1501.0], [3214.0, 1507.0], [3211.0, 1491.0], [3199.0, 1493.0], [3190.0, 1503.0], [3193.0, 1495.0], [3196.0, 1484.0], [3188.0, 1481.0], [3180.0, 1486.0], [3173.0, 1482.0], [3173.0, 1498.0], [3160.0, 1504.0], [3156.0, 1514.0], [3143.0, 1524.0], [3154.0, 1514.0], [3158.0, 1505.0], [3170.0, 1495.0], [3167.0, 1483.0], [3161.0, 1481.0], [3169.0, 1481.0], [3168.0, 1473.0], [3158.0, 1468.0], [3150.0, 1466.0], [3158.0, 1465.0], [3171.0, 1475.0], [3181.0, 1477.0], [3177.0, 1469.0], [3186.0, 1476.0], [3194.0, 1476.0], [3205.0, 1475.0], [3202.0, 1457.0], [3194.0, 1451.0], [3190.0, 1460.0], [3192.0, 1450.0], [3184.0, 1454.0], [3183.0, 1446.0], [3171.0, 1440.0], [3185.0, 1439.0], [3193.0, 1446.0], [3190.0, 1436.0], [3188.0, 1426.0], [3190.0, 1414.0], [3194.0, 1425.0], [3199.0, 1436.0], [3204.0, 1448.0], [3208.0, 1457.0], [3220.0, 1459.0], [3217.0, 1447.0], [3235.0, 1444.0], [3244.0, 1445.0], [3227.0, 1412.0], [3223.0, 1389.0], [3186.0, 1367.0], [3158.0, 1353.0], [3158.0, 1372.0], [3147.0, 1369.0], [3145.0, 1383.0], [3143.0, 1375.0], [3150.0, 1363.0], [3158.0, 1350.0], [3136.0, 1332.0], [3138.0, 1340.0], [3132.0, 1337.0], [3132.0, 1346.0], [3128.0, 1336.0], [3117.0, 1348.0], [3118.0, 1340.0], [3127.0, 1336.0], [3135.0, 1332.0], [3126.0, 1320.0], [3109.0, 1322.0], [3109.0, 1331.0], [3105.0, 1319.0], [3106.0, 1311.0], [3108.0, 1321.0], [3120.0, 1317.0], [3116.0, 1308.0], [3126.0, 1318.0], [3126.0, 1309.0], [3130.0, 1320.0], [3125.0, 1288.0], [3113.0, 1271.0], [3115.0, 1255.0], [3090.0, 1247.0], [3072.0, 1252.0], [3071.0, 1265.0], [3084.0, 1266.0], [3084.0, 1297.0], [3074.0, 1305.0], [3083.0, 1300.0], [3083.0, 1308.0], [3080.0, 1324.0], [3078.0, 1335.0], [3066.0, 1334.0], [3063.0, 1342.0], [3065.0, 1357.0], [3059.0, 1348.0], [3047.0, 1347.0], [3046.0, 1355.0], [3046.0, 1344.0], [3023.0, 1344.0], [3034.0, 1343.0], [3052.0, 1344.0], [3049.0, 1336.0], [3061.0, 1333.0], [3069.0, 1328.0], [3071.0, 1320.0], [3071.0, 1303.0], [3066.0, 1293.0], [3056.0, 1295.0], [3057.0, 1306.0], [3043.0, 1309.0], [3054.0, 1297.0], [3042.0, 1300.0], [3055.0, 1292.0], [3057.0, 1286.0], [3043.0, 1286.0], [3056.0, 1282.0], [3048.0, 1278.0], [3036.0, 1255.0], [3025.0, 1249.0], [3008.0, 1240.0], [3003.0, 1234.0], [2984.0, 1234.0], [2981.0, 1253.0], [2954.0, 1274.0], [2977.0, 1258.0], [2984.0, 1267.0], [2971.0, 1269.0], [2980.0, 1273.0], [2975.0, 1286.0], [2988.0, 1280.0], [2990.0, 1289.0], [3007.0, 1293.0], [2995.0, 1291.0], [2993.0, 1302.0], [2992.0, 1293.0], [2982.0, 1289.0], [2984.0, 1297.0], [2970.0, 1288.0], [2968.0, 1277.0], [2965.0, 1291.0], [2964.0, 1300.0], [2976.0, 1304.0], [2975.0, 1319.0], [2971.0, 1331.0], [2965.0, 1340.0], [2957.0, 1342.0], [2942.0, 1338.0], [2938.0, 1353.0], [2949.0, 1368.0], [2953.0, 1376.0], [2962.0, 1390.0], [2972.0, 1389.0], [2983.0, 1398.0], [2986.0, 1407.0], [2994.0, 1410.0], [2983.0, 1409.0], [2975.0, 1396.0], [2950.0, 1384.0], [2939.0, 1386.0], [2950.0, 1380.0], [2934.0, 1360.0], [2931.0, 1351.0], [2932.0, 1343.0], [2921.0, 1341.0], [2906.0, 1338.0], [2937.0, 1337.0], [2942.0, 1329.0], [2953.0, 1332.0], [2959.0, 1323.0], [2948.0, 1315.0], [2961.0, 1320.0], [2952.0, 1311.0], [2953.0, 1294.0], [2930.0, 1282.0], [2923.0, 1291.0], [2929.0, 1281.0], [2920.0, 1277.0], [2917.0, 1265.0], [2909.0, 1258.0], [2898.0, 1255.0], [2889.0, 1265.0], [2898.0, 1246.0], [2885.0, 1237.0], [2883.0, 1246.0], [2875.0, 1243.0], [2879.0, 1246.0], [2879.0, 1238.0], [2876.0, 1222.0], [2840.0, 1217.0], [2804.0, 1226.0], [2783.0, 1227.0], [2760.0, 1220.0], [2754.0, 1212.0], [2728.0, 1209.0], [2688.0, 1174.0], [2681.0, 1194.0], [2673.0, 1197.0], [2666.0, 1212.0], [2663.0, 1197.0], [2669.0, 1197.0], [2669.0, 1189.0], [2675.0, 1178.0], [2684.0, 1170.0], [2670.0, 1160.0], [2640.0, 1155.0], [2619.0, 1142.0], [2609.0, 1149.0], [2596.0, 1150.0], [2587.0, 1147.0], [2583.0, 1137.0], [2576.0, 1144.0], [2576.0, 1153.0], [2568.0, 1157.0], [2561.0, 1149.0], [2544.0, 1154.0], [2534.0, 1161.0], [2525.0, 1161.0], [2538.0, 1160.0], [2539.0, 1150.0], [2558.0, 1148.0], [2567.0, 1145.0], [2572.0, 1136.0], [2585.0, 1123.0], [2602.0, 1137.0], [2603.0, 1126.0], [2597.0, 1111.0], [2598.0, 1102.0], [2589.0, 1101.0], [2590.0, 1093.0], [2591.0, 1082.0], [2593.0, 1069.0], [2585.0, 1072.0], [2583.0, 1080.0], [2574.0, 1074.0], [2583.0, 1076.0], [2591.0, 1062.0], [2601.0, 1074.0], [2601.0, 1082.0], [2602.0, 1093.0], [2600.0, 1104.0], [2609.0, 1098.0], [2611.0, 1080.0], [2604.0, 1054.0], [2578.0, 1023.0], [2544.0, 1023.0], [2530.0, 1027.0], [2529.0, 1033.0], [2505.0, 1033.0], [2526.0, 1027.0], [2535.0, 1019.0], [2537.0, 1012.0], [2546.0, 1012.0], [2523.0, 975.0], [2515.0, 980.0], [2511.0, 969.0], [2506.0, 953.0], [2486.0, 957.0], [2472.0, 945.0], [2457.0, 942.0], [2456.0, 933.0], [2465.0, 935.0], [2463.0, 918.0], [2463.0, 902.0], [2474.0, 931.0], [2486.0, 931.0], [2492.0, 938.0], [2502.0, 938.0], [2515.0, 931.0], [2518.0, 918.0], [2527.0, 912.0], [2524.0, 896.0], [2528.0, 864.0], [2525.0, 842.0], [2488.0, 787.0], [2467.0, 794.0], [2447.0, 827.0], [2432.0, 843.0], [2412.0, 850.0], [2395.0, 845.0], [2373.0, 849.0], [2348.0, 848.0], [2317.0, 884.0], [2289.0, 897.0], [2251.0, 898.0], [2196.0, 916.0], [2162.0, 913.0], [2141.0, 901.0], [2129.0, 864.0], [2117.0, 844.0], [2086.0, 819.0], [2090.0, 808.0], [2087.0, 796.0], [2095.0, 793.0], [2084.0, 755.0], [2078.0, 716.0], [2073.0, 707.0], [2079.0, 661.0], [2097.0, 610.0], [2112.0, 596.0], [2108.0, 580.0], [2133.0, 565.0], [2136.0, 550.0], [2127.0, 550.0], [2142.0, 539.0], [2146.0, 514.0], [2174.0, 488.0], [2166.0, 472.0], [2162.0, 452.0], [2165.0, 443.0], [2173.0, 447.0], [2190.0, 428.0], [2257.0, 380.0], [2270.0, 380.0], [2282.0, 415.0], [2269.0, 444.0], [2243.0, 473.0], [2247.0, 483.0], [2256.0, 486.0], [2277.0, 475.0], [2272.0, 467.0], [2281.0, 454.0], [2288.0, 462.0], [2299.0, 446.0], [2312.0, 443.0], [2314.0, 434.0], [2326.0, 435.0], [2337.0, 425.0], [2345.0, 427.0], [2350.0, 438.0], [2341.0, 439.0], [2334.0, 447.0], [2325.0, 442.0], [2315.0, 455.0], [2301.0, 455.0], [2305.0, 466.0], [2299.0, 480.0], [2285.0, 473.0], [2286.0, 484.0], [2274.0, 492.0], [2266.0, 487.0], [2260.0, 498.0], [2234.0, 498.0], [2205.0, 503.0], [2190.0, 534.0], [2196.0, 523.0], [2209.0, 514.0], [2217.0, 518.0], [2217.0, 509.0], [2220.0, 518.0], [2210.0, 523.0], [2215.0, 533.0], [2201.0, 541.0], [2191.0, 547.0], [2177.0, 554.0], [2174.0, 569.0], [2165.0, 580.0], [2149.0, 636.0], [2162.0, 651.0], [2163.0, 659.0], [2155.0, 668.0], [2166.0, 690.0], [2162.0, 707.0], [2175.0, 728.0], [2162.0, 757.0], [2188.0, 823.0], [2234.0, 856.0], [2250.0, 861.0], [2278.0, 852.0], [2296.0, 836.0], [2308.0, 833.0], [2320.0, 821.0], [2351.0, 803.0], [2364.0, 785.0], [2368.0, 768.0], [2395.0, 776.0], [2415.0, 773.0], [2437.0, 742.0], [2447.0, 739.0], [2487.0, 706.0], [2485.0, 697.0], [2471.0, 699.0], [2468.0, 696.0], [2468.0, 704.0], [2457.0, 708.0], [2463.0, 700.0], [2464.0, 692.0], [2474.0, 691.0], [2483.0, 685.0], [2490.0, 664.0], [2486.0, 650.0], [2490.0, 639.0], [2506.0, 627.0], [2512.0, 612.0], [2510.0, 592.0], [2517.0, 611.0], [2513.0, 636.0], [2509.0, 647.0], [2501.0, 645.0], [2503.0, 656.0], [2504.0, 671.0], [2511.0, 685.0], [2520.0, 693.0], [2546.0, 745.0], [2550.0, 768.0], [2559.0, 790.0], [2568.0, 799.0], [2568.0, 813.0], [2573.0, 857.0], [2585.0, 874.0], [2585.0, 886.0], [2580.0, 896.0], [2596.0, 896.0], [2625.0, 914.0], [2618.0, 901.0], [2635.0, 906.0], [2648.0, 901.0], [2637.0, 910.0], [2634.0, 919.0], [2651.0, 931.0], [2658.0, 943.0], [2677.0, 950.0], [2686.0, 930.0], [2680.0, 950.0], [2685.0, 960.0], [2703.0, 960.0], [2688.0, 962.0], [2704.0, 982.0], [2694.0, 977.0], [2680.0, 969.0], [2678.0, 960.0], [2662.0, 950.0], [2656.0, 983.0], [2659.0, 992.0], [2683.0, 994.0], [2731.0, 1023.0], [2717.0, 1013.0], [2716.0, 994.0], [2718.0, 1012.0], [2730.0, 1020.0], [2738.0, 1019.0], [2748.0, 1027.0], [2744.0, 1019.0], [2753.0, 1024.0], [2767.0, 1036.0], [2778.0, 1035.0], [2841.0, 1066.0], [2856.0, 1060.0], [2849.0, 1045.0], [2856.0, 1034.0], [2855.0, 1056.0], [2872.0, 1068.0], [2855.0, 1063.0], [2860.0, 1074.0], [2869.0, 1074.0], [2876.0, 1093.0], [2870.0, 1104.0], [2878.0, 1109.0], [2887.0, 1109.0], [2902.0, 1110.0], [2911.0, 1118.0], [2920.0, 1108.0], [2922.0, 1100.0], [2909.0, 1094.0], [2898.0, 1094.0], [2894.0, 1086.0], [2892.0, 1073.0], [2897.0, 1084.0], [2903.0, 1083.0], [2903.0, 1092.0], [2913.0, 1090.0], [2922.0, 1095.0], [2942.0, 1065.0], [2934.0, 1069.0], [2940.0, 1061.0], [2933.0, 1051.0], [2925.0, 1050.0], [2915.0, 1046.0], [2924.0, 1041.0], [2933.0, 1044.0], [2946.0, 1044.0], [2947.0, 1059.0], [2955.0, 1058.0], [2955.0, 1046.0], [2967.0, 1037.0], [2959.0, 1030.0], [2941.0, 1025.0], [2958.0, 1028.0], [2956.0, 1017.0], [2948.0, 1014.0], [2960.0, 1013.0], [2961.0, 999.0], [2947.0, 988.0], [2932.0, 989.0], [2938.0, 981.0], [2948.0, 984.0], [2952.0, 976.0], [2952.0, 966.0], [2942.0, 953.0], [2943.0, 938.0], [2948.0, 940.0], [2948.0, 950.0], [2959.0, 959.0], [2962.0, 973.0], [2964.0, 983.0], [2975.0, 985.0], [2975.0, 994.0], [2978.0, 1001.0], [2987.0, 1001.0], [2979.0, 1002.0], [2978.0, 1016.0], [2972.0, 1025.0], [2980.0, 1031.0], [2984.0, 1042.0], [2976.0, 1052.0], [2979.0, 1063.0], [2953.0, 1082.0], [2957.0, 1097.0], [2979.0, 1096.0], [2988.0, 1084.0], [2989.0, 1071.0], [2991.0, 1055.0], [2986.0, 1047.0], [2997.0, 1046.0], [3014.0, 1049.0], [3014.0, 1040.0], [3010.0, 1036.0], [3019.0, 1036.0], [3022.0, 1045.0], [3037.0, 1051.0], [3052.0, 1042.0], [3054.0, 1033.0], [3069.0, 1031.0], [3077.0, 1024.0], [3084.0, 1012.0], [3072.0, 1007.0], [3064.0, 1006.0], [3056.0, 995.0], [3061.0, 998.0], [3071.0, 998.0], [3083.0, 999.0], [3092.0, 1002.0], [3096.0, 1011.0], [3087.0, 1025.0], [3088.0, 1046.0], [3083.0, 1037.0], [3073.0, 1037.0], [3068.0, 1046.0], [3056.0, 1068.0], [3036.0, 1075.0], [3028.0, 1075.0], [3004.0, 1085.0], [3006.0, 1097.0], [3031.0, 1096.0], [3057.0, 1110.0], [3073.0, 1112.0], [3073.0, 1112.0], [3088.0, 1112.0], [3145.0, 1132.0], [3142.0, 1129.0], [3160.0, 1129.0], [3147.0, 1133.0], [3172.0, 1154.0], [3196.0, 1139.0], [3183.0, 1148.0], [3174.0, 1155.0], [3199.0, 1181.0], [3204.0, 1202.0], [3208.0, 1200.0], [3208.0, 1190.0], [3204.0, 1181.0], [3213.0, 1189.0], [3215.0, 1179.0], [3218.0, 1193.0], [3231.0, 1192.0], [3228.0, 1175.0], [3234.0, 1192.0], [3244.0, 1178.0], [3238.0, 1191.0], [3251.0, 1194.0], [3225.0, 1199.0], [3231.0, 1211.0], [3223.0, 1218.0], [3227.0, 1210.0], [3213.0, 1201.0], [3205.0, 1203.0], [3225.0, 1257.0], [3230.0, 1249.0], [3231.0, 1239.0], [3231.0, 1257.0], [3240.0, 1255.0], [3233.0, 1264.0], [3252.0, 1264.0], [3237.0, 1271.0], [3229.0, 1257.0], [3233.0, 1282.0], [3251.0, 1268.0], [3262.0, 1268.0], [3277.0, 1275.0], [3270.0, 1264.0], [3268.0, 1256.0], [3258.0, 1251.0], [3263.0, 1242.0], [3252.0, 1232.0], [3238.0, 1219.0], [3244.0, 1212.0], [3244.0, 1220.0], [3250.0, 1215.0], [3250.0, 1223.0], [3255.0, 1217.0], [3255.0, 1226.0], [3266.0, 1222.0], [3275.0, 1223.0], [3259.0, 1230.0], [3271.0, 1236.0], [3289.0, 1233.0], [3279.0, 1239.0], [3279.0, 1250.0], [3296.0, 1252.0], [3306.0, 1255.0], [3287.0, 1259.0], [3315.0, 1281.0], [3311.0, 1270.0], [3326.0, 1274.0], [3325.0, 1291.0], [3333.0, 1271.0], [3323.0, 1253.0], [3316.0, 1233.0], [3308.0, 1234.0], [3310.0, 1215.0], [3314.0, 1230.0], [3323.0, 1230.0], [3324.0, 1243.0], [3349.0, 1236.0], [3335.0, 1219.0], [3326.0, 1211.0], [3334.0, 1213.0], [3348.0, 1207.0], [3346.0, 1198.0], [3358.0, 1187.0], [3361.0, 1175.0], [3353.0, 1177.0], [3342.0, 1172.0], [3346.0, 1159.0], [3333.0, 1155.0], [3329.0, 1147.0], [3312.0, 1138.0], [3309.0, 1130.0], [3306.0, 1121.0], [3296.0, 1116.0], [3310.0, 1116.0], [3317.0, 1126.0], [3333.0, 1115.0], [3322.0, 1128.0], [3330.0, 1137.0], [3341.0, 1135.0], [3343.0, 1144.0], [3356.0, 1148.0], [3354.0, 1165.0], [3371.0, 1160.0], [3380.0, 1170.0], [3375.0, 1179.0], [3374.0, 1198.0], [3359.0, 1216.0], [3366.0, 1231.0], [3379.0, 1232.0], [3372.0, 1219.0], [3381.0, 1228.0], [3383.0, 1210.0], [3386.0, 1220.0], [3404.0, 1208.0], [3399.0, 1217.0], [3415.0, 1228.0], [3404.0, 1226.0], [3386.0, 1228.0], [3384.0, 1239.0], [3392.0, 1245.0], [3381.0, 1237.0], [3362.0, 1250.0], [3371.0, 1258.0], [3368.0, 1280.0], [3381.0, 1300.0], [3391.0, 1325.0], [3391.0, 1333.0], [3403.0, 1331.0], [3399.0, 1320.0], [3407.0, 1312.0], [3404.0, 1302.0], [3395.0, 1300.0], [3396.0, 1292.0], [3394.0, 1274.0], [3405.0, 1267.0], [3402.0, 1286.0], [3410.0, 1286.0], [3407.0, 1297.0], [3419.0, 1303.0], [3417.0, 1311.0], [3425.0, 1308.0], [3422.0, 1296.0], [3421.0, 1273.0], [3434.0, 1274.0], [3431.0, 1289.0], [3427.0, 1294.0], [3435.0, 1294.0], [3430.0, 1303.0], [3443.0, 1299.0], [3445.0, 1308.0], [3430.0, 1307.0], [3431.0, 1320.0], [3420.0, 1315.0], [3413.0, 1326.0], [3425.0, 1336.0], [3433.0, 1338.0], [3439.0, 1326.0], [3449.0, 1327.0], [3435.0, 1338.0], [3437.0, 1356.0], [3446.0, 1355.0], [3449.0, 1347.0], [3445.0, 1359.0], [3460.0, 1373.0], [3473.0, 1408.0], [3490.0, 1430.0], [3512.0, 1430.0], [3526.0, 1434.0], [3516.0, 1430.0], [3497.0, 1427.0], [3487.0, 1423.0], [3490.0, 1409.0], [3498.0, 1421.0], [3506.0, 1426.0], [3505.0, 1414.0], [3514.0, 1425.0], [3519.0, 1417.0], [3529.0, 1415.0], [3512.0, 1397.0], [3525.0, 1400.0], [3522.0, 1346.0], [3507.0, 1307.0], [3538.0, 1272.0], [3530.0, 1256.0], [3529.0, 1265.0], [3527.0, 1254.0], [3521.0, 1241.0], [3508.0, 1239.0], [3520.0, 1239.0], [3487.0, 1196.0], [3474.0, 1166.0], [3443.0, 1123.0], [3424.0, 1056.0], [3427.0, 1023.0], [3433.0, 1011.0], [3461.0, 975.0], [3422.0, 956.0], [3410.0, 965.0], [3390.0, 964.0], [3395.0, 930.0], [3441.0, 860.0], [3436.0, 833.0], [3449.0, 814.0], [3428.0, 807.0], [3419.0, 799.0], [3400.0, 761.0], [3384.0, 746.0], [3371.0, 726.0], [3348.0, 709.0], [3343.0, 700.0], [3330.0, 679.0], [3319.0, 668.0], [3296.0, 646.0], [3276.0, 611.0], [3251.0, 537.0], [3253.0, 432.0], [3261.0, 387.0], [3255.0, 379.0], [3238.0, 317.0], [3229.0, 246.0], [3211.0, 256.0], [3197.0, 264.0], [3206.0, 261.0], [3207.0, 252.0], [3223.0, 243.0], [3239.0, 184.0], [3217.0, 176.0], [3214.0, 186.0], [3216.0, 176.0], [3204.0, 169.0], [3194.0, 149.0], [3194.0, 140.0], [3185.0, 139.0], [3194.0, 137.0], [3191.0, 121.0], [3190.0, 103.0], [3180.0, 101.0], [3185.0, 74.0], [3185.0, 89.0], [3194.0, 70.0], [3195.0, 61.0], [3188.0, 42.0], [3198.0, 61.0], [3188.0, 91.0], [3197.0, 99.0], [3208.0, 91.0], [3219.0, 89.0], [3206.0, 84.0], [3209.0, 71.0], [3219.0, 74.0], [3216.0, 83.0], [3226.0, 79.0], [3239.0, 67.0], [3223.0, 69.0], [3220.0, 59.0], [3226.0, 67.0], [3236.0, 66.0], [3236.0, 55.0], [3221.0, 49.0], [3234.0, 50.0], [3236.0, 42.0], [3240.0, 53.0], [3262.0, 40.0], [3268.0, 32.0], [3267.0, 24.0], [3280.0, 14.0], [3282.0, 3.0], [3285.0, -18.0], [3292.0, -32.0], [3282.0, -57.0], [3276.0, -53.0], [3276.0, -45.0], [3264.0, -42.0], [3255.0, -43.0], [3267.0, -47.0], [3258.0, -53.0], [3244.0, -48.0], [3235.0, -52.0], [3229.0, -34.0], [3238.0, -28.0], [3254.0, -20.0], [3236.0, -19.0], [3242.0, -6.0], [3233.0, -10.0], [3229.0, -18.0], [3221.0, -21.0], [3211.0, -17.0], [3214.0, -9.0], [3206.0, -7.0], [3202.0, -16.0], [3201.0, -25.0], [3211.0, -21.0], [3199.0, -38.0], [3210.0, -36.0], [3215.0, -28.0], [3222.0, -40.0], [3214.0, -49.0], [3212.0, -59.0], [3223.0, -48.0], [3223.0, -67.0], [3213.0, -66.0], [3214.0, -75.0], [3227.0, -75.0], [3223.0, -87.0], [3232.0, -75.0], [3242.0, -81.0], [3237.0, -94.0], [3244.0, -74.0], [3249.0, -86.0], [3239.0, -98.0], [3242.0, -111.0], [3231.0, -105.0], [3223.0, -109.0], [3218.0, -100.0], [3212.0, -108.0], [3209.0, -98.0], [3201.0, -98.0], [3198.0, -112.0], [3201.0, -103.0], [3213.0, -112.0], [3220.0, -122.0], [3230.0, -128.0], [3238.0, -121.0], [3250.0, -118.0], [3252.0, -110.0], [3257.0, -122.0], [3261.0, -110.0], [3252.0, -101.0], [3263.0, -87.0], [3290.0, -117.0], [3289.0, -128.0], [4224.0, -128.0], [4224.0, -128.0], [3474.0, -128.0], [3475.0, -116.0], [3486.0, -120.0], [3475.0, -115.0], [3473.0, -89.0], [3458.0, -49.0], [3458.0, -31.0], [3469.0, -26.0], [3476.0, -53.0], [3493.0, -64.0], [3507.0, -62.0], [3502.0, -77.0], [3504.0, -95.0], [3507.0, -75.0], [3510.0, -85.0], [3509.0, -67.0], [3523.0, -78.0], [3513.0, -128.0], [3509.0, -119.0], [3509.0, -128.0], [3717.0, -128.0], [3705.0, -60.0], [3718.0, -44.0], [3721.0, -21.0], [3735.0, -16.0], [3727.0, -13.0], [3724.0, -3.0], [3716.0, 18.0], [3714.0, 38.0], [3723.0, 41.0], [3719.0, 29.0], [3727.0, 30.0], [3736.0, 24.0], [3739.0, 15.0], [3754.0, 13.0], [3761.0, -1.0], [3771.0, -9.0], [3765.0, -1.0], [3763.0, 10.0], [3755.0, 14.0], [3764.0, 18.0], [3739.0, 25.0], [3742.0, 35.0], [3725.0, 50.0], [3735.0, 54.0], [3734.0, 72.0], [3742.0, 70.0], [3752.0, 54.0], [3760.0, 51.0], [3768.0, 50.0], [3779.0, 46.0], [3764.0, 53.0], [3748.0, 66.0], [3758.0, 80.0], [3768.0, 73.0], [3758.0, 88.0], [3782.0, 84.0], [3785.0, 75.0], [3794.0, 67.0], [3794.0, 59.0], [3807.0, 50.0], [3815.0, 56.0], [3825.0, 47.0], [3819.0, 43.0], [3819.0, 25.0], [3819.0, 1.0], [3812.0, -11.0], [3821.0, -7.0], [3833.0, -17.0], [3846.0, -36.0], [3859.0, -51.0], [3854.0, -41.0], [3844.0, -33.0], [3837.0, -19.0], [3823.0, -5.0], [3826.0, 12.0], [3835.0, 8.0], [3826.0, 15.0], [3823.0, 26.0], [3829.0, 39.0], [3841.0, 30.0], [3841.0, 22.0], [3850.0, 20.0], [3865.0, 19.0], [3842.0, 32.0], [3831.0, 46.0], [3871.0, 49.0], [3850.0, 53.0], [3840.0, 53.0], [3830.0, 56.0], [3818.0, 62.0], [3827.0, 67.0], [3822.0, 78.0], [3815.0, 66.0], [3807.0, 66.0], [3812.0, 77.0], [3805.0, 69.0], [3796.0, 77.0], [3799.0, 88.0], [3788.0, 93.0], [3793.0, 101.0], [3787.0, 94.0], [3779.0, 94.0], [3779.0, 106.0], [3768.0, 106.0], [3765.0, 116.0], [3777.0, 114.0], [3765.0, 119.0], [3752.0, 134.0], [3751.0, 142.0], [3760.0, 149.0], [3745.0, 148.0], [3750.0, 137.0], [3735.0, 120.0], [3733.0, 103.0], [3724.0, 99.0], [3716.0, 89.0], [3713.0, 103.0], [3706.0, 91.0], [3703.0, 79.0], [3690.0, 67.0], [3681.0, 69.0], [3681.0, 58.0], [3693.0, 51.0], [3685.0, 49.0], [3684.0, 41.0], [3680.0, 31.0], [3692.0, 40.0], [3674.0, 6.0], [3672.0, 16.0], [3667.0, 5.0], [3658.0, 5.0], [3662.0, 13.0], [3651.0, 19.0], [3645.0, 11.0], [3643.0, -1.0], [3641.0, -14.0], [3633.0, -18.0], [3634.0, -9.0], [3623.0, -8.0], [3622.0, -18.0], [3632.0, -24.0], [3630.0, -34.0], [3620.0, -36.0], [3617.0, -20.0], [3614.0, -28.0], [3612.0, -41.0], [3618.0, -40.0], [3618.0, -55.0], [3638.0, -65.0], [3636.0, -74.0], [3598.0, -35.0], [3602.0, -16.0], [3593.0, -15.0], [3587.0, -5.0], [3576.0, -6.0], [3565.0, 1.0], [3557.0, 13.0], [3554.0, 48.0], [3541.0, 66.0], [3542.0, 74.0], [3536.0, 92.0], [3526.0, 90.0], [3531.0, 104.0], [3529.0, 112.0], [3525.0, 122.0], [3521.0, 114.0], [3510.0, 126.0], [3509.0, 169.0], [3513.0, 178.0], [3519.0, 162.0], [3518.0, 174.0], [3518.0, 191.0], [3510.0, 190.0], [3503.0, 218.0], [3506.0, 231.0], [3501.0, 245.0], [3504.0, 270.0], [3511.0, 284.0], [3510.0, 265.0], [3506.0, 265.0], [3506.0, 254.0], [3516.0, 260.0], [3515.0, 250.0], [3524.0, 263.0], [3534.0, 268.0], [3536.0, 250.0], [3528.0, 245.0], [3518.0, 224.0], [3521.0, 211.0], [3527.0, 200.0], [3527.0, 191.0], [3541.0, 170.0], [3549.0, 171.0], [3547.0, 159.0], [3539.0, 162.0], [3530.0, 160.0], [3531.0, 147.0], [3539.0, 149.0], [3539.0, 138.0], [3543.0, 147.0], [3548.0, 125.0], [3542.0, 117.0], [3540.0, 101.0], [3548.0, 101.0], [3549.0, 81.0], [3558.0, 94.0], [3568.0, 101.0], [3574.0, 92.0], [3563.0, 79.0], [3569.0, 87.0], [3581.0, 89.0], [3570.0, 75.0], [3568.0, 65.0], [3568.0, 55.0], [3573.0, 69.0], [3582.0, 80.0], [3582.0, 71.0], [3585.0, 71.0], [3585.0, 60.0], [3588.0, 73.0], [3593.0, 64.0], [3591.0, 73.0], [3588.0, 82.0], [3589.0, 91.0], [3601.0, 86.0], [3602.0, 72.0], [3603.0, 51.0], [3595.0, 41.0], [3586.0, 42.0], [3582.0, 16.0], [3588.0, 25.0], [3590.0, 38.0], [3598.0, 36.0], [3610.0, 53.0], [3610.0, 42.0], [3618.0, 39.0], [3611.0, 24.0], [3626.0, 20.0], [3625.0, 11.0], [3631.0, 19.0], [3618.0, 30.0], [3621.0, 38.0], [3614.0, 59.0], [3616.0, 74.0], [3609.0, 85.0], [3612.0, 93.0], [3602.0, 99.0], [3592.0, 103.0], [3577.0, 110.0], [3569.0, 125.0], [3572.0, 138.0], [3573.0, 160.0], [3576.0, 162.0], [3576.0, 171.0], [3574.0, 182.0], [3570.0, 193.0], [3578.0, 184.0], [3585.0, 193.0], [3594.0, 199.0], [3610.0, 212.0], [3610.0, 197.0], [3603.0, 199.0], [3603.0, 187.0], [3590.0, 181.0], [3582.0, 172.0], [3594.0, 169.0], [3597.0, 175.0], [3597.0, 166.0], [3601.0, 176.0], [3611.0, 183.0], [3616.0, 173.0], [3610.0, 164.0], [3621.0, 173.0], [3634.0, 162.0], [3630.0, 151.0], [3619.0, 161.0], [3611.0, 159.0], [3598.0, 153.0], [3587.0, 160.0], [3594.0, 148.0], [3590.0, 139.0], [3581.0, 139.0], [3583.0, 119.0], [3588.0, 135.0], [3596.0, 137.0], [3597.0, 128.0], [3599.0, 143.0], [3607.0, 118.0], [3605.0, 147.0], [3610.0, 136.0], [3617.0, 146.0], [3619.0, 138.0], [3627.0, 127.0], [3617.0, 121.0], [3614.0, 111.0], [3635.0, 120.0], [3637.0, 130.0], [3633.0, 137.0], [3649.0, 137.0], [3646.0, 124.0], [3644.0, 114.0], [3625.0, 105.0], [3623.0, 91.0], [3623.0, 78.0], [3626.0, 69.0], [3630.0, 42.0], [3636.0, 54.0], [3649.0, 40.0], [3655.0, 29.0], [3656.0, 45.0], [3643.0, 51.0], [3642.0, 59.0], [3633.0, 62.0], [3631.0, 70.0], [3631.0, 78.0], [3646.0, 71.0], [3634.0, 88.0], [3643.0, 93.0], [3642.0, 102.0], [3655.0, 101.0], [3663.0, 112.0], [3669.0, 97.0], [3654.0, 96.0], [3666.0, 94.0], [3662.0, 80.0], [3672.0, 81.0], [3674.0, 97.0], [3683.0, 88.0], [3692.0, 96.0], [3682.0, 98.0], [3687.0, 111.0], [3681.0, 103.0], [3672.0, 112.0], [3682.0, 122.0], [3679.0, 135.0], [3690.0, 141.0], [3695.0, 150.0], [3695.0, 129.0], [3705.0, 147.0], [3715.0, 161.0], [3714.0, 141.0], [3707.0, 129.0], [3715.0, 126.0], [3712.0, 135.0], [3718.0, 146.0], [3720.0, 136.0], [3729.0, 140.0], [3726.0, 151.0], [3725.0, 173.0], [3726.0, 159.0], [3738.0, 154.0], [3730.0, 162.0], [3733.0, 178.0], [3738.0, 170.0], [3733.0, 185.0], [3722.0, 182.0], [3711.0, 176.0], [3708.0, 186.0], [3706.0, 175.0], [3689.0, 178.0], [3689.0, 186.0], [3682.0, 178.0], [3679.0, 168.0], [3671.0, 156.0], [3659.0, 161.0], [3668.0, 170.0], [3669.0, 186.0], [3661.0, 185.0], [3657.0, 195.0], [3658.0, 206.0], [3664.0, 217.0], [3675.0, 233.0], [3675.0, 209.0], [3673.0, 199.0], [3689.0, 214.0], [3695.0, 203.0], [3699.0, 216.0], [3712.0, 220.0], [3710.0, 204.0], [3714.0, 215.0], [3715.0, 230.0], [3707.0, 229.0], [3697.0, 225.0], [3689.0, 223.0], [3696.0, 233.0], [3687.0, 231.0], [3696.0, 245.0], [3692.0, 253.0], [3696.0, 263.0], [3712.0, 281.0], [3642.0, 370.0], [3642.0, 391.0], [3631.0, 389.0], [3633.0, 400.0], [3625.0, 383.0], [3622.0, 397.0], [3613.0, 397.0], [3600.0, 392.0], [3601.0, 383.0], [3589.0, 367.0], [3599.0, 394.0], [3606.0, 411.0], [3627.0, 407.0], [3624.0, 415.0], [3620.0, 424.0], [3609.0, 432.0], [3619.0, 429.0], [3624.0, 437.0], [3616.0, 436.0], [3616.0, 446.0], [3601.0, 455.0], [3596.0, 473.0], [3592.0, 462.0], [3583.0, 457.0], [3585.0, 448.0], [3574.0, 458.0], [3561.0, 466.0], [3570.0, 472.0], [3585.0, 475.0], [3599.0, 492.0], [3605.0, 507.0], [3603.0, 519.0], [3614.0, 517.0], [3617.0, 509.0], [3609.0, 506.0], [3607.0, 497.0], [3604.0, 483.0], [3612.0, 479.0], [3610.0, 468.0], [3610.0, 458.0], [3618.0, 465.0], [3615.0, 487.0], [3622.0, 489.0], [3622.0, 503.0], [3625.0, 513.0], [3633.0, 513.0], [3634.0, 525.0], [3643.0, 529.0], [3641.0, 517.0], [3642.0, 506.0], [3632.0, 506.0], [3642.0, 498.0], [3640.0, 491.0], [3632.0, 491.0], [3628.0, 482.0], [3637.0, 484.0], [3645.0, 475.0], [3637.0, 470.0], [3638.0, 461.0], [3646.0, 463.0], [3635.0, 427.0], [3641.0, 435.0], [3654.0, 431.0], [3652.0, 420.0], [3658.0, 412.0], [3669.0, 419.0], [3659.0, 417.0], [3661.0, 425.0], [3656.0, 435.0], [3645.0, 439.0], [3653.0, 451.0], [3650.0, 473.0], [3660.0, 475.0], [3647.0, 484.0], [3648.0, 513.0], [3652.0, 510.0], [3652.0, 489.0], [3660.0, 485.0], [3657.0, 493.0], [3658.0, 507.0], [3653.0, 516.0], [3661.0, 512.0], [3671.0, 507.0], [3682.0, 507.0], [3681.0, 498.0], [3677.0, 485.0], [3687.0, 483.0], [3680.0, 473.0], [3668.0, 473.0], [3670.0, 464.0], [3667.0, 454.0], [3674.0, 449.0], [3684.0, 449.0], [3684.0, 436.0], [3692.0, 436.0], [3686.0, 451.0], [3675.0, 456.0], [3678.0, 464.0], [3675.0, 466.0], [3687.0, 466.0], [3687.0, 474.0], [3698.0, 478.0], [3702.0, 474.0], [3702.0, 464.0], [3706.0, 465.0], [3706.0, 457.0], [3710.0, 458.0], [3710.0, 431.0], [3713.0, 446.0], [3706.0, 475.0], [3700.0, 492.0], [3708.0, 487.0], [3719.0, 488.0], [3721.0, 478.0], [3723.0, 487.0], [3732.0, 484.0], [3736.0, 475.0], [3745.0, 469.0], [3744.0, 461.0], [3736.0, 451.0], [3737.0, 436.0], [3740.0, 446.0], [3747.0, 462.0], [3759.0, 449.0], [3761.0, 459.0], [3770.0, 463.0], [3786.0, 462.0], [3768.0, 467.0], [3757.0, 463.0], [3747.0, 485.0], [3755.0, 488.0], [3757.0, 499.0], [3752.0, 491.0], [3743.0, 514.0], [3738.0, 496.0], [3729.0, 499.0], [3718.0, 504.0], [3706.0, 504.0], [3694.0, 514.0], [3709.0, 525.0], [3705.0, 535.0], [3713.0, 536.0], [3716.0, 545.0], [3731.0, 543.0], [3746.0, 546.0], [3740.0, 537.0], [3748.0, 542.0], [3747.0, 534.0], [3758.0, 542.0], [3762.0, 531.0], [3761.0, 539.0], [3771.0, 538.0], [3780.0, 538.0], [3781.0, 530.0], [3787.0, 540.0], [3792.0, 536.0], [3792.0, 525.0], [3808.0, 524.0], [3820.0, 512.0], [3810.0, 527.0], [3794.0, 529.0], [3795.0, 540.0], [3796.0, 548.0], [3806.0, 544.0], [3817.0, 551.0], [3826.0, 549.0], [3816.0, 555.0], [3801.0, 547.0], [3798.0, 568.0], [3793.0, 558.0], [3792.0, 542.0], [3775.0, 547.0], [3779.0, 561.0], [3772.0, 541.0], [3764.0, 548.0], [3751.0, 546.0], [3747.0, 557.0], [3760.0, 559.0], [3749.0, 571.0], [3749.0, 579.0], [3754.0, 588.0], [3765.0, 589.0], [3777.0, 598.0], [3768.0, 595.0], [3750.0, 594.0], [3747.0, 586.0], [3737.0, 591.0], [3746.0, 582.0], [3746.0, 563.0], [3742.0, 551.0], [3724.0, 557.0], [3727.0, 565.0], [3721.0, 555.0], [3709.0, 559.0], [3707.0, 546.0], [3697.0, 548.0], [3689.0, 529.0], [3681.0, 528.0], [3685.0, 543.0], [3695.0, 554.0], [3692.0, 565.0], [3698.0, 577.0], [3683.0, 579.0], [3681.0, 588.0], [3677.0, 569.0], [3674.0, 556.0], [3666.0, 553.0], [3659.0, 562.0], [3660.0, 592.0], [3649.0, 570.0], [3652.0, 561.0], [3644.0, 559.0], [3623.0, 595.0], [3633.0, 616.0], [3625.0, 618.0], [3619.0, 607.0], [3606.0, 612.0], [3593.0, 631.0], [3584.0, 658.0], [3592.0, 673.0], [3579.0, 669.0], [3578.0, 658.0], [3570.0, 657.0], [3560.0, 639.0], [3572.0, 653.0], [3580.0, 650.0], [3578.0, 642.0], [3581.0, 629.0], [3582.0, 640.0], [3595.0, 611.0], [3585.0, 612.0], [3583.0, 604.0], [3589.0, 596.0], [3568.0, 600.0], [3553.0, 608.0], [3553.0, 617.0], [3544.0, 607.0], [3527.0, 604.0], [3535.0, 618.0], [3532.0, 637.0], [3521.0, 626.0], [3520.0, 636.0], [3524.0, 652.0], [3538.0, 675.0], [3549.0, 683.0], [3551.0, 694.0], [3565.0, 709.0], [3580.0, 741.0], [3594.0, 757.0], [3599.0, 778.0], [3594.0, 803.0], [3601.0, 820.0], [3609.0, 833.0], [3630.0, 848.0], [3638.0, 850.0], [3649.0, 873.0], [3661.0, 887.0], [3674.0, 878.0], [3666.0, 852.0], [3668.0, 829.0], [3676.0, 823.0], [3667.0, 805.0], [3672.0, 797.0], [3681.0, 798.0], [3690.0, 807.0], [3695.0, 827.0], [3693.0, 838.0], [3688.0, 853.0], [3699.0, 851.0], [3709.0, 842.0], [3720.0, 856.0], [3739.0, 865.0], [3757.0, 897.0], [3755.0, 905.0], [3762.0, 914.0], [3774.0, 932.0], [3761.0, 933.0], [3755.0, 925.0], [3766.0, 944.0], [3775.0, 945.0], [3793.0, 954.0], [3814.0, 957.0], [3813.0, 970.0], [3802.0, 973.0], [3802.0, 988.0], [3788.0, 1002.0], [3788.0, 1025.0], [3766.0, 1025.0], [3789.0, 1026.0], [3839.0, 1025.0], [3789.0, 1025.0], [3791.0, 1015.0], [3804.0, 1008.0], [3826.0, 1009.0], [3814.0, 998.0], [3815.0, 970.0], [3827.0, 976.0], [3834.0, 964.0], [3842.0, 977.0], [3851.0, 980.0], [3837.0, 988.0], [3844.0, 998.0], [3859.0, 988.0], [3864.0, 999.0], [3861.0, 1008.0], [3872.0, 1009.0], [3887.0, 1009.0], [3880.0, 1024.0], [3888.0, 1023.0], [3891.0, 1034.0], [3877.0, 1045.0], [3868.0, 1043.0], [3859.0, 1052.0], [3851.0, 1046.0], [3860.0, 1059.0], [3896.0, 1062.0], [3902.0, 1080.0], [3921.0, 1107.0], [3956.0, 1127.0], [3952.0, 1100.0], [3941.0, 1079.0], [3940.0, 1068.0], [3949.0, 1033.0], [3948.0, 1025.0], [3958.0, 1019.0], [3965.0, 1008.0], [3960.0, 995.0], [3932.0, 966.0], [3917.0, 964.0], [3929.0, 957.0], [3943.0, 933.0], [3979.0, 912.0], [3987.0, 877.0], [3995.0, 871.0], [3988.0, 862.0], [4002.0, 835.0], [4013.0, 843.0], [4043.0, 850.0], [4061.0, 902.0], [4059.0, 919.0], [4058.0, 928.0], [4038.0, 944.0], [3979.0, 955.0], [3992.0, 967.0], [4029.0, 1023.0], [4029.0, 1023.0], [4029.0, 1037.0], [4059.0, 1062.0], [4065.0, 1076.0], [4064.0, 1087.0], [4060.0, 1076.0], [4049.0, 1096.0], [4079.0, 1089.0], [4090.0, 1072.0], [4097.0, 1072.0], [4139.0, 1072.0], [4155.0, 1105.0], [4174.0, 1105.0], [4166.0, 1086.0], [4176.0, 1075.0], [4174.0, 1065.0], [4185.0, 1057.0], [4193.0, 1052.0], [4195.0, 1064.0], [4191.0, 1078.0], [4208.0, 1098.0], [4208.0, 1106.0], [4222.0, 1110.0], [4224.0, 1109.0], [4224.0, 1144.0], [4202.0, 1158.0], [4177.0, 1161.0], [4182.0, 1181.0], [4169.0, 1152.0], [4140.0, 1163.0], [4117.0, 1177.0], [4134.0, 1177.0], [4126.0, 1181.0], [4124.0, 1189.0], [4115.0, 1194.0], [4115.0, 1178.0], [4096.0, 1179.0], [4075.0, 1201.0], [4081.0, 1215.0], [4086.0, 1216.0], [4086.0, 1205.0], [4087.0, 1214.0], [4095.0, 1216.0], [4109.0, 1227.0], [4102.0, 1242.0], [4100.0, 1231.0], [4096.0, 1234.0], [4096.0, 1244.0], [4103.0, 1254.0], [4115.0, 1249.0], [4127.0, 1259.0], [4131.0, 1250.0], [4133.0, 1258.0], [4140.0, 1245.0], [4138.0, 1256.0], [4141.0, 1275.0], [4150.0, 1271.0], [4142.0, 1271.0], [4159.0, 1255.0], [4169.0, 1255.0], [4168.0, 1246.0], [4177.0, 1245.0], [4180.0, 1259.0], [4190.0, 1271.0], [4182.0, 1272.0], [4196.0, 1281.0], [4192.0, 1267.0], [4209.0, 1270.0], [4218.0, 1267.0], [4221.0, 1249.0], [4214.0, 1230.0], [4215.0, 1215.0], [4221.0, 1223.0], [4219.0, 1231.0], [4224.0, 1243.0], [4224.0, 1257.0], [4224.0, 1262.0], [4224.0, 1345.0], [4224.0, 1339.0], [4224.0, 1328.0], [4215.0, 1335.0], [4213.0, 1346.0], [4203.0, 1355.0], [4213.0, 1357.0], [4215.0, 1369.0], [4224.0, 1363.0], [4215.0, 1377.0], [4208.0, 1387.0], [4217.0, 1401.0], [4224.0, 1403.0], [4224.0, 1520.0], [4219.0, 1535.0], [4221.0, 1544.0], [4217.0, 1553.0], [4209.0, 1549.0], [4215.0, 1558.0], [4206.0, 1559.0], [4207.0, 1580.0], [4199.0, 1593.0], [4205.0, 1605.0], [4215.0, 1604.0], [4223.0, 1595.0], [4221.0, 1611.0], [4212.0, 1608.0], [4211.0, 1618.0], [4206.0, 1626.0], [4214.0, 1625.0], [4219.0, 1637.0], [4214.0, 1648.0], [4224.0, 1645.0], [4224.0, 1640.0], [4224.0, 2108.0], [4220.0, 2125.0], [4224.0, 2125.0], [4224.0, 2143.0], [4205.0, 2141.0], [4180.0, 2159.0], [4188.0, 2155.0], [4195.0, 2165.0], [4207.0, 2164.0], [4196.0, 2172.0], [4201.0, 2182.0], [4187.0, 2168.0], [4170.0, 2169.0], [4171.0, 2181.0], [4163.0, 2189.0], [4173.0, 2188.0], [4163.0, 2194.0], [4164.0, 2202.0], [4173.0, 2196.0], [4179.0, 2205.0], [4186.0, 2196.0], [4185.0, 2205.0], [4174.0, 2208.0], [4176.0, 2229.0], [4192.0, 2223.0], [4199.0, 2211.0], [4210.0, 2218.0], [4212.0, 2210.0], [4223.0, 2214.0], [4224.0, 2207.0], [4224.0, 2216.0], [4217.0, 2225.0], [4221.0, 2233.0], [4215.0, 2224.0], [4203.0, 2227.0], [4199.0, 2238.0], [4209.0, 2248.0], [4197.0, 2242.0], [4185.0, 2240.0], [4186.0, 2254.0], [4198.0, 2276.0], [4187.0, 2260.0], [4178.0, 2259.0], [4161.0, 2258.0], [4153.0, 2249.0], [4150.0, 2238.0], [4150.0, 2228.0], [4136.0, 2231.0], [4144.0, 2218.0], [4114.0, 2274.0], [4123.0, 2272.0], [4133.0, 2270.0], [4117.0, 2280.0], [4139.0, 2273.0], [4152.0, 2274.0], [4140.0, 2276.0], [4133.0, 2284.0], [4141.0, 2288.0], [4147.0, 2303.0], [4157.0, 2311.0], [4171.0, 2308.0], [4163.0, 2310.0], [4158.0, 2319.0], [4144.0, 2315.0], [4135.0, 2305.0], [4125.0, 2299.0], [4108.0, 2303.0], [4097.0, 2318.0], [4091.0, 2343.0], [4096.0, 2347.0], [4096.0, 2339.0], [4112.0, 2344.0], [4110.0, 2332.0], [4119.0, 2332.0], [4121.0, 2347.0], [4131.0, 2344.0], [4146.0, 2338.0], [4155.0, 2337.0], [4164.0, 2350.0], [4180.0, 2355.0], [4195.0, 2352.0], [4200.0, 2342.0], [4201.0, 2354.0], [4213.0, 2352.0], [4224.0, 2348.0], [4224.0, 2356.0], [4207.0, 2361.0], [4184.0, 2358.0], [4176.0, 2367.0], [4162.0, 2364.0], [4155.0, 2354.0], [4141.0, 2357.0], [4119.0, 2361.0], [4115.0, 2355.0], [4106.0, 2355.0], [4101.0, 2364.0], [4105.0, 2372.0], [4108.0, 2386.0], [4096.0, 2372.0], [4091.0, 2388.0], [4078.0, 2409.0], [4067.0, 2446.0], [4059.0, 2493.0], [4073.0, 2495.0], [4074.0, 2478.0], [4073.0, 2490.0], [4087.0, 2492.0], [4084.0, 2484.0], [4089.0, 2481.0], [4097.0, 2481.0], [4099.0, 2473.0], [4088.0, 2474.0], [4092.0, 2464.0], [4103.0, 2465.0], [4102.0, 2473.0], [4118.0, 2470.0], [4118.0, 2459.0], [4129.0, 2458.0], [4129.0, 2449.0], [4121.0, 2447.0], [4126.0, 2439.0], [4135.0, 2447.0], [4143.0, 2440.0], [4142.0, 2426.0], [4148.0, 2416.0], [4156.0, 2414.0], [4159.0, 2406.0], [4161.0, 2417.0], [4153.0, 2419.0], [4144.0, 2434.0], [4154.0, 2440.0], [4152.0, 2449.0], [4163.0, 2459.0], [4171.0, 2453.0], [4166.0, 2461.0], [4153.0, 2458.0], [4136.0, 2458.0], [4121.0, 2480.0], [4141.0, 2472.0], [4157.0, 2483.0], [4168.0, 2487.0], [4160.0, 2482.0], [4149.0, 2480.0], [4140.0, 2480.0], [4128.0, 2487.0], [4112.0, 2483.0], [4102.0, 2487.0], [4097.0, 2499.0], [4098.0, 2510.0], [4108.0, 2514.0], [4117.0, 2522.0], [4140.0, 2526.0], [4126.0, 2524.0], [4114.0, 2523.0], [4100.0, 2524.0], [4091.0, 2519.0], [4084.0, 2528.0], [4093.0, 2511.0], [4089.0, 2501.0], [4079.0, 2509.0], [4065.0, 2508.0], [4057.0, 2516.0], [4050.0, 2536.0], [4067.0, 2544.0], [4057.0, 2546.0], [4057.0, 2558.0], [4050.0, 2538.0], [4037.0, 2561.0], [4029.0, 2589.0], [4033.0, 2579.0], [4030.0, 2604.0], [4034.0, 2629.0], [4035.0, 2614.0], [4034.0, 2601.0], [4038.0, 2593.0], [4043.0, 2610.0], [4048.0, 2598.0], [4063.0, 2585.0], [4076.0, 2582.0], [4080.0, 2573.0], [4089.0, 2570.0], [4097.0, 2562.0], [4095.0, 2571.0], [4075.0, 2586.0], [4084.0, 2597.0], [4097.0, 2592.0], [4108.0, 2591.0], [4095.0, 2596.0], [4103.0, 2601.0], [4095.0, 2605.0], [4087.0, 2606.0], [4066.0, 2597.0], [4063.0, 2606.0], [4073.0, 2606.0], [4048.0, 2618.0], [4052.0, 2632.0], [4052.0, 2642.0], [4064.0, 2646.0], [4082.0, 2654.0], [4091.0, 2645.0], [4084.0, 2654.0], [4097.0, 2656.0], [4094.0, 2667.0], [4085.0, 2664.0], [4086.0, 2672.0], [4080.0, 2656.0], [4068.0, 2654.0], [4060.0, 2654.0], [4042.0, 2652.0], [4053.0, 2661.0], [4045.0, 2660.0], [4035.0, 2665.0], [4025.0, 2666.0], [4026.0, 2684.0], [4032.0, 2697.0], [4046.0, 2699.0], [4037.0, 2714.0], [4039.0, 2702.0], [4031.0, 2709.0], [4027.0, 2691.0], [3983.0, 2765.0], [3974.0, 2811.0], [3974.0, 2820.0], [3977.0, 2809.0], [3985.0, 2796.0], [3995.0, 2794.0], [3998.0, 2785.0], [4007.0, 2786.0], [4004.0, 2776.0], [4012.0, 2775.0], [4015.0, 2755.0], [4022.0, 2763.0], [4038.0, 2761.0], [4020.0, 2771.0], [4035.0, 2784.0], [4044.0, 2783.0], [4034.0, 2789.0], [4024.0, 2793.0], [4024.0, 2798.0], [4013.0, 2798.0], [4017.0, 2810.0], [4013.0, 2802.0], [4001.0, 2803.0], [4002.0, 2818.0], [4013.0, 2823.0], [4003.0, 2819.0], [3991.0, 2818.0], [3982.0, 2835.0], [3983.0, 2846.0], [3993.0, 2848.0], [4005.0, 2853.0], [4017.0, 2855.0], [4028.0, 2846.0], [4019.0, 2853.0], [4027.0, 2861.0], [4010.0, 2854.0], [4000.0, 2854.0], [3992.0, 2861.0], [3992.0, 2851.0], [3973.0, 2866.0], [3968.0, 2877.0], [3984.0, 2880.0], [3967.0, 2885.0], [3970.0, 2902.0], [3983.0, 2931.0], [3987.0, 2920.0], [3988.0, 2931.0], [4002.0, 2936.0], [4010.0, 2917.0], [4008.0, 2903.0], [4016.0, 2893.0], [4021.0, 2901.0], [4012.0, 2913.0], [4026.0, 2916.0], [4014.0, 2921.0], [4015.0, 2934.0], [4032.0, 2937.0], [4018.0, 2934.0], [4014.0, 2944.0], [4024.0, 2948.0], [4015.0, 2950.0], [4006.0, 2943.0], [3992.0, 2946.0], [3986.0, 2988.0], [3992.0, 3004.0], [4017.0, 3037.0], [4023.0, 3053.0], [4022.0, 3063.0], [4031.0, 3065.0], [4036.0, 3073.0], [4050.0, 3135.0], [4050.0, 3151.0], [4091.0, 3119.0], [4083.0, 3115.0], [4076.0, 3096.0], [4087.0, 3101.0], [4086.0, 3072.0], [4087.0, 3061.0], [4087.0, 3046.0], [4094.0, 3022.0], [4092.0, 3012.0], [4091.0, 3001.0], [4097.0, 2989.0], [4097.0, 3013.0], [4098.0, 2945.0], [4101.0, 2937.0], [4097.0, 2920.0], [4105.0, 2913.0], [4113.0, 2856.0], [4122.0, 2844.0], [4112.0, 2840.0], [4123.0, 2834.0], [4124.0, 2817.0], [4129.0, 2791.0], [4136.0, 2775.0], [4140.0, 2760.0], [4130.0, 2754.0], [4145.0, 2754.0], [4154.0, 2742.0], [4150.0, 2733.0], [4139.0, 2720.0], [4154.0, 2722.0], [4159.0, 2707.0], [4183.0, 2662.0], [4182.0, 2654.0], [4183.0, 2640.0], [4189.0, 2644.0], [4189.0, 2653.0], [4197.0, 2632.0], [4209.0, 2634.0], [4205.0, 2624.0], [4213.0, 2607.0], [4203.0, 2605.0], [4209.0, 2589.0], [4211.0, 2600.0], [4219.0, 2599.0], [4224.0, 2592.0], [4224.0, 2574.0], [4223.0, 2566.0], [4224.0, 2562.0], [4224.0, 2553.0], [4224.0, 2552.0], [4224.0, -128.0], [4224.0, 4224.0], [4205.0, 4224.0], [4183.0, 4096.0], [4158.0, 4018.0], [4122.0, 3935.0], [4097.0, 3854.0], [4092.0, 3841.0], [4075.0, 3785.0], [4050.0, 3675.0], [4045.0, 3686.0], [4047.0, 3676.0], [4031.0, 3585.0], [4015.0, 3513.0], [3993.0, 3494.0], [3963.0, 3488.0], [3945.0, 3491.0], [3919.0, 3515.0], [3880.0, 3525.0], [3873.0, 3533.0], [3887.0, 3539.0], [3897.0, 3536.0], [3924.0, 3532.0], [3923.0, 3524.0], [3925.0, 3533.0], [3936.0, 3526.0], [3950.0, 3529.0], [3962.0, 3536.0], [3976.0, 3555.0], [3981.0, 3567.0], [4002.0, 3574.0], [4018.0, 3572.0], [4003.0, 3575.0], [4018.0, 3580.0], [4022.0, 3593.0], [4014.0, 3591.0], [4009.0, 3579.0], [3992.0, 3572.0], [3992.0, 3585.0], [3993.0, 3598.0], [4002.0, 3596.0], [4007.0, 3594.0], [4007.0, 3603.0], [4010.0, 3613.0], [4027.0, 3623.0], [4012.0, 3619.0], [4004.0, 3619.0], [4005.0, 3609.0], [3995.0, 3606.0], [3986.0, 3604.0], [3995.0, 3609.0], [3990.0, 3617.0], [3998.0, 3617.0], [3999.0, 3627.0], [3987.0, 3633.0], [3991.0, 3622.0], [3978.0, 3621.0], [3985.0, 3610.0], [3981.0, 3600.0], [3981.0, 3590.0], [3986.0, 3598.0], [3986.0, 3585.0], [3976.0, 3587.0], [3984.0, 3581.0], [3983.0, 3571.0], [3975.0, 3572.0], [3967.0, 3566.0], [3948.0, 3558.0], [3955.0, 3583.0], [3946.0, 3559.0], [3936.0, 3547.0], [3882.0, 3539.0], [3879.0, 3548.0], [3887.0, 3553.0], [3893.0, 3543.0], [3891.0, 3556.0], [3899.0, 3562.0], [3898.0, 3554.0], [3907.0, 3558.0], [3909.0, 3544.0], [3913.0, 3557.0], [3915.0, 3566.0], [3907.0, 3567.0], [3905.0, 3577.0], [3916.0, 3574.0], [3911.0, 3584.0], [3914.0, 3592.0], [3916.0, 3583.0], [3920.0, 3598.0], [3909.0, 3596.0], [3901.0, 3596.0], [3907.0, 3604.0], [3913.0, 3612.0], [3908.0, 3625.0], [3917.0, 3622.0], [3923.0, 3631.0], [3914.0, 3628.0], [3919.0, 3647.0], [3922.0, 3656.0], [3917.0, 3666.0], [3922.0, 3655.0], [3913.0, 3650.0], [3916.0, 3641.0], [3915.0, 3632.0], [3906.0, 3639.0], [3907.0, 3647.0], [3898.0, 3647.0], [3908.0, 3632.0], [3899.0, 3631.0], [3905.0, 3623.0], [3903.0, 3615.0], [3895.0, 3624.0], [3897.0, 3632.0], [3894.0, 3622.0], [3883.0, 3622.0], [3891.0, 3625.0], [3888.0, 3614.0], [3898.0, 3617.0], [3901.0, 3607.0], [3890.0, 3610.0], [3891.0, 3600.0], [3892.0, 3588.0], [3903.0, 3590.0], [3893.0, 3583.0], [3890.0, 3574.0], [3877.0, 3572.0], [3880.0, 3581.0], [3872.0, 3589.0], [3869.0, 3574.0], [3869.0, 3563.0], [3858.0, 3561.0], [3860.0, 3573.0], [3851.0, 3572.0], [3851.0, 3583.0], [3847.0, 3591.0], [3855.0, 3588.0], [3854.0, 3601.0], [3865.0, 3597.0], [3861.0, 3605.0], [3869.0, 3605.0], [3858.0, 3605.0], [3850.0, 3599.0], [3841.0, 3611.0], [3842.0, 3622.0], [3852.0, 3623.0], [3843.0, 3624.0], [3843.0, 3634.0], [3840.0, 3625.0], [3823.0, 3628.0], [3821.0, 3638.0], [3813.0, 3642.0], [3822.0, 3625.0], [3836.0, 3616.0], [3828.0, 3615.0], [3830.0, 3606.0], [3840.0, 3605.0], [3847.0, 3596.0], [3839.0, 3591.0], [3849.0, 3584.0], [3837.0, 3581.0], [3825.0, 3591.0], [3831.0, 3583.0], [3830.0, 3571.0], [3839.0, 3577.0], [3832.0, 3566.0], [3844.0, 3568.0], [3841.0, 3558.0], [3851.0, 3557.0], [3851.0, 3542.0], [3842.0, 3535.0], [3863.0, 3543.0], [3865.0, 3534.0], [3802.0, 3504.0], [3749.0, 3491.0], [3749.0, 3507.0], [3764.0, 3505.0], [3750.0, 3513.0], [3767.0, 3518.0], [3742.0, 3522.0], [3734.0, 3526.0], [3739.0, 3508.0], [3729.0, 3507.0], [3745.0, 3506.0], [3745.0, 3489.0], [3684.0, 3476.0], [3607.0, 3424.0], [3585.0, 3422.0], [3573.0, 3420.0], [3569.0, 3428.0], [3583.0, 3430.0], [3583.0, 3430.0], [3608.0, 3430.0], [3619.0, 3435.0], [3609.0, 3433.0], [3607.0, 3452.0], [3588.0, 3459.0], [3587.0, 3468.0], [3578.0, 3464.0], [3568.0, 3455.0], [3567.0, 3445.0], [3555.0, 3447.0], [3546.0, 3446.0], [3536.0, 3439.0], [3523.0, 3438.0], [3528.0, 3447.0], [3520.0, 3446.0], [3528.0, 3448.0], [3520.0, 3453.0], [3526.0, 3463.0], [3518.0, 3472.0], [3531.0, 3476.0], [3520.0, 3478.0], [3518.0, 3482.0], [3526.0, 3482.0], [3519.0, 3486.0], [3532.0, 3486.0], [3519.0, 3488.0], [3527.0, 3514.0], [3530.0, 3540.0], [3541.0, 3520.0], [3550.0, 3529.0], [3560.0, 3533.0], [3562.0, 3524.0], [3570.0, 3520.0], [3565.0, 3530.0], [3573.0, 3524.0], [3571.0, 3534.0], [3583.0, 3538.0], [3583.0, 3536.0], [3583.0, 3526.0], [3594.0, 3523.0], [3592.0, 3532.0], [3586.0, 3545.0], [3585.0, 3554.0], [3584.0, 3563.0], [3590.0, 3551.0], [3591.0, 3561.0], [3600.0, 3565.0], [3605.0, 3551.0], [3608.0, 3543.0], [3621.0, 3543.0], [3629.0, 3551.0], [3613.0, 3549.0], [3619.0, 3559.0], [3631.0, 3564.0], [3618.0, 3563.0], [3614.0, 3553.0], [3611.0, 3563.0], [3606.0, 3576.0], [3613.0, 3584.0], [3608.0, 3593.0], [3615.0, 3604.0], [3628.0, 3611.0], [3605.0, 3600.0], [3607.0, 3586.0], [3601.0, 3572.0], [3591.0, 3575.0], [3593.0, 3567.0], [3583.0, 3566.0], [3577.0, 3577.0], [3580.0, 3564.0], [3567.0, 3568.0], [3576.0, 3561.0], [3573.0, 3553.0], [3566.0, 3540.0], [3556.0, 3544.0], [3558.0, 3553.0], [3552.0, 3539.0], [3543.0, 3573.0], [3534.0, 3578.0], [3522.0, 3584.0], [3530.0, 3585.0], [3522.0, 3589.0], [3527.0, 3597.0], [3542.0, 3604.0], [3542.0, 3612.0], [3550.0, 3612.0], [3557.0, 3622.0], [3568.0, 3634.0], [3566.0, 3623.0], [3569.0, 3627.0], [3577.0, 3627.0], [3575.0, 3638.0], [3585.0, 3650.0], [3605.0, 3655.0], [3626.0, 3651.0], [3649.0, 3657.0], [3658.0, 3656.0], [3655.0, 3648.0], [3661.0, 3657.0], [3671.0, 3657.0], [3674.0, 3649.0], [3669.0, 3640.0], [3669.0, 3628.0], [3679.0, 3631.0], [3679.0, 3643.0], [3684.0, 3651.0], [3695.0, 3648.0], [3679.0, 3653.0], [3686.0, 3663.0], [3694.0, 3658.0], [3693.0, 3670.0], [3703.0, 3667.0], [3706.0, 3659.0], [3704.0, 3668.0], [3718.0, 3655.0], [3712.0, 3676.0], [3722.0, 3674.0], [3722.0, 3681.0], [3714.0, 3681.0], [3699.0, 3671.0], [3696.0, 3683.0], [3692.0, 3694.0], [3691.0, 3685.0], [3693.0, 3673.0], [3682.0, 3668.0], [3679.0, 3679.0], [3681.0, 3663.0], [3671.0, 3670.0], [3675.0, 3662.0], [3666.0, 3662.0], [3657.0, 3664.0], [3671.0, 3676.0], [3672.0, 3687.0], [3661.0, 3687.0], [3652.0, 3702.0], [3651.0, 3692.0], [3664.0, 3682.0], [3654.0, 3671.0], [3647.0, 3663.0], [3636.0, 3663.0], [3631.0, 3678.0], [3633.0, 3662.0], [3617.0, 3660.0], [3610.0, 3669.0], [3605.0, 3659.0], [3596.0, 3657.0], [3583.0, 3656.0], [3578.0, 3672.0], [3584.0, 3680.0], [3589.0, 3689.0], [3585.0, 3691.0], [3594.0, 3691.0], [3585.0, 3692.0], [3584.0, 3694.0], [3584.0, 3703.0], [3587.0, 3713.0], [3600.0, 3711.0], [3589.0, 3713.0], [3583.0, 3719.0], [3583.0, 3739.0], [3566.0, 3748.0], [3566.0, 3759.0], [3558.0, 3766.0], [3574.0, 3769.0], [3585.0, 3759.0], [3576.0, 3770.0], [3578.0, 3778.0], [3578.0, 3788.0], [3573.0, 3802.0], [3583.0, 3804.0], [3575.0, 3804.0], [3579.0, 3812.0], [3574.0, 3823.0], [3572.0, 3835.0], [3585.0, 3846.0], [3605.0, 3855.0], [3614.0, 3851.0], [3637.0, 3864.0], [3650.0, 3861.0], [3660.0, 3867.0], [3651.0, 3869.0], [3642.0, 3864.0], [3633.0, 3865.0], [3624.0, 3870.0], [3615.0, 3855.0], [3603.0, 3857.0], [3589.0, 3857.0], [3579.0, 3850.0], [3571.0, 3846.0], [3567.0, 3837.0], [3571.0, 3829.0], [3573.0, 3811.0], [3563.0, 3805.0], [3550.0, 3802.0], [3560.0, 3798.0], [3564.0, 3784.0], [3561.0, 3797.0], [3574.0, 3775.0], [3554.0, 3769.0], [3547.0, 3778.0], [3553.0, 3769.0], [3543.0, 3759.0], [3534.0, 3759.0], [3522.0, 3759.0], [3533.0, 3757.0], [3541.0, 3757.0], [3554.0, 3764.0], [3560.0, 3745.0], [3575.0, 3738.0], [3566.0, 3733.0], [3543.0, 3711.0], [3568.0, 3731.0], [3579.0, 3726.0], [3580.0, 3718.0], [3579.0, 3708.0], [3567.0, 3708.0], [3571.0, 3700.0], [3580.0, 3691.0], [3573.0, 3672.0], [3574.0, 3655.0], [3564.0, 3655.0], [3563.0, 3640.0], [3548.0, 3640.0], [3537.0, 3646.0], [3537.0, 3659.0], [3537.0, 3648.0], [3529.0, 3648.0], [3540.0, 3640.0], [3551.0, 3634.0], [3543.0, 3628.0], [3522.0, 3626.0], [3507.0, 3629.0], [3508.0, 3641.0], [3496.0, 3636.0], [3493.0, 3644.0], [3496.0, 3653.0], [3490.0, 3645.0], [3491.0, 3654.0], [3482.0, 3653.0], [3486.0, 3667.0], [3474.0, 3666.0], [3474.0, 3676.0], [3485.0, 3689.0], [3474.0, 3681.0], [3472.0, 3668.0], [3463.0, 3666.0], [3460.0, 3677.0], [3456.0, 3662.0], [3441.0, 3668.0], [3438.0, 3682.0], [3428.0, 3684.0], [3424.0, 3692.0], [3418.0, 3705.0], [3423.0, 3717.0], [3416.0, 3725.0], [3416.0, 3702.0], [3420.0, 3692.0], [3428.0, 3671.0], [3438.0, 3669.0], [3443.0, 3660.0], [3439.0, 3649.0], [3431.0, 3650.0], [3439.0, 3646.0], [3441.0, 3636.0], [3446.0, 3656.0], [3465.0, 3656.0], [3473.0, 3652.0], [3474.0, 3644.0], [3483.0, 3633.0], [3475.0, 3630.0], [3472.0, 3622.0], [3465.0, 3630.0], [3461.0, 3621.0], [3472.0, 3619.0], [3468.0, 3608.0], [3474.0, 3620.0], [3484.0, 3623.0], [3507.0, 3621.0], [3506.0, 3612.0], [3514.0, 3608.0], [3505.0, 3611.0], [3490.0, 3607.0], [3500.0, 3606.0], [3493.0, 3583.0], [3492.0, 3575.0], [3472.0, 3576.0], [3463.0, 3574.0], [3481.0, 3569.0], [3491.0, 3567.0], [3508.0, 3577.0], [3511.0, 3559.0], [3496.0, 3556.0], [3487.0, 3503.0], [3447.0, 3502.0], [3440.0, 3554.0], [3411.0, 3553.0], [3385.0, 3540.0], [3361.0, 3536.0], [3349.0, 3543.0], [3344.0, 3560.0], [3343.0, 3575.0], [3329.0, 3577.0], [3329.0, 3578.0], [3320.0, 3578.0], [3312.0, 3580.0], [3304.0, 3591.0], [3317.0, 3587.0], [3309.0, 3591.0], [3312.0, 3599.0], [3307.0, 3619.0], [3304.0, 3630.0], [3300.0, 3621.0], [3307.0, 3617.0], [3307.0, 3608.0], [3300.0, 3596.0], [3301.0, 3587.0], [3293.0, 3581.0], [3274.0, 3583.0], [3283.0, 3583.0], [3290.0, 3595.0], [3278.0, 3605.0], [3264.0, 3607.0], [3268.0, 3617.0], [3259.0, 3627.0], [3246.0, 3628.0], [3254.0, 3637.0], [3267.0, 3635.0], [3271.0, 3644.0], [3263.0, 3643.0], [3253.0, 3644.0], [3230.0, 3654.0], [3226.0, 3668.0], [3216.0, 3667.0], [3212.0, 3671.0], [3197.0, 3671.0], [3206.0, 3694.0], [3203.0, 3703.0], [3193.0, 3709.0], [3192.0, 3725.0], [3196.0, 3738.0], [3187.0, 3749.0], [3192.0, 3764.0], [3208.0, 3762.0], [3210.0, 3775.0], [3197.0, 3781.0], [3186.0, 3780.0], [3184.0, 3788.0], [3185.0, 3780.0], [3199.0, 3779.0], [3209.0, 3773.0], [3207.0, 3763.0], [3190.0, 3765.0], [3184.0, 3749.0], [3184.0, 3729.0], [3190.0, 3719.0], [3182.0, 3710.0], [3195.0, 3699.0], [3185.0, 3690.0], [3181.0, 3672.0], [3188.0, 3658.0], [3204.0, 3659.0], [3210.0, 3649.0], [3217.0, 3637.0], [3231.0, 3638.0], [3240.0, 3610.0], [3234.0, 3601.0], [3253.0, 3597.0], [3257.0, 3586.0], [3262.0, 3565.0], [3272.0, 3560.0], [3287.0, 3563.0], [3295.0, 3557.0], [3306.0, 3521.0], [3292.0, 3508.0], [3289.0, 3516.0], [3280.0, 3520.0], [3274.0, 3533.0], [3260.0, 3531.0], [3248.0, 3540.0], [3236.0, 3553.0], [3229.0, 3565.0], [3219.0, 3564.0], [3216.0, 3574.0], [3201.0, 3577.0], [3192.0, 3578.0], [3202.0, 3576.0], [3215.0, 3573.0], [3218.0, 3562.0], [3227.0, 3564.0], [3242.0, 3541.0], [3236.0, 3518.0], [3228.0, 3519.0], [3237.0, 3517.0], [3241.0, 3532.0], [3252.0, 3524.0], [3250.0, 3513.0], [3260.0, 3519.0], [3274.0, 3520.0], [3263.0, 3509.0], [3271.0, 3507.0], [3280.0, 3507.0], [3278.0, 3478.0], [3270.0, 3458.0], [3261.0, 3455.0], [3272.0, 3458.0], [3280.0, 3462.0], [3284.0, 3471.0], [3298.0, 3477.0], [3294.0, 3456.0], [3297.0, 3444.0], [3286.0, 3430.0], [3257.0, 3423.0], [3241.0, 3406.0], [3239.0, 3394.0], [3220.0, 3388.0], [3201.0, 3366.0], [3183.0, 3358.0], [3175.0, 3364.0], [3184.0, 3374.0], [3175.0, 3367.0], [3149.0, 3375.0], [3134.0, 3374.0], [3121.0, 3376.0], [3113.0, 3380.0], [3098.0, 3378.0], [3105.0, 3388.0], [3100.0, 3405.0], [3108.0, 3409.0], [3107.0, 3418.0], [3098.0, 3405.0], [3100.0, 3397.0], [3089.0, 3395.0], [3101.0, 3394.0], [3095.0, 3375.0], [3105.0, 3369.0], [3115.0, 3348.0], [3123.0, 3357.0], [3134.0, 3357.0], [3137.0, 3366.0], [3160.0, 3369.0], [3158.0, 3357.0], [3167.0, 3337.0], [3164.0, 3324.0], [3151.0, 3311.0], [3117.0, 3300.0], [3073.0, 3300.0], [3058.0, 3297.0], [3046.0, 3288.0], [3030.0, 3260.0], [3031.0, 3236.0], [3049.0, 3183.0], [3048.0, 3164.0], [3037.0, 3143.0], [3038.0, 3124.0], [3034.0, 3109.0], [3026.0, 3107.0], [3030.0, 3089.0], [3026.0, 3072.0], [3016.0, 3062.0], [3013.0, 3047.0], [3024.0, 3051.0], [3032.0, 3048.0], [3032.0, 3056.0], [3038.0, 3046.0], [3029.0, 3028.0], [3017.0, 3020.0], [3006.0, 3004.0], [2998.0, 3016.0], [2993.0, 3040.0], [2982.0, 3053.0], [2991.0, 3061.0], [2981.0, 3054.0], [2975.0, 3071.0], [2966.0, 3091.0], [2970.0, 3100.0], [2973.0, 3109.0], [2981.0, 3120.0], [2973.0, 3133.0], [2962.0, 3130.0], [2955.0, 3128.0], [2967.0, 3128.0], [2976.0, 3119.0], [2968.0, 3110.0], [2969.0, 3102.0], [2967.0, 3094.0], [2941.0, 3099.0], [2925.0, 3096.0], [2916.0, 3089.0], [2905.0, 3073.0], [2894.0, 3056.0], [2866.0, 3042.0], [2862.0, 3029.0], [2847.0, 3019.0], [2834.0, 3024.0], [2836.0, 3038.0], [2829.0, 3047.0], [2813.0, 3051.0], [2803.0, 3052.0], [2794.0, 3061.0], [2776.0, 3065.0], [2778.0, 3061.0], [2769.0, 3061.0], [2794.0, 3053.0], [2803.0, 3040.0], [2814.0, 3045.0], [2822.0, 3037.0], [2824.0, 3024.0], [2832.0, 3014.0], [2838.0, 2988.0], [2812.0, 2990.0], [2745.0, 2984.0], [2713.0, 3000.0], [2693.0, 3001.0], [2656.0, 2970.0], [2639.0, 2961.0], [2623.0, 2943.0], [2613.0, 2942.0], [2582.0, 2927.0], [2571.0, 2929.0], [2565.0, 2941.0], [2553.0, 2936.0], [2539.0, 2944.0], [2529.0, 2945.0], [2538.0, 2938.0], [2550.0, 2923.0], [2556.0, 2923.0], [2564.0, 2923.0], [2567.0, 2895.0], [2560.0, 2885.0], [2559.0, 2868.0], [2549.0, 2856.0], [2547.0, 2842.0], [2551.0, 2832.0], [2542.0, 2828.0], [2558.0, 2826.0], [2538.0, 2811.0], [2529.0, 2811.0], [2532.0, 2822.0], [2523.0, 2822.0], [2496.0, 2840.0], [2472.0, 2883.0], [2454.0, 2888.0], [2452.0, 2896.0], [2461.0, 2901.0], [2451.0, 2897.0], [2451.0, 2885.0], [2433.0, 2868.0], [2423.0, 2869.0], [2419.0, 2844.0], [2413.0, 2802.0], [2398.0, 2818.0], [2383.0, 2818.0], [2375.0, 2824.0], [2356.0, 2820.0], [2346.0, 2822.0], [2331.0, 2817.0], [2325.0, 2826.0], [2309.0, 2833.0], [2309.0, 2840.0], [2309.0, 2832.0], [2315.0, 2821.0], [2288.0, 2841.0], [2266.0, 2843.0], [2255.0, 2836.0], [2250.0, 2809.0], [2238.0, 2810.0], [2224.0, 2792.0], [2221.0, 2813.0], [2191.0, 2823.0], [2170.0, 2839.0], [2182.0, 2825.0], [2163.0, 2809.0], [2155.0, 2799.0], [2150.0, 2786.0], [2142.0, 2794.0], [2133.0, 2801.0], [2121.0, 2797.0], [2119.0, 2783.0], [2121.0, 2769.0], [2143.0, 2760.0], [2155.0, 2743.0], [2156.0, 2733.0], [2146.0, 2721.0], [2130.0, 2721.0], [2105.0, 2709.0], [2096.0, 2695.0], [2097.0, 2680.0], [2115.0, 2648.0], [2114.0, 2621.0], [2119.0, 2643.0], [2103.0, 2682.0], [2102.0, 2693.0], [2112.0, 2709.0], [2149.0, 2716.0], [2168.0, 2738.0], [2165.0, 2748.0], [2173.0, 2759.0], [2164.0, 2751.0], [2165.0, 2764.0], [2167.0, 2778.0], [2177.0, 2781.0], [2190.0, 2775.0], [2204.0, 2774.0], [2222.0, 2772.0], [2251.0, 2770.0], [2275.0, 2782.0], [2285.0, 2773.0], [2275.0, 2783.0], [2278.0, 2791.0], [2313.0, 2799.0], [2350.0, 2796.0], [2339.0, 2793.0], [2343.0, 2778.0], [2334.0, 2780.0], [2338.0, 2772.0], [2347.0, 2793.0], [2362.0, 2799.0], [2371.0, 2794.0], [2389.0, 2783.0], [2405.0, 2786.0], [2423.0, 2781.0], [2435.0, 2771.0], [2425.0, 2773.0], [2447.0, 2765.0], [2442.0, 2775.0], [2433.0, 2779.0], [2440.0, 2792.0], [2441.0, 2805.0], [2432.0, 2851.0], [2441.0, 2866.0], [2454.0, 2851.0], [2473.0, 2839.0], [2496.0, 2816.0], [2487.0, 2811.0], [2475.0, 2823.0], [2464.0, 2822.0], [2455.0, 2821.0], [2475.0, 2819.0], [2485.0, 2810.0], [2497.0, 2814.0], [2514.0, 2807.0], [2527.0, 2793.0], [2540.0, 2804.0], [2566.0, 2808.0], [2563.0, 2818.0], [2569.0, 2826.0], [2571.0, 2859.0], [2608.0, 2909.0], [2634.0, 2931.0], [2651.0, 2930.0], [2670.0, 2938.0], [2668.0, 2928.0], [2674.0, 2920.0], [2684.0, 2922.0], [2698.0, 2917.0], [2697.0, 2908.0], [2682.0, 2898.0], [2699.0, 2907.0], [2715.0, 2903.0], [2725.0, 2893.0], [2724.0, 2884.0], [2715.0, 2876.0], [2716.0, 2861.0], [2706.0, 2839.0], [2698.0, 2834.0], [2702.0, 2825.0], [2700.0, 2840.0], [2710.0, 2849.0], [2718.0, 2853.0], [2715.0, 2835.0], [2728.0, 2829.0], [2726.0, 2817.0], [2715.0, 2810.0], [2709.0, 2795.0], [2725.0, 2785.0], [2727.0, 2750.0], [2731.0, 2736.0], [2722.0, 2729.0], [2706.0, 2728.0], [2721.0, 2728.0], [2722.0, 2717.0], [2712.0, 2713.0], [2722.0, 2716.0], [2731.0, 2700.0], [2726.0, 2691.0], [2708.0, 2707.0], [2696.0, 2710.0], [2689.0, 2725.0], [2679.0, 2729.0], [2676.0, 2695.0], [2693.0, 2682.0], [2685.0, 2680.0], [2665.0, 2662.0], [2668.0, 2653.0], [2685.0, 2646.0], [2686.0, 2625.0], [2680.0, 2638.0], [2669.0, 2644.0], [2653.0, 2636.0], [2653.0, 2652.0], [2649.0, 2660.0], [2635.0, 2666.0], [2627.0, 2666.0], [2626.0, 2651.0], [2627.0, 2643.0], [2631.0, 2645.0], [2631.0, 2662.0], [2646.0, 2654.0], [2652.0, 2634.0], [2672.0, 2640.0], [2677.0, 2627.0], [2688.0, 2623.0], [2689.0, 2642.0], [2686.0, 2650.0], [2678.0, 2655.0], [2670.0, 2654.0], [2687.0, 2669.0], [2695.0, 2658.0], [2691.0, 2667.0], [2702.0, 2677.0], [2694.0, 2694.0], [2682.0, 2699.0], [2688.0, 2707.0], [2696.0, 2701.0], [2705.0, 2701.0], [2716.0, 2689.0], [2728.0, 2687.0], [2732.0, 2695.0], [2735.0, 2704.0], [2745.0, 2693.0], [2724.0, 2724.0], [2736.0, 2740.0], [2744.0, 2740.0], [2743.0, 2732.0], [2746.0, 2745.0], [2753.0, 2732.0], [2753.0, 2740.0], [2773.0, 2748.0], [2771.0, 2740.0], [2778.0, 2751.0], [2792.0, 2742.0], [2782.0, 2746.0], [2782.0, 2757.0], [2782.0, 2767.0], [2768.0, 2768.0], [2765.0, 2783.0], [2761.0, 2791.0], [2766.0, 2791.0], [2766.0, 2783.0], [2759.0, 2795.0], [2760.0, 2805.0], [2748.0, 2798.0], [2754.0, 2786.0], [2740.0, 2784.0], [2748.0, 2798.0], [2733.0, 2806.0], [2746.0, 2821.0], [2758.0, 2826.0], [2765.0, 2812.0], [2763.0, 2825.0], [2760.0, 2839.0], [2738.0, 2845.0], [2727.0, 2840.0], [2728.0, 2849.0], [2739.0, 2849.0], [2743.0, 2861.0], [2759.0, 2855.0], [2764.0, 2842.0], [2772.0, 2843.0], [2783.0, 2856.0], [2774.0, 2881.0], [2753.0, 2881.0], [2729.0, 2871.0], [2733.0, 2882.0], [2744.0, 2893.0], [2743.0, 2902.0], [2732.0, 2908.0], [2740.0, 2922.0], [2768.0, 2925.0], [2807.0, 2919.0], [2823.0, 2928.0], [2848.0, 2940.0], [2864.0, 2973.0], [2864.0, 2959.0], [2873.0, 2954.0], [2881.0, 2943.0], [2900.0, 2942.0], [2891.0, 2941.0], [2904.0, 2939.0], [2874.0, 2956.0], [2873.0, 2967.0], [2884.0, 2967.0], [2895.0, 2970.0], [2938.0, 2977.0], [2982.0, 2968.0], [2993.0, 2960.0], [2984.0, 2966.0], [2969.0, 2964.0], [2970.0, 2953.0], [2973.0, 2943.0], [2985.0, 2947.0], [2981.0, 2957.0], [2993.0, 2949.0], [3044.0, 2957.0], [3061.0, 2963.0], [3071.0, 2970.0], [3074.0, 2958.0], [3084.0, 2960.0], [3075.0, 2973.0], [3098.0, 3002.0], [3114.0, 3055.0], [3123.0, 3051.0], [3118.0, 3030.0], [3121.0, 3019.0], [3119.0, 3031.0], [3126.0, 3040.0], [3139.0, 3033.0], [3153.0, 3035.0], [3145.0, 3033.0], [3139.0, 3041.0], [3130.0, 3047.0], [3139.0, 3054.0], [3128.0, 3051.0], [3118.0, 3060.0], [3116.0, 3071.0], [3116.0, 3071.0]], [[3585.0, 3686.0], [3585.0, 3686.0]], [[3585.0, 3683.0], [3585.0, 3683.0]], [[3583.0, 3682.0], [3585.0, 3682.0]], [[3840.0, 3591.0], [3834.0, 3595.0]], [[3898.0, 3585.0], [3897.0, 3585.0]], [[3583.0, 3560.0], [3583.0, 3559.0]], [[3585.0, 3552.0], [3585.0, 3552.0]], [[3871.0, 3583.0], [3872.0, 3584.0]], [[3585.0, 3523.0], [3585.0, 3528.0]], [[3583.0, 2711.0], [3583.0, 2711.0]], [[3329.0, 2659.0], [3329.0, 2659.0]], [[4095.0, 2657.0], [4099.0, 2657.0]], [[4097.0, 2339.0], [4097.0, 2340.0]], [[4206.0, 1537.0], [4204.0, 1535.0]], [[3313.0, 1535.0], [3316.0, 1535.0]], [[4095.0, 1312.0], [4095.0, 1312.0]], [[4095.0, 1278.0], [4095.0, 1278.0]], [[4096.0, 1276.0], [4096.0, 1276.0]], [[4096.0, 1275.0], [4095.0, 1275.0]], [[4095.0, 1273.0], [4095.0, 1273.0]], [[4095.0, 1268.0], [4096.0, 1268.0]], [[4095.0, 1262.0], [4095.0, 1262.0]], [[4096.0, 1260.0], [4096.0, 1260.0]], [[4095.0, 1238.0], [4095.0, 1236.0]], [[3399.0, 2667.0], [3399.0, 2667.0], [3399.0, 2667.0]], [[3645.0, 2653.0], [3645.0, 2653.0], [3645.0, 2653.0]], [[3645.0, 2651.0], [3645.0, 2638.0], [3645.0, 2651.0], [3645.0, 2651.0]], [[3529.0, 1415.0], [3532.0, 1422.0], [3529.0, 1415.0], [3529.0, 1415.0]], [[3532.0, 1422.0], [3526.0, 1436.0], [3536.0, 1459.0], [3537.0, 1437.0], [3532.0, 1422.0], [3532.0, 1422.0]], [[3742.0, 70.0], [3742.0, 70.0], [3742.0, 70.0]], [[3986.0, 2932.0], [3986.0, 2932.0], [3986.0, 2932.0]], [[3989.0, 2934.0], [3989.0, 2934.0], [3989.0, 2934.0]], [[3531.0, 3432.0], [3531.0, 3432.0], [3523.0, 3430.0], [3531.0, 3432.0], [3531.0, 3432.0]], [[3419.0, 3718.0], [3419.0, 3718.0], [3419.0, 3718.0]], [[3306.0, 3602.0], [3306.0, 3602.0], [3306.0, 3602.0]], [[3242.0, 3400.0], [3242.0, 3400.0], [3242.0, 3400.0]], [[3116.0, 3071.0], [3116.0, 3071.0], [3116.0, 3071.0]], [[3565.0, 3391.0], [3565.0, 3391.0], [3565.0, 3391.0]], [[3565.0, 3391.0], [3565.0, 3391.0], [3565.0, 3391.0]], [[4174.0, 1692.0], [4174.0, 1692.0], [4174.0, 1692.0]], [[4172.0, 1690.0], [4172.0, 1690.0], [4172.0, 1690.0]], [[4172.0, 1690.0], [4169.0, 1684.0], [4161.0, 1680.0], [4172.0, 1690.0], [4172.0, 1690.0]], [[4016.0, 1878.0], [4016.0, 1879.0], [4033.0, 1889.0], [4016.0, 1878.0], [4016.0, 1878.0]], [[4016.0, 1878.0], [4016.0, 1864.0], [4029.0, 1859.0], [4024.0, 1850.0], [4008.0, 1839.0], [4006.0, 1863.0], [4016.0, 1878.0], [4016.0, 1878.0]], [[3315.0, 1339.0], [3327.0, 1332.0], [3331.0, 1324.0], [3323.0, 1329.0], [3311.0, 1313.0], [3325.0, 1327.0], [3321.0, 1308.0], [3312.0, 1308.0], [3290.0, 1293.0], [3305.0, 1315.0], [3315.0, 1339.0], [3315.0, 1339.0]], [[3290.0, 1293.0], [3278.0, 1276.0], [3290.0, 1293.0], [3290.0, 1293.0]], [[3635.0, 3864.0], [3623.0, 3858.0], [3623.0, 3867.0], [3635.0, 3864.0], [3635.0, 3864.0]], [[3305.0, 3591.0], [3305.0, 3591.0], [3305.0, 3591.0]], [[4097.0, 3131.0], [4102.0, 3126.0], [4062.0, 3147.0], [4084.0, 3142.0], [4097.0, 3131.0], [4097.0, 3131.0]], [[4136.0, 3071.0], [4136.0, 3071.0], [4136.0, 3080.0], [4139.0, 3072.0], [4139.0, 3057.0], [4139.0, 3036.0], [4143.0, 3020.0], [4137.0, 3036.0], [4136.0, 3071.0], [4136.0, 3071.0]], [[4193.0, 3071.0], [4206.0, 3071.0], [4193.0, 3071.0], [4193.0, 3071.0]], [[4218.0, 3073.0], [4212.0, 3077.0], [4203.0, 3078.0], [4206.0, 3087.0], [4188.0, 3104.0], [4183.0, 3114.0], [4171.0, 3114.0], [4172.0, 3122.0], [4164.0, 3122.0], [4143.0, 3131.0], [4125.0, 3156.0], [4117.0, 3157.0], [4128.0, 3161.0], [4159.0, 3147.0], [4223.0, 3071.0], [4224.0, 3071.0], [4224.0, 3063.0], [4218.0, 3073.0], [4218.0, 3073.0]], [[4113.0, 3121.0], [4113.0, 3111.0], [4102.0, 3117.0], [4113.0, 3121.0], [4113.0, 3121.0]], [[4176.0, 3076.0], [4176.0, 3076.0], [4176.0, 3076.0]], [[4200.0, 3077.0], [4200.0, 3077.0], [4200.0, 3077.0]], [[3617.0, 3544.0], [3614.0, 3548.0], [3617.0, 3544.0], [3617.0, 3544.0]], [[3912.0, 3542.0], [3924.0, 3541.0], [3934.0, 3536.0], [3942.0, 3535.0], [3955.0, 3536.0], [3939.0, 3532.0], [3949.0, 3533.0], [3937.0, 3527.0], [3929.0, 3534.0], [3900.0, 3540.0], [3912.0, 3542.0], [3912.0, 3542.0]], [[3882.0, 3558.0], [3882.0, 3558.0], [3882.0, 3558.0]], [[3876.0, 3557.0], [3876.0, 3557.0], [3876.0, 3557.0]], [[3867.0, 3549.0], [3867.0, 3549.0], [3867.0, 3549.0]], [[3542.0, 3361.0], [3542.0, 3361.0], [3542.0, 3361.0]], [[3562.0, 3323.0], [3562.0, 3323.0], [3562.0, 3323.0]], [[3566.0, 3315.0], [3569.0, 3318.0], [3566.0, 3315.0], [3566.0, 3315.0]], [[4061.0, 3204.0], [4063.0, 3200.0], [4056.0, 3190.0], [4061.0, 3204.0], [4061.0, 3204.0]], [[4050.0, 3172.0], [4049.0, 3161.0], [4044.0, 3170.0], [4047.0, 3190.0], [4039.0, 3183.0], [4044.0, 3171.0], [4033.0, 3187.0], [4034.0, 3197.0], [4043.0, 3210.0], [4063.0, 3214.0], [4085.0, 3209.0], [4092.0, 3193.0], [4090.0, 3179.0], [4068.0, 3166.0], [4068.0, 3174.0], [4080.0, 3181.0], [4081.0, 3192.0], [4077.0, 3205.0], [4064.0, 3206.0], [4051.0, 3200.0], [4050.0, 3172.0], [4050.0, 3172.0]], [[4123.0, 2945.0], [4123.0, 2945.0], [4123.0, 2945.0]], [[4158.0, 2943.0], [4160.0, 2943.0], [4160.0, 2935.0], [4158.0, 2943.0], [4158.0, 2943.0]], [[4163.0, 3063.0], [4163.0, 3063.0], [4163.0, 3063.0]], [[4167.0, 3060.0], [4167.0, 3060.0], [4167.0, 3060.0]], [[4145.0, 3018.0], [4147.0, 3005.0], [4145.0, 3018.0], [4145.0, 3018.0]], [[4151.0, 2998.0], [4158.0, 2977.0], [4155.0, 2967.0], [4159.0, 2946.0], [4150.0, 2976.0], [4147.0, 2988.0], [4151.0, 2985.0], [4151.0, 2998.0], [4151.0, 2998.0]], [[4176.0, 3066.0], [4176.0, 3066.0], [4176.0, 3066.0]], [[4185.0, 3071.0], [4189.0, 3067.0], [4185.0, 3071.0], [4185.0, 3071.0]], [[3460.0, 3072.0], [3462.0, 3080.0], [3477.0, 3083.0], [3477.0, 3075.0], [3479.0, 3067.0], [3460.0, 3072.0], [3460.0, 3072.0]], [[3433.0, 2796.0], [3432.0, 2792.0], [3422.0, 2793.0], [3433.0, 2796.0], [3433.0, 2796.0]], [[3475.0, 2876.0], [3482.0, 2867.0], [3475.0, 2876.0], [3475.0, 2876.0]], [[3438.0, 2804.0], [3438.0, 2804.0], [3438.0, 2804.0]], [[3440.0, 2953.0], [3440.0, 2953.0], [3440.0, 2953.0]], [[3444.0, 2825.0], [3444.0, 2825.0], [3444.0, 2825.0]], [[3490.0, 2860.0], [3490.0, 2860.0], [3490.0, 2860.0]], [[3418.0, 2748.0], [3407.0, 2743.0], [3404.0, 2735.0], [3418.0, 2748.0], [3418.0, 2748.0]], [[3457.0, 2870.0], [3457.0, 2870.0], [3457.0, 2870.0]], [[3417.0, 2974.0], [3419.0, 2958.0], [3400.0, 2957.0], [3407.0, 2969.0], [3417.0, 2974.0], [3417.0, 2974.0]], [[3379.0, 2902.0], [3391.0, 2899.0], [3379.0, 2902.0], [3379.0, 2902.0]], [[4209.0, 2617.0], [4209.0, 2617.0], [4209.0, 2617.0]], [[2174.0, 2799.0], [2174.0, 2799.0], [2174.0, 2799.0]], [[2762.0, 2876.0], [2769.0, 2873.0], [2752.0, 2869.0], [2738.0, 2867.0], [2738.0, 2869.0], [2751.0, 2869.0], [2762.0, 2876.0], [2762.0, 2876.0]], [[2754.0, 2782.0], [2764.0, 2779.0], [2766.0, 2769.0], [2739.0, 2775.0], [2754.0, 2782.0], [2754.0, 2782.0]], [[2735.0, 2862.0], [2735.0, 2862.0], [2735.0, 2862.0]], [[2745.0, 2763.0], [2752.0, 2758.0], [2752.0, 2754.0], [2732.0, 2754.0], [2745.0, 2763.0], [2745.0, 2763.0]], [[2679.0, 2726.0], [2688.0, 2709.0], [2682.0, 2701.0], [2679.0, 2726.0], [2679.0, 2726.0]], [[2920.0, 3005.0], [2935.0, 2994.0], [2914.0, 2986.0], [2896.0, 2981.0], [2884.0, 2974.0], [2864.0, 2973.0], [2868.0, 2983.0], [2886.0, 2997.0], [2905.0, 3016.0], [2916.0, 3016.0], [2920.0, 3005.0], [2920.0, 3005.0]], [[4096.0, 2494.0], [4096.0, 2494.0], [4096.0, 2494.0]], [[3571.0, 2424.0], [3572.0, 2433.0], [3567.0, 2446.0], [3545.0, 2469.0], [3553.0, 2469.0], [3559.0, 2457.0], [3576.0, 2461.0], [3578.0, 2451.0], [3577.0, 2467.0], [3587.0, 2474.0], [3594.0, 2467.0], [3594.0, 2475.0], [3608.0, 2480.0], [3610.0, 2469.0], [3596.0, 2434.0], [3580.0, 2421.0], [3571.0, 2424.0], [3571.0, 2424.0]], [[3637.0, 2553.0], [3628.0, 2527.0], [3637.0, 2553.0], [3637.0, 2553.0]], [[4064.0, 2507.0], [4064.0, 2507.0], [4064.0, 2507.0]], [[3622.0, 2504.0], [3622.0, 2504.0], [3622.0, 2504.0]], [[3620.0, 2493.0], [3618.0, 2484.0], [3620.0, 2493.0], [3620.0, 2493.0]], [[3730.0, 1024.0], [3735.0, 1040.0], [3748.0, 1056.0], [3748.0, 1040.0], [3730.0, 1024.0], [3730.0, 1024.0]], [[3740.0, 1025.0], [3738.0, 1026.0], [3735.0, 993.0], [3728.0, 984.0], [3725.0, 995.0], [3732.0, 1009.0], [3733.0, 1001.0], [3740.0, 1025.0], [3740.0, 1025.0]], [[4095.0, 1229.0], [4093.0, 1228.0], [4081.0, 1217.0], [4073.0, 1234.0], [4071.0, 1253.0], [4064.0, 1278.0], [4076.0, 1293.0], [4087.0, 1283.0], [4097.0, 1285.0], [4110.0, 1286.0], [4101.0, 1291.0], [4121.0, 1285.0], [4119.0, 1276.0], [4104.0, 1258.0], [4096.0, 1258.0], [4093.0, 1268.0], [4094.0, 1258.0], [4096.0, 1245.0], [4095.0, 1229.0], [4095.0, 1229.0]], [[4173.0, 1536.0], [4167.0, 1554.0], [4153.0, 1576.0], [4166.0, 1585.0], [4154.0, 1576.0], [4163.0, 1574.0], [4172.0, 1578.0], [4183.0, 1582.0], [4186.0, 1574.0], [4198.0, 1571.0], [4190.0, 1562.0], [4191.0, 1551.0], [4199.0, 1547.0], [4196.0, 1538.0], [4196.0, 1532.0], [4188.0, 1532.0], [4196.0, 1532.0], [4204.0, 1527.0], [4201.0, 1535.0], [4207.0, 1535.0], [4207.0, 1527.0], [4213.0, 1535.0], [4224.0, 1512.0], [4224.0, 1511.0], [4209.0, 1511.0], [4198.0, 1506.0], [4190.0, 1517.0], [4194.0, 1509.0], [4192.0, 1499.0], [4200.0, 1496.0], [4202.0, 1504.0], [4224.0, 1510.0], [4224.0, 1488.0], [4215.0, 1486.0], [4216.0, 1478.0], [4224.0, 1472.0], [4224.0, 1464.0], [4207.0, 1458.0], [4193.0, 1464.0], [4189.0, 1476.0], [4197.0, 1465.0], [4203.0, 1476.0], [4204.0, 1464.0], [4212.0, 1469.0], [4204.0, 1480.0], [4195.0, 1477.0], [4198.0, 1491.0], [4207.0, 1488.0], [4196.0, 1493.0], [4173.0, 1536.0], [4173.0, 1536.0]], [[3934.0, 1537.0], [3932.0, 1553.0], [3936.0, 1580.0], [3944.0, 1579.0], [3950.0, 1595.0], [3960.0, 1604.0], [3968.0, 1630.0], [3964.0, 1639.0], [3963.0, 1650.0], [3960.0, 1666.0], [3968.0, 1673.0], [3968.0, 1664.0], [3983.0, 1662.0], [3975.0, 1640.0], [3981.0, 1627.0], [3980.0, 1618.0], [3970.0, 1606.0], [3959.0, 1589.0], [3973.0, 1578.0], [3975.0, 1562.0], [3971.0, 1570.0], [3972.0, 1556.0], [3964.0, 1540.0], [3959.0, 1537.0], [3943.0, 1537.0], [3934.0, 1537.0], [3934.0, 1537.0]], [[3959.0, 1535.0], [3959.0, 1535.0], [3959.0, 1535.0]], [[4163.0, 1915.0], [4161.0, 1880.0], [4156.0, 1894.0], [4163.0, 1915.0], [4163.0, 1915.0]], [[4151.0, 1726.0], [4153.0, 1719.0], [4145.0, 1717.0], [4151.0, 1726.0], [4151.0, 1726.0]], [[4147.0, 1710.0], [4150.0, 1702.0], [4147.0, 1710.0], [4147.0, 1710.0]], [[4182.0, 1653.0], [4177.0, 1649.0], [4187.0, 1648.0], [4195.0, 1643.0], [4189.0, 1635.0], [4198.0, 1632.0], [4196.0, 1624.0], [4186.0, 1624.0], [4175.0, 1638.0], [4167.0, 1647.0], [4169.0, 1657.0], [4164.0, 1646.0], [4165.0, 1632.0], [4175.0, 1633.0], [4178.0, 1633.0], [4178.0, 1625.0], [4168.0, 1619.0], [4166.0, 1622.0], [4166.0, 1614.0], [4161.0, 1661.0], [4157.0, 1674.0], [4171.0, 1676.0], [4169.0, 1666.0], [4163.0, 1656.0], [4176.0, 1673.0], [4180.0, 1665.0], [4180.0, 1674.0], [4189.0, 1682.0], [4209.0, 1678.0], [4193.0, 1677.0], [4192.0, 1666.0], [4200.0, 1669.0], [4209.0, 1673.0], [4216.0, 1671.0], [4216.0, 1655.0], [4205.0, 1653.0], [4197.0, 1647.0], [4182.0, 1653.0], [4182.0, 1653.0]], [[4200.0, 1619.0], [4196.0, 1620.0], [4200.0, 1632.0], [4195.0, 1642.0], [4207.0, 1648.0], [4211.0, 1636.0], [4205.0, 1627.0], [4203.0, 1636.0], [4200.0, 1619.0], [4200.0, 1619.0]], [[4170.0, 1684.0], [4172.0, 1689.0], [4184.0, 1683.0], [4177.0, 1675.0], [4170.0, 1684.0], [4170.0, 1684.0]], [[4026.0, 1835.0], [4025.0, 1830.0], [4020.0, 1821.0], [4016.0, 1808.0], [4007.0, 1836.0], [4019.0, 1836.0], [4011.0, 1832.0], [4009.0, 1822.0], [4020.0, 1830.0], [4016.0, 1820.0], [4026.0, 1835.0], [4026.0, 1835.0]], [[4003.0, 1782.0], [3999.0, 1779.0], [4003.0, 1782.0], [4003.0, 1782.0]], [[4020.0, 1840.0], [4020.0, 1840.0], [4020.0, 1840.0]], [[4037.0, 1849.0], [4044.0, 1837.0], [4036.0, 1833.0], [4037.0, 1849.0], [4037.0, 1849.0]], [[3982.0, 1555.0], [3982.0, 1555.0], [3982.0, 1555.0]], [[3971.0, 1555.0], [3975.0, 1554.0], [3973.0, 1545.0], [3965.0, 1537.0], [3971.0, 1555.0], [3971.0, 1555.0]], [[4005.0, 1781.0], [4001.0, 1770.0], [3993.0, 1764.0], [4005.0, 1781.0], [4005.0, 1781.0]], [[3941.0, 1613.0], [3944.0, 1598.0], [3941.0, 1613.0], [3941.0, 1613.0]], [[3976.0, 1591.0], [3978.0, 1588.0], [3976.0, 1591.0], [3976.0, 1591.0]], [[4095.0, 1317.0], [4095.0, 1317.0], [4097.0, 1291.0], [4088.0, 1298.0], [4081.0, 1309.0], [4088.0, 1322.0], [4092.0, 1304.0], [4095.0, 1317.0], [4095.0, 1317.0]], [[4130.0, 1263.0], [4130.0, 1263.0], [4130.0, 1263.0]], [[4203.0, 1352.0], [4201.0, 1345.0], [4203.0, 1352.0], [4203.0, 1352.0]], [[4216.0, 1371.0], [4205.0, 1369.0], [4203.0, 1363.0], [4191.0, 1363.0], [4200.0, 1366.0], [4206.0, 1385.0], [4216.0, 1371.0], [4216.0, 1371.0]], [[4199.0, 1384.0], [4192.0, 1379.0], [4182.0, 1389.0], [4190.0, 1393.0], [4192.0, 1402.0], [4206.0, 1412.0], [4216.0, 1401.0], [4208.0, 1401.0], [4209.0, 1393.0], [4199.0, 1384.0], [4199.0, 1384.0]], [[4196.0, 1408.0], [4190.0, 1407.0], [4189.0, 1417.0], [4199.0, 1420.0], [4200.0, 1410.0], [4196.0, 1410.0], [4196.0, 1418.0], [4196.0, 1408.0], [4196.0, 1408.0]], [[3308.0, 1121.0], [3308.0, 1121.0], [3308.0, 1121.0]], [[3911.0, 1297.0], [3911.0, 1297.0], [3911.0, 1297.0]], [[3797.0, 1096.0], [3798.0, 1084.0], [3802.0, 1090.0], [3802.0, 1081.0], [3801.0, 1072.0], [3795.0, 1082.0], [3797.0, 1096.0], [3797.0, 1096.0]], [[3760.0, 1062.0], [3759.0, 1054.0], [3749.0, 1051.0], [3752.0, 1061.0], [3760.0, 1062.0], [3760.0, 1062.0]], [[3939.0, 1523.0], [3944.0, 1517.0], [3939.0, 1523.0], [3939.0, 1523.0]], [[3874.0, 1315.0], [3881.0, 1311.0], [3880.0, 1298.0], [3877.0, 1307.0], [3874.0, 1315.0], [3874.0, 1315.0]], [[3926.0, 1251.0], [3930.0, 1256.0], [3948.0, 1251.0], [3959.0, 1255.0], [3963.0, 1243.0], [3953.0, 1235.0], [3950.0, 1224.0], [3940.0, 1222.0], [3953.0, 1218.0], [3963.0, 1218.0], [3969.0, 1206.0], [3964.0, 1188.0], [3954.0, 1185.0], [3944.0, 1188.0], [3952.0, 1172.0], [3933.0, 1150.0], [3924.0, 1154.0], [3913.0, 1149.0], [3902.0, 1153.0], [3924.0, 1164.0], [3923.0, 1173.0], [3908.0, 1181.0], [3887.0, 1180.0], [3878.0, 1173.0], [3888.0, 1210.0], [3874.0, 1211.0], [3876.0, 1229.0], [3882.0, 1239.0], [3884.0, 1231.0], [3897.0, 1235.0], [3893.0, 1227.0], [3880.0, 1218.0], [3896.0, 1229.0], [3904.0, 1227.0], [3892.0, 1217.0], [3903.0, 1221.0], [3904.0, 1206.0], [3894.0, 1207.0], [3898.0, 1205.0], [3898.0, 1197.0], [3912.0, 1213.0], [3929.0, 1205.0], [3921.0, 1216.0], [3926.0, 1251.0], [3926.0, 1251.0]], [[3926.0, 1354.0], [3922.0, 1360.0], [3958.0, 1389.0], [3953.0, 1374.0], [3962.0, 1375.0], [3972.0, 1373.0], [3984.0, 1390.0], [3992.0, 1388.0], [3989.0, 1384.0], [3997.0, 1384.0], [3991.0, 1352.0], [3982.0, 1338.0], [3968.0, 1332.0], [3960.0, 1322.0], [3955.0, 1299.0], [3954.0, 1307.0], [3943.0, 1311.0], [3946.0, 1321.0], [3935.0, 1329.0], [3946.0, 1335.0], [3956.0, 1338.0], [3959.0, 1348.0], [3956.0, 1361.0], [3964.0, 1362.0], [3955.0, 1370.0], [3952.0, 1360.0], [3951.0, 1368.0], [3940.0, 1363.0], [3926.0, 1354.0], [3926.0, 1354.0]], [[3964.0, 1319.0], [3977.0, 1297.0], [3965.0, 1319.0], [3974.0, 1329.0], [3974.0, 1313.0], [3983.0, 1297.0], [3980.0, 1307.0], [3984.0, 1285.0], [3975.0, 1284.0], [3971.0, 1299.0], [3963.0, 1301.0], [3964.0, 1311.0], [3964.0, 1319.0], [3964.0, 1319.0]], [[3880.0, 1273.0], [3882.0, 1260.0], [3874.0, 1251.0], [3873.0, 1262.0], [3880.0, 1273.0], [3880.0, 1273.0]], [[3899.0, 1252.0], [3899.0, 1252.0], [3899.0, 1252.0]], [[2850.0, 1094.0], [2856.0, 1086.0], [2850.0, 1094.0], [2850.0, 1094.0]], [[2827.0, 1062.0], [2827.0, 1062.0], [2827.0, 1062.0]], [[2920.0, 1145.0], [2924.0, 1142.0], [2917.0, 1130.0], [2920.0, 1145.0], [2920.0, 1145.0]], [[2928.0, 1260.0], [2930.0, 1254.0], [2922.0, 1241.0], [2899.0, 1222.0], [2922.0, 1242.0], [2928.0, 1260.0], [2928.0, 1260.0]], [[2778.0, 1057.0], [2785.0, 1044.0], [2770.0, 1042.0], [2746.0, 1030.0], [2778.0, 1057.0], [2778.0, 1057.0]], [[2115.0, 589.0], [2115.0, 589.0], [2115.0, 589.0]], [[3687.0, 959.0], [3682.0, 948.0], [3672.0, 941.0], [3669.0, 928.0], [3681.0, 942.0], [3680.0, 927.0], [3678.0, 908.0], [3689.0, 908.0], [3698.0, 920.0], [3686.0, 901.0], [3696.0, 903.0], [3684.0, 896.0], [3678.0, 885.0], [3666.0, 890.0], [3664.0, 908.0], [3670.0, 921.0], [3665.0, 930.0], [3671.0, 947.0], [3678.0, 959.0], [3687.0, 959.0], [3687.0, 959.0]], [[3606.0, 543.0], [3602.0, 537.0], [3602.0, 524.0], [3606.0, 543.0], [3606.0, 543.0]], [[3631.0, 900.0], [3631.0, 900.0], [3631.0, 900.0]], [[3512.0, 596.0], [3514.0, 583.0], [3512.0, 596.0], [3512.0, 596.0]], [[3628.0, 906.0], [3629.0, 897.0], [3619.0, 900.0], [3628.0, 906.0], [3628.0, 906.0]], [[3630.0, 928.0], [3639.0, 923.0], [3632.0, 913.0], [3630.0, 914.0], [3630.0, 928.0], [3630.0, 928.0]], [[3514.0, 576.0], [3514.0, 576.0], [3514.0, 576.0]], [[3452.0, 79.0], [3436.0, 104.0], [3437.0, 124.0], [3448.0, 146.0], [3456.0, 149.0], [3476.0, 141.0], [3460.0, 133.0], [3459.0, 125.0], [3452.0, 116.0], [3450.0, 107.0], [3467.0, 85.0], [3460.0, 75.0], [3452.0, 79.0], [3452.0, 79.0]], [[3751.0, 927.0], [3754.0, 924.0], [3746.0, 910.0], [3738.0, 906.0], [3729.0, 914.0], [3737.0, 921.0], [3720.0, 923.0], [3719.0, 942.0], [3739.0, 929.0], [3751.0, 927.0], [3751.0, 927.0]], [[3464.0, 131.0], [3475.0, 136.0], [3475.0, 128.0], [3464.0, 131.0], [3464.0, 131.0]], [[3754.0, 98.0], [3750.0, 80.0], [3754.0, 98.0], [3754.0, 98.0]], [[3221.0, -111.0], [3221.0, -111.0], [3221.0, -111.0]], [[3538.0, 1694.0], [3526.0, 1695.0], [3538.0, 1694.0], [3538.0, 1694.0]], [[4182.0, 1653.0], [4182.0, 1653.0], [4182.0, 1653.0]], [[4186.0, 1574.0], [4186.0, 1574.0], [4186.0, 1574.0]], [[4204.0, 1527.0], [4204.0, 1527.0], [4204.0, 1527.0]], [[4196.0, 1493.0], [4196.0, 1493.0], [4196.0, 1493.0]], [[4202.0, 1495.0], [4202.0, 1495.0], [4202.0, 1495.0]], [[4202.0, 1495.0], [4213.0, 1492.0], [4202.0, 1495.0], [4202.0, 1495.0]], [[4211.0, 1491.0], [4211.0, 1491.0], [4211.0, 1491.0]], [[4214.0, 1488.0], [4214.0, 1488.0], [4214.0, 1488.0]], [[4211.0, 1491.0], [4211.0, 1491.0], [4211.0, 1491.0]], [[4089.0, 1275.0], [4089.0, 1275.0], [4089.0, 1275.0]], [[4099.0, 1265.0], [4102.0, 1266.0], [4101.0, 1276.0], [4099.0, 1265.0], [4099.0, 1265.0]], [[4072.0, 1259.0], [4072.0, 1259.0], [4072.0, 1259.0]], [[4072.0, 1259.0], [4072.0, 1259.0], [4072.0, 1259.0]], [[4099.0, 1265.0], [4099.0, 1265.0], [4099.0, 1265.0]], [[3792.0, 525.0], [3792.0, 525.0], [3792.0, 525.0]], [[3792.0, 525.0], [3792.0, 517.0], [3792.0, 525.0], [3792.0, 525.0]], [[2897.0, 3001.0], [2897.0, 3001.0], [2897.0, 3001.0]], [[2918.0, 3005.0], [2918.0, 3005.0], [2918.0, 3005.0]], [[2920.0, 3005.0], [2920.0, 3005.0], [2920.0, 3005.0]], [[2918.0, 3005.0], [2918.0, 3005.0], [2918.0, 3005.0]], [[2906.0, 3002.0], [2916.0, 3001.0], [2906.0, 3002.0], [2897.0, 3001.0], [2906.0, 3002.0], [2906.0, 3002.0]], [[2748.0, 2798.0], [2748.0, 2798.0], [2748.0, 2798.0]], [[3570.0, 2759.0], [3570.0, 2759.0], [3570.0, 2759.0]], [[3300.0, 2746.0], [3300.0, 2746.0], [3300.0, 2746.0]], [[3114.0, 2710.0], [3114.0, 2710.0], [3114.0, 2710.0]], [[3114.0, 2710.0], [3118.0, 2703.0], [3114.0, 2710.0], [3114.0, 2710.0]], [[4095.0, 2569.0], [4098.0, 2560.0], [4106.0, 2555.0], [4095.0, 2569.0], [4095.0, 2569.0]], [[4048.0, 2598.0], [4048.0, 2598.0], [4048.0, 2598.0]], [[4048.0, 2598.0], [4044.0, 2590.0], [4048.0, 2590.0], [4048.0, 2598.0], [4048.0, 2598.0]], [[3605.0, 3551.0], [3605.0, 3551.0], [3605.0, 3551.0]], [[3897.0, 3536.0], [3893.0, 3537.0], [3897.0, 3536.0], [3897.0, 3536.0]], [[3938.0, 3532.0], [3938.0, 3532.0], [3938.0, 3532.0]], [[3938.0, 3532.0], [3938.0, 3532.0], [3938.0, 3532.0]], [[3656.0, 3693.0], [3656.0, 3693.0], [3656.0, 3693.0]], [[3654.0, 3671.0], [3654.0, 3671.0], [3654.0, 3671.0]], [[3300.0, 3621.0], [3300.0, 3621.0], [3300.0, 3621.0]], [[3306.0, 3594.0], [3306.0, 3594.0], [3306.0, 3594.0]]];
-testOutput["water_huge"]["triangles"]=[4771, 4772, 4773, 4773, 4774, 4775, 4775, 4776, 4777, 4777, 4778, 4779, 4780, 4781, 4782, 4782, 185, 186, 187, 188, 189, 189, 190, 191, 191, 192, 193, 194, 195, 196, 201, 202, 203, 206, 207, 208, 208, 209, 210, 210, 211, 212, 214, 215, 216, 216, 217, 218, 218, 219, 220, 222, 223, 224, 226, 227, 228, 231, 232, 233, 233, 234, 235, 238, 239, 240, 240, 241, 242, 243, 244, 245, 245, 246, 247, 247, 248, 249, 252, 4897, 4898, 4899, 4900, 4901, 4901, 4902, 252, 253, 254, 255, 256, 257, 258, 258, 259, 260, 262, 263, 264, 264, 265, 266, 266, 267, 268, 270, 271, 272, 272, 273, 274, 274, 275, 276, 278, 279, 280, 282, 283, 284, 284, 285, 286, 287, 288, 289, 289, 290, 291, 293, 294, 295, 301, 302, 303, 303, 304, 305, 305, 306, 307, 307, 308, 309, 310, 311, 312, 4935, 314, 315, 315, 316, 317, 318, 319, 320, 320, 321, 322, 322, 323, 324, 325, 326, 327, 327, 328, 329, 333, 334, 335, 335, 336, 337, 337, 338, 339, 341, 342, 343, 343, 344, 345, 345, 346, 347, 347, 348, 349, 349, 350, 351, 352, 353, 354, 355, 356, 357, 357, 358, 359, 359, 360, 361, 362, 363, 364, 364, 365, 366, 366, 367, 368, 368, 369, 370, 371, 372, 373, 373, 374, 375, 375, 376, 377, 381, 382, 383, 383, 384, 385, 386, 387, 388, 391, 392, 393, 395, 396, 397, 398, 399, 400, 400, 401, 402, 406, 407, 408, 409, 410, 411, 411, 412, 413, 414, 415, 416, 416, 417, 418, 420, 421, 422, 423, 424, 425, 426, 427, 428, 431, 432, 433, 434, 435, 436, 438, 439, 440, 441, 442, 443, 443, 444, 445, 447, 448, 449, 449, 450, 451, 453, 454, 455, 455, 456, 457, 457, 458, 459, 460, 461, 462, 463, 464, 465, 472, 473, 474, 474, 475, 476, 478, 479, 480, 480, 481, 482, 483, 484, 485, 488, 489, 490, 490, 491, 492, 493, 494, 495, 496, 497, 498, 501, 502, 503, 503, 504, 505, 506, 507, 508, 509, 510, 511, 511, 512, 513, 515, 516, 517, 517, 518, 519, 519, 520, 521, 524, 525, 526, 529, 530, 531, 531, 532, 533, 534, 4906, 4904, 535, 536, 537, 537, 538, 539, 539, 540, 541, 543, 544, 545, 545, 546, 547, 547, 548, 549, 551, 552, 553, 553, 554, 555, 557, 558, 559, 562, 563, 564, 567, 568, 569, 4927, 571, 572, 574, 575, 576, 577, 578, 579, 580, 581, 582, 582, 583, 584, 584, 585, 586, 586, 587, 588, 590, 591, 592, 593, 594, 4623, 4623, 594, 595, 595, 596, 597, 597, 598, 599, 599, 600, 601, 601, 602, 603, 603, 604, 605, 605, 606, 607, 608, 609, 610, 610, 611, 612, 613, 614, 615, 618, 619, 620, 620, 621, 622, 622, 623, 624, 628, 629, 630, 631, 632, 633, 633, 634, 635, 636, 637, 638, 641, 642, 643, 643, 644, 645, 645, 646, 647, 650, 651, 652, 654, 655, 656, 656, 657, 658, 660, 661, 662, 662, 663, 664, 666, 667, 668, 669, 670, 671, 673, 674, 675, 676, 677, 678, 679, 680, 681, 682, 683, 684, 684, 685, 686, 686, 687, 688, 692, 693, 694, 694, 695, 696, 698, 699, 700, 700, 701, 702, 702, 703, 704, 705, 706, 707, 707, 708, 709, 713, 714, 715, 718, 719, 720, 721, 722, 723, 723, 724, 725, 725, 726, 727, 727, 728, 729, 729, 730, 731, 731, 732, 733, 733, 734, 735, 735, 736, 738, 739, 740, 741, 743, 744, 745, 746, 747, 748, 751, 752, 753, 754, 755, 756, 759, 760, 761, 766, 767, 768, 768, 769, 770, 771, 772, 4621, 4621, 772, 773, 777, 778, 779, 781, 782, 783, 784, 785, 786, 786, 787, 788, 789, 790, 791, 792, 793, 794, 795, 796, 797, 797, 798, 799, 801, 802, 803, 804, 805, 806, 808, 809, 810, 810, 811, 812, 813, 814, 815, 818, 819, 820, 820, 821, 822, 822, 823, 824, 826, 827, 828, 832, 833, 834, 834, 835, 836, 837, 838, 839, 839, 840, 841, 842, 843, 844, 845, 846, 847, 849, 850, 851, 851, 852, 853, 853, 854, 855, 857, 858, 859, 859, 4995, 4996, 4996, 4997, 4998, 4998, 4999, 5000, 5001, 5002, 5003, 4992, 4993, 4994, 4994, 4995, 859, 861, 862, 863, 863, 864, 865, 865, 866, 867, 867, 868, 869, 870, 871, 872, 873, 874, 875, 876, 877, 878, 878, 879, 880, 880, 881, 882, 882, 883, 884, 885, 886, 887, 888, 889, 890, 891, 892, 893, 895, 896, 897, 897, 898, 899, 901, 902, 903, 904, 905, 906, 906, 907, 908, 909, 910, 911, 911, 912, 913, 914, 915, 916, 916, 917, 918, 918, 919, 920, 924, 925, 926, 927, 928, 929, 931, 932, 933, 933, 934, 935, 935, 936, 937, 937, 938, 939, 939, 940, 941, 944, 945, 946, 948, 949, 950, 950, 951, 952, 954, 955, 956, 957, 958, 959, 959, 960, 961, 962, 963, 964, 966, 967, 968, 968, 969, 970, 971, 972, 973, 973, 974, 975, 977, 978, 979, 984, 985, 986, 987, 988, 989, 991, 992, 993, 994, 995, 996, 996, 997, 998, 998, 999, 1000, 1001, 1002, 1003, 1003, 1004, 1005, 1011, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 1019, 1019, 1020, 1021, 1023, 1024, 1025, 1025, 1026, 1027, 1027, 1028, 1029, 1029, 1030, 1031, 1032, 1033, 1034, 1034, 1035, 1036, 1040, 1041, 1042, 1042, 1043, 1044, 1046, 1047, 1048, 1048, 1049, 1050, 1051, 1052, 1053, 1053, 1054, 1055, 1057, 1058, 1059, 1060, 1061, 1062, 1063, 1064, 1065, 1068, 1069, 1070, 1070, 1071, 1072, 1077, 1078, 1079, 1080, 1081, 1082, 1082, 1083, 1084, 1086, 1087, 1088, 1092, 1093, 1094, 1095, 1096, 1097, 1100, 1101, 1102, 1105, 1106, 1107, 1107, 1108, 1109, 1110, 1111, 1112, 1114, 1115, 1116, 1116, 1117, 1118, 1119, 1120, 1121, 1121, 1122, 1123, 1125, 1126, 1127, 1127, 1128, 1129, 1130, 1131, 1132, 1134, 1135, 1136, 1137, 1138, 1139, 1142, 1143, 1144, 1145, 1146, 1147, 1147, 5142, 5140, 5142, 1147, 1148, 1148, 1150, 1151, 1151, 1152, 1153, 1153, 1154, 1155, 1157, 1158, 1159, 1162, 1163, 1164, 1164, 1165, 1166, 1167, 1168, 1169, 1170, 1171, 1172, 1172, 1173, 1174, 1174, 1175, 1176, 1177, 1178, 1179, 1179, 1180, 1181, 1182, 1183, 1184, 1184, 1185, 1186, 1186, 1187, 1188, 1190, 1191, 1192, 1192, 1193, 1194, 1195, 1196, 1197, 1197, 1198, 1199, 1200, 1201, 1202, 1202, 1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1212, 1213, 1214, 1215, 1216, 1217, 1218, 1219, 1220, 1220, 1221, 1222, 1222, 1223, 1224, 1225, 1226, 1227, 1227, 1228, 1229, 1230, 1231, 1232, 1233, 1235, 1236, 1240, 1241, 1242, 1242, 1243, 1244, 1247, 1248, 1249, 1249, 1250, 1251, 1253, 1254, 1255, 1255, 1256, 1257, 1257, 1258, 1259, 1259, 1260, 1261, 1261, 1262, 1263, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1276, 1277, 1278, 1278, 4724, 4714, 4716, 4719, 4720, 4723, 4724, 1278, 1278, 1279, 1280, 1281, 5213, 5214, 5214, 5215, 5216, 5217, 5218, 5209, 5230, 5210, 5211, 1283, 1284, 1285, 1285, 1286, 1287, 1289, 1290, 1291, 1295, 1296, 1297, 1298, 1299, 1300, 1301, 1302, 1303, 1304, 1305, 1306, 1306, 1307, 1308, 1310, 1311, 1312, 1312, 1313, 1314, 1316, 1317, 1318, 1320, 1321, 1322, 1322, 1323, 1324, 1325, 1326, 1327, 1328, 1329, 1330, 1332, 1333, 1334, 1337, 1338, 1339, 1340, 1341, 1342, 1343, 1344, 1345, 1346, 1347, 1348, 5147, 1348, 1349, 1350, 1351, 1352, 1353, 1354, 1355, 1355, 1356, 1357, 1361, 1362, 1363, 1366, 1367, 1368, 1368, 1369, 1370, 1370, 1371, 1372, 1373, 1374, 1375, 1378, 1379, 1380, 1381, 1382, 1383, 1389, 1390, 1391, 1396, 1397, 1398, 1400, 1401, 1402, 5112, 5113, 5114, 5114, 5115, 5116, 5117, 5118, 5119, 4709, 4710, 5203, 5206, 5207, 4710, 5176, 5177, 5178, 5178, 5179, 5180, 5182, 5183, 5184, 5184, 5185, 5186, 5188, 5189, 5190, 5154, 5155, 5156, 5157, 5158, 5196, 5199, 5200, 5193, 5195, 5196, 5158, 5161, 5162, 5163, 5163, 5164, 5165, 5165, 5166, 5167, 5170, 5171, 5172, 5172, 5173, 5174, 5122, 5123, 5124, 5125, 5126, 5127, 5127, 5128, 5061, 5062, 5063, 5064, 5066, 5067, 5068, 5068, 5069, 5070, 5071, 5072, 5075, 5075, 5076, 5077, 5078, 5079, 5080, 5082, 5083, 5084, 5084, 5085, 5086, 5086, 5087, 5088, 5091, 5092, 5093, 5098, 5099, 5100, 5100, 5101, 5102, 5105, 5106, 5107, 5059, 5060, 5061, 5061, 5128, 5129, 5129, 5130, 5131, 5110, 5111, 1406, 1406, 1407, 1408, 1408, 1409, 1410, 1411, 1412, 1413, 1413, 1414, 1415, 1415, 1416, 1417, 1421, 1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429, 1429, 1430, 1431, 1433, 1434, 1435, 1435, 1436, 1437, 1438, 1439, 1440, 1443, 1444, 1445, 1445, 1446, 1447, 1447, 1448, 1449, 1451, 1452, 1453, 1455, 1456, 1457, 1457, 1458, 1459, 1460, 1461, 1462, 1462, 1463, 1464, 1465, 1466, 1467, 1469, 1470, 1471, 1471, 1472, 1473, 1473, 1474, 1475, 1476, 1477, 1478, 1478, 1479, 1480, 1480, 1481, 1482, 1483, 1484, 1485, 1488, 1489, 1490, 1490, 1491, 1492, 1493, 1494, 1495, 1495, 1496, 1497, 1498, 1499, 1500, 1500, 1501, 1502, 1502, 1503, 1504, 1505, 1506, 1507, 1509, 1510, 1511, 1513, 1514, 1515, 1518, 1519, 1520, 1523, 1524, 1525, 1527, 1528, 1529, 1531, 1532, 1533, 1537, 1538, 1539, 1539, 1540, 1541, 1541, 1542, 1543, 1544, 1545, 1546, 1546, 1547, 1548, 1548, 1549, 1550, 1552, 1553, 1554, 1555, 1556, 4666, 4666, 1556, 1557, 1559, 1560, 1561, 1561, 1562, 1563, 1563, 1564, 1565, 1566, 1567, 1568, 1568, 1569, 1570, 1570, 1571, 1572, 1576, 1577, 1578, 1578, 1579, 1580, 1581, 1582, 1583, 1583, 1584, 1585, 1586, 1587, 1588, 1591, 1592, 1593, 1594, 1595, 1596, 1597, 1598, 1599, 1600, 1601, 1602, 1603, 1604, 1605, 1605, 1606, 1607, 1608, 1609, 1610, 1615, 1616, 1617, 1618, 1619, 1620, 1621, 1622, 1623, 1623, 1624, 1625, 1626, 1627, 1628, 1629, 1630, 1631, 1632, 1633, 1634, 1634, 1635, 1636, 1636, 1637, 1638, 1638, 1639, 1640, 1640, 1641, 1642, 1643, 1644, 1645, 1648, 1649, 1650, 1650, 1651, 1652, 1652, 1653, 1654, 1655, 1656, 1657, 1658, 1659, 1660, 1661, 1662, 1663, 1665, 1666, 1667, 1667, 1668, 1669, 1670, 1671, 1672, 1673, 1674, 1675, 1676, 1677, 1678, 1678, 1679, 1680, 1681, 1682, 1683, 1683, 1684, 1685, 1687, 1688, 1689, 1690, 1691, 1692, 5442, 1695, 1696, 1696, 1697, 1698, 1698, 1699, 1700, 1700, 1701, 1702, 1702, 1703, 1704, 1705, 1706, 1707, 1707, 1708, 1709, 1711, 1712, 1713, 1713, 1714, 1715, 1715, 1716, 1717, 1718, 1719, 1720, 1722, 1723, 1724, 1728, 1729, 1730, 1732, 1733, 1734, 1734, 1735, 1736, 1736, 1737, 1738, 1740, 1741, 1742, 1743, 1744, 1745, 1745, 1746, 1747, 5447, 5448, 5449, 1753, 1754, 1755, 1757, 1758, 1759, 1760, 1761, 1762, 1763, 1764, 1765, 1765, 1766, 1767, 1768, 1769, 1770, 1771, 1772, 1773, 1775, 1776, 1777, 1777, 1778, 1779, 1782, 1783, 1784, 1784, 1785, 1786, 1786, 1787, 1788, 1788, 1789, 1790, 1791, 1792, 1793, 1793, 1794, 1795, 1796, 1797, 1798, 1798, 1799, 1800, 1800, 1801, 1802, 1802, 1803, 1804, 1804, 1805, 1806, 1806, 1807, 1808, 1809, 1810, 1811, 1811, 1812, 1813, 1813, 1814, 1815, 1815, 1816, 1817, 1817, 1818, 1819, 1819, 1820, 1821, 1821, 1822, 1823, 1826, 1827, 1828, 1829, 1830, 1831, 1831, 1832, 1833, 1833, 1834, 1835, 1835, 1836, 1837, 1837, 1838, 1839, 1839, 1840, 1841, 1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 1851, 1852, 1853, 1854, 1855, 1856, 1857, 1858, 1859, 1859, 1860, 1861, 1861, 1862, 1863, 1864, 1865, 1866, 1867, 1868, 1869, 1869, 1870, 1871, 1875, 1876, 1877, 1877, 1878, 1879, 1880, 1881, 1882, 1883, 1884, 1885, 1887, 1888, 1889, 1889, 1890, 1891, 1892, 1893, 1894, 1896, 1897, 1898, 1900, 1901, 1902, 1902, 1903, 1904, 1905, 1906, 1907, 1908, 1909, 1910, 1912, 1913, 1914, 1915, 1916, 1917, 1918, 1919, 1920, 1920, 1921, 1922, 1922, 1923, 1924, 1925, 1926, 1927, 1928, 1929, 1930, 1931, 1932, 1933, 1933, 1934, 1935, 1935, 1936, 1937, 1940, 1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1948, 1949, 1950, 1951, 1952, 1953, 1955, 1956, 1957, 1960, 1961, 1962, 1964, 1965, 1966, 1971, 1972, 1973, 1974, 1975, 1976, 1976, 1977, 1978, 1979, 1980, 1981, 1982, 1983, 1984, 1984, 1985, 1986, 1986, 1987, 1988, 1989, 1990, 1991, 1992, 1993, 1994, 1995, 1996, 1997, 1999, 2000, 2001, 2004, 2005, 2006, 2006, 2007, 2008, 2008, 2009, 2010, 2011, 2012, 2013, 2014, 2015, 2016, 2016, 2017, 2018, 2018, 2019, 2020, 2021, 2022, 2023, 2026, 2027, 2028, 2028, 2029, 2030, 2032, 2033, 2034, 2037, 2038, 2039, 2042, 2043, 2044, 2045, 2046, 2047, 2047, 2048, 2049, 2050, 2051, 2052, 2054, 2055, 2056, 2059, 2060, 2061, 2063, 2064, 2065, 2065, 2066, 2067, 2069, 2070, 2071, 2072, 2073, 2074, 2076, 2077, 2078, 2078, 2079, 2080, 2081, 2082, 2083, 2083, 2084, 2085, 2085, 2086, 2087, 2088, 2089, 2090, 2094, 2095, 2096, 2096, 2097, 2098, 2098, 2099, 2100, 4735, 4736, 4727, 4730, 4731, 4732, 4733, 4734, 4739, 2103, 2104, 2105, 2105, 2106, 2107, 2107, 2108, 2109, 2109, 2110, 2111, 2111, 2112, 2113, 2113, 2114, 2115, 2115, 2116, 2117, 2117, 2118, 2119, 2121, 2122, 2123, 2124, 2125, 2126, 2128, 2129, 2130, 2132, 2133, 2134, 2135, 2136, 2137, 2137, 2138, 2139, 2141, 2142, 2143, 2144, 2145, 2146, 2147, 2148, 2149, 2150, 2151, 2152, 2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 2161, 2162, 2163, 2163, 2164, 2165, 2165, 2166, 2167, 2168, 2169, 2170, 2172, 2173, 2174, 2174, 2175, 2176, 2176, 2177, 2178, 2178, 2179, 2180, 2182, 2183, 2184, 2185, 2186, 2187, 2187, 2188, 2189, 2191, 2192, 2193, 2194, 2195, 2196, 2196, 2197, 2198, 2199, 2200, 2201, 2201, 2202, 2203, 2204, 2205, 2206, 2206, 2207, 2208, 2209, 2210, 2211, 2211, 2212, 2213, 2214, 2215, 2216, 2219, 2220, 2221, 2224, 2225, 2226, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2234, 2235, 2236, 2237, 2238, 2239, 2240, 5292, 5293, 5294, 5295, 5290, 5291, 5292, 2240, 2241, 2242, 2243, 2243, 2244, 5375, 5376, 5377, 5378, 5378, 5379, 5380, 5380, 5281, 5282, 5285, 5286, 5287, 5381, 5382, 5383, 5383, 5384, 5274, 5274, 5275, 5276, 5271, 5272, 5273, 5273, 5274, 5384, 5385, 5386, 5387, 5414, 5415, 5404, 5404, 5405, 5406, 5407, 5408, 5259, 5258, 5259, 5408, 5408, 5409, 5410, 5412, 5413, 5414, 5388, 5389, 5390, 5390, 5391, 5392, 5393, 5394, 5395, 5396, 5397, 5398, 5399, 5400, 5401, 5374, 5375, 2244, 2244, 2245, 5323, 4635, 2245, 5044, 5045, 5046, 5047, 5047, 5048, 5049, 5039, 5040, 5041, 4637, 2245, 2246, 5420, 5421, 2247, 2247, 2248, 2249, 2250, 2251, 2252, 2252, 2253, 5354, 5356, 5357, 5358, 5359, 5360, 5361, 5362, 5363, 5364, 5365, 5366, 5367, 5369, 5370, 5371, 5330, 5331, 5332, 5333, 5334, 5335, 5335, 5336, 5337, 5341, 5342, 5343, 5344, 5345, 5346, 5347, 5348, 5349, 5349, 5350, 5351, 5352, 5353, 5354, 5354, 2253, 2254, 2254, 2255, 2256, 2256, 5310, 5305, 5305, 5306, 5307, 5309, 5310, 2256, 5028, 2256, 2257, 2257, 2258, 5034, 5035, 5036, 5030, 5030, 5031, 5032, 5033, 5034, 2258, 2260, 2261, 2262, 5469, 5470, 5471, 5456, 5457, 5458, 5523, 5524, 5516, 5520, 5521, 5522, 5460, 5461, 5462, 5463, 5464, 5465, 5465, 5466, 5467, 2263, 5490, 5488, 5489, 5490, 2263, 2264, 2265, 2266, 2266, 2267, 2268, 2269, 2270, 2271, 2271, 2272, 2273, 2275, 2276, 2277, 5478, 2278, 2279, 2280, 2281, 2282, 2286, 2287, 2288, 2290, 2291, 2292, 2292, 2293, 2294, 2295, 2296, 2297, 2297, 2298, 2299, 2299, 5503, 5504, 5508, 5509, 5527, 5529, 5530, 5509, 5509, 5510, 5511, 5502, 5503, 2299, 2300, 2301, 2302, 2304, 2305, 2306, 2308, 2309, 2310, 2310, 2311, 2312, 2312, 2313, 2314, 2317, 2318, 2319, 2321, 2322, 2323, 2323, 2324, 2325, 2327, 2328, 2329, 2330, 2331, 2332, 2334, 2335, 2336, 2336, 2337, 2338, 2339, 2340, 2341, 2343, 2344, 2345, 2345, 2346, 2347, 2350, 2351, 2352, 2352, 2353, 2354, 2355, 2356, 2357, 2359, 2360, 2361, 2363, 2364, 2365, 2366, 2367, 2368, 2372, 2373, 2374, 2374, 2375, 2376, 2376, 2377, 2378, 2378, 2379, 2380, 2380, 2381, 2382, 2383, 2384, 2385, 2386, 2387, 2388, 2388, 2389, 2390, 2393, 2394, 2397, 2398, 2399, 2400, 2400, 2401, 2402, 2404, 2405, 2406, 2407, 2408, 2409, 2410, 2411, 2412, 2414, 2415, 2416, 2418, 2419, 2420, 2420, 2421, 2422, 2422, 2423, 2424, 2426, 2427, 2428, 2429, 2430, 2431, 2431, 2432, 2433, 2434, 2435, 2436, 2436, 2437, 2438, 2438, 2439, 2440, 2440, 2441, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2450, 2451, 2452, 2453, 2454, 2455, 2455, 2456, 2457, 2459, 2460, 2462, 2462, 2463, 2464, 2466, 2467, 2468, 2472, 2473, 2474, 2477, 2478, 2479, 2479, 2480, 2481, 2482, 2483, 2484, 2487, 2488, 2489, 2491, 2492, 2493, 2494, 2495, 2496, 2496, 2497, 2498, 2499, 2500, 2501, 2502, 2503, 2504, 2506, 2507, 2508, 2509, 2510, 2511, 2511, 2512, 2513, 2513, 2514, 2515, 2517, 2518, 2519, 2520, 2521, 2522, 2522, 2523, 2524, 2525, 2526, 2527, 2528, 2529, 2530, 2530, 2531, 2532, 2534, 2535, 2536, 2536, 2537, 2538, 2540, 2541, 2542, 2542, 2543, 2544, 2544, 2545, 2546, 2548, 2549, 2550, 2550, 2551, 2552, 2554, 2555, 2556, 2556, 2557, 2558, 2559, 2560, 2561, 2561, 2562, 2563, 2566, 2567, 2568, 2568, 2569, 2570, 2571, 2572, 2573, 2576, 2577, 2578, 2579, 2580, 2581, 2583, 2584, 2585, 2585, 2586, 2587, 2587, 2588, 2589, 2591, 2592, 2593, 2593, 2594, 2595, 2595, 2596, 2597, 2598, 2599, 2600, 2601, 2602, 2603, 2608, 2609, 2610, 2610, 2611, 2612, 2613, 2614, 2615, 2615, 2616, 2617, 2617, 2618, 2619, 2626, 2627, 2628, 2631, 2632, 2633, 2635, 2636, 2637, 2640, 2641, 2642, 2642, 2643, 2644, 2647, 2648, 2649, 2649, 2650, 2651, 2651, 2652, 2653, 2653, 2654, 2655, 2655, 2656, 2657, 2658, 2659, 2660, 2660, 2661, 2662, 2662, 2663, 2664, 2664, 2665, 2666, 2668, 2669, 2670, 2672, 2673, 2674, 2675, 2676, 2677, 2679, 2680, 2681, 2682, 2683, 2684, 2684, 2685, 2686, 2686, 2687, 2688, 2688, 2689, 2690, 2690, 2691, 2692, 2692, 2693, 2694, 2696, 2697, 2698, 2698, 2699, 2700, 2700, 2701, 2702, 2702, 2703, 2704, 2705, 2706, 2707, 2708, 2709, 2710, 2712, 2713, 2714, 2714, 2715, 2716, 2716, 2717, 2718, 2719, 2720, 2721, 2721, 2722, 2723, 2724, 2725, 2726, 2727, 2728, 2729, 2730, 2731, 2732, 2732, 2733, 2734, 2734, 2735, 2736, 2739, 2740, 2741, 2742, 2743, 2744, 2747, 2748, 2749, 2750, 2751, 2752, 2752, 2753, 2754, 2754, 2755, 2756, 2757, 2758, 2759, 2762, 2763, 2764, 2770, 2771, 2772, 2772, 2773, 2774, 2775, 2776, 2777, 2778, 2779, 2780, 2781, 2782, 2783, 2783, 2784, 2785, 2786, 2787, 2788, 2788, 2789, 2790, 2790, 2791, 2792, 2792, 2793, 2794, 2794, 2795, 2796, 2799, 2800, 2801, 2801, 2802, 2803, 2803, 2804, 2805, 2806, 2807, 2808, 2808, 2809, 2810, 2810, 2811, 2812, 2812, 2813, 2814, 2815, 2816, 2817, 2817, 2818, 2819, 2821, 2822, 2823, 2823, 2824, 2825, 2825, 2826, 2827, 2827, 2828, 2829, 2829, 2830, 2831, 2832, 2833, 2834, 2835, 2836, 2837, 2837, 2838, 2839, 2840, 2841, 2842, 2844, 2845, 2846, 2847, 2848, 2849, 2850, 2851, 2852, 2852, 2853, 2854, 2854, 2855, 2856, 2857, 2858, 2859, 2859, 2860, 2861, 2864, 2865, 2866, 2866, 2867, 2868, 2868, 2870, 2871, 2872, 2873, 2874, 2875, 2876, 2877, 2877, 2878, 2879, 2880, 2881, 2882, 2884, 2885, 2886, 2886, 2887, 2888, 2890, 2891, 2892, 2892, 2893, 2894, 2894, 2895, 2896, 2898, 2899, 2900, 2900, 2901, 2902, 2903, 2904, 2905, 2906, 2907, 2908, 2910, 2911, 2912, 2913, 2914, 2915, 2918, 2919, 2920, 2920, 2921, 2922, 2923, 2924, 2925, 2926, 2927, 2928, 2928, 2929, 2930, 2930, 2931, 2932, 2932, 2933, 2934, 2934, 2935, 2936, 2936, 2937, 2938, 2938, 2939, 2940, 2940, 2941, 2942, 2945, 2946, 2947, 2952, 2953, 2954, 2955, 2956, 2957, 2959, 2960, 2961, 2961, 2962, 2963, 2964, 2965, 2966, 2968, 2969, 2970, 2971, 2972, 2973, 2973, 2974, 2975, 2978, 2979, 2980, 2980, 2981, 2982, 2982, 2983, 2984, 2986, 2987, 2988, 2988, 2989, 2990, 2990, 2991, 2992, 2995, 2996, 2997, 3000, 3001, 3002, 3006, 3007, 3008, 3010, 3011, 3012, 3013, 3014, 3015, 3017, 3018, 3019, 3022, 3023, 3024, 3025, 3026, 3027, 3031, 3032, 3033, 3033, 3034, 3035, 3035, 3036, 3037, 3041, 3042, 3043, 3045, 3046, 3047, 3048, 3049, 3050, 3050, 3051, 3052, 3052, 3053, 3054, 3056, 3057, 3058, 3058, 3059, 3060, 3061, 3062, 3063, 3063, 3064, 3065, 3066, 3067, 3068, 3068, 3069, 3070, 3071, 3072, 3073, 3073, 3074, 3075, 3077, 3078, 3079, 3079, 3080, 3081, 3081, 3082, 3083, 3084, 3085, 3086, 3086, 3087, 3088, 3089, 3090, 3091, 3093, 3094, 3095, 3095, 3096, 3097, 3097, 3098, 3099, 3099, 3100, 3101, 3102, 3103, 3104, 3107, 3108, 3109, 3110, 3111, 3112, 3115, 3116, 3117, 3117, 3118, 3119, 3119, 3120, 3121, 3121, 3122, 3123, 3124, 3125, 3126, 3127, 3128, 3129, 3130, 3131, 3133, 3134, 3135, 3136, 3136, 3137, 3138, 3140, 3141, 3143, 3145, 3146, 3147, 3147, 3148, 3149, 3149, 3150, 3151, 3152, 3153, 3154, 3155, 3156, 3157, 3157, 3158, 3159, 3159, 3160, 3161, 3163, 3164, 3165, 3165, 3166, 3167, 3172, 3173, 3174, 3174, 3175, 3176, 3176, 3177, 3178, 3181, 3182, 3183, 3183, 3184, 3185, 3185, 3186, 3187, 3189, 3190, 3191, 3192, 3193, 3194, 3195, 3196, 3197, 3198, 3199, 3200, 3202, 3203, 3204, 3204, 3205, 3206, 3207, 3212, 3213, 3213, 3214, 3215, 3215, 3216, 3217, 3218, 3219, 3220, 3224, 3225, 3226, 3227, 3228, 3229, 3229, 3230, 3231, 3233, 3234, 3235, 3236, 3237, 3238, 3238, 3239, 3240, 3242, 3244, 3245, 3245, 3246, 3247, 3249, 3250, 3251, 3251, 3252, 3253, 3253, 3254, 3255, 3256, 3257, 3258, 3258, 3259, 3260, 3261, 3262, 3263, 3263, 3264, 3265, 3268, 3269, 3270, 3270, 3271, 3272, 3272, 3273, 3274, 3275, 3276, 3277, 3279, 3280, 3281, 3283, 3284, 3285, 3286, 3287, 3288, 3288, 3289, 3290, 3290, 3291, 3292, 3293, 3294, 3295, 3297, 3298, 3299, 3300, 3301, 3302, 3303, 3304, 3305, 3305, 3306, 3307, 3313, 3314, 3315, 3315, 3316, 3317, 3319, 3320, 3321, 3324, 3325, 3326, 3327, 3328, 3329, 3329, 3330, 3331, 3331, 3332, 3333, 3334, 3335, 3336, 3339, 3340, 3341, 3342, 3343, 3344, 3350, 3351, 3352, 3354, 3355, 3356, 3356, 3357, 3358, 3359, 3360, 3361, 3362, 3363, 3364, 3367, 3368, 3369, 3369, 3370, 3371, 3373, 3374, 3375, 3376, 3377, 3378, 3387, 3388, 3389, 3392, 3393, 3394, 3394, 3395, 3396, 3397, 3398, 3399, 3399, 3400, 3401, 3402, 3403, 3404, 3406, 3407, 3408, 3408, 3409, 3410, 3412, 5638, 5640, 3416, 3417, 3418, 3422, 3423, 3424, 3428, 3429, 3430, 3431, 3432, 3433, 3433, 3434, 3435, 3436, 3437, 3438, 3438, 3439, 3440, 3440, 3441, 3442, 3442, 3443, 3444, 3444, 3445, 3446, 3447, 3448, 3449, 3450, 3451, 3452, 3452, 3453, 3454, 3454, 3455, 3456, 3459, 3460, 3461, 3466, 3467, 3468, 3469, 3470, 3471, 3471, 3472, 3473, 3473, 3474, 3475, 3475, 3476, 3477, 3478, 3479, 3480, 3481, 3482, 3483, 3483, 3484, 3485, 3487, 3488, 3489, 3492, 3493, 3494, 3500, 3501, 3502, 3504, 3505, 3506, 3508, 3509, 3510, 3512, 3513, 3514, 3514, 3515, 3516, 3516, 3517, 3518, 3522, 3523, 3524, 3528, 3529, 3530, 3531, 3532, 3533, 3533, 3534, 3535, 3535, 4753, 4754, 3536, 3537, 4792, 4791, 4792, 3537, 3537, 3538, 3539, 3539, 3540, 4758, 4760, 4762, 4763, 4764, 4765, 3540, 3541, 3542, 3543, 3543, 3544, 3545, 3545, 3546, 3547, 4886, 4887, 4881, 4882, 4883, 4884, 3548, 4866, 4867, 4868, 4869, 3548, 3549, 3550, 3551, 3551, 3552, 3553, 3553, 3554, 3555, 3555, 3556, 3557, 3559, 3560, 3561, 3563, 3564, 3565, 3565, 3566, 3567, 3567, 3568, 3569, 3569, 3570, 3571, 3571, 3572, 3573, 3573, 3574, 3575, 3576, 3577, 3578, 3581, 3582, 3583, 3587, 3588, 3589, 3590, 3591, 3592, 3592, 3593, 3594, 3595, 3596, 3597, 3602, 3603, 3604, 3606, 3607, 3608, 3608, 3609, 3610, 3611, 3612, 3613, 3613, 3614, 3615, 3616, 3617, 3618, 3619, 3620, 3621, 3626, 3627, 3628, 3629, 3630, 3631, 3631, 3632, 3633, 3635, 3636, 3637, 3637, 3638, 3639, 3639, 3640, 3641, 3641, 3642, 3643, 3643, 3644, 3645, 3647, 3648, 3649, 3650, 3651, 3652, 3653, 3654, 3655, 4806, 4807, 4808, 4809, 4810, 4811, 3659, 3660, 3661, 3662, 3663, 3664, 3664, 3665, 3666, 3666, 3667, 3668, 3669, 3670, 3671, 3672, 3673, 3674, 3676, 3678, 3679, 3679, 3680, 3681, 3683, 3684, 3685, 3686, 3687, 3688, 3690, 3691, 3692, 3693, 3694, 3695, 3697, 3698, 3699, 3701, 3702, 3703, 3704, 3705, 3706, 3706, 3707, 3708, 3708, 3709, 3710, 3711, 3712, 3713, 3713, 3714, 3715, 3716, 3717, 3718, 3720, 3721, 3722, 3722, 3723, 3724, 3724, 3726, 3727, 3729, 3730, 3731, 3731, 3732, 3733, 3734, 3735, 3736, 3738, 3739, 3740, 3745, 3746, 3747, 3747, 3748, 3749, 3749, 3750, 3751, 3751, 3752, 3753, 3754, 3755, 3756, 3760, 3761, 3762, 3762, 3763, 3764, 3764, 3765, 3766, 3766, 3767, 3768, 3769, 3770, 3771, 3771, 3772, 3773, 3777, 3778, 3779, 3779, 3780, 3781, 3781, 3782, 3783, 3783, 3784, 3785, 3785, 3786, 3787, 3789, 3790, 3791, 3791, 3792, 3793, 3793, 3794, 3795, 3795, 3796, 3797, 3798, 3799, 3800, 3800, 3801, 3802, 3802, 3803, 3804, 3804, 3805, 3806, 3807, 3808, 3809, 3810, 3811, 3812, 3813, 3815, 3816, 3816, 3817, 3818, 3820, 3821, 3822, 3823, 3824, 3825, 3825, 3826, 3827, 3829, 3830, 3831, 3832, 3833, 3834, 3834, 3835, 3836, 3837, 3838, 3839, 3841, 3842, 3843, 3844, 3845, 3846, 3846, 3847, 3848, 3851, 3852, 3853, 3853, 3854, 3855, 3856, 3857, 3858, 3859, 3860, 3861, 3861, 3862, 3863, 3864, 3865, 3866, 3866, 3867, 3868, 3870, 3871, 3872, 3873, 3874, 3875, 3877, 3878, 3879, 3879, 3880, 3881, 3882, 3883, 3884, 3885, 3886, 3887, 3888, 3889, 3890, 3890, 3891, 3892, 3892, 3893, 3894, 3894, 3895, 3896, 3896, 3897, 3898, 3898, 3899, 3900, 3901, 3902, 3903, 3903, 3904, 3905, 3907, 3908, 3909, 3910, 3911, 3912, 3913, 3914, 3915, 3916, 3917, 3918, 3919, 3920, 3921, 3924, 3925, 3926, 3927, 3928, 3929, 3932, 3933, 3934, 3937, 3938, 3939, 3940, 3941, 3942, 3942, 3943, 3944, 3945, 3946, 3947, 3947, 3948, 3949, 3955, 3956, 3957, 3961, 3962, 3963, 3964, 3965, 3966, 3969, 3970, 3971, 3971, 3972, 3973, 3975, 3976, 3977, 3978, 3980, 3981, 3985, 3986, 3987, 3991, 3992, 3993, 3994, 3995, 4603, 4603, 3995, 4605, 3996, 3997, 3998, 4000, 4002, 4003, 4003, 4004, 4005, 4008, 4009, 4010, 4011, 4012, 4013, 4013, 4014, 4015, 4015, 4016, 4017, 4018, 4019, 4020, 4022, 4023, 4024, 4025, 4026, 4027, 4029, 4030, 4031, 4033, 4034, 4035, 4037, 4038, 4039, 4039, 4040, 4041, 4043, 4044, 4045, 4045, 4046, 4047, 4047, 4048, 4049, 4050, 4051, 4052, 4054, 4055, 4056, 4056, 4057, 4058, 4059, 4060, 4061, 4061, 4062, 4063, 4063, 4064, 4065, 4067, 4068, 4069, 4070, 4071, 4072, 4076, 4077, 4078, 4078, 4079, 4080, 4082, 4083, 4084, 4084, 4085, 4086, 4086, 4087, 4088, 4090, 4091, 4092, 4094, 4095, 4096, 4096, 4097, 4098, 4098, 4099, 4100, 4102, 4103, 4104, 4107, 4108, 4109, 4109, 4110, 4111, 4111, 4112, 4113, 4115, 4116, 4117, 4122, 4123, 4124, 4129, 4130, 4131, 4131, 4132, 4133, 4133, 4134, 4135, 4135, 4136, 4137, 4138, 4139, 4140, 4141, 4142, 4143, 4144, 4145, 4146, 4150, 4151, 4152, 4152, 4153, 4154, 4159, 4160, 4161, 4167, 4168, 4169, 4171, 4172, 4173, 4174, 4175, 4176, 4179, 4180, 4181, 4181, 4182, 4183, 4184, 4185, 4186, 4187, 4188, 4189, 4190, 4191, 4192, 4194, 4195, 4196, 4196, 4197, 4198, 4199, 4200, 4201, 4203, 4204, 4205, 4207, 4208, 4209, 4211, 4212, 4213, 4213, 4214, 4215, 4216, 4217, 4218, 4221, 4222, 4223, 4223, 4224, 4225, 4225, 4226, 4227, 4228, 4229, 4230, 4231, 4232, 4233, 4234, 4235, 4236, 4237, 4238, 4239, 4241, 4242, 4243, 4244, 4245, 4246, 4246, 4247, 4248, 4248, 4249, 4250, 4250, 4251, 4252, 4254, 4255, 4256, 4258, 4259, 4260, 4261, 4262, 4263, 4263, 4264, 4265, 4266, 4267, 4268, 4270, 4271, 4272, 4272, 4273, 4274, 4274, 4275, 4276, 4277, 4278, 4279, 4279, 4280, 4281, 4282, 4283, 4284, 4285, 4286, 4287, 4287, 4288, 4289, 4290, 4291, 4292, 4292, 4293, 4294, 4295, 4296, 4297, 4297, 4298, 4299, 4300, 4301, 4303, 4304, 4305, 4306, 4306, 4307, 4308, 4309, 4310, 4311, 4313, 4314, 4315, 4316, 4317, 4318, 4319, 4320, 4321, 4323, 4324, 4325, 4326, 4327, 4328, 4328, 4329, 4330, 4330, 4331, 4332, 4332, 4333, 4334, 4334, 4336, 4337, 4337, 4338, 4339, 4339, 4340, 4341, 4341, 4342, 4343, 4343, 4344, 4345, 4345, 4346, 4347, 4347, 4348, 4349, 4350, 4351, 4352, 4352, 4353, 4354, 4354, 4355, 4356, 4359, 4360, 4361, 4361, 4362, 4363, 4364, 4365, 4366, 4370, 4371, 4372, 4372, 4373, 4374, 4377, 4378, 4379, 4379, 4380, 4381, 4382, 4383, 4384, 4389, 4390, 4391, 4394, 4395, 4396, 4398, 4399, 4400, 4400, 4401, 4402, 4402, 4403, 4404, 4406, 4407, 4408, 4411, 4412, 4413, 4417, 4418, 4419, 4419, 4420, 4421, 4421, 4422, 4423, 4425, 4426, 4427, 4427, 4428, 4429, 4432, 4433, 4434, 4953, 4954, 4949, 4952, 4438, 4439, 4440, 4441, 4442, 4445, 4446, 4447, 4448, 4449, 4450, 4454, 4455, 4456, 4457, 4458, 4459, 4461, 4462, 4463, 4463, 4464, 4465, 4465, 4466, 4972, 4974, 4975, 4466, 4467, 4468, 4469, 4472, 4473, 4474, 4475, 4476, 4477, 4477, 4478, 4479, 4479, 4480, 4481, 4481, 4482, 4483, 4488, 4489, 4490, 4490, 4491, 4492, 4493, 4494, 4495, 4496, 4497, 4498, 4499, 4500, 4501, 4501, 4502, 4503, 4503, 4504, 4505, 4505, 4506, 4507, 4509, 4510, 4511, 4511, 4512, 4513, 4514, 4515, 4516, 4518, 4520, 4521, 4521, 4522, 4523, 4526, 4527, 4528, 4530, 4531, 4532, 4534, 4535, 4536, 4536, 4537, 4538, 4540, 4541, 4542, 4543, 4544, 4545, 4545, 4546, 4547, 4547, 4548, 4549, 4550, 4551, 4552, 4555, 4556, 4557, 4557, 4558, 4982, 4983, 4984, 4985, 4986, 4977, 4978, 4979, 4980, 4981, 4559, 4560, 4561, 4561, 4562, 4563, 4563, 4564, 4565, 4567, 4568, 4569, 4572, 4573, 4574, 4574, 4575, 4576, 4576, 4577, 4578, 4578, 4579, 4580, 4580, 4581, 4582, 4582, 4583, 4584, 4585, 4586, 4587, 4589, 4590, 4591, 4592, 4593, 4594, 4595, 4596, 4597, 4597, 4598, 4599, 0, 1, 2, 4, 5, 6, 6, 7, 8, 11, 12, 13, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 24, 25, 26, 27, 28, 29, 33, 34, 35, 36, 37, 38, 40, 41, 42, 42, 43, 44, 44, 45, 46, 48, 49, 50, 50, 51, 52, 54, 55, 56, 56, 57, 58, 58, 59, 60, 62, 63, 64, 64, 65, 66, 68, 69, 70, 70, 71, 72, 72, 73, 74, 74, 75, 76, 78, 79, 80, 80, 81, 82, 83, 84, 85, 85, 86, 87, 88, 89, 90, 90, 91, 92, 92, 93, 94, 95, 96, 97, 99, 100, 101, 101, 102, 103, 103, 104, 105, 105, 106, 107, 110, 111, 112, 112, 113, 114, 114, 115, 4847, 4855, 4856, 4857, 4857, 4858, 4859, 4860, 4841, 4838, 4837, 4838, 4841, 4844, 4845, 4846, 4846, 4847, 115, 117, 118, 119, 120, 122, 123, 123, 124, 125, 126, 127, 128, 128, 129, 130, 130, 131, 132, 133, 134, 135, 135, 136, 137, 139, 140, 141, 142, 143, 144, 144, 145, 146, 146, 147, 148, 148, 149, 150, 152, 153, 154, 154, 155, 156, 156, 157, 158, 160, 161, 162, 164, 165, 166, 167, 168, 169, 169, 170, 171, 171, 172, 173, 174, 175, 176, 177, 178, 179, 181, 182, 183, 184, 185, 4782, 4784, 4785, 4786, 4787, 4771, 4773, 4773, 4775, 4777, 4777, 4779, 4780, 4782, 186, 187, 189, 191, 193, 194, 196, 197, 205, 206, 208, 208, 210, 212, 214, 216, 218, 222, 224, 225, 226, 228, 229, 231, 233, 235, 237, 238, 240, 240, 242, 243, 243, 245, 247, 247, 249, 250, 4901, 252, 253, 253, 255, 256, 261, 262, 264, 266, 268, 269, 269, 270, 272, 274, 276, 277, 278, 280, 281, 282, 284, 286, 286, 287, 289, 289, 291, 292, 299, 301, 303, 303, 305, 307, 307, 309, 310, 310, 312, 313, 4934, 4935, 315, 315, 317, 318, 318, 320, 322, 327, 329, 330, 333, 335, 337, 343, 345, 347, 349, 351, 352, 352, 354, 355, 355, 357, 359, 362, 364, 366, 370, 371, 373, 373, 375, 377, 383, 385, 386, 390, 391, 393, 398, 400, 402, 408, 409, 411, 413, 414, 416, 419, 420, 422, 426, 428, 429, 431, 433, 434, 440, 441, 443, 443, 445, 446, 447, 449, 451, 453, 455, 457, 460, 462, 463, 463, 465, 466, 474, 476, 477, 478, 480, 482, 483, 485, 486, 487, 488, 490, 492, 493, 495, 496, 498, 499, 501, 503, 505, 508, 509, 511, 511, 513, 514, 514, 515, 517, 517, 519, 521, 523, 524, 526, 529, 531, 533, 535, 537, 539, 539, 541, 542, 542, 543, 545, 545, 547, 549, 553, 555, 556, 557, 559, 560, 562, 564, 565, 567, 569, 570, 4927, 572, 573, 573, 574, 576, 577, 579, 580, 584, 586, 588, 590, 592, 593, 595, 597, 599, 599, 601, 603, 607, 608, 610, 610, 612, 613, 613, 615, 616, 617, 618, 620, 620, 622, 624, 627, 628, 630, 630, 631, 633, 635, 636, 638, 641, 643, 645, 645, 647, 648, 650, 652, 653, 656, 658, 659, 659, 660, 662, 662, 664, 665, 665, 666, 668, 669, 671, 672, 672, 673, 675, 676, 678, 679, 690, 692, 694, 694, 696, 697, 702, 704, 705, 705, 707, 709, 713, 715, 716, 717, 718, 720, 720, 721, 723, 725, 727, 729, 731, 733, 735, 739, 741, 742, 743, 745, 746, 750, 751, 753, 753, 754, 756, 759, 761, 762, 765, 766, 768, 771, 4621, 773, 776, 777, 779, 783, 784, 786, 791, 792, 794, 794, 795, 797, 808, 810, 812, 813, 815, 816, 820, 822, 824, 826, 828, 829, 832, 834, 836, 836, 837, 839, 845, 847, 848, 853, 855, 856, 857, 859, 4996, 4996, 4998, 5000, 5001, 5003, 5004, 4992, 4994, 859, 861, 863, 865, 865, 867, 869, 870, 872, 873, 878, 880, 882, 882, 884, 885, 885, 887, 888, 890, 891, 893, 908, 909, 911, 924, 926, 927, 927, 929, 930, 931, 933, 935, 937, 939, 941, 944, 946, 947, 953, 954, 956, 962, 964, 965, 966, 968, 970, 970, 971, 973, 973, 975, 976, 984, 986, 987, 1003, 1005, 1006, 1010, 1011, 1013, 1014, 1016, 1017, 1017, 1019, 1021, 1023, 1025, 1027, 1034, 1036, 1037, 1039, 1040, 1042, 1042, 1044, 1045, 1046, 1048, 1050, 1051, 1053, 1055, 1059, 1060, 1062, 1063, 1065, 1066, 1068, 1070, 1072, 1076, 1077, 1079, 1079, 1080, 1082, 1085, 1086, 1088, 1091, 1092, 1094, 1099, 1100, 1102, 1104, 1105, 1107, 1110, 1112, 1113, 1114, 1116, 1118, 1118, 1119, 1121, 1121, 1123, 1124, 1127, 1129, 1130, 1137, 1139, 1140, 1144, 1145, 1147, 5142, 1148, 1151, 1151, 1153, 1155, 1157, 1159, 1160, 1169, 1170, 1172, 1174, 1176, 1177, 1179, 1181, 1182, 1182, 1184, 1186, 1192, 1194, 1195, 1197, 1199, 1200, 1200, 1202, 1204, 1204, 1205, 1207, 1207, 1208, 1210, 1215, 1217, 1218, 1222, 1224, 1225, 1227, 1229, 1230, 1233, 1236, 1237, 1240, 1242, 1244, 1249, 1251, 1252, 1253, 1255, 1257, 1257, 1259, 1261, 1264, 1266, 1267, 1267, 1269, 1270, 1270, 1272, 1273, 1276, 1278, 4714, 4716, 4720, 4721, 1278, 1280, 1281, 5214, 5216, 5217, 5209, 5210, 5230, 5229, 5230, 5211, 5212, 5213, 1281, 1282, 1283, 1285, 1285, 1287, 1288, 5244, 1288, 1289, 1289, 1291, 1292, 1294, 1295, 1297, 1301, 1303, 1304, 1306, 1308, 1309, 1312, 1314, 1315, 1315, 1316, 1318, 1319, 1320, 1322, 1322, 1324, 1325, 1325, 1327, 1328, 1328, 1330, 1331, 1332, 1334, 1335, 1337, 1339, 1340, 1342, 1343, 1345, 5147, 1349, 1350, 1350, 1352, 1353, 1355, 1357, 1358, 1360, 1361, 1363, 1366, 1368, 1370, 1370, 1372, 1373, 1373, 1375, 1376, 1377, 1378, 1380, 1381, 1383, 1384, 1389, 1391, 1392, 1395, 1396, 1398, 1400, 1402, 1403, 5112, 5114, 5116, 4709, 5203, 5204, 5206, 4710, 4711, 5175, 5176, 5178, 5178, 5180, 5181, 5182, 5184, 5186, 5190, 5154, 5156, 5157, 5196, 5197, 5198, 5199, 5193, 5195, 5158, 5159, 5163, 5165, 5167, 5169, 5170, 5172, 5125, 5127, 5061, 5062, 5064, 5065, 5068, 5070, 5071, 5071, 5075, 5077, 5084, 5086, 5088, 5091, 5093, 5094, 5100, 5102, 5103, 5104, 5105, 5107, 5059, 5061, 5129, 1423, 1424, 1426, 1429, 1431, 1432, 1433, 1435, 1437, 1438, 1440, 1441, 1442, 1443, 1445, 1445, 1447, 1449, 1451, 1453, 1454, 1457, 1459, 1460, 1462, 1464, 1465, 1469, 1471, 1473, 1476, 1478, 1480, 1480, 1482, 1483, 1483, 1485, 1486, 1488, 1490, 1492, 1498, 1500, 1502, 1504, 1505, 1507, 1509, 1511, 1512, 1517, 1518, 1520, 1522, 1523, 1525, 1526, 1527, 1529, 1531, 1533, 1534, 1539, 1541, 1543, 1544, 1546, 1548, 1552, 1554, 1555, 1555, 4666, 4667, 1561, 1563, 1565, 1566, 1568, 1570, 1570, 1572, 1573, 1576, 1578, 1580, 1580, 1581, 1583, 1591, 1593, 1594, 1594, 1596, 1597, 1599, 1600, 1602, 1602, 1603, 1605, 1605, 1607, 1608, 1608, 1610, 1611, 1615, 1617, 1618, 1618, 1620, 1621, 1625, 1626, 1628, 1631, 1632, 1634, 1636, 1638, 1640, 1640, 1642, 1643, 1648, 1650, 1652, 1655, 1657, 1658, 1660, 1661, 1663, 1664, 1665, 1667, 1667, 1669, 1670, 1670, 1672, 1673, 1680, 1681, 1683, 1686, 1687, 1689, 1696, 1698, 1700, 1704, 1705, 1707, 1707, 1709, 1710, 1713, 1715, 1717, 1718, 1720, 1721, 1734, 1736, 1738, 1739, 1740, 1742, 1752, 1753, 1755, 1756, 1757, 1759, 1760, 1762, 1763, 1763, 1765, 1767, 1768, 1770, 1771, 1782, 1784, 1786, 1788, 1790, 1791, 1791, 1793, 1795, 1796, 1798, 1800, 1800, 1802, 1804, 1804, 1806, 1808, 1808, 1809, 1811, 1813, 1815, 1817, 1817, 1819, 1821, 1826, 1828, 1829, 1829, 1831, 1833, 1833, 1835, 1837, 1841, 1843, 1844, 1844, 1846, 1847, 1847, 1849, 1850, 1851, 1853, 1854, 1854, 1856, 1857, 1857, 1859, 1861, 1864, 1866, 1867, 1867, 1869, 1871, 1879, 1880, 1882, 1883, 1885, 1886, 1889, 1891, 1892, 1896, 1898, 1899, 1899, 1900, 1902, 1902, 1904, 1905, 1908, 1910, 1911, 1911, 1912, 1914, 1915, 1917, 1918, 1920, 1922, 1924, 1928, 1930, 1931, 1931, 1933, 1935, 1942, 1943, 1945, 1948, 1950, 1951, 1954, 1955, 1957, 1960, 1962, 1963, 1964, 1966, 1967, 1971, 1973, 1974, 1982, 1984, 1986, 1991, 1992, 1994, 1994, 1995, 1997, 1999, 2001, 2002, 2004, 2006, 2008, 2008, 2010, 2011, 2011, 2013, 2014, 2014, 2016, 2018, 2018, 2020, 2021, 2026, 2028, 2030, 2031, 2032, 2034, 2041, 2042, 2044, 2045, 2047, 2049, 2050, 2052, 2053, 2053, 2054, 2056, 2061, 2063, 2065, 2065, 2067, 2068, 2069, 2071, 2072, 2075, 2076, 2078, 2080, 2081, 2083, 2083, 2085, 2087, 2087, 2088, 2090, 4729, 4730, 4732, 4733, 4739, 4740, 2105, 2107, 2109, 2109, 2111, 2113, 2113, 2115, 2117, 2117, 2119, 2120, 2121, 2123, 2124, 2124, 2126, 2127, 2128, 2130, 2131, 2134, 2135, 2137, 2140, 2141, 2143, 2147, 2149, 2150, 2150, 2152, 2154, 2154, 2155, 2157, 2158, 2160, 2161, 2161, 2163, 2165, 2165, 2167, 2168, 2172, 2174, 2176, 2176, 2178, 2180, 2181, 2182, 2184, 2187, 2189, 2190, 2196, 2198, 2199, 2206, 2208, 2209, 2211, 2213, 2214, 2219, 2221, 2222, 2223, 2224, 2226, 2234, 2236, 2237, 5291, 2240, 2241, 2241, 2243, 5375, 5376, 5378, 5380, 5380, 5282, 5283, 5280, 5281, 5380, 5381, 5383, 5274, 5273, 5384, 5385, 5404, 5406, 5407, 5407, 5259, 5260, 5257, 5258, 5408, 5412, 5414, 5388, 5392, 5393, 5395, 5396, 5398, 5399, 5399, 5401, 5374, 5374, 2244, 5323, 5325, 5326, 5327, 2246, 2247, 5421, 5420, 2247, 2249, 2250, 2252, 5354, 5358, 5359, 5361, 5362, 5364, 5365, 5365, 5367, 5368, 5369, 5371, 5329, 5333, 5335, 5337, 5340, 5341, 5343, 5347, 5349, 5351, 5352, 5354, 2254, 2254, 2256, 5305, 5028, 2257, 5034, 5033, 2258, 2259, 2260, 2262, 2263, 5456, 5458, 5459, 5459, 5460, 5462, 5469, 2263, 5488, 5489, 2263, 2264, 2266, 2268, 2269, 2271, 2273, 2274, 2274, 2275, 2277, 5478, 2279, 2280, 2280, 2282, 2283, 2290, 2292, 2294, 2297, 2299, 5504, 5507, 5508, 5527, 5529, 5509, 5511, 5513, 5502, 2299, 2299, 2300, 2302, 2303, 2304, 2306, 2310, 2312, 2314, 2316, 2317, 2319, 2321, 2323, 2325, 2329, 2330, 2332, 2334, 2336, 2338, 2342, 2343, 2345, 2345, 2347, 2348, 2350, 2352, 2354, 2355, 2357, 2358, 2359, 2361, 2362, 2363, 2365, 2366, 2374, 2376, 2378, 2378, 2380, 2382, 2383, 2385, 2386, 2388, 2390, 2391, 2392, 2393, 2397, 2397, 2398, 2400, 2404, 2406, 2407, 2407, 2409, 2410, 2420, 2422, 2424, 2426, 2428, 2429, 2431, 2433, 2434, 2445, 2446, 2448, 2449, 2450, 2452, 2453, 2455, 2457, 2466, 2468, 2469, 2471, 2472, 2474, 2477, 2479, 2481, 2482, 2484, 2485, 2487, 2489, 2490, 2491, 2493, 2494, 2494, 2496, 2498, 2502, 2504, 2505, 2505, 2506, 2508, 2513, 2515, 2516, 2516, 2517, 2519, 2520, 2522, 2524, 2528, 2530, 2532, 2534, 2536, 2538, 2540, 2542, 2544, 2548, 2550, 2552, 2556, 2558, 2559, 2559, 2561, 2563, 2566, 2568, 2570, 2576, 2578, 2579, 2579, 2581, 2582, 2583, 2585, 2587, 2587, 2589, 2590, 2591, 2593, 2595, 2598, 2600, 2601, 2601, 2603, 2604, 2608, 2610, 2612, 2613, 2615, 2617, 2617, 2619, 2620, 2626, 2628, 2629, 2634, 2635, 2637, 2640, 2642, 2644, 2649, 2651, 2653, 2653, 2655, 2657, 2660, 2662, 2664, 2664, 2666, 2667, 2667, 2668, 2670, 2672, 2674, 2675, 2675, 2677, 2678, 2678, 2679, 2681, 2681, 2682, 2684, 2690, 2692, 2694, 2698, 2700, 2702, 2702, 2704, 2705, 2705, 2707, 2708, 2719, 2721, 2723, 2724, 2726, 2727, 2727, 2729, 2730, 2734, 2736, 2737, 2738, 2739, 2741, 2742, 2744, 2745, 2746, 2747, 2749, 2749, 2750, 2752, 2756, 2757, 2759, 2762, 2764, 2765, 2769, 2770, 2772, 2772, 2774, 2775, 2775, 2777, 2778, 2785, 2786, 2788, 2788, 2790, 2792, 2792, 2794, 2796, 2799, 2801, 2803, 2803, 2805, 2806, 2806, 2808, 2810, 2814, 2815, 2817, 2821, 2823, 2825, 2825, 2827, 2829, 2829, 2831, 2832, 2835, 2837, 2839, 2840, 2842, 2843, 2844, 2846, 2847, 2850, 2852, 2854, 2857, 2859, 2861, 2863, 2864, 2866, 2871, 2872, 2874, 2880, 2882, 2883, 2890, 2892, 2894, 2894, 2896, 2897, 2906, 2908, 2909, 2910, 2912, 2913, 2913, 2915, 2916, 2917, 2918, 2920, 2920, 2922, 2923, 2932, 2934, 2936, 2936, 2938, 2940, 2944, 2945, 2947, 2951, 2952, 2954, 2958, 2959, 2961, 2964, 2966, 2967, 2968, 2970, 2971, 2973, 2975, 2976, 2977, 2978, 2980, 2980, 2982, 2984, 2986, 2988, 2990, 2990, 2992, 2993, 2994, 2995, 2997, 3000, 3002, 3003, 3005, 3006, 3008, 3009, 3010, 3012, 3013, 3015, 3016, 3017, 3019, 3020, 3021, 3022, 3024, 3025, 3027, 3028, 3031, 3033, 3035, 3035, 3037, 3038, 3040, 3041, 3043, 3045, 3047, 3048, 3048, 3050, 3052, 3052, 3054, 3055, 3055, 3056, 3058, 3058, 3060, 3061, 3061, 3063, 3065, 3066, 3068, 3070, 3071, 3073, 3075, 3077, 3079, 3081, 3081, 3083, 3084, 3084, 3086, 3088, 3089, 3091, 3092, 3092, 3093, 3095, 3095, 3097, 3099, 3102, 3104, 3105, 3107, 3109, 3110, 3114, 3115, 3117, 3121, 3123, 3124, 3124, 3126, 3127, 3127, 3129, 3130, 3130, 3133, 3134, 3134, 3136, 3138, 3140, 3143, 3144, 3145, 3147, 3149, 3149, 3151, 3152, 3154, 3155, 3157, 3157, 3159, 3161, 3163, 3165, 3167, 3172, 3174, 3176, 3185, 3187, 3188, 3189, 3191, 3192, 3192, 3194, 3195, 3198, 3200, 3201, 3202, 3204, 3206, 3207, 3213, 3215, 3224, 3226, 3227, 3232, 3233, 3235, 3245, 3247, 3248, 3249, 3251, 3253, 3263, 3265, 3266, 3267, 3268, 3270, 3270, 3272, 3274, 3274, 3275, 3277, 3278, 3279, 3281, 3283, 3285, 3286, 3286, 3288, 3290, 3293, 3295, 3296, 3296, 3297, 3299, 3303, 3305, 3307, 3315, 3317, 3318, 3318, 3319, 3321, 3323, 3324, 3326, 3326, 3327, 3329, 3331, 3333, 3334, 3334, 3336, 3337, 3339, 3341, 3342, 3350, 3352, 3353, 3354, 3356, 3358, 3362, 3364, 3365, 3369, 3371, 3372, 3376, 3378, 3379, 3394, 3396, 3397, 3397, 3399, 3401, 3406, 3408, 3410, 3415, 3416, 3418, 3422, 3424, 5632, 3428, 3430, 3431, 3435, 3436, 3438, 3442, 3444, 3446, 3446, 3447, 3449, 3452, 3454, 3456, 3458, 3459, 3461, 3466, 3468, 3469, 3471, 3473, 3475, 3477, 3478, 3480, 3483, 3485, 3486, 3487, 3489, 3490, 3499, 3500, 3502, 3508, 3510, 3511, 3512, 3514, 3516, 3516, 3518, 3519, 3522, 3524, 3525, 3528, 3530, 3531, 3531, 3533, 3535, 3536, 4792, 4790, 4791, 3537, 3539, 3539, 4758, 4759, 4763, 4764, 3540, 3540, 3541, 3543, 3543, 3545, 3547, 3547, 4886, 4881, 4885, 4886, 3547, 3547, 3548, 4867, 4868, 3548, 3549, 3549, 3551, 3553, 3553, 3555, 3557, 3558, 3559, 3561, 3562, 3563, 3565, 3567, 3569, 3571, 3575, 3576, 3578, 3590, 3592, 3594, 3595, 3597, 3598, 3606, 3608, 3610, 3615, 3616, 3618, 3619, 3621, 3622, 3625, 3626, 3628, 3629, 3631, 3633, 3637, 3639, 3641, 3641, 3643, 3645, 3647, 3649, 3650, 3650, 3652, 3653, 3653, 3655, 3656, 4806, 4808, 4809, 3658, 3659, 3661, 3662, 3664, 3666, 3669, 3671, 3672, 3672, 3674, 3675, 3679, 3681, 3682, 3686, 3688, 3689, 3690, 3692, 3693, 3693, 3695, 3696, 3696, 3697, 3699, 3711, 3713, 3715, 3722, 3724, 3727, 3729, 3731, 3733, 3734, 3736, 3737, 3738, 3740, 3741, 3745, 3747, 3749, 3749, 3751, 3753, 3753, 3754, 3756, 3760, 3762, 3764, 3764, 3766, 3768, 3776, 3777, 3779, 3779, 3781, 3783, 3785, 3787, 3788, 3791, 3793, 3795, 3795, 3797, 3798, 3798, 3800, 3802, 3804, 3806, 3807, 3807, 3809, 3810, 3813, 3816, 3818, 3820, 3822, 3823, 3825, 3827, 3828, 3828, 3829, 3831, 3836, 3837, 3839, 3843, 3844, 3846, 3846, 3848, 3849, 3850, 3851, 3853, 3853, 3855, 3856, 3861, 3863, 3864, 3864, 3866, 3868, 3872, 3873, 3875, 3877, 3879, 3881, 3881, 3882, 3884, 3887, 3888, 3890, 3892, 3894, 3896, 3896, 3898, 3900, 3900, 3901, 3903, 3910, 3912, 3913, 3915, 3916, 3918, 3919, 3921, 3922, 3927, 3929, 3930, 3932, 3934, 3935, 3939, 3940, 3942, 3942, 3944, 3945, 3945, 3947, 3949, 3955, 3957, 3958, 3963, 3964, 3966, 3968, 3969, 3971, 3975, 3977, 3978, 3978, 3981, 3982, 3985, 3987, 3988, 3991, 3993, 3994, 3999, 4000, 4003, 4010, 4011, 4013, 4018, 4020, 4021, 4021, 4022, 4024, 4029, 4031, 4032, 4036, 4037, 4039, 4047, 4049, 4050, 4056, 4058, 4059, 4061, 4063, 4065, 4070, 4072, 4073, 4075, 4076, 4078, 4086, 4088, 4089, 4094, 4096, 4098, 4098, 4100, 4101, 4102, 4104, 4105, 4107, 4109, 4111, 4114, 4115, 4117, 4122, 4124, 4125, 4129, 4131, 4133, 4137, 4138, 4140, 4140, 4141, 4143, 4143, 4144, 4146, 4150, 4152, 4154, 4159, 4161, 4162, 4166, 4167, 4169, 4170, 4171, 4173, 4174, 4176, 4177, 4178, 4179, 4181, 4187, 4189, 4190, 4196, 4198, 4199, 4203, 4205, 4206, 4206, 4207, 4209, 4211, 4213, 4215, 4221, 4223, 4225, 4230, 4231, 4233, 4234, 4236, 4237, 4244, 4246, 4248, 4254, 4256, 4257, 4258, 4260, 4261, 4261, 4263, 4265, 4274, 4276, 4277, 4277, 4279, 4281, 4287, 4289, 4290, 4295, 4297, 4299, 4299, 4300, 4303, 4306, 4308, 4309, 4313, 4315, 4316, 4318, 4319, 4321, 4322, 4323, 4325, 4326, 4328, 4330, 4332, 4334, 4337, 4337, 4339, 4341, 4343, 4345, 4347, 4347, 4349, 4350, 4350, 4352, 4354, 4363, 4364, 4366, 4370, 4372, 4374, 4377, 4379, 4381, 4381, 4382, 4384, 4393, 4394, 4396, 4397, 4398, 4400, 4402, 4404, 4405, 4411, 4413, 4414, 4416, 4417, 4419, 4427, 4429, 4430, 4431, 4432, 4434, 4951, 4952, 4439, 4439, 4440, 4442, 4448, 4450, 4451, 4969, 4451, 4452, 4453, 4454, 4456, 4457, 4459, 4460, 4463, 4465, 4972, 4467, 4469, 4470, 4479, 4481, 4483, 4493, 4495, 4496, 4508, 4509, 4511, 4511, 4513, 4514, 4526, 4528, 4529, 4534, 4536, 4538, 4543, 4545, 4547, 4550, 4552, 4553, 4555, 4557, 4982, 4978, 4979, 4981, 4567, 4569, 4570, 4574, 4576, 4578, 4578, 4580, 4582, 4582, 4584, 4585, 4588, 4589, 4591, 4597, 4599, 4600, 4600, 0, 2, 11, 13, 15, 15, 16, 18, 24, 26, 27, 32, 33, 35, 35, 36, 38, 42, 44, 46, 48, 50, 52, 54, 56, 58, 58, 60, 61, 70, 72, 74, 78, 80, 82, 82, 83, 85, 87, 88, 90, 99, 101, 103, 103, 105, 107, 110, 112, 114, 114, 4847, 4848, 4855, 4857, 4859, 4860, 4838, 4836, 4837, 4841, 4842, 4843, 4844, 4846, 4846, 115, 116, 117, 119, 120, 123, 125, 126, 126, 128, 130, 130, 132, 133, 133, 135, 137, 138, 139, 141, 141, 142, 144, 144, 146, 148, 148, 150, 151, 154, 156, 158, 160, 162, 163, 164, 166, 167, 167, 169, 171, 171, 173, 174, 174, 176, 177, 177, 179, 180, 181, 183, 184, 4773, 4777, 4780, 4782, 187, 189, 189, 193, 194, 205, 208, 212, 213, 214, 218, 221, 222, 225, 230, 231, 235, 237, 240, 243, 247, 250, 251, 4901, 253, 256, 260, 261, 264, 264, 266, 269, 269, 272, 274, 282, 286, 289, 289, 292, 293, 299, 303, 307, 307, 310, 313, 318, 322, 324, 325, 327, 330, 333, 337, 339, 341, 343, 347, 347, 349, 352, 355, 359, 361, 361, 362, 366, 370, 373, 377, 381, 383, 386, 390, 393, 394, 397, 398, 402, 406, 408, 411, 413, 416, 418, 419, 422, 423, 425, 426, 429, 431, 434, 436, 440, 443, 446, 453, 457, 459, 460, 463, 466, 472, 474, 477, 482, 483, 486, 492, 495, 496, 500, 501, 505, 508, 511, 514, 514, 517, 521, 523, 526, 528, 529, 533, 534, 4905, 4906, 534, 535, 539, 542, 545, 549, 550, 553, 556, 557, 557, 560, 561, 561, 562, 565, 573, 576, 577, 582, 584, 588, 599, 603, 605, 605, 607, 610, 620, 624, 625, 627, 630, 633, 635, 638, 639, 640, 641, 645, 654, 656, 659, 659, 662, 665, 665, 668, 669, 676, 679, 681, 690, 694, 697, 705, 709, 710, 712, 713, 716, 717, 720, 723, 725, 729, 731, 731, 735, 738, 742, 743, 746, 750, 753, 756, 759, 762, 763, 776, 779, 780, 783, 786, 788, 791, 794, 797, 808, 812, 813, 818, 820, 824, 826, 829, 830, 831, 832, 836, 836, 839, 841, 845, 848, 849, 853, 856, 857, 4996, 5000, 5001, 4992, 859, 860, 870, 873, 875, 878, 882, 885, 888, 890, 893, 908, 911, 913, 923, 924, 927, 937, 941, 942, 943, 944, 947, 952, 953, 956, 961, 962, 965, 966, 970, 973, 984, 987, 989, 1003, 1006, 1007, 1013, 1014, 1017, 1032, 1034, 1037, 1042, 1045, 1046, 1051, 1055, 1056, 1059, 1062, 1063, 1063, 1066, 1068, 1068, 1072, 1073, 1079, 1082, 1084, 1084, 1085, 1088, 1099, 1102, 1103, 1109, 1110, 1113, 1113, 1114, 1118, 1118, 1121, 1124, 1127, 1130, 1132, 1136, 1137, 1140, 1144, 1147, 5140, 1151, 1155, 1156, 1169, 1172, 1174, 1174, 1177, 1179, 1182, 1186, 1188, 1190, 1192, 1195, 1197, 1200, 1204, 1207, 1210, 1211, 1214, 1215, 1218, 1222, 1225, 1227, 1227, 1230, 1232, 1233, 1237, 1238, 1239, 1240, 1244, 1249, 1252, 1253, 1253, 1257, 1261, 1263, 1264, 1267, 1267, 1270, 1273, 1275, 1276, 4714, 4723, 1278, 1281, 5214, 5217, 5209, 5209, 5230, 5228, 5229, 5211, 5212, 5212, 1281, 1282, 1282, 1285, 1288, 1289, 1292, 1293, 1294, 1297, 1298, 1300, 1301, 1304, 1306, 1309, 1310, 1319, 1322, 1325, 1331, 1332, 1335, 1337, 1340, 1342, 1342, 1345, 1346, 1359, 1360, 1363, 1373, 1376, 1377, 1377, 1380, 1381, 1388, 1389, 1392, 1399, 1400, 1403, 5112, 5116, 5117, 5120, 4711, 4709, 4709, 5204, 5205, 5182, 5186, 5187, 5190, 5156, 5157, 5194, 5195, 5159, 5163, 5167, 5168, 5124, 5125, 5061, 5062, 5065, 5066, 5068, 5071, 5077, 5084, 5088, 5089, 5100, 5103, 5104, 5104, 5107, 5059, 5059, 5129, 5131, 1423, 1426, 1427, 1442, 1445, 1449, 1451, 1454, 1455, 1460, 1462, 1465, 1480, 1483, 1486, 1488, 1492, 1493, 1498, 1502, 1504, 1508, 1509, 1512, 1517, 1520, 1521, 1526, 1529, 1530, 1537, 1539, 1543, 1544, 1548, 1550, 1552, 1555, 4667, 1559, 1561, 1565, 1566, 1570, 1573, 1575, 1576, 1580, 1602, 1605, 1608, 1615, 1618, 1621, 1636, 1640, 1643, 1660, 1663, 1664, 1670, 1673, 1675, 1680, 1683, 1685, 1685, 1686, 1689, 1696, 1700, 1702, 1702, 1704, 1707, 1717, 1718, 1721, 1732, 1734, 1738, 1752, 1755, 1756, 1763, 1767, 1768, 1768, 1771, 1773, 1782, 1786, 1788, 1791, 1795, 1796, 1796, 1800, 1804, 1808, 1811, 1813, 1813, 1817, 1821, 1833, 1837, 1839, 1841, 1844, 1847, 1851, 1854, 1857, 1864, 1867, 1871, 1877, 1879, 1882, 1889, 1892, 1894, 1895, 1896, 1899, 1899, 1902, 1905, 1907, 1908, 1911, 1911, 1914, 1915, 1920, 1924, 1925, 1927, 1928, 1931, 1931, 1935, 1937, 1942, 1945, 1946, 1946, 1948, 1951, 1954, 1957, 1958, 1959, 1960, 1963, 1971, 1974, 1976, 1981, 1982, 1986, 1991, 1994, 1997, 1998, 1999, 2002, 2004, 2008, 2011, 2011, 2014, 2018, 2018, 2021, 2023, 2026, 2030, 2031, 2031, 2034, 2035, 2041, 2044, 2045, 2049, 2050, 2053, 2053, 2056, 2057, 2061, 2065, 2068, 2069, 2072, 2074, 2075, 2078, 2080, 2080, 2083, 2087, 2087, 2090, 2091, 4729, 4732, 4733, 2109, 2113, 2117, 2120, 2121, 2124, 2127, 2128, 2131, 2134, 2137, 2139, 2140, 2143, 2144, 2154, 2157, 2158, 2158, 2161, 2165, 2176, 2180, 2181, 2181, 2184, 2185, 2194, 2196, 2199, 2204, 2206, 2209, 2209, 2211, 2214, 2219, 2222, 2223, 2223, 2226, 2228, 2232, 2234, 2237, 2241, 5375, 5376, 5376, 5380, 5283, 5280, 5380, 5381, 5257, 5408, 5410, 5392, 5395, 5396, 5399, 5374, 5323, 5325, 5327, 2245, 2246, 5421, 5418, 5420, 2249, 2250, 2250, 5354, 5355, 5358, 5361, 5362, 5365, 5368, 5369, 5333, 5337, 5338, 5347, 5351, 5352, 5352, 2254, 5305, 2256, 5024, 5025, 5028, 5034, 5035, 5033, 2259, 2260, 2260, 2263, 5493, 5455, 5456, 5459, 5459, 5462, 5463, 5468, 5469, 5488, 5489, 2264, 2266, 2266, 2269, 2271, 2271, 2274, 2277, 5478, 2280, 2283, 2289, 2290, 2294, 2295, 2297, 5504, 5507, 5527, 5528, 5529, 5511, 5512, 2303, 2306, 2307, 2310, 2314, 2315, 2316, 2319, 2320, 2320, 2321, 2325, 2334, 2338, 2339, 2342, 2345, 2348, 2350, 2354, 2355, 2355, 2358, 2359, 2362, 2363, 2366, 2378, 2382, 2383, 2386, 2388, 2391, 2392, 2397, 2400, 2407, 2410, 2412, 2418, 2420, 2424, 2431, 2434, 2436, 2443, 2445, 2448, 2453, 2457, 2458, 2465, 2466, 2469, 2471, 2474, 2475, 2476, 2477, 2481, 2482, 2485, 2486, 2487, 2490, 2491, 2494, 2498, 2499, 2501, 2502, 2505, 2505, 2508, 2509, 2513, 2516, 2519, 2527, 2528, 2532, 2533, 2534, 2538, 2539, 2540, 2544, 2548, 2552, 2553, 2554, 2556, 2559, 2559, 2563, 2564, 2565, 2566, 2570, 2575, 2576, 2579, 2583, 2587, 2590, 2591, 2595, 2597, 2598, 2601, 2604, 2608, 2612, 2613, 2613, 2617, 2620, 2625, 2626, 2629, 2634, 2637, 2638, 2647, 2649, 2653, 2658, 2660, 2664, 2667, 2670, 2671, 2675, 2678, 2681, 2688, 2690, 2694, 2702, 2705, 2708, 2719, 2723, 2724, 2724, 2727, 2730, 2738, 2741, 2742, 2742, 2745, 2746, 2746, 2749, 2752, 2756, 2759, 2760, 2762, 2765, 2766, 2772, 2775, 2778, 2783, 2785, 2788, 2788, 2792, 2796, 2798, 2799, 2803, 2803, 2806, 2810, 2814, 2817, 2819, 2821, 2825, 2829, 2840, 2843, 2844, 2850, 2854, 2856, 2863, 2866, 2868, 2879, 2880, 2883, 2890, 2894, 2897, 2906, 2909, 2910, 2910, 2913, 2916, 2917, 2920, 2923, 2932, 2936, 2940, 2944, 2947, 2948, 2951, 2954, 2955, 2968, 2971, 2973, 2985, 2986, 2990, 2994, 2997, 2998, 3000, 3003, 3004, 3005, 3008, 3009, 3009, 3012, 3013, 3013, 3016, 3017, 3021, 3024, 3025, 3031, 3035, 3038, 3040, 3043, 3044, 3045, 3048, 3052, 3055, 3058, 3061, 3066, 3070, 3071, 3077, 3081, 3084, 3084, 3088, 3089, 3089, 3092, 3095, 3095, 3099, 3101, 3106, 3107, 3110, 3114, 3117, 3119, 3119, 3121, 3124, 3124, 3127, 3130, 3134, 3138, 3139, 3139, 3140, 3144, 3145, 3149, 3152, 3152, 3154, 3157, 3163, 3167, 3168, 3171, 3172, 3176, 3183, 3185, 3188, 3189, 3192, 3195, 3202, 3206, 3207, 3223, 3224, 3227, 3232, 3235, 3236, 3242, 3245, 3248, 3249, 3253, 3255, 3261, 3263, 3266, 3267, 3270, 3274, 3274, 3277, 3278, 3278, 3281, 3282, 3282, 3283, 3286, 3286, 3290, 3292, 3296, 3299, 3300, 3302, 3303, 3307, 3313, 3315, 3318, 3318, 3321, 3322, 3323, 3326, 3329, 3334, 3337, 3338, 3338, 3339, 3342, 3353, 3354, 3358, 3361, 3362, 3365, 3367, 3369, 3372, 3375, 3376, 3379, 3392, 3394, 3397, 3406, 3410, 3411, 3415, 3418, 3419, 3428, 3431, 3433, 3442, 3446, 3449, 3458, 3461, 3462, 3466, 3469, 3471, 3471, 3475, 3477, 3477, 3480, 3481, 3481, 3483, 3486, 3486, 3487, 3490, 3511, 3512, 3516, 3521, 3522, 3525, 3528, 3531, 3535, 4791, 3539, 4759, 4763, 3540, 3543, 3543, 3547, 4881, 4884, 4885, 3547, 4868, 3549, 3553, 3553, 3557, 3558, 3558, 3561, 3562, 3562, 3565, 3567, 3567, 3571, 3573, 3575, 3578, 3579, 3590, 3594, 3595, 3618, 3619, 3622, 3625, 3628, 3629, 3635, 3637, 3641, 3650, 3653, 3656, 3658, 3661, 3662, 3662, 3666, 3668, 3669, 3672, 3675, 3689, 3690, 3693, 3710, 3711, 3715, 3720, 3722, 3727, 3728, 3729, 3733, 3733, 3734, 3737, 3738, 3741, 3742, 3744, 3745, 3749, 3749, 3753, 3756, 3759, 3760, 3764, 3776, 3779, 3783, 3807, 3810, 3812, 3812, 3813, 3818, 3823, 3825, 3828, 3828, 3831, 3832, 3836, 3839, 3840, 3864, 3868, 3869, 3876, 3877, 3881, 3890, 3892, 3896, 3909, 3910, 3913, 3915, 3918, 3919, 3931, 3932, 3935, 3954, 3955, 3958, 3968, 3971, 3973, 3974, 3975, 3978, 3978, 3982, 3983, 3985, 3988, 3989, 3990, 3991, 3994, 3999, 4003, 4005, 4010, 4013, 4015, 4017, 4018, 4021, 4028, 4029, 4032, 4036, 4039, 4041, 4045, 4047, 4050, 4056, 4059, 4061, 4061, 4065, 4066, 4070, 4073, 4074, 4075, 4078, 4080, 4084, 4086, 4089, 4094, 4098, 4101, 4102, 4105, 4106, 4121, 4122, 4125, 4143, 4146, 4147, 4159, 4162, 4163, 4166, 4169, 4170, 4170, 4173, 4174, 4178, 4181, 4183, 4196, 4199, 4201, 4202, 4203, 4206, 4210, 4211, 4215, 4220, 4221, 4225, 4228, 4230, 4233, 4233, 4234, 4237, 4253, 4254, 4257, 4261, 4265, 4266, 4274, 4277, 4281, 4285, 4287, 4290, 4295, 4299, 4303, 4304, 4306, 4309, 4318, 4321, 4322, 4326, 4330, 4332, 4332, 4337, 4341, 4343, 4347, 4350, 4350, 4354, 4356, 4363, 4366, 4367, 4377, 4381, 4384, 4393, 4396, 4397, 4397, 4400, 4402, 4410, 4411, 4414, 4427, 4430, 4431, 4431, 4434, 4435, 4447, 4448, 4451, 4960, 4451, 4969, 4968, 4969, 4452, 4456, 4457, 4460, 4463, 4972, 4973, 4477, 4479, 4483, 4511, 4514, 4516, 4533, 4534, 4538, 4549, 4550, 4553, 4554, 4555, 4982, 4572, 4574, 4578, 4578, 4582, 4585, 4588, 4591, 4592, 4600, 2, 3, 11, 15, 18, 32, 35, 38, 47, 48, 52, 54, 58, 61, 70, 74, 76, 82, 85, 87, 87, 90, 92, 99, 103, 107, 109, 110, 114, 114, 4848, 4849, 4854, 4855, 4859, 4859, 4860, 4836, 4843, 4846, 116, 117, 120, 123, 138, 141, 144, 152, 154, 158, 160, 163, 164, 167, 171, 174, 177, 180, 181, 189, 194, 197, 204, 205, 212, 213, 218, 220, 230, 235, 236, 236, 237, 243, 243, 247, 251, 4901, 256, 258, 260, 264, 269, 282, 289, 293, 299, 307, 313, 318, 324, 325, 325, 330, 331, 333, 339, 340, 341, 347, 352, 352, 355, 361, 361, 366, 368, 368, 370, 377, 380, 381, 386, 389, 390, 394, 411, 413, 418, 419, 423, 425, 425, 429, 430, 431, 436, 437, 438, 440, 446, 452, 453, 459, 459, 460, 466, 492, 496, 499, 500, 505, 506, 514, 521, 522, 522, 523, 528, 528, 529, 534, 4905, 534, 535, 542, 545, 550, 553, 557, 561, 561, 565, 566, 573, 577, 580, 582, 588, 589, 595, 599, 605, 617, 620, 625, 627, 633, 635, 639, 640, 645, 654, 659, 665, 676, 681, 682, 690, 697, 698, 702, 705, 710, 717, 723, 725, 742, 746, 748, 749, 750, 756, 781, 783, 788, 791, 797, 799, 808, 813, 816, 825, 826, 830, 831, 836, 841, 844, 845, 849, 851, 853, 857, 4996, 5001, 5004, 4992, 860, 861, 869, 870, 875, 906, 908, 913, 935, 937, 942, 943, 947, 948, 952, 956, 957, 959, 961, 965, 965, 966, 973, 1001, 1003, 1007, 1013, 1017, 1021, 1042, 1046, 1050, 1050, 1051, 1056, 1068, 1073, 1074, 1084, 1088, 1089, 1098, 1099, 1103, 1109, 1113, 1118, 1136, 1140, 1141, 1167, 1169, 1174, 1179, 1182, 1188, 1195, 1197, 1204, 1204, 1207, 1211, 1214, 1218, 1220, 1220, 1222, 1227, 1233, 1238, 1239, 1239, 1244, 1245, 1249, 1253, 1261, 1261, 1263, 1267, 1274, 1275, 4714, 4723, 1281, 5214, 5214, 5209, 5228, 5212, 1282, 1288, 1289, 1293, 1294, 1300, 1304, 1306, 1318, 1319, 1325, 1336, 1337, 1342, 1359, 1363, 1364, 1370, 1373, 1377, 1399, 1403, 1404, 5112, 5117, 5119, 5188, 5190, 5157, 5194, 5159, 5160, 5161, 5163, 5168, 5122, 5124, 5061, 5068, 5077, 5078, 5082, 5084, 5089, 5098, 5100, 5104, 5104, 5059, 5131, 1442, 1449, 1450, 1451, 1455, 1457, 1460, 1465, 1467, 1480, 1486, 1487, 1498, 1504, 1507, 1517, 1521, 1522, 1525, 1526, 1530, 1536, 1537, 1543, 1544, 1550, 1551, 1558, 1559, 1565, 1566, 1573, 1574, 1575, 1580, 1583, 1599, 1602, 1608, 1614, 1615, 1621, 1636, 1643, 1645, 1658, 1660, 1664, 1680, 1685, 1689, 5442, 1696, 1702, 1702, 1707, 1710, 1752, 1756, 1759, 1763, 1768, 1773, 1796, 1804, 1808, 1808, 1813, 1821, 1829, 1833, 1839, 1851, 1857, 1861, 1877, 1882, 1883, 1887, 1889, 1894, 1895, 1899, 1905, 1907, 1911, 1915, 1918, 1920, 1925, 1927, 1931, 1937, 1942, 1946, 1951, 1954, 1958, 1959, 1970, 1971, 1976, 1979, 1981, 1986, 1991, 1997, 1998, 1998, 2002, 2003, 2003, 2004, 2011, 2026, 2031, 2035, 2041, 2045, 2049, 2049, 2053, 2057, 2059, 2061, 2068, 2068, 2069, 2074, 2075, 2080, 2087, 2109, 2117, 2120, 2120, 2124, 2127, 2132, 2134, 2139, 2140, 2144, 2146, 2150, 2154, 2158, 2172, 2176, 2181, 2193, 2194, 2199, 2204, 2209, 2214, 2218, 2219, 2223, 2232, 2237, 2239, 5291, 2241, 5376, 5280, 5381, 5274, 5392, 5396, 5399, 5399, 5323, 5324, 4637, 2246, 5418, 4641, 5418, 5419, 5420, 2250, 5355, 5362, 5365, 5369, 5333, 5338, 5339, 5347, 5352, 5305, 2256, 5025, 5026, 5028, 5035, 5030, 5455, 5459, 5523, 5522, 5523, 5459, 5468, 5488, 5489, 5489, 2266, 2271, 2271, 2277, 2278, 2289, 2294, 2295, 2295, 5504, 5505, 5507, 5528, 5529, 2302, 2303, 2307, 2310, 2315, 2316, 2316, 2320, 2325, 2333, 2334, 2339, 2342, 2348, 2349, 2349, 2350, 2355, 2359, 2362, 2366, 2374, 2378, 2383, 2392, 2400, 2402, 2407, 2412, 2413, 2418, 2424, 2425, 2429, 2431, 2436, 2443, 2448, 2449, 2465, 2469, 2470, 2476, 2481, 2482, 2482, 2486, 2487, 2501, 2505, 2509, 2511, 2513, 2519, 2525, 2527, 2532, 2533, 2538, 2539, 2539, 2544, 2546, 2554, 2559, 2564, 2565, 2570, 2571, 2575, 2579, 2582, 2582, 2583, 2590, 2591, 2597, 2598, 2608, 2613, 2620, 2625, 2629, 2630, 2633, 2634, 2638, 2658, 2664, 2667, 2675, 2681, 2684, 2688, 2694, 2695, 2702, 2708, 2710, 2718, 2719, 2724, 2737, 2738, 2742, 2746, 2752, 2754, 2754, 2756, 2760, 2772, 2778, 2780, 2781, 2783, 2788, 2788, 2796, 2797, 2797, 2798, 2803, 2803, 2810, 2812, 2821, 2829, 2832, 2839, 2840, 2844, 2849, 2850, 2856, 2862, 2863, 2868, 2879, 2883, 2884, 2889, 2890, 2897, 2905, 2906, 2910, 2910, 2916, 2917, 2917, 2923, 2925, 2943, 2944, 2948, 2950, 2951, 2955, 2967, 2968, 2973, 2985, 2990, 2993, 2994, 2998, 2999, 2999, 3000, 3004, 3005, 3009, 3013, 3020, 3021, 3025, 3030, 3031, 3038, 3045, 3052, 3055, 3066, 3071, 3075, 3077, 3084, 3089, 3089, 3095, 3101, 3106, 3110, 3112, 3113, 3114, 3119, 3119, 3124, 3130, 3130, 3134, 3139, 3145, 3152, 3157, 3162, 3163, 3168, 3171, 3176, 3178, 3181, 3183, 3188, 3188, 3189, 3195, 3201, 3202, 3207, 3232, 3236, 3238, 3242, 3248, 3249, 3249, 3255, 3256, 3261, 3266, 3267, 3267, 3274, 3278, 3282, 3286, 3292, 3296, 3300, 3302, 3302, 3307, 3308, 3313, 3318, 3322, 3323, 3329, 3331, 3353, 3358, 3359, 3361, 3365, 3366, 3367, 3372, 3373, 3373, 3375, 3379, 3392, 3397, 3401, 3405, 3406, 3411, 3428, 3433, 3435, 3440, 3442, 3449, 3457, 3458, 3462, 3471, 3477, 3481, 3511, 3516, 3519, 3521, 3525, 3526, 4790, 4791, 4759, 4763, 3543, 4881, 4884, 3547, 4867, 4868, 3553, 3558, 3562, 3567, 3573, 3573, 3575, 3579, 3589, 3590, 3595, 3615, 3618, 3622, 3625, 3629, 3633, 3635, 3641, 3645, 3662, 3668, 3669, 3733, 3737, 3738, 3759, 3764, 3768, 3774, 3776, 3783, 3804, 3807, 3812, 3823, 3828, 3832, 3834, 3836, 3840, 3861, 3864, 3869, 3876, 3881, 3884, 3887, 3890, 3896, 3907, 3909, 3913, 3915, 3919, 3922, 3930, 3931, 3935, 3954, 3958, 3959, 3974, 3978, 3983, 3990, 3994, 4603, 3998, 3999, 4005, 4015, 4017, 4021, 4027, 4028, 4032, 4035, 4036, 4041, 4045, 4050, 4052, 4056, 4061, 4066, 4075, 4080, 4081, 4084, 4089, 4090, 4101, 4102, 4106, 4120, 4121, 4125, 4140, 4143, 4147, 4158, 4159, 4163, 4166, 4170, 4174, 4177, 4178, 4183, 4202, 4206, 4209, 4210, 4215, 4216, 4220, 4225, 4227, 4228, 4233, 4237, 4253, 4257, 4258, 4261, 4266, 4268, 4272, 4274, 4281, 4285, 4290, 4292, 4294, 4295, 4303, 4304, 4309, 4311, 4316, 4318, 4322, 4326, 4332, 4341, 4350, 4356, 4357, 4363, 4367, 4368, 4377, 4384, 4385, 4393, 4397, 4402, 4410, 4414, 4415, 4447, 4451, 4960, 4960, 4969, 4966, 4968, 4452, 4453, 4477, 4483, 4484, 4532, 4533, 4538, 4554, 4982, 4983, 4572, 4578, 4585, 4600, 3, 4, 31, 32, 38, 46, 47, 52, 54, 61, 62, 82, 87, 92, 98, 99, 107, 109, 114, 4849, 4859, 4836, 4837, 4842, 4843, 116, 117, 123, 126, 138, 144, 148, 160, 164, 167, 167, 174, 177, 204, 212, 213, 213, 220, 221, 236, 243, 251, 258, 260, 269, 282, 293, 295, 299, 313, 314, 325, 331, 333, 341, 352, 361, 361, 368, 377, 379, 380, 386, 389, 394, 395, 406, 411, 418, 419, 425, 430, 437, 438, 446, 452, 459, 466, 490, 492, 499, 514, 522, 528, 542, 550, 551, 551, 553, 561, 580, 582, 589, 595, 605, 610, 617, 625, 626, 639, 645, 648, 653, 654, 665, 675, 676, 682, 702, 710, 711, 716, 717, 725, 742, 748, 749, 749, 756, 757, 825, 830, 831, 831, 841, 842, 851, 857, 4996, 4992, 861, 865, 906, 913, 914, 943, 948, 950, 952, 957, 959, 965, 973, 976, 1001, 1007, 1008, 1010, 1013, 1021, 1050, 1056, 1057, 1079, 1084, 1089, 1098, 1103, 1104, 1109, 1118, 1124, 1134, 1136, 1141, 1167, 1174, 1179, 1195, 1204, 1211, 1232, 1233, 1239, 1239, 1245, 1246, 1247, 1249, 1261, 1261, 1267, 1273, 1274, 4714, 4715, 4722, 4723, 5214, 1289, 1294, 1298, 1300, 1306, 1310, 1318, 1325, 1328, 1336, 1342, 1346, 1359, 1364, 1365, 5188, 5157, 5197, 5194, 5160, 5161, 5161, 5168, 5169, 5122, 5061, 5062, 5068, 5078, 5080, 5082, 5089, 5090, 5098, 5104, 5131, 1441, 1442, 1450, 1460, 1467, 1468, 1476, 1480, 1487, 1497, 1498, 1507, 1516, 1517, 1522, 1522, 1525, 1530, 1536, 1543, 1544, 1557, 1558, 1565, 1566, 1574, 1575, 1575, 1583, 1585, 1599, 1608, 1611, 1614, 1621, 1623, 1634, 1636, 1645, 5442, 1702, 1710, 1751, 1752, 1759, 1763, 1773, 1774, 1829, 1839, 1841, 1850, 1851, 1861, 1875, 1877, 1883, 1887, 1894, 1895, 1895, 1905, 1907, 1907, 1915, 1918, 1918, 1925, 1927, 1927, 1937, 1938, 1953, 1954, 1959, 1970, 1976, 1978, 1979, 1986, 1988, 1998, 2003, 2011, 2025, 2026, 2035, 2040, 2041, 2049, 2049, 2057, 2058, 2059, 2068, 2074, 2074, 2075, 2087, 2105, 2109, 2120, 2132, 2139, 2140, 2140, 2146, 2147, 2147, 2150, 2158, 2172, 2181, 2185, 2193, 2199, 2201, 2203, 2204, 2214, 2217, 2218, 2223, 2231, 2232, 2239, 5291, 5376, 5283, 5279, 5280, 5274, 5392, 5399, 5324, 4637, 5418, 4641, 4641, 5419, 5420, 5420, 5355, 5356, 5362, 5369, 5329, 5346, 5347, 5305, 5027, 5028, 5030, 5455, 5523, 5516, 5520, 5522, 5459, 5467, 5468, 5489, 5489, 2271, 2278, 2289, 2295, 5505, 2299, 2302, 2307, 2316, 2325, 2326, 2333, 2339, 2341, 2341, 2342, 2349, 2349, 2355, 2359, 2359, 2366, 2368, 2374, 2383, 2386, 2392, 2402, 2403, 2429, 2436, 2438, 2443, 2449, 2452, 2464, 2465, 2470, 2475, 2476, 2482, 2482, 2487, 2491, 2501, 2509, 2511, 2511, 2519, 2520, 2525, 2532, 2533, 2533, 2539, 2546, 2564, 2565, 2571, 2590, 2591, 2598, 2608, 2620, 2621, 2624, 2625, 2630, 2633, 2638, 2639, 2657, 2658, 2667, 2675, 2684, 2686, 2688, 2695, 2696, 2702, 2710, 2711, 2718, 2724, 2730, 2737, 2742, 2746, 2746, 2754, 2760, 2772, 2780, 2781, 2781, 2788, 2797, 2797, 2803, 2812, 2820, 2821, 2832, 2835, 2839, 2844, 2849, 2856, 2857, 2877, 2879, 2884, 2889, 2897, 2898, 2905, 2910, 2917, 2943, 2948, 2949, 2950, 2955, 2957, 2964, 2967, 2973, 2985, 2993, 2994, 3005, 3013, 3017, 3030, 3038, 3039, 3045, 3055, 3061, 3065, 3066, 3075, 3077, 3089, 3101, 3106, 3112, 3113, 3113, 3119, 3130, 3144, 3145, 3157, 3162, 3168, 3169, 3188, 3195, 3197, 3201, 3207, 3215, 3231, 3232, 3238, 3242, 3249, 3256, 3267, 3278, 3282, 3296, 3302, 3308, 3313, 3322, 3323, 3323, 3331, 3334, 3350, 3353, 3359, 3366, 3367, 3373, 3391, 3392, 3401, 3427, 3428, 3435, 3440, 3449, 3450, 3457, 3462, 3463, 3471, 3481, 3486, 3511, 3519, 3520, 4760, 4763, 4881, 4882, 4884, 4867, 4868, 3558, 3562, 3573, 3579, 3580, 3587, 3589, 3595, 3615, 3622, 3623, 3625, 3633, 3634, 3635, 3645, 3646, 3658, 3662, 3669, 3728, 3733, 3738, 3759, 3768, 3769, 3774, 3783, 3785, 3823, 3832, 3834, 3834, 3840, 3841, 3876, 3884, 3885, 3887, 3896, 3900, 3907, 3913, 3915, 3989, 3990, 4603, 3998, 4005, 4006, 4015, 4021, 4024, 4027, 4032, 4033, 4035, 4041, 4042, 4045, 4052, 4053, 4056, 4066, 4067, 4082, 4084, 4090, 4101, 4106, 4107, 4120, 4125, 4126, 4158, 4163, 4164, 4177, 4183, 4184, 4201, 4202, 4209, 4219, 4220, 4227, 4228, 4237, 4239, 4252, 4253, 4258, 4261, 4268, 4269, 4272, 4281, 4282, 4285, 4292, 4294, 4294, 4303, 4304, 4361, 4363, 4368, 4377, 4385, 4386, 4410, 4415, 4416, 4959, 4960, 4966, 4475, 4477, 4484, 4532, 4538, 4539, 4554, 4983, 4985, 4571, 4572, 4585, 31, 38, 39, 42, 46, 52, 54, 62, 64, 82, 92, 94, 98, 107, 108, 109, 4849, 4850, 4854, 4859, 4837, 4842, 116, 117, 160, 167, 177, 204, 213, 221, 230, 236, 251, 258, 269, 274, 282, 295, 296, 298, 299, 314, 325, 333, 340, 361, 377, 378, 379, 386, 388, 419, 430, 431, 437, 446, 447, 451, 452, 466, 508, 514, 528, 535, 542, 551, 551, 561, 566, 580, 589, 590, 4623, 595, 610, 635, 639, 648, 653, 665, 669, 700, 702, 711, 739, 742, 749, 825, 831, 842, 849, 851, 4996, 5007, 4992, 865, 904, 906, 914, 952, 959, 965, 965, 976, 977, 1000, 1001, 1008, 1042, 1050, 1057, 1097, 1098, 1104, 1109, 1124, 1125, 1133, 1134, 1141, 1190, 1195, 1211, 1247, 1261, 1273, 1274, 4715, 4716, 4721, 4722, 5214, 1298, 1300, 1310, 1315, 1318, 1328, 1335, 1336, 1346, 1358, 1359, 1365, 5194, 5161, 5169, 5122, 5062, 5066, 5068, 5080, 5081, 5097, 5098, 5131, 1441, 1450, 1451, 1497, 1507, 1508, 1515, 1516, 1522, 1536, 1544, 1551, 1557, 1565, 1566, 1566, 1575, 1585, 1597, 1599, 1611, 1614, 1623, 1625, 1634, 1645, 1646, 5442, 1710, 1711, 5449, 1751, 1759, 1760, 1763, 1774, 1826, 1829, 1841, 1886, 1887, 1895, 1895, 1907, 1918, 1918, 1927, 1938, 1953, 1959, 1963, 1969, 1970, 1978, 1978, 1979, 1988, 1991, 1998, 2011, 2025, 2035, 2036, 2039, 2040, 2049, 2074, 2087, 2091, 2105, 2120, 2127, 2131, 2132, 2140, 2147, 2158, 2165, 2171, 2172, 2185, 2193, 2201, 2203, 2203, 2214, 2216, 2217, 2223, 2228, 2231, 2239, 2240, 5290, 5291, 5283, 5287, 5279, 5274, 5390, 5392, 5324, 5420, 5356, 5358, 5358, 5362, 5329, 5344, 5346, 5305, 5027, 5030, 5032, 5519, 5520, 5459, 2288, 2289, 5505, 2299, 2307, 2308, 2341, 2349, 2359, 2359, 2368, 2369, 2374, 2386, 2391, 2392, 2403, 2404, 2426, 2429, 2438, 2442, 2443, 2452, 2464, 2470, 2471, 2499, 2501, 2511, 2511, 2520, 2524, 2525, 2533, 2546, 2590, 2598, 2604, 2607, 2608, 2621, 2624, 2630, 2631, 2631, 2633, 2639, 2657, 2667, 2671, 2672, 2675, 2686, 2702, 2711, 2712, 2718, 2730, 2732, 2734, 2737, 2746, 2746, 2760, 2761, 2769, 2772, 2781, 2797, 2812, 2814, 2820, 2832, 2834, 2835, 2844, 2847, 2847, 2849, 2857, 2877, 2884, 2886, 2888, 2889, 2898, 2903, 2905, 2917, 2942, 2943, 2949, 2949, 2950, 2957, 2964, 2973, 2976, 2985, 2994, 2999, 3004, 3005, 3017, 3076, 3077, 3101, 3106, 3113, 3130, 3139, 3144, 3157, 3161, 3162, 3169, 3188, 3197, 3198, 3198, 3201, 3215, 3242, 3256, 3258, 3267, 3282, 3292, 3296, 3308, 3309, 3313, 3323, 3334, 3366, 3373, 3379, 3391, 3401, 3402, 3427, 3435, 3438, 3440, 3450, 3452, 3457, 3463, 3464, 3466, 3471, 3486, 4760, 4881, 4882, 4868, 3562, 3573, 3587, 3595, 3598, 3615, 3623, 3624, 3625, 3634, 3635, 3658, 3669, 3675, 3727, 3728, 3738, 3758, 3759, 3769, 3774, 3785, 3788, 3823, 3834, 3841, 3875, 3876, 3885, 3885, 3887, 3900, 3906, 3907, 3915, 4025, 4027, 4033, 4045, 4053, 4054, 4082, 4090, 4092, 4101, 4107, 4111, 4119, 4120, 4126, 4157, 4158, 4164, 4174, 4177, 4184, 4201, 4209, 4210, 4219, 4227, 4228, 4228, 4239, 4240, 4250, 4252, 4258, 4270, 4272, 4282, 4294, 4304, 4311, 4361, 4368, 4369, 4409, 4410, 4416, 4959, 4966, 4967, 4475, 4484, 4485, 4570, 4571, 4585, 31, 39, 40, 42, 52, 53, 53, 54, 64, 82, 94, 95, 97, 98, 108, 109, 4850, 4851, 4854, 4837, 4842, 160, 177, 181, 204, 221, 225, 229, 230, 251, 4901, 258, 274, 282, 296, 297, 297, 298, 314, 318, 325, 340, 341, 361, 378, 379, 388, 389, 418, 419, 431, 431, 437, 447, 451, 466, 467, 506, 508, 528, 535, 551, 566, 580, 590, 593, 593, 4623, 610, 635, 648, 649, 650, 653, 669, 700, 711, 712, 825, 842, 844, 849, 4996, 5004, 5007, 865, 869, 904, 914, 916, 965, 977, 979, 1000, 1008, 1009, 1039, 1042, 1057, 1095, 1097, 1104, 1133, 1141, 1142, 1190, 1211, 1212, 1246, 1247, 1273, 4721, 5214, 5228, 1298, 1310, 1312, 1315, 1328, 1331, 1358, 1365, 1366, 5194, 5169, 5172, 5096, 5097, 5131, 1438, 1441, 1451, 1515, 1522, 1530, 1535, 1536, 1551, 1557, 1566, 1585, 1597, 1611, 1612, 1631, 1634, 1646, 5442, 1711, 1713, 5449, 1759, 1760, 1760, 1774, 1775, 1826, 1841, 1847, 1886, 1895, 1918, 1918, 1938, 1939, 1969, 1978, 1988, 1991, 2011, 2018, 2025, 2036, 2037, 2039, 2049, 2058, 2074, 2091, 2092, 2105, 2127, 2131, 2147, 2165, 2168, 2170, 2171, 2185, 2191, 2193, 2203, 2203, 2216, 2217, 5294, 5290, 5283, 5285, 5287, 5274, 5388, 5390, 5324, 5420, 5358, 5329, 5344, 5305, 5307, 5519, 5459, 5463, 2288, 5505, 5506, 5513, 2299, 2308, 2341, 2359, 2369, 2372, 2374, 2391, 2426, 2438, 2440, 2462, 2464, 2471, 2511, 2524, 2525, 2525, 2546, 2547, 2582, 2590, 2604, 2623, 2624, 2631, 2631, 2639, 2640, 2672, 2686, 2688, 2702, 2712, 2714, 2716, 2718, 2732, 2734, 2746, 2761, 2769, 2781, 2797, 2819, 2820, 2834, 2847, 2857, 2861, 2875, 2877, 2886, 2886, 2888, 2898, 2902, 2903, 2917, 2949, 2957, 2958, 2964, 2976, 2977, 2999, 3004, 3017, 3075, 3076, 3101, 3106, 3130, 3139, 3139, 3157, 3161, 3188, 3198, 3215, 3261, 3267, 3292, 3293, 3296, 3309, 3313, 3334, 3338, 3366, 3379, 3380, 3390, 3391, 3402, 3438, 3440, 3452, 3456, 3457, 3464, 3466, 3486, 3490, 4867, 4868, 3573, 3587, 3598, 3599, 3613, 3615, 3624, 3624, 3625, 3635, 3727, 3738, 3742, 3758, 3769, 3771, 3773, 3774, 3788, 3823, 3841, 3843, 3872, 3875, 3885, 3885, 3900, 3903, 3906, 3915, 3922, 4025, 4033, 4035, 4043, 4045, 4054, 4081, 4082, 4092, 4119, 4126, 4127, 4157, 4164, 4165, 4201, 4210, 4216, 4219, 4228, 4240, 4250, 4258, 4261, 4270, 4282, 4284, 4359, 4361, 4369, 4409, 4416, 4419, 4959, 4967, 4968, 4474, 4475, 4485, 30, 31, 40, 40, 42, 53, 78, 82, 95, 159, 160, 181, 203, 204, 225, 229, 251, 252, 4901, 274, 277, 297, 314, 4935, 318, 340, 341, 341, 378, 379, 379, 389, 395, 431, 447, 451, 451, 467, 468, 506, 528, 534, 4905, 535, 566, 573, 580, 593, 593, 610, 613, 627, 635, 649, 649, 650, 669, 698, 700, 712, 824, 825, 844, 844, 849, 5004, 5007, 869, 875, 903, 904, 916, 1039, 1057, 1059, 1095, 1104, 1107, 1132, 1133, 1142, 1190, 1212, 1214, 1246, 1273, 1274, 4716, 4721, 5228, 1312, 1315, 1331, 1355, 1358, 1366, 5193, 5194, 5172, 1438, 1451, 1457, 1513, 1515, 1530, 1535, 1551, 1552, 1557, 1585, 1586, 1594, 1597, 1612, 1631, 1646, 1647, 5449, 1760, 1775, 1825, 1826, 1847, 1918, 1939, 1940, 1968, 1969, 1988, 1991, 2018, 2023, 2025, 2037, 2039, 2059, 2074, 2092, 2140, 2147, 2168, 2191, 2203, 2217, 5294, 5283, 5284, 5285, 5274, 5276, 5387, 5388, 5414, 5412, 5388, 5324, 5420, 5329, 5330, 5344, 5307, 5308, 5519, 5463, 5465, 2288, 5506, 5507, 5513, 2308, 2310, 2372, 2391, 2392, 2425, 2426, 2440, 2462, 2471, 2475, 2511, 2525, 2547, 2582, 2604, 2605, 2622, 2623, 2631, 2631, 2640, 2644, 2672, 2688, 2696, 2698, 2702, 2714, 2716, 2732, 2734, 2768, 2769, 2797, 2819, 2834, 2835, 2835, 2847, 2861, 2875, 2886, 2898, 2900, 2902, 2917, 2942, 2949, 2958, 2963, 2964, 2977, 2999, 3017, 3020, 3075, 3101, 3102, 3106, 3139, 3161, 3181, 3188, 3215, 3260, 3261, 3292, 3293, 3309, 3310, 3313, 3338, 3342, 3361, 3366, 3380, 3390, 3402, 3404, 3438, 3452, 3456, 3466, 3490, 3491, 4882, 4867, 3573, 3624, 3635, 3646, 3720, 3727, 3742, 3773, 3788, 3789, 3820, 3823, 3843, 3870, 3872, 3885, 3885, 3903, 3905, 3905, 3906, 3922, 4024, 4025, 4035, 4075, 4081, 4092, 4118, 4119, 4127, 4156, 4157, 4165, 4196, 4201, 4216, 4250, 4261, 4269, 4269, 4270, 4284, 4359, 4369, 4370, 4408, 4409, 4419, 4474, 4485, 4486, 29, 30, 40, 77, 78, 95, 203, 225, 226, 226, 229, 252, 4901, 277, 278, 282, 297, 4935, 318, 341, 379, 418, 431, 451, 451, 468, 469, 500, 506, 534, 4905, 566, 567, 4927, 573, 593, 593, 613, 616, 627, 649, 669, 698, 712, 716, 818, 824, 844, 901, 903, 916, 1039, 1059, 1063, 1094, 1095, 1107, 1132, 1142, 1144, 1189, 1190, 1214, 1239, 1246, 1274, 4716, 5228, 5229, 1312, 1331, 1335, 1355, 1366, 1370, 1437, 1438, 1457, 1513, 1530, 1531, 1535, 1552, 4667, 1557, 1586, 1588, 1594, 1612, 1613, 1631, 1647, 1648, 1750, 1751, 5449, 1825, 1847, 1850, 1968, 1988, 1989, 1989, 1991, 2023, 2025, 2039, 2058, 2059, 2092, 2093, 2131, 2140, 2168, 2191, 2217, 2228, 5293, 5294, 5284, 5387, 5414, 5404, 5412, 5324, 5325, 4641, 5420, 5330, 5344, 5308, 5309, 5519, 5465, 5467, 5513, 2310, 2316, 2371, 2372, 2392, 2425, 2440, 2442, 2459, 2462, 2475, 2622, 2631, 2644, 2671, 2672, 2696, 2698, 2714, 2716, 2716, 2734, 2761, 2767, 2768, 2797, 2835, 2861, 2862, 2874, 2875, 2898, 2898, 2900, 2917, 2940, 2942, 2958, 2963, 2977, 2980, 2985, 2999, 3020, 3075, 3102, 3105, 3106, 3161, 3169, 3181, 3215, 3217, 3258, 3260, 3292, 3293, 3310, 3311, 3312, 3313, 3342, 3359, 3361, 3380, 3466, 3491, 3492, 4882, 3573, 3580, 3624, 3646, 3647, 3720, 3742, 3743, 3820, 3843, 3846, 3870, 3885, 3905, 3905, 3922, 3923, 4024, 4035, 4042, 4075, 4092, 4093, 4118, 4127, 4128, 4156, 4165, 4166, 4194, 4196, 4216, 4248, 4250, 4269, 4269, 4284, 4285, 4358, 4359, 4370, 4406, 4408, 4419, 4472, 4474, 4486, 27, 29, 40, 77, 95, 97, 226, 252, 4898, 282, 4935, 4936, 318, 379, 395, 4905, 567, 570, 4927, 593, 616, 626, 627, 669, 698, 716, 725, 818, 844, 5004, 901, 916, 918, 1038, 1039, 1063, 1127, 1132, 1144, 1188, 1189, 1214, 1298, 1312, 1335, 1353, 1355, 1370, 1437, 1457, 1460, 1512, 1513, 1531, 1534, 1535, 4667, 1591, 1594, 1613, 1631, 1648, 1652, 1749, 1750, 5449, 1824, 1825, 1850, 1968, 1989, 2023, 2025, 2058, 2059, 2131, 2168, 2170, 2190, 2191, 2228, 5293, 5284, 5285, 5385, 5387, 5404, 5412, 5325, 2245, 4641, 5330, 5332, 5513, 2316, 2326, 2371, 2392, 2404, 2459, 2475, 2482, 2621, 2622, 2644, 2657, 2671, 2696, 2696, 2698, 2716, 2716, 2761, 2762, 2766, 2767, 2797, 2835, 2862, 2868, 2871, 2874, 2898, 2898, 2917, 2925, 2940, 2958, 2961, 2985, 3020, 3025, 3075, 3105, 3106, 3106, 3169, 3170, 3242, 3258, 3292, 3312, 3342, 3344, 3350, 3359, 3380, 3624, 3647, 3650, 3719, 3720, 3743, 3820, 3846, 3849, 3870, 3905, 3923, 4015, 4024, 4042, 4075, 4093, 4094, 4117, 4118, 4128, 4155, 4156, 4166, 4193, 4194, 4216, 4244, 4248, 4269, 4358, 4370, 4374, 4472, 4486, 4487, 24, 27, 40, 76, 77, 97, 203, 226, 4898, 281, 282, 4936, 315, 318, 395, 570, 571, 4927, 4928, 4927, 616, 617, 626, 669, 690, 698, 725, 817, 818, 5004, 900, 901, 918, 1038, 1063, 1068, 1125, 1127, 1144, 1188, 1214, 1220, 1289, 1298, 1335, 1353, 1370, 1377, 1433, 1437, 1460, 1512, 1531, 1534, 1591, 1613, 1614, 1748, 1749, 5449, 1823, 1824, 1850, 1967, 1968, 2023, 2024, 2025, 2059, 2131, 2170, 2185, 2190, 2228, 2229, 5385, 5404, 5407, 5412, 2245, 4635, 4641, 5332, 5333, 5513, 2326, 2327, 2458, 2459, 2482, 2607, 2621, 2644, 2696, 2716, 2762, 2762, 2766, 2797, 2835, 2868, 2871, 2871, 2898, 2925, 2932, 2940, 2961, 3075, 3106, 3170, 3242, 3292, 3293, 3312, 3344, 3345, 3613, 3624, 3650, 3719, 3743, 3744, 3869, 3870, 3923, 4015, 4042, 4043, 4074, 4075, 4094, 4117, 4128, 4129, 4154, 4155, 4166, 4193, 4216, 4218, 4357, 4358, 4374, 4472, 4487, 4488, 24, 40, 53, 70, 76, 97, 203, 4898, 4899, 281, 4936, 4933, 315, 395, 397, 570, 4927, 4926, 4928, 616, 617, 617, 669, 672, 690, 725, 731, 817, 5004, 5005, 900, 918, 920, 1125, 1144, 5140, 1179, 1188, 1220, 1289, 1335, 1346, 1350, 1353, 1377, 1433, 1460, 1468, 1508, 1512, 1534, 1590, 1591, 1614, 1747, 1748, 5449, 1821, 1823, 1850, 1967, 2023, 2024, 2024, 2059, 2093, 2131, 2185, 2187, 5273, 5385, 5407, 5411, 5412, 4635, 4641, 5333, 5339, 5513, 2327, 2329, 2453, 2458, 2482, 2607, 2644, 2645, 2657, 2696, 2762, 2835, 2871, 2925, 2932, 2961, 2963, 3075, 3170, 3171, 3242, 3293, 3311, 3613, 3650, 3656, 3718, 3719, 3744, 3861, 3869, 3923, 4010, 4015, 4043, 4114, 4117, 4129, 4154, 4166, 4174, 4192, 4193, 4218, 4350, 4357, 4374, 4471, 4472, 4488, 22, 24, 53, 70, 97, 108, 201, 203, 4899, 281, 4933, 4934, 4905, 570, 4926, 4928, 617, 672, 689, 690, 731, 899, 900, 920, 1109, 1125, 5140, 1167, 1179, 1220, 5244, 1289, 1346, 1350, 1377, 1381, 1590, 1614, 1625, 1747, 5449, 5446, 1808, 1821, 1850, 1967, 2024, 2093, 2131, 2187, 2190, 5273, 5407, 5260, 4633, 5260, 5261, 5410, 5411, 4635, 4641, 5339, 5340, 5513, 2329, 2332, 2453, 2482, 2491, 2657, 2762, 2797, 2835, 2925, 2926, 2932, 2963, 2980, 3242, 3311, 3312, 3718, 3744, 3749, 3859, 3861, 3923, 4010, 4043, 4054, 4114, 4129, 4133, 4150, 4154, 4174, 4190, 4192, 4218, 4350, 4374, 4375, 4471, 4488, 4490, 21, 22, 53, 70, 108, 109, 201, 4899, 4901, 4905, 4926, 4928, 4928, 672, 675, 688, 689, 731, 899, 920, 921, 1167, 1220, 1227, 5244, 1346, 1348, 5147, 1350, 1381, 1589, 1590, 1625, 1808, 1850, 1861, 1964, 1967, 2093, 2105, 2131, 2190, 4641, 5340, 5343, 5513, 2332, 2333, 2453, 2491, 2494, 2653, 2657, 2797, 2930, 2932, 2980, 3242, 3312, 3345, 3716, 3718, 3749, 4008, 4010, 4054, 4113, 4114, 4133, 4150, 4174, 4184, 4187, 4190, 4218, 4350, 4375, 4376, 4470, 4471, 4490, 19, 21, 53, 70, 109, 4851, 200, 201, 4901, 4905, 4928, 675, 686, 688, 731, 897, 899, 921, 1166, 1167, 1227, 1589, 1625, 1628, 1808, 1861, 1863, 2105, 2190, 2229, 4641, 5343, 5344, 2453, 2494, 2499, 2653, 2797, 2814, 2928, 2930, 2980, 3716, 3749, 3756, 4007, 4008, 4054, 4111, 4113, 4133, 4149, 4150, 4184, 4187, 4218, 4219, 4350, 4376, 4377, 4470, 4490, 4492, 18, 19, 53, 200, 4901, 278, 4904, 4905, 675, 686, 731, 738, 897, 921, 922, 1164, 1166, 1227, 1808, 1863, 1864, 2452, 2453, 2499, 2647, 2653, 2814, 2928, 2980, 2984, 3715, 3716, 3756, 4006, 4007, 4054, 4111, 4133, 4135, 4149, 4184, 4186, 4343, 4350, 4377, 4467, 4470, 4492, 18, 53, 64, 199, 200, 278, 4904, 675, 682, 684, 686, 738, 897, 922, 923, 1164, 1227, 1232, 1796, 1808, 1864, 2452, 2499, 2511, 2926, 2928, 2984, 3710, 3715, 3756, 4006, 4054, 4056, 4111, 4135, 4137, 4343, 4377, 4386, 4467, 4492, 4493, 11, 18, 64, 198, 199, 278, 4904, 682, 684, 684, 738, 739, 895, 897, 923, 1162, 1164, 1232, 1796, 1864, 1871, 2442, 2452, 2511, 2926, 2984, 2985, 3710, 3756, 3757, 3998, 4006, 4056, 4101, 4111, 4137, 4341, 4343, 4386, 4467, 4493, 4496, 11, 64, 66, 197, 198, 278, 4904, 684, 739, 894, 895, 923, 1796, 1871, 1872, 2425, 2442, 2511, 2835, 2926, 2985, 3708, 3710, 3757, 4101, 4137, 4140, 4326, 4341, 4386, 4466, 4467, 4496, 197, 278, 281, 4904, 739, 749, 894, 923, 927, 1791, 1796, 1872, 2425, 2511, 2547, 2835, 2985, 3025, 4094, 4101, 4140, 4466, 4496, 4498, 197, 281, 4934, 4904, 749, 757, 893, 894, 927, 1791, 1872, 1873, 2418, 2425, 2547, 4094, 4140, 4147, 4974, 4466, 4498, 893, 927, 930, 1791, 1873, 1874, 2417, 2418, 2547, 4074, 4094, 4147, 4973, 4974, 4498, 888, 893, 930, 1788, 1791, 1874, 4070, 4074, 4147, 4973, 4498, 4499, 888, 930, 931, 1782, 1788, 1874, 4070, 4147, 4148, 4463, 4973, 4499, 885, 888, 931, 1781, 1782, 1874, 4070, 4148, 4149, 4463, 4499, 4501, 885, 931, 935, 1781, 1874, 1875, 4070, 4149, 4186, 4461, 4463, 4501, 878, 885, 935, 1781, 1875, 1883, 4069, 4070, 4186, 4461, 4501, 4503, 878, 935, 942, 1781, 1883, 1886, 4069, 4186, 4187, 4460, 4461, 4503, 878, 942, 943, 1780, 1781, 1886, 4069, 4187, 4219, 4460, 4503, 4505, 876, 878, 943, 1780, 1886, 1918, 4456, 4460, 4505, 875, 876, 943, 1779, 1780, 1918, 4456, 4505, 4507, 875, 943, 950, 1777, 1779, 1918, 4453, 4456, 4507, 875, 950, 952, 1775, 1777, 1918, 4453, 4507, 4508, 5007, 875, 952, 1775, 1918, 1940, 4968, 4453, 4508, 5007, 952, 965, 5449, 1775, 1940, 4968, 4508, 4511, 5007, 965, 979, 5449, 1940, 1942, 4959, 4968, 4511, 5006, 5007, 979, 5447, 5449, 1942, 4959, 4511, 4516, 5005, 5006, 979, 5447, 1942, 1951, 4959, 4516, 4517, 5005, 979, 980, 5447, 1951, 1953, 5005, 980, 981, 5446, 5447, 1953, 817, 5005, 981, 1747, 5446, 1953, 816, 817, 981, 1747, 1953, 1963, 1747, 1963, 1964, 2096, 2098, 2101, 2413, 2414, 2417, 775, 776, 781, 1021, 1022, 1027, 1487, 1493, 1495, 2094, 2096, 2101, 2407, 2413, 2417, 773, 775, 781, 1010, 1021, 1027, 1487, 1495, 1497, 2093, 2094, 2101, 2404, 2407, 2417, 771, 773, 781, 1010, 1027, 1029, 1476, 1487, 1497, 2093, 2101, 2102, 2404, 2417, 2547, 770, 771, 781, 1010, 1029, 1031, 1476, 1497, 1508, 1964, 2093, 2102, 2404, 2547, 2548, 770, 781, 788, 1010, 1031, 1032, 1476, 1508, 1534, 2404, 2548, 2553, 768, 770, 788, 1010, 1032, 1037, 1475, 1476, 1534, 2371, 2404, 2553, 765, 768, 788, 1009, 1010, 1037, 1475, 1534, 4667, 2371, 2553, 2554, 765, 788, 789, 1000, 1009, 1037, 1473, 1475, 4667, 998, 1000, 1037, 1469, 1473, 4667, 998, 1037, 1038, 998, 1038, 1068, 998, 1068, 1074, 998, 1074, 1075, 1090, 1091, 1107, 1160, 1161, 1232, 1384, 1385, 1387, 1394, 1395, 1399, 1655, 1658, 1667, 1690, 1692, 1694, 1722, 1724, 1726, 1738, 1739, 1743, 5260, 4633, 5256, 5410, 4635, 5045, 2284, 2285, 2288, 2341, 2369, 2371, 2605, 2606, 2645, 3347, 3348, 3350, 3495, 3496, 3498, 3511, 3520, 3526, 3699, 3700, 3703, 4388, 4389, 4392, 4959, 4517, 4521, 4523, 4524, 4526, 4561, 4563, 4566, 4592, 4594, 4597, 8, 9, 11, 126, 130, 137, 148, 151, 158, 402, 403, 405, 471, 472, 478, 499, 500, 4904, 759, 763, 765, 799, 800, 803, 991, 993, 996, 1089, 1090, 1107, 1157, 1160, 1232, 1384, 1387, 1388, 1394, 1399, 1404, 1655, 1667, 1670, 1690, 1694, 1695, 1721, 1722, 1726, 1738, 1743, 1745, 5273, 5260, 5256, 5257, 5410, 5045, 5042, 5043, 5044, 5044, 2245, 4637, 2283, 2284, 2288, 2333, 2341, 2371, 2605, 2645, 2646, 3495, 3498, 3499, 3508, 3511, 3526, 3696, 3699, 3703, 4387, 4388, 4392, 4958, 4959, 4521, 4521, 4523, 4526, 4559, 4561, 4566, 4588, 4592, 4597, 6, 8, 11, 126, 137, 138, 148, 158, 159, 397, 402, 405, 471, 478, 482, 490, 499, 4904, 759, 765, 789, 791, 799, 803, 990, 991, 996, 1079, 1089, 1107, 1157, 1232, 1239, 1381, 1384, 1388, 1393, 1394, 1404, 1654, 1655, 1670, 1689, 1690, 1695, 1721, 1726, 1727, 1738, 1745, 1747, 5273, 5256, 5257, 5257, 5045, 5047, 2283, 2288, 5507, 2333, 2371, 2554, 2605, 2646, 2647, 3494, 3495, 3499, 3508, 3526, 3527, 3696, 3703, 3704, 4387, 4392, 4393, 4958, 4521, 4526, 4559, 4566, 4567, 4588, 4597, 4600, 6, 11, 66, 117, 126, 138, 138, 148, 159, 397, 405, 406, 470, 471, 482, 490, 4904, 757, 758, 759, 789, 791, 803, 804, 989, 990, 996, 1079, 1107, 1109, 1157, 1239, 1274, 1381, 1388, 1392, 1654, 1670, 1675, 1680, 1689, 1695, 1721, 1727, 1728, 1732, 1738, 1747, 5271, 5273, 5257, 5257, 5047, 5049, 5513, 2333, 2554, 2582, 2605, 2647, 3494, 3499, 3502, 3507, 3508, 3527, 3693, 3696, 3704, 4386, 4387, 4393, 4957, 4958, 4526, 4981, 4559, 4567, 4587, 4588, 4600, 138, 159, 181, 315, 397, 406, 469, 470, 482, 487, 490, 757, 791, 804, 806, 989, 996, 998, 1076, 1079, 1109, 1156, 1157, 1274, 5147, 1381, 1392, 1652, 1654, 1675, 1717, 1721, 1728, 1732, 1747, 1964, 5271, 5257, 5049, 5512, 5513, 2554, 2582, 2647, 2814, 3492, 3494, 3502, 4326, 4386, 4393, 4957, 4526, 4529, 4978, 4981, 4567, 4587, 4600, 4, 117, 138, 181, 315, 406, 418, 486, 487, 757, 789, 791, 806, 984, 989, 998, 1075, 1076, 1109, 1151, 1156, 1274, 5147, 1392, 1393, 1652, 1675, 1678, 1717, 1728, 1730, 5271, 5049, 5050, 5529, 5512, 2554, 2575, 2582, 2814, 3492, 3502, 3503, 4326, 4393, 4402, 4960, 4957, 4529, 4978, 4567, 4570, 4585, 4587, 4, 117, 181, 184, 315, 418, 451, 482, 486, 757, 789, 806, 807, 984, 998, 1075, 1075, 1109, 5140, 5142, 1151, 1274, 5147, 1393, 1404, 1631, 1652, 1678, 1717, 1730, 1731, 5271, 5050, 5051, 5507, 5529, 2554, 2574, 2575, 2814, 3466, 3492, 3503, 4325, 4326, 4402, 4960, 4529, 4530, 4978, 4570, 4585, 4585, 4, 6, 4842, 117, 184, 4934, 315, 451, 758, 789, 807, 983, 984, 1075, 5142, 1274, 4716, 1631, 1678, 1680, 1713, 1717, 1731, 5436, 1731, 1732, 5271, 5051, 5052, 5507, 2554, 2564, 3465, 3466, 3503, 4325, 4402, 4405, 4960, 4530, 4532, 4986, 4978, 4585, 4842, 184, 4782, 4934, 451, 469, 982, 983, 1075, 5141, 5142, 4716, 5442, 1713, 1731, 5436, 1732, 1964, 5507, 2564, 2571, 3465, 3503, 3504, 4322, 4325, 4405, 4447, 4960, 4532, 4854, 4842, 4782, 4934, 469, 482, 981, 982, 1075, 5141, 4716, 5229, 5436, 1964, 2102, 2283, 5507, 2571, 3465, 3504, 3506, 4322, 4405, 4406, 4447, 4532, 4539, 4853, 4854, 4782, 197, 4934, 482, 981, 1075, 5140, 5141, 5229, 5212, 5442, 1731, 5436, 5435, 5436, 2102, 2283, 2571, 2573, 4316, 4322, 4406, 4445, 4447, 4539, 4852, 4853, 4782, 189, 197, 482, 816, 981, 5140, 1680, 1695, 5442, 5442, 5443, 5439, 5441, 5442, 5436, 5434, 5435, 2102, 2283, 2573, 2574, 4313, 4316, 4406, 4445, 4539, 4540, 4851, 4852, 4782, 189, 482, 757, 808, 816, 5140, 1680, 5442, 5439, 5440, 5441, 5436, 2283, 2574, 2814, 4313, 4406, 4419, 4445, 4540, 4542, 4851, 4782, 4783, 189, 757, 758, 807, 808, 5140, 1631, 1680, 5439, 5440, 5436, 5434, 5478, 2283, 2814, 4312, 4313, 4419, 4851, 4783, 4784, 189, 758, 807, 1629, 1631, 5439, 5478, 2814, 2819, 4311, 4312, 4419, 189, 807, 5140, 1629, 5439, 5440, 2278, 5478, 5477, 5479, 5478, 2819, 4311, 4419, 4421, 1629, 5440, 5434, 2278, 5477, 5479, 5479, 2819, 2835, 4294, 4311, 4421, 1628, 1629, 5434, 5479, 2835, 3025, 4294, 4421, 4423, 1589, 1628, 5434, 2278, 5479, 3025, 4294, 4423, 4424, 1588, 1589, 5434, 2278, 3025, 3028, 4294, 4424, 4425, 1588, 5434, 2102, 2278, 3028, 3029, 4285, 4294, 4425, 1557, 1588, 2102, 4740, 2102, 2103, 2278, 3029, 3030, 4285, 4425, 4427, 4740, 2103, 2105, 5489, 2278, 3030, 4285, 4427, 4431, 1557, 2102, 4740, 4740, 4735, 4727, 4733, 4740, 2105, 5489, 3030, 3039, 1557, 4740, 4727, 4733, 2105, 2229, 5489, 3039, 3040, 4729, 4733, 2229, 5489, 3040, 3044, 4728, 4729, 2229, 5467, 5489, 3044, 4727, 4728, 2229, 5467, 3044, 3045, 1557, 4727, 2229, 5519, 5467, 3045, 4666, 1557, 2229, 5519, 3045, 3061, 4666, 2229, 2231, 5518, 5519, 3061, 4665, 4666, 2231, 5517, 5518, 3061, 4665, 2231, 2240, 5517, 3061, 3065, 4665, 2240, 5293, 5516, 5517, 3065, 4668, 4665, 5293, 5516, 3065, 3075, 4667, 4668, 5293, 1469, 4667, 5293, 5285, 3220, 3221, 5293, 5285, 3221, 5293, 3221, 3222, 3227, 5068, 5081, 3223, 3227, 5081, 5293, 3222, 5095, 5293, 5095, 5096, 1469, 5293, 5096, 5096, 5131, 5237, 5096, 5237, 5238, 5096, 5238, 5239, 1469, 5096, 5239, 1469, 5239, 5110, 1469, 5110, 1406, 1433, 1469, 1406, 1433, 1406, 1408, 1432, 1433, 1408, 1429, 1432, 1408, 1429, 1408, 1410, 1429, 1410, 1411, 1427, 1429, 1411, 1427, 1411, 1413, 1427, 1413, 1415, 1423, 1427, 1415, 1421, 1423, 1415, 1421, 1415, 1417, 1420, 1421, 1417, 1419, 1420, 1417, 5237, 5131, 5132, 5132, 5133, 5239, 5237, 5132, 5239, 5068, 3227, 3229, 1404, 1405, 1406, 5068, 3229, 3231, 1404, 1406, 5111, 5066, 5068, 3231, 1404, 5111, 5112, 5122, 5066, 3231, 1404, 5112, 5119, 1404, 5119, 5120, 5147, 1404, 5120, 4711, 5120, 5174, 5172, 5174, 5120, 5120, 5121, 5122, 5147, 5120, 4709, 4711, 5174, 5175, 5172, 5120, 5122, 5244, 1348, 5147, 5146, 5147, 4709, 5206, 4711, 5175, 5172, 5122, 3231, 5212, 1288, 5244, 5243, 5244, 5147, 5145, 5146, 4709, 5206, 5175, 5178, 5193, 5172, 3231, 5212, 5244, 5242, 5242, 5243, 5147, 5145, 4709, 5205, 5205, 5206, 5178, 5193, 3231, 3238, 5141, 5212, 5242, 5242, 5147, 5145, 5205, 5178, 5181, 5198, 5193, 3238, 5141, 5242, 5145, 5145, 5205, 5181, 5198, 3238, 3240, 5141, 5145, 5181, 5197, 5198, 3240, 5140, 5141, 5181, 5197, 3240, 3241, 5140, 5181, 5182, 5188, 5197, 3241, 5140, 5182, 5187, 5188, 3241, 3242, 5187, 5188, 3242, 5140, 5187, 3242, 5140, 3242, 3345, 189, 5140, 3345, 189, 3345, 3346, 189, 3346, 3347, 3350, 3389, 3390, 3350, 3390, 3404, 3347, 3350, 3404, 3347, 3404, 3405, 189, 3347, 3405, 189, 3405, 3411, 189, 3411, 3412, 5639, 5638, 3412, 3412, 3413, 3414, 3415, 3421, 3422, 3422, 5632, 3427, 3415, 3422, 3427, 3415, 3427, 3438, 3415, 3438, 3456, 3414, 3415, 3456, 3412, 3414, 3456, 3412, 3456, 3464, 3464, 3465, 189, 3412, 3464, 189, 189, 3465, 3506, 189, 3506, 3507, 189, 3507, 3527, 189, 3527, 3528, 189, 3528, 3535, 4782, 189, 3535, 4752, 4753, 3535, 4780, 4782, 3535, 4752, 3535, 3536, 4780, 3535, 4754, 4752, 3536, 4790, 4773, 4780, 4754, 4751, 4752, 4790, 4787, 4773, 4754, 4754, 4751, 4790, 4787, 4754, 4790, 4787, 4790, 4759, 4787, 4759, 4760, 4787, 4760, 4882, 4787, 4882, 3580, 4784, 4786, 3587, 4851, 4784, 3587, 4851, 3587, 3599, 4851, 3599, 3600, 4851, 3600, 3601, 4851, 3601, 3602, 4851, 3602, 3604, 4851, 3604, 3605, 3606, 4813, 4814, 3606, 4814, 3657, 3605, 3606, 3657, 3605, 3657, 3658, 3675, 3708, 3757, 3658, 3675, 3757, 3605, 3658, 3757, 4851, 3605, 3757, 4851, 3757, 3758, 4851, 3758, 3771, 4851, 3771, 3773, 70, 4851, 3773, 70, 3773, 3789, 68, 70, 3789, 68, 3789, 3791, 68, 3791, 3795, 67, 68, 3795, 67, 3795, 3798, 67, 3798, 3802, 3804, 3853, 3856, 3802, 3804, 3856, 3802, 3856, 3858, 3859, 3998, 4056, 3858, 3859, 4056, 3802, 3858, 4056, 3802, 4056, 4067, 3802, 4067, 4069, 67, 3802, 4069, 67, 4069, 4219, 66, 67, 4219, 6, 66, 4219, 6, 4219, 4240, 4585, 6, 4240, 4585, 4240, 4241, 4585, 4241, 4243, 4986, 4585, 4243, 4986, 4243, 4244, 4986, 4244, 4269, 4985, 4986, 4269, 4985, 4269, 4285, 4554, 4985, 4285, 4554, 4285, 4431, 4553, 4554, 4431, 4553, 4431, 4435, 4553, 4435, 4436, 4549, 4553, 4436, 4549, 4436, 4437, 4549, 4437, 4438, 4549, 4438, 4952, 4549, 4952, 4953, 4547, 4549, 4953, 4547, 4953, 4949, 4547, 4949, 4950, 4543, 4547, 4950, 4542, 4543, 4950, 4445, 4542, 4950, 4445, 4950, 4951, 4445, 4951, 4439, 4444, 4445, 4439, 4443, 4444, 4439, 3998, 3859, 3923, 3996, 3998, 3923, 3995, 3996, 3923, 3995, 3923, 3924, 4605, 3995, 3924, 4605, 3924, 3926, 4603, 4605, 3926, 4603, 3926, 3927, 4603, 3927, 3930, 3989, 4603, 3930, 3989, 3930, 3935, 3985, 3989, 3935, 3985, 3935, 3936, 3984, 3985, 3936, 3984, 3936, 3937, 3983, 3984, 3937, 3983, 3937, 3939, 3974, 3983, 3939, 3974, 3939, 3942, 3974, 3942, 3945, 3973, 3974, 3945, 3968, 3973, 3945, 3968, 3945, 3949, 3967, 3968, 3949, 3967, 3949, 3950, 3966, 3967, 3950, 3963, 3966, 3950, 3963, 3950, 3951, 3963, 3951, 3952, 3961, 3963, 3952, 3961, 3952, 3953, 4744, 3961, 3953, 3961, 4744, 4745, 4743, 4744, 3953, 3960, 3961, 4745, 4743, 3953, 3954, 3959, 3960, 4745, 4745, 4743, 3954, 3954, 3959, 4745, 3853, 3804, 3812, 3850, 3853, 3812, 3820, 3849, 3850, 4615, 3850, 3812, 3820, 3850, 4615, 4615, 3812, 3818, 3818, 3820, 4615, 3708, 3675, 3676, 3706, 3708, 3676, 3704, 3706, 3676, 3693, 3704, 3676, 3693, 3676, 3679, 3689, 3693, 3679, 3689, 3679, 3682, 3682, 3683, 3685, 3686, 3689, 3682, 4813, 3606, 3610, 3656, 3657, 4814, 4812, 4813, 3610, 3656, 4814, 4805, 4812, 3610, 3611, 3656, 4805, 4806, 4809, 4812, 3611, 3613, 3656, 4806, 4809, 3611, 3613, 3613, 4806, 4809, 3426, 3427, 5632, 5630, 5633, 3424, 3424, 3425, 3426, 3426, 5632, 5631, 3421, 3415, 3419, 3419, 3420, 3421, 3389, 3350, 3380, 3389, 3380, 3381, 3387, 3389, 3381, 3387, 3381, 3382, 3386, 3387, 3382, 3385, 3386, 3382, 3220, 5285, 5276, 3218, 3220, 5276, 3218, 5276, 5271, 3217, 3218, 5271, 3181, 3217, 5271, 3181, 5271, 5052, 3180, 3181, 5052, 5052, 5055, 5056, 3180, 5052, 5056, 3180, 5056, 5039, 3179, 3180, 5039, 3178, 3179, 5039, 3171, 3178, 5039, 3171, 5039, 5041, 3075, 3171, 5041, 3075, 5041, 5042, 5027, 5516, 3075, 5344, 5027, 3075, 5344, 3075, 5042, 4641, 5344, 5042, 5516, 5027, 5032, 5516, 5032, 5033, 5455, 5516, 5033, 5473, 5455, 5033, 5473, 5033, 2260, 5472, 5473, 2260, 5471, 5472, 2260, 5471, 2260, 5493, 5496, 5497, 2263, 5471, 5493, 5494, 5495, 5496, 2263, 5469, 5471, 5494, 5495, 2263, 5469, 5469, 5494, 5495, 5027, 5344, 5309, 5026, 5027, 5315, 5314, 5315, 5027, 5026, 5315, 5316, 5314, 5027, 5309, 2256, 5026, 5316, 5313, 5314, 5309, 5309, 2256, 5316, 5316, 5313, 5309, 5055, 5053, 5054];
+testOutput["water_huge"]["triangles"]=[5105, 5106, 5107, 5059, 5060, 5061, 5061, 5128, 5129, 5129, 5130, 5131, 5110, 5111, 1406, 1406, 1407, 1408, 1408, 1409, 1410, 1411, 1412, 1413, 1413, 1414, 1415, 1415, 1416, 1417, 1421, 1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429, 1429, 1430, 1431, 1433, 1434, 1435, 1435, 1436, 1437, 1438, 1439, 1440, 1443, 1444, 1445, 1445, 1446, 1447, 1447, 1448, 1449, 1451, 1452, 1453, 1455, 1456, 1457, 1457, 1458, 1459, 1460, 1461, 1462, 1462, 1463, 1464, 1465, 1466, 1467, 1469, 1470, 1471, 1471, 1472, 1473, 1473, 1474, 1475, 1476, 1477, 1478, 1478, 1479, 1480, 1480, 1481, 1482, 1483, 1484, 1485, 1488, 1489, 1490, 1490, 1491, 1492, 1493, 1494, 1495, 1495, 1496, 1497, 1498, 1499, 1500, 1500, 1501, 1502, 1502, 1503, 1504, 1505, 1506, 1507, 1509, 1510, 1511, 1513, 1514, 1515, 1518, 1519, 1520, 1523, 1524, 1525, 1527, 1528, 1529, 1531, 1532, 1533, 1537, 1538, 1539, 1539, 1540, 1541, 1541, 1542, 1543, 1544, 1545, 1546, 1546, 1547, 1548, 1548, 1549, 1550, 1552, 1553, 1554, 1555, 1556, 4666, 4666, 1556, 1557, 1559, 1560, 1561, 1561, 1562, 1563, 1563, 1564, 1565, 1566, 1567, 1568, 1568, 1569, 1570, 1570, 1571, 1572, 1576, 1577, 1578, 1578, 1579, 1580, 1581, 1582, 1583, 1583, 1584, 1585, 1586, 1587, 1588, 1591, 1592, 1593, 1594, 1595, 1596, 1597, 1598, 1599, 1600, 1601, 1602, 1603, 1604, 1605, 1605, 1606, 1607, 1608, 1609, 1610, 1615, 1616, 1617, 1618, 1619, 1620, 1621, 1622, 1623, 1623, 1624, 1625, 1626, 1627, 1628, 1629, 1630, 1631, 1632, 1633, 1634, 1634, 1635, 1636, 1636, 1637, 1638, 1638, 1639, 1640, 1640, 1641, 1642, 1643, 1644, 1645, 1648, 1649, 1650, 1650, 1651, 1652, 1652, 1653, 1654, 1655, 1656, 1657, 1658, 1659, 1660, 1661, 1662, 1663, 1665, 1666, 1667, 1667, 1668, 1669, 1670, 1671, 1672, 1673, 1674, 1675, 1676, 1677, 1678, 1678, 1679, 1680, 1681, 1682, 1683, 1683, 1684, 1685, 1687, 1688, 1689, 1690, 1691, 1692, 5442, 1695, 1696, 1696, 1697, 1698, 1698, 1699, 1700, 1700, 1701, 1702, 1702, 1703, 1704, 1705, 1706, 1707, 1707, 1708, 1709, 1711, 1712, 1713, 1713, 1714, 1715, 1715, 1716, 1717, 1718, 1719, 1720, 1722, 1723, 1724, 1728, 1729, 1730, 1732, 1733, 1734, 1734, 1735, 1736, 1736, 1737, 1738, 1740, 1741, 1742, 1743, 1744, 1745, 1745, 1746, 1747, 5447, 5448, 5449, 1753, 1754, 1755, 1757, 1758, 1759, 1760, 1761, 1762, 1763, 1764, 1765, 1765, 1766, 1767, 1768, 1769, 1770, 1771, 1772, 1773, 1775, 1776, 1777, 1777, 1778, 1779, 1782, 1783, 1784, 1784, 1785, 1786, 1786, 1787, 1788, 1788, 1789, 1790, 1791, 1792, 1793, 1793, 1794, 1795, 1796, 1797, 1798, 1798, 1799, 1800, 1800, 1801, 1802, 1802, 1803, 1804, 1804, 1805, 1806, 1806, 1807, 1808, 1809, 1810, 1811, 1811, 1812, 1813, 1813, 1814, 1815, 1815, 1816, 1817, 1817, 1818, 1819, 1819, 1820, 1821, 1821, 1822, 1823, 1826, 1827, 1828, 1829, 1830, 1831, 1831, 1832, 1833, 1833, 1834, 1835, 1835, 1836, 1837, 1837, 1838, 1839, 1839, 1840, 1841, 1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 1851, 1852, 1853, 1854, 1855, 1856, 1857, 1858, 1859, 1859, 1860, 1861, 1861, 1862, 1863, 1864, 1865, 1866, 1867, 1868, 1869, 1869, 1870, 1871, 1875, 1876, 1877, 1877, 1878, 1879, 1880, 1881, 1882, 1883, 1884, 1885, 1887, 1888, 1889, 1889, 1890, 1891, 1892, 1893, 1894, 1896, 1897, 1898, 1900, 1901, 1902, 1902, 1903, 1904, 1905, 1906, 1907, 1908, 1909, 1910, 1912, 1913, 1914, 1915, 1916, 1917, 1918, 1919, 1920, 1920, 1921, 1922, 1922, 1923, 1924, 1925, 1926, 1927, 1928, 1929, 1930, 1931, 1932, 1933, 1933, 1934, 1935, 1935, 1936, 1937, 1940, 1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1948, 1949, 1950, 1951, 1952, 1953, 1955, 1956, 1957, 1960, 1961, 1962, 1964, 1965, 1966, 1971, 1972, 1973, 1974, 1975, 1976, 1976, 1977, 1978, 1979, 1980, 1981, 1982, 1983, 1984, 1984, 1985, 1986, 1986, 1987, 1988, 1989, 1990, 1991, 1992, 1993, 1994, 1995, 1996, 1997, 1999, 2000, 2001, 2004, 2005, 2006, 2006, 2007, 2008, 2008, 2009, 2010, 2011, 2012, 2013, 2014, 2015, 2016, 2016, 2017, 2018, 2018, 2019, 2020, 2021, 2022, 2023, 2026, 2027, 2028, 2028, 2029, 2030, 2032, 2033, 2034, 2037, 2038, 2039, 2042, 2043, 2044, 2045, 2046, 2047, 2047, 2048, 2049, 2050, 2051, 2052, 2054, 2055, 2056, 2059, 2060, 2061, 2063, 2064, 2065, 2065, 2066, 2067, 2069, 2070, 2071, 2072, 2073, 2074, 2076, 2077, 2078, 2078, 2079, 2080, 2081, 2082, 2083, 2083, 2084, 2085, 2085, 2086, 2087, 2088, 2089, 2090, 2094, 2095, 2096, 2096, 2097, 2098, 2098, 2099, 2100, 4735, 4736, 4727, 4730, 4731, 4732, 4733, 4734, 4739, 2103, 2104, 2105, 2105, 2106, 2107, 2107, 2108, 2109, 2109, 2110, 2111, 2111, 2112, 2113, 2113, 2114, 2115, 2115, 2116, 2117, 2117, 2118, 2119, 2121, 2122, 2123, 2124, 2125, 2126, 2128, 2129, 2130, 2132, 2133, 2134, 2135, 2136, 2137, 2137, 2138, 2139, 2141, 2142, 2143, 2144, 2145, 2146, 2147, 2148, 2149, 2150, 2151, 2152, 2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 2161, 2162, 2163, 2163, 2164, 2165, 2165, 2166, 2167, 2168, 2169, 2170, 2172, 2173, 2174, 2174, 2175, 2176, 2176, 2177, 2178, 2178, 2179, 2180, 2182, 2183, 2184, 2185, 2186, 2187, 2187, 2188, 2189, 2191, 2192, 2193, 2194, 2195, 2196, 2196, 2197, 2198, 2199, 2200, 2201, 2201, 2202, 2203, 2204, 2205, 2206, 2206, 2207, 2208, 2209, 2210, 2211, 2211, 2212, 2213, 2214, 2215, 2216, 2219, 2220, 2221, 2224, 2225, 2226, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2234, 2235, 2236, 2237, 2238, 2239, 2240, 5292, 5293, 5294, 5295, 5290, 5291, 5292, 2240, 2241, 2242, 2243, 2243, 2244, 5375, 5376, 5377, 5378, 5378, 5379, 5380, 5380, 5281, 5282, 5285, 5286, 5287, 5381, 5382, 5383, 5383, 5384, 5274, 5274, 5275, 5276, 5271, 5272, 5273, 5273, 5274, 5384, 5385, 5386, 5387, 5414, 5415, 5404, 5404, 5405, 5406, 5407, 5408, 5259, 5258, 5259, 5408, 5408, 5409, 5410, 5412, 5413, 5414, 5388, 5389, 5390, 5390, 5391, 5392, 5393, 5394, 5395, 5396, 5397, 5398, 5399, 5400, 5401, 5374, 5375, 2244, 2244, 2245, 5323, 4635, 2245, 5044, 5045, 5046, 5047, 5047, 5048, 5049, 5039, 5040, 5041, 4637, 2245, 2246, 5420, 5421, 2247, 2247, 2248, 2249, 2250, 2251, 2252, 2252, 2253, 5354, 5356, 5357, 5358, 5359, 5360, 5361, 5362, 5363, 5364, 5365, 5366, 5367, 5369, 5370, 5371, 5330, 5331, 5332, 5333, 5334, 5335, 5335, 5336, 5337, 5341, 5342, 5343, 5344, 5345, 5346, 5347, 5348, 5349, 5349, 5350, 5351, 5352, 5353, 5354, 5354, 2253, 2254, 2254, 2255, 2256, 2256, 5310, 5305, 5305, 5306, 5307, 5309, 5310, 2256, 5028, 2256, 2257, 2257, 2258, 5034, 5035, 5036, 5030, 5030, 5031, 5032, 5033, 5034, 2258, 2260, 2261, 2262, 5469, 5470, 5471, 5456, 5457, 5458, 5523, 5524, 5516, 5520, 5521, 5522, 5460, 5461, 5462, 5463, 5464, 5465, 5465, 5466, 5467, 2263, 5490, 5488, 5489, 5490, 2263, 2264, 2265, 2266, 2266, 2267, 2268, 2269, 2270, 2271, 2271, 2272, 2273, 2275, 2276, 2277, 5478, 2278, 2279, 2280, 2281, 2282, 2286, 2287, 2288, 2290, 2291, 2292, 2292, 2293, 2294, 2295, 2296, 2297, 2297, 2298, 2299, 2299, 5503, 5504, 5508, 5509, 5527, 5529, 5530, 5509, 5509, 5510, 5511, 5502, 5503, 2299, 2300, 2301, 2302, 2304, 2305, 2306, 2308, 2309, 2310, 2310, 2311, 2312, 2312, 2313, 2314, 2317, 2318, 2319, 2321, 2322, 2323, 2323, 2324, 2325, 2327, 2328, 2329, 2330, 2331, 2332, 2334, 2335, 2336, 2336, 2337, 2338, 2339, 2340, 2341, 2343, 2344, 2345, 2345, 2346, 2347, 2350, 2351, 2352, 2352, 2353, 2354, 2355, 2356, 2357, 2359, 2360, 2361, 2363, 2364, 2365, 2366, 2367, 2368, 2372, 2373, 2374, 2374, 2375, 2376, 2376, 2377, 2378, 2378, 2379, 2380, 2380, 2381, 2382, 2383, 2384, 2385, 2386, 2387, 2388, 2388, 2389, 2390, 2393, 2394, 2397, 2398, 2399, 2400, 2400, 2401, 2402, 2404, 2405, 2406, 2407, 2408, 2409, 2410, 2411, 2412, 2414, 2415, 2416, 2418, 2419, 2420, 2420, 2421, 2422, 2422, 2423, 2424, 2426, 2427, 2428, 2429, 2430, 2431, 2431, 2432, 2433, 2434, 2435, 2436, 2436, 2437, 2438, 2438, 2439, 2440, 2440, 2441, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2450, 2451, 2452, 2453, 2454, 2455, 2455, 2456, 2457, 2459, 2460, 2462, 2462, 2463, 2464, 2466, 2467, 2468, 2472, 2473, 2474, 2477, 2478, 2479, 2479, 2480, 2481, 2482, 2483, 2484, 2487, 2488, 2489, 2491, 2492, 2493, 2494, 2495, 2496, 2496, 2497, 2498, 2499, 2500, 2501, 2502, 2503, 2504, 2506, 2507, 2508, 2509, 2510, 2511, 2511, 2512, 2513, 2513, 2514, 2515, 2517, 2518, 2519, 2520, 2521, 2522, 2522, 2523, 2524, 2525, 2526, 2527, 2528, 2529, 2530, 2530, 2531, 2532, 2534, 2535, 2536, 2536, 2537, 2538, 2540, 2541, 2542, 2542, 2543, 2544, 2544, 2545, 2546, 2548, 2549, 2550, 2550, 2551, 2552, 2554, 2555, 2556, 2556, 2557, 2558, 2559, 2560, 2561, 2561, 2562, 2563, 2566, 2567, 2568, 2568, 2569, 2570, 2571, 2572, 2573, 2576, 2577, 2578, 2579, 2580, 2581, 2583, 2584, 2585, 2585, 2586, 2587, 2587, 2588, 2589, 2591, 2592, 2593, 2593, 2594, 2595, 2595, 2596, 2597, 2598, 2599, 2600, 2601, 2602, 2603, 2608, 2609, 2610, 2610, 2611, 2612, 2613, 2614, 2615, 2615, 2616, 2617, 2617, 2618, 2619, 2626, 2627, 2628, 2631, 2632, 2633, 2635, 2636, 2637, 2640, 2641, 2642, 2642, 2643, 2644, 2647, 2648, 2649, 2649, 2650, 2651, 2651, 2652, 2653, 2653, 2654, 2655, 2655, 2656, 2657, 2658, 2659, 2660, 2660, 2661, 2662, 2662, 2663, 2664, 2664, 2665, 2666, 2668, 2669, 2670, 2672, 2673, 2674, 2675, 2676, 2677, 2679, 2680, 2681, 2682, 2683, 2684, 2684, 2685, 2686, 2686, 2687, 2688, 2688, 2689, 2690, 2690, 2691, 2692, 2692, 2693, 2694, 2696, 2697, 2698, 2698, 2699, 2700, 2700, 2701, 2702, 2702, 2703, 2704, 2705, 2706, 2707, 2708, 2709, 2710, 2712, 2713, 2714, 2714, 2715, 2716, 2716, 2717, 2718, 2719, 2720, 2721, 2721, 2722, 2723, 2724, 2725, 2726, 2727, 2728, 2729, 2730, 2731, 2732, 2732, 2733, 2734, 2734, 2735, 2736, 2739, 2740, 2741, 2742, 2743, 2744, 2747, 2748, 2749, 2750, 2751, 2752, 2752, 2753, 2754, 2754, 2755, 2756, 2757, 2758, 2759, 2762, 2763, 2764, 2770, 2771, 2772, 2772, 2773, 2774, 2775, 2776, 2777, 2778, 2779, 2780, 2781, 2782, 2783, 2783, 2784, 2785, 2786, 2787, 2788, 2788, 2789, 2790, 2790, 2791, 2792, 2792, 2793, 2794, 2794, 2795, 2796, 2799, 2800, 2801, 2801, 2802, 2803, 2803, 2804, 2805, 2806, 2807, 2808, 2808, 2809, 2810, 2810, 2811, 2812, 2812, 2813, 2814, 2815, 2816, 2817, 2817, 2818, 2819, 2821, 2822, 2823, 2823, 2824, 2825, 2825, 2826, 2827, 2827, 2828, 2829, 2829, 2830, 2831, 2832, 2833, 2834, 2835, 2836, 2837, 2837, 2838, 2839, 2840, 2841, 2842, 2844, 2845, 2846, 2847, 2848, 2849, 2850, 2851, 2852, 2852, 2853, 2854, 2854, 2855, 2856, 2857, 2858, 2859, 2859, 2860, 2861, 2864, 2865, 2866, 2866, 2867, 2868, 2868, 2870, 2871, 2872, 2873, 2874, 2875, 2876, 2877, 2877, 2878, 2879, 2880, 2881, 2882, 2884, 2885, 2886, 2886, 2887, 2888, 2890, 2891, 2892, 2892, 2893, 2894, 2894, 2895, 2896, 2898, 2899, 2900, 2900, 2901, 2902, 2903, 2904, 2905, 2906, 2907, 2908, 2910, 2911, 2912, 2913, 2914, 2915, 2918, 2919, 2920, 2920, 2921, 2922, 2923, 2924, 2925, 2926, 2927, 2928, 2928, 2929, 2930, 2930, 2931, 2932, 2932, 2933, 2934, 2934, 2935, 2936, 2936, 2937, 2938, 2938, 2939, 2940, 2940, 2941, 2942, 2945, 2946, 2947, 2952, 2953, 2954, 2955, 2956, 2957, 2959, 2960, 2961, 2961, 2962, 2963, 2964, 2965, 2966, 2968, 2969, 2970, 2971, 2972, 2973, 2973, 2974, 2975, 2978, 2979, 2980, 2980, 2981, 2982, 2982, 2983, 2984, 2986, 2987, 2988, 2988, 2989, 2990, 2990, 2991, 2992, 2995, 2996, 2997, 3000, 3001, 3002, 3006, 3007, 3008, 3010, 3011, 3012, 3013, 3014, 3015, 3017, 3018, 3019, 3022, 3023, 3024, 3025, 3026, 3027, 3031, 3032, 3033, 3033, 3034, 3035, 3035, 3036, 3037, 3041, 3042, 3043, 3045, 3046, 3047, 3048, 3049, 3050, 3050, 3051, 3052, 3052, 3053, 3054, 3056, 3057, 3058, 3058, 3059, 3060, 3061, 3062, 3063, 3063, 3064, 3065, 3066, 3067, 3068, 3068, 3069, 3070, 3071, 3072, 3073, 3073, 3074, 3075, 3077, 3078, 3079, 3079, 3080, 3081, 3081, 3082, 3083, 3084, 3085, 3086, 3086, 3087, 3088, 3089, 3090, 3091, 3093, 3094, 3095, 3095, 3096, 3097, 3097, 3098, 3099, 3099, 3100, 3101, 3102, 3103, 3104, 3107, 3108, 3109, 3110, 3111, 3112, 3115, 3116, 3117, 3117, 3118, 3119, 3119, 3120, 3121, 3121, 3122, 3123, 3124, 3125, 3126, 3127, 3128, 3129, 3130, 3131, 3133, 3134, 3135, 3136, 3136, 3137, 3138, 3140, 3141, 3143, 3145, 3146, 3147, 3147, 3148, 3149, 3149, 3150, 3151, 3152, 3153, 3154, 3155, 3156, 3157, 3157, 3158, 3159, 3159, 3160, 3161, 3163, 3164, 3165, 3165, 3166, 3167, 3172, 3173, 3174, 3174, 3175, 3176, 3176, 3177, 3178, 3181, 3182, 3183, 3183, 3184, 3185, 3185, 3186, 3187, 3189, 3190, 3191, 3192, 3193, 3194, 3195, 3196, 3197, 3198, 3199, 3200, 3202, 3203, 3204, 3204, 3205, 3206, 3207, 3212, 3213, 3213, 3214, 3215, 3215, 3216, 3217, 3218, 3219, 3220, 3224, 3225, 3226, 3227, 3228, 3229, 3229, 3230, 3231, 3233, 3234, 3235, 3236, 3237, 3238, 3238, 3239, 3240, 3242, 3244, 3245, 3245, 3246, 3247, 3249, 3250, 3251, 3251, 3252, 3253, 3253, 3254, 3255, 3256, 3257, 3258, 3258, 3259, 3260, 3261, 3262, 3263, 3263, 3264, 3265, 3268, 3269, 3270, 3270, 3271, 3272, 3272, 3273, 3274, 3275, 3276, 3277, 3279, 3280, 3281, 3283, 3284, 3285, 3286, 3287, 3288, 3288, 3289, 3290, 3290, 3291, 3292, 3293, 3294, 3295, 3297, 3298, 3299, 3300, 3301, 3302, 3303, 3304, 3305, 3305, 3306, 3307, 3313, 3314, 3315, 3315, 3316, 3317, 3319, 3320, 3321, 3324, 3325, 3326, 3327, 3328, 3329, 3329, 3330, 3331, 3331, 3332, 3333, 3334, 3335, 3336, 3339, 3340, 3341, 3342, 3343, 3344, 3350, 3351, 3352, 3354, 3355, 3356, 3356, 3357, 3358, 3359, 3360, 3361, 3362, 3363, 3364, 3367, 3368, 3369, 3369, 3370, 3371, 3373, 3374, 3375, 3376, 3377, 3378, 3387, 3388, 3389, 3392, 3393, 3394, 3394, 3395, 3396, 3397, 3398, 3399, 3399, 3400, 3401, 3402, 3403, 3404, 3406, 3407, 3408, 3408, 3409, 3410, 3412, 5638, 5640, 3416, 3417, 3418, 3422, 3423, 3424, 3428, 3429, 3430, 3431, 3432, 3433, 3433, 3434, 3435, 3436, 3437, 3438, 3438, 3439, 3440, 3440, 3441, 3442, 3442, 3443, 3444, 3444, 3445, 3446, 3447, 3448, 3449, 3450, 3451, 3452, 3452, 3453, 3454, 3454, 3455, 3456, 3459, 3460, 3461, 3466, 3467, 3468, 3469, 3470, 3471, 3471, 3472, 3473, 3473, 3474, 3475, 3475, 3476, 3477, 3478, 3479, 3480, 3481, 3482, 3483, 3483, 3484, 3485, 3487, 3488, 3489, 3492, 3493, 3494, 3500, 3501, 3502, 3504, 3505, 3506, 3508, 3509, 3510, 3512, 3513, 3514, 3514, 3515, 3516, 3516, 3517, 3518, 3522, 3523, 3524, 3528, 3529, 3530, 3531, 3532, 3533, 3533, 3534, 3535, 3535, 4753, 4754, 3536, 3537, 4792, 4791, 4792, 3537, 3537, 3538, 3539, 3539, 3540, 4758, 4760, 4762, 4763, 4764, 4765, 3540, 3541, 3542, 3543, 3543, 3544, 3545, 3545, 3546, 3547, 4886, 4887, 4881, 4882, 4883, 4884, 3548, 4866, 4867, 4868, 4869, 3548, 3549, 3550, 3551, 3551, 3552, 3553, 3553, 3554, 3555, 3555, 3556, 3557, 3559, 3560, 3561, 3563, 3564, 3565, 3565, 3566, 3567, 3567, 3568, 3569, 3569, 3570, 3571, 3571, 3572, 3573, 3573, 3574, 3575, 3576, 3577, 3578, 3581, 3582, 3583, 3587, 3588, 3589, 3590, 3591, 3592, 3592, 3593, 3594, 3595, 3596, 3597, 3602, 3603, 3604, 3606, 3607, 3608, 3608, 3609, 3610, 3611, 3612, 3613, 3613, 3614, 3615, 3616, 3617, 3618, 3619, 3620, 3621, 3626, 3627, 3628, 3629, 3630, 3631, 3631, 3632, 3633, 3635, 3636, 3637, 3637, 3638, 3639, 3639, 3640, 3641, 3641, 3642, 3643, 3643, 3644, 3645, 3647, 3648, 3649, 3650, 3651, 3652, 3653, 3654, 3655, 4806, 4807, 4808, 4809, 4810, 4811, 3659, 3660, 3661, 3662, 3663, 3664, 3664, 3665, 3666, 3666, 3667, 3668, 3669, 3670, 3671, 3672, 3673, 3674, 3676, 3678, 3679, 3679, 3680, 3681, 3683, 3684, 3685, 3686, 3687, 3688, 3690, 3691, 3692, 3693, 3694, 3695, 3697, 3698, 3699, 3701, 3702, 3703, 3704, 3705, 3706, 3706, 3707, 3708, 3708, 3709, 3710, 3711, 3712, 3713, 3713, 3714, 3715, 3716, 3717, 3718, 3720, 3721, 3722, 3722, 3723, 3724, 3724, 3726, 3727, 3729, 3730, 3731, 3731, 3732, 3733, 3734, 3735, 3736, 3738, 3739, 3740, 3745, 3746, 3747, 3747, 3748, 3749, 3749, 3750, 3751, 3751, 3752, 3753, 3754, 3755, 3756, 3760, 3761, 3762, 3762, 3763, 3764, 3764, 3765, 3766, 3766, 3767, 3768, 3769, 3770, 3771, 3771, 3772, 3773, 3777, 3778, 3779, 3779, 3780, 3781, 3781, 3782, 3783, 3783, 3784, 3785, 3785, 3786, 3787, 3789, 3790, 3791, 3791, 3792, 3793, 3793, 3794, 3795, 3795, 3796, 3797, 3798, 3799, 3800, 3800, 3801, 3802, 3802, 3803, 3804, 3804, 3805, 3806, 3807, 3808, 3809, 3810, 3811, 3812, 3813, 3815, 3816, 3816, 3817, 3818, 3820, 3821, 3822, 3823, 3824, 3825, 3825, 3826, 3827, 3829, 3830, 3831, 3832, 3833, 3834, 3834, 3835, 3836, 3837, 3838, 3839, 3841, 3842, 3843, 3844, 3845, 3846, 3846, 3847, 3848, 3851, 3852, 3853, 3853, 3854, 3855, 3856, 3857, 3858, 3859, 3860, 3861, 3861, 3862, 3863, 3864, 3865, 3866, 3866, 3867, 3868, 3870, 3871, 3872, 3873, 3874, 3875, 3877, 3878, 3879, 3879, 3880, 3881, 3882, 3883, 3884, 3885, 3886, 3887, 3888, 3889, 3890, 3890, 3891, 3892, 3892, 3893, 3894, 3894, 3895, 3896, 3896, 3897, 3898, 3898, 3899, 3900, 3901, 3902, 3903, 3903, 3904, 3905, 3907, 3908, 3909, 3910, 3911, 3912, 3913, 3914, 3915, 3916, 3917, 3918, 3919, 3920, 3921, 3924, 3925, 3926, 3927, 3928, 3929, 3932, 3933, 3934, 3937, 3938, 3939, 3940, 3941, 3942, 3942, 3943, 3944, 3945, 3946, 3947, 3947, 3948, 3949, 3955, 3956, 3957, 3961, 3962, 3963, 3964, 3965, 3966, 3969, 3970, 3971, 3971, 3972, 3973, 3975, 3976, 3977, 3978, 3980, 3981, 3985, 3986, 3987, 3991, 3992, 3993, 3994, 3995, 4603, 4603, 3995, 4605, 3996, 3997, 3998, 4000, 4002, 4003, 4003, 4004, 4005, 4008, 4009, 4010, 4011, 4012, 4013, 4013, 4014, 4015, 4015, 4016, 4017, 4018, 4019, 4020, 4022, 4023, 4024, 4025, 4026, 4027, 4029, 4030, 4031, 4033, 4034, 4035, 4037, 4038, 4039, 4039, 4040, 4041, 4043, 4044, 4045, 4045, 4046, 4047, 4047, 4048, 4049, 4050, 4051, 4052, 4054, 4055, 4056, 4056, 4057, 4058, 4059, 4060, 4061, 4061, 4062, 4063, 4063, 4064, 4065, 4067, 4068, 4069, 4070, 4071, 4072, 4076, 4077, 4078, 4078, 4079, 4080, 4082, 4083, 4084, 4084, 4085, 4086, 4086, 4087, 4088, 4090, 4091, 4092, 4094, 4095, 4096, 4096, 4097, 4098, 4098, 4099, 4100, 4102, 4103, 4104, 4107, 4108, 4109, 4109, 4110, 4111, 4111, 4112, 4113, 4115, 4116, 4117, 4122, 4123, 4124, 4129, 4130, 4131, 4131, 4132, 4133, 4133, 4134, 4135, 4135, 4136, 4137, 4138, 4139, 4140, 4141, 4142, 4143, 4144, 4145, 4146, 4150, 4151, 4152, 4152, 4153, 4154, 4159, 4160, 4161, 4167, 4168, 4169, 4171, 4172, 4173, 4174, 4175, 4176, 4179, 4180, 4181, 4181, 4182, 4183, 4184, 4185, 4186, 4187, 4188, 4189, 4190, 4191, 4192, 4194, 4195, 4196, 4196, 4197, 4198, 4199, 4200, 4201, 4203, 4204, 4205, 4207, 4208, 4209, 4211, 4212, 4213, 4213, 4214, 4215, 4216, 4217, 4218, 4221, 4222, 4223, 4223, 4224, 4225, 4225, 4226, 4227, 4228, 4229, 4230, 4231, 4232, 4233, 4234, 4235, 4236, 4237, 4238, 4239, 4241, 4242, 4243, 4244, 4245, 4246, 4246, 4247, 4248, 4248, 4249, 4250, 4250, 4251, 4252, 4254, 4255, 4256, 4258, 4259, 4260, 4261, 4262, 4263, 4263, 4264, 4265, 4266, 4267, 4268, 4270, 4271, 4272, 4272, 4273, 4274, 4274, 4275, 4276, 4277, 4278, 4279, 4279, 4280, 4281, 4282, 4283, 4284, 4285, 4286, 4287, 4287, 4288, 4289, 4290, 4291, 4292, 4292, 4293, 4294, 4295, 4296, 4297, 4297, 4298, 4299, 4300, 4301, 4303, 4304, 4305, 4306, 4306, 4307, 4308, 4309, 4310, 4311, 4313, 4314, 4315, 4316, 4317, 4318, 4319, 4320, 4321, 4323, 4324, 4325, 4326, 4327, 4328, 4328, 4329, 4330, 4330, 4331, 4332, 4332, 4333, 4334, 4334, 4336, 4337, 4337, 4338, 4339, 4339, 4340, 4341, 4341, 4342, 4343, 4343, 4344, 4345, 4345, 4346, 4347, 4347, 4348, 4349, 4350, 4351, 4352, 4352, 4353, 4354, 4354, 4355, 4356, 4359, 4360, 4361, 4361, 4362, 4363, 4364, 4365, 4366, 4370, 4371, 4372, 4372, 4373, 4374, 4377, 4378, 4379, 4379, 4380, 4381, 4382, 4383, 4384, 4389, 4390, 4391, 4394, 4395, 4396, 4398, 4399, 4400, 4400, 4401, 4402, 4402, 4403, 4404, 4406, 4407, 4408, 4411, 4412, 4413, 4417, 4418, 4419, 4419, 4420, 4421, 4421, 4422, 4423, 4425, 4426, 4427, 4427, 4428, 4429, 4432, 4433, 4434, 4953, 4954, 4949, 4952, 4438, 4439, 4440, 4441, 4442, 4445, 4446, 4447, 4448, 4449, 4450, 4454, 4455, 4456, 4457, 4458, 4459, 4461, 4462, 4463, 4463, 4464, 4465, 4465, 4466, 4972, 4974, 4975, 4466, 4467, 4468, 4469, 4472, 4473, 4474, 4475, 4476, 4477, 4477, 4478, 4479, 4479, 4480, 4481, 4481, 4482, 4483, 4488, 4489, 4490, 4490, 4491, 4492, 4493, 4494, 4495, 4496, 4497, 4498, 4499, 4500, 4501, 4501, 4502, 4503, 4503, 4504, 4505, 4505, 4506, 4507, 4509, 4510, 4511, 4511, 4512, 4513, 4514, 4515, 4516, 4518, 4520, 4521, 4521, 4522, 4523, 4526, 4527, 4528, 4530, 4531, 4532, 4534, 4535, 4536, 4536, 4537, 4538, 4540, 4541, 4542, 4543, 4544, 4545, 4545, 4546, 4547, 4547, 4548, 4549, 4550, 4551, 4552, 4555, 4556, 4557, 4557, 4558, 4982, 4983, 4984, 4985, 4986, 4977, 4978, 4979, 4980, 4981, 4559, 4560, 4561, 4561, 4562, 4563, 4563, 4564, 4565, 4567, 4568, 4569, 4572, 4573, 4574, 4574, 4575, 4576, 4576, 4577, 4578, 4578, 4579, 4580, 4580, 4581, 4582, 4582, 4583, 4584, 4585, 4586, 4587, 4589, 4590, 4591, 4592, 4593, 4594, 4595, 4596, 4597, 4597, 4598, 4599, 0, 1, 2, 4, 5, 6, 6, 7, 8, 11, 12, 13, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 24, 25, 26, 27, 28, 29, 33, 34, 35, 36, 37, 38, 40, 41, 42, 42, 43, 44, 44, 45, 46, 48, 49, 50, 50, 51, 52, 54, 55, 56, 56, 57, 58, 58, 59, 60, 62, 63, 64, 64, 65, 66, 68, 69, 70, 70, 71, 72, 72, 73, 74, 74, 75, 76, 78, 79, 80, 80, 81, 82, 83, 84, 85, 85, 86, 87, 88, 89, 90, 90, 91, 92, 92, 93, 94, 95, 96, 97, 99, 100, 101, 101, 102, 103, 103, 104, 105, 105, 106, 107, 110, 111, 112, 112, 113, 114, 114, 115, 4847, 4855, 4856, 4857, 4857, 4858, 4859, 4860, 4841, 4838, 4837, 4838, 4841, 4844, 4845, 4846, 4846, 4847, 115, 117, 118, 119, 120, 122, 123, 123, 124, 125, 126, 127, 128, 128, 129, 130, 130, 131, 132, 133, 134, 135, 135, 136, 137, 139, 140, 141, 142, 143, 144, 144, 145, 146, 146, 147, 148, 148, 149, 150, 152, 153, 154, 154, 155, 156, 156, 157, 158, 160, 161, 162, 164, 165, 166, 167, 168, 169, 169, 170, 171, 171, 172, 173, 174, 175, 176, 177, 178, 179, 181, 182, 183, 184, 185, 4782, 4784, 4785, 4786, 4787, 4771, 4772, 4773, 4774, 4775, 4775, 4776, 4777, 4777, 4778, 4779, 4780, 4781, 4782, 4782, 185, 186, 187, 188, 189, 189, 190, 191, 191, 192, 193, 194, 195, 196, 201, 202, 203, 206, 207, 208, 208, 209, 210, 210, 211, 212, 214, 215, 216, 216, 217, 218, 218, 219, 220, 222, 223, 224, 226, 227, 228, 231, 232, 233, 233, 234, 235, 238, 239, 240, 240, 241, 242, 243, 244, 245, 245, 246, 247, 247, 248, 249, 252, 4897, 4898, 4899, 4900, 4901, 4901, 4902, 252, 253, 254, 255, 256, 257, 258, 258, 259, 260, 262, 263, 264, 264, 265, 266, 266, 267, 268, 270, 271, 272, 272, 273, 274, 274, 275, 276, 278, 279, 280, 282, 283, 284, 284, 285, 286, 287, 288, 289, 289, 290, 291, 293, 294, 295, 301, 302, 303, 303, 304, 305, 305, 306, 307, 307, 308, 309, 310, 311, 312, 4935, 314, 315, 315, 316, 317, 318, 319, 320, 320, 321, 322, 322, 323, 324, 325, 326, 327, 327, 328, 329, 333, 334, 335, 335, 336, 337, 337, 338, 339, 341, 342, 343, 343, 344, 345, 345, 346, 347, 347, 348, 349, 349, 350, 351, 352, 353, 354, 355, 356, 357, 357, 358, 359, 359, 360, 361, 362, 363, 364, 364, 365, 366, 366, 367, 368, 368, 369, 370, 371, 372, 373, 373, 374, 375, 375, 376, 377, 381, 382, 383, 383, 384, 385, 386, 387, 388, 391, 392, 393, 395, 396, 397, 398, 399, 400, 400, 401, 402, 406, 407, 408, 409, 410, 411, 411, 412, 413, 414, 415, 416, 416, 417, 418, 420, 421, 422, 423, 424, 425, 426, 427, 428, 431, 432, 433, 434, 435, 436, 438, 439, 440, 441, 442, 443, 443, 444, 445, 447, 448, 449, 449, 450, 451, 453, 454, 455, 455, 456, 457, 457, 458, 459, 460, 461, 462, 463, 464, 465, 472, 473, 474, 474, 475, 476, 478, 479, 480, 480, 481, 482, 483, 484, 485, 488, 489, 490, 490, 491, 492, 493, 494, 495, 496, 497, 498, 501, 502, 503, 503, 504, 505, 506, 507, 508, 509, 510, 511, 511, 512, 513, 515, 516, 517, 517, 518, 519, 519, 520, 521, 524, 525, 526, 529, 530, 531, 531, 532, 533, 534, 4906, 4904, 535, 536, 537, 537, 538, 539, 539, 540, 541, 543, 544, 545, 545, 546, 547, 547, 548, 549, 551, 552, 553, 553, 554, 555, 557, 558, 559, 562, 563, 564, 567, 568, 569, 4927, 571, 572, 574, 575, 576, 577, 578, 579, 580, 581, 582, 582, 583, 584, 584, 585, 586, 586, 587, 588, 590, 591, 592, 593, 594, 4623, 4623, 594, 595, 595, 596, 597, 597, 598, 599, 599, 600, 601, 601, 602, 603, 603, 604, 605, 605, 606, 607, 608, 609, 610, 610, 611, 612, 613, 614, 615, 618, 619, 620, 620, 621, 622, 622, 623, 624, 628, 629, 630, 631, 632, 633, 633, 634, 635, 636, 637, 638, 641, 642, 643, 643, 644, 645, 645, 646, 647, 650, 651, 652, 654, 655, 656, 656, 657, 658, 660, 661, 662, 662, 663, 664, 666, 667, 668, 669, 670, 671, 673, 674, 675, 676, 677, 678, 679, 680, 681, 682, 683, 684, 684, 685, 686, 686, 687, 688, 692, 693, 694, 694, 695, 696, 698, 699, 700, 700, 701, 702, 702, 703, 704, 705, 706, 707, 707, 708, 709, 713, 714, 715, 718, 719, 720, 721, 722, 723, 723, 724, 725, 725, 726, 727, 727, 728, 729, 729, 730, 731, 731, 732, 733, 733, 734, 735, 735, 736, 738, 739, 740, 741, 743, 744, 745, 746, 747, 748, 751, 752, 753, 754, 755, 756, 759, 760, 761, 766, 767, 768, 768, 769, 770, 771, 772, 4621, 4621, 772, 773, 777, 778, 779, 781, 782, 783, 784, 785, 786, 786, 787, 788, 789, 790, 791, 792, 793, 794, 795, 796, 797, 797, 798, 799, 801, 802, 803, 804, 805, 806, 808, 809, 810, 810, 811, 812, 813, 814, 815, 818, 819, 820, 820, 821, 822, 822, 823, 824, 826, 827, 828, 832, 833, 834, 834, 835, 836, 837, 838, 839, 839, 840, 841, 842, 843, 844, 845, 846, 847, 849, 850, 851, 851, 852, 853, 853, 854, 855, 857, 858, 859, 859, 4995, 4996, 4996, 4997, 4998, 4998, 4999, 5000, 5001, 5002, 5003, 4992, 4993, 4994, 4994, 4995, 859, 861, 862, 863, 863, 864, 865, 865, 866, 867, 867, 868, 869, 870, 871, 872, 873, 874, 875, 876, 877, 878, 878, 879, 880, 880, 881, 882, 882, 883, 884, 885, 886, 887, 888, 889, 890, 891, 892, 893, 895, 896, 897, 897, 898, 899, 901, 902, 903, 904, 905, 906, 906, 907, 908, 909, 910, 911, 911, 912, 913, 914, 915, 916, 916, 917, 918, 918, 919, 920, 924, 925, 926, 927, 928, 929, 931, 932, 933, 933, 934, 935, 935, 936, 937, 937, 938, 939, 939, 940, 941, 944, 945, 946, 948, 949, 950, 950, 951, 952, 954, 955, 956, 957, 958, 959, 959, 960, 961, 962, 963, 964, 966, 967, 968, 968, 969, 970, 971, 972, 973, 973, 974, 975, 977, 978, 979, 984, 985, 986, 987, 988, 989, 991, 992, 993, 994, 995, 996, 996, 997, 998, 998, 999, 1000, 1001, 1002, 1003, 1003, 1004, 1005, 1011, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 1019, 1019, 1020, 1021, 1023, 1024, 1025, 1025, 1026, 1027, 1027, 1028, 1029, 1029, 1030, 1031, 1032, 1033, 1034, 1034, 1035, 1036, 1040, 1041, 1042, 1042, 1043, 1044, 1046, 1047, 1048, 1048, 1049, 1050, 1051, 1052, 1053, 1053, 1054, 1055, 1057, 1058, 1059, 1060, 1061, 1062, 1063, 1064, 1065, 1068, 1069, 1070, 1070, 1071, 1072, 1077, 1078, 1079, 1080, 1081, 1082, 1082, 1083, 1084, 1086, 1087, 1088, 1092, 1093, 1094, 1095, 1096, 1097, 1100, 1101, 1102, 1105, 1106, 1107, 1107, 1108, 1109, 1110, 1111, 1112, 1114, 1115, 1116, 1116, 1117, 1118, 1119, 1120, 1121, 1121, 1122, 1123, 1125, 1126, 1127, 1127, 1128, 1129, 1130, 1131, 1132, 1134, 1135, 1136, 1137, 1138, 1139, 1142, 1143, 1144, 1145, 1146, 1147, 1147, 5142, 5140, 5142, 1147, 1148, 1148, 1150, 1151, 1151, 1152, 1153, 1153, 1154, 1155, 1157, 1158, 1159, 1162, 1163, 1164, 1164, 1165, 1166, 1167, 1168, 1169, 1170, 1171, 1172, 1172, 1173, 1174, 1174, 1175, 1176, 1177, 1178, 1179, 1179, 1180, 1181, 1182, 1183, 1184, 1184, 1185, 1186, 1186, 1187, 1188, 1190, 1191, 1192, 1192, 1193, 1194, 1195, 1196, 1197, 1197, 1198, 1199, 1200, 1201, 1202, 1202, 1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1212, 1213, 1214, 1215, 1216, 1217, 1218, 1219, 1220, 1220, 1221, 1222, 1222, 1223, 1224, 1225, 1226, 1227, 1227, 1228, 1229, 1230, 1231, 1232, 1233, 1235, 1236, 1240, 1241, 1242, 1242, 1243, 1244, 1247, 1248, 1249, 1249, 1250, 1251, 1253, 1254, 1255, 1255, 1256, 1257, 1257, 1258, 1259, 1259, 1260, 1261, 1261, 1262, 1263, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1276, 1277, 1278, 1278, 4724, 4714, 4716, 4719, 4720, 4723, 4724, 1278, 1278, 1279, 1280, 1281, 5213, 5214, 5214, 5215, 5216, 5217, 5218, 5209, 5230, 5210, 5211, 1283, 1284, 1285, 1285, 1286, 1287, 1289, 1290, 1291, 1295, 1296, 1297, 1298, 1299, 1300, 1301, 1302, 1303, 1304, 1305, 1306, 1306, 1307, 1308, 1310, 1311, 1312, 1312, 1313, 1314, 1316, 1317, 1318, 1320, 1321, 1322, 1322, 1323, 1324, 1325, 1326, 1327, 1328, 1329, 1330, 1332, 1333, 1334, 1337, 1338, 1339, 1340, 1341, 1342, 1343, 1344, 1345, 1346, 1347, 1348, 5147, 1348, 1349, 1350, 1351, 1352, 1353, 1354, 1355, 1355, 1356, 1357, 1361, 1362, 1363, 1366, 1367, 1368, 1368, 1369, 1370, 1370, 1371, 1372, 1373, 1374, 1375, 1378, 1379, 1380, 1381, 1382, 1383, 1389, 1390, 1391, 1396, 1397, 1398, 1400, 1401, 1402, 5112, 5113, 5114, 5114, 5115, 5116, 5117, 5118, 5119, 4709, 4710, 5203, 5206, 5207, 4710, 5176, 5177, 5178, 5178, 5179, 5180, 5182, 5183, 5184, 5184, 5185, 5186, 5188, 5189, 5190, 5154, 5155, 5156, 5157, 5158, 5196, 5199, 5200, 5193, 5195, 5196, 5158, 5161, 5162, 5163, 5163, 5164, 5165, 5165, 5166, 5167, 5170, 5171, 5172, 5172, 5173, 5174, 5122, 5123, 5124, 5125, 5126, 5127, 5127, 5128, 5061, 5062, 5063, 5064, 5066, 5067, 5068, 5068, 5069, 5070, 5071, 5072, 5075, 5075, 5076, 5077, 5078, 5079, 5080, 5082, 5083, 5084, 5084, 5085, 5086, 5086, 5087, 5088, 5091, 5092, 5093, 5098, 5099, 5100, 5100, 5101, 5102, 5104, 5105, 5107, 5059, 5061, 5129, 1423, 1424, 1426, 1429, 1431, 1432, 1433, 1435, 1437, 1438, 1440, 1441, 1442, 1443, 1445, 1445, 1447, 1449, 1451, 1453, 1454, 1457, 1459, 1460, 1462, 1464, 1465, 1469, 1471, 1473, 1476, 1478, 1480, 1480, 1482, 1483, 1483, 1485, 1486, 1488, 1490, 1492, 1498, 1500, 1502, 1504, 1505, 1507, 1509, 1511, 1512, 1517, 1518, 1520, 1522, 1523, 1525, 1526, 1527, 1529, 1531, 1533, 1534, 1539, 1541, 1543, 1544, 1546, 1548, 1552, 1554, 1555, 1555, 4666, 4667, 1561, 1563, 1565, 1566, 1568, 1570, 1570, 1572, 1573, 1576, 1578, 1580, 1580, 1581, 1583, 1591, 1593, 1594, 1594, 1596, 1597, 1599, 1600, 1602, 1602, 1603, 1605, 1605, 1607, 1608, 1608, 1610, 1611, 1615, 1617, 1618, 1618, 1620, 1621, 1625, 1626, 1628, 1631, 1632, 1634, 1636, 1638, 1640, 1640, 1642, 1643, 1648, 1650, 1652, 1655, 1657, 1658, 1660, 1661, 1663, 1664, 1665, 1667, 1667, 1669, 1670, 1670, 1672, 1673, 1680, 1681, 1683, 1686, 1687, 1689, 1696, 1698, 1700, 1704, 1705, 1707, 1707, 1709, 1710, 1713, 1715, 1717, 1718, 1720, 1721, 1734, 1736, 1738, 1739, 1740, 1742, 1752, 1753, 1755, 1756, 1757, 1759, 1760, 1762, 1763, 1763, 1765, 1767, 1768, 1770, 1771, 1782, 1784, 1786, 1788, 1790, 1791, 1791, 1793, 1795, 1796, 1798, 1800, 1800, 1802, 1804, 1804, 1806, 1808, 1808, 1809, 1811, 1813, 1815, 1817, 1817, 1819, 1821, 1826, 1828, 1829, 1829, 1831, 1833, 1833, 1835, 1837, 1841, 1843, 1844, 1844, 1846, 1847, 1847, 1849, 1850, 1851, 1853, 1854, 1854, 1856, 1857, 1857, 1859, 1861, 1864, 1866, 1867, 1867, 1869, 1871, 1879, 1880, 1882, 1883, 1885, 1886, 1889, 1891, 1892, 1896, 1898, 1899, 1899, 1900, 1902, 1902, 1904, 1905, 1908, 1910, 1911, 1911, 1912, 1914, 1915, 1917, 1918, 1920, 1922, 1924, 1928, 1930, 1931, 1931, 1933, 1935, 1942, 1943, 1945, 1948, 1950, 1951, 1954, 1955, 1957, 1960, 1962, 1963, 1964, 1966, 1967, 1971, 1973, 1974, 1982, 1984, 1986, 1991, 1992, 1994, 1994, 1995, 1997, 1999, 2001, 2002, 2004, 2006, 2008, 2008, 2010, 2011, 2011, 2013, 2014, 2014, 2016, 2018, 2018, 2020, 2021, 2026, 2028, 2030, 2031, 2032, 2034, 2041, 2042, 2044, 2045, 2047, 2049, 2050, 2052, 2053, 2053, 2054, 2056, 2061, 2063, 2065, 2065, 2067, 2068, 2069, 2071, 2072, 2075, 2076, 2078, 2080, 2081, 2083, 2083, 2085, 2087, 2087, 2088, 2090, 4729, 4730, 4732, 4733, 4739, 4740, 2105, 2107, 2109, 2109, 2111, 2113, 2113, 2115, 2117, 2117, 2119, 2120, 2121, 2123, 2124, 2124, 2126, 2127, 2128, 2130, 2131, 2134, 2135, 2137, 2140, 2141, 2143, 2147, 2149, 2150, 2150, 2152, 2154, 2154, 2155, 2157, 2158, 2160, 2161, 2161, 2163, 2165, 2165, 2167, 2168, 2172, 2174, 2176, 2176, 2178, 2180, 2181, 2182, 2184, 2187, 2189, 2190, 2196, 2198, 2199, 2206, 2208, 2209, 2211, 2213, 2214, 2219, 2221, 2222, 2223, 2224, 2226, 2234, 2236, 2237, 5291, 2240, 2241, 2241, 2243, 5375, 5376, 5378, 5380, 5380, 5282, 5283, 5280, 5281, 5380, 5381, 5383, 5274, 5273, 5384, 5385, 5404, 5406, 5407, 5407, 5259, 5260, 5257, 5258, 5408, 5412, 5414, 5388, 5392, 5393, 5395, 5396, 5398, 5399, 5399, 5401, 5374, 5374, 2244, 5323, 5325, 5326, 5327, 2246, 2247, 5421, 5420, 2247, 2249, 2250, 2252, 5354, 5358, 5359, 5361, 5362, 5364, 5365, 5365, 5367, 5368, 5369, 5371, 5329, 5333, 5335, 5337, 5340, 5341, 5343, 5347, 5349, 5351, 5352, 5354, 2254, 2254, 2256, 5305, 5028, 2257, 5034, 5033, 2258, 2259, 2260, 2262, 2263, 5456, 5458, 5459, 5459, 5460, 5462, 5469, 2263, 5488, 5489, 2263, 2264, 2266, 2268, 2269, 2271, 2273, 2274, 2274, 2275, 2277, 5478, 2279, 2280, 2280, 2282, 2283, 2290, 2292, 2294, 2297, 2299, 5504, 5507, 5508, 5527, 5529, 5509, 5511, 5513, 5502, 2299, 2299, 2300, 2302, 2303, 2304, 2306, 2310, 2312, 2314, 2316, 2317, 2319, 2321, 2323, 2325, 2329, 2330, 2332, 2334, 2336, 2338, 2342, 2343, 2345, 2345, 2347, 2348, 2350, 2352, 2354, 2355, 2357, 2358, 2359, 2361, 2362, 2363, 2365, 2366, 2374, 2376, 2378, 2378, 2380, 2382, 2383, 2385, 2386, 2388, 2390, 2391, 2392, 2393, 2397, 2397, 2398, 2400, 2404, 2406, 2407, 2407, 2409, 2410, 2420, 2422, 2424, 2426, 2428, 2429, 2431, 2433, 2434, 2445, 2446, 2448, 2449, 2450, 2452, 2453, 2455, 2457, 2466, 2468, 2469, 2471, 2472, 2474, 2477, 2479, 2481, 2482, 2484, 2485, 2487, 2489, 2490, 2491, 2493, 2494, 2494, 2496, 2498, 2502, 2504, 2505, 2505, 2506, 2508, 2513, 2515, 2516, 2516, 2517, 2519, 2520, 2522, 2524, 2528, 2530, 2532, 2534, 2536, 2538, 2540, 2542, 2544, 2548, 2550, 2552, 2556, 2558, 2559, 2559, 2561, 2563, 2566, 2568, 2570, 2576, 2578, 2579, 2579, 2581, 2582, 2583, 2585, 2587, 2587, 2589, 2590, 2591, 2593, 2595, 2598, 2600, 2601, 2601, 2603, 2604, 2608, 2610, 2612, 2613, 2615, 2617, 2617, 2619, 2620, 2626, 2628, 2629, 2634, 2635, 2637, 2640, 2642, 2644, 2649, 2651, 2653, 2653, 2655, 2657, 2660, 2662, 2664, 2664, 2666, 2667, 2667, 2668, 2670, 2672, 2674, 2675, 2675, 2677, 2678, 2678, 2679, 2681, 2681, 2682, 2684, 2690, 2692, 2694, 2698, 2700, 2702, 2702, 2704, 2705, 2705, 2707, 2708, 2719, 2721, 2723, 2724, 2726, 2727, 2727, 2729, 2730, 2734, 2736, 2737, 2738, 2739, 2741, 2742, 2744, 2745, 2746, 2747, 2749, 2749, 2750, 2752, 2756, 2757, 2759, 2762, 2764, 2765, 2769, 2770, 2772, 2772, 2774, 2775, 2775, 2777, 2778, 2785, 2786, 2788, 2788, 2790, 2792, 2792, 2794, 2796, 2799, 2801, 2803, 2803, 2805, 2806, 2806, 2808, 2810, 2814, 2815, 2817, 2821, 2823, 2825, 2825, 2827, 2829, 2829, 2831, 2832, 2835, 2837, 2839, 2840, 2842, 2843, 2844, 2846, 2847, 2850, 2852, 2854, 2857, 2859, 2861, 2863, 2864, 2866, 2871, 2872, 2874, 2880, 2882, 2883, 2890, 2892, 2894, 2894, 2896, 2897, 2906, 2908, 2909, 2910, 2912, 2913, 2913, 2915, 2916, 2917, 2918, 2920, 2920, 2922, 2923, 2932, 2934, 2936, 2936, 2938, 2940, 2944, 2945, 2947, 2951, 2952, 2954, 2958, 2959, 2961, 2964, 2966, 2967, 2968, 2970, 2971, 2973, 2975, 2976, 2977, 2978, 2980, 2980, 2982, 2984, 2986, 2988, 2990, 2990, 2992, 2993, 2994, 2995, 2997, 3000, 3002, 3003, 3005, 3006, 3008, 3009, 3010, 3012, 3013, 3015, 3016, 3017, 3019, 3020, 3021, 3022, 3024, 3025, 3027, 3028, 3031, 3033, 3035, 3035, 3037, 3038, 3040, 3041, 3043, 3045, 3047, 3048, 3048, 3050, 3052, 3052, 3054, 3055, 3055, 3056, 3058, 3058, 3060, 3061, 3061, 3063, 3065, 3066, 3068, 3070, 3071, 3073, 3075, 3077, 3079, 3081, 3081, 3083, 3084, 3084, 3086, 3088, 3089, 3091, 3092, 3092, 3093, 3095, 3095, 3097, 3099, 3102, 3104, 3105, 3107, 3109, 3110, 3114, 3115, 3117, 3121, 3123, 3124, 3124, 3126, 3127, 3127, 3129, 3130, 3130, 3133, 3134, 3134, 3136, 3138, 3140, 3143, 3144, 3145, 3147, 3149, 3149, 3151, 3152, 3154, 3155, 3157, 3157, 3159, 3161, 3163, 3165, 3167, 3172, 3174, 3176, 3185, 3187, 3188, 3189, 3191, 3192, 3192, 3194, 3195, 3198, 3200, 3201, 3202, 3204, 3206, 3207, 3213, 3215, 3224, 3226, 3227, 3232, 3233, 3235, 3245, 3247, 3248, 3249, 3251, 3253, 3263, 3265, 3266, 3267, 3268, 3270, 3270, 3272, 3274, 3274, 3275, 3277, 3278, 3279, 3281, 3283, 3285, 3286, 3286, 3288, 3290, 3293, 3295, 3296, 3296, 3297, 3299, 3303, 3305, 3307, 3315, 3317, 3318, 3318, 3319, 3321, 3323, 3324, 3326, 3326, 3327, 3329, 3331, 3333, 3334, 3334, 3336, 3337, 3339, 3341, 3342, 3350, 3352, 3353, 3354, 3356, 3358, 3362, 3364, 3365, 3369, 3371, 3372, 3376, 3378, 3379, 3394, 3396, 3397, 3397, 3399, 3401, 3406, 3408, 3410, 3415, 3416, 3418, 3422, 3424, 5632, 3428, 3430, 3431, 3435, 3436, 3438, 3442, 3444, 3446, 3446, 3447, 3449, 3452, 3454, 3456, 3458, 3459, 3461, 3466, 3468, 3469, 3471, 3473, 3475, 3477, 3478, 3480, 3483, 3485, 3486, 3487, 3489, 3490, 3499, 3500, 3502, 3508, 3510, 3511, 3512, 3514, 3516, 3516, 3518, 3519, 3522, 3524, 3525, 3528, 3530, 3531, 3531, 3533, 3535, 3536, 4792, 4790, 4791, 3537, 3539, 3539, 4758, 4759, 4763, 4764, 3540, 3540, 3541, 3543, 3543, 3545, 3547, 3547, 4886, 4881, 4885, 4886, 3547, 3547, 3548, 4867, 4868, 3548, 3549, 3549, 3551, 3553, 3553, 3555, 3557, 3558, 3559, 3561, 3562, 3563, 3565, 3567, 3569, 3571, 3575, 3576, 3578, 3590, 3592, 3594, 3595, 3597, 3598, 3606, 3608, 3610, 3615, 3616, 3618, 3619, 3621, 3622, 3625, 3626, 3628, 3629, 3631, 3633, 3637, 3639, 3641, 3641, 3643, 3645, 3647, 3649, 3650, 3650, 3652, 3653, 3653, 3655, 3656, 4806, 4808, 4809, 3658, 3659, 3661, 3662, 3664, 3666, 3669, 3671, 3672, 3672, 3674, 3675, 3679, 3681, 3682, 3686, 3688, 3689, 3690, 3692, 3693, 3693, 3695, 3696, 3696, 3697, 3699, 3711, 3713, 3715, 3722, 3724, 3727, 3729, 3731, 3733, 3734, 3736, 3737, 3738, 3740, 3741, 3745, 3747, 3749, 3749, 3751, 3753, 3753, 3754, 3756, 3760, 3762, 3764, 3764, 3766, 3768, 3776, 3777, 3779, 3779, 3781, 3783, 3785, 3787, 3788, 3791, 3793, 3795, 3795, 3797, 3798, 3798, 3800, 3802, 3804, 3806, 3807, 3807, 3809, 3810, 3813, 3816, 3818, 3820, 3822, 3823, 3825, 3827, 3828, 3828, 3829, 3831, 3836, 3837, 3839, 3843, 3844, 3846, 3846, 3848, 3849, 3850, 3851, 3853, 3853, 3855, 3856, 3861, 3863, 3864, 3864, 3866, 3868, 3872, 3873, 3875, 3877, 3879, 3881, 3881, 3882, 3884, 3887, 3888, 3890, 3892, 3894, 3896, 3896, 3898, 3900, 3900, 3901, 3903, 3910, 3912, 3913, 3915, 3916, 3918, 3919, 3921, 3922, 3927, 3929, 3930, 3932, 3934, 3935, 3939, 3940, 3942, 3942, 3944, 3945, 3945, 3947, 3949, 3955, 3957, 3958, 3963, 3964, 3966, 3968, 3969, 3971, 3975, 3977, 3978, 3978, 3981, 3982, 3985, 3987, 3988, 3991, 3993, 3994, 3999, 4000, 4003, 4010, 4011, 4013, 4018, 4020, 4021, 4021, 4022, 4024, 4029, 4031, 4032, 4036, 4037, 4039, 4047, 4049, 4050, 4056, 4058, 4059, 4061, 4063, 4065, 4070, 4072, 4073, 4075, 4076, 4078, 4086, 4088, 4089, 4094, 4096, 4098, 4098, 4100, 4101, 4102, 4104, 4105, 4107, 4109, 4111, 4114, 4115, 4117, 4122, 4124, 4125, 4129, 4131, 4133, 4137, 4138, 4140, 4140, 4141, 4143, 4143, 4144, 4146, 4150, 4152, 4154, 4159, 4161, 4162, 4166, 4167, 4169, 4170, 4171, 4173, 4174, 4176, 4177, 4178, 4179, 4181, 4187, 4189, 4190, 4196, 4198, 4199, 4203, 4205, 4206, 4206, 4207, 4209, 4211, 4213, 4215, 4221, 4223, 4225, 4230, 4231, 4233, 4234, 4236, 4237, 4244, 4246, 4248, 4254, 4256, 4257, 4258, 4260, 4261, 4261, 4263, 4265, 4274, 4276, 4277, 4277, 4279, 4281, 4287, 4289, 4290, 4295, 4297, 4299, 4299, 4300, 4303, 4306, 4308, 4309, 4313, 4315, 4316, 4318, 4319, 4321, 4322, 4323, 4325, 4326, 4328, 4330, 4332, 4334, 4337, 4337, 4339, 4341, 4343, 4345, 4347, 4347, 4349, 4350, 4350, 4352, 4354, 4363, 4364, 4366, 4370, 4372, 4374, 4377, 4379, 4381, 4381, 4382, 4384, 4393, 4394, 4396, 4397, 4398, 4400, 4402, 4404, 4405, 4411, 4413, 4414, 4416, 4417, 4419, 4427, 4429, 4430, 4431, 4432, 4434, 4951, 4952, 4439, 4439, 4440, 4442, 4448, 4450, 4451, 4969, 4451, 4452, 4453, 4454, 4456, 4457, 4459, 4460, 4463, 4465, 4972, 4467, 4469, 4470, 4479, 4481, 4483, 4493, 4495, 4496, 4508, 4509, 4511, 4511, 4513, 4514, 4526, 4528, 4529, 4534, 4536, 4538, 4543, 4545, 4547, 4550, 4552, 4553, 4555, 4557, 4982, 4978, 4979, 4981, 4567, 4569, 4570, 4574, 4576, 4578, 4578, 4580, 4582, 4582, 4584, 4585, 4588, 4589, 4591, 4597, 4599, 4600, 4600, 0, 2, 11, 13, 15, 15, 16, 18, 24, 26, 27, 32, 33, 35, 35, 36, 38, 42, 44, 46, 48, 50, 52, 54, 56, 58, 58, 60, 61, 70, 72, 74, 78, 80, 82, 82, 83, 85, 87, 88, 90, 99, 101, 103, 103, 105, 107, 110, 112, 114, 114, 4847, 4848, 4855, 4857, 4859, 4860, 4838, 4836, 4837, 4841, 4842, 4843, 4844, 4846, 4846, 115, 116, 117, 119, 120, 123, 125, 126, 126, 128, 130, 130, 132, 133, 133, 135, 137, 138, 139, 141, 141, 142, 144, 144, 146, 148, 148, 150, 151, 154, 156, 158, 160, 162, 163, 164, 166, 167, 167, 169, 171, 171, 173, 174, 174, 176, 177, 177, 179, 180, 181, 183, 184, 4787, 4772, 4773, 4773, 4775, 4777, 4777, 4779, 4780, 4782, 186, 187, 189, 191, 193, 194, 196, 197, 205, 206, 208, 208, 210, 212, 214, 216, 218, 222, 224, 225, 226, 228, 229, 231, 233, 235, 237, 238, 240, 240, 242, 243, 243, 245, 247, 247, 249, 250, 4901, 252, 253, 253, 255, 256, 261, 262, 264, 266, 268, 269, 269, 270, 272, 274, 276, 277, 278, 280, 281, 282, 284, 286, 286, 287, 289, 289, 291, 292, 299, 301, 303, 303, 305, 307, 307, 309, 310, 310, 312, 313, 4934, 4935, 315, 315, 317, 318, 318, 320, 322, 327, 329, 330, 333, 335, 337, 343, 345, 347, 349, 351, 352, 352, 354, 355, 355, 357, 359, 362, 364, 366, 370, 371, 373, 373, 375, 377, 383, 385, 386, 390, 391, 393, 398, 400, 402, 408, 409, 411, 413, 414, 416, 419, 420, 422, 426, 428, 429, 431, 433, 434, 440, 441, 443, 443, 445, 446, 447, 449, 451, 453, 455, 457, 460, 462, 463, 463, 465, 466, 474, 476, 477, 478, 480, 482, 483, 485, 486, 487, 488, 490, 492, 493, 495, 496, 498, 499, 501, 503, 505, 508, 509, 511, 511, 513, 514, 514, 515, 517, 517, 519, 521, 523, 524, 526, 529, 531, 533, 535, 537, 539, 539, 541, 542, 542, 543, 545, 545, 547, 549, 553, 555, 556, 557, 559, 560, 562, 564, 565, 567, 569, 570, 4927, 572, 573, 573, 574, 576, 577, 579, 580, 584, 586, 588, 590, 592, 593, 595, 597, 599, 599, 601, 603, 607, 608, 610, 610, 612, 613, 613, 615, 616, 617, 618, 620, 620, 622, 624, 627, 628, 630, 630, 631, 633, 635, 636, 638, 641, 643, 645, 645, 647, 648, 650, 652, 653, 656, 658, 659, 659, 660, 662, 662, 664, 665, 665, 666, 668, 669, 671, 672, 672, 673, 675, 676, 678, 679, 690, 692, 694, 694, 696, 697, 702, 704, 705, 705, 707, 709, 713, 715, 716, 717, 718, 720, 720, 721, 723, 725, 727, 729, 731, 733, 735, 739, 741, 742, 743, 745, 746, 750, 751, 753, 753, 754, 756, 759, 761, 762, 765, 766, 768, 771, 4621, 773, 776, 777, 779, 783, 784, 786, 791, 792, 794, 794, 795, 797, 808, 810, 812, 813, 815, 816, 820, 822, 824, 826, 828, 829, 832, 834, 836, 836, 837, 839, 845, 847, 848, 853, 855, 856, 857, 859, 4996, 4996, 4998, 5000, 5001, 5003, 5004, 4992, 4994, 859, 861, 863, 865, 865, 867, 869, 870, 872, 873, 878, 880, 882, 882, 884, 885, 885, 887, 888, 890, 891, 893, 908, 909, 911, 924, 926, 927, 927, 929, 930, 931, 933, 935, 937, 939, 941, 944, 946, 947, 953, 954, 956, 962, 964, 965, 966, 968, 970, 970, 971, 973, 973, 975, 976, 984, 986, 987, 1003, 1005, 1006, 1010, 1011, 1013, 1014, 1016, 1017, 1017, 1019, 1021, 1023, 1025, 1027, 1034, 1036, 1037, 1039, 1040, 1042, 1042, 1044, 1045, 1046, 1048, 1050, 1051, 1053, 1055, 1059, 1060, 1062, 1063, 1065, 1066, 1068, 1070, 1072, 1076, 1077, 1079, 1079, 1080, 1082, 1085, 1086, 1088, 1091, 1092, 1094, 1099, 1100, 1102, 1104, 1105, 1107, 1110, 1112, 1113, 1114, 1116, 1118, 1118, 1119, 1121, 1121, 1123, 1124, 1127, 1129, 1130, 1137, 1139, 1140, 1144, 1145, 1147, 5142, 1148, 1151, 1151, 1153, 1155, 1157, 1159, 1160, 1169, 1170, 1172, 1174, 1176, 1177, 1179, 1181, 1182, 1182, 1184, 1186, 1192, 1194, 1195, 1197, 1199, 1200, 1200, 1202, 1204, 1204, 1205, 1207, 1207, 1208, 1210, 1215, 1217, 1218, 1222, 1224, 1225, 1227, 1229, 1230, 1233, 1236, 1237, 1240, 1242, 1244, 1249, 1251, 1252, 1253, 1255, 1257, 1257, 1259, 1261, 1264, 1266, 1267, 1267, 1269, 1270, 1270, 1272, 1273, 1276, 1278, 4714, 4716, 4720, 4721, 1278, 1280, 1281, 5214, 5216, 5217, 5209, 5210, 5230, 5229, 5230, 5211, 5212, 5213, 1281, 1282, 1283, 1285, 1285, 1287, 1288, 5244, 1288, 1289, 1289, 1291, 1292, 1294, 1295, 1297, 1301, 1303, 1304, 1306, 1308, 1309, 1312, 1314, 1315, 1315, 1316, 1318, 1319, 1320, 1322, 1322, 1324, 1325, 1325, 1327, 1328, 1328, 1330, 1331, 1332, 1334, 1335, 1337, 1339, 1340, 1342, 1343, 1345, 5147, 1349, 1350, 1350, 1352, 1353, 1355, 1357, 1358, 1360, 1361, 1363, 1366, 1368, 1370, 1370, 1372, 1373, 1373, 1375, 1376, 1377, 1378, 1380, 1381, 1383, 1384, 1389, 1391, 1392, 1395, 1396, 1398, 1400, 1402, 1403, 5112, 5114, 5116, 4709, 5203, 5204, 5206, 4710, 4711, 5175, 5176, 5178, 5178, 5180, 5181, 5182, 5184, 5186, 5190, 5154, 5156, 5157, 5196, 5197, 5198, 5199, 5193, 5195, 5158, 5159, 5163, 5165, 5167, 5169, 5170, 5172, 5125, 5127, 5061, 5062, 5064, 5065, 5068, 5070, 5071, 5071, 5075, 5077, 5084, 5086, 5088, 5091, 5093, 5094, 5100, 5102, 5103, 5104, 5107, 5059, 5059, 5129, 5131, 1423, 1426, 1427, 1442, 1445, 1449, 1451, 1454, 1455, 1460, 1462, 1465, 1480, 1483, 1486, 1488, 1492, 1493, 1498, 1502, 1504, 1508, 1509, 1512, 1517, 1520, 1521, 1526, 1529, 1530, 1537, 1539, 1543, 1544, 1548, 1550, 1552, 1555, 4667, 1559, 1561, 1565, 1566, 1570, 1573, 1575, 1576, 1580, 1602, 1605, 1608, 1615, 1618, 1621, 1636, 1640, 1643, 1660, 1663, 1664, 1670, 1673, 1675, 1680, 1683, 1685, 1685, 1686, 1689, 1696, 1700, 1702, 1702, 1704, 1707, 1717, 1718, 1721, 1732, 1734, 1738, 1752, 1755, 1756, 1763, 1767, 1768, 1768, 1771, 1773, 1782, 1786, 1788, 1791, 1795, 1796, 1796, 1800, 1804, 1808, 1811, 1813, 1813, 1817, 1821, 1833, 1837, 1839, 1841, 1844, 1847, 1851, 1854, 1857, 1864, 1867, 1871, 1877, 1879, 1882, 1889, 1892, 1894, 1895, 1896, 1899, 1899, 1902, 1905, 1907, 1908, 1911, 1911, 1914, 1915, 1920, 1924, 1925, 1927, 1928, 1931, 1931, 1935, 1937, 1942, 1945, 1946, 1946, 1948, 1951, 1954, 1957, 1958, 1959, 1960, 1963, 1971, 1974, 1976, 1981, 1982, 1986, 1991, 1994, 1997, 1998, 1999, 2002, 2004, 2008, 2011, 2011, 2014, 2018, 2018, 2021, 2023, 2026, 2030, 2031, 2031, 2034, 2035, 2041, 2044, 2045, 2049, 2050, 2053, 2053, 2056, 2057, 2061, 2065, 2068, 2069, 2072, 2074, 2075, 2078, 2080, 2080, 2083, 2087, 2087, 2090, 2091, 4729, 4732, 4733, 2109, 2113, 2117, 2120, 2121, 2124, 2127, 2128, 2131, 2134, 2137, 2139, 2140, 2143, 2144, 2154, 2157, 2158, 2158, 2161, 2165, 2176, 2180, 2181, 2181, 2184, 2185, 2194, 2196, 2199, 2204, 2206, 2209, 2209, 2211, 2214, 2219, 2222, 2223, 2223, 2226, 2228, 2232, 2234, 2237, 2241, 5375, 5376, 5376, 5380, 5283, 5280, 5380, 5381, 5257, 5408, 5410, 5392, 5395, 5396, 5399, 5374, 5323, 5325, 5327, 2245, 2246, 5421, 5418, 5420, 2249, 2250, 2250, 5354, 5355, 5358, 5361, 5362, 5365, 5368, 5369, 5333, 5337, 5338, 5347, 5351, 5352, 5352, 2254, 5305, 2256, 5024, 5025, 5028, 5034, 5035, 5033, 2259, 2260, 2260, 2263, 5493, 5455, 5456, 5459, 5459, 5462, 5463, 5468, 5469, 5488, 5489, 2264, 2266, 2266, 2269, 2271, 2271, 2274, 2277, 5478, 2280, 2283, 2289, 2290, 2294, 2295, 2297, 5504, 5507, 5527, 5528, 5529, 5511, 5512, 2303, 2306, 2307, 2310, 2314, 2315, 2316, 2319, 2320, 2320, 2321, 2325, 2334, 2338, 2339, 2342, 2345, 2348, 2350, 2354, 2355, 2355, 2358, 2359, 2362, 2363, 2366, 2378, 2382, 2383, 2386, 2388, 2391, 2392, 2397, 2400, 2407, 2410, 2412, 2418, 2420, 2424, 2431, 2434, 2436, 2443, 2445, 2448, 2453, 2457, 2458, 2465, 2466, 2469, 2471, 2474, 2475, 2476, 2477, 2481, 2482, 2485, 2486, 2487, 2490, 2491, 2494, 2498, 2499, 2501, 2502, 2505, 2505, 2508, 2509, 2513, 2516, 2519, 2527, 2528, 2532, 2533, 2534, 2538, 2539, 2540, 2544, 2548, 2552, 2553, 2554, 2556, 2559, 2559, 2563, 2564, 2565, 2566, 2570, 2575, 2576, 2579, 2583, 2587, 2590, 2591, 2595, 2597, 2598, 2601, 2604, 2608, 2612, 2613, 2613, 2617, 2620, 2625, 2626, 2629, 2634, 2637, 2638, 2647, 2649, 2653, 2658, 2660, 2664, 2667, 2670, 2671, 2675, 2678, 2681, 2688, 2690, 2694, 2702, 2705, 2708, 2719, 2723, 2724, 2724, 2727, 2730, 2738, 2741, 2742, 2742, 2745, 2746, 2746, 2749, 2752, 2756, 2759, 2760, 2762, 2765, 2766, 2772, 2775, 2778, 2783, 2785, 2788, 2788, 2792, 2796, 2798, 2799, 2803, 2803, 2806, 2810, 2814, 2817, 2819, 2821, 2825, 2829, 2840, 2843, 2844, 2850, 2854, 2856, 2863, 2866, 2868, 2879, 2880, 2883, 2890, 2894, 2897, 2906, 2909, 2910, 2910, 2913, 2916, 2917, 2920, 2923, 2932, 2936, 2940, 2944, 2947, 2948, 2951, 2954, 2955, 2968, 2971, 2973, 2985, 2986, 2990, 2994, 2997, 2998, 3000, 3003, 3004, 3005, 3008, 3009, 3009, 3012, 3013, 3013, 3016, 3017, 3021, 3024, 3025, 3031, 3035, 3038, 3040, 3043, 3044, 3045, 3048, 3052, 3055, 3058, 3061, 3066, 3070, 3071, 3077, 3081, 3084, 3084, 3088, 3089, 3089, 3092, 3095, 3095, 3099, 3101, 3106, 3107, 3110, 3114, 3117, 3119, 3119, 3121, 3124, 3124, 3127, 3130, 3134, 3138, 3139, 3139, 3140, 3144, 3145, 3149, 3152, 3152, 3154, 3157, 3163, 3167, 3168, 3171, 3172, 3176, 3183, 3185, 3188, 3189, 3192, 3195, 3202, 3206, 3207, 3223, 3224, 3227, 3232, 3235, 3236, 3242, 3245, 3248, 3249, 3253, 3255, 3261, 3263, 3266, 3267, 3270, 3274, 3274, 3277, 3278, 3278, 3281, 3282, 3282, 3283, 3286, 3286, 3290, 3292, 3296, 3299, 3300, 3302, 3303, 3307, 3313, 3315, 3318, 3318, 3321, 3322, 3323, 3326, 3329, 3334, 3337, 3338, 3338, 3339, 3342, 3353, 3354, 3358, 3361, 3362, 3365, 3367, 3369, 3372, 3375, 3376, 3379, 3392, 3394, 3397, 3406, 3410, 3411, 3415, 3418, 3419, 3428, 3431, 3433, 3442, 3446, 3449, 3458, 3461, 3462, 3466, 3469, 3471, 3471, 3475, 3477, 3477, 3480, 3481, 3481, 3483, 3486, 3486, 3487, 3490, 3511, 3512, 3516, 3521, 3522, 3525, 3528, 3531, 3535, 4791, 3539, 4759, 4763, 3540, 3543, 3543, 3547, 4881, 4884, 4885, 3547, 4868, 3549, 3553, 3553, 3557, 3558, 3558, 3561, 3562, 3562, 3565, 3567, 3567, 3571, 3573, 3575, 3578, 3579, 3590, 3594, 3595, 3618, 3619, 3622, 3625, 3628, 3629, 3635, 3637, 3641, 3650, 3653, 3656, 3658, 3661, 3662, 3662, 3666, 3668, 3669, 3672, 3675, 3689, 3690, 3693, 3710, 3711, 3715, 3720, 3722, 3727, 3728, 3729, 3733, 3733, 3734, 3737, 3738, 3741, 3742, 3744, 3745, 3749, 3749, 3753, 3756, 3759, 3760, 3764, 3776, 3779, 3783, 3807, 3810, 3812, 3812, 3813, 3818, 3823, 3825, 3828, 3828, 3831, 3832, 3836, 3839, 3840, 3864, 3868, 3869, 3876, 3877, 3881, 3890, 3892, 3896, 3909, 3910, 3913, 3915, 3918, 3919, 3931, 3932, 3935, 3954, 3955, 3958, 3968, 3971, 3973, 3974, 3975, 3978, 3978, 3982, 3983, 3985, 3988, 3989, 3990, 3991, 3994, 3999, 4003, 4005, 4010, 4013, 4015, 4017, 4018, 4021, 4028, 4029, 4032, 4036, 4039, 4041, 4045, 4047, 4050, 4056, 4059, 4061, 4061, 4065, 4066, 4070, 4073, 4074, 4075, 4078, 4080, 4084, 4086, 4089, 4094, 4098, 4101, 4102, 4105, 4106, 4121, 4122, 4125, 4143, 4146, 4147, 4159, 4162, 4163, 4166, 4169, 4170, 4170, 4173, 4174, 4178, 4181, 4183, 4196, 4199, 4201, 4202, 4203, 4206, 4210, 4211, 4215, 4220, 4221, 4225, 4228, 4230, 4233, 4233, 4234, 4237, 4253, 4254, 4257, 4261, 4265, 4266, 4274, 4277, 4281, 4285, 4287, 4290, 4295, 4299, 4303, 4304, 4306, 4309, 4318, 4321, 4322, 4326, 4330, 4332, 4332, 4337, 4341, 4343, 4347, 4350, 4350, 4354, 4356, 4363, 4366, 4367, 4377, 4381, 4384, 4393, 4396, 4397, 4397, 4400, 4402, 4410, 4411, 4414, 4427, 4430, 4431, 4431, 4434, 4435, 4447, 4448, 4451, 4960, 4451, 4969, 4968, 4969, 4452, 4456, 4457, 4460, 4463, 4972, 4973, 4477, 4479, 4483, 4511, 4514, 4516, 4533, 4534, 4538, 4549, 4550, 4553, 4554, 4555, 4982, 4572, 4574, 4578, 4578, 4582, 4585, 4588, 4591, 4592, 4600, 2, 3, 11, 15, 18, 32, 35, 38, 47, 48, 52, 54, 58, 61, 70, 74, 76, 82, 85, 87, 87, 90, 92, 99, 103, 107, 109, 110, 114, 114, 4848, 4849, 4854, 4855, 4859, 4859, 4860, 4836, 4843, 4846, 116, 117, 120, 123, 138, 141, 144, 152, 154, 158, 160, 163, 164, 167, 171, 174, 177, 180, 181, 4773, 4777, 4780, 4782, 187, 189, 189, 193, 194, 205, 208, 212, 213, 214, 218, 221, 222, 225, 230, 231, 235, 237, 240, 243, 247, 250, 251, 4901, 253, 256, 260, 261, 264, 264, 266, 269, 269, 272, 274, 282, 286, 289, 289, 292, 293, 299, 303, 307, 307, 310, 313, 318, 322, 324, 325, 327, 330, 333, 337, 339, 341, 343, 347, 347, 349, 352, 355, 359, 361, 361, 362, 366, 370, 373, 377, 381, 383, 386, 390, 393, 394, 397, 398, 402, 406, 408, 411, 413, 416, 418, 419, 422, 423, 425, 426, 429, 431, 434, 436, 440, 443, 446, 453, 457, 459, 460, 463, 466, 472, 474, 477, 482, 483, 486, 492, 495, 496, 500, 501, 505, 508, 511, 514, 514, 517, 521, 523, 526, 528, 529, 533, 534, 4905, 4906, 534, 535, 539, 542, 545, 549, 550, 553, 556, 557, 557, 560, 561, 561, 562, 565, 573, 576, 577, 582, 584, 588, 599, 603, 605, 605, 607, 610, 620, 624, 625, 627, 630, 633, 635, 638, 639, 640, 641, 645, 654, 656, 659, 659, 662, 665, 665, 668, 669, 676, 679, 681, 690, 694, 697, 705, 709, 710, 712, 713, 716, 717, 720, 723, 725, 729, 731, 731, 735, 738, 742, 743, 746, 750, 753, 756, 759, 762, 763, 776, 779, 780, 783, 786, 788, 791, 794, 797, 808, 812, 813, 818, 820, 824, 826, 829, 830, 831, 832, 836, 836, 839, 841, 845, 848, 849, 853, 856, 857, 4996, 5000, 5001, 4992, 859, 860, 870, 873, 875, 878, 882, 885, 888, 890, 893, 908, 911, 913, 923, 924, 927, 937, 941, 942, 943, 944, 947, 952, 953, 956, 961, 962, 965, 966, 970, 973, 984, 987, 989, 1003, 1006, 1007, 1013, 1014, 1017, 1032, 1034, 1037, 1042, 1045, 1046, 1051, 1055, 1056, 1059, 1062, 1063, 1063, 1066, 1068, 1068, 1072, 1073, 1079, 1082, 1084, 1084, 1085, 1088, 1099, 1102, 1103, 1109, 1110, 1113, 1113, 1114, 1118, 1118, 1121, 1124, 1127, 1130, 1132, 1136, 1137, 1140, 1144, 1147, 5140, 1151, 1155, 1156, 1169, 1172, 1174, 1174, 1177, 1179, 1182, 1186, 1188, 1190, 1192, 1195, 1197, 1200, 1204, 1207, 1210, 1211, 1214, 1215, 1218, 1222, 1225, 1227, 1227, 1230, 1232, 1233, 1237, 1238, 1239, 1240, 1244, 1249, 1252, 1253, 1253, 1257, 1261, 1263, 1264, 1267, 1267, 1270, 1273, 1275, 1276, 4714, 4723, 1278, 1281, 5214, 5217, 5209, 5209, 5230, 5228, 5229, 5211, 5212, 5212, 1281, 1282, 1282, 1285, 1288, 1289, 1292, 1293, 1294, 1297, 1298, 1300, 1301, 1304, 1306, 1309, 1310, 1319, 1322, 1325, 1331, 1332, 1335, 1337, 1340, 1342, 1342, 1345, 1346, 1359, 1360, 1363, 1373, 1376, 1377, 1377, 1380, 1381, 1388, 1389, 1392, 1399, 1400, 1403, 5112, 5116, 5117, 5120, 4711, 4709, 4709, 5204, 5205, 5182, 5186, 5187, 5190, 5156, 5157, 5194, 5195, 5159, 5163, 5167, 5168, 5124, 5125, 5061, 5062, 5065, 5066, 5068, 5071, 5077, 5084, 5088, 5089, 5100, 5103, 5104, 5104, 5059, 5131, 1442, 1449, 1450, 1451, 1455, 1457, 1460, 1465, 1467, 1480, 1486, 1487, 1498, 1504, 1507, 1517, 1521, 1522, 1525, 1526, 1530, 1536, 1537, 1543, 1544, 1550, 1551, 1558, 1559, 1565, 1566, 1573, 1574, 1575, 1580, 1583, 1599, 1602, 1608, 1614, 1615, 1621, 1636, 1643, 1645, 1658, 1660, 1664, 1680, 1685, 1689, 5442, 1696, 1702, 1702, 1707, 1710, 1752, 1756, 1759, 1763, 1768, 1773, 1796, 1804, 1808, 1808, 1813, 1821, 1829, 1833, 1839, 1851, 1857, 1861, 1877, 1882, 1883, 1887, 1889, 1894, 1895, 1899, 1905, 1907, 1911, 1915, 1918, 1920, 1925, 1927, 1931, 1937, 1942, 1946, 1951, 1954, 1958, 1959, 1970, 1971, 1976, 1979, 1981, 1986, 1991, 1997, 1998, 1998, 2002, 2003, 2003, 2004, 2011, 2026, 2031, 2035, 2041, 2045, 2049, 2049, 2053, 2057, 2059, 2061, 2068, 2068, 2069, 2074, 2075, 2080, 2087, 2109, 2117, 2120, 2120, 2124, 2127, 2132, 2134, 2139, 2140, 2144, 2146, 2150, 2154, 2158, 2172, 2176, 2181, 2193, 2194, 2199, 2204, 2209, 2214, 2218, 2219, 2223, 2232, 2237, 2239, 5291, 2241, 5376, 5280, 5381, 5274, 5392, 5396, 5399, 5399, 5323, 5324, 4637, 2246, 5418, 4641, 5418, 5419, 5420, 2250, 5355, 5362, 5365, 5369, 5333, 5338, 5339, 5347, 5352, 5305, 2256, 5025, 5026, 5028, 5035, 5030, 5455, 5459, 5523, 5522, 5523, 5459, 5468, 5488, 5489, 5489, 2266, 2271, 2271, 2277, 2278, 2289, 2294, 2295, 2295, 5504, 5505, 5507, 5528, 5529, 2302, 2303, 2307, 2310, 2315, 2316, 2316, 2320, 2325, 2333, 2334, 2339, 2342, 2348, 2349, 2349, 2350, 2355, 2359, 2362, 2366, 2374, 2378, 2383, 2392, 2400, 2402, 2407, 2412, 2413, 2418, 2424, 2425, 2429, 2431, 2436, 2443, 2448, 2449, 2465, 2469, 2470, 2476, 2481, 2482, 2482, 2486, 2487, 2501, 2505, 2509, 2511, 2513, 2519, 2525, 2527, 2532, 2533, 2538, 2539, 2539, 2544, 2546, 2554, 2559, 2564, 2565, 2570, 2571, 2575, 2579, 2582, 2582, 2583, 2590, 2591, 2597, 2598, 2608, 2613, 2620, 2625, 2629, 2630, 2633, 2634, 2638, 2658, 2664, 2667, 2675, 2681, 2684, 2688, 2694, 2695, 2702, 2708, 2710, 2718, 2719, 2724, 2737, 2738, 2742, 2746, 2752, 2754, 2754, 2756, 2760, 2772, 2778, 2780, 2781, 2783, 2788, 2788, 2796, 2797, 2797, 2798, 2803, 2803, 2810, 2812, 2821, 2829, 2832, 2839, 2840, 2844, 2849, 2850, 2856, 2862, 2863, 2868, 2879, 2883, 2884, 2889, 2890, 2897, 2905, 2906, 2910, 2910, 2916, 2917, 2917, 2923, 2925, 2943, 2944, 2948, 2950, 2951, 2955, 2967, 2968, 2973, 2985, 2990, 2993, 2994, 2998, 2999, 2999, 3000, 3004, 3005, 3009, 3013, 3020, 3021, 3025, 3030, 3031, 3038, 3045, 3052, 3055, 3066, 3071, 3075, 3077, 3084, 3089, 3089, 3095, 3101, 3106, 3110, 3112, 3113, 3114, 3119, 3119, 3124, 3130, 3130, 3134, 3139, 3145, 3152, 3157, 3162, 3163, 3168, 3171, 3176, 3178, 3181, 3183, 3188, 3188, 3189, 3195, 3201, 3202, 3207, 3232, 3236, 3238, 3242, 3248, 3249, 3249, 3255, 3256, 3261, 3266, 3267, 3267, 3274, 3278, 3282, 3286, 3292, 3296, 3300, 3302, 3302, 3307, 3308, 3313, 3318, 3322, 3323, 3329, 3331, 3353, 3358, 3359, 3361, 3365, 3366, 3367, 3372, 3373, 3373, 3375, 3379, 3392, 3397, 3401, 3405, 3406, 3411, 3428, 3433, 3435, 3440, 3442, 3449, 3457, 3458, 3462, 3471, 3477, 3481, 3511, 3516, 3519, 3521, 3525, 3526, 4790, 4791, 4759, 4763, 3543, 4881, 4884, 3547, 4867, 4868, 3553, 3558, 3562, 3567, 3573, 3573, 3575, 3579, 3589, 3590, 3595, 3615, 3618, 3622, 3625, 3629, 3633, 3635, 3641, 3645, 3662, 3668, 3669, 3733, 3737, 3738, 3759, 3764, 3768, 3774, 3776, 3783, 3804, 3807, 3812, 3823, 3828, 3832, 3834, 3836, 3840, 3861, 3864, 3869, 3876, 3881, 3884, 3887, 3890, 3896, 3907, 3909, 3913, 3915, 3919, 3922, 3930, 3931, 3935, 3954, 3958, 3959, 3974, 3978, 3983, 3990, 3994, 4603, 3998, 3999, 4005, 4015, 4017, 4021, 4027, 4028, 4032, 4035, 4036, 4041, 4045, 4050, 4052, 4056, 4061, 4066, 4075, 4080, 4081, 4084, 4089, 4090, 4101, 4102, 4106, 4120, 4121, 4125, 4140, 4143, 4147, 4158, 4159, 4163, 4166, 4170, 4174, 4177, 4178, 4183, 4202, 4206, 4209, 4210, 4215, 4216, 4220, 4225, 4227, 4228, 4233, 4237, 4253, 4257, 4258, 4261, 4266, 4268, 4272, 4274, 4281, 4285, 4290, 4292, 4294, 4295, 4303, 4304, 4309, 4311, 4316, 4318, 4322, 4326, 4332, 4341, 4350, 4356, 4357, 4363, 4367, 4368, 4377, 4384, 4385, 4393, 4397, 4402, 4410, 4414, 4415, 4447, 4451, 4960, 4960, 4969, 4966, 4968, 4452, 4453, 4477, 4483, 4484, 4532, 4533, 4538, 4554, 4982, 4983, 4572, 4578, 4585, 4600, 3, 4, 31, 32, 38, 46, 47, 52, 54, 61, 62, 82, 87, 92, 98, 99, 107, 109, 114, 4849, 4859, 4836, 4837, 4842, 4843, 116, 117, 123, 126, 138, 144, 148, 160, 164, 167, 167, 174, 177, 189, 194, 197, 204, 205, 212, 213, 218, 220, 230, 235, 236, 236, 237, 243, 243, 247, 251, 4901, 256, 258, 260, 264, 269, 282, 289, 293, 299, 307, 313, 318, 324, 325, 325, 330, 331, 333, 339, 340, 341, 347, 352, 352, 355, 361, 361, 366, 368, 368, 370, 377, 380, 381, 386, 389, 390, 394, 411, 413, 418, 419, 423, 425, 425, 429, 430, 431, 436, 437, 438, 440, 446, 452, 453, 459, 459, 460, 466, 492, 496, 499, 500, 505, 506, 514, 521, 522, 522, 523, 528, 528, 529, 534, 4905, 534, 535, 542, 545, 550, 553, 557, 561, 561, 565, 566, 573, 577, 580, 582, 588, 589, 595, 599, 605, 617, 620, 625, 627, 633, 635, 639, 640, 645, 654, 659, 665, 676, 681, 682, 690, 697, 698, 702, 705, 710, 717, 723, 725, 742, 746, 748, 749, 750, 756, 781, 783, 788, 791, 797, 799, 808, 813, 816, 825, 826, 830, 831, 836, 841, 844, 845, 849, 851, 853, 857, 4996, 5001, 5004, 4992, 860, 861, 869, 870, 875, 906, 908, 913, 935, 937, 942, 943, 947, 948, 952, 956, 957, 959, 961, 965, 965, 966, 973, 1001, 1003, 1007, 1013, 1017, 1021, 1042, 1046, 1050, 1050, 1051, 1056, 1068, 1073, 1074, 1084, 1088, 1089, 1098, 1099, 1103, 1109, 1113, 1118, 1136, 1140, 1141, 1167, 1169, 1174, 1179, 1182, 1188, 1195, 1197, 1204, 1204, 1207, 1211, 1214, 1218, 1220, 1220, 1222, 1227, 1233, 1238, 1239, 1239, 1244, 1245, 1249, 1253, 1261, 1261, 1263, 1267, 1274, 1275, 4714, 4723, 1281, 5214, 5214, 5209, 5228, 5212, 1282, 1288, 1289, 1293, 1294, 1300, 1304, 1306, 1318, 1319, 1325, 1336, 1337, 1342, 1359, 1363, 1364, 1370, 1373, 1377, 1399, 1403, 1404, 5112, 5117, 5119, 5188, 5190, 5157, 5194, 5159, 5160, 5161, 5163, 5168, 5122, 5124, 5061, 5068, 5077, 5078, 5082, 5084, 5089, 5098, 5100, 5104, 1441, 1442, 1450, 1460, 1467, 1468, 1476, 1480, 1487, 1497, 1498, 1507, 1516, 1517, 1522, 1522, 1525, 1530, 1536, 1543, 1544, 1557, 1558, 1565, 1566, 1574, 1575, 1575, 1583, 1585, 1599, 1608, 1611, 1614, 1621, 1623, 1634, 1636, 1645, 5442, 1702, 1710, 1751, 1752, 1759, 1763, 1773, 1774, 1829, 1839, 1841, 1850, 1851, 1861, 1875, 1877, 1883, 1887, 1894, 1895, 1895, 1905, 1907, 1907, 1915, 1918, 1918, 1925, 1927, 1927, 1937, 1938, 1953, 1954, 1959, 1970, 1976, 1978, 1979, 1986, 1988, 1998, 2003, 2011, 2025, 2026, 2035, 2040, 2041, 2049, 2049, 2057, 2058, 2059, 2068, 2074, 2074, 2075, 2087, 2105, 2109, 2120, 2132, 2139, 2140, 2140, 2146, 2147, 2147, 2150, 2158, 2172, 2181, 2185, 2193, 2199, 2201, 2203, 2204, 2214, 2217, 2218, 2223, 2231, 2232, 2239, 5291, 5376, 5283, 5279, 5280, 5274, 5392, 5399, 5324, 4637, 5418, 4641, 4641, 5419, 5420, 5420, 5355, 5356, 5362, 5369, 5329, 5346, 5347, 5305, 5027, 5028, 5030, 5455, 5523, 5516, 5520, 5522, 5459, 5467, 5468, 5489, 5489, 2271, 2278, 2289, 2295, 5505, 2299, 2302, 2307, 2316, 2325, 2326, 2333, 2339, 2341, 2341, 2342, 2349, 2349, 2355, 2359, 2359, 2366, 2368, 2374, 2383, 2386, 2392, 2402, 2403, 2429, 2436, 2438, 2443, 2449, 2452, 2464, 2465, 2470, 2475, 2476, 2482, 2482, 2487, 2491, 2501, 2509, 2511, 2511, 2519, 2520, 2525, 2532, 2533, 2533, 2539, 2546, 2564, 2565, 2571, 2590, 2591, 2598, 2608, 2620, 2621, 2624, 2625, 2630, 2633, 2638, 2639, 2657, 2658, 2667, 2675, 2684, 2686, 2688, 2695, 2696, 2702, 2710, 2711, 2718, 2724, 2730, 2737, 2742, 2746, 2746, 2754, 2760, 2772, 2780, 2781, 2781, 2788, 2797, 2797, 2803, 2812, 2820, 2821, 2832, 2835, 2839, 2844, 2849, 2856, 2857, 2877, 2879, 2884, 2889, 2897, 2898, 2905, 2910, 2917, 2943, 2948, 2949, 2950, 2955, 2957, 2964, 2967, 2973, 2985, 2993, 2994, 3005, 3013, 3017, 3030, 3038, 3039, 3045, 3055, 3061, 3065, 3066, 3075, 3077, 3089, 3101, 3106, 3112, 3113, 3113, 3119, 3130, 3144, 3145, 3157, 3162, 3168, 3169, 3188, 3195, 3197, 3201, 3207, 3215, 3231, 3232, 3238, 3242, 3249, 3256, 3267, 3278, 3282, 3296, 3302, 3308, 3313, 3322, 3323, 3323, 3331, 3334, 3350, 3353, 3359, 3366, 3367, 3373, 3391, 3392, 3401, 3427, 3428, 3435, 3440, 3449, 3450, 3457, 3462, 3463, 3471, 3481, 3486, 3511, 3519, 3520, 4760, 4763, 4881, 4882, 4884, 4867, 4868, 3558, 3562, 3573, 3579, 3580, 3587, 3589, 3595, 3615, 3622, 3623, 3625, 3633, 3634, 3635, 3645, 3646, 3658, 3662, 3669, 3728, 3733, 3738, 3759, 3768, 3769, 3774, 3783, 3785, 3823, 3832, 3834, 3834, 3840, 3841, 3876, 3884, 3885, 3887, 3896, 3900, 3907, 3913, 3915, 3989, 3990, 4603, 3998, 4005, 4006, 4015, 4021, 4024, 4027, 4032, 4033, 4035, 4041, 4042, 4045, 4052, 4053, 4056, 4066, 4067, 4082, 4084, 4090, 4101, 4106, 4107, 4120, 4125, 4126, 4158, 4163, 4164, 4177, 4183, 4184, 4201, 4202, 4209, 4219, 4220, 4227, 4228, 4237, 4239, 4252, 4253, 4258, 4261, 4268, 4269, 4272, 4281, 4282, 4285, 4292, 4294, 4294, 4303, 4304, 4361, 4363, 4368, 4377, 4385, 4386, 4410, 4415, 4416, 4959, 4960, 4966, 4475, 4477, 4484, 4532, 4538, 4539, 4554, 4983, 4985, 4571, 4572, 4585, 31, 38, 39, 42, 46, 52, 54, 62, 64, 82, 92, 94, 98, 107, 108, 109, 4849, 4850, 4854, 4859, 4837, 4842, 116, 117, 160, 167, 177, 204, 212, 213, 213, 220, 221, 236, 243, 251, 258, 260, 269, 282, 293, 295, 299, 313, 314, 325, 331, 333, 341, 352, 361, 361, 368, 377, 379, 380, 386, 389, 394, 395, 406, 411, 418, 419, 425, 430, 437, 438, 446, 452, 459, 466, 490, 492, 499, 514, 522, 528, 542, 550, 551, 551, 553, 561, 580, 582, 589, 595, 605, 610, 617, 625, 626, 639, 645, 648, 653, 654, 665, 675, 676, 682, 702, 710, 711, 716, 717, 725, 742, 748, 749, 749, 756, 757, 825, 830, 831, 831, 841, 842, 851, 857, 4996, 4992, 861, 865, 906, 913, 914, 943, 948, 950, 952, 957, 959, 965, 973, 976, 1001, 1007, 1008, 1010, 1013, 1021, 1050, 1056, 1057, 1079, 1084, 1089, 1098, 1103, 1104, 1109, 1118, 1124, 1134, 1136, 1141, 1167, 1174, 1179, 1195, 1204, 1211, 1232, 1233, 1239, 1239, 1245, 1246, 1247, 1249, 1261, 1261, 1267, 1273, 1274, 4714, 4715, 4722, 4723, 5214, 1289, 1294, 1298, 1300, 1306, 1310, 1318, 1325, 1328, 1336, 1342, 1346, 1359, 1364, 1365, 5188, 5157, 5197, 5194, 5160, 5161, 5161, 5168, 5169, 5122, 5061, 5062, 5068, 5078, 5080, 5082, 5089, 5090, 5098, 5104, 5131, 1441, 1450, 1451, 1497, 1507, 1508, 1515, 1516, 1522, 1536, 1544, 1551, 1557, 1565, 1566, 1566, 1575, 1585, 1597, 1599, 1611, 1614, 1623, 1625, 1634, 1645, 1646, 5442, 1710, 1711, 5449, 1751, 1759, 1760, 1763, 1774, 1826, 1829, 1841, 1886, 1887, 1895, 1895, 1907, 1918, 1918, 1927, 1938, 1953, 1959, 1963, 1969, 1970, 1978, 1978, 1979, 1988, 1991, 1998, 2011, 2025, 2035, 2036, 2039, 2040, 2049, 2074, 2087, 2091, 2105, 2120, 2127, 2131, 2132, 2140, 2147, 2158, 2165, 2171, 2172, 2185, 2193, 2201, 2203, 2203, 2214, 2216, 2217, 2223, 2228, 2231, 2239, 2240, 5290, 5291, 5283, 5287, 5279, 5274, 5390, 5392, 5324, 5420, 5356, 5358, 5358, 5362, 5329, 5344, 5346, 5305, 5027, 5030, 5032, 5519, 5520, 5459, 2288, 2289, 5505, 2299, 2307, 2308, 2341, 2349, 2359, 2359, 2368, 2369, 2374, 2386, 2391, 2392, 2403, 2404, 2426, 2429, 2438, 2442, 2443, 2452, 2464, 2470, 2471, 2499, 2501, 2511, 2511, 2520, 2524, 2525, 2533, 2546, 2590, 2598, 2604, 2607, 2608, 2621, 2624, 2630, 2631, 2631, 2633, 2639, 2657, 2667, 2671, 2672, 2675, 2686, 2702, 2711, 2712, 2718, 2730, 2732, 2734, 2737, 2746, 2746, 2760, 2761, 2769, 2772, 2781, 2797, 2812, 2814, 2820, 2832, 2834, 2835, 2844, 2847, 2847, 2849, 2857, 2877, 2884, 2886, 2888, 2889, 2898, 2903, 2905, 2917, 2942, 2943, 2949, 2949, 2950, 2957, 2964, 2973, 2976, 2985, 2994, 2999, 3004, 3005, 3017, 3076, 3077, 3101, 3106, 3113, 3130, 3139, 3144, 3157, 3161, 3162, 3169, 3188, 3197, 3198, 3198, 3201, 3215, 3242, 3256, 3258, 3267, 3282, 3292, 3296, 3308, 3309, 3313, 3323, 3334, 3366, 3373, 3379, 3391, 3401, 3402, 3427, 3435, 3438, 3440, 3450, 3452, 3457, 3463, 3464, 3466, 3471, 3486, 4760, 4881, 4882, 4868, 3562, 3573, 3587, 3595, 3598, 3615, 3623, 3624, 3625, 3634, 3635, 3658, 3669, 3675, 3727, 3728, 3738, 3758, 3759, 3769, 3774, 3785, 3788, 3823, 3834, 3841, 3875, 3876, 3885, 3885, 3887, 3900, 3906, 3907, 3915, 4025, 4027, 4033, 4045, 4053, 4054, 4082, 4090, 4092, 4101, 4107, 4111, 4119, 4120, 4126, 4157, 4158, 4164, 4174, 4177, 4184, 4201, 4209, 4210, 4219, 4227, 4228, 4228, 4239, 4240, 4250, 4252, 4258, 4270, 4272, 4282, 4294, 4304, 4311, 4361, 4368, 4369, 4409, 4410, 4416, 4959, 4966, 4967, 4475, 4484, 4485, 4570, 4571, 4585, 31, 39, 40, 42, 52, 53, 53, 54, 64, 82, 94, 95, 97, 98, 108, 109, 4850, 4851, 4854, 4837, 4842, 160, 177, 181, 204, 213, 221, 230, 236, 251, 258, 269, 274, 282, 295, 296, 298, 299, 314, 325, 333, 340, 361, 377, 378, 379, 386, 388, 419, 430, 431, 437, 446, 447, 451, 452, 466, 508, 514, 528, 535, 542, 551, 551, 561, 566, 580, 589, 590, 4623, 595, 610, 635, 639, 648, 653, 665, 669, 700, 702, 711, 739, 742, 749, 825, 831, 842, 849, 851, 4996, 5007, 4992, 865, 904, 906, 914, 952, 959, 965, 965, 976, 977, 1000, 1001, 1008, 1042, 1050, 1057, 1097, 1098, 1104, 1109, 1124, 1125, 1133, 1134, 1141, 1190, 1195, 1211, 1247, 1261, 1273, 1274, 4715, 4716, 4721, 4722, 5214, 1298, 1300, 1310, 1315, 1318, 1328, 1335, 1336, 1346, 1358, 1359, 1365, 5194, 5161, 5169, 5122, 5062, 5066, 5068, 5080, 5081, 5097, 5098, 5131, 1438, 1441, 1451, 1515, 1522, 1530, 1535, 1536, 1551, 1557, 1566, 1585, 1597, 1611, 1612, 1631, 1634, 1646, 5442, 1711, 1713, 5449, 1759, 1760, 1760, 1774, 1775, 1826, 1841, 1847, 1886, 1895, 1918, 1918, 1938, 1939, 1969, 1978, 1988, 1991, 2011, 2018, 2025, 2036, 2037, 2039, 2049, 2058, 2074, 2091, 2092, 2105, 2127, 2131, 2147, 2165, 2168, 2170, 2171, 2185, 2191, 2193, 2203, 2203, 2216, 2217, 5294, 5290, 5283, 5285, 5287, 5274, 5388, 5390, 5324, 5420, 5358, 5329, 5344, 5305, 5307, 5519, 5459, 5463, 2288, 5505, 5506, 5513, 2299, 2308, 2341, 2359, 2369, 2372, 2374, 2391, 2426, 2438, 2440, 2462, 2464, 2471, 2511, 2524, 2525, 2525, 2546, 2547, 2582, 2590, 2604, 2623, 2624, 2631, 2631, 2639, 2640, 2672, 2686, 2688, 2702, 2712, 2714, 2716, 2718, 2732, 2734, 2746, 2761, 2769, 2781, 2797, 2819, 2820, 2834, 2847, 2857, 2861, 2875, 2877, 2886, 2886, 2888, 2898, 2902, 2903, 2917, 2949, 2957, 2958, 2964, 2976, 2977, 2999, 3004, 3017, 3075, 3076, 3101, 3106, 3130, 3139, 3139, 3157, 3161, 3188, 3198, 3215, 3261, 3267, 3292, 3293, 3296, 3309, 3313, 3334, 3338, 3366, 3379, 3380, 3390, 3391, 3402, 3438, 3440, 3452, 3456, 3457, 3464, 3466, 3486, 3490, 4867, 4868, 3573, 3587, 3598, 3599, 3613, 3615, 3624, 3624, 3625, 3635, 3727, 3738, 3742, 3758, 3769, 3771, 3773, 3774, 3788, 3823, 3841, 3843, 3872, 3875, 3885, 3885, 3900, 3903, 3906, 3915, 3922, 4025, 4033, 4035, 4043, 4045, 4054, 4081, 4082, 4092, 4119, 4126, 4127, 4157, 4164, 4165, 4201, 4210, 4216, 4219, 4228, 4240, 4250, 4258, 4261, 4270, 4282, 4284, 4359, 4361, 4369, 4409, 4416, 4419, 4959, 4967, 4968, 4474, 4475, 4485, 30, 31, 40, 40, 42, 53, 78, 82, 95, 159, 160, 181, 204, 221, 225, 229, 230, 251, 4901, 258, 274, 282, 296, 297, 297, 298, 314, 318, 325, 340, 341, 361, 378, 379, 388, 389, 418, 419, 431, 431, 437, 447, 451, 466, 467, 506, 508, 528, 535, 551, 566, 580, 590, 593, 593, 4623, 610, 635, 648, 649, 650, 653, 669, 700, 711, 712, 825, 842, 844, 849, 4996, 5004, 5007, 865, 869, 904, 914, 916, 965, 977, 979, 1000, 1008, 1009, 1039, 1042, 1057, 1095, 1097, 1104, 1133, 1141, 1142, 1190, 1211, 1212, 1246, 1247, 1273, 4721, 5214, 5228, 1298, 1310, 1312, 1315, 1328, 1331, 1358, 1365, 1366, 5194, 5169, 5172, 5096, 5097, 5131, 1438, 1451, 1457, 1513, 1515, 1530, 1535, 1551, 1552, 1557, 1585, 1586, 1594, 1597, 1612, 1631, 1646, 1647, 5449, 1760, 1775, 1825, 1826, 1847, 1918, 1939, 1940, 1968, 1969, 1988, 1991, 2018, 2023, 2025, 2037, 2039, 2059, 2074, 2092, 2140, 2147, 2168, 2191, 2203, 2217, 5294, 5283, 5284, 5285, 5274, 5276, 5387, 5388, 5414, 5412, 5388, 5324, 5420, 5329, 5330, 5344, 5307, 5308, 5519, 5463, 5465, 2288, 5506, 5507, 5513, 2308, 2310, 2372, 2391, 2392, 2425, 2426, 2440, 2462, 2471, 2475, 2511, 2525, 2547, 2582, 2604, 2605, 2622, 2623, 2631, 2631, 2640, 2644, 2672, 2688, 2696, 2698, 2702, 2714, 2716, 2732, 2734, 2768, 2769, 2797, 2819, 2834, 2835, 2835, 2847, 2861, 2875, 2886, 2898, 2900, 2902, 2917, 2942, 2949, 2958, 2963, 2964, 2977, 2999, 3017, 3020, 3075, 3101, 3102, 3106, 3139, 3161, 3181, 3188, 3215, 3260, 3261, 3292, 3293, 3309, 3310, 3313, 3338, 3342, 3361, 3366, 3380, 3390, 3402, 3404, 3438, 3452, 3456, 3466, 3490, 3491, 4882, 4867, 3573, 3624, 3635, 3646, 3720, 3727, 3742, 3773, 3788, 3789, 3820, 3823, 3843, 3870, 3872, 3885, 3885, 3903, 3905, 3905, 3906, 3922, 4024, 4025, 4035, 4075, 4081, 4092, 4118, 4119, 4127, 4156, 4157, 4165, 4196, 4201, 4216, 4250, 4261, 4269, 4269, 4270, 4284, 4359, 4369, 4370, 4408, 4409, 4419, 4474, 4485, 4486, 29, 30, 40, 77, 78, 95, 203, 204, 225, 229, 251, 252, 4901, 274, 277, 297, 314, 4935, 318, 340, 341, 341, 378, 379, 379, 389, 395, 431, 447, 451, 451, 467, 468, 506, 528, 534, 4905, 535, 566, 573, 580, 593, 593, 610, 613, 627, 635, 649, 649, 650, 669, 698, 700, 712, 824, 825, 844, 844, 849, 5004, 5007, 869, 875, 903, 904, 916, 1039, 1057, 1059, 1095, 1104, 1107, 1132, 1133, 1142, 1190, 1212, 1214, 1246, 1273, 1274, 4716, 4721, 5228, 1312, 1315, 1331, 1355, 1358, 1366, 5193, 5194, 5172, 1437, 1438, 1457, 1513, 1530, 1531, 1535, 1552, 4667, 1557, 1586, 1588, 1594, 1612, 1613, 1631, 1647, 1648, 1750, 1751, 5449, 1825, 1847, 1850, 1968, 1988, 1989, 1989, 1991, 2023, 2025, 2039, 2058, 2059, 2092, 2093, 2131, 2140, 2168, 2191, 2217, 2228, 5293, 5294, 5284, 5387, 5414, 5404, 5412, 5324, 5325, 4641, 5420, 5330, 5344, 5308, 5309, 5519, 5465, 5467, 5513, 2310, 2316, 2371, 2372, 2392, 2425, 2440, 2442, 2459, 2462, 2475, 2622, 2631, 2644, 2671, 2672, 2696, 2698, 2714, 2716, 2716, 2734, 2761, 2767, 2768, 2797, 2835, 2861, 2862, 2874, 2875, 2898, 2898, 2900, 2917, 2940, 2942, 2958, 2963, 2977, 2980, 2985, 2999, 3020, 3075, 3102, 3105, 3106, 3161, 3169, 3181, 3215, 3217, 3258, 3260, 3292, 3293, 3310, 3311, 3312, 3313, 3342, 3359, 3361, 3380, 3466, 3491, 3492, 4882, 3573, 3580, 3624, 3646, 3647, 3720, 3742, 3743, 3820, 3843, 3846, 3870, 3885, 3905, 3905, 3922, 3923, 4024, 4035, 4042, 4075, 4092, 4093, 4118, 4127, 4128, 4156, 4165, 4166, 4194, 4196, 4216, 4248, 4250, 4269, 4269, 4284, 4285, 4358, 4359, 4370, 4406, 4408, 4419, 4472, 4474, 4486, 27, 29, 40, 77, 95, 97, 203, 225, 226, 226, 229, 252, 4901, 277, 278, 282, 297, 4935, 318, 341, 379, 418, 431, 451, 451, 468, 469, 500, 506, 534, 4905, 566, 567, 4927, 573, 593, 593, 613, 616, 627, 649, 669, 698, 712, 716, 818, 824, 844, 901, 903, 916, 1039, 1059, 1063, 1094, 1095, 1107, 1132, 1142, 1144, 1189, 1190, 1214, 1239, 1246, 1274, 4716, 5228, 5229, 1312, 1331, 1335, 1355, 1366, 1370, 1437, 1457, 1460, 1512, 1513, 1531, 1534, 1535, 4667, 1591, 1594, 1613, 1631, 1648, 1652, 1749, 1750, 5449, 1824, 1825, 1850, 1968, 1989, 2023, 2025, 2058, 2059, 2131, 2168, 2170, 2190, 2191, 2228, 5293, 5284, 5285, 5385, 5387, 5404, 5412, 5325, 2245, 4641, 5330, 5332, 5513, 2316, 2326, 2371, 2392, 2404, 2459, 2475, 2482, 2621, 2622, 2644, 2657, 2671, 2696, 2696, 2698, 2716, 2716, 2761, 2762, 2766, 2767, 2797, 2835, 2862, 2868, 2871, 2874, 2898, 2898, 2917, 2925, 2940, 2958, 2961, 2985, 3020, 3025, 3075, 3105, 3106, 3106, 3169, 3170, 3242, 3258, 3292, 3312, 3342, 3344, 3350, 3359, 3380, 3624, 3647, 3650, 3719, 3720, 3743, 3820, 3846, 3849, 3870, 3905, 3923, 4015, 4024, 4042, 4075, 4093, 4094, 4117, 4118, 4128, 4155, 4156, 4166, 4193, 4194, 4216, 4244, 4248, 4269, 4358, 4370, 4374, 4472, 4486, 4487, 24, 27, 40, 76, 77, 97, 226, 252, 4898, 282, 4935, 4936, 318, 379, 395, 4905, 567, 570, 4927, 593, 616, 626, 627, 669, 698, 716, 725, 818, 844, 5004, 901, 916, 918, 1038, 1039, 1063, 1127, 1132, 1144, 1188, 1189, 1214, 1298, 1312, 1335, 1353, 1355, 1370, 1433, 1437, 1460, 1512, 1531, 1534, 1591, 1613, 1614, 1748, 1749, 5449, 1823, 1824, 1850, 1967, 1968, 2023, 2024, 2025, 2059, 2131, 2170, 2185, 2190, 2228, 2229, 5385, 5404, 5407, 5412, 2245, 4635, 4641, 5332, 5333, 5513, 2326, 2327, 2458, 2459, 2482, 2607, 2621, 2644, 2696, 2716, 2762, 2762, 2766, 2797, 2835, 2868, 2871, 2871, 2898, 2925, 2932, 2940, 2961, 3075, 3106, 3170, 3242, 3292, 3293, 3312, 3344, 3345, 3613, 3624, 3650, 3719, 3743, 3744, 3869, 3870, 3923, 4015, 4042, 4043, 4074, 4075, 4094, 4117, 4128, 4129, 4154, 4155, 4166, 4193, 4216, 4218, 4357, 4358, 4374, 4472, 4487, 4488, 24, 40, 53, 70, 76, 97, 203, 226, 4898, 281, 282, 4936, 315, 318, 395, 570, 571, 4927, 4928, 4927, 616, 617, 626, 669, 690, 698, 725, 817, 818, 5004, 900, 901, 918, 1038, 1063, 1068, 1125, 1127, 1144, 1188, 1214, 1220, 1289, 1298, 1335, 1353, 1370, 1377, 1433, 1460, 1468, 1508, 1512, 1534, 1590, 1591, 1614, 1747, 1748, 5449, 1821, 1823, 1850, 1967, 2023, 2024, 2024, 2059, 2093, 2131, 2185, 2187, 5273, 5385, 5407, 5411, 5412, 4635, 4641, 5333, 5339, 5513, 2327, 2329, 2453, 2458, 2482, 2607, 2644, 2645, 2657, 2696, 2762, 2835, 2871, 2925, 2932, 2961, 2963, 3075, 3170, 3171, 3242, 3293, 3311, 3613, 3650, 3656, 3718, 3719, 3744, 3861, 3869, 3923, 4010, 4015, 4043, 4114, 4117, 4129, 4154, 4166, 4174, 4192, 4193, 4218, 4350, 4357, 4374, 4471, 4472, 4488, 22, 24, 53, 70, 97, 108, 203, 4898, 4899, 281, 4936, 4933, 315, 395, 397, 570, 4927, 4926, 4928, 616, 617, 617, 669, 672, 690, 725, 731, 817, 5004, 5005, 900, 918, 920, 1125, 1144, 5140, 1179, 1188, 1220, 1289, 1335, 1346, 1350, 1353, 1377, 1590, 1614, 1625, 1747, 5449, 5446, 1808, 1821, 1850, 1967, 2024, 2093, 2131, 2187, 2190, 5273, 5407, 5260, 4633, 5260, 5261, 5410, 5411, 4635, 4641, 5339, 5340, 5513, 2329, 2332, 2453, 2482, 2491, 2657, 2762, 2797, 2835, 2925, 2926, 2932, 2963, 2980, 3242, 3311, 3312, 3718, 3744, 3749, 3859, 3861, 3923, 4010, 4043, 4054, 4114, 4129, 4133, 4150, 4154, 4174, 4190, 4192, 4218, 4350, 4374, 4375, 4471, 4488, 4490, 21, 22, 53, 70, 108, 109, 201, 203, 4899, 281, 4933, 4934, 4905, 570, 4926, 4928, 617, 672, 689, 690, 731, 899, 900, 920, 1109, 1125, 5140, 1167, 1179, 1220, 5244, 1289, 1346, 1350, 1377, 1381, 1589, 1590, 1625, 1808, 1850, 1861, 1964, 1967, 2093, 2105, 2131, 2190, 4641, 5340, 5343, 5513, 2332, 2333, 2453, 2491, 2494, 2653, 2657, 2797, 2930, 2932, 2980, 3242, 3312, 3345, 3716, 3718, 3749, 4008, 4010, 4054, 4113, 4114, 4133, 4150, 4174, 4184, 4187, 4190, 4218, 4350, 4375, 4376, 4470, 4471, 4490, 19, 21, 53, 70, 109, 4851, 201, 4899, 4901, 4905, 4926, 4928, 4928, 672, 675, 688, 689, 731, 899, 920, 921, 1167, 1220, 1227, 5244, 1346, 1348, 5147, 1350, 1381, 1589, 1625, 1628, 1808, 1861, 1863, 2105, 2190, 2229, 4641, 5343, 5344, 2453, 2494, 2499, 2653, 2797, 2814, 2928, 2930, 2980, 3716, 3749, 3756, 4007, 4008, 4054, 4111, 4113, 4133, 4149, 4150, 4184, 4187, 4218, 4219, 4350, 4376, 4377, 4470, 4490, 4492, 18, 19, 53, 200, 201, 4901, 4905, 4928, 675, 686, 688, 731, 897, 899, 921, 1166, 1167, 1227, 1808, 1863, 1864, 2452, 2453, 2499, 2647, 2653, 2814, 2928, 2980, 2984, 3715, 3716, 3756, 4006, 4007, 4054, 4111, 4133, 4135, 4149, 4184, 4186, 4343, 4350, 4377, 4467, 4470, 4492, 18, 53, 64, 200, 4901, 278, 4904, 4905, 675, 686, 731, 738, 897, 921, 922, 1164, 1166, 1227, 1796, 1808, 1864, 2452, 2499, 2511, 2926, 2928, 2984, 3710, 3715, 3756, 4006, 4054, 4056, 4111, 4135, 4137, 4343, 4377, 4386, 4467, 4492, 4493, 11, 18, 64, 199, 200, 278, 4904, 675, 682, 684, 686, 738, 897, 922, 923, 1164, 1227, 1232, 1796, 1864, 1871, 2442, 2452, 2511, 2926, 2984, 2985, 3710, 3756, 3757, 3998, 4006, 4056, 4101, 4111, 4137, 4341, 4343, 4386, 4467, 4493, 4496, 11, 64, 66, 198, 199, 278, 4904, 682, 684, 684, 738, 739, 895, 897, 923, 1162, 1164, 1232, 1796, 1871, 1872, 2425, 2442, 2511, 2835, 2926, 2985, 3708, 3710, 3757, 4101, 4137, 4140, 4326, 4341, 4386, 4466, 4467, 4496, 197, 198, 278, 4904, 684, 739, 894, 895, 923, 1791, 1796, 1872, 2425, 2511, 2547, 2835, 2985, 3025, 4094, 4101, 4140, 4466, 4496, 4498, 197, 278, 281, 4904, 739, 749, 894, 923, 927, 1791, 1872, 1873, 2418, 2425, 2547, 4094, 4140, 4147, 4974, 4466, 4498, 197, 281, 4934, 4904, 749, 757, 893, 894, 927, 1791, 1873, 1874, 2417, 2418, 2547, 4074, 4094, 4147, 4973, 4974, 4498, 893, 927, 930, 1788, 1791, 1874, 4070, 4074, 4147, 4973, 4498, 4499, 888, 893, 930, 1782, 1788, 1874, 4070, 4147, 4148, 4463, 4973, 4499, 888, 930, 931, 1781, 1782, 1874, 4070, 4148, 4149, 4463, 4499, 4501, 885, 888, 931, 1781, 1874, 1875, 4070, 4149, 4186, 4461, 4463, 4501, 885, 931, 935, 1781, 1875, 1883, 4069, 4070, 4186, 4461, 4501, 4503, 878, 885, 935, 1781, 1883, 1886, 4069, 4186, 4187, 4460, 4461, 4503, 878, 935, 942, 1780, 1781, 1886, 4069, 4187, 4219, 4460, 4503, 4505, 878, 942, 943, 1780, 1886, 1918, 4456, 4460, 4505, 876, 878, 943, 1779, 1780, 1918, 4456, 4505, 4507, 875, 876, 943, 1777, 1779, 1918, 4453, 4456, 4507, 875, 943, 950, 1775, 1777, 1918, 4453, 4507, 4508, 875, 950, 952, 1775, 1918, 1940, 4968, 4453, 4508, 5007, 875, 952, 5449, 1775, 1940, 4968, 4508, 4511, 5007, 952, 965, 5449, 1940, 1942, 4959, 4968, 4511, 5007, 965, 979, 5447, 5449, 1942, 4959, 4511, 4516, 5006, 5007, 979, 5447, 1942, 1951, 4959, 4516, 4517, 5005, 5006, 979, 5447, 1951, 1953, 5005, 979, 980, 5446, 5447, 1953, 5005, 980, 981, 1747, 5446, 1953, 817, 5005, 981, 1747, 1953, 1963, 816, 817, 981, 1747, 1963, 1964, 2096, 2098, 2101, 2413, 2414, 2417, 775, 776, 781, 1021, 1022, 1027, 1487, 1493, 1495, 2094, 2096, 2101, 2407, 2413, 2417, 773, 775, 781, 1010, 1021, 1027, 1487, 1495, 1497, 2093, 2094, 2101, 2404, 2407, 2417, 771, 773, 781, 1010, 1027, 1029, 1476, 1487, 1497, 2093, 2101, 2102, 2404, 2417, 2547, 770, 771, 781, 1010, 1029, 1031, 1476, 1497, 1508, 1964, 2093, 2102, 2404, 2547, 2548, 770, 781, 788, 1010, 1031, 1032, 1476, 1508, 1534, 2404, 2548, 2553, 768, 770, 788, 1010, 1032, 1037, 1475, 1476, 1534, 2371, 2404, 2553, 765, 768, 788, 1009, 1010, 1037, 1475, 1534, 4667, 2371, 2553, 2554, 765, 788, 789, 1000, 1009, 1037, 1473, 1475, 4667, 998, 1000, 1037, 1469, 1473, 4667, 998, 1037, 1038, 998, 1038, 1068, 998, 1068, 1074, 998, 1074, 1075, 1090, 1091, 1107, 1160, 1161, 1232, 1384, 1385, 1387, 1394, 1395, 1399, 1655, 1658, 1667, 1690, 1692, 1694, 1722, 1724, 1726, 1738, 1739, 1743, 5260, 4633, 5256, 5410, 4635, 5045, 2284, 2285, 2288, 2341, 2369, 2371, 2605, 2606, 2645, 3347, 3348, 3350, 3495, 3496, 3498, 3511, 3520, 3526, 3699, 3700, 3703, 4388, 4389, 4392, 4959, 4517, 4521, 4523, 4524, 4526, 4561, 4563, 4566, 4592, 4594, 4597, 8, 9, 11, 126, 130, 137, 148, 151, 158, 402, 403, 405, 471, 472, 478, 499, 500, 4904, 759, 763, 765, 799, 800, 803, 991, 993, 996, 1089, 1090, 1107, 1157, 1160, 1232, 1384, 1387, 1388, 1394, 1399, 1404, 1655, 1667, 1670, 1690, 1694, 1695, 1721, 1722, 1726, 1738, 1743, 1745, 5273, 5260, 5256, 5257, 5410, 5045, 5042, 5043, 5044, 5044, 2245, 4637, 2283, 2284, 2288, 2333, 2341, 2371, 2605, 2645, 2646, 3495, 3498, 3499, 3508, 3511, 3526, 3696, 3699, 3703, 4387, 4388, 4392, 4958, 4959, 4521, 4521, 4523, 4526, 4559, 4561, 4566, 4588, 4592, 4597, 6, 8, 11, 126, 137, 138, 148, 158, 159, 397, 402, 405, 471, 478, 482, 490, 499, 4904, 759, 765, 789, 791, 799, 803, 990, 991, 996, 1079, 1089, 1107, 1157, 1232, 1239, 1381, 1384, 1388, 1393, 1394, 1404, 1654, 1655, 1670, 1689, 1690, 1695, 1721, 1726, 1727, 1738, 1745, 1747, 5273, 5256, 5257, 5257, 5045, 5047, 2283, 2288, 5507, 2333, 2371, 2554, 2605, 2646, 2647, 3494, 3495, 3499, 3508, 3526, 3527, 3696, 3703, 3704, 4387, 4392, 4393, 4958, 4521, 4526, 4559, 4566, 4567, 4588, 4597, 4600, 6, 11, 66, 117, 126, 138, 138, 148, 159, 397, 405, 406, 470, 471, 482, 490, 4904, 757, 758, 759, 789, 791, 803, 804, 989, 990, 996, 1079, 1107, 1109, 1157, 1239, 1274, 1381, 1388, 1392, 1654, 1670, 1675, 1680, 1689, 1695, 1721, 1727, 1728, 1732, 1738, 1747, 5271, 5273, 5257, 5257, 5047, 5049, 5513, 2333, 2554, 2582, 2605, 2647, 3494, 3499, 3502, 3507, 3508, 3527, 3693, 3696, 3704, 4386, 4387, 4393, 4957, 4958, 4526, 4981, 4559, 4567, 4587, 4588, 4600, 138, 159, 181, 315, 397, 406, 469, 470, 482, 487, 490, 757, 791, 804, 806, 989, 996, 998, 1076, 1079, 1109, 1156, 1157, 1274, 5147, 1381, 1392, 1652, 1654, 1675, 1717, 1721, 1728, 1732, 1747, 1964, 5271, 5257, 5049, 5512, 5513, 2554, 2582, 2647, 2814, 3492, 3494, 3502, 4326, 4386, 4393, 4957, 4526, 4529, 4978, 4981, 4567, 4587, 4600, 4, 117, 138, 181, 315, 406, 418, 486, 487, 757, 789, 791, 806, 984, 989, 998, 1075, 1076, 1109, 1151, 1156, 1274, 5147, 1392, 1393, 1652, 1675, 1678, 1717, 1728, 1730, 5271, 5049, 5050, 5529, 5512, 2554, 2575, 2582, 2814, 3492, 3502, 3503, 4326, 4393, 4402, 4960, 4957, 4529, 4978, 4567, 4570, 4585, 4587, 4, 117, 181, 184, 315, 418, 451, 482, 486, 757, 789, 806, 807, 984, 998, 1075, 1075, 1109, 5140, 5142, 1151, 1274, 5147, 1393, 1404, 1631, 1652, 1678, 1717, 1730, 1731, 5271, 5050, 5051, 5507, 5529, 2554, 2574, 2575, 2814, 3466, 3492, 3503, 4325, 4326, 4402, 4960, 4529, 4530, 4978, 4570, 4585, 4585, 4, 6, 4842, 117, 184, 4934, 315, 451, 758, 789, 807, 983, 984, 1075, 5142, 1274, 4716, 1631, 1678, 1680, 1713, 1717, 1731, 5436, 1731, 1732, 5271, 5051, 5052, 5507, 2554, 2564, 3465, 3466, 3503, 4325, 4402, 4405, 4960, 4530, 4532, 4986, 4978, 4585, 4842, 184, 4782, 4934, 451, 469, 982, 983, 1075, 5141, 5142, 4716, 5442, 1713, 1731, 5436, 1732, 1964, 5507, 2564, 2571, 3465, 3503, 3504, 4322, 4325, 4405, 4447, 4960, 4532, 4854, 4842, 4782, 4934, 469, 482, 981, 982, 1075, 5141, 4716, 5229, 5436, 1964, 2102, 2283, 5507, 2571, 3465, 3504, 3506, 4322, 4405, 4406, 4447, 4532, 4539, 4853, 4854, 4782, 197, 4934, 482, 981, 1075, 5140, 5141, 5229, 5212, 5442, 1731, 5436, 5435, 5436, 2102, 2283, 2571, 2573, 4316, 4322, 4406, 4445, 4447, 4539, 4852, 4853, 4782, 189, 197, 482, 816, 981, 5140, 1680, 1695, 5442, 5442, 5443, 5439, 5441, 5442, 5436, 5434, 5435, 2102, 2283, 2573, 2574, 4313, 4316, 4406, 4445, 4539, 4540, 4851, 4852, 4782, 189, 482, 757, 808, 816, 5140, 1680, 5442, 5439, 5440, 5441, 5436, 2283, 2574, 2814, 4313, 4406, 4419, 4445, 4540, 4542, 4851, 4782, 4783, 189, 757, 758, 807, 808, 5140, 1631, 1680, 5439, 5440, 5436, 5434, 5478, 2283, 2814, 4312, 4313, 4419, 4851, 4783, 4784, 189, 758, 807, 1629, 1631, 5439, 5478, 2814, 2819, 4311, 4312, 4419, 189, 807, 5140, 1629, 5439, 5440, 2278, 5478, 5477, 5479, 5478, 2819, 4311, 4419, 4421, 1629, 5440, 5434, 2278, 5477, 5479, 5479, 2819, 2835, 4294, 4311, 4421, 1628, 1629, 5434, 5479, 2835, 3025, 4294, 4421, 4423, 1589, 1628, 5434, 2278, 5479, 3025, 4294, 4423, 4424, 1588, 1589, 5434, 2278, 3025, 3028, 4294, 4424, 4425, 1588, 5434, 2102, 2278, 3028, 3029, 4285, 4294, 4425, 1557, 1588, 2102, 4740, 2102, 2103, 2278, 3029, 3030, 4285, 4425, 4427, 4740, 2103, 2105, 5489, 2278, 3030, 4285, 4427, 4431, 1557, 2102, 4740, 4740, 4735, 4727, 4733, 4740, 2105, 5489, 3030, 3039, 1557, 4740, 4727, 4733, 2105, 2229, 5489, 3039, 3040, 4729, 4733, 2229, 5489, 3040, 3044, 4728, 4729, 2229, 5467, 5489, 3044, 4727, 4728, 2229, 5467, 3044, 3045, 1557, 4727, 2229, 5519, 5467, 3045, 4666, 1557, 2229, 5519, 3045, 3061, 4666, 2229, 2231, 5518, 5519, 3061, 4665, 4666, 2231, 5517, 5518, 3061, 4665, 2231, 2240, 5517, 3061, 3065, 4665, 2240, 5293, 5516, 5517, 3065, 4668, 4665, 5293, 5516, 3065, 3075, 4667, 4668, 5293, 1469, 4667, 5293, 5285, 3220, 3221, 5293, 5285, 3221, 5293, 3221, 3222, 3227, 5068, 5081, 3223, 3227, 5081, 5293, 3222, 5095, 5293, 5095, 5096, 1469, 5293, 5096, 5096, 5131, 5237, 5096, 5237, 5238, 5096, 5238, 5239, 1469, 5096, 5239, 1469, 5239, 5110, 1469, 5110, 1406, 1433, 1469, 1406, 1433, 1406, 1408, 1432, 1433, 1408, 1429, 1432, 1408, 1429, 1408, 1410, 1429, 1410, 1411, 1427, 1429, 1411, 1427, 1411, 1413, 1427, 1413, 1415, 1423, 1427, 1415, 1421, 1423, 1415, 1421, 1415, 1417, 1420, 1421, 1417, 1419, 1420, 1417, 5237, 5131, 5132, 5132, 5133, 5239, 5237, 5132, 5239, 5068, 3227, 3229, 1404, 1405, 1406, 5068, 3229, 3231, 1404, 1406, 5111, 5066, 5068, 3231, 1404, 5111, 5112, 5122, 5066, 3231, 1404, 5112, 5119, 1404, 5119, 5120, 5147, 1404, 5120, 4711, 5120, 5174, 5172, 5174, 5120, 5120, 5121, 5122, 5147, 5120, 4709, 4711, 5174, 5175, 5172, 5120, 5122, 5244, 1348, 5147, 5146, 5147, 4709, 5206, 4711, 5175, 5172, 5122, 3231, 5212, 1288, 5244, 5243, 5244, 5147, 5145, 5146, 4709, 5206, 5175, 5178, 5193, 5172, 3231, 5212, 5244, 5242, 5242, 5243, 5147, 5145, 4709, 5205, 5205, 5206, 5178, 5193, 3231, 3238, 5141, 5212, 5242, 5242, 5147, 5145, 5205, 5178, 5181, 5198, 5193, 3238, 5141, 5242, 5145, 5145, 5205, 5181, 5198, 3238, 3240, 5141, 5145, 5181, 5197, 5198, 3240, 5140, 5141, 5181, 5197, 3240, 3241, 5140, 5181, 5182, 5188, 5197, 3241, 5140, 5182, 5187, 5188, 3241, 3242, 5187, 5188, 3242, 5140, 5187, 3242, 5140, 3242, 3345, 189, 5140, 3345, 189, 3345, 3346, 189, 3346, 3347, 3350, 3389, 3390, 3350, 3390, 3404, 3347, 3350, 3404, 3347, 3404, 3405, 189, 3347, 3405, 189, 3405, 3411, 189, 3411, 3412, 5639, 5638, 3412, 3412, 3413, 3414, 3415, 3421, 3422, 3426, 3422, 5632, 5630, 5633, 3424, 3424, 3425, 3426, 3426, 5632, 5631, 3422, 3426, 3427, 3415, 3422, 3427, 3415, 3427, 3438, 3415, 3438, 3456, 3414, 3415, 3456, 3412, 3414, 3456, 3412, 3456, 3464, 3464, 3465, 189, 3412, 3464, 189, 189, 3465, 3506, 189, 3506, 3507, 189, 3507, 3527, 189, 3527, 3528, 189, 3528, 3535, 4782, 189, 3535, 4752, 4753, 3535, 4780, 4782, 3535, 4752, 3535, 3536, 4780, 3535, 4754, 4752, 3536, 4790, 4773, 4780, 4754, 4751, 4752, 4790, 4787, 4773, 4754, 4754, 4751, 4790, 4787, 4754, 4790, 4787, 4790, 4759, 4787, 4759, 4760, 4787, 4760, 4882, 4787, 4882, 3580, 4784, 4786, 3587, 4851, 4784, 3587, 4851, 3587, 3599, 4851, 3599, 3600, 4851, 3600, 3601, 4851, 3601, 3602, 4851, 3602, 3604, 4851, 3604, 3605, 3606, 4813, 4814, 3606, 4814, 3657, 3605, 3606, 3657, 3605, 3657, 3658, 3675, 3708, 3757, 3658, 3675, 3757, 3605, 3658, 3757, 4851, 3605, 3757, 4851, 3757, 3758, 4851, 3758, 3771, 4851, 3771, 3773, 70, 4851, 3773, 70, 3773, 3789, 68, 70, 3789, 68, 3789, 3791, 68, 3791, 3795, 67, 68, 3795, 67, 3795, 3798, 67, 3798, 3802, 3804, 3853, 3856, 3802, 3804, 3856, 3802, 3856, 3858, 3859, 3998, 4056, 3858, 3859, 4056, 3802, 3858, 4056, 3802, 4056, 4067, 3802, 4067, 4069, 67, 3802, 4069, 67, 4069, 4219, 66, 67, 4219, 6, 66, 4219, 6, 4219, 4240, 4585, 6, 4240, 4585, 4240, 4241, 4585, 4241, 4243, 4986, 4585, 4243, 4986, 4243, 4244, 4986, 4244, 4269, 4985, 4986, 4269, 4985, 4269, 4285, 4554, 4985, 4285, 4554, 4285, 4431, 4553, 4554, 4431, 4553, 4431, 4435, 4553, 4435, 4436, 4549, 4553, 4436, 4549, 4436, 4437, 4549, 4437, 4438, 4549, 4438, 4952, 4549, 4952, 4953, 4547, 4549, 4953, 4547, 4953, 4949, 4547, 4949, 4950, 4543, 4547, 4950, 4542, 4543, 4950, 4445, 4542, 4950, 4445, 4950, 4951, 4445, 4951, 4439, 4444, 4445, 4439, 4443, 4444, 4439, 3998, 3859, 3923, 3996, 3998, 3923, 3995, 3996, 3923, 3995, 3923, 3924, 4605, 3995, 3924, 4605, 3924, 3926, 4603, 4605, 3926, 4603, 3926, 3927, 4603, 3927, 3930, 3989, 4603, 3930, 3989, 3930, 3935, 3985, 3989, 3935, 3985, 3935, 3936, 3984, 3985, 3936, 3984, 3936, 3937, 3983, 3984, 3937, 3983, 3937, 3939, 3974, 3983, 3939, 3974, 3939, 3942, 3974, 3942, 3945, 3973, 3974, 3945, 3968, 3973, 3945, 3968, 3945, 3949, 3967, 3968, 3949, 3967, 3949, 3950, 3966, 3967, 3950, 3963, 3966, 3950, 3963, 3950, 3951, 3963, 3951, 3952, 3961, 3963, 3952, 3961, 3952, 3953, 4744, 3961, 3953, 3961, 4744, 4745, 4743, 4744, 3953, 3960, 3961, 4745, 4743, 3953, 3954, 3959, 3960, 4745, 4745, 4743, 3954, 3954, 3959, 4745, 3853, 3804, 3812, 3850, 3853, 3812, 3820, 3849, 3850, 4615, 3850, 3812, 3820, 3850, 4615, 4615, 3812, 3818, 3818, 3820, 4615, 3708, 3675, 3676, 3706, 3708, 3676, 3704, 3706, 3676, 3693, 3704, 3676, 3693, 3676, 3679, 3689, 3693, 3679, 3689, 3679, 3682, 3682, 3683, 3685, 3686, 3689, 3682, 4813, 3606, 3610, 3656, 3657, 4814, 4812, 4813, 3610, 3656, 4814, 4805, 4812, 3610, 3611, 3656, 4805, 4806, 4809, 4812, 3611, 3613, 3656, 4806, 4809, 3611, 3613, 3613, 4806, 4809, 3421, 3415, 3419, 3419, 3420, 3421, 3389, 3350, 3380, 3389, 3380, 3381, 3387, 3389, 3381, 3387, 3381, 3382, 3386, 3387, 3382, 3385, 3386, 3382, 3220, 5285, 5276, 3218, 3220, 5276, 3218, 5276, 5271, 3217, 3218, 5271, 3181, 3217, 5271, 3181, 5271, 5052, 3180, 3181, 5052, 5052, 5055, 5056, 3180, 5052, 5056, 3180, 5056, 5039, 3179, 3180, 5039, 3178, 3179, 5039, 3171, 3178, 5039, 3171, 5039, 5041, 3075, 3171, 5041, 3075, 5041, 5042, 5027, 5516, 3075, 5344, 5027, 3075, 5344, 3075, 5042, 4641, 5344, 5042, 5516, 5027, 5032, 5516, 5032, 5033, 5455, 5516, 5033, 5473, 5455, 5033, 5473, 5033, 2260, 5472, 5473, 2260, 5471, 5472, 2260, 5471, 2260, 5493, 5496, 5497, 2263, 5471, 5493, 5494, 5495, 5496, 2263, 5469, 5471, 5494, 5495, 2263, 5469, 5469, 5494, 5495, 5027, 5344, 5309, 5026, 5027, 5315, 5314, 5315, 5027, 5026, 5315, 5316, 5314, 5027, 5309, 2256, 5026, 5316, 5313, 5314, 5309, 5309, 2256, 5316, 5316, 5313, 5309, 5055, 5053, 5054];
 testOutput["water_huge"]["pass"]=true;
 testOutput["water_huge"]["report"]="exp numtri:5174\nexp dev:0.0011\nact numtri:5174\nact dev:0.0010700549959386028";
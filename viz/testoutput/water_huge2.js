@@ -1,5 +1,5 @@
 testOutput["water_huge2"]=[];
 testOutput["water_huge2"]["json"]=[[[-128.0, 4224.0], [-128.0, 2734.0], [-116.0, 2736.0], [-109.0, 2749.0], [-99.0, 2750.0], [-101.0, 2734.0], [-96.0, 2724.0], [-90.0, 2708.0], [-76.0, 2704.0], [-61.0, 2690.0], [-51.0, 2691.0], [-40.0, 2674.0], [-40.0, 2660.0], [-29.0, 2647.0], [-19.0, 2647.0], [-11.0, 2643.0], [-13.0, 2652.0], [-15.0, 2671.0], [-15.0, 2679.0], [-23.0, 2679.0], [-27.0, 2696.0], [-37.0, 2706.0], [-36.0, 2715.0], [-45.0, 2708.0], [-45.0, 2720.0], [-43.0, 2732.0], [-46.0, 2745.0], [-34.0, 2757.0], [-54.0, 2764.0], [-62.0, 2769.0], [-62.0, 2786.0], [-69.0, 2800.0], [-72.0, 2824.0], [-77.0, 2838.0], [-82.0, 2867.0], [-86.0, 2884.0], [-108.0, 2908.0], [-101.0, 2919.0], [-85.0, 2911.0], [-72.0, 2897.0], [-70.0, 2880.0], [-60.0, 2880.0], [-59.0, 2873.0], [-48.0, 2873.0], [-46.0, 2859.0], [-37.0, 2860.0], [-28.0, 2850.0], [-17.0, 2842.0], [-17.0, 2834.0], [-4.0, 2825.0], [8.0, 2825.0], [10.0, 2817.0], [12.0, 2799.0], [4.0, 2800.0], [8.0, 2797.0], [8.0, 2787.0], [22.0, 2796.0], [32.0, 2788.0], [52.0, 2801.0], [67.0, 2781.0], [78.0, 2747.0], [95.0, 2732.0], [97.0, 2720.0], [119.0, 2709.0], [109.0, 2695.0], [117.0, 2701.0], [114.0, 2689.0], [123.0, 2680.0], [128.0, 2670.0], [117.0, 2666.0], [120.0, 2654.0], [122.0, 2669.0], [131.0, 2668.0], [141.0, 2666.0], [143.0, 2676.0], [149.0, 2684.0], [148.0, 2692.0], [160.0, 2698.0], [152.0, 2698.0], [130.0, 2699.0], [133.0, 2717.0], [127.0, 2722.0], [118.0, 2722.0], [119.0, 2743.0], [102.0, 2760.0], [93.0, 2761.0], [83.0, 2772.0], [96.0, 2790.0], [116.0, 2800.0], [104.0, 2800.0], [85.0, 2802.0], [76.0, 2799.0], [80.0, 2809.0], [47.0, 2819.0], [31.0, 2818.0], [19.0, 2846.0], [21.0, 2851.0], [41.0, 2851.0], [61.0, 2850.0], [74.0, 2856.0], [77.0, 2865.0], [93.0, 2877.0], [100.0, 2895.0], [115.0, 2898.0], [138.0, 2890.0], [139.0, 2882.0], [139.0, 2890.0], [148.0, 2891.0], [170.0, 2891.0], [182.0, 2885.0], [197.0, 2894.0], [217.0, 2897.0], [193.0, 2896.0], [182.0, 2889.0], [152.0, 2899.0], [136.0, 2894.0], [113.0, 2905.0], [127.0, 2910.0], [137.0, 2919.0], [158.0, 2924.0], [167.0, 2932.0], [155.0, 2927.0], [143.0, 2927.0], [133.0, 2926.0], [123.0, 2914.0], [101.0, 2909.0], [94.0, 2896.0], [83.0, 2909.0], [91.0, 2914.0], [89.0, 2929.0], [81.0, 2939.0], [84.0, 2926.0], [89.0, 2915.0], [82.0, 2904.0], [92.0, 2893.0], [76.0, 2887.0], [74.0, 2877.0], [63.0, 2878.0], [54.0, 2880.0], [63.0, 2877.0], [49.0, 2869.0], [40.0, 2879.0], [40.0, 2887.0], [30.0, 2887.0], [22.0, 2892.0], [16.0, 2873.0], [1.0, 2873.0], [-13.0, 2873.0], [-27.0, 2891.0], [-20.0, 2901.0], [-1.0, 2907.0], [-10.0, 2918.0], [-19.0, 2910.0], [-33.0, 2903.0], [-43.0, 2895.0], [-57.0, 2922.0], [-60.0, 2940.0], [-69.0, 2949.0], [-85.0, 2954.0], [-95.0, 2970.0], [-99.0, 2987.0], [-88.0, 2988.0], [-75.0, 2988.0], [-79.0, 2998.0], [-93.0, 2998.0], [-94.0, 2990.0], [-101.0, 2998.0], [-106.0, 2989.0], [-128.0, 3002.0], [-128.0, 4224.0], [-128.0, 4224.0], [1249.0, 4224.0], [1247.0, 4199.0], [1231.0, 4132.0], [1221.0, 4130.0], [1230.0, 4148.0], [1214.0, 4165.0], [1198.0, 4171.0], [1162.0, 4169.0], [1134.0, 4155.0], [1091.0, 4128.0], [1068.0, 4096.0], [1025.0, 4052.0], [989.0, 4001.0], [924.0, 3927.0], [909.0, 3902.0], [904.0, 3882.0], [908.0, 3871.0], [909.0, 3858.0], [911.0, 3830.0], [905.0, 3799.0], [868.0, 3727.0], [827.0, 3685.0], [799.0, 3668.0], [787.0, 3655.0], [784.0, 3642.0], [782.0, 3582.0], [771.0, 3537.0], [765.0, 3481.0], [767.0, 3441.0], [786.0, 3409.0], [789.0, 3381.0], [785.0, 3371.0], [786.0, 3361.0], [776.0, 3339.0], [777.0, 3314.0], [770.0, 3305.0], [775.0, 3278.0], [734.0, 3220.0], [728.0, 3203.0], [729.0, 3178.0], [708.0, 3167.0], [695.0, 3147.0], [667.0, 3134.0], [666.0, 3126.0], [651.0, 3121.0], [645.0, 3113.0], [650.0, 3104.0], [630.0, 3089.0], [616.0, 3074.0], [615.0, 3053.0], [607.0, 3047.0], [607.0, 3035.0], [599.0, 3034.0], [603.0, 3026.0], [575.0, 2980.0], [532.0, 2939.0], [524.0, 2926.0], [509.0, 2903.0], [501.0, 2865.0], [509.0, 2844.0], [509.0, 2831.0], [526.0, 2809.0], [531.0, 2794.0], [540.0, 2718.0], [552.0, 2713.0], [543.0, 2711.0], [551.0, 2705.0], [537.0, 2701.0], [512.0, 2675.0], [503.0, 2708.0], [492.0, 2717.0], [502.0, 2705.0], [510.0, 2671.0], [493.0, 2656.0], [463.0, 2663.0], [485.0, 2654.0], [471.0, 2627.0], [474.0, 2614.0], [470.0, 2603.0], [475.0, 2588.0], [483.0, 2583.0], [486.0, 2573.0], [511.0, 2538.0], [550.0, 2523.0], [553.0, 2514.0], [561.0, 2507.0], [582.0, 2472.0], [595.0, 2460.0], [597.0, 2452.0], [605.0, 2447.0], [622.0, 2409.0], [638.0, 2357.0], [654.0, 2337.0], [663.0, 2311.0], [687.0, 2278.0], [718.0, 2256.0], [729.0, 2254.0], [739.0, 2249.0], [760.0, 2240.0], [776.0, 2227.0], [789.0, 2219.0], [786.0, 2213.0], [797.0, 2213.0], [819.0, 2191.0], [855.0, 2170.0], [865.0, 2163.0], [884.0, 2150.0], [897.0, 2147.0], [889.0, 2134.0], [898.0, 2146.0], [906.0, 2143.0], [907.0, 2126.0], [909.0, 2137.0], [917.0, 2138.0], [934.0, 2145.0], [958.0, 2142.0], [989.0, 2146.0], [996.0, 2166.0], [971.0, 2170.0], [967.0, 2181.0], [953.0, 2181.0], [933.0, 2170.0], [900.0, 2180.0], [888.0, 2187.0], [883.0, 2196.0], [868.0, 2204.0], [859.0, 2204.0], [872.0, 2219.0], [873.0, 2234.0], [884.0, 2226.0], [874.0, 2236.0], [876.0, 2244.0], [867.0, 2229.0], [870.0, 2221.0], [861.0, 2209.0], [842.0, 2213.0], [833.0, 2218.0], [812.0, 2228.0], [811.0, 2242.0], [796.0, 2258.0], [760.0, 2272.0], [740.0, 2289.0], [726.0, 2295.0], [704.0, 2319.0], [693.0, 2326.0], [689.0, 2349.0], [672.0, 2382.0], [671.0, 2415.0], [653.0, 2417.0], [643.0, 2443.0], [629.0, 2448.0], [623.0, 2457.0], [624.0, 2473.0], [613.0, 2512.0], [588.0, 2545.0], [576.0, 2549.0], [567.0, 2563.0], [534.0, 2585.0], [534.0, 2596.0], [547.0, 2625.0], [556.0, 2624.0], [571.0, 2640.0], [589.0, 2624.0], [573.0, 2642.0], [581.0, 2642.0], [584.0, 2651.0], [607.0, 2634.0], [615.0, 2638.0], [602.0, 2645.0], [596.0, 2658.0], [602.0, 2665.0], [610.0, 2665.0], [621.0, 2679.0], [620.0, 2695.0], [609.0, 2721.0], [589.0, 2747.0], [598.0, 2758.0], [594.0, 2808.0], [600.0, 2837.0], [583.0, 2842.0], [579.0, 2818.0], [575.0, 2830.0], [575.0, 2880.0], [584.0, 2898.0], [626.0, 2901.0], [630.0, 2912.0], [639.0, 2919.0], [653.0, 2920.0], [672.0, 2937.0], [683.0, 2934.0], [700.0, 2968.0], [706.0, 2979.0], [705.0, 2970.0], [711.0, 2986.0], [740.0, 3032.0], [748.0, 3038.0], [757.0, 3034.0], [769.0, 3035.0], [773.0, 3044.0], [788.0, 3053.0], [798.0, 3089.0], [824.0, 3100.0], [833.0, 3112.0], [844.0, 3105.0], [830.0, 3099.0], [837.0, 3087.0], [847.0, 3084.0], [845.0, 3072.0], [856.0, 3070.0], [858.0, 3062.0], [848.0, 3056.0], [851.0, 3048.0], [833.0, 3037.0], [852.0, 3047.0], [860.0, 3043.0], [877.0, 3052.0], [877.0, 3042.0], [881.0, 3028.0], [893.0, 3034.0], [893.0, 3046.0], [901.0, 3029.0], [914.0, 3030.0], [921.0, 3045.0], [938.0, 3041.0], [942.0, 3054.0], [948.0, 3043.0], [962.0, 3052.0], [962.0, 3043.0], [973.0, 3041.0], [974.0, 3050.0], [984.0, 3045.0], [988.0, 3056.0], [996.0, 3049.0], [1004.0, 3055.0], [1014.0, 3049.0], [1023.0, 3049.0], [1034.0, 3045.0], [1032.0, 3033.0], [1034.0, 3013.0], [1025.0, 3007.0], [1025.0, 2977.0], [1030.0, 2990.0], [1029.0, 3006.0], [1037.0, 3003.0], [1037.0, 3012.0], [1035.0, 3042.0], [1043.0, 3045.0], [1023.0, 3053.0], [1000.0, 3061.0], [997.0, 3053.0], [984.0, 3057.0], [982.0, 3047.0], [973.0, 3052.0], [967.0, 3040.0], [965.0, 3053.0], [950.0, 3048.0], [958.0, 3062.0], [947.0, 3057.0], [933.0, 3059.0], [929.0, 3047.0], [916.0, 3045.0], [915.0, 3034.0], [901.0, 3032.0], [902.0, 3040.0], [897.0, 3048.0], [881.0, 3038.0], [885.0, 3049.0], [880.0, 3055.0], [871.0, 3055.0], [860.0, 3046.0], [857.0, 3055.0], [857.0, 3074.0], [847.0, 3073.0], [850.0, 3086.0], [834.0, 3096.0], [846.0, 3101.0], [846.0, 3110.0], [858.0, 3106.0], [868.0, 3108.0], [890.0, 3123.0], [899.0, 3119.0], [910.0, 3120.0], [915.0, 3130.0], [901.0, 3128.0], [904.0, 3137.0], [917.0, 3140.0], [909.0, 3145.0], [901.0, 3139.0], [894.0, 3130.0], [892.0, 3139.0], [909.0, 3182.0], [946.0, 3217.0], [952.0, 3207.0], [947.0, 3195.0], [961.0, 3188.0], [954.0, 3175.0], [957.0, 3156.0], [949.0, 3157.0], [933.0, 3163.0], [923.0, 3153.0], [930.0, 3143.0], [934.0, 3156.0], [943.0, 3148.0], [943.0, 3140.0], [949.0, 3156.0], [957.0, 3155.0], [959.0, 3141.0], [968.0, 3139.0], [961.0, 3121.0], [973.0, 3118.0], [971.0, 3094.0], [973.0, 3104.0], [987.0, 3095.0], [974.0, 3106.0], [976.0, 3115.0], [988.0, 3104.0], [997.0, 3103.0], [989.0, 3107.0], [988.0, 3116.0], [976.0, 3116.0], [966.0, 3131.0], [987.0, 3135.0], [979.0, 3132.0], [984.0, 3124.0], [993.0, 3137.0], [1001.0, 3135.0], [971.0, 3133.0], [971.0, 3141.0], [961.0, 3146.0], [960.0, 3155.0], [961.0, 3169.0], [966.0, 3181.0], [953.0, 3195.0], [976.0, 3193.0], [1004.0, 3203.0], [1001.0, 3194.0], [1014.0, 3189.0], [1007.0, 3197.0], [997.0, 3208.0], [1012.0, 3227.0], [1020.0, 3227.0], [1023.0, 3218.0], [1032.0, 3203.0], [1026.0, 3219.0], [1027.0, 3228.0], [1024.0, 3218.0], [1023.0, 3227.0], [1011.0, 3241.0], [1014.0, 3249.0], [1025.0, 3254.0], [1057.0, 3270.0], [1068.0, 3271.0], [1077.0, 3284.0], [1088.0, 3304.0], [1100.0, 3300.0], [1098.0, 3292.0], [1100.0, 3302.0], [1125.0, 3305.0], [1133.0, 3310.0], [1122.0, 3306.0], [1107.0, 3308.0], [1116.0, 3320.0], [1122.0, 3353.0], [1129.0, 3361.0], [1137.0, 3357.0], [1153.0, 3350.0], [1144.0, 3353.0], [1139.0, 3362.0], [1122.0, 3377.0], [1132.0, 3387.0], [1146.0, 3408.0], [1161.0, 3413.0], [1166.0, 3423.0], [1170.0, 3412.0], [1179.0, 3406.0], [1177.0, 3395.0], [1166.0, 3396.0], [1155.0, 3397.0], [1166.0, 3394.0], [1157.0, 3387.0], [1170.0, 3377.0], [1169.0, 3391.0], [1177.0, 3391.0], [1193.0, 3374.0], [1188.0, 3366.0], [1196.0, 3367.0], [1210.0, 3360.0], [1203.0, 3350.0], [1195.0, 3347.0], [1196.0, 3338.0], [1205.0, 3338.0], [1198.0, 3329.0], [1207.0, 3336.0], [1207.0, 3353.0], [1221.0, 3357.0], [1219.0, 3348.0], [1220.0, 3362.0], [1233.0, 3367.0], [1243.0, 3364.0], [1257.0, 3363.0], [1260.0, 3360.0], [1276.0, 3360.0], [1268.0, 3344.0], [1270.0, 3327.0], [1282.0, 3329.0], [1270.0, 3327.0], [1269.0, 3339.0], [1281.0, 3339.0], [1269.0, 3339.0], [1274.0, 3354.0], [1282.0, 3349.0], [1280.0, 3360.0], [1290.0, 3360.0], [1287.0, 3348.0], [1287.0, 3356.0], [1296.0, 3343.0], [1291.0, 3359.0], [1304.0, 3348.0], [1320.0, 3351.0], [1311.0, 3339.0], [1321.0, 3322.0], [1331.0, 3318.0], [1343.0, 3325.0], [1330.0, 3300.0], [1338.0, 3305.0], [1336.0, 3287.0], [1344.0, 3285.0], [1342.0, 3294.0], [1350.0, 3273.0], [1355.0, 3276.0], [1355.0, 3288.0], [1359.0, 3280.0], [1358.0, 3270.0], [1345.0, 3270.0], [1360.0, 3269.0], [1352.0, 3255.0], [1365.0, 3245.0], [1373.0, 3248.0], [1376.0, 3238.0], [1380.0, 3221.0], [1386.0, 3209.0], [1372.0, 3201.0], [1375.0, 3196.0], [1367.0, 3196.0], [1376.0, 3196.0], [1367.0, 3191.0], [1377.0, 3193.0], [1380.0, 3188.0], [1365.0, 3188.0], [1361.0, 3177.0], [1368.0, 3167.0], [1357.0, 3164.0], [1353.0, 3155.0], [1361.0, 3152.0], [1346.0, 3149.0], [1341.0, 3141.0], [1342.0, 3133.0], [1334.0, 3128.0], [1335.0, 3119.0], [1322.0, 3112.0], [1330.0, 3109.0], [1320.0, 3099.0], [1319.0, 3091.0], [1310.0, 3073.0], [1321.0, 3073.0], [1312.0, 3075.0], [1321.0, 3074.0], [1317.0, 3082.0], [1326.0, 3081.0], [1330.0, 3094.0], [1334.0, 3102.0], [1341.0, 3110.0], [1345.0, 3121.0], [1337.0, 3128.0], [1345.0, 3130.0], [1347.0, 3122.0], [1357.0, 3117.0], [1359.0, 3111.0], [1359.0, 3103.0], [1363.0, 3093.0], [1363.0, 3102.0], [1360.0, 3113.0], [1350.0, 3129.0], [1364.0, 3150.0], [1358.0, 3160.0], [1368.0, 3164.0], [1373.0, 3176.0], [1381.0, 3183.0], [1389.0, 3176.0], [1381.0, 3184.0], [1381.0, 3200.0], [1391.0, 3198.0], [1398.0, 3206.0], [1413.0, 3186.0], [1391.0, 3215.0], [1379.0, 3228.0], [1382.0, 3238.0], [1384.0, 3230.0], [1397.0, 3226.0], [1395.0, 3228.0], [1407.0, 3228.0], [1394.0, 3229.0], [1397.0, 3248.0], [1391.0, 3258.0], [1380.0, 3260.0], [1377.0, 3283.0], [1361.0, 3291.0], [1359.0, 3303.0], [1343.0, 3303.0], [1352.0, 3317.0], [1350.0, 3326.0], [1341.0, 3328.0], [1330.0, 3322.0], [1320.0, 3331.0], [1332.0, 3325.0], [1354.0, 3334.0], [1357.0, 3343.0], [1347.0, 3347.0], [1335.0, 3349.0], [1346.0, 3351.0], [1337.0, 3357.0], [1345.0, 3360.0], [1337.0, 3361.0], [1338.0, 3369.0], [1328.0, 3363.0], [1334.0, 3382.0], [1325.0, 3398.0], [1345.0, 3401.0], [1346.0, 3388.0], [1347.0, 3397.0], [1358.0, 3402.0], [1361.0, 3393.0], [1370.0, 3383.0], [1371.0, 3394.0], [1362.0, 3399.0], [1387.0, 3402.0], [1374.0, 3401.0], [1388.0, 3398.0], [1376.0, 3392.0], [1388.0, 3396.0], [1375.0, 3389.0], [1387.0, 3391.0], [1375.0, 3383.0], [1388.0, 3391.0], [1404.0, 3390.0], [1387.0, 3377.0], [1396.0, 3379.0], [1413.0, 3374.0], [1411.0, 3386.0], [1407.0, 3400.0], [1435.0, 3408.0], [1436.0, 3399.0], [1419.0, 3387.0], [1427.0, 3376.0], [1420.0, 3366.0], [1429.0, 3370.0], [1427.0, 3378.0], [1422.0, 3389.0], [1430.0, 3387.0], [1430.0, 3395.0], [1435.0, 3409.0], [1453.0, 3416.0], [1445.0, 3406.0], [1443.0, 3399.0], [1443.0, 3386.0], [1454.0, 3397.0], [1462.0, 3391.0], [1465.0, 3382.0], [1473.0, 3390.0], [1474.0, 3382.0], [1458.0, 3364.0], [1477.0, 3383.0], [1473.0, 3394.0], [1482.0, 3395.0], [1478.0, 3410.0], [1494.0, 3408.0], [1494.0, 3416.0], [1493.0, 3426.0], [1502.0, 3427.0], [1510.0, 3436.0], [1514.0, 3428.0], [1505.0, 3420.0], [1514.0, 3428.0], [1513.0, 3419.0], [1522.0, 3417.0], [1507.0, 3411.0], [1520.0, 3412.0], [1518.0, 3401.0], [1526.0, 3394.0], [1522.0, 3403.0], [1523.0, 3411.0], [1532.0, 3426.0], [1543.0, 3438.0], [1548.0, 3429.0], [1554.0, 3443.0], [1568.0, 3435.0], [1570.0, 3456.0], [1579.0, 3457.0], [1588.0, 3465.0], [1580.0, 3459.0], [1579.0, 3469.0], [1580.0, 3459.0], [1569.0, 3457.0], [1563.0, 3441.0], [1555.0, 3444.0], [1546.0, 3438.0], [1537.0, 3442.0], [1528.0, 3440.0], [1526.0, 3453.0], [1537.0, 3458.0], [1542.0, 3450.0], [1537.0, 3460.0], [1526.0, 3461.0], [1532.0, 3486.0], [1528.0, 3502.0], [1527.0, 3523.0], [1536.0, 3528.0], [1522.0, 3525.0], [1522.0, 3557.0], [1513.0, 3583.0], [1511.0, 3591.0], [1509.0, 3602.0], [1509.0, 3593.0], [1473.0, 3664.0], [1450.0, 3709.0], [1429.0, 3764.0], [1417.0, 3824.0], [1458.0, 3825.0], [1491.0, 3840.0], [1504.0, 3850.0], [1522.0, 3849.0], [1526.0, 3834.0], [1518.0, 3833.0], [1530.0, 3834.0], [1532.0, 3826.0], [1527.0, 3823.0], [1535.0, 3823.0], [1535.0, 3820.0], [1544.0, 3820.0], [1560.0, 3816.0], [1571.0, 3811.0], [1563.0, 3799.0], [1554.0, 3800.0], [1543.0, 3811.0], [1552.0, 3804.0], [1551.0, 3796.0], [1559.0, 3802.0], [1556.0, 3787.0], [1547.0, 3790.0], [1556.0, 3786.0], [1564.0, 3784.0], [1560.0, 3776.0], [1552.0, 3778.0], [1564.0, 3775.0], [1570.0, 3788.0], [1586.0, 3800.0], [1574.0, 3783.0], [1588.0, 3799.0], [1582.0, 3787.0], [1597.0, 3787.0], [1595.0, 3778.0], [1580.0, 3769.0], [1579.0, 3780.0], [1579.0, 3769.0], [1581.0, 3747.0], [1589.0, 3731.0], [1604.0, 3729.0], [1603.0, 3720.0], [1605.0, 3730.0], [1596.0, 3733.0], [1608.0, 3738.0], [1609.0, 3747.0], [1617.0, 3731.0], [1633.0, 3721.0], [1631.0, 3710.0], [1615.0, 3710.0], [1614.0, 3723.0], [1610.0, 3715.0], [1616.0, 3708.0], [1632.0, 3708.0], [1642.0, 3702.0], [1651.0, 3702.0], [1635.0, 3681.0], [1625.0, 3681.0], [1616.0, 3693.0], [1621.0, 3685.0], [1615.0, 3662.0], [1625.0, 3660.0], [1635.0, 3637.0], [1644.0, 3637.0], [1655.0, 3630.0], [1648.0, 3615.0], [1680.0, 3638.0], [1678.0, 3599.0], [1664.0, 3598.0], [1661.0, 3590.0], [1672.0, 3572.0], [1687.0, 3560.0], [1701.0, 3556.0], [1711.0, 3562.0], [1716.0, 3571.0], [1724.0, 3570.0], [1735.0, 3561.0], [1753.0, 3559.0], [1762.0, 3547.0], [1740.0, 3551.0], [1727.0, 3545.0], [1716.0, 3531.0], [1714.0, 3517.0], [1723.0, 3512.0], [1734.0, 3526.0], [1744.0, 3530.0], [1752.0, 3525.0], [1780.0, 3522.0], [1774.0, 3507.0], [1749.0, 3508.0], [1742.0, 3494.0], [1729.0, 3488.0], [1751.0, 3468.0], [1764.0, 3474.0], [1777.0, 3478.0], [1798.0, 3472.0], [1807.0, 3473.0], [1796.0, 3475.0], [1794.0, 3495.0], [1782.0, 3507.0], [1779.0, 3540.0], [1792.0, 3530.0], [1810.0, 3485.0], [1843.0, 3426.0], [1872.0, 3393.0], [1873.0, 3383.0], [1860.0, 3397.0], [1841.0, 3405.0], [1845.0, 3413.0], [1835.0, 3420.0], [1835.0, 3429.0], [1824.0, 3449.0], [1803.0, 3448.0], [1807.0, 3459.0], [1809.0, 3470.0], [1798.0, 3472.0], [1803.0, 3453.0], [1794.0, 3449.0], [1796.0, 3439.0], [1788.0, 3432.0], [1787.0, 3418.0], [1802.0, 3409.0], [1815.0, 3391.0], [1844.0, 3380.0], [1847.0, 3372.0], [1844.0, 3359.0], [1854.0, 3349.0], [1853.0, 3337.0], [1876.0, 3335.0], [1880.0, 3313.0], [1901.0, 3306.0], [1908.0, 3298.0], [1880.0, 3315.0], [1882.0, 3333.0], [1868.0, 3337.0], [1884.0, 3336.0], [1872.0, 3344.0], [1882.0, 3351.0], [1870.0, 3345.0], [1870.0, 3355.0], [1881.0, 3360.0], [1874.0, 3370.0], [1949.0, 3282.0], [1971.0, 3261.0], [2006.0, 3241.0], [2018.0, 3223.0], [2016.0, 3204.0], [2004.0, 3208.0], [2001.0, 3216.0], [1979.0, 3235.0], [1962.0, 3244.0], [1960.0, 3252.0], [1951.0, 3245.0], [1956.0, 3256.0], [1947.0, 3245.0], [1944.0, 3253.0], [1953.0, 3258.0], [1943.0, 3254.0], [1933.0, 3264.0], [1925.0, 3279.0], [1912.0, 3281.0], [1908.0, 3291.0], [1904.0, 3281.0], [1909.0, 3250.0], [1915.0, 3238.0], [1924.0, 3238.0], [1917.0, 3224.0], [1904.0, 3222.0], [1899.0, 3187.0], [1905.0, 3178.0], [1902.0, 3165.0], [1912.0, 3166.0], [1913.0, 3150.0], [1911.0, 3160.0], [1907.0, 3170.0], [1918.0, 3194.0], [1927.0, 3194.0], [1936.0, 3184.0], [1951.0, 3187.0], [1966.0, 3178.0], [1964.0, 3169.0], [1973.0, 3167.0], [1982.0, 3159.0], [1995.0, 3160.0], [1984.0, 3168.0], [1970.0, 3169.0], [1975.0, 3181.0], [1992.0, 3194.0], [2006.0, 3176.0], [1992.0, 3168.0], [1995.0, 3160.0], [1995.0, 3151.0], [1992.0, 3143.0], [2004.0, 3136.0], [2005.0, 3122.0], [2015.0, 3113.0], [2029.0, 3111.0], [2027.0, 3099.0], [2030.0, 3111.0], [2032.0, 3122.0], [2033.0, 3131.0], [2040.0, 3117.0], [2034.0, 3104.0], [2028.0, 3084.0], [2038.0, 3099.0], [2049.0, 3097.0], [2049.0, 3111.0], [2050.0, 3091.0], [2063.0, 3095.0], [2055.0, 3091.0], [2067.0, 3083.0], [2063.0, 3072.0], [2056.0, 3062.0], [2061.0, 3048.0], [2070.0, 3041.0], [2089.0, 3046.0], [2100.0, 3055.0], [2102.0, 3063.0], [2112.0, 3053.0], [2117.0, 3067.0], [2135.0, 3066.0], [2157.0, 3075.0], [2168.0, 3085.0], [2170.0, 3073.0], [2159.0, 3071.0], [2151.0, 3057.0], [2162.0, 3052.0], [2161.0, 3042.0], [2167.0, 3033.0], [2160.0, 3042.0], [2166.0, 3033.0], [2153.0, 3047.0], [2145.0, 3049.0], [2139.0, 3058.0], [2149.0, 3057.0], [2145.0, 3066.0], [2146.0, 3058.0], [2135.0, 3053.0], [2141.0, 3044.0], [2133.0, 3044.0], [2120.0, 3039.0], [2130.0, 3043.0], [2143.0, 3039.0], [2130.0, 3031.0], [2120.0, 3031.0], [2128.0, 3029.0], [2139.0, 3034.0], [2131.0, 3029.0], [2144.0, 3026.0], [2149.0, 3016.0], [2139.0, 3014.0], [2136.0, 3023.0], [2128.0, 3024.0], [2111.0, 3022.0], [2105.0, 3013.0], [2112.0, 3005.0], [2112.0, 2997.0], [2101.0, 2994.0], [2113.0, 2997.0], [2110.0, 2989.0], [2117.0, 2980.0], [2121.0, 2969.0], [2123.0, 2959.0], [2122.0, 2972.0], [2143.0, 2972.0], [2146.0, 2953.0], [2134.0, 2950.0], [2146.0, 2952.0], [2128.0, 2948.0], [2148.0, 2944.0], [2138.0, 2937.0], [2151.0, 2942.0], [2151.0, 2925.0], [2169.0, 2922.0], [2169.0, 2913.0], [2164.0, 2901.0], [2172.0, 2905.0], [2164.0, 2899.0], [2172.0, 2904.0], [2173.0, 2896.0], [2182.0, 2898.0], [2171.0, 2891.0], [2197.0, 2906.0], [2222.0, 2930.0], [2233.0, 2910.0], [2223.0, 2900.0], [2214.0, 2891.0], [2224.0, 2898.0], [2222.0, 2890.0], [2238.0, 2893.0], [2247.0, 2903.0], [2243.0, 2890.0], [2234.0, 2890.0], [2243.0, 2890.0], [2240.0, 2879.0], [2231.0, 2875.0], [2222.0, 2879.0], [2230.0, 2874.0], [2218.0, 2869.0], [2204.0, 2876.0], [2212.0, 2882.0], [2189.0, 2864.0], [2202.0, 2860.0], [2215.0, 2861.0], [2225.0, 2864.0], [2223.0, 2850.0], [2235.0, 2849.0], [2247.0, 2857.0], [2249.0, 2849.0], [2247.0, 2858.0], [2254.0, 2857.0], [2254.0, 2869.0], [2265.0, 2863.0], [2266.0, 2852.0], [2258.0, 2849.0], [2247.0, 2840.0], [2239.0, 2847.0], [2245.0, 2836.0], [2227.0, 2821.0], [2224.0, 2811.0], [2224.0, 2798.0], [2213.0, 2786.0], [2201.0, 2782.0], [2183.0, 2795.0], [2185.0, 2785.0], [2169.0, 2795.0], [2182.0, 2791.0], [2189.0, 2777.0], [2187.0, 2789.0], [2194.0, 2781.0], [2202.0, 2779.0], [2201.0, 2767.0], [2206.0, 2783.0], [2221.0, 2785.0], [2219.0, 2769.0], [2225.0, 2753.0], [2215.0, 2742.0], [2205.0, 2729.0], [2197.0, 2729.0], [2194.0, 2738.0], [2194.0, 2739.0], [2194.0, 2755.0], [2182.0, 2752.0], [2174.0, 2747.0], [2173.0, 2755.0], [2160.0, 2752.0], [2156.0, 2760.0], [2143.0, 2768.0], [2147.0, 2758.0], [2156.0, 2753.0], [2171.0, 2755.0], [2173.0, 2743.0], [2169.0, 2731.0], [2174.0, 2719.0], [2166.0, 2716.0], [2166.0, 2734.0], [2153.0, 2737.0], [2147.0, 2719.0], [2131.0, 2733.0], [2122.0, 2729.0], [2120.0, 2717.0], [2111.0, 2721.0], [2109.0, 2729.0], [2101.0, 2738.0], [2102.0, 2728.0], [2096.0, 2720.0], [2089.0, 2733.0], [2089.0, 2725.0], [2095.0, 2716.0], [2094.0, 2708.0], [2085.0, 2712.0], [2085.0, 2696.0], [2076.0, 2696.0], [2072.0, 2685.0], [2059.0, 2697.0], [2049.0, 2692.0], [2032.0, 2681.0], [2025.0, 2666.0], [2003.0, 2662.0], [2024.0, 2665.0], [2013.0, 2642.0], [1998.0, 2644.0], [1987.0, 2638.0], [1978.0, 2642.0], [1967.0, 2625.0], [1964.0, 2614.0], [1951.0, 2608.0], [1930.0, 2593.0], [1918.0, 2595.0], [1926.0, 2593.0], [1915.0, 2589.0], [1904.0, 2598.0], [1900.0, 2594.0], [1900.0, 2584.0], [1890.0, 2593.0], [1895.0, 2584.0], [1905.0, 2596.0], [1911.0, 2585.0], [1956.0, 2606.0], [1967.0, 2613.0], [1969.0, 2624.0], [1978.0, 2628.0], [2004.0, 2639.0], [2012.0, 2632.0], [2026.0, 2655.0], [2049.0, 2671.0], [2059.0, 2679.0], [2058.0, 2691.0], [2072.0, 2682.0], [2078.0, 2690.0], [2094.0, 2662.0], [2087.0, 2688.0], [2094.0, 2698.0], [2110.0, 2698.0], [2114.0, 2706.0], [2106.0, 2713.0], [2119.0, 2709.0], [2130.0, 2706.0], [2123.0, 2698.0], [2117.0, 2680.0], [2108.0, 2678.0], [2116.0, 2679.0], [2113.0, 2655.0], [2117.0, 2647.0], [2105.0, 2636.0], [2099.0, 2604.0], [2094.0, 2591.0], [2088.0, 2575.0], [2088.0, 2564.0], [2098.0, 2578.0], [2089.0, 2586.0], [2096.0, 2588.0], [2096.0, 2598.0], [2105.0, 2606.0], [2103.0, 2616.0], [2107.0, 2625.0], [2110.0, 2637.0], [2118.0, 2641.0], [2131.0, 2626.0], [2121.0, 2635.0], [2125.0, 2648.0], [2116.0, 2655.0], [2115.0, 2668.0], [2123.0, 2673.0], [2123.0, 2681.0], [2131.0, 2676.0], [2123.0, 2681.0], [2122.0, 2673.0], [2122.0, 2689.0], [2131.0, 2689.0], [2124.0, 2697.0], [2131.0, 2705.0], [2125.0, 2716.0], [2140.0, 2715.0], [2141.0, 2707.0], [2145.0, 2698.0], [2140.0, 2690.0], [2130.0, 2684.0], [2140.0, 2689.0], [2141.0, 2697.0], [2152.0, 2697.0], [2145.0, 2714.0], [2158.0, 2732.0], [2160.0, 2716.0], [2166.0, 2704.0], [2175.0, 2712.0], [2189.0, 2715.0], [2198.0, 2718.0], [2218.0, 2708.0], [2211.0, 2697.0], [2214.0, 2686.0], [2206.0, 2689.0], [2196.0, 2694.0], [2199.0, 2685.0], [2209.0, 2684.0], [2219.0, 2687.0], [2232.0, 2692.0], [2234.0, 2683.0], [2231.0, 2693.0], [2219.0, 2691.0], [2219.0, 2705.0], [2222.0, 2713.0], [2229.0, 2703.0], [2223.0, 2714.0], [2219.0, 2728.0], [2228.0, 2711.0], [2236.0, 2709.0], [2230.0, 2717.0], [2239.0, 2719.0], [2239.0, 2727.0], [2230.0, 2728.0], [2243.0, 2727.0], [2243.0, 2739.0], [2249.0, 2751.0], [2259.0, 2749.0], [2267.0, 2747.0], [2258.0, 2739.0], [2268.0, 2742.0], [2263.0, 2733.0], [2268.0, 2745.0], [2281.0, 2736.0], [2290.0, 2737.0], [2289.0, 2729.0], [2280.0, 2735.0], [2272.0, 2737.0], [2277.0, 2727.0], [2285.0, 2730.0], [2269.0, 2715.0], [2268.0, 2724.0], [2262.0, 2715.0], [2264.0, 2707.0], [2276.0, 2711.0], [2276.0, 2719.0], [2284.0, 2718.0], [2291.0, 2709.0], [2299.0, 2706.0], [2308.0, 2710.0], [2316.0, 2713.0], [2319.0, 2705.0], [2330.0, 2694.0], [2326.0, 2667.0], [2325.0, 2656.0], [2326.0, 2666.0], [2328.0, 2676.0], [2339.0, 2679.0], [2347.0, 2680.0], [2348.0, 2668.0], [2340.0, 2674.0], [2350.0, 2664.0], [2335.0, 2670.0], [2347.0, 2664.0], [2334.0, 2668.0], [2350.0, 2663.0], [2361.0, 2659.0], [2363.0, 2647.0], [2352.0, 2640.0], [2360.0, 2639.0], [2369.0, 2642.0], [2363.0, 2632.0], [2376.0, 2630.0], [2385.0, 2623.0], [2398.0, 2630.0], [2394.0, 2617.0], [2378.0, 2613.0], [2368.0, 2596.0], [2379.0, 2613.0], [2390.0, 2618.0], [2407.0, 2612.0], [2418.0, 2613.0], [2433.0, 2625.0], [2441.0, 2618.0], [2439.0, 2607.0], [2429.0, 2605.0], [2426.0, 2597.0], [2418.0, 2593.0], [2405.0, 2582.0], [2392.0, 2577.0], [2405.0, 2581.0], [2414.0, 2590.0], [2427.0, 2595.0], [2437.0, 2600.0], [2438.0, 2584.0], [2439.0, 2570.0], [2436.0, 2559.0], [2441.0, 2569.0], [2436.0, 2581.0], [2445.0, 2591.0], [2453.0, 2584.0], [2452.0, 2569.0], [2456.0, 2573.0], [2456.0, 2561.0], [2450.0, 2541.0], [2438.0, 2517.0], [2453.0, 2536.0], [2453.0, 2547.0], [2464.0, 2553.0], [2473.0, 2550.0], [2479.0, 2542.0], [2493.0, 2550.0], [2489.0, 2535.0], [2493.0, 2547.0], [2503.0, 2550.0], [2499.0, 2534.0], [2487.0, 2519.0], [2494.0, 2527.0], [2500.0, 2519.0], [2494.0, 2508.0], [2502.0, 2516.0], [2492.0, 2498.0], [2508.0, 2510.0], [2503.0, 2491.0], [2493.0, 2482.0], [2505.0, 2492.0], [2507.0, 2501.0], [2521.0, 2474.0], [2519.0, 2466.0], [2533.0, 2460.0], [2551.0, 2454.0], [2550.0, 2439.0], [2538.0, 2436.0], [2534.0, 2424.0], [2550.0, 2433.0], [2561.0, 2435.0], [2577.0, 2440.0], [2569.0, 2426.0], [2560.0, 2429.0], [2548.0, 2429.0], [2558.0, 2427.0], [2575.0, 2420.0], [2567.0, 2416.0], [2550.0, 2402.0], [2540.0, 2404.0], [2532.0, 2403.0], [2541.0, 2397.0], [2551.0, 2401.0], [2559.0, 2401.0], [2569.0, 2400.0], [2580.0, 2407.0], [2573.0, 2392.0], [2576.0, 2381.0], [2567.0, 2381.0], [2579.0, 2375.0], [2561.0, 2368.0], [2552.0, 2365.0], [2547.0, 2357.0], [2554.0, 2368.0], [2550.0, 2377.0], [2548.0, 2377.0], [2548.0, 2368.0], [2539.0, 2363.0], [2545.0, 2375.0], [2547.0, 2385.0], [2541.0, 2390.0], [2533.0, 2390.0], [2546.0, 2385.0], [2543.0, 2374.0], [2532.0, 2359.0], [2528.0, 2350.0], [2528.0, 2340.0], [2533.0, 2327.0], [2535.0, 2337.0], [2535.0, 2328.0], [2536.0, 2337.0], [2537.0, 2329.0], [2529.0, 2341.0], [2529.0, 2349.0], [2529.0, 2358.0], [2537.0, 2358.0], [2546.0, 2349.0], [2541.0, 2324.0], [2533.0, 2326.0], [2529.0, 2317.0], [2537.0, 2316.0], [2541.0, 2324.0], [2546.0, 2313.0], [2551.0, 2305.0], [2542.0, 2300.0], [2551.0, 2305.0], [2549.0, 2297.0], [2553.0, 2286.0], [2545.0, 2278.0], [2555.0, 2280.0], [2557.0, 2268.0], [2543.0, 2268.0], [2553.0, 2267.0], [2553.0, 2257.0], [2561.0, 2256.0], [2574.0, 2239.0], [2565.0, 2238.0], [2556.0, 2242.0], [2538.0, 2236.0], [2546.0, 2233.0], [2560.0, 2237.0], [2573.0, 2235.0], [2583.0, 2225.0], [2565.0, 2226.0], [2573.0, 2223.0], [2578.0, 2212.0], [2567.0, 2215.0], [2578.0, 2212.0], [2580.0, 2224.0], [2588.0, 2213.0], [2576.0, 2211.0], [2568.0, 2210.0], [2560.0, 2215.0], [2524.0, 2219.0], [2518.0, 2229.0], [2522.0, 2219.0], [2561.0, 2205.0], [2585.0, 2205.0], [2613.0, 2180.0], [2602.0, 2186.0], [2609.0, 2177.0], [2600.0, 2185.0], [2607.0, 2177.0], [2599.0, 2184.0], [2590.0, 2182.0], [2608.0, 2176.0], [2587.0, 2173.0], [2568.0, 2165.0], [2588.0, 2171.0], [2604.0, 2169.0], [2614.0, 2167.0], [2624.0, 2153.0], [2621.0, 2142.0], [2629.0, 2151.0], [2634.0, 2142.0], [2592.0, 2140.0], [2617.0, 2140.0], [2602.0, 2139.0], [2613.0, 2137.0], [2626.0, 2141.0], [2625.0, 2126.0], [2621.0, 2134.0], [2609.0, 2133.0], [2583.0, 2127.0], [2578.0, 2122.0], [2586.0, 2122.0], [2610.0, 2122.0], [2631.0, 2118.0], [2623.0, 2116.0], [2622.0, 2106.0], [2614.0, 2104.0], [2621.0, 2100.0], [2611.0, 2100.0], [2619.0, 2100.0], [2611.0, 2098.0], [2619.0, 2096.0], [2617.0, 2088.0], [2618.0, 2081.0], [2626.0, 2081.0], [2625.0, 2071.0], [2623.0, 2060.0], [2623.0, 2052.0], [2628.0, 2063.0], [2637.0, 2052.0], [2627.0, 2048.0], [2614.0, 2046.0], [2616.0, 2038.0], [2615.0, 2046.0], [2623.0, 2039.0], [2630.0, 2047.0], [2642.0, 2037.0], [2654.0, 2038.0], [2665.0, 2027.0], [2660.0, 2016.0], [2668.0, 2008.0], [2651.0, 2007.0], [2650.0, 2015.0], [2642.0, 2014.0], [2630.0, 2013.0], [2615.0, 2010.0], [2609.0, 2001.0], [2594.0, 2006.0], [2600.0, 1998.0], [2569.0, 1988.0], [2560.0, 1993.0], [2544.0, 1979.0], [2534.0, 1973.0], [2546.0, 1971.0], [2553.0, 1979.0], [2561.0, 1979.0], [2574.0, 1977.0], [2589.0, 1980.0], [2600.0, 1989.0], [2612.0, 1993.0], [2621.0, 1996.0], [2627.0, 1986.0], [2619.0, 1979.0], [2628.0, 1985.0], [2644.0, 1982.0], [2645.0, 1973.0], [2645.0, 1982.0], [2654.0, 1976.0], [2660.0, 1967.0], [2661.0, 1950.0], [2636.0, 1947.0], [2645.0, 1951.0], [2656.0, 1944.0], [2659.0, 1932.0], [2647.0, 1932.0], [2653.0, 1923.0], [2642.0, 1916.0], [2634.0, 1916.0], [2628.0, 1904.0], [2611.0, 1886.0], [2602.0, 1885.0], [2602.0, 1877.0], [2623.0, 1880.0], [2620.0, 1872.0], [2610.0, 1871.0], [2625.0, 1870.0], [2635.0, 1876.0], [2651.0, 1875.0], [2654.0, 1865.0], [2643.0, 1861.0], [2658.0, 1859.0], [2659.0, 1873.0], [2666.0, 1852.0], [2656.0, 1852.0], [2651.0, 1844.0], [2616.0, 1843.0], [2638.0, 1839.0], [2621.0, 1832.0], [2635.0, 1832.0], [2620.0, 1816.0], [2630.0, 1820.0], [2624.0, 1809.0], [2629.0, 1818.0], [2645.0, 1832.0], [2654.0, 1830.0], [2649.0, 1822.0], [2649.0, 1813.0], [2654.0, 1823.0], [2666.0, 1830.0], [2670.0, 1846.0], [2682.0, 1844.0], [2696.0, 1852.0], [2709.0, 1834.0], [2700.0, 1832.0], [2707.0, 1822.0], [2699.0, 1821.0], [2709.0, 1819.0], [2711.0, 1809.0], [2728.0, 1808.0], [2739.0, 1799.0], [2730.0, 1788.0], [2714.0, 1780.0], [2695.0, 1788.0], [2680.0, 1779.0], [2673.0, 1787.0], [2663.0, 1781.0], [2650.0, 1776.0], [2639.0, 1775.0], [2647.0, 1769.0], [2638.0, 1763.0], [2629.0, 1758.0], [2614.0, 1740.0], [2622.0, 1746.0], [2630.0, 1753.0], [2638.0, 1754.0], [2666.0, 1774.0], [2702.0, 1771.0], [2716.0, 1767.0], [2728.0, 1770.0], [2723.0, 1762.0], [2708.0, 1766.0], [2676.0, 1764.0], [2712.0, 1761.0], [2691.0, 1750.0], [2689.0, 1728.0], [2696.0, 1744.0], [2700.0, 1752.0], [2716.0, 1762.0], [2724.0, 1758.0], [2747.0, 1755.0], [2745.0, 1745.0], [2753.0, 1751.0], [2751.0, 1769.0], [2742.0, 1821.0], [2729.0, 1831.0], [2739.0, 1825.0], [2735.0, 1831.0], [2727.0, 1831.0], [2737.0, 1832.0], [2729.0, 1836.0], [2739.0, 1840.0], [2730.0, 1844.0], [2725.0, 1852.0], [2736.0, 1847.0], [2735.0, 1858.0], [2731.0, 1871.0], [2732.0, 1861.0], [2722.0, 1868.0], [2730.0, 1875.0], [2725.0, 1889.0], [2723.0, 1899.0], [2714.0, 1903.0], [2722.0, 1907.0], [2714.0, 1907.0], [2715.0, 1915.0], [2717.0, 1924.0], [2712.0, 1938.0], [2697.0, 1944.0], [2707.0, 1951.0], [2708.0, 1960.0], [2700.0, 1956.0], [2703.0, 1972.0], [2705.0, 1983.0], [2706.0, 1998.0], [2699.0, 2008.0], [2708.0, 2008.0], [2704.0, 2009.0], [2704.0, 2029.0], [2694.0, 2049.0], [2681.0, 2049.0], [2697.0, 2049.0], [2694.0, 2057.0], [2691.0, 2073.0], [2699.0, 2089.0], [2694.0, 2109.0], [2690.0, 2171.0], [2687.0, 2180.0], [2688.0, 2197.0], [2682.0, 2243.0], [2675.0, 2252.0], [2674.0, 2265.0], [2682.0, 2290.0], [2674.0, 2287.0], [2679.0, 2300.0], [2671.0, 2305.0], [2662.0, 2299.0], [2651.0, 2289.0], [2654.0, 2300.0], [2667.0, 2308.0], [2681.0, 2323.0], [2710.0, 2049.0], [2733.0, 1891.0], [2764.0, 1727.0], [2776.0, 1685.0], [2767.0, 1679.0], [2765.0, 1683.0], [2754.0, 1683.0], [2743.0, 1689.0], [2734.0, 1703.0], [2720.0, 1709.0], [2711.0, 1708.0], [2699.0, 1700.0], [2690.0, 1686.0], [2673.0, 1680.0], [2681.0, 1678.0], [2681.0, 1665.0], [2678.0, 1656.0], [2675.0, 1639.0], [2680.0, 1631.0], [2681.0, 1644.0], [2688.0, 1654.0], [2691.0, 1667.0], [2694.0, 1673.0], [2694.0, 1683.0], [2699.0, 1691.0], [2710.0, 1697.0], [2719.0, 1697.0], [2734.0, 1688.0], [2756.0, 1669.0], [2746.0, 1666.0], [2748.0, 1657.0], [2757.0, 1661.0], [2765.0, 1660.0], [2762.0, 1670.0], [2754.0, 1671.0], [2767.0, 1674.0], [2781.0, 1626.0], [2810.0, 1522.0], [2794.0, 1521.0], [2783.0, 1526.0], [2776.0, 1535.0], [2765.0, 1535.0], [2744.0, 1531.0], [2734.0, 1510.0], [2745.0, 1515.0], [2762.0, 1525.0], [2766.0, 1513.0], [2764.0, 1505.0], [2781.0, 1510.0], [2786.0, 1520.0], [2809.0, 1520.0], [2812.0, 1494.0], [2828.0, 1438.0], [2850.0, 1332.0], [2862.0, 1242.0], [2863.0, 1186.0], [2858.0, 1103.0], [2849.0, 1075.0], [2847.0, 1025.0], [2832.0, 985.0], [2814.0, 958.0], [2810.0, 966.0], [2796.0, 966.0], [2794.0, 977.0], [2811.0, 986.0], [2817.0, 1021.0], [2830.0, 1025.0], [2739.0, 1025.0], [2831.0, 1026.0], [2830.0, 1057.0], [2842.0, 1063.0], [2845.0, 1081.0], [2836.0, 1076.0], [2850.0, 1090.0], [2848.0, 1101.0], [2855.0, 1117.0], [2859.0, 1174.0], [2853.0, 1235.0], [2844.0, 1227.0], [2836.0, 1240.0], [2835.0, 1232.0], [2827.0, 1235.0], [2835.0, 1242.0], [2838.0, 1253.0], [2846.0, 1252.0], [2844.0, 1263.0], [2842.0, 1254.0], [2828.0, 1252.0], [2831.0, 1261.0], [2839.0, 1279.0], [2828.0, 1273.0], [2811.0, 1286.0], [2820.0, 1277.0], [2827.0, 1261.0], [2824.0, 1246.0], [2808.0, 1252.0], [2806.0, 1262.0], [2807.0, 1250.0], [2794.0, 1258.0], [2791.0, 1266.0], [2785.0, 1277.0], [2776.0, 1277.0], [2788.0, 1262.0], [2768.0, 1263.0], [2759.0, 1272.0], [2751.0, 1272.0], [2775.0, 1260.0], [2790.0, 1259.0], [2797.0, 1240.0], [2784.0, 1243.0], [2802.0, 1233.0], [2777.0, 1234.0], [2792.0, 1228.0], [2798.0, 1220.0], [2800.0, 1225.0], [2809.0, 1225.0], [2811.0, 1217.0], [2804.0, 1215.0], [2812.0, 1215.0], [2820.0, 1205.0], [2829.0, 1202.0], [2842.0, 1199.0], [2854.0, 1187.0], [2851.0, 1160.0], [2827.0, 1161.0], [2806.0, 1154.0], [2797.0, 1161.0], [2788.0, 1159.0], [2776.0, 1176.0], [2754.0, 1175.0], [2744.0, 1183.0], [2740.0, 1195.0], [2724.0, 1204.0], [2713.0, 1198.0], [2708.0, 1213.0], [2699.0, 1221.0], [2703.0, 1229.0], [2705.0, 1209.0], [2710.0, 1195.0], [2723.0, 1193.0], [2724.0, 1179.0], [2739.0, 1170.0], [2755.0, 1166.0], [2784.0, 1148.0], [2797.0, 1145.0], [2797.0, 1134.0], [2785.0, 1123.0], [2794.0, 1125.0], [2799.0, 1134.0], [2811.0, 1136.0], [2819.0, 1131.0], [2829.0, 1145.0], [2848.0, 1143.0], [2850.0, 1123.0], [2846.0, 1108.0], [2821.0, 1092.0], [2811.0, 1094.0], [2788.0, 1088.0], [2770.0, 1081.0], [2762.0, 1081.0], [2734.0, 1068.0], [2712.0, 1051.0], [2704.0, 1048.0], [2653.0, 1025.0], [2626.0, 1005.0], [2606.0, 1020.0], [2598.0, 1022.0], [2579.0, 1020.0], [2565.0, 1004.0], [2548.0, 1023.0], [2540.0, 1034.0], [2511.0, 1046.0], [2520.0, 1038.0], [2516.0, 1024.0], [2494.0, 1012.0], [2478.0, 1004.0], [2465.0, 1002.0], [2447.0, 992.0], [2429.0, 1008.0], [2429.0, 1016.0], [2444.0, 991.0], [2428.0, 951.0], [2424.0, 940.0], [2402.0, 935.0], [2393.0, 923.0], [2397.0, 914.0], [2372.0, 915.0], [2356.0, 945.0], [2341.0, 955.0], [2319.0, 960.0], [2308.0, 959.0], [2304.0, 970.0], [2295.0, 978.0], [2284.0, 980.0], [2288.0, 963.0], [2275.0, 966.0], [2264.0, 962.0], [2261.0, 948.0], [2247.0, 958.0], [2242.0, 944.0], [2231.0, 944.0], [2223.0, 938.0], [2208.0, 948.0], [2194.0, 947.0], [2180.0, 922.0], [2166.0, 911.0], [2120.0, 900.0], [2099.0, 866.0], [2072.0, 842.0], [2062.0, 814.0], [2047.0, 809.0], [2057.0, 808.0], [2066.0, 816.0], [2073.0, 841.0], [2100.0, 866.0], [2122.0, 900.0], [2169.0, 909.0], [2181.0, 920.0], [2189.0, 940.0], [2197.0, 945.0], [2207.0, 945.0], [2221.0, 935.0], [2241.0, 938.0], [2250.0, 948.0], [2258.0, 945.0], [2254.0, 944.0], [2264.0, 944.0], [2265.0, 958.0], [2283.0, 955.0], [2283.0, 943.0], [2283.0, 955.0], [2291.0, 962.0], [2288.0, 976.0], [2301.0, 969.0], [2302.0, 954.0], [2312.0, 951.0], [2302.0, 939.0], [2312.0, 951.0], [2331.0, 947.0], [2341.0, 939.0], [2353.0, 918.0], [2376.0, 901.0], [2392.0, 899.0], [2403.0, 905.0], [2410.0, 915.0], [2434.0, 923.0], [2443.0, 897.0], [2449.0, 886.0], [2458.0, 880.0], [2452.0, 849.0], [2450.0, 817.0], [2451.0, 828.0], [2455.0, 820.0], [2484.0, 802.0], [2500.0, 809.0], [2509.0, 786.0], [2517.0, 740.0], [2528.0, 737.0], [2529.0, 728.0], [2521.0, 724.0], [2523.0, 692.0], [2527.0, 667.0], [2544.0, 646.0], [2561.0, 636.0], [2576.0, 628.0], [2592.0, 611.0], [2605.0, 617.0], [2619.0, 589.0], [2588.0, 563.0], [2612.0, 577.0], [2624.0, 560.0], [2617.0, 551.0], [2597.0, 542.0], [2619.0, 548.0], [2629.0, 561.0], [2641.0, 544.0], [2646.0, 530.0], [2641.0, 506.0], [2649.0, 467.0], [2641.0, 460.0], [2652.0, 461.0], [2647.0, 496.0], [2652.0, 506.0], [2672.0, 497.0], [2674.0, 489.0], [2683.0, 466.0], [2710.0, 452.0], [2710.0, 447.0], [2687.0, 447.0], [2681.0, 435.0], [2691.0, 414.0], [2689.0, 388.0], [2698.0, 373.0], [2688.0, 370.0], [2690.0, 362.0], [2698.0, 371.0], [2701.0, 360.0], [2712.0, 349.0], [2693.0, 330.0], [2715.0, 349.0], [2732.0, 333.0], [2772.0, 327.0], [2776.0, 313.0], [2766.0, 291.0], [2774.0, 286.0], [2775.0, 269.0], [2779.0, 245.0], [2769.0, 219.0], [2770.0, 200.0], [2771.0, 198.0], [2771.0, 217.0], [2781.0, 242.0], [2778.0, 255.0], [2789.0, 269.0], [2802.0, 270.0], [2816.0, 219.0], [2809.0, 246.0], [2804.0, 273.0], [2791.0, 273.0], [2781.0, 259.0], [2778.0, 273.0], [2781.0, 283.0], [2772.0, 293.0], [2780.0, 320.0], [2773.0, 332.0], [2765.0, 334.0], [2773.0, 340.0], [2781.0, 342.0], [2771.0, 351.0], [2773.0, 359.0], [2765.0, 363.0], [2763.0, 353.0], [2770.0, 343.0], [2762.0, 350.0], [2754.0, 351.0], [2761.0, 342.0], [2758.0, 334.0], [2738.0, 337.0], [2724.0, 352.0], [2714.0, 355.0], [2692.0, 390.0], [2696.0, 410.0], [2688.0, 426.0], [2686.0, 440.0], [2710.0, 438.0], [2714.0, 453.0], [2705.0, 463.0], [2692.0, 467.0], [2678.0, 503.0], [2667.0, 511.0], [2667.0, 531.0], [2660.0, 549.0], [2657.0, 558.0], [2648.0, 577.0], [2639.0, 593.0], [2636.0, 605.0], [2628.0, 617.0], [2618.0, 623.0], [2611.0, 637.0], [2612.0, 647.0], [2648.0, 642.0], [2652.0, 631.0], [2680.0, 636.0], [2695.0, 628.0], [2689.0, 611.0], [2691.0, 606.0], [2680.0, 606.0], [2665.0, 598.0], [2693.0, 601.0], [2719.0, 614.0], [2682.0, 587.0], [2693.0, 585.0], [2725.0, 604.0], [2703.0, 584.0], [2717.0, 587.0], [2719.0, 579.0], [2709.0, 572.0], [2718.0, 574.0], [2705.0, 560.0], [2717.0, 560.0], [2726.0, 549.0], [2742.0, 557.0], [2740.0, 548.0], [2762.0, 530.0], [2771.0, 528.0], [2744.0, 515.0], [2760.0, 517.0], [2775.0, 513.0], [2774.0, 505.0], [2772.0, 493.0], [2783.0, 484.0], [2777.0, 475.0], [2768.0, 473.0], [2783.0, 474.0], [2790.0, 452.0], [2790.0, 442.0], [2782.0, 432.0], [2794.0, 428.0], [2792.0, 420.0], [2801.0, 402.0], [2807.0, 398.0], [2807.0, 390.0], [2822.0, 377.0], [2834.0, 342.0], [2845.0, 336.0], [2845.0, 328.0], [2867.0, 287.0], [2866.0, 272.0], [2881.0, 247.0], [2883.0, 232.0], [2903.0, 188.0], [2934.0, 195.0], [2949.0, 205.0], [2940.0, 206.0], [2931.0, 197.0], [2913.0, 231.0], [2899.0, 250.0], [2900.0, 262.0], [2889.0, 292.0], [2883.0, 304.0], [2840.0, 375.0], [2838.0, 385.0], [2831.0, 398.0], [2826.0, 407.0], [2818.0, 413.0], [2818.0, 421.0], [2806.0, 440.0], [2808.0, 449.0], [2804.0, 459.0], [2806.0, 475.0], [2800.0, 484.0], [2803.0, 498.0], [2794.0, 527.0], [2803.0, 539.0], [2814.0, 534.0], [2824.0, 522.0], [2849.0, 520.0], [2863.0, 487.0], [2859.0, 470.0], [2863.0, 455.0], [2905.0, 395.0], [2904.0, 379.0], [2925.0, 355.0], [2925.0, 344.0], [2917.0, 328.0], [2916.0, 279.0], [2937.0, 235.0], [2951.0, 215.0], [2950.0, 206.0], [2919.0, 278.0], [2921.0, 332.0], [2927.0, 342.0], [2950.0, 353.0], [2964.0, 337.0], [2974.0, 333.0], [2989.0, 342.0], [2999.0, 341.0], [3014.0, 334.0], [3014.0, 326.0], [3026.0, 331.0], [3043.0, 337.0], [3042.0, 324.0], [3051.0, 313.0], [3059.0, 335.0], [3067.0, 337.0], [3067.0, 329.0], [3089.0, 320.0], [3123.0, 339.0], [3106.0, 323.0], [3103.0, 315.0], [3108.0, 323.0], [3116.0, 316.0], [3106.0, 310.0], [3102.0, 298.0], [3092.0, 300.0], [3094.0, 292.0], [3090.0, 284.0], [3089.0, 263.0], [3088.0, 247.0], [3094.0, 233.0], [3083.0, 233.0], [3079.0, 212.0], [3083.0, 183.0], [3073.0, 175.0], [3082.0, 160.0], [3078.0, 176.0], [3081.0, 211.0], [3091.0, 211.0], [3091.0, 225.0], [3101.0, 231.0], [3107.0, 243.0], [3112.0, 254.0], [3115.0, 241.0], [3125.0, 229.0], [3125.0, 216.0], [3134.0, 215.0], [3135.0, 205.0], [3136.0, 195.0], [3128.0, 190.0], [3121.0, 205.0], [3110.0, 220.0], [3099.0, 209.0], [3109.0, 214.0], [3124.0, 185.0], [3134.0, 177.0], [3138.0, 168.0], [3148.0, 152.0], [3142.0, 174.0], [3152.0, 166.0], [3162.0, 160.0], [3165.0, 149.0], [3160.0, 137.0], [3152.0, 130.0], [3161.0, 129.0], [3169.0, 133.0], [3173.0, 123.0], [3189.0, 124.0], [3195.0, 120.0], [3195.0, 105.0], [3196.0, 91.0], [3198.0, 101.0], [3204.0, 92.0], [3203.0, 101.0], [3213.0, 94.0], [3208.0, 112.0], [3213.0, 100.0], [3221.0, 100.0], [3227.0, 80.0], [3204.0, 71.0], [3215.0, 71.0], [3211.0, 60.0], [3221.0, 59.0], [3218.0, 68.0], [3229.0, 73.0], [3237.0, 59.0], [3235.0, 67.0], [3247.0, 68.0], [3255.0, 59.0], [3263.0, 51.0], [3271.0, 41.0], [3261.0, 78.0], [3267.0, 69.0], [3281.0, 69.0], [3276.0, 61.0], [3284.0, 59.0], [3281.0, 47.0], [3292.0, 31.0], [3300.0, 26.0], [3314.0, 25.0], [3322.0, 0.0], [3313.0, -4.0], [3317.0, 8.0], [3309.0, 2.0], [3313.0, -15.0], [3321.0, -13.0], [3337.0, -42.0], [3345.0, -62.0], [3345.0, -47.0], [3353.0, -42.0], [3365.0, -42.0], [3369.0, -57.0], [3360.0, -67.0], [3368.0, -65.0], [3363.0, -75.0], [3372.0, -60.0], [3374.0, -71.0], [3377.0, -58.0], [3384.0, -79.0], [3386.0, -71.0], [3385.0, -62.0], [3398.0, -74.0], [3402.0, -93.0], [3412.0, -103.0], [3414.0, -81.0], [3418.0, -73.0], [3409.0, -72.0], [3414.0, -60.0], [3423.0, -60.0], [3424.0, -69.0], [3433.0, -70.0], [3437.0, -80.0], [3440.0, -92.0], [3443.0, -83.0], [3448.0, -75.0], [3445.0, -66.0], [3447.0, -57.0], [3439.0, -47.0], [3430.0, -54.0], [3427.0, -41.0], [3446.0, -42.0], [3451.0, -63.0], [3459.0, -72.0], [3471.0, -83.0], [3478.0, -99.0], [3476.0, -88.0], [3480.0, -83.0], [3480.0, -91.0], [3488.0, -96.0], [3483.0, -121.0], [3490.0, -102.0], [3490.0, -110.0], [3495.0, -100.0], [3496.0, -108.0], [3505.0, -120.0], [3502.0, -128.0], [3508.0, -119.0], [3497.0, -103.0], [3507.0, -71.0], [3517.0, -77.0], [3526.0, -97.0], [3517.0, -103.0], [3517.0, -121.0], [3520.0, -113.0], [3539.0, -113.0], [3543.0, -126.0], [3550.0, -128.0], [3562.0, -128.0], [3568.0, -118.0], [3571.0, -128.0], [3582.0, -108.0], [3585.0, -123.0], [3584.0, -128.0], [4224.0, -128.0], [4224.0, 28.0], [4216.0, 28.0], [4216.0, 32.0], [4224.0, 32.0], [4220.0, 45.0], [4212.0, 31.0], [4166.0, 14.0], [4145.0, 14.0], [4156.0, 19.0], [4169.0, 17.0], [4164.0, 25.0], [4180.0, 56.0], [4172.0, 65.0], [4165.0, 55.0], [4139.0, 42.0], [4141.0, 56.0], [4130.0, 60.0], [4130.0, 70.0], [4123.0, 59.0], [4113.0, 56.0], [4113.0, 48.0], [4121.0, 58.0], [4132.0, 50.0], [4132.0, 37.0], [4124.0, 35.0], [4120.0, 23.0], [4105.0, 32.0], [4110.0, 43.0], [4106.0, 53.0], [4109.0, 63.0], [4104.0, 51.0], [4095.0, 52.0], [4091.0, 40.0], [4107.0, 26.0], [4111.0, 17.0], [4124.0, 22.0], [4147.0, 26.0], [4142.0, 20.0], [4125.0, 20.0], [4106.0, 5.0], [4095.0, 15.0], [4079.0, 25.0], [4077.0, 35.0], [4068.0, 35.0], [4059.0, 28.0], [4073.0, 29.0], [4047.0, 24.0], [4068.0, 47.0], [4063.0, 104.0], [4068.0, 100.0], [4068.0, 85.0], [4070.0, 70.0], [4074.0, 81.0], [4074.0, 89.0], [4069.0, 100.0], [4059.0, 114.0], [4063.0, 125.0], [4041.0, 131.0], [4029.0, 146.0], [4032.0, 167.0], [4021.0, 160.0], [4011.0, 142.0], [4012.0, 127.0], [4027.0, 115.0], [4026.0, 124.0], [4033.0, 112.0], [4045.0, 113.0], [4058.0, 104.0], [3959.0, 139.0], [3939.0, 136.0], [3944.0, 144.0], [3934.0, 136.0], [3891.0, 126.0], [3850.0, 107.0], [3815.0, 101.0], [3776.0, 100.0], [3776.0, 114.0], [3774.0, 104.0], [3743.0, 92.0], [3709.0, 50.0], [3696.0, 47.0], [3690.0, 104.0], [3686.0, 124.0], [3688.0, 114.0], [3693.0, 97.0], [3714.0, 110.0], [3721.0, 100.0], [3716.0, 84.0], [3729.0, 90.0], [3722.0, 72.0], [3740.0, 94.0], [3732.0, 90.0], [3724.0, 99.0], [3739.0, 96.0], [3766.0, 111.0], [3762.0, 128.0], [3764.0, 154.0], [3764.0, 163.0], [3762.0, 172.0], [3750.0, 176.0], [3748.0, 174.0], [3748.0, 163.0], [3753.0, 155.0], [3732.0, 127.0], [3723.0, 149.0], [3731.0, 152.0], [3736.0, 163.0], [3725.0, 155.0], [3717.0, 146.0], [3701.0, 137.0], [3680.0, 146.0], [3646.0, 143.0], [3649.0, 154.0], [3667.0, 162.0], [3671.0, 171.0], [3668.0, 182.0], [3665.0, 174.0], [3659.0, 163.0], [3651.0, 161.0], [3631.0, 155.0], [3641.0, 152.0], [3647.0, 137.0], [3636.0, 139.0], [3625.0, 129.0], [3579.0, 133.0], [3574.0, 138.0], [3574.0, 130.0], [3620.0, 127.0], [3634.0, 114.0], [3648.0, 111.0], [3644.0, 128.0], [3654.0, 114.0], [3652.0, 98.0], [3642.0, 88.0], [3603.0, 83.0], [3581.0, 72.0], [3572.0, 63.0], [3560.0, 74.0], [3562.0, 83.0], [3567.0, 71.0], [3568.0, 79.0], [3560.0, 90.0], [3560.0, 81.0], [3554.0, 109.0], [3563.0, 117.0], [3579.0, 154.0], [3591.0, 202.0], [3606.0, 211.0], [3604.0, 232.0], [3594.0, 220.0], [3602.0, 221.0], [3603.0, 216.0], [3590.0, 216.0], [3584.0, 208.0], [3567.0, 199.0], [3563.0, 188.0], [3557.0, 163.0], [3536.0, 167.0], [3534.0, 178.0], [3544.0, 204.0], [3534.0, 201.0], [3517.0, 204.0], [3506.0, 197.0], [3489.0, 199.0], [3480.0, 182.0], [3480.0, 152.0], [3452.0, 160.0], [3457.0, 178.0], [3443.0, 162.0], [3445.0, 151.0], [3456.0, 154.0], [3468.0, 142.0], [3479.0, 147.0], [3492.0, 146.0], [3501.0, 139.0], [3510.0, 126.0], [3517.0, 137.0], [3516.0, 157.0], [3508.0, 169.0], [3501.0, 173.0], [3501.0, 181.0], [3520.0, 180.0], [3521.0, 162.0], [3526.0, 152.0], [3539.0, 151.0], [3536.0, 139.0], [3519.0, 125.0], [3503.0, 129.0], [3481.0, 137.0], [3454.0, 124.0], [3433.0, 135.0], [3411.0, 138.0], [3408.0, 147.0], [3385.0, 150.0], [3378.0, 158.0], [3356.0, 148.0], [3354.0, 158.0], [3352.0, 166.0], [3340.0, 177.0], [3325.0, 195.0], [3322.0, 224.0], [3329.0, 238.0], [3352.0, 228.0], [3343.0, 234.0], [3336.0, 243.0], [3327.0, 254.0], [3328.0, 279.0], [3335.0, 304.0], [3332.0, 345.0], [3327.0, 334.0], [3326.0, 303.0], [3331.0, 293.0], [3323.0, 290.0], [3318.0, 279.0], [3294.0, 237.0], [3237.0, 212.0], [3230.0, 221.0], [3215.0, 219.0], [3219.0, 238.0], [3204.0, 255.0], [3216.0, 266.0], [3218.0, 284.0], [3218.0, 273.0], [3232.0, 275.0], [3245.0, 267.0], [3246.0, 283.0], [3252.0, 272.0], [3260.0, 282.0], [3251.0, 296.0], [3253.0, 319.0], [3243.0, 334.0], [3245.0, 359.0], [3235.0, 335.0], [3234.0, 317.0], [3229.0, 307.0], [3215.0, 301.0], [3212.0, 293.0], [3201.0, 292.0], [3197.0, 284.0], [3186.0, 281.0], [3181.0, 273.0], [3176.0, 283.0], [3181.0, 305.0], [3164.0, 321.0], [3166.0, 333.0], [3183.0, 349.0], [3182.0, 371.0], [3191.0, 379.0], [3179.0, 390.0], [3181.0, 415.0], [3163.0, 396.0], [3151.0, 374.0], [3146.0, 353.0], [3137.0, 356.0], [3124.0, 368.0], [3121.0, 356.0], [3095.0, 352.0], [3084.0, 345.0], [3073.0, 352.0], [3073.0, 360.0], [3067.0, 369.0], [3059.0, 367.0], [3054.0, 351.0], [3046.0, 354.0], [3035.0, 358.0], [3033.0, 368.0], [3032.0, 381.0], [3041.0, 381.0], [3040.0, 403.0], [3048.0, 413.0], [3056.0, 410.0], [3060.0, 419.0], [3057.0, 429.0], [3055.0, 413.0], [3039.0, 425.0], [3026.0, 419.0], [3022.0, 410.0], [3031.0, 406.0], [3008.0, 384.0], [3009.0, 373.0], [3003.0, 382.0], [2988.0, 386.0], [2991.0, 396.0], [2982.0, 397.0], [2980.0, 381.0], [2964.0, 385.0], [2973.0, 372.0], [2953.0, 364.0], [2926.0, 392.0], [2916.0, 389.0], [2917.0, 404.0], [2905.0, 414.0], [2885.0, 444.0], [2876.0, 464.0], [2889.0, 462.0], [2899.0, 466.0], [2911.0, 456.0], [2900.0, 467.0], [2912.0, 480.0], [2938.0, 493.0], [2937.0, 509.0], [2926.0, 506.0], [2921.0, 515.0], [2932.0, 501.0], [2926.0, 487.0], [2912.0, 482.0], [2903.0, 469.0], [2894.0, 465.0], [2884.0, 464.0], [2876.0, 474.0], [2878.0, 489.0], [2869.0, 506.0], [2865.0, 521.0], [2869.0, 532.0], [2862.0, 521.0], [2864.0, 539.0], [2857.0, 540.0], [2857.0, 530.0], [2830.0, 530.0], [2825.0, 540.0], [2821.0, 548.0], [2820.0, 558.0], [2812.0, 559.0], [2804.0, 569.0], [2794.0, 578.0], [2794.0, 592.0], [2803.0, 590.0], [2807.0, 598.0], [2799.0, 606.0], [2807.0, 599.0], [2820.0, 600.0], [2820.0, 610.0], [2807.0, 611.0], [2799.0, 614.0], [2799.0, 623.0], [2790.0, 623.0], [2791.0, 632.0], [2786.0, 641.0], [2781.0, 646.0], [2772.0, 646.0], [2768.0, 656.0], [2762.0, 673.0], [2761.0, 692.0], [2763.0, 702.0], [2775.0, 719.0], [2808.0, 731.0], [2820.0, 745.0], [2821.0, 757.0], [2830.0, 752.0], [2822.0, 757.0], [2827.0, 765.0], [2836.0, 760.0], [2841.0, 769.0], [2812.0, 765.0], [2804.0, 779.0], [2819.0, 788.0], [2922.0, 777.0], [2920.0, 767.0], [2889.0, 763.0], [2950.0, 766.0], [2948.0, 758.0], [2931.0, 760.0], [2922.0, 756.0], [2920.0, 741.0], [2924.0, 743.0], [2924.0, 751.0], [2932.0, 748.0], [2931.0, 758.0], [2947.0, 752.0], [2921.0, 726.0], [2932.0, 731.0], [2945.0, 733.0], [2950.0, 742.0], [2950.0, 757.0], [2968.0, 754.0], [2974.0, 775.0], [2986.0, 773.0], [2997.0, 769.0], [3001.0, 760.0], [2993.0, 723.0], [2948.0, 727.0], [2939.0, 716.0], [2943.0, 705.0], [2943.0, 718.0], [2951.0, 724.0], [2965.0, 720.0], [2964.0, 711.0], [2955.0, 697.0], [2965.0, 708.0], [2968.0, 720.0], [2977.0, 718.0], [2982.0, 710.0], [2973.0, 702.0], [2973.0, 688.0], [2986.0, 677.0], [3001.0, 662.0], [2980.0, 636.0], [3006.0, 661.0], [2997.0, 626.0], [3016.0, 655.0], [3030.0, 647.0], [3026.0, 625.0], [3031.0, 633.0], [3041.0, 633.0], [3039.0, 644.0], [3054.0, 645.0], [3055.0, 612.0], [3062.0, 638.0], [3064.0, 619.0], [3066.0, 627.0], [3073.0, 636.0], [3079.0, 637.0], [3079.0, 615.0], [3097.0, 615.0], [3086.0, 614.0], [3079.0, 627.0], [3086.0, 642.0], [3138.0, 673.0], [3126.0, 688.0], [3114.0, 701.0], [3114.0, 709.0], [3123.0, 714.0], [3127.0, 706.0], [3132.0, 693.0], [3143.0, 678.0], [3156.0, 686.0], [3162.0, 676.0], [3188.0, 663.0], [3189.0, 651.0], [3179.0, 639.0], [3189.0, 647.0], [3191.0, 661.0], [3199.0, 659.0], [3194.0, 669.0], [3203.0, 669.0], [3190.0, 672.0], [3158.0, 694.0], [3174.0, 695.0], [3166.0, 703.0], [3179.0, 709.0], [3169.0, 713.0], [3162.0, 703.0], [3152.0, 705.0], [3155.0, 730.0], [3142.0, 737.0], [3149.0, 722.0], [3150.0, 714.0], [3128.0, 741.0], [3127.0, 732.0], [3133.0, 724.0], [3124.0, 731.0], [3109.0, 733.0], [3109.0, 745.0], [3099.0, 743.0], [3071.0, 760.0], [3056.0, 766.0], [3039.0, 765.0], [3000.0, 790.0], [2978.0, 791.0], [2965.0, 802.0], [2951.0, 798.0], [2929.0, 805.0], [2908.0, 818.0], [2908.0, 841.0], [2954.0, 817.0], [2977.0, 812.0], [3071.0, 771.0], [3080.0, 767.0], [3146.0, 750.0], [3180.0, 749.0], [3193.0, 741.0], [3205.0, 741.0], [3204.0, 727.0], [3218.0, 717.0], [3209.0, 729.0], [3208.0, 737.0], [3221.0, 737.0], [3232.0, 740.0], [3252.0, 747.0], [3276.0, 744.0], [3278.0, 729.0], [3264.0, 721.0], [3259.0, 736.0], [3256.0, 726.0], [3246.0, 723.0], [3252.0, 713.0], [3250.0, 699.0], [3259.0, 692.0], [3270.0, 680.0], [3268.0, 688.0], [3276.0, 686.0], [3274.0, 677.0], [3268.0, 666.0], [3270.0, 658.0], [3270.0, 666.0], [3277.0, 681.0], [3280.0, 670.0], [3288.0, 674.0], [3284.0, 682.0], [3295.0, 670.0], [3307.0, 676.0], [3307.0, 687.0], [3317.0, 667.0], [3316.0, 655.0], [3325.0, 660.0], [3316.0, 685.0], [3326.0, 677.0], [3323.0, 686.0], [3327.0, 695.0], [3328.0, 707.0], [3315.0, 720.0], [3314.0, 734.0], [3325.0, 737.0], [3337.0, 734.0], [3328.0, 731.0], [3339.0, 733.0], [3334.0, 722.0], [3342.0, 722.0], [3341.0, 710.0], [3344.0, 699.0], [3358.0, 707.0], [3367.0, 704.0], [3378.0, 692.0], [3379.0, 677.0], [3371.0, 668.0], [3382.0, 673.0], [3389.0, 686.0], [3400.0, 687.0], [3411.0, 688.0], [3414.0, 675.0], [3405.0, 663.0], [3406.0, 649.0], [3406.0, 657.0], [3423.0, 670.0], [3421.0, 654.0], [3426.0, 680.0], [3426.0, 669.0], [3428.0, 680.0], [3427.0, 669.0], [3429.0, 679.0], [3430.0, 655.0], [3438.0, 684.0], [3440.0, 663.0], [3444.0, 683.0], [3452.0, 672.0], [3450.0, 648.0], [3441.0, 646.0], [3440.0, 632.0], [3444.0, 645.0], [3453.0, 632.0], [3457.0, 671.0], [3469.0, 662.0], [3468.0, 648.0], [3465.0, 639.0], [3469.0, 648.0], [3482.0, 657.0], [3485.0, 643.0], [3478.0, 633.0], [3485.0, 642.0], [3485.0, 631.0], [3491.0, 648.0], [3499.0, 648.0], [3504.0, 657.0], [3507.0, 649.0], [3498.0, 638.0], [3509.0, 647.0], [3505.0, 618.0], [3513.0, 649.0], [3521.0, 655.0], [3529.0, 640.0], [3528.0, 648.0], [3539.0, 654.0], [3538.0, 676.0], [3547.0, 684.0], [3548.0, 676.0], [3556.0, 661.0], [3547.0, 661.0], [3566.0, 646.0], [3572.0, 632.0], [3578.0, 622.0], [3579.0, 632.0], [3580.0, 617.0], [3581.0, 636.0], [3589.0, 632.0], [3591.0, 619.0], [3595.0, 611.0], [3593.0, 624.0], [3596.0, 611.0], [3594.0, 622.0], [3603.0, 640.0], [3612.0, 637.0], [3616.0, 624.0], [3619.0, 631.0], [3619.0, 616.0], [3622.0, 626.0], [3628.0, 617.0], [3622.0, 608.0], [3631.0, 604.0], [3626.0, 628.0], [3635.0, 629.0], [3646.0, 633.0], [3649.0, 618.0], [3659.0, 623.0], [3660.0, 614.0], [3664.0, 624.0], [3672.0, 625.0], [3675.0, 621.0], [3675.0, 601.0], [3682.0, 624.0], [3682.0, 614.0], [3684.0, 625.0], [3688.0, 617.0], [3696.0, 625.0], [3696.0, 610.0], [3696.0, 624.0], [3705.0, 623.0], [3708.0, 605.0], [3711.0, 619.0], [3725.0, 619.0], [3717.0, 615.0], [3714.0, 605.0], [3712.0, 597.0], [3719.0, 609.0], [3723.0, 594.0], [3725.0, 602.0], [3729.0, 615.0], [3731.0, 601.0], [3744.0, 603.0], [3742.0, 585.0], [3745.0, 604.0], [3755.0, 603.0], [3759.0, 593.0], [3753.0, 578.0], [3759.0, 594.0], [3769.0, 599.0], [3769.0, 582.0], [3774.0, 591.0], [3786.0, 587.0], [3787.0, 573.0], [3788.0, 562.0], [3789.0, 588.0], [3791.0, 576.0], [3791.0, 585.0], [3795.0, 575.0], [3807.0, 575.0], [3805.0, 567.0], [3808.0, 577.0], [3806.0, 567.0], [3811.0, 576.0], [3808.0, 566.0], [3811.0, 575.0], [3810.0, 566.0], [3813.0, 575.0], [3813.0, 566.0], [3818.0, 578.0], [3821.0, 566.0], [3813.0, 562.0], [3815.0, 545.0], [3815.0, 558.0], [3820.0, 564.0], [3820.0, 554.0], [3822.0, 563.0], [3824.0, 555.0], [3827.0, 564.0], [3833.0, 556.0], [3831.0, 564.0], [3840.0, 549.0], [3843.0, 567.0], [3844.0, 558.0], [3848.0, 568.0], [3874.0, 569.0], [3871.0, 561.0], [3893.0, 547.0], [3887.0, 537.0], [3894.0, 548.0], [3903.0, 539.0], [3915.0, 532.0], [3901.0, 517.0], [3915.0, 531.0], [3914.0, 521.0], [3932.0, 517.0], [3924.0, 517.0], [3923.0, 494.0], [3931.0, 505.0], [3942.0, 489.0], [3939.0, 504.0], [3951.0, 499.0], [3951.0, 485.0], [3953.0, 490.0], [3953.0, 501.0], [3952.0, 511.0], [3951.0, 522.0], [3956.0, 514.0], [3955.0, 525.0], [3963.0, 520.0], [3964.0, 528.0], [3981.0, 513.0], [3983.0, 491.0], [3983.0, 507.0], [3980.0, 517.0], [3999.0, 530.0], [4002.0, 518.0], [4010.0, 512.0], [4007.0, 520.0], [4015.0, 515.0], [4006.0, 521.0], [4020.0, 536.0], [4022.0, 527.0], [4022.0, 537.0], [4053.0, 542.0], [4062.0, 541.0], [4069.0, 516.0], [4081.0, 505.0], [4085.0, 489.0], [4095.0, 491.0], [4118.0, 501.0], [4127.0, 503.0], [4139.0, 498.0], [4176.0, 494.0], [4195.0, 485.0], [4203.0, 471.0], [4201.0, 484.0], [4224.0, 482.0], [4223.0, 473.0], [4224.0, 476.0], [4224.0, 589.0], [4215.0, 588.0], [4206.0, 596.0], [4176.0, 603.0], [4128.0, 621.0], [4095.0, 627.0], [4080.0, 631.0], [4069.0, 636.0], [4050.0, 635.0], [4033.0, 648.0], [4010.0, 651.0], [3998.0, 657.0], [3997.0, 648.0], [3976.0, 661.0], [3952.0, 670.0], [3936.0, 670.0], [3906.0, 681.0], [3854.0, 682.0], [3844.0, 675.0], [3832.0, 683.0], [3837.0, 677.0], [3818.0, 677.0], [3826.0, 689.0], [3840.0, 691.0], [3915.0, 686.0], [4095.0, 635.0], [4224.0, 592.0], [4224.0, 4224.0], [-128.0, 4224.0], [-128.0, 4224.0]], [[1747.0, 3583.0], [1749.0, 3583.0]], [[2049.0, 3132.0], [2049.0, 3134.0]], [[2049.0, 3135.0], [2049.0, 3136.0]], [[2175.0, 3040.0], [2177.0, 3039.0]], [[2177.0, 3039.0], [2178.0, 3039.0]], [[2175.0, 3009.0], [2175.0, 3008.0]], [[2175.0, 3004.0], [2175.0, 3001.0]], [[1025.0, 2978.0], [1025.0, 2985.0]], [[2303.0, 2955.0], [2303.0, 2957.0]], [[2176.0, 2986.0], [2173.0, 2987.0]], [[2304.0, 2928.0], [2304.0, 2926.0]], [[2305.0, 2923.0], [2306.0, 2924.0]], [[2303.0, 2915.0], [2304.0, 2913.0]], [[2561.0, 2531.0], [2562.0, 2532.0]], [[2551.0, 2559.0], [2551.0, 2559.0]], [[2559.0, 2427.0], [2559.0, 2427.0]], [[2559.0, 2206.0], [2561.0, 2206.0]], [[2639.0, 2049.0], [2638.0, 2049.0]], [[3071.0, 361.0], [3071.0, 360.0]], [[855.0, 2206.0], [855.0, 2206.0], [855.0, 2206.0]], [[854.0, 2206.0], [843.0, 2201.0], [834.0, 2208.0], [843.0, 2201.0], [854.0, 2206.0], [854.0, 2206.0]], [[2494.0, 2527.0], [2494.0, 2527.0], [2494.0, 2527.0]], [[2495.0, 2527.0], [2500.0, 2533.0], [2504.0, 2543.0], [2509.0, 2534.0], [2500.0, 2524.0], [2507.0, 2535.0], [2495.0, 2527.0], [2495.0, 2527.0]], [[2504.0, 2513.0], [2504.0, 2523.0], [2504.0, 2513.0], [2504.0, 2513.0]], [[2607.0, 2128.0], [2607.0, 2128.0], [2607.0, 2128.0]], [[2637.0, 2042.0], [2637.0, 2042.0], [2637.0, 2042.0]], [[2637.0, 2042.0], [2637.0, 2042.0], [2637.0, 2042.0]], [[2665.0, 2017.0], [2665.0, 2017.0], [2665.0, 2017.0]], [[2635.0, 1757.0], [2635.0, 1757.0], [2635.0, 1757.0]], [[2635.0, 1757.0], [2635.0, 1757.0], [2635.0, 1757.0]], [[2721.0, 1892.0], [2721.0, 1892.0], [2721.0, 1892.0]], [[2720.0, 1893.0], [2720.0, 1893.0], [2720.0, 1893.0]], [[2773.0, 1679.0], [2773.0, 1679.0], [2773.0, 1679.0]], [[2444.0, 991.0], [2444.0, 991.0], [2444.0, 991.0]], [[3178.0, 128.0], [3178.0, 128.0], [3178.0, 128.0]], [[3176.0, 130.0], [3176.0, 130.0], [3176.0, 130.0]], [[2799.0, 606.0], [2794.0, 593.0], [2799.0, 606.0], [2799.0, 606.0]], [[2049.0, 3181.0], [2049.0, 3183.0], [2037.0, 3194.0], [2047.0, 3190.0], [2110.0, 3142.0], [2156.0, 3116.0], [2224.0, 3084.0], [2223.0, 3073.0], [2217.0, 3057.0], [2208.0, 3059.0], [2196.0, 3054.0], [2207.0, 3053.0], [2204.0, 3040.0], [2195.0, 3037.0], [2186.0, 3045.0], [2186.0, 3034.0], [2177.0, 3037.0], [2169.0, 3036.0], [2165.0, 3044.0], [2169.0, 3055.0], [2177.0, 3048.0], [2175.0, 3061.0], [2174.0, 3073.0], [2174.0, 3084.0], [2162.0, 3092.0], [2155.0, 3102.0], [2126.0, 3123.0], [2118.0, 3121.0], [2109.0, 3127.0], [2103.0, 3102.0], [2102.0, 3114.0], [2075.0, 3126.0], [2079.0, 3144.0], [2069.0, 3142.0], [2069.0, 3151.0], [2059.0, 3159.0], [2049.0, 3181.0], [2049.0, 3181.0]], [[2206.0, 3059.0], [2206.0, 3059.0], [2206.0, 3059.0]], [[1578.0, 3811.0], [1578.0, 3811.0], [1578.0, 3811.0]], [[1577.0, 3813.0], [1577.0, 3813.0], [1577.0, 3813.0]], [[1578.0, 3811.0], [1580.0, 3811.0], [1580.0, 3819.0], [1575.0, 3827.0], [1566.0, 3832.0], [1558.0, 3844.0], [1563.0, 3846.0], [1551.0, 3846.0], [1555.0, 3864.0], [1567.0, 3883.0], [1601.0, 3834.0], [1675.0, 3768.0], [1675.0, 3752.0], [1665.0, 3738.0], [1662.0, 3717.0], [1656.0, 3727.0], [1658.0, 3712.0], [1643.0, 3707.0], [1641.0, 3717.0], [1643.0, 3725.0], [1653.0, 3726.0], [1645.0, 3740.0], [1642.0, 3750.0], [1647.0, 3758.0], [1648.0, 3748.0], [1658.0, 3750.0], [1657.0, 3738.0], [1659.0, 3751.0], [1647.0, 3759.0], [1635.0, 3756.0], [1620.0, 3755.0], [1633.0, 3767.0], [1625.0, 3770.0], [1611.0, 3773.0], [1621.0, 3786.0], [1610.0, 3776.0], [1612.0, 3786.0], [1598.0, 3815.0], [1589.0, 3821.0], [1578.0, 3811.0], [1578.0, 3811.0]], [[1370.0, 3184.0], [1370.0, 3184.0], [1370.0, 3184.0]], [[1370.0, 3184.0], [1373.0, 3183.0], [1370.0, 3184.0], [1370.0, 3184.0]], [[2304.0, 2930.0], [2298.0, 2928.0], [2312.0, 2926.0], [2301.0, 2920.0], [2292.0, 2925.0], [2295.0, 2914.0], [2304.0, 2919.0], [2313.0, 2926.0], [2324.0, 2928.0], [2335.0, 2918.0], [2331.0, 2908.0], [2320.0, 2918.0], [2307.0, 2911.0], [2321.0, 2916.0], [2329.0, 2906.0], [2316.0, 2904.0], [2319.0, 2896.0], [2308.0, 2899.0], [2303.0, 2891.0], [2290.0, 2890.0], [2287.0, 2880.0], [2286.0, 2892.0], [2283.0, 2911.0], [2296.0, 2905.0], [2288.0, 2907.0], [2288.0, 2922.0], [2279.0, 2926.0], [2287.0, 2927.0], [2292.0, 2938.0], [2303.0, 2934.0], [2313.0, 2929.0], [2304.0, 2930.0], [2304.0, 2930.0]], [[2316.0, 2895.0], [2317.0, 2895.0], [2316.0, 2901.0], [2331.0, 2901.0], [2332.0, 2889.0], [2321.0, 2885.0], [2323.0, 2898.0], [2325.0, 2889.0], [2315.0, 2882.0], [2308.0, 2896.0], [2316.0, 2895.0], [2316.0, 2895.0]], [[2304.0, 2850.0], [2300.0, 2851.0], [2311.0, 2859.0], [2323.0, 2868.0], [2339.0, 2871.0], [2335.0, 2861.0], [2341.0, 2873.0], [2341.0, 2885.0], [2350.0, 2887.0], [2341.0, 2887.0], [2335.0, 2895.0], [2336.0, 2906.0], [2344.0, 2910.0], [2369.0, 2866.0], [2369.0, 2856.0], [2357.0, 2828.0], [2348.0, 2824.0], [2349.0, 2840.0], [2346.0, 2854.0], [2335.0, 2839.0], [2339.0, 2853.0], [2331.0, 2857.0], [2317.0, 2851.0], [2304.0, 2850.0], [2304.0, 2850.0]], [[2344.0, 2910.0], [2344.0, 2910.0], [2344.0, 2910.0]], [[2344.0, 2910.0], [2344.0, 2910.0], [2344.0, 2910.0]], [[2151.0, 2999.0], [2152.0, 2999.0], [2162.0, 3004.0], [2151.0, 2999.0], [2151.0, 2999.0]], [[2151.0, 2999.0], [2151.0, 2999.0], [2151.0, 2999.0]], [[2203.0, 2998.0], [2197.0, 2989.0], [2195.0, 2997.0], [2203.0, 2998.0], [2203.0, 2998.0]], [[2195.0, 2997.0], [2195.0, 2997.0], [2195.0, 2997.0]], [[2195.0, 2997.0], [2195.0, 2997.0], [2195.0, 2997.0]], [[2205.0, 2728.0], [2212.0, 2734.0], [2212.0, 2719.0], [2205.0, 2728.0], [2205.0, 2728.0]], [[2212.0, 2734.0], [2212.0, 2734.0], [2212.0, 2734.0]], [[3840.0, 644.0], [3835.0, 649.0], [3857.0, 646.0], [3851.0, 637.0], [3840.0, 644.0], [3840.0, 644.0]], [[3835.0, 649.0], [3835.0, 649.0], [3835.0, 649.0]], [[3835.0, 649.0], [3835.0, 649.0], [3835.0, 649.0]], [[3451.0, 713.0], [3451.0, 713.0], [3451.0, 713.0]], [[3451.0, 711.0], [3451.0, 711.0], [3451.0, 711.0]], [[3911.0, 623.0], [3911.0, 623.0], [3911.0, 623.0]], [[3911.0, 623.0], [3911.0, 623.0], [3911.0, 623.0]], [[2048.0, 3128.0], [2040.0, 3140.0], [2049.0, 3147.0], [2049.0, 3155.0], [2057.0, 3142.0], [2049.0, 3135.0], [2058.0, 3126.0], [2048.0, 3128.0], [2048.0, 3128.0]], [[2047.0, 3163.0], [2042.0, 3168.0], [2038.0, 3148.0], [2012.0, 3172.0], [2008.0, 3180.0], [2009.0, 3189.0], [2043.0, 3172.0], [2047.0, 3173.0], [2047.0, 3163.0], [2047.0, 3163.0]], [[1714.0, 3583.0], [1714.0, 3583.0], [1720.0, 3595.0], [1719.0, 3607.0], [1709.0, 3606.0], [1706.0, 3619.0], [1721.0, 3630.0], [1725.0, 3638.0], [1722.0, 3649.0], [1714.0, 3654.0], [1712.0, 3664.0], [1702.0, 3665.0], [1698.0, 3688.0], [1695.0, 3700.0], [1681.0, 3706.0], [1682.0, 3717.0], [1697.0, 3707.0], [1743.0, 3631.0], [1782.0, 3583.0], [1797.0, 3564.0], [1775.0, 3544.0], [1780.0, 3552.0], [1781.0, 3560.0], [1765.0, 3583.0], [1759.0, 3592.0], [1749.0, 3589.0], [1757.0, 3587.0], [1747.0, 3583.0], [1735.0, 3586.0], [1734.0, 3595.0], [1744.0, 3599.0], [1746.0, 3591.0], [1746.0, 3603.0], [1752.0, 3595.0], [1747.0, 3605.0], [1755.0, 3598.0], [1732.0, 3633.0], [1742.0, 3604.0], [1731.0, 3596.0], [1724.0, 3583.0], [1720.0, 3574.0], [1712.0, 3574.0], [1714.0, 3583.0], [1714.0, 3583.0]], [[1537.0, 3873.0], [1553.0, 3869.0], [1546.0, 3859.0], [1536.0, 3859.0], [1508.0, 3867.0], [1500.0, 3864.0], [1502.0, 3854.0], [1492.0, 3859.0], [1475.0, 3878.0], [1495.0, 3855.0], [1456.0, 3827.0], [1419.0, 3828.0], [1405.0, 3876.0], [1410.0, 3886.0], [1420.0, 3894.0], [1451.0, 3893.0], [1461.0, 3898.0], [1493.0, 3895.0], [1537.0, 3873.0], [1537.0, 3873.0]], [[1537.0, 3849.0], [1544.0, 3849.0], [1536.0, 3837.0], [1526.0, 3847.0], [1537.0, 3849.0], [1537.0, 3849.0]], [[1535.0, 3837.0], [1535.0, 3836.0], [1545.0, 3848.0], [1551.0, 3833.0], [1541.0, 3830.0], [1535.0, 3837.0], [1535.0, 3837.0]], [[1597.0, 3801.0], [1602.0, 3800.0], [1594.0, 3793.0], [1597.0, 3801.0], [1597.0, 3801.0]], [[1574.0, 3798.0], [1568.0, 3800.0], [1573.0, 3808.0], [1582.0, 3802.0], [1574.0, 3798.0], [1574.0, 3798.0]], [[1707.0, 3657.0], [1699.0, 3648.0], [1705.0, 3635.0], [1683.0, 3630.0], [1679.0, 3643.0], [1688.0, 3651.0], [1695.0, 3657.0], [1707.0, 3657.0], [1707.0, 3657.0]], [[1699.0, 3678.0], [1699.0, 3678.0], [1699.0, 3678.0]], [[1552.0, 3843.0], [1552.0, 3843.0], [1552.0, 3843.0]], [[1607.0, 3779.0], [1600.0, 3764.0], [1591.0, 3765.0], [1590.0, 3753.0], [1582.0, 3770.0], [1596.0, 3777.0], [1598.0, 3791.0], [1607.0, 3779.0], [1607.0, 3779.0]], [[1701.0, 3627.0], [1695.0, 3621.0], [1697.0, 3607.0], [1693.0, 3598.0], [1685.0, 3606.0], [1681.0, 3624.0], [1687.0, 3627.0], [1701.0, 3627.0], [1701.0, 3627.0]], [[1585.0, 3757.0], [1585.0, 3757.0], [1585.0, 3757.0]], [[1626.0, 3762.0], [1614.0, 3751.0], [1607.0, 3762.0], [1607.0, 3774.0], [1618.0, 3767.0], [1629.0, 3771.0], [1626.0, 3762.0], [1626.0, 3762.0]], [[1706.0, 3669.0], [1700.0, 3667.0], [1706.0, 3669.0], [1706.0, 3669.0]], [[1756.0, 3583.0], [1759.0, 3583.0], [1767.0, 3578.0], [1754.0, 3569.0], [1742.0, 3575.0], [1756.0, 3583.0], [1756.0, 3583.0]], [[2034.0, 3135.0], [2034.0, 3135.0], [2034.0, 3135.0]], [[1765.0, 3569.0], [1771.0, 3572.0], [1756.0, 3560.0], [1765.0, 3569.0], [1765.0, 3569.0]], [[1770.0, 3573.0], [1756.0, 3568.0], [1770.0, 3573.0], [1770.0, 3573.0]], [[1763.0, 3543.0], [1772.0, 3538.0], [1771.0, 3524.0], [1753.0, 3525.0], [1745.0, 3532.0], [1728.0, 3525.0], [1744.0, 3539.0], [1763.0, 3543.0], [1763.0, 3543.0]], [[1709.0, 3569.0], [1709.0, 3569.0], [1709.0, 3569.0]], [[1736.0, 3539.0], [1736.0, 3539.0], [1736.0, 3539.0]], [[1739.0, 3544.0], [1739.0, 3544.0], [1739.0, 3544.0]], [[1791.0, 3485.0], [1791.0, 3485.0], [1791.0, 3485.0]], [[1849.0, 3373.0], [1867.0, 3355.0], [1867.0, 3346.0], [1850.0, 3363.0], [1849.0, 3373.0], [1849.0, 3373.0]], [[1858.0, 3351.0], [1867.0, 3346.0], [1864.0, 3337.0], [1858.0, 3337.0], [1858.0, 3351.0], [1858.0, 3351.0]], [[1963.0, 3207.0], [1966.0, 3211.0], [1977.0, 3201.0], [1967.0, 3196.0], [1957.0, 3199.0], [1963.0, 3207.0], [1963.0, 3207.0]], [[1960.0, 3190.0], [1955.0, 3192.0], [1960.0, 3190.0], [1960.0, 3190.0]], [[1963.0, 3186.0], [1959.0, 3188.0], [1972.0, 3191.0], [1963.0, 3186.0], [1963.0, 3186.0]], [[1962.0, 3230.0], [1962.0, 3230.0], [1962.0, 3230.0]], [[1978.0, 3199.0], [1979.0, 3203.0], [1967.0, 3210.0], [1975.0, 3212.0], [1978.0, 3199.0], [1978.0, 3199.0]], [[1970.0, 3220.0], [1970.0, 3220.0], [1970.0, 3220.0]], [[1965.0, 3227.0], [1959.0, 3233.0], [1965.0, 3227.0], [1965.0, 3227.0]], [[1739.0, 3545.0], [1734.0, 3542.0], [1745.0, 3549.0], [1754.0, 3543.0], [1739.0, 3545.0], [1739.0, 3545.0]], [[1470.0, 3408.0], [1478.0, 3409.0], [1482.0, 3396.0], [1474.0, 3399.0], [1470.0, 3408.0], [1470.0, 3408.0]], [[1373.0, 3252.0], [1383.0, 3251.0], [1384.0, 3242.0], [1373.0, 3252.0], [1373.0, 3252.0]], [[1385.0, 3243.0], [1386.0, 3252.0], [1385.0, 3243.0], [1385.0, 3243.0]], [[1388.0, 3254.0], [1391.0, 3254.0], [1393.0, 3235.0], [1388.0, 3254.0], [1388.0, 3254.0]], [[1385.0, 3241.0], [1385.0, 3241.0], [1385.0, 3241.0]], [[1363.0, 3263.0], [1372.0, 3257.0], [1366.0, 3246.0], [1355.0, 3254.0], [1363.0, 3263.0], [1363.0, 3263.0]], [[1375.0, 3270.0], [1379.0, 3260.0], [1364.0, 3268.0], [1375.0, 3270.0], [1375.0, 3270.0]], [[1345.0, 3298.0], [1357.0, 3301.0], [1345.0, 3298.0], [1345.0, 3298.0]], [[1324.0, 3340.0], [1320.0, 3337.0], [1332.0, 3336.0], [1324.0, 3340.0], [1324.0, 3340.0]], [[1389.0, 3392.0], [1390.0, 3402.0], [1403.0, 3394.0], [1389.0, 3392.0], [1389.0, 3392.0]], [[1400.0, 3383.0], [1410.0, 3386.0], [1400.0, 3383.0], [1400.0, 3383.0]], [[1367.0, 3279.0], [1368.0, 3276.0], [1358.0, 3270.0], [1360.0, 3279.0], [1371.0, 3285.0], [1376.0, 3277.0], [1367.0, 3279.0], [1367.0, 3279.0]], [[2547.0, 2560.0], [2550.0, 2563.0], [2537.0, 2585.0], [2527.0, 2605.0], [2518.0, 2609.0], [2507.0, 2631.0], [2495.0, 2652.0], [2489.0, 2656.0], [2489.0, 2671.0], [2477.0, 2683.0], [2477.0, 2691.0], [2471.0, 2699.0], [2463.0, 2703.0], [2459.0, 2719.0], [2447.0, 2724.0], [2448.0, 2738.0], [2438.0, 2750.0], [2436.0, 2758.0], [2421.0, 2770.0], [2414.0, 2787.0], [2405.0, 2799.0], [2394.0, 2808.0], [2383.0, 2815.0], [2375.0, 2815.0], [2384.0, 2825.0], [2403.0, 2810.0], [2470.0, 2722.0], [2559.0, 2562.0], [2620.0, 2456.0], [2658.0, 2368.0], [2685.0, 2336.0], [2667.0, 2324.0], [2658.0, 2346.0], [2649.0, 2342.0], [2648.0, 2334.0], [2648.0, 2310.0], [2648.0, 2332.0], [2638.0, 2337.0], [2640.0, 2347.0], [2649.0, 2350.0], [2646.0, 2368.0], [2642.0, 2376.0], [2641.0, 2384.0], [2626.0, 2389.0], [2634.0, 2389.0], [2639.0, 2397.0], [2630.0, 2395.0], [2638.0, 2399.0], [2630.0, 2395.0], [2638.0, 2400.0], [2627.0, 2396.0], [2636.0, 2403.0], [2624.0, 2397.0], [2629.0, 2406.0], [2626.0, 2422.0], [2621.0, 2431.0], [2617.0, 2442.0], [2609.0, 2453.0], [2618.0, 2451.0], [2609.0, 2458.0], [2607.0, 2469.0], [2589.0, 2498.0], [2569.0, 2511.0], [2565.0, 2519.0], [2561.0, 2527.0], [2552.0, 2536.0], [2548.0, 2550.0], [2547.0, 2560.0], [2547.0, 2560.0]], [[2176.0, 3057.0], [2176.0, 3055.0], [2166.0, 3056.0], [2162.0, 3069.0], [2172.0, 3068.0], [2176.0, 3057.0], [2176.0, 3057.0]], [[2107.0, 3073.0], [2110.0, 3083.0], [2098.0, 3093.0], [2107.0, 3098.0], [2117.0, 3090.0], [2127.0, 3086.0], [2144.0, 3086.0], [2132.0, 3073.0], [2122.0, 3073.0], [2113.0, 3059.0], [2107.0, 3073.0], [2107.0, 3073.0]], [[2117.0, 3120.0], [2125.0, 3122.0], [2128.0, 3114.0], [2137.0, 3112.0], [2142.0, 3101.0], [2156.0, 3099.0], [2164.0, 3083.0], [2151.0, 3082.0], [2144.0, 3090.0], [2129.0, 3094.0], [2121.0, 3093.0], [2106.0, 3103.0], [2117.0, 3120.0], [2117.0, 3120.0]], [[2303.0, 2981.0], [2303.0, 2981.0], [2289.0, 2967.0], [2265.0, 2977.0], [2273.0, 2988.0], [2284.0, 2993.0], [2275.0, 3005.0], [2265.0, 3009.0], [2254.0, 3021.0], [2238.0, 3022.0], [2226.0, 3010.0], [2229.0, 3025.0], [2218.0, 3031.0], [2210.0, 3035.0], [2215.0, 3043.0], [2218.0, 3034.0], [2235.0, 3027.0], [2230.0, 3041.0], [2216.0, 3043.0], [2232.0, 3064.0], [2286.0, 3012.0], [2303.0, 2989.0], [2338.0, 2945.0], [2344.0, 2928.0], [2330.0, 2936.0], [2305.0, 2942.0], [2307.0, 2954.0], [2313.0, 2941.0], [2320.0, 2951.0], [2321.0, 2943.0], [2329.0, 2947.0], [2322.0, 2957.0], [2309.0, 2959.0], [2307.0, 2967.0], [2303.0, 2981.0], [2303.0, 2981.0]], [[2304.0, 2858.0], [2292.0, 2854.0], [2291.0, 2864.0], [2301.0, 2863.0], [2297.0, 2875.0], [2305.0, 2877.0], [2303.0, 2885.0], [2316.0, 2875.0], [2324.0, 2870.0], [2311.0, 2866.0], [2304.0, 2858.0], [2304.0, 2858.0]], [[2303.0, 2885.0], [2295.0, 2882.0], [2286.0, 2872.0], [2271.0, 2863.0], [2271.0, 2877.0], [2287.0, 2877.0], [2292.0, 2887.0], [2303.0, 2885.0], [2303.0, 2885.0]], [[2303.0, 2963.0], [2303.0, 2955.0], [2301.0, 2945.0], [2289.0, 2951.0], [2291.0, 2960.0], [2300.0, 2955.0], [2303.0, 2963.0], [2303.0, 2963.0]], [[2317.0, 2958.0], [2317.0, 2958.0], [2317.0, 2958.0]], [[2175.0, 3002.0], [2173.0, 3007.0], [2185.0, 3008.0], [2183.0, 3000.0], [2175.0, 3001.0], [2181.0, 2991.0], [2179.0, 2983.0], [2163.0, 2987.0], [2166.0, 2997.0], [2174.0, 2998.0], [2164.0, 3000.0], [2152.0, 2991.0], [2175.0, 3002.0], [2175.0, 3002.0]], [[2175.0, 3012.0], [2161.0, 3009.0], [2175.0, 3012.0], [2175.0, 3012.0]], [[2175.0, 3022.0], [2166.0, 3019.0], [2153.0, 3026.0], [2150.0, 3017.0], [2150.0, 3025.0], [2141.0, 3029.0], [2145.0, 3038.0], [2144.0, 3048.0], [2154.0, 3046.0], [2153.0, 3037.0], [2159.0, 3031.0], [2175.0, 3031.0], [2184.0, 3030.0], [2175.0, 3022.0], [2175.0, 3022.0]], [[2161.0, 3068.0], [2165.0, 3054.0], [2161.0, 3068.0], [2161.0, 3068.0]], [[2158.0, 3018.0], [2161.0, 3014.0], [2150.0, 3009.0], [2158.0, 3018.0], [2158.0, 3018.0]], [[2255.0, 2896.0], [2257.0, 2892.0], [2255.0, 2896.0], [2255.0, 2896.0]], [[2212.0, 3026.0], [2194.0, 3013.0], [2199.0, 3026.0], [2212.0, 3026.0], [2212.0, 3026.0]], [[2212.0, 3025.0], [2216.0, 3018.0], [2202.0, 3012.0], [2212.0, 3025.0], [2212.0, 3025.0]], [[2198.0, 3015.0], [2201.0, 3009.0], [2190.0, 3009.0], [2198.0, 3015.0], [2198.0, 3015.0]], [[2221.0, 2867.0], [2217.0, 2869.0], [2221.0, 2867.0], [2221.0, 2867.0]], [[2232.0, 2946.0], [2236.0, 2939.0], [2226.0, 2934.0], [2218.0, 2933.0], [2210.0, 2929.0], [2217.0, 2943.0], [2232.0, 2946.0], [2232.0, 2946.0]], [[2221.0, 2945.0], [2216.0, 2944.0], [2214.0, 2932.0], [2221.0, 2945.0], [2221.0, 2945.0]], [[2211.0, 2928.0], [2211.0, 2928.0], [2211.0, 2928.0]], [[2199.0, 2909.0], [2209.0, 2930.0], [2212.0, 2922.0], [2199.0, 2909.0], [2199.0, 2909.0]], [[2233.0, 2855.0], [2233.0, 2862.0], [2247.0, 2868.0], [2246.0, 2860.0], [2233.0, 2855.0], [2233.0, 2855.0]], [[2247.0, 2858.0], [2247.0, 2867.0], [2247.0, 2858.0], [2247.0, 2858.0]], [[2252.0, 2980.0], [2252.0, 2980.0], [2252.0, 2980.0]], [[2261.0, 2946.0], [2246.0, 2954.0], [2258.0, 2957.0], [2261.0, 2946.0], [2261.0, 2946.0]], [[2232.0, 2948.0], [2223.0, 2948.0], [2232.0, 2948.0], [2232.0, 2948.0]], [[2226.0, 2970.0], [2226.0, 2966.0], [2234.0, 2963.0], [2222.0, 2947.0], [2226.0, 2970.0], [2226.0, 2970.0]], [[2286.0, 2913.0], [2282.0, 2923.0], [2286.0, 2913.0], [2286.0, 2913.0]], [[2280.0, 2933.0], [2280.0, 2933.0], [2280.0, 2933.0]], [[2277.0, 2940.0], [2278.0, 2934.0], [2268.0, 2925.0], [2277.0, 2940.0], [2277.0, 2940.0]], [[2274.0, 2940.0], [2266.0, 2931.0], [2274.0, 2940.0], [2274.0, 2940.0]], [[2236.0, 2976.0], [2235.0, 2970.0], [2223.0, 2973.0], [2236.0, 2976.0], [2236.0, 2976.0]], [[2224.0, 3021.0], [2224.0, 3021.0], [2224.0, 3021.0]], [[2197.0, 2986.0], [2203.0, 2995.0], [2197.0, 2986.0], [2197.0, 2986.0]], [[2190.0, 3027.0], [2190.0, 3027.0], [2190.0, 3027.0]], [[2193.0, 2974.0], [2190.0, 2973.0], [2186.0, 2983.0], [2193.0, 2974.0], [2193.0, 2974.0]], [[2187.0, 3040.0], [2187.0, 3040.0], [2187.0, 3040.0]], [[2210.0, 2987.0], [2214.0, 2986.0], [2222.0, 2978.0], [2214.0, 2975.0], [2209.0, 2963.0], [2216.0, 2983.0], [2207.0, 2980.0], [2199.0, 2984.0], [2210.0, 2987.0], [2210.0, 2987.0]], [[2284.0, 2949.0], [2285.0, 2949.0], [2269.0, 2945.0], [2278.0, 2951.0], [2284.0, 2949.0], [2284.0, 2949.0]], [[2265.0, 2953.0], [2270.0, 2950.0], [2262.0, 2950.0], [2263.0, 2958.0], [2254.0, 2963.0], [2253.0, 2979.0], [2257.0, 2969.0], [2261.0, 2979.0], [2269.0, 2970.0], [2279.0, 2966.0], [2265.0, 2953.0], [2265.0, 2953.0]], [[2208.0, 2871.0], [2211.0, 2873.0], [2219.0, 2866.0], [2209.0, 2866.0], [2219.0, 2865.0], [2206.0, 2861.0], [2208.0, 2871.0], [2208.0, 2871.0]], [[2229.0, 2928.0], [2237.0, 2939.0], [2236.0, 2924.0], [2239.0, 2920.0], [2239.0, 2933.0], [2250.0, 2928.0], [2241.0, 2922.0], [2239.0, 2914.0], [2224.0, 2900.0], [2237.0, 2914.0], [2228.0, 2925.0], [2229.0, 2928.0], [2229.0, 2928.0]], [[2330.0, 2792.0], [2332.0, 2787.0], [2314.0, 2776.0], [2330.0, 2792.0], [2330.0, 2792.0]], [[2319.0, 2884.0], [2331.0, 2882.0], [2340.0, 2881.0], [2334.0, 2871.0], [2319.0, 2884.0], [2319.0, 2884.0]], [[2331.0, 2901.0], [2331.0, 2901.0], [2331.0, 2901.0]], [[2324.0, 2799.0], [2312.0, 2778.0], [2324.0, 2799.0], [2324.0, 2799.0]], [[2319.0, 2798.0], [2312.0, 2789.0], [2319.0, 2798.0], [2319.0, 2798.0]], [[2325.0, 2807.0], [2316.0, 2803.0], [2325.0, 2807.0], [2325.0, 2807.0]], [[2047.0, 2683.0], [2047.0, 2676.0], [2038.0, 2670.0], [2047.0, 2683.0], [2047.0, 2683.0]], [[2178.0, 2723.0], [2174.0, 2733.0], [2187.0, 2742.0], [2178.0, 2723.0], [2178.0, 2723.0]], [[2191.0, 2724.0], [2191.0, 2724.0], [2191.0, 2724.0]], [[2051.0, 2679.0], [2051.0, 2679.0], [2051.0, 2679.0]], [[2561.0, 2451.0], [2569.0, 2450.0], [2563.0, 2441.0], [2561.0, 2451.0], [2561.0, 2451.0]], [[2559.0, 2448.0], [2559.0, 2443.0], [2559.0, 2448.0], [2559.0, 2448.0]], [[2638.0, 2050.0], [2638.0, 2050.0], [2638.0, 2050.0]], [[2577.0, 2438.0], [2577.0, 2438.0], [2577.0, 2438.0]], [[2575.0, 2431.0], [2585.0, 2427.0], [2588.0, 2419.0], [2575.0, 2431.0], [2575.0, 2431.0]], [[2532.0, 2473.0], [2539.0, 2473.0], [2543.0, 2463.0], [2532.0, 2473.0], [2532.0, 2473.0]], [[2531.0, 2473.0], [2525.0, 2476.0], [2533.0, 2468.0], [2520.0, 2466.0], [2521.0, 2474.0], [2511.0, 2503.0], [2523.0, 2492.0], [2541.0, 2488.0], [2546.0, 2491.0], [2546.0, 2483.0], [2531.0, 2485.0], [2531.0, 2473.0], [2531.0, 2473.0]], [[2544.0, 2434.0], [2544.0, 2434.0], [2544.0, 2434.0]], [[2549.0, 2527.0], [2546.0, 2530.0], [2549.0, 2527.0], [2549.0, 2527.0]], [[2547.0, 2526.0], [2546.0, 2519.0], [2535.0, 2516.0], [2538.0, 2524.0], [2529.0, 2519.0], [2533.0, 2527.0], [2536.0, 2537.0], [2528.0, 2541.0], [2544.0, 2538.0], [2547.0, 2526.0], [2547.0, 2526.0]], [[2544.0, 2470.0], [2553.0, 2462.0], [2543.0, 2471.0], [2556.0, 2462.0], [2544.0, 2470.0], [2544.0, 2470.0]], [[2643.0, 1910.0], [2650.0, 1904.0], [2650.0, 1894.0], [2635.0, 1905.0], [2643.0, 1910.0], [2643.0, 1910.0]], [[2695.0, 1980.0], [2701.0, 1981.0], [2700.0, 1970.0], [2695.0, 1980.0], [2695.0, 1980.0]], [[2697.0, 1988.0], [2699.0, 1987.0], [2692.0, 1981.0], [2680.0, 1981.0], [2697.0, 1988.0], [2697.0, 1988.0]], [[2722.0, 1883.0], [2723.0, 1879.0], [2716.0, 1875.0], [2716.0, 1885.0], [2724.0, 1889.0], [2722.0, 1883.0], [2722.0, 1883.0]], [[2750.0, 1684.0], [2757.0, 1676.0], [2750.0, 1684.0], [2750.0, 1684.0]], [[2788.0, 1522.0], [2779.0, 1518.0], [2777.0, 1527.0], [2788.0, 1522.0], [2788.0, 1522.0]], [[2559.0, 646.0], [2550.0, 647.0], [2530.0, 671.0], [2529.0, 697.0], [2538.0, 704.0], [2533.0, 715.0], [2535.0, 738.0], [2524.0, 751.0], [2521.0, 785.0], [2513.0, 810.0], [2503.0, 818.0], [2489.0, 815.0], [2463.0, 835.0], [2471.0, 858.0], [2468.0, 882.0], [2464.0, 892.0], [2456.0, 895.0], [2449.0, 908.0], [2453.0, 922.0], [2462.0, 930.0], [2485.0, 919.0], [2505.0, 916.0], [2520.0, 900.0], [2531.0, 898.0], [2552.0, 886.0], [2560.0, 884.0], [2615.0, 852.0], [2616.0, 840.0], [2628.0, 833.0], [2636.0, 842.0], [2627.0, 854.0], [2617.0, 854.0], [2619.0, 865.0], [2643.0, 837.0], [2652.0, 831.0], [2664.0, 820.0], [2674.0, 816.0], [2682.0, 802.0], [2712.0, 771.0], [2746.0, 731.0], [2743.0, 719.0], [2717.0, 683.0], [2715.0, 673.0], [2723.0, 676.0], [2715.0, 669.0], [2715.0, 660.0], [2716.0, 652.0], [2707.0, 638.0], [2697.0, 637.0], [2678.0, 644.0], [2662.0, 643.0], [2651.0, 651.0], [2637.0, 654.0], [2633.0, 651.0], [2625.0, 651.0], [2604.0, 659.0], [2596.0, 658.0], [2581.0, 650.0], [2572.0, 651.0], [2559.0, 646.0], [2559.0, 646.0]], [[2532.0, 728.0], [2529.0, 717.0], [2533.0, 705.0], [2526.0, 716.0], [2532.0, 728.0], [2532.0, 728.0]], [[3840.0, 653.0], [3841.0, 653.0], [3826.0, 650.0], [3818.0, 645.0], [3809.0, 641.0], [3819.0, 648.0], [3806.0, 645.0], [3795.0, 656.0], [3773.0, 665.0], [3743.0, 671.0], [3751.0, 672.0], [3733.0, 681.0], [3720.0, 678.0], [3718.0, 689.0], [3706.0, 682.0], [3697.0, 685.0], [3700.0, 693.0], [3690.0, 698.0], [3687.0, 688.0], [3677.0, 692.0], [3675.0, 702.0], [3671.0, 694.0], [3663.0, 697.0], [3650.0, 707.0], [3642.0, 702.0], [3634.0, 703.0], [3605.0, 707.0], [3584.0, 716.0], [3575.0, 722.0], [3547.0, 733.0], [3560.0, 725.0], [3551.0, 723.0], [3565.0, 716.0], [3553.0, 712.0], [3534.0, 720.0], [3534.0, 728.0], [3523.0, 732.0], [3506.0, 733.0], [3494.0, 738.0], [3485.0, 742.0], [3474.0, 746.0], [3478.0, 750.0], [3457.0, 750.0], [3443.0, 767.0], [3440.0, 780.0], [3472.0, 767.0], [3583.0, 734.0], [3667.0, 710.0], [3750.0, 679.0], [3808.0, 665.0], [3826.0, 667.0], [3825.0, 656.0], [3839.0, 657.0], [3866.0, 655.0], [3893.0, 663.0], [3908.0, 659.0], [3876.0, 649.0], [3840.0, 653.0], [3840.0, 653.0]], [[3585.0, 668.0], [3585.0, 668.0], [3577.0, 668.0], [3571.0, 658.0], [3563.0, 668.0], [3576.0, 677.0], [3585.0, 668.0], [3585.0, 668.0]], [[3583.0, 690.0], [3583.0, 692.0], [3575.0, 691.0], [3562.0, 686.0], [3554.0, 698.0], [3573.0, 704.0], [3583.0, 694.0], [3593.0, 684.0], [3583.0, 690.0], [3583.0, 690.0]], [[3584.0, 705.0], [3572.0, 715.0], [3583.0, 714.0], [3584.0, 705.0], [3584.0, 705.0]], [[3327.0, 741.0], [3284.0, 746.0], [3242.0, 750.0], [3195.0, 745.0], [3180.0, 758.0], [3201.0, 758.0], [3253.0, 763.0], [3326.0, 764.0], [3372.0, 759.0], [3438.0, 756.0], [3440.0, 742.0], [3424.0, 744.0], [3381.0, 737.0], [3379.0, 740.0], [3358.0, 740.0], [3357.0, 740.0], [3349.0, 740.0], [3349.0, 751.0], [3348.0, 740.0], [3346.0, 751.0], [3346.0, 740.0], [3342.0, 740.0], [3342.0, 751.0], [3341.0, 740.0], [3338.0, 741.0], [3338.0, 751.0], [3337.0, 741.0], [3327.0, 741.0], [3327.0, 741.0]], [[3287.0, 738.0], [3287.0, 738.0], [3287.0, 738.0]], [[3317.0, 716.0], [3320.0, 714.0], [3316.0, 701.0], [3308.0, 715.0], [3317.0, 716.0], [3317.0, 716.0]], [[3267.0, 707.0], [3267.0, 707.0], [3267.0, 707.0]], [[3306.0, 709.0], [3315.0, 696.0], [3299.0, 699.0], [3306.0, 709.0], [3306.0, 709.0]], [[3322.0, 712.0], [3326.0, 698.0], [3318.0, 688.0], [3322.0, 712.0], [3322.0, 712.0]], [[3219.0, 742.0], [3219.0, 742.0], [3219.0, 742.0]], [[3253.0, 725.0], [3266.0, 719.0], [3268.0, 710.0], [3253.0, 725.0], [3253.0, 725.0]], [[3314.0, 727.0], [3314.0, 720.0], [3304.0, 722.0], [3311.0, 737.0], [3314.0, 727.0], [3314.0, 727.0]], [[3282.0, 702.0], [3284.0, 696.0], [3273.0, 688.0], [3265.0, 699.0], [3272.0, 709.0], [3273.0, 721.0], [3281.0, 722.0], [3281.0, 712.0], [3290.0, 714.0], [3292.0, 696.0], [3282.0, 702.0], [3282.0, 702.0]], [[3300.0, 722.0], [3301.0, 721.0], [3299.0, 712.0], [3291.0, 724.0], [3290.0, 732.0], [3299.0, 736.0], [3300.0, 722.0], [3300.0, 722.0]], [[3294.0, 693.0], [3297.0, 698.0], [3297.0, 690.0], [3307.0, 689.0], [3299.0, 689.0], [3291.0, 685.0], [3294.0, 692.0], [3285.0, 692.0], [3294.0, 693.0], [3294.0, 693.0]], [[3410.0, 737.0], [3394.0, 730.0], [3410.0, 737.0], [3410.0, 737.0]], [[3576.0, 688.0], [3582.0, 684.0], [3567.0, 680.0], [3558.0, 684.0], [3576.0, 688.0], [3576.0, 688.0]], [[3442.0, 702.0], [3448.0, 707.0], [3461.0, 701.0], [3443.0, 690.0], [3442.0, 702.0], [3442.0, 702.0]], [[3511.0, 714.0], [3514.0, 705.0], [3504.0, 697.0], [3498.0, 711.0], [3505.0, 721.0], [3511.0, 714.0], [3511.0, 714.0]], [[3522.0, 676.0], [3527.0, 682.0], [3519.0, 682.0], [3522.0, 695.0], [3534.0, 689.0], [3533.0, 680.0], [3522.0, 676.0], [3522.0, 676.0]], [[3469.0, 688.0], [3476.0, 679.0], [3478.0, 661.0], [3468.0, 667.0], [3463.0, 676.0], [3469.0, 688.0], [3469.0, 688.0]], [[3528.0, 703.0], [3532.0, 703.0], [3535.0, 695.0], [3528.0, 703.0], [3528.0, 703.0]], [[3486.0, 729.0], [3503.0, 720.0], [3496.0, 711.0], [3500.0, 701.0], [3485.0, 702.0], [3475.0, 698.0], [3471.0, 709.0], [3486.0, 729.0], [3486.0, 729.0]], [[3571.0, 722.0], [3571.0, 722.0], [3571.0, 722.0]], [[3387.0, 735.0], [3395.0, 725.0], [3392.0, 717.0], [3387.0, 726.0], [3387.0, 735.0], [3387.0, 735.0]], [[3478.0, 679.0], [3489.0, 671.0], [3481.0, 665.0], [3478.0, 679.0], [3478.0, 679.0]], [[3581.0, 655.0], [3577.0, 644.0], [3569.0, 652.0], [3581.0, 655.0], [3581.0, 655.0]], [[3547.0, 702.0], [3552.0, 687.0], [3547.0, 702.0], [3547.0, 702.0]], [[3566.0, 712.0], [3566.0, 712.0], [3566.0, 712.0]], [[3425.0, 721.0], [3443.0, 713.0], [3426.0, 711.0], [3424.0, 702.0], [3413.0, 699.0], [3422.0, 708.0], [3425.0, 721.0], [3425.0, 721.0]], [[3358.0, 735.0], [3364.0, 722.0], [3370.0, 712.0], [3359.0, 716.0], [3359.0, 724.0], [3358.0, 735.0], [3358.0, 735.0]], [[3345.0, 732.0], [3355.0, 721.0], [3354.0, 709.0], [3343.0, 710.0], [3345.0, 732.0], [3345.0, 732.0]], [[3420.0, 726.0], [3423.0, 723.0], [3422.0, 711.0], [3412.0, 704.0], [3412.0, 723.0], [3420.0, 726.0], [3420.0, 726.0]], [[3516.0, 694.0], [3514.0, 688.0], [3519.0, 679.0], [3508.0, 680.0], [3503.0, 672.0], [3505.0, 691.0], [3516.0, 694.0], [3516.0, 694.0]], [[3376.0, 721.0], [3382.0, 719.0], [3389.0, 702.0], [3373.0, 702.0], [3376.0, 721.0], [3376.0, 721.0]], [[3521.0, 723.0], [3541.0, 707.0], [3530.0, 705.0], [3521.0, 723.0], [3521.0, 723.0]], [[3527.0, 660.0], [3535.0, 656.0], [3527.0, 660.0], [3527.0, 660.0]], [[3375.0, 734.0], [3377.0, 731.0], [3375.0, 734.0], [3375.0, 734.0]], [[3422.0, 681.0], [3422.0, 681.0], [3422.0, 681.0]], [[3387.0, 724.0], [3393.0, 714.0], [3390.0, 706.0], [3387.0, 724.0], [3387.0, 724.0]], [[3447.0, 722.0], [3442.0, 718.0], [3429.0, 722.0], [3420.0, 730.0], [3419.0, 738.0], [3440.0, 735.0], [3447.0, 722.0], [3447.0, 722.0]], [[3471.0, 714.0], [3466.0, 712.0], [3452.0, 716.0], [3454.0, 730.0], [3471.0, 733.0], [3461.0, 737.0], [3475.0, 731.0], [3471.0, 714.0], [3471.0, 714.0]], [[3469.0, 700.0], [3461.0, 675.0], [3451.0, 676.0], [3446.0, 688.0], [3469.0, 700.0], [3469.0, 700.0]], [[3562.0, 650.0], [3558.0, 658.0], [3562.0, 650.0], [3562.0, 650.0]], [[3425.0, 690.0], [3417.0, 697.0], [3427.0, 700.0], [3425.0, 690.0], [3425.0, 690.0]], [[3441.0, 702.0], [3449.0, 696.0], [3439.0, 687.0], [3441.0, 702.0], [3441.0, 702.0]], [[3513.0, 703.0], [3513.0, 703.0], [3513.0, 703.0]], [[3486.0, 692.0], [3490.0, 694.0], [3502.0, 699.0], [3502.0, 690.0], [3494.0, 677.0], [3487.0, 689.0], [3491.0, 680.0], [3474.0, 687.0], [3486.0, 692.0], [3486.0, 692.0]], [[3432.0, 699.0], [3432.0, 699.0], [3433.0, 681.0], [3424.0, 687.0], [3427.0, 701.0], [3428.0, 709.0], [3439.0, 708.0], [3432.0, 699.0], [3432.0, 699.0]], [[3634.0, 668.0], [3635.0, 659.0], [3634.0, 668.0], [3634.0, 668.0]], [[3650.0, 697.0], [3670.0, 690.0], [3650.0, 697.0], [3650.0, 697.0]], [[3717.0, 681.0], [3717.0, 681.0], [3717.0, 681.0]], [[3691.0, 650.0], [3691.0, 650.0], [3691.0, 650.0]], [[3789.0, 647.0], [3789.0, 647.0], [3789.0, 647.0]], [[3670.0, 668.0], [3670.0, 661.0], [3662.0, 662.0], [3670.0, 668.0], [3670.0, 668.0]], [[3768.0, 643.0], [3774.0, 638.0], [3768.0, 643.0], [3768.0, 643.0]], [[3693.0, 667.0], [3698.0, 669.0], [3693.0, 667.0], [3693.0, 667.0]], [[3766.0, 661.0], [3773.0, 659.0], [3766.0, 661.0], [3766.0, 661.0]], [[3604.0, 703.0], [3604.0, 703.0], [3604.0, 703.0]], [[3739.0, 670.0], [3748.0, 667.0], [3739.0, 658.0], [3739.0, 670.0], [3739.0, 670.0]], [[3785.0, 653.0], [3785.0, 653.0], [3785.0, 653.0]], [[3641.0, 669.0], [3641.0, 669.0], [3641.0, 669.0]], [[3700.0, 663.0], [3703.0, 656.0], [3692.0, 659.0], [3700.0, 663.0], [3700.0, 663.0]], [[3714.0, 645.0], [3714.0, 645.0], [3714.0, 645.0]], [[3724.0, 669.0], [3723.0, 657.0], [3711.0, 657.0], [3724.0, 669.0], [3724.0, 669.0]], [[3658.0, 659.0], [3661.0, 655.0], [3658.0, 659.0], [3658.0, 659.0]], [[3649.0, 658.0], [3649.0, 658.0], [3649.0, 658.0]], [[3620.0, 702.0], [3620.0, 702.0], [3620.0, 702.0]], [[3655.0, 666.0], [3658.0, 664.0], [3649.0, 662.0], [3646.0, 666.0], [3655.0, 666.0], [3655.0, 666.0]], [[3614.0, 703.0], [3614.0, 703.0], [3614.0, 703.0]], [[3695.0, 669.0], [3676.0, 663.0], [3674.0, 683.0], [3685.0, 683.0], [3695.0, 669.0], [3695.0, 669.0]], [[3780.0, 637.0], [3780.0, 637.0], [3780.0, 637.0]], [[3795.0, 642.0], [3796.0, 635.0], [3795.0, 642.0], [3795.0, 642.0]], [[3676.0, 660.0], [3686.0, 652.0], [3676.0, 646.0], [3676.0, 660.0], [3676.0, 660.0]], [[3724.0, 602.0], [3724.0, 602.0], [3724.0, 602.0]], [[3654.0, 702.0], [3654.0, 702.0], [3654.0, 702.0]], [[3644.0, 696.0], [3646.0, 697.0], [3645.0, 683.0], [3631.0, 681.0], [3627.0, 692.0], [3636.0, 691.0], [3626.0, 694.0], [3644.0, 696.0], [3644.0, 696.0]], [[3662.0, 675.0], [3665.0, 677.0], [3640.0, 674.0], [3647.0, 684.0], [3645.0, 692.0], [3669.0, 682.0], [3662.0, 675.0], [3662.0, 675.0]], [[3708.0, 648.0], [3715.0, 649.0], [3706.0, 643.0], [3705.0, 652.0], [3708.0, 648.0], [3708.0, 648.0]], [[3762.0, 662.0], [3763.0, 662.0], [3767.0, 654.0], [3783.0, 654.0], [3785.0, 646.0], [3786.0, 638.0], [3774.0, 639.0], [3771.0, 648.0], [3760.0, 640.0], [3747.0, 652.0], [3742.0, 653.0], [3742.0, 663.0], [3762.0, 662.0], [3762.0, 662.0]], [[3073.0, 740.0], [3073.0, 740.0], [3073.0, 740.0]], [[3071.0, 738.0], [3071.0, 737.0], [3062.0, 739.0], [3071.0, 738.0], [3071.0, 738.0]], [[3071.0, 703.0], [3071.0, 703.0], [3080.0, 711.0], [3080.0, 720.0], [3082.0, 728.0], [3073.0, 735.0], [3082.0, 741.0], [3092.0, 703.0], [3079.0, 655.0], [3064.0, 657.0], [3068.0, 669.0], [3065.0, 677.0], [3058.0, 701.0], [3071.0, 703.0], [3071.0, 703.0]], [[3107.0, 700.0], [3111.0, 696.0], [3115.0, 682.0], [3104.0, 689.0], [3107.0, 700.0], [3107.0, 700.0]], [[3097.0, 734.0], [3097.0, 726.0], [3097.0, 734.0], [3097.0, 734.0]], [[3134.0, 703.0], [3128.0, 707.0], [3126.0, 715.0], [3141.0, 704.0], [3134.0, 693.0], [3134.0, 703.0], [3134.0, 703.0]], [[3118.0, 713.0], [3109.0, 716.0], [3107.0, 722.0], [3118.0, 722.0], [3118.0, 713.0], [3118.0, 713.0]], [[3932.0, 513.0], [3935.0, 514.0], [3944.0, 513.0], [3936.0, 513.0], [3938.0, 505.0], [3932.0, 513.0], [3932.0, 513.0]], [[3946.0, 637.0], [3951.0, 636.0], [3947.0, 632.0], [3936.0, 632.0], [3946.0, 637.0], [3946.0, 637.0]], [[3862.0, 635.0], [3862.0, 635.0], [3862.0, 635.0]], [[3849.0, 634.0], [3849.0, 627.0], [3841.0, 632.0], [3849.0, 634.0], [3849.0, 634.0]], [[3918.0, 638.0], [3924.0, 628.0], [3912.0, 618.0], [3919.0, 632.0], [3918.0, 638.0], [3918.0, 638.0]], [[3929.0, 621.0], [3929.0, 621.0], [3929.0, 621.0]], [[4003.0, 618.0], [4000.0, 621.0], [4018.0, 629.0], [4018.0, 617.0], [4003.0, 618.0], [4003.0, 618.0]], [[3862.0, 644.0], [3862.0, 644.0], [3862.0, 644.0]], [[3992.0, 627.0], [3997.0, 623.0], [3985.0, 617.0], [3992.0, 627.0], [3992.0, 627.0]], [[3916.0, 632.0], [3916.0, 632.0], [3916.0, 632.0]], [[3881.0, 637.0], [3871.0, 642.0], [3885.0, 647.0], [3904.0, 647.0], [3912.0, 642.0], [3904.0, 642.0], [3906.0, 630.0], [3915.0, 631.0], [3914.0, 626.0], [3903.0, 626.0], [3893.0, 617.0], [3891.0, 625.0], [3881.0, 624.0], [3881.0, 635.0], [3879.0, 627.0], [3871.0, 626.0], [3861.0, 638.0], [3870.0, 641.0], [3881.0, 637.0], [3881.0, 637.0]], [[2804.0, 556.0], [2804.0, 553.0], [2793.0, 552.0], [2783.0, 568.0], [2804.0, 556.0], [2804.0, 556.0]], [[2991.0, 355.0], [2986.0, 350.0], [2979.0, 353.0], [2979.0, 365.0], [2998.0, 375.0], [3009.0, 372.0], [3008.0, 364.0], [2991.0, 355.0], [2991.0, 355.0]], [[2971.0, 342.0], [2971.0, 342.0], [2971.0, 342.0]], [[2878.0, 443.0], [2907.0, 405.0], [2878.0, 443.0], [2878.0, 443.0]], [[2916.0, 379.0], [2926.0, 383.0], [2947.0, 359.0], [2934.0, 343.0], [2926.0, 361.0], [2916.0, 379.0], [2916.0, 379.0]], [[3031.0, 674.0], [3038.0, 672.0], [3031.0, 674.0], [3031.0, 674.0]], [[3001.0, 701.0], [3017.0, 695.0], [3017.0, 677.0], [2998.0, 691.0], [3001.0, 701.0], [3001.0, 701.0]], [[3061.0, 751.0], [3061.0, 751.0], [3061.0, 751.0]], [[3057.0, 746.0], [3058.0, 749.0], [3056.0, 737.0], [3057.0, 746.0], [3057.0, 746.0]], [[2946.0, 747.0], [2949.0, 742.0], [2937.0, 732.0], [2946.0, 747.0], [2946.0, 747.0]], [[2758.0, 543.0], [2761.0, 546.0], [2758.0, 543.0], [2758.0, 543.0]], [[3034.0, 718.0], [3041.0, 712.0], [3034.0, 718.0], [3034.0, 718.0]], [[3042.0, 718.0], [3053.0, 722.0], [3060.0, 707.0], [3047.0, 704.0], [3042.0, 718.0], [3042.0, 718.0]], [[3061.0, 720.0], [3061.0, 720.0], [3061.0, 720.0]], [[3032.0, 689.0], [3030.0, 693.0], [3040.0, 690.0], [3032.0, 689.0], [3032.0, 689.0]], [[3040.0, 668.0], [3048.0, 660.0], [3040.0, 668.0], [3040.0, 668.0]], [[3030.0, 738.0], [3040.0, 733.0], [3023.0, 726.0], [3030.0, 738.0], [3030.0, 738.0]], [[2714.0, 562.0], [2719.0, 570.0], [2714.0, 562.0], [2714.0, 562.0]], [[3143.0, 180.0], [3141.0, 175.0], [3132.0, 186.0], [3143.0, 180.0], [3143.0, 180.0]], [[3157.0, 193.0], [3161.0, 191.0], [3161.0, 182.0], [3157.0, 193.0], [3157.0, 193.0]], [[3176.0, 181.0], [3176.0, 181.0], [3176.0, 181.0]], [[3126.0, 242.0], [3125.0, 256.0], [3138.0, 277.0], [3140.0, 253.0], [3130.0, 243.0], [3129.0, 234.0], [3126.0, 242.0], [3126.0, 242.0]], [[3157.0, 232.0], [3153.0, 232.0], [3153.0, 247.0], [3158.0, 260.0], [3157.0, 245.0], [3157.0, 232.0], [3157.0, 232.0]], [[3133.0, 231.0], [3133.0, 231.0], [3133.0, 231.0]], [[3250.0, 75.0], [3256.0, 67.0], [3248.0, 73.0], [3250.0, 75.0], [3250.0, 75.0]], [[3368.0, -8.0], [3362.0, -7.0], [3368.0, -8.0], [3368.0, -8.0]], [[3350.0, -24.0], [3342.0, -32.0], [3350.0, -24.0], [3350.0, -24.0]], [[861.0, 2190.0], [876.0, 2187.0], [869.0, 2179.0], [861.0, 2190.0], [861.0, 2190.0]], [[536.0, 2663.0], [543.0, 2655.0], [520.0, 2636.0], [526.0, 2654.0], [536.0, 2663.0], [536.0, 2663.0]], [[960.0, 2157.0], [970.0, 2155.0], [918.0, 2153.0], [960.0, 2157.0], [960.0, 2157.0]], [[766.0, 3048.0], [770.0, 3048.0], [768.0, 3037.0], [766.0, 3048.0], [766.0, 3048.0]], [[843.0, 3122.0], [835.0, 3118.0], [843.0, 3122.0], [843.0, 3122.0]], [[540.0, 2806.0], [548.0, 2794.0], [547.0, 2786.0], [540.0, 2806.0], [540.0, 2806.0]], [[1497.0, 3860.0], [1497.0, 3860.0], [1497.0, 3860.0]], [[1656.0, 3727.0], [1657.0, 3738.0], [1656.0, 3727.0], [1656.0, 3727.0]], [[1647.0, 3722.0], [1647.0, 3722.0], [1647.0, 3722.0]], [[1749.0, 3589.0], [1749.0, 3589.0], [1749.0, 3589.0]], [[1726.0, 3635.0], [1726.0, 3635.0], [1726.0, 3635.0]], [[1740.0, 3546.0], [1740.0, 3546.0], [1740.0, 3546.0]], [[1521.0, 3409.0], [1521.0, 3409.0], [1521.0, 3409.0]], [[1515.0, 3425.0], [1515.0, 3425.0], [1515.0, 3425.0]], [[1365.0, 3279.0], [1365.0, 3279.0], [1365.0, 3279.0]], [[1992.0, 3168.0], [1992.0, 3168.0], [1992.0, 3168.0]], [[1995.0, 3160.0], [1995.0, 3160.0], [1995.0, 3160.0]], [[2117.0, 3090.0], [2117.0, 3090.0], [2117.0, 3090.0]], [[2661.0, 2015.0], [2661.0, 2015.0], [2661.0, 2015.0]], [[2661.0, 2015.0], [2661.0, 2015.0], [2661.0, 2015.0]], [[2763.0, 1664.0], [2763.0, 1664.0], [2763.0, 1664.0]], [[3707.0, 616.0], [3707.0, 616.0], [3707.0, 616.0]], [[3292.0, 725.0], [3300.0, 722.0], [3292.0, 725.0], [3292.0, 725.0]], [[3645.0, 706.0], [3645.0, 706.0], [3645.0, 706.0]], [[3281.0, 712.0], [3281.0, 712.0], [3281.0, 712.0]], [[3486.0, 706.0], [3486.0, 706.0], [3486.0, 706.0]], [[3287.0, 713.0], [3287.0, 713.0], [3287.0, 713.0]], [[3281.0, 705.0], [3281.0, 705.0], [3281.0, 705.0]], [[3281.0, 705.0], [3281.0, 705.0], [3281.0, 705.0]], [[3663.0, 697.0], [3663.0, 697.0], [3663.0, 697.0]], [[3432.0, 699.0], [3432.0, 699.0], [3432.0, 699.0]], [[3644.0, 696.0], [3644.0, 696.0], [3644.0, 696.0]], [[3428.0, 695.0], [3428.0, 695.0], [3428.0, 695.0]], [[3915.0, 632.0], [3915.0, 632.0], [3915.0, 632.0]], [[3884.0, 625.0], [3884.0, 625.0], [3884.0, 625.0]], [[3887.0, 638.0], [3887.0, 638.0], [3887.0, 638.0]], [[3873.0, 632.0], [3873.0, 632.0], [3873.0, 632.0]], [[3887.0, 638.0], [3888.0, 632.0], [3887.0, 638.0], [3887.0, 638.0]], [[3906.0, 630.0], [3906.0, 630.0], [3906.0, 630.0]], [[3487.0, 689.0], [3487.0, 689.0], [3487.0, 689.0]], [[3294.0, 693.0], [3294.0, 693.0], [3294.0, 693.0]], [[3297.0, 690.0], [3297.0, 690.0], [3297.0, 690.0]], [[3662.0, 675.0], [3662.0, 675.0], [3662.0, 675.0]], [[3319.0, 683.0], [3319.0, 683.0], [3319.0, 683.0]], [[3754.0, 654.0], [3754.0, 654.0], [3754.0, 654.0]], [[3759.0, 658.0], [3759.0, 658.0], [3759.0, 658.0]], [[3759.0, 658.0], [3759.0, 658.0], [3759.0, 658.0]], [[3759.0, 656.0], [3759.0, 656.0], [3759.0, 656.0]], [[3710.0, 644.0], [3710.0, 644.0], [3710.0, 644.0]], [[3707.0, 647.0], [3707.0, 647.0], [3707.0, 647.0]], [[3875.0, 560.0], [3875.0, 560.0], [3875.0, 560.0]], [[2704.0, 1048.0], [2704.0, 1048.0], [2704.0, 1048.0]], [[2704.0, 1048.0], [2704.0, 1048.0], [2704.0, 1048.0]], [[2183.0, 3051.0], [2183.0, 3051.0], [2183.0, 3051.0]], [[2186.0, 3045.0], [2186.0, 3045.0], [2186.0, 3045.0]], [[2183.0, 3051.0], [2183.0, 3051.0], [2183.0, 3051.0]], [[2181.0, 3039.0], [2181.0, 3039.0], [2181.0, 3039.0]], [[2181.0, 3039.0], [2181.0, 3039.0], [2181.0, 3039.0]], [[2235.0, 3027.0], [2235.0, 3027.0], [2235.0, 3027.0]], [[2235.0, 3027.0], [2235.0, 3027.0], [2235.0, 3027.0]], [[2186.0, 3006.0], [2186.0, 3006.0], [2186.0, 3006.0]], [[2175.0, 3002.0], [2175.0, 3002.0], [2175.0, 3002.0]], [[2183.0, 3000.0], [2183.0, 3000.0], [2183.0, 3000.0]], [[2151.0, 3057.0], [2151.0, 3057.0], [2151.0, 3057.0]], [[2164.0, 2990.0], [2164.0, 2990.0], [2164.0, 2990.0]], [[2172.0, 2992.0], [2172.0, 2992.0], [2172.0, 2992.0]], [[2165.0, 2991.0], [2165.0, 2991.0], [2165.0, 2991.0]], [[2172.0, 2992.0], [2172.0, 2992.0], [2172.0, 2992.0]], [[2165.0, 2991.0], [2165.0, 2991.0], [2165.0, 2991.0]], [[2280.0, 2722.0], [2280.0, 2722.0], [2280.0, 2722.0]], [[2265.0, 2952.0], [2265.0, 2952.0], [2265.0, 2952.0]], [[2179.0, 2983.0], [2179.0, 2983.0], [2179.0, 2983.0]], [[2210.0, 2987.0], [2210.0, 2987.0], [2210.0, 2987.0]], [[2163.0, 2988.0], [2163.0, 2988.0], [2163.0, 2988.0]], [[2163.0, 2987.0], [2163.0, 2987.0], [2163.0, 2987.0]], [[2207.0, 2980.0], [2207.0, 2980.0], [2207.0, 2980.0]], [[2179.0, 2983.0], [2179.0, 2983.0], [2179.0, 2983.0]], [[2207.0, 2980.0], [2207.0, 2980.0], [2207.0, 2980.0]], [[2284.0, 2949.0], [2284.0, 2949.0], [2284.0, 2949.0]], [[2314.0, 2924.0], [2314.0, 2924.0], [2314.0, 2924.0]], [[2229.0, 2928.0], [2229.0, 2928.0], [2229.0, 2928.0]], [[2290.0, 2925.0], [2290.0, 2925.0], [2290.0, 2925.0]], [[2300.0, 2917.0], [2300.0, 2917.0], [2300.0, 2917.0]], [[2239.0, 2914.0], [2239.0, 2914.0], [2239.0, 2914.0]], [[2339.0, 2909.0], [2339.0, 2909.0], [2339.0, 2909.0]], [[2354.0, 2861.0], [2359.0, 2862.0], [2361.0, 2871.0], [2359.0, 2880.0], [2354.0, 2861.0], [2354.0, 2861.0]], [[2358.0, 2862.0], [2358.0, 2862.0], [2358.0, 2862.0]], [[2335.0, 2861.0], [2335.0, 2861.0], [2335.0, 2861.0]], [[2332.0, 2876.0], [2332.0, 2876.0], [2332.0, 2876.0]], [[2332.0, 2876.0], [2332.0, 2876.0], [2332.0, 2876.0]], [[2309.0, 2907.0], [2309.0, 2907.0], [2309.0, 2907.0]], [[2297.0, 2908.0], [2304.0, 2910.0], [2304.0, 2901.0], [2291.0, 2903.0], [2297.0, 2898.0], [2288.0, 2898.0], [2297.0, 2896.0], [2305.0, 2900.0], [2297.0, 2908.0], [2297.0, 2908.0]], [[2223.0, 2900.0], [2223.0, 2900.0], [2223.0, 2900.0]], [[2296.0, 2905.0], [2296.0, 2905.0], [2296.0, 2905.0]], [[2291.0, 2903.0], [2291.0, 2903.0], [2291.0, 2903.0]], [[2291.0, 2903.0], [2291.0, 2903.0], [2291.0, 2903.0]], [[2331.0, 2889.0], [2331.0, 2889.0], [2331.0, 2889.0]], [[2314.0, 2895.0], [2314.0, 2895.0], [2314.0, 2895.0]], [[2296.0, 2896.0], [2296.0, 2896.0], [2296.0, 2896.0]], [[2296.0, 2896.0], [2296.0, 2896.0], [2296.0, 2896.0]], [[2208.0, 2871.0], [2208.0, 2871.0], [2208.0, 2871.0]], [[2325.0, 2857.0], [2325.0, 2857.0], [2325.0, 2857.0]], [[2321.0, 2861.0], [2321.0, 2861.0], [2321.0, 2861.0]], [[2332.0, 2876.0], [2323.0, 2876.0], [2332.0, 2876.0], [2332.0, 2876.0]], [[2285.0, 2872.0], [2285.0, 2872.0], [2285.0, 2872.0]], [[2310.0, 2875.0], [2310.0, 2875.0], [2310.0, 2875.0]], [[2347.0, 2857.0], [2347.0, 2857.0], [2347.0, 2857.0]], [[2358.0, 2862.0], [2349.0, 2845.0], [2358.0, 2862.0], [2358.0, 2862.0]], [[2348.0, 2843.0], [2348.0, 2843.0], [2348.0, 2843.0]], [[2258.0, 2849.0], [2258.0, 2849.0], [2258.0, 2849.0]], [[2223.0, 2850.0], [2223.0, 2850.0], [2223.0, 2850.0]], [[2276.0, 2719.0], [2276.0, 2719.0], [2276.0, 2719.0]], [[2280.0, 2722.0], [2280.0, 2722.0], [2280.0, 2722.0]], [[2276.0, 2719.0], [2276.0, 2719.0], [2276.0, 2719.0]], [[2271.0, 2710.0], [2271.0, 2710.0], [2271.0, 2710.0]], [[2504.0, 2543.0], [2504.0, 2543.0], [2507.0, 2535.0], [2504.0, 2543.0], [2504.0, 2543.0]], [[2507.0, 2535.0], [2507.0, 2535.0], [2507.0, 2535.0]], [[2543.0, 2471.0], [2543.0, 2471.0], [2543.0, 2471.0]], [[2617.0, 2442.0], [2617.0, 2442.0], [2617.0, 2442.0]], [[2624.0, 2428.0], [2624.0, 2428.0], [2624.0, 2428.0]], [[2624.0, 2428.0], [2624.0, 2428.0], [2624.0, 2428.0]], [[2645.0, 2346.0], [2645.0, 2346.0], [2645.0, 2346.0]], [[2644.0, 2345.0], [2644.0, 2345.0], [2644.0, 2345.0]], [[2644.0, 2345.0], [2644.0, 2345.0], [2644.0, 2345.0]], [[2644.0, 2369.0], [2644.0, 2369.0], [2644.0, 2369.0]], [[2602.0, 2174.0], [2602.0, 2174.0], [2602.0, 2174.0]], [[2600.0, 2174.0], [2600.0, 2174.0], [2600.0, 2174.0]], [[2596.0, 2174.0], [2596.0, 2174.0], [2588.0, 2174.0], [2596.0, 2174.0], [2596.0, 2174.0]], [[2600.0, 2174.0], [2600.0, 2174.0], [2600.0, 2174.0]], [[2602.0, 2174.0], [2602.0, 2174.0], [2602.0, 2174.0]], [[2621.0, 2091.0], [2621.0, 2091.0], [2621.0, 2091.0]], [[2533.0, 2318.0], [2533.0, 2318.0], [2533.0, 2318.0]], [[2533.0, 2318.0], [2533.0, 2318.0], [2533.0, 2318.0]]];
-testOutput["water_huge2"]["triangles"]=[267, 268, 269, 269, 270, 271, 271, 272, 273, 273, 274, 5058, 5060, 5061, 274, 5071, 276, 277, 278, 279, 280, 281, 282, 283, 283, 284, 285, 285, 286, 287, 287, 288, 289, 289, 290, 291, 294, 295, 296, 299, 300, 301, 301, 302, 303, 306, 307, 308, 308, 309, 310, 311, 312, 313, 313, 314, 315, 315, 316, 317, 317, 318, 319, 319, 320, 321, 322, 323, 324, 324, 325, 326, 326, 327, 328, 330, 331, 332, 332, 333, 334, 334, 335, 336, 336, 337, 338, 341, 342, 343, 343, 344, 345, 346, 347, 348, 348, 349, 350, 354, 355, 356, 357, 358, 359, 359, 360, 361, 362, 363, 364, 366, 367, 368, 369, 370, 371, 371, 372, 373, 375, 376, 377, 380, 381, 382, 382, 383, 384, 386, 387, 388, 388, 389, 390, 397, 398, 399, 404, 405, 406, 407, 408, 409, 410, 411, 412, 413, 414, 415, 417, 418, 419, 420, 421, 422, 426, 427, 428, 428, 429, 430, 433, 434, 435, 436, 437, 438, 438, 439, 440, 447, 448, 449, 450, 451, 452, 452, 453, 454, 455, 456, 457, 457, 458, 459, 465, 466, 467, 469, 470, 471, 473, 474, 475, 480, 481, 482, 482, 483, 484, 486, 487, 488, 488, 489, 490, 492, 493, 494, 501, 502, 503, 503, 504, 505, 505, 506, 507, 507, 508, 509, 514, 515, 516, 519, 520, 521, 521, 522, 523, 528, 529, 530, 531, 532, 533, 534, 535, 536, 537, 538, 539, 539, 540, 541, 541, 542, 543, 543, 544, 545, 546, 548, 549, 549, 550, 551, 554, 555, 556, 558, 559, 560, 561, 562, 563, 568, 569, 570, 570, 571, 572, 573, 576, 579, 580, 581, 582, 585, 586, 587, 587, 588, 589, 590, 591, 592, 593, 594, 595, 595, 596, 597, 598, 599, 600, 3717, 3712, 3713, 603, 604, 605, 605, 606, 3691, 3691, 606, 3686, 3685, 3686, 606, 611, 612, 613, 615, 616, 617, 618, 619, 620, 624, 625, 626, 629, 630, 631, 631, 632, 633, 634, 635, 636, 636, 637, 638, 638, 639, 640, 641, 642, 643, 645, 646, 647, 648, 649, 650, 650, 651, 652, 658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 669, 670, 671, 672, 672, 673, 674, 677, 678, 679, 679, 680, 681, 684, 685, 686, 686, 687, 688, 689, 690, 691, 691, 692, 693, 693, 694, 695, 695, 696, 697, 698, 699, 700, 701, 702, 703, 703, 704, 705, 705, 706, 707, 708, 709, 710, 710, 711, 3703, 3705, 3706, 711, 712, 713, 714, 715, 716, 717, 717, 718, 719, 724, 725, 726, 728, 729, 730, 732, 733, 3660, 3663, 3664, 733, 733, 734, 735, 737, 738, 739, 740, 741, 742, 745, 746, 747, 748, 749, 750, 753, 754, 755, 755, 756, 757, 758, 759, 760, 768, 769, 772, 776, 777, 778, 780, 781, 782, 783, 784, 785, 786, 787, 788, 788, 789, 790, 791, 792, 793, 793, 794, 795, 798, 799, 800, 801, 3501, 3498, 3500, 3501, 801, 3504, 3505, 3506, 3249, 3250, 3251, 3252, 3253, 3254, 3254, 3255, 3256, 3450, 3451, 3452, 3454, 3455, 3456, 3458, 3459, 3460, 3464, 3465, 3466, 3466, 3467, 3468, 3468, 3469, 3470, 3573, 3574, 3474, 3435, 3436, 3437, 3439, 3440, 3441, 3441, 3442, 3443, 3443, 3444, 3445, 3445, 3446, 3447, 3447, 3448, 3257, 3259, 3260, 3261, 3263, 3264, 3265, 3265, 3266, 3267, 3271, 3272, 3273, 3273, 3274, 3275, 3275, 3276, 3277, 3277, 3278, 3240, 3240, 3241, 3242, 3242, 3243, 3244, 3245, 3246, 3247, 3508, 3509, 801, 802, 803, 804, 805, 806, 807, 807, 808, 809, 3516, 3517, 812, 818, 819, 820, 821, 822, 824, 826, 827, 828, 3513, 829, 830, 3559, 3560, 3540, 3540, 3541, 832, 832, 833, 834, 834, 835, 836, 837, 838, 839, 840, 841, 842, 842, 843, 844, 846, 847, 848, 850, 851, 852, 854, 855, 856, 856, 857, 858, 858, 3526, 3527, 3522, 3523, 3524, 3526, 858, 859, 861, 862, 863, 3546, 3547, 3548, 864, 865, 866, 866, 867, 868, 868, 869, 870, 3582, 873, 874, 876, 877, 878, 878, 3655, 3656, 3657, 3654, 3655, 3655, 878, 879, 879, 3594, 3595, 3593, 3594, 879, 879, 880, 881, 885, 886, 887, 887, 888, 889, 891, 892, 893, 894, 895, 896, 898, 899, 900, 902, 903, 904, 904, 905, 906, 906, 907, 908, 909, 910, 911, 911, 912, 913, 913, 914, 915, 915, 916, 917, 918, 919, 920, 920, 3610, 3611, 3620, 922, 923, 929, 930, 931, 931, 932, 933, 933, 934, 935, 936, 937, 938, 938, 939, 940, 944, 945, 946, 947, 948, 949, 949, 950, 951, 952, 953, 954, 955, 956, 957, 957, 958, 959, 959, 960, 961, 963, 964, 965, 969, 970, 971, 3644, 3641, 3642, 973, 3634, 3635, 3196, 3197, 3198, 3201, 3202, 3203, 3836, 3837, 3838, 3838, 3839, 3840, 3842, 3843, 3844, 3845, 3846, 3847, 3848, 3849, 3850, 3850, 3851, 3852, 3852, 3853, 3854, 3854, 3855, 3823, 3826, 3827, 3828, 3829, 3830, 3831, 3832, 3833, 3834, 3834, 3835, 3208, 3208, 3209, 3210, 3212, 3213, 3214, 3809, 3810, 3811, 3811, 3812, 3813, 3816, 3817, 3818, 3818, 3819, 3222, 3222, 3223, 3224, 3224, 3225, 3226, 3226, 3227, 3228, 3429, 3430, 3431, 3424, 3425, 3426, 985, 986, 987, 988, 989, 3416, 3416, 3417, 3418, 3419, 3420, 3421, 3415, 3416, 989, 990, 991, 992, 993, 994, 995, 995, 996, 997, 998, 999, 1000, 1001, 1002, 1003, 1003, 1004, 1005, 1005, 1006, 3798, 3799, 3800, 3801, 3803, 3804, 3805, 3796, 3797, 3798, 3798, 1006, 1007, 1008, 1009, 1010, 1010, 1011, 1012, 1012, 1013, 1014, 1016, 1017, 1018, 1021, 1022, 3792, 3791, 3792, 1022, 1023, 1024, 1025, 1025, 1026, 1027, 1027, 1028, 1029, 1031, 1032, 1033, 1033, 1034, 1035, 1035, 1036, 1037, 1040, 1041, 1042, 1043, 1044, 3913, 3916, 3917, 3918, 3912, 3913, 1044, 1047, 1048, 1049, 1049, 1050, 1051, 3929, 1051, 1052, 1052, 1053, 3367, 3365, 3368, 1053, 1053, 1054, 1055, 3893, 3894, 3374, 3373, 3374, 3894, 4043, 4044, 4064, 4064, 4065, 4066, 4066, 4067, 4068, 4068, 4059, 4060, 4064, 4044, 4045, 4047, 4048, 4049, 3898, 3899, 3900, 3901, 1056, 1057, 1058, 1059, 1060, 1062, 1063, 1064, 3998, 3999, 4000, 4001, 1065, 1066, 1066, 1067, 1068, 3965, 1069, 1070, 1071, 1072, 1073, 4056, 3882, 3883, 3883, 3884, 3879, 3881, 3882, 4056, 4056, 4053, 4054, 3957, 3958, 3959, 1073, 1074, 1075, 1075, 1076, 1077, 1077, 1078, 1079, 4080, 4081, 4082, 4084, 4085, 4013, 4012, 4013, 3314, 3314, 3315, 3316, 3318, 3288, 3289, 3291, 3292, 3293, 3293, 3294, 3295, 3299, 3300, 3301, 3302, 3303, 3304, 3330, 3321, 3322, 4097, 4098, 4099, 3308, 3309, 3310, 3312, 3313, 3314, 3314, 4013, 4085, 4087, 1083, 1084, 1084, 1085, 1086, 1087, 1090, 5354, 5354, 5353, 5352, 5351, 5350, 5357, 5355, 5354, 1090, 1092, 1093, 1094, 4073, 4074, 4075, 4076, 1098, 1099, 1099, 1100, 1101, 1101, 1102, 3976, 3979, 3980, 1102, 1102, 1103, 1104, 1104, 1105, 1106, 1106, 1107, 1108, 3860, 3861, 3862, 3866, 3867, 3858, 3859, 3860, 3873, 3873, 1109, 1110, 1110, 1111, 3334, 3334, 3335, 3336, 3340, 3341, 3342, 3349, 3350, 3351, 3352, 3353, 3354, 3333, 3334, 1111, 1112, 1113, 1114, 1114, 1115, 1116, 1116, 3743, 3744, 3746, 3747, 3748, 3748, 3749, 3750, 3751, 3752, 3753, 3753, 3754, 3756, 3758, 3759, 3760, 3761, 3762, 3763, 3763, 3764, 3765, 3768, 3769, 3770, 3770, 3771, 3772, 3772, 3773, 3774, 3775, 3776, 3777, 3777, 3778, 3779, 3779, 3780, 3781, 3782, 3784, 3785, 3720, 3721, 3722, 3722, 3723, 3724, 3724, 3725, 3726, 3727, 3728, 3729, 3729, 3730, 3731, 3732, 3733, 3734, 3734, 3735, 3736, 3736, 3737, 3738, 3738, 3739, 3740, 3740, 3741, 3742, 3742, 3743, 1116, 1125, 1126, 1127, 1127, 1128, 1129, 4094, 1131, 1132, 1132, 1133, 1134, 1136, 1138, 1139, 1140, 1141, 1142, 1142, 1143, 1144, 1144, 1145, 1146, 1148, 1149, 4124, 3385, 3386, 4123, 4123, 4124, 1149, 1154, 1155, 1156, 1158, 1159, 1160, 1162, 1163, 1164, 1166, 1167, 1168, 1168, 1169, 1170, 1171, 1172, 1173, 1174, 1175, 1176, 1177, 1178, 1179, 1179, 1180, 1181, 1182, 1183, 1184, 1184, 1185, 1186, 1188, 1189, 1190, 1190, 1191, 1192, 1194, 1195, 1196, 1201, 1202, 1203, 1206, 1207, 1208, 1209, 1210, 1211, 1212, 1213, 1214, 1216, 1217, 1218, 1222, 1223, 1224, 1224, 1225, 1226, 1227, 1228, 1229, 1231, 1232, 1233, 1236, 1237, 1238, 1240, 1243, 1244, 1245, 1246, 1247, 1250, 1251, 1252, 1253, 1254, 1255, 1259, 1260, 1261, 1262, 1263, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1273, 1274, 1275, 1276, 1278, 1279, 1280, 1281, 1282, 1283, 1284, 1285, 1286, 1287, 1288, 1289, 1292, 1293, 1294, 1296, 1297, 1298, 1299, 1300, 1301, 1301, 1302, 1303, 1304, 1305, 1306, 1306, 1307, 1308, 1310, 1311, 1312, 1314, 1315, 1316, 1317, 1318, 1319, 1323, 1324, 1325, 1325, 1326, 1327, 1327, 1328, 1329, 1331, 1332, 1333, 1334, 1335, 1336, 1336, 1337, 1338, 1340, 1341, 1342, 1344, 1345, 1346, 1348, 1349, 1350, 1350, 1351, 1352, 1352, 1353, 1354, 1355, 1356, 1357, 1357, 1358, 1359, 1359, 1360, 1361, 1364, 1365, 1366, 1368, 1369, 1370, 1373, 1374, 1375, 1375, 1376, 1377, 1379, 1380, 1381, 4181, 4182, 4183, 4183, 4184, 4185, 3145, 3146, 3147, 3147, 1381, 1382, 1382, 1383, 1384, 1384, 1385, 1386, 1387, 1388, 1389, 4164, 4165, 4166, 4193, 4190, 4191, 4156, 4157, 4159, 4159, 4160, 4161, 1392, 1393, 1394, 1395, 1396, 1397, 1398, 1399, 1400, 1401, 1402, 1403, 1403, 1404, 3121, 3121, 1404, 1405, 1405, 1406, 1407, 1407, 1408, 1409, 1409, 1410, 1411, 1412, 1413, 1414, 1414, 1415, 1416, 1416, 1417, 1418, 1419, 1420, 1421, 1421, 1422, 1423, 1423, 1424, 1425, 1428, 1429, 1430, 1432, 1433, 1434, 1435, 1436, 1437, 1437, 1438, 1439, 1447, 1448, 1449, 1454, 1455, 1456, 1456, 1457, 1458, 1459, 1460, 1461, 1461, 1462, 1463, 1465, 1466, 1467, 1467, 1468, 1469, 1471, 1472, 1473, 1473, 1476, 1477, 1480, 1481, 1482, 1482, 1483, 1484, 1484, 1485, 1486, 1487, 1488, 1489, 1489, 1490, 1491, 1491, 1492, 1493, 1495, 1496, 1497, 1500, 1501, 1502, 1503, 1504, 1505, 1505, 1506, 1507, 1509, 1510, 1511, 1511, 1512, 1513, 1516, 1517, 1518, 1518, 1519, 1520, 1520, 1522, 1523, 1524, 1525, 1526, 1528, 1529, 1530, 1534, 1535, 1536, 1536, 1537, 1538, 1538, 1539, 1540, 1541, 1542, 1543, 1544, 1545, 1546, 1546, 1547, 1548, 1549, 1550, 1551, 1552, 1553, 1554, 1554, 1555, 1556, 1558, 1559, 1560, 1564, 1565, 1566, 1567, 1568, 1569, 1576, 1577, 1578, 1579, 1580, 1581, 1581, 4199, 4196, 4198, 4199, 1581, 1582, 1583, 1584, 1586, 1587, 1588, 4217, 4213, 4214, 1591, 1592, 1593, 1595, 1596, 1597, 1597, 1598, 1599, 1599, 1600, 1601, 1601, 1602, 1603, 1603, 1604, 1605, 1607, 1608, 1609, 1610, 1611, 1612, 1612, 1613, 1614, 1615, 1616, 1617, 1617, 1618, 1619, 1619, 1620, 1621, 1624, 1625, 1626, 1626, 1627, 1628, 1629, 1630, 1631, 1632, 1633, 1634, 1634, 1635, 1636, 1636, 1637, 1638, 1639, 1640, 1641, 1642, 1643, 1644, 1646, 1647, 1648, 1650, 1651, 1652, 1652, 1653, 1654, 1654, 1655, 1656, 1657, 1658, 1659, 1660, 1661, 1662, 1662, 1663, 1664, 1665, 1666, 1667, 1667, 1668, 1669, 1670, 1671, 1672, 1672, 1673, 1674, 1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1682, 1683, 1684, 1685, 1685, 1686, 1687, 1687, 1688, 1689, 1689, 1690, 1691, 1691, 1693, 1694, 1695, 1696, 1697, 1697, 1698, 1699, 1699, 1700, 1701, 1703, 1704, 1705, 1705, 1706, 1707, 1707, 1708, 1709, 1710, 1711, 1712, 1712, 1713, 1714, 1714, 1715, 1716, 1717, 1718, 1719, 1720, 1721, 1722, 1722, 1723, 1724, 1725, 1726, 1727, 1728, 1729, 1730, 1730, 1731, 1732, 1732, 1733, 1734, 1734, 1735, 1736, 1741, 1742, 1743, 1744, 1745, 1746, 1752, 1753, 1754, 1754, 1755, 1756, 1756, 1757, 1758, 1759, 1760, 1761, 1763, 1764, 1765, 1769, 1770, 1771, 1773, 1774, 1775, 1776, 1777, 1778, 1778, 1779, 1780, 1780, 1781, 1782, 1782, 1783, 1784, 1785, 1786, 1787, 1787, 1788, 1789, 1789, 1790, 1791, 1791, 1792, 1793, 1794, 1795, 1796, 1797, 1798, 1799, 1801, 1802, 1803, 1803, 1804, 1805, 1808, 1809, 1810, 1811, 1812, 1813, 1813, 1814, 1815, 1816, 1817, 1818, 1818, 1819, 1820, 1821, 1822, 1823, 1823, 1824, 1825, 1825, 1826, 1827, 1829, 1830, 1831, 1831, 1832, 1833, 1836, 1837, 1838, 1838, 1839, 1840, 1840, 1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 1850, 1851, 1852, 1853, 1854, 1855, 1856, 1857, 1858, 1859, 1860, 1862, 1863, 1864, 1868, 1869, 1870, 1871, 1872, 1873, 1874, 1875, 1876, 1877, 1878, 1879, 1879, 1880, 1881, 1882, 1883, 1884, 1888, 1889, 1890, 1893, 1894, 1895, 1895, 1896, 1897, 1899, 1900, 1901, 1901, 1902, 1903, 1903, 1904, 1905, 1907, 1908, 1909, 1912, 1913, 1914, 1922, 1923, 1924, 1937, 1938, 1939, 1940, 1943, 1944, 1946, 1947, 1950, 1952, 1953, 1954, 1954, 1955, 1956, 1958, 4246, 4247, 4248, 4249, 4250, 4250, 4251, 4252, 4252, 4253, 4254, 4255, 4256, 4257, 4257, 4258, 4259, 4261, 4262, 4263, 4263, 4264, 4265, 4265, 4266, 4267, 4269, 4270, 4271, 4272, 4273, 4274, 4277, 4278, 4279, 4279, 4280, 4281, 4283, 4284, 4285, 4286, 4287, 4229, 4236, 4237, 4238, 4238, 4239, 4240, 4241, 4242, 4243, 4243, 4244, 4245, 1959, 1960, 1961, 1962, 1963, 1964, 1964, 1965, 1966, 1968, 1969, 1970, 4290, 4291, 4292, 4293, 1972, 1973, 1974, 1975, 1976, 1977, 1978, 1979, 1980, 1981, 1982, 1984, 1985, 1986, 1991, 1992, 1993, 1996, 1997, 1998, 2005, 2006, 2007, 2008, 2009, 2010, 2010, 2011, 2012, 2017, 2018, 2019, 2020, 2021, 2022, 2027, 2028, 2029, 2033, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2041, 2042, 2043, 2043, 2044, 2045, 2045, 2046, 2047, 2050, 2051, 2052, 2058, 2059, 2060, 2060, 2061, 2062, 2062, 2063, 2064, 2064, 2065, 2066, 2067, 2068, 2069, 2073, 2074, 2075, 2076, 2077, 2078, 2078, 2079, 2080, 2080, 2081, 2082, 2082, 2083, 2084, 2085, 2086, 2087, 2088, 2089, 2090, 2090, 2091, 4930, 2092, 2093, 2094, 2094, 2095, 2096, 2097, 2098, 2099, 2101, 2102, 2103, 2104, 2105, 2106, 2108, 2109, 2110, 2110, 2111, 2112, 2113, 2114, 2115, 2115, 2116, 2117, 2117, 2118, 2119, 2119, 2120, 2121, 2121, 2122, 2123, 2124, 2125, 2126, 2127, 2128, 2129, 2129, 2130, 2131, 2131, 2132, 2133, 2133, 2134, 2135, 2135, 2136, 2137, 2137, 2138, 2139, 2139, 2140, 2141, 2141, 2142, 2143, 2143, 2144, 2145, 2147, 2148, 2149, 2150, 2151, 2152, 2153, 2154, 4949, 4952, 4953, 4954, 4954, 2154, 2155, 2158, 2159, 2160, 2165, 4935, 4936, 4939, 4933, 4934, 2166, 2167, 2168, 2170, 2171, 2172, 2173, 2174, 2175, 2177, 2178, 2179, 2181, 2182, 2183, 2184, 2185, 2186, 2186, 2187, 2188, 2188, 2189, 2190, 2190, 2191, 2192, 2192, 2193, 2194, 2196, 2197, 2198, 2199, 2200, 2201, 2201, 2202, 2203, 2204, 5028, 5029, 5038, 5039, 5035, 2206, 2207, 2208, 5015, 5016, 2211, 2211, 2212, 2213, 2213, 2214, 2215, 2215, 2216, 2217, 2217, 2218, 2219, 2220, 2221, 2222, 2224, 2225, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2235, 2235, 2236, 2237, 2237, 2238, 2239, 2240, 2241, 2242, 2242, 2243, 2244, 2246, 5047, 5045, 2249, 2250, 2251, 2251, 2252, 2253, 2253, 2254, 2255, 2255, 2256, 2257, 2257, 2258, 2259, 2259, 2260, 2261, 2263, 2264, 2265, 2268, 2269, 2270, 2273, 2274, 2275, 2275, 2276, 2277, 2277, 2278, 2279, 2279, 2280, 2281, 2283, 2284, 2285, 2286, 2287, 2288, 2290, 2291, 2292, 2293, 2294, 2295, 2295, 2296, 2297, 2297, 2298, 2299, 2302, 2303, 2304, 2305, 2306, 2307, 2308, 2309, 2310, 2310, 2311, 2312, 2312, 2313, 2314, 2314, 2315, 2316, 2316, 2317, 2318, 2323, 2324, 2325, 2326, 2327, 2328, 2328, 2329, 2330, 2330, 2331, 2332, 2333, 2334, 2335, 2335, 2336, 2337, 2338, 2339, 2340, 2344, 2345, 2346, 2346, 2347, 2348, 2350, 2351, 2352, 2352, 2353, 2354, 2355, 2356, 2357, 2359, 2360, 2361, 2362, 2363, 2364, 2364, 2365, 2366, 2366, 2367, 2368, 2369, 2370, 2371, 2373, 2374, 2375, 2375, 2376, 2377, 2377, 2378, 2379, 2379, 2380, 2381, 2382, 2383, 2384, 2385, 2386, 2387, 2387, 2388, 2389, 2391, 2392, 2393, 2394, 2395, 2396, 2396, 2397, 2398, 2398, 2399, 2400, 2400, 2401, 2402, 2403, 2404, 2405, 2405, 2406, 2407, 2407, 2408, 2409, 2411, 2412, 2413, 2413, 2414, 2415, 2416, 2417, 2419, 2419, 2420, 2421, 2422, 2423, 2424, 2425, 2426, 2427, 2428, 2429, 2430, 2431, 2432, 2433, 2433, 2434, 2435, 2435, 2436, 2437, 2440, 2441, 2442, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2449, 2450, 2450, 2451, 2452, 2453, 2454, 2455, 2457, 2458, 2459, 2460, 2461, 2462, 2463, 2464, 2465, 2469, 2470, 2471, 2474, 2475, 2476, 2479, 2480, 2481, 2484, 2485, 2486, 2487, 2488, 2489, 2489, 2490, 2491, 2494, 2495, 2496, 2496, 2497, 2498, 2498, 2499, 2500, 2502, 2503, 2504, 2504, 2505, 2506, 2506, 2507, 2508, 2510, 2511, 2512, 2512, 2513, 2514, 2517, 2518, 2519, 2523, 2524, 2525, 2525, 2526, 2527, 2527, 2528, 2529, 2529, 2530, 2531, 2531, 2532, 2533, 2537, 2538, 2539, 2539, 2540, 2541, 2543, 2544, 2545, 2545, 2546, 2547, 2547, 2548, 2549, 2551, 2552, 2553, 2553, 2554, 2555, 2555, 2556, 2557, 2557, 2558, 2559, 2559, 2560, 2561, 2561, 2562, 2563, 2564, 2565, 2566, 2566, 2567, 2568, 2570, 2571, 2572, 2572, 2573, 2574, 2574, 2575, 2576, 2577, 2578, 2579, 2580, 2581, 2582, 2582, 2583, 2584, 2584, 2585, 2586, 2586, 2587, 2588, 2589, 2590, 2591, 2591, 2592, 2593, 2594, 2595, 2596, 2596, 2597, 2598, 2598, 2599, 2600, 2602, 2603, 2604, 2606, 2607, 2608, 2609, 2610, 2611, 2611, 2612, 2613, 2613, 2614, 2615, 2615, 2616, 2617, 2617, 2618, 2619, 2620, 2621, 2622, 2625, 2626, 2627, 2631, 2632, 2633, 2645, 2646, 2647, 2648, 2649, 2650, 2650, 2651, 2652, 2656, 2657, 2658, 2658, 2659, 2660, 2661, 2662, 2663, 2664, 2665, 2666, 2666, 2667, 2668, 2669, 2670, 2671, 2671, 2672, 2673, 2673, 2674, 2675, 2680, 2681, 2682, 2683, 2684, 2685, 2686, 2687, 2688, 2693, 2694, 2695, 2698, 2699, 2700, 2701, 2702, 2703, 2704, 2705, 2706, 4976, 2706, 2707, 2709, 2710, 2711, 2717, 2718, 2719, 2722, 2723, 2724, 2725, 2726, 4987, 4990, 4991, 2726, 2727, 2728, 2729, 2729, 4963, 4960, 4960, 4834, 4823, 4823, 4824, 4825, 4825, 4826, 4827, 4828, 4856, 4857, 4851, 4847, 4848, 4839, 4840, 4830, 4831, 4832, 4833, 4962, 4963, 2729, 2731, 2732, 2733, 2733, 2734, 2735, 2736, 2737, 2738, 2738, 2739, 2740, 2741, 2742, 2743, 2743, 2744, 2745, 2752, 2753, 2754, 2759, 2760, 2761, 2761, 2762, 2763, 2764, 2765, 2766, 2767, 2768, 2769, 2769, 2770, 2771, 2772, 2773, 2774, 2774, 2775, 2776, 2779, 2780, 2781, 2782, 2783, 2784, 2785, 2786, 2787, 2787, 2788, 2789, 2789, 2790, 2791, 2792, 2793, 2794, 2794, 2795, 2796, 2799, 2800, 2801, 4340, 4341, 4342, 4343, 4344, 4345, 4346, 4347, 4348, 4348, 4349, 4350, 4297, 4298, 4299, 4924, 4907, 4908, 4911, 4912, 4913, 4917, 4918, 4919, 4919, 4920, 4921, 4922, 4923, 3394, 4300, 4301, 4302, 4302, 4303, 4304, 4305, 4306, 4307, 4308, 4309, 4310, 4311, 4312, 4313, 4315, 4316, 4317, 4318, 4319, 4320, 4323, 4324, 4325, 4326, 4327, 4328, 4330, 4331, 4332, 4334, 4335, 4336, 4336, 4337, 4338, 2802, 2803, 4382, 4382, 4383, 4384, 4385, 4386, 4387, 4390, 4391, 4394, 4394, 4395, 4396, 4396, 4397, 4398, 4399, 4400, 4401, 4402, 4403, 4404, 4378, 4379, 4380, 4382, 2803, 2804, 2805, 2806, 2807, 2808, 2809, 2810, 2811, 2812, 2813, 2815, 4459, 4460, 4460, 4455, 4439, 4438, 4439, 4455, 2817, 2818, 2819, 2819, 2820, 4432, 4434, 4435, 2820, 2821, 2822, 4446, 4446, 4447, 4448, 4449, 4450, 4451, 4452, 4443, 4444, 4470, 4463, 4464, 4464, 4465, 4466, 4466, 4467, 4468, 4468, 4469, 4470, 4445, 4446, 2822, 2823, 2824, 2825, 2828, 2829, 2830, 2831, 2832, 2833, 2834, 2835, 2836, 2838, 2839, 2840, 2841, 2842, 2843, 2848, 4559, 4528, 4559, 4560, 4561, 4562, 4563, 4564, 2849, 2850, 2851, 2851, 2852, 2853, 2853, 2854, 4569, 4568, 4569, 2854, 2854, 2855, 2856, 4625, 4626, 4627, 4641, 4642, 4590, 4590, 2857, 2858, 2859, 2860, 2861, 2864, 4667, 4668, 4517, 4518, 4492, 4512, 4367, 4368, 4368, 4783, 4777, 4781, 4782, 4783, 4363, 4364, 4365, 4366, 4367, 4512, 4491, 4492, 4518, 4519, 4520, 4521, 4646, 4483, 4484, 4504, 4661, 4654, 4658, 4659, 4660, 4496, 4497, 4498, 4498, 4580, 4581, 4581, 4582, 4583, 4507, 4508, 4632, 4648, 4665, 4666, 4666, 4667, 2864, 2866, 2867, 2868, 2869, 2870, 2871, 2871, 2872, 2873, 2873, 2874, 2875, 2877, 2878, 2879, 2882, 2883, 2884, 2888, 2889, 2890, 2892, 2893, 2894, 2894, 2895, 2896, 2896, 2897, 2898, 2899, 2900, 2901, 2901, 2902, 2903, 2904, 2905, 2906, 2906, 2907, 2908, 2910, 4359, 4360, 4790, 4755, 4756, 4791, 4786, 4787, 4691, 4692, 4746, 4766, 4723, 4721, 4811, 4800, 4801, 4801, 4802, 4803, 4806, 4807, 4808, 4808, 4809, 4810, 4722, 4723, 4766, 4766, 4767, 4797, 4797, 4794, 4795, 4768, 4769, 4358, 4358, 4359, 2910, 2911, 2912, 4541, 4540, 4541, 2912, 2913, 2914, 2915, 2916, 2917, 2918, 2919, 2920, 2921, 2922, 2923, 2924, 2926, 2927, 2928, 2928, 2929, 2930, 2931, 2932, 2933, 2934, 2935, 2936, 2936, 4878, 4876, 2938, 2939, 2940, 2942, 2943, 2944, 2944, 2945, 2946, 2946, 2947, 2948, 2948, 2950, 2951, 2951, 2952, 2953, 2954, 2955, 2956, 2956, 2957, 2958, 2958, 2959, 2960, 2961, 2962, 2963, 2963, 2964, 2965, 2970, 2971, 2972, 2973, 2974, 2975, 2976, 2977, 2978, 2978, 2979, 2980, 2980, 2981, 2982, 2982, 2983, 2984, 2984, 2985, 2986, 2986, 2987, 2988, 2988, 2989, 2990, 2992, 2993, 2994, 2995, 2996, 2997, 2997, 2998, 2999, 2999, 3000, 3001, 3001, 3002, 3003, 3003, 3004, 3005, 3006, 3007, 3008, 3008, 3009, 3010, 3010, 3011, 3012, 3012, 3013, 3014, 3014, 3015, 3016, 3016, 3017, 3018, 4862, 4863, 4864, 3019, 3020, 3021, 3022, 3023, 3024, 3024, 3025, 3026, 3027, 3028, 3029, 3029, 3030, 3031, 3032, 3033, 3034, 3036, 3037, 3038, 3039, 3040, 3041, 3042, 3043, 3044, 3046, 3047, 3048, 3048, 3049, 3050, 3052, 3053, 3054, 3055, 3056, 3057, 3058, 3059, 3060, 3062, 3063, 3064, 3064, 3065, 3066, 3067, 3068, 3069, 3069, 3070, 3071, 3071, 3072, 3073, 3073, 3074, 3075, 3076, 3077, 3078, 3079, 3080, 3081, 3086, 3087, 3088, 1, 2, 3, 4, 5, 6, 6, 7, 8, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 21, 22, 23, 24, 25, 26, 26, 27, 28, 29, 30, 31, 31, 32, 33, 33, 34, 35, 39, 40, 41, 41, 42, 43, 43, 44, 45, 45, 46, 47, 47, 48, 49, 52, 53, 54, 54, 55, 56, 56, 57, 58, 59, 60, 61, 61, 62, 63, 63, 64, 65, 65, 66, 67, 68, 69, 70, 72, 73, 74, 74, 75, 76, 76, 77, 78, 79, 80, 81, 82, 83, 84, 87, 88, 89, 89, 90, 91, 91, 92, 93, 97, 98, 99, 100, 101, 102, 104, 105, 106, 110, 111, 112, 116, 117, 118, 118, 119, 120, 121, 122, 123, 124, 125, 126, 128, 129, 130, 130, 131, 132, 132, 133, 134, 134, 135, 136, 137, 138, 139, 141, 142, 143, 143, 144, 145, 149, 150, 151, 152, 153, 154, 155, 156, 157, 161, 162, 163, 163, 164, 165, 165, 166, 167, 174, 175, 176, 176, 177, 178, 178, 179, 180, 181, 182, 183, 183, 184, 185, 185, 186, 3490, 3492, 3493, 3494, 3495, 3478, 3479, 3481, 3482, 3483, 3485, 3486, 3487, 3489, 3490, 186, 192, 193, 194, 194, 195, 196, 196, 197, 198, 198, 199, 200, 201, 202, 203, 203, 204, 205, 205, 206, 207, 207, 208, 209, 210, 211, 212, 212, 213, 214, 214, 215, 216, 217, 218, 219, 220, 221, 5074, 5076, 5077, 221, 222, 223, 224, 225, 226, 227, 227, 228, 229, 230, 231, 232, 233, 234, 235, 235, 236, 237, 237, 238, 239, 240, 241, 242, 244, 245, 246, 5065, 247, 248, 248, 249, 250, 251, 252, 253, 254, 255, 256, 256, 257, 258, 258, 259, 260, 261, 262, 263, 263, 264, 265, 265, 266, 267, 271, 273, 5058, 5060, 274, 275, 275, 276, 5071, 5070, 5071, 277, 278, 280, 281, 281, 283, 285, 287, 289, 291, 294, 296, 297, 299, 301, 303, 308, 310, 311, 315, 317, 319, 322, 324, 326, 330, 332, 334, 336, 338, 339, 341, 343, 345, 346, 348, 350, 354, 356, 357, 357, 359, 361, 362, 364, 365, 380, 382, 384, 397, 399, 400, 410, 412, 413, 433, 435, 436, 447, 449, 450, 450, 452, 454, 455, 457, 459, 469, 471, 472, 472, 473, 475, 480, 482, 484, 485, 486, 488, 492, 494, 495, 500, 501, 503, 513, 514, 516, 519, 521, 523, 527, 528, 530, 549, 551, 552, 554, 556, 557, 557, 558, 560, 561, 563, 564, 568, 570, 572, 573, 579, 580, 595, 597, 598, 598, 600, 601, 602, 3714, 3715, 3717, 3713, 603, 603, 605, 3691, 3691, 3686, 3683, 3685, 606, 607, 611, 613, 615, 615, 617, 618, 624, 626, 627, 631, 633, 634, 634, 636, 638, 638, 640, 641, 641, 643, 644, 648, 650, 652, 657, 658, 660, 667, 669, 670, 686, 688, 689, 689, 691, 693, 698, 700, 701, 701, 703, 705, 708, 710, 3703, 3705, 711, 712, 715, 717, 719, 724, 726, 727, 728, 730, 731, 732, 3660, 3661, 3662, 3663, 733, 733, 735, 736, 736, 737, 739, 739, 740, 742, 745, 747, 748, 750, 753, 755, 755, 757, 758, 758, 760, 761, 767, 768, 772, 779, 780, 782, 783, 785, 786, 788, 790, 791, 800, 801, 3498, 3500, 801, 3504, 3450, 3452, 3453, 3457, 3458, 3460, 3463, 3464, 3466, 3466, 3468, 3470, 3472, 3473, 3474, 3474, 3574, 3570, 3573, 3474, 3475, 3475, 3435, 3437, 3439, 3441, 3443, 3258, 3259, 3261, 3263, 3265, 3267, 3240, 3242, 3244, 3244, 3245, 3247, 801, 802, 804, 804, 805, 807, 807, 809, 810, 817, 818, 820, 821, 824, 825, 828, 829, 3513, 831, 832, 3542, 3540, 832, 834, 836, 837, 839, 846, 848, 849, 854, 856, 858, 858, 3527, 3528, 3526, 859, 860, 860, 861, 863, 864, 866, 868, 868, 870, 871, 3582, 874, 875, 876, 878, 3656, 3655, 879, 3595, 3593, 879, 881, 885, 887, 889, 891, 893, 894, 898, 900, 901, 906, 908, 909, 909, 911, 913, 913, 915, 917, 3619, 3620, 923, 931, 933, 935, 938, 940, 941, 944, 946, 947, 947, 949, 951, 951, 952, 954, 954, 955, 957, 957, 959, 961, 962, 963, 965, 978, 979, 980, 980, 981, 982, 3196, 3198, 3199, 3201, 3203, 3204, 3836, 3838, 3840, 3850, 3852, 3854, 3825, 3826, 3828, 3829, 3831, 3832, 3212, 3214, 3215, 3809, 3811, 3813, 3224, 3226, 3228, 985, 987, 988, 990, 992, 993, 993, 995, 997, 998, 1000, 1001, 1003, 1005, 3798, 3805, 3796, 3798, 3798, 1007, 1008, 1008, 1010, 1012, 1020, 1021, 3792, 3790, 3791, 1022, 1030, 1031, 1033, 1039, 1040, 1042, 1043, 3913, 3914, 3912, 1044, 1045, 1047, 1049, 1051, 3937, 3929, 3948, 3947, 3929, 1052, 1052, 3367, 3366, 3365, 1053, 1055, 3892, 3893, 3374, 3373, 3894, 3895, 4043, 4064, 4066, 4064, 4045, 4046, 4047, 4049, 4050, 3897, 3898, 3900, 1057, 1058, 1060, 4001, 1066, 1068, 1070, 1071, 1073, 3881, 4056, 4054, 3974, 1073, 1075, 1075, 1077, 1079, 1081, 1083, 4087, 4080, 4082, 4083, 4083, 4084, 4013, 4012, 3314, 3316, 3318, 3289, 3290, 3290, 3291, 3293, 3298, 3299, 3301, 3330, 3322, 3323, 3314, 4085, 4086, 4086, 4087, 1084, 1084, 1086, 1087, 1087, 5354, 5352, 5355, 1090, 1091, 1091, 1092, 1094, 4076, 1099, 1101, 1101, 3976, 3977, 3979, 1102, 1104, 1108, 1109, 3873, 3872, 3873, 3860, 3859, 3873, 1110, 3340, 3342, 3343, 3349, 3351, 3352, 3352, 3354, 3355, 3333, 1111, 1112, 3751, 3753, 3756, 3758, 3760, 3761, 3763, 3765, 3768, 3772, 3774, 3775, 3777, 3779, 3781, 3786, 3720, 3722, 3724, 3726, 3727, 3729, 3731, 3732, 3734, 3736, 3738, 3738, 3740, 3742, 3742, 1116, 1117, 1135, 1136, 1139, 1142, 1144, 1146, 1148, 4124, 4125, 4123, 1149, 1150, 1154, 1156, 1157, 1158, 1160, 1161, 1162, 1164, 1165, 1171, 1173, 1174, 4120, 1174, 1176, 1184, 1186, 1187, 1206, 1208, 1209, 1209, 1211, 1212, 1222, 1224, 1226, 1229, 1230, 1231, 1239, 1240, 1244, 1252, 1253, 1255, 1267, 1268, 1270, 1278, 1280, 1281, 1281, 1283, 1284, 1284, 1286, 1287, 1287, 1289, 1290, 1299, 1301, 1303, 1304, 1306, 1308, 1310, 1312, 1313, 1316, 1317, 1319, 1322, 1323, 1325, 1325, 1327, 1329, 1331, 1333, 1334, 1334, 1336, 1338, 1339, 1340, 1342, 1350, 1352, 1354, 1357, 1359, 1361, 1368, 1370, 1371, 1375, 1377, 1378, 1379, 1381, 3141, 3145, 3147, 1382, 1382, 1384, 1386, 1386, 1387, 1389, 4164, 4166, 4167, 4168, 4169, 4170, 4156, 4159, 4161, 1395, 1397, 1398, 1401, 1403, 3121, 1407, 1409, 1411, 1411, 1412, 1414, 1414, 1416, 1418, 1418, 1419, 1421, 1421, 1423, 1425, 1428, 1430, 1431, 1432, 1434, 1435, 1435, 1437, 1439, 1454, 1456, 1458, 1459, 1461, 1463, 1465, 1467, 1469, 1471, 1473, 1477, 1480, 1482, 1484, 1491, 1493, 1494, 1494, 1495, 1497, 1499, 1500, 1502, 1505, 1507, 1508, 1509, 1511, 1513, 1516, 1518, 1520, 1520, 1523, 1524, 1524, 1526, 1527, 1528, 1530, 1531, 1533, 1534, 1536, 1544, 1546, 1548, 1551, 1552, 1554, 1554, 1556, 1557, 1557, 1558, 1560, 1563, 1564, 1566, 1566, 1567, 1569, 4210, 1569, 4202, 4204, 4205, 1569, 1578, 1579, 1581, 4198, 1581, 1582, 1582, 1584, 1585, 1585, 1586, 1588, 1590, 1591, 1593, 1597, 1599, 1601, 1603, 1605, 1606, 1607, 1609, 1610, 1615, 1617, 1619, 1626, 1628, 1629, 1631, 1632, 1634, 1638, 1639, 1641, 1646, 1648, 1649, 1652, 1654, 1656, 1660, 1662, 1664, 1665, 1667, 1669, 1676, 1677, 1679, 1680, 1682, 1683, 1687, 1689, 1691, 1691, 1694, 1695, 1695, 1697, 1699, 1699, 1701, 1702, 1703, 1705, 1707, 1712, 1714, 1716, 1722, 1724, 1725, 1725, 1727, 1728, 1728, 1730, 1732, 1732, 1734, 1736, 1741, 1743, 1744, 1754, 1756, 1758, 1758, 1759, 1761, 1776, 1778, 1780, 1782, 1784, 1785, 1791, 1793, 1794, 1796, 1797, 1799, 1800, 1801, 1803, 1803, 1805, 1806, 1807, 1808, 1810, 1811, 1813, 1815, 1820, 1821, 1823, 1823, 1825, 1827, 1828, 1829, 1831, 1831, 1833, 1834, 1843, 1845, 1846, 1852, 1854, 1855, 1858, 1860, 1861, 1861, 1862, 1864, 1868, 1870, 1871, 1871, 1873, 1874, 1874, 1876, 1877, 1877, 1879, 1881, 1881, 1882, 1884, 1895, 1897, 1898, 1922, 1924, 1925, 1936, 1937, 1939, 1954, 1956, 1957, 1958, 4247, 4248, 4250, 4252, 4254, 4255, 4257, 4259, 4261, 4263, 4265, 4265, 4267, 4268, 4269, 4271, 4272, 4272, 4274, 4275, 4279, 4281, 4282, 4283, 4285, 4286, 1961, 1962, 1964, 1964, 1966, 1967, 1971, 1972, 4293, 4292, 4293, 1973, 1976, 1977, 1979, 1980, 1982, 1983, 1983, 1984, 1986, 1990, 1991, 1993, 2005, 2007, 2008, 2008, 2010, 2012, 2020, 2022, 2023, 2027, 2029, 2030, 2039, 2041, 2043, 2045, 2047, 2048, 2062, 2064, 2066, 2067, 2069, 2070, 2078, 2080, 2082, 2082, 2084, 2085, 2085, 2087, 2088, 2088, 2090, 4930, 2094, 2096, 2097, 2097, 2099, 2100, 2100, 2101, 2103, 2103, 2104, 2106, 2107, 2108, 2110, 2110, 2112, 2113, 2119, 2121, 2123, 2124, 2126, 2127, 2129, 2131, 2133, 2133, 2135, 2137, 2139, 2141, 2143, 2146, 2147, 2149, 2150, 2152, 2153, 2153, 4949, 4950, 4952, 4954, 2155, 2165, 4936, 4937, 2169, 2170, 2172, 2173, 2175, 2176, 2177, 2179, 2180, 2180, 2181, 2183, 2186, 2188, 2190, 2195, 2196, 2198, 2201, 2203, 2204, 2211, 2213, 2215, 2217, 2219, 2220, 2223, 2224, 2226, 2230, 2232, 2233, 2233, 2235, 2237, 2242, 2244, 2245, 2257, 2259, 2261, 2263, 2265, 2266, 2267, 2268, 2270, 2273, 2275, 2277, 2279, 2281, 2282, 2283, 2285, 2286, 2292, 2293, 2295, 2295, 2297, 2299, 2302, 2304, 2305, 2305, 2307, 2308, 2310, 2312, 2314, 2316, 2318, 2319, 2322, 2323, 2325, 2326, 2328, 2330, 2332, 2333, 2335, 2338, 2340, 2341, 2343, 2344, 2346, 2346, 2348, 2349, 2354, 2355, 2357, 2362, 2364, 2366, 2377, 2379, 2381, 2385, 2387, 2389, 2390, 2391, 2393, 2394, 2396, 2398, 2403, 2405, 2407, 2411, 2413, 2415, 2421, 2422, 2424, 2424, 2425, 2427, 2428, 2430, 2431, 2431, 2433, 2435, 2435, 2437, 2438, 2440, 2442, 2444, 2450, 2452, 2453, 2453, 2455, 2456, 2459, 2460, 2462, 2463, 2465, 2466, 2469, 2471, 2472, 2474, 2476, 2477, 2479, 2481, 2482, 2483, 2484, 2486, 2487, 2489, 2491, 2494, 2496, 2498, 2498, 2500, 2501, 2501, 2502, 2504, 2504, 2506, 2508, 2509, 2510, 2512, 2512, 2514, 2515, 2517, 2519, 2520, 2522, 2523, 2525, 2527, 2529, 2531, 2531, 2533, 2534, 2537, 2539, 2541, 2543, 2545, 2547, 2547, 2549, 2550, 2557, 2559, 2561, 2561, 2563, 2564, 2564, 2566, 2568, 2572, 2574, 2576, 2580, 2582, 2584, 2584, 2586, 2588, 2588, 2589, 2591, 2594, 2596, 2598, 2606, 2608, 2609, 2617, 2619, 2620, 2625, 2627, 2628, 2630, 2631, 2633, 2645, 2647, 2648, 2650, 2652, 2653, 2654, 2656, 2658, 2661, 2663, 2664, 2664, 2666, 2668, 2671, 2673, 2675, 2680, 2682, 2683, 2683, 2685, 2686, 2686, 2688, 2689, 2692, 2693, 2695, 2698, 2700, 2701, 2704, 2706, 4976, 4975, 4976, 2707, 2716, 2717, 2719, 2721, 2722, 2724, 2725, 4987, 4988, 4990, 2726, 2727, 2727, 2729, 4960, 4823, 4825, 4827, 4831, 4833, 4834, 4834, 4960, 4961, 4962, 2729, 2730, 2730, 2731, 2733, 2733, 2735, 2736, 2736, 2738, 2740, 2743, 2745, 2746, 2764, 2766, 2767, 2767, 2769, 2771, 2772, 2774, 2776, 2778, 2779, 2781, 2782, 2784, 2785, 2787, 2789, 2791, 2792, 2794, 2796, 2799, 2801, 4340, 4340, 4342, 4343, 4343, 4345, 4346, 4346, 4348, 4350, 4297, 4299, 3392, 4923, 4924, 4908, 4911, 4913, 4914, 4919, 4921, 4922, 3391, 3392, 4299, 4300, 4302, 4304, 4305, 4307, 4308, 4311, 4313, 4314, 4314, 4315, 4317, 4317, 4318, 4320, 4323, 4325, 4326, 4330, 4332, 4333, 4333, 4334, 4336, 2801, 2802, 4382, 4390, 4394, 4396, 4401, 4402, 4404, 4382, 2804, 2805, 2807, 2808, 2810, 2811, 2813, 2814, 4460, 4439, 4440, 4438, 4455, 4456, 2817, 2819, 4432, 4434, 2820, 2821, 4468, 4470, 4445, 4445, 2822, 2823, 2823, 2825, 2826, 2827, 2828, 2830, 2831, 2833, 2834, 2834, 2836, 2837, 2838, 2840, 2841, 2841, 2843, 2844, 2853, 4569, 4566, 4568, 2854, 2856, 4555, 4590, 4642, 4641, 4590, 2858, 2858, 2859, 2861, 2862, 2864, 4668, 4517, 4492, 4493, 4511, 4512, 4368, 4368, 4777, 4778, 4780, 4781, 4783, 4370, 4363, 4365, 4366, 4512, 4513, 4670, 4665, 4648, 4648, 4634, 4631, 4631, 4632, 4508, 4504, 4654, 4655, 4658, 4660, 4661, 4579, 4580, 4498, 4496, 4498, 4581, 4507, 4632, 4633, 2865, 2866, 2868, 2868, 2869, 2871, 2881, 2882, 2884, 2887, 2888, 2890, 2892, 2894, 2896, 2898, 2899, 2901, 2901, 2903, 2904, 2904, 2906, 2908, 4805, 4806, 4808, 4808, 4810, 4722, 4722, 4766, 4797, 4796, 4797, 4767, 4358, 2910, 2911, 2911, 4541, 4539, 4540, 2912, 2913, 2913, 2915, 2916, 2919, 2921, 2922, 2922, 2924, 2925, 2928, 2930, 2931, 2931, 2933, 2934, 2941, 2942, 2944, 2951, 2953, 2954, 2954, 2956, 2958, 2958, 2960, 2961, 2961, 2963, 2965, 2970, 2972, 2973, 2973, 2975, 2976, 2976, 2978, 2980, 2984, 2986, 2988, 2991, 2992, 2994, 2995, 2997, 2999, 2999, 3001, 3003, 3006, 3008, 3010, 3012, 3014, 3016, 3016, 3018, 4860, 4865, 3018, 3019, 3022, 3024, 3026, 3032, 3034, 3035, 3038, 3039, 3041, 3046, 3048, 3050, 3054, 3055, 3057, 3062, 3064, 3066, 3066, 3067, 3069, 3069, 3071, 3073, 3073, 3075, 3076, 3076, 3078, 3079, 3079, 3081, 3082, 3085, 3086, 3088, 4, 6, 8, 11, 13, 14, 14, 16, 17, 19, 21, 23, 23, 24, 26, 26, 28, 29, 33, 35, 36, 39, 41, 43, 43, 45, 47, 47, 49, 50, 52, 54, 56, 59, 61, 63, 63, 65, 67, 72, 74, 76, 76, 78, 79, 79, 81, 82, 82, 84, 85, 87, 89, 91, 91, 93, 94, 96, 97, 99, 103, 104, 106, 109, 110, 112, 118, 120, 121, 121, 123, 124, 128, 130, 132, 136, 137, 139, 140, 141, 143, 148, 149, 151, 155, 157, 158, 160, 161, 163, 174, 176, 178, 178, 180, 181, 181, 183, 185, 185, 3490, 3491, 3481, 3483, 3484, 3484, 3485, 3487, 3489, 186, 187, 192, 194, 196, 196, 198, 200, 203, 205, 207, 207, 209, 210, 214, 216, 217, 217, 219, 220, 220, 5074, 5075, 225, 227, 229, 229, 230, 232, 239, 240, 242, 244, 246, 247, 5065, 248, 250, 251, 253, 254, 254, 256, 258, 261, 263, 265, 265, 267, 269, 269, 271, 5058, 5060, 275, 5071, 5070, 277, 278, 278, 281, 285, 298, 299, 303, 308, 311, 313, 315, 319, 321, 322, 326, 328, 330, 334, 336, 336, 339, 340, 340, 341, 345, 346, 350, 351, 354, 357, 361, 409, 410, 413, 447, 450, 454, 454, 455, 459, 468, 469, 472, 480, 484, 485, 485, 488, 490, 491, 492, 495, 499, 500, 503, 512, 513, 516, 519, 523, 524, 527, 530, 531, 546, 549, 552, 553, 554, 557, 560, 561, 564, 572, 573, 580, 593, 595, 598, 601, 602, 3715, 3717, 603, 3691, 3690, 3691, 3683, 3684, 3685, 3666, 3669, 3685, 607, 631, 634, 638, 647, 648, 652, 667, 670, 672, 689, 693, 695, 701, 705, 707, 708, 3703, 3704, 3705, 712, 714, 715, 719, 720, 723, 724, 727, 3662, 733, 736, 739, 742, 743, 755, 758, 761, 767, 772, 773, 779, 782, 783, 783, 786, 788, 788, 791, 793, 800, 3498, 3499, 3506, 3247, 3248, 3466, 3470, 3471, 3475, 3437, 3438, 3258, 3261, 3262, 3263, 3267, 3268, 3240, 3244, 3247, 3247, 3506, 3507, 3508, 801, 804, 804, 807, 810, 817, 820, 821, 821, 825, 826, 828, 3513, 3511, 830, 831, 3542, 3540, 834, 836, 836, 839, 840, 845, 846, 849, 853, 854, 858, 858, 3528, 3522, 3526, 860, 863, 863, 864, 3551, 3550, 3551, 864, 864, 868, 871, 872, 873, 3582, 3581, 3582, 875, 875, 876, 3656, 3657, 3655, 3595, 3593, 881, 882, 885, 889, 890, 891, 894, 896, 904, 906, 909, 909, 913, 917, 3619, 923, 924, 931, 935, 936, 944, 947, 951, 951, 954, 957, 957, 961, 962, 962, 965, 966, 978, 980, 982, 3201, 3204, 3205, 3836, 3840, 3841, 3848, 3850, 3854, 3825, 3828, 3829, 3211, 3212, 3215, 985, 988, 3416, 990, 993, 997, 998, 1001, 1003, 1003, 3798, 3799, 3798, 1008, 1012, 1020, 3792, 3793, 3790, 1022, 1023, 1039, 1042, 1043, 3911, 3912, 1045, 1047, 1051, 3929, 3928, 3929, 3937, 3936, 3937, 3948, 3365, 1055, 1056, 3892, 3374, 3372, 3897, 3900, 3901, 3901, 1057, 1060, 4001, 1068, 1069, 3881, 4054, 4055, 3974, 1075, 1079, 1081, 4087, 4088, 4080, 4083, 4013, 4011, 4012, 3316, 3290, 3293, 3295, 3298, 3301, 3302, 3314, 4086, 1084, 5355, 1091, 1094, 1098, 4076, 4071, 4075, 4076, 1101, 1101, 3977, 3978, 3979, 1104, 1106, 1108, 3873, 3874, 3871, 3872, 3860, 3859, 1110, 3334, 3349, 3352, 3355, 3355, 3333, 1112, 3757, 3758, 3761, 3763, 3768, 3770, 3772, 3775, 3777, 3777, 3781, 3782, 3785, 3786, 3722, 3727, 3729, 3732, 3734, 3738, 3742, 3742, 1117, 1118, 1140, 1142, 1146, 3385, 4123, 1150, 1158, 1161, 1162, 1162, 1165, 1166, 4120, 1176, 1177, 1206, 1209, 1212, 1221, 1222, 1226, 1227, 1229, 1231, 1239, 1244, 1245, 1267, 1270, 1271, 1277, 1278, 1281, 1281, 1284, 1287, 1299, 1303, 1304, 1304, 1308, 1309, 1310, 1313, 1314, 1316, 1319, 1320, 1325, 1329, 1330, 1330, 1331, 1334, 1367, 1368, 1371, 1379, 3141, 3142, 3144, 3145, 1382, 1386, 1389, 1390, 4168, 4170, 4155, 4156, 4161, 4162, 1394, 1395, 4134, 4136, 4137, 1395, 1395, 1398, 1400, 1401, 3121, 1405, 1405, 1407, 1411, 1421, 1425, 1426, 1427, 1428, 1431, 1454, 1458, 1459, 1459, 1463, 1464, 1464, 1465, 1469, 1471, 1477, 1478, 1480, 1484, 1486, 1489, 1491, 1494, 1494, 1497, 1498, 1503, 1505, 1508, 1509, 1513, 1515, 1516, 1520, 1524, 1527, 1528, 1531, 1533, 1536, 1538, 1544, 1548, 1549, 1549, 1551, 1554, 1554, 1557, 1560, 1563, 1566, 1569, 1569, 4210, 4207, 4209, 4210, 4202, 4204, 1569, 1570, 1578, 1581, 4196, 4198, 1582, 1585, 1585, 1588, 1589, 1590, 1593, 1594, 1595, 1597, 1601, 1601, 1603, 1606, 1607, 1610, 1612, 1615, 1619, 1621, 1624, 1626, 1629, 1631, 1634, 1636, 1645, 1646, 1649, 1660, 1664, 1665, 1665, 1669, 1670, 1674, 1676, 1679, 1680, 1683, 1685, 1687, 1691, 1695, 1695, 1699, 1702, 1703, 1707, 1709, 1725, 1728, 1732, 1741, 1744, 1746, 1754, 1758, 4226, 4225, 4226, 1758, 1775, 1776, 1780, 1780, 1782, 1785, 1796, 1799, 1800, 1800, 1803, 1806, 1823, 1827, 1828, 1828, 1831, 1834, 1852, 1855, 1857, 1857, 1858, 1861, 1868, 1871, 1874, 1881, 1884, 1885, 1921, 1922, 1925, 4255, 4259, 4260, 4269, 4272, 4275, 4279, 4282, 4283, 4283, 4286, 4229, 1971, 4293, 4290, 1983, 1986, 1987, 1990, 1993, 1994, 2005, 2008, 2012, 2019, 2020, 2023, 2026, 2027, 2030, 2039, 2043, 2045, 2045, 2048, 2049, 2062, 2066, 2067, 2076, 2078, 2082, 2085, 2088, 4930, 4930, 2091, 2092, 2097, 2100, 2103, 2103, 2106, 2107, 2107, 2110, 2113, 2123, 2124, 2127, 2129, 2133, 2137, 2139, 2143, 2145, 2149, 2150, 2153, 4952, 2155, 2156, 2169, 2172, 2173, 2173, 2176, 2177, 2180, 2183, 2184, 2184, 2186, 2190, 2211, 2215, 2217, 2223, 2226, 2227, 2230, 2233, 2237, 2240, 2242, 2245, 2255, 2257, 2261, 2262, 2263, 2266, 2267, 2270, 2271, 2272, 2273, 2277, 2282, 2283, 2286, 2292, 2295, 2299, 2302, 2305, 2308, 2308, 2310, 2314, 2314, 2316, 2319, 2322, 2325, 2326, 2326, 2330, 2332, 2337, 2338, 2341, 2343, 2346, 2349, 2352, 2354, 2357, 2362, 2366, 2368, 2375, 2377, 2381, 2385, 2389, 2390, 2393, 2394, 2398, 2403, 2407, 2409, 2410, 2411, 2415, 2421, 2424, 2427, 2431, 2435, 2438, 2439, 2440, 2444, 2448, 2450, 2453, 2459, 2462, 2463, 2474, 2477, 2478, 2483, 2486, 2487, 2487, 2491, 2492, 2493, 2494, 2498, 2501, 2504, 2508, 2509, 2512, 2515, 2525, 2527, 2531, 2531, 2534, 2535, 2536, 2537, 2541, 2542, 2543, 2547, 2557, 2561, 2564, 2564, 2568, 2569, 2580, 2584, 2588, 2588, 2591, 2593, 2593, 2594, 2598, 2605, 2606, 2609, 2615, 2617, 2620, 2645, 2648, 2650, 2650, 2653, 2654, 2654, 2658, 2660, 2664, 2668, 2669, 2680, 2683, 2686, 2697, 2698, 2701, 2704, 4976, 4974, 4975, 2707, 2708, 2716, 2719, 2720, 2721, 2724, 2725, 4990, 2727, 4960, 4962, 2730, 2733, 2733, 2736, 2740, 2741, 2743, 2746, 2763, 2764, 2767, 2767, 2771, 2772, 2772, 2776, 2777, 2778, 2781, 2782, 2782, 2785, 2787, 2787, 2791, 2792, 2792, 2796, 2797, 4340, 4343, 4346, 4297, 3392, 3393, 4917, 4919, 4922, 3394, 3391, 4299, 4305, 4308, 4310, 4310, 4311, 4314, 4314, 4317, 4320, 4322, 4323, 4326, 4330, 4333, 4336, 2801, 4382, 4384, 4390, 4396, 4398, 4401, 4404, 4378, 4381, 4382, 2805, 2807, 2810, 2811, 2815, 4460, 4440, 2817, 4432, 4433, 4434, 2821, 4446, 4445, 2823, 2826, 2827, 2830, 2831, 2831, 2834, 2837, 2851, 2853, 4566, 4567, 4568, 2856, 4589, 4590, 4555, 4554, 4555, 4642, 2858, 2861, 2862, 4670, 4648, 4646, 4486, 4487, 4646, 4631, 4508, 4504, 4504, 4655, 4656, 4658, 4661, 4504, 4504, 4505, 4534, 4579, 4498, 4499, 4496, 4581, 4583, 4583, 4534, 4535, 4536, 4537, 4505, 4506, 4507, 4633, 2865, 2868, 2871, 2881, 2884, 2885, 2887, 2890, 2891, 2891, 2892, 2896, 2898, 2901, 2904, 4808, 4722, 4797, 4796, 4767, 4768, 4358, 2911, 4539, 4540, 2913, 2916, 2919, 2922, 2925, 2928, 2931, 2934, 2941, 2944, 2946, 2948, 2951, 2954, 2954, 2958, 2961, 2961, 2965, 2966, 2970, 2973, 2976, 2976, 2980, 2982, 2984, 2988, 2990, 2991, 2994, 2995, 2995, 2999, 3003, 3006, 3010, 3012, 3016, 4860, 4861, 4864, 4865, 3019, 3021, 3022, 3026, 3031, 3032, 3035, 3036, 3038, 3041, 3046, 3050, 3051, 3054, 3057, 3058, 3062, 3066, 3069, 3073, 3076, 3079, 3084, 3085, 3088, 4, 8, 10, 10, 11, 14, 14, 17, 19, 23, 26, 29, 31, 33, 36, 38, 39, 43, 43, 47, 50, 51, 52, 56, 63, 67, 68, 72, 76, 79, 79, 82, 85, 86, 87, 91, 96, 99, 100, 103, 106, 107, 109, 112, 113, 118, 121, 124, 127, 128, 132, 136, 139, 140, 140, 143, 145, 148, 151, 152, 154, 155, 158, 160, 163, 165, 174, 178, 181, 181, 185, 3491, 3480, 3481, 3484, 3484, 3487, 3488, 3489, 187, 188, 191, 192, 196, 196, 200, 201, 201, 203, 207, 212, 214, 217, 217, 220, 5075, 225, 229, 232, 239, 242, 243, 243, 244, 247, 247, 5065, 5066, 5064, 5065, 250, 254, 258, 260, 260, 261, 265, 265, 269, 5058, 5059, 5060, 5071, 5070, 278, 285, 298, 303, 304, 315, 321, 322, 336, 340, 345, 346, 351, 352, 354, 361, 362, 409, 413, 415, 447, 454, 459, 468, 472, 475, 480, 485, 490, 511, 512, 516, 527, 531, 533, 546, 552, 553, 557, 560, 564, 572, 580, 582, 601, 3715, 3716, 3717, 3691, 3689, 3690, 3683, 3684, 3684, 3666, 3675, 3678, 3666, 3667, 3669, 607, 608, 629, 631, 638, 647, 652, 653, 707, 708, 3704, 3705, 714, 715, 723, 727, 728, 736, 739, 743, 755, 761, 762, 766, 767, 773, 779, 783, 788, 788, 793, 795, 3463, 3466, 3471, 3475, 3438, 3439, 3262, 3263, 3268, 3240, 3247, 3507, 3508, 804, 810, 826, 828, 3511, 830, 3542, 3543, 3538, 3539, 3540, 3540, 836, 840, 845, 849, 850, 852, 853, 858, 3525, 3526, 863, 3549, 3550, 864, 875, 3656, 3657, 3657, 3595, 3589, 3592, 3593, 882, 884, 885, 890, 890, 891, 896, 904, 909, 917, 3619, 924, 925, 951, 957, 962, 962, 966, 967, 977, 978, 982, 3848, 3854, 3823, 3825, 3829, 3832, 989, 990, 997, 997, 998, 1003, 3798, 1012, 1014, 1019, 1020, 3793, 1039, 1043, 3914, 3911, 1045, 1046, 1046, 1047, 3929, 3927, 3928, 3937, 3936, 3948, 3946, 3943, 3946, 3947, 3365, 1056, 3901, 3901, 3890, 3891, 3897, 3901, 1060, 1064, 1065, 4001, 4000, 4001, 3990, 3989, 3990, 4001, 1070, 1073, 3971, 3973, 3974, 1079, 1080, 1081, 4088, 4080, 4013, 4011, 3318, 3290, 3295, 3314, 1084, 1087, 5355, 1094, 1095, 1097, 1098, 4071, 4073, 4075, 1101, 3978, 3979, 1106, 3871, 3860, 3862, 3858, 3859, 3334, 3349, 3355, 1112, 3757, 3761, 3763, 3763, 3770, 3772, 3772, 3777, 3782, 3785, 3722, 3724, 3727, 3732, 3734, 3385, 1150, 1151, 1157, 1158, 1162, 1174, 4120, 4118, 1227, 1231, 1233, 1239, 1245, 1247, 1267, 1271, 1273, 1281, 1287, 1290, 1299, 1304, 1309, 1316, 1320, 1321, 1322, 1325, 1330, 1330, 1334, 1338, 1367, 1371, 1372, 3119, 1372, 1373, 1378, 1379, 3142, 3144, 1382, 1386, 1386, 1390, 4164, 4168, 4155, 4192, 4191, 4192, 4155, 4164, 1390, 1391, 1392, 1394, 4134, 4136, 1395, 1400, 1400, 1401, 4149, 4151, 4152, 1401, 1405, 1411, 1414, 1427, 1431, 1432, 1454, 1459, 1464, 1464, 1469, 1470, 1479, 1480, 1486, 1487, 1489, 1494, 1494, 1498, 1499, 1503, 1508, 1509, 1509, 1515, 1516, 1516, 1524, 1527, 1527, 1531, 1532, 1532, 1533, 1538, 1543, 1544, 1549, 1549, 1554, 1560, 1563, 1569, 4207, 4208, 4209, 4202, 4204, 1570, 1571, 1578, 4196, 4197, 4198, 1585, 1589, 4215, 1590, 1594, 1595, 1601, 1606, 1606, 1607, 1612, 1614, 1615, 1621, 1631, 1636, 1638, 1645, 1649, 1650, 1660, 1665, 1670, 1674, 1679, 1680, 1702, 1703, 1709, 1725, 1732, 1736, 1741, 1746, 1747, 1752, 1754, 4226, 4225, 1758, 1761, 1780, 1785, 1787, 1794, 1796, 1800, 1800, 1806, 1807, 1820, 1823, 1828, 1857, 1861, 1864, 1868, 1874, 1877, 1881, 1885, 1886, 1921, 1925, 1926, 4254, 4255, 4260, 1970, 1971, 4290, 1980, 1983, 1987, 1989, 1990, 1994, 2004, 2005, 2012, 2019, 2023, 2024, 2026, 2030, 2031, 2038, 2039, 2045, 2062, 2067, 2070, 2082, 2085, 4930, 4929, 4930, 2092, 2103, 2107, 2113, 2119, 2123, 2127, 2168, 2169, 2173, 2173, 2177, 2180, 2184, 2190, 2192, 2210, 2211, 5019, 2211, 5016, 5014, 5015, 2211, 2217, 2223, 2227, 2229, 2229, 2230, 2237, 2240, 2245, 2246, 5046, 5047, 2246, 2246, 2247, 2248, 2262, 2266, 2267, 2267, 2271, 2272, 2272, 2277, 2279, 2282, 2286, 2288, 2290, 2292, 2299, 2302, 2308, 2314, 2314, 2319, 2320, 2321, 2322, 2326, 2335, 2337, 2341, 2352, 2357, 2358, 2361, 2362, 2368, 2375, 2381, 2382, 2384, 2385, 2390, 2393, 2398, 2400, 2403, 2409, 2410, 2421, 2427, 2428, 2428, 2431, 2438, 2439, 2444, 2445, 2448, 2453, 2456, 2457, 2459, 2463, 2473, 2474, 2478, 2482, 2483, 2487, 2498, 2501, 2508, 2508, 2509, 2515, 2536, 2541, 2542, 2542, 2547, 2550, 2557, 2564, 2569, 2579, 2580, 2588, 2605, 2609, 2611, 2661, 2664, 2669, 2680, 2686, 2689, 2697, 2701, 2703, 2704, 4974, 4975, 4975, 2708, 2709, 2715, 2716, 2720, 2720, 2721, 2725, 4990, 4960, 4823, 4961, 4962, 4997, 4962, 2733, 2740, 2741, 2746, 2747, 2761, 2763, 2767, 2772, 2777, 2778, 2778, 2782, 2787, 2787, 2792, 2797, 3393, 3394, 4923, 3394, 4299, 4300, 4310, 4314, 4320, 4330, 4336, 4338, 2801, 4384, 4385, 4390, 4398, 4399, 4381, 2805, 2807, 2807, 2811, 2814, 2816, 2817, 4433, 4434, 4446, 4448, 4452, 4444, 4445, 4445, 4470, 4464, 4468, 4445, 2826, 2826, 2827, 2831, 2849, 2851, 4566, 4554, 4642, 4643, 4641, 2858, 2862, 2862, 4668, 4669, 4658, 4504, 4534, 4583, 4535, 4536, 4536, 4505, 4506, 2864, 2865, 2871, 2880, 2881, 2885, 2886, 2887, 2891, 2891, 2896, 2898, 2898, 2904, 2908, 4356, 4357, 4358, 4808, 4797, 4795, 4768, 4358, 4539, 4539, 4540, 2916, 2918, 2919, 2925, 2926, 2928, 2934, 2954, 2961, 2966, 2976, 2982, 2984, 2991, 2995, 3003, 3006, 3012, 3016, 3016, 4861, 4862, 4864, 3019, 3021, 3021, 3026, 3027, 3031, 3035, 3036, 3036, 3041, 3042, 3046, 3051, 3052, 3062, 3069, 3073, 3073, 3079, 3082, 3083, 3084, 3088, 10, 14, 19, 29, 31, 36, 38, 43, 50, 50, 51, 56, 79, 85, 86, 108, 109, 113, 116, 118, 124, 140, 145, 147, 148, 152, 154, 160, 165, 167, 173, 174, 181, 181, 3491, 3492, 3489, 188, 189, 191, 196, 201, 210, 212, 217, 224, 225, 232, 237, 239, 243, 243, 247, 5066, 5064, 250, 251, 265, 5058, 5059, 5059, 5071, 5069, 5070, 285, 287, 297, 298, 304, 315, 322, 328, 330, 336, 345, 354, 362, 365, 407, 409, 415, 447, 459, 460, 467, 468, 475, 480, 490, 491, 511, 516, 517, 545, 546, 553, 557, 564, 565, 568, 572, 582, 598, 601, 3716, 3717, 3689, 3690, 3690, 3684, 3675, 3677, 3678, 3667, 3668, 3669, 608, 629, 638, 641, 645, 647, 653, 701, 707, 3704, 3705, 715, 720, 722, 723, 728, 3662, 736, 743, 750, 755, 762, 766, 773, 774, 779, 788, 795, 3463, 3471, 3472, 3262, 3268, 3269, 3240, 3507, 3508, 3508, 810, 811, 3513, 830, 3543, 3538, 3540, 3560, 3540, 840, 842, 844, 845, 850, 852, 858, 3522, 3525, 863, 3551, 875, 3657, 3589, 3592, 882, 883, 884, 890, 896, 902, 904, 917, 3618, 3619, 925, 944, 951, 962, 976, 977, 982, 3847, 3848, 3823, 3211, 3215, 3216, 3415, 989, 997, 997, 1003, 3799, 3805, 3798, 1014, 1019, 3793, 3789, 1039, 3914, 3915, 3911, 1046, 3929, 3936, 3946, 3943, 3942, 3943, 3947, 3366, 3365, 3901, 3896, 3897, 1060, 1062, 1064, 4001, 3998, 4000, 3990, 1070, 3971, 3972, 3973, 1079, 1080, 1080, 4088, 4089, 3318, 3295, 3296, 1097, 4071, 4072, 4072, 4073, 1101, 3978, 1106, 1108, 3871, 3862, 3863, 3866, 3858, 3334, 3349, 1112, 1114, 3757, 3763, 3772, 3772, 3782, 3785, 3785, 3724, 3727, 3727, 3734, 3742, 1171, 1174, 4118, 1299, 1309, 1310, 1316, 1321, 1322, 1322, 1330, 1338, 1366, 1367, 1372, 3119, 1373, 1375, 1378, 3142, 3143, 4168, 4192, 4193, 4191, 4155, 4156, 1392, 4134, 4135, 4135, 4136, 1400, 1400, 4149, 4150, 4151, 1401, 1405, 1426, 1427, 1432, 1464, 1470, 1471, 1478, 1479, 1486, 1487, 1494, 1499, 1503, 1509, 1516, 1516, 1527, 1532, 1532, 1538, 1540, 1549, 1560, 1561, 4208, 4202, 4203, 4204, 1571, 1572, 1576, 1578, 4197, 4198, 1589, 1590, 4215, 1594, 1595, 1595, 1606, 1612, 1614, 1621, 1622, 1629, 1631, 1638, 1644, 1645, 1650, 1695, 1702, 1709, 1725, 1736, 1737, 1740, 1741, 1747, 1751, 1752, 4226, 4225, 1761, 1762, 1794, 1800, 1807, 1820, 1828, 1834, 1881, 1886, 1887, 1920, 1921, 1926, 4250, 4254, 4260, 1968, 1970, 4290, 1980, 1987, 1988, 1989, 1994, 1995, 2019, 2024, 2025, 2026, 2031, 2032, 2038, 2045, 2049, 2062, 2070, 2071, 2082, 4930, 4927, 4929, 2092, 2094, 2097, 2103, 2113, 2117, 2119, 2127, 2168, 2173, 2180, 2180, 2184, 2192, 2208, 2210, 5019, 5021, 5022, 2211, 5015, 2217, 2220, 2222, 2223, 2229, 2240, 2246, 5045, 2246, 2248, 2249, 2262, 2267, 2272, 2272, 2279, 2282, 2282, 2288, 2289, 2290, 2299, 2300, 2302, 2314, 2320, 2321, 2326, 2332, 2335, 2341, 2342, 2350, 2352, 2358, 2361, 2368, 2369, 2384, 2390, 2393, 2393, 2400, 2402, 2403, 2410, 2415, 2428, 2438, 2439, 2439, 2445, 2447, 2447, 2448, 2456, 2457, 2463, 2466, 2473, 2478, 2479, 2482, 2487, 2492, 2508, 2515, 2516, 2536, 2542, 2550, 2557, 2569, 2570, 2577, 2579, 2588, 2604, 2605, 2611, 2661, 2669, 2671, 2680, 2689, 2690, 2696, 2697, 2703, 2704, 4975, 2709, 2715, 2720, 2725, 4989, 4990, 4823, 4834, 4961, 4997, 4996, 4997, 4962, 4962, 2740, 2741, 2761, 2767, 2772, 2772, 2778, 2787, 3393, 4923, 4908, 4310, 4320, 4321, 4329, 4330, 4338, 2801, 4385, 4387, 4390, 4399, 4401, 4380, 4381, 2807, 4433, 4434, 4448, 4452, 4445, 4464, 4468, 2826, 2831, 4553, 4554, 4643, 4658, 4534, 4583, 4496, 4583, 4536, 4666, 2864, 2871, 2880, 2885, 2886, 2891, 2898, 2908, 4356, 4358, 4747, 4746, 4747, 4358, 4808, 4795, 4796, 4796, 4768, 4539, 4539, 2916, 2918, 2926, 2934, 2936, 4877, 4878, 2936, 2936, 2937, 2938, 2970, 2976, 2984, 2990, 2991, 3003, 3006, 3016, 4862, 4862, 4864, 3021, 3031, 3036, 3042, 3046, 3052, 3054, 3082, 3083, 3088, 4, 10, 19, 23, 29, 36, 50, 56, 58, 108, 113, 114, 116, 124, 126, 147, 148, 154, 159, 160, 167, 173, 181, 3492, 3488, 3489, 189, 190, 191, 201, 210, 217, 5075, 222, 224, 232, 237, 243, 5066, 5059, 5069, 5070, 5070, 287, 291, 297, 304, 305, 330, 345, 346, 407, 415, 416, 447, 460, 461, 467, 475, 476, 479, 480, 491, 511, 517, 518, 545, 553, 557, 568, 582, 583, 3690, 3675, 3676, 3677, 3667, 3668, 3668, 608, 609, 629, 641, 644, 645, 653, 654, 3704, 3705, 720, 3662, 743, 744, 750, 762, 763, 765, 766, 774, 778, 779, 795, 3462, 3463, 3472, 3258, 3262, 3269, 3240, 3508, 811, 3512, 3513, 3543, 3538, 3560, 3561, 3559, 3540, 842, 3525, 3551, 3552, 3581, 875, 3589, 3591, 3592, 883, 884, 896, 897, 902, 917, 918, 976, 982, 983, 3415, 997, 3799, 3805, 1014, 1015, 3910, 3911, 3929, 3936, 3943, 3941, 3366, 3901, 3891, 3373, 3895, 3896, 3896, 4050, 4043, 4050, 3896, 4037, 4036, 4037, 3896, 3896, 1060, 1061, 1061, 1062, 4001, 3998, 3990, 3991, 3965, 1070, 3972, 3959, 3972, 3973, 1080, 4089, 4079, 3317, 3318, 3296, 3978, 1108, 3874, 3866, 3334, 3336, 3349, 1114, 1116, 3757, 3772, 3785, 3785, 3727, 3742, 1171, 4118, 4119, 1299, 1310, 1314, 1314, 1316, 1322, 1364, 1366, 1372, 3119, 1375, 1378, 1378, 3143, 4186, 4183, 4185, 4186, 4186, 3143, 3144, 4167, 4168, 4193, 4151, 1405, 1414, 1426, 1432, 1435, 1454, 1464, 1471, 1478, 1486, 1487, 1487, 1499, 1502, 1503, 1516, 1532, 1549, 1561, 1563, 1576, 4197, 4198, 4215, 1595, 1612, 1624, 1629, 1638, 1642, 1644, 1650, 1687, 1695, 1709, 1722, 1725, 1737, 1740, 1747, 1748, 1751, 4226, 4224, 4224, 4225, 1762, 1794, 1807, 1810, 1820, 1834, 1835, 1881, 1887, 1888, 1920, 1926, 1927, 4250, 4260, 4261, 1967, 1968, 4290, 1989, 1995, 1996, 2017, 2019, 2025, 2025, 2026, 2032, 4929, 2094, 2097, 2097, 2113, 2115, 2115, 2117, 2127, 2206, 2208, 5019, 5021, 2211, 5014, 5014, 5015, 2220, 2222, 2229, 2237, 2239, 2240, 5045, 5046, 2246, 2249, 2261, 2262, 2272, 2272, 2282, 2289, 2289, 2290, 2300, 2320, 2321, 2332, 2332, 2335, 2342, 2350, 2358, 2359, 2361, 2369, 2371, 2384, 2393, 2402, 2403, 2415, 2416, 2421, 2428, 2439, 2447, 2456, 2457, 2457, 2466, 2467, 2479, 2482, 2492, 2498, 2508, 2516, 2535, 2536, 2550, 2555, 2557, 2570, 2604, 2611, 2613, 2660, 2661, 2671, 2679, 2680, 2690, 2696, 2703, 2704, 5007, 2715, 2725, 4988, 4989, 4823, 4834, 4997, 4998, 4998, 4996, 4962, 4962, 2741, 2747, 4297, 3393, 4908, 4305, 4310, 4321, 4390, 4401, 4378, 4380, 2807, 2814, 2816, 4433, 4448, 4466, 4468, 2831, 4657, 4658, 4583, 4496, 4536, 4506, 4666, 2871, 2873, 2891, 2908, 2909, 4356, 4747, 4744, 4745, 4746, 4692, 4691, 4746, 4358, 4805, 4808, 4796, 4539, 2918, 2925, 2925, 2926, 2936, 2936, 2938, 2940, 2970, 2984, 2990, 2990, 3003, 3005, 4862, 3021, 3027, 3031, 3042, 3044, 3046, 3054, 3058, 4, 19, 23, 38, 50, 58, 107, 108, 114, 159, 167, 168, 172, 173, 3492, 3488, 189, 190, 190, 201, 207, 207, 210, 5075, 222, 232, 5083, 5085, 5086, 232, 237, 5066, 5063, 5070, 291, 292, 297, 305, 306, 329, 330, 346, 407, 416, 417, 465, 467, 476, 478, 479, 491, 510, 511, 518, 545, 557, 565, 628, 629, 644, 644, 645, 654, 3704, 720, 721, 765, 774, 775, 778, 795, 796, 3462, 3472, 3474, 3258, 3269, 3270, 3512, 3543, 3537, 3537, 3538, 3561, 3559, 842, 844, 3524, 3525, 3552, 3581, 3589, 3590, 3591, 883, 884, 902, 918, 920, 3614, 920, 921, 975, 976, 983, 3421, 3415, 3799, 3803, 3805, 1015, 3910, 3929, 3927, 3936, 3941, 3942, 1052, 3366, 3891, 3373, 3896, 4043, 4047, 4050, 4037, 4036, 3896, 1061, 3965, 3972, 3959, 1080, 4079, 4080, 3316, 3317, 3296, 1101, 3978, 3874, 3866, 3336, 3337, 3349, 1116, 3744, 1298, 1299, 1314, 1314, 1322, 1338, 1364, 1372, 3119, 3119, 1378, 4186, 4183, 4186, 3144, 4151, 1414, 1418, 1454, 1471, 1478, 1487, 1502, 1503, 1503, 1532, 1540, 1543, 1549, 1563, 1576, 4198, 1590, 1590, 4215, 4216, 4214, 4215, 1612, 1624, 1638, 1641, 1642, 1650, 1652, 1722, 1737, 1738, 1739, 1740, 1748, 1751, 4224, 1762, 1794, 1810, 1811, 1881, 1888, 1890, 1919, 1920, 1927, 1988, 1989, 1996, 2017, 2025, 2032, 4928, 4929, 2097, 2115, 2127, 2129, 2205, 2206, 5019, 5021, 5014, 2220, 2222, 2237, 2239, 5046, 2249, 2251, 2255, 2261, 2272, 2272, 2289, 2300, 2349, 2350, 2359, 2359, 2361, 2371, 2384, 2402, 2403, 2419, 2421, 2439, 2439, 2447, 2457, 2479, 2492, 2493, 2498, 2516, 2517, 2531, 2535, 2550, 2555, 2570, 2572, 2602, 2604, 2613, 2660, 2671, 2675, 2695, 2696, 2704, 5007, 2725, 4988, 4988, 4823, 4827, 4831, 4834, 4998, 4297, 4908, 4909, 4378, 4380, 2814, 2816, 4448, 4449, 4466, 2831, 2837, 2837, 2838, 2841, 4656, 4657, 4583, 4666, 2873, 2875, 4744, 4745, 4692, 4691, 4358, 4766, 4796, 4539, 2925, 2925, 2936, 4876, 2936, 2940, 2941, 2970, 2990, 3005, 3006, 4862, 3027, 3031, 3044, 3045, 4, 23, 36, 107, 114, 115, 158, 159, 168, 171, 172, 3492, 190, 207, 5075, 222, 5083, 5084, 5085, 232, 233, 237, 5063, 5064, 5059, 5070, 292, 294, 297, 306, 329, 346, 352, 406, 407, 417, 465, 476, 477, 509, 510, 518, 543, 545, 565, 627, 628, 644, 701, 3704, 721, 765, 775, 776, 3461, 3462, 3474, 3257, 3258, 3270, 3524, 3552, 3546, 3590, 3591, 884, 902, 920, 3611, 3613, 3614, 921, 3909, 3910, 3927, 1052, 3891, 3892, 3372, 3373, 4043, 4047, 4037, 4035, 4036, 1061, 4001, 3348, 3349, 3744, 1363, 1364, 3119, 3119, 4186, 4187, 4183, 3144, 1386, 1454, 1478, 1487, 1503, 1540, 1541, 1543, 1563, 4207, 1576, 1590, 4216, 4214, 1612, 1614, 1623, 1624, 1641, 1641, 1642, 1652, 1722, 1738, 1739, 1750, 1751, 1762, 1794, 1811, 1815, 1877, 1881, 1890, 1919, 1927, 1928, 1980, 1988, 1996, 2017, 2032, 2033, 2115, 2129, 2137, 2205, 5019, 5020, 5021, 2220, 2222, 2222, 2239, 5045, 5046, 2251, 2253, 2255, 2272, 2300, 2349, 2359, 2371, 2382, 2384, 2403, 2419, 2439, 2457, 2473, 2479, 2493, 2493, 2498, 2517, 2531, 2550, 2551, 2555, 2572, 2576, 2602, 2613, 2615, 2660, 2675, 2676, 2695, 2704, 2709, 2714, 2715, 5007, 5006, 5007, 4988, 4988, 4827, 4828, 4831, 4998, 4962, 4378, 2814, 2815, 4458, 4459, 2815, 2815, 2816, 4449, 4426, 2837, 2841, 4656, 4583, 4584, 4666, 2875, 2876, 4744, 4692, 4690, 4690, 4691, 4766, 4805, 4796, 2925, 4877, 2936, 2941, 2970, 3005, 3006, 3006, 3027, 3029, 3, 4, 36, 103, 107, 115, 154, 158, 168, 171, 3492, 3494, 222, 5084, 5085, 5085, 233, 235, 5059, 292, 293, 293, 294, 306, 404, 406, 417, 509, 518, 519, 627, 644, 654, 698, 701, 721, 764, 765, 776, 3461, 3474, 3570, 3524, 3546, 3548, 3590, 884, 897, 3613, 921, 922, 3909, 3927, 3937, 3947, 1052, 3892, 3372, 4043, 4066, 4047, 4035, 4036, 3347, 3348, 3744, 3119, 4187, 4179, 4181, 4183, 1386, 1454, 1487, 1503, 1541, 1543, 4207, 1575, 1576, 4216, 1622, 1623, 1641, 1720, 1722, 1739, 1750, 1762, 1763, 1918, 1919, 1928, 1979, 1980, 1996, 2017, 2033, 2035, 2115, 2137, 2139, 5020, 5021, 2222, 5045, 5046, 2253, 2255, 2300, 2301, 2349, 2371, 2372, 2382, 2403, 2416, 2416, 2419, 2457, 2493, 2517, 2520, 2531, 2551, 2553, 2553, 2555, 2576, 2601, 2602, 2615, 2660, 2676, 2677, 2695, 2709, 2711, 2713, 2714, 5007, 4831, 4962, 2747, 4390, 4378, 2815, 4457, 4458, 2815, 2815, 4449, 4451, 4421, 4451, 4452, 4466, 2837, 4426, 4425, 4426, 2841, 4666, 2876, 2877, 4356, 4744, 4690, 4805, 2925, 4876, 4877, 2941, 2946, 3006, 3029, 3031, 1, 3, 36, 103, 115, 116, 171, 3494, 3495, 265, 5059, 293, 293, 306, 308, 404, 417, 419, 624, 627, 654, 763, 764, 776, 3460, 3461, 3570, 3613, 922, 3616, 3908, 3909, 3937, 3947, 3892, 3372, 4047, 4036, 4001, 3346, 3347, 3744, 1503, 1541, 4207, 1575, 4216, 4217, 1622, 1641, 1652, 1719, 1720, 1739, 1750, 1763, 1765, 1918, 1928, 1929, 2016, 2017, 2035, 2097, 2115, 2139, 5020, 2222, 5045, 2255, 2301, 2302, 2343, 2349, 2372, 2375, 2382, 2416, 2416, 2457, 2467, 2473, 2493, 2520, 2600, 2601, 2615, 2692, 2695, 2711, 2713, 5007, 5005, 4971, 5005, 5006, 4830, 4831, 2747, 4457, 2815, 4451, 4451, 4421, 4419, 4421, 4452, 4464, 4464, 4466, 4426, 4425, 2841, 2844, 4648, 4666, 2877, 4877, 2946, 2948, 3006, 3031, 3045, 102, 103, 116, 171, 3495, 3479, 265, 293, 308, 403, 404, 419, 624, 654, 655, 763, 776, 778, 3460, 3570, 3571, 3920, 3908, 3937, 3942, 3947, 3372, 4046, 4047, 4022, 4022, 4047, 4001, 3346, 3744, 3745, 1503, 4207, 4208, 1622, 1652, 1656, 1719, 1739, 1748, 1917, 1918, 1929, 2015, 2016, 2035, 2097, 2139, 2145, 5020, 5045, 2253, 2255, 2302, 2320, 2343, 2372, 2373, 2416, 2467, 2468, 2473, 2520, 2521, 2691, 2692, 2711, 4971, 5006, 4988, 4829, 4830, 4840, 4839, 4830, 2747, 4457, 4451, 4419, 4420, 4421, 4464, 4424, 4425, 2844, 4634, 4648, 2877, 4877, 2948, 2954, 2970, 3006, 3045, 102, 116, 126, 265, 308, 313, 403, 419, 420, 623, 624, 655, 750, 763, 778, 3457, 3460, 3571, 3920, 3937, 3938, 3942, 3372, 4066, 4046, 4022, 4020, 4022, 4001, 3998, 3345, 3346, 3745, 1622, 1656, 1657, 1717, 1719, 1748, 1917, 1929, 1930, 2015, 2035, 2036, 4928, 2097, 2145, 5020, 2253, 2255, 2255, 2320, 2332, 2343, 2373, 2375, 2416, 2468, 2469, 2473, 2521, 2522, 2713, 5005, 4971, 4970, 4971, 4819, 4818, 4819, 4971, 4829, 4840, 4837, 4438, 4456, 4457, 4420, 4464, 4426, 4424, 2844, 2845, 4634, 2877, 2879, 4877, 2954, 2966, 2970, 3045, 3046, 100, 102, 126, 265, 313, 315, 402, 403, 420, 622, 623, 655, 750, 778, 796, 3457, 3571, 3572, 3920, 3938, 3936, 4064, 4046, 4020, 4021, 4022, 3998, 3345, 3745, 3746, 1716, 1717, 1748, 1916, 1917, 1930, 2014, 2015, 2036, 4928, 2145, 2146, 2205, 5020, 2255, 2342, 2343, 2375, 2416, 2469, 2472, 2473, 2522, 2525, 2713, 4971, 4969, 4970, 4819, 4817, 4818, 4971, 4988, 4438, 4457, 4413, 4413, 4457, 4419, 4420, 4426, 4424, 4424, 2845, 2846, 4633, 4634, 2879, 260, 265, 315, 621, 622, 655, 748, 750, 796, 3456, 3457, 3572, 4063, 4064, 4020, 4021, 3998, 3991, 1716, 1748, 1749, 2014, 2036, 2038, 2205, 2255, 2332, 2332, 2342, 2375, 2472, 2473, 2525, 2713, 4969, 4970, 4818, 4988, 4828, 4438, 4413, 4410, 4412, 4413, 4419, 4633, 2879, 2880, 254, 260, 315, 621, 655, 656, 745, 748, 796, 4063, 4020, 4021, 1716, 1749, 1750, 2013, 2014, 2038, 2332, 2375, 2416, 2472, 2525, 2531, 2712, 2713, 4970, 4817, 4818, 4828, 4438, 4410, 4411, 4412, 4419, 4420, 4633, 2880, 2886, 254, 315, 328, 621, 656, 657, 745, 796, 797, 4062, 4063, 4021, 1712, 1716, 1750, 2013, 2038, 2049, 2332, 2416, 2472, 4970, 4817, 4828, 4411, 4412, 4420, 4506, 4633, 2886, 251, 254, 328, 620, 621, 657, 4062, 4021, 3991, 1712, 1750, 1765, 2013, 2049, 2050, 2332, 2472, 2531, 4411, 4420, 4424, 4506, 2886, 2891, 251, 328, 329, 618, 620, 657, 4061, 4062, 3991, 1712, 1765, 1766, 2012, 2013, 2050, 2332, 2531, 2553, 4411, 4424, 2846, 4496, 4506, 2891, 5064, 251, 329, 618, 657, 660, 4061, 3991, 3989, 2012, 2050, 2052, 4438, 4411, 2846, 4501, 4496, 2891, 237, 5064, 329, 618, 660, 661, 4060, 4061, 3989, 2004, 2012, 2052, 4437, 4438, 2846, 4501, 2891, 2909, 237, 329, 352, 615, 618, 661, 2004, 2052, 2053, 4437, 2846, 2847, 4500, 4501, 2909, 4479, 4480, 2909, 2909, 2910, 4360, 4788, 4360, 4356, 235, 237, 352, 611, 615, 661, 2003, 2004, 2053, 4440, 4437, 2847, 4499, 4500, 2909, 4479, 2909, 4360, 4788, 4356, 4690, 5085, 235, 352, 611, 661, 663, 2003, 2053, 2054, 4440, 2847, 2848, 4499, 2909, 4480, 4478, 4479, 4360, 4787, 4788, 4690, 5085, 352, 353, 610, 611, 663, 2002, 2003, 2054, 2815, 4440, 2848, 4564, 2848, 2849, 4478, 4360, 4788, 4791, 4787, 4690, 222, 5085, 353, 2002, 2054, 2055, 4390, 2815, 2848, 4564, 2849, 4566, 4791, 4690, 4766, 222, 353, 354, 2002, 2055, 2056, 4390, 2848, 4528, 4564, 4566, 4567, 222, 354, 365, 2001, 2002, 2056, 4388, 4389, 4390, 4562, 4564, 4567, 221, 222, 365, 2000, 2001, 2056, 4388, 4390, 4618, 4617, 4618, 4390, 4562, 4567, 2856, 221, 365, 366, 2000, 2056, 2057, 4388, 4618, 4619, 4617, 4390, 4528, 4531, 4532, 4559, 4561, 4562, 2856, 5076, 221, 366, 2000, 2057, 2058, 4388, 4619, 4614, 4617, 4528, 4529, 4531, 4559, 4609, 4561, 2856, 4590, 5076, 366, 368, 1999, 2000, 2058, 4387, 4388, 4614, 4617, 4529, 4530, 4530, 4531, 4609, 4561, 4590, 4587, 5075, 5076, 368, 1998, 1999, 2058, 4530, 4609, 4610, 4559, 4561, 4587, 190, 5075, 368, 1998, 2058, 2060, 4617, 4530, 4610, 4612, 4559, 4587, 190, 368, 369, 1996, 1998, 2060, 4612, 4587, 4588, 190, 369, 371, 1979, 1996, 2060, 4611, 4612, 4588, 190, 371, 373, 1979, 2060, 2062, 4611, 4588, 4589, 190, 373, 374, 1979, 2062, 2071, 4617, 4610, 4611, 4575, 4611, 4589, 1976, 1979, 2071, 4575, 4589, 4555, 1976, 2071, 2072, 4611, 4575, 4576, 4574, 4575, 4555, 4617, 4611, 4576, 4574, 4555, 4556, 4617, 4576, 4572, 4573, 4574, 4556, 4616, 4617, 4572, 4573, 4556, 4551, 4623, 4624, 4551, 4616, 4572, 4573, 4623, 4551, 4552, 4615, 4616, 4573, 4623, 4552, 4553, 4615, 4573, 4551, 4615, 4551, 4624, 4614, 4615, 4624, 4387, 4614, 4624, 4387, 4624, 4625, 4387, 4625, 4627, 4387, 4627, 4628, 4890, 4891, 4901, 3046, 3058, 3061, 4181, 1386, 4164, 4162, 1391, 1392, 1622, 1657, 1660, 4893, 4890, 4901, 3046, 3061, 3062, 4180, 4181, 4164, 4156, 4162, 1392, 1614, 1622, 1660, 2970, 3046, 3062, 4180, 4164, 4167, 4156, 1392, 4135, 1614, 1660, 1670, 4179, 4180, 4167, 4191, 4156, 4135, 4214, 1614, 1670, 3757, 3785, 3742, 3119, 4179, 4167, 4193, 4191, 4135, 4217, 4214, 1670, 3119, 4167, 4193, 4217, 1670, 1672, 3119, 4193, 4135, 1575, 4217, 1672, 3119, 4135, 1400, 1575, 1672, 1674, 3119, 1400, 4150, 1575, 1674, 1680, 3119, 4150, 4151, 1574, 1575, 1680, 1815, 1816, 1820, 1846, 1848, 1851, 1890, 1891, 1893, 1916, 1930, 1932, 2158, 2160, 2162, 2194, 2195, 2199, 2636, 2637, 2639, 2748, 2749, 2751, 4352, 4296, 4909, 4486, 4646, 4648, 2966, 2967, 2969, 68, 70, 72, 126, 127, 134, 401, 402, 422, 491, 495, 497, 503, 505, 509, 524, 525, 527, 534, 536, 539, 543, 565, 567, 583, 584, 587, 610, 663, 666, 3661, 3662, 745, 3499, 3500, 3506, 813, 814, 816, 926, 927, 929, 967, 968, 971, 3803, 1015, 1018, 1029, 1030, 1035, 1035, 1037, 1039, 3326, 3327, 3329, 3310, 3311, 3314, 1121, 1122, 1124, 1140, 1146, 1148, 1188, 1190, 1193, 1221, 1226, 1233, 1234, 1235, 1238, 1252, 1255, 1257, 1267, 1273, 1276, 1292, 1294, 1296, 1339, 1342, 1344, 1350, 1354, 1357, 1357, 1361, 1363, 1435, 1439, 1441, 1572, 1573, 1680, 1794, 1815, 1820, 1846, 1851, 1852, 1877, 1890, 1893, 1915, 1916, 1932, 2157, 2158, 2162, 2192, 2194, 2199, 2635, 2636, 2639, 4351, 4352, 4909, 4670, 4646, 4484, 4485, 4486, 4648, 2966, 2969, 2970, 63, 68, 72, 100, 126, 134, 400, 401, 422, 478, 491, 497, 503, 509, 519, 524, 527, 533, 533, 534, 539, 543, 567, 568, 583, 587, 589, 609, 610, 666, 732, 3661, 745, 3499, 3506, 3248, 812, 813, 816, 925, 926, 929, 962, 967, 971, 3802, 3803, 1018, 1027, 1029, 1035, 1035, 1039, 3915, 3308, 3310, 3314, 3314, 1087, 5352, 1120, 1121, 1124, 1139, 1140, 1148, 1187, 1188, 1193, 1220, 1221, 1233, 1250, 1252, 1257, 1265, 1267, 1276, 1292, 1296, 1298, 1338, 1339, 1344, 1348, 1350, 1357, 1357, 1363, 3119, 1426, 1435, 1441, 4204, 1572, 1680, 1794, 1820, 1835, 1843, 1846, 1852, 1877, 1893, 1895, 1915, 1932, 1933, 2157, 2162, 2163, 2192, 2199, 2201, 2635, 2639, 2640, 4351, 4909, 4910, 4485, 4648, 4631, 4877, 2966, 2970, 63, 72, 79, 100, 134, 136, 400, 422, 423, 478, 497, 498, 519, 524, 533, 543, 568, 583, 609, 666, 667, 732, 745, 797, 3499, 3248, 3249, 811, 812, 3517, 3516, 812, 816, 962, 971, 972, 972, 3626, 3623, 3314, 5352, 5351, 1120, 1124, 1125, 1139, 1148, 4125, 1187, 1193, 1194, 1220, 1233, 1234, 1250, 1257, 1258, 1265, 1276, 1281, 1338, 1344, 1346, 1348, 1357, 3119, 4204, 1680, 1685, 1791, 1794, 1835, 1842, 1843, 1852, 1877, 1895, 1898, 1914, 1915, 1933, 2156, 2157, 2163, 2192, 2201, 2204, 4351, 4910, 4911, 4484, 4485, 4631, 59, 63, 79, 96, 100, 136, 397, 400, 423, 477, 478, 498, 503, 519, 533, 543, 583, 589, 3668, 609, 667, 731, 732, 797, 811, 3517, 3518, 1119, 1120, 1125, 1135, 1139, 4125, 1184, 1187, 1194, 1220, 1234, 1238, 1314, 1338, 1346, 1347, 1348, 3119, 4203, 4204, 1685, 1789, 1791, 1835, 1842, 1852, 1857, 1914, 1933, 1934, 2156, 2163, 2164, 2180, 2192, 2204, 5027, 5028, 2204, 4351, 4911, 4914, 58, 59, 79, 96, 136, 140, 477, 498, 499, 503, 533, 539, 3677, 3668, 667, 728, 731, 797, 3240, 811, 3518, 1119, 1125, 1127, 1135, 4125, 4123, 1219, 1220, 1238, 1298, 1314, 1346, 1346, 1347, 3119, 4208, 4203, 1685, 1789, 1835, 1836, 1840, 1842, 1857, 1912, 1914, 1934, 4952, 2156, 2164, 2180, 2204, 5029, 5032, 5027, 2204, 58, 79, 86, 96, 140, 147, 465, 477, 499, 503, 539, 541, 3677, 667, 672, 3277, 3240, 3518, 1119, 1127, 1129, 1134, 1135, 4123, 1219, 1238, 1239, 1298, 1346, 3119, 1503, 4208, 1685, 1838, 1840, 1857, 1912, 1934, 1935, 5032, 2204, 2205, 58, 86, 91, 95, 96, 147, 3676, 3677, 672, 3277, 3518, 3519, 1118, 1119, 1129, 1134, 4123, 3383, 1218, 1219, 1239, 1503, 1685, 1687, 1836, 1838, 1857, 1911, 1912, 1935, 5032, 2205, 2332, 58, 91, 94, 94, 95, 147, 3676, 672, 674, 3742, 1118, 1129, 1132, 1134, 3383, 1216, 1218, 1239, 1503, 1687, 1709, 1836, 1857, 1864, 1911, 1935, 1936, 5030, 5031, 5032, 5036, 5032, 2332, 38, 58, 94, 94, 147, 154, 3690, 3676, 674, 3742, 1129, 1130, 1132, 3383, 3384, 1215, 1216, 1239, 1454, 1503, 1709, 1836, 1864, 1865, 1910, 1911, 1936, 5030, 5032, 5036, 5035, 5036, 2332, 38, 94, 154, 3690, 674, 675, 3742, 1130, 1131, 1132, 3384, 3385, 1215, 1239, 1247, 1451, 1454, 1709, 1789, 1836, 1865, 1910, 1936, 1939, 5029, 5030, 5036, 5038, 5035, 2332, 37, 38, 154, 3717, 3690, 675, 1214, 1215, 1247, 1451, 1709, 1710, 1789, 1865, 1866, 1909, 1910, 1939, 2180, 5029, 5036, 5038, 2332, 2553, 37, 154, 168, 3717, 675, 676, 1214, 1247, 1248, 1451, 1710, 1712, 1787, 1789, 1866, 1909, 1939, 1940, 2180, 5036, 5037, 5038, 2553, 2576, 36, 37, 168, 3716, 3717, 676, 1214, 1248, 1249, 1787, 1866, 1867, 1907, 1909, 1940, 2180, 5037, 5038, 5038, 2576, 2577, 1, 36, 168, 3716, 676, 677, 1212, 1214, 1249, 1780, 1787, 1867, 1907, 1940, 1944, 2180, 5038, 2577, 598, 3716, 677, 1780, 1867, 1868, 1906, 1907, 1944, 2180, 2577, 2588, 598, 677, 679, 1780, 1868, 1877, 1906, 1944, 1945, 2180, 2588, 2593, 593, 598, 679, 1775, 1780, 1877, 1905, 1906, 1945, 2168, 2180, 2593, 593, 679, 681, 1773, 1775, 1877, 1905, 1945, 1946, 2168, 2593, 2598, 593, 681, 682, 1903, 1905, 1946, 2168, 2598, 2600, 592, 593, 682, 1903, 1946, 1950, 2166, 2168, 2600, 592, 682, 683, 1901, 1903, 1950, 590, 592, 683, 1901, 1950, 1951, 590, 683, 684, 1899, 1901, 1951, 3699, 590, 684, 1899, 1951, 1952, 589, 590, 3699, 3700, 3699, 684, 1899, 1952, 1954, 589, 3699, 3701, 3700, 684, 686, 1898, 1899, 1954, 589, 3701, 3700, 3700, 686, 689, 1877, 1898, 1954, 589, 3700, 689, 1877, 1954, 1957, 543, 589, 689, 1877, 1957, 1958, 4245, 4246, 1958, 1958, 1959, 1961, 543, 689, 695, 1877, 1958, 4248, 4245, 1958, 1961, 543, 695, 697, 1773, 1877, 4248, 4243, 4245, 1961, 543, 697, 698, 1773, 4248, 4250, 4241, 4243, 1961, 543, 698, 721, 1773, 4250, 4261, 4241, 1961, 1964, 1773, 4261, 4265, 4240, 4241, 1964, 1773, 4265, 4268, 4240, 1964, 1967, 1772, 1773, 4268, 4238, 4240, 1967, 1771, 1772, 4268, 4236, 4238, 1967, 1771, 4268, 4269, 4236, 1967, 4290, 4235, 4236, 4290, 4234, 4235, 4290, 4234, 4290, 4292, 4233, 4234, 4292, 4232, 4233, 4292, 4232, 4292, 1973, 4231, 4232, 1973, 4231, 1973, 1974, 4230, 4231, 1974, 4230, 1974, 1976, 4229, 4230, 1976, 4283, 4229, 1976, 4283, 1976, 2072, 4279, 4283, 2072, 4277, 4279, 2072, 4277, 2072, 2073, 4277, 2073, 2075, 4277, 2075, 2076, 4276, 4277, 2076, 4276, 2076, 2082, 4275, 4276, 2082, 4269, 4275, 2082, 1771, 4269, 2082, 4927, 2654, 2660, 2082, 4927, 2660, 2082, 2660, 2677, 2082, 2677, 2678, 2082, 2678, 2679, 1771, 2082, 2679, 1771, 2679, 2690, 1771, 2690, 2691, 1769, 1771, 2691, 2747, 2751, 2752, 4900, 4901, 4891, 3325, 3326, 4097, 4100, 4101, 3329, 4839, 2747, 2752, 4899, 4900, 4891, 3325, 4097, 4099, 4839, 2752, 2754, 4899, 4891, 4892, 3324, 3325, 4099, 4838, 4839, 2754, 4837, 4838, 2754, 4837, 2754, 2755, 4829, 4837, 2755, 4829, 2755, 2756, 4829, 2756, 2757, 397, 423, 425, 464, 465, 503, 3516, 816, 821, 3618, 925, 931, 1182, 1184, 1196, 1426, 1441, 1444, 1426, 1444, 1445, 463, 464, 503, 3519, 3516, 821, 1181, 1182, 1196, 1421, 1426, 1445, 463, 503, 541, 3277, 3519, 821, 1181, 1196, 1197, 1418, 1421, 1445, 462, 463, 541, 3277, 821, 826, 1179, 1181, 1197, 461, 462, 541, 3277, 826, 3511, 1179, 1197, 1198, 3277, 3511, 3512, 1179, 1198, 1199, 3275, 3277, 3512, 1177, 1179, 1199, 3275, 3512, 3537, 1177, 1199, 1200, 3273, 3275, 3537, 1177, 1200, 1201, 3273, 3537, 3561, 3561, 3562, 3563, 4120, 1177, 1201, 3271, 3273, 3561, 4119, 4120, 1201, 4119, 1201, 1203, 4119, 1203, 1204, 1171, 4119, 1204, 1170, 1171, 1204, 1170, 1204, 1205, 1170, 1205, 1206, 1168, 1170, 1206, 1168, 1206, 1212, 1166, 1168, 1212, 1162, 1166, 1212, 1157, 1162, 1212, 1157, 1212, 1249, 1154, 1157, 1249, 1154, 1249, 1250, 1154, 1250, 1258, 1154, 1258, 1259, 1153, 1154, 1259, 1152, 1153, 1259, 1151, 1152, 1259, 1151, 1259, 1261, 1151, 1261, 1262, 1151, 1262, 1264, 3385, 1151, 1264, 3385, 1264, 1265, 3385, 1265, 1281, 1132, 3385, 1281, 1132, 1281, 1290, 4094, 1132, 1290, 4094, 1290, 1291, 4094, 1291, 1292, 1131, 4094, 4092, 4093, 4094, 1292, 3742, 1131, 4092, 4093, 1292, 1298, 3742, 4092, 4093, 4093, 1298, 3119, 3742, 4093, 3119, 4151, 3757, 3742, 3757, 4151, 1418, 3757, 1418, 1445, 3756, 3757, 1445, 3751, 3756, 1445, 396, 397, 426, 395, 396, 426, 395, 426, 428, 395, 428, 430, 394, 395, 430, 393, 394, 430, 393, 430, 431, 393, 431, 432, 392, 393, 432, 391, 392, 432, 391, 432, 433, 390, 391, 433, 3271, 3561, 3558, 388, 390, 436, 388, 436, 438, 3270, 3271, 3558, 386, 388, 438, 3270, 3558, 3559, 385, 386, 438, 3257, 3270, 3559, 385, 438, 440, 3257, 3559, 844, 384, 385, 440, 3257, 844, 850, 384, 440, 441, 3256, 3257, 3448, 3447, 3257, 850, 380, 384, 441, 3254, 3256, 3448, 3447, 850, 852, 3252, 3254, 3448, 3445, 3447, 852, 3251, 3252, 3448, 3443, 3445, 852, 3251, 3448, 3449, 3443, 852, 3522, 3251, 3449, 3450, 3439, 3443, 3522, 3251, 3450, 3453, 3439, 3522, 3524, 3439, 3524, 3548, 3475, 3439, 3548, 3475, 3548, 3549, 3475, 3549, 864, 3475, 864, 871, 3573, 3475, 871, 3573, 871, 872, 3573, 872, 3582, 3572, 3573, 3582, 3572, 3582, 3580, 3572, 3580, 3581, 3456, 3572, 3581, 3454, 3456, 3581, 3454, 3581, 3590, 3453, 3454, 3590, 3453, 3590, 897, 3453, 897, 898, 3453, 898, 901, 902, 931, 936, 902, 936, 938, 902, 938, 941, 901, 902, 941, 3453, 901, 941, 3251, 3453, 941, 3251, 941, 942, 379, 380, 442, 379, 442, 443, 378, 379, 443, 377, 378, 443, 377, 443, 444, 377, 444, 445, 375, 377, 445, 374, 375, 445, 374, 445, 446, 374, 446, 447, 190, 374, 447, 190, 447, 461, 190, 461, 541, 190, 541, 543, 190, 543, 721, 797, 3488, 190, 728, 797, 190, 3488, 797, 798, 3484, 3488, 798, 3484, 798, 800, 3480, 3484, 800, 3480, 800, 3499, 3479, 3480, 3499, 3479, 3499, 3249, 171, 3479, 3249, 3088, 171, 3249, 3088, 3249, 3251, 3088, 3251, 942, 3088, 942, 943, 944, 3644, 3642, 3642, 3643, 972, 943, 944, 3642, 3642, 972, 3624, 3625, 3626, 972, 943, 3642, 3624, 3625, 972, 973, 3633, 3634, 973, 973, 974, 975, 943, 3624, 3625, 3625, 973, 3635, 3633, 973, 975, 943, 3625, 3635, 3633, 975, 983, 943, 3635, 3633, 3633, 983, 984, 943, 3633, 984, 984, 3428, 3429, 3427, 3428, 984, 984, 985, 3416, 943, 984, 3195, 3193, 3194, 3195, 3195, 984, 3429, 3427, 984, 3416, 3088, 943, 3195, 3228, 3193, 3195, 3195, 3429, 3431, 3426, 3427, 3416, 3088, 3195, 3196, 3224, 3228, 3195, 3424, 3426, 3416, 3088, 3196, 3199, 3224, 3195, 3431, 3424, 3416, 3418, 3088, 3199, 3200, 3224, 3431, 3424, 3424, 3418, 3419, 3088, 3200, 3201, 3224, 3424, 3419, 3224, 3419, 3421, 3222, 3224, 3421, 3222, 3421, 3799, 3221, 3222, 3819, 3818, 3222, 3799, 3221, 3819, 3808, 3818, 3799, 3801, 3220, 3221, 3808, 3816, 3818, 3801, 3219, 3220, 3808, 3816, 3801, 3802, 3219, 3808, 3809, 3815, 3816, 3802, 3218, 3219, 3809, 3815, 3802, 1018, 3218, 3809, 3813, 3814, 3815, 1018, 3217, 3218, 3813, 3814, 1018, 1019, 3217, 3813, 3814, 3217, 3814, 1019, 3216, 3217, 1019, 3211, 3216, 1019, 1023, 3211, 1019, 3211, 1023, 1025, 3210, 3211, 1025, 3208, 3210, 1025, 3206, 3207, 3208, 3208, 3835, 3836, 3834, 3208, 1025, 3206, 3208, 3836, 3834, 1025, 1027, 3205, 3206, 3836, 3201, 3205, 3836, 3201, 3836, 3841, 3088, 3201, 3841, 3088, 3841, 3842, 3088, 3842, 3844, 3082, 3088, 3844, 3082, 3844, 3845, 3847, 3879, 3880, 4055, 3955, 3956, 3957, 3959, 3973, 3847, 3880, 3881, 3881, 4055, 3956, 3957, 3973, 1080, 3847, 3881, 3956, 3956, 3957, 1080, 3956, 1080, 4080, 3847, 3956, 4080, 3847, 4080, 4011, 3847, 4011, 3316, 3845, 3847, 3316, 3845, 3316, 3296, 3845, 3296, 3297, 3845, 3297, 3298, 3305, 3330, 3323, 3329, 3330, 3306, 3329, 3306, 3307, 1095, 1101, 3874, 3308, 1095, 3874, 3308, 3874, 3875, 3307, 3308, 3875, 3307, 3875, 3876, 3870, 3307, 3876, 3329, 3307, 3864, 3864, 3865, 3866, 4100, 3329, 3864, 4100, 3864, 3866, 4100, 3866, 3337, 4099, 4100, 3337, 3339, 4099, 3337, 3338, 3339, 3337, 4099, 3339, 3340, 3324, 4099, 3340, 3324, 3340, 3343, 3324, 3343, 3344, 3323, 3324, 3344, 3323, 3344, 3345, 3305, 3323, 3345, 3298, 3304, 3345, 3845, 3298, 3345, 3845, 3345, 3746, 3082, 3845, 3746, 3082, 3746, 3748, 3082, 3748, 3750, 4339, 4340, 2801, 4339, 2801, 4387, 4338, 4339, 4387, 4329, 4338, 4387, 4628, 4522, 4516, 4516, 4517, 4493, 4522, 4669, 4670, 4387, 4628, 4516, 4522, 4670, 4484, 4387, 4516, 4593, 4522, 4484, 4631, 4329, 4387, 4593, 4596, 4516, 4493, 4521, 4522, 4631, 4329, 4593, 4594, 4595, 4596, 4493, 4521, 4631, 4504, 4328, 4329, 4374, 4373, 4374, 4329, 4595, 4493, 4489, 4519, 4521, 4504, 4326, 4328, 4374, 4373, 4329, 4594, 4595, 4489, 4490, 4519, 4504, 4656, 4326, 4374, 4375, 4373, 4594, 4595, 4518, 4519, 4656, 4322, 4326, 4375, 4491, 4518, 4656, 4322, 4375, 4373, 4491, 4656, 4584, 4321, 4322, 4373, 4595, 4490, 4491, 4491, 4584, 4579, 4321, 4373, 4595, 4595, 4491, 4511, 4513, 4514, 4491, 4491, 4579, 4499, 4595, 4511, 4368, 4513, 4491, 4499, 4321, 4595, 4368, 4780, 4783, 4368, 4366, 4513, 4499, 4321, 4368, 4778, 4780, 4368, 4370, 4366, 4499, 4480, 4305, 4321, 4778, 4365, 4366, 4480, 4365, 4480, 4477, 4370, 4365, 4477, 4370, 4477, 4478, 4780, 4370, 4478, 4780, 4478, 4788, 4779, 4780, 4788, 4779, 4788, 4789, 4778, 4779, 4789, 4778, 4789, 4756, 4778, 4756, 4710, 4755, 4790, 4791, 4305, 4778, 4710, 4713, 4756, 4753, 4754, 4755, 4791, 4304, 4305, 4710, 4754, 4791, 4766, 4304, 4710, 4711, 4753, 4754, 4766, 4304, 4711, 4712, 4753, 4766, 4721, 4731, 4721, 4722, 4753, 4721, 4731, 4730, 4731, 4722, 4753, 4731, 4729, 4730, 4722, 4810, 4713, 4753, 4729, 4729, 4730, 4810, 4712, 4713, 4729, 4712, 4729, 4810, 4712, 4810, 4811, 4801, 4304, 4712, 4304, 4801, 4803, 4300, 4304, 4803, 4300, 4803, 4804, 4300, 4804, 4805, 3394, 4300, 4805, 3394, 4805, 4876, 4922, 3394, 4876, 4917, 4922, 4876, 4917, 4876, 4877, 4917, 4877, 2970, 4915, 4916, 4917, 4883, 4917, 4901, 4893, 4917, 2970, 4915, 4917, 4883, 4883, 4884, 4881, 4882, 4883, 4901, 4893, 2970, 3062, 4915, 4883, 4881, 4882, 4901, 4899, 4892, 4893, 3062, 4914, 4915, 4881, 4882, 4899, 4892, 4892, 3062, 3073, 4351, 4914, 4881, 4870, 4881, 4882, 4351, 4881, 4870, 4869, 4870, 4882, 4351, 4870, 4867, 4869, 4882, 4892, 4351, 4867, 4868, 4868, 4869, 4892, 4351, 4868, 4892, 4351, 4892, 3073, 4350, 4351, 3073, 4350, 3073, 3082, 4346, 4350, 3082, 4340, 4346, 3082, 1712, 4340, 3082, 1712, 3082, 3750, 1712, 3750, 3751, 1447, 1449, 1446, 1446, 1712, 3751, 4522, 4628, 4622, 4522, 4622, 4623, 4669, 4522, 4623, 4669, 4623, 4553, 2862, 4669, 4553, 4340, 1712, 1766, 4340, 1766, 1767, 2799, 4340, 1767, 2798, 2799, 1767, 2798, 1767, 1768, 2798, 1768, 1769, 2798, 1769, 2691, 2797, 2798, 2691, 2797, 2691, 2711, 2797, 2711, 2712, 2787, 2797, 2712, 2787, 2712, 4828, 4855, 4856, 4828, 2787, 4828, 4857, 4855, 4828, 4829, 2772, 2787, 4857, 4854, 4855, 4829, 4857, 4858, 4849, 4854, 4829, 2757, 2772, 4857, 4849, 4849, 4854, 2757, 2772, 4849, 4850, 4848, 4849, 2757, 2761, 2772, 4850, 4851, 4848, 2757, 2761, 4850, 4851, 4851, 2757, 2758, 2759, 2761, 4851, 4851, 2758, 2759, 1712, 1450, 1451, 1446, 3751, 1445, 3864, 3871, 3863, 1101, 1095, 1096, 1097, 4072, 1101, 1095, 3308, 3314, 3330, 3305, 3306, 3879, 3847, 3823, 4055, 4056, 3883, 3883, 3879, 3823, 4055, 3883, 3823, 4055, 3823, 3824, 3825, 3942, 4066, 3825, 4066, 4068, 3824, 3825, 4068, 4055, 3824, 4068, 4055, 4068, 4060, 4055, 4060, 3989, 3955, 4055, 3989, 3955, 3989, 4001, 4001, 1069, 3964, 4001, 3964, 3966, 3955, 4001, 3966, 3960, 3955, 3966, 3964, 1069, 3965, 3942, 3825, 3832, 3936, 3942, 3832, 3936, 3832, 3834, 3920, 3936, 3834, 3919, 3920, 3834, 3919, 3834, 1027, 3918, 3919, 1027, 3916, 3918, 1027, 3644, 944, 962, 962, 972, 3643, 3643, 3644, 962, 931, 902, 3611, 931, 3611, 3617, 3617, 3618, 931, 3616, 3612, 3613, 2654, 4927, 4928, 2654, 4928, 2146, 2654, 2146, 2149, 2650, 2654, 2149, 2645, 2650, 2149, 2634, 2640, 2641, 2633, 2634, 2641, 4951, 4952, 2165, 2633, 2641, 2643, 4951, 2165, 4937, 4934, 4935, 2165, 2630, 2633, 2643, 4934, 2165, 2166, 2630, 2643, 2644, 4934, 2166, 2600, 2629, 2630, 2644, 4939, 4934, 2600, 2629, 2644, 2645, 4938, 4939, 2600, 2629, 2645, 2149, 4938, 2600, 2615, 2628, 2629, 2149, 4937, 4938, 2615, 2628, 2149, 2153, 4937, 2615, 2620, 2625, 2628, 2153, 4937, 2620, 2622, 2625, 2153, 4950, 4951, 4937, 2622, 2624, 2625, 4950, 4951, 2622, 2623, 2623, 2624, 4950, 4950, 4951, 2623];
+testOutput["water_huge2"]["triangles"]=[4917, 4918, 4919, 4919, 4920, 4921, 4922, 4923, 3394, 4300, 4301, 4302, 4302, 4303, 4304, 4305, 4306, 4307, 4308, 4309, 4310, 4311, 4312, 4313, 4315, 4316, 4317, 4318, 4319, 4320, 4323, 4324, 4325, 4326, 4327, 4328, 4330, 4331, 4332, 4334, 4335, 4336, 4336, 4337, 4338, 2802, 2803, 4382, 4382, 4383, 4384, 4385, 4386, 4387, 4390, 4391, 4394, 4394, 4395, 4396, 4396, 4397, 4398, 4399, 4400, 4401, 4402, 4403, 4404, 4378, 4379, 4380, 4382, 2803, 2804, 2805, 2806, 2807, 2808, 2809, 2810, 2811, 2812, 2813, 2815, 4459, 4460, 4460, 4455, 4439, 4438, 4439, 4455, 2817, 2818, 2819, 2819, 2820, 4432, 4434, 4435, 2820, 2821, 2822, 4446, 4446, 4447, 4448, 4449, 4450, 4451, 4452, 4443, 4444, 4470, 4463, 4464, 4464, 4465, 4466, 4466, 4467, 4468, 4468, 4469, 4470, 4445, 4446, 2822, 2823, 2824, 2825, 2828, 2829, 2830, 2831, 2832, 2833, 2834, 2835, 2836, 2838, 2839, 2840, 2841, 2842, 2843, 2848, 4559, 4528, 4559, 4560, 4561, 4562, 4563, 4564, 2849, 2850, 2851, 2851, 2852, 2853, 2853, 2854, 4569, 4568, 4569, 2854, 2854, 2855, 2856, 4625, 4626, 4627, 4641, 4642, 4590, 4590, 2857, 2858, 2859, 2860, 2861, 2864, 4667, 4668, 4517, 4518, 4492, 4512, 4367, 4368, 4368, 4783, 4777, 4781, 4782, 4783, 4363, 4364, 4365, 4366, 4367, 4512, 4491, 4492, 4518, 4519, 4520, 4521, 4646, 4483, 4484, 4509, 4661, 4654, 4658, 4659, 4660, 4496, 4497, 4498, 4498, 4580, 4581, 4581, 4582, 4583, 4507, 4508, 4632, 4648, 4665, 4666, 4666, 4667, 2864, 2866, 2867, 2868, 2869, 2870, 2871, 2871, 2872, 2873, 2873, 2874, 2875, 2877, 2878, 2879, 2882, 2883, 2884, 2888, 2889, 2890, 2892, 2893, 2894, 2894, 2895, 2896, 2896, 2897, 2898, 2899, 2900, 2901, 2901, 2902, 2903, 2904, 2905, 2906, 2906, 2907, 2908, 2910, 4359, 4360, 4790, 4755, 4756, 4791, 4786, 4787, 4691, 4692, 4746, 4766, 4723, 4721, 4811, 4800, 4801, 4801, 4802, 4803, 4806, 4807, 4808, 4808, 4809, 4810, 4722, 4723, 4766, 4766, 4767, 4797, 4797, 4794, 4795, 4768, 4769, 4358, 4358, 4359, 2910, 2911, 2912, 4541, 4540, 4541, 2912, 2913, 2914, 2915, 2916, 2917, 2918, 2919, 2920, 2921, 2922, 2923, 2924, 2926, 2927, 2928, 2928, 2929, 2930, 2931, 2932, 2933, 2934, 2935, 2936, 2936, 4878, 4876, 2938, 2939, 2940, 2942, 2943, 2944, 2944, 2945, 2946, 2946, 2947, 2948, 2948, 2950, 2951, 2951, 2952, 2953, 2954, 2955, 2956, 2956, 2957, 2958, 2958, 2959, 2960, 2961, 2962, 2963, 2963, 2964, 2965, 2970, 2971, 2972, 2973, 2974, 2975, 2976, 2977, 2978, 2978, 2979, 2980, 2980, 2981, 2982, 2982, 2983, 2984, 2984, 2985, 2986, 2986, 2987, 2988, 2988, 2989, 2990, 2992, 2993, 2994, 2995, 2996, 2997, 2997, 2998, 2999, 2999, 3000, 3001, 3001, 3002, 3003, 3003, 3004, 3005, 3006, 3007, 3008, 3008, 3009, 3010, 3010, 3011, 3012, 3012, 3013, 3014, 3014, 3015, 3016, 3016, 3017, 3018, 4862, 4863, 4864, 3019, 3020, 3021, 3022, 3023, 3024, 3024, 3025, 3026, 3027, 3028, 3029, 3029, 3030, 3031, 3032, 3033, 3034, 3036, 3037, 3038, 3039, 3040, 3041, 3042, 3043, 3044, 3046, 3047, 3048, 3048, 3049, 3050, 3052, 3053, 3054, 3055, 3056, 3057, 3058, 3059, 3060, 3062, 3063, 3064, 3064, 3065, 3066, 3067, 3068, 3069, 3069, 3070, 3071, 3071, 3072, 3073, 3073, 3074, 3075, 3076, 3077, 3078, 3079, 3080, 3081, 3086, 3087, 3088, 1, 2, 3, 4, 5, 6, 6, 7, 8, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 21, 22, 23, 24, 25, 26, 26, 27, 28, 29, 30, 31, 31, 32, 33, 33, 34, 35, 39, 40, 41, 41, 42, 43, 43, 44, 45, 45, 46, 47, 47, 48, 49, 52, 53, 54, 54, 55, 56, 56, 57, 58, 59, 60, 61, 61, 62, 63, 63, 64, 65, 65, 66, 67, 68, 69, 70, 72, 73, 74, 74, 75, 76, 76, 77, 78, 79, 80, 81, 82, 83, 84, 87, 88, 89, 89, 90, 91, 91, 92, 93, 97, 98, 99, 100, 101, 102, 104, 105, 106, 110, 111, 112, 116, 117, 118, 118, 119, 120, 121, 122, 123, 124, 125, 126, 128, 129, 130, 130, 131, 132, 132, 133, 134, 134, 135, 136, 137, 138, 139, 141, 142, 143, 143, 144, 145, 149, 150, 151, 152, 153, 154, 155, 156, 157, 161, 162, 163, 163, 164, 165, 165, 166, 167, 174, 175, 176, 176, 177, 178, 178, 179, 180, 181, 182, 183, 183, 184, 185, 185, 186, 3490, 3492, 3493, 3494, 3495, 3478, 3479, 3481, 3482, 3483, 3485, 3486, 3487, 3489, 3490, 186, 192, 193, 194, 194, 195, 196, 196, 197, 198, 198, 199, 200, 201, 202, 203, 203, 204, 205, 205, 206, 207, 207, 208, 209, 210, 211, 212, 212, 213, 214, 214, 215, 216, 217, 218, 219, 220, 221, 5074, 5076, 5077, 221, 222, 223, 224, 225, 226, 227, 227, 228, 229, 230, 231, 232, 233, 234, 235, 235, 236, 237, 237, 238, 239, 240, 241, 242, 244, 245, 246, 5065, 247, 248, 248, 249, 250, 251, 252, 253, 254, 255, 256, 256, 257, 258, 258, 259, 260, 261, 262, 263, 263, 264, 265, 265, 266, 267, 267, 268, 269, 269, 270, 271, 271, 272, 273, 273, 274, 5058, 5060, 5061, 274, 5071, 276, 277, 278, 279, 280, 281, 282, 283, 283, 284, 285, 285, 286, 287, 287, 288, 289, 289, 290, 291, 294, 295, 296, 299, 300, 301, 301, 302, 303, 306, 307, 308, 308, 309, 310, 311, 312, 313, 313, 314, 315, 315, 316, 317, 317, 318, 319, 319, 320, 321, 322, 323, 324, 324, 325, 326, 326, 327, 328, 330, 331, 332, 332, 333, 334, 334, 335, 336, 336, 337, 338, 341, 342, 343, 343, 344, 345, 346, 347, 348, 348, 349, 350, 354, 355, 356, 357, 358, 359, 359, 360, 361, 362, 363, 364, 366, 367, 368, 369, 370, 371, 371, 372, 373, 375, 376, 377, 380, 381, 382, 382, 383, 384, 386, 387, 388, 388, 389, 390, 397, 398, 399, 404, 405, 406, 407, 408, 409, 410, 411, 412, 413, 414, 415, 417, 418, 419, 420, 421, 422, 426, 427, 428, 428, 429, 430, 433, 434, 435, 436, 437, 438, 438, 439, 440, 447, 448, 449, 450, 451, 452, 452, 453, 454, 455, 456, 457, 457, 458, 459, 465, 466, 467, 469, 470, 471, 473, 474, 475, 480, 481, 482, 482, 483, 484, 486, 487, 488, 488, 489, 490, 492, 493, 494, 501, 502, 503, 503, 504, 505, 505, 506, 507, 507, 508, 509, 514, 515, 516, 519, 520, 521, 521, 522, 523, 528, 529, 530, 531, 532, 533, 534, 535, 536, 537, 538, 539, 539, 540, 541, 541, 542, 543, 543, 544, 545, 546, 548, 549, 549, 550, 551, 554, 555, 556, 558, 559, 560, 561, 562, 563, 568, 569, 570, 570, 571, 572, 573, 576, 579, 580, 581, 582, 585, 586, 587, 587, 588, 589, 590, 591, 592, 593, 594, 595, 595, 596, 597, 598, 599, 600, 3717, 3712, 3713, 603, 604, 605, 605, 606, 3691, 3691, 606, 3686, 3685, 3686, 606, 611, 612, 613, 615, 616, 617, 618, 619, 620, 624, 625, 626, 629, 630, 631, 631, 632, 633, 634, 635, 636, 636, 637, 638, 638, 639, 640, 641, 642, 643, 645, 646, 647, 648, 649, 650, 650, 651, 652, 658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 669, 670, 671, 672, 672, 673, 674, 677, 678, 679, 679, 680, 681, 684, 685, 686, 686, 687, 688, 689, 690, 691, 691, 692, 693, 693, 694, 695, 695, 696, 697, 698, 699, 700, 701, 702, 703, 703, 704, 705, 705, 706, 707, 708, 709, 710, 710, 711, 3703, 3705, 3706, 711, 712, 713, 714, 715, 716, 717, 717, 718, 719, 724, 725, 726, 728, 729, 730, 732, 733, 3660, 3663, 3664, 733, 733, 734, 735, 737, 738, 739, 740, 741, 742, 745, 746, 747, 748, 749, 750, 753, 754, 755, 755, 756, 757, 758, 759, 760, 768, 769, 772, 776, 777, 778, 780, 781, 782, 783, 784, 785, 786, 787, 788, 788, 789, 790, 791, 792, 793, 793, 794, 795, 798, 799, 800, 801, 3501, 3498, 3500, 3501, 801, 3504, 3505, 3506, 3249, 3250, 3251, 3252, 3253, 3254, 3254, 3255, 3256, 3450, 3451, 3452, 3454, 3455, 3456, 3458, 3459, 3460, 3464, 3465, 3466, 3466, 3467, 3468, 3468, 3469, 3470, 3573, 3574, 3474, 3435, 3436, 3437, 3439, 3440, 3441, 3441, 3442, 3443, 3443, 3444, 3445, 3445, 3446, 3447, 3447, 3448, 3257, 3259, 3260, 3261, 3263, 3264, 3265, 3265, 3266, 3267, 3271, 3272, 3273, 3273, 3274, 3275, 3275, 3276, 3277, 3277, 3278, 3240, 3240, 3241, 3242, 3242, 3243, 3244, 3245, 3246, 3247, 3508, 3509, 801, 802, 803, 804, 805, 806, 807, 807, 808, 809, 3516, 3517, 812, 818, 819, 820, 821, 822, 824, 826, 827, 828, 3513, 829, 830, 3559, 3560, 3540, 3540, 3541, 832, 832, 833, 834, 834, 835, 836, 837, 838, 839, 840, 841, 842, 842, 843, 844, 846, 847, 848, 850, 851, 852, 854, 855, 856, 856, 857, 858, 858, 3526, 3527, 3522, 3523, 3524, 3526, 858, 859, 861, 862, 863, 3546, 3547, 3548, 864, 865, 866, 866, 867, 868, 868, 869, 870, 3582, 873, 874, 876, 877, 878, 878, 3655, 3656, 3657, 3654, 3655, 3655, 878, 879, 879, 3594, 3595, 3593, 3594, 879, 879, 880, 881, 885, 886, 887, 887, 888, 889, 891, 892, 893, 894, 895, 896, 898, 899, 900, 902, 903, 904, 904, 905, 906, 906, 907, 908, 909, 910, 911, 911, 912, 913, 913, 914, 915, 915, 916, 917, 918, 919, 920, 920, 3610, 3611, 3620, 922, 923, 929, 930, 931, 931, 932, 933, 933, 934, 935, 936, 937, 938, 938, 939, 940, 944, 945, 946, 947, 948, 949, 949, 950, 951, 952, 953, 954, 955, 956, 957, 957, 958, 959, 959, 960, 961, 963, 964, 965, 969, 970, 971, 3644, 3641, 3642, 973, 3634, 3635, 3196, 3197, 3198, 3201, 3202, 3203, 3836, 3837, 3838, 3838, 3839, 3840, 3842, 3843, 3844, 3845, 3846, 3847, 3848, 3849, 3850, 3850, 3851, 3852, 3852, 3853, 3854, 3854, 3855, 3823, 3826, 3827, 3828, 3829, 3830, 3831, 3832, 3833, 3834, 3834, 3835, 3208, 3208, 3209, 3210, 3212, 3213, 3214, 3809, 3810, 3811, 3811, 3812, 3813, 3816, 3817, 3818, 3818, 3819, 3222, 3222, 3223, 3224, 3224, 3225, 3226, 3226, 3227, 3228, 3429, 3430, 3431, 3424, 3425, 3426, 985, 986, 987, 988, 989, 3416, 3416, 3417, 3418, 3419, 3420, 3421, 3415, 3416, 989, 990, 991, 992, 993, 994, 995, 995, 996, 997, 998, 999, 1000, 1001, 1002, 1003, 1003, 1004, 1005, 1005, 1006, 3798, 3799, 3800, 3801, 3803, 3804, 3805, 3796, 3797, 3798, 3798, 1006, 1007, 1008, 1009, 1010, 1010, 1011, 1012, 1012, 1013, 1014, 1016, 1017, 1018, 1021, 1022, 3792, 3791, 3792, 1022, 1023, 1024, 1025, 1025, 1026, 1027, 1027, 1028, 1029, 1031, 1032, 1033, 1033, 1034, 1035, 1035, 1036, 1037, 1040, 1041, 1042, 1043, 1044, 3913, 3916, 3917, 3918, 3912, 3913, 1044, 1047, 1048, 1049, 1049, 1050, 1051, 3929, 1051, 1052, 1052, 1053, 3367, 3365, 3368, 1053, 1053, 1054, 1055, 3893, 3894, 3374, 3373, 3374, 3894, 4043, 4044, 4064, 4064, 4065, 4066, 4066, 4067, 4068, 4068, 4059, 4060, 4064, 4044, 4045, 4047, 4048, 4049, 3898, 3899, 3900, 3901, 1056, 1057, 1058, 1059, 1060, 1062, 1063, 1064, 3998, 3999, 4000, 4001, 1065, 1066, 1066, 1067, 1068, 3965, 1069, 1070, 1071, 1072, 1073, 4056, 3882, 3883, 3883, 3884, 3879, 3881, 3882, 4056, 4056, 4053, 4054, 3957, 3958, 3959, 1073, 1074, 1075, 1075, 1076, 1077, 1077, 1078, 1079, 4080, 4081, 4082, 4084, 4085, 4013, 4012, 4013, 3314, 3314, 3315, 3316, 3318, 3288, 3289, 3291, 3292, 3293, 3293, 3294, 3295, 3299, 3300, 3301, 3302, 3303, 3304, 3330, 3321, 3322, 4097, 4098, 4099, 3308, 3309, 3310, 3312, 3313, 3314, 3314, 4013, 4085, 4087, 1083, 1084, 1084, 1085, 1086, 1087, 1090, 5354, 5354, 5353, 5352, 5351, 5350, 5357, 5355, 5354, 1090, 1092, 1093, 1094, 4073, 4074, 4075, 4076, 1098, 1099, 1099, 1100, 1101, 1101, 1102, 3976, 3979, 3980, 1102, 1102, 1103, 1104, 1104, 1105, 1106, 1106, 1107, 1108, 3860, 3861, 3862, 3866, 3867, 3858, 3859, 3860, 3873, 3873, 1109, 1110, 1110, 1111, 3334, 3334, 3335, 3336, 3340, 3341, 3342, 3349, 3350, 3351, 3352, 3353, 3354, 3333, 3334, 1111, 1112, 1113, 1114, 1114, 1115, 1116, 1116, 3743, 3744, 3746, 3747, 3748, 3748, 3749, 3750, 3751, 3752, 3753, 3753, 3754, 3756, 3758, 3759, 3760, 3761, 3762, 3763, 3763, 3764, 3765, 3768, 3769, 3770, 3770, 3771, 3772, 3772, 3773, 3774, 3775, 3776, 3777, 3777, 3778, 3779, 3779, 3780, 3781, 3782, 3784, 3785, 3720, 3721, 3722, 3722, 3723, 3724, 3724, 3725, 3726, 3727, 3728, 3729, 3729, 3730, 3731, 3732, 3733, 3734, 3734, 3735, 3736, 3736, 3737, 3738, 3738, 3739, 3740, 3740, 3741, 3742, 3742, 3743, 1116, 1125, 1126, 1127, 1127, 1128, 1129, 4094, 1131, 1132, 1132, 1133, 1134, 1136, 1138, 1139, 1140, 1141, 1142, 1142, 1143, 1144, 1144, 1145, 1146, 1148, 1149, 4124, 3385, 3386, 4123, 4123, 4124, 1149, 1154, 1155, 1156, 1158, 1159, 1160, 1162, 1163, 1164, 1166, 1167, 1168, 1168, 1169, 1170, 1171, 1172, 1173, 1174, 1175, 1176, 1177, 1178, 1179, 1179, 1180, 1181, 1182, 1183, 1184, 1184, 1185, 1186, 1188, 1189, 1190, 1190, 1191, 1192, 1194, 1195, 1196, 1201, 1202, 1203, 1206, 1207, 1208, 1209, 1210, 1211, 1212, 1213, 1214, 1216, 1217, 1218, 1222, 1223, 1224, 1224, 1225, 1226, 1227, 1228, 1229, 1231, 1232, 1233, 1236, 1237, 1238, 1240, 1243, 1244, 1245, 1246, 1247, 1250, 1251, 1252, 1253, 1254, 1255, 1259, 1260, 1261, 1262, 1263, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1273, 1274, 1275, 1276, 1278, 1279, 1280, 1281, 1282, 1283, 1284, 1285, 1286, 1287, 1288, 1289, 1292, 1293, 1294, 1296, 1297, 1298, 1299, 1300, 1301, 1301, 1302, 1303, 1304, 1305, 1306, 1306, 1307, 1308, 1310, 1311, 1312, 1314, 1315, 1316, 1317, 1318, 1319, 1323, 1324, 1325, 1325, 1326, 1327, 1327, 1328, 1329, 1331, 1332, 1333, 1334, 1335, 1336, 1336, 1337, 1338, 1340, 1341, 1342, 1344, 1345, 1346, 1348, 1349, 1350, 1350, 1351, 1352, 1352, 1353, 1354, 1355, 1356, 1357, 1357, 1358, 1359, 1359, 1360, 1361, 1364, 1365, 1366, 1368, 1369, 1370, 1373, 1374, 1375, 1375, 1376, 1377, 1379, 1380, 1381, 4181, 4182, 4183, 4183, 4184, 4185, 3145, 3146, 3147, 3147, 1381, 1382, 1382, 1383, 1384, 1384, 1385, 1386, 1387, 1388, 1389, 4164, 4165, 4166, 4193, 4190, 4191, 4156, 4157, 4159, 4159, 4160, 4161, 1392, 1393, 1394, 1395, 1396, 1397, 1398, 1399, 1400, 1401, 1402, 1403, 1403, 1404, 3121, 3121, 1404, 1405, 1405, 1406, 1407, 1407, 1408, 1409, 1409, 1410, 1411, 1412, 1413, 1414, 1414, 1415, 1416, 1416, 1417, 1418, 1419, 1420, 1421, 1421, 1422, 1423, 1423, 1424, 1425, 1428, 1429, 1430, 1432, 1433, 1434, 1435, 1436, 1437, 1437, 1438, 1439, 1447, 1448, 1449, 1454, 1455, 1456, 1456, 1457, 1458, 1459, 1460, 1461, 1461, 1462, 1463, 1465, 1466, 1467, 1467, 1468, 1469, 1471, 1472, 1473, 1473, 1476, 1477, 1480, 1481, 1482, 1482, 1483, 1484, 1484, 1485, 1486, 1487, 1488, 1489, 1489, 1490, 1491, 1491, 1492, 1493, 1495, 1496, 1497, 1500, 1501, 1502, 1503, 1504, 1505, 1505, 1506, 1507, 1509, 1510, 1511, 1511, 1512, 1513, 1516, 1517, 1518, 1518, 1519, 1520, 1520, 1522, 1523, 1524, 1525, 1526, 1528, 1529, 1530, 1534, 1535, 1536, 1536, 1537, 1538, 1538, 1539, 1540, 1541, 1542, 1543, 1544, 1545, 1546, 1546, 1547, 1548, 1549, 1550, 1551, 1552, 1553, 1554, 1554, 1555, 1556, 1558, 1559, 1560, 1564, 1565, 1566, 1567, 1568, 1569, 1576, 1577, 1578, 1579, 1580, 1581, 1581, 4199, 4196, 4198, 4199, 1581, 1582, 1583, 1584, 1586, 1587, 1588, 4217, 4213, 4214, 1591, 1592, 1593, 1595, 1596, 1597, 1597, 1598, 1599, 1599, 1600, 1601, 1601, 1602, 1603, 1603, 1604, 1605, 1607, 1608, 1609, 1610, 1611, 1612, 1612, 1613, 1614, 1615, 1616, 1617, 1617, 1618, 1619, 1619, 1620, 1621, 1624, 1625, 1626, 1626, 1627, 1628, 1629, 1630, 1631, 1632, 1633, 1634, 1634, 1635, 1636, 1636, 1637, 1638, 1639, 1640, 1641, 1642, 1643, 1644, 1646, 1647, 1648, 1650, 1651, 1652, 1652, 1653, 1654, 1654, 1655, 1656, 1657, 1658, 1659, 1660, 1661, 1662, 1662, 1663, 1664, 1665, 1666, 1667, 1667, 1668, 1669, 1670, 1671, 1672, 1672, 1673, 1674, 1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1682, 1683, 1684, 1685, 1685, 1686, 1687, 1687, 1688, 1689, 1689, 1690, 1691, 1691, 1693, 1694, 1695, 1696, 1697, 1697, 1698, 1699, 1699, 1700, 1701, 1703, 1704, 1705, 1705, 1706, 1707, 1707, 1708, 1709, 1710, 1711, 1712, 1712, 1713, 1714, 1714, 1715, 1716, 1717, 1718, 1719, 1720, 1721, 1722, 1722, 1723, 1724, 1725, 1726, 1727, 1728, 1729, 1730, 1730, 1731, 1732, 1732, 1733, 1734, 1734, 1735, 1736, 1741, 1742, 1743, 1744, 1745, 1746, 1752, 1753, 1754, 1754, 1755, 1756, 1756, 1757, 1758, 1759, 1760, 1761, 1763, 1764, 1765, 1769, 1770, 1771, 1773, 1774, 1775, 1776, 1777, 1778, 1778, 1779, 1780, 1780, 1781, 1782, 1782, 1783, 1784, 1785, 1786, 1787, 1787, 1788, 1789, 1789, 1790, 1791, 1791, 1792, 1793, 1794, 1795, 1796, 1797, 1798, 1799, 1801, 1802, 1803, 1803, 1804, 1805, 1808, 1809, 1810, 1811, 1812, 1813, 1813, 1814, 1815, 1816, 1817, 1818, 1818, 1819, 1820, 1821, 1822, 1823, 1823, 1824, 1825, 1825, 1826, 1827, 1829, 1830, 1831, 1831, 1832, 1833, 1836, 1837, 1838, 1838, 1839, 1840, 1840, 1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 1850, 1851, 1852, 1853, 1854, 1855, 1856, 1857, 1858, 1859, 1860, 1862, 1863, 1864, 1868, 1869, 1870, 1871, 1872, 1873, 1874, 1875, 1876, 1877, 1878, 1879, 1879, 1880, 1881, 1882, 1883, 1884, 1888, 1889, 1890, 1893, 1894, 1895, 1895, 1896, 1897, 1899, 1900, 1901, 1901, 1902, 1903, 1903, 1904, 1905, 1907, 1908, 1909, 1912, 1913, 1914, 1922, 1923, 1924, 1937, 1938, 1939, 1940, 1943, 1944, 1946, 1947, 1950, 1952, 1953, 1954, 1954, 1955, 1956, 1958, 4246, 4247, 4248, 4249, 4250, 4250, 4251, 4252, 4252, 4253, 4254, 4255, 4256, 4257, 4257, 4258, 4259, 4261, 4262, 4263, 4263, 4264, 4265, 4265, 4266, 4267, 4269, 4270, 4271, 4272, 4273, 4274, 4277, 4278, 4279, 4279, 4280, 4281, 4283, 4284, 4285, 4286, 4287, 4229, 4236, 4237, 4238, 4238, 4239, 4240, 4241, 4242, 4243, 4243, 4244, 4245, 1959, 1960, 1961, 1962, 1963, 1964, 1964, 1965, 1966, 1968, 1969, 1970, 4290, 4291, 4292, 4293, 1972, 1973, 1974, 1975, 1976, 1977, 1978, 1979, 1980, 1981, 1982, 1984, 1985, 1986, 1991, 1992, 1993, 1996, 1997, 1998, 2005, 2006, 2007, 2008, 2009, 2010, 2010, 2011, 2012, 2017, 2018, 2019, 2020, 2021, 2022, 2027, 2028, 2029, 2033, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2041, 2042, 2043, 2043, 2044, 2045, 2045, 2046, 2047, 2050, 2051, 2052, 2058, 2059, 2060, 2060, 2061, 2062, 2062, 2063, 2064, 2064, 2065, 2066, 2067, 2068, 2069, 2073, 2074, 2075, 2076, 2077, 2078, 2078, 2079, 2080, 2080, 2081, 2082, 2082, 2083, 2084, 2085, 2086, 2087, 2088, 2089, 2090, 2090, 2091, 4930, 2092, 2093, 2094, 2094, 2095, 2096, 2097, 2098, 2099, 2101, 2102, 2103, 2104, 2105, 2106, 2108, 2109, 2110, 2110, 2111, 2112, 2113, 2114, 2115, 2115, 2116, 2117, 2117, 2118, 2119, 2119, 2120, 2121, 2121, 2122, 2123, 2124, 2125, 2126, 2127, 2128, 2129, 2129, 2130, 2131, 2131, 2132, 2133, 2133, 2134, 2135, 2135, 2136, 2137, 2137, 2138, 2139, 2139, 2140, 2141, 2141, 2142, 2143, 2143, 2144, 2145, 2147, 2148, 2149, 2150, 2151, 2152, 2153, 2154, 4949, 4952, 4953, 4954, 4954, 2154, 2155, 2158, 2159, 2160, 2165, 4935, 4936, 4939, 4933, 4934, 2166, 2167, 2168, 2170, 2171, 2172, 2173, 2174, 2175, 2177, 2178, 2179, 2181, 2182, 2183, 2184, 2185, 2186, 2186, 2187, 2188, 2188, 2189, 2190, 2190, 2191, 2192, 2192, 2193, 2194, 2196, 2197, 2198, 2199, 2200, 2201, 2201, 2202, 2203, 2204, 5028, 5029, 5038, 5039, 5035, 2206, 2207, 2208, 5015, 5016, 2211, 2211, 2212, 2213, 2213, 2214, 2215, 2215, 2216, 2217, 2217, 2218, 2219, 2220, 2221, 2222, 2224, 2225, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2235, 2235, 2236, 2237, 2237, 2238, 2239, 2240, 2241, 2242, 2242, 2243, 2244, 2246, 5047, 5045, 2249, 2250, 2251, 2251, 2252, 2253, 2253, 2254, 2255, 2255, 2256, 2257, 2257, 2258, 2259, 2259, 2260, 2261, 2263, 2264, 2265, 2268, 2269, 2270, 2273, 2274, 2275, 2275, 2276, 2277, 2277, 2278, 2279, 2279, 2280, 2281, 2283, 2284, 2285, 2286, 2287, 2288, 2290, 2291, 2292, 2293, 2294, 2295, 2295, 2296, 2297, 2297, 2298, 2299, 2302, 2303, 2304, 2305, 2306, 2307, 2308, 2309, 2310, 2310, 2311, 2312, 2312, 2313, 2314, 2314, 2315, 2316, 2316, 2317, 2318, 2323, 2324, 2325, 2326, 2327, 2328, 2328, 2329, 2330, 2330, 2331, 2332, 2333, 2334, 2335, 2335, 2336, 2337, 2338, 2339, 2340, 2344, 2345, 2346, 2346, 2347, 2348, 2350, 2351, 2352, 2352, 2353, 2354, 2355, 2356, 2357, 2359, 2360, 2361, 2362, 2363, 2364, 2364, 2365, 2366, 2366, 2367, 2368, 2369, 2370, 2371, 2373, 2374, 2375, 2375, 2376, 2377, 2377, 2378, 2379, 2379, 2380, 2381, 2382, 2383, 2384, 2385, 2386, 2387, 2387, 2388, 2389, 2391, 2392, 2393, 2394, 2395, 2396, 2396, 2397, 2398, 2398, 2399, 2400, 2400, 2401, 2402, 2403, 2404, 2405, 2405, 2406, 2407, 2407, 2408, 2409, 2411, 2412, 2413, 2413, 2414, 2415, 2416, 2417, 2419, 2419, 2420, 2421, 2422, 2423, 2424, 2425, 2426, 2427, 2428, 2429, 2430, 2431, 2432, 2433, 2433, 2434, 2435, 2435, 2436, 2437, 2440, 2441, 2442, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2449, 2450, 2450, 2451, 2452, 2453, 2454, 2455, 2457, 2458, 2459, 2460, 2461, 2462, 2463, 2464, 2465, 2469, 2470, 2471, 2474, 2475, 2476, 2479, 2480, 2481, 2484, 2485, 2486, 2487, 2488, 2489, 2489, 2490, 2491, 2494, 2495, 2496, 2496, 2497, 2498, 2498, 2499, 2500, 2502, 2503, 2504, 2504, 2505, 2506, 2506, 2507, 2508, 2510, 2511, 2512, 2512, 2513, 2514, 2517, 2518, 2519, 2523, 2524, 2525, 2525, 2526, 2527, 2527, 2528, 2529, 2529, 2530, 2531, 2531, 2532, 2533, 2537, 2538, 2539, 2539, 2540, 2541, 2543, 2544, 2545, 2545, 2546, 2547, 2547, 2548, 2549, 2551, 2552, 2553, 2553, 2554, 2555, 2555, 2556, 2557, 2557, 2558, 2559, 2559, 2560, 2561, 2561, 2562, 2563, 2564, 2565, 2566, 2566, 2567, 2568, 2570, 2571, 2572, 2572, 2573, 2574, 2574, 2575, 2576, 2577, 2578, 2579, 2580, 2581, 2582, 2582, 2583, 2584, 2584, 2585, 2586, 2586, 2587, 2588, 2589, 2590, 2591, 2591, 2592, 2593, 2594, 2595, 2596, 2596, 2597, 2598, 2598, 2599, 2600, 2602, 2603, 2604, 2606, 2607, 2608, 2609, 2610, 2611, 2611, 2612, 2613, 2613, 2614, 2615, 2615, 2616, 2617, 2617, 2618, 2619, 2620, 2621, 2622, 2625, 2626, 2627, 2631, 2632, 2633, 2645, 2646, 2647, 2648, 2649, 2650, 2650, 2651, 2652, 2656, 2657, 2658, 2658, 2659, 2660, 2661, 2662, 2663, 2664, 2665, 2666, 2666, 2667, 2668, 2669, 2670, 2671, 2671, 2672, 2673, 2673, 2674, 2675, 2680, 2681, 2682, 2683, 2684, 2685, 2686, 2687, 2688, 2693, 2694, 2695, 2698, 2699, 2700, 2701, 2702, 2703, 2704, 2705, 2706, 4976, 2706, 2707, 2709, 2710, 2711, 2717, 2718, 2719, 2722, 2723, 2724, 2725, 2726, 4987, 4990, 4991, 2726, 2727, 2728, 2729, 2729, 4963, 4960, 4960, 4834, 4823, 4823, 4824, 4825, 4825, 4826, 4827, 4828, 4856, 4857, 4851, 4847, 4848, 4839, 4840, 4830, 4831, 4832, 4833, 4962, 4963, 2729, 2731, 2732, 2733, 2733, 2734, 2735, 2736, 2737, 2738, 2738, 2739, 2740, 2741, 2742, 2743, 2743, 2744, 2745, 2752, 2753, 2754, 2759, 2760, 2761, 2761, 2762, 2763, 2764, 2765, 2766, 2767, 2768, 2769, 2769, 2770, 2771, 2772, 2773, 2774, 2774, 2775, 2776, 2779, 2780, 2781, 2782, 2783, 2784, 2785, 2786, 2787, 2787, 2788, 2789, 2789, 2790, 2791, 2792, 2793, 2794, 2794, 2795, 2796, 2799, 2800, 2801, 4340, 4341, 4342, 4343, 4344, 4345, 4346, 4347, 4348, 4348, 4349, 4350, 4297, 4298, 4299, 4924, 4907, 4908, 4911, 4912, 4913, 4919, 4921, 4922, 4300, 4302, 4304, 4305, 4307, 4308, 4311, 4313, 4314, 4314, 4315, 4317, 4317, 4318, 4320, 4323, 4325, 4326, 4330, 4332, 4333, 4333, 4334, 4336, 2801, 2802, 4382, 4390, 4394, 4396, 4401, 4402, 4404, 4382, 2804, 2805, 2807, 2808, 2810, 2811, 2813, 2814, 4460, 4439, 4440, 4438, 4455, 4456, 2817, 2819, 4432, 4434, 2820, 2821, 4468, 4470, 4445, 4445, 2822, 2823, 2823, 2825, 2826, 2827, 2828, 2830, 2831, 2833, 2834, 2834, 2836, 2837, 2838, 2840, 2841, 2841, 2843, 2844, 2853, 4569, 4566, 4568, 2854, 2856, 4555, 4590, 4642, 4641, 4590, 2858, 2858, 2859, 2861, 2862, 2864, 4668, 4517, 4492, 4493, 4511, 4512, 4368, 4368, 4777, 4778, 4780, 4781, 4783, 4370, 4363, 4365, 4366, 4512, 4513, 4670, 4665, 4648, 4648, 4634, 4631, 4631, 4632, 4508, 4509, 4654, 4655, 4658, 4660, 4661, 4579, 4580, 4498, 4496, 4498, 4581, 4507, 4632, 4633, 2865, 2866, 2868, 2868, 2869, 2871, 2881, 2882, 2884, 2887, 2888, 2890, 2892, 2894, 2896, 2898, 2899, 2901, 2901, 2903, 2904, 2904, 2906, 2908, 4805, 4806, 4808, 4808, 4810, 4722, 4722, 4766, 4797, 4796, 4797, 4767, 4358, 2910, 2911, 2911, 4541, 4539, 4540, 2912, 2913, 2913, 2915, 2916, 2919, 2921, 2922, 2922, 2924, 2925, 2928, 2930, 2931, 2931, 2933, 2934, 2941, 2942, 2944, 2951, 2953, 2954, 2954, 2956, 2958, 2958, 2960, 2961, 2961, 2963, 2965, 2970, 2972, 2973, 2973, 2975, 2976, 2976, 2978, 2980, 2984, 2986, 2988, 2991, 2992, 2994, 2995, 2997, 2999, 2999, 3001, 3003, 3006, 3008, 3010, 3012, 3014, 3016, 3016, 3018, 4860, 4865, 3018, 3019, 3022, 3024, 3026, 3032, 3034, 3035, 3038, 3039, 3041, 3046, 3048, 3050, 3054, 3055, 3057, 3062, 3064, 3066, 3066, 3067, 3069, 3069, 3071, 3073, 3073, 3075, 3076, 3076, 3078, 3079, 3079, 3081, 3082, 3085, 3086, 3088, 4, 6, 8, 11, 13, 14, 14, 16, 17, 19, 21, 23, 23, 24, 26, 26, 28, 29, 33, 35, 36, 39, 41, 43, 43, 45, 47, 47, 49, 50, 52, 54, 56, 59, 61, 63, 63, 65, 67, 72, 74, 76, 76, 78, 79, 79, 81, 82, 82, 84, 85, 87, 89, 91, 91, 93, 94, 96, 97, 99, 103, 104, 106, 109, 110, 112, 118, 120, 121, 121, 123, 124, 128, 130, 132, 136, 137, 139, 140, 141, 143, 148, 149, 151, 155, 157, 158, 160, 161, 163, 174, 176, 178, 178, 180, 181, 181, 183, 185, 185, 3490, 3491, 3481, 3483, 3484, 3484, 3485, 3487, 3489, 186, 187, 192, 194, 196, 196, 198, 200, 203, 205, 207, 207, 209, 210, 214, 216, 217, 217, 219, 220, 220, 5074, 5075, 225, 227, 229, 229, 230, 232, 239, 240, 242, 244, 246, 247, 5065, 248, 250, 251, 253, 254, 254, 256, 258, 261, 263, 265, 265, 267, 269, 271, 273, 5058, 5060, 274, 275, 275, 276, 5071, 5070, 5071, 277, 278, 280, 281, 281, 283, 285, 287, 289, 291, 294, 296, 297, 299, 301, 303, 308, 310, 311, 315, 317, 319, 322, 324, 326, 330, 332, 334, 336, 338, 339, 341, 343, 345, 346, 348, 350, 354, 356, 357, 357, 359, 361, 362, 364, 365, 380, 382, 384, 397, 399, 400, 410, 412, 413, 433, 435, 436, 447, 449, 450, 450, 452, 454, 455, 457, 459, 469, 471, 472, 472, 473, 475, 480, 482, 484, 485, 486, 488, 492, 494, 495, 500, 501, 503, 513, 514, 516, 519, 521, 523, 527, 528, 530, 549, 551, 552, 554, 556, 557, 557, 558, 560, 561, 563, 564, 568, 570, 572, 573, 579, 580, 595, 597, 598, 598, 600, 601, 602, 3714, 3715, 3717, 3713, 603, 603, 605, 3691, 3691, 3686, 3683, 3685, 606, 607, 611, 613, 615, 615, 617, 618, 624, 626, 627, 631, 633, 634, 634, 636, 638, 638, 640, 641, 641, 643, 644, 648, 650, 652, 657, 658, 660, 667, 669, 670, 686, 688, 689, 689, 691, 693, 698, 700, 701, 701, 703, 705, 708, 710, 3703, 3705, 711, 712, 715, 717, 719, 724, 726, 727, 728, 730, 731, 732, 3660, 3661, 3662, 3663, 733, 733, 735, 736, 736, 737, 739, 739, 740, 742, 745, 747, 748, 750, 753, 755, 755, 757, 758, 758, 760, 761, 767, 768, 772, 779, 780, 782, 783, 785, 786, 788, 790, 791, 800, 801, 3498, 3500, 801, 3504, 3450, 3452, 3453, 3457, 3458, 3460, 3463, 3464, 3466, 3466, 3468, 3470, 3472, 3473, 3474, 3474, 3574, 3570, 3573, 3474, 3475, 3475, 3435, 3437, 3439, 3441, 3443, 3258, 3259, 3261, 3263, 3265, 3267, 3240, 3242, 3244, 3244, 3245, 3247, 801, 802, 804, 804, 805, 807, 807, 809, 810, 817, 818, 820, 821, 824, 825, 828, 829, 3513, 831, 832, 3542, 3540, 832, 834, 836, 837, 839, 846, 848, 849, 854, 856, 858, 858, 3527, 3528, 3526, 859, 860, 860, 861, 863, 864, 866, 868, 868, 870, 871, 3582, 874, 875, 876, 878, 3656, 3655, 879, 3595, 3593, 879, 881, 885, 887, 889, 891, 893, 894, 898, 900, 901, 906, 908, 909, 909, 911, 913, 913, 915, 917, 3619, 3620, 923, 931, 933, 935, 938, 940, 941, 944, 946, 947, 947, 949, 951, 951, 952, 954, 954, 955, 957, 957, 959, 961, 962, 963, 965, 978, 979, 980, 980, 981, 982, 3196, 3198, 3199, 3201, 3203, 3204, 3836, 3838, 3840, 3850, 3852, 3854, 3825, 3826, 3828, 3829, 3831, 3832, 3212, 3214, 3215, 3809, 3811, 3813, 3224, 3226, 3228, 985, 987, 988, 990, 992, 993, 993, 995, 997, 998, 1000, 1001, 1003, 1005, 3798, 3805, 3796, 3798, 3798, 1007, 1008, 1008, 1010, 1012, 1020, 1021, 3792, 3790, 3791, 1022, 1030, 1031, 1033, 1039, 1040, 1042, 1043, 3913, 3914, 3912, 1044, 1045, 1047, 1049, 1051, 3937, 3929, 3948, 3947, 3929, 1052, 1052, 3367, 3366, 3365, 1053, 1055, 3892, 3893, 3374, 3373, 3894, 3895, 4043, 4064, 4066, 4064, 4045, 4046, 4047, 4049, 4050, 3897, 3898, 3900, 1057, 1058, 1060, 4001, 1066, 1068, 1070, 1071, 1073, 3881, 4056, 4054, 3974, 1073, 1075, 1075, 1077, 1079, 1081, 1083, 4087, 4080, 4082, 4083, 4083, 4084, 4013, 4012, 3314, 3316, 3318, 3289, 3290, 3290, 3291, 3293, 3298, 3299, 3301, 3330, 3322, 3323, 3314, 4085, 4086, 4086, 4087, 1084, 1084, 1086, 1087, 1087, 5354, 5352, 5355, 1090, 1091, 1091, 1092, 1094, 4076, 1099, 1101, 1101, 3976, 3977, 3979, 1102, 1104, 1108, 1109, 3873, 3872, 3873, 3860, 3859, 3873, 1110, 3340, 3342, 3343, 3349, 3351, 3352, 3352, 3354, 3355, 3333, 1111, 1112, 3751, 3753, 3756, 3758, 3760, 3761, 3763, 3765, 3768, 3772, 3774, 3775, 3777, 3779, 3781, 3786, 3720, 3722, 3724, 3726, 3727, 3729, 3731, 3732, 3734, 3736, 3738, 3738, 3740, 3742, 3742, 1116, 1117, 1135, 1136, 1139, 1142, 1144, 1146, 1148, 4124, 4125, 4123, 1149, 1150, 1154, 1156, 1157, 1158, 1160, 1161, 1162, 1164, 1165, 1171, 1173, 1174, 4120, 1174, 1176, 1184, 1186, 1187, 1206, 1208, 1209, 1209, 1211, 1212, 1222, 1224, 1226, 1229, 1230, 1231, 1239, 1240, 1244, 1252, 1253, 1255, 1267, 1268, 1270, 1278, 1280, 1281, 1281, 1283, 1284, 1284, 1286, 1287, 1287, 1289, 1290, 1299, 1301, 1303, 1304, 1306, 1308, 1310, 1312, 1313, 1316, 1317, 1319, 1322, 1323, 1325, 1325, 1327, 1329, 1331, 1333, 1334, 1334, 1336, 1338, 1339, 1340, 1342, 1350, 1352, 1354, 1357, 1359, 1361, 1368, 1370, 1371, 1375, 1377, 1378, 1379, 1381, 3141, 3145, 3147, 1382, 1382, 1384, 1386, 1386, 1387, 1389, 4164, 4166, 4167, 4168, 4169, 4170, 4156, 4159, 4161, 1395, 1397, 1398, 1401, 1403, 3121, 1407, 1409, 1411, 1411, 1412, 1414, 1414, 1416, 1418, 1418, 1419, 1421, 1421, 1423, 1425, 1428, 1430, 1431, 1432, 1434, 1435, 1435, 1437, 1439, 1454, 1456, 1458, 1459, 1461, 1463, 1465, 1467, 1469, 1471, 1473, 1477, 1480, 1482, 1484, 1491, 1493, 1494, 1494, 1495, 1497, 1499, 1500, 1502, 1505, 1507, 1508, 1509, 1511, 1513, 1516, 1518, 1520, 1520, 1523, 1524, 1524, 1526, 1527, 1528, 1530, 1531, 1533, 1534, 1536, 1544, 1546, 1548, 1551, 1552, 1554, 1554, 1556, 1557, 1557, 1558, 1560, 1563, 1564, 1566, 1566, 1567, 1569, 4210, 1569, 4202, 4204, 4205, 1569, 1578, 1579, 1581, 4198, 1581, 1582, 1582, 1584, 1585, 1585, 1586, 1588, 1590, 1591, 1593, 1597, 1599, 1601, 1603, 1605, 1606, 1607, 1609, 1610, 1615, 1617, 1619, 1626, 1628, 1629, 1631, 1632, 1634, 1638, 1639, 1641, 1646, 1648, 1649, 1652, 1654, 1656, 1660, 1662, 1664, 1665, 1667, 1669, 1676, 1677, 1679, 1680, 1682, 1683, 1687, 1689, 1691, 1691, 1694, 1695, 1695, 1697, 1699, 1699, 1701, 1702, 1703, 1705, 1707, 1712, 1714, 1716, 1722, 1724, 1725, 1725, 1727, 1728, 1728, 1730, 1732, 1732, 1734, 1736, 1741, 1743, 1744, 1754, 1756, 1758, 1758, 1759, 1761, 1776, 1778, 1780, 1782, 1784, 1785, 1791, 1793, 1794, 1796, 1797, 1799, 1800, 1801, 1803, 1803, 1805, 1806, 1807, 1808, 1810, 1811, 1813, 1815, 1820, 1821, 1823, 1823, 1825, 1827, 1828, 1829, 1831, 1831, 1833, 1834, 1843, 1845, 1846, 1852, 1854, 1855, 1858, 1860, 1861, 1861, 1862, 1864, 1868, 1870, 1871, 1871, 1873, 1874, 1874, 1876, 1877, 1877, 1879, 1881, 1881, 1882, 1884, 1895, 1897, 1898, 1922, 1924, 1925, 1936, 1937, 1939, 1954, 1956, 1957, 1958, 4247, 4248, 4250, 4252, 4254, 4255, 4257, 4259, 4261, 4263, 4265, 4265, 4267, 4268, 4269, 4271, 4272, 4272, 4274, 4275, 4279, 4281, 4282, 4283, 4285, 4286, 1961, 1962, 1964, 1964, 1966, 1967, 1971, 1972, 4293, 4292, 4293, 1973, 1976, 1977, 1979, 1980, 1982, 1983, 1983, 1984, 1986, 1990, 1991, 1993, 2005, 2007, 2008, 2008, 2010, 2012, 2020, 2022, 2023, 2027, 2029, 2030, 2039, 2041, 2043, 2045, 2047, 2048, 2062, 2064, 2066, 2067, 2069, 2070, 2078, 2080, 2082, 2082, 2084, 2085, 2085, 2087, 2088, 2088, 2090, 4930, 2094, 2096, 2097, 2097, 2099, 2100, 2100, 2101, 2103, 2103, 2104, 2106, 2107, 2108, 2110, 2110, 2112, 2113, 2119, 2121, 2123, 2124, 2126, 2127, 2129, 2131, 2133, 2133, 2135, 2137, 2139, 2141, 2143, 2146, 2147, 2149, 2150, 2152, 2153, 2153, 4949, 4950, 4952, 4954, 2155, 2165, 4936, 4937, 2169, 2170, 2172, 2173, 2175, 2176, 2177, 2179, 2180, 2180, 2181, 2183, 2186, 2188, 2190, 2195, 2196, 2198, 2201, 2203, 2204, 2211, 2213, 2215, 2217, 2219, 2220, 2223, 2224, 2226, 2230, 2232, 2233, 2233, 2235, 2237, 2242, 2244, 2245, 2257, 2259, 2261, 2263, 2265, 2266, 2267, 2268, 2270, 2273, 2275, 2277, 2279, 2281, 2282, 2283, 2285, 2286, 2292, 2293, 2295, 2295, 2297, 2299, 2302, 2304, 2305, 2305, 2307, 2308, 2310, 2312, 2314, 2316, 2318, 2319, 2322, 2323, 2325, 2326, 2328, 2330, 2332, 2333, 2335, 2338, 2340, 2341, 2343, 2344, 2346, 2346, 2348, 2349, 2354, 2355, 2357, 2362, 2364, 2366, 2377, 2379, 2381, 2385, 2387, 2389, 2390, 2391, 2393, 2394, 2396, 2398, 2403, 2405, 2407, 2411, 2413, 2415, 2421, 2422, 2424, 2424, 2425, 2427, 2428, 2430, 2431, 2431, 2433, 2435, 2435, 2437, 2438, 2440, 2442, 2444, 2450, 2452, 2453, 2453, 2455, 2456, 2459, 2460, 2462, 2463, 2465, 2466, 2469, 2471, 2472, 2474, 2476, 2477, 2479, 2481, 2482, 2483, 2484, 2486, 2487, 2489, 2491, 2494, 2496, 2498, 2498, 2500, 2501, 2501, 2502, 2504, 2504, 2506, 2508, 2509, 2510, 2512, 2512, 2514, 2515, 2517, 2519, 2520, 2522, 2523, 2525, 2527, 2529, 2531, 2531, 2533, 2534, 2537, 2539, 2541, 2543, 2545, 2547, 2547, 2549, 2550, 2557, 2559, 2561, 2561, 2563, 2564, 2564, 2566, 2568, 2572, 2574, 2576, 2580, 2582, 2584, 2584, 2586, 2588, 2588, 2589, 2591, 2594, 2596, 2598, 2606, 2608, 2609, 2617, 2619, 2620, 2625, 2627, 2628, 2630, 2631, 2633, 2645, 2647, 2648, 2650, 2652, 2653, 2654, 2656, 2658, 2661, 2663, 2664, 2664, 2666, 2668, 2671, 2673, 2675, 2680, 2682, 2683, 2683, 2685, 2686, 2686, 2688, 2689, 2692, 2693, 2695, 2698, 2700, 2701, 2704, 2706, 4976, 4975, 4976, 2707, 2716, 2717, 2719, 2721, 2722, 2724, 2725, 4987, 4988, 4990, 2726, 2727, 2727, 2729, 4960, 4823, 4825, 4827, 4831, 4833, 4834, 4834, 4960, 4961, 4962, 2729, 2730, 2730, 2731, 2733, 2733, 2735, 2736, 2736, 2738, 2740, 2743, 2745, 2746, 2764, 2766, 2767, 2767, 2769, 2771, 2772, 2774, 2776, 2778, 2779, 2781, 2782, 2784, 2785, 2787, 2789, 2791, 2792, 2794, 2796, 2799, 2801, 4340, 4340, 4342, 4343, 4343, 4345, 4346, 4346, 4348, 4350, 4297, 4299, 3392, 4923, 4924, 4908, 4911, 4913, 4914, 4917, 4919, 4922, 3391, 3392, 4299, 4305, 4308, 4310, 4310, 4311, 4314, 4314, 4317, 4320, 4322, 4323, 4326, 4330, 4333, 4336, 2801, 4382, 4384, 4390, 4396, 4398, 4401, 4404, 4378, 4381, 4382, 2805, 2807, 2810, 2811, 2815, 4460, 4440, 2817, 4432, 4433, 4434, 2821, 4446, 4445, 2823, 2826, 2827, 2830, 2831, 2831, 2834, 2837, 2851, 2853, 4566, 4567, 4568, 2856, 4589, 4590, 4555, 4554, 4555, 4642, 2858, 2861, 2862, 4670, 4648, 4646, 4486, 4487, 4646, 4631, 4508, 4509, 4509, 4655, 4656, 4658, 4661, 4504, 4504, 4505, 4534, 4579, 4498, 4499, 4496, 4581, 4583, 4583, 4534, 4535, 4536, 4537, 4505, 4506, 4507, 4633, 2865, 2868, 2871, 2881, 2884, 2885, 2887, 2890, 2891, 2891, 2892, 2896, 2898, 2901, 2904, 4808, 4722, 4797, 4796, 4767, 4768, 4358, 2911, 4539, 4540, 2913, 2916, 2919, 2922, 2925, 2928, 2931, 2934, 2941, 2944, 2946, 2948, 2951, 2954, 2954, 2958, 2961, 2961, 2965, 2966, 2970, 2973, 2976, 2976, 2980, 2982, 2984, 2988, 2990, 2991, 2994, 2995, 2995, 2999, 3003, 3006, 3010, 3012, 3016, 4860, 4861, 4864, 4865, 3019, 3021, 3022, 3026, 3031, 3032, 3035, 3036, 3038, 3041, 3046, 3050, 3051, 3054, 3057, 3058, 3062, 3066, 3069, 3073, 3076, 3079, 3084, 3085, 3088, 4, 8, 10, 10, 11, 14, 14, 17, 19, 23, 26, 29, 31, 33, 36, 38, 39, 43, 43, 47, 50, 51, 52, 56, 63, 67, 68, 72, 76, 79, 79, 82, 85, 86, 87, 91, 96, 99, 100, 103, 106, 107, 109, 112, 113, 118, 121, 124, 127, 128, 132, 136, 139, 140, 140, 143, 145, 148, 151, 152, 154, 155, 158, 160, 163, 165, 174, 178, 181, 181, 185, 3491, 3480, 3481, 3484, 3484, 3487, 3488, 3489, 187, 188, 191, 192, 196, 196, 200, 201, 201, 203, 207, 212, 214, 217, 217, 220, 5075, 225, 229, 232, 239, 242, 243, 243, 244, 247, 247, 5065, 5066, 5064, 5065, 250, 254, 258, 260, 260, 261, 265, 269, 271, 5058, 5060, 275, 5071, 5070, 277, 278, 278, 281, 285, 298, 299, 303, 308, 311, 313, 315, 319, 321, 322, 326, 328, 330, 334, 336, 336, 339, 340, 340, 341, 345, 346, 350, 351, 354, 357, 361, 409, 410, 413, 447, 450, 454, 454, 455, 459, 468, 469, 472, 480, 484, 485, 485, 488, 490, 491, 492, 495, 499, 500, 503, 512, 513, 516, 519, 523, 524, 527, 530, 531, 546, 549, 552, 553, 554, 557, 560, 561, 564, 572, 573, 580, 593, 595, 598, 601, 602, 3715, 3717, 603, 3691, 3690, 3691, 3683, 3684, 3685, 3666, 3669, 3685, 607, 631, 634, 638, 647, 648, 652, 667, 670, 672, 689, 693, 695, 701, 705, 707, 708, 3703, 3704, 3705, 712, 714, 715, 719, 720, 723, 724, 727, 3662, 733, 736, 739, 742, 743, 755, 758, 761, 767, 772, 773, 779, 782, 783, 783, 786, 788, 788, 791, 793, 800, 3498, 3499, 3506, 3247, 3248, 3466, 3470, 3471, 3475, 3437, 3438, 3258, 3261, 3262, 3263, 3267, 3268, 3240, 3244, 3247, 3247, 3506, 3507, 3508, 801, 804, 804, 807, 810, 817, 820, 821, 821, 825, 826, 828, 3513, 3511, 830, 831, 3542, 3540, 834, 836, 836, 839, 840, 845, 846, 849, 853, 854, 858, 858, 3528, 3522, 3526, 860, 863, 863, 864, 3551, 3550, 3551, 864, 864, 868, 871, 872, 873, 3582, 3581, 3582, 875, 875, 876, 3656, 3657, 3655, 3595, 3593, 881, 882, 885, 889, 890, 891, 894, 896, 904, 906, 909, 909, 913, 917, 3619, 923, 924, 931, 935, 936, 944, 947, 951, 951, 954, 957, 957, 961, 962, 962, 965, 966, 978, 980, 982, 3201, 3204, 3205, 3836, 3840, 3841, 3848, 3850, 3854, 3825, 3828, 3829, 3211, 3212, 3215, 985, 988, 3416, 990, 993, 997, 998, 1001, 1003, 1003, 3798, 3799, 3798, 1008, 1012, 1020, 3792, 3793, 3790, 1022, 1023, 1039, 1042, 1043, 3911, 3912, 1045, 1047, 1051, 3929, 3928, 3929, 3937, 3936, 3937, 3948, 3365, 1055, 1056, 3892, 3374, 3372, 3897, 3900, 3901, 3901, 1057, 1060, 4001, 1068, 1069, 3881, 4054, 4055, 3974, 1075, 1079, 1081, 4087, 4088, 4080, 4083, 4013, 4011, 4012, 3316, 3290, 3293, 3295, 3298, 3301, 3302, 3314, 4086, 1084, 5355, 1091, 1094, 1098, 4076, 4071, 4075, 4076, 1101, 1101, 3977, 3978, 3979, 1104, 1106, 1108, 3873, 3874, 3871, 3872, 3860, 3859, 1110, 3334, 3349, 3352, 3355, 3355, 3333, 1112, 3757, 3758, 3761, 3763, 3768, 3770, 3772, 3775, 3777, 3777, 3781, 3782, 3785, 3786, 3722, 3727, 3729, 3732, 3734, 3738, 3742, 3742, 1117, 1118, 1140, 1142, 1146, 3385, 4123, 1150, 1158, 1161, 1162, 1162, 1165, 1166, 4120, 1176, 1177, 1206, 1209, 1212, 1221, 1222, 1226, 1227, 1229, 1231, 1239, 1244, 1245, 1267, 1270, 1271, 1277, 1278, 1281, 1281, 1284, 1287, 1299, 1303, 1304, 1304, 1308, 1309, 1310, 1313, 1314, 1316, 1319, 1320, 1325, 1329, 1330, 1330, 1331, 1334, 1367, 1368, 1371, 1379, 3141, 3142, 3144, 3145, 1382, 1386, 1389, 1390, 4168, 4170, 4155, 4156, 4161, 4162, 1394, 1395, 4134, 4136, 4137, 1395, 1395, 1398, 1400, 1401, 3121, 1405, 1405, 1407, 1411, 1421, 1425, 1426, 1427, 1428, 1431, 1454, 1458, 1459, 1459, 1463, 1464, 1464, 1465, 1469, 1471, 1477, 1478, 1480, 1484, 1486, 1489, 1491, 1494, 1494, 1497, 1498, 1503, 1505, 1508, 1509, 1513, 1515, 1516, 1520, 1524, 1527, 1528, 1531, 1533, 1536, 1538, 1544, 1548, 1549, 1549, 1551, 1554, 1554, 1557, 1560, 1563, 1566, 1569, 1569, 4210, 4207, 4209, 4210, 4202, 4204, 1569, 1570, 1578, 1581, 4196, 4198, 1582, 1585, 1585, 1588, 1589, 1590, 1593, 1594, 1595, 1597, 1601, 1601, 1603, 1606, 1607, 1610, 1612, 1615, 1619, 1621, 1624, 1626, 1629, 1631, 1634, 1636, 1645, 1646, 1649, 1660, 1664, 1665, 1665, 1669, 1670, 1674, 1676, 1679, 1680, 1683, 1685, 1687, 1691, 1695, 1695, 1699, 1702, 1703, 1707, 1709, 1725, 1728, 1732, 1741, 1744, 1746, 1754, 1758, 4226, 4225, 4226, 1758, 1775, 1776, 1780, 1780, 1782, 1785, 1796, 1799, 1800, 1800, 1803, 1806, 1823, 1827, 1828, 1828, 1831, 1834, 1852, 1855, 1857, 1857, 1858, 1861, 1868, 1871, 1874, 1881, 1884, 1885, 1921, 1922, 1925, 4255, 4259, 4260, 4269, 4272, 4275, 4279, 4282, 4283, 4283, 4286, 4229, 1971, 4293, 4290, 1983, 1986, 1987, 1990, 1993, 1994, 2005, 2008, 2012, 2019, 2020, 2023, 2026, 2027, 2030, 2039, 2043, 2045, 2045, 2048, 2049, 2062, 2066, 2067, 2076, 2078, 2082, 2085, 2088, 4930, 4930, 2091, 2092, 2097, 2100, 2103, 2103, 2106, 2107, 2107, 2110, 2113, 2123, 2124, 2127, 2129, 2133, 2137, 2139, 2143, 2145, 2149, 2150, 2153, 4952, 2155, 2156, 2169, 2172, 2173, 2173, 2176, 2177, 2180, 2183, 2184, 2184, 2186, 2190, 2211, 2215, 2217, 2223, 2226, 2227, 2230, 2233, 2237, 2240, 2242, 2245, 2255, 2257, 2261, 2262, 2263, 2266, 2267, 2270, 2271, 2272, 2273, 2277, 2282, 2283, 2286, 2292, 2295, 2299, 2302, 2305, 2308, 2308, 2310, 2314, 2314, 2316, 2319, 2322, 2325, 2326, 2326, 2330, 2332, 2337, 2338, 2341, 2343, 2346, 2349, 2352, 2354, 2357, 2362, 2366, 2368, 2375, 2377, 2381, 2385, 2389, 2390, 2393, 2394, 2398, 2403, 2407, 2409, 2410, 2411, 2415, 2421, 2424, 2427, 2431, 2435, 2438, 2439, 2440, 2444, 2448, 2450, 2453, 2459, 2462, 2463, 2474, 2477, 2478, 2483, 2486, 2487, 2487, 2491, 2492, 2493, 2494, 2498, 2501, 2504, 2508, 2509, 2512, 2515, 2525, 2527, 2531, 2531, 2534, 2535, 2536, 2537, 2541, 2542, 2543, 2547, 2557, 2561, 2564, 2564, 2568, 2569, 2580, 2584, 2588, 2588, 2591, 2593, 2593, 2594, 2598, 2605, 2606, 2609, 2615, 2617, 2620, 2645, 2648, 2650, 2650, 2653, 2654, 2654, 2658, 2660, 2664, 2668, 2669, 2680, 2683, 2686, 2697, 2698, 2701, 2704, 4976, 4974, 4975, 2707, 2708, 2716, 2719, 2720, 2721, 2724, 2725, 4990, 2727, 4960, 4962, 2730, 2733, 2733, 2736, 2740, 2741, 2743, 2746, 2763, 2764, 2767, 2767, 2771, 2772, 2772, 2776, 2777, 2778, 2781, 2782, 2782, 2785, 2787, 2787, 2791, 2792, 2792, 2796, 2797, 4340, 4343, 4346, 4297, 3392, 3393, 3394, 3391, 4299, 4310, 4314, 4320, 4330, 4336, 4338, 2801, 4384, 4385, 4390, 4398, 4399, 4381, 2805, 2807, 2807, 2811, 2814, 2816, 2817, 4433, 4434, 4446, 4448, 4452, 4444, 4445, 4445, 4470, 4464, 4468, 4445, 2826, 2826, 2827, 2831, 2849, 2851, 4566, 4554, 4642, 4643, 4641, 2858, 2862, 2862, 4668, 4669, 4658, 4504, 4534, 4583, 4535, 4536, 4536, 4505, 4506, 2864, 2865, 2871, 2880, 2881, 2885, 2886, 2887, 2891, 2891, 2896, 2898, 2898, 2904, 2908, 4356, 4357, 4358, 4808, 4797, 4795, 4768, 4358, 4539, 4539, 4540, 2916, 2918, 2919, 2925, 2926, 2928, 2934, 2954, 2961, 2966, 2976, 2982, 2984, 2991, 2995, 3003, 3006, 3012, 3016, 3016, 4861, 4862, 4864, 3019, 3021, 3021, 3026, 3027, 3031, 3035, 3036, 3036, 3041, 3042, 3046, 3051, 3052, 3062, 3069, 3073, 3073, 3079, 3082, 3083, 3084, 3088, 10, 14, 19, 29, 31, 36, 38, 43, 50, 50, 51, 56, 79, 85, 86, 108, 109, 113, 116, 118, 124, 140, 145, 147, 148, 152, 154, 160, 165, 167, 173, 174, 181, 181, 3491, 3492, 3489, 188, 189, 191, 196, 201, 210, 212, 217, 224, 225, 232, 237, 239, 243, 243, 247, 5066, 5064, 250, 251, 265, 269, 5058, 5059, 5060, 5071, 5070, 278, 285, 298, 303, 304, 315, 321, 322, 336, 340, 345, 346, 351, 352, 354, 361, 362, 409, 413, 415, 447, 454, 459, 468, 472, 475, 480, 485, 490, 511, 512, 516, 527, 531, 533, 546, 552, 553, 557, 560, 564, 572, 580, 582, 601, 3715, 3716, 3717, 3691, 3689, 3690, 3683, 3684, 3684, 3666, 3675, 3678, 3666, 3667, 3669, 607, 608, 629, 631, 638, 647, 652, 653, 707, 708, 3704, 3705, 714, 715, 723, 727, 728, 736, 739, 743, 755, 761, 762, 766, 767, 773, 779, 783, 788, 788, 793, 795, 3463, 3466, 3471, 3475, 3438, 3439, 3262, 3263, 3268, 3240, 3247, 3507, 3508, 804, 810, 826, 828, 3511, 830, 3542, 3543, 3538, 3539, 3540, 3540, 836, 840, 845, 849, 850, 852, 853, 858, 3525, 3526, 863, 3549, 3550, 864, 875, 3656, 3657, 3657, 3595, 3589, 3592, 3593, 882, 884, 885, 890, 890, 891, 896, 904, 909, 917, 3619, 924, 925, 951, 957, 962, 962, 966, 967, 977, 978, 982, 3848, 3854, 3823, 3825, 3829, 3832, 989, 990, 997, 997, 998, 1003, 3798, 1012, 1014, 1019, 1020, 3793, 1039, 1043, 3914, 3911, 1045, 1046, 1046, 1047, 3929, 3927, 3928, 3937, 3936, 3948, 3946, 3943, 3946, 3947, 3365, 1056, 3901, 3901, 3890, 3891, 3897, 3901, 1060, 1064, 1065, 4001, 4000, 4001, 3990, 3989, 3990, 4001, 1070, 1073, 3971, 3973, 3974, 1079, 1080, 1081, 4088, 4080, 4013, 4011, 3318, 3290, 3295, 3314, 1084, 1087, 5355, 1094, 1095, 1097, 1098, 4071, 4073, 4075, 1101, 3978, 3979, 1106, 3871, 3860, 3862, 3858, 3859, 3334, 3349, 3355, 1112, 3757, 3761, 3763, 3763, 3770, 3772, 3772, 3777, 3782, 3785, 3722, 3724, 3727, 3732, 3734, 3385, 1150, 1151, 1157, 1158, 1162, 1174, 4120, 4121, 1227, 1231, 1233, 1239, 1245, 1247, 1267, 1271, 1273, 1281, 1287, 1290, 1299, 1304, 1309, 1316, 1320, 1321, 1322, 1325, 1330, 1330, 1334, 1338, 1367, 1371, 1372, 3119, 1372, 1373, 1378, 1379, 3142, 3144, 1382, 1386, 1386, 1390, 4164, 4168, 4155, 4192, 4191, 4192, 4155, 4164, 1390, 1391, 1392, 1394, 4134, 4136, 1395, 1400, 1400, 1401, 4149, 4151, 4152, 1401, 1405, 1411, 1414, 1427, 1431, 1432, 1454, 1459, 1464, 1464, 1469, 1470, 1479, 1480, 1486, 1487, 1489, 1494, 1494, 1498, 1499, 1503, 1508, 1509, 1509, 1515, 1516, 1516, 1524, 1527, 1527, 1531, 1532, 1532, 1533, 1538, 1543, 1544, 1549, 1549, 1554, 1560, 1563, 1569, 4207, 4208, 4209, 4202, 4204, 1570, 1571, 1578, 4196, 4197, 4198, 1585, 1589, 4215, 1590, 1594, 1595, 1601, 1606, 1606, 1607, 1612, 1614, 1615, 1621, 1631, 1636, 1638, 1645, 1649, 1650, 1660, 1665, 1670, 1674, 1679, 1680, 1702, 1703, 1709, 1725, 1732, 1736, 1741, 1746, 1747, 1752, 1754, 4226, 4225, 1758, 1761, 1780, 1785, 1787, 1794, 1796, 1800, 1800, 1806, 1807, 1820, 1823, 1828, 1857, 1861, 1864, 1868, 1874, 1877, 1881, 1885, 1886, 1921, 1925, 1926, 4254, 4255, 4260, 1970, 1971, 4290, 1980, 1983, 1987, 1989, 1990, 1994, 2004, 2005, 2012, 2019, 2023, 2024, 2026, 2030, 2031, 2038, 2039, 2045, 2062, 2067, 2070, 2082, 2085, 4930, 4929, 4930, 2092, 2103, 2107, 2113, 2119, 2123, 2127, 2168, 2169, 2173, 2173, 2177, 2180, 2184, 2190, 2192, 2210, 2211, 5019, 2211, 5016, 5014, 5015, 2211, 2217, 2223, 2227, 2229, 2229, 2230, 2237, 2240, 2245, 2246, 5046, 5047, 2246, 2246, 2247, 2248, 2262, 2266, 2267, 2267, 2271, 2272, 2272, 2277, 2279, 2282, 2286, 2288, 2290, 2292, 2299, 2302, 2308, 2314, 2314, 2319, 2320, 2321, 2322, 2326, 2335, 2337, 2341, 2352, 2357, 2358, 2361, 2362, 2368, 2375, 2381, 2382, 2384, 2385, 2390, 2393, 2398, 2400, 2403, 2409, 2410, 2421, 2427, 2428, 2428, 2431, 2438, 2439, 2444, 2445, 2448, 2453, 2456, 2457, 2459, 2463, 2473, 2474, 2478, 2482, 2483, 2487, 2498, 2501, 2508, 2508, 2509, 2515, 2536, 2541, 2542, 2542, 2547, 2550, 2557, 2564, 2569, 2579, 2580, 2588, 2605, 2609, 2611, 2661, 2664, 2669, 2680, 2686, 2689, 2697, 2701, 2703, 2704, 4974, 4975, 4975, 2708, 2709, 2715, 2716, 2720, 2720, 2721, 2725, 4990, 4960, 4823, 4961, 4962, 4997, 4962, 2733, 2740, 2741, 2746, 2747, 2761, 2763, 2767, 2772, 2777, 2778, 2778, 2782, 2787, 2787, 2792, 2797, 3393, 3394, 4923, 3394, 4299, 4300, 4310, 4320, 4321, 4329, 4330, 4338, 2801, 4385, 4387, 4390, 4399, 4401, 4380, 4381, 2807, 4433, 4434, 4448, 4452, 4445, 4464, 4468, 2826, 2831, 4553, 4554, 4643, 4658, 4534, 4583, 4496, 4583, 4536, 4666, 2864, 2871, 2880, 2885, 2886, 2891, 2898, 2908, 4356, 4358, 4747, 4746, 4747, 4358, 4808, 4795, 4796, 4796, 4768, 4539, 4539, 2916, 2918, 2926, 2934, 2936, 4877, 4878, 2936, 2936, 2937, 2938, 2970, 2976, 2984, 2990, 2991, 3003, 3006, 3016, 4862, 4862, 4864, 3021, 3031, 3036, 3042, 3046, 3052, 3054, 3082, 3083, 3088, 4, 10, 19, 23, 29, 36, 50, 56, 58, 108, 113, 114, 116, 124, 126, 147, 148, 154, 159, 160, 167, 173, 181, 3492, 3488, 3489, 189, 190, 191, 201, 210, 217, 5075, 222, 224, 232, 237, 243, 5066, 265, 5058, 5059, 5059, 5071, 5069, 5070, 285, 287, 297, 298, 304, 315, 322, 328, 330, 336, 345, 354, 362, 365, 407, 409, 415, 447, 459, 460, 467, 468, 475, 480, 490, 491, 511, 516, 517, 545, 546, 553, 557, 564, 565, 568, 572, 582, 598, 601, 3716, 3717, 3689, 3690, 3690, 3684, 3675, 3677, 3678, 3667, 3668, 3669, 608, 629, 638, 641, 645, 647, 653, 701, 707, 3704, 3705, 715, 720, 722, 723, 728, 3662, 736, 743, 750, 755, 762, 766, 773, 774, 779, 788, 795, 3463, 3471, 3472, 3262, 3268, 3269, 3240, 3507, 3508, 3508, 810, 811, 3513, 830, 3543, 3538, 3540, 3560, 3540, 840, 842, 844, 845, 850, 852, 858, 3522, 3525, 863, 3551, 875, 3657, 3589, 3592, 882, 883, 884, 890, 896, 902, 904, 917, 3618, 3619, 925, 944, 951, 962, 976, 977, 982, 3847, 3848, 3823, 3211, 3215, 3216, 3415, 989, 997, 997, 1003, 3799, 3805, 3798, 1014, 1019, 3793, 3789, 1039, 3914, 3915, 3911, 1046, 3929, 3936, 3946, 3943, 3942, 3943, 3947, 3366, 3365, 3901, 3896, 3897, 1060, 1062, 1064, 4001, 3998, 4000, 3990, 1070, 3971, 3972, 3973, 1079, 1080, 1080, 4088, 4089, 3318, 3295, 3296, 1097, 4071, 4072, 4072, 4073, 1101, 3978, 1106, 1108, 3871, 3862, 3863, 3866, 3858, 3334, 3349, 1112, 1114, 3757, 3763, 3772, 3772, 3782, 3785, 3785, 3724, 3727, 3727, 3734, 3742, 1171, 1174, 4121, 1299, 1309, 1310, 1316, 1321, 1322, 1322, 1330, 1338, 1366, 1367, 1372, 3119, 1373, 1375, 1378, 3142, 3143, 4168, 4192, 4193, 4191, 4155, 4156, 1392, 4134, 4135, 4135, 4136, 1400, 1400, 4149, 4150, 4151, 1401, 1405, 1426, 1427, 1432, 1464, 1470, 1471, 1478, 1479, 1486, 1487, 1494, 1499, 1503, 1509, 1516, 1516, 1527, 1532, 1532, 1538, 1540, 1549, 1560, 1561, 4208, 4202, 4203, 4204, 1571, 1572, 1576, 1578, 4197, 4198, 1589, 1590, 4215, 1594, 1595, 1595, 1606, 1612, 1614, 1621, 1622, 1629, 1631, 1638, 1644, 1645, 1650, 1695, 1702, 1709, 1725, 1736, 1737, 1740, 1741, 1747, 1751, 1752, 4226, 4225, 1761, 1762, 1794, 1800, 1807, 1820, 1828, 1834, 1881, 1886, 1887, 1920, 1921, 1926, 4250, 4254, 4260, 1968, 1970, 4290, 1980, 1987, 1988, 1989, 1994, 1995, 2019, 2024, 2025, 2026, 2031, 2032, 2038, 2045, 2049, 2062, 2070, 2071, 2082, 4930, 4927, 4929, 2092, 2094, 2097, 2103, 2113, 2117, 2119, 2127, 2168, 2173, 2180, 2180, 2184, 2192, 2208, 2210, 5019, 5021, 5022, 2211, 5015, 2217, 2220, 2222, 2223, 2229, 2240, 2246, 5045, 2246, 2248, 2249, 2262, 2267, 2272, 2272, 2279, 2282, 2282, 2288, 2289, 2290, 2299, 2300, 2302, 2314, 2320, 2321, 2326, 2332, 2335, 2341, 2342, 2350, 2352, 2358, 2361, 2368, 2369, 2384, 2390, 2393, 2393, 2400, 2402, 2403, 2410, 2415, 2428, 2438, 2439, 2439, 2445, 2447, 2447, 2448, 2456, 2457, 2463, 2466, 2473, 2478, 2479, 2482, 2487, 2492, 2508, 2515, 2516, 2536, 2542, 2550, 2557, 2569, 2570, 2577, 2579, 2588, 2604, 2605, 2611, 2661, 2669, 2671, 2680, 2689, 2690, 2696, 2697, 2703, 2704, 4975, 2709, 2715, 2720, 2725, 4989, 4990, 4823, 4834, 4961, 4997, 4996, 4997, 4962, 4962, 2740, 2741, 2761, 2767, 2772, 2772, 2778, 2787, 3393, 4923, 4908, 4305, 4310, 4321, 4390, 4401, 4378, 4380, 2807, 2814, 2816, 4433, 4448, 4466, 4468, 2831, 4657, 4658, 4583, 4496, 4536, 4506, 4666, 2871, 2873, 2891, 2908, 2909, 4356, 4747, 4744, 4745, 4746, 4692, 4691, 4746, 4358, 4805, 4808, 4796, 4539, 2918, 2925, 2925, 2926, 2936, 2936, 2938, 2940, 2970, 2984, 2990, 2990, 3003, 3005, 4862, 3021, 3027, 3031, 3042, 3044, 3046, 3054, 3058, 4, 19, 23, 38, 50, 58, 107, 108, 114, 159, 167, 168, 172, 173, 3492, 3488, 189, 190, 190, 201, 207, 207, 210, 5075, 222, 232, 5083, 5085, 5086, 232, 237, 5066, 5063, 5059, 5069, 5070, 5070, 287, 291, 297, 304, 305, 330, 345, 346, 407, 415, 416, 447, 460, 461, 467, 475, 476, 479, 480, 491, 511, 517, 518, 545, 553, 557, 568, 582, 583, 3690, 3675, 3676, 3677, 3667, 3668, 3668, 608, 609, 629, 641, 644, 645, 653, 654, 3704, 3705, 720, 3662, 743, 744, 750, 762, 763, 765, 766, 774, 778, 779, 795, 3462, 3463, 3472, 3258, 3262, 3269, 3240, 3508, 811, 3512, 3513, 3543, 3538, 3560, 3561, 3559, 3540, 842, 3525, 3551, 3552, 3581, 875, 3589, 3591, 3592, 883, 884, 896, 897, 902, 917, 918, 976, 982, 983, 3415, 997, 3799, 3805, 1014, 1015, 3910, 3911, 3929, 3936, 3943, 3941, 3366, 3901, 3891, 3373, 3895, 3896, 3896, 4050, 4043, 4050, 3896, 4037, 4036, 4037, 3896, 3896, 1060, 1061, 1061, 1062, 4001, 3998, 3990, 3991, 3965, 1070, 3972, 3959, 3972, 3973, 1080, 4089, 4079, 3317, 3318, 3296, 3978, 1108, 3874, 3866, 3334, 3336, 3349, 1114, 1116, 3757, 3772, 3785, 3785, 3727, 3742, 1171, 4121, 4119, 1299, 1310, 1314, 1314, 1316, 1322, 1364, 1366, 1372, 3119, 1375, 1378, 1378, 3143, 4186, 4183, 4185, 4186, 4186, 3143, 3144, 4167, 4168, 4193, 4151, 1405, 1414, 1426, 1432, 1435, 1454, 1464, 1471, 1478, 1486, 1487, 1487, 1499, 1502, 1503, 1516, 1532, 1549, 1561, 1563, 1576, 4197, 4198, 4215, 1595, 1612, 1624, 1629, 1638, 1642, 1644, 1650, 1687, 1695, 1709, 1722, 1725, 1737, 1740, 1747, 1748, 1751, 4226, 4224, 4224, 4225, 1762, 1794, 1807, 1810, 1820, 1834, 1835, 1881, 1887, 1888, 1920, 1926, 1927, 4250, 4260, 4261, 1967, 1968, 4290, 1989, 1995, 1996, 2017, 2019, 2025, 2025, 2026, 2032, 4929, 2094, 2097, 2097, 2113, 2115, 2115, 2117, 2127, 2206, 2208, 5019, 5021, 2211, 5014, 5014, 5015, 2220, 2222, 2229, 2237, 2239, 2240, 5045, 5046, 2246, 2249, 2261, 2262, 2272, 2272, 2282, 2289, 2289, 2290, 2300, 2320, 2321, 2332, 2332, 2335, 2342, 2350, 2358, 2359, 2361, 2369, 2371, 2384, 2393, 2402, 2403, 2415, 2416, 2421, 2428, 2439, 2447, 2456, 2457, 2457, 2466, 2467, 2479, 2482, 2492, 2498, 2508, 2516, 2535, 2536, 2550, 2555, 2557, 2570, 2604, 2611, 2613, 2660, 2661, 2671, 2679, 2680, 2690, 2696, 2703, 2704, 5007, 2715, 2725, 4988, 4989, 4823, 4834, 4997, 4998, 4998, 4996, 4962, 4962, 2741, 2747, 4297, 3393, 4908, 4378, 4380, 2814, 2816, 4448, 4449, 4466, 2831, 2837, 2837, 2838, 2841, 4656, 4657, 4583, 4666, 2873, 2875, 4744, 4745, 4692, 4691, 4358, 4766, 4796, 4539, 2925, 2925, 2936, 4876, 2936, 2940, 2941, 2970, 2990, 3005, 3006, 4862, 3027, 3031, 3044, 3045, 4, 23, 36, 107, 114, 115, 158, 159, 168, 171, 172, 3492, 190, 207, 5075, 222, 5083, 5084, 5085, 232, 233, 237, 5063, 5064, 5070, 291, 292, 297, 305, 306, 329, 330, 346, 407, 416, 417, 465, 467, 476, 478, 479, 491, 510, 511, 518, 545, 557, 565, 628, 629, 644, 644, 645, 654, 3704, 720, 721, 765, 774, 775, 778, 795, 796, 3462, 3472, 3474, 3258, 3269, 3270, 3512, 3543, 3537, 3537, 3538, 3561, 3559, 842, 844, 3524, 3525, 3552, 3581, 3589, 3590, 3591, 883, 884, 902, 918, 920, 3614, 920, 921, 975, 976, 983, 3421, 3415, 3799, 3803, 3805, 1015, 3910, 3929, 3927, 3936, 3941, 3942, 1052, 3366, 3891, 3373, 3896, 4043, 4047, 4050, 4037, 4036, 3896, 1061, 3965, 3972, 3959, 1080, 4079, 4080, 3316, 3317, 3296, 1101, 3978, 3874, 3866, 3336, 3337, 3349, 1116, 3744, 1298, 1299, 1314, 1314, 1322, 1338, 1364, 1372, 3119, 3119, 1378, 4186, 4183, 4186, 3144, 4151, 1414, 1418, 1454, 1471, 1478, 1487, 1502, 1503, 1503, 1532, 1540, 1543, 1549, 1563, 1576, 4198, 1590, 1590, 4215, 4216, 4214, 4215, 1612, 1624, 1638, 1641, 1642, 1650, 1652, 1722, 1737, 1738, 1739, 1740, 1748, 1751, 4224, 1762, 1794, 1810, 1811, 1881, 1888, 1890, 1919, 1920, 1927, 1988, 1989, 1996, 2017, 2025, 2032, 4928, 4929, 2097, 2115, 2127, 2129, 2205, 2206, 5019, 5021, 5014, 2220, 2222, 2237, 2239, 5046, 2249, 2251, 2255, 2261, 2272, 2272, 2289, 2300, 2349, 2350, 2359, 2359, 2361, 2371, 2384, 2402, 2403, 2419, 2421, 2439, 2439, 2447, 2457, 2479, 2492, 2493, 2498, 2516, 2517, 2531, 2535, 2550, 2555, 2570, 2572, 2602, 2604, 2613, 2660, 2671, 2675, 2695, 2696, 2704, 5007, 2725, 4988, 4988, 4823, 4827, 4831, 4834, 4998, 4297, 4908, 4909, 4378, 2814, 2815, 4458, 4459, 2815, 2815, 2816, 4449, 4426, 2837, 2841, 4656, 4583, 4584, 4666, 2875, 2876, 4744, 4692, 4690, 4690, 4691, 4766, 4805, 4796, 2925, 4877, 2936, 2941, 2970, 3005, 3006, 3006, 3027, 3029, 3, 4, 36, 103, 107, 115, 154, 158, 168, 171, 3492, 3494, 222, 5084, 5085, 5085, 233, 235, 5059, 5070, 292, 294, 297, 306, 329, 346, 352, 406, 407, 417, 465, 476, 477, 509, 510, 518, 543, 545, 565, 627, 628, 644, 701, 3704, 721, 765, 775, 776, 3461, 3462, 3474, 3257, 3258, 3270, 3524, 3552, 3546, 3590, 3591, 884, 902, 920, 3611, 3613, 3614, 921, 3909, 3910, 3927, 1052, 3891, 3892, 3372, 3373, 4043, 4047, 4037, 4035, 4036, 1061, 4001, 3348, 3349, 3744, 1363, 1364, 3119, 3119, 4186, 4187, 4183, 3144, 1386, 1454, 1478, 1487, 1503, 1540, 1541, 1543, 1563, 4207, 1576, 1590, 4216, 4214, 1612, 1614, 1623, 1624, 1641, 1641, 1642, 1652, 1722, 1738, 1739, 1750, 1751, 1762, 1794, 1811, 1815, 1877, 1881, 1890, 1919, 1927, 1928, 1980, 1988, 1996, 2017, 2032, 2033, 2115, 2129, 2137, 2205, 5019, 5020, 5021, 2220, 2222, 2222, 2239, 5045, 5046, 2251, 2253, 2255, 2272, 2300, 2349, 2359, 2371, 2382, 2384, 2403, 2419, 2439, 2457, 2473, 2479, 2493, 2493, 2498, 2517, 2531, 2550, 2551, 2555, 2572, 2576, 2602, 2613, 2615, 2660, 2675, 2676, 2695, 2704, 2709, 2714, 2715, 5007, 5006, 5007, 4988, 4988, 4827, 4828, 4831, 4998, 4962, 4390, 4378, 2815, 4457, 4458, 2815, 2815, 4449, 4451, 4421, 4451, 4452, 4466, 2837, 4426, 4425, 4426, 2841, 4666, 2876, 2877, 4356, 4744, 4690, 4805, 2925, 4876, 4877, 2941, 2946, 3006, 3029, 3031, 1, 3, 36, 103, 115, 116, 171, 3494, 3495, 5059, 292, 293, 293, 294, 306, 404, 406, 417, 509, 518, 519, 627, 644, 654, 698, 701, 721, 764, 765, 776, 3461, 3474, 3570, 3524, 3546, 3548, 3590, 884, 897, 3613, 921, 922, 3909, 3927, 3937, 3947, 1052, 3892, 3372, 4043, 4066, 4047, 4035, 4036, 3347, 3348, 3744, 3119, 4187, 4179, 4181, 4183, 1386, 1454, 1487, 1503, 1541, 1543, 4207, 1575, 1576, 4216, 1622, 1623, 1641, 1720, 1722, 1739, 1750, 1762, 1763, 1918, 1919, 1928, 1979, 1980, 1996, 2017, 2033, 2035, 2115, 2137, 2139, 5020, 5021, 2222, 5045, 5046, 2253, 2255, 2300, 2301, 2349, 2371, 2372, 2382, 2403, 2416, 2416, 2419, 2457, 2493, 2517, 2520, 2531, 2551, 2553, 2553, 2555, 2576, 2601, 2602, 2615, 2660, 2676, 2677, 2695, 2709, 2711, 2713, 2714, 5007, 4831, 4962, 2747, 4457, 2815, 4451, 4451, 4421, 4419, 4421, 4452, 4464, 4464, 4466, 4426, 4425, 2841, 2844, 4648, 4666, 2877, 4877, 2946, 2948, 3006, 3031, 3045, 102, 103, 116, 171, 3495, 3479, 265, 5059, 293, 293, 306, 308, 404, 417, 419, 624, 627, 654, 763, 764, 776, 3460, 3461, 3570, 3613, 922, 3616, 3908, 3909, 3937, 3947, 3892, 3372, 4047, 4036, 4001, 3346, 3347, 3744, 1503, 1541, 4207, 1575, 4216, 4217, 1622, 1641, 1652, 1719, 1720, 1739, 1750, 1763, 1765, 1918, 1928, 1929, 2016, 2017, 2035, 2097, 2115, 2139, 5020, 2222, 5045, 2255, 2301, 2302, 2343, 2349, 2372, 2375, 2382, 2416, 2416, 2457, 2467, 2473, 2493, 2520, 2600, 2601, 2615, 2692, 2695, 2711, 2713, 5007, 5005, 4971, 5005, 5006, 4830, 4831, 2747, 4457, 4451, 4419, 4420, 4421, 4464, 4424, 4425, 2844, 4634, 4648, 2877, 4877, 2948, 2954, 2970, 3006, 3045, 102, 116, 126, 265, 293, 308, 403, 404, 419, 624, 654, 655, 763, 776, 778, 3460, 3570, 3571, 3920, 3908, 3937, 3942, 3947, 3372, 4046, 4047, 4022, 4022, 4047, 4001, 3346, 3744, 3745, 1503, 4207, 4208, 1622, 1652, 1656, 1719, 1739, 1748, 1917, 1918, 1929, 2015, 2016, 2035, 2097, 2139, 2145, 5020, 5045, 2253, 2255, 2302, 2320, 2343, 2372, 2373, 2416, 2467, 2468, 2473, 2520, 2521, 2691, 2692, 2711, 4971, 5006, 4988, 4829, 4830, 4840, 4839, 4830, 2747, 4438, 4456, 4457, 4420, 4464, 4426, 4424, 2844, 2845, 4634, 2877, 2879, 4877, 2954, 2966, 2970, 3045, 3046, 100, 102, 126, 265, 308, 313, 403, 419, 420, 623, 624, 655, 750, 763, 778, 3457, 3460, 3571, 3920, 3937, 3938, 3942, 3372, 4066, 4046, 4022, 4020, 4022, 4001, 3998, 3345, 3346, 3745, 1622, 1656, 1657, 1717, 1719, 1748, 1917, 1929, 1930, 2015, 2035, 2036, 4928, 2097, 2145, 5020, 2253, 2255, 2255, 2320, 2332, 2343, 2373, 2375, 2416, 2468, 2469, 2473, 2521, 2522, 2713, 5005, 4971, 4970, 4971, 4819, 4818, 4819, 4971, 4829, 4840, 4837, 4438, 4457, 4413, 4413, 4457, 4419, 4420, 4426, 4424, 4424, 2845, 2846, 4633, 4634, 2879, 265, 313, 315, 402, 403, 420, 622, 623, 655, 750, 778, 796, 3457, 3571, 3572, 3920, 3938, 3936, 4064, 4046, 4020, 4021, 4022, 3998, 3345, 3745, 3746, 1716, 1717, 1748, 1916, 1917, 1930, 2014, 2015, 2036, 4928, 2145, 2146, 2205, 5020, 2255, 2342, 2343, 2375, 2416, 2469, 2472, 2473, 2522, 2525, 2713, 4971, 4969, 4970, 4819, 4817, 4818, 4971, 4988, 4438, 4413, 4410, 4412, 4413, 4419, 4633, 2879, 2880, 260, 265, 315, 621, 622, 655, 748, 750, 796, 3456, 3457, 3572, 4063, 4064, 4020, 4021, 3998, 3991, 1716, 1748, 1749, 2014, 2036, 2038, 2205, 2255, 2332, 2332, 2342, 2375, 2472, 2473, 2525, 2713, 4969, 4970, 4818, 4988, 4828, 4438, 4410, 4411, 4412, 4419, 4420, 4633, 2880, 2886, 254, 260, 315, 621, 655, 656, 745, 748, 796, 4063, 4020, 4021, 1716, 1749, 1750, 2013, 2014, 2038, 2332, 2375, 2416, 2472, 2525, 2531, 2712, 2713, 4970, 4817, 4818, 4828, 4411, 4412, 4420, 4506, 4633, 2886, 254, 315, 328, 621, 656, 657, 745, 796, 797, 4062, 4063, 4021, 1712, 1716, 1750, 2013, 2038, 2049, 2332, 2416, 2472, 4970, 4817, 4828, 4411, 4420, 4424, 4506, 2886, 2891, 251, 254, 328, 620, 621, 657, 4062, 4021, 3991, 1712, 1750, 1765, 2013, 2049, 2050, 2332, 2472, 2531, 4411, 4424, 2846, 4496, 4506, 2891, 251, 328, 329, 618, 620, 657, 4061, 4062, 3991, 1712, 1765, 1766, 2012, 2013, 2050, 2332, 2531, 2553, 4438, 4411, 2846, 4501, 4496, 2891, 5064, 251, 329, 618, 657, 660, 4061, 3991, 3989, 2012, 2050, 2052, 4437, 4438, 2846, 4501, 2891, 2909, 237, 5064, 329, 618, 660, 661, 4060, 4061, 3989, 2004, 2012, 2052, 4437, 2846, 2847, 4500, 4501, 2909, 4479, 4480, 2909, 2909, 2910, 4360, 4788, 4360, 4356, 237, 329, 352, 615, 618, 661, 2004, 2052, 2053, 4440, 4437, 2847, 4499, 4500, 2909, 4479, 2909, 4360, 4788, 4356, 4690, 235, 237, 352, 611, 615, 661, 2003, 2004, 2053, 4440, 2847, 2848, 4499, 2909, 4480, 4478, 4479, 4360, 4787, 4788, 4690, 5085, 235, 352, 611, 661, 663, 2003, 2053, 2054, 2815, 4440, 2848, 4564, 2848, 2849, 4478, 4360, 4788, 4791, 4787, 4690, 5085, 352, 353, 610, 611, 663, 2002, 2003, 2054, 4390, 2815, 2848, 4564, 2849, 4566, 4791, 4690, 4766, 222, 5085, 353, 2002, 2054, 2055, 4390, 2848, 4528, 4564, 4566, 4567, 222, 353, 354, 2002, 2055, 2056, 4388, 4389, 4390, 4562, 4564, 4567, 222, 354, 365, 2001, 2002, 2056, 4388, 4390, 4618, 4617, 4618, 4390, 4562, 4567, 2856, 221, 222, 365, 2000, 2001, 2056, 4388, 4618, 4619, 4617, 4390, 4528, 4531, 4532, 4559, 4561, 4562, 2856, 221, 365, 366, 2000, 2056, 2057, 4388, 4619, 4614, 4617, 4528, 4529, 4531, 4559, 4609, 4561, 2856, 4590, 5076, 221, 366, 2000, 2057, 2058, 4387, 4388, 4614, 4617, 4529, 4530, 4530, 4531, 4609, 4561, 4590, 4587, 5076, 366, 368, 1999, 2000, 2058, 4530, 4609, 4610, 4559, 4561, 4587, 5075, 5076, 368, 1998, 1999, 2058, 4617, 4530, 4610, 4612, 4559, 4587, 190, 5075, 368, 1998, 2058, 2060, 4612, 4587, 4588, 190, 368, 369, 1996, 1998, 2060, 4611, 4612, 4588, 190, 369, 371, 1979, 1996, 2060, 4611, 4588, 4589, 190, 371, 373, 1979, 2060, 2062, 4617, 4610, 4611, 4575, 4611, 4589, 190, 373, 374, 1979, 2062, 2071, 4575, 4589, 4555, 1976, 1979, 2071, 4611, 4575, 4576, 4574, 4575, 4555, 1976, 2071, 2072, 4617, 4611, 4576, 4574, 4555, 4556, 4617, 4576, 4572, 4573, 4574, 4556, 4616, 4617, 4572, 4573, 4556, 4551, 4623, 4624, 4551, 4616, 4572, 4573, 4623, 4551, 4552, 4615, 4616, 4573, 4623, 4552, 4553, 4615, 4573, 4551, 4615, 4551, 4624, 4614, 4615, 4624, 4387, 4614, 4624, 4387, 4624, 4625, 4387, 4625, 4627, 4387, 4627, 4628, 4890, 4891, 4901, 3046, 3058, 3061, 4181, 1386, 4164, 4162, 1391, 1392, 1622, 1657, 1660, 4893, 4890, 4901, 3046, 3061, 3062, 4180, 4181, 4164, 4156, 4162, 1392, 1614, 1622, 1660, 2970, 3046, 3062, 4180, 4164, 4167, 4156, 1392, 4135, 1614, 1660, 1670, 4179, 4180, 4167, 4191, 4156, 4135, 4214, 1614, 1670, 3757, 3785, 3742, 3119, 4179, 4167, 4193, 4191, 4135, 4217, 4214, 1670, 3119, 4167, 4193, 4217, 1670, 1672, 3119, 4193, 4135, 1575, 4217, 1672, 3119, 4135, 1400, 1575, 1672, 1674, 3119, 1400, 4150, 1575, 1674, 1680, 3119, 4150, 4151, 1574, 1575, 1680, 4486, 4646, 4648, 2966, 2967, 2969, 68, 70, 72, 126, 127, 134, 401, 402, 422, 491, 495, 497, 503, 505, 509, 524, 525, 527, 534, 536, 539, 543, 565, 567, 583, 584, 587, 610, 663, 666, 3661, 3662, 745, 3499, 3500, 3506, 813, 814, 816, 926, 927, 929, 967, 968, 971, 3803, 1015, 1018, 1029, 1030, 1035, 1035, 1037, 1039, 3326, 3327, 3329, 3310, 3311, 3314, 1121, 1122, 1124, 1140, 1146, 1148, 1188, 1190, 1193, 1221, 1226, 1233, 1234, 1235, 1238, 1252, 1255, 1257, 1267, 1273, 1276, 1292, 1294, 1296, 1339, 1342, 1344, 1350, 1354, 1357, 1357, 1361, 1363, 1435, 1439, 1441, 1572, 1573, 1680, 1815, 1816, 1820, 1846, 1848, 1851, 1890, 1891, 1893, 1916, 1930, 1932, 2158, 2160, 2162, 2194, 2195, 2199, 2636, 2637, 2639, 2748, 2749, 2751, 4352, 4296, 4909, 2747, 2751, 2752, 4351, 4352, 4909, 4900, 4901, 4891, 4670, 4646, 4484, 4485, 4486, 4648, 2966, 2969, 2970, 63, 68, 72, 100, 126, 134, 400, 401, 422, 478, 491, 497, 503, 509, 519, 524, 527, 533, 533, 534, 539, 543, 567, 568, 583, 587, 589, 609, 610, 666, 732, 3661, 745, 3499, 3506, 3248, 812, 813, 816, 925, 926, 929, 962, 967, 971, 3802, 3803, 1018, 1027, 1029, 1035, 1035, 1039, 3915, 3325, 3326, 4097, 4100, 4101, 3329, 3308, 3310, 3314, 3314, 1087, 5352, 1120, 1121, 1124, 1139, 1140, 1148, 1187, 1188, 1193, 1220, 1221, 1233, 1250, 1252, 1257, 1265, 1267, 1276, 1292, 1296, 1298, 1338, 1339, 1344, 1348, 1350, 1357, 1357, 1363, 3119, 1426, 1435, 1441, 4204, 1572, 1680, 1794, 1815, 1820, 1846, 1851, 1852, 1877, 1890, 1893, 1915, 1916, 1932, 2157, 2158, 2162, 2192, 2194, 2199, 2635, 2636, 2639, 4839, 2747, 2752, 4351, 4909, 4910, 4899, 4900, 4891, 4485, 4648, 4631, 4877, 2966, 2970, 63, 72, 79, 100, 134, 136, 400, 422, 423, 478, 497, 498, 519, 524, 533, 543, 568, 583, 609, 666, 667, 732, 745, 797, 3499, 3248, 3249, 811, 812, 3517, 3516, 812, 816, 962, 971, 972, 972, 3626, 3623, 3325, 4097, 4099, 3314, 5352, 5351, 1120, 1124, 1125, 1139, 1148, 4125, 1187, 1193, 1194, 1220, 1233, 1234, 1250, 1257, 1258, 1265, 1276, 1281, 1338, 1344, 1346, 1348, 1357, 3119, 4204, 1680, 1685, 1794, 1820, 1835, 1843, 1846, 1852, 1877, 1893, 1895, 1915, 1932, 1933, 2157, 2162, 2163, 2192, 2199, 2201, 2635, 2639, 2640, 4839, 2752, 2754, 4351, 4910, 4911, 4899, 4891, 4892, 4484, 4485, 4631, 59, 63, 79, 96, 100, 136, 397, 400, 423, 477, 478, 498, 503, 519, 533, 543, 583, 589, 3668, 609, 667, 731, 732, 797, 811, 3517, 3518, 3324, 3325, 4099, 1119, 1120, 1125, 1135, 1139, 4125, 1184, 1187, 1194, 1220, 1234, 1238, 1314, 1338, 1346, 1347, 1348, 3119, 4203, 4204, 1685, 1791, 1794, 1835, 1842, 1843, 1852, 1877, 1895, 1898, 1914, 1915, 1933, 2156, 2157, 2163, 2192, 2201, 2204, 4838, 4839, 2754, 4351, 4911, 4914, 58, 59, 79, 96, 136, 140, 477, 498, 499, 503, 533, 539, 3677, 3668, 667, 728, 731, 797, 3240, 811, 3518, 1119, 1125, 1127, 1135, 4125, 4123, 1219, 1220, 1238, 1298, 1314, 1346, 1346, 1347, 3119, 4208, 4203, 1685, 1789, 1791, 1835, 1842, 1852, 1857, 1914, 1933, 1934, 2156, 2163, 2164, 2180, 2192, 2204, 5027, 5028, 2204, 4837, 4838, 2754, 58, 79, 86, 96, 140, 147, 465, 477, 499, 503, 539, 541, 3677, 667, 672, 3277, 3240, 3518, 1119, 1127, 1129, 1134, 1135, 4123, 1219, 1238, 1239, 1298, 1346, 3119, 1503, 4208, 1685, 1789, 1835, 1836, 1840, 1842, 1857, 1912, 1914, 1934, 4952, 2156, 2164, 2180, 2204, 5029, 5032, 5027, 2204, 4837, 2754, 2755, 58, 86, 91, 95, 96, 147, 3676, 3677, 672, 3277, 3518, 3519, 1118, 1119, 1129, 1134, 4123, 3383, 1218, 1219, 1239, 1503, 1685, 1687, 1838, 1840, 1857, 1912, 1934, 1935, 5032, 2204, 2205, 4829, 4837, 2755, 58, 91, 94, 94, 95, 147, 3676, 672, 674, 3742, 1118, 1129, 1132, 1134, 3383, 1216, 1218, 1239, 1503, 1687, 1709, 1836, 1838, 1857, 1911, 1912, 1935, 5032, 2205, 2332, 4829, 2755, 2756, 38, 58, 94, 94, 147, 154, 3690, 3676, 674, 3742, 1129, 1130, 1132, 3383, 3384, 1215, 1216, 1239, 1454, 1503, 1709, 1836, 1857, 1864, 1911, 1935, 1936, 5030, 5031, 5032, 5036, 5032, 2332, 4829, 2756, 2757, 38, 94, 154, 3690, 674, 675, 3742, 1130, 1131, 1132, 3384, 3385, 1215, 1239, 1247, 1451, 1454, 1709, 1836, 1864, 1865, 1910, 1911, 1936, 5030, 5032, 5036, 5035, 5036, 2332, 37, 38, 154, 3717, 3690, 675, 1214, 1215, 1247, 1451, 1709, 1710, 1789, 1836, 1865, 1910, 1936, 1939, 5029, 5030, 5036, 5038, 5035, 2332, 37, 154, 168, 3717, 675, 676, 1214, 1247, 1248, 1451, 1710, 1712, 1789, 1865, 1866, 1909, 1910, 1939, 2180, 5029, 5036, 5038, 2332, 2553, 36, 37, 168, 3716, 3717, 676, 1214, 1248, 1249, 1787, 1789, 1866, 1909, 1939, 1940, 2180, 5036, 5037, 5038, 2553, 2576, 1, 36, 168, 3716, 676, 677, 1212, 1214, 1249, 1787, 1866, 1867, 1907, 1909, 1940, 2180, 5037, 5038, 5038, 2576, 2577, 598, 3716, 677, 1780, 1787, 1867, 1907, 1940, 1944, 2180, 5038, 2577, 598, 677, 679, 1780, 1867, 1868, 1906, 1907, 1944, 2180, 2577, 2588, 593, 598, 679, 1780, 1868, 1877, 1906, 1944, 1945, 2180, 2588, 2593, 593, 679, 681, 1775, 1780, 1877, 1905, 1906, 1945, 2168, 2180, 2593, 593, 681, 682, 1773, 1775, 1877, 1905, 1945, 1946, 2168, 2593, 2598, 592, 593, 682, 1903, 1905, 1946, 2168, 2598, 2600, 592, 682, 683, 1903, 1946, 1950, 2166, 2168, 2600, 590, 592, 683, 1901, 1903, 1950, 590, 683, 684, 1901, 1950, 1951, 3699, 590, 684, 1899, 1901, 1951, 589, 590, 3699, 3700, 3699, 684, 1899, 1951, 1952, 589, 3699, 3701, 3700, 684, 686, 1899, 1952, 1954, 589, 3701, 3700, 3700, 686, 689, 1898, 1899, 1954, 589, 3700, 689, 1877, 1898, 1954, 543, 589, 689, 1877, 1954, 1957, 543, 689, 695, 1877, 1957, 1958, 4245, 4246, 1958, 1958, 1959, 1961, 543, 695, 697, 1877, 1958, 4248, 4245, 1958, 1961, 543, 697, 698, 1773, 1877, 4248, 4243, 4245, 1961, 543, 698, 721, 1773, 4248, 4250, 4241, 4243, 1961, 1773, 4250, 4261, 4241, 1961, 1964, 1773, 4261, 4265, 4240, 4241, 1964, 1773, 4265, 4268, 4240, 1964, 1967, 1772, 1773, 4268, 4238, 4240, 1967, 1771, 1772, 4268, 4236, 4238, 1967, 1771, 4268, 4269, 4236, 1967, 4290, 4235, 4236, 4290, 4234, 4235, 4290, 4234, 4290, 4292, 4233, 4234, 4292, 4232, 4233, 4292, 4232, 4292, 1973, 4231, 4232, 1973, 4231, 1973, 1974, 4230, 4231, 1974, 4230, 1974, 1976, 4229, 4230, 1976, 4283, 4229, 1976, 4283, 1976, 2072, 4279, 4283, 2072, 4277, 4279, 2072, 4277, 2072, 2073, 4277, 2073, 2075, 4277, 2075, 2076, 4276, 4277, 2076, 4276, 2076, 2082, 4275, 4276, 2082, 4269, 4275, 2082, 1771, 4269, 2082, 4927, 2654, 2660, 2082, 4927, 2660, 2082, 2660, 2677, 2082, 2677, 2678, 2082, 2678, 2679, 1771, 2082, 2679, 1771, 2679, 2690, 1771, 2690, 2691, 1769, 1771, 2691, 1182, 1184, 1196, 1426, 1441, 1444, 397, 423, 425, 464, 465, 503, 3516, 816, 821, 3618, 925, 931, 396, 397, 426, 463, 464, 503, 3519, 3516, 821, 1181, 1182, 1196, 1421, 1426, 1444, 395, 396, 426, 463, 503, 541, 3277, 3519, 821, 1181, 1196, 1197, 1421, 1444, 1445, 395, 426, 428, 462, 463, 541, 3277, 821, 826, 1179, 1181, 1197, 1418, 1421, 1445, 395, 428, 430, 461, 462, 541, 3277, 826, 3511, 1179, 1197, 1198, 394, 395, 430, 3277, 3511, 3512, 1179, 1198, 1199, 393, 394, 430, 3275, 3277, 3512, 1177, 1179, 1199, 393, 430, 431, 3275, 3512, 3537, 1177, 1199, 1200, 393, 431, 432, 3273, 3275, 3537, 1177, 1200, 1201, 392, 393, 432, 3273, 3537, 3561, 3561, 3562, 3563, 4120, 1177, 1201, 391, 392, 432, 3271, 3273, 3561, 4119, 4120, 1201, 391, 432, 433, 4119, 1201, 1203, 390, 391, 433, 4119, 1203, 1204, 1171, 4119, 1204, 1170, 1171, 1204, 1170, 1204, 1205, 1170, 1205, 1206, 1168, 1170, 1206, 1168, 1206, 1212, 1166, 1168, 1212, 1162, 1166, 1212, 1157, 1162, 1212, 1157, 1212, 1249, 1154, 1157, 1249, 1154, 1249, 1250, 1154, 1250, 1258, 1154, 1258, 1259, 1153, 1154, 1259, 1152, 1153, 1259, 1151, 1152, 1259, 1151, 1259, 1261, 1151, 1261, 1262, 1151, 1262, 1264, 3385, 1151, 1264, 3385, 1264, 1265, 3385, 1265, 1281, 1132, 3385, 1281, 1132, 1281, 1290, 4094, 1132, 1290, 4094, 1290, 1291, 4094, 1291, 1292, 1131, 4094, 4092, 4093, 4094, 1292, 3742, 1131, 4092, 4093, 1292, 1298, 3742, 4092, 4093, 4093, 1298, 3119, 3742, 4093, 3119, 4151, 3757, 3742, 3757, 4151, 1418, 3757, 1418, 1445, 3756, 3757, 1445, 3751, 3756, 1445, 388, 390, 436, 3271, 3561, 3558, 3271, 3558, 3559, 386, 388, 436, 3270, 3271, 3559, 386, 436, 438, 3257, 3270, 3559, 385, 386, 438, 3257, 3559, 844, 385, 438, 440, 3257, 844, 850, 384, 385, 440, 3256, 3257, 3448, 3447, 3257, 850, 384, 440, 441, 3254, 3256, 3448, 3447, 850, 852, 380, 384, 441, 3252, 3254, 3448, 3445, 3447, 852, 3251, 3252, 3448, 3443, 3445, 852, 3251, 3448, 3449, 3443, 852, 3522, 3251, 3449, 3450, 3439, 3443, 3522, 3251, 3450, 3453, 3439, 3522, 3524, 3439, 3524, 3548, 3475, 3439, 3548, 3475, 3548, 3549, 3475, 3549, 864, 3475, 864, 871, 3573, 3475, 871, 3573, 871, 872, 3573, 872, 3582, 3572, 3573, 3582, 3572, 3582, 3580, 3572, 3580, 3581, 3456, 3572, 3581, 3454, 3456, 3581, 3454, 3581, 3590, 3453, 3454, 3590, 3453, 3590, 897, 3453, 897, 898, 3453, 898, 901, 902, 931, 936, 902, 936, 938, 902, 938, 941, 901, 902, 941, 3453, 901, 941, 3251, 3453, 941, 3251, 941, 942, 379, 380, 442, 379, 442, 443, 378, 379, 443, 377, 378, 443, 377, 443, 444, 377, 444, 445, 375, 377, 445, 374, 375, 445, 374, 445, 446, 374, 446, 447, 190, 374, 447, 190, 447, 461, 190, 461, 541, 190, 541, 543, 190, 543, 721, 797, 3488, 190, 728, 797, 190, 3488, 797, 798, 3484, 3488, 798, 3484, 798, 800, 3480, 3484, 800, 3480, 800, 3499, 3479, 3480, 3499, 3479, 3499, 3249, 171, 3479, 3249, 3088, 171, 3249, 3088, 3249, 3251, 3088, 3251, 942, 3088, 942, 943, 944, 3644, 3642, 3642, 3643, 972, 943, 944, 3642, 3642, 972, 3624, 3625, 3626, 972, 943, 3642, 3624, 3625, 972, 973, 3633, 3634, 973, 973, 974, 975, 943, 3624, 3625, 3625, 973, 3635, 3633, 973, 975, 943, 3625, 3635, 3633, 975, 983, 943, 3635, 3633, 3633, 983, 984, 943, 3633, 984, 984, 3428, 3429, 3427, 3428, 984, 984, 985, 3416, 943, 984, 3195, 3193, 3194, 3195, 3195, 984, 3429, 3427, 984, 3416, 3088, 943, 3195, 3228, 3193, 3195, 3195, 3429, 3431, 3426, 3427, 3416, 3088, 3195, 3196, 3224, 3228, 3195, 3424, 3426, 3416, 3088, 3196, 3199, 3224, 3195, 3431, 3424, 3416, 3418, 3088, 3199, 3200, 3224, 3431, 3424, 3424, 3418, 3419, 3088, 3200, 3201, 3224, 3424, 3419, 3224, 3419, 3421, 3222, 3224, 3421, 3222, 3421, 3799, 3221, 3222, 3819, 3818, 3222, 3799, 3221, 3819, 3808, 3818, 3799, 3801, 3220, 3221, 3808, 3816, 3818, 3801, 3219, 3220, 3808, 3816, 3801, 3802, 3219, 3808, 3809, 3815, 3816, 3802, 3218, 3219, 3809, 3815, 3802, 1018, 3218, 3809, 3813, 3814, 3815, 1018, 3217, 3218, 3813, 3814, 1018, 1019, 3217, 3813, 3814, 3217, 3814, 1019, 3216, 3217, 1019, 3211, 3216, 1019, 1023, 3211, 1019, 3211, 1023, 1025, 3210, 3211, 1025, 3208, 3210, 1025, 3206, 3207, 3208, 3208, 3835, 3836, 3834, 3208, 1025, 3206, 3208, 3836, 3834, 1025, 1027, 3205, 3206, 3836, 3201, 3205, 3836, 3201, 3836, 3841, 3088, 3201, 3841, 3088, 3841, 3842, 3088, 3842, 3844, 3082, 3088, 3844, 3082, 3844, 3845, 3847, 3879, 3880, 4055, 3955, 3956, 3957, 3959, 3973, 3847, 3880, 3881, 3881, 4055, 3956, 3957, 3973, 1080, 3847, 3881, 3956, 3956, 3957, 1080, 3956, 1080, 4080, 3847, 3956, 4080, 3847, 4080, 4011, 3847, 4011, 3316, 3845, 3847, 3316, 3845, 3316, 3296, 3845, 3296, 3297, 3845, 3297, 3298, 3305, 3330, 3323, 3329, 3330, 3306, 3329, 3306, 3307, 1095, 1101, 3874, 3308, 1095, 3874, 3308, 3874, 3875, 3307, 3308, 3875, 3307, 3875, 3876, 3307, 3876, 3864, 3864, 3865, 3866, 3329, 3307, 3864, 4100, 3329, 3864, 4100, 3864, 3866, 4100, 3866, 3337, 4099, 4100, 3337, 3339, 4099, 3337, 3338, 3339, 3337, 4099, 3339, 3340, 3324, 4099, 3340, 3324, 3340, 3343, 3324, 3343, 3344, 3323, 3324, 3344, 3323, 3344, 3345, 3305, 3323, 3345, 3298, 3304, 3345, 3845, 3298, 3345, 3845, 3345, 3746, 3082, 3845, 3746, 3082, 3746, 3748, 3082, 3748, 3750, 3751, 1451, 1712, 3750, 3751, 1712, 3082, 3750, 1712, 3082, 1712, 1766, 3082, 1766, 1767, 3082, 1767, 1768, 3082, 1768, 1769, 2691, 2797, 2798, 1769, 2691, 2798, 1769, 2798, 2799, 1769, 2799, 4340, 4339, 4340, 2801, 3082, 1769, 4340, 4339, 2801, 4387, 3082, 4340, 4346, 4338, 4339, 4387, 3082, 4346, 4350, 4329, 4338, 4387, 3073, 3082, 4350, 3062, 3073, 4350, 3062, 4350, 4351, 4914, 4884, 4881, 4351, 4914, 4881, 4870, 4881, 4882, 4351, 4881, 4870, 4869, 4870, 4882, 4351, 4870, 4867, 4869, 4882, 4883, 4351, 4867, 4868, 4869, 4899, 4892, 4868, 4869, 4892, 4351, 4868, 4892, 3062, 4351, 4892, 3062, 4892, 4893, 2970, 3062, 4893, 2970, 4893, 4917, 4877, 2970, 4917, 4876, 4877, 4917, 4876, 4917, 4922, 4876, 4922, 3394, 4805, 4876, 3394, 4805, 3394, 4300, 4804, 4805, 4300, 4803, 4804, 4300, 4803, 4300, 4304, 4801, 4803, 4304, 4811, 4801, 4304, 4780, 4783, 4368, 4513, 4514, 4491, 4522, 4669, 4670, 4780, 4368, 4370, 4522, 4670, 4484, 4522, 4484, 4631, 4521, 4522, 4631, 4521, 4631, 4509, 4519, 4521, 4509, 4519, 4509, 4656, 4518, 4519, 4656, 4491, 4518, 4656, 4491, 4656, 4584, 4491, 4584, 4579, 4491, 4579, 4499, 4513, 4491, 4499, 4366, 4513, 4499, 4366, 4499, 4480, 4365, 4366, 4480, 4365, 4480, 4477, 4370, 4365, 4477, 4370, 4477, 4478, 4780, 4370, 4478, 4780, 4478, 4788, 4779, 4780, 4788, 4779, 4788, 4789, 4778, 4779, 4789, 4778, 4789, 4756, 4778, 4756, 4710, 4755, 4790, 4791, 4305, 4778, 4710, 4713, 4756, 4753, 4754, 4755, 4791, 4304, 4305, 4710, 4754, 4791, 4766, 4304, 4710, 4711, 4753, 4754, 4766, 4811, 4304, 4711, 4753, 4766, 4721, 4731, 4721, 4722, 4753, 4721, 4731, 4730, 4731, 4722, 4753, 4731, 4729, 4730, 4722, 4810, 4713, 4753, 4729, 4729, 4730, 4810, 4712, 4713, 4729, 4712, 4729, 4810, 4711, 4712, 4810, 4778, 4305, 4321, 4490, 4491, 4511, 4368, 4778, 4321, 4489, 4490, 4511, 4493, 4489, 4511, 4368, 4373, 4374, 4511, 4368, 4374, 4516, 4517, 4493, 4374, 4329, 4594, 4596, 4516, 4493, 4511, 4374, 4594, 4595, 4596, 4493, 4511, 4594, 4595, 4595, 4493, 4511, 4593, 4594, 4329, 4593, 4329, 4387, 4516, 4593, 4387, 4516, 4387, 4628, 4522, 4516, 4628, 4522, 4628, 4622, 4522, 4622, 4623, 4669, 4522, 4623, 4669, 4623, 4553, 2862, 4669, 4553, 4373, 4368, 4321, 4328, 4329, 4374, 4373, 4321, 4322, 4326, 4328, 4374, 4375, 4373, 4322, 4326, 4374, 4375, 4375, 4322, 4326, 4899, 4869, 4883, 4883, 4917, 4901, 4901, 4899, 4883, 4884, 4914, 4915, 4915, 4916, 4917, 4883, 4884, 4915, 4915, 4917, 4883, 2797, 2691, 2711, 2797, 2711, 2712, 2787, 2797, 2712, 2787, 2712, 4828, 4855, 4856, 4828, 2787, 4828, 4857, 4855, 4828, 4829, 2772, 2787, 4857, 4854, 4855, 4829, 4857, 4858, 4849, 4854, 4829, 2757, 2772, 4857, 4849, 4849, 4854, 2757, 2772, 4849, 4850, 4848, 4849, 2757, 2761, 2772, 4850, 4851, 4848, 2757, 2761, 4850, 4851, 4851, 2757, 2758, 2759, 2761, 4851, 4851, 2758, 2759, 1451, 3751, 1445, 1451, 1445, 1450, 1447, 1449, 1446, 3871, 3863, 3870, 1101, 1095, 1096, 1097, 4072, 1101, 1095, 3308, 3314, 3330, 3305, 3306, 3879, 3847, 3823, 4055, 4056, 3883, 3883, 3879, 3823, 4055, 3883, 3823, 4055, 3823, 3824, 3825, 3942, 4066, 3825, 4066, 4068, 3824, 3825, 4068, 4055, 3824, 4068, 4055, 4068, 4060, 4055, 4060, 3989, 3955, 4055, 3989, 3955, 3989, 4001, 4001, 1069, 3964, 4001, 3964, 3966, 3955, 4001, 3966, 3960, 3955, 3966, 3964, 1069, 3965, 3942, 3825, 3832, 3936, 3942, 3832, 3936, 3832, 3834, 3920, 3936, 3834, 3919, 3920, 3834, 3919, 3834, 1027, 3918, 3919, 1027, 3916, 3918, 1027, 3644, 944, 962, 962, 972, 3643, 3643, 3644, 962, 931, 902, 3611, 931, 3611, 3617, 3617, 3618, 931, 3613, 3616, 3612, 2654, 4927, 4928, 2654, 4928, 2146, 2654, 2146, 2149, 2650, 2654, 2149, 2645, 2650, 2149, 2634, 2640, 2641, 2633, 2634, 2641, 4951, 4952, 2165, 2633, 2641, 2643, 4951, 2165, 4937, 4934, 4935, 2165, 2630, 2633, 2643, 4934, 2165, 2166, 2630, 2643, 2644, 4934, 2166, 2600, 2629, 2630, 2644, 4939, 4934, 2600, 2629, 2644, 2645, 4938, 4939, 2600, 2629, 2645, 2149, 4938, 2600, 2615, 2628, 2629, 2149, 4937, 4938, 2615, 2628, 2149, 2153, 4937, 2615, 2620, 2625, 2628, 2153, 4937, 2620, 2622, 2625, 2153, 4950, 4951, 4937, 2622, 2624, 2625, 4950, 4951, 2622, 2623, 2623, 2624, 4950, 4950, 4951, 2623];
 testOutput["water_huge2"]["pass"]=true;
-testOutput["water_huge2"]["report"]="exp numtri:4461\nexp dev:0.0028\nact numtri:4461\nact dev:0.002743968120987072";
+testOutput["water_huge2"]["report"]="exp numtri:4461\nexp dev:0.0028\nact numtri:4461\nact dev:0.002740892282734641";
@@ -0,0 +1,25 @@
+// writes the triangles earcut.js makes of each of the test fixtures to
+// tests/upstream, for upstream_test to compare earcutr against.
+//   node viz/upstream.js
+
+"use strict";
+
+var fs = require("fs");
+var path = require("path");
+
+// earcut.js logs as it goes; keep the output clean
+console.log = function () {};
+
+var earcut = require("./earcut.js");
+var fixtures = path.join(__dirname, "..", "tests", "fixtures");
+var upstream = path.join(__dirname, "..", "tests", "upstream");
+
+if (!fs.existsSync(upstream)) fs.mkdirSync(upstream);
+
+fs.readdirSync(fixtures).filter(function (name) {
+    return name.endsWith(".json");
+}).sort().forEach(function (name) {
+    var data = earcut.flatten(JSON.parse(fs.readFileSync(path.join(fixtures, name))));
+    var triangles = earcut(data.vertices, data.holes, data.dimensions);
+    fs.writeFileSync(path.join(upstream, name), JSON.stringify(triangles) + "\n");
+});